@@ -0,0 +1,278 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Bid amendment policy handlers.
+//!
+//! Each bid year records whether, and for how long, a controller may
+//! amend a bid they have already submitted. The policy is fixed while the
+//! bid year is being set up and enforced by the core on every further
+//! leave entry in a round.
+
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{OffsetDateTime, PrimitiveDateTime};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{BidAmendment, BidAmendmentPolicy, BidYearLifecycle, DomainError};
+use zab_bid_persistence::{
+    BidAmendmentPolicyData, LeaveBidData, OperatorData, RoundBidderData, SqlitePersistence,
+};
+
+use crate::auth::AuthenticatedActor;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_domain_error};
+use crate::leave_bids::load_lifecycle_state;
+use crate::request_response::{
+    GetBidAmendmentPolicyResponse, SetBidAmendmentPolicyRequest, SetBidAmendmentPolicyResponse,
+};
+use crate::webhooks::require_admin;
+
+/// Describes a policy for audit snapshots.
+fn describe_policy(policy: BidAmendmentPolicy) -> String {
+    policy.window_hours().map_or_else(
+        || format!("amendment_policy={}", policy.as_str()),
+        |hours| format!("amendment_policy={},window_hours={hours}", policy.as_str()),
+    )
+}
+
+/// Parses the stored creation time of a leave bid (UTC).
+fn parse_created_at(bid: &LeaveBidData) -> Result<OffsetDateTime, ApiError> {
+    PrimitiveDateTime::parse(
+        &bid.created_at,
+        format_description!("[year]-[month]-[day] [hour]:[minute]:[second]"),
+    )
+    .map(PrimitiveDateTime::assume_utc)
+    .map_err(|e| ApiError::Internal {
+        message: format!(
+            "Leave bid {} has an invalid creation time '{}': {e}",
+            bid.leave_bid_id, bid.created_at
+        ),
+    })
+}
+
+/// Loads when a user's bid window in a round closes, if one is scheduled.
+fn load_window_end(
+    persistence: &mut SqlitePersistence,
+    area_id: i64,
+    round_id: i64,
+    user_id: i64,
+) -> Result<Option<OffsetDateTime>, ApiError> {
+    let bidders: Vec<RoundBidderData> =
+        persistence
+            .list_round_bidders(area_id, round_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list round bidders: {e}"),
+            })?;
+    let Some(bidder) = bidders.iter().find(|bidder| bidder.user_id == user_id) else {
+        return Ok(None);
+    };
+
+    OffsetDateTime::parse(&bidder.window_end_datetime, &Rfc3339)
+        .map(Some)
+        .map_err(|e| ApiError::Internal {
+            message: format!(
+                "Failed to parse window timestamp '{}': {e}",
+                bidder.window_end_datetime
+            ),
+        })
+}
+
+/// Loads a bid year's amendment policy.
+///
+/// A bid year without a stored policy allows amendments at any time.
+///
+/// # Errors
+///
+/// Returns an error if the policy cannot be read or the stored policy is
+/// invalid.
+pub fn load_amendment_policy(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<BidAmendmentPolicy, ApiError> {
+    let Some(data): Option<BidAmendmentPolicyData> = persistence
+        .get_bid_amendment_policy(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get bid amendment policy: {e}"),
+        })?
+    else {
+        return Ok(BidAmendmentPolicy::default());
+    };
+
+    let invalid = || ApiError::Internal {
+        message: format!("Stored amendment policy of bid year {bid_year_id} is invalid"),
+    };
+    let window_hours: Option<u32> = data
+        .window_hours
+        .map(u32::try_from)
+        .transpose()
+        .map_err(|_| invalid())?;
+    BidAmendmentPolicy::from_parts(&data.policy, window_hours).map_err(|_| invalid())
+}
+
+/// Builds the amendment context for a further leave entry in a round.
+///
+/// The first submission time comes from the controller's earliest leave
+/// bid in the round and the deadline for `until_window_closes` from their
+/// scheduled window.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `policy` - The bid year's amendment policy
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+/// * `user_id` - The controller's user ID
+/// * `user_bids` - The controller's leave bids in the round
+/// * `now` - When the amendment is being entered
+///
+/// # Errors
+///
+/// Returns an error if stored timestamps cannot be read.
+pub fn bid_amendment(
+    persistence: &mut SqlitePersistence,
+    policy: BidAmendmentPolicy,
+    area_id: i64,
+    round_id: i64,
+    user_id: i64,
+    user_bids: &[LeaveBidData],
+    now: OffsetDateTime,
+) -> Result<Option<BidAmendment>, ApiError> {
+    let Some(first_submitted_at) = user_bids
+        .iter()
+        .map(parse_created_at)
+        .collect::<Result<Vec<OffsetDateTime>, ApiError>>()?
+        .into_iter()
+        .min()
+    else {
+        return Ok(None);
+    };
+
+    let window_closes_at: Option<OffsetDateTime> =
+        if policy == BidAmendmentPolicy::UntilWindowCloses {
+            load_window_end(persistence, area_id, round_id, user_id)?
+        } else {
+            None
+        };
+
+    Ok(Some(BidAmendment {
+        policy,
+        first_submitted_at,
+        window_closes_at,
+        amended_at: now,
+    }))
+}
+
+/// Gets a bid year's amendment policy.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn get_bid_amendment_policy(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<GetBidAmendmentPolicyResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+    let policy: BidAmendmentPolicy = load_amendment_policy(persistence, bid_year_id)?;
+
+    Ok(GetBidAmendmentPolicyResponse {
+        bid_year_id,
+        policy: policy.as_str().to_string(),
+        window_hours: policy.window_hours(),
+    })
+}
+
+/// Sets a bid year's amendment policy.
+///
+/// The policy is fixed once bidding starts, so every controller's bid is
+/// held to the same amendment window.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year and its new policy
+/// * `authenticated_actor` - The authenticated actor setting the policy
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist
+/// - Bidding has already started
+/// - The policy is unknown or its window is invalid
+/// - The database operation fails
+pub fn set_bid_amendment_policy(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetBidAmendmentPolicyRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetBidAmendmentPolicyResponse, ApiError> {
+    require_admin(authenticated_actor, "set bid amendment policy")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    if matches!(
+        lifecycle_state,
+        BidYearLifecycle::BiddingActive | BidYearLifecycle::BiddingClosed
+    ) {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("set bid amendment policy"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+
+    let policy: BidAmendmentPolicy =
+        BidAmendmentPolicy::from_parts(request.policy.trim(), request.window_hours)
+            .map_err(translate_domain_error)?;
+    let window_hours: Option<i32> = policy
+        .window_hours()
+        .map(i32::try_from)
+        .transpose()
+        .map_err(|_| ApiError::InvalidInput {
+            field: String::from("window_hours"),
+            message: String::from("Amendment window is out of range"),
+        })?;
+
+    let previous: BidAmendmentPolicy = load_amendment_policy(persistence, request.bid_year_id)?;
+    persistence
+        .set_bid_amendment_policy(request.bid_year_id, policy.as_str(), window_hours)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to store bid amendment policy: {e}"),
+        })?;
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("SetBidAmendmentPolicy"),
+        Some(format!(
+            "Set bid amendment policy for bid year {year} to {}",
+            policy.as_str()
+        )),
+    );
+    let before: StateSnapshot = StateSnapshot::new(describe_policy(previous));
+    let after: StateSnapshot = StateSnapshot::new(describe_policy(policy));
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetBidAmendmentPolicyResponse {
+        bid_year_id: request.bid_year_id,
+        policy: policy.as_str().to_string(),
+        window_hours: policy.window_hours(),
+        message: format!(
+            "Bid year {year} amendment policy set to {}",
+            policy.as_str()
+        ),
+    })
+}
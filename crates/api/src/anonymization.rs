@@ -0,0 +1,159 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Data retention and erasure handlers.
+//!
+//! Once a bid year has closed and its retention period has passed, an
+//! Admin may erase a former employee's personal data. Names and seniority
+//! dates are replaced with tombstone values in canonical tables and stored
+//! snapshots. Initials, bids, and the audit log are kept, so the year's
+//! history still reads the same. Each erasure is recorded as an audit
+//! event.
+
+use time::{Date, Duration};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{Area, BidYear, BidYearLifecycle, CanonicalBidYear};
+use zab_bid_persistence::{OperatorData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_domain_error};
+use crate::leave_bids::load_lifecycle_state;
+use crate::request_response::{AnonymizeUserRequest, AnonymizeUserResponse};
+use crate::user_initials::user_initials_history;
+use crate::user_merges::user_area;
+use crate::webhooks::require_admin;
+
+/// Default number of days personal data is kept after a bid year ends.
+pub const DEFAULT_RETENTION_DAYS: u32 = 365;
+
+/// Erases a user's personal data once their bid year's retention period
+/// has passed.
+///
+/// The bid year must be `BiddingClosed`, and `today` must be more than
+/// `retention_days` after the bid year's last day.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year and user to anonymize
+/// * `retention_days` - Days personal data is kept after the bid year ends
+/// * `today` - The current date
+/// * `authenticated_actor` - The authenticated actor erasing the data
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year or user does not exist
+/// - The bid year is not closed, or its retention period has not passed
+/// - The user was already anonymized
+/// - The database operation fails
+#[allow(clippy::too_many_arguments)]
+pub fn anonymize_user(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &AnonymizeUserRequest,
+    retention_days: u32,
+    today: Date,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<AnonymizeUserResponse, ApiError> {
+    require_admin(authenticated_actor, "anonymize user")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    if lifecycle_state != BidYearLifecycle::BiddingClosed {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("anonymize_after_close"),
+            message: format!(
+                "Personal data can only be erased once bid year {year} is closed (state: {lifecycle_state})"
+            ),
+        });
+    }
+
+    let canonical_bid_year: CanonicalBidYear = persistence
+        .list_bid_years()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load bid years: {e}"),
+        })?
+        .into_iter()
+        .find(|by| by.year() == year)
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Bid year {year} exists in metadata but not in storage"),
+        })?;
+    let end_date: Date = canonical_bid_year
+        .end_date()
+        .map_err(translate_domain_error)?;
+    let retained_until: Date = end_date
+        .checked_add(Duration::days(i64::from(retention_days)))
+        .ok_or_else(|| ApiError::InvalidInput {
+            field: String::from("retention_days"),
+            message: format!("Retention period of {retention_days} days is too long"),
+        })?;
+    if today <= retained_until {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("retention_period"),
+            message: format!(
+                "Bid year {year} data is retained until {retained_until} ({retention_days} days after it ended on {end_date})"
+            ),
+        });
+    }
+
+    let area: Area = user_area(persistence, metadata, request.bid_year_id, request.user_id)?;
+    let already: bool = persistence
+        .get_user_anonymization(request.user_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to check user anonymization: {e}"),
+        })?
+        .is_some();
+    if already {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("anonymize_once"),
+            message: format!("User {} was already anonymized", request.user_id),
+        });
+    }
+    let held_initials: Vec<String> = user_initials_history(persistence, request.user_id)?;
+    let initials: String = held_initials[0].clone();
+
+    let event: AuditEvent = AuditEvent::new(
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+        Action::new(
+            String::from("AnonymizeUser"),
+            Some(format!(
+                "Erased personal data of user_id={} with initials '{initials}' for bid year {year} after a {retention_days}-day retention period",
+                request.user_id
+            )),
+        ),
+        StateSnapshot::new(format!("user_id={},anonymized=false", request.user_id)),
+        StateSnapshot::new(format!("user_id={},anonymized=true", request.user_id)),
+        BidYear::with_id(request.bid_year_id, year),
+        area,
+    )
+    .with_user_id(Some(request.user_id));
+
+    let (audit_event_id, snapshots_rewritten): (i64, usize) = persistence
+        .anonymize_user(&event, request.bid_year_id, request.user_id, &held_initials)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to anonymize user: {e}"),
+        })?;
+
+    Ok(AnonymizeUserResponse {
+        bid_year_id: request.bid_year_id,
+        user_id: request.user_id,
+        initials: initials.clone(),
+        snapshots_rewritten,
+        audit_event_id,
+        message: format!(
+            "Erased personal data of user '{initials}' ({snapshots_rewritten} snapshots rewritten)"
+        ),
+    })
+}
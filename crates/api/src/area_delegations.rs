@@ -0,0 +1,365 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Area rep delegations.
+//!
+//! A Bidder assigned to areas is that area's rep and may bid only in the
+//! areas assigned to them (see
+//! [`AuthorizationService::authorize_area_bidding`]). To cover a rep's
+//! vacation, an Admin delegates the rep's authority for one area to another
+//! Bidder for a range of dates. The delegate may bid in the area on those
+//! dates only; the delegation expires on its own after its end date, and an
+//! Admin may revoke it sooner. Creating and revoking delegations are
+//! recorded as global audit events.
+//!
+//! [`AuthorizationService::authorize_area_bidding`]: crate::auth::AuthorizationService::authorize_area_bidding
+
+use time::Date;
+use time::format_description::well_known::Iso8601;
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::Area;
+use zab_bid_persistence::{AreaDelegationData, OperatorData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::error::ApiError;
+use crate::leave_bids::resolve_area;
+use crate::request_response::{
+    AreaDelegationInfo, CreateAreaDelegationRequest, CreateAreaDelegationResponse,
+    ListAreaDelegationsResponse, RevokeAreaDelegationRequest, RevokeAreaDelegationResponse,
+};
+use crate::webhooks::{operator_actor, require_admin};
+
+/// The most days a single delegation may span.
+pub const MAX_DELEGATION_DAYS: i64 = 366;
+
+/// Parses a `YYYY-MM-DD` delegation date.
+fn parse_delegation_date(field: &str, value: &str) -> Result<Date, ApiError> {
+    Date::parse(value, &Iso8601::DEFAULT).map_err(|_| ApiError::InvalidInput {
+        field: field.to_string(),
+        message: format!("Invalid date format: {value}"),
+    })
+}
+
+/// Loads an operator or returns `ResourceNotFound`.
+fn load_operator(
+    persistence: &mut SqlitePersistence,
+    operator_id: i64,
+) -> Result<OperatorData, ApiError> {
+    persistence
+        .get_operator_by_id(operator_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get operator: {e}"),
+        })?
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("Operator"),
+            message: format!("Operator with ID {operator_id} not found"),
+        })
+}
+
+/// Returns a delegation's status on `today`.
+fn delegation_status(data: &AreaDelegationData, today: &str) -> &'static str {
+    if today < data.start_date.as_str() {
+        "scheduled"
+    } else if today > data.end_date.as_str() {
+        "expired"
+    } else {
+        "active"
+    }
+}
+
+/// Converts a stored delegation into its API representation.
+fn to_area_delegation_info(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    data: &AreaDelegationData,
+    today: &str,
+) -> Result<AreaDelegationInfo, ApiError> {
+    let area_code: String = metadata
+        .areas
+        .iter()
+        .find(|(_, area)| area.area_id() == Some(data.area_id))
+        .map_or_else(
+            || data.area_id.to_string(),
+            |(_, area)| area.area_code().to_string(),
+        );
+    let delegator: OperatorData = load_operator(persistence, data.delegator_operator_id)?;
+    let delegate: OperatorData = load_operator(persistence, data.delegate_operator_id)?;
+
+    Ok(AreaDelegationInfo {
+        area_delegation_id: data.area_delegation_id,
+        area_id: data.area_id,
+        area_code,
+        delegator_operator_id: data.delegator_operator_id,
+        delegator_login_name: delegator.login_name,
+        delegate_operator_id: data.delegate_operator_id,
+        delegate_login_name: delegate.login_name,
+        start_date: data.start_date.clone(),
+        end_date: data.end_date.clone(),
+        status: delegation_status(data, today).to_string(),
+    })
+}
+
+/// Formats a delegation for audit snapshots.
+fn delegation_snapshot(data: &AreaDelegationData) -> String {
+    format!(
+        "area_delegation_id={},area_id={},delegator_operator_id={},delegate_operator_id={},start_date={},end_date={}",
+        data.area_delegation_id,
+        data.area_id,
+        data.delegator_operator_id,
+        data.delegate_operator_id,
+        data.start_date,
+        data.end_date
+    )
+}
+
+/// Delegates an area rep's bidding authority in one area to another
+/// Bidder for a range of dates (admin only).
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The area, the two operators, and the dates
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The area or either operator does not exist, or the area is a system
+///   area
+/// - The delegator is not assigned to the area
+/// - The delegate is the delegator, is disabled, or is not a Bidder
+/// - A date is invalid, the range is reversed, longer than
+///   [`MAX_DELEGATION_DAYS`], or already over
+/// - The database operation fails
+#[allow(clippy::too_many_lines)]
+pub fn create_area_delegation(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &CreateAreaDelegationRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<CreateAreaDelegationResponse, ApiError> {
+    require_admin(authenticated_actor, "create_area_delegation")?;
+
+    let (_, area): (_, &Area) = resolve_area(metadata, request.area_id)?;
+    if area.is_system_area() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("area_id"),
+            message: format!("System area '{}' cannot be delegated", area.area_code()),
+        });
+    }
+
+    let start_date: Date = parse_delegation_date("start_date", &request.start_date)?;
+    let end_date: Date = parse_delegation_date("end_date", &request.end_date)?;
+    if end_date < start_date {
+        return Err(ApiError::InvalidInput {
+            field: String::from("end_date"),
+            message: String::from("End date must not be before start date"),
+        });
+    }
+    if (end_date - start_date).whole_days() >= MAX_DELEGATION_DAYS {
+        return Err(ApiError::InvalidInput {
+            field: String::from("end_date"),
+            message: format!("A delegation may span at most {MAX_DELEGATION_DAYS} days"),
+        });
+    }
+    if end_date < persistence.now().date() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("end_date"),
+            message: format!("End date {end_date} has already passed"),
+        });
+    }
+
+    let delegator: OperatorData = load_operator(persistence, request.delegator_operator_id)?;
+    let delegate: OperatorData = load_operator(persistence, request.delegate_operator_id)?;
+    if delegate.operator_id == delegator.operator_id {
+        return Err(ApiError::InvalidInput {
+            field: String::from("delegate_operator_id"),
+            message: String::from("An operator cannot delegate to themselves"),
+        });
+    }
+    if delegate.is_disabled {
+        return Err(ApiError::InvalidInput {
+            field: String::from("delegate_operator_id"),
+            message: format!("Operator {} is disabled", delegate.login_name),
+        });
+    }
+    if delegate.role != "Bidder" {
+        return Err(ApiError::InvalidInput {
+            field: String::from("delegate_operator_id"),
+            message: format!(
+                "Operator {} is not a Bidder and needs no delegation",
+                delegate.login_name
+            ),
+        });
+    }
+    let delegator_areas: Vec<i64> = persistence
+        .list_operator_area_ids(delegator.operator_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load operator area scopes: {e}"),
+        })?;
+    if !delegator_areas.contains(&request.area_id) {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("delegator_must_be_area_rep"),
+            message: format!(
+                "Operator {} is not assigned to area '{}'",
+                delegator.login_name,
+                area.area_code()
+            ),
+        });
+    }
+
+    let start: String = start_date.to_string();
+    let end: String = end_date.to_string();
+    let area_delegation_id: i64 = persistence
+        .insert_area_delegation(
+            request.area_id,
+            delegator.operator_id,
+            delegate.operator_id,
+            &start,
+            &end,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record area delegation: {e}"),
+        })?;
+    let data: AreaDelegationData = persistence
+        .get_area_delegation(area_delegation_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to reload area delegation: {e}"),
+        })?
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Area delegation {area_delegation_id} vanished after insert"),
+        })?;
+
+    let audit_event: AuditEvent = AuditEvent::new_global(
+        operator_actor(operator),
+        cause,
+        Action::new(
+            String::from("CreateAreaDelegation"),
+            Some(format!(
+                "Delegated area '{}' from {} to {} for {start} to {end}",
+                area.area_code(),
+                delegator.login_name,
+                delegate.login_name
+            )),
+        ),
+        StateSnapshot::new(String::new()),
+        StateSnapshot::new(delegation_snapshot(&data)),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    let today: String = persistence.now().date().to_string();
+    let delegation: AreaDelegationInfo =
+        to_area_delegation_info(persistence, metadata, &data, &today)?;
+    Ok(CreateAreaDelegationResponse {
+        message: format!(
+            "{} may bid in area '{}' for {} from {start} to {end}",
+            delegate.login_name,
+            area.area_code(),
+            delegator.login_name
+        ),
+        delegation,
+    })
+}
+
+/// Revokes an area delegation before it expires (admin only).
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The delegation to revoke
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin, the delegation does not
+/// exist, or the database operation fails.
+pub fn revoke_area_delegation(
+    persistence: &mut SqlitePersistence,
+    request: &RevokeAreaDelegationRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<RevokeAreaDelegationResponse, ApiError> {
+    require_admin(authenticated_actor, "revoke_area_delegation")?;
+
+    let area_delegation_id: i64 = request.area_delegation_id;
+    let data: AreaDelegationData = persistence
+        .get_area_delegation(area_delegation_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get area delegation: {e}"),
+        })?
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("AreaDelegation"),
+            message: format!("Area delegation {area_delegation_id} not found"),
+        })?;
+
+    persistence
+        .delete_area_delegation(area_delegation_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to revoke area delegation: {e}"),
+        })?;
+
+    let audit_event: AuditEvent = AuditEvent::new_global(
+        operator_actor(operator),
+        cause,
+        Action::new(
+            String::from("RevokeAreaDelegation"),
+            Some(format!("Revoked area delegation {area_delegation_id}")),
+        ),
+        StateSnapshot::new(delegation_snapshot(&data)),
+        StateSnapshot::new(String::new()),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(RevokeAreaDelegationResponse {
+        area_delegation_id,
+        message: format!("Revoked area delegation {area_delegation_id}"),
+    })
+}
+
+/// Lists every area delegation with its status as of today (admin only).
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin or the database cannot be
+/// queried.
+pub fn list_area_delegations(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<ListAreaDelegationsResponse, ApiError> {
+    require_admin(authenticated_actor, "list_area_delegations")?;
+
+    let rows: Vec<AreaDelegationData> =
+        persistence
+            .list_area_delegations()
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list area delegations: {e}"),
+            })?;
+    let today: String = persistence.now().date().to_string();
+    let delegations: Vec<AreaDelegationInfo> = rows
+        .iter()
+        .map(|data| to_area_delegation_info(persistence, metadata, data, &today))
+        .collect::<Result<Vec<AreaDelegationInfo>, ApiError>>()?;
+
+    Ok(ListAreaDelegationsResponse { delegations })
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Audit event annotation handlers.
+//!
+//! Audit events are immutable. Operators explain an event after the fact
+//! by attaching a timestamped note to it; notes are stored apart from the
+//! event and listed inline with it in the audit timeline.
+
+use std::collections::BTreeMap;
+
+use zab_bid_persistence::{AuditAnnotationData, OperatorData, PersistenceError, SqlitePersistence};
+
+use crate::error::ApiError;
+use crate::free_text::MAX_NOTE_LENGTH;
+use crate::request_response::{
+    AnnotateAuditEventRequest, AnnotateAuditEventResponse, AuditAnnotationInfo,
+};
+
+impl From<AuditAnnotationData> for AuditAnnotationInfo {
+    fn from(data: AuditAnnotationData) -> Self {
+        Self {
+            annotation_id: data.annotation_id,
+            operator_id: data.operator_id,
+            login_name: data.operator_login_name,
+            display_name: data.operator_display_name,
+            note: data.note,
+            created_at: data.created_at,
+        }
+    }
+}
+
+/// Loads the annotations of a set of audit events, grouped by event.
+///
+/// # Errors
+///
+/// Returns an error if the annotations cannot be read.
+pub fn annotations_by_event(
+    persistence: &mut SqlitePersistence,
+    event_ids: &[i64],
+) -> Result<BTreeMap<i64, Vec<AuditAnnotationInfo>>, ApiError> {
+    let annotations: Vec<AuditAnnotationData> = persistence
+        .list_audit_annotations(event_ids)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load audit annotations: {e}"),
+        })?;
+
+    let mut by_event: BTreeMap<i64, Vec<AuditAnnotationInfo>> = BTreeMap::new();
+    for annotation in annotations {
+        by_event
+            .entry(annotation.event_id)
+            .or_default()
+            .push(AuditAnnotationInfo::from(annotation));
+    }
+    Ok(by_event)
+}
+
+/// Attaches a note to an existing audit event.
+///
+/// Any operator may annotate an event. The event itself is left untouched.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The annotated event and the note
+/// * `operator` - The operator writing the note
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The note is empty or too long
+/// - The audit event does not exist
+/// - The annotation cannot be recorded
+pub fn annotate_audit_event(
+    persistence: &mut SqlitePersistence,
+    request: &AnnotateAuditEventRequest,
+    operator: &OperatorData,
+) -> Result<AnnotateAuditEventResponse, ApiError> {
+    let note: &str = request.note.trim();
+    if note.is_empty() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("note"),
+            message: String::from("Note must not be empty"),
+        });
+    }
+    if note.chars().count() > MAX_NOTE_LENGTH {
+        return Err(ApiError::InvalidInput {
+            field: String::from("note"),
+            message: format!("Note must be at most {MAX_NOTE_LENGTH} characters"),
+        });
+    }
+
+    let annotation_id: i64 = persistence
+        .add_audit_annotation(request.event_id, operator.operator_id, note)
+        .map_err(|e| match e {
+            PersistenceError::NotFound(message) => ApiError::ResourceNotFound {
+                resource_type: String::from("AuditEvent"),
+                message,
+            },
+            other => ApiError::Internal {
+                message: format!("Failed to annotate audit event: {other}"),
+            },
+        })?;
+
+    Ok(AnnotateAuditEventResponse {
+        annotation_id,
+        event_id: request.event_id,
+        message: format!("Annotated audit event {}", request.event_id),
+    })
+}
@@ -0,0 +1,243 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Audit log compaction handlers.
+//!
+//! Once a bid year has closed, most of its audit events are no longer
+//! read. Compacting it writes an `AuditLogCompacted` digest event in each
+//! area, counting the events archived by action, with a sealed snapshot of
+//! the area's final state. The area events nothing else references are
+//! then moved to the archive, hash-chained in event order, and the chain
+//! head is recorded so the archive can be verified later. A bid year is
+//! compacted at most once.
+
+use std::collections::BTreeMap;
+use zab_bid::{BootstrapMetadata, State};
+use zab_bid_audit::{Action, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{Area, BidYear, BidYearLifecycle};
+use zab_bid_persistence::{
+    ArchivableEventData, AuditArchiveVerification, AuditCompactionData, OperatorData,
+    SqlitePersistence,
+};
+
+use crate::auth::AuthenticatedActor;
+use crate::auto_checkpoints::BID_YEAR_LOAD_CONNECTIONS;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::ApiError;
+use crate::leave_bids::load_lifecycle_state;
+use crate::request_response::{
+    AuditArchiveVerificationInfo, AuditCompactionInfo, CompactAuditLogRequest,
+    CompactAuditLogResponse, GetAuditCompactionResponse,
+};
+use crate::webhooks::require_admin;
+
+/// Converts a compaction into its API representation.
+fn to_compaction_info(data: AuditCompactionData) -> AuditCompactionInfo {
+    AuditCompactionInfo {
+        compaction_id: data.compaction_id,
+        bid_year_id: data.bid_year_id,
+        archived_event_count: data.archived_event_count,
+        first_event_id: data.first_event_id,
+        last_event_id: data.last_event_id,
+        chain_head: data.chain_head,
+        created_at: data.created_at,
+    }
+}
+
+/// Loads a bid year's compaction, if it has one.
+fn load_compaction(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<Option<AuditCompactionData>, ApiError> {
+    persistence
+        .get_audit_compaction(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to fetch audit compaction: {e}"),
+        })
+}
+
+/// Summarizes the events archived from one area, by action.
+fn digest_details(year: u16, area: &Area, events: &[&ArchivableEventData]) -> String {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for event in events {
+        *counts.entry(event.action_name.as_str()).or_default() += 1;
+    }
+    let summary: Vec<String> = counts
+        .iter()
+        .map(|(action_name, count)| format!("{action_name} x{count}"))
+        .collect();
+    if summary.is_empty() {
+        format!(
+            "Sealed bid year {year} area '{}'; no events archived",
+            area.id()
+        )
+    } else {
+        format!(
+            "Sealed bid year {year} area '{}'; archived {} events: {}",
+            area.id(),
+            events.len(),
+            summary.join(", ")
+        )
+    }
+}
+
+/// Compacts a closed bid year's audit log.
+///
+/// Each area gets an `AuditLogCompacted` digest event with a sealed
+/// snapshot of its current state. Area events that no other record refers
+/// to are moved to the archive and chained by SHA-256. Snapshotted
+/// events, the bid year's own events, and events not yet published stay
+/// in the live log.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year to compact
+/// * `authenticated_actor` - The authenticated actor compacting the log
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist
+/// - The bid year is not closed
+/// - The bid year was already compacted
+/// - The database operation fails
+pub fn compact_audit_log(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &CompactAuditLogRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: &Cause,
+) -> Result<CompactAuditLogResponse, ApiError> {
+    require_admin(authenticated_actor, "compact the audit log")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    if lifecycle_state != BidYearLifecycle::BiddingClosed {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("compact_after_close"),
+            message: format!(
+                "The audit log can only be compacted once bid year {year} is closed (state: {lifecycle_state})"
+            ),
+        });
+    }
+    if let Some(existing) = load_compaction(persistence, request.bid_year_id)? {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("compact_once"),
+            message: format!(
+                "Bid year {year} was already compacted (compaction {})",
+                existing.compaction_id
+            ),
+        });
+    }
+
+    let archivable: Vec<ArchivableEventData> = persistence
+        .list_archivable_audit_events(request.bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list archivable audit events: {e}"),
+        })?;
+
+    let states: Vec<State> = persistence
+        .load_bid_year_states(&BidYear::new(year), BID_YEAR_LOAD_CONNECTIONS)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load the states of bid year {year}: {e}"),
+        })?;
+    let mut digests: Vec<(AuditEvent, State)> = Vec::with_capacity(states.len());
+    for state in states {
+        let area: &Area = &state.area;
+        let area_events: Vec<&ArchivableEventData> = archivable
+            .iter()
+            .filter(|event| event.area_code == area.id())
+            .collect();
+        let event: AuditEvent = AuditEvent::new(
+            authenticated_actor.to_audit_actor(operator),
+            cause.clone(),
+            Action::new(
+                String::from("AuditLogCompacted"),
+                Some(digest_details(year, area, &area_events)),
+            ),
+            StateSnapshot::new(String::from("compacted=false")),
+            StateSnapshot::new(format!(
+                "compacted=true,archived_events={}",
+                area_events.len()
+            )),
+            BidYear::with_id(request.bid_year_id, year),
+            area.clone(),
+        );
+        digests.push((event, state));
+    }
+
+    let event_ids: Vec<i64> = archivable.iter().map(|event| event.event_id).collect();
+    let (compaction, digest_event_ids): (AuditCompactionData, Vec<i64>) = persistence
+        .compact_audit_log(request.bid_year_id, &event_ids, &digests)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to compact audit log: {e}"),
+        })?;
+
+    Ok(CompactAuditLogResponse {
+        message: format!(
+            "Compacted bid year {year}: archived {} events across {} areas",
+            compaction.archived_event_count,
+            digest_event_ids.len()
+        ),
+        compaction: to_compaction_info(compaction),
+        digest_event_ids,
+    })
+}
+
+/// Returns a bid year's audit log compaction, checking its archive
+/// against the recorded hash chain.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `bid_year_id` - The canonical bid year ID
+/// * `authenticated_actor` - The authenticated actor reading the compaction
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist
+/// - The database operation fails
+pub fn get_audit_compaction(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<GetAuditCompactionResponse, ApiError> {
+    require_admin(authenticated_actor, "view audit log compactions")?;
+    resolve_bid_year(metadata, bid_year_id)?;
+
+    let Some(compaction) = load_compaction(persistence, bid_year_id)? else {
+        return Ok(GetAuditCompactionResponse {
+            bid_year_id,
+            compaction: None,
+            verification: None,
+        });
+    };
+    let verification: AuditArchiveVerification = persistence
+        .verify_audit_archive(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to verify audit archive: {e}"),
+        })?;
+
+    Ok(GetAuditCompactionResponse {
+        bid_year_id,
+        compaction: Some(to_compaction_info(compaction)),
+        verification: Some(AuditArchiveVerificationInfo {
+            events_checked: verification.events_checked,
+            is_intact: verification.is_intact(),
+            recomputed_head: verification.recomputed_head,
+            broken_event_ids: verification.broken_event_ids,
+        }),
+    })
+}
@@ -7,9 +7,11 @@
 
 use time::{Duration, OffsetDateTime};
 use zab_bid_audit::Actor;
-use zab_bid_persistence::{OperatorData, PersistenceError, SessionData, SqlitePersistence};
+use zab_bid_persistence::{
+    OperatorData, PersistenceError, SecretToken, SessionData, SqlitePersistence,
+};
 
-use crate::error::AuthError;
+use crate::error::{ApiError, AuthError};
 
 /// Actor roles for authorization.
 ///
@@ -235,6 +237,60 @@ impl AuthorizationService {
             }),
         }
     }
+
+    /// Checks if an actor may perform bidding actions in an area.
+    ///
+    /// Admins may bid in any area, as may Bidders not assigned to any area.
+    /// A Bidder assigned to areas (an area rep) may bid only in those areas
+    /// and in areas another rep has delegated to them for today's date.
+    ///
+    /// # Arguments
+    ///
+    /// * `persistence` - The persistence layer
+    /// * `actor` - The authenticated actor
+    /// * `operator` - The operator data for the authenticated actor
+    /// * `area_id` - The canonical area ID the action applies to
+    /// * `action` - The action being attempted, for the error message
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the actor is an area rep for other areas only,
+    /// or if the database cannot be queried.
+    pub fn authorize_area_bidding(
+        persistence: &mut SqlitePersistence,
+        actor: &AuthenticatedActor,
+        operator: &OperatorData,
+        area_id: i64,
+        action: &str,
+    ) -> Result<(), ApiError> {
+        if actor.role == Role::Admin {
+            return Ok(());
+        }
+
+        let assigned: Vec<i64> = persistence
+            .list_operator_area_ids(operator.operator_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to load operator area scopes: {e}"),
+            })?;
+        if assigned.is_empty() || assigned.contains(&area_id) {
+            return Ok(());
+        }
+
+        let today: String = persistence.now().date().to_string();
+        let delegated: Vec<i64> = persistence
+            .list_delegated_area_ids(operator.operator_id, &today)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to load area delegations: {e}"),
+            })?;
+        if delegated.contains(&area_id) {
+            return Ok(());
+        }
+
+        Err(ApiError::Unauthorized {
+            action: format!("{action} in area {area_id}"),
+            required_role: String::from("Admin or area-assigned Bidder"),
+        })
+    }
 }
 
 /// Authentication service for session-based authentication (Phase 14).
@@ -265,7 +321,7 @@ impl AuthenticationService {
         persistence: &mut SqlitePersistence,
         login_name: &str,
         password: &str,
-    ) -> Result<(String, AuthenticatedActor, OperatorData), AuthError> {
+    ) -> Result<(SecretToken, AuthenticatedActor, OperatorData), AuthError> {
         // Retrieve operator by login name
         let operator: OperatorData = persistence
             .get_operator_by_login(login_name)
@@ -301,6 +357,9 @@ impl AuthenticationService {
 
         if !password_valid {
             tracing::info!(login_name = %operator.login_name, "Invalid password attempt");
+            if let Err(e) = persistence.record_failed_login(operator.operator_id) {
+                tracing::warn!(login_name = %operator.login_name, error = %e, "Failed to record failed login");
+            }
             return Err(AuthError::AuthenticationFailed {
                 reason: String::from("invalid_credentials"),
             });
@@ -318,11 +377,10 @@ impl AuthenticationService {
         };
 
         // Generate session token
-        let session_token: String = Self::generate_session_token();
+        let session_token: SecretToken = SecretToken::generate();
 
         // Calculate expiration time
-        let expires_at: OffsetDateTime =
-            OffsetDateTime::now_utc() + Self::DEFAULT_SESSION_EXPIRATION;
+        let expires_at: OffsetDateTime = persistence.now() + Self::DEFAULT_SESSION_EXPIRATION;
 
         // Format with microsecond precision for MySQL compatibility
         // MySQL DATETIME supports up to 6 decimal places (microseconds), not 9 (nanoseconds)
@@ -377,7 +435,7 @@ impl AuthenticationService {
     /// Returns an error if the session is invalid or expired.
     pub fn validate_session(
         persistence: &mut SqlitePersistence,
-        session_token: &str,
+        session_token: &SecretToken,
     ) -> Result<(AuthenticatedActor, OperatorData), AuthError> {
         // Retrieve session
         let session: SessionData = persistence
@@ -418,7 +476,7 @@ impl AuthenticationService {
                 .assume_utc()
         };
 
-        if OffsetDateTime::now_utc() > expires_at {
+        if persistence.now() > expires_at {
             return Err(AuthError::AuthenticationFailed {
                 reason: String::from("Session expired"),
             });
@@ -473,7 +531,7 @@ impl AuthenticationService {
     /// Returns an error if the logout fails.
     pub fn logout(
         persistence: &mut SqlitePersistence,
-        session_token: &str,
+        session_token: &SecretToken,
     ) -> Result<(), AuthError> {
         persistence
             .delete_session(session_token)
@@ -484,20 +542,6 @@ impl AuthenticationService {
         Ok(())
     }
 
-    /// Generates a session token.
-    ///
-    /// In a production system, this would use a cryptographically secure
-    /// random number generator. For simplicity, we use a timestamp-based
-    /// approach here.
-    fn generate_session_token() -> String {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        let timestamp: u128 = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_else(|_| std::time::Duration::from_secs(0))
-            .as_nanos();
-        format!("session_{timestamp}_{}", rand::random::<u64>())
-    }
-
     /// Maps persistence errors to authentication errors.
     fn map_persistence_error(err: PersistenceError) -> AuthError {
         match err {
@@ -542,6 +586,9 @@ pub fn authenticate_stub(actor_id: String, role: Role) -> Result<AuthenticatedAc
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
+    use time::macros::datetime;
+    use zab_bid::ManualClock;
     use zab_bid_persistence::SqlitePersistence;
 
     fn create_test_persistence() -> SqlitePersistence {
@@ -653,6 +700,33 @@ mod tests {
         assert_eq!(operator.login_name.to_lowercase(), "validuser");
     }
 
+    #[test]
+    fn test_session_expires_by_persistence_clock() {
+        let mut persistence = create_test_persistence();
+        let clock: Arc<ManualClock> = Arc::new(ManualClock::new(datetime!(2026-03-01 12:00 UTC)));
+        persistence.set_clock(clock.clone());
+        create_test_operator(
+            &mut persistence,
+            "validuser",
+            "Valid User",
+            "validpass",
+            "Admin",
+        );
+        let (session_token, _, _) =
+            AuthenticationService::login(&mut persistence, "validuser", "validpass").unwrap();
+
+        clock.advance(AuthenticationService::DEFAULT_SESSION_EXPIRATION);
+        assert!(AuthenticationService::validate_session(&mut persistence, &session_token).is_ok());
+
+        clock.advance(Duration::seconds(1));
+        let Err(AuthError::AuthenticationFailed { reason }) =
+            AuthenticationService::validate_session(&mut persistence, &session_token)
+        else {
+            panic!("Expected AuthenticationFailed");
+        };
+        assert_eq!(reason, "Session expired");
+    }
+
     /// `PHASE_22.1`: Verify all auth failures return same error string
     #[test]
     fn test_all_auth_failures_return_same_error() {
@@ -0,0 +1,206 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Automatic checkpoints before destructive operations.
+//!
+//! Rollback, canonicalization, round sign-off, bulk import, and user merge
+//! each record a labeled checkpoint in every area they touch before they
+//! change anything. The label is `auto-before-<operation>`, so a single
+//! rollback to that label undoes the operation in an area.
+//!
+//! Each run of an operation gets a correlation ID. The checkpoints and the
+//! operation's own audit events are recorded under it, linking the
+//! recovery point to what it recovers from.
+
+use zab_bid::{BootstrapMetadata, Command, State, TransitionResult, apply};
+use zab_bid_audit::{Actor, Cause, Ulid};
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_persistence::SqlitePersistence;
+
+use crate::error::{ApiError, translate_core_error};
+
+/// The label prefix of automatic checkpoints.
+pub const AUTO_CHECKPOINT_LABEL_PREFIX: &str = "auto-before-";
+
+/// The most connections used to load every area of a bid year at once.
+pub const BID_YEAR_LOAD_CONNECTIONS: usize = 4;
+
+/// The checkpoints recorded before one run of an operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoCheckpoints {
+    /// The correlation ID of the operation run.
+    pub correlation_id: String,
+    /// The checkpoints' audit event identifiers, one per area.
+    pub event_ids: Vec<i64>,
+}
+
+/// Returns the label of the automatic checkpoint recorded before an
+/// operation.
+#[must_use]
+pub fn auto_checkpoint_label(operation: &str) -> String {
+    format!("{AUTO_CHECKPOINT_LABEL_PREFIX}{operation}")
+}
+
+/// Records an automatic checkpoint in each of the given areas.
+///
+/// The checkpoints share a new correlation ID, which the operation's own
+/// events are then recorded under with [`record_operation_events`].
+///
+/// # Errors
+///
+/// Returns an error if a checkpoint cannot be created or persisted.
+pub fn record_auto_checkpoints(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    areas: &[(BidYear, Area)],
+    operation: &str,
+    actor: &Actor,
+    cause: &Cause,
+) -> Result<AutoCheckpoints, ApiError> {
+    record_with_auto_checkpoints(persistence, metadata, areas, operation, actor, cause, &[])
+        .map(|(checkpoints, _)| checkpoints)
+}
+
+/// Records an automatic checkpoint in each of the given areas together
+/// with the operation's own transitions.
+///
+/// The checkpoints are persisted first, then the transitions, all in one
+/// database transaction and under one new correlation ID. Either all of
+/// them are recorded or none are.
+///
+/// # Returns
+///
+/// The checkpoints, and the event IDs of the operation's transitions in
+/// the order given.
+///
+/// # Errors
+///
+/// Returns an error if a checkpoint cannot be created, or if anything
+/// fails to persist.
+pub fn record_with_auto_checkpoints(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    areas: &[(BidYear, Area)],
+    operation: &str,
+    actor: &Actor,
+    cause: &Cause,
+    results: &[TransitionResult],
+) -> Result<(AutoCheckpoints, Vec<i64>), ApiError> {
+    let states: Vec<State> = areas
+        .iter()
+        .map(|(bid_year, area)| {
+            persistence
+                .get_current_state(bid_year, area)
+                .unwrap_or_else(|_| State::new(bid_year.clone(), area.clone()))
+        })
+        .collect();
+    record_with_state_checkpoints(
+        persistence,
+        metadata,
+        &states,
+        operation,
+        actor,
+        cause,
+        results,
+    )
+}
+
+/// Records an automatic checkpoint on top of each of the given states
+/// together with the operation's own transitions.
+///
+/// See [`record_with_auto_checkpoints`].
+fn record_with_state_checkpoints(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    states: &[State],
+    operation: &str,
+    actor: &Actor,
+    cause: &Cause,
+    results: &[TransitionResult],
+) -> Result<(AutoCheckpoints, Vec<i64>), ApiError> {
+    let correlation_id: String = Ulid::new().to_string();
+    let mut transitions: Vec<TransitionResult> = Vec::with_capacity(states.len() + results.len());
+    for state in states {
+        let command: Command = Command::Checkpoint {
+            label: Some(auto_checkpoint_label(operation)),
+            description: Some(format!(
+                "Recorded automatically before {}",
+                operation.replace('-', " ")
+            )),
+        };
+        let result: TransitionResult = apply(
+            metadata,
+            state,
+            &state.bid_year,
+            command,
+            actor.clone(),
+            cause.clone(),
+        )
+        .map_err(translate_core_error)?;
+        transitions.push(result);
+    }
+    transitions.extend_from_slice(results);
+
+    let mut event_ids: Vec<i64> = persistence
+        .persist_correlated_transitions(&transitions, &correlation_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist automatic checkpoints: {e}"),
+        })?
+        .into_iter()
+        .map(|persisted| persisted.event_id)
+        .collect();
+    let operation_event_ids: Vec<i64> = event_ids.split_off(states.len());
+
+    Ok((
+        AutoCheckpoints {
+            correlation_id,
+            event_ids,
+        },
+        operation_event_ids,
+    ))
+}
+
+/// Records an automatic checkpoint in every area of a bid year.
+///
+/// The areas' states are loaded together with
+/// [`SqlitePersistence::load_bid_year_states`].
+///
+/// # Errors
+///
+/// Returns an error if the bid year's states cannot be loaded, or if a
+/// checkpoint cannot be created or persisted.
+pub fn record_bid_year_auto_checkpoints(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    year: u16,
+    operation: &str,
+    actor: &Actor,
+    cause: &Cause,
+) -> Result<AutoCheckpoints, ApiError> {
+    let states: Vec<State> = persistence
+        .load_bid_year_states(&BidYear::new(year), BID_YEAR_LOAD_CONNECTIONS)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load the states of bid year {year}: {e}"),
+        })?;
+    record_with_state_checkpoints(persistence, metadata, &states, operation, actor, cause, &[])
+        .map(|(checkpoints, _)| checkpoints)
+}
+
+/// Records an operation's audit events under its correlation ID.
+///
+/// # Errors
+///
+/// Returns an error if the correlations cannot be recorded.
+pub fn record_operation_events(
+    persistence: &mut SqlitePersistence,
+    event_ids: &[i64],
+    correlation_id: &str,
+) -> Result<(), ApiError> {
+    persistence
+        .record_event_correlations(event_ids, correlation_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record event correlations: {e}"),
+        })
+}
@@ -0,0 +1,283 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Bid validation rule handlers.
+//!
+//! Each bid year stores the facility's own contract rules as data. They
+//! are replaced as a whole while the bid year is being set up, and every
+//! leave bid is checked against them by the core rule engine.
+
+use std::collections::BTreeSet;
+
+use time::Date;
+use time::format_description::well_known::Iso8601;
+use zab_bid::{BidRule, BootstrapMetadata, RuleViolation, evaluate_rules};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{BidYear, BidYearLifecycle, DomainError};
+use zab_bid_persistence::{BidRuleData, BidRuleSpecData, OperatorData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::error::{ApiError, translate_domain_error};
+use crate::leave_bids::load_lifecycle_state;
+use crate::request_response::{
+    BidRuleInfo, ListBidRulesResponse, SetBidRulesRequest, SetBidRulesResponse,
+};
+use crate::webhooks::require_admin;
+
+/// Parses the dates parameter of a rule.
+fn parse_rule_dates(dates: &[String]) -> Result<Vec<Date>, ApiError> {
+    dates
+        .iter()
+        .map(|date| {
+            Date::parse(date, &Iso8601::DEFAULT).map_err(|_| ApiError::InvalidInput {
+                field: String::from("rules"),
+                message: format!("Invalid date format: {date}"),
+            })
+        })
+        .collect()
+}
+
+/// Converts a stored rule into a core rule.
+fn to_bid_rule(data: &BidRuleData) -> Result<BidRule, ApiError> {
+    let invalid = || ApiError::Internal {
+        message: format!("Stored bid rule {} is invalid", data.bid_rule_id),
+    };
+    let value: Option<u32> = data
+        .rule_value
+        .map(u32::try_from)
+        .transpose()
+        .map_err(|_| invalid())?;
+    let dates: Vec<Date> = parse_rule_dates(&data.rule_dates).map_err(|_| invalid())?;
+
+    BidRule::new(&data.rule_kind, value, &dates).map_err(|_| invalid())
+}
+
+/// Converts a core rule into its API representation.
+fn to_rule_info(rule: &BidRule) -> BidRuleInfo {
+    BidRuleInfo {
+        kind: rule.kind().to_string(),
+        value: rule.value(),
+        dates: rule.dates().iter().map(Date::to_string).collect(),
+    }
+}
+
+/// Describes a rule list for an audit snapshot.
+fn describe_rules(rules: &[BidRule]) -> String {
+    let described: Vec<String> = rules
+        .iter()
+        .map(|rule| {
+            rule.value().map_or_else(
+                || {
+                    let dates: Vec<String> = rule.dates().iter().map(Date::to_string).collect();
+                    format!("{}={}", rule.kind(), dates.join("|"))
+                },
+                |value| format!("{}={value}", rule.kind()),
+            )
+        })
+        .collect();
+    format!("rules=[{}]", described.join(";"))
+}
+
+/// Finds the year of a bid year by its canonical ID.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist.
+pub fn resolve_bid_year(metadata: &BootstrapMetadata, bid_year_id: i64) -> Result<u16, ApiError> {
+    metadata
+        .bid_years
+        .iter()
+        .find(|by| by.bid_year_id() == Some(bid_year_id))
+        .map(BidYear::year)
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("BidYear"),
+            message: format!("Bid year with ID {bid_year_id} not found"),
+        })
+}
+
+/// Loads a bid year's validation rules in evaluation order.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `bid_year_id` - The canonical bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the rules cannot be read or a stored rule is invalid.
+pub fn load_bid_rules(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<Vec<BidRule>, ApiError> {
+    persistence
+        .list_bid_rules(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list bid rules: {e}"),
+        })?
+        .iter()
+        .map(to_bid_rule)
+        .collect()
+}
+
+/// Checks leave being bid against a bid year's validation rules.
+///
+/// Every violation is reported in one error, under the kind of the first
+/// rule broken.
+///
+/// # Arguments
+///
+/// * `rules` - The bid year's rules
+/// * `held` - The leave the controller already holds in the round
+/// * `requested` - The leave days being bid
+///
+/// # Errors
+///
+/// Returns an error if the bid breaks any rule.
+pub fn enforce_bid_rules(
+    rules: &[BidRule],
+    held: &BTreeSet<Date>,
+    requested: &[Date],
+) -> Result<(), ApiError> {
+    let violations: Vec<RuleViolation> = evaluate_rules(rules, held, requested);
+    let Some(first) = violations.first() else {
+        return Ok(());
+    };
+
+    let reason: String = violations
+        .iter()
+        .map(|violation| {
+            let dates: Vec<String> = violation.dates.iter().map(Date::to_string).collect();
+            format!("{} ({})", violation.message, dates.join(", "))
+        })
+        .collect::<Vec<String>>()
+        .join("; ");
+    Err(translate_domain_error(DomainError::BidRuleViolated {
+        rule: first.rule.to_string(),
+        reason,
+    }))
+}
+
+/// Lists a bid year's validation rules in evaluation order.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn list_bid_rules(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<ListBidRulesResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+
+    let rules: Vec<BidRuleInfo> = load_bid_rules(persistence, bid_year_id)?
+        .iter()
+        .map(to_rule_info)
+        .collect();
+
+    Ok(ListBidRulesResponse { bid_year_id, rules })
+}
+
+/// Replaces a bid year's validation rules.
+///
+/// Rules are fixed once bidding starts, so leave already awarded was
+/// always judged by the rules now in force.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year and its new rules
+/// * `authenticated_actor` - The authenticated actor setting the rules
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist
+/// - Bidding has already started
+/// - A rule's kind is unknown or its parameters are invalid
+/// - The database operation fails
+pub fn set_bid_rules(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetBidRulesRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetBidRulesResponse, ApiError> {
+    require_admin(authenticated_actor, "set bid rules")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    if matches!(
+        lifecycle_state,
+        BidYearLifecycle::BiddingActive | BidYearLifecycle::BiddingClosed
+    ) {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("set bid rules"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+
+    let rules: Vec<BidRule> = request
+        .rules
+        .iter()
+        .map(|rule| {
+            let dates: Vec<Date> = parse_rule_dates(&rule.dates)?;
+            BidRule::new(rule.kind.trim(), rule.value, &dates).map_err(translate_domain_error)
+        })
+        .collect::<Result<Vec<BidRule>, ApiError>>()?;
+    let specs: Vec<BidRuleSpecData> = rules
+        .iter()
+        .map(|rule| {
+            let rule_value: Option<i32> =
+                rule.value().map(i32::try_from).transpose().map_err(|_| {
+                    ApiError::InvalidInput {
+                        field: String::from("rules"),
+                        message: format!("Value of '{}' is out of range", rule.kind()),
+                    }
+                })?;
+            Ok(BidRuleSpecData {
+                rule_kind: rule.kind().to_string(),
+                rule_value,
+                rule_dates: rule.dates().iter().map(Date::to_string).collect(),
+            })
+        })
+        .collect::<Result<Vec<BidRuleSpecData>, ApiError>>()?;
+
+    let previous: Vec<BidRule> = load_bid_rules(persistence, request.bid_year_id)?;
+    persistence
+        .replace_bid_rules(request.bid_year_id, &specs)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to store bid rules: {e}"),
+        })?;
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("SetBidRules"),
+        Some(format!(
+            "Set {} bid rule(s) for bid year {year}",
+            rules.len()
+        )),
+    );
+    let before: StateSnapshot = StateSnapshot::new(describe_rules(&previous));
+    let after: StateSnapshot = StateSnapshot::new(describe_rules(&rules));
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetBidRulesResponse {
+        bid_year_id: request.bid_year_id,
+        rules: rules.iter().map(to_rule_info).collect(),
+        message: format!("Bid year {year} now has {} bid rule(s)", rules.len()),
+    })
+}
@@ -0,0 +1,222 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Live bidding progress.
+//!
+//! Gathers everything a wall display shows for an area in one payload: the
+//! round in progress, the bidder whose window is active, who bids next and
+//! when, how much of the area's bidding is complete, and the latest bid
+//! events. The payload is read straight from the canonical tables, so it is
+//! current the moment a bid is recorded.
+
+use zab_bid::BootstrapMetadata;
+use zab_bid_domain::{BidStatus, Round};
+use zab_bid_persistence::{
+    AuditTimelineEntry, BidStatusRow, CurrentBidderStateData, RoundBidderData, SqlitePersistence,
+};
+
+use crate::current_bidder::load_current_bidder;
+use crate::error::{ApiError, translate_domain_error};
+use crate::leave_bids::resolve_area;
+use crate::request_response::{
+    BidEventInfo, BiddingProgressRoundInfo, CurrentBidderInfo, GetBiddingProgressRequest,
+    GetBiddingProgressResponse, UpcomingBidderInfo,
+};
+use crate::round_bid_order::{RoundSequence, load_round_sequences};
+
+/// How many upcoming bidders are listed when the request does not say.
+pub const DEFAULT_UPCOMING_BIDDERS: u32 = 5;
+
+/// The most upcoming bidders a request may ask for.
+pub const MAX_UPCOMING_BIDDERS: u32 = 50;
+
+/// How many recent bid events are listed.
+pub const RECENT_BID_EVENTS: u32 = 5;
+
+/// The audit actions shown as bid events.
+pub const BID_EVENT_ACTIONS: &[&str] = &[
+    "AdvanceBidder",
+    "EnterLeaveBid",
+    "ApplyBidPreference",
+    "WithdrawLeaveBid",
+    "WindowExpired",
+];
+
+/// Finds the first round, by round number, that has bidders but has not
+/// started.
+fn next_unstarted_round(
+    persistence: &mut SqlitePersistence,
+    area_id: i64,
+    mut rounds: Vec<RoundSequence>,
+) -> Result<Option<(i64, Vec<RoundBidderData>)>, ApiError> {
+    rounds.sort_by_key(|round| (round.round_number, round.round_id));
+    for round in rounds {
+        let state: Option<CurrentBidderStateData> = persistence
+            .get_current_bidder_state(area_id, round.round_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get current bidder: {e}"),
+            })?;
+        if state.is_some() {
+            continue;
+        }
+        let bidders: Vec<RoundBidderData> = persistence
+            .list_round_bidders(area_id, round.round_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list round bidders: {e}"),
+            })?;
+        if !bidders.is_empty() {
+            return Ok(Some((round.round_id, bidders)));
+        }
+    }
+    Ok(None)
+}
+
+/// Counts final bid statuses against all bid statuses.
+fn count_completed(rows: &[BidStatusRow]) -> Result<(u32, u32), ApiError> {
+    let mut completed: u32 = 0;
+    for row in rows {
+        let status: BidStatus = row.status.parse().map_err(translate_domain_error)?;
+        if status.is_terminal() {
+            completed += 1;
+        }
+    }
+    Ok((completed, u32::try_from(rows.len()).unwrap_or(u32::MAX)))
+}
+
+/// Converts a recorded audit event to a bid event.
+fn to_bid_event(entry: AuditTimelineEntry) -> Option<BidEventInfo> {
+    Some(BidEventInfo {
+        event_id: entry.event.event_id?,
+        action: entry.event.action.name,
+        details: entry.event.action.details,
+        created_at: entry.created_at,
+    })
+}
+
+/// Gets the live bidding progress of an area.
+///
+/// The current round is the round of the current bidder. When no round is
+/// in progress it is the lowest-numbered round that has not started, and
+/// its first bidders are listed as upcoming.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The area and how many upcoming bidders to list
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The area does not exist
+/// - More than [`MAX_UPCOMING_BIDDERS`] upcoming bidders are requested
+/// - The database cannot be queried
+pub fn get_bidding_progress(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &GetBiddingProgressRequest,
+) -> Result<GetBiddingProgressResponse, ApiError> {
+    let upcoming_count: u32 = request.upcoming_count.unwrap_or(DEFAULT_UPCOMING_BIDDERS);
+    if upcoming_count > MAX_UPCOMING_BIDDERS {
+        return Err(ApiError::InvalidInput {
+            field: String::from("upcoming_count"),
+            message: format!("At most {MAX_UPCOMING_BIDDERS} upcoming bidders can be listed"),
+        });
+    }
+
+    let (bid_year, _) = resolve_area(metadata, request.area_id)?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+
+    let current_bidder: Option<CurrentBidderInfo> =
+        load_current_bidder(persistence, request.area_id)?;
+    let (round_id, started, upcoming): (Option<i64>, bool, Vec<RoundBidderData>) =
+        if let Some(current) = &current_bidder {
+            let bidders: Vec<RoundBidderData> = persistence
+                .list_round_bidders(request.area_id, current.round_id)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to list round bidders: {e}"),
+                })?;
+            let upcoming: Vec<RoundBidderData> = bidders
+                .into_iter()
+                .skip_while(|bidder| bidder.user_id != current.user_id)
+                .skip(1)
+                .collect();
+            (Some(current.round_id), true, upcoming)
+        } else {
+            let rounds: Vec<RoundSequence> = load_round_sequences(persistence, bid_year_id)?;
+            match next_unstarted_round(persistence, request.area_id, rounds)? {
+                Some((round_id, bidders)) => (Some(round_id), false, bidders),
+                None => (None, false, Vec::new()),
+            }
+        };
+
+    let current_round: Option<BiddingProgressRoundInfo> = match round_id {
+        Some(round_id) => {
+            let round: Round = persistence
+                .get_round(round_id)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to get round: {e}"),
+                })?;
+            Some(BiddingProgressRoundInfo {
+                round_id,
+                round_number: round.round_number(),
+                round_name: round.name().to_string(),
+                started,
+            })
+        }
+        None => None,
+    };
+
+    let upcoming_bidders: Vec<UpcomingBidderInfo> = upcoming
+        .into_iter()
+        .take(upcoming_count as usize)
+        .map(|bidder| UpcomingBidderInfo {
+            user_id: bidder.user_id,
+            initials: bidder.initials,
+            window_start_datetime: bidder.window_start_datetime,
+            window_end_datetime: bidder.window_end_datetime,
+        })
+        .collect();
+
+    let statuses: Vec<BidStatusRow> = persistence
+        .get_bid_status_for_area(bid_year_id, request.area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get bid status: {e}"),
+        })?;
+    let (completed, total): (u32, u32) = count_completed(&statuses)?;
+    let percent_complete: u32 = if total == 0 {
+        0
+    } else {
+        u32::try_from(u64::from(completed) * 100 / u64::from(total)).unwrap_or(100)
+    };
+
+    let recent_events: Vec<BidEventInfo> = persistence
+        .get_recent_area_events(
+            bid_year_id,
+            request.area_id,
+            BID_EVENT_ACTIONS,
+            RECENT_BID_EVENTS,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read bid events: {e}"),
+        })?
+        .into_iter()
+        .filter_map(to_bid_event)
+        .collect();
+
+    Ok(GetBiddingProgressResponse {
+        bid_year_id,
+        area_id: request.area_id,
+        current_round,
+        current_bidder,
+        upcoming_bidders,
+        completed,
+        total,
+        percent_complete,
+        recent_events,
+    })
+}
@@ -235,9 +235,7 @@ mod tests {
             password_hash: String::from("hash"),
             role: String::from(role),
             is_disabled,
-            created_at: time::OffsetDateTime::now_utc()
-                .format(&time::format_description::well_known::Iso8601::DEFAULT)
-                .unwrap(),
+            created_at: String::from("2026-01-01T00:00:00Z"),
             disabled_at: None,
             last_login_at: None,
         }
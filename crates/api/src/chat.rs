@@ -0,0 +1,428 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Chat channel management handlers.
+//!
+//! Chat channels are Slack or Microsoft Teams incoming webhooks configured
+//! per bid year, optionally narrowed to one area. The server posts round
+//! openings, window advancements, and readiness blockers to them. Only
+//! Admin actors may manage channels. Every configuration change is recorded
+//! as a global audit event. Incoming webhook URLs grant posting access, so
+//! only their host appears in audit records and listing responses.
+
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, Cause, StateSnapshot};
+use zab_bid_persistence::{
+    ChatChannelData, ChatNotificationLogData, OperatorData, SqlitePersistence,
+};
+
+use crate::auth::AuthenticatedActor;
+use crate::error::ApiError;
+use crate::request_response::{
+    ChatChannelInfo, ChatNotificationInfo, CreateChatChannelRequest, CreateChatChannelResponse,
+    DeleteChatChannelRequest, DeleteChatChannelResponse, ListChatChannelsResponse,
+    ListChatNotificationsResponse, UpdateChatChannelRequest, UpdateChatChannelResponse,
+};
+use crate::webhooks::{operator_actor, require_admin};
+
+/// Default number of announcements returned.
+const DEFAULT_NOTIFICATION_LIMIT: u32 = 100;
+
+/// Maximum number of announcements returned.
+const MAX_NOTIFICATION_LIMIT: u32 = 1000;
+
+/// Returns the host of an https URL, or `None` if the URL has no host.
+fn url_host(url: &str) -> Option<&str> {
+    url.strip_prefix("https://")
+        .map(|rest| rest.split(['/', '?', '#']).next().unwrap_or_default())
+        .filter(|host| !host.is_empty())
+}
+
+/// Validates an incoming webhook URL.
+fn validate_url(url: &str) -> Result<(), ApiError> {
+    if url_host(url).is_none() || url.chars().any(char::is_whitespace) {
+        return Err(ApiError::InvalidInput {
+            field: String::from("url"),
+            message: String::from("Chat channel URL must be an absolute https URL"),
+        });
+    }
+    Ok(())
+}
+
+/// Validates an incoming webhook provider.
+fn validate_provider(provider: &str) -> Result<(), ApiError> {
+    if provider != ChatChannelData::PROVIDER_SLACK && provider != ChatChannelData::PROVIDER_TEAMS {
+        return Err(ApiError::InvalidInput {
+            field: String::from("provider"),
+            message: format!("Unknown chat provider '{provider}'. Valid options: slack, teams"),
+        });
+    }
+    Ok(())
+}
+
+/// Verifies the bid year exists and, if given, that the area belongs to it.
+fn validate_scope(
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+    area_id: Option<i64>,
+) -> Result<(), ApiError> {
+    if !metadata
+        .bid_years
+        .iter()
+        .any(|by| by.bid_year_id() == Some(bid_year_id))
+    {
+        return Err(ApiError::ResourceNotFound {
+            resource_type: String::from("BidYear"),
+            message: format!("Bid year with ID {bid_year_id} not found"),
+        });
+    }
+    if let Some(area_id) = area_id {
+        let in_bid_year: bool = metadata.areas.iter().any(|(by, area)| {
+            by.bid_year_id() == Some(bid_year_id) && area.area_id() == Some(area_id)
+        });
+        if !in_bid_year {
+            return Err(ApiError::ResourceNotFound {
+                resource_type: String::from("Area"),
+                message: format!("Area with ID {area_id} not found in bid year {bid_year_id}"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Formats a channel's configuration for audit snapshots, omitting the URL path.
+fn channel_snapshot(channel: &ChatChannelData) -> String {
+    format!(
+        "chat_channel_id={},bid_year_id={},area_id={},provider={},url_host={},is_enabled={}",
+        channel.chat_channel_id,
+        channel.bid_year_id,
+        channel
+            .area_id
+            .map_or_else(|| String::from("all"), |id| id.to_string()),
+        channel.provider,
+        url_host(&channel.url).unwrap_or_default(),
+        channel.is_enabled
+    )
+}
+
+/// Records a global audit event for a chat channel configuration change.
+fn persist_chat_audit_event(
+    persistence: &mut SqlitePersistence,
+    operator: &OperatorData,
+    cause: Cause,
+    action: Action,
+    before: String,
+    after: String,
+) -> Result<(), ApiError> {
+    let audit_event: AuditEvent = AuditEvent::new_global(
+        operator_actor(operator),
+        cause,
+        action,
+        StateSnapshot::new(before),
+        StateSnapshot::new(after),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    Ok(())
+}
+
+/// Loads a chat channel or returns `ResourceNotFound`.
+fn load_channel(
+    persistence: &mut SqlitePersistence,
+    chat_channel_id: i64,
+) -> Result<ChatChannelData, ApiError> {
+    persistence
+        .get_chat_channel(chat_channel_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get chat channel: {e}"),
+        })?
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("ChatChannel"),
+            message: format!("Chat channel with ID {chat_channel_id} not found"),
+        })
+}
+
+impl From<ChatChannelData> for ChatChannelInfo {
+    fn from(channel: ChatChannelData) -> Self {
+        Self {
+            chat_channel_id: channel.chat_channel_id,
+            bid_year_id: channel.bid_year_id,
+            area_id: channel.area_id,
+            url_host: url_host(&channel.url).unwrap_or_default().to_string(),
+            provider: channel.provider,
+            is_enabled: channel.is_enabled,
+            created_at: channel.created_at,
+        }
+    }
+}
+
+impl From<ChatNotificationLogData> for ChatNotificationInfo {
+    fn from(entry: ChatNotificationLogData) -> Self {
+        Self {
+            chat_notification_id: entry.chat_notification_id,
+            chat_channel_id: entry.chat_channel_id,
+            kind: entry.kind,
+            reference_key: entry.reference_key,
+            message: entry.message,
+            delivered: entry.delivered,
+            error: entry.error,
+            sent_at: entry.sent_at,
+        }
+    }
+}
+
+/// Creates a chat channel.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The channel configuration
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The provider or URL is invalid
+/// - The bid year or area does not exist
+/// - The database operation fails
+pub fn create_chat_channel(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &CreateChatChannelRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<CreateChatChannelResponse, ApiError> {
+    require_admin(authenticated_actor, "create_chat_channel")?;
+    validate_provider(&request.provider)?;
+    validate_url(&request.url)?;
+    validate_scope(metadata, request.bid_year_id, request.area_id)?;
+
+    let chat_channel_id: i64 = persistence
+        .create_chat_channel(
+            request.bid_year_id,
+            request.area_id,
+            &request.provider,
+            &request.url,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to create chat channel: {e}"),
+        })?;
+
+    let channel: ChatChannelData = load_channel(persistence, chat_channel_id)?;
+
+    persist_chat_audit_event(
+        persistence,
+        operator,
+        cause,
+        Action::new(
+            String::from("CreateChatChannel"),
+            Some(format!(
+                "Created {} chat channel {chat_channel_id}",
+                request.provider
+            )),
+        ),
+        String::from("chat_channel_does_not_exist"),
+        channel_snapshot(&channel),
+    )?;
+
+    Ok(CreateChatChannelResponse {
+        channel: ChatChannelInfo::from(channel),
+    })
+}
+
+/// Lists chat channels.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `bid_year_id` - Restrict to one bid year, or `None` for all
+/// * `authenticated_actor` - The authenticated actor performing this action
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin or the query fails.
+pub fn list_chat_channels(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: Option<i64>,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<ListChatChannelsResponse, ApiError> {
+    require_admin(authenticated_actor, "list_chat_channels")?;
+
+    let channels: Vec<ChatChannelInfo> = persistence
+        .list_chat_channels(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list chat channels: {e}"),
+        })?
+        .into_iter()
+        .map(ChatChannelInfo::from)
+        .collect();
+
+    Ok(ListChatChannelsResponse { channels })
+}
+
+/// Updates a chat channel's provider, URL, and enabled flag.
+///
+/// The bid year and area are fixed; delete and recreate the channel to
+/// change them.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The new configuration
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The channel does not exist
+/// - The provider or URL is invalid
+/// - The database operation fails
+pub fn update_chat_channel(
+    persistence: &mut SqlitePersistence,
+    request: &UpdateChatChannelRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<UpdateChatChannelResponse, ApiError> {
+    require_admin(authenticated_actor, "update_chat_channel")?;
+    validate_provider(&request.provider)?;
+    if let Some(url) = &request.url {
+        validate_url(url)?;
+    }
+
+    let existing: ChatChannelData = load_channel(persistence, request.chat_channel_id)?;
+    let url: &str = request.url.as_deref().unwrap_or(&existing.url);
+
+    persistence
+        .update_chat_channel(
+            request.chat_channel_id,
+            &request.provider,
+            url,
+            request.is_enabled,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to update chat channel: {e}"),
+        })?;
+
+    let channel: ChatChannelData = load_channel(persistence, request.chat_channel_id)?;
+
+    persist_chat_audit_event(
+        persistence,
+        operator,
+        cause,
+        Action::new(
+            String::from("UpdateChatChannel"),
+            Some(format!("Updated chat channel {}", request.chat_channel_id)),
+        ),
+        channel_snapshot(&existing),
+        channel_snapshot(&channel),
+    )?;
+
+    Ok(UpdateChatChannelResponse {
+        channel: ChatChannelInfo::from(channel),
+    })
+}
+
+/// Deletes a chat channel and its announcement log.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The channel to delete
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The channel does not exist
+/// - The database operation fails
+pub fn delete_chat_channel(
+    persistence: &mut SqlitePersistence,
+    request: DeleteChatChannelRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<DeleteChatChannelResponse, ApiError> {
+    require_admin(authenticated_actor, "delete_chat_channel")?;
+
+    let existing: ChatChannelData = load_channel(persistence, request.chat_channel_id)?;
+
+    persistence
+        .delete_chat_channel(request.chat_channel_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to delete chat channel: {e}"),
+        })?;
+
+    persist_chat_audit_event(
+        persistence,
+        operator,
+        cause,
+        Action::new(
+            String::from("DeleteChatChannel"),
+            Some(format!("Deleted chat channel {}", request.chat_channel_id)),
+        ),
+        channel_snapshot(&existing),
+        String::from("chat_channel_deleted"),
+    )?;
+
+    Ok(DeleteChatChannelResponse {
+        message: format!("Chat channel {} deleted", request.chat_channel_id),
+    })
+}
+
+/// Lists chat announcements, newest first.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `chat_channel_id` - Restrict to one channel, or `None` for all
+/// * `limit` - Maximum entries to return (default 100, max 1000)
+/// * `authenticated_actor` - The authenticated actor performing this action
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin, the limit is out of
+/// range, or the query fails.
+pub fn list_chat_notifications(
+    persistence: &mut SqlitePersistence,
+    chat_channel_id: Option<i64>,
+    limit: Option<u32>,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<ListChatNotificationsResponse, ApiError> {
+    require_admin(authenticated_actor, "list_chat_notifications")?;
+
+    let limit: u32 = limit.unwrap_or(DEFAULT_NOTIFICATION_LIMIT);
+    if limit == 0 || limit > MAX_NOTIFICATION_LIMIT {
+        return Err(ApiError::InvalidInput {
+            field: String::from("limit"),
+            message: format!("limit must be between 1 and {MAX_NOTIFICATION_LIMIT}"),
+        });
+    }
+
+    let notifications: Vec<ChatNotificationInfo> = persistence
+        .list_chat_notifications(chat_channel_id, limit)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list chat notifications: {e}"),
+        })?
+        .into_iter()
+        .map(ChatNotificationInfo::from)
+        .collect();
+
+    Ok(ListChatNotificationsResponse { notifications })
+}
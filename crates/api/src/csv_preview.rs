@@ -281,10 +281,7 @@ fn validate_user_against_metadata(
         }
 
         if let Ok(state) = persistence.get_current_state(bid_year, area)
-            && state
-                .users
-                .iter()
-                .any(|u| u.initials.value() == user.initials.value())
+            && state.users.contains_key(&user.initials)
         {
             initials_exists_in_db = true;
             break;
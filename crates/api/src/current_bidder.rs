@@ -0,0 +1,588 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Current bidder tracking handlers.
+//!
+//! While a bid year is `BiddingActive`, each round in an area hands the
+//! floor from one bidder to the next in window order. These handlers expose
+//! whose window is active now and advance a round to its next bidder,
+//! either manually by an Admin or when the current window elapses. Every
+//! advance goes through the core `AdvanceBidder` command and is recorded as
+//! an audit event scoped to the area. A window that elapses without a
+//! submitted bid is additionally recorded through `ExpireBidWindow`. When a
+//! bidder's window opens, their pre-submitted preference list is converted
+//! into leave.
+
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use zab_bid::{BootstrapMetadata, BootstrapResult, Command, apply_bootstrap};
+use zab_bid_audit::{Actor, Cause};
+use zab_bid_domain::{Area, BidStatus, BidYear, BidYearLifecycle, DomainError, FeatureFlag};
+use zab_bid_persistence::{
+    BidStatusRow, CurrentBidderData, CurrentBidderStateData, OperatorData, PersistenceError,
+    RoundBidderData, SqlitePersistence,
+};
+
+use crate::auth::AuthenticatedActor;
+use crate::error::{ApiError, translate_core_error, translate_domain_error};
+use crate::feature_flags::is_feature_enabled;
+use crate::leave_bids::{PreparedBidPreferences, prepare_bid_preferences, record_bid_preferences};
+use crate::request_response::{
+    AdvanceBidderRequest, AdvanceBidderResponse, CurrentBidderInfo, GetCurrentBidderResponse,
+};
+use crate::webhooks::{operator_actor, require_admin};
+
+impl From<CurrentBidderData> for CurrentBidderInfo {
+    fn from(data: CurrentBidderData) -> Self {
+        Self {
+            bid_year_id: data.bid_year_id,
+            area_id: data.area_id,
+            round_id: data.round_id,
+            round_number: data.round_number,
+            round_name: data.round_name,
+            user_id: data.user_id,
+            initials: data.initials,
+            window_start_datetime: data.window_start_datetime,
+            window_end_datetime: data.window_end_datetime,
+            became_current_at: data.became_current_at,
+        }
+    }
+}
+
+/// Finds an area and its bid year in the metadata by canonical area ID.
+fn resolve_area(metadata: &BootstrapMetadata, area_id: i64) -> Result<(&BidYear, &Area), ApiError> {
+    metadata
+        .areas
+        .iter()
+        .find(|(_, area)| area.area_id() == Some(area_id))
+        .map(|(bid_year, area)| (bid_year, area))
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("Area"),
+            message: format!("Area with ID {area_id} not found"),
+        })
+}
+
+/// Loads the area's current bidder.
+pub fn load_current_bidder(
+    persistence: &mut SqlitePersistence,
+    area_id: i64,
+) -> Result<Option<CurrentBidderInfo>, ApiError> {
+    Ok(persistence
+        .get_current_bidder(area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get current bidder: {e}"),
+        })?
+        .map(CurrentBidderInfo::from))
+}
+
+/// Parses a stored window timestamp.
+fn parse_window_datetime(value: &str) -> Result<OffsetDateTime, ApiError> {
+    OffsetDateTime::parse(value, &Rfc3339).map_err(|e| ApiError::Internal {
+        message: format!("Failed to parse window timestamp '{value}': {e}"),
+    })
+}
+
+/// Determines who bids after the tracked current bidder.
+///
+/// A round that has not started hands the floor to its first bidder.
+fn next_bidder(
+    state: Option<&CurrentBidderStateData>,
+    bidders: &[RoundBidderData],
+) -> Result<Option<i64>, ApiError> {
+    let Some(state) = state else {
+        return Ok(bidders.first().map(|bidder| bidder.user_id));
+    };
+    let Some(current_user_id) = state.user_id else {
+        return Ok(None);
+    };
+
+    let position: usize = bidders
+        .iter()
+        .position(|bidder| bidder.user_id == current_user_id)
+        .ok_or_else(|| {
+            translate_domain_error(DomainError::InvalidBidderAdvance {
+                reason: format!(
+                    "Current bidder {current_user_id} has no window in round {}",
+                    state.round_id
+                ),
+            })
+        })?;
+
+    Ok(bidders.get(position + 1).map(|bidder| bidder.user_id))
+}
+
+/// Advances a round to its next bidder and records the handover.
+///
+/// # Errors
+///
+/// Returns an error if the area does not exist, the bid year is not
+/// `BiddingActive`, the round has no bidders left, or persistence fails.
+#[allow(clippy::too_many_arguments)]
+fn advance_bidder_impl(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    area_id: i64,
+    round_id: i64,
+    actor: Actor,
+    cause: Cause,
+    time_driven: bool,
+    now: OffsetDateTime,
+) -> Result<AdvanceBidderResponse, ApiError> {
+    let (bid_year, area) = resolve_area(metadata, area_id)?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+
+    let lifecycle_state: BidYearLifecycle = persistence
+        .get_lifecycle_state(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get lifecycle state: {e}"),
+        })?
+        .parse()
+        .map_err(translate_domain_error)?;
+    if lifecycle_state != BidYearLifecycle::BiddingActive {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("advance bidder"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+
+    let state: Option<CurrentBidderStateData> = persistence
+        .get_current_bidder_state(area_id, round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get current bidder: {e}"),
+        })?;
+    let bidders: Vec<RoundBidderData> =
+        persistence
+            .list_round_bidders(area_id, round_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list round bidders: {e}"),
+            })?;
+
+    let previous_user_id: Option<i64> = state.as_ref().and_then(|s| s.user_id);
+    let next_user_id: Option<i64> = next_bidder(state.as_ref(), &bidders)?;
+
+    let command: Command = Command::AdvanceBidder {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id,
+        previous_user_id,
+        next_user_id,
+        time_driven,
+    };
+    let result: BootstrapResult =
+        apply_bootstrap(metadata, bid_year, command, actor.clone(), cause.clone())
+            .map_err(translate_core_error)?;
+    // The next bidder's window opens; their preference list is checked
+    // before the handover is written
+    let prepared: Option<PreparedBidPreferences> = match next_user_id {
+        Some(user_id) => prepare_bid_preferences(
+            persistence,
+            metadata,
+            area_id,
+            round_id,
+            user_id,
+            actor,
+            cause,
+        )?,
+        None => None,
+    };
+
+    let became_current_at: String = now.format(&Rfc3339).map_err(|e| ApiError::Internal {
+        message: format!("Timestamp formatting failed: {e}"),
+    })?;
+    persistence
+        .set_current_bidder(
+            bid_year_id,
+            area_id,
+            round_id,
+            next_user_id,
+            &became_current_at,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to update current bidder: {e}"),
+        })?;
+
+    persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    let applied_preference_rank: Option<u32> = match prepared {
+        Some(prepared) => record_bid_preferences(persistence, prepared)?,
+        None => None,
+    };
+
+    let message: String = next_user_id.map_or_else(
+        || format!("Round {round_id} in area {} is complete", area.area_code()),
+        |user_id| {
+            format!(
+                "User {user_id} is now the current bidder in round {round_id} of area {}",
+                area.area_code()
+            )
+        },
+    );
+
+    Ok(AdvanceBidderResponse {
+        area_id,
+        round_id,
+        previous_user_id,
+        next_user_id,
+        current_bidder: load_current_bidder(persistence, area_id)?,
+        applied_preference_rank,
+        message,
+    })
+}
+
+/// Gets the bidder whose window is active now in an area.
+///
+/// When several rounds are in progress, the lowest-numbered round wins.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `area_id` - The canonical area ID
+///
+/// # Errors
+///
+/// Returns an error if the area does not exist or the query fails.
+pub fn get_current_bidder(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    area_id: i64,
+) -> Result<GetCurrentBidderResponse, ApiError> {
+    resolve_area(metadata, area_id)?;
+
+    Ok(GetCurrentBidderResponse {
+        area_id,
+        current_bidder: load_current_bidder(persistence, area_id)?,
+    })
+}
+
+/// Manually advances a round to its next bidder.
+///
+/// The first advance of a round starts it with the first bidder in window
+/// order; advancing past the last bidder completes the round.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The area and round to advance
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The area does not exist
+/// - The bid year is not `BiddingActive`
+/// - The round has no bidders left to advance to
+/// - The operator is the next bidder's controller and the next bidder has
+///   preferences pending
+/// - The database operation fails
+pub fn advance_bidder(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &AdvanceBidderRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<AdvanceBidderResponse, ApiError> {
+    require_admin(authenticated_actor, "advance bidder")?;
+
+    advance_bidder_impl(
+        persistence,
+        metadata,
+        request.area_id,
+        request.round_id,
+        operator_actor(operator),
+        cause,
+        false,
+        persistence.now(),
+    )
+}
+
+/// What the window expiry scheduler does to a bidder whose window elapses
+/// without a submitted bid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissedWindowPolicy {
+    /// Mark a bidder who never started as missed.
+    MarkMissed,
+    /// Leave the bidder's status for an operator to resolve.
+    LeaveStatus,
+}
+
+impl MissedWindowPolicy {
+    /// Returns the policy's command-line spelling.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::MarkMissed => "mark-missed",
+            Self::LeaveStatus => "leave-status",
+        }
+    }
+}
+
+impl std::str::FromStr for MissedWindowPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mark-missed" => Ok(Self::MarkMissed),
+            "leave-status" => Ok(Self::LeaveStatus),
+            _ => Err(format!(
+                "Unknown missed window policy: '{s}'. Valid options: mark-missed, leave-status"
+            )),
+        }
+    }
+}
+
+/// The outcome of advancing a round on the clock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElapsedWindowAdvance {
+    /// The bidder whose window elapsed without a submitted bid, if any.
+    pub expired_user_id: Option<i64>,
+    /// Whether that bidder was marked missed.
+    pub marked_missed: bool,
+    /// The handover to the next bidder.
+    pub advance: AdvanceBidderResponse,
+}
+
+/// Records that the current bidder's window elapsed without a submitted bid.
+///
+/// A bidder has submitted when their bid status is terminal. Otherwise a
+/// `WindowExpired` audit event is recorded and, under
+/// [`MissedWindowPolicy::MarkMissed`], a bidder who never started is marked
+/// missed.
+///
+/// # Returns
+///
+/// `None` if the bidder had submitted, otherwise whether they were marked
+/// missed.
+#[allow(clippy::too_many_arguments)]
+fn expire_window(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    area_id: i64,
+    round_id: i64,
+    user_id: i64,
+    actor: &Actor,
+    cause: &Cause,
+    policy: MissedWindowPolicy,
+    now: OffsetDateTime,
+) -> Result<Option<bool>, ApiError> {
+    let (bid_year, area) = resolve_area(metadata, area_id)?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+
+    let status_row: Option<BidStatusRow> = match persistence.get_bid_status_for_user_and_round(
+        bid_year_id,
+        area_id,
+        user_id,
+        round_id,
+    ) {
+        Ok(row) => Some(row),
+        Err(PersistenceError::NotFound(_)) => None,
+        Err(e) => {
+            return Err(ApiError::Internal {
+                message: format!("Failed to get bid status: {e}"),
+            });
+        }
+    };
+    let status: Option<BidStatus> = status_row
+        .as_ref()
+        .map(|row| row.status.parse())
+        .transpose()
+        .map_err(translate_domain_error)?;
+    if status.is_some_and(|status| status.is_terminal()) {
+        return Ok(None);
+    }
+
+    let mark_missed: bool = policy == MissedWindowPolicy::MarkMissed
+        && matches!(
+            status,
+            Some(BidStatus::NotStartedPreWindow | BidStatus::NotStartedInWindow)
+        );
+
+    let command: Command = Command::ExpireBidWindow {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id,
+        user_id,
+        marked_missed: mark_missed,
+    };
+    let result: BootstrapResult =
+        apply_bootstrap(metadata, bid_year, command, actor.clone(), cause.clone())
+            .map_err(translate_core_error)?;
+    let audit_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    if let Some(row) = status_row.filter(|_| mark_missed) {
+        let transitioned_at: String = now.format(&Rfc3339).map_err(|e| ApiError::Internal {
+            message: format!("Timestamp formatting failed: {e}"),
+        })?;
+        let operator_id: i64 = actor.operator_id.ok_or_else(|| ApiError::Internal {
+            message: String::from("Scheduler actor has no operator"),
+        })?;
+        let notes: &str = "Bid window elapsed without a submitted bid";
+        let missed: &str = BidStatus::Missed.as_str();
+
+        persistence
+            .update_bid_status(
+                row.bid_status_id,
+                missed,
+                &transitioned_at,
+                operator_id,
+                Some(notes),
+            )
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to update bid status: {e}"),
+            })?;
+        persistence
+            .insert_bid_status_history(
+                row.bid_status_id,
+                audit_event_id,
+                Some(&row.status),
+                missed,
+                &transitioned_at,
+                operator_id,
+                Some(notes),
+            )
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to insert bid status history: {e}"),
+            })?;
+    }
+
+    Ok(Some(mark_missed))
+}
+
+/// Advances a round whose current window has elapsed.
+///
+/// A round that has not started is started once its first window opens.
+/// Nothing happens while the current window is still open, after the
+/// round is complete, or when the bid year has the `auto_advance` feature
+/// disabled. When the current bidder has not submitted, the expiry is
+/// recorded as a `WindowExpired` audit event and `policy` decides whether
+/// they are marked missed. Every change is attributed to the scheduler
+/// acting under the given operator.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `area_id` - The canonical area ID
+/// * `round_id` - The round to check
+/// * `operator` - The operator the scheduler runs as
+/// * `policy` - How to treat a bidder whose window elapsed without a bid
+/// * `now` - The current instant
+///
+/// # Returns
+///
+/// The advance performed, or `None` if the round was left unchanged.
+///
+/// # Errors
+///
+/// Returns an error if the area does not exist, the bid year is not
+/// `BiddingActive`, or persistence fails.
+pub fn advance_elapsed_bidder(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    area_id: i64,
+    round_id: i64,
+    operator: &OperatorData,
+    policy: MissedWindowPolicy,
+    now: OffsetDateTime,
+) -> Result<Option<ElapsedWindowAdvance>, ApiError> {
+    let (bid_year, _): (&BidYear, &Area) = resolve_area(metadata, area_id)?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+    if !is_feature_enabled(persistence, bid_year_id, FeatureFlag::AutoAdvance)? {
+        return Ok(None);
+    }
+
+    let state: Option<CurrentBidderStateData> = persistence
+        .get_current_bidder_state(area_id, round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get current bidder: {e}"),
+        })?;
+    let bidders: Vec<RoundBidderData> =
+        persistence
+            .list_round_bidders(area_id, round_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list round bidders: {e}"),
+            })?;
+
+    let current_user_id: Option<i64> = state.as_ref().and_then(|s| s.user_id);
+    let due: bool = match state.as_ref().map(|s| s.user_id) {
+        None => match bidders.first() {
+            Some(first) => parse_window_datetime(&first.window_start_datetime)? <= now,
+            None => false,
+        },
+        Some(Some(user_id)) => match bidders.iter().find(|b| b.user_id == user_id) {
+            Some(current) => parse_window_datetime(&current.window_end_datetime)? <= now,
+            None => false,
+        },
+        Some(None) => false,
+    };
+    if !due {
+        return Ok(None);
+    }
+
+    let actor: Actor = Actor::with_operator(
+        String::from("scheduler"),
+        String::from("system"),
+        operator.operator_id,
+        operator.login_name.clone(),
+        operator.display_name.clone(),
+    );
+    let cause: Cause = Cause::new(
+        String::from("window_elapsed"),
+        String::from("Current bid window elapsed"),
+    );
+
+    let expiry: Option<bool> = match current_user_id {
+        Some(user_id) => expire_window(
+            persistence,
+            metadata,
+            area_id,
+            round_id,
+            user_id,
+            &actor,
+            &cause,
+            policy,
+            now,
+        )?,
+        None => None,
+    };
+
+    let advance: AdvanceBidderResponse = advance_bidder_impl(
+        persistence,
+        metadata,
+        area_id,
+        round_id,
+        actor,
+        cause,
+        true,
+        now,
+    )?;
+
+    Ok(Some(ElapsedWindowAdvance {
+        expired_user_id: expiry.and(current_user_id),
+        marked_missed: expiry.unwrap_or(false),
+        advance,
+    }))
+}
@@ -0,0 +1,466 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Dashboard read projections.
+//!
+//! Dashboards read denormalized tables instead of recomputing aggregates on
+//! every request: the remaining leave slots on each day of each round, each
+//! user's approved leave, and each round's bid status counts per area.
+//!
+//! [`refresh_dashboard_projections`] keeps those tables current. It reads
+//! the scopes of the audit events recorded since its last run and rebuilds
+//! every area they touch from the canonical tables: an area-scoped event
+//! rebuilds its area, and a bid-year-scoped event rebuilds every area of
+//! the year. The first run builds every area. Dashboards lag the canonical
+//! tables by at most one refresh, and every response reports the last
+//! audit event it reflects.
+
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+
+use time::{Date, Duration};
+use zab_bid::BootstrapMetadata;
+use zab_bid_domain::{Area, BidStatus, CanonicalBidYear, DailySlots, Round, SlotInventory};
+use zab_bid_persistence::{
+    AreaProjectionData, LeaveBidData, ProjectedAreaProgressData, ProjectedDailySlotsData,
+    ProjectedUserAwardData, SqlitePersistence,
+};
+
+use crate::bid_rules::resolve_bid_year;
+use crate::error::ApiError;
+use crate::leave_bids::resolve_area;
+use crate::request_response::{
+    AreaProgressInfo, DashboardDayInfo, GetAreaDashboardRequest, GetAreaDashboardResponse,
+    GetBidYearDashboardResponse, RoundProgressInfo, UserAwardInfo,
+};
+use crate::slot_inventory::{load_slot_inventory, parse_inventory_range};
+
+/// The name the dashboard projections record their cursor under.
+pub const DASHBOARD_PROJECTION: &str = "dashboards";
+
+/// The outcome of one projection refresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProjectionRefresh {
+    /// Areas whose projections were rebuilt.
+    pub areas_refreshed: usize,
+    /// The last audit event the projections now reflect.
+    pub last_event_id: Option<i64>,
+}
+
+/// Converts a stored count to `i32`, saturating.
+fn to_count(value: u32) -> i32 {
+    i32::try_from(value).unwrap_or(i32::MAX)
+}
+
+/// Converts a projected count back to `u32`, treating negatives as zero.
+fn from_count(value: i32) -> u32 {
+    u32::try_from(value).unwrap_or(0)
+}
+
+/// Returns the areas touched by a set of audit event scopes.
+///
+/// Area IDs that no longer exist are returned with their bid year so their
+/// rows can be cleared.
+fn affected_areas(
+    metadata: &BootstrapMetadata,
+    scopes: &[(Option<i64>, Option<i64>)],
+) -> BTreeSet<(i64, i64)> {
+    let mut areas: BTreeSet<(i64, i64)> = BTreeSet::new();
+    for scope in scopes {
+        match *scope {
+            (Some(bid_year_id), Some(area_id)) => {
+                areas.insert((bid_year_id, area_id));
+            }
+            (Some(bid_year_id), None) => {
+                areas.extend(
+                    metadata
+                        .areas
+                        .iter()
+                        .filter(|(bid_year, _)| bid_year.bid_year_id() == Some(bid_year_id))
+                        .filter_map(|(_, area)| area.area_id().map(|id| (bid_year_id, id))),
+                );
+            }
+            (None, _) => {}
+        }
+    }
+    areas
+}
+
+/// A bid year's slot inventory over its whole span.
+struct BidYearSlots {
+    start_date: Date,
+    end_date: Date,
+    inventory: SlotInventory,
+}
+
+/// Loads a bid year's slot inventory from its start date to its end date.
+fn load_bid_year_slots(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    year: u16,
+) -> Result<BidYearSlots, ApiError> {
+    let canonical_bid_year: CanonicalBidYear = persistence
+        .list_bid_years()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load bid years: {e}"),
+        })?
+        .into_iter()
+        .find(|by| by.year() == year)
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Bid year {year} exists in metadata but not in storage"),
+        })?;
+    let start_date: Date = canonical_bid_year.start_date();
+    let end_date: Date = canonical_bid_year
+        .end_date()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to compute end of bid year {year}: {e}"),
+        })?;
+    let inventory: SlotInventory =
+        load_slot_inventory(persistence, bid_year_id, start_date, end_date)?;
+    Ok(BidYearSlots {
+        start_date,
+        end_date,
+        inventory,
+    })
+}
+
+/// Builds every projection row of one area from the canonical tables.
+fn project_area(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    area: &Area,
+    slots: &BidYearSlots,
+) -> Result<AreaProjectionData, ApiError> {
+    let mut projection: AreaProjectionData = AreaProjectionData {
+        bid_year_id,
+        area_id: area.area_id().unwrap_or_default(),
+        ..AreaProjectionData::default()
+    };
+    let area_id: i64 = projection.area_id;
+
+    if let Some(round_group_id) = area.round_group_id() {
+        let rounds: Vec<Round> =
+            persistence
+                .list_rounds(round_group_id)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to list rounds: {e}"),
+                })?;
+        for round_id in rounds.iter().filter_map(Round::round_id) {
+            let mut date: Date = slots.start_date;
+            while date <= slots.end_date {
+                let day: DailySlots = slots.inventory.slots(area_id, round_id, date);
+                projection.daily_slots.push(ProjectedDailySlotsData {
+                    area_id,
+                    round_id,
+                    slot_date: date.to_string(),
+                    capacity: to_count(day.capacity()),
+                    used: to_count(day.used),
+                    remaining: to_count(day.remaining()),
+                });
+                date += Duration::days(1);
+            }
+        }
+    }
+
+    let mut awards: BTreeMap<i64, (u32, u32)> = BTreeMap::new();
+    for bid in persistence
+        .list_leave_bids(bid_year_id, area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load leave bids: {e}"),
+        })?
+        .into_iter()
+        .filter(|bid| bid.status == LeaveBidData::STATUS_APPROVED)
+    {
+        let (days, hours): &mut (u32, u32) = awards.entry(bid.user_id).or_default();
+        *days += 1;
+        *hours += u32::try_from(bid.hours).unwrap_or(0);
+    }
+    projection.user_awards = awards
+        .into_iter()
+        .map(|(user_id, (days, hours))| ProjectedUserAwardData {
+            user_id,
+            area_id,
+            awarded_days: to_count(days),
+            awarded_hours: to_count(hours),
+        })
+        .collect();
+
+    let mut progress: BTreeMap<i64, ProjectedAreaProgressData> = BTreeMap::new();
+    for row in persistence
+        .get_bid_status_for_area(bid_year_id, area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load bid status: {e}"),
+        })?
+    {
+        let status: BidStatus = row.status.parse().map_err(|_| ApiError::Internal {
+            message: format!("Stored bid status '{}' is invalid", row.status),
+        })?;
+        let counts: &mut ProjectedAreaProgressData =
+            progress
+                .entry(row.round_id)
+                .or_insert_with(|| ProjectedAreaProgressData {
+                    area_id,
+                    round_id: row.round_id,
+                    bidders: 0,
+                    not_started: 0,
+                    in_progress: 0,
+                    completed: 0,
+                });
+        counts.bidders += 1;
+        match status {
+            BidStatus::NotStartedPreWindow | BidStatus::NotStartedInWindow => {
+                counts.not_started += 1;
+            }
+            BidStatus::InProgress => counts.in_progress += 1,
+            _ => counts.completed += 1,
+        }
+    }
+    projection.progress = progress.into_values().collect();
+
+    Ok(projection)
+}
+
+/// Rebuilds the dashboard projections of every area changed since the
+/// last refresh.
+///
+/// The rebuilt rows and the new cursor are written in one transaction, so
+/// a failed refresh is retried in full by the next one.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `updated_at` - When the refresh runs (RFC 3339)
+///
+/// # Errors
+///
+/// Returns an error if the audit log, the canonical tables, or the
+/// projections cannot be read or written.
+pub fn refresh_dashboard_projections(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    updated_at: &str,
+) -> Result<ProjectionRefresh, ApiError> {
+    let cursor: Option<i64> = persistence
+        .get_projection_cursor(DASHBOARD_PROJECTION)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read projection cursor: {e}"),
+        })?;
+    let Some(latest) = persistence
+        .get_latest_audit_event_id()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read latest audit event: {e}"),
+        })?
+        .filter(|latest| cursor.is_none_or(|cursor| cursor < *latest))
+    else {
+        return Ok(ProjectionRefresh {
+            areas_refreshed: 0,
+            last_event_id: cursor,
+        });
+    };
+
+    let areas: BTreeSet<(i64, i64)> = match cursor {
+        Some(cursor) => {
+            let scopes: Vec<(Option<i64>, Option<i64>)> = persistence
+                .list_audit_scopes(cursor, latest)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to read audit scopes: {e}"),
+                })?;
+            affected_areas(metadata, &scopes)
+        }
+        None => metadata
+            .areas
+            .iter()
+            .filter_map(|(bid_year, area)| bid_year.bid_year_id().zip(area.area_id()))
+            .collect(),
+    };
+
+    let mut bid_year_slots: BTreeMap<i64, BidYearSlots> = BTreeMap::new();
+    let mut projections: Vec<AreaProjectionData> = Vec::new();
+    for (bid_year_id, area_id) in areas {
+        let Some((bid_year, area)) = metadata
+            .areas
+            .iter()
+            .find(|(_, area)| area.area_id() == Some(area_id))
+        else {
+            // The area no longer exists; clear what was projected for it
+            projections.push(AreaProjectionData {
+                bid_year_id,
+                area_id,
+                ..AreaProjectionData::default()
+            });
+            continue;
+        };
+        let slots: &BidYearSlots = match bid_year_slots.entry(bid_year_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(load_bid_year_slots(
+                persistence,
+                bid_year_id,
+                bid_year.year(),
+            )?),
+        };
+        projections.push(project_area(persistence, bid_year_id, area, slots)?);
+    }
+
+    persistence
+        .apply_area_projections(DASHBOARD_PROJECTION, &projections, latest, updated_at)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to write projections: {e}"),
+        })?;
+
+    Ok(ProjectionRefresh {
+        areas_refreshed: projections.len(),
+        last_event_id: Some(latest),
+    })
+}
+
+/// Converts projected progress into its API representation.
+fn to_progress_info(progress: &ProjectedAreaProgressData) -> RoundProgressInfo {
+    RoundProgressInfo {
+        round_id: progress.round_id,
+        bidders: from_count(progress.bidders),
+        not_started: from_count(progress.not_started),
+        in_progress: from_count(progress.in_progress),
+        completed: from_count(progress.completed),
+    }
+}
+
+/// Reads the dashboard projection cursor.
+fn load_cursor(persistence: &mut SqlitePersistence) -> Result<Option<i64>, ApiError> {
+    persistence
+        .get_projection_cursor(DASHBOARD_PROJECTION)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read projection cursor: {e}"),
+        })
+}
+
+/// Reads the projected bid progress of every area in a bid year.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `bid_year_id` - The canonical bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the projections
+/// cannot be read.
+pub fn get_bid_year_dashboard(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<GetBidYearDashboardResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+
+    let projected_through_event_id: Option<i64> = load_cursor(persistence)?;
+    let progress: Vec<ProjectedAreaProgressData> = persistence
+        .list_projected_area_progress(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read area progress: {e}"),
+        })?;
+
+    let mut areas: Vec<AreaProgressInfo> = Vec::new();
+    for row in &progress {
+        if areas.last().is_none_or(|area| area.area_id != row.area_id) {
+            let area_code: String = metadata
+                .areas
+                .iter()
+                .find(|(_, area)| area.area_id() == Some(row.area_id))
+                .map_or_else(String::new, |(_, area)| area.area_code().to_string());
+            areas.push(AreaProgressInfo {
+                area_id: row.area_id,
+                area_code,
+                rounds: Vec::new(),
+            });
+        }
+        if let Some(area) = areas.last_mut() {
+            area.rounds.push(to_progress_info(row));
+        }
+    }
+
+    Ok(GetBidYearDashboardResponse {
+        bid_year_id,
+        projected_through_event_id,
+        areas,
+    })
+}
+
+/// Reads an area's projected bid progress, awarded leave, and remaining
+/// slots.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The area and the date range of remaining slots
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The date range is invalid or longer than a year
+/// - The area does not exist
+/// - The projections cannot be read
+pub fn get_area_dashboard(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &GetAreaDashboardRequest,
+) -> Result<GetAreaDashboardResponse, ApiError> {
+    let (start_date, end_date): (Date, Date) =
+        parse_inventory_range(&request.start_date, &request.end_date)?;
+    let (bid_year, _) = resolve_area(metadata, request.area_id)?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+
+    let projected_through_event_id: Option<i64> = load_cursor(persistence)?;
+    let progress: Vec<RoundProgressInfo> = persistence
+        .list_projected_area_progress(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read area progress: {e}"),
+        })?
+        .iter()
+        .filter(|row| row.area_id == request.area_id)
+        .map(to_progress_info)
+        .collect();
+    let awards: Vec<UserAwardInfo> = persistence
+        .list_projected_user_awards(request.area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read awarded leave: {e}"),
+        })?
+        .into_iter()
+        .map(|award| UserAwardInfo {
+            user_id: award.user_id,
+            awarded_days: from_count(award.awarded_days),
+            awarded_hours: from_count(award.awarded_hours),
+        })
+        .collect();
+    let days: Vec<DashboardDayInfo> = persistence
+        .list_projected_daily_slots(
+            request.area_id,
+            &start_date.to_string(),
+            &end_date.to_string(),
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read remaining slots: {e}"),
+        })?
+        .into_iter()
+        .map(|day| DashboardDayInfo {
+            round_id: day.round_id,
+            date: day.slot_date,
+            capacity: from_count(day.capacity),
+            used: from_count(day.used),
+            remaining: from_count(day.remaining),
+        })
+        .collect();
+
+    Ok(GetAreaDashboardResponse {
+        area_id: request.area_id,
+        projected_through_event_id,
+        progress,
+        awards,
+        days,
+    })
+}
@@ -0,0 +1,274 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Eligibility handlers.
+//!
+//! Whether a user may bid is derived by the core eligibility rules from
+//! their user type, participation flags, and area assignment, together
+//! with the exceptions the facility configures per bid year. Once a bid
+//! year is canonicalized the derived value is recomputed whenever any of
+//! those inputs change. Eligibility overrides sit on top of it.
+
+use zab_bid::{BootstrapMetadata, EligibilityException, validate_exceptions};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{BidYearLifecycle, DomainError, UserType};
+use zab_bid_persistence::{
+    EligibilityExceptionData, EligibilityExceptionSpecData, OperatorData, SqlitePersistence,
+    UserEligibilityData,
+};
+
+use crate::auth::AuthenticatedActor;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_domain_error};
+use crate::leave_bids::load_lifecycle_state;
+use crate::request_response::{
+    EligibilityExceptionInfo, ListEligibilityExceptionsResponse, ListUserEligibilityResponse,
+    SetEligibilityExceptionsRequest, SetEligibilityExceptionsResponse, UserEligibilityInfo,
+};
+use crate::webhooks::require_admin;
+
+/// Converts a stored exception into its API representation.
+fn to_exception_info(data: &EligibilityExceptionData) -> EligibilityExceptionInfo {
+    EligibilityExceptionInfo {
+        user_type: data.user_type.clone(),
+        area_id: data.area_id,
+        can_bid: data.can_bid,
+    }
+}
+
+/// Describes an exception list for an audit snapshot.
+fn describe_exceptions(exceptions: &[EligibilityExceptionInfo]) -> String {
+    let described: Vec<String> = exceptions
+        .iter()
+        .map(|exception| {
+            let scope: String = exception
+                .area_id
+                .map_or_else(|| String::from("all"), |area_id| area_id.to_string());
+            format!("{}@{scope}={}", exception.user_type, exception.can_bid)
+        })
+        .collect();
+    format!("exceptions=[{}]", described.join(";"))
+}
+
+/// Brings a bid year's derived eligibility up to date.
+///
+/// Called after anything eligibility is derived from changes. A bid year
+/// that has not been canonicalized has nothing to recompute.
+///
+/// # Returns
+///
+/// The number of users whose derived eligibility changed.
+///
+/// # Errors
+///
+/// Returns an error if the recomputation fails.
+pub fn refresh_derived_eligibility(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<usize, ApiError> {
+    persistence
+        .recompute_eligibility(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to recompute eligibility: {e}"),
+        })
+}
+
+/// Lists a bid year's eligibility exceptions.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn list_eligibility_exceptions(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<ListEligibilityExceptionsResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+
+    let exceptions: Vec<EligibilityExceptionInfo> = persistence
+        .list_eligibility_exceptions(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list eligibility exceptions: {e}"),
+        })?
+        .iter()
+        .map(to_exception_info)
+        .collect();
+
+    Ok(ListEligibilityExceptionsResponse {
+        bid_year_id,
+        exceptions,
+    })
+}
+
+/// Replaces a bid year's eligibility exceptions and recomputes the derived
+/// eligibility of its users.
+///
+/// Exceptions are fixed once bidding starts, so nobody's eligibility
+/// changes mid-bid without an explicit override.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year and its new exceptions
+/// * `authenticated_actor` - The authenticated actor setting the exceptions
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist
+/// - Bidding has already started
+/// - A user type is unknown, an area is not a bid area of the bid year, or
+///   two exceptions apply to the same user type in the same scope
+/// - The database operation fails
+pub fn set_eligibility_exceptions(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetEligibilityExceptionsRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetEligibilityExceptionsResponse, ApiError> {
+    require_admin(authenticated_actor, "set eligibility exceptions")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    if matches!(
+        lifecycle_state,
+        BidYearLifecycle::BiddingActive | BidYearLifecycle::BiddingClosed
+    ) {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("set eligibility exceptions"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+
+    let exceptions: Vec<EligibilityException> = request
+        .exceptions
+        .iter()
+        .map(|exception| {
+            let user_type: UserType =
+                UserType::parse(exception.user_type.trim()).map_err(translate_domain_error)?;
+            if let Some(area_id) = exception.area_id {
+                let area = metadata
+                    .areas
+                    .iter()
+                    .filter(|(bid_year, _)| bid_year.year() == year)
+                    .map(|(_, area)| area)
+                    .find(|area| area.area_id() == Some(area_id))
+                    .ok_or_else(|| ApiError::ResourceNotFound {
+                        resource_type: String::from("Area"),
+                        message: format!("Area with ID {area_id} not found in bid year {year}"),
+                    })?;
+                if area.is_system_area() {
+                    return Err(translate_domain_error(
+                        DomainError::InvalidEligibilityException {
+                            reason: format!(
+                                "Area {} is a system area, where nobody bids",
+                                area.area_code()
+                            ),
+                        },
+                    ));
+                }
+            }
+            Ok(EligibilityException {
+                user_type,
+                area_id: exception.area_id,
+                can_bid: exception.can_bid,
+            })
+        })
+        .collect::<Result<Vec<EligibilityException>, ApiError>>()?;
+    validate_exceptions(&exceptions).map_err(translate_domain_error)?;
+
+    let specs: Vec<EligibilityExceptionSpecData> = exceptions
+        .iter()
+        .map(|exception| EligibilityExceptionSpecData {
+            user_type: exception.user_type.as_str().to_string(),
+            area_id: exception.area_id,
+            can_bid: exception.can_bid,
+        })
+        .collect();
+    let stored: Vec<EligibilityExceptionInfo> = specs
+        .iter()
+        .map(|spec| EligibilityExceptionInfo {
+            user_type: spec.user_type.clone(),
+            area_id: spec.area_id,
+            can_bid: spec.can_bid,
+        })
+        .collect();
+
+    let previous: Vec<EligibilityExceptionInfo> =
+        list_eligibility_exceptions(persistence, metadata, request.bid_year_id)?.exceptions;
+    persistence
+        .replace_eligibility_exceptions(request.bid_year_id, &specs)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to store eligibility exceptions: {e}"),
+        })?;
+    let users_changed: usize = refresh_derived_eligibility(persistence, request.bid_year_id)?;
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("SetEligibilityExceptions"),
+        Some(format!(
+            "Set {} eligibility exception(s) for bid year {year}; derived eligibility changed for {users_changed} user(s)",
+            stored.len()
+        )),
+    );
+    let before: StateSnapshot = StateSnapshot::new(describe_exceptions(&previous));
+    let after: StateSnapshot = StateSnapshot::new(describe_exceptions(&stored));
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetEligibilityExceptionsResponse {
+        bid_year_id: request.bid_year_id,
+        message: format!(
+            "Bid year {year} now has {} eligibility exception(s)",
+            stored.len()
+        ),
+        exceptions: stored,
+        users_changed,
+    })
+}
+
+/// Lists the canonical eligibility of every user in a bid year, showing
+/// the derived value beneath any override.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn list_user_eligibility(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<ListUserEligibilityResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+
+    let users: Vec<UserEligibilityInfo> = persistence
+        .list_user_eligibility(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list eligibility: {e}"),
+        })?
+        .into_iter()
+        .map(|data: UserEligibilityData| UserEligibilityInfo {
+            user_id: data.user_id,
+            initials: data.initials,
+            derived_can_bid: data.derived_can_bid,
+            can_bid: data.can_bid,
+            is_overridden: data.is_overridden,
+            override_reason: data.override_reason,
+        })
+        .collect();
+
+    Ok(ListUserEligibilityResponse { bid_year_id, users })
+}
@@ -307,6 +307,16 @@ pub fn translate_domain_error(err: DomainError) -> ApiError {
                 "Cannot activate bid year: year {active_year} is already in BiddingActive state"
             ),
         },
+        DomainError::BiddingNotReady {
+            year,
+            blocking_reasons,
+        } => ApiError::DomainRuleViolation {
+            rule: String::from("bidding_requires_readiness"),
+            message: format!(
+                "Cannot activate bidding for bid year {year}: {}",
+                blocking_reasons.join("; ")
+            ),
+        },
         DomainError::OperationNotAllowedInState { operation, state } => {
             ApiError::DomainRuleViolation {
                 rule: String::from("operation_allowed_in_state"),
@@ -500,6 +510,117 @@ pub fn translate_domain_error(err: DomainError) -> ApiError {
                 message: format!("Invalid status transition from '{from}' to '{to}': {reason}"),
             }
         }
+        DomainError::InvalidBidderAdvance { reason } => ApiError::DomainRuleViolation {
+            rule: String::from("bidder_advance"),
+            message: format!("Invalid bidder advance: {reason}"),
+        },
+        DomainError::InvalidBidReceiptMethod { method } => ApiError::InvalidInput {
+            field: String::from("received_via"),
+            message: format!(
+                "Invalid bid receipt method: '{method}' (expected phone, in_person, or written)"
+            ),
+        },
+        DomainError::InvalidLeaveBid { reason } => ApiError::DomainRuleViolation {
+            rule: String::from("leave_bid"),
+            message: format!("Invalid leave bid: {reason}"),
+        },
+        DomainError::InvalidBidPreference { reason } => ApiError::DomainRuleViolation {
+            rule: String::from("bid_preference"),
+            message: format!("Invalid bid preference: {reason}"),
+        },
+        DomainError::InvalidOverbid { reason } => ApiError::DomainRuleViolation {
+            rule: String::from("overbid"),
+            message: format!("Invalid overbid: {reason}"),
+        },
+        DomainError::InvalidSelfBid { reason } => ApiError::DomainRuleViolation {
+            rule: String::from("self_bid"),
+            message: format!("Invalid self-service bid: {reason}"),
+        },
+        DomainError::InvalidBidRule { reason } => ApiError::InvalidInput {
+            field: String::from("rules"),
+            message: format!("Invalid bid rule: {reason}"),
+        },
+        DomainError::InvalidEligibilityException { reason } => ApiError::InvalidInput {
+            field: String::from("exceptions"),
+            message: format!("Invalid eligibility exception: {reason}"),
+        },
+        DomainError::BidRuleViolated { rule, reason } => ApiError::DomainRuleViolation {
+            message: format!("Bid rule '{rule}' violated: {reason}"),
+            rule,
+        },
+        DomainError::RoundNotComplete {
+            round_id,
+            outstanding,
+        } => ApiError::DomainRuleViolation {
+            rule: String::from("round_sign_off"),
+            message: format!(
+                "Round {round_id} cannot be signed off: {} still to bid ({})",
+                outstanding.len(),
+                outstanding.join(", ")
+            ),
+        },
+        DomainError::RoundSignedOff { round_id } => ApiError::DomainRuleViolation {
+            rule: String::from("round_signed_off"),
+            message: format!(
+                "Round {round_id} has been signed off; its bids cannot change without an override"
+            ),
+        },
+        DomainError::InvalidBidAmendmentPolicy { reason } => ApiError::InvalidInput {
+            field: String::from("policy"),
+            message: reason,
+        },
+        err @ DomainError::AmendmentWindowPassed { .. } => ApiError::DomainRuleViolation {
+            rule: String::from("amendment_window"),
+            message: err.to_string(),
+        },
+        DomainError::InvalidWaitlistOffer { reason } => ApiError::DomainRuleViolation {
+            rule: String::from("waitlist_offer"),
+            message: reason,
+        },
+        err @ DomainError::WaitlistOfferExpired { .. } => ApiError::DomainRuleViolation {
+            rule: String::from("waitlist_offer_expired"),
+            message: err.to_string(),
+        },
+        DomainError::InvalidLeaveCap { reason } => ApiError::InvalidInput {
+            field: String::from("carryover_cap_hours"),
+            message: reason,
+        },
+        err @ DomainError::UnknownFeatureFlag { .. } => ApiError::InvalidInput {
+            field: String::from("flag"),
+            message: err.to_string(),
+        },
+        err @ DomainError::InvalidReopenJustification { .. } => ApiError::InvalidInput {
+            field: String::from("justification"),
+            message: err.to_string(),
+        },
+        err @ DomainError::BidYearHasBids { .. } => ApiError::DomainRuleViolation {
+            rule: String::from("reopen_requires_no_bids"),
+            message: err.to_string(),
+        },
+        err @ DomainError::InvalidUserMerge { .. } => ApiError::DomainRuleViolation {
+            rule: String::from("user_merge"),
+            message: err.to_string(),
+        },
+        err @ DomainError::ScopeFrozen { .. } => ApiError::DomainRuleViolation {
+            rule: String::from("scope_frozen"),
+            message: err.to_string(),
+        },
+        err @ DomainError::ScopeNotFrozen { .. } => ApiError::DomainRuleViolation {
+            rule: String::from("scope_not_frozen"),
+            message: err.to_string(),
+        },
+        err @ DomainError::InvalidFreezeReason { .. } => ApiError::InvalidInput {
+            field: String::from("reason"),
+            message: err.to_string(),
+        },
+        err @ DomainError::InvalidCheckpointLabel { .. } => ApiError::InvalidInput {
+            field: String::from("label"),
+            message: err.to_string(),
+        },
+        DomainError::InvalidFacility { field, reason } => ApiError::InvalidInput {
+            field,
+            message: reason,
+        },
     }
 }
 
@@ -0,0 +1,217 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Facility configuration handlers.
+//!
+//! The facility records the defaults every bid year inherits unless it
+//! configures its own: the timezone of the bid schedule, the leave accrual
+//! rate of each service tier, and the annual leave carryover cap. Until the
+//! facility is configured, bid years use the built-in defaults.
+
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{AccrualRates, Facility, LeaveCapPolicy};
+use zab_bid_persistence::{FacilityData, OperatorData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::error::{ApiError, translate_domain_error};
+use crate::request_response::{
+    FacilityInfo, GetFacilityResponse, SetFacilityRequest, SetFacilityResponse,
+};
+use crate::webhooks::require_admin;
+
+/// Converts a stored facility into its domain configuration.
+fn to_facility(data: &FacilityData) -> Option<Facility> {
+    let rates: AccrualRates = AccrualRates::new(
+        u8::try_from(data.junior_accrual_hours).ok()?,
+        u8::try_from(data.mid_accrual_hours).ok()?,
+        u8::try_from(data.senior_accrual_hours).ok()?,
+    )
+    .ok()?;
+    let cap: LeaveCapPolicy =
+        LeaveCapPolicy::new(u16::try_from(data.max_carryover_hours).ok()?).ok()?;
+    Facility::new(
+        &data.identifier,
+        &data.name,
+        &data.default_timezone,
+        rates,
+        cap,
+    )
+    .ok()
+}
+
+/// Converts a facility into its API representation.
+fn to_facility_info(facility: &Facility, updated_at: String) -> FacilityInfo {
+    FacilityInfo {
+        identifier: facility.identifier().to_string(),
+        name: facility.name().to_string(),
+        default_timezone: facility.default_timezone().to_string(),
+        junior_accrual_hours: facility.accrual_rates().junior_hours(),
+        mid_accrual_hours: facility.accrual_rates().mid_hours(),
+        senior_accrual_hours: facility.accrual_rates().senior_hours(),
+        max_carryover_hours: facility.leave_cap().carryover_cap_hours(),
+        updated_at,
+    }
+}
+
+/// Describes a facility for audit snapshots.
+fn describe_facility(facility: Option<&Facility>) -> String {
+    facility.map_or_else(
+        || String::from("facility=unset"),
+        |facility| {
+            let rates: AccrualRates = facility.accrual_rates();
+            format!(
+                "identifier={},name={},default_timezone={},accrual_hours={}/{}/{},max_carryover_hours={}",
+                facility.identifier(),
+                facility.name(),
+                facility.default_timezone(),
+                rates.junior_hours(),
+                rates.mid_hours(),
+                rates.senior_hours(),
+                facility.leave_cap().carryover_cap_hours()
+            )
+        },
+    )
+}
+
+/// Loads the facility's configuration, with when it last changed.
+fn load_facility_with_timestamp(
+    persistence: &mut SqlitePersistence,
+) -> Result<Option<(Facility, String)>, ApiError> {
+    let Some(data) = persistence.get_facility().map_err(|e| ApiError::Internal {
+        message: format!("Failed to get facility: {e}"),
+    })?
+    else {
+        return Ok(None);
+    };
+
+    let facility: Facility = to_facility(&data).ok_or_else(|| ApiError::Internal {
+        message: String::from("Stored facility configuration is invalid"),
+    })?;
+    Ok(Some((facility, data.updated_at)))
+}
+
+/// Loads the facility's configuration, if it has been set.
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be read or is invalid.
+pub fn load_facility(persistence: &mut SqlitePersistence) -> Result<Option<Facility>, ApiError> {
+    Ok(load_facility_with_timestamp(persistence)?.map(|(facility, _)| facility))
+}
+
+/// Loads the leave accrual rates bid years inherit.
+///
+/// Without a configured facility, the default rates apply.
+///
+/// # Errors
+///
+/// Returns an error if the facility cannot be read or is invalid.
+pub fn load_accrual_rates(persistence: &mut SqlitePersistence) -> Result<AccrualRates, ApiError> {
+    Ok(load_facility(persistence)?
+        .as_ref()
+        .map_or_else(AccrualRates::default, Facility::accrual_rates))
+}
+
+/// Gets the facility's configuration.
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be read or is invalid.
+pub fn get_facility(persistence: &mut SqlitePersistence) -> Result<GetFacilityResponse, ApiError> {
+    let facility: Option<FacilityInfo> = load_facility_with_timestamp(persistence)?
+        .map(|(facility, updated_at)| to_facility_info(&facility, updated_at));
+
+    Ok(GetFacilityResponse { facility })
+}
+
+/// Sets the facility's configuration.
+///
+/// Bid years that have not set their own carryover cap or bid schedule
+/// timezone pick up the new values.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The new configuration
+/// * `authenticated_actor` - The authenticated actor setting the configuration
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - A field is invalid
+/// - The database operation fails
+pub fn set_facility(
+    persistence: &mut SqlitePersistence,
+    request: &SetFacilityRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetFacilityResponse, ApiError> {
+    require_admin(authenticated_actor, "set facility configuration")?;
+
+    let rates: AccrualRates = AccrualRates::new(
+        request.junior_accrual_hours,
+        request.mid_accrual_hours,
+        request.senior_accrual_hours,
+    )
+    .map_err(translate_domain_error)?;
+    let cap: LeaveCapPolicy =
+        LeaveCapPolicy::new(request.max_carryover_hours).map_err(translate_domain_error)?;
+    let facility: Facility = Facility::new(
+        &request.identifier,
+        &request.name,
+        &request.default_timezone,
+        rates,
+        cap,
+    )
+    .map_err(translate_domain_error)?;
+
+    let previous: Option<Facility> = load_facility(persistence)?;
+    persistence
+        .set_facility(&FacilityData {
+            identifier: facility.identifier().to_string(),
+            name: facility.name().to_string(),
+            default_timezone: facility.default_timezone().to_string(),
+            junior_accrual_hours: i32::from(rates.junior_hours()),
+            mid_accrual_hours: i32::from(rates.mid_hours()),
+            senior_accrual_hours: i32::from(rates.senior_hours()),
+            max_carryover_hours: i32::from(cap.carryover_cap_hours()),
+            updated_at: String::new(),
+        })
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to store facility: {e}"),
+        })?;
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("SetFacility"),
+        Some(format!(
+            "Set facility configuration for {} ({})",
+            facility.identifier(),
+            facility.name()
+        )),
+    );
+    let before: StateSnapshot = StateSnapshot::new(describe_facility(previous.as_ref()));
+    let after: StateSnapshot = StateSnapshot::new(describe_facility(Some(&facility)));
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    let (stored, updated_at): (Facility, String) = load_facility_with_timestamp(persistence)?
+        .ok_or_else(|| ApiError::Internal {
+            message: String::from("Facility configuration was not stored"),
+        })?;
+
+    Ok(SetFacilityResponse {
+        message: format!("Facility {} configured", stored.identifier()),
+        facility: to_facility_info(&stored, updated_at),
+    })
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Feature flag handlers.
+//!
+//! Risky subsystems are switched on or off per bid year rather than per
+//! build. API functions that belong to a flagged subsystem call
+//! [`is_feature_enabled`] before doing any work.
+
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::FeatureFlag;
+use zab_bid_persistence::{FeatureFlagData, OperatorData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_domain_error};
+use crate::request_response::{
+    FeatureFlagInfo, GetFeatureFlagsResponse, SetFeatureFlagRequest, SetFeatureFlagResponse,
+};
+use crate::webhooks::require_admin;
+
+/// Describes a flag setting for audit snapshots.
+fn describe_flag(flag: FeatureFlag, enabled: bool) -> String {
+    format!("feature_flag={},enabled={enabled}", flag.as_str())
+}
+
+/// Returns whether a feature is enabled in a bid year.
+///
+/// A bid year without a stored setting uses the flag's default.
+///
+/// # Errors
+///
+/// Returns an error if the setting cannot be read.
+pub fn is_feature_enabled(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    flag: FeatureFlag,
+) -> Result<bool, ApiError> {
+    let stored: Option<bool> = persistence
+        .get_feature_flag(bid_year_id, flag.as_str())
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get feature flag: {e}"),
+        })?;
+    Ok(stored.unwrap_or_else(|| flag.default_enabled()))
+}
+
+/// Gets every feature flag's setting in a bid year.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn get_feature_flags(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<GetFeatureFlagsResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+    let stored: Vec<FeatureFlagData> =
+        persistence
+            .list_feature_flags(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list feature flags: {e}"),
+            })?;
+
+    let flags: Vec<FeatureFlagInfo> = FeatureFlag::ALL
+        .into_iter()
+        .map(|flag| {
+            let setting: Option<bool> = stored
+                .iter()
+                .find(|data| data.flag == flag.as_str())
+                .map(|data| data.enabled);
+            FeatureFlagInfo {
+                flag: flag.as_str().to_string(),
+                enabled: setting.unwrap_or_else(|| flag.default_enabled()),
+                is_default: setting.is_none(),
+            }
+        })
+        .collect();
+
+    Ok(GetFeatureFlagsResponse { bid_year_id, flags })
+}
+
+/// Enables or disables a feature in a bid year.
+///
+/// Flags may be changed at any point in the lifecycle, so a subsystem
+/// that misbehaves during bidding can be switched off without a redeploy.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year, flag, and new setting
+/// * `authenticated_actor` - The authenticated actor setting the flag
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist
+/// - The flag is unknown
+/// - The database operation fails
+pub fn set_feature_flag(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetFeatureFlagRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetFeatureFlagResponse, ApiError> {
+    require_admin(authenticated_actor, "set feature flag")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let flag: FeatureFlag = request
+        .flag
+        .trim()
+        .parse()
+        .map_err(translate_domain_error)?;
+
+    let previous: bool = is_feature_enabled(persistence, request.bid_year_id, flag)?;
+    persistence
+        .set_feature_flag(request.bid_year_id, flag.as_str(), request.enabled)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to store feature flag: {e}"),
+        })?;
+
+    let state: &str = if request.enabled {
+        "enabled"
+    } else {
+        "disabled"
+    };
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("SetFeatureFlag"),
+        Some(format!(
+            "Set feature {} to {state} for bid year {year}",
+            flag.as_str()
+        )),
+    );
+    let before: StateSnapshot = StateSnapshot::new(describe_flag(flag, previous));
+    let after: StateSnapshot = StateSnapshot::new(describe_flag(flag, request.enabled));
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetFeatureFlagResponse {
+        bid_year_id: request.bid_year_id,
+        flag: flag.as_str().to_string(),
+        enabled: request.enabled,
+        message: format!("Feature {} {state} for bid year {year}", flag.as_str()),
+    })
+}
@@ -0,0 +1,167 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Limits on free-text request fields.
+//!
+//! Names, reasons, and notes are typed by operators and end up in the audit
+//! log, exports, and printed reports. Request DTOs check them as they are
+//! deserialized, so an oversized value or one carrying control characters
+//! is rejected before it reaches core.
+//!
+//! Each check is a `deserialize_with` function:
+//!
+//! ```ignore
+//! #[derive(serde::Deserialize)]
+//! struct Example {
+//!     #[serde(deserialize_with = "zab_bid_api::free_text::reason")]
+//!     reason: String,
+//!     #[serde(default, deserialize_with = "zab_bid_api::free_text::optional_note")]
+//!     notes: Option<String>,
+//! }
+//! ```
+//!
+//! Names must fit on one line. Reasons and notes may span several lines, so
+//! line feeds, carriage returns, and tabs are allowed in them.
+
+use serde::{Deserialize, Deserializer, de::Error as _};
+
+/// Maximum length of a name or label, in characters.
+pub const MAX_NAME_LENGTH: usize = 100;
+
+/// Maximum length of a reason or justification, in characters.
+pub const MAX_REASON_LENGTH: usize = 1000;
+
+/// Maximum length of a note, in characters.
+pub const MAX_NOTE_LENGTH: usize = 2000;
+
+/// The kinds of free text a request carries.
+#[derive(Debug, Clone, Copy)]
+enum FreeText {
+    Name,
+    Reason,
+    Note,
+}
+
+impl FreeText {
+    const fn max_length(self) -> usize {
+        match self {
+            Self::Name => MAX_NAME_LENGTH,
+            Self::Reason => MAX_REASON_LENGTH,
+            Self::Note => MAX_NOTE_LENGTH,
+        }
+    }
+
+    const fn allows_line_breaks(self) -> bool {
+        !matches!(self, Self::Name)
+    }
+
+    /// Checks a value, returning a message describing the first problem.
+    fn check(self, value: &str) -> Result<(), String> {
+        let max_length: usize = self.max_length();
+        if value.chars().count() > max_length {
+            return Err(format!("must be at most {max_length} characters"));
+        }
+        let allowed = |c: char| self.allows_line_breaks() && matches!(c, '\n' | '\r' | '\t');
+        if value.chars().any(|c| c.is_control() && !allowed(c)) {
+            return Err(String::from("must not contain control characters"));
+        }
+        Ok(())
+    }
+
+    fn deserialize<'de, D>(self, deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value: String = String::deserialize(deserializer)?;
+        self.check(&value).map_err(D::Error::custom)?;
+        Ok(value)
+    }
+
+    fn deserialize_optional<'de, D>(self, deserializer: D) -> Result<Option<String>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value: Option<String> = Option::deserialize(deserializer)?;
+        if let Some(value) = &value {
+            self.check(value).map_err(D::Error::custom)?;
+        }
+        Ok(value)
+    }
+}
+
+/// Deserializes a name or label.
+///
+/// # Errors
+///
+/// Returns an error if the value is longer than [`MAX_NAME_LENGTH`] or
+/// contains any control character.
+pub fn name<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    FreeText::Name.deserialize(deserializer)
+}
+
+/// Deserializes an optional name or label.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`name`].
+pub fn optional_name<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    FreeText::Name.deserialize_optional(deserializer)
+}
+
+/// Deserializes a reason or justification.
+///
+/// # Errors
+///
+/// Returns an error if the value is longer than [`MAX_REASON_LENGTH`] or
+/// contains a control character other than a line break or tab.
+pub fn reason<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    FreeText::Reason.deserialize(deserializer)
+}
+
+/// Deserializes an optional reason or justification.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`reason`].
+pub fn optional_reason<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    FreeText::Reason.deserialize_optional(deserializer)
+}
+
+/// Deserializes a note.
+///
+/// # Errors
+///
+/// Returns an error if the value is longer than [`MAX_NOTE_LENGTH`] or
+/// contains a control character other than a line break or tab.
+pub fn note<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    FreeText::Note.deserialize(deserializer)
+}
+
+/// Deserializes an optional note.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`note`].
+pub fn optional_note<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    FreeText::Note.deserialize_optional(deserializer)
+}
@@ -6,56 +6,87 @@
 //! API handler functions for state-changing and read-only operations.
 
 use num_traits::cast::ToPrimitive;
+use std::collections::BTreeMap;
 use std::str::FromStr;
 use std::time::{SystemTime, UNIX_EPOCH};
 use zab_bid::{
-    BootstrapMetadata, BootstrapResult, Command, State, TransitionResult, apply, apply_bootstrap,
-    validate_area_exists, validate_bid_year_exists,
+    BatchTransitionResult, BootstrapMetadata, BootstrapResult, Command, State, TransitionResult,
+    apply, apply_batch, apply_bootstrap, validate_area_exists, validate_bid_year_exists,
 };
 use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
 use zab_bid_domain::{
-    Area, BidSchedule, BidYear, BidYearLifecycle, CanonicalBidYear, Crew, DomainError, Initials,
-    LeaveAccrualResult, LeaveAvailabilityResult, LeaveUsage, RoundGroup, SeniorityData, UserType,
-    calculate_leave_accrual, calculate_leave_availability,
+    AccrualRates, Area, BidSchedule, BidYear, BidYearLifecycle, CanonicalBidYear, Crew,
+    DomainError, Initials, LeaveAccrualResult, LeaveAvailabilityResult, LeaveUsage,
+    ReadinessEvaluation, RoundGroup, SeniorityData, UserType, calculate_leave_accrual_with_rates,
+    calculate_leave_availability,
+};
+use zab_bid_persistence::{
+    AreaBidScheduleOverrideFields, BidScheduleFields, BlackoutDateData, CanonicalOverrideData,
+    OperatorActivityData, OperatorData, OverrideValue, RoundBidOrderData, RoundGroupSpecData,
+    RoundGroupTemplateData, RoundSpecData, SecretToken, SqlitePersistence, merge_area_bid_schedule,
 };
-use zab_bid_persistence::{OperatorData, SqlitePersistence};
 
+use crate::audit_annotations::annotations_by_event;
 use crate::auth::{AuthenticatedActor, AuthenticationService, AuthorizationService, Role};
+use crate::auto_checkpoints::{
+    AutoCheckpoints, record_bid_year_auto_checkpoints, record_operation_events,
+};
 use crate::csv_preview::{CsvRowResult, preview_csv_users as preview_csv_users_impl};
+use crate::eligibility::refresh_derived_eligibility;
 use crate::error::{ApiError, AuthError, translate_core_error, translate_domain_error};
+use crate::facility::load_facility;
+use crate::leave_caps::{LeaveBalanceWarnings, leave_balance_warnings};
 use crate::password_policy::PasswordPolicy;
 use crate::request_response::{
     AdjustBidOrderRequest, AdjustBidOrderResponse, AdjustBidWindowRequest, AdjustBidWindowResponse,
-    AreaCompletenessInfo, BidOrderPositionInfo, BidScheduleInfo, BidStatusHistoryInfo,
-    BidStatusInfo, BidYearCompletenessInfo, BidYearInfo, BlockingReason,
-    BulkUpdateBidStatusRequest, BulkUpdateBidStatusResponse, ChangePasswordRequest,
-    ChangePasswordResponse, ConfirmReadyToBidRequest, ConfirmReadyToBidResponse, CreateAreaRequest,
-    CreateBidYearRequest, CreateOperatorRequest, CreateOperatorResponse, CsvImportRowResult,
-    CsvImportRowStatus, CsvRowPreview, CsvRowStatus, DeleteOperatorRequest, DeleteOperatorResponse,
+    AreaBidScheduleInfo, AreaBootstrapStatusInfo, AreaCompletenessInfo, AuditActorInfo,
+    AuditAnnotationInfo, AuditFieldChange, AuditTimelineEntryInfo, AuditTimelineFilter,
+    AuditTimelinePageRequest, AuditTimelineScope, BidOrderPositionInfo, BidScheduleInfo,
+    BidStatusHistoryInfo, BidStatusInfo, BidYearCompletenessInfo, BidYearInfo, BlackoutDateInfo,
+    BlackoutDateResponse, BlockingReason, BulkRegisterRowResult, BulkRegisterRowStatus,
+    BulkUpdateBidStatusRequest, BulkUpdateBidStatusResponse, ChangeOperatorRoleRequest,
+    ChangeOperatorRoleResponse, ChangePasswordRequest, ChangePasswordResponse, CheckpointRequest,
+    ClearAreaBidScheduleResponse, ConfirmReadyToBidRequest, ConfirmReadyToBidResponse,
+    CreateAreaRequest, CreateBidYearRequest, CreateBlackoutDateRequest, CreateOperatorRequest,
+    CreateOperatorResponse, CsvImportRowResult, CsvImportRowStatus, CsvRowPreview, CsvRowStatus,
+    DeleteBlackoutDateResponse, DeleteOperatorRequest, DeleteOperatorResponse,
     DisableOperatorRequest, DisableOperatorResponse, EnableOperatorRequest, EnableOperatorResponse,
-    GetActiveBidYearResponse, GetBidOrderPreviewResponse, GetBidScheduleResponse,
-    GetBidStatusForAreaRequest, GetBidStatusForAreaResponse, GetBidStatusRequest,
-    GetBidStatusResponse, GetBidYearReadinessResponse, GetBootstrapCompletenessResponse,
-    GetLeaveAvailabilityResponse, GlobalCapabilities, ImportCsvUsersRequest,
-    ImportCsvUsersResponse, ListAreasRequest, ListAreasResponse, ListBidYearsResponse,
-    ListOperatorsResponse, ListUsersResponse, LoginRequest, LoginResponse, OperatorCapabilities,
-    OperatorInfo, OverrideAreaAssignmentRequest, OverrideAreaAssignmentResponse,
-    OverrideBidOrderRequest, OverrideBidOrderResponse, OverrideBidWindowRequest,
-    OverrideBidWindowResponse, OverrideEligibilityRequest, OverrideEligibilityResponse,
-    PreviewCsvUsersRequest, PreviewCsvUsersResponse, ReadinessDetailsInfo,
-    RecalculateBidWindowsRequest, RecalculateBidWindowsResponse, RegisterUserRequest,
-    ResetPasswordRequest, ResetPasswordResponse, ReviewNoBidUserResponse, SeniorityInputsInfo,
-    SetActiveBidYearRequest, SetActiveBidYearResponse, SetBidScheduleRequest,
-    SetBidScheduleResponse, SetExpectedAreaCountRequest, SetExpectedAreaCountResponse,
-    SetExpectedUserCountRequest, SetExpectedUserCountResponse, TransitionBidStatusRequest,
+    GetActiveBidYearResponse, GetAuditTimelineResponse, GetBidOrderPreviewResponse,
+    GetBidScheduleResponse, GetBidStatusForAreaRequest, GetBidStatusForAreaResponse,
+    GetBidStatusRequest, GetBidStatusResponse, GetBidYearBootstrapStatusResponse,
+    GetBidYearReadinessResponse, GetBootstrapCompletenessResponse, GetLeaveAvailabilityResponse,
+    GlobalCapabilities, ImportCsvUsersRequest, ImportCsvUsersResponse, ListAreasRequest,
+    ListAreasResponse, ListBidYearsResponse, ListBlackoutDatesResponse, ListOperatorsResponse,
+    ListOverridesResponse, ListUnreviewedNoBidUsersResponse, ListUsersResponse, LoginRequest,
+    LoginResponse, OperatorAreaScopeInfo, OperatorCapabilities, OperatorInfo,
+    OverrideAreaAssignmentRequest, OverrideAreaAssignmentResponse, OverrideBidOrderRequest,
+    OverrideBidOrderResponse, OverrideBidWindowRequest, OverrideBidWindowResponse,
+    OverrideEligibilityRequest, OverrideEligibilityResponse, OverrideInfo, PreviewCsvUsersRequest,
+    PreviewCsvUsersResponse, ReadinessDetailsInfo, RecalculateBidWindowsRequest,
+    RecalculateBidWindowsResponse, RegisterUserRequest, RegisterUsersBulkRequest,
+    RegisterUsersBulkResponse, ReopenBidYearRequest, ReopenBidYearResponse, ResetPasswordRequest,
+    ResetPasswordResponse, RevertOverrideResponse, ReviewNoBidUserRequest, ReviewNoBidUserResponse,
+    ReviewNoBidUsersRequest, ReviewNoBidUsersResponse, RevokeOperatorSessionsRequest,
+    RevokeOperatorSessionsResponse, SeniorityInputsInfo, SetActiveBidYearRequest,
+    SetActiveBidYearResponse, SetAreaBidScheduleRequest, SetAreaBidScheduleResponse,
+    SetBidScheduleRequest, SetBidScheduleResponse, SetExpectedAreaCountRequest,
+    SetExpectedAreaCountResponse, SetExpectedUserCountRequest, SetExpectedUserCountResponse,
+    SetOperatorAreaScopesRequest, SetOperatorAreaScopesResponse, TransitionBidStatusRequest,
     TransitionBidStatusResponse, TransitionToBiddingActiveRequest,
     TransitionToBiddingActiveResponse, TransitionToBiddingClosedRequest,
     TransitionToBiddingClosedResponse, TransitionToBootstrapCompleteRequest,
     TransitionToBootstrapCompleteResponse, TransitionToCanonicalizedRequest,
-    TransitionToCanonicalizedResponse, UpdateAreaRequest, UpdateAreaResponse,
-    UpdateBidYearMetadataRequest, UpdateBidYearMetadataResponse, UpdateUserRequest,
-    UpdateUserResponse, UserCapabilities, UserInfo, WhoAmIResponse,
+    TransitionToCanonicalizedResponse, UnreviewedNoBidUserInfo, UpdateAreaRequest,
+    UpdateAreaResponse, UpdateBidYearMetadataRequest, UpdateBidYearMetadataResponse,
+    UpdateBlackoutDateRequest, UpdateUserRequest, UpdateUserResponse, UserCapabilities, UserInfo,
+    WhoAmIResponse,
 };
+use crate::round_bid_order::{
+    AreaRoundOrders, RoundSequence, build_area_round_orders, load_round_sequences,
+};
+use crate::round_eligibility::{RoundEligibilityRules, get_round_eligibility_info};
+use crate::scope_freezes::require_not_frozen;
+use crate::user_initials::{resolve_initials_user, user_initials_history};
 use zab_bid_persistence::PersistenceError;
 
 /// Internal result type for user registration before ID population.
@@ -93,7 +124,7 @@ pub struct RegisterUserResult {
 /// Returns an error if:
 /// - No active bid year is set
 /// - Database query fails
-fn resolve_active_bid_year(persistence: &mut SqlitePersistence) -> Result<BidYear, ApiError> {
+pub fn resolve_active_bid_year(persistence: &mut SqlitePersistence) -> Result<BidYear, ApiError> {
     let year: u16 = persistence.get_active_bid_year().map_err(|e| match e {
         zab_bid_persistence::PersistenceError::NotFound(_) => {
             translate_domain_error(zab_bid_domain::DomainError::NoActiveBidYear)
@@ -106,6 +137,85 @@ fn resolve_active_bid_year(persistence: &mut SqlitePersistence) -> Result<BidYea
     Ok(BidYear::new(year))
 }
 
+/// Ensures the bid year's lifecycle state still permits user registration.
+///
+/// If the bid year has no canonical ID in metadata, it is assumed to be in
+/// `Draft` and registration is allowed.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The lifecycle state cannot be read or parsed
+/// - The lifecycle state is locked (after confirmation)
+pub fn ensure_user_registration_allowed(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year: &BidYear,
+) -> Result<(), ApiError> {
+    let Some(bid_year_id) = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.year() == bid_year.year())
+        .and_then(BidYear::bid_year_id)
+    else {
+        return Ok(());
+    };
+
+    let lifecycle_state_str: String =
+        persistence
+            .get_lifecycle_state(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get lifecycle state: {e}"),
+            })?;
+
+    let lifecycle_state: BidYearLifecycle = lifecycle_state_str
+        .parse()
+        .map_err(translate_domain_error)?;
+
+    if lifecycle_state.is_locked() {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("user_registration_lifecycle"),
+            message: format!(
+                "Cannot register user in state '{lifecycle_state}': structural changes locked after confirmation"
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Translates a user registration request into a core `RegisterUser` command.
+///
+/// # Errors
+///
+/// Returns an error if the user type or crew is invalid.
+pub fn build_register_user_command(request: &RegisterUserRequest) -> Result<Command, ApiError> {
+    let user_type: UserType =
+        UserType::parse(&request.user_type).map_err(translate_domain_error)?;
+
+    let crew: Option<Crew> = match request.crew {
+        Some(crew_num) => Some(Crew::new(crew_num).map_err(translate_domain_error)?),
+        None => None,
+    };
+
+    let seniority_data: SeniorityData = SeniorityData::new(
+        request.cumulative_natca_bu_date.clone(),
+        request.natca_bu_date.clone(),
+        request.eod_faa_date.clone(),
+        request.service_computation_date.clone(),
+        request.lottery_value,
+    );
+
+    Ok(Command::RegisterUser {
+        initials: Initials::new(&request.initials),
+        name: request.name.clone(),
+        area: Area::new(&request.area),
+        user_type,
+        crew,
+        seniority_data,
+    })
+}
+
 /// The result of an API operation that includes both the response and the audit event.
 ///
 /// This ensures that successful API operations always produce an audit trail.
@@ -163,67 +273,13 @@ pub fn register_user(
     let bid_year: BidYear = resolve_active_bid_year(persistence)?;
 
     // Enforce lifecycle constraints: user registration blocked after Canonicalized
-    // Get bid_year_id from metadata (if bid year has no ID, assume Draft state and allow)
-    if let Some(bid_year_id) = metadata
-        .bid_years
-        .iter()
-        .find(|by| by.year() == bid_year.year())
-        .and_then(BidYear::bid_year_id)
-    {
-        let lifecycle_state_str: String =
-            persistence
-                .get_lifecycle_state(bid_year_id)
-                .map_err(|e| ApiError::Internal {
-                    message: format!("Failed to get lifecycle state: {e}"),
-                })?;
-
-        let lifecycle_state: BidYearLifecycle = lifecycle_state_str
-            .parse()
-            .map_err(translate_domain_error)?;
-
-        if lifecycle_state.is_locked() {
-            return Err(ApiError::DomainRuleViolation {
-                rule: String::from("user_registration_lifecycle"),
-                message: format!(
-                    "Cannot register user in state '{lifecycle_state}': structural changes locked after confirmation"
-                ),
-            });
-        }
-    }
+    ensure_user_registration_allowed(persistence, metadata, &bid_year)?;
 
     // Convert authenticated actor to audit actor with operator information
     let actor: Actor = authenticated_actor.to_audit_actor(operator);
     // Translate API request into domain types
     let initials: Initials = Initials::new(&request.initials);
-    let area: Area = Area::new(&request.area);
-
-    // Parse user type
-    let user_type: UserType =
-        UserType::parse(&request.user_type).map_err(translate_domain_error)?;
-
-    // Parse optional crew
-    let crew: Option<Crew> = match request.crew {
-        Some(crew_num) => Some(Crew::new(crew_num).map_err(translate_domain_error)?),
-        None => None,
-    };
-
-    let seniority_data: SeniorityData = SeniorityData::new(
-        request.cumulative_natca_bu_date,
-        request.natca_bu_date,
-        request.eod_faa_date,
-        request.service_computation_date,
-        request.lottery_value,
-    );
-
-    // Create core command
-    let command: Command = Command::RegisterUser {
-        initials: initials.clone(),
-        name: request.name.clone(),
-        area,
-        user_type,
-        crew,
-        seniority_data,
-    };
+    let command: Command = build_register_user_command(&request)?;
 
     // Apply command via core transition
     let transition_result: TransitionResult =
@@ -248,62 +304,151 @@ pub fn register_user(
     })
 }
 
-/// Creates a checkpoint via the API boundary with authorization.
+/// Registers many users in one operation.
 ///
-/// This function:
-/// - Verifies the actor is authorized (Admin role required)
-/// - Creates a checkpoint command
-/// - Applies the command to the current state
-/// - Returns the transition result on success
+/// Every row is first validated by dry-running its `RegisterUser` command
+/// through a core batch apply, grouped by area so that duplicates within the
+/// request are detected. The valid rows are then persisted in a single
+/// transaction. When `all_or_nothing` is set, any invalid row prevents all
+/// rows from being registered.
 ///
 /// # Arguments
 ///
+/// * `persistence` - The persistence layer
 /// * `metadata` - The current bootstrap metadata
-/// * `state` - The current system state
+/// * `request` - The rows to register and the atomicity mode
 /// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit trail
 /// * `cause` - The cause or reason for this action
 ///
 /// # Returns
 ///
-/// * `Ok(TransitionResult)` on success
-/// * `Err(ApiError)` if unauthorized or the command fails
+/// * `Ok(RegisterUsersBulkResponse)` with per-row results
+/// * `Err(ApiError)` if unauthorized, the lifecycle forbids registration, or persistence fails
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - The actor is not authorized (not an Admin)
-/// - The command execution fails
-pub fn checkpoint(
+/// - No active bid year is set
+/// - The bid year lifecycle no longer allows registration
+/// - Persisting the valid rows fails (no rows are persisted in that case)
+///
+/// Individual row validation failures are reported in the response, not as errors.
+pub fn register_users_bulk(
     persistence: &mut SqlitePersistence,
     metadata: &BootstrapMetadata,
-    state: &State,
+    request: &RegisterUsersBulkRequest,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
-    cause: Cause,
-) -> Result<TransitionResult, ApiError> {
-    // Enforce authorization before executing command
-    AuthorizationService::authorize_checkpoint(authenticated_actor)?;
+    cause: &Cause,
+) -> Result<RegisterUsersBulkResponse, ApiError> {
+    AuthorizationService::authorize_register_user(authenticated_actor)?;
 
-    // Resolve the active bid year from canonical state
-    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+    let bid_year: BidYear = resolve_active_bid_year(persistence)?;
+    ensure_user_registration_allowed(persistence, metadata, &bid_year)?;
 
-    // Convert authenticated actor to audit actor with operator information
     let actor: Actor = authenticated_actor.to_audit_actor(operator);
 
-    // Create and apply checkpoint command
-    let command: Command = Command::Checkpoint;
-    let transition_result: TransitionResult =
-        apply(metadata, state, &active_bid_year, command, actor, cause)
-            .map_err(translate_core_error)?;
+    let mut results: Vec<BulkRegisterRowResult> = request
+        .users
+        .iter()
+        .enumerate()
+        .map(|(row_index, row)| BulkRegisterRowResult {
+            row_index,
+            initials: Initials::new(&row.initials).value().to_string(),
+            status: BulkRegisterRowStatus::Skipped,
+            user_id: None,
+            event_id: None,
+            error: None,
+        })
+        .collect();
 
-    Ok(transition_result)
+    // Translate rows into commands, grouped by area in first-seen order
+    let mut groups: Vec<(Area, Vec<usize>, Vec<Command>)> = Vec::new();
+    for (row_index, row) in request.users.iter().enumerate() {
+        match build_register_user_command(row) {
+            Ok(command) => {
+                let area: Area = Area::new(&row.area);
+                if let Some(group) = groups.iter_mut().find(|(a, _, _)| a.id() == area.id()) {
+                    group.1.push(row_index);
+                    group.2.push(command);
+                } else {
+                    groups.push((area, vec![row_index], vec![command]));
+                }
+            }
+            Err(e) => {
+                results[row_index].status = BulkRegisterRowStatus::Failed;
+                results[row_index].error = Some(e.to_string());
+            }
+        }
+    }
+
+    // Dry-run each area group against its current state
+    let mut transitions: Vec<(usize, TransitionResult)> = Vec::new();
+    for (area, row_indices, commands) in groups {
+        let state: State = persistence
+            .get_current_state(&bid_year, &area)
+            .unwrap_or_else(|_| State::new(bid_year.clone(), area.clone()));
+        let batch: BatchTransitionResult =
+            apply_batch(metadata, &state, &bid_year, commands, &actor, cause);
+
+        for (row_index, outcome) in row_indices.into_iter().zip(batch.outcomes) {
+            match outcome {
+                Ok(transition) => transitions.push((row_index, transition)),
+                Err(e) => {
+                    results[row_index].status = BulkRegisterRowStatus::Failed;
+                    results[row_index].error = Some(translate_core_error(e).to_string());
+                }
+            }
+        }
+    }
+
+    let failed_count: usize = results
+        .iter()
+        .filter(|r| r.status == BulkRegisterRowStatus::Failed)
+        .count();
+
+    let blocked: bool = request.all_or_nothing && failed_count > 0;
+    if !blocked && !transitions.is_empty() {
+        // Persist in request order so the audit trail mirrors the input
+        transitions.sort_by_key(|(row_index, _)| *row_index);
+        let to_persist: Vec<TransitionResult> =
+            transitions.iter().map(|(_, t)| t.clone()).collect();
+        let persisted =
+            persistence
+                .persist_transitions(&to_persist)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to persist bulk registration: {e}"),
+                })?;
+
+        for ((row_index, _), persisted) in transitions.iter().zip(persisted) {
+            let row: &mut BulkRegisterRowResult = &mut results[*row_index];
+            row.status = BulkRegisterRowStatus::Registered;
+            row.user_id = persisted.user_id;
+            row.event_id = Some(persisted.event_id);
+        }
+    }
+
+    let registered_count: usize = results
+        .iter()
+        .filter(|r| r.status == BulkRegisterRowStatus::Registered)
+        .count();
+
+    Ok(RegisterUsersBulkResponse {
+        bid_year: bid_year.year(),
+        total: request.users.len(),
+        registered_count,
+        failed_count,
+        results,
+    })
 }
 
-/// Finalizes a round via the API boundary with authorization.
+/// Creates a checkpoint via the API boundary with authorization.
 ///
 /// This function:
 /// - Verifies the actor is authorized (Admin role required)
-/// - Creates a finalize command
+/// - Creates a checkpoint command
 /// - Applies the command to the current state
 /// - Returns the transition result on success
 ///
@@ -311,6 +456,7 @@ pub fn checkpoint(
 ///
 /// * `metadata` - The current bootstrap metadata
 /// * `state` - The current system state
+/// * `request` - The checkpoint's optional label and description
 /// * `authenticated_actor` - The authenticated actor performing this action
 /// * `cause` - The cause or reason for this action
 ///
@@ -323,17 +469,19 @@ pub fn checkpoint(
 ///
 /// Returns an error if:
 /// - The actor is not authorized (not an Admin)
+/// - The label or description is invalid
 /// - The command execution fails
-pub fn finalize(
+pub fn checkpoint(
     persistence: &mut SqlitePersistence,
     metadata: &BootstrapMetadata,
     state: &State,
+    request: &CheckpointRequest,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
     cause: Cause,
 ) -> Result<TransitionResult, ApiError> {
     // Enforce authorization before executing command
-    AuthorizationService::authorize_finalize(authenticated_actor)?;
+    AuthorizationService::authorize_checkpoint(authenticated_actor)?;
 
     // Resolve the active bid year from canonical state
     let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
@@ -341,8 +489,11 @@ pub fn finalize(
     // Convert authenticated actor to audit actor with operator information
     let actor: Actor = authenticated_actor.to_audit_actor(operator);
 
-    // Create and apply finalize command
-    let command: Command = Command::Finalize;
+    // Create and apply checkpoint command
+    let command: Command = Command::Checkpoint {
+        label: request.label.clone(),
+        description: request.description.clone(),
+    };
     let transition_result: TransitionResult =
         apply(metadata, state, &active_bid_year, command, actor, cause)
             .map_err(translate_core_error)?;
@@ -350,11 +501,11 @@ pub fn finalize(
     Ok(transition_result)
 }
 
-/// Rolls back to a specific event via the API boundary with authorization.
+/// Finalizes a round via the API boundary with authorization.
 ///
 /// This function:
 /// - Verifies the actor is authorized (Admin role required)
-/// - Creates a rollback command
+/// - Creates a finalize command
 /// - Applies the command to the current state
 /// - Returns the transition result on success
 ///
@@ -362,7 +513,6 @@ pub fn finalize(
 ///
 /// * `metadata` - The current bootstrap metadata
 /// * `state` - The current system state
-/// * `target_event_id` - The event ID to rollback to
 /// * `authenticated_actor` - The authenticated actor performing this action
 /// * `cause` - The cause or reason for this action
 ///
@@ -376,17 +526,16 @@ pub fn finalize(
 /// Returns an error if:
 /// - The actor is not authorized (not an Admin)
 /// - The command execution fails
-pub fn rollback(
+pub fn finalize(
     persistence: &mut SqlitePersistence,
     metadata: &BootstrapMetadata,
     state: &State,
-    target_event_id: i64,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
     cause: Cause,
 ) -> Result<TransitionResult, ApiError> {
     // Enforce authorization before executing command
-    AuthorizationService::authorize_rollback(authenticated_actor)?;
+    AuthorizationService::authorize_finalize(authenticated_actor)?;
 
     // Resolve the active bid year from canonical state
     let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
@@ -394,8 +543,8 @@ pub fn rollback(
     // Convert authenticated actor to audit actor with operator information
     let actor: Actor = authenticated_actor.to_audit_actor(operator);
 
-    // Create and apply rollback command
-    let command: Command = Command::RollbackToEventId { target_event_id };
+    // Create and apply finalize command
+    let command: Command = Command::Finalize;
     let transition_result: TransitionResult =
         apply(metadata, state, &active_bid_year, command, actor, cause)
             .map_err(translate_core_error)?;
@@ -606,29 +755,10 @@ pub fn list_bid_years(
                 .unwrap_or((None, None));
 
             // Fetch bid schedule from persistence
-            let bid_schedule = persistence.get_bid_schedule(bid_year_id).ok().and_then(
-                |(tz, sd, wst, wet, bpd)| {
-                    // Only construct BidScheduleInfo if all fields are present
-                    if let (
-                        Some(timezone),
-                        Some(start_date),
-                        Some(window_start_time),
-                        Some(window_end_time),
-                        Some(bidders_per_day),
-                    ) = (tz, sd, wst, wet, bpd)
-                    {
-                        Some(BidScheduleInfo {
-                            timezone,
-                            start_date,
-                            window_start_time,
-                            window_end_time,
-                            bidders_per_day: bidders_per_day.cast_unsigned(),
-                        })
-                    } else {
-                        None
-                    }
-                },
-            );
+            let bid_schedule: Option<BidScheduleInfo> = persistence
+                .get_bid_schedule(bid_year_id)
+                .ok()
+                .and_then(bid_schedule_info_from_fields);
 
             Ok(BidYearInfo {
                 bid_year_id,
@@ -855,6 +985,7 @@ pub fn update_area(
 
     // Validate lifecycle state allows editing
     validate_lifecycle_allows_area_edit(persistence, bid_year_id, bid_year.year())?;
+    require_not_frozen(persistence, bid_year_id, Some(request.area_id))?;
 
     // Update the area name in the canonical table
     persistence
@@ -930,6 +1061,8 @@ pub fn update_area(
 /// * `state` - The current state for this scope
 /// * `authenticated_actor` - The authenticated actor (for capability computation)
 /// * `actor_operator` - The authenticated operator's data (for capability computation)
+/// * `lifecycle_state` - The bid year's lifecycle state
+/// * `rates` - The facility's leave accrual rates
 ///
 /// # Returns
 ///
@@ -943,7 +1076,7 @@ pub fn update_area(
 /// - The area has not been created in the bid year
 ///
 /// Phase 26A: Added `lifecycle_state` parameter for lifecycle-aware capability computation.
-/// This brings the parameter count to 8, which exceeds clippy's default limit of 7.
+/// This brings the parameter count past clippy's default limit of 7.
 /// Grouping these into a struct would add complexity without improving clarity.
 #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 pub fn list_users(
@@ -955,6 +1088,7 @@ pub fn list_users(
     authenticated_actor: &AuthenticatedActor,
     actor_operator: &OperatorData,
     lifecycle_state: zab_bid_domain::BidYearLifecycle,
+    rates: AccrualRates,
 ) -> Result<ListUsersResponse, ApiError> {
     // Validate bid year and area exist before processing
     validate_area_exists(metadata, bid_year, area).map_err(translate_domain_error)?;
@@ -1000,7 +1134,7 @@ pub fn list_users(
 
     let users: Result<Vec<UserInfo>, ApiError> = state
         .users
-        .iter()
+        .values()
         .map(|user| {
             // Verify user_id is present (data integrity check)
             let user_id: i64 = user.user_id.ok_or_else(|| ApiError::Internal {
@@ -1012,14 +1146,14 @@ pub fn list_users(
 
             // Calculate leave accrual for this user
             let leave_accrual_result: LeaveAccrualResult =
-                calculate_leave_accrual(user, canonical_bid_year).unwrap_or_else(|_| {
-                    LeaveAccrualResult {
+                calculate_leave_accrual_with_rates(user, canonical_bid_year, rates).unwrap_or_else(
+                    |_| LeaveAccrualResult {
                         total_hours: 0,
                         total_days: 0,
                         rounded_up: false,
                         breakdown: vec![],
-                    }
-                });
+                    },
+                );
 
             let earned_hours: u16 = leave_accrual_result.total_hours;
             let earned_days: u16 = leave_accrual_result.total_days;
@@ -1170,6 +1304,7 @@ pub fn get_historical_state(
 /// * `area` - The area
 /// * `initials` - The user's initials
 /// * `state` - The current state
+/// * `rates` - The facility's leave accrual rates
 ///
 /// # Returns
 ///
@@ -1190,6 +1325,7 @@ pub fn get_leave_availability(
     area: &Area,
     initials: &Initials,
     state: &State,
+    rates: AccrualRates,
 ) -> Result<GetLeaveAvailabilityResponse, ApiError> {
     let bid_year: BidYear = BidYear::new(canonical_bid_year.year());
 
@@ -1212,8 +1348,7 @@ pub fn get_leave_availability(
     // Find the user
     let user = state
         .users
-        .iter()
-        .find(|u| u.initials == *initials)
+        .get(initials)
         .ok_or_else(|| ApiError::ResourceNotFound {
             resource_type: String::from("User"),
             message: format!(
@@ -1233,8 +1368,8 @@ pub fn get_leave_availability(
     })?;
 
     // Calculate leave accrual using Phase 9
-    let accrual =
-        calculate_leave_accrual(user, canonical_bid_year).map_err(translate_domain_error)?;
+    let accrual = calculate_leave_accrual_with_rates(user, canonical_bid_year, rates)
+        .map_err(translate_domain_error)?;
 
     // Retrieve leave usage records
     // Note: For Phase 10, no persistence for leave usage exists yet.
@@ -1406,7 +1541,7 @@ pub fn login(
     request: &LoginRequest,
 ) -> Result<LoginResponse, ApiError> {
     let (session_token, _authenticated_actor, operator): (
-        String,
+        SecretToken,
         AuthenticatedActor,
         OperatorData,
     ) = AuthenticationService::login(persistence, &request.login_name, &request.password)?;
@@ -1425,7 +1560,7 @@ pub fn login(
         .expires_at;
 
     Ok(LoginResponse {
-        session_token,
+        session_token: session_token.expose().to_owned(),
         login_name: operator.login_name,
         display_name: operator.display_name,
         role: operator.role,
@@ -1443,7 +1578,10 @@ pub fn login(
 /// # Errors
 ///
 /// Returns an error if the logout fails.
-pub fn logout(persistence: &mut SqlitePersistence, session_token: &str) -> Result<(), ApiError> {
+pub fn logout(
+    persistence: &mut SqlitePersistence,
+    session_token: &SecretToken,
+) -> Result<(), ApiError> {
     AuthenticationService::logout(persistence, session_token)?;
     Ok(())
 }
@@ -1630,16 +1768,17 @@ pub fn list_operators(
         });
     }
 
-    let operators: Vec<OperatorData> =
+    let operators: Vec<OperatorActivityData> =
         persistence
-            .list_operators()
+            .list_operators_with_activity()
             .map_err(|e| ApiError::Internal {
                 message: format!("Failed to list operators: {e}"),
             })?;
 
     let operator_infos: Result<Vec<OperatorInfo>, ApiError> = operators
         .into_iter()
-        .map(|op| {
+        .map(|activity| {
+            let op: OperatorData = activity.operator;
             let capabilities: OperatorCapabilities =
                 crate::capabilities::compute_operator_capabilities(
                     authenticated_actor,
@@ -1659,6 +1798,18 @@ pub fn list_operators(
                 is_disabled: op.is_disabled,
                 created_at: op.created_at,
                 last_login_at: op.last_login_at,
+                active_session_count: activity.active_session_count,
+                failed_login_count: activity.failed_login_count,
+                area_scopes: activity
+                    .area_scopes
+                    .into_iter()
+                    .map(|scope| OperatorAreaScopeInfo {
+                        area_id: scope.area_id,
+                        bid_year_id: scope.bid_year_id,
+                        year: scope.year,
+                        area_code: scope.area_code,
+                    })
+                    .collect(),
                 capabilities,
             })
         })
@@ -1669,6 +1820,39 @@ pub fn list_operators(
     })
 }
 
+/// Rejects an operation that would take away the last active admin.
+///
+/// Disabling, deleting, or demoting an operator is only guarded when the
+/// target is currently an active admin.
+///
+/// # Errors
+///
+/// Returns `ApiError::DomainRuleViolation` if the target is the only active
+/// admin, or an internal error if the admins cannot be counted.
+fn ensure_not_last_active_admin(
+    persistence: &mut SqlitePersistence,
+    target_operator: &OperatorData,
+) -> Result<(), ApiError> {
+    if target_operator.role != "Admin" || target_operator.is_disabled {
+        return Ok(());
+    }
+
+    let active_admin_count: i64 =
+        persistence
+            .count_active_admin_operators()
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to count active admins: {e}"),
+            })?;
+
+    if active_admin_count <= 1 {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("last_active_admin"),
+            message: String::from("Operation would leave the system without an active admin"),
+        });
+    }
+    Ok(())
+}
+
 /// Disables an operator.
 ///
 /// Only Admin actors may disable operators.
@@ -1723,22 +1907,7 @@ pub fn disable_operator(
         })?;
 
     // Enforce invariant: cannot disable the last active admin
-    // Only check if the target is an active admin
-    if target_operator.role == "Admin" && !target_operator.is_disabled {
-        let active_admin_count: i64 =
-            persistence
-                .count_active_admin_operators()
-                .map_err(|e| ApiError::Internal {
-                    message: format!("Failed to count active admins: {e}"),
-                })?;
-
-        if active_admin_count <= 1 {
-            return Err(ApiError::DomainRuleViolation {
-                rule: String::from("last_active_admin"),
-                message: String::from("Operation would leave the system without an active admin"),
-            });
-        }
-    }
+    ensure_not_last_active_admin(persistence, &target_operator)?;
 
     // Perform the disable operation
     persistence
@@ -1941,22 +2110,7 @@ pub fn delete_operator(
         })?;
 
     // Enforce invariant: cannot delete the last active admin
-    // Only check if the target is an active admin
-    if target_operator.role == "Admin" && !target_operator.is_disabled {
-        let active_admin_count: i64 =
-            persistence
-                .count_active_admin_operators()
-                .map_err(|e| ApiError::Internal {
-                    message: format!("Failed to count active admins: {e}"),
-                })?;
-
-        if active_admin_count <= 1 {
-            return Err(ApiError::DomainRuleViolation {
-                rule: String::from("last_active_admin"),
-                message: String::from("Operation would leave the system without an active admin"),
-            });
-        }
-    }
+    ensure_not_last_active_admin(persistence, &target_operator)?;
 
     // Perform the delete operation (will fail if operator is referenced)
     persistence
@@ -2012,76 +2166,89 @@ pub fn delete_operator(
     })
 }
 
-/// Changes an operator's own password.
+/// Changes an operator's role.
 ///
-/// Any authenticated operator may change their own password.
-/// Validates the current password, enforces password policy, and invalidates all sessions.
+/// Only Admin actors may change roles. Demoting the last active admin is
+/// rejected. The new role takes effect on the operator's next request;
+/// their sessions are left in place.
 /// Emits an audit event on success.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
-/// * `request` - The change password request
+/// * `request` - The change role request
 /// * `authenticated_actor` - The authenticated actor performing this action
 /// * `operator` - The operator data for audit attribution
 /// * `cause` - The cause for this action
 ///
-/// # Returns
-///
-/// * `Ok(ChangePasswordResponse)` on success
-/// * `Err(ApiError)` if validation fails or operation fails
-///
 /// # Errors
 ///
 /// Returns an error if:
-/// - Current password is incorrect
-/// - New password does not meet policy requirements
-/// - Password confirmation does not match
+/// - The actor is not authorized (not an Admin)
+/// - The role is not 'Admin' or 'Bidder'
+/// - The operator does not exist or already has the role
+/// - The operator is the last active admin and would be demoted
 /// - Database operations fail
-pub fn change_password(
+pub fn change_operator_role(
     persistence: &mut SqlitePersistence,
-    request: &ChangePasswordRequest,
-    _authenticated_actor: &AuthenticatedActor,
+    request: &ChangeOperatorRoleRequest,
+    authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
     cause: Cause,
-) -> Result<ChangePasswordResponse, ApiError> {
-    // Verify current password
-    let password_valid: bool = persistence
-        .verify_password(&request.current_password, &operator.password_hash)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Password verification failed: {e}"),
-        })?;
-
-    if !password_valid {
-        return Err(ApiError::AuthenticationFailed {
-            reason: String::from("Current password is incorrect"),
+) -> Result<ChangeOperatorRoleResponse, ApiError> {
+    // Enforce authorization before executing command
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("change_operator_role"),
+            required_role: String::from("Admin"),
         });
     }
 
-    // Validate new password policy
-    let policy: PasswordPolicy = PasswordPolicy::default();
-    policy.validate(
-        &request.new_password,
-        &request.new_password_confirmation,
-        &operator.login_name,
-        &operator.display_name,
-    )?;
+    // Validate role
+    if request.role != "Admin" && request.role != "Bidder" {
+        return Err(ApiError::InvalidInput {
+            field: String::from("role"),
+            message: format!(
+                "Invalid role: {}. Must be 'Admin' or 'Bidder'",
+                request.role
+            ),
+        });
+    }
 
-    // Update password
-    persistence
-        .update_password(operator.operator_id, &request.new_password)
+    // Get target operator to verify existence and get details for audit
+    let target_operator: OperatorData = persistence
+        .get_operator_by_id(request.operator_id)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to update password: {e}"),
+            message: format!("Failed to get operator: {e}"),
+        })?
+        .ok_or_else(|| {
+            let operator_id = request.operator_id;
+            ApiError::ResourceNotFound {
+                resource_type: String::from("Operator"),
+                message: format!("Operator with ID {operator_id} not found"),
+            }
         })?;
 
-    // Invalidate all sessions for this operator
+    if target_operator.role == request.role {
+        return Err(ApiError::InvalidInput {
+            field: String::from("role"),
+            message: format!(
+                "Operator {} already has role {}",
+                target_operator.login_name, request.role
+            ),
+        });
+    }
+
+    // Enforce invariant: cannot demote the last active admin
+    ensure_not_last_active_admin(persistence, &target_operator)?;
+
     persistence
-        .delete_sessions_for_operator(operator.operator_id)
+        .update_operator_role(request.operator_id, &request.role)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to invalidate sessions: {e}"),
+            message: format!("Failed to change operator role: {e}"),
         })?;
 
-    // Create audit event for password change
+    // Create audit event for operator role change
     let actor: Actor = Actor::with_operator(
         operator.operator_id.to_string(),
         String::from("operator"),
@@ -2091,17 +2258,23 @@ pub fn change_password(
     );
 
     let action: Action = Action::new(
-        String::from("ChangePassword"),
+        String::from("ChangeOperatorRole"),
         Some(format!(
-            "Operator {} changed their own password",
-            operator.login_name
+            "Changed role of operator {} from {} to {}",
+            target_operator.login_name, target_operator.role, request.role
         )),
     );
 
-    let operator_id = operator.operator_id;
-    let before: StateSnapshot = StateSnapshot::new(format!("operator_id={operator_id}"));
-    let after: StateSnapshot =
-        StateSnapshot::new(format!("operator_id={operator_id},password_changed"));
+    let operator_id = request.operator_id;
+    let target_login = &target_operator.login_name;
+    let before: StateSnapshot = StateSnapshot::new(format!(
+        "operator_id={operator_id},login_name={target_login},role={}",
+        target_operator.role
+    ));
+    let after: StateSnapshot = StateSnapshot::new(format!(
+        "operator_id={operator_id},login_name={target_login},role={}",
+        request.role
+    ));
 
     // Phase 23B: Use global event for operator management
     let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
@@ -2113,54 +2286,52 @@ pub fn change_password(
             message: format!("Failed to persist audit event: {e}"),
         })?;
 
-    Ok(ChangePasswordResponse {
-        message: String::from("Password changed successfully. All sessions have been invalidated."),
+    Ok(ChangeOperatorRoleResponse {
+        operator_id,
+        role: request.role.clone(),
+        message: format!("Operator {target_login} now has role {}", request.role),
     })
 }
 
-/// Resets another operator's password (admin only).
+/// Replaces the set of areas an operator is assigned to (admin only).
 ///
-/// Only Admin actors may reset other operators' passwords.
-/// Does not require the old password, enforces password policy, and invalidates all sessions.
-/// Emits an audit event on success.
+/// Area scopes record which areas an operator looks after and are shown
+/// when listing operators. A Bidder with scopes may only act on bids in
+/// those areas, plus any areas delegated to them. Emits an audit event on
+/// success.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
-/// * `request` - The reset password request
+/// * `metadata` - The bootstrap metadata, used to validate the areas
+/// * `request` - The operator and the areas to assign
 /// * `authenticated_actor` - The authenticated actor performing this action
-/// * `operator` - The operator data for audit attribution (the admin)
+/// * `operator` - The operator data for audit attribution
 /// * `cause` - The cause for this action
 ///
-/// # Returns
-///
-/// * `Ok(ResetPasswordResponse)` on success
-/// * `Err(ApiError)` if unauthorized or operation fails
-///
 /// # Errors
 ///
 /// Returns an error if:
 /// - The actor is not authorized (not an Admin)
-/// - The target operator does not exist
-/// - New password does not meet policy requirements
-/// - Password confirmation does not match
+/// - The operator or one of the areas does not exist
 /// - Database operations fail
-pub fn reset_password(
+pub fn set_operator_area_scopes(
     persistence: &mut SqlitePersistence,
-    request: &ResetPasswordRequest,
+    metadata: &BootstrapMetadata,
+    request: &SetOperatorAreaScopesRequest,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
     cause: Cause,
-) -> Result<ResetPasswordResponse, ApiError> {
+) -> Result<SetOperatorAreaScopesResponse, ApiError> {
     // Enforce authorization before executing command
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("reset_password"),
+            action: String::from("set_operator_area_scopes"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Get target operator to verify existence and get details for validation and audit
+    // Get target operator to verify existence and get details for audit
     let target_operator: OperatorData = persistence
         .get_operator_by_id(request.operator_id)
         .map_err(|e| ApiError::Internal {
@@ -2174,30 +2345,30 @@ pub fn reset_password(
             }
         })?;
 
-    // Validate new password policy
-    let policy: PasswordPolicy = PasswordPolicy::default();
-    policy.validate(
-        &request.new_password,
-        &request.new_password_confirmation,
-        &target_operator.login_name,
-        &target_operator.display_name,
-    )?;
+    let mut area_ids: Vec<i64> = request.area_ids.clone();
+    area_ids.sort_unstable();
+    area_ids.dedup();
 
-    // Update password
-    persistence
-        .update_password(request.operator_id, &request.new_password)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to update password: {e}"),
-        })?;
+    for area_id in &area_ids {
+        if !metadata
+            .areas
+            .iter()
+            .any(|(_, area)| area.area_id() == Some(*area_id))
+        {
+            return Err(ApiError::ResourceNotFound {
+                resource_type: String::from("Area"),
+                message: format!("Area with ID {area_id} not found"),
+            });
+        }
+    }
 
-    // Invalidate all sessions for the target operator
     persistence
-        .delete_sessions_for_operator(request.operator_id)
+        .set_operator_area_scopes(request.operator_id, &area_ids)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to invalidate sessions: {e}"),
+            message: format!("Failed to set operator area scopes: {e}"),
         })?;
 
-    // Create audit event for password reset
+    // Create audit event for the scope change
     let actor: Actor = Actor::with_operator(
         operator.operator_id.to_string(),
         String::from("operator"),
@@ -2207,20 +2378,26 @@ pub fn reset_password(
     );
 
     let action: Action = Action::new(
-        String::from("ResetPassword"),
+        String::from("SetOperatorAreaScopes"),
         Some(format!(
-            "Admin {} reset password for operator {}",
-            operator.login_name, target_operator.login_name
+            "Assigned {} area(s) to operator {}",
+            area_ids.len(),
+            target_operator.login_name
         )),
     );
 
     let operator_id = request.operator_id;
     let target_login = &target_operator.login_name;
+    let area_list: String = area_ids
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(";");
     let before: StateSnapshot = StateSnapshot::new(format!(
         "operator_id={operator_id},login_name={target_login}"
     ));
     let after: StateSnapshot = StateSnapshot::new(format!(
-        "operator_id={operator_id},login_name={target_login},password_reset"
+        "operator_id={operator_id},login_name={target_login},area_ids={area_list}"
     ));
 
     // Phase 23B: Use global event for operator management
@@ -2233,276 +2410,532 @@ pub fn reset_password(
             message: format!("Failed to persist audit event: {e}"),
         })?;
 
-    Ok(ResetPasswordResponse {
+    Ok(SetOperatorAreaScopesResponse {
+        operator_id,
         message: format!(
-            "Password reset successfully for operator {}. All sessions have been invalidated.",
-            target_operator.login_name
+            "Operator {target_login} is assigned to {} area(s)",
+            area_ids.len()
         ),
-        operator_id: request.operator_id,
+        area_ids,
     })
 }
 
-// ========================================================================
-// Bootstrap Authentication (Phase 15)
-// ========================================================================
-
-/// Checks whether the system is in bootstrap mode.
+/// Revokes every session of an operator (admin only).
 ///
-/// Bootstrap mode is active when no operators exist in the database.
+/// The operator is signed out on all devices but remains enabled and may
+/// sign in again. Emits an audit event on success.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
-///
-/// # Returns
-///
-/// * `Ok(BootstrapAuthStatusResponse)` indicating bootstrap status
-/// * `Err(ApiError)` if the query fails
+/// * `request` - The revoke sessions request
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
 ///
 /// # Errors
 ///
-/// Returns an error if database operations fail.
-pub fn check_bootstrap_status(
+/// Returns an error if:
+/// - The actor is not authorized (not an Admin)
+/// - The operator does not exist
+/// - Database operations fail
+pub fn revoke_operator_sessions(
     persistence: &mut SqlitePersistence,
-) -> Result<crate::BootstrapAuthStatusResponse, ApiError> {
-    let operator_count: i64 = persistence
-        .count_operators()
+    request: RevokeOperatorSessionsRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<RevokeOperatorSessionsResponse, ApiError> {
+    // Enforce authorization before executing command
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("revoke_operator_sessions"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    // Get target operator to verify existence and get details for audit
+    let target_operator: OperatorData = persistence
+        .get_operator_by_id(request.operator_id)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to count operators: {e}"),
+            message: format!("Failed to get operator: {e}"),
+        })?
+        .ok_or_else(|| {
+            let operator_id = request.operator_id;
+            ApiError::ResourceNotFound {
+                resource_type: String::from("Operator"),
+                message: format!("Operator with ID {operator_id} not found"),
+            }
         })?;
 
-    Ok(crate::BootstrapAuthStatusResponse {
-        is_bootstrap_mode: operator_count == 0,
+    let sessions_revoked: usize = persistence
+        .delete_sessions_for_operator(request.operator_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to revoke sessions: {e}"),
+        })?;
+
+    // Create audit event for session revocation
+    let actor: Actor = Actor::with_operator(
+        operator.operator_id.to_string(),
+        String::from("operator"),
+        operator.operator_id,
+        operator.login_name.clone(),
+        operator.display_name.clone(),
+    );
+
+    let action: Action = Action::new(
+        String::from("RevokeOperatorSessions"),
+        Some(format!(
+            "Revoked {sessions_revoked} session(s) of operator {}",
+            target_operator.login_name
+        )),
+    );
+
+    let operator_id = request.operator_id;
+    let target_login = &target_operator.login_name;
+    let before: StateSnapshot = StateSnapshot::new(format!(
+        "operator_id={operator_id},login_name={target_login}"
+    ));
+    let after: StateSnapshot = StateSnapshot::new(format!(
+        "operator_id={operator_id},login_name={target_login},sessions_revoked={sessions_revoked}"
+    ));
+
+    // Phase 23B: Use global event for operator management
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+
+    // Persist audit event
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(RevokeOperatorSessionsResponse {
+        operator_id,
+        sessions_revoked,
+        message: format!("Revoked {sessions_revoked} session(s) of operator {target_login}"),
     })
 }
 
-/// Performs bootstrap login with hardcoded credentials.
-///
-/// This function only succeeds when:
-/// - No operators exist in the database (bootstrap mode)
-/// - Username is exactly "admin"
-/// - Password is exactly "admin"
+/// Changes an operator's own password.
 ///
-/// The returned token is a temporary bootstrap session, not a real operator session.
+/// Any authenticated operator may change their own password.
+/// Validates the current password, enforces password policy, and invalidates all sessions.
+/// Emits an audit event on success.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
-/// * `request` - The bootstrap login request
+/// * `request` - The change password request
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
 ///
 /// # Returns
 ///
-/// * `Ok(BootstrapLoginResponse)` with a bootstrap token
-/// * `Err(ApiError)` if bootstrap mode is not active or credentials are invalid
+/// * `Ok(ChangePasswordResponse)` on success
+/// * `Err(ApiError)` if validation fails or operation fails
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - Operators already exist (not in bootstrap mode)
-/// - Credentials are not exactly "admin" / "admin"
+/// - Current password is incorrect
+/// - New password does not meet policy requirements
+/// - Password confirmation does not match
 /// - Database operations fail
-///
-/// # Panics
-///
-/// Panics if the system time is before the Unix epoch.
-pub fn bootstrap_login(
+pub fn change_password(
     persistence: &mut SqlitePersistence,
-    request: &crate::BootstrapLoginRequest,
-) -> Result<crate::BootstrapLoginResponse, ApiError> {
-    // Check if we're in bootstrap mode
-    let operator_count: i64 = persistence
-        .count_operators()
+    request: &ChangePasswordRequest,
+    _authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<ChangePasswordResponse, ApiError> {
+    // Verify current password
+    let password_valid: bool = persistence
+        .verify_password(&request.current_password, &operator.password_hash)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to count operators: {e}"),
+            message: format!("Password verification failed: {e}"),
         })?;
 
-    if operator_count > 0 {
-        return Err(ApiError::Unauthorized {
-            action: String::from("bootstrap_login"),
-            required_role: String::from("Bootstrap mode (no operators exist)"),
+    if !password_valid {
+        return Err(ApiError::AuthenticationFailed {
+            reason: String::from("Current password is incorrect"),
         });
     }
 
-    // Verify hardcoded credentials
-    if request.username != "admin" || request.password != "admin" {
-        return Err(ApiError::from(AuthError::AuthenticationFailed {
-            reason: String::from("Invalid bootstrap credentials"),
-        }));
-    }
-
-    // Generate a bootstrap token (simple, temporary)
-    let timestamp: u128 = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_else(|_| std::time::Duration::from_secs(0))
-        .as_nanos();
-    let bootstrap_token: String = format!("bootstrap_{timestamp}_{}", rand::random::<u64>());
+    // Validate new password policy
+    let policy: PasswordPolicy = PasswordPolicy::default();
+    policy.validate(
+        &request.new_password,
+        &request.new_password_confirmation,
+        &operator.login_name,
+        &operator.display_name,
+    )?;
 
-    Ok(crate::BootstrapLoginResponse {
-        bootstrap_token,
-        is_bootstrap: true,
-    })
-}
+    // Update password
+    persistence
+        .update_password(operator.operator_id, &request.new_password)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to update password: {e}"),
+        })?;
 
-/// Creates the first admin operator during bootstrap.
-///
-/// This function only succeeds when:
-/// - No operators exist in the database (bootstrap mode)
-/// - A valid bootstrap token is provided
-///
-/// After successful creation, the bootstrap session is terminated and
-/// the system transitions out of bootstrap mode.
-///
-/// # Arguments
-///
-/// * `persistence` - The persistence layer
-/// * `request` - The create first admin request
-///
-/// # Returns
-///
-/// * `Ok(CreateFirstAdminResponse)` on success
-/// * `Err(ApiError)` if not in bootstrap mode or creation fails
-///
-/// # Errors
-///
-/// Returns an error if:
-/// - Operators already exist (not in bootstrap mode)
-/// - Login name already exists
-/// - Password validation fails
-/// - Database operations fail
-pub fn create_first_admin(
-    persistence: &mut SqlitePersistence,
-    request: crate::CreateFirstAdminRequest,
-) -> Result<crate::CreateFirstAdminResponse, ApiError> {
-    // Check if we're in bootstrap mode
-    let operator_count: i64 = persistence
-        .count_operators()
+    // Invalidate all sessions for this operator
+    persistence
+        .delete_sessions_for_operator(operator.operator_id)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to count operators: {e}"),
+            message: format!("Failed to invalidate sessions: {e}"),
         })?;
 
-    if operator_count > 0 {
-        return Err(ApiError::Unauthorized {
-            action: String::from("create_first_admin"),
-            required_role: String::from("Bootstrap mode (no operators exist)"),
-        });
-    }
+    // Create audit event for password change
+    let actor: Actor = Actor::with_operator(
+        operator.operator_id.to_string(),
+        String::from("operator"),
+        operator.operator_id,
+        operator.login_name.clone(),
+        operator.display_name.clone(),
+    );
 
-    // Validate password policy
-    let policy: PasswordPolicy = PasswordPolicy::default();
-    policy.validate(
-        &request.password,
-        &request.password_confirmation,
-        &request.login_name,
-        &request.display_name,
-    )?;
+    let action: Action = Action::new(
+        String::from("ChangePassword"),
+        Some(format!(
+            "Operator {} changed their own password",
+            operator.login_name
+        )),
+    );
 
-    // Create the first admin operator
-    let operator_id: i64 = persistence
-        .create_operator(
-            &request.login_name,
-            &request.display_name,
-            &request.password,
-            "Admin",
-        )
+    let operator_id = operator.operator_id;
+    let before: StateSnapshot = StateSnapshot::new(format!("operator_id={operator_id}"));
+    let after: StateSnapshot =
+        StateSnapshot::new(format!("operator_id={operator_id},password_changed"));
+
+    // Phase 23B: Use global event for operator management
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+
+    // Persist audit event
+    persistence
+        .persist_audit_event(&audit_event)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to create first admin: {e}"),
+            message: format!("Failed to persist audit event: {e}"),
         })?;
 
-    Ok(crate::CreateFirstAdminResponse {
-        operator_id,
-        login_name: request.login_name,
-        display_name: request.display_name,
-        message: String::from("First admin operator created successfully"),
+    Ok(ChangePasswordResponse {
+        message: String::from("Password changed successfully. All sessions have been invalidated."),
     })
 }
 
-// ========================================================================
-// Phase 18: Bootstrap Workflow Completion Handlers
-// ========================================================================
-
-/// Sets the active bid year.
-#[allow(dead_code)]
+/// Resets another operator's password (admin only).
 ///
-/// Only admins can set the active bid year.
-/// Exactly one bid year may be active at a time.
+/// Only Admin actors may reset other operators' passwords.
+/// Does not require the old password, enforces password policy, and invalidates all sessions.
+/// Emits an audit event on success.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
-/// * `metadata` - The current bootstrap metadata
-/// * `request` - The set active bid year request
+/// * `request` - The reset password request
 /// * `authenticated_actor` - The authenticated actor performing this action
-/// * `operator` - The operator data
-/// * `cause` - The cause or reason for this action
+/// * `operator` - The operator data for audit attribution (the admin)
+/// * `cause` - The cause for this action
+///
+/// # Returns
+///
+/// * `Ok(ResetPasswordResponse)` on success
+/// * `Err(ApiError)` if unauthorized or operation fails
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - The actor is not authorized (not an Admin)
-/// - The bid year does not exist
+/// - The target operator does not exist
+/// - New password does not meet policy requirements
+/// - Password confirmation does not match
 /// - Database operations fail
-pub fn set_active_bid_year(
+pub fn reset_password(
     persistence: &mut SqlitePersistence,
-    metadata: &BootstrapMetadata,
-    request: &SetActiveBidYearRequest,
+    request: &ResetPasswordRequest,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
     cause: Cause,
-) -> Result<SetActiveBidYearResponse, ApiError> {
-    // Enforce authorization - only admins can set active bid year
+) -> Result<ResetPasswordResponse, ApiError> {
+    // Enforce authorization before executing command
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("set_active_bid_year"),
+            action: String::from("reset_password"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Resolve bid_year_id to BidYear from metadata
-    let bid_year: &BidYear = metadata
-        .bid_years
-        .iter()
-        .find(|by| by.bid_year_id() == Some(request.bid_year_id))
-        .ok_or_else(|| ApiError::ResourceNotFound {
-            resource_type: String::from("BidYear"),
-            message: format!("Bid year with ID {} not found", request.bid_year_id),
+    // Get target operator to verify existence and get details for validation and audit
+    let target_operator: OperatorData = persistence
+        .get_operator_by_id(request.operator_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get operator: {e}"),
+        })?
+        .ok_or_else(|| {
+            let operator_id = request.operator_id;
+            ApiError::ResourceNotFound {
+                resource_type: String::from("Operator"),
+                message: format!("Operator with ID {operator_id} not found"),
+            }
         })?;
 
-    let year: u16 = bid_year.year();
+    // Validate new password policy
+    let policy: PasswordPolicy = PasswordPolicy::default();
+    policy.validate(
+        &request.new_password,
+        &request.new_password_confirmation,
+        &target_operator.login_name,
+        &target_operator.display_name,
+    )?;
 
-    // Apply the command
-    let command = Command::SetActiveBidYear { year };
-    let actor: Actor = authenticated_actor.to_audit_actor(operator);
-    let result: BootstrapResult =
-        apply_bootstrap(metadata, bid_year, command, actor, cause).map_err(translate_core_error)?;
+    // Update password
+    persistence
+        .update_password(request.operator_id, &request.new_password)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to update password: {e}"),
+        })?;
 
-    // Persist the active bid year setting
+    // Invalidate all sessions for the target operator
     persistence
-        .set_active_bid_year(bid_year)
+        .delete_sessions_for_operator(request.operator_id)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to set active bid year: {e}"),
+            message: format!("Failed to invalidate sessions: {e}"),
         })?;
 
+    // Create audit event for password reset
+    let actor: Actor = Actor::with_operator(
+        operator.operator_id.to_string(),
+        String::from("operator"),
+        operator.operator_id,
+        operator.login_name.clone(),
+        operator.display_name.clone(),
+    );
+
+    let action: Action = Action::new(
+        String::from("ResetPassword"),
+        Some(format!(
+            "Admin {} reset password for operator {}",
+            operator.login_name, target_operator.login_name
+        )),
+    );
+
+    let operator_id = request.operator_id;
+    let target_login = &target_operator.login_name;
+    let before: StateSnapshot = StateSnapshot::new(format!(
+        "operator_id={operator_id},login_name={target_login}"
+    ));
+    let after: StateSnapshot = StateSnapshot::new(format!(
+        "operator_id={operator_id},login_name={target_login},password_reset"
+    ));
+
+    // Phase 23B: Use global event for operator management
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+
     // Persist audit event
     persistence
-        .persist_audit_event(&result.audit_event)
+        .persist_audit_event(&audit_event)
         .map_err(|e| ApiError::Internal {
             message: format!("Failed to persist audit event: {e}"),
         })?;
 
-    Ok(SetActiveBidYearResponse {
-        bid_year_id: request.bid_year_id,
-        year,
-        message: format!("Bid year {year} is now active"),
-    })
+    Ok(ResetPasswordResponse {
+        message: format!(
+            "Password reset successfully for operator {}. All sessions have been invalidated.",
+            target_operator.login_name
+        ),
+        operator_id: request.operator_id,
+    })
 }
 
-/// Transitions a bid year from `Draft` to `BootstrapComplete`.
+// ========================================================================
+// Bootstrap Authentication (Phase 15)
+// ========================================================================
+
+/// Checks whether the system is in bootstrap mode.
+///
+/// Bootstrap mode is active when no operators exist in the database.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+///
+/// # Returns
+///
+/// * `Ok(BootstrapAuthStatusResponse)` indicating bootstrap status
+/// * `Err(ApiError)` if the query fails
+///
+/// # Errors
+///
+/// Returns an error if database operations fail.
+pub fn check_bootstrap_status(
+    persistence: &mut SqlitePersistence,
+) -> Result<crate::BootstrapAuthStatusResponse, ApiError> {
+    let operator_count: i64 = persistence
+        .count_operators()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to count operators: {e}"),
+        })?;
+
+    Ok(crate::BootstrapAuthStatusResponse {
+        is_bootstrap_mode: operator_count == 0,
+    })
+}
+
+/// Performs bootstrap login with hardcoded credentials.
+///
+/// This function only succeeds when:
+/// - No operators exist in the database (bootstrap mode)
+/// - Username is exactly "admin"
+/// - Password is exactly "admin"
+///
+/// The returned token is a temporary bootstrap session, not a real operator session.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The bootstrap login request
+///
+/// # Returns
+///
+/// * `Ok(BootstrapLoginResponse)` with a bootstrap token
+/// * `Err(ApiError)` if bootstrap mode is not active or credentials are invalid
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Operators already exist (not in bootstrap mode)
+/// - Credentials are not exactly "admin" / "admin"
+/// - Database operations fail
+///
+/// # Panics
+///
+/// Panics if the system time is before the Unix epoch.
+pub fn bootstrap_login(
+    persistence: &mut SqlitePersistence,
+    request: &crate::BootstrapLoginRequest,
+) -> Result<crate::BootstrapLoginResponse, ApiError> {
+    // Check if we're in bootstrap mode
+    let operator_count: i64 = persistence
+        .count_operators()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to count operators: {e}"),
+        })?;
+
+    if operator_count > 0 {
+        return Err(ApiError::Unauthorized {
+            action: String::from("bootstrap_login"),
+            required_role: String::from("Bootstrap mode (no operators exist)"),
+        });
+    }
+
+    // Verify hardcoded credentials
+    if request.username != "admin" || request.password != "admin" {
+        return Err(ApiError::from(AuthError::AuthenticationFailed {
+            reason: String::from("Invalid bootstrap credentials"),
+        }));
+    }
+
+    // Generate a bootstrap token (simple, temporary)
+    let timestamp: u128 = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_else(|_| std::time::Duration::from_secs(0))
+        .as_nanos();
+    let bootstrap_token: String = format!("bootstrap_{timestamp}_{}", rand::random::<u64>());
+
+    Ok(crate::BootstrapLoginResponse {
+        bootstrap_token,
+        is_bootstrap: true,
+    })
+}
+
+/// Creates the first admin operator during bootstrap.
+///
+/// This function only succeeds when:
+/// - No operators exist in the database (bootstrap mode)
+/// - A valid bootstrap token is provided
+///
+/// After successful creation, the bootstrap session is terminated and
+/// the system transitions out of bootstrap mode.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The create first admin request
+///
+/// # Returns
+///
+/// * `Ok(CreateFirstAdminResponse)` on success
+/// * `Err(ApiError)` if not in bootstrap mode or creation fails
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Operators already exist (not in bootstrap mode)
+/// - Login name already exists
+/// - Password validation fails
+/// - Database operations fail
+pub fn create_first_admin(
+    persistence: &mut SqlitePersistence,
+    request: crate::CreateFirstAdminRequest,
+) -> Result<crate::CreateFirstAdminResponse, ApiError> {
+    // Check if we're in bootstrap mode
+    let operator_count: i64 = persistence
+        .count_operators()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to count operators: {e}"),
+        })?;
+
+    if operator_count > 0 {
+        return Err(ApiError::Unauthorized {
+            action: String::from("create_first_admin"),
+            required_role: String::from("Bootstrap mode (no operators exist)"),
+        });
+    }
+
+    // Validate password policy
+    let policy: PasswordPolicy = PasswordPolicy::default();
+    policy.validate(
+        &request.password,
+        &request.password_confirmation,
+        &request.login_name,
+        &request.display_name,
+    )?;
+
+    // Create the first admin operator
+    let operator_id: i64 = persistence
+        .create_operator(
+            &request.login_name,
+            &request.display_name,
+            &request.password,
+            "Admin",
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to create first admin: {e}"),
+        })?;
+
+    Ok(crate::CreateFirstAdminResponse {
+        operator_id,
+        login_name: request.login_name,
+        display_name: request.display_name,
+        message: String::from("First admin operator created successfully"),
+    })
+}
+
+// ========================================================================
+// Phase 18: Bootstrap Workflow Completion Handlers
+// ========================================================================
+
+/// Sets the active bid year.
+#[allow(dead_code)]
+///
+/// Only admins can set the active bid year.
+/// Exactly one bid year may be active at a time.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
 /// * `metadata` - The current bootstrap metadata
-/// * `request` - The transition request
+/// * `request` - The set active bid year request
 /// * `authenticated_actor` - The authenticated actor performing this action
 /// * `operator` - The operator data
 /// * `cause` - The cause or reason for this action
@@ -2512,20 +2945,19 @@ pub fn set_active_bid_year(
 /// Returns an error if:
 /// - The actor is not authorized (not an Admin)
 /// - The bid year does not exist
-/// - Bootstrap is not complete
-/// - The transition is invalid
-pub fn transition_to_bootstrap_complete(
+/// - Database operations fail
+pub fn set_active_bid_year(
     persistence: &mut SqlitePersistence,
     metadata: &BootstrapMetadata,
-    request: &TransitionToBootstrapCompleteRequest,
+    request: &SetActiveBidYearRequest,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
     cause: Cause,
-) -> Result<TransitionToBootstrapCompleteResponse, ApiError> {
-    // Enforce authorization - only admins can transition lifecycle states
+) -> Result<SetActiveBidYearResponse, ApiError> {
+    // Enforce authorization - only admins can set active bid year
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("transition_to_bootstrap_complete"),
+            action: String::from("set_active_bid_year"),
             required_role: String::from("Admin"),
         });
     }
@@ -2542,48 +2974,121 @@ pub fn transition_to_bootstrap_complete(
 
     let year: u16 = bid_year.year();
 
-    // Load current lifecycle state
-    let current_state_str: String = persistence
-        .get_lifecycle_state(request.bid_year_id)
+    // Apply the command
+    let command = Command::SetActiveBidYear { year };
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let result: BootstrapResult =
+        apply_bootstrap(metadata, bid_year, command, actor, cause).map_err(translate_core_error)?;
+
+    // Persist the active bid year setting
+    persistence
+        .set_active_bid_year(bid_year)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to get lifecycle state: {e}"),
+            message: format!("Failed to set active bid year: {e}"),
         })?;
 
-    let current_state: zab_bid_domain::BidYearLifecycle =
-        current_state_str.parse().map_err(translate_domain_error)?;
-
-    let target_state = zab_bid_domain::BidYearLifecycle::BootstrapComplete;
-
-    // Validate transition
-    if !current_state.can_transition_to(target_state) {
-        return Err(translate_domain_error(
-            DomainError::InvalidStateTransition {
-                current: current_state.as_str().to_string(),
-                target: target_state.as_str().to_string(),
-            },
-        ));
-    }
-
-    // Check bootstrap completeness
-    let completeness_response: GetBootstrapCompletenessResponse =
-        get_bootstrap_completeness(persistence, metadata)?;
-    if !completeness_response.is_ready_for_bidding {
-        return Err(translate_domain_error(DomainError::BootstrapIncomplete));
-    }
-
-    // Phase 25B: Check for users in No Bid area
-    let users_in_no_bid: usize = persistence
-        .count_users_in_system_area(request.bid_year_id)
+    // Persist audit event
+    persistence
+        .persist_audit_event(&result.audit_event)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to check No Bid area: {e}"),
+            message: format!("Failed to persist audit event: {e}"),
         })?;
 
-    if users_in_no_bid > 0 {
-        let sample_initials: Vec<String> = persistence
-            .list_users_in_system_area(request.bid_year_id, 5)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to list users in No Bid area: {e}"),
-            })?;
+    Ok(SetActiveBidYearResponse {
+        bid_year_id: request.bid_year_id,
+        year,
+        message: format!("Bid year {year} is now active"),
+    })
+}
+
+/// Transitions a bid year from `Draft` to `BootstrapComplete`.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The current bootstrap metadata
+/// * `request` - The transition request
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data
+/// * `cause` - The cause or reason for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not authorized (not an Admin)
+/// - The bid year does not exist
+/// - Bootstrap is not complete
+/// - The transition is invalid
+pub fn transition_to_bootstrap_complete(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &TransitionToBootstrapCompleteRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<TransitionToBootstrapCompleteResponse, ApiError> {
+    // Enforce authorization - only admins can transition lifecycle states
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("transition_to_bootstrap_complete"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    // Resolve bid_year_id to BidYear from metadata
+    let bid_year: &BidYear = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.bid_year_id() == Some(request.bid_year_id))
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("BidYear"),
+            message: format!("Bid year with ID {} not found", request.bid_year_id),
+        })?;
+
+    let year: u16 = bid_year.year();
+
+    // Load current lifecycle state
+    let current_state_str: String = persistence
+        .get_lifecycle_state(request.bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get lifecycle state: {e}"),
+        })?;
+
+    let current_state: zab_bid_domain::BidYearLifecycle =
+        current_state_str.parse().map_err(translate_domain_error)?;
+
+    let target_state = zab_bid_domain::BidYearLifecycle::BootstrapComplete;
+
+    // Validate transition
+    if !current_state.can_transition_to(target_state) {
+        return Err(translate_domain_error(
+            DomainError::InvalidStateTransition {
+                current: current_state.as_str().to_string(),
+                target: target_state.as_str().to_string(),
+            },
+        ));
+    }
+
+    // Check bootstrap completeness
+    let completeness_response: GetBootstrapCompletenessResponse =
+        get_bootstrap_completeness(persistence, metadata)?;
+    if !completeness_response.is_ready_for_bidding {
+        return Err(translate_domain_error(DomainError::BootstrapIncomplete));
+    }
+
+    // Phase 25B: Check for users in No Bid area
+    let users_in_no_bid: usize = persistence
+        .count_users_in_system_area(request.bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to check No Bid area: {e}"),
+        })?;
+
+    if users_in_no_bid > 0 {
+        let sample_initials: Vec<String> = persistence
+            .list_users_in_system_area(request.bid_year_id, 5)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list users in No Bid area: {e}"),
+            })?;
 
         return Err(translate_domain_error(DomainError::UsersInNoBidArea {
             bid_year: year,
@@ -2622,6 +3127,9 @@ pub fn transition_to_bootstrap_complete(
 
 /// Transitions a bid year from `BootstrapComplete` to `Canonicalized`.
 ///
+/// An automatic checkpoint labeled `auto-before-canonicalization` is
+/// recorded in every area of the bid year first.
+///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
@@ -2637,6 +3145,7 @@ pub fn transition_to_bootstrap_complete(
 /// - The actor is not authorized (not an Admin)
 /// - The bid year does not exist
 /// - The transition is invalid
+/// - An automatic checkpoint cannot be recorded
 pub fn transition_to_canonicalized(
     persistence: &mut SqlitePersistence,
     metadata: &BootstrapMetadata,
@@ -2714,12 +3223,23 @@ pub fn transition_to_canonicalized(
     let result: BootstrapResult =
         apply_bootstrap(metadata, bid_year, command, actor, cause).map_err(translate_core_error)?;
 
+    // Record a recovery point in every area before canonical data is written
+    let checkpoints: AutoCheckpoints = record_bid_year_auto_checkpoints(
+        persistence,
+        metadata,
+        year,
+        "canonicalization",
+        &result.audit_event.actor,
+        &result.audit_event.cause,
+    )?;
+
     // Perform canonicalization (within implicit transaction via persistence layer)
-    persistence
+    let event_id: i64 = persistence
         .canonicalize_bid_year(request.bid_year_id, &result.audit_event)
         .map_err(|e| ApiError::Internal {
             message: format!("Failed to canonicalize bid year: {e}"),
         })?;
+    record_operation_events(persistence, &[event_id], &checkpoints.correlation_id)?;
 
     // Update lifecycle state
     persistence
@@ -2733,6 +3253,7 @@ pub fn transition_to_canonicalized(
         year,
         lifecycle_state: target_state.as_str().to_string(),
         message: format!("Bid year {year} transitioned to {}", target_state.as_str()),
+        correlation_id: checkpoints.correlation_id,
     })
 }
 
@@ -2754,6 +3275,7 @@ pub fn transition_to_canonicalized(
 /// - The bid year does not exist
 /// - Another bid year is already `BiddingActive`
 /// - The transition is invalid
+/// - Any readiness criterion is unsatisfied
 pub fn transition_to_bidding_active(
     persistence: &mut SqlitePersistence,
     metadata: &BootstrapMetadata,
@@ -2818,8 +3340,10 @@ pub fn transition_to_bidding_active(
         ));
     }
 
-    // Apply the command
-    let command = Command::TransitionToBiddingActive { year };
+    // Apply the command; the core refuses activation while any readiness
+    // criterion is unsatisfied
+    let (readiness, _) = evaluate_bid_year_readiness(persistence, request.bid_year_id)?;
+    let command = Command::ActivateBidding { year, readiness };
     let actor: Actor = authenticated_actor.to_audit_actor(operator);
     let result: BootstrapResult =
         apply_bootstrap(metadata, bid_year, command, actor, cause).map_err(translate_core_error)?;
@@ -2941,6 +3465,113 @@ pub fn transition_to_bidding_closed(
     })
 }
 
+/// Reopens a canonicalized bid year, returning it to `BootstrapComplete`.
+///
+/// Canonicalization and bid readiness confirmation are undone: every
+/// canonical row, and the bid order, bid windows, and bid status derived
+/// from them, is deleted in the same transaction that records the audit
+/// event. Only permitted while no leave bid or bid preference has been
+/// entered, and only with a written justification.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The current bootstrap metadata
+/// * `request` - The reopen request
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data
+/// * `cause` - The cause or reason for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not authorized (not an Admin)
+/// - The bid year does not exist
+/// - The bid year is not `Canonicalized`
+/// - The justification is shorter than 20 characters
+/// - Any bid has been entered
+pub fn reopen_bid_year(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &ReopenBidYearRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<ReopenBidYearResponse, ApiError> {
+    // Enforce authorization - only admins can transition lifecycle states
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("reopen_bid_year"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    // Resolve bid_year_id to BidYear from metadata
+    let bid_year: &BidYear = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.bid_year_id() == Some(request.bid_year_id))
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("BidYear"),
+            message: format!("Bid year with ID {} not found", request.bid_year_id),
+        })?;
+
+    let year: u16 = bid_year.year();
+
+    // Load current lifecycle state
+    let current_state_str: String = persistence
+        .get_lifecycle_state(request.bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get lifecycle state: {e}"),
+        })?;
+
+    let current_state: zab_bid_domain::BidYearLifecycle =
+        current_state_str.parse().map_err(translate_domain_error)?;
+
+    let target_state = zab_bid_domain::BidYearLifecycle::BootstrapComplete;
+
+    // Only a canonicalized bid year that has not started bidding reopens
+    if current_state != zab_bid_domain::BidYearLifecycle::Canonicalized {
+        return Err(translate_domain_error(
+            DomainError::InvalidStateTransition {
+                current: current_state.as_str().to_string(),
+                target: target_state.as_str().to_string(),
+            },
+        ));
+    }
+
+    // Apply the command to validate the justification
+    let command = Command::ReopenBidYear {
+        year,
+        justification: request.justification.clone(),
+    };
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let result: BootstrapResult =
+        apply_bootstrap(metadata, bid_year, command, actor, cause).map_err(translate_core_error)?;
+
+    // Check for bids, clear canonical data, record the event, and reopen
+    // atomically
+    let (audit_event_id, rows_cleared): (i64, usize) = persistence
+        .reopen_bid_year(request.bid_year_id, &result.audit_event)
+        .map_err(|e| match e {
+            PersistenceError::BidYearHasBids { bids, .. } => {
+                translate_domain_error(DomainError::BidYearHasBids { year, bids })
+            }
+            _ => ApiError::Internal {
+                message: format!("Failed to reopen bid year: {e}"),
+            },
+        })?;
+
+    Ok(ReopenBidYearResponse {
+        bid_year_id: request.bid_year_id,
+        year,
+        lifecycle_state: target_state.as_str().to_string(),
+        audit_event_id,
+        rows_cleared,
+        message: format!("Bid year {year} reopened to {}", target_state.as_str()),
+    })
+}
+
 /// Updates the metadata (label and notes) for a bid year.
 ///
 /// This is an admin-only operation that can be performed in any lifecycle state.
@@ -3097,7 +3728,8 @@ pub fn update_bid_year_metadata(
 
 /// Sets the bid schedule for a bid year.
 ///
-/// Phase 29C: Configures when and how bidding occurs.
+/// Phase 29C: Configures when and how bidding occurs. A request without a
+/// timezone inherits the facility's default timezone.
 ///
 /// # Arguments
 ///
@@ -3118,6 +3750,7 @@ pub fn update_bid_year_metadata(
 /// Returns an error if:
 /// - The operator is not an admin
 /// - The bid year is in a locked lifecycle state
+/// - No timezone is given and the facility has no default timezone
 /// - Validation of the bid schedule fails
 /// - Database operations fail
 #[allow(dead_code, clippy::too_many_lines)]
@@ -3170,6 +3803,19 @@ pub fn set_bid_schedule(
         });
     }
 
+    // A schedule without its own timezone inherits the facility's
+    let timezone: String = match &request.timezone {
+        Some(timezone) => timezone.clone(),
+        None => load_facility(persistence)?
+            .map(|facility| facility.default_timezone().to_string())
+            .ok_or_else(|| ApiError::InvalidInput {
+                field: String::from("timezone"),
+                message: String::from(
+                    "A timezone is required until the facility's default timezone is configured",
+                ),
+            })?,
+    };
+
     // Parse and validate the bid schedule fields
     let start_date: time::Date = time::Date::parse(
         &request.start_date,
@@ -3194,7 +3840,7 @@ pub fn set_bid_schedule(
 
     // Create and validate BidSchedule domain object
     let _bid_schedule: BidSchedule = BidSchedule::new(
-        request.timezone.clone(),
+        timezone.clone(),
         start_date,
         window_start_time,
         window_end_time,
@@ -3209,7 +3855,7 @@ pub fn set_bid_schedule(
     persistence
         .update_bid_schedule(
             request.bid_year_id,
-            Some(&request.timezone),
+            Some(&timezone),
             Some(&request.start_date),
             Some(&request.window_start_time),
             Some(&request.window_end_time),
@@ -3231,7 +3877,7 @@ pub fn set_bid_schedule(
         name: String::from("SetBidSchedule"),
         details: Some(format!(
             "Set bid schedule for bid year {year}: timezone={}, start_date={}, window={}–{}, bidders_per_day={}",
-            request.timezone,
+            timezone,
             request.start_date,
             request.window_start_time,
             request.window_end_time,
@@ -3258,7 +3904,7 @@ pub fn set_bid_schedule(
 
     let after_snapshot: String = format!(
         r#"{{"timezone":"{}","start_date":"{}","window_start_time":"{}","window_end_time":"{}","bidders_per_day":{}}}"#,
-        request.timezone,
+        timezone,
         request.start_date,
         request.window_start_time,
         request.window_end_time,
@@ -3281,7 +3927,7 @@ pub fn set_bid_schedule(
         bid_year_id: request.bid_year_id,
         year,
         bid_schedule: BidScheduleInfo {
-            timezone: request.timezone.clone(),
+            timezone,
             start_date: request.start_date.clone(),
             window_start_time: request.window_start_time.clone(),
             window_end_time: request.window_end_time.clone(),
@@ -3327,1145 +3973,3062 @@ pub fn get_bid_schedule(
     let year: u16 = bid_year.year();
 
     // Fetch bid schedule from persistence
-    let bid_schedule = persistence
+    let bid_schedule: Option<BidScheduleInfo> = persistence
         .get_bid_schedule(bid_year_id)
-        .map_err(|e| match e {
-            PersistenceError::NotFound(_) => ApiError::ResourceNotFound {
-                resource_type: String::from("BidYear"),
-                message: format!("Bid year with ID {bid_year_id} not found"),
-            },
-            _ => ApiError::Internal {
-                message: format!("Failed to get bid schedule: {e}"),
-            },
-        })
         .ok()
-        .and_then(|(tz, sd, wst, wet, bpd)| {
-            // Only construct BidScheduleInfo if all fields are present
-            if let (
-                Some(timezone),
-                Some(start_date),
-                Some(window_start_time),
-                Some(window_end_time),
-                Some(bidders_per_day),
-            ) = (tz, sd, wst, wet, bpd)
-            {
-                Some(BidScheduleInfo {
-                    timezone,
-                    start_date,
-                    window_start_time,
-                    window_end_time,
-                    bidders_per_day: bidders_per_day.cast_unsigned(),
-                })
-            } else {
-                None
-            }
-        });
+        .and_then(bid_schedule_info_from_fields);
+
+    let overrides = persistence
+        .list_area_bid_schedule_overrides(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list area bid schedule overrides: {e}"),
+        })?;
+    let area_overrides: Vec<AreaBidScheduleInfo> = overrides
+        .into_iter()
+        .map(|(area_id, area_code, fields)| {
+            area_bid_schedule_info(persistence, area_id, area_code, fields)
+        })
+        .collect::<Result<Vec<AreaBidScheduleInfo>, ApiError>>()?;
 
     Ok(GetBidScheduleResponse {
         bid_year_id,
         year,
         bid_schedule,
+        area_overrides,
     })
 }
 
-/// Gets the currently active bid year.
-#[allow(dead_code)]
-///
-/// # Arguments
+/// Converts raw bid schedule fields into a `BidScheduleInfo`.
+///
+/// Returns `None` unless every field is present.
+fn bid_schedule_info_from_fields(fields: BidScheduleFields) -> Option<BidScheduleInfo> {
+    if let (
+        Some(timezone),
+        Some(start_date),
+        Some(window_start_time),
+        Some(window_end_time),
+        Some(bidders_per_day),
+    ) = fields
+    {
+        Some(BidScheduleInfo {
+            timezone,
+            start_date,
+            window_start_time,
+            window_end_time,
+            bidders_per_day: bidders_per_day.cast_unsigned(),
+        })
+    } else {
+        None
+    }
+}
+
+/// Parses raw bid schedule fields into a validated `BidSchedule`.
 ///
-/// * `persistence` - The persistence layer
+/// Returns `Ok(None)` if any field is unset.
 ///
 /// # Errors
 ///
-/// Returns an error if database operations fail.
-pub fn get_active_bid_year(
-    persistence: &mut SqlitePersistence,
-    metadata: &BootstrapMetadata,
-) -> Result<GetActiveBidYearResponse, ApiError> {
-    let year: u16 = persistence
-        .get_active_bid_year()
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to get active bid year: {e}"),
-        })?;
+/// Returns an error if a stored field cannot be parsed or the resulting
+/// schedule violates a domain rule.
+fn parse_bid_schedule_fields(fields: BidScheduleFields) -> Result<Option<BidSchedule>, ApiError> {
+    let (
+        Some(timezone),
+        Some(start_date_str),
+        Some(window_start_time_str),
+        Some(window_end_time_str),
+        Some(bidders_per_day),
+    ) = fields
+    else {
+        return Ok(None);
+    };
 
-    // Extract bid_year_id if there is an active year
-    let bid_year_id: Option<i64> = metadata
-        .bid_years
-        .iter()
-        .find(|by| by.year() == year)
-        .and_then(zab_bid_domain::BidYear::bid_year_id);
+    let start_date = time::Date::parse(
+        &start_date_str,
+        &time::format_description::well_known::Iso8601::DEFAULT,
+    )
+    .map_err(|_| ApiError::Internal {
+        message: format!("Failed to parse bid start date: {start_date_str}"),
+    })?;
 
-    Ok(GetActiveBidYearResponse {
-        bid_year_id,
-        year: Some(year),
-    })
+    let window_start_time = time::Time::parse(
+        &window_start_time_str,
+        &time::format_description::well_known::Iso8601::DEFAULT,
+    )
+    .map_err(|_| ApiError::Internal {
+        message: format!("Failed to parse window start time: {window_start_time_str}"),
+    })?;
+
+    let window_end_time = time::Time::parse(
+        &window_end_time_str,
+        &time::format_description::well_known::Iso8601::DEFAULT,
+    )
+    .map_err(|_| ApiError::Internal {
+        message: format!("Failed to parse window end time: {window_end_time_str}"),
+    })?;
+
+    let bidders_per_day_u32 = bidders_per_day.to_u32().ok_or_else(|| ApiError::Internal {
+        message: format!("Invalid bidders_per_day value: {bidders_per_day}"),
+    })?;
+
+    BidSchedule::new(
+        timezone,
+        start_date,
+        window_start_time,
+        window_end_time,
+        bidders_per_day_u32,
+    )
+    .map(Some)
+    .map_err(translate_domain_error)
 }
 
-/// Sets the expected area count for a bid year.
-#[allow(dead_code)]
+/// Resolves the bid schedule that applies to an area.
 ///
-/// Only admins can set expected area counts.
+/// Areas without an override use the bid year's schedule unchanged.
 ///
-/// # Arguments
+/// # Errors
 ///
-/// * `persistence` - The persistence layer
+/// Returns an error if the override cannot be read or the merged
+/// schedule is invalid.
+fn effective_bid_schedule_for_area(
+    persistence: &mut SqlitePersistence,
+    area_id: i64,
+    bid_year_schedule: &BidSchedule,
+) -> Result<BidSchedule, ApiError> {
+    let area_override = persistence
+        .get_area_bid_schedule_override(area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get area bid schedule override: {e}"),
+        })?;
+
+    if area_override.is_none() {
+        return Ok(bid_year_schedule.clone());
+    }
+
+    let fields = persistence
+        .get_effective_bid_schedule(area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get effective bid schedule: {e}"),
+        })?;
+
+    parse_bid_schedule_fields(fields)?.ok_or_else(|| ApiError::Internal {
+        message: format!("Effective bid schedule for area {area_id} is incomplete"),
+    })
+}
+
+/// Builds the API view of an area's bid schedule override.
+///
+/// # Errors
+///
+/// Returns an error if the effective schedule cannot be read.
+fn area_bid_schedule_info(
+    persistence: &mut SqlitePersistence,
+    area_id: i64,
+    area_code: String,
+    fields: (Option<String>, Option<String>, Option<String>, Option<i32>),
+) -> Result<AreaBidScheduleInfo, ApiError> {
+    let effective = persistence
+        .get_effective_bid_schedule(area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get effective bid schedule: {e}"),
+        })?;
+    let (start_date, window_start_time, window_end_time, bidders_per_day) = fields;
+
+    Ok(AreaBidScheduleInfo {
+        area_id,
+        area_code,
+        start_date,
+        window_start_time,
+        window_end_time,
+        bidders_per_day: bidders_per_day.map(i32::cast_unsigned),
+        effective_schedule: bid_schedule_info_from_fields(effective),
+    })
+}
+
+/// Formats an area bid schedule override as a JSON snapshot for audit.
+fn area_bid_schedule_snapshot(fields: Option<&AreaBidScheduleOverrideFields>) -> String {
+    let Some((sd, wst, wet, bpd)) = fields else {
+        return String::from("null");
+    };
+    let quote = |v: &Option<String>| {
+        v.as_ref()
+            .map_or_else(|| "null".to_string(), |s| format!("\"{s}\""))
+    };
+    format!(
+        r#"{{"start_date":{},"window_start_time":{},"window_end_time":{},"bidders_per_day":{}}}"#,
+        quote(sd),
+        quote(wst),
+        quote(wet),
+        bpd.map_or_else(|| "null".to_string(), |v| v.to_string())
+    )
+}
+
+/// Resolves an area and its bid year for a bid schedule override, and
+/// checks that the bid year still permits schedule edits.
+///
+/// # Errors
+///
+/// Returns an error if the area or bid year does not exist, the area is a
+/// system area, or the bid year is in a locked lifecycle state.
+fn resolve_area_for_schedule_override(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    area_id: i64,
+) -> Result<(BidYear, Area, i64), ApiError> {
+    let (bid_year, area) = metadata
+        .areas
+        .iter()
+        .find(|(_, a)| a.area_id() == Some(area_id))
+        .map(|(by, a)| (by.clone(), a.clone()))
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("Area"),
+            message: format!("Area with ID {area_id} not found"),
+        })?;
+
+    let bid_year_id: i64 = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.year() == bid_year.year())
+        .and_then(BidYear::bid_year_id)
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Bid year {} has no ID", bid_year.year()),
+        })?;
+
+    validate_not_system_area(persistence, area_id, area.area_code())?;
+
+    let lifecycle_state: BidYearLifecycle = persistence
+        .get_lifecycle_state(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get lifecycle state: {e}"),
+        })
+        .and_then(|s| {
+            s.parse::<BidYearLifecycle>()
+                .map_err(translate_domain_error)
+        })?;
+
+    if lifecycle_state.is_locked() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("lifecycle_state"),
+            message: format!("Cannot modify bid schedule: bid year is in {lifecycle_state} state"),
+        });
+    }
+
+    Ok((bid_year, area, bid_year_id))
+}
+
+/// Sets a per-area bid schedule override.
+///
+/// Areas that start bidding later than the rest of the facility can
+/// override the start date, daily window times, and bidders per day.
+/// Unset fields fall back to the bid year's schedule, which also supplies
+/// the timezone.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
 /// * `metadata` - The current bootstrap metadata
-/// * `request` - The set expected area count request
-/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `request` - The set area bid schedule request
+/// * `authenticated_actor` - The authenticated operator
 /// * `operator` - The operator data
-/// * `cause` - The cause or reason for this action
+/// * `cause` - The cause of this action
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The actor is not authorized (not an Admin)
-/// - The bid year does not exist
-/// - The expected count is zero
+/// - The operator is not an admin
+/// - The area does not exist or is a system area
+/// - The bid year is in a locked lifecycle state
+/// - No field is set, or a field fails validation
+/// - The merged schedule violates a bid schedule rule
 /// - Database operations fail
-pub fn set_expected_area_count(
+#[allow(clippy::too_many_lines)]
+pub fn set_area_bid_schedule(
     persistence: &mut SqlitePersistence,
     metadata: &BootstrapMetadata,
-    request: &SetExpectedAreaCountRequest,
+    request: &SetAreaBidScheduleRequest,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
     cause: Cause,
-) -> Result<SetExpectedAreaCountResponse, ApiError> {
-    // Enforce authorization - only admins can set expected counts
+) -> Result<SetAreaBidScheduleResponse, ApiError> {
+    const TIME_FORMAT: &[time::format_description::FormatItem<'_>] =
+        time::macros::format_description!("[hour]:[minute]:[second]");
+
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("set_expected_area_count"),
+            action: String::from("set area bid schedule"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Resolve the active bid year from canonical state
-    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+    let (bid_year, area, bid_year_id) =
+        resolve_area_for_schedule_override(persistence, metadata, request.area_id)?;
 
-    let command = Command::SetExpectedAreaCount {
-        expected_count: request.expected_count,
-    };
+    if request.start_date.is_none()
+        && request.window_start_time.is_none()
+        && request.window_end_time.is_none()
+        && request.bidders_per_day.is_none()
+    {
+        return Err(ApiError::InvalidInput {
+            field: String::from("area_bid_schedule"),
+            message: String::from("At least one schedule field must be overridden"),
+        });
+    }
 
-    let actor: Actor = authenticated_actor.to_audit_actor(operator);
-    let result: BootstrapResult =
-        apply_bootstrap(metadata, &active_bid_year, command, actor, cause)
-            .map_err(translate_core_error)?;
+    // Validate each overridden field on its own
+    if let Some(start_date) = &request.start_date {
+        let date: time::Date = time::Date::parse(
+            start_date,
+            &time::format_description::well_known::Iso8601::DEFAULT,
+        )
+        .map_err(|_| ApiError::InvalidInput {
+            field: String::from("start_date"),
+            message: format!("Invalid date format: {start_date}"),
+        })?;
+        if date.weekday() != time::Weekday::Monday {
+            return Err(translate_domain_error(DomainError::BidStartDateNotMonday(
+                date,
+            )));
+        }
+    }
+    let window_start_time: Option<time::Time> = request
+        .window_start_time
+        .as_ref()
+        .map(|t| {
+            time::Time::parse(t, TIME_FORMAT).map_err(|_| ApiError::InvalidInput {
+                field: String::from("window_start_time"),
+                message: format!("Invalid time format: {t}"),
+            })
+        })
+        .transpose()?;
+    let window_end_time: Option<time::Time> = request
+        .window_end_time
+        .as_ref()
+        .map(|t| {
+            time::Time::parse(t, TIME_FORMAT).map_err(|_| ApiError::InvalidInput {
+                field: String::from("window_end_time"),
+                message: format!("Invalid time format: {t}"),
+            })
+        })
+        .transpose()?;
+    if let (Some(start), Some(end)) = (window_start_time, window_end_time)
+        && start >= end
+    {
+        return Err(translate_domain_error(DomainError::InvalidBidWindowTimes {
+            start,
+            end,
+        }));
+    }
+    if request.bidders_per_day == Some(0) {
+        return Err(translate_domain_error(DomainError::InvalidBiddersPerDay(0)));
+    }
+    let bidders_per_day: Option<i32> = request
+        .bidders_per_day
+        .map(|b| {
+            b.to_i32().ok_or_else(|| ApiError::InvalidInput {
+                field: String::from("bidders_per_day"),
+                message: format!("Invalid bidders_per_day value: {b}"),
+            })
+        })
+        .transpose()?;
+
+    let old_override = persistence
+        .get_area_bid_schedule_override(request.area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get area bid schedule override: {e}"),
+        })?;
+
+    let new_fields = (
+        request.start_date.clone(),
+        request.window_start_time.clone(),
+        request.window_end_time.clone(),
+        bidders_per_day,
+    );
+
+    // The merged schedule must satisfy the same rules as a bid year schedule
+    let bid_year_schedule =
+        persistence
+            .get_bid_schedule(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get bid schedule: {e}"),
+            })?;
+    parse_bid_schedule_fields(merge_area_bid_schedule(
+        bid_year_schedule,
+        Some(new_fields.clone()),
+    ))?;
 
-    // Persist the expected area count
     persistence
-        .set_expected_area_count(&active_bid_year, request.expected_count as usize)
+        .set_area_bid_schedule_override(
+            request.area_id,
+            request.start_date.as_deref(),
+            request.window_start_time.as_deref(),
+            request.window_end_time.as_deref(),
+            bidders_per_day,
+        )
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to set expected area count: {e}"),
+            message: format!("Failed to set area bid schedule override: {e}"),
         })?;
 
-    // Persist audit event
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("SetAreaBidSchedule"),
+        Some(format!(
+            "Set bid schedule override for area {} in bid year {}",
+            area.area_code(),
+            bid_year.year()
+        )),
+    );
+    let before: StateSnapshot =
+        StateSnapshot::new(area_bid_schedule_snapshot(old_override.as_ref()));
+    let after: StateSnapshot = StateSnapshot::new(area_bid_schedule_snapshot(Some(&new_fields)));
+    let audit_event: AuditEvent =
+        AuditEvent::new(actor, cause, action, before, after, bid_year, area.clone());
+
     persistence
-        .persist_audit_event(&result.audit_event)
+        .persist_audit_event(&audit_event)
         .map_err(|e| ApiError::Internal {
             message: format!("Failed to persist audit event: {e}"),
         })?;
 
-    // Extract bid_year_id from metadata
-    let bid_year_id: i64 = metadata
-        .bid_years
-        .iter()
-        .find(|by| by.year() == active_bid_year.year())
-        .and_then(zab_bid_domain::BidYear::bid_year_id)
-        .ok_or_else(|| ApiError::Internal {
-            message: format!(
-                "Bid year {} exists but has no ID in metadata",
-                active_bid_year.year()
-            ),
-        })?;
+    let area_schedule: AreaBidScheduleInfo = area_bid_schedule_info(
+        persistence,
+        request.area_id,
+        area.area_code().to_string(),
+        new_fields,
+    )?;
 
-    Ok(SetExpectedAreaCountResponse {
+    Ok(SetAreaBidScheduleResponse {
         bid_year_id,
-        bid_year: active_bid_year.year(),
-        expected_count: request.expected_count,
-        message: format!(
-            "Expected area count set to {} for bid year {}",
-            request.expected_count,
-            active_bid_year.year()
-        ),
+        area_schedule,
+        message: format!("Bid schedule override set for area {}", area.area_code()),
     })
 }
 
-/// Sets the expected user count for an area.
-#[allow(dead_code)]
+/// Clears a per-area bid schedule override.
 ///
-/// Only admins can set expected user counts.
+/// The area reverts to the bid year's schedule.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
 /// * `metadata` - The current bootstrap metadata
-/// * `request` - The set expected user count request
-/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `area_id` - The canonical area ID
+/// * `authenticated_actor` - The authenticated operator
 /// * `operator` - The operator data
-/// * `cause` - The cause or reason for this action
+/// * `cause` - The cause of this action
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The actor is not authorized (not an Admin)
-/// - The bid year or area does not exist
-/// - The expected count is zero
+/// - The operator is not an admin
+/// - The area does not exist or has no override
+/// - The bid year is in a locked lifecycle state
 /// - Database operations fail
-pub fn set_expected_user_count(
+pub fn clear_area_bid_schedule(
     persistence: &mut SqlitePersistence,
     metadata: &BootstrapMetadata,
-    request: &SetExpectedUserCountRequest,
+    area_id: i64,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
     cause: Cause,
-) -> Result<SetExpectedUserCountResponse, ApiError> {
-    // Enforce authorization - only admins can set expected counts
+) -> Result<ClearAreaBidScheduleResponse, ApiError> {
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("set_expected_user_count"),
+            action: String::from("clear area bid schedule"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Resolve the active bid year from canonical state
-    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+    let (bid_year, area, _bid_year_id) =
+        resolve_area_for_schedule_override(persistence, metadata, area_id)?;
 
-    // Resolve area_id to Area from metadata
-    let area: &Area = metadata
-        .areas
-        .iter()
-        .filter(|(by, _)| by.year() == active_bid_year.year())
-        .find(|(_, a)| a.area_id() == Some(request.area_id))
-        .map(|(_, a)| a)
+    let old_override = persistence
+        .get_area_bid_schedule_override(area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get area bid schedule override: {e}"),
+        })?
         .ok_or_else(|| ApiError::ResourceNotFound {
-            resource_type: String::from("Area"),
-            message: format!(
-                "Area with ID {} not found in active bid year",
-                request.area_id
-            ),
+            resource_type: String::from("AreaBidSchedule"),
+            message: format!("Area {} has no bid schedule override", area.area_code()),
         })?;
 
-    // Check if this is a system area (No Bid should not have expected count)
-    let is_system =
-        persistence
-            .is_system_area(request.area_id)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to check system area status: {e}"),
-            })?;
+    persistence
+        .clear_area_bid_schedule_override(area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to clear area bid schedule override: {e}"),
+        })?;
 
-    if is_system {
-        return Err(ApiError::InvalidInput {
-            field: String::from("area_id"),
-            message: format!(
-                "Cannot set expected user count for system area '{}'",
-                area.area_code()
-            ),
-        });
-    }
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("ClearAreaBidSchedule"),
+        Some(format!(
+            "Cleared bid schedule override for area {} in bid year {}",
+            area.area_code(),
+            bid_year.year()
+        )),
+    );
+    let before: StateSnapshot = StateSnapshot::new(area_bid_schedule_snapshot(Some(&old_override)));
+    let after: StateSnapshot = StateSnapshot::new(area_bid_schedule_snapshot(None));
+    let audit_event: AuditEvent =
+        AuditEvent::new(actor, cause, action, before, after, bid_year, area.clone());
 
-    // Get bid_year_id for lifecycle check
-    let bid_year_id = metadata
-        .bid_years
-        .iter()
-        .find(|by| by.year() == active_bid_year.year())
-        .and_then(zab_bid_domain::BidYear::bid_year_id)
-        .ok_or_else(|| ApiError::Internal {
-            message: format!("Bid year {} has no ID", active_bid_year.year()),
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
         })?;
 
-    // Check lifecycle state - reject if >= Canonicalized
-    let lifecycle_state_str =
-        persistence
-            .get_lifecycle_state(bid_year_id)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to get lifecycle state: {e}"),
-            })?;
+    Ok(ClearAreaBidScheduleResponse {
+        area_id,
+        message: format!(
+            "Bid schedule override cleared for area {}",
+            area.area_code()
+        ),
+    })
+}
 
-    let lifecycle_state = zab_bid_domain::BidYearLifecycle::from_str(&lifecycle_state_str)
-        .map_err(|_| ApiError::Internal {
-            message: format!("Invalid lifecycle state: {lifecycle_state_str}"),
-        })?;
+/// Converts a persisted blackout date to its API representation.
+fn blackout_date_info(data: BlackoutDateData) -> BlackoutDateInfo {
+    BlackoutDateInfo {
+        blackout_date_id: data.blackout_date_id,
+        date: data.blackout_date,
+        reason: data.reason,
+    }
+}
+
+/// Validates a blackout date and reason.
+///
+/// # Errors
+///
+/// Returns an error if the date is not a valid ISO 8601 date, falls on a
+/// weekend, or the reason is empty.
+fn validate_blackout_date(date: &str, reason: &str) -> Result<time::Date, ApiError> {
+    let parsed: time::Date = time::Date::parse(
+        date,
+        &time::format_description::well_known::Iso8601::DEFAULT,
+    )
+    .map_err(|_| ApiError::InvalidInput {
+        field: String::from("date"),
+        message: format!("Invalid date format: {date}"),
+    })?;
 
-    // Expected user count can only be set before canonicalization
     if matches!(
-        lifecycle_state,
-        zab_bid_domain::BidYearLifecycle::Canonicalized
-            | zab_bid_domain::BidYearLifecycle::BiddingActive
-            | zab_bid_domain::BidYearLifecycle::BiddingClosed
+        parsed.weekday(),
+        time::Weekday::Saturday | time::Weekday::Sunday
     ) {
-        return Err(translate_domain_error(
-            DomainError::CannotEditAreaAfterCanonicalization {
-                bid_year: active_bid_year.year(),
-                lifecycle_state: lifecycle_state_str,
-            },
-        ));
+        return Err(ApiError::InvalidInput {
+            field: String::from("date"),
+            message: format!("Blackout date {date} falls on a weekend, when no bidding occurs"),
+        });
     }
 
-    let command = Command::SetExpectedUserCount {
-        area: area.clone(),
-        expected_count: request.expected_count,
-    };
+    if reason.trim().is_empty() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("reason"),
+            message: String::from("Blackout date reason cannot be empty"),
+        });
+    }
 
-    let actor: Actor = authenticated_actor.to_audit_actor(operator);
-    let result: BootstrapResult =
-        apply_bootstrap(metadata, &active_bid_year, command, actor, cause)
-            .map_err(translate_core_error)?;
+    Ok(parsed)
+}
 
-    // Persist the expected user count
-    persistence
-        .set_expected_user_count(&active_bid_year, area, request.expected_count as usize)
+/// Ensures a bid year's blackout dates may still be changed.
+///
+/// Blackout dates shape bid windows, so they follow the bid schedule and
+/// are locked once the bid year is confirmed.
+///
+/// # Errors
+///
+/// Returns an error if the lifecycle state cannot be read or is locked.
+fn require_blackout_dates_editable(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<(), ApiError> {
+    let lifecycle_state: BidYearLifecycle = persistence
+        .get_lifecycle_state(bid_year_id)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to set expected user count: {e}"),
+            message: format!("Failed to get lifecycle state: {e}"),
+        })
+        .and_then(|s| {
+            s.parse::<BidYearLifecycle>()
+                .map_err(translate_domain_error)
         })?;
 
-    // Persist audit event
+    if lifecycle_state.is_locked() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("lifecycle_state"),
+            message: format!(
+                "Cannot modify blackout dates: bid year is in {lifecycle_state} state"
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Loads a bid year's blackout dates as parsed dates for window generation.
+///
+/// # Errors
+///
+/// Returns an error if the blackout dates cannot be read or parsed.
+fn load_blackout_dates(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<Vec<time::Date>, ApiError> {
     persistence
-        .persist_audit_event(&result.audit_event)
+        .list_blackout_dates(bid_year_id)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to persist audit event: {e}"),
-        })?;
-
-    Ok(SetExpectedUserCountResponse {
-        bid_year_id,
-        bid_year: active_bid_year.year(),
-        area_id: request.area_id,
-        area_code: area.area_code().to_string(),
-        expected_count: request.expected_count,
-        message: format!(
-            "Expected user count set to {} for area '{}' in bid year {}",
-            request.expected_count,
-            area.area_code(),
-            active_bid_year.year()
-        ),
-    })
+            message: format!("Failed to list blackout dates: {e}"),
+        })?
+        .iter()
+        .map(|blackout| {
+            time::Date::parse(
+                &blackout.blackout_date,
+                &time::format_description::well_known::Iso8601::DEFAULT,
+            )
+            .map_err(|_| ApiError::Internal {
+                message: format!("Failed to parse blackout date: {}", blackout.blackout_date),
+            })
+        })
+        .collect()
 }
 
-/// Updates an existing user's information.
-#[allow(dead_code)]
+/// Creates a blackout date for a bid year.
 ///
-/// Only admins can update users.
+/// No bid windows are laid out on a blackout date; bidders scheduled on or
+/// after it shift to the next bidding day.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
 /// * `metadata` - The current bootstrap metadata
-/// * `state` - The current system state
-/// * `request` - The update user request
-/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `request` - The create blackout date request
+/// * `authenticated_actor` - The authenticated operator
 /// * `operator` - The operator data
-/// * `cause` - The cause or reason for this action
+/// * `cause` - The cause of this action
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The actor is not authorized (not an Admin)
-/// - The user does not exist
-/// - Validation fails
+/// - The operator is not an admin
+/// - The bid year does not exist or is in a locked lifecycle state
+/// - The date is invalid, on a weekend, or already blacked out
 /// - Database operations fail
-pub fn update_user(
+pub fn create_blackout_date(
     persistence: &mut SqlitePersistence,
     metadata: &BootstrapMetadata,
-    state: &State,
-    request: &UpdateUserRequest,
+    request: &CreateBlackoutDateRequest,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
     cause: Cause,
-) -> Result<ApiResult<UpdateUserResponse>, ApiError> {
-    // Enforce authorization - only admins can update users
-    AuthorizationService::authorize_register_user(authenticated_actor)?;
-
-    // Resolve the active bid year from canonical state
-    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+) -> Result<BlackoutDateResponse, ApiError> {
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("create blackout date"),
+            required_role: String::from("Admin"),
+        });
+    }
 
-    // Resolve area_id to Area from metadata
-    let area: &Area = metadata
-        .areas
+    let year: u16 = metadata
+        .bid_years
         .iter()
-        .filter(|(by, _)| by.year() == active_bid_year.year())
-        .find(|(_, a)| a.area_id() == Some(request.area_id))
-        .map(|(_, a)| a)
+        .find(|by| by.bid_year_id() == Some(request.bid_year_id))
+        .map(BidYear::year)
         .ok_or_else(|| ApiError::ResourceNotFound {
-            resource_type: String::from("Area"),
-            message: format!(
-                "Area with ID {} not found in active bid year",
-                request.area_id
-            ),
+            resource_type: String::from("BidYear"),
+            message: format!("Bid year with ID {} not found", request.bid_year_id),
         })?;
 
-    // Translate API request into domain types
-    let initials: Initials = Initials::new(&request.initials);
-    let user_type: UserType =
-        UserType::parse(&request.user_type).map_err(translate_domain_error)?;
-    let crew: Option<Crew> = match request.crew {
-        Some(crew_num) => Some(Crew::new(crew_num).map_err(translate_domain_error)?),
-        None => None,
-    };
-    let seniority_data: SeniorityData = SeniorityData::new(
-        request.cumulative_natca_bu_date.clone(),
-        request.natca_bu_date.clone(),
-        request.eod_faa_date.clone(),
-        request.service_computation_date.clone(),
-        request.lottery_value,
-    );
-
-    // Create command
-    let command = Command::UpdateUser {
-        user_id: request.user_id,
-        initials: initials.clone(),
-        name: request.name.clone(),
-        area: area.clone(),
-        user_type,
-        crew,
-        seniority_data,
-    };
-
-    // Convert authenticated actor to audit actor
-    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    require_blackout_dates_editable(persistence, request.bid_year_id)?;
+    validate_blackout_date(&request.date, &request.reason)?;
 
-    // Apply the command
-    let result: TransitionResult = apply(metadata, state, &active_bid_year, command, actor, cause)
-        .map_err(translate_core_error)?;
+    let exists: bool = persistence
+        .blackout_date_exists(request.bid_year_id, &request.date, None)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to check blackout date: {e}"),
+        })?;
+    if exists {
+        return Err(ApiError::InvalidInput {
+            field: String::from("date"),
+            message: format!(
+                "{} is already a blackout date in bid year {year}",
+                request.date
+            ),
+        });
+    }
 
-    // Persist the updated canonical user state using user_id from request
-    persistence
-        .update_user(
-            request.user_id,
-            &initials,
-            &request.name,
-            area,
-            &request.user_type,
-            request.crew,
-            &request.cumulative_natca_bu_date,
-            &request.natca_bu_date,
-            &request.eod_faa_date,
-            &request.service_computation_date,
-            request.lottery_value,
-        )
+    let blackout_date_id: i64 = persistence
+        .insert_blackout_date(request.bid_year_id, &request.date, request.reason.trim())
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to update user: {e}"),
+            message: format!("Failed to create blackout date: {e}"),
         })?;
 
-    // Persist audit event
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("CreateBlackoutDate"),
+        Some(format!(
+            "Blacked out {} in bid year {year}: {}",
+            request.date,
+            request.reason.trim()
+        )),
+    );
+    let before: StateSnapshot = StateSnapshot::new(String::from("null"));
+    let after: StateSnapshot = StateSnapshot::new(format!(
+        "blackout_date_id={blackout_date_id},date={}",
+        request.date
+    ));
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+
     persistence
-        .persist_audit_event(&result.audit_event)
+        .persist_audit_event(&audit_event)
         .map_err(|e| ApiError::Internal {
             message: format!("Failed to persist audit event: {e}"),
         })?;
 
-    // Extract bid_year_id from metadata
-    let bid_year_id: i64 = metadata
-        .bid_years
-        .iter()
-        .find(|by| by.year() == active_bid_year.year())
-        .and_then(zab_bid_domain::BidYear::bid_year_id)
-        .ok_or_else(|| ApiError::Internal {
-            message: format!(
-                "Bid year {} exists but has no ID in metadata",
-                active_bid_year.year()
-            ),
-        })?;
-
-    // Build response
-    let response = UpdateUserResponse {
-        bid_year_id,
-        bid_year: active_bid_year.year(),
-        user_id: request.user_id,
-        initials: request.initials.clone(),
-        name: request.name.clone(),
-        message: String::from("User updated successfully"),
-    };
-
-    Ok(ApiResult {
-        response,
-        audit_event: result.audit_event,
-        new_state: result.new_state,
+    Ok(BlackoutDateResponse {
+        bid_year_id: request.bid_year_id,
+        blackout_date: BlackoutDateInfo {
+            blackout_date_id,
+            date: request.date.clone(),
+            reason: request.reason.trim().to_string(),
+        },
+        message: format!("Blackout date {} created", request.date),
     })
 }
 
-/// Gets the bootstrap completeness status for all bid years and areas.
-#[allow(dead_code)]
-///
-/// This function computes whether each bid year and area meets its
-/// expected counts and returns detailed blocking reasons.
-///
-/// # Arguments
-///
-/// * `persistence` - The persistence layer
-/// * `metadata` - The current bootstrap metadata
+/// Lists a bid year's blackout dates, ordered by date.
 ///
 /// # Errors
 ///
-/// Returns an error if database operations fail.
-#[allow(clippy::too_many_lines)]
-pub fn get_bootstrap_completeness(
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn list_blackout_dates(
     persistence: &mut SqlitePersistence,
     metadata: &BootstrapMetadata,
-) -> Result<GetBootstrapCompletenessResponse, ApiError> {
-    let active_bid_year: Option<u16> = persistence.get_active_bid_year().ok();
+    bid_year_id: i64,
+) -> Result<ListBlackoutDatesResponse, ApiError> {
+    if !metadata
+        .bid_years
+        .iter()
+        .any(|by| by.bid_year_id() == Some(bid_year_id))
+    {
+        return Err(ApiError::ResourceNotFound {
+            resource_type: String::from("BidYear"),
+            message: format!("Bid year with ID {bid_year_id} not found"),
+        });
+    }
+
+    let blackout_dates: Vec<BlackoutDateInfo> = persistence
+        .list_blackout_dates(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list blackout dates: {e}"),
+        })?
+        .into_iter()
+        .map(blackout_date_info)
+        .collect();
+
+    Ok(ListBlackoutDatesResponse {
+        bid_year_id,
+        blackout_dates,
+    })
+}
+
+/// Looks up a blackout date, mapping a missing record to `ResourceNotFound`.
+///
+/// # Errors
+///
+/// Returns an error if the blackout date does not exist or the query fails.
+fn get_blackout_date_or_not_found(
+    persistence: &mut SqlitePersistence,
+    blackout_date_id: i64,
+) -> Result<BlackoutDateData, ApiError> {
+    persistence
+        .get_blackout_date(blackout_date_id)
+        .map_err(|e| match e {
+            PersistenceError::NotFound(_) => ApiError::ResourceNotFound {
+                resource_type: String::from("BlackoutDate"),
+                message: format!("Blackout date {blackout_date_id} not found"),
+            },
+            _ => ApiError::Internal {
+                message: format!("Failed to get blackout date: {e}"),
+            },
+        })
+}
+
+/// Updates the date and reason of a blackout date.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `blackout_date_id` - The blackout date to update
+/// * `request` - The update blackout date request
+/// * `authenticated_actor` - The authenticated operator
+/// * `operator` - The operator data
+/// * `cause` - The cause of this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The operator is not an admin
+/// - The blackout date does not exist
+/// - The bid year is in a locked lifecycle state
+/// - The new date is invalid, on a weekend, or already blacked out
+/// - Database operations fail
+pub fn update_blackout_date(
+    persistence: &mut SqlitePersistence,
+    blackout_date_id: i64,
+    request: &UpdateBlackoutDateRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<BlackoutDateResponse, ApiError> {
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("update blackout date"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    let existing: BlackoutDateData = get_blackout_date_or_not_found(persistence, blackout_date_id)?;
+    require_blackout_dates_editable(persistence, existing.bid_year_id)?;
+    validate_blackout_date(&request.date, &request.reason)?;
+
+    let exists: bool = persistence
+        .blackout_date_exists(existing.bid_year_id, &request.date, Some(blackout_date_id))
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to check blackout date: {e}"),
+        })?;
+    if exists {
+        return Err(ApiError::InvalidInput {
+            field: String::from("date"),
+            message: format!("{} is already a blackout date", request.date),
+        });
+    }
+
+    persistence
+        .update_blackout_date(blackout_date_id, &request.date, request.reason.trim())
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to update blackout date: {e}"),
+        })?;
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("UpdateBlackoutDate"),
+        Some(format!(
+            "Moved blackout date {} to {}: {}",
+            existing.blackout_date,
+            request.date,
+            request.reason.trim()
+        )),
+    );
+    let before: StateSnapshot = StateSnapshot::new(format!(
+        "blackout_date_id={blackout_date_id},date={}",
+        existing.blackout_date
+    ));
+    let after: StateSnapshot = StateSnapshot::new(format!(
+        "blackout_date_id={blackout_date_id},date={}",
+        request.date
+    ));
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(BlackoutDateResponse {
+        bid_year_id: existing.bid_year_id,
+        blackout_date: BlackoutDateInfo {
+            blackout_date_id,
+            date: request.date.clone(),
+            reason: request.reason.trim().to_string(),
+        },
+        message: format!("Blackout date {} updated", request.date),
+    })
+}
+
+/// Deletes a blackout date.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The operator is not an admin
+/// - The blackout date does not exist
+/// - The bid year is in a locked lifecycle state
+/// - Database operations fail
+pub fn delete_blackout_date(
+    persistence: &mut SqlitePersistence,
+    blackout_date_id: i64,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<DeleteBlackoutDateResponse, ApiError> {
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("delete blackout date"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    let existing: BlackoutDateData = get_blackout_date_or_not_found(persistence, blackout_date_id)?;
+    require_blackout_dates_editable(persistence, existing.bid_year_id)?;
+
+    persistence
+        .delete_blackout_date(blackout_date_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to delete blackout date: {e}"),
+        })?;
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("DeleteBlackoutDate"),
+        Some(format!(
+            "Removed blackout date {} ({})",
+            existing.blackout_date, existing.reason
+        )),
+    );
+    let before: StateSnapshot = StateSnapshot::new(format!(
+        "blackout_date_id={blackout_date_id},date={}",
+        existing.blackout_date
+    ));
+    let after: StateSnapshot = StateSnapshot::new(String::from("null"));
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(DeleteBlackoutDateResponse {
+        blackout_date_id,
+        message: format!("Blackout date {} deleted", existing.blackout_date),
+    })
+}
+
+/// Gets the currently active bid year.
+#[allow(dead_code)]
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+///
+/// # Errors
+///
+/// Returns an error if database operations fail.
+pub fn get_active_bid_year(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+) -> Result<GetActiveBidYearResponse, ApiError> {
+    let year: u16 = persistence
+        .get_active_bid_year()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get active bid year: {e}"),
+        })?;
+
+    // Extract bid_year_id if there is an active year
+    let bid_year_id: Option<i64> = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.year() == year)
+        .and_then(zab_bid_domain::BidYear::bid_year_id);
+
+    Ok(GetActiveBidYearResponse {
+        bid_year_id,
+        year: Some(year),
+    })
+}
+
+/// Sets the expected area count for a bid year.
+#[allow(dead_code)]
+///
+/// Only admins can set expected area counts.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The current bootstrap metadata
+/// * `request` - The set expected area count request
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data
+/// * `cause` - The cause or reason for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not authorized (not an Admin)
+/// - The bid year does not exist
+/// - The expected count is zero
+/// - Database operations fail
+pub fn set_expected_area_count(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetExpectedAreaCountRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetExpectedAreaCountResponse, ApiError> {
+    // Enforce authorization - only admins can set expected counts
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("set_expected_area_count"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    // Resolve the active bid year from canonical state
+    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+
+    let command = Command::SetExpectedAreaCount {
+        expected_count: request.expected_count,
+    };
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let result: BootstrapResult =
+        apply_bootstrap(metadata, &active_bid_year, command, actor, cause)
+            .map_err(translate_core_error)?;
+
+    // Persist the expected area count
+    persistence
+        .set_expected_area_count(&active_bid_year, request.expected_count as usize)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to set expected area count: {e}"),
+        })?;
+
+    // Persist audit event
+    persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    // Extract bid_year_id from metadata
+    let bid_year_id: i64 = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.year() == active_bid_year.year())
+        .and_then(zab_bid_domain::BidYear::bid_year_id)
+        .ok_or_else(|| ApiError::Internal {
+            message: format!(
+                "Bid year {} exists but has no ID in metadata",
+                active_bid_year.year()
+            ),
+        })?;
+
+    Ok(SetExpectedAreaCountResponse {
+        bid_year_id,
+        bid_year: active_bid_year.year(),
+        expected_count: request.expected_count,
+        message: format!(
+            "Expected area count set to {} for bid year {}",
+            request.expected_count,
+            active_bid_year.year()
+        ),
+    })
+}
+
+/// Sets the expected user count for an area.
+#[allow(dead_code)]
+///
+/// Only admins can set expected user counts.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The current bootstrap metadata
+/// * `request` - The set expected user count request
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data
+/// * `cause` - The cause or reason for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not authorized (not an Admin)
+/// - The bid year or area does not exist
+/// - The expected count is zero
+/// - Database operations fail
+pub fn set_expected_user_count(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetExpectedUserCountRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetExpectedUserCountResponse, ApiError> {
+    // Enforce authorization - only admins can set expected counts
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("set_expected_user_count"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    // Resolve the active bid year from canonical state
+    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+
+    // Resolve area_id to Area from metadata
+    let area: &Area = metadata
+        .areas
+        .iter()
+        .filter(|(by, _)| by.year() == active_bid_year.year())
+        .find(|(_, a)| a.area_id() == Some(request.area_id))
+        .map(|(_, a)| a)
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("Area"),
+            message: format!(
+                "Area with ID {} not found in active bid year",
+                request.area_id
+            ),
+        })?;
+
+    // Check if this is a system area (No Bid should not have expected count)
+    let is_system =
+        persistence
+            .is_system_area(request.area_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to check system area status: {e}"),
+            })?;
+
+    if is_system {
+        return Err(ApiError::InvalidInput {
+            field: String::from("area_id"),
+            message: format!(
+                "Cannot set expected user count for system area '{}'",
+                area.area_code()
+            ),
+        });
+    }
+
+    // Get bid_year_id for lifecycle check
+    let bid_year_id = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.year() == active_bid_year.year())
+        .and_then(zab_bid_domain::BidYear::bid_year_id)
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Bid year {} has no ID", active_bid_year.year()),
+        })?;
+
+    // Check lifecycle state - reject if >= Canonicalized
+    let lifecycle_state_str =
+        persistence
+            .get_lifecycle_state(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get lifecycle state: {e}"),
+            })?;
+
+    let lifecycle_state = zab_bid_domain::BidYearLifecycle::from_str(&lifecycle_state_str)
+        .map_err(|_| ApiError::Internal {
+            message: format!("Invalid lifecycle state: {lifecycle_state_str}"),
+        })?;
+
+    // Expected user count can only be set before canonicalization
+    if matches!(
+        lifecycle_state,
+        zab_bid_domain::BidYearLifecycle::Canonicalized
+            | zab_bid_domain::BidYearLifecycle::BiddingActive
+            | zab_bid_domain::BidYearLifecycle::BiddingClosed
+    ) {
+        return Err(translate_domain_error(
+            DomainError::CannotEditAreaAfterCanonicalization {
+                bid_year: active_bid_year.year(),
+                lifecycle_state: lifecycle_state_str,
+            },
+        ));
+    }
+
+    let command = Command::SetExpectedUserCount {
+        area: area.clone(),
+        expected_count: request.expected_count,
+    };
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let result: BootstrapResult =
+        apply_bootstrap(metadata, &active_bid_year, command, actor, cause)
+            .map_err(translate_core_error)?;
+
+    // Persist the expected user count
+    persistence
+        .set_expected_user_count(&active_bid_year, area, request.expected_count as usize)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to set expected user count: {e}"),
+        })?;
+
+    // Persist audit event
+    persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetExpectedUserCountResponse {
+        bid_year_id,
+        bid_year: active_bid_year.year(),
+        area_id: request.area_id,
+        area_code: area.area_code().to_string(),
+        expected_count: request.expected_count,
+        message: format!(
+            "Expected user count set to {} for area '{}' in bid year {}",
+            request.expected_count,
+            area.area_code(),
+            active_bid_year.year()
+        ),
+    })
+}
+
+/// Updates an existing user's information.
+#[allow(dead_code)]
+///
+/// Only admins can update users.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The current bootstrap metadata
+/// * `state` - The current system state
+/// * `request` - The update user request
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data
+/// * `cause` - The cause or reason for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not authorized (not an Admin)
+/// - The user does not exist
+/// - Validation fails
+/// - Database operations fail
+pub fn update_user(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    state: &State,
+    request: &UpdateUserRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<ApiResult<UpdateUserResponse>, ApiError> {
+    // Enforce authorization - only admins can update users
+    AuthorizationService::authorize_register_user(authenticated_actor)?;
+
+    // Convert authenticated actor to audit actor
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+
+    apply_update_user(persistence, metadata, state, request, actor, cause)
+}
+
+/// Applies a user update as the given audit actor, without authorization.
+///
+/// Callers authorize the change first; roster sync uses this to apply
+/// approved directory changes as the `system` actor.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The user does not exist
+/// - Validation fails
+/// - Database operations fail
+pub fn apply_update_user(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    state: &State,
+    request: &UpdateUserRequest,
+    actor: Actor,
+    cause: Cause,
+) -> Result<ApiResult<UpdateUserResponse>, ApiError> {
+    // Resolve the active bid year from canonical state
+    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+
+    // Resolve area_id to Area from metadata
+    let area: &Area = metadata
+        .areas
+        .iter()
+        .filter(|(by, _)| by.year() == active_bid_year.year())
+        .find(|(_, a)| a.area_id() == Some(request.area_id))
+        .map(|(_, a)| a)
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("Area"),
+            message: format!(
+                "Area with ID {} not found in active bid year",
+                request.area_id
+            ),
+        })?;
+
+    // Translate API request into domain types
+    let initials: Initials = Initials::new(&request.initials);
+    let user_type: UserType =
+        UserType::parse(&request.user_type).map_err(translate_domain_error)?;
+    let crew: Option<Crew> = match request.crew {
+        Some(crew_num) => Some(Crew::new(crew_num).map_err(translate_domain_error)?),
+        None => None,
+    };
+    let seniority_data: SeniorityData = SeniorityData::new(
+        request.cumulative_natca_bu_date.clone(),
+        request.natca_bu_date.clone(),
+        request.eod_faa_date.clone(),
+        request.service_computation_date.clone(),
+        request.lottery_value,
+    );
+
+    // Create command
+    let command = Command::UpdateUser {
+        user_id: request.user_id,
+        initials: initials.clone(),
+        name: request.name.clone(),
+        area: area.clone(),
+        user_type,
+        crew,
+        seniority_data,
+    };
+
+    // Apply the command
+    let result: TransitionResult = apply(metadata, state, &active_bid_year, command, actor, cause)
+        .map_err(translate_core_error)?;
+
+    // Persist the updated canonical user state using user_id from request
+    persistence
+        .update_user(
+            request.user_id,
+            &initials,
+            &request.name,
+            area,
+            &request.user_type,
+            request.crew,
+            &request.cumulative_natca_bu_date,
+            &request.natca_bu_date,
+            &request.eod_faa_date,
+            &request.service_computation_date,
+            request.lottery_value,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to update user: {e}"),
+        })?;
+
+    // Persist audit event
+    persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    // Extract bid_year_id from metadata
+    let bid_year_id: i64 = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.year() == active_bid_year.year())
+        .and_then(zab_bid_domain::BidYear::bid_year_id)
+        .ok_or_else(|| ApiError::Internal {
+            message: format!(
+                "Bid year {} exists but has no ID in metadata",
+                active_bid_year.year()
+            ),
+        })?;
+
+    // A user's type and area feed their derived eligibility
+    refresh_derived_eligibility(persistence, bid_year_id)?;
+
+    // Build response
+    let response = UpdateUserResponse {
+        bid_year_id,
+        bid_year: active_bid_year.year(),
+        user_id: request.user_id,
+        initials: request.initials.clone(),
+        name: request.name.clone(),
+        message: String::from("User updated successfully"),
+    };
+
+    Ok(ApiResult {
+        response,
+        audit_event: result.audit_event,
+        new_state: result.new_state,
+    })
+}
+
+/// Gets the bootstrap completeness status for all bid years and areas.
+#[allow(dead_code)]
+///
+/// This function computes whether each bid year and area meets its
+/// expected counts and returns detailed blocking reasons.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The current bootstrap metadata
+///
+/// # Errors
+///
+/// Returns an error if database operations fail.
+#[allow(clippy::too_many_lines)]
+pub fn get_bootstrap_completeness(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+) -> Result<GetBootstrapCompletenessResponse, ApiError> {
+    let active_bid_year: Option<u16> = persistence.get_active_bid_year().ok();
+
+    // Extract active_bid_year_id if there is an active year
+    let active_bid_year_id: Option<i64> = active_bid_year.and_then(|y| {
+        metadata
+            .bid_years
+            .iter()
+            .find(|by| by.year() == y)
+            .and_then(zab_bid_domain::BidYear::bid_year_id)
+    });
+
+    let mut bid_years_info: Vec<BidYearCompletenessInfo> = Vec::new();
+    let mut areas_info: Vec<AreaCompletenessInfo> = Vec::new();
+    let mut top_level_blocking: Vec<BlockingReason> = Vec::new();
+
+    // If no active bid year, that's a top-level blocker
+    if active_bid_year.is_none() {
+        top_level_blocking.push(BlockingReason::NoActiveBidYear);
+    }
+
+    // Phase 25E: Check for users in No Bid area across all bid years
+    for bid_year in &metadata.bid_years {
+        let year: u16 = bid_year.year();
+        let bid_year_id: i64 = match bid_year.bid_year_id() {
+            Some(id) => id,
+            None => continue, // Skip bid years without IDs
+        };
+
+        let users_in_no_bid: usize = persistence
+            .count_users_in_system_area(bid_year_id)
+            .unwrap_or(0);
+
+        if users_in_no_bid > 0 {
+            let sample_initials: Vec<String> = persistence
+                .list_users_in_system_area(bid_year_id, 5)
+                .unwrap_or_default();
+
+            top_level_blocking.push(BlockingReason::UsersInNoBidArea {
+                bid_year_id,
+                bid_year: year,
+                user_count: users_in_no_bid,
+                sample_initials,
+            });
+        }
+    }
+
+    // Check each bid year
+    for bid_year in &metadata.bid_years {
+        let year: u16 = bid_year.year();
+        let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+            message: format!("Bid year {year} has no ID in metadata"),
+        })?;
+        let is_active: bool = active_bid_year == Some(year);
+
+        let expected_area_count: Option<u32> = persistence
+            .get_expected_area_count(&BidYear::new(year))
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get expected area count: {e}"),
+            })?
+            .map(|v| {
+                u32::try_from(v).unwrap_or_else(|_| {
+                    tracing::warn!("Expected area count out of range: {}", v);
+                    u32::MAX
+                })
+            });
+
+        let actual_area_count: usize = persistence
+            .get_actual_area_count(&BidYear::new(year))
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get actual area count: {e}"),
+            })?;
+
+        let mut blocking_reasons: Vec<BlockingReason> = Vec::new();
+
+        // Check if expected count is set
+        let expected_count = expected_area_count.unwrap_or_else(|| {
+            blocking_reasons.push(BlockingReason::ExpectedAreaCountNotSet {
+                bid_year_id,
+                bid_year: year,
+            });
+            0 // Placeholder
+        });
+
+        // Check if actual matches expected
+        if expected_area_count.is_some() && actual_area_count != expected_count as usize {
+            blocking_reasons.push(BlockingReason::AreaCountMismatch {
+                bid_year_id,
+                bid_year: year,
+                expected: expected_count,
+                actual: actual_area_count,
+            });
+        }
+
+        let is_complete: bool = blocking_reasons.is_empty() && expected_area_count.is_some();
+
+        // Fetch lifecycle state
+        let lifecycle_state: String =
+            persistence
+                .get_lifecycle_state(bid_year_id)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to get lifecycle state: {e}"),
+                })?;
+
+        bid_years_info.push(BidYearCompletenessInfo {
+            bid_year_id,
+            year,
+            is_active,
+            expected_area_count,
+            actual_area_count,
+            is_complete,
+            blocking_reasons,
+            lifecycle_state,
+        });
+    }
+
+    // Check each area
+    for (bid_year, area) in &metadata.areas {
+        let year: u16 = bid_year.year();
+        let bid_year_id: i64 = metadata
+            .bid_years
+            .iter()
+            .find(|by| by.year() == year)
+            .and_then(zab_bid_domain::BidYear::bid_year_id)
+            .ok_or_else(|| ApiError::Internal {
+                message: format!("Bid year {year} has no ID in metadata"),
+            })?;
+        let area_code: String = area.area_code().to_string();
+        let area_id: i64 = area.area_id().ok_or_else(|| ApiError::Internal {
+            message: format!("Area '{area_code}' in bid year {year} has no ID in metadata"),
+        })?;
+
+        let expected_user_count: Option<u32> = persistence
+            .get_expected_user_count(bid_year, area)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get expected user count: {e}"),
+            })?
+            .map(|v| {
+                u32::try_from(v).unwrap_or_else(|_| {
+                    tracing::warn!("Expected user count out of range: {}", v);
+                    u32::MAX
+                })
+            });
+
+        let actual_user_count: usize =
+            persistence
+                .get_actual_user_count(bid_year, area)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to get actual user count: {e}"),
+                })?;
+
+        let mut blocking_reasons: Vec<BlockingReason> = Vec::new();
+
+        // Check if expected count is set
+        let expected_count = expected_user_count.unwrap_or_else(|| {
+            blocking_reasons.push(BlockingReason::ExpectedUserCountNotSet {
+                bid_year_id,
+                bid_year: year,
+                area_id,
+                area_code: area_code.clone(),
+            });
+            0 // Placeholder
+        });
+
+        // Check if actual matches expected
+        if expected_user_count.is_some() && actual_user_count != expected_count as usize {
+            blocking_reasons.push(BlockingReason::UserCountMismatch {
+                bid_year_id,
+                bid_year: year,
+                area_id,
+                area_code: area_code.clone(),
+                expected: expected_count,
+                actual: actual_user_count,
+            });
+        }
+
+        let is_complete: bool = blocking_reasons.is_empty() && expected_user_count.is_some();
+
+        areas_info.push(AreaCompletenessInfo {
+            bid_year_id,
+            bid_year: year,
+            area_id,
+            area_code,
+            expected_user_count,
+            actual_user_count,
+            is_complete,
+            blocking_reasons,
+        });
+    }
+
+    // Determine if system is ready for bidding
+    // System is ready only when there are NO blocking reasons at any level
+    let is_ready_for_bidding: bool = top_level_blocking.is_empty()
+        && bid_years_info.iter().all(|b| b.blocking_reasons.is_empty())
+        && areas_info.iter().all(|a| a.blocking_reasons.is_empty());
+
+    Ok(GetBootstrapCompletenessResponse {
+        active_bid_year_id,
+        active_bid_year,
+        bid_years: bid_years_info,
+        areas: areas_info,
+        is_ready_for_bidding,
+        blocking_reasons: top_level_blocking,
+    })
+}
+
+/// Previews and validates CSV user data without persisting.
+///
+/// This handler:
+/// - Accepts CSV content and a bid year
+/// - Parses and validates each row
+/// - Returns structured preview results
+/// - Does NOT mutate state or emit audit events
+///
+/// # Arguments
+///
+/// * `metadata` - The current bootstrap metadata
+/// * `persistence` - The persistence layer for querying existing users
+/// * `request` - The preview request containing bid year and CSV content
+/// * `authenticated_actor` - The authenticated actor making the request
+///
+/// # Returns
+///
+/// * `Ok(PreviewCsvUsersResponse)` with per-row validation results
+/// * `Err(ApiError)` if unauthorized or CSV format is invalid
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an admin
+/// - The bid year does not exist
+/// - The CSV format is invalid
+pub fn preview_csv_users(
+    metadata: &BootstrapMetadata,
+    persistence: &mut SqlitePersistence,
+    request: &PreviewCsvUsersRequest,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<PreviewCsvUsersResponse, ApiError> {
+    // Enforce authorization - only admins can preview CSV imports
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("preview_csv_users"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    // Resolve the active bid year from canonical state
+    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+
+    // Validate bid year exists
+    validate_bid_year_exists(metadata, &active_bid_year).map_err(translate_domain_error)?;
+
+    // Perform CSV preview validation
+    let preview_result = preview_csv_users_impl(
+        &request.csv_content,
+        &active_bid_year,
+        metadata,
+        persistence,
+    )?;
+
+    // Convert internal result to API response
+    let rows: Vec<CsvRowPreview> = preview_result
+        .rows
+        .into_iter()
+        .map(|r: CsvRowResult| CsvRowPreview {
+            row_number: r.row_number,
+            initials: r.initials,
+            name: r.name,
+            area_id: r.area_id,
+            user_type: r.user_type,
+            crew: r.crew,
+            status: match r.status {
+                crate::csv_preview::CsvRowStatus::Valid => CsvRowStatus::Valid,
+                crate::csv_preview::CsvRowStatus::Invalid => CsvRowStatus::Invalid,
+            },
+            errors: r.errors,
+        })
+        .collect();
+
+    Ok(PreviewCsvUsersResponse {
+        bid_year: active_bid_year.year(),
+        rows,
+        total_rows: preview_result.total_rows,
+        valid_count: preview_result.valid_count,
+        invalid_count: preview_result.invalid_count,
+    })
+}
+
+/// Imports selected CSV rows as users.
+///
+/// This function:
+/// - Verifies the actor is authorized (Admin role required)
+/// - Re-parses each selected CSV row
+/// - Attempts to create each user individually
+/// - Returns per-row success/failure results
+/// - Does NOT roll back on failure
+///
+/// An automatic checkpoint labeled `auto-before-csv-import` is recorded in
+/// every area of the bid year before any row is imported.
+///
+/// # Arguments
+///
+/// * `metadata` - The current bootstrap metadata
+/// * `state` - The current system state
+/// * `persistence` - The persistence layer
+/// * `request` - The API request containing CSV content and selected row indices
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit trail
+/// * `cause` - The cause or reason for this action
+///
+/// # Returns
+///
+/// * `Ok((ImportCsvUsersResponse, Vec<AuditEvent>, State))` on completion
+/// * `Err(ApiError)` if unauthorized or CSV parsing fails
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not authorized (not an Admin)
+/// - The CSV cannot be parsed
+/// - The bid year does not exist
+/// - An automatic checkpoint cannot be recorded
+///
+/// Individual row failures are captured in the response, not as errors.
+#[allow(clippy::too_many_lines)]
+pub fn import_csv_users(
+    metadata: &BootstrapMetadata,
+    _state: &State,
+    persistence: &mut SqlitePersistence,
+    request: &ImportCsvUsersRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: &Cause,
+) -> Result<ImportCsvUsersResponse, ApiError> {
+    // Enforce authorization - only admins can import users
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("import_csv_users"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    // Resolve the active bid year from canonical state
+    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+
+    // Validate bid year exists
+    validate_bid_year_exists(metadata, &active_bid_year).map_err(translate_domain_error)?;
+
+    // Convert authenticated actor to audit actor
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+
+    // Parse CSV and collect all rows first
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .flexible(false)
+        .from_reader(request.csv_content.as_bytes());
+
+    let headers = reader
+        .headers()
+        .map_err(|e| ApiError::InvalidCsvFormat {
+            reason: format!("Failed to read CSV headers: {e}"),
+        })?
+        .clone();
+
+    // Build header map for field extraction
+    let mut header_map: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    for (idx, header) in headers.iter().enumerate() {
+        let normalized = header.trim().to_lowercase().replace(' ', "_");
+        header_map.insert(normalized, idx);
+    }
+
+    // Collect all records into a vec so we can index into them
+    let all_records: Vec<csv::StringRecord> = reader
+        .records()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| ApiError::InvalidCsvFormat {
+            reason: format!("Failed to read CSV records: {e}"),
+        })?;
+
+    let checkpoints: AutoCheckpoints = record_bid_year_auto_checkpoints(
+        persistence,
+        metadata,
+        active_bid_year.year(),
+        "csv-import",
+        &actor,
+        cause,
+    )?;
+    let mut event_ids: Vec<i64> = Vec::new();
+
+    let total_selected: usize = request.selected_row_indices.len();
+    let mut successful_count: usize = 0;
+    let mut failed_count: usize = 0;
+    let mut results: Vec<CsvImportRowResult> = Vec::new();
+
+    // Process each selected row
+    for &row_index in &request.selected_row_indices {
+        let row_number: usize = row_index + 1;
+
+        // Check if row index is valid
+        if row_index >= all_records.len() {
+            results.push(CsvImportRowResult {
+                row_index,
+                row_number,
+                initials: None,
+                status: CsvImportRowStatus::Failed,
+                error: Some(String::from("Row index out of bounds")),
+            });
+            failed_count += 1;
+            continue;
+        }
+
+        let record = &all_records[row_index];
+
+        // Extract fields using header map
+        let get_field = |name: &str| -> Option<String> {
+            header_map
+                .get(name)
+                .and_then(|&idx| record.get(idx))
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        };
+
+        // Extract required fields
+        let Some(initials_str) = get_field("initials") else {
+            results.push(CsvImportRowResult {
+                row_index,
+                row_number,
+                initials: None,
+                status: CsvImportRowStatus::Failed,
+                error: Some(String::from("Missing initials")),
+            });
+            failed_count += 1;
+            continue;
+        };
+
+        let Some(name) = get_field("name") else {
+            results.push(CsvImportRowResult {
+                row_index,
+                row_number,
+                initials: Some(initials_str.clone()),
+                status: CsvImportRowStatus::Failed,
+                error: Some(String::from("Missing name")),
+            });
+            failed_count += 1;
+            continue;
+        };
+
+        let Some(area_str) = get_field("area_id") else {
+            results.push(CsvImportRowResult {
+                row_index,
+                row_number,
+                initials: Some(initials_str.clone()),
+                status: CsvImportRowStatus::Failed,
+                error: Some(String::from("Missing area_id")),
+            });
+            failed_count += 1;
+            continue;
+        };
+
+        let Some(user_type_str) = get_field("user_type") else {
+            results.push(CsvImportRowResult {
+                row_index,
+                row_number,
+                initials: Some(initials_str.clone()),
+                status: CsvImportRowStatus::Failed,
+                error: Some(String::from("Missing user_type")),
+            });
+            failed_count += 1;
+            continue;
+        };
+
+        let Some(crew_str) = get_field("crew") else {
+            results.push(CsvImportRowResult {
+                row_index,
+                row_number,
+                initials: Some(initials_str.clone()),
+                status: CsvImportRowStatus::Failed,
+                error: Some(String::from("Missing crew")),
+            });
+            failed_count += 1;
+            continue;
+        };
+
+        let Some(service_computation_date) = get_field("service_computation_date") else {
+            results.push(CsvImportRowResult {
+                row_index,
+                row_number,
+                initials: Some(initials_str.clone()),
+                status: CsvImportRowStatus::Failed,
+                error: Some(String::from("Missing service_computation_date")),
+            });
+            failed_count += 1;
+            continue;
+        };
+
+        let Some(eod_faa_date) = get_field("eod_faa_date").or_else(|| get_field("eod_date")) else {
+            results.push(CsvImportRowResult {
+                row_index,
+                row_number,
+                initials: Some(initials_str.clone()),
+                status: CsvImportRowStatus::Failed,
+                error: Some(String::from("Missing eod_faa_date or eod_date")),
+            });
+            failed_count += 1;
+            continue;
+        };
+
+        // Parse crew
+        let Ok(crew_num) = crew_str.parse::<u8>() else {
+            results.push(CsvImportRowResult {
+                row_index,
+                row_number,
+                initials: Some(initials_str.clone()),
+                status: CsvImportRowStatus::Failed,
+                error: Some(format!("Invalid crew number: {crew_str}")),
+            });
+            failed_count += 1;
+            continue;
+        };
+
+        // Optional fields
+        let cumulative_natca_bu_date = get_field("cumulative_natca_bu_date").unwrap_or_default();
+        let natca_bu_date = get_field("natca_bu_date").unwrap_or_default();
+        let lottery_value = get_field("lottery_value").and_then(|v| v.parse().ok());
+
+        // Parse domain types
+        let initials = Initials::new(&initials_str);
+        let area = Area::new(&area_str);
+
+        let user_type = match UserType::parse(&user_type_str).map_err(translate_domain_error) {
+            Ok(ut) => ut,
+            Err(e) => {
+                results.push(CsvImportRowResult {
+                    row_index,
+                    row_number,
+                    initials: Some(initials_str.clone()),
+                    status: CsvImportRowStatus::Failed,
+                    error: Some(format!("Invalid user type: {e}")),
+                });
+                failed_count += 1;
+                continue;
+            }
+        };
+
+        let crew = match Crew::new(crew_num).map_err(translate_domain_error) {
+            Ok(c) => Some(c),
+            Err(e) => {
+                results.push(CsvImportRowResult {
+                    row_index,
+                    row_number,
+                    initials: Some(initials_str.clone()),
+                    status: CsvImportRowStatus::Failed,
+                    error: Some(format!("Invalid crew: {e}")),
+                });
+                failed_count += 1;
+                continue;
+            }
+        };
+
+        let seniority_data = SeniorityData::new(
+            cumulative_natca_bu_date,
+            natca_bu_date,
+            eod_faa_date,
+            service_computation_date,
+            lottery_value,
+        );
+
+        // Load current state for this user's area from the database
+        // This ensures duplicate detection works correctly across areas
+        let area_state: State = persistence
+            .get_current_state(&active_bid_year, &area)
+            .unwrap_or_else(|_| State::new(active_bid_year.clone(), area.clone()));
+
+        // Create the command
+        let command = Command::RegisterUser {
+            initials: initials.clone(),
+            name: name.clone(),
+            area: area.clone(),
+            user_type,
+            crew,
+            seniority_data,
+        };
+
+        // Attempt to apply the command
+        match apply(
+            metadata,
+            &area_state,
+            &active_bid_year,
+            command,
+            actor.clone(),
+            cause.clone(),
+        )
+        .map_err(translate_core_error)
+        {
+            Ok(transition_result) => {
+                // Persist immediately to ensure subsequent rows see this user
+                match persistence.persist_transition(&transition_result) {
+                    Ok(persisted) => event_ids.push(persisted.event_id),
+                    Err(persist_err) => {
+                        results.push(CsvImportRowResult {
+                            row_index,
+                            row_number,
+                            initials: Some(initials.value().to_string()),
+                            status: CsvImportRowStatus::Failed,
+                            error: Some(format!("Failed to persist: {persist_err}")),
+                        });
+                        failed_count += 1;
+                        continue;
+                    }
+                }
+
+                // Success
+                results.push(CsvImportRowResult {
+                    row_index,
+                    row_number,
+                    initials: Some(initials.value().to_string()),
+                    status: CsvImportRowStatus::Success,
+                    error: None,
+                });
+                successful_count += 1;
+            }
+            Err(e) => {
+                // Failure
+                results.push(CsvImportRowResult {
+                    row_index,
+                    row_number,
+                    initials: Some(initials.value().to_string()),
+                    status: CsvImportRowStatus::Failed,
+                    error: Some(format!("{e}")),
+                });
+                failed_count += 1;
+            }
+        }
+    }
+
+    record_operation_events(persistence, &event_ids, &checkpoints.correlation_id)?;
+
+    let response = ImportCsvUsersResponse {
+        bid_year: active_bid_year.year(),
+        total_selected,
+        successful_count,
+        failed_count,
+        results,
+        correlation_id: checkpoints.correlation_id,
+    };
+
+    Ok(response)
+}
+
+/// Override a user's area assignment after canonicalization.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The override request
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data
+///
+/// # Returns
+///
+/// Returns the audit event ID on success.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an admin
+/// - The lifecycle state is not >= Canonicalized
+/// - The override reason is invalid
+/// - The target area is a system area
+/// - The canonical record does not exist
+#[allow(clippy::too_many_lines)]
+#[allow(dead_code)]
+pub fn override_area_assignment(
+    persistence: &mut SqlitePersistence,
+    request: &OverrideAreaAssignmentRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<OverrideAreaAssignmentResponse, ApiError> {
+    // Enforce authorization - only admins can perform overrides
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("override_area_assignment"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    // Validate override reason (min 10 chars)
+    let reason = request.reason.trim();
+    if reason.len() < 10 {
+        return Err(translate_domain_error(DomainError::InvalidOverrideReason {
+            reason: request.reason.clone(),
+        }));
+    }
+
+    // Get user details
+    let (bid_year_id, user_initials): (i64, String) = persistence
+        .get_user_details(request.user_id)
+        .map_err(|_| ApiError::ResourceNotFound {
+            resource_type: String::from("User"),
+            message: format!("User with ID {} not found", request.user_id),
+        })?;
+
+    // Check lifecycle state >= Canonicalized
+    let lifecycle_state =
+        persistence
+            .get_lifecycle_state(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get lifecycle state: {e}"),
+            })?;
+
+    if !matches!(
+        lifecycle_state.as_str(),
+        "Canonicalized" | "BiddingActive" | "BiddingClosed"
+    ) {
+        return Err(translate_domain_error(
+            DomainError::CannotOverrideBeforeCanonicalization {
+                current_state: lifecycle_state,
+            },
+        ));
+    }
+
+    // Verify target area exists and is not a system area
+    let (area_code, area_name): (String, Option<String>) = persistence
+        .get_area_details(request.new_area_id)
+        .map_err(|_| ApiError::ResourceNotFound {
+            resource_type: String::from("Area"),
+            message: format!("Area with ID {} not found", request.new_area_id),
+        })?;
+
+    // Check if target area is a system area
+    let is_system = persistence
+        .is_system_area(request.new_area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to check system area: {e}"),
+        })?;
+
+    if is_system {
+        return Err(translate_domain_error(
+            DomainError::CannotAssignToSystemArea { area_code },
+        ));
+    }
+
+    // A frozen area can neither lose the user nor gain them
+    require_user_area_not_frozen(persistence, bid_year_id, request.user_id)?;
+    require_not_frozen(persistence, bid_year_id, Some(request.new_area_id))?;
+
+    // Get previous area info for audit event
+    let previous_area_id: i64 = persistence
+        .get_current_area_assignment(bid_year_id, request.user_id)
+        .map_err(|_| {
+            translate_domain_error(DomainError::CanonicalRecordNotFound {
+                description: format!(
+                    "Canonical area membership not found for user_id={}",
+                    request.user_id
+                ),
+            })
+        })?;
+
+    let (prev_area_code, prev_area_name): (String, Option<String>) = persistence
+        .get_area_details(previous_area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to fetch previous area info: {e}"),
+        })?;
+
+    // Perform override
+    let (_, was_already_overridden) = persistence
+        .override_area_assignment(bid_year_id, request.user_id, request.new_area_id, reason)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to override area assignment: {e}"),
+        })?;
+
+    // Create and persist audit event
+    let actor = authenticated_actor.to_audit_actor(operator);
+    let cause = Cause::new(
+        String::from("override_area_assignment"),
+        format!("Override area assignment for user {user_initials}"),
+    );
+
+    let action = Action::new(
+        String::from("UserAreaAssignmentOverridden"),
+        Some(format!(
+            "user_id={}, previous_area={}, new_area={}, reason={}, was_overridden={}",
+            request.user_id,
+            prev_area_name.unwrap_or(prev_area_code),
+            area_name.unwrap_or(area_code),
+            reason,
+            was_already_overridden
+        )),
+    );
+
+    let before = StateSnapshot::new(format!("area_id={previous_area_id}"));
+    let after = StateSnapshot::new(format!("area_id={}", request.new_area_id));
+
+    let year = persistence
+        .get_bid_year_from_id(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get bid year: {e}"),
+        })?;
+    let bid_year = BidYear::new(year);
+    let area: Area = user_audit_area(persistence, request.user_id)?;
+
+    let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area)
+        .with_user_id(Some(request.user_id));
+
+    let event_id =
+        persistence
+            .persist_audit_event(&audit_event)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to persist audit event: {e}"),
+            })?;
+
+    let override_id: i64 = record_override(
+        persistence,
+        bid_year_id,
+        request.user_id,
+        &OverrideValue::AreaAssignment {
+            area_id: previous_area_id,
+        },
+        &OverrideValue::AreaAssignment {
+            area_id: request.new_area_id,
+        },
+        reason,
+        event_id,
+    )?;
+    refresh_derived_eligibility(persistence, bid_year_id)?;
+
+    Ok(OverrideAreaAssignmentResponse {
+        audit_event_id: event_id,
+        override_id,
+        message: format!(
+            "Area assignment overridden for user {user_initials} (audit event {event_id})"
+        ),
+    })
+}
+
+/// Override a user's eligibility after canonicalization.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The override request
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data
+///
+/// # Returns
+///
+/// Returns the audit event ID on success.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an admin
+/// - The lifecycle state is not >= Canonicalized
+/// - The override reason is invalid
+/// - The canonical record does not exist
+#[allow(dead_code)]
+pub fn override_eligibility(
+    persistence: &mut SqlitePersistence,
+    request: &OverrideEligibilityRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<OverrideEligibilityResponse, ApiError> {
+    // Enforce authorization - only admins can perform overrides
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("override_eligibility"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    // Validate override reason (min 10 chars)
+    let reason = request.reason.trim();
+    if reason.len() < 10 {
+        return Err(translate_domain_error(DomainError::InvalidOverrideReason {
+            reason: request.reason.clone(),
+        }));
+    }
+
+    // Get user details
+    let (bid_year_id, user_initials): (i64, String) =
+        persistence.get_user_details(request.user_id).map_err(|_| {
+            let user_id = request.user_id;
+            ApiError::ResourceNotFound {
+                resource_type: String::from("User"),
+                message: format!("User with ID {user_id} not found"),
+            }
+        })?;
+
+    // Check lifecycle state >= Canonicalized
+    let lifecycle_state =
+        persistence
+            .get_lifecycle_state(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get lifecycle state: {e}"),
+            })?;
+
+    if !matches!(
+        lifecycle_state.as_str(),
+        "Canonicalized" | "BiddingActive" | "BiddingClosed"
+    ) {
+        return Err(translate_domain_error(
+            DomainError::CannotOverrideBeforeCanonicalization {
+                current_state: lifecycle_state,
+            },
+        ));
+    }
+
+    require_user_area_not_frozen(persistence, bid_year_id, request.user_id)?;
+
+    // Perform override
+    let (previous_eligibility, was_already_overridden) = persistence
+        .override_eligibility(bid_year_id, request.user_id, request.can_bid, reason)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to override eligibility: {e}"),
+        })?;
+
+    // Create and persist audit event
+    let actor = authenticated_actor.to_audit_actor(operator);
+    let cause = Cause::new(
+        String::from("override_eligibility"),
+        format!("Override eligibility for user {user_initials}"),
+    );
+
+    let action = Action::new(
+        String::from("UserEligibilityOverridden"),
+        Some(format!(
+            "user_id={}, previous_eligibility={}, new_eligibility={}, reason={}, was_overridden={}",
+            request.user_id, previous_eligibility, request.can_bid, reason, was_already_overridden
+        )),
+    );
+
+    let before = StateSnapshot::new(format!("can_bid={previous_eligibility}"));
+    let after = StateSnapshot::new(format!("can_bid={}", request.can_bid));
+
+    let year = persistence
+        .get_bid_year_from_id(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get bid year: {e}"),
+        })?;
+    let bid_year = BidYear::new(year);
+    let area: Area = user_audit_area(persistence, request.user_id)?;
+
+    let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area)
+        .with_user_id(Some(request.user_id));
+
+    let event_id =
+        persistence
+            .persist_audit_event(&audit_event)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to persist audit event: {e}"),
+            })?;
+
+    let override_id: i64 = record_override(
+        persistence,
+        bid_year_id,
+        request.user_id,
+        &OverrideValue::Eligibility {
+            can_bid: previous_eligibility,
+        },
+        &OverrideValue::Eligibility {
+            can_bid: request.can_bid,
+        },
+        reason,
+        event_id,
+    )?;
+
+    Ok(OverrideEligibilityResponse {
+        audit_event_id: event_id,
+        override_id,
+        message: format!(
+            "Eligibility overridden for user {user_initials} (audit event {event_id})"
+        ),
+    })
+}
+
+/// Override a user's bid order after canonicalization.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The override request
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data
+///
+/// # Returns
+///
+/// Returns the audit event ID on success.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an admin
+/// - The lifecycle state is not >= Canonicalized
+/// - The override reason is invalid
+/// - The bid order is invalid (must be positive if provided)
+/// - The canonical record does not exist
+#[allow(dead_code)]
+pub fn override_bid_order(
+    persistence: &mut SqlitePersistence,
+    request: &OverrideBidOrderRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<OverrideBidOrderResponse, ApiError> {
+    // Enforce authorization - only admins can perform overrides
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("override_bid_order"),
+            required_role: String::from("Admin"),
+        });
+    }
 
-    // Extract active_bid_year_id if there is an active year
-    let active_bid_year_id: Option<i64> = active_bid_year.and_then(|y| {
-        metadata
-            .bid_years
-            .iter()
-            .find(|by| by.year() == y)
-            .and_then(zab_bid_domain::BidYear::bid_year_id)
-    });
+    // Validate override reason (min 10 chars)
+    let reason = request.reason.trim();
+    if reason.len() < 10 {
+        return Err(translate_domain_error(DomainError::InvalidOverrideReason {
+            reason: request.reason.clone(),
+        }));
+    }
 
-    let mut bid_years_info: Vec<BidYearCompletenessInfo> = Vec::new();
-    let mut areas_info: Vec<AreaCompletenessInfo> = Vec::new();
-    let mut top_level_blocking: Vec<BlockingReason> = Vec::new();
+    // Validate bid order if provided
+    if let Some(order) = request.bid_order
+        && order <= 0
+    {
+        return Err(translate_domain_error(DomainError::InvalidBidOrder {
+            reason: format!("Bid order must be positive (got: {order})"),
+        }));
+    }
 
-    // If no active bid year, that's a top-level blocker
-    if active_bid_year.is_none() {
-        top_level_blocking.push(BlockingReason::NoActiveBidYear);
+    // Get user details
+    let (bid_year_id, user_initials): (i64, String) =
+        persistence.get_user_details(request.user_id).map_err(|_| {
+            let user_id = request.user_id;
+            ApiError::ResourceNotFound {
+                resource_type: String::from("User"),
+                message: format!("User with ID {user_id} not found"),
+            }
+        })?;
+
+    // Check lifecycle state >= Canonicalized
+    let lifecycle_state =
+        persistence
+            .get_lifecycle_state(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get lifecycle state: {e}"),
+            })?;
+
+    if !matches!(
+        lifecycle_state.as_str(),
+        "Canonicalized" | "BiddingActive" | "BiddingClosed"
+    ) {
+        return Err(translate_domain_error(
+            DomainError::CannotOverrideBeforeCanonicalization {
+                current_state: lifecycle_state,
+            },
+        ));
     }
 
-    // Phase 25E: Check for users in No Bid area across all bid years
-    for bid_year in &metadata.bid_years {
-        let year: u16 = bid_year.year();
-        let bid_year_id: i64 = match bid_year.bid_year_id() {
-            Some(id) => id,
-            None => continue, // Skip bid years without IDs
-        };
+    require_user_area_not_frozen(persistence, bid_year_id, request.user_id)?;
 
-        let users_in_no_bid: usize = persistence
-            .count_users_in_system_area(bid_year_id)
-            .unwrap_or(0);
+    // Perform override
+    let (previous_bid_order, was_already_overridden) = persistence
+        .override_bid_order(bid_year_id, request.user_id, request.bid_order, reason)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to override bid order: {e}"),
+        })?;
 
-        if users_in_no_bid > 0 {
-            let sample_initials: Vec<String> = persistence
-                .list_users_in_system_area(bid_year_id, 5)
-                .unwrap_or_default();
+    // Create and persist audit event
+    let actor = authenticated_actor.to_audit_actor(operator);
+    let cause = Cause::new(
+        String::from("override_bid_order"),
+        format!("Override bid order for user {user_initials}"),
+    );
 
-            top_level_blocking.push(BlockingReason::UsersInNoBidArea {
-                bid_year_id,
-                bid_year: year,
-                user_count: users_in_no_bid,
-                sample_initials,
-            });
+    let action = Action::new(
+        String::from("UserBidOrderOverridden"),
+        Some(format!(
+            "user_id={}, previous_bid_order={:?}, new_bid_order={:?}, reason={}, was_overridden={}",
+            request.user_id, previous_bid_order, request.bid_order, reason, was_already_overridden
+        )),
+    );
+
+    let before = StateSnapshot::new(format!("bid_order={previous_bid_order:?}"));
+    let after = StateSnapshot::new(format!("bid_order={:?}", request.bid_order));
+
+    let year = persistence
+        .get_bid_year_from_id(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get bid year: {e}"),
+        })?;
+    let bid_year = BidYear::new(year);
+    let area: Area = user_audit_area(persistence, request.user_id)?;
+
+    let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area)
+        .with_user_id(Some(request.user_id));
+
+    let event_id =
+        persistence
+            .persist_audit_event(&audit_event)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to persist audit event: {e}"),
+            })?;
+
+    let override_id: i64 = record_override(
+        persistence,
+        bid_year_id,
+        request.user_id,
+        &OverrideValue::BidOrder {
+            bid_order: previous_bid_order,
+        },
+        &OverrideValue::BidOrder {
+            bid_order: request.bid_order,
+        },
+        reason,
+        event_id,
+    )?;
+
+    Ok(OverrideBidOrderResponse {
+        audit_event_id: event_id,
+        override_id,
+        message: format!("Bid order overridden for user {user_initials} (audit event {event_id})"),
+    })
+}
+
+/// Override a user's bid window after canonicalization.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The override request
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data
+///
+/// # Returns
+///
+/// Returns the audit event ID on success.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an admin
+/// - The lifecycle state is not >= Canonicalized
+/// - The override reason is invalid
+/// - The bid window dates are invalid (start > end, partial window)
+/// - The canonical record does not exist
+#[allow(clippy::too_many_lines)]
+#[allow(dead_code)]
+pub fn override_bid_window(
+    persistence: &mut SqlitePersistence,
+    request: &OverrideBidWindowRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<OverrideBidWindowResponse, ApiError> {
+    // Enforce authorization - only admins can perform overrides
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("override_bid_window"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    // Validate override reason (min 10 chars)
+    let reason = request.reason.trim();
+    if reason.len() < 10 {
+        return Err(translate_domain_error(DomainError::InvalidOverrideReason {
+            reason: request.reason.clone(),
+        }));
+    }
+
+    // Validate bid window - both must be present or both must be None
+    match (&request.window_start, &request.window_end) {
+        (Some(start), Some(end)) => {
+            // Parse dates to validate format and ordering
+            let start_date = time::Date::parse(
+                start,
+                time::macros::format_description!("[year]-[month]-[day]"),
+            )
+            .map_err(|e| {
+                translate_domain_error(DomainError::DateParseError {
+                    date_string: start.clone(),
+                    error: e.to_string(),
+                })
+            })?;
+            let end_date = time::Date::parse(
+                end,
+                time::macros::format_description!("[year]-[month]-[day]"),
+            )
+            .map_err(|e| {
+                translate_domain_error(DomainError::DateParseError {
+                    date_string: end.clone(),
+                    error: e.to_string(),
+                })
+            })?;
+
+            if start_date > end_date {
+                return Err(translate_domain_error(DomainError::InvalidBidWindow {
+                    reason: format!("Window start date ({start}) must be <= end date ({end})"),
+                }));
+            }
+        }
+        (None, None) => {
+            // Both None is valid (clears the window)
+        }
+        _ => {
+            return Err(translate_domain_error(DomainError::InvalidBidWindow {
+                reason: String::from(
+                    "Both window_start and window_end must be provided or both must be null",
+                ),
+            }));
         }
     }
 
-    // Check each bid year
-    for bid_year in &metadata.bid_years {
-        let year: u16 = bid_year.year();
-        let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
-            message: format!("Bid year {year} has no ID in metadata"),
+    // Get user details
+    let (bid_year_id, user_initials): (i64, String) =
+        persistence.get_user_details(request.user_id).map_err(|_| {
+            let user_id = request.user_id;
+            ApiError::ResourceNotFound {
+                resource_type: String::from("User"),
+                message: format!("User with ID {user_id} not found"),
+            }
         })?;
-        let is_active: bool = active_bid_year == Some(year);
-
-        let expected_area_count: Option<u32> = persistence
-            .get_expected_area_count(&BidYear::new(year))
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to get expected area count: {e}"),
-            })?
-            .map(|v| {
-                u32::try_from(v).unwrap_or_else(|_| {
-                    tracing::warn!("Expected area count out of range: {}", v);
-                    u32::MAX
-                })
-            });
 
-        let actual_area_count: usize = persistence
-            .get_actual_area_count(&BidYear::new(year))
+    // Check lifecycle state >= Canonicalized
+    let lifecycle_state =
+        persistence
+            .get_lifecycle_state(bid_year_id)
             .map_err(|e| ApiError::Internal {
-                message: format!("Failed to get actual area count: {e}"),
+                message: format!("Failed to get lifecycle state: {e}"),
             })?;
 
-        let mut blocking_reasons: Vec<BlockingReason> = Vec::new();
+    if !matches!(
+        lifecycle_state.as_str(),
+        "Canonicalized" | "BiddingActive" | "BiddingClosed"
+    ) {
+        return Err(translate_domain_error(
+            DomainError::CannotOverrideBeforeCanonicalization {
+                current_state: lifecycle_state,
+            },
+        ));
+    }
 
-        // Check if expected count is set
-        let expected_count = expected_area_count.unwrap_or_else(|| {
-            blocking_reasons.push(BlockingReason::ExpectedAreaCountNotSet {
-                bid_year_id,
-                bid_year: year,
-            });
-            0 // Placeholder
-        });
+    require_user_area_not_frozen(persistence, bid_year_id, request.user_id)?;
 
-        // Check if actual matches expected
-        if expected_area_count.is_some() && actual_area_count != expected_count as usize {
-            blocking_reasons.push(BlockingReason::AreaCountMismatch {
-                bid_year_id,
-                bid_year: year,
-                expected: expected_count,
-                actual: actual_area_count,
-            });
-        }
+    // Perform override
+    let (previous_start, previous_end, was_already_overridden) = persistence
+        .override_bid_window(
+            bid_year_id,
+            request.user_id,
+            request.window_start.as_ref(),
+            request.window_end.as_ref(),
+            reason,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to override bid window: {e}"),
+        })?;
 
-        let is_complete: bool = blocking_reasons.is_empty() && expected_area_count.is_some();
+    // Create and persist audit event
+    let actor = authenticated_actor.to_audit_actor(operator);
+    let cause = Cause::new(
+        String::from("override_bid_window"),
+        format!("Override bid window for user {user_initials}"),
+    );
 
-        // Fetch lifecycle state
-        let lifecycle_state: String =
-            persistence
-                .get_lifecycle_state(bid_year_id)
-                .map_err(|e| ApiError::Internal {
-                    message: format!("Failed to get lifecycle state: {e}"),
-                })?;
+    let action = Action::new(
+        String::from("UserBidWindowOverridden"),
+        Some(format!(
+            "user_id={}, previous_start={:?}, previous_end={:?}, new_start={:?}, new_end={:?}, reason={}, was_overridden={}",
+            request.user_id,
+            previous_start,
+            previous_end,
+            request.window_start,
+            request.window_end,
+            reason,
+            was_already_overridden
+        )),
+    );
 
-        bid_years_info.push(BidYearCompletenessInfo {
-            bid_year_id,
-            year,
-            is_active,
-            expected_area_count,
-            actual_area_count,
-            is_complete,
-            blocking_reasons,
-            lifecycle_state,
-        });
-    }
+    let before = StateSnapshot::new(format!(
+        "window_start={previous_start:?}, window_end={previous_end:?}"
+    ));
+    let after = StateSnapshot::new(format!(
+        "window_start={:?}, window_end={:?}",
+        request.window_start, request.window_end
+    ));
 
-    // Check each area
-    for (bid_year, area) in &metadata.areas {
-        let year: u16 = bid_year.year();
-        let bid_year_id: i64 = metadata
-            .bid_years
-            .iter()
-            .find(|by| by.year() == year)
-            .and_then(zab_bid_domain::BidYear::bid_year_id)
-            .ok_or_else(|| ApiError::Internal {
-                message: format!("Bid year {year} has no ID in metadata"),
-            })?;
-        let area_code: String = area.area_code().to_string();
-        let area_id: i64 = area.area_id().ok_or_else(|| ApiError::Internal {
-            message: format!("Area '{area_code}' in bid year {year} has no ID in metadata"),
+    let year = persistence
+        .get_bid_year_from_id(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get bid year: {e}"),
         })?;
+    let bid_year = BidYear::new(year);
+    let area: Area = user_audit_area(persistence, request.user_id)?;
 
-        let expected_user_count: Option<u32> = persistence
-            .get_expected_user_count(bid_year, area)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to get expected user count: {e}"),
-            })?
-            .map(|v| {
-                u32::try_from(v).unwrap_or_else(|_| {
-                    tracing::warn!("Expected user count out of range: {}", v);
-                    u32::MAX
-                })
-            });
-
-        let actual_user_count: usize =
-            persistence
-                .get_actual_user_count(bid_year, area)
-                .map_err(|e| ApiError::Internal {
-                    message: format!("Failed to get actual user count: {e}"),
-                })?;
-
-        let mut blocking_reasons: Vec<BlockingReason> = Vec::new();
+    let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area)
+        .with_user_id(Some(request.user_id));
 
-        // Check if expected count is set
-        let expected_count = expected_user_count.unwrap_or_else(|| {
-            blocking_reasons.push(BlockingReason::ExpectedUserCountNotSet {
-                bid_year_id,
-                bid_year: year,
-                area_id,
-                area_code: area_code.clone(),
-            });
-            0 // Placeholder
-        });
+    let event_id =
+        persistence
+            .persist_audit_event(&audit_event)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to persist audit event: {e}"),
+            })?;
 
-        // Check if actual matches expected
-        if expected_user_count.is_some() && actual_user_count != expected_count as usize {
-            blocking_reasons.push(BlockingReason::UserCountMismatch {
-                bid_year_id,
-                bid_year: year,
-                area_id,
-                area_code: area_code.clone(),
-                expected: expected_count,
-                actual: actual_user_count,
-            });
-        }
+    let override_id: i64 = record_override(
+        persistence,
+        bid_year_id,
+        request.user_id,
+        &OverrideValue::BidWindow {
+            window_start: previous_start,
+            window_end: previous_end,
+        },
+        &OverrideValue::BidWindow {
+            window_start: request.window_start.clone(),
+            window_end: request.window_end.clone(),
+        },
+        reason,
+        event_id,
+    )?;
 
-        let is_complete: bool = blocking_reasons.is_empty() && expected_user_count.is_some();
+    Ok(OverrideBidWindowResponse {
+        audit_event_id: event_id,
+        override_id,
+        message: format!("Bid window overridden for user {user_initials} (audit event {event_id})"),
+    })
+}
 
-        areas_info.push(AreaCompletenessInfo {
+/// Records an applied override in the override ledger.
+fn record_override(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    user_id: i64,
+    previous_value: &OverrideValue,
+    new_value: &OverrideValue,
+    reason: &str,
+    audit_event_id: i64,
+) -> Result<i64, ApiError> {
+    persistence
+        .record_override(
             bid_year_id,
-            bid_year: year,
-            area_id,
-            area_code,
-            expected_user_count,
-            actual_user_count,
-            is_complete,
-            blocking_reasons,
-        });
-    }
+            user_id,
+            previous_value,
+            new_value,
+            reason,
+            audit_event_id,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record override: {e}"),
+        })
+}
 
-    // Determine if system is ready for bidding
-    // System is ready only when there are NO blocking reasons at any level
-    let is_ready_for_bidding: bool = top_level_blocking.is_empty()
-        && bid_years_info.iter().all(|b| b.blocking_reasons.is_empty())
-        && areas_info.iter().all(|a| a.blocking_reasons.is_empty());
+/// Refuses an override for a user whose area is frozen.
+fn require_user_area_not_frozen(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    user_id: i64,
+) -> Result<(), ApiError> {
+    let area_id: i64 = persistence
+        .get_user_area_id(user_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to fetch user area: {e}"),
+        })?;
+    require_not_frozen(persistence, bid_year_id, Some(area_id))
+}
 
-    Ok(GetBootstrapCompletenessResponse {
-        active_bid_year_id,
-        active_bid_year,
-        bid_years: bid_years_info,
-        areas: areas_info,
-        is_ready_for_bidding,
-        blocking_reasons: top_level_blocking,
+/// Returns the area a user is currently assigned to, for scoping override
+/// audit events.
+fn user_audit_area(persistence: &mut SqlitePersistence, user_id: i64) -> Result<Area, ApiError> {
+    let area_id: i64 = persistence
+        .get_user_area_id(user_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to fetch user area: {e}"),
+        })?;
+    let (area_code, _area_name): (String, Option<String>) = persistence
+        .get_area_details(area_id)
+        .map_err(|e| ApiError::Internal {
+        message: format!("Failed to fetch area info: {e}"),
+    })?;
+    Ok(Area::new(&area_code))
+}
+
+/// Renders an override value for listings and audit details.
+fn describe_override_value(
+    persistence: &mut SqlitePersistence,
+    value: &OverrideValue,
+) -> Result<String, ApiError> {
+    Ok(match value {
+        OverrideValue::AreaAssignment { area_id } => {
+            let (area_code, _area_name): (String, Option<String>) = persistence
+                .get_area_details(*area_id)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to fetch area info: {e}"),
+                })?;
+            format!("area={area_code}")
+        }
+        OverrideValue::Eligibility { can_bid } => format!("can_bid={can_bid}"),
+        OverrideValue::BidOrder { bid_order } => format!("bid_order={bid_order:?}"),
+        OverrideValue::BidWindow {
+            window_start,
+            window_end,
+        } => format!("window_start={window_start:?}, window_end={window_end:?}"),
     })
 }
 
-/// Previews and validates CSV user data without persisting.
-///
-/// This handler:
-/// - Accepts CSV content and a bid year
-/// - Parses and validates each row
-/// - Returns structured preview results
-/// - Does NOT mutate state or emit audit events
-///
-/// # Arguments
-///
-/// * `metadata` - The current bootstrap metadata
-/// * `persistence` - The persistence layer for querying existing users
-/// * `request` - The preview request containing bid year and CSV content
-/// * `authenticated_actor` - The authenticated actor making the request
+/// Returns the ID of a newer unreverted override that supersedes `record`.
+fn find_superseding_override(
+    overrides: &[CanonicalOverrideData],
+    record: &CanonicalOverrideData,
+) -> Option<i64> {
+    overrides
+        .iter()
+        .filter(|other| {
+            other.user_id == record.user_id
+                && other.override_kind == record.override_kind
+                && other.override_id > record.override_id
+                && !other.is_reverted
+        })
+        .map(|other| other.override_id)
+        .max()
+}
+
+/// Lists every override applied to a bid year's canonical data.
 ///
-/// # Returns
+/// Overrides are returned oldest first, including reverted ones. An override
+/// is revertible only while it is the newest unreverted override of its kind
+/// for the user.
 ///
-/// * `Ok(PreviewCsvUsersResponse)` with per-row validation results
-/// * `Err(ApiError)` if unauthorized or CSV format is invalid
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `bid_year_id` - The canonical bid year ID
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The actor is not an admin
 /// - The bid year does not exist
-/// - The CSV format is invalid
-pub fn preview_csv_users(
-    metadata: &BootstrapMetadata,
+/// - Database queries fail
+pub fn list_overrides(
     persistence: &mut SqlitePersistence,
-    request: &PreviewCsvUsersRequest,
-    authenticated_actor: &AuthenticatedActor,
-) -> Result<PreviewCsvUsersResponse, ApiError> {
-    // Enforce authorization - only admins can preview CSV imports
-    if authenticated_actor.role != Role::Admin {
-        return Err(ApiError::Unauthorized {
-            action: String::from("preview_csv_users"),
-            required_role: String::from("Admin"),
-        });
-    }
-
-    // Resolve the active bid year from canonical state
-    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+    bid_year_id: i64,
+) -> Result<ListOverridesResponse, ApiError> {
+    let year: u16 =
+        persistence
+            .get_bid_year_from_id(bid_year_id)
+            .map_err(|_| ApiError::ResourceNotFound {
+                resource_type: String::from("BidYear"),
+                message: format!("Bid year with ID {bid_year_id} not found"),
+            })?;
 
-    // Validate bid year exists
-    validate_bid_year_exists(metadata, &active_bid_year).map_err(translate_domain_error)?;
+    let records: Vec<CanonicalOverrideData> =
+        persistence
+            .list_overrides(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list overrides: {e}"),
+            })?;
 
-    // Perform CSV preview validation
-    let preview_result = preview_csv_users_impl(
-        &request.csv_content,
-        &active_bid_year,
-        metadata,
-        persistence,
-    )?;
+    let mut overrides: Vec<OverrideInfo> = Vec::with_capacity(records.len());
+    for record in &records {
+        let (_, user_initials): (i64, String) = persistence
+            .get_user_details(record.user_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to fetch user info: {e}"),
+            })?;
 
-    // Convert internal result to API response
-    let rows: Vec<CsvRowPreview> = preview_result
-        .rows
-        .into_iter()
-        .map(|r: CsvRowResult| CsvRowPreview {
-            row_number: r.row_number,
-            initials: r.initials,
-            name: r.name,
-            area_id: r.area_id,
-            user_type: r.user_type,
-            crew: r.crew,
-            status: match r.status {
-                crate::csv_preview::CsvRowStatus::Valid => CsvRowStatus::Valid,
-                crate::csv_preview::CsvRowStatus::Invalid => CsvRowStatus::Invalid,
-            },
-            errors: r.errors,
-        })
-        .collect();
+        overrides.push(OverrideInfo {
+            override_id: record.override_id,
+            user_id: record.user_id,
+            user_initials,
+            override_kind: record.override_kind.clone(),
+            previous_value: describe_override_value(persistence, &record.previous_value)?,
+            new_value: describe_override_value(persistence, &record.new_value)?,
+            reason: record.reason.clone(),
+            audit_event_id: record.audit_event_id,
+            is_reverted: record.is_reverted,
+            is_revertible: !record.is_reverted
+                && find_superseding_override(&records, record).is_none(),
+            created_at: record.created_at.clone(),
+        });
+    }
 
-    Ok(PreviewCsvUsersResponse {
-        bid_year: active_bid_year.year(),
-        rows,
-        total_rows: preview_result.total_rows,
-        valid_count: preview_result.valid_count,
-        invalid_count: preview_result.invalid_count,
+    Ok(ListOverridesResponse {
+        bid_year_id,
+        year,
+        overrides,
     })
 }
 
-/// Imports selected CSV rows as users.
+/// Reverts an override, restoring the canonical value it replaced.
 ///
-/// This function:
-/// - Verifies the actor is authorized (Admin role required)
-/// - Re-parses each selected CSV row
-/// - Attempts to create each user individually
-/// - Returns per-row success/failure results
-/// - Does NOT roll back on failure
+/// Overrides of the same kind for a user stack, so only the newest
+/// unreverted one can be reverted. The revert is audited with the reason
+/// that was stored when the override was applied.
 ///
 /// # Arguments
 ///
-/// * `metadata` - The current bootstrap metadata
-/// * `state` - The current system state
 /// * `persistence` - The persistence layer
-/// * `request` - The API request containing CSV content and selected row indices
-/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `override_id` - The override to revert
+/// * `authenticated_actor` - The authenticated actor performing the revert
 /// * `operator` - The operator data for audit trail
-/// * `cause` - The cause or reason for this action
-///
-/// # Returns
-///
-/// * `Ok((ImportCsvUsersResponse, Vec<AuditEvent>, State))` on completion
-/// * `Err(ApiError)` if unauthorized or CSV parsing fails
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The actor is not authorized (not an Admin)
-/// - The CSV cannot be parsed
-/// - The bid year does not exist
-///
-/// Individual row failures are captured in the response, not as errors.
-#[allow(clippy::too_many_lines)]
-pub fn import_csv_users(
-    metadata: &BootstrapMetadata,
-    _state: &State,
+/// - The actor is not an admin
+/// - The override does not exist
+/// - The override has already been reverted
+/// - A newer override of the same kind is still in effect for the user
+/// - Database operations fail
+pub fn revert_override(
     persistence: &mut SqlitePersistence,
-    request: &ImportCsvUsersRequest,
-    authenticated_actor: &AuthenticatedActor,
-    operator: &OperatorData,
-    cause: &Cause,
-) -> Result<ImportCsvUsersResponse, ApiError> {
-    // Enforce authorization - only admins can import users
-    if authenticated_actor.role != Role::Admin {
-        return Err(ApiError::Unauthorized {
-            action: String::from("import_csv_users"),
-            required_role: String::from("Admin"),
-        });
-    }
-
-    // Resolve the active bid year from canonical state
-    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
-
-    // Validate bid year exists
-    validate_bid_year_exists(metadata, &active_bid_year).map_err(translate_domain_error)?;
-
-    // Convert authenticated actor to audit actor
-    let actor: Actor = authenticated_actor.to_audit_actor(operator);
-
-    // Parse CSV and collect all rows first
-    let mut reader = csv::ReaderBuilder::new()
-        .has_headers(true)
-        .flexible(false)
-        .from_reader(request.csv_content.as_bytes());
-
-    let headers = reader
-        .headers()
-        .map_err(|e| ApiError::InvalidCsvFormat {
-            reason: format!("Failed to read CSV headers: {e}"),
-        })?
-        .clone();
-
-    // Build header map for field extraction
-    let mut header_map: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
-    for (idx, header) in headers.iter().enumerate() {
-        let normalized = header.trim().to_lowercase().replace(' ', "_");
-        header_map.insert(normalized, idx);
-    }
-
-    // Collect all records into a vec so we can index into them
-    let all_records: Vec<csv::StringRecord> = reader
-        .records()
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| ApiError::InvalidCsvFormat {
-            reason: format!("Failed to read CSV records: {e}"),
-        })?;
-
-    let total_selected: usize = request.selected_row_indices.len();
-    let mut successful_count: usize = 0;
-    let mut failed_count: usize = 0;
-    let mut results: Vec<CsvImportRowResult> = Vec::new();
-
-    // Process each selected row
-    for &row_index in &request.selected_row_indices {
-        let row_number: usize = row_index + 1;
-
-        // Check if row index is valid
-        if row_index >= all_records.len() {
-            results.push(CsvImportRowResult {
-                row_index,
-                row_number,
-                initials: None,
-                status: CsvImportRowStatus::Failed,
-                error: Some(String::from("Row index out of bounds")),
-            });
-            failed_count += 1;
-            continue;
-        }
-
-        let record = &all_records[row_index];
-
-        // Extract fields using header map
-        let get_field = |name: &str| -> Option<String> {
-            header_map
-                .get(name)
-                .and_then(|&idx| record.get(idx))
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-        };
-
-        // Extract required fields
-        let Some(initials_str) = get_field("initials") else {
-            results.push(CsvImportRowResult {
-                row_index,
-                row_number,
-                initials: None,
-                status: CsvImportRowStatus::Failed,
-                error: Some(String::from("Missing initials")),
-            });
-            failed_count += 1;
-            continue;
-        };
-
-        let Some(name) = get_field("name") else {
-            results.push(CsvImportRowResult {
-                row_index,
-                row_number,
-                initials: Some(initials_str.clone()),
-                status: CsvImportRowStatus::Failed,
-                error: Some(String::from("Missing name")),
-            });
-            failed_count += 1;
-            continue;
-        };
-
-        let Some(area_str) = get_field("area_id") else {
-            results.push(CsvImportRowResult {
-                row_index,
-                row_number,
-                initials: Some(initials_str.clone()),
-                status: CsvImportRowStatus::Failed,
-                error: Some(String::from("Missing area_id")),
-            });
-            failed_count += 1;
-            continue;
-        };
-
-        let Some(user_type_str) = get_field("user_type") else {
-            results.push(CsvImportRowResult {
-                row_index,
-                row_number,
-                initials: Some(initials_str.clone()),
-                status: CsvImportRowStatus::Failed,
-                error: Some(String::from("Missing user_type")),
-            });
-            failed_count += 1;
-            continue;
-        };
-
-        let Some(crew_str) = get_field("crew") else {
-            results.push(CsvImportRowResult {
-                row_index,
-                row_number,
-                initials: Some(initials_str.clone()),
-                status: CsvImportRowStatus::Failed,
-                error: Some(String::from("Missing crew")),
-            });
-            failed_count += 1;
-            continue;
-        };
-
-        let Some(service_computation_date) = get_field("service_computation_date") else {
-            results.push(CsvImportRowResult {
-                row_index,
-                row_number,
-                initials: Some(initials_str.clone()),
-                status: CsvImportRowStatus::Failed,
-                error: Some(String::from("Missing service_computation_date")),
-            });
-            failed_count += 1;
-            continue;
-        };
+    override_id: i64,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<RevertOverrideResponse, ApiError> {
+    // Enforce authorization - only admins can revert overrides
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("revert_override"),
+            required_role: String::from("Admin"),
+        });
+    }
 
-        let Some(eod_faa_date) = get_field("eod_faa_date").or_else(|| get_field("eod_date")) else {
-            results.push(CsvImportRowResult {
-                row_index,
-                row_number,
-                initials: Some(initials_str.clone()),
-                status: CsvImportRowStatus::Failed,
-                error: Some(String::from("Missing eod_faa_date or eod_date")),
-            });
-            failed_count += 1;
-            continue;
-        };
+    let record: CanonicalOverrideData =
+        persistence.get_override(override_id).map_err(|e| match e {
+            PersistenceError::NotFound(_) => ApiError::ResourceNotFound {
+                resource_type: String::from("Override"),
+                message: format!("Override with ID {override_id} not found"),
+            },
+            other => ApiError::Internal {
+                message: format!("Failed to get override: {other}"),
+            },
+        })?;
 
-        // Parse crew
-        let Ok(crew_num) = crew_str.parse::<u8>() else {
-            results.push(CsvImportRowResult {
-                row_index,
-                row_number,
-                initials: Some(initials_str.clone()),
-                status: CsvImportRowStatus::Failed,
-                error: Some(format!("Invalid crew number: {crew_str}")),
-            });
-            failed_count += 1;
-            continue;
-        };
+    if record.is_reverted {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("override_already_reverted"),
+            message: format!("Override {override_id} has already been reverted"),
+        });
+    }
 
-        // Optional fields
-        let cumulative_natca_bu_date = get_field("cumulative_natca_bu_date").unwrap_or_default();
-        let natca_bu_date = get_field("natca_bu_date").unwrap_or_default();
-        let lottery_value = get_field("lottery_value").and_then(|v| v.parse().ok());
+    let overrides: Vec<CanonicalOverrideData> = persistence
+        .list_overrides(record.bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list overrides: {e}"),
+        })?;
+    if let Some(newer_id) = find_superseding_override(&overrides, &record) {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("override_superseded"),
+            message: format!(
+                "Override {override_id} is superseded by override {newer_id}, which must be reverted first"
+            ),
+        });
+    }
 
-        // Parse domain types
-        let initials = Initials::new(&initials_str);
-        let area = Area::new(&area_str);
+    // Reverting an area assignment returns the user to their previous area
+    require_user_area_not_frozen(persistence, record.bid_year_id, record.user_id)?;
+    if let OverrideValue::AreaAssignment { area_id } = &record.previous_value {
+        require_not_frozen(persistence, record.bid_year_id, Some(*area_id))?;
+    }
 
-        let user_type = match UserType::parse(&user_type_str).map_err(translate_domain_error) {
-            Ok(ut) => ut,
-            Err(e) => {
-                results.push(CsvImportRowResult {
-                    row_index,
-                    row_number,
-                    initials: Some(initials_str.clone()),
-                    status: CsvImportRowStatus::Failed,
-                    error: Some(format!("Invalid user type: {e}")),
-                });
-                failed_count += 1;
-                continue;
-            }
-        };
+    let (_, user_initials): (i64, String) =
+        persistence
+            .get_user_details(record.user_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to fetch user info: {e}"),
+            })?;
+    let overridden: String = describe_override_value(persistence, &record.new_value)?;
+    let restored: String = describe_override_value(persistence, &record.previous_value)?;
 
-        let crew = match Crew::new(crew_num).map_err(translate_domain_error) {
-            Ok(c) => Some(c),
-            Err(e) => {
-                results.push(CsvImportRowResult {
-                    row_index,
-                    row_number,
-                    initials: Some(initials_str.clone()),
-                    status: CsvImportRowStatus::Failed,
-                    error: Some(format!("Invalid crew: {e}")),
-                });
-                failed_count += 1;
-                continue;
-            }
-        };
+    persistence
+        .revert_override(&record)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to revert override: {e}"),
+        })?;
 
-        let seniority_data = SeniorityData::new(
-            cumulative_natca_bu_date,
-            natca_bu_date,
-            eod_faa_date,
-            service_computation_date,
-            lottery_value,
-        );
+    // Create and persist audit event
+    let actor = authenticated_actor.to_audit_actor(operator);
+    let cause = Cause::new(
+        String::from("revert_override"),
+        format!(
+            "Revert {} override for user {user_initials}",
+            record.override_kind
+        ),
+    );
 
-        // Load current state for this user's area from the database
-        // This ensures duplicate detection works correctly across areas
-        let area_state: State = persistence
-            .get_current_state(&active_bid_year, &area)
-            .unwrap_or_else(|_| State::new(active_bid_year.clone(), area.clone()));
+    let action = Action::new(
+        String::from("OverrideReverted"),
+        Some(format!(
+            "override_id={}, user_id={}, kind={}, original_audit_event={}, reason={}",
+            override_id, record.user_id, record.override_kind, record.audit_event_id, record.reason
+        )),
+    );
 
-        // Create the command
-        let command = Command::RegisterUser {
-            initials: initials.clone(),
-            name: name.clone(),
-            area: area.clone(),
-            user_type,
-            crew,
-            seniority_data,
-        };
+    let before = StateSnapshot::new(overridden);
+    let after = StateSnapshot::new(restored);
 
-        // Attempt to apply the command
-        match apply(
-            metadata,
-            &area_state,
-            &active_bid_year,
-            command,
-            actor.clone(),
-            cause.clone(),
-        )
-        .map_err(translate_core_error)
-        {
-            Ok(transition_result) => {
-                // Persist immediately to ensure subsequent rows see this user
-                if let Err(persist_err) = persistence.persist_transition(&transition_result) {
-                    results.push(CsvImportRowResult {
-                        row_index,
-                        row_number,
-                        initials: Some(initials.value().to_string()),
-                        status: CsvImportRowStatus::Failed,
-                        error: Some(format!("Failed to persist: {persist_err}")),
-                    });
-                    failed_count += 1;
-                    continue;
-                }
+    let year = persistence
+        .get_bid_year_from_id(record.bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get bid year: {e}"),
+        })?;
+    let bid_year = BidYear::new(year);
+    let area: Area = user_audit_area(persistence, record.user_id)?;
 
-                // Success
-                results.push(CsvImportRowResult {
-                    row_index,
-                    row_number,
-                    initials: Some(initials.value().to_string()),
-                    status: CsvImportRowStatus::Success,
-                    error: None,
-                });
-                successful_count += 1;
-            }
-            Err(e) => {
-                // Failure
-                results.push(CsvImportRowResult {
-                    row_index,
-                    row_number,
-                    initials: Some(initials.value().to_string()),
-                    status: CsvImportRowStatus::Failed,
-                    error: Some(format!("{e}")),
-                });
-                failed_count += 1;
-            }
-        }
-    }
+    let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area)
+        .with_user_id(Some(record.user_id));
 
-    let response = ImportCsvUsersResponse {
-        bid_year: active_bid_year.year(),
-        total_selected,
-        successful_count,
-        failed_count,
-        results,
-    };
+    let event_id =
+        persistence
+            .persist_audit_event(&audit_event)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to persist audit event: {e}"),
+            })?;
+    if matches!(record.previous_value, OverrideValue::AreaAssignment { .. }) {
+        refresh_derived_eligibility(persistence, record.bid_year_id)?;
+    }
 
-    Ok(response)
+    Ok(RevertOverrideResponse {
+        override_id,
+        audit_event_id: event_id,
+        message: format!(
+            "Reverted {} override for user {user_initials} (audit event {event_id})",
+            record.override_kind
+        ),
+    })
 }
 
-/// Override a user's area assignment after canonicalization.
+// ============================================================================
+// Phase 29G: Post-Confirmation Bid Order Adjustments
+// ============================================================================
+
+/// Adjust bid order for multiple users in bulk.
 ///
 /// # Arguments
 ///
-/// * `persistence` - The persistence layer
-/// * `request` - The override request
-/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `persistence` - Persistence layer
+/// * `bid_year_id` - The bid year ID
+/// * `area_id` - The area ID
+/// * `request` - The bulk adjustment request
+/// * `authenticated_actor` - The authenticated actor performing the adjustment
 /// * `operator` - The operator data
 ///
 /// # Returns
 ///
-/// Returns the audit event ID on success.
+/// Returns a success response with the audit event ID.
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - The actor is not an admin
-/// - The lifecycle state is not >= Canonicalized
-/// - The override reason is invalid
-/// - The target area is a system area
-/// - The canonical record does not exist
-#[allow(clippy::too_many_lines)]
-#[allow(dead_code)]
-pub fn override_area_assignment(
+/// - The reason is too short
+/// - Any bid order value is invalid
+/// - The lifecycle state is not Canonicalized or later
+/// - The database operation fails
+pub fn adjust_bid_order(
     persistence: &mut SqlitePersistence,
-    request: &OverrideAreaAssignmentRequest,
+    bid_year_id: i64,
+    area_id: i64,
+    request: &AdjustBidOrderRequest,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
-) -> Result<OverrideAreaAssignmentResponse, ApiError> {
-    // Enforce authorization - only admins can perform overrides
+) -> Result<AdjustBidOrderResponse, ApiError> {
+    // Enforce authorization - only admins can perform adjustments
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("override_area_assignment"),
+            action: String::from("adjust_bid_order"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Validate override reason (min 10 chars)
+    // Validate reason (min 10 chars)
     let reason = request.reason.trim();
     if reason.len() < 10 {
         return Err(translate_domain_error(DomainError::InvalidOverrideReason {
@@ -4473,13 +7036,17 @@ pub fn override_area_assignment(
         }));
     }
 
-    // Get user details
-    let (bid_year_id, user_initials): (i64, String) = persistence
-        .get_user_details(request.user_id)
-        .map_err(|_| ApiError::ResourceNotFound {
-            resource_type: String::from("User"),
-            message: format!("User with ID {} not found", request.user_id),
-        })?;
+    // Validate all bid orders are positive
+    for adjustment in &request.adjustments {
+        if adjustment.new_bid_order <= 0 {
+            return Err(translate_domain_error(DomainError::InvalidBidOrder {
+                reason: format!(
+                    "Bid order must be positive (got: {})",
+                    adjustment.new_bid_order
+                ),
+            }));
+        }
+    }
 
     // Check lifecycle state >= Canonicalized
     let lifecycle_state =
@@ -4500,73 +7067,53 @@ pub fn override_area_assignment(
         ));
     }
 
-    // Verify target area exists and is not a system area
-    let (area_code, area_name): (String, Option<String>) = persistence
-        .get_area_details(request.new_area_id)
-        .map_err(|_| ApiError::ResourceNotFound {
-            resource_type: String::from("Area"),
-            message: format!("Area with ID {} not found", request.new_area_id),
-        })?;
-
-    // Check if target area is a system area
-    let is_system = persistence
-        .is_system_area(request.new_area_id)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to check system area: {e}"),
-        })?;
+    require_not_frozen(persistence, bid_year_id, Some(area_id))?;
 
-    if is_system {
-        return Err(translate_domain_error(
-            DomainError::CannotAssignToSystemArea { area_code },
-        ));
-    }
+    // Apply adjustments
+    let mut users_adjusted = 0;
+    for adjustment in &request.adjustments {
+        // Verify user exists and get details
+        let (_user_bid_year_id, _user_initials) = persistence
+            .get_user_details(adjustment.user_id)
+            .map_err(|_| ApiError::ResourceNotFound {
+                resource_type: String::from("User"),
+                message: format!("User with ID {} not found", adjustment.user_id),
+            })?;
 
-    // Get previous area info for audit event
-    let previous_area_id: i64 = persistence
-        .get_current_area_assignment(bid_year_id, request.user_id)
-        .map_err(|_| {
-            translate_domain_error(DomainError::CanonicalRecordNotFound {
-                description: format!(
-                    "Canonical area membership not found for user_id={}",
-                    request.user_id
+        // Perform override using existing function
+        persistence
+            .override_bid_order(
+                bid_year_id,
+                adjustment.user_id,
+                Some(adjustment.new_bid_order),
+                reason,
+            )
+            .map_err(|e| ApiError::Internal {
+                message: format!(
+                    "Failed to adjust bid order for user {}: {e}",
+                    adjustment.user_id
                 ),
-            })
-        })?;
-
-    let (prev_area_code, prev_area_name): (String, Option<String>) = persistence
-        .get_area_details(previous_area_id)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to fetch previous area info: {e}"),
-        })?;
+            })?;
 
-    // Perform override
-    let (_, was_already_overridden) = persistence
-        .override_area_assignment(bid_year_id, request.user_id, request.new_area_id, reason)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to override area assignment: {e}"),
-        })?;
+        users_adjusted += 1;
+    }
 
     // Create and persist audit event
     let actor = authenticated_actor.to_audit_actor(operator);
     let cause = Cause::new(
-        String::from("override_area_assignment"),
-        format!("Override area assignment for user {user_initials}"),
+        String::from("adjust_bid_order"),
+        format!("Bulk bid order adjustment for {users_adjusted} users"),
     );
 
     let action = Action::new(
-        String::from("UserAreaAssignmentOverridden"),
+        String::from("BulkBidOrderAdjustment"),
         Some(format!(
-            "user_id={}, previous_area={}, new_area={}, reason={}, was_overridden={}",
-            request.user_id,
-            prev_area_name.unwrap_or(prev_area_code),
-            area_name.unwrap_or(area_code),
-            reason,
-            was_already_overridden
+            "area_id={area_id}, users_adjusted={users_adjusted}, reason={reason}"
         )),
     );
 
-    let before = StateSnapshot::new(format!("area_id={previous_area_id}"));
-    let after = StateSnapshot::new(format!("area_id={}", request.new_area_id));
+    let before = StateSnapshot::new(String::from("bulk_adjustment_requested"));
+    let after = StateSnapshot::new(format!("users_adjusted={users_adjusted}"));
 
     let year = persistence
         .get_bid_year_from_id(bid_year_id)
@@ -4574,7 +7121,7 @@ pub fn override_area_assignment(
             message: format!("Failed to get bid year: {e}"),
         })?;
     let bid_year = BidYear::new(year);
-    let area = Area::new("_override");
+    let area = Area::new("_bulk_adjustment");
 
     let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area);
 
@@ -4585,50 +7132,53 @@ pub fn override_area_assignment(
                 message: format!("Failed to persist audit event: {e}"),
             })?;
 
-    Ok(OverrideAreaAssignmentResponse {
+    Ok(AdjustBidOrderResponse {
         audit_event_id: event_id,
-        message: format!(
-            "Area assignment overridden for user {user_initials} (audit event {event_id})"
-        ),
+        users_adjusted,
+        message: format!("Adjusted bid order for {users_adjusted} users (audit event {event_id})"),
     })
 }
 
-/// Override a user's eligibility after canonicalization.
+/// Adjust a bid window for a specific user and round.
 ///
 /// # Arguments
 ///
-/// * `persistence` - The persistence layer
-/// * `request` - The override request
-/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `persistence` - Persistence layer
+/// * `bid_year_id` - The bid year ID
+/// * `area_id` - The area ID
+/// * `request` - The adjustment request
+/// * `authenticated_actor` - The authenticated actor performing the adjustment
 /// * `operator` - The operator data
 ///
 /// # Returns
 ///
-/// Returns the audit event ID on success.
+/// Returns a success response with the audit event ID.
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - The actor is not an admin
-/// - The lifecycle state is not >= Canonicalized
-/// - The override reason is invalid
-/// - The canonical record does not exist
-#[allow(dead_code)]
-pub fn override_eligibility(
+/// - The reason is too short
+/// - The window start/end datetimes are invalid
+/// - The lifecycle state is not Canonicalized or later
+/// - The database operation fails
+pub fn adjust_bid_window(
     persistence: &mut SqlitePersistence,
-    request: &OverrideEligibilityRequest,
+    bid_year_id: i64,
+    area_id: i64,
+    request: &AdjustBidWindowRequest,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
-) -> Result<OverrideEligibilityResponse, ApiError> {
-    // Enforce authorization - only admins can perform overrides
+) -> Result<AdjustBidWindowResponse, ApiError> {
+    // Enforce authorization - only admins can perform adjustments
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("override_eligibility"),
+            action: String::from("adjust_bid_window"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Validate override reason (min 10 chars)
+    // Validate reason (min 10 chars)
     let reason = request.reason.trim();
     if reason.len() < 10 {
         return Err(translate_domain_error(DomainError::InvalidOverrideReason {
@@ -4636,59 +7186,53 @@ pub fn override_eligibility(
         }));
     }
 
-    // Get user details
-    let (bid_year_id, user_initials): (i64, String) =
-        persistence.get_user_details(request.user_id).map_err(|_| {
-            let user_id = request.user_id;
-            ApiError::ResourceNotFound {
-                resource_type: String::from("User"),
-                message: format!("User with ID {user_id} not found"),
-            }
-        })?;
-
-    // Check lifecycle state >= Canonicalized
-    let lifecycle_state =
-        persistence
-            .get_lifecycle_state(bid_year_id)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to get lifecycle state: {e}"),
-            })?;
-
-    if !matches!(
-        lifecycle_state.as_str(),
-        "Canonicalized" | "BiddingActive" | "BiddingClosed"
-    ) {
-        return Err(translate_domain_error(
-            DomainError::CannotOverrideBeforeCanonicalization {
-                current_state: lifecycle_state,
-            },
-        ));
+    // Validate window times (basic format check - detailed validation happens in persistence layer)
+    let window_start = &request.new_window_start;
+    let window_end = &request.new_window_end;
+    if window_start >= window_end {
+        return Err(translate_domain_error(DomainError::InvalidBidWindow {
+            reason: format!(
+                "Window start ({window_start}) must be before window end ({window_end})"
+            ),
+        }));
     }
 
-    // Perform override
-    let (previous_eligibility, was_already_overridden) = persistence
-        .override_eligibility(bid_year_id, request.user_id, request.can_bid, reason)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to override eligibility: {e}"),
-        })?;
+    let (user_initials, previous_start, previous_end) = adjust_bid_window_impl(
+        persistence,
+        bid_year_id,
+        area_id,
+        request.user_id,
+        request.round_id,
+        &request.new_window_start,
+        &request.new_window_end,
+    )?;
 
     // Create and persist audit event
     let actor = authenticated_actor.to_audit_actor(operator);
     let cause = Cause::new(
-        String::from("override_eligibility"),
-        format!("Override eligibility for user {user_initials}"),
+        String::from("adjust_bid_window"),
+        format!(
+            "Adjust bid window for user {user_initials}, round {}",
+            request.round_id
+        ),
     );
 
+    let user_id = request.user_id;
+    let round_id = request.round_id;
+    let new_start = &request.new_window_start;
+    let new_end = &request.new_window_end;
+
     let action = Action::new(
-        String::from("UserEligibilityOverridden"),
+        String::from("BidWindowAdjusted"),
         Some(format!(
-            "user_id={}, previous_eligibility={}, new_eligibility={}, reason={}, was_overridden={}",
-            request.user_id, previous_eligibility, request.can_bid, reason, was_already_overridden
+            "user_id={user_id}, round_id={round_id}, previous_start={previous_start}, previous_end={previous_end}, new_start={new_start}, new_end={new_end}, reason={reason}"
         )),
     );
 
-    let before = StateSnapshot::new(format!("can_bid={previous_eligibility}"));
-    let after = StateSnapshot::new(format!("can_bid={}", request.can_bid));
+    let before = StateSnapshot::new(format!(
+        "window_start={previous_start}, window_end={previous_end}"
+    ));
+    let after = StateSnapshot::new(format!("window_start={new_start}, window_end={new_end}"));
 
     let year = persistence
         .get_bid_year_from_id(bid_year_id)
@@ -4696,9 +7240,10 @@ pub fn override_eligibility(
             message: format!("Failed to get bid year: {e}"),
         })?;
     let bid_year = BidYear::new(year);
-    let area = Area::new("_override");
+    let area = Area::new("_window_adjustment");
 
-    let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area);
+    let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area)
+        .with_user_id(Some(user_id));
 
     let event_id =
         persistence
@@ -4707,51 +7252,114 @@ pub fn override_eligibility(
                 message: format!("Failed to persist audit event: {e}"),
             })?;
 
-    Ok(OverrideEligibilityResponse {
+    let round_id = request.round_id;
+    Ok(AdjustBidWindowResponse {
         audit_event_id: event_id,
         message: format!(
-            "Eligibility overridden for user {user_initials} (audit event {event_id})"
+            "Adjusted bid window for user {user_initials}, round {round_id} (audit event {event_id})"
         ),
     })
 }
 
-/// Override a user's bid order after canonicalization.
+/// Internal helper for bid window adjustment implementation.
+fn adjust_bid_window_impl(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    area_id: i64,
+    user_id: i64,
+    round_id: i64,
+    new_window_start: &str,
+    new_window_end: &str,
+) -> Result<(String, String, String), ApiError> {
+    // Get user details
+    let (_user_bid_year_id, user_initials) =
+        persistence
+            .get_user_details(user_id)
+            .map_err(|_| ApiError::ResourceNotFound {
+                resource_type: String::from("User"),
+                message: format!("User with ID {user_id} not found"),
+            })?;
+
+    // Check lifecycle state >= Canonicalized
+    let lifecycle_state =
+        persistence
+            .get_lifecycle_state(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get lifecycle state: {e}"),
+            })?;
+
+    if !matches!(
+        lifecycle_state.as_str(),
+        "Canonicalized" | "BiddingActive" | "BiddingClosed"
+    ) {
+        return Err(translate_domain_error(
+            DomainError::CannotOverrideBeforeCanonicalization {
+                current_state: lifecycle_state,
+            },
+        ));
+    }
+
+    require_not_frozen(persistence, bid_year_id, Some(area_id))?;
+
+    // Perform adjustment
+    let (previous_start, previous_end) = persistence
+        .adjust_bid_window(
+            bid_year_id,
+            area_id,
+            user_id,
+            round_id,
+            new_window_start,
+            new_window_end,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to adjust bid window: {e}"),
+        })?;
+
+    Ok((user_initials, previous_start, previous_end))
+}
+
+/// Recalculate bid windows for multiple users and rounds in bulk.
+///
+/// This endpoint deletes existing bid windows and allows them to be recalculated.
+/// The actual recalculation logic is expected to be invoked separately.
 ///
 /// # Arguments
 ///
-/// * `persistence` - The persistence layer
-/// * `request` - The override request
-/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `persistence` - Persistence layer
+/// * `bid_year_id` - The bid year ID
+/// * `area_id` - The area ID
+/// * `request` - The recalculation request
+/// * `authenticated_actor` - The authenticated actor performing the recalculation
 /// * `operator` - The operator data
 ///
 /// # Returns
 ///
-/// Returns the audit event ID on success.
+/// Returns a success response with the audit event ID.
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - The actor is not an admin
-/// - The lifecycle state is not >= Canonicalized
-/// - The override reason is invalid
-/// - The bid order is invalid (must be positive if provided)
-/// - The canonical record does not exist
-#[allow(dead_code)]
-pub fn override_bid_order(
+/// - The reason is too short
+/// - The lifecycle state is not Canonicalized or later
+/// - The database operation fails
+pub fn recalculate_bid_windows(
     persistence: &mut SqlitePersistence,
-    request: &OverrideBidOrderRequest,
+    bid_year_id: i64,
+    area_id: i64,
+    request: &RecalculateBidWindowsRequest,
     authenticated_actor: &AuthenticatedActor,
     operator: &OperatorData,
-) -> Result<OverrideBidOrderResponse, ApiError> {
-    // Enforce authorization - only admins can perform overrides
+) -> Result<RecalculateBidWindowsResponse, ApiError> {
+    // Enforce authorization - only admins can perform recalculations
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("override_bid_order"),
+            action: String::from("recalculate_bid_windows"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Validate override reason (min 10 chars)
+    // Validate reason (min 10 chars)
     let reason = request.reason.trim();
     if reason.len() < 10 {
         return Err(translate_domain_error(DomainError::InvalidOverrideReason {
@@ -4759,25 +7367,6 @@ pub fn override_bid_order(
         }));
     }
 
-    // Validate bid order if provided
-    if let Some(order) = request.bid_order
-        && order <= 0
-    {
-        return Err(translate_domain_error(DomainError::InvalidBidOrder {
-            reason: format!("Bid order must be positive (got: {order})"),
-        }));
-    }
-
-    // Get user details
-    let (bid_year_id, user_initials): (i64, String) =
-        persistence.get_user_details(request.user_id).map_err(|_| {
-            let user_id = request.user_id;
-            ApiError::ResourceNotFound {
-                resource_type: String::from("User"),
-                message: format!("User with ID {user_id} not found"),
-            }
-        })?;
-
     // Check lifecycle state >= Canonicalized
     let lifecycle_state =
         persistence
@@ -4797,30 +7386,42 @@ pub fn override_bid_order(
         ));
     }
 
-    // Perform override
-    let (previous_bid_order, was_already_overridden) = persistence
-        .override_bid_order(bid_year_id, request.user_id, request.bid_order, reason)
+    require_not_frozen(persistence, bid_year_id, Some(area_id))?;
+
+    // Delete existing bid windows for the specified users and rounds
+    let windows_deleted = persistence
+        .delete_bid_windows_for_users_and_rounds(
+            bid_year_id,
+            area_id,
+            &request.user_ids,
+            &request.rounds,
+        )
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to override bid order: {e}"),
+            message: format!("Failed to delete bid windows: {e}"),
         })?;
 
     // Create and persist audit event
     let actor = authenticated_actor.to_audit_actor(operator);
     let cause = Cause::new(
-        String::from("override_bid_order"),
-        format!("Override bid order for user {user_initials}"),
+        String::from("recalculate_bid_windows"),
+        format!(
+            "Bulk bid window recalculation for {} users, {} rounds",
+            request.user_ids.len(),
+            request.rounds.len()
+        ),
     );
 
     let action = Action::new(
-        String::from("UserBidOrderOverridden"),
+        String::from("BulkBidWindowRecalculation"),
         Some(format!(
-            "user_id={}, previous_bid_order={:?}, new_bid_order={:?}, reason={}, was_overridden={}",
-            request.user_id, previous_bid_order, request.bid_order, reason, was_already_overridden
+            "area_id={area_id}, user_count={}, round_count={}, windows_deleted={windows_deleted}, reason={reason}",
+            request.user_ids.len(),
+            request.rounds.len()
         )),
     );
 
-    let before = StateSnapshot::new(format!("bid_order={previous_bid_order:?}"));
-    let after = StateSnapshot::new(format!("bid_order={:?}", request.bid_order));
+    let before = StateSnapshot::new(format!("windows_existed={windows_deleted}"));
+    let after = StateSnapshot::new(String::from("windows_deleted_for_recalculation"));
 
     let year = persistence
         .get_bid_year_from_id(bid_year_id)
@@ -4828,7 +7429,7 @@ pub fn override_bid_order(
             message: format!("Failed to get bid year: {e}"),
         })?;
     let bid_year = BidYear::new(year);
-    let area = Area::new("_override");
+    let area = Area::new("_window_recalculation");
 
     let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area);
 
@@ -4839,867 +7440,674 @@ pub fn override_bid_order(
                 message: format!("Failed to persist audit event: {e}"),
             })?;
 
-    Ok(OverrideBidOrderResponse {
+    Ok(RecalculateBidWindowsResponse {
         audit_event_id: event_id,
-        message: format!("Bid order overridden for user {user_initials} (audit event {event_id})"),
+        windows_recalculated: windows_deleted,
+        message: format!(
+            "Deleted {windows_deleted} bid windows for recalculation (audit event {event_id})"
+        ),
     })
 }
 
-/// Override a user's bid window after canonicalization.
+/// Update a user's participation flags.
+///
+/// Phase 29A: Controls bid order derivation and leave calculation inclusion.
+///
+/// # Directional Invariant
+///
+/// `excluded_from_leave_calculation == true` ⇒ `excluded_from_bidding == true`
+///
+/// A user may never be included in bidding while excluded from leave calculation.
+///
+/// # Lifecycle Constraints
+///
+/// Flags are editable in `Draft` and `BootstrapComplete` states.
+/// After canonicalization, flags become immutable (or require override).
 ///
 /// # Arguments
 ///
-/// * `persistence` - The persistence layer
-/// * `request` - The override request
-/// * `authenticated_actor` - The authenticated actor performing this action
-/// * `operator` - The operator data
+/// * `metadata` - Bootstrap metadata
+/// * `persistence` - Persistence layer
+/// * `request` - The participation flag update request
+/// * `authenticated_actor` - The authenticated actor performing the update
 ///
 /// # Returns
 ///
-/// Returns the audit event ID on success.
+/// * `Ok(UpdateUserParticipationResponse)` on success
+/// * `Err(ApiError)` on validation failure or lifecycle constraint violation
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The actor is not an admin
-/// - The lifecycle state is not >= Canonicalized
-/// - The override reason is invalid
-/// - The bid window dates are invalid (start > end, partial window)
-/// - The canonical record does not exist
-#[allow(clippy::too_many_lines)]
-#[allow(dead_code)]
-pub fn override_bid_window(
+/// - User does not exist
+/// - Directional invariant is violated
+/// - Lifecycle state does not allow flag updates
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+pub fn update_user_participation(
+    metadata: &BootstrapMetadata,
     persistence: &mut SqlitePersistence,
-    request: &OverrideBidWindowRequest,
-    authenticated_actor: &AuthenticatedActor,
-    operator: &OperatorData,
-) -> Result<OverrideBidWindowResponse, ApiError> {
-    // Enforce authorization - only admins can perform overrides
-    if authenticated_actor.role != Role::Admin {
-        return Err(ApiError::Unauthorized {
-            action: String::from("override_bid_window"),
-            required_role: String::from("Admin"),
-        });
-    }
+    request: &crate::request_response::UpdateUserParticipationRequest,
+    authenticated_actor: &Actor,
+    lifecycle_state: zab_bid_domain::BidYearLifecycle,
+) -> Result<crate::request_response::UpdateUserParticipationResponse, ApiError> {
+    use zab_bid_domain::DomainError;
 
-    // Validate override reason (min 10 chars)
-    let reason = request.reason.trim();
-    if reason.len() < 10 {
-        return Err(translate_domain_error(DomainError::InvalidOverrideReason {
-            reason: request.reason.clone(),
-        }));
+    // Enforce lifecycle constraints: participation flags locked after Canonicalized
+    if lifecycle_state.is_locked() {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("participation_flags_lifecycle"),
+            message: format!(
+                "Cannot update participation flags in state '{lifecycle_state}': structural changes locked after confirmation"
+            ),
+        });
     }
 
-    // Validate bid window - both must be present or both must be None
-    match (&request.window_start, &request.window_end) {
-        (Some(start), Some(end)) => {
-            // Parse dates to validate format and ordering
-            let start_date = time::Date::parse(
-                start,
-                time::macros::format_description!("[year]-[month]-[day]"),
-            )
-            .map_err(|e| {
-                translate_domain_error(DomainError::DateParseError {
-                    date_string: start.clone(),
-                    error: e.to_string(),
-                })
-            })?;
-            let end_date = time::Date::parse(
-                end,
-                time::macros::format_description!("[year]-[month]-[day]"),
-            )
-            .map_err(|e| {
-                translate_domain_error(DomainError::DateParseError {
-                    date_string: end.clone(),
-                    error: e.to_string(),
-                })
-            })?;
-
-            if start_date > end_date {
-                return Err(translate_domain_error(DomainError::InvalidBidWindow {
-                    reason: format!("Window start date ({start}) must be <= end date ({end})"),
-                }));
-            }
-        }
-        (None, None) => {
-            // Both None is valid (clears the window)
-        }
-        _ => {
-            return Err(translate_domain_error(DomainError::InvalidBidWindow {
+    // Validate directional invariant before constructing command
+    if request.excluded_from_leave_calculation && !request.excluded_from_bidding {
+        return Err(translate_domain_error(
+            DomainError::ParticipationFlagViolation {
+                user_initials: format!("user_id={}", request.user_id),
                 reason: String::from(
-                    "Both window_start and window_end must be provided or both must be null",
+                    "User excluded from leave calculation must also be excluded from bidding",
                 ),
-            }));
-        }
+            },
+        ));
     }
 
-    // Get user details
-    let (bid_year_id, user_initials): (i64, String) =
-        persistence.get_user_details(request.user_id).map_err(|_| {
-            let user_id = request.user_id;
-            ApiError::ResourceNotFound {
-                resource_type: String::from("User"),
-                message: format!("User with ID {user_id} not found"),
-            }
+    // Resolve the active bid year from canonical state
+    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+
+    // Find bid_year_id
+    let bid_year_id: i64 = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.year() == active_bid_year.year())
+        .and_then(BidYear::bid_year_id)
+        .ok_or_else(|| ApiError::Internal {
+            message: format!(
+                "Active bid year {} has no ID in metadata",
+                active_bid_year.year()
+            ),
         })?;
 
-    // Check lifecycle state >= Canonicalized
-    let lifecycle_state =
-        persistence
-            .get_lifecycle_state(bid_year_id)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to get lifecycle state: {e}"),
-            })?;
+    // We need to iterate through all areas to find the user
+    // since we don't know which area the user is in
+    let mut found_user: Option<(zab_bid_domain::User, Area, State)> = None;
 
-    if !matches!(
-        lifecycle_state.as_str(),
-        "Canonicalized" | "BiddingActive" | "BiddingClosed"
-    ) {
-        return Err(translate_domain_error(
-            DomainError::CannotOverrideBeforeCanonicalization {
-                current_state: lifecycle_state,
-            },
-        ));
+    for (by, area_meta) in &metadata.areas {
+        if by.year() != active_bid_year.year() {
+            continue;
+        }
+
+        let area = Area::new(area_meta.area_code());
+
+        // Try to load state for this area
+        let Ok(state) = persistence.get_current_state(&active_bid_year, &area) else {
+            continue; // Skip areas with no state
+        };
+
+        // Check if the user is in this area
+        if let Some(user) = state.find_user_by_id(request.user_id) {
+            found_user = Some((user.clone(), area, state));
+            break;
+        }
     }
 
-    // Perform override
-    let (previous_start, previous_end, was_already_overridden) = persistence
-        .override_bid_window(
-            bid_year_id,
-            request.user_id,
-            request.window_start.as_ref(),
-            request.window_end.as_ref(),
-            reason,
-        )
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to override bid window: {e}"),
-        })?;
+    let (user, _area, state) = found_user.ok_or_else(|| ApiError::ResourceNotFound {
+        resource_type: String::from("User"),
+        message: format!(
+            "User with user_id={} not found in active bid year",
+            request.user_id
+        ),
+    })?;
 
-    // Create and persist audit event
-    let actor = authenticated_actor.to_audit_actor(operator);
+    // Create the command
+    let command: Command = Command::UpdateUserParticipation {
+        user_id: request.user_id,
+        initials: user.initials.clone(),
+        excluded_from_bidding: request.excluded_from_bidding,
+        excluded_from_leave_calculation: request.excluded_from_leave_calculation,
+    };
+
+    // Apply the command
     let cause = Cause::new(
-        String::from("override_bid_window"),
-        format!("Override bid window for user {user_initials}"),
+        String::from("update_user_participation"),
+        format!(
+            "Update participation flags for user {}",
+            user.initials.value()
+        ),
     );
+    let result: TransitionResult = apply(
+        metadata,
+        &state,
+        &active_bid_year,
+        command,
+        authenticated_actor.clone(),
+        cause,
+    )
+    .map_err(translate_core_error)?;
 
-    let action = Action::new(
-        String::from("UserBidWindowOverridden"),
-        Some(format!(
-            "user_id={}, previous_start={:?}, previous_end={:?}, new_start={:?}, new_end={:?}, reason={}, was_overridden={}",
+    // Persist the flags and the audit event
+    persistence
+        .set_user_participation(
             request.user_id,
-            previous_start,
-            previous_end,
-            request.window_start,
-            request.window_end,
-            reason,
-            was_already_overridden
-        )),
-    );
-
-    let before = StateSnapshot::new(format!(
-        "window_start={previous_start:?}, window_end={previous_end:?}"
-    ));
-    let after = StateSnapshot::new(format!(
-        "window_start={:?}, window_end={:?}",
-        request.window_start, request.window_end
-    ));
-
-    let year = persistence
-        .get_bid_year_from_id(bid_year_id)
+            request.excluded_from_bidding,
+            request.excluded_from_leave_calculation,
+        )
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to get bid year: {e}"),
+            message: format!("Failed to update participation flags: {e}"),
         })?;
-    let bid_year = BidYear::new(year);
-    let area = Area::new("_override");
-
-    let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area);
-
-    let event_id =
-        persistence
-            .persist_audit_event(&audit_event)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to persist audit event: {e}"),
-            })?;
+    persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    refresh_derived_eligibility(persistence, bid_year_id)?;
 
-    Ok(OverrideBidWindowResponse {
-        audit_event_id: event_id,
-        message: format!("Bid window overridden for user {user_initials} (audit event {event_id})"),
+    Ok(crate::request_response::UpdateUserParticipationResponse {
+        bid_year_id,
+        bid_year: active_bid_year.year(),
+        user_id: request.user_id,
+        initials: user.initials.value().to_string(),
+        excluded_from_bidding: request.excluded_from_bidding,
+        excluded_from_leave_calculation: request.excluded_from_leave_calculation,
+        message: format!(
+            "Updated participation flags for user '{}'",
+            user.initials.value()
+        ),
     })
 }
 
+// TODO Phase 26C: Add integration tests for update_area handler:
+// - test_update_area_allowed_in_draft
+// - test_update_area_denied_after_canonicalization
+// - test_update_area_denied_for_system_area
+// - test_update_area_requires_admin
+// - test_update_area_creates_audit_event
+
 // ============================================================================
-// Phase 29G: Post-Confirmation Bid Order Adjustments
+// Phase 29B: Round Groups and Rounds
 // ============================================================================
 
-/// Adjust bid order for multiple users in bulk.
+/// Creates a new round group for a bid year.
+///
+/// Round groups are editable in `Draft` and `BootstrapComplete` states.
+/// After canonicalization, round configuration becomes immutable (or requires override).
 ///
 /// # Arguments
 ///
-/// * `persistence` - Persistence layer
-/// * `bid_year_id` - The bid year ID
-/// * `area_id` - The area ID
-/// * `request` - The bulk adjustment request
-/// * `authenticated_actor` - The authenticated actor performing the adjustment
-/// * `operator` - The operator data
+/// * `persistence` - The persistence layer
+/// * `bid_year_id` - The bid year ID this round group belongs to
+/// * `request` - The round group creation request
+/// * `authenticated_actor` - The authenticated actor performing the operation
 ///
 /// # Returns
 ///
-/// Returns a success response with the audit event ID.
+/// * `Ok(CreateRoundGroupResponse)` on success
+/// * `Err(ApiError)` on validation failure or lifecycle constraint violation
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The actor is not an admin
-/// - The reason is too short
-/// - Any bid order value is invalid
-/// - The lifecycle state is not Canonicalized or later
-/// - The database operation fails
-pub fn adjust_bid_order(
+/// - Actor is not authorized (Admin role required)
+/// - Lifecycle state does not allow round group creation
+/// - Round group name already exists in bid year
+/// - Validation fails
+#[allow(dead_code)]
+pub fn create_round_group(
     persistence: &mut SqlitePersistence,
     bid_year_id: i64,
-    area_id: i64,
-    request: &AdjustBidOrderRequest,
+    request: &crate::request_response::CreateRoundGroupRequest,
     authenticated_actor: &AuthenticatedActor,
-    operator: &OperatorData,
-) -> Result<AdjustBidOrderResponse, ApiError> {
-    // Enforce authorization - only admins can perform adjustments
+) -> Result<crate::request_response::CreateRoundGroupResponse, ApiError> {
+    use zab_bid_domain::BidYearLifecycle;
+
+    // Enforce authorization - only admins can manage round groups
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("adjust_bid_order"),
+            action: String::from("create_round_group"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Validate reason (min 10 chars)
-    let reason = request.reason.trim();
-    if reason.len() < 10 {
-        return Err(translate_domain_error(DomainError::InvalidOverrideReason {
-            reason: request.reason.clone(),
-        }));
-    }
-
-    // Validate all bid orders are positive
-    for adjustment in &request.adjustments {
-        if adjustment.new_bid_order <= 0 {
-            return Err(translate_domain_error(DomainError::InvalidBidOrder {
-                reason: format!(
-                    "Bid order must be positive (got: {})",
-                    adjustment.new_bid_order
-                ),
-            }));
-        }
-    }
-
-    // Check lifecycle state >= Canonicalized
-    let lifecycle_state =
+    // Enforce lifecycle constraints: round configuration locked after Canonicalized
+    let lifecycle_state_str: String =
         persistence
             .get_lifecycle_state(bid_year_id)
             .map_err(|e| ApiError::Internal {
                 message: format!("Failed to get lifecycle state: {e}"),
             })?;
 
-    if !matches!(
-        lifecycle_state.as_str(),
-        "Canonicalized" | "BiddingActive" | "BiddingClosed"
-    ) {
-        return Err(translate_domain_error(
-            DomainError::CannotOverrideBeforeCanonicalization {
-                current_state: lifecycle_state,
-            },
-        ));
-    }
+    let lifecycle_state: BidYearLifecycle = lifecycle_state_str
+        .parse()
+        .map_err(translate_domain_error)?;
 
-    // Apply adjustments
-    let mut users_adjusted = 0;
-    for adjustment in &request.adjustments {
-        // Verify user exists and get details
-        let (_user_bid_year_id, _user_initials) = persistence
-            .get_user_details(adjustment.user_id)
-            .map_err(|_| ApiError::ResourceNotFound {
-                resource_type: String::from("User"),
-                message: format!("User with ID {} not found", adjustment.user_id),
-            })?;
+    if lifecycle_state.is_locked() {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("round_group_lifecycle"),
+            message: format!(
+                "Cannot create round group in state '{lifecycle_state}': structural changes locked after confirmation"
+            ),
+        });
+    }
 
-        // Perform override using existing function
-        persistence
-            .override_bid_order(
-                bid_year_id,
-                adjustment.user_id,
-                Some(adjustment.new_bid_order),
-                reason,
-            )
-            .map_err(|e| ApiError::Internal {
-                message: format!(
-                    "Failed to adjust bid order for user {}: {e}",
-                    adjustment.user_id
-                ),
-            })?;
+    require_not_frozen(persistence, bid_year_id, None)?;
 
-        users_adjusted += 1;
+    // Validate round group name is not empty
+    if request.name.trim().is_empty() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("name"),
+            message: String::from("Round group name cannot be empty"),
+        });
     }
 
-    // Create and persist audit event
-    let actor = authenticated_actor.to_audit_actor(operator);
-    let cause = Cause::new(
-        String::from("adjust_bid_order"),
-        format!("Bulk bid order adjustment for {users_adjusted} users"),
-    );
-
-    let action = Action::new(
-        String::from("BulkBidOrderAdjustment"),
-        Some(format!(
-            "area_id={area_id}, users_adjusted={users_adjusted}, reason={reason}"
-        )),
-    );
+    // Check for duplicate name
+    let name_exists = persistence
+        .round_group_name_exists(bid_year_id, &request.name, None)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to check round group name: {e}"),
+        })?;
 
-    let before = StateSnapshot::new(String::from("bulk_adjustment_requested"));
-    let after = StateSnapshot::new(format!("users_adjusted={users_adjusted}"));
+    if name_exists {
+        return Err(translate_domain_error(
+            DomainError::DuplicateRoundGroupName {
+                bid_year: 0, // We don't have the year value here, but error translation handles it
+                name: request.name.clone(),
+            },
+        ));
+    }
 
-    let year = persistence
-        .get_bid_year_from_id(bid_year_id)
+    // Insert the round group
+    let round_group_id = persistence
+        .insert_round_group(bid_year_id, &request.name, request.editing_enabled)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to get bid year: {e}"),
+            message: format!("Failed to insert round group: {e}"),
         })?;
-    let bid_year = BidYear::new(year);
-    let area = Area::new("_bulk_adjustment");
-
-    let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area);
-
-    let event_id =
-        persistence
-            .persist_audit_event(&audit_event)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to persist audit event: {e}"),
-            })?;
 
-    Ok(AdjustBidOrderResponse {
-        audit_event_id: event_id,
-        users_adjusted,
-        message: format!("Adjusted bid order for {users_adjusted} users (audit event {event_id})"),
+    Ok(crate::request_response::CreateRoundGroupResponse {
+        round_group_id,
+        bid_year_id,
+        name: request.name.clone(),
+        editing_enabled: request.editing_enabled,
+        message: format!("Created round group '{}'", request.name),
     })
 }
 
-/// Adjust a bid window for a specific user and round.
+/// Lists all round groups for a bid year.
 ///
 /// # Arguments
 ///
-/// * `persistence` - Persistence layer
+/// * `persistence` - The persistence layer
 /// * `bid_year_id` - The bid year ID
-/// * `area_id` - The area ID
-/// * `request` - The adjustment request
-/// * `authenticated_actor` - The authenticated actor performing the adjustment
-/// * `operator` - The operator data
+/// * `authenticated_actor` - The authenticated actor performing the operation
 ///
 /// # Returns
 ///
-/// Returns a success response with the audit event ID.
+/// * `Ok(ListRoundGroupsResponse)` on success
+/// * `Err(ApiError)` on query failure
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The actor is not an admin
-/// - The reason is too short
-/// - The window start/end datetimes are invalid
-/// - The lifecycle state is not Canonicalized or later
-/// - The database operation fails
-pub fn adjust_bid_window(
+/// - Actor is not authorized (Admin role required)
+/// - Database query fails
+///
+/// # Panics
+///
+/// Panics if a persisted round group does not have an ID.
+#[allow(dead_code)]
+pub fn list_round_groups(
     persistence: &mut SqlitePersistence,
     bid_year_id: i64,
-    area_id: i64,
-    request: &AdjustBidWindowRequest,
     authenticated_actor: &AuthenticatedActor,
-    operator: &OperatorData,
-) -> Result<AdjustBidWindowResponse, ApiError> {
-    // Enforce authorization - only admins can perform adjustments
+) -> Result<crate::request_response::ListRoundGroupsResponse, ApiError> {
+    // Enforce authorization - only admins can view round groups
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("adjust_bid_window"),
+            action: String::from("list_round_groups"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Validate reason (min 10 chars)
-    let reason = request.reason.trim();
-    if reason.len() < 10 {
-        return Err(translate_domain_error(DomainError::InvalidOverrideReason {
-            reason: request.reason.clone(),
-        }));
-    }
-
-    // Validate window times (basic format check - detailed validation happens in persistence layer)
-    let window_start = &request.new_window_start;
-    let window_end = &request.new_window_end;
-    if window_start >= window_end {
-        return Err(translate_domain_error(DomainError::InvalidBidWindow {
-            reason: format!(
-                "Window start ({window_start}) must be before window end ({window_end})"
-            ),
-        }));
-    }
-
-    let (user_initials, previous_start, previous_end) = adjust_bid_window_impl(
-        persistence,
-        bid_year_id,
-        area_id,
-        request.user_id,
-        request.round_id,
-        &request.new_window_start,
-        &request.new_window_end,
-    )?;
-
-    // Create and persist audit event
-    let actor = authenticated_actor.to_audit_actor(operator);
-    let cause = Cause::new(
-        String::from("adjust_bid_window"),
-        format!(
-            "Adjust bid window for user {user_initials}, round {}",
-            request.round_id
-        ),
-    );
-
-    let user_id = request.user_id;
-    let round_id = request.round_id;
-    let new_start = &request.new_window_start;
-    let new_end = &request.new_window_end;
-
-    let action = Action::new(
-        String::from("BidWindowAdjusted"),
-        Some(format!(
-            "user_id={user_id}, round_id={round_id}, previous_start={previous_start}, previous_end={previous_end}, new_start={new_start}, new_end={new_end}, reason={reason}"
-        )),
-    );
-
-    let before = StateSnapshot::new(format!(
-        "window_start={previous_start}, window_end={previous_end}"
-    ));
-    let after = StateSnapshot::new(format!("window_start={new_start}, window_end={new_end}"));
-
-    let year = persistence
-        .get_bid_year_from_id(bid_year_id)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to get bid year: {e}"),
-        })?;
-    let bid_year = BidYear::new(year);
-    let area = Area::new("_window_adjustment");
-
-    let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area);
-
-    let event_id =
+    let round_groups: Vec<RoundGroup> =
         persistence
-            .persist_audit_event(&audit_event)
+            .list_round_groups(bid_year_id)
             .map_err(|e| ApiError::Internal {
-                message: format!("Failed to persist audit event: {e}"),
-            })?;
-
-    let round_id = request.round_id;
-    Ok(AdjustBidWindowResponse {
-        audit_event_id: event_id,
-        message: format!(
-            "Adjusted bid window for user {user_initials}, round {round_id} (audit event {event_id})"
-        ),
-    })
-}
-
-/// Internal helper for bid window adjustment implementation.
-fn adjust_bid_window_impl(
-    persistence: &mut SqlitePersistence,
-    bid_year_id: i64,
-    area_id: i64,
-    user_id: i64,
-    round_id: i64,
-    new_window_start: &str,
-    new_window_end: &str,
-) -> Result<(String, String, String), ApiError> {
-    // Get user details
-    let (_user_bid_year_id, user_initials) =
-        persistence
-            .get_user_details(user_id)
-            .map_err(|_| ApiError::ResourceNotFound {
-                resource_type: String::from("User"),
-                message: format!("User with ID {user_id} not found"),
+                message: format!("Failed to list round groups: {e}"),
             })?;
 
-    // Check lifecycle state >= Canonicalized
-    let lifecycle_state =
-        persistence
-            .get_lifecycle_state(bid_year_id)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to get lifecycle state: {e}"),
+    let round_group_infos: Vec<crate::request_response::RoundGroupInfo> = round_groups
+        .into_iter()
+        .map(|rg| {
+            let round_group_id = rg.round_group_id().ok_or_else(|| ApiError::Internal {
+                message: String::from("persisted round group missing ID"),
             })?;
+            Ok(crate::request_response::RoundGroupInfo {
+                round_group_id,
+                bid_year_id,
+                name: rg.name().to_string(),
+                editing_enabled: rg.editing_enabled(),
+            })
+        })
+        .collect::<Result<Vec<_>, ApiError>>()?;
 
-    if !matches!(
-        lifecycle_state.as_str(),
-        "Canonicalized" | "BiddingActive" | "BiddingClosed"
-    ) {
-        return Err(translate_domain_error(
-            DomainError::CannotOverrideBeforeCanonicalization {
-                current_state: lifecycle_state,
-            },
-        ));
-    }
-
-    // Perform adjustment
-    let (previous_start, previous_end) = persistence
-        .adjust_bid_window(
-            bid_year_id,
-            area_id,
-            user_id,
-            round_id,
-            new_window_start,
-            new_window_end,
-        )
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to adjust bid window: {e}"),
-        })?;
-
-    Ok((user_initials, previous_start, previous_end))
+    Ok(crate::request_response::ListRoundGroupsResponse {
+        bid_year_id,
+        round_groups: round_group_infos,
+    })
 }
 
-/// Recalculate bid windows for multiple users and rounds in bulk.
+/// Updates an existing round group.
 ///
-/// This endpoint deletes existing bid windows and allows them to be recalculated.
-/// The actual recalculation logic is expected to be invoked separately.
+/// Round groups are editable in `Draft` and `BootstrapComplete` states.
+/// After canonicalization, round configuration becomes immutable (or requires override).
 ///
 /// # Arguments
 ///
-/// * `persistence` - Persistence layer
-/// * `bid_year_id` - The bid year ID
-/// * `area_id` - The area ID
-/// * `request` - The recalculation request
-/// * `authenticated_actor` - The authenticated actor performing the recalculation
-/// * `operator` - The operator data
+/// * `persistence` - The persistence layer
+/// * `request` - The round group update request
+/// * `authenticated_actor` - The authenticated actor performing the operation
 ///
 /// # Returns
 ///
-/// Returns a success response with the audit event ID.
+/// * `Ok(UpdateRoundGroupResponse)` on success
+/// * `Err(ApiError)` on validation failure or lifecycle constraint violation
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The actor is not an admin
-/// - The reason is too short
-/// - The lifecycle state is not Canonicalized or later
-/// - The database operation fails
-pub fn recalculate_bid_windows(
+/// - Actor is not authorized (Admin role required)
+/// - Round group does not exist
+/// - Lifecycle state does not allow updates
+/// - Round group name already exists (duplicate)
+///
+/// # Panics
+///
+/// Panics if the persisted round group's bid year does not have an ID.
+#[allow(dead_code)]
+pub fn update_round_group(
     persistence: &mut SqlitePersistence,
-    bid_year_id: i64,
-    area_id: i64,
-    request: &RecalculateBidWindowsRequest,
-    authenticated_actor: &AuthenticatedActor,
-    operator: &OperatorData,
-) -> Result<RecalculateBidWindowsResponse, ApiError> {
-    // Enforce authorization - only admins can perform recalculations
+    request: &crate::request_response::UpdateRoundGroupRequest,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<crate::request_response::UpdateRoundGroupResponse, ApiError> {
+    use zab_bid_domain::BidYearLifecycle;
+
+    // Enforce authorization - only admins can manage round groups
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("recalculate_bid_windows"),
+            action: String::from("update_round_group"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Validate reason (min 10 chars)
-    let reason = request.reason.trim();
-    if reason.len() < 10 {
-        return Err(translate_domain_error(DomainError::InvalidOverrideReason {
-            reason: request.reason.clone(),
-        }));
-    }
+    // Get the existing round group to find its bid_year_id
+    let existing_rg: RoundGroup = persistence
+        .get_round_group(request.round_group_id)
+        .map_err(|e| match e {
+            PersistenceError::NotFound(_) => {
+                translate_domain_error(DomainError::RoundGroupNotFound {
+                    round_group_id: request.round_group_id,
+                })
+            }
+            _ => ApiError::Internal {
+                message: format!("Failed to get round group: {e}"),
+            },
+        })?;
 
-    // Check lifecycle state >= Canonicalized
-    let lifecycle_state =
+    let bid_year_id = existing_rg
+        .bid_year()
+        .bid_year_id()
+        .ok_or_else(|| ApiError::Internal {
+            message: String::from("persisted bid year missing ID"),
+        })?;
+
+    // Enforce lifecycle constraints
+    let lifecycle_state_str: String =
         persistence
             .get_lifecycle_state(bid_year_id)
             .map_err(|e| ApiError::Internal {
                 message: format!("Failed to get lifecycle state: {e}"),
             })?;
 
-    if !matches!(
-        lifecycle_state.as_str(),
-        "Canonicalized" | "BiddingActive" | "BiddingClosed"
-    ) {
-        return Err(translate_domain_error(
-            DomainError::CannotOverrideBeforeCanonicalization {
-                current_state: lifecycle_state,
-            },
-        ));
-    }
-
-    // Delete existing bid windows for the specified users and rounds
-    let windows_deleted = persistence
-        .delete_bid_windows_for_users_and_rounds(
-            bid_year_id,
-            area_id,
-            &request.user_ids,
-            &request.rounds,
-        )
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to delete bid windows: {e}"),
-        })?;
+    let lifecycle_state: BidYearLifecycle = lifecycle_state_str
+        .parse()
+        .map_err(translate_domain_error)?;
 
-    // Create and persist audit event
-    let actor = authenticated_actor.to_audit_actor(operator);
-    let cause = Cause::new(
-        String::from("recalculate_bid_windows"),
-        format!(
-            "Bulk bid window recalculation for {} users, {} rounds",
-            request.user_ids.len(),
-            request.rounds.len()
-        ),
-    );
+    if lifecycle_state.is_locked() {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("round_group_lifecycle"),
+            message: format!(
+                "Cannot update round group in state '{lifecycle_state}': structural changes locked after confirmation"
+            ),
+        });
+    }
 
-    let action = Action::new(
-        String::from("BulkBidWindowRecalculation"),
-        Some(format!(
-            "area_id={area_id}, user_count={}, round_count={}, windows_deleted={windows_deleted}, reason={reason}",
-            request.user_ids.len(),
-            request.rounds.len()
-        )),
-    );
+    require_not_frozen(persistence, bid_year_id, None)?;
 
-    let before = StateSnapshot::new(format!("windows_existed={windows_deleted}"));
-    let after = StateSnapshot::new(String::from("windows_deleted_for_recalculation"));
+    // Validate round group name is not empty
+    if request.name.trim().is_empty() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("name"),
+            message: String::from("Round group name cannot be empty"),
+        });
+    }
 
-    let year = persistence
-        .get_bid_year_from_id(bid_year_id)
+    // Check for duplicate name (excluding this round group)
+    let name_exists = persistence
+        .round_group_name_exists(bid_year_id, &request.name, Some(request.round_group_id))
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to get bid year: {e}"),
+            message: format!("Failed to check round group name: {e}"),
         })?;
-    let bid_year = BidYear::new(year);
-    let area = Area::new("_window_recalculation");
 
-    let audit_event = AuditEvent::new(actor, cause, action, before, after, bid_year, area);
+    if name_exists {
+        return Err(translate_domain_error(
+            DomainError::DuplicateRoundGroupName {
+                bid_year: 0,
+                name: request.name.clone(),
+            },
+        ));
+    }
 
-    let event_id =
-        persistence
-            .persist_audit_event(&audit_event)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to persist audit event: {e}"),
-            })?;
+    // Update the round group
+    persistence
+        .update_round_group(
+            request.round_group_id,
+            &request.name,
+            request.editing_enabled,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to update round group: {e}"),
+        })?;
 
-    Ok(RecalculateBidWindowsResponse {
-        audit_event_id: event_id,
-        windows_recalculated: windows_deleted,
-        message: format!(
-            "Deleted {windows_deleted} bid windows for recalculation (audit event {event_id})"
-        ),
+    Ok(crate::request_response::UpdateRoundGroupResponse {
+        round_group_id: request.round_group_id,
+        bid_year_id,
+        name: request.name.clone(),
+        editing_enabled: request.editing_enabled,
+        message: format!("Updated round group '{}'", request.name),
     })
 }
 
-/// Update a user's participation flags.
-///
-/// Phase 29A: Controls bid order derivation and leave calculation inclusion.
-///
-/// # Directional Invariant
-///
-/// `excluded_from_leave_calculation == true` ⇒ `excluded_from_bidding == true`
-///
-/// A user may never be included in bidding while excluded from leave calculation.
-///
-/// # Lifecycle Constraints
+/// Deletes a round group.
 ///
-/// Flags are editable in `Draft` and `BootstrapComplete` states.
-/// After canonicalization, flags become immutable (or require override).
+/// Round groups can only be deleted if no rounds reference them.
+/// Deletion is only allowed in `Draft` and `BootstrapComplete` states.
 ///
 /// # Arguments
 ///
-/// * `metadata` - Bootstrap metadata
-/// * `persistence` - Persistence layer
-/// * `request` - The participation flag update request
-/// * `authenticated_actor` - The authenticated actor performing the update
+/// * `persistence` - The persistence layer
+/// * `round_group_id` - The round group ID to delete
+/// * `authenticated_actor` - The authenticated actor performing the operation
 ///
 /// # Returns
 ///
-/// * `Ok(UpdateUserParticipationResponse)` on success
+/// * `Ok(DeleteRoundGroupResponse)` on success
 /// * `Err(ApiError)` on validation failure or lifecycle constraint violation
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - User does not exist
-/// - Directional invariant is violated
-/// - Lifecycle state does not allow flag updates
-#[allow(clippy::too_many_arguments)]
-pub fn update_user_participation(
-    metadata: &BootstrapMetadata,
+/// - Actor is not authorized (Admin role required)
+/// - Round group does not exist
+/// - Lifecycle state does not allow deletion
+/// - Round group is referenced by rounds
+///
+/// # Panics
+///
+/// Panics if the persisted round group's bid year does not have an ID.
+#[allow(dead_code)]
+pub fn delete_round_group(
     persistence: &mut SqlitePersistence,
-    request: &crate::request_response::UpdateUserParticipationRequest,
-    authenticated_actor: &Actor,
-    lifecycle_state: zab_bid_domain::BidYearLifecycle,
-) -> Result<crate::request_response::UpdateUserParticipationResponse, ApiError> {
-    use zab_bid_domain::DomainError;
+    round_group_id: i64,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<crate::request_response::DeleteRoundGroupResponse, ApiError> {
+    use zab_bid_domain::BidYearLifecycle;
 
-    // Enforce lifecycle constraints: participation flags locked after Canonicalized
-    if lifecycle_state.is_locked() {
-        return Err(ApiError::DomainRuleViolation {
-            rule: String::from("participation_flags_lifecycle"),
-            message: format!(
-                "Cannot update participation flags in state '{lifecycle_state}': structural changes locked after confirmation"
-            ),
+    // Enforce authorization - only admins can manage round groups
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("delete_round_group"),
+            required_role: String::from("Admin"),
         });
     }
 
-    // Validate directional invariant before constructing command
-    if request.excluded_from_leave_calculation && !request.excluded_from_bidding {
-        return Err(translate_domain_error(
-            DomainError::ParticipationFlagViolation {
-                user_initials: format!("user_id={}", request.user_id),
-                reason: String::from(
-                    "User excluded from leave calculation must also be excluded from bidding",
-                ),
-            },
-        ));
-    }
-
-    // Resolve the active bid year from canonical state
-    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
-
-    // Find bid_year_id
-    let bid_year_id: i64 = metadata
-        .bid_years
-        .iter()
-        .find(|by| by.year() == active_bid_year.year())
-        .and_then(BidYear::bid_year_id)
-        .ok_or_else(|| ApiError::Internal {
-            message: format!(
-                "Active bid year {} has no ID in metadata",
-                active_bid_year.year()
-            ),
-        })?;
-
-    // We need to iterate through all areas to find the user
-    // since we don't know which area the user is in
-    let mut found_user: Option<(zab_bid_domain::User, Area, State)> = None;
+    // Get the existing round group to find its bid_year_id
+    let existing_rg: RoundGroup =
+        persistence
+            .get_round_group(round_group_id)
+            .map_err(|e| match e {
+                PersistenceError::NotFound(_) => {
+                    translate_domain_error(DomainError::RoundGroupNotFound { round_group_id })
+                }
+                _ => ApiError::Internal {
+                    message: format!("Failed to get round group: {e}"),
+                },
+            })?;
 
-    for (by, area_meta) in &metadata.areas {
-        if by.year() != active_bid_year.year() {
-            continue;
-        }
+    let bid_year_id = existing_rg
+        .bid_year()
+        .bid_year_id()
+        .ok_or_else(|| ApiError::Internal {
+            message: String::from("persisted bid year missing ID"),
+        })?;
 
-        let area = Area::new(area_meta.area_code());
+    // Enforce lifecycle constraints
+    let lifecycle_state_str: String =
+        persistence
+            .get_lifecycle_state(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get lifecycle state: {e}"),
+            })?;
 
-        // Try to load state for this area
-        let Ok(state) = persistence.get_current_state(&active_bid_year, &area) else {
-            continue; // Skip areas with no state
-        };
+    let lifecycle_state: BidYearLifecycle = lifecycle_state_str
+        .parse()
+        .map_err(translate_domain_error)?;
 
-        // Check if the user is in this area
-        if let Some(user) = state
-            .users
-            .iter()
-            .find(|u| u.user_id == Some(request.user_id))
-        {
-            found_user = Some((user.clone(), area, state));
-            break;
-        }
+    if lifecycle_state.is_locked() {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("round_group_lifecycle"),
+            message: format!(
+                "Cannot delete round group in state '{lifecycle_state}': structural changes locked after confirmation"
+            ),
+        });
     }
 
-    let (user, _area, state) = found_user.ok_or_else(|| ApiError::ResourceNotFound {
-        resource_type: String::from("User"),
-        message: format!(
-            "User with user_id={} not found in active bid year",
-            request.user_id
-        ),
-    })?;
+    require_not_frozen(persistence, bid_year_id, None)?;
 
-    // Create the command
-    let command: Command = Command::UpdateUserParticipation {
-        user_id: request.user_id,
-        initials: user.initials.clone(),
-        excluded_from_bidding: request.excluded_from_bidding,
-        excluded_from_leave_calculation: request.excluded_from_leave_calculation,
-    };
+    // Check if round group is in use
+    let round_count = persistence
+        .count_rounds_using_group(round_group_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to check round group usage: {e}"),
+        })?;
 
-    // Apply the command
-    let cause = Cause::new(
-        String::from("update_user_participation"),
-        format!(
-            "Update participation flags for user {}",
-            user.initials.value()
-        ),
-    );
-    let result: TransitionResult = apply(
-        metadata,
-        &state,
-        &active_bid_year,
-        command,
-        authenticated_actor.clone(),
-        cause,
-    )
-    .map_err(translate_core_error)?;
+    if round_count > 0 {
+        return Err(translate_domain_error(DomainError::RoundGroupInUse {
+            round_group_id,
+            round_count,
+        }));
+    }
 
-    // Persist the audit event and new state
+    // Delete the round group
     persistence
-        .persist_audit_event(&result.audit_event)
+        .delete_round_group(round_group_id)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to persist audit event: {e}"),
+            message: format!("Failed to delete round group: {e}"),
         })?;
 
-    Ok(crate::request_response::UpdateUserParticipationResponse {
-        bid_year_id,
-        bid_year: active_bid_year.year(),
-        user_id: request.user_id,
-        initials: user.initials.value().to_string(),
-        excluded_from_bidding: request.excluded_from_bidding,
-        excluded_from_leave_calculation: request.excluded_from_leave_calculation,
-        message: format!(
-            "Updated participation flags for user '{}'",
-            user.initials.value()
-        ),
+    Ok(crate::request_response::DeleteRoundGroupResponse {
+        message: format!("Deleted round group '{}'", existing_rg.name()),
     })
 }
 
-// TODO Phase 26C: Add integration tests for update_area handler:
-// - test_update_area_allowed_in_draft
-// - test_update_area_denied_after_canonicalization
-// - test_update_area_denied_for_system_area
-// - test_update_area_requires_admin
-// - test_update_area_creates_audit_event
-
-// ============================================================================
-// Phase 29B: Round Groups and Rounds
-// ============================================================================
-
-/// Creates a new round group for a bid year.
+/// Creates a new round in a round group.
 ///
-/// Round groups are editable in `Draft` and `BootstrapComplete` states.
+/// Rounds are editable in `Draft` and `BootstrapComplete` states.
 /// After canonicalization, round configuration becomes immutable (or requires override).
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
-/// * `bid_year_id` - The bid year ID this round group belongs to
-/// * `request` - The round group creation request
+/// * `round_group_id` - The round group ID this round belongs to
+/// * `request` - The round creation request
 /// * `authenticated_actor` - The authenticated actor performing the operation
 ///
 /// # Returns
 ///
-/// * `Ok(CreateRoundGroupResponse)` on success
+/// * `Ok(CreateRoundResponse)` on success
 /// * `Err(ApiError)` on validation failure or lifecycle constraint violation
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Actor is not authorized (Admin role required)
-/// - Lifecycle state does not allow round group creation
-/// - Round group name already exists in bid year
-/// - Validation fails
+/// - Round group does not exist
+/// - Lifecycle state does not allow round creation
+/// - Round number already exists in round group
+/// - Validation fails (`slots_per_day`, `max_groups`, `max_total_hours` must be > 0)
+///
+/// # Panics
+///
+/// Panics if the persisted round group does not have a `bid_year_id`.
 #[allow(dead_code)]
-pub fn create_round_group(
+#[allow(clippy::too_many_lines)]
+pub fn create_round(
     persistence: &mut SqlitePersistence,
-    bid_year_id: i64,
-    request: &crate::request_response::CreateRoundGroupRequest,
+    round_group_id: i64,
+    request: &crate::request_response::CreateRoundRequest,
     authenticated_actor: &AuthenticatedActor,
-) -> Result<crate::request_response::CreateRoundGroupResponse, ApiError> {
+) -> Result<crate::request_response::CreateRoundResponse, ApiError> {
     use zab_bid_domain::BidYearLifecycle;
 
-    // Enforce authorization - only admins can manage round groups
+    // Enforce authorization - only admins can manage rounds
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("create_round_group"),
+            action: String::from("create_round"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Enforce lifecycle constraints: round configuration locked after Canonicalized
+    // Get area to validate it exists and get bid_year_id
+    // Verify round group exists and get its bid year
+    let round_group = persistence
+        .get_round_group(round_group_id)
+        .map_err(|e| match e {
+            PersistenceError::NotFound(_) => {
+                translate_domain_error(DomainError::RoundGroupNotFound { round_group_id })
+            }
+            _ => ApiError::Internal {
+                message: format!("Failed to get round group: {e}"),
+            },
+        })?;
+
+    let bid_year_id = round_group
+        .bid_year()
+        .bid_year_id()
+        .ok_or_else(|| ApiError::Internal {
+            message: String::from("persisted bid year missing ID"),
+        })?;
+
+    // Enforce lifecycle constraints
     let lifecycle_state_str: String =
         persistence
             .get_lifecycle_state(bid_year_id)
@@ -5713,64 +8121,91 @@ pub fn create_round_group(
 
     if lifecycle_state.is_locked() {
         return Err(ApiError::DomainRuleViolation {
-            rule: String::from("round_group_lifecycle"),
+            rule: String::from("round_lifecycle"),
             message: format!(
-                "Cannot create round group in state '{lifecycle_state}': structural changes locked after confirmation"
+                "Cannot create round in state '{lifecycle_state}': structural changes locked after confirmation"
             ),
         });
     }
 
-    // Validate round group name is not empty
+    require_not_frozen(persistence, bid_year_id, None)?;
+
+    // Validate round configuration
+    if request.slots_per_day == 0 {
+        return Err(ApiError::InvalidInput {
+            field: String::from("slots_per_day"),
+            message: String::from("slots_per_day must be greater than 0"),
+        });
+    }
+    if request.max_groups == 0 {
+        return Err(ApiError::InvalidInput {
+            field: String::from("max_groups"),
+            message: String::from("max_groups must be greater than 0"),
+        });
+    }
+    if request.max_total_hours == 0 {
+        return Err(ApiError::InvalidInput {
+            field: String::from("max_total_hours"),
+            message: String::from("max_total_hours must be greater than 0"),
+        });
+    }
     if request.name.trim().is_empty() {
         return Err(ApiError::InvalidInput {
             field: String::from("name"),
-            message: String::from("Round group name cannot be empty"),
+            message: String::from("Round name cannot be empty"),
         });
     }
 
-    // Check for duplicate name
-    let name_exists = persistence
-        .round_group_name_exists(bid_year_id, &request.name, None)
+    // Check for duplicate round number within the round group
+    let round_number_exists = persistence
+        .round_number_exists(round_group_id, request.round_number, None)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to check round group name: {e}"),
+            message: format!("Failed to check round number: {e}"),
         })?;
 
-    if name_exists {
-        return Err(translate_domain_error(
-            DomainError::DuplicateRoundGroupName {
-                bid_year: 0, // We don't have the year value here, but error translation handles it
-                name: request.name.clone(),
-            },
-        ));
+    if round_number_exists {
+        return Err(translate_domain_error(DomainError::DuplicateRoundNumber {
+            area_code: round_group.name().to_string(),
+            round_number: request.round_number,
+        }));
     }
 
-    // Insert the round group
-    let round_group_id = persistence
-        .insert_round_group(bid_year_id, &request.name, request.editing_enabled)
+    // Insert the round
+    let round_id = persistence
+        .insert_round(
+            round_group_id,
+            request.round_number,
+            &request.name,
+            request.slots_per_day,
+            request.max_groups,
+            request.max_total_hours,
+            request.include_holidays,
+            request.allow_overbid,
+        )
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to insert round group: {e}"),
+            message: format!("Failed to insert round: {e}"),
         })?;
 
-    Ok(crate::request_response::CreateRoundGroupResponse {
+    Ok(crate::request_response::CreateRoundResponse {
+        round_id,
         round_group_id,
-        bid_year_id,
+        round_number: request.round_number,
         name: request.name.clone(),
-        editing_enabled: request.editing_enabled,
-        message: format!("Created round group '{}'", request.name),
+        message: format!("Created round {} '{}'", request.round_number, request.name),
     })
 }
 
-/// Lists all round groups for a bid year.
+/// Lists all rounds in a round group.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
-/// * `bid_year_id` - The bid year ID
+/// * `round_group_id` - The round group ID
 /// * `authenticated_actor` - The authenticated actor performing the operation
 ///
 /// # Returns
 ///
-/// * `Ok(ListRoundGroupsResponse)` on success
+/// * `Ok(ListRoundsResponse)` on success
 /// * `Err(ApiError)` on query failure
 ///
 /// # Errors
@@ -5781,107 +8216,133 @@ pub fn create_round_group(
 ///
 /// # Panics
 ///
-/// Panics if a persisted round group does not have an ID.
+/// Panics if a persisted round or its round group does not have an ID.
 #[allow(dead_code)]
-pub fn list_round_groups(
+pub fn list_rounds(
     persistence: &mut SqlitePersistence,
-    bid_year_id: i64,
+    round_group_id: i64,
     authenticated_actor: &AuthenticatedActor,
-) -> Result<crate::request_response::ListRoundGroupsResponse, ApiError> {
-    // Enforce authorization - only admins can view round groups
+) -> Result<crate::request_response::ListRoundsResponse, ApiError> {
+    // Enforce authorization - only admins can view rounds
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("list_round_groups"),
+            action: String::from("list_rounds"),
             required_role: String::from("Admin"),
         });
     }
 
-    let round_groups: Vec<RoundGroup> =
-        persistence
-            .list_round_groups(bid_year_id)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to list round groups: {e}"),
-            })?;
+    let rounds = persistence
+        .list_rounds(round_group_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list rounds: {e}"),
+        })?;
 
-    let round_group_infos: Vec<crate::request_response::RoundGroupInfo> = round_groups
+    let round_infos: Vec<crate::request_response::RoundInfo> = rounds
         .into_iter()
-        .map(|rg| {
-            let round_group_id = rg.round_group_id().ok_or_else(|| ApiError::Internal {
-                message: String::from("persisted round group missing ID"),
+        .map(|r| {
+            let round_id = r.round_id().ok_or_else(|| ApiError::Internal {
+                message: String::from("persisted round missing ID"),
             })?;
-            Ok(crate::request_response::RoundGroupInfo {
+            let round_group_id =
+                r.round_group()
+                    .round_group_id()
+                    .ok_or_else(|| ApiError::Internal {
+                        message: String::from("persisted round group missing ID"),
+                    })?;
+            Ok(crate::request_response::RoundInfo {
+                round_id,
                 round_group_id,
-                bid_year_id,
-                name: rg.name().to_string(),
-                editing_enabled: rg.editing_enabled(),
+                name: r.name().to_string(),
+                round_number: r.round_number(),
+                slots_per_day: r.slots_per_day(),
+                max_groups: r.max_groups(),
+                max_total_hours: r.max_total_hours(),
+                include_holidays: r.include_holidays(),
+                allow_overbid: r.allow_overbid(),
+                eligibility: get_round_eligibility_info(persistence, round_id)?,
             })
         })
         .collect::<Result<Vec<_>, ApiError>>()?;
 
-    Ok(crate::request_response::ListRoundGroupsResponse {
-        bid_year_id,
-        round_groups: round_group_infos,
+    Ok(crate::request_response::ListRoundsResponse {
+        round_group_id,
+        rounds: round_infos,
     })
 }
 
-/// Updates an existing round group.
+/// Updates an existing round.
 ///
-/// Round groups are editable in `Draft` and `BootstrapComplete` states.
+/// Rounds are editable in `Draft` and `BootstrapComplete` states.
 /// After canonicalization, round configuration becomes immutable (or requires override).
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
-/// * `request` - The round group update request
+/// * `request` - The round update request
 /// * `authenticated_actor` - The authenticated actor performing the operation
 ///
 /// # Returns
 ///
-/// * `Ok(UpdateRoundGroupResponse)` on success
+/// * `Ok(UpdateRoundResponse)` on success
 /// * `Err(ApiError)` on validation failure or lifecycle constraint violation
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Actor is not authorized (Admin role required)
-/// - Round group does not exist
+/// - Round does not exist
 /// - Lifecycle state does not allow updates
-/// - Round group name already exists (duplicate)
+/// - Round number already exists (duplicate)
+/// - Validation fails
 ///
 /// # Panics
 ///
-/// Panics if the persisted round group's bid year does not have an ID.
+/// Panics if the persisted round's round group does not have an ID or `bid_year_id`.
 #[allow(dead_code)]
-pub fn update_round_group(
+#[allow(clippy::too_many_lines)]
+pub fn update_round(
     persistence: &mut SqlitePersistence,
-    request: &crate::request_response::UpdateRoundGroupRequest,
+    request: &crate::request_response::UpdateRoundRequest,
     authenticated_actor: &AuthenticatedActor,
-) -> Result<crate::request_response::UpdateRoundGroupResponse, ApiError> {
+) -> Result<crate::request_response::UpdateRoundResponse, ApiError> {
     use zab_bid_domain::BidYearLifecycle;
 
-    // Enforce authorization - only admins can manage round groups
+    // Enforce authorization - only admins can manage rounds
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("update_round_group"),
+            action: String::from("update_round"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Get the existing round group to find its bid_year_id
-    let existing_rg: RoundGroup = persistence
-        .get_round_group(request.round_group_id)
+    // Get the existing round to find its round_group_id and bid_year_id
+    let existing_round = persistence
+        .get_round(request.round_id)
         .map_err(|e| match e {
-            PersistenceError::NotFound(_) => {
-                translate_domain_error(DomainError::RoundGroupNotFound {
-                    round_group_id: request.round_group_id,
-                })
-            }
+            PersistenceError::NotFound(_) => translate_domain_error(DomainError::RoundNotFound {
+                round_id: request.round_id,
+            }),
             _ => ApiError::Internal {
-                message: format!("Failed to get round group: {e}"),
+                message: format!("Failed to get round: {e}"),
             },
         })?;
 
-    let bid_year_id = existing_rg
+    let round_group_id = existing_round
+        .round_group()
+        .round_group_id()
+        .ok_or_else(|| ApiError::Internal {
+            message: String::from("persisted round group missing ID"),
+        })?;
+
+    // Get bid_year_id from the round group
+    let round_group =
+        persistence
+            .get_round_group(round_group_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get round group: {e}"),
+            })?;
+
+    let bid_year_id = round_group
         .bid_year()
         .bid_year_id()
         .ok_or_else(|| ApiError::Internal {
@@ -5902,114 +8363,145 @@ pub fn update_round_group(
 
     if lifecycle_state.is_locked() {
         return Err(ApiError::DomainRuleViolation {
-            rule: String::from("round_group_lifecycle"),
+            rule: String::from("round_lifecycle"),
             message: format!(
-                "Cannot update round group in state '{lifecycle_state}': structural changes locked after confirmation"
+                "Cannot update round in state '{lifecycle_state}': structural changes locked after confirmation"
             ),
         });
     }
 
-    // Validate round group name is not empty
+    require_not_frozen(persistence, bid_year_id, None)?;
+
+    // Validate round configuration
+    if request.slots_per_day == 0 {
+        return Err(ApiError::InvalidInput {
+            field: String::from("slots_per_day"),
+            message: String::from("slots_per_day must be greater than 0"),
+        });
+    }
+    if request.max_groups == 0 {
+        return Err(ApiError::InvalidInput {
+            field: String::from("max_groups"),
+            message: String::from("max_groups must be greater than 0"),
+        });
+    }
+    if request.max_total_hours == 0 {
+        return Err(ApiError::InvalidInput {
+            field: String::from("max_total_hours"),
+            message: String::from("max_total_hours must be greater than 0"),
+        });
+    }
     if request.name.trim().is_empty() {
         return Err(ApiError::InvalidInput {
             field: String::from("name"),
-            message: String::from("Round group name cannot be empty"),
+            message: String::from("Round name cannot be empty"),
         });
     }
 
-    // Check for duplicate name (excluding this round group)
-    let name_exists = persistence
-        .round_group_name_exists(bid_year_id, &request.name, Some(request.round_group_id))
+    // Check for duplicate round number within the round group (excluding this round)
+    let round_number_exists = persistence
+        .round_number_exists(round_group_id, request.round_number, Some(request.round_id))
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to check round group name: {e}"),
+            message: format!("Failed to check round number: {e}"),
         })?;
 
-    if name_exists {
-        return Err(translate_domain_error(
-            DomainError::DuplicateRoundGroupName {
-                bid_year: 0,
-                name: request.name.clone(),
-            },
-        ));
+    if round_number_exists {
+        return Err(translate_domain_error(DomainError::DuplicateRoundNumber {
+            area_code: round_group.name().to_string(),
+            round_number: request.round_number,
+        }));
     }
 
-    // Update the round group
+    // Update the round
     persistence
-        .update_round_group(
-            request.round_group_id,
+        .update_round(
+            request.round_id,
             &request.name,
-            request.editing_enabled,
+            request.slots_per_day,
+            request.max_groups,
+            request.max_total_hours,
+            request.include_holidays,
+            request.allow_overbid,
         )
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to update round group: {e}"),
+            message: format!("Failed to update round: {e}"),
         })?;
 
-    Ok(crate::request_response::UpdateRoundGroupResponse {
-        round_group_id: request.round_group_id,
-        bid_year_id,
+    Ok(crate::request_response::UpdateRoundResponse {
+        round_id: request.round_id,
+        round_group_id,
+        round_number: request.round_number,
         name: request.name.clone(),
-        editing_enabled: request.editing_enabled,
-        message: format!("Updated round group '{}'", request.name),
+        message: format!("Updated round {} '{}'", request.round_number, request.name),
     })
 }
 
-/// Deletes a round group.
+/// Deletes a round.
 ///
-/// Round groups can only be deleted if no rounds reference them.
-/// Deletion is only allowed in `Draft` and `BootstrapComplete` states.
+/// Rounds can be deleted only in `Draft` and `BootstrapComplete` states.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
-/// * `round_group_id` - The round group ID to delete
+/// * `round_id` - The round ID to delete
 /// * `authenticated_actor` - The authenticated actor performing the operation
 ///
 /// # Returns
 ///
-/// * `Ok(DeleteRoundGroupResponse)` on success
+/// * `Ok(DeleteRoundResponse)` on success
 /// * `Err(ApiError)` on validation failure or lifecycle constraint violation
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Actor is not authorized (Admin role required)
-/// - Round group does not exist
+/// - Round does not exist
 /// - Lifecycle state does not allow deletion
-/// - Round group is referenced by rounds
 ///
 /// # Panics
 ///
-/// Panics if the persisted round group's bid year does not have an ID.
+/// Panics if the persisted round's round group does not have an ID or `bid_year_id`.
 #[allow(dead_code)]
-pub fn delete_round_group(
+pub fn delete_round(
     persistence: &mut SqlitePersistence,
-    round_group_id: i64,
+    round_id: i64,
     authenticated_actor: &AuthenticatedActor,
-) -> Result<crate::request_response::DeleteRoundGroupResponse, ApiError> {
+) -> Result<crate::request_response::DeleteRoundResponse, ApiError> {
     use zab_bid_domain::BidYearLifecycle;
 
-    // Enforce authorization - only admins can manage round groups
+    // Enforce authorization - only admins can manage rounds
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("delete_round_group"),
+            action: String::from("delete_round"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Get the existing round group to find its bid_year_id
-    let existing_rg: RoundGroup =
+    // Get the existing round to find its bid_year_id
+    let existing_round = persistence.get_round(round_id).map_err(|e| match e {
+        PersistenceError::NotFound(_) => {
+            translate_domain_error(DomainError::RoundNotFound { round_id })
+        }
+        _ => ApiError::Internal {
+            message: format!("Failed to get round: {e}"),
+        },
+    })?;
+
+    // Get bid_year_id from the round group
+    let round_group_id = existing_round
+        .round_group()
+        .round_group_id()
+        .ok_or_else(|| ApiError::Internal {
+            message: String::from("persisted round group missing ID"),
+        })?;
+    let round_group =
         persistence
             .get_round_group(round_group_id)
-            .map_err(|e| match e {
-                PersistenceError::NotFound(_) => {
-                    translate_domain_error(DomainError::RoundGroupNotFound { round_group_id })
-                }
-                _ => ApiError::Internal {
-                    message: format!("Failed to get round group: {e}"),
-                },
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get round group: {e}"),
             })?;
 
-    let bid_year_id = existing_rg
+    let bid_year_id = round_group
         .bid_year()
         .bid_year_id()
         .ok_or_else(|| ApiError::Internal {
@@ -6030,88 +8522,65 @@ pub fn delete_round_group(
 
     if lifecycle_state.is_locked() {
         return Err(ApiError::DomainRuleViolation {
-            rule: String::from("round_group_lifecycle"),
+            rule: String::from("round_lifecycle"),
             message: format!(
-                "Cannot delete round group in state '{lifecycle_state}': structural changes locked after confirmation"
+                "Cannot delete round in state '{lifecycle_state}': structural changes locked after confirmation"
             ),
         });
     }
 
-    // Check if round group is in use
-    let round_count = persistence
-        .count_rounds_using_group(round_group_id)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to check round group usage: {e}"),
-        })?;
-
-    if round_count > 0 {
-        return Err(translate_domain_error(DomainError::RoundGroupInUse {
-            round_group_id,
-            round_count,
-        }));
-    }
+    require_not_frozen(persistence, bid_year_id, None)?;
 
-    // Delete the round group
+    // Delete the round
     persistence
-        .delete_round_group(round_group_id)
+        .delete_round(round_id)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to delete round group: {e}"),
+            message: format!("Failed to delete round: {e}"),
         })?;
 
-    Ok(crate::request_response::DeleteRoundGroupResponse {
-        message: format!("Deleted round group '{}'", existing_rg.name()),
+    Ok(crate::request_response::DeleteRoundResponse {
+        message: format!(
+            "Deleted round {} '{}'",
+            existing_round.round_number(),
+            existing_round.name()
+        ),
     })
 }
 
-/// Creates a new round in a round group.
+/// Renumbers the rounds of a round group.
 ///
-/// Rounds are editable in `Draft` and `BootstrapComplete` states.
-/// After canonicalization, round configuration becomes immutable (or requires override).
+/// The rounds are renumbered 1..n in the requested order. This allows a
+/// round to be created with any free number and then moved into place,
+/// instead of deleting and recreating every round after it.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
-/// * `round_group_id` - The round group ID this round belongs to
-/// * `request` - The round creation request
+/// * `request` - The reorder request
 /// * `authenticated_actor` - The authenticated actor performing the operation
 ///
-/// # Returns
-///
-/// * `Ok(CreateRoundResponse)` on success
-/// * `Err(ApiError)` on validation failure or lifecycle constraint violation
-///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Actor is not authorized (Admin role required)
 /// - Round group does not exist
-/// - Lifecycle state does not allow round creation
-/// - Round number already exists in round group
-/// - Validation fails (`slots_per_day`, `max_groups`, `max_total_hours` must be > 0)
-///
-/// # Panics
-///
-/// Panics if the persisted round group does not have a `bid_year_id`.
-#[allow(dead_code)]
-#[allow(clippy::too_many_lines)]
-pub fn create_round(
+/// - Lifecycle state does not allow round changes
+/// - `round_ids` does not list every round of the group exactly once
+pub fn reorder_rounds(
     persistence: &mut SqlitePersistence,
-    round_group_id: i64,
-    request: &crate::request_response::CreateRoundRequest,
+    request: &crate::request_response::ReorderRoundsRequest,
     authenticated_actor: &AuthenticatedActor,
-) -> Result<crate::request_response::CreateRoundResponse, ApiError> {
+) -> Result<crate::request_response::ReorderRoundsResponse, ApiError> {
     use zab_bid_domain::BidYearLifecycle;
 
-    // Enforce authorization - only admins can manage rounds
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("create_round"),
+            action: String::from("reorder_rounds"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Get area to validate it exists and get bid_year_id
-    // Verify round group exists and get its bid year
+    let round_group_id: i64 = request.round_group_id;
     let round_group = persistence
         .get_round_group(round_group_id)
         .map_err(|e| match e {
@@ -6130,7 +8599,6 @@ pub fn create_round(
             message: String::from("persisted bid year missing ID"),
         })?;
 
-    // Enforce lifecycle constraints
     let lifecycle_state_str: String =
         persistence
             .get_lifecycle_state(bid_year_id)
@@ -6146,420 +8614,543 @@ pub fn create_round(
         return Err(ApiError::DomainRuleViolation {
             rule: String::from("round_lifecycle"),
             message: format!(
-                "Cannot create round in state '{lifecycle_state}': structural changes locked after confirmation"
+                "Cannot reorder rounds in state '{lifecycle_state}': structural changes locked after confirmation"
             ),
         });
     }
 
-    // Validate round configuration
-    if request.slots_per_day == 0 {
+    require_not_frozen(persistence, bid_year_id, None)?;
+
+    let mut existing_ids: Vec<i64> = persistence
+        .list_rounds(round_group_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list rounds: {e}"),
+        })?
+        .iter()
+        .filter_map(zab_bid_domain::Round::round_id)
+        .collect();
+    existing_ids.sort_unstable();
+    let mut requested_ids: Vec<i64> = request.round_ids.clone();
+    requested_ids.sort_unstable();
+    if existing_ids != requested_ids {
         return Err(ApiError::InvalidInput {
-            field: String::from("slots_per_day"),
-            message: String::from("slots_per_day must be greater than 0"),
+            field: String::from("round_ids"),
+            message: format!(
+                "round_ids must list each of the {} round(s) in round group '{}' exactly once",
+                existing_ids.len(),
+                round_group.name()
+            ),
         });
     }
-    if request.max_groups == 0 {
-        return Err(ApiError::InvalidInput {
-            field: String::from("max_groups"),
-            message: String::from("max_groups must be greater than 0"),
+
+    persistence
+        .reorder_rounds(round_group_id, &request.round_ids)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to reorder rounds: {e}"),
+        })?;
+
+    let mut rounds: Vec<crate::request_response::RoundInfo> =
+        list_rounds(persistence, round_group_id, authenticated_actor)?.rounds;
+    rounds.sort_by_key(|r| r.round_number);
+
+    Ok(crate::request_response::ReorderRoundsResponse {
+        round_group_id,
+        message: format!(
+            "Renumbered {} round(s) in round group '{}'",
+            rounds.len(),
+            round_group.name()
+        ),
+        rounds,
+    })
+}
+
+/// Validates the rounds of a round group template.
+///
+/// Applies the same rules as round creation, and additionally requires
+/// round numbers to be unique within the template.
+fn validate_round_template_specs(
+    rounds: &[crate::request_response::RoundTemplateSpec],
+) -> Result<(), ApiError> {
+    let mut seen_round_numbers: std::collections::HashSet<u32> = std::collections::HashSet::new();
+
+    for round in rounds {
+        if round.slots_per_day == 0 {
+            return Err(ApiError::InvalidInput {
+                field: String::from("slots_per_day"),
+                message: format!(
+                    "slots_per_day must be greater than 0 (round {})",
+                    round.round_number
+                ),
+            });
+        }
+        if round.max_groups == 0 {
+            return Err(ApiError::InvalidInput {
+                field: String::from("max_groups"),
+                message: format!(
+                    "max_groups must be greater than 0 (round {})",
+                    round.round_number
+                ),
+            });
+        }
+        if round.max_total_hours == 0 {
+            return Err(ApiError::InvalidInput {
+                field: String::from("max_total_hours"),
+                message: format!(
+                    "max_total_hours must be greater than 0 (round {})",
+                    round.round_number
+                ),
+            });
+        }
+        if round.name.trim().is_empty() {
+            return Err(ApiError::InvalidInput {
+                field: String::from("name"),
+                message: format!("Round name cannot be empty (round {})", round.round_number),
+            });
+        }
+        if !seen_round_numbers.insert(round.round_number) {
+            return Err(ApiError::InvalidInput {
+                field: String::from("rounds"),
+                message: format!(
+                    "Round number {} appears more than once in the template",
+                    round.round_number
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensures round configuration in a bid year can still be changed.
+fn require_round_config_unlocked(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    operation: &str,
+) -> Result<(), ApiError> {
+    use zab_bid_domain::BidYearLifecycle;
+
+    let lifecycle_state_str: String =
+        persistence
+            .get_lifecycle_state(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get lifecycle state: {e}"),
+            })?;
+
+    let lifecycle_state: BidYearLifecycle = lifecycle_state_str
+        .parse()
+        .map_err(translate_domain_error)?;
+
+    if lifecycle_state.is_locked() {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("round_group_lifecycle"),
+            message: format!(
+                "Cannot {operation} in state '{lifecycle_state}': structural changes locked after confirmation"
+            ),
         });
     }
-    if request.max_total_hours == 0 {
-        return Err(ApiError::InvalidInput {
-            field: String::from("max_total_hours"),
-            message: String::from("max_total_hours must be greater than 0"),
+
+    require_not_frozen(persistence, bid_year_id, None)?;
+
+    Ok(())
+}
+
+/// Creates a named round group template.
+///
+/// Templates are global: they belong to no bid year and can be
+/// instantiated in any bid year whose round configuration is unlocked.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The template creation request
+/// * `authenticated_actor` - The authenticated actor performing the operation
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Actor is not authorized (Admin role required)
+/// - The name is empty or already used by another template
+/// - A round fails validation or round numbers repeat
+pub fn create_round_group_template(
+    persistence: &mut SqlitePersistence,
+    request: &crate::request_response::CreateRoundGroupTemplateRequest,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<crate::request_response::CreateRoundGroupTemplateResponse, ApiError> {
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("create_round_group_template"),
+            required_role: String::from("Admin"),
         });
     }
-    if request.name.trim().is_empty() {
+
+    let name: &str = request.name.trim();
+    if name.is_empty() {
         return Err(ApiError::InvalidInput {
             field: String::from("name"),
-            message: String::from("Round name cannot be empty"),
+            message: String::from("Template name cannot be empty"),
         });
     }
 
-    // Check for duplicate round number within the round group
-    let round_number_exists = persistence
-        .round_number_exists(round_group_id, request.round_number, None)
+    validate_round_template_specs(&request.rounds)?;
+
+    let name_exists: bool = persistence
+        .round_group_template_name_exists(name)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to check round number: {e}"),
+            message: format!("Failed to check template name: {e}"),
         })?;
-
-    if round_number_exists {
-        return Err(translate_domain_error(DomainError::DuplicateRoundNumber {
-            area_code: round_group.name().to_string(),
-            round_number: request.round_number,
-        }));
+    if name_exists {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("unique_round_group_template_name"),
+            message: format!("Round group template with name '{name}' already exists"),
+        });
     }
 
-    // Insert the round
-    let round_id = persistence
-        .insert_round(
-            round_group_id,
-            request.round_number,
-            &request.name,
-            request.slots_per_day,
-            request.max_groups,
-            request.max_total_hours,
-            request.include_holidays,
-            request.allow_overbid,
-        )
+    let rounds: Vec<RoundSpecData> = request
+        .rounds
+        .iter()
+        .map(|round| RoundSpecData {
+            round_number: round.round_number,
+            name: round.name.clone(),
+            slots_per_day: round.slots_per_day,
+            max_groups: round.max_groups,
+            max_total_hours: round.max_total_hours,
+            include_holidays: round.include_holidays,
+            allow_overbid: round.allow_overbid,
+        })
+        .collect();
+
+    let template_id: i64 = persistence
+        .insert_round_group_template(name, request.editing_enabled, &rounds)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to insert round: {e}"),
+            message: format!("Failed to insert round group template: {e}"),
         })?;
 
-    Ok(crate::request_response::CreateRoundResponse {
-        round_id,
-        round_group_id,
-        round_number: request.round_number,
-        name: request.name.clone(),
-        message: format!("Created round {} '{}'", request.round_number, request.name),
+    Ok(crate::request_response::CreateRoundGroupTemplateResponse {
+        template_id,
+        name: name.to_string(),
+        round_count: rounds.len(),
+        message: format!(
+            "Created round group template '{name}' with {} round(s)",
+            rounds.len()
+        ),
     })
 }
 
-/// Lists all rounds in a round group.
-///
-/// # Arguments
-///
-/// * `persistence` - The persistence layer
-/// * `round_group_id` - The round group ID
-/// * `authenticated_actor` - The authenticated actor performing the operation
-///
-/// # Returns
-///
-/// * `Ok(ListRoundsResponse)` on success
-/// * `Err(ApiError)` on query failure
+/// Lists all round group templates with their rounds.
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Actor is not authorized (Admin role required)
 /// - Database query fails
-///
-/// # Panics
-///
-/// Panics if a persisted round or its round group does not have an ID.
-#[allow(dead_code)]
-pub fn list_rounds(
+pub fn list_round_group_templates(
     persistence: &mut SqlitePersistence,
-    round_group_id: i64,
     authenticated_actor: &AuthenticatedActor,
-) -> Result<crate::request_response::ListRoundsResponse, ApiError> {
-    // Enforce authorization - only admins can view rounds
+) -> Result<crate::request_response::ListRoundGroupTemplatesResponse, ApiError> {
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("list_rounds"),
+            action: String::from("list_round_group_templates"),
             required_role: String::from("Admin"),
         });
     }
 
-    let rounds = persistence
-        .list_rounds(round_group_id)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to list rounds: {e}"),
-        })?;
-
-    let round_infos: Vec<crate::request_response::RoundInfo> = rounds
-        .into_iter()
-        .map(|r| {
-            let round_id = r.round_id().ok_or_else(|| ApiError::Internal {
-                message: String::from("persisted round missing ID"),
+    let templates: Vec<RoundGroupTemplateData> =
+        persistence
+            .list_round_group_templates()
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list round group templates: {e}"),
             })?;
-            let round_group_id =
-                r.round_group()
-                    .round_group_id()
-                    .ok_or_else(|| ApiError::Internal {
-                        message: String::from("persisted round group missing ID"),
-                    })?;
-            Ok(crate::request_response::RoundInfo {
-                round_id,
-                round_group_id,
-                name: r.name().to_string(),
-                round_number: r.round_number(),
-                slots_per_day: r.slots_per_day(),
-                max_groups: r.max_groups(),
-                max_total_hours: r.max_total_hours(),
-                include_holidays: r.include_holidays(),
-                allow_overbid: r.allow_overbid(),
-            })
-        })
-        .collect::<Result<Vec<_>, ApiError>>()?;
 
-    Ok(crate::request_response::ListRoundsResponse {
-        round_group_id,
-        rounds: round_infos,
+    Ok(crate::request_response::ListRoundGroupTemplatesResponse {
+        templates: templates
+            .into_iter()
+            .map(|template| crate::request_response::RoundGroupTemplateInfo {
+                template_id: template.template_id,
+                name: template.name,
+                editing_enabled: template.editing_enabled,
+                rounds: template
+                    .rounds
+                    .into_iter()
+                    .map(|round| crate::request_response::RoundTemplateSpec {
+                        round_number: round.round_number,
+                        name: round.name,
+                        slots_per_day: round.slots_per_day,
+                        max_groups: round.max_groups,
+                        max_total_hours: round.max_total_hours,
+                        include_holidays: round.include_holidays,
+                        allow_overbid: round.allow_overbid,
+                    })
+                    .collect(),
+                created_at: template.created_at,
+            })
+            .collect(),
     })
 }
 
-/// Updates an existing round.
+/// Deletes a round group template.
 ///
-/// Rounds are editable in `Draft` and `BootstrapComplete` states.
-/// After canonicalization, round configuration becomes immutable (or requires override).
-///
-/// # Arguments
+/// Round groups previously instantiated from the template are unaffected.
 ///
-/// * `persistence` - The persistence layer
-/// * `request` - The round update request
-/// * `authenticated_actor` - The authenticated actor performing the operation
+/// # Errors
 ///
-/// # Returns
+/// Returns an error if:
+/// - Actor is not authorized (Admin role required)
+/// - Template does not exist
+pub fn delete_round_group_template(
+    persistence: &mut SqlitePersistence,
+    template_id: i64,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<crate::request_response::DeleteRoundGroupTemplateResponse, ApiError> {
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("delete_round_group_template"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    let template: RoundGroupTemplateData =
+        get_round_group_template_or_not_found(persistence, template_id)?;
+
+    persistence
+        .delete_round_group_template(template_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to delete round group template: {e}"),
+        })?;
+
+    Ok(crate::request_response::DeleteRoundGroupTemplateResponse {
+        message: format!("Deleted round group template '{}'", template.name),
+    })
+}
+
+/// Loads a round group template, mapping a missing template to `ResourceNotFound`.
+fn get_round_group_template_or_not_found(
+    persistence: &mut SqlitePersistence,
+    template_id: i64,
+) -> Result<RoundGroupTemplateData, ApiError> {
+    persistence
+        .get_round_group_template(template_id)
+        .map_err(|e| match e {
+            PersistenceError::NotFound(_) => ApiError::ResourceNotFound {
+                resource_type: String::from("RoundGroupTemplate"),
+                message: format!("Round group template with ID {template_id} not found"),
+            },
+            _ => ApiError::Internal {
+                message: format!("Failed to get round group template: {e}"),
+            },
+        })
+}
+
+/// Instantiates a round group template in a bid year.
 ///
-/// * `Ok(UpdateRoundResponse)` on success
-/// * `Err(ApiError)` on validation failure or lifecycle constraint violation
+/// Creates one round group named after the template (or the requested
+/// name) together with all of the template's rounds, atomically.
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Actor is not authorized (Admin role required)
-/// - Round does not exist
-/// - Lifecycle state does not allow updates
-/// - Round number already exists (duplicate)
-/// - Validation fails
-///
-/// # Panics
-///
-/// Panics if the persisted round's round group does not have an ID or `bid_year_id`.
-#[allow(dead_code)]
-#[allow(clippy::too_many_lines)]
-pub fn update_round(
+/// - Template or bid year does not exist
+/// - Lifecycle state does not allow round group creation
+/// - The round group name already exists in the bid year
+pub fn apply_round_group_template(
     persistence: &mut SqlitePersistence,
-    request: &crate::request_response::UpdateRoundRequest,
+    bid_year_id: i64,
+    request: &crate::request_response::ApplyRoundGroupTemplateRequest,
     authenticated_actor: &AuthenticatedActor,
-) -> Result<crate::request_response::UpdateRoundResponse, ApiError> {
-    use zab_bid_domain::BidYearLifecycle;
-
-    // Enforce authorization - only admins can manage rounds
+) -> Result<crate::request_response::ApplyRoundGroupTemplateResponse, ApiError> {
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("update_round"),
+            action: String::from("apply_round_group_template"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Get the existing round to find its round_group_id and bid_year_id
-    let existing_round = persistence
-        .get_round(request.round_id)
-        .map_err(|e| match e {
-            PersistenceError::NotFound(_) => translate_domain_error(DomainError::RoundNotFound {
-                round_id: request.round_id,
-            }),
-            _ => ApiError::Internal {
-                message: format!("Failed to get round: {e}"),
-            },
-        })?;
-
-    let round_group_id = existing_round
-        .round_group()
-        .round_group_id()
-        .ok_or_else(|| ApiError::Internal {
-            message: String::from("persisted round group missing ID"),
-        })?;
-
-    // Get bid_year_id from the round group
-    let round_group =
-        persistence
-            .get_round_group(round_group_id)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to get round group: {e}"),
-            })?;
-
-    let bid_year_id = round_group
-        .bid_year()
-        .bid_year_id()
-        .ok_or_else(|| ApiError::Internal {
-            message: String::from("persisted bid year missing ID"),
-        })?;
-
-    // Enforce lifecycle constraints
-    let lifecycle_state_str: String =
+    let year: u16 =
         persistence
-            .get_lifecycle_state(bid_year_id)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to get lifecycle state: {e}"),
+            .get_bid_year_from_id(bid_year_id)
+            .map_err(|_| ApiError::ResourceNotFound {
+                resource_type: String::from("BidYear"),
+                message: format!("Bid year with ID {bid_year_id} not found"),
             })?;
 
-    let lifecycle_state: BidYearLifecycle = lifecycle_state_str
-        .parse()
-        .map_err(translate_domain_error)?;
+    let template: RoundGroupTemplateData =
+        get_round_group_template_or_not_found(persistence, request.template_id)?;
 
-    if lifecycle_state.is_locked() {
-        return Err(ApiError::DomainRuleViolation {
-            rule: String::from("round_lifecycle"),
-            message: format!(
-                "Cannot update round in state '{lifecycle_state}': structural changes locked after confirmation"
-            ),
-        });
-    }
+    require_round_config_unlocked(persistence, bid_year_id, "apply round group template")?;
 
-    // Validate round configuration
-    if request.slots_per_day == 0 {
-        return Err(ApiError::InvalidInput {
-            field: String::from("slots_per_day"),
-            message: String::from("slots_per_day must be greater than 0"),
-        });
-    }
-    if request.max_groups == 0 {
-        return Err(ApiError::InvalidInput {
-            field: String::from("max_groups"),
-            message: String::from("max_groups must be greater than 0"),
-        });
-    }
-    if request.max_total_hours == 0 {
-        return Err(ApiError::InvalidInput {
-            field: String::from("max_total_hours"),
-            message: String::from("max_total_hours must be greater than 0"),
-        });
-    }
-    if request.name.trim().is_empty() {
+    let name: String = request
+        .name
+        .as_deref()
+        .map_or_else(|| template.name.clone(), |n| n.trim().to_string());
+    if name.is_empty() {
         return Err(ApiError::InvalidInput {
             field: String::from("name"),
-            message: String::from("Round name cannot be empty"),
+            message: String::from("Round group name cannot be empty"),
         });
     }
 
-    // Check for duplicate round number within the round group (excluding this round)
-    let round_number_exists = persistence
-        .round_number_exists(round_group_id, request.round_number, Some(request.round_id))
+    let name_exists: bool = persistence
+        .round_group_name_exists(bid_year_id, &name, None)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to check round number: {e}"),
+            message: format!("Failed to check round group name: {e}"),
         })?;
-
-    if round_number_exists {
-        return Err(translate_domain_error(DomainError::DuplicateRoundNumber {
-            area_code: round_group.name().to_string(),
-            round_number: request.round_number,
-        }));
+    if name_exists {
+        return Err(translate_domain_error(
+            DomainError::DuplicateRoundGroupName {
+                bid_year: year,
+                name,
+            },
+        ));
     }
 
-    // Update the round
-    persistence
-        .update_round(
-            request.round_id,
-            &request.name,
-            request.slots_per_day,
-            request.max_groups,
-            request.max_total_hours,
-            request.include_holidays,
-            request.allow_overbid,
+    let round_count: usize = template.rounds.len();
+    let round_group_ids: Vec<i64> = persistence
+        .instantiate_round_groups(
+            bid_year_id,
+            &[RoundGroupSpecData {
+                name: name.clone(),
+                editing_enabled: template.editing_enabled,
+                rounds: template.rounds,
+            }],
         )
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to update round: {e}"),
+            message: format!("Failed to instantiate round group template: {e}"),
         })?;
+    let round_group_id: i64 =
+        round_group_ids
+            .first()
+            .copied()
+            .ok_or_else(|| ApiError::Internal {
+                message: String::from("template instantiation returned no round group"),
+            })?;
 
-    Ok(crate::request_response::UpdateRoundResponse {
-        round_id: request.round_id,
+    Ok(crate::request_response::ApplyRoundGroupTemplateResponse {
         round_group_id,
-        round_number: request.round_number,
-        name: request.name.clone(),
-        message: format!("Updated round {} '{}'", request.round_number, request.name),
+        bid_year_id,
+        name: name.clone(),
+        round_count,
+        message: format!(
+            "Created round group '{name}' with {round_count} round(s) from template '{}'",
+            template.name
+        ),
     })
 }
 
-/// Deletes a round.
-///
-/// Rounds can be deleted only in `Draft` and `BootstrapComplete` states.
-///
-/// # Arguments
-///
-/// * `persistence` - The persistence layer
-/// * `round_id` - The round ID to delete
-/// * `authenticated_actor` - The authenticated actor performing the operation
-///
-/// # Returns
+/// Copies all round groups and rounds from one bid year into another.
 ///
-/// * `Ok(DeleteRoundResponse)` on success
-/// * `Err(ApiError)` on validation failure or lifecycle constraint violation
+/// The copy is all-or-nothing: if any source round group name already
+/// exists in the target bid year, nothing is created.
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Actor is not authorized (Admin role required)
-/// - Round does not exist
-/// - Lifecycle state does not allow deletion
-///
-/// # Panics
-///
-/// Panics if the persisted round's round group does not have an ID or `bid_year_id`.
-#[allow(dead_code)]
-pub fn delete_round(
+/// - Source and target are the same bid year, or either does not exist
+/// - Lifecycle state of the target does not allow round group creation
+/// - Any source round group name already exists in the target
+pub fn copy_round_config(
     persistence: &mut SqlitePersistence,
-    round_id: i64,
+    request: &crate::request_response::CopyRoundConfigRequest,
     authenticated_actor: &AuthenticatedActor,
-) -> Result<crate::request_response::DeleteRoundResponse, ApiError> {
-    use zab_bid_domain::BidYearLifecycle;
-
-    // Enforce authorization - only admins can manage rounds
+) -> Result<crate::request_response::CopyRoundConfigResponse, ApiError> {
     if authenticated_actor.role != Role::Admin {
         return Err(ApiError::Unauthorized {
-            action: String::from("delete_round"),
+            action: String::from("copy_round_config"),
             required_role: String::from("Admin"),
         });
     }
 
-    // Get the existing round to find its bid_year_id
-    let existing_round = persistence.get_round(round_id).map_err(|e| match e {
-        PersistenceError::NotFound(_) => {
-            translate_domain_error(DomainError::RoundNotFound { round_id })
-        }
-        _ => ApiError::Internal {
-            message: format!("Failed to get round: {e}"),
-        },
-    })?;
+    if request.from_bid_year_id == request.to_bid_year_id {
+        return Err(ApiError::InvalidInput {
+            field: String::from("to_bid_year_id"),
+            message: String::from("Source and target bid years must differ"),
+        });
+    }
 
-    // Get bid_year_id from the round group
-    let round_group_id = existing_round
-        .round_group()
-        .round_group_id()
-        .ok_or_else(|| ApiError::Internal {
-            message: String::from("persisted round group missing ID"),
+    let mut years: Vec<u16> = Vec::with_capacity(2);
+    for bid_year_id in [request.from_bid_year_id, request.to_bid_year_id] {
+        let year: u16 = persistence.get_bid_year_from_id(bid_year_id).map_err(|_| {
+            ApiError::ResourceNotFound {
+                resource_type: String::from("BidYear"),
+                message: format!("Bid year with ID {bid_year_id} not found"),
+            }
         })?;
-    let round_group =
-        persistence
-            .get_round_group(round_group_id)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Failed to get round group: {e}"),
-            })?;
+        years.push(year);
+    }
+    let (from_year, to_year): (u16, u16) = (years[0], years[1]);
 
-    let bid_year_id = round_group
-        .bid_year()
-        .bid_year_id()
-        .ok_or_else(|| ApiError::Internal {
-            message: String::from("persisted bid year missing ID"),
+    require_round_config_unlocked(
+        persistence,
+        request.to_bid_year_id,
+        "copy round configuration",
+    )?;
+
+    let source_groups: Vec<RoundGroup> = persistence
+        .list_round_groups(request.from_bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list round groups: {e}"),
         })?;
 
-    // Enforce lifecycle constraints
-    let lifecycle_state_str: String =
-        persistence
-            .get_lifecycle_state(bid_year_id)
+    let mut groups: Vec<RoundGroupSpecData> = Vec::with_capacity(source_groups.len());
+    for group in source_groups {
+        let name_exists: bool = persistence
+            .round_group_name_exists(request.to_bid_year_id, group.name(), None)
             .map_err(|e| ApiError::Internal {
-                message: format!("Failed to get lifecycle state: {e}"),
+                message: format!("Failed to check round group name: {e}"),
             })?;
+        if name_exists {
+            return Err(translate_domain_error(
+                DomainError::DuplicateRoundGroupName {
+                    bid_year: to_year,
+                    name: group.name().to_string(),
+                },
+            ));
+        }
 
-    let lifecycle_state: BidYearLifecycle = lifecycle_state_str
-        .parse()
-        .map_err(translate_domain_error)?;
+        let round_group_id: i64 = group.round_group_id().ok_or_else(|| ApiError::Internal {
+            message: String::from("persisted round group missing ID"),
+        })?;
+        let rounds: Vec<zab_bid_domain::Round> =
+            persistence
+                .list_rounds(round_group_id)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to list rounds: {e}"),
+                })?;
 
-    if lifecycle_state.is_locked() {
-        return Err(ApiError::DomainRuleViolation {
-            rule: String::from("round_lifecycle"),
-            message: format!(
-                "Cannot delete round in state '{lifecycle_state}': structural changes locked after confirmation"
-            ),
+        groups.push(RoundGroupSpecData {
+            name: group.name().to_string(),
+            editing_enabled: group.editing_enabled(),
+            rounds: rounds
+                .iter()
+                .map(|round| RoundSpecData {
+                    round_number: round.round_number(),
+                    name: round.name().to_string(),
+                    slots_per_day: round.slots_per_day(),
+                    max_groups: round.max_groups(),
+                    max_total_hours: round.max_total_hours(),
+                    include_holidays: round.include_holidays(),
+                    allow_overbid: round.allow_overbid(),
+                })
+                .collect(),
         });
     }
 
-    // Delete the round
-    persistence
-        .delete_round(round_id)
+    let round_count: usize = groups.iter().map(|g| g.rounds.len()).sum();
+    let round_group_ids: Vec<i64> = persistence
+        .instantiate_round_groups(request.to_bid_year_id, &groups)
         .map_err(|e| ApiError::Internal {
-            message: format!("Failed to delete round: {e}"),
+            message: format!("Failed to copy round configuration: {e}"),
         })?;
 
-    Ok(crate::request_response::DeleteRoundResponse {
+    Ok(crate::request_response::CopyRoundConfigResponse {
+        from_bid_year_id: request.from_bid_year_id,
+        to_bid_year_id: request.to_bid_year_id,
         message: format!(
-            "Deleted round {} '{}'",
-            existing_round.round_number(),
-            existing_round.name()
+            "Copied {} round group(s) and {round_count} round(s) from {from_year} to {to_year}",
+            round_group_ids.len()
         ),
+        round_group_ids,
+        round_count,
     })
 }
 
@@ -6567,19 +9158,18 @@ pub fn delete_round(
 ///
 /// # Returns
 ///
-/// A tuple of (`conflict_count`, `detailed_conflict_messages`).
+/// One `(area_id, detailed_conflict_message)` pair per conflicting area.
 fn detect_seniority_conflicts(
     persistence: &mut SqlitePersistence,
     bid_year_id: i64,
-) -> Result<(usize, Vec<String>), ApiError> {
+) -> Result<Vec<(i64, String)>, ApiError> {
     let users_by_area = persistence
         .get_users_by_area_for_conflict_detection(bid_year_id)
         .map_err(|e| ApiError::Internal {
             message: format!("Failed to get users for conflict detection: {e}"),
         })?;
 
-    let mut seniority_conflicts: usize = 0;
-    let mut conflict_areas: Vec<String> = Vec::new();
+    let mut conflict_areas: Vec<(i64, String)> = Vec::new();
 
     for (area_id, area_code, users) in users_by_area {
         // Attempt to compute bid order for this area
@@ -6592,9 +9182,11 @@ fn detect_seniority_conflicts(
                 user2_initials,
                 reason,
             }) => {
-                seniority_conflicts += 1;
-                conflict_areas.push(format!(
-                    "Area '{area_code}': seniority conflict between '{user1_initials}' and '{user2_initials}' ({reason})"
+                conflict_areas.push((
+                    area_id,
+                    format!(
+                        "Area '{area_code}': seniority conflict between '{user1_initials}' and '{user2_initials}' ({reason})"
+                    ),
                 ));
             }
             Err(e) => {
@@ -6607,51 +9199,7 @@ fn detect_seniority_conflicts(
         }
     }
 
-    Ok((seniority_conflicts, conflict_areas))
-}
-
-/// Builds the list of blocking reasons for bid year readiness.
-fn build_blocking_reasons(
-    areas_missing_rounds: &[String],
-    no_bid_users_pending_review: usize,
-    participation_flag_violations: usize,
-    seniority_conflicts: usize,
-    conflict_details: &[String],
-    bid_schedule_set: bool,
-) -> Vec<String> {
-    let mut blocking_reasons: Vec<String> = Vec::new();
-
-    for area_code in areas_missing_rounds {
-        blocking_reasons.push(format!("Area '{area_code}' has no rounds configured"));
-    }
-
-    if no_bid_users_pending_review > 0 {
-        blocking_reasons.push(format!(
-            "{no_bid_users_pending_review} users in No Bid area have not been reviewed"
-        ));
-    }
-
-    if participation_flag_violations > 0 {
-        blocking_reasons.push(format!(
-            "{participation_flag_violations} users violate participation flag invariant"
-        ));
-    }
-
-    if seniority_conflicts > 0 {
-        blocking_reasons.push(format!(
-            "{seniority_conflicts} seniority conflict(s) detected"
-        ));
-        // Add detailed conflict information
-        for conflict_detail in conflict_details {
-            blocking_reasons.push(conflict_detail.clone());
-        }
-    }
-
-    if !bid_schedule_set {
-        blocking_reasons.push(String::from("Bid schedule is not set"));
-    }
-
-    blocking_reasons
+    Ok(conflict_areas)
 }
 
 /// Gets the readiness status for a bid year.
@@ -6692,6 +9240,61 @@ pub fn get_bid_year_readiness(
         })?
         .year();
 
+    let (evaluation, _) = evaluate_bid_year_readiness(persistence, bid_year_id)?;
+    let warnings: LeaveBalanceWarnings =
+        leave_balance_warnings(persistence, metadata, bid_year_id)?;
+    Ok(readiness_response(
+        bid_year_id,
+        bid_year_value,
+        evaluation,
+        warnings,
+    ))
+}
+
+/// Builds the readiness response for an evaluated bid year.
+///
+/// `warnings` are non-blocking: users projected to lose annual leave at
+/// year end, and users who have bid more leave than they will accrue.
+fn readiness_response(
+    bid_year_id: i64,
+    year: u16,
+    evaluation: ReadinessEvaluation,
+    warnings: LeaveBalanceWarnings,
+) -> GetBidYearReadinessResponse {
+    let blocking_reasons: Vec<String> = evaluation.blocking_reasons();
+
+    GetBidYearReadinessResponse {
+        bid_year_id,
+        year,
+        is_ready: blocking_reasons.is_empty(),
+        blocking_reasons,
+        details: ReadinessDetailsInfo {
+            areas_missing_rounds: evaluation.areas_missing_rounds,
+            no_bid_users_pending_review: evaluation.no_bid_users_pending_review,
+            participation_flag_violations: evaluation.participation_flag_violations,
+            seniority_conflicts: evaluation.seniority_conflicts.len(),
+            bid_schedule_set: evaluation.bid_schedule_set,
+            users_over_use_or_lose: warnings.use_or_lose.len(),
+            users_exceeding_accrual: warnings.overdrawn.len(),
+        },
+        warnings: warnings
+            .use_or_lose
+            .into_iter()
+            .chain(warnings.overdrawn)
+            .collect(),
+    }
+}
+
+/// Evaluates every readiness criterion for a bid year.
+///
+/// # Returns
+///
+/// The evaluation together with the area ID of each seniority conflict it
+/// counted.
+fn evaluate_bid_year_readiness(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<(ReadinessEvaluation, Vec<(i64, String)>), ApiError> {
     // Query readiness criteria from persistence
     let areas_missing_rounds: Vec<String> = persistence
         .get_areas_missing_rounds(bid_year_id)
@@ -6739,33 +9342,108 @@ pub fn get_bid_year_readiness(
             })?;
 
     // Detect seniority conflicts
-    let (seniority_conflicts, conflict_areas) =
-        detect_seniority_conflicts(persistence, bid_year_id)?;
-
-    // Build blocking reasons
-    let blocking_reasons = build_blocking_reasons(
-        &areas_missing_rounds,
-        no_bid_users_pending_review_usize,
-        participation_flag_violations_usize,
-        seniority_conflicts,
-        &conflict_areas,
-        bid_schedule_set,
-    );
+    let conflicts: Vec<(i64, String)> = detect_seniority_conflicts(persistence, bid_year_id)?;
 
-    let is_ready: bool = blocking_reasons.is_empty();
-
-    Ok(GetBidYearReadinessResponse {
-        bid_year_id,
-        year: bid_year_value,
-        is_ready,
-        blocking_reasons,
-        details: ReadinessDetailsInfo {
+    Ok((
+        ReadinessEvaluation {
             areas_missing_rounds,
             no_bid_users_pending_review: no_bid_users_pending_review_usize,
             participation_flag_violations: participation_flag_violations_usize,
-            seniority_conflicts,
+            seniority_conflicts: conflicts.iter().map(|(_, d)| d.clone()).collect(),
             bid_schedule_set,
         },
+        conflicts,
+    ))
+}
+
+/// Gets a bid year's bootstrap progress in a single response.
+///
+/// Combines the bid year's completeness (expected versus actual area and
+/// user counts), its readiness evaluation (missing rounds, unreviewed No
+/// Bid users, seniority conflicts, and bid schedule), and its lifecycle
+/// state, broken down per area.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - Bootstrap metadata
+/// * `bid_year_id` - The canonical bid year ID
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The bid year does not exist
+/// - Database queries fail
+/// - Seniority conflict detection fails
+pub fn get_bid_year_bootstrap_status(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<GetBidYearBootstrapStatusResponse, ApiError> {
+    let completeness: GetBootstrapCompletenessResponse =
+        get_bootstrap_completeness(persistence, metadata)?;
+    let bid_year: BidYearCompletenessInfo = completeness
+        .bid_years
+        .into_iter()
+        .find(|by| by.bid_year_id == bid_year_id)
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("BidYear"),
+            message: format!("Bid year with ID {bid_year_id} not found"),
+        })?;
+
+    let (evaluation, conflicts) = evaluate_bid_year_readiness(persistence, bid_year_id)?;
+    let warnings: LeaveBalanceWarnings =
+        leave_balance_warnings(persistence, metadata, bid_year_id)?;
+    let readiness: GetBidYearReadinessResponse =
+        readiness_response(bid_year_id, bid_year.year, evaluation, warnings);
+
+    let areas: Vec<AreaBootstrapStatusInfo> = completeness
+        .areas
+        .into_iter()
+        .filter(|area| area.bid_year_id == bid_year_id)
+        .map(|area| AreaBootstrapStatusInfo {
+            is_missing_rounds: readiness
+                .details
+                .areas_missing_rounds
+                .contains(&area.area_code),
+            seniority_conflicts: conflicts
+                .iter()
+                .filter(|(area_id, _)| *area_id == area.area_id)
+                .map(|(_, detail)| detail.clone())
+                .collect(),
+            area_id: area.area_id,
+            area_code: area.area_code,
+            expected_user_count: area.expected_user_count,
+            actual_user_count: area.actual_user_count,
+            is_complete: area.is_complete,
+            blocking_reasons: area.blocking_reasons,
+        })
+        .collect();
+
+    // System-wide blockers apply to every bid year; the rest are scoped.
+    let mut blocking_reasons: Vec<BlockingReason> = completeness
+        .blocking_reasons
+        .into_iter()
+        .filter(|reason| match reason {
+            BlockingReason::UsersInNoBidArea {
+                bid_year_id: id, ..
+            } => *id == bid_year_id,
+            _ => true,
+        })
+        .collect();
+    blocking_reasons.extend(bid_year.blocking_reasons);
+
+    Ok(GetBidYearBootstrapStatusResponse {
+        bid_year_id,
+        year: bid_year.year,
+        is_active: bid_year.is_active,
+        lifecycle_state: bid_year.lifecycle_state,
+        expected_area_count: bid_year.expected_area_count,
+        actual_area_count: bid_year.actual_area_count,
+        is_complete: bid_year.is_complete,
+        blocking_reasons,
+        areas,
+        readiness,
     })
 }
 
@@ -6886,60 +9564,13 @@ pub fn confirm_ready_to_bid(
             message: format!("Failed to get bid schedule: {e}"),
         })?;
 
-    let bid_schedule: zab_bid_domain::BidSchedule = match bid_schedule_result {
-        (
-            Some(timezone),
-            Some(start_date_str),
-            Some(window_start_time_str),
-            Some(window_end_time_str),
-            Some(bidders_per_day),
-        ) => {
-            // Parse date and times from strings
-            let start_date = time::Date::parse(
-                &start_date_str,
-                &time::format_description::well_known::Iso8601::DEFAULT,
-            )
-            .map_err(|_| ApiError::Internal {
-                message: format!("Failed to parse bid start date: {start_date_str}"),
-            })?;
-
-            let window_start_time = time::Time::parse(
-                &window_start_time_str,
-                &time::format_description::well_known::Iso8601::DEFAULT,
-            )
-            .map_err(|_| ApiError::Internal {
-                message: format!("Failed to parse window start time: {window_start_time_str}"),
-            })?;
-
-            let window_end_time = time::Time::parse(
-                &window_end_time_str,
-                &time::format_description::well_known::Iso8601::DEFAULT,
-            )
-            .map_err(|_| ApiError::Internal {
-                message: format!("Failed to parse window end time: {window_end_time_str}"),
-            })?;
-
-            let bidders_per_day_u32 =
-                bidders_per_day.to_u32().ok_or_else(|| ApiError::Internal {
-                    message: format!("Invalid bidders_per_day value: {bidders_per_day}"),
-                })?;
+    let bid_schedule: zab_bid_domain::BidSchedule = parse_bid_schedule_fields(bid_schedule_result)?
+        .ok_or_else(|| ApiError::DomainRuleViolation {
+            rule: String::from("Bid schedule must be set before confirmation"),
+            message: format!("No bid schedule configured for bid year {year}"),
+        })?;
 
-            zab_bid_domain::BidSchedule::new(
-                timezone,
-                start_date,
-                window_start_time,
-                window_end_time,
-                bidders_per_day_u32,
-            )
-            .map_err(translate_domain_error)?
-        }
-        _ => {
-            return Err(ApiError::DomainRuleViolation {
-                rule: String::from("Bid schedule must be set before confirmation"),
-                message: format!("No bid schedule configured for bid year {year}"),
-            });
-        }
-    };
+    let blackout_dates: Vec<time::Date> = load_blackout_dates(persistence, request.bid_year_id)?;
 
     // Get all users grouped by area for this bid year
     let users_by_area = persistence
@@ -6955,16 +9586,25 @@ pub fn confirm_ready_to_bid(
             message: format!("Failed to get rounds for bid year {year}: {e}"),
         })?;
 
-    let round_ids: Vec<i64> = all_rounds.iter().map(|(id, _name)| *id).collect();
+    let round_sequences: Vec<RoundSequence> =
+        load_round_sequences(persistence, request.bid_year_id)?;
+    let round_eligibility: RoundEligibilityRules = RoundEligibilityRules::load(
+        persistence,
+        metadata,
+        request.bid_year_id,
+        users_by_area.iter().flat_map(|(_, _, users)| users),
+    )?;
 
     // Materialize bid order and calculate windows for each area
     let mut total_bid_order_count: usize = 0;
     let mut total_bid_windows_count: usize = 0;
 
-    for (_area_id, _area_code, users_in_area) in &users_by_area {
+    for (area_id, _area_code, users_in_area) in &users_by_area {
         if users_in_area.is_empty() {
             continue;
         }
+        let area_schedule: zab_bid_domain::BidSchedule =
+            effective_bid_schedule_for_area(persistence, *area_id, &bid_schedule)?;
 
         // Compute bid order
         let bid_order_positions: Vec<zab_bid_domain::BidOrderPosition> =
@@ -6974,18 +9614,17 @@ pub fn confirm_ready_to_bid(
             continue;
         }
 
-        // Calculate bid windows (per-round)
-        let user_positions: Vec<(i64, usize)> = bid_order_positions
-            .iter()
-            .map(|pos| (pos.user_id, pos.position))
-            .collect();
-
-        let bid_windows: Vec<zab_bid_domain::BidWindow> =
-            zab_bid_domain::calculate_bid_windows(&user_positions, &round_ids, &bid_schedule)
-                .map_err(translate_domain_error)?;
+        // Derive each round's sequence and calculate its bid windows
+        let round_orders: AreaRoundOrders = build_area_round_orders(
+            &bid_order_positions,
+            &round_sequences,
+            &round_eligibility,
+            &area_schedule,
+            &blackout_dates,
+        )?;
 
         total_bid_order_count += bid_order_positions.len();
-        total_bid_windows_count += bid_windows.len();
+        total_bid_windows_count += round_orders.bid_windows.len();
     }
 
     // Apply the core command
@@ -7006,6 +9645,8 @@ pub fn confirm_ready_to_bid(
         if users_in_area.is_empty() {
             continue;
         }
+        let area_schedule: zab_bid_domain::BidSchedule =
+            effective_bid_schedule_for_area(persistence, *area_id, &bid_schedule)?;
 
         // Compute bid order again (deterministic, so same result)
         let bid_order_positions: Vec<zab_bid_domain::BidOrderPosition> =
@@ -7038,18 +9679,39 @@ pub fn confirm_ready_to_bid(
                 message: format!("Failed to persist bid order: {e}"),
             })?;
 
-        // Calculate bid windows (per-round)
-        let user_positions: Vec<(i64, usize)> = bid_order_positions
-            .iter()
-            .map(|pos| (pos.user_id, pos.position))
-            .collect();
-
-        let bid_windows: Vec<zab_bid_domain::BidWindow> =
-            zab_bid_domain::calculate_bid_windows(&user_positions, &round_ids, &bid_schedule)
-                .map_err(translate_domain_error)?;
+        // Derive each round's sequence and calculate its bid windows
+        let round_orders: AreaRoundOrders = build_area_round_orders(
+            &bid_order_positions,
+            &round_sequences,
+            &round_eligibility,
+            &area_schedule,
+            &blackout_dates,
+        )?;
+
+        // Persist each round's sequence
+        let mut round_bid_order_records: Vec<RoundBidOrderData> = Vec::new();
+        for (round_id, sequence) in &round_orders.sequences {
+            for pos in sequence {
+                round_bid_order_records.push(RoundBidOrderData {
+                    round_id: *round_id,
+                    user_id: pos.user_id,
+                    bid_year_id: request.bid_year_id,
+                    area_id: *area_id,
+                    position: i32::try_from(pos.position).map_err(|_| ApiError::Internal {
+                        message: format!("Bid order position {} is out of range", pos.position),
+                    })?,
+                });
+            }
+        }
+        persistence
+            .bulk_insert_round_bid_order(&round_bid_order_records)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to persist round bid order: {e}"),
+            })?;
 
         // Convert to persistence records
-        let bid_window_records: Vec<zab_bid_persistence::data_models::NewBidWindow> = bid_windows
+        let bid_window_records: Vec<zab_bid_persistence::data_models::NewBidWindow> = round_orders
+            .bid_windows
             .iter()
             .map(|window| zab_bid_persistence::data_models::NewBidWindow {
                 bid_year_id: request.bid_year_id,
@@ -7080,119 +9742,436 @@ pub fn confirm_ready_to_bid(
     // Initialize bid status tracking for all users in all rounds
     if !all_rounds.is_empty() {
         // Create initial bid status records for all user/round combinations
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|e| ApiError::Internal {
-                message: format!("System time error: {e}"),
-            })?
-            .as_secs();
         let current_timestamp =
-            time::OffsetDateTime::from_unix_timestamp(now.to_i64().ok_or_else(|| {
-                ApiError::Internal {
-                    message: String::from("Timestamp conversion failed"),
+            time::OffsetDateTime::from_unix_timestamp(persistence.now().unix_timestamp())
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Invalid timestamp: {e}"),
+                })?
+                .format(&time::format_description::well_known::Rfc3339)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Timestamp formatting failed: {e}"),
+                })?;
+        let mut bid_status_records = Vec::new();
+
+        for (area_id, _area_code, users_in_area) in &users_by_area {
+            for user in users_in_area {
+                // All users should have IDs at confirmation time
+                let user_id = user.user_id.ok_or_else(|| ApiError::Internal {
+                    message: format!("User with initials {:?} has no user_id", user.initials),
+                })?;
+
+                for (round_id, _round_name) in &all_rounds {
+                    if !round_eligibility.admits(user_id, *round_id) {
+                        continue;
+                    }
+                    bid_status_records.push(zab_bid_persistence::data_models::NewBidStatus {
+                        bid_year_id: request.bid_year_id,
+                        area_id: *area_id,
+                        user_id,
+                        round_id: *round_id,
+                        status: String::from("NotStartedPreWindow"),
+                        updated_at: current_timestamp.clone(),
+                        updated_by: operator.operator_id,
+                        notes: Some(String::from("Initial status at confirmation")),
+                    });
                 }
-            })?)
-            .map_err(|e| ApiError::Internal {
-                message: format!("Invalid timestamp: {e}"),
-            })?
-            .format(&time::format_description::well_known::Rfc3339)
+            }
+        }
+
+        // Bulk insert all bid status records
+        if !bid_status_records.is_empty() {
+            persistence
+                .bulk_insert_bid_status(&bid_status_records)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to initialize bid status tracking: {e}"),
+                })?;
+        }
+    }
+
+    Ok(ConfirmReadyToBidResponse {
+        bid_year_id: request.bid_year_id,
+        year,
+        lifecycle_state: target_state.as_str().to_string(),
+        audit_event_id,
+        message: format!("Bid year {year} confirmed ready to bid"),
+        bid_order_count: total_bid_order_count,
+        bid_windows_calculated: total_bid_windows_count,
+    })
+}
+
+/// Minimum length of a No Bid review reason, after trimming.
+const MIN_NO_BID_REVIEW_REASON_LEN: usize = 10;
+
+/// Marks a user in the No Bid system area as reviewed.
+///
+/// This endpoint is used to confirm that a user assigned to a system area
+/// (e.g., "No Bid") has been reviewed and their assignment is correct.
+/// The review reason is recorded in an audit event scoped to the system area.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The user to review and the review reason
+/// * `authenticated_actor` - The authenticated actor performing the action
+/// * `operator` - The operator data for audit trail
+///
+/// # Returns
+///
+/// A success response confirming the review.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Authorization fails
+/// - The reason is shorter than 10 characters
+/// - The user does not exist
+/// - The user is not in a system area
+/// - Database update fails
+pub fn review_no_bid_user(
+    persistence: &mut SqlitePersistence,
+    request: &ReviewNoBidUserRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<ReviewNoBidUserResponse, ApiError> {
+    let mut reviewed: Vec<ReviewNoBidUserResponse> = review_no_bid_users_internal(
+        persistence,
+        &[request.user_id],
+        &request.reason,
+        authenticated_actor,
+        operator,
+    )?;
+
+    reviewed.pop().ok_or_else(|| ApiError::Internal {
+        message: String::from("No review result produced"),
+    })
+}
+
+/// Marks several users in the No Bid system area as reviewed.
+///
+/// All users are validated before any are marked, so a single invalid
+/// user rejects the whole batch. Each review records its own audit event
+/// carrying the shared reason.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The users to review and the review reason
+/// * `authenticated_actor` - The authenticated actor performing the action
+/// * `operator` - The operator data for audit trail
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Authorization fails
+/// - The user list is empty or contains duplicates
+/// - The reason is shorter than 10 characters
+/// - Any user does not exist or is not in a system area
+/// - Database update fails
+pub fn review_no_bid_users(
+    persistence: &mut SqlitePersistence,
+    request: &ReviewNoBidUsersRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<ReviewNoBidUsersResponse, ApiError> {
+    if request.user_ids.is_empty() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("user_ids"),
+            message: String::from("At least one user must be provided"),
+        });
+    }
+
+    let mut seen: std::collections::HashSet<i64> = std::collections::HashSet::new();
+    if let Some(duplicate) = request.user_ids.iter().find(|id| !seen.insert(**id)) {
+        return Err(ApiError::InvalidInput {
+            field: String::from("user_ids"),
+            message: format!("User {duplicate} is listed more than once"),
+        });
+    }
+
+    let reviewed: Vec<ReviewNoBidUserResponse> = review_no_bid_users_internal(
+        persistence,
+        &request.user_ids,
+        &request.reason,
+        authenticated_actor,
+        operator,
+    )?;
+
+    Ok(ReviewNoBidUsersResponse {
+        message: format!("{} No Bid user(s) marked as reviewed", reviewed.len()),
+        reviewed,
+    })
+}
+
+/// A No Bid user that has passed review validation.
+struct NoBidReviewTarget {
+    user_id: i64,
+    initials: String,
+    was_reviewed: bool,
+    year: u16,
+    area_code: String,
+}
+
+/// Validates and reviews a set of No Bid users.
+///
+/// Validation covers every user before the first one is marked.
+fn review_no_bid_users_internal(
+    persistence: &mut SqlitePersistence,
+    user_ids: &[i64],
+    reason: &str,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<Vec<ReviewNoBidUserResponse>, ApiError> {
+    // Enforce authorization - only admins can review No Bid users
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("review_no_bid_user"),
+            required_role: String::from("Admin"),
+        });
+    }
+
+    let reason = reason.trim();
+    if reason.len() < MIN_NO_BID_REVIEW_REASON_LEN {
+        return Err(ApiError::InvalidInput {
+            field: String::from("reason"),
+            message: format!(
+                "Review reason must be at least {MIN_NO_BID_REVIEW_REASON_LEN} characters"
+            ),
+        });
+    }
+
+    let mut targets: Vec<NoBidReviewTarget> = Vec::with_capacity(user_ids.len());
+    for &user_id in user_ids {
+        targets.push(resolve_no_bid_review_target(persistence, user_id)?);
+    }
+
+    let mut reviewed: Vec<ReviewNoBidUserResponse> = Vec::with_capacity(targets.len());
+    for target in targets {
+        persistence
+            .mark_user_no_bid_reviewed(target.user_id)
             .map_err(|e| ApiError::Internal {
-                message: format!("Timestamp formatting failed: {e}"),
+                message: format!("Failed to mark user as reviewed: {e}"),
             })?;
-        let mut bid_status_records = Vec::new();
-
-        for (area_id, _area_code, users_in_area) in &users_by_area {
-            for user in users_in_area {
-                // All users should have IDs at confirmation time
-                let user_id = user.user_id.ok_or_else(|| ApiError::Internal {
-                    message: format!("User with initials {:?} has no user_id", user.initials),
-                })?;
 
-                for (round_id, _round_name) in &all_rounds {
-                    bid_status_records.push(zab_bid_persistence::data_models::NewBidStatus {
-                        bid_year_id: request.bid_year_id,
-                        area_id: *area_id,
-                        user_id,
-                        round_id: *round_id,
-                        status: String::from("NotStartedPreWindow"),
-                        updated_at: current_timestamp.clone(),
-                        updated_by: operator.operator_id,
-                        notes: Some(String::from("Initial status at confirmation")),
-                    });
-                }
-            }
-        }
+        let actor = authenticated_actor.to_audit_actor(operator);
+        let cause = Cause::new(
+            String::from("review_no_bid_user"),
+            format!("Review No Bid assignment for user {}", target.initials),
+        );
+        let action = Action::new(
+            String::from("ReviewNoBidUser"),
+            Some(format!(
+                "user_id={}, initials={}, reason={}",
+                target.user_id, target.initials, reason
+            )),
+        );
+        let before = StateSnapshot::new(format!("no_bid_reviewed={}", target.was_reviewed));
+        let after = StateSnapshot::new(String::from("no_bid_reviewed=true"));
+
+        let audit_event = AuditEvent::new(
+            actor,
+            cause,
+            action,
+            before,
+            after,
+            BidYear::new(target.year),
+            Area::new(&target.area_code),
+        )
+        .with_user_id(Some(target.user_id));
 
-        // Bulk insert all bid status records
-        if !bid_status_records.is_empty() {
+        let event_id =
             persistence
-                .bulk_insert_bid_status(&bid_status_records)
+                .persist_audit_event(&audit_event)
                 .map_err(|e| ApiError::Internal {
-                    message: format!("Failed to initialize bid status tracking: {e}"),
+                    message: format!("Failed to persist audit event: {e}"),
                 })?;
-        }
+
+        reviewed.push(ReviewNoBidUserResponse {
+            user_id: target.user_id,
+            audit_event_id: event_id,
+            message: format!(
+                "User {} marked as reviewed (audit event {event_id})",
+                target.initials
+            ),
+        });
     }
 
-    Ok(ConfirmReadyToBidResponse {
-        bid_year_id: request.bid_year_id,
+    Ok(reviewed)
+}
+
+/// Confirms that a user exists and is currently assigned to a system area.
+fn resolve_no_bid_review_target(
+    persistence: &mut SqlitePersistence,
+    user_id: i64,
+) -> Result<NoBidReviewTarget, ApiError> {
+    let (bid_year_id, initials): (i64, String) =
+        persistence
+            .get_user_details(user_id)
+            .map_err(|_| ApiError::ResourceNotFound {
+                resource_type: String::from("User"),
+                message: format!("User with ID {user_id} not found"),
+            })?;
+
+    let area_id: i64 = persistence
+        .get_user_area_id(user_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get user area: {e}"),
+        })?;
+
+    let is_system = persistence
+        .is_system_area(area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to check system area: {e}"),
+        })?;
+
+    if !is_system {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("no_bid_review_requires_system_area"),
+            message: format!("User {initials} is not assigned to a system area"),
+        });
+    }
+
+    let (area_code, _area_name): (String, Option<String>) = persistence
+        .get_area_details(area_id)
+        .map_err(|e| ApiError::Internal {
+        message: format!("Failed to fetch area info: {e}"),
+    })?;
+
+    let year: u16 =
+        persistence
+            .get_bid_year_from_id(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get bid year: {e}"),
+            })?;
+
+    let users: Vec<zab_bid_domain::User> = persistence
+        .list_users(&BidYear::new(year), &Area::new(&area_code))
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list system area users: {e}"),
+        })?;
+    let was_reviewed: bool = users
+        .iter()
+        .find(|u| u.user_id == Some(user_id))
+        .is_some_and(|u| u.no_bid_reviewed);
+
+    Ok(NoBidReviewTarget {
+        user_id,
+        initials,
+        was_reviewed,
         year,
-        lifecycle_state: target_state.as_str().to_string(),
-        audit_event_id,
-        message: format!("Bid year {year} confirmed ready to bid"),
-        bid_order_count: total_bid_order_count,
-        bid_windows_calculated: total_bid_windows_count,
+        area_code,
     })
 }
 
-/// Marks a user in a system area as reviewed.
+/// Lists users in the No Bid system area that have not yet been reviewed.
 ///
-/// This endpoint is used to confirm that a user assigned to a system area
-/// (e.g., "No Bid") has been reviewed and their assignment is correct.
+/// Each user is paired with the audit event that placed them in the
+/// system area, so reviewers can see why the user landed there.
 ///
 /// # Arguments
 ///
 /// * `persistence` - The persistence layer
-/// * `user_id` - The user's canonical ID
-/// * `authenticated_actor` - The authenticated actor performing the action
-///
-/// # Returns
-///
-/// A success response confirming the review.
+/// * `bid_year_id` - The canonical bid year ID
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The user does not exist
-/// - The user is not in a system area
-/// - Authorization fails
-/// - Database update fails
-#[allow(dead_code)] // Phase 29D: Will be used when wired up in server
-pub fn review_no_bid_user(
+/// - The bid year does not exist
+/// - Database queries fail
+pub fn list_unreviewed_no_bid_users(
     persistence: &mut SqlitePersistence,
-    user_id: i64,
-    authenticated_actor: &AuthenticatedActor,
-) -> Result<ReviewNoBidUserResponse, ApiError> {
-    // Enforce authorization - only admins can review No Bid users
-    if authenticated_actor.role != Role::Admin {
-        return Err(ApiError::Unauthorized {
-            action: String::from("review_no_bid_user"),
-            required_role: String::from("Admin"),
+    bid_year_id: i64,
+) -> Result<ListUnreviewedNoBidUsersResponse, ApiError> {
+    let year: u16 =
+        persistence
+            .get_bid_year_from_id(bid_year_id)
+            .map_err(|_| ApiError::ResourceNotFound {
+                resource_type: String::from("BidYear"),
+                message: format!("Bid year with ID {bid_year_id} not found"),
+            })?;
+
+    let Some((area_id, area_code)) =
+        persistence
+            .find_system_area(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to find system area: {e}"),
+            })?
+    else {
+        return Ok(ListUnreviewedNoBidUsersResponse {
+            bid_year_id,
+            year,
+            area_id: None,
+            users: Vec::new(),
         });
-    }
+    };
 
-    // Mark the user as reviewed
-    persistence
-        .mark_user_no_bid_reviewed(user_id)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to mark user as reviewed: {e}"),
-        })?;
+    let bid_year = BidYear::new(year);
+    let area = Area::new(&area_code);
 
-    Ok(ReviewNoBidUserResponse {
-        user_id,
-        message: format!("User {user_id} marked as reviewed"),
+    let users: Vec<zab_bid_domain::User> =
+        persistence
+            .list_users(&bid_year, &area)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list system area users: {e}"),
+            })?;
+
+    let timeline: Vec<AuditEvent> =
+        persistence
+            .get_audit_timeline(&bid_year, &area)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get audit timeline: {e}"),
+            })?;
+
+    let users: Vec<UnreviewedNoBidUserInfo> = users
+        .into_iter()
+        .filter(|user| !user.no_bid_reviewed)
+        .filter_map(|user| {
+            let user_id = user.user_id?;
+            let initials = user.initials.value().to_string();
+            let placement = find_no_bid_placement(&timeline, &initials);
+            Some(UnreviewedNoBidUserInfo {
+                user_id,
+                initials,
+                name: user.name,
+                placement_action: placement.map(|event| event.action.name.clone()),
+                placement_audit_event_id: placement.and_then(|event| event.event_id),
+                placement_reason: placement.map_or_else(
+                    || String::from("No recorded assignment to this area"),
+                    describe_no_bid_placement,
+                ),
+            })
+        })
+        .collect();
+
+    Ok(ListUnreviewedNoBidUsersResponse {
+        bid_year_id,
+        year,
+        area_id: Some(area_id),
+        users,
+    })
+}
+
+/// Finds the most recent event that placed a user in the system area.
+fn find_no_bid_placement<'a>(timeline: &'a [AuditEvent], initials: &str) -> Option<&'a AuditEvent> {
+    let needle = format!("'{initials}'");
+    timeline.iter().rev().find(|event| {
+        matches!(event.action.name.as_str(), "RegisterUser" | "UpdateUser")
+            && event
+                .action
+                .details
+                .as_deref()
+                .is_some_and(|details| details.contains(&needle))
     })
 }
 
+/// Describes why a placement event put a user in the system area.
+fn describe_no_bid_placement(event: &AuditEvent) -> String {
+    let how = match event.action.name.as_str() {
+        "RegisterUser" => "Registered directly into No Bid",
+        _ => "Moved into No Bid by user update",
+    };
+    format!("{how}: {}", event.cause.description)
+}
+
 /// Gets a preview of the derived bid order for an area.
 ///
 /// This is a read-only preview endpoint that shows what the bid order will be
@@ -7568,7 +10547,7 @@ pub fn transition_bid_status(
 fn transition_bid_status_impl(
     persistence: &mut SqlitePersistence,
     actor: &AuthenticatedActor,
-    _operator: &OperatorData,
+    operator: &OperatorData,
     bid_status_id: i64,
     new_status_str: &str,
     notes: &str,
@@ -7602,6 +10581,19 @@ fn transition_bid_status_impl(
             },
         })?;
 
+    AuthorizationService::authorize_area_bidding(
+        persistence,
+        actor,
+        operator,
+        current_row.area_id,
+        "transition_bid_status",
+    )?;
+    require_not_frozen(
+        persistence,
+        current_row.bid_year_id,
+        Some(current_row.area_id),
+    )?;
+
     // Parse current and new status
     let current_status =
         zab_bid_domain::BidStatus::from_str(&current_row.status).map_err(translate_domain_error)?;
@@ -7614,25 +10606,15 @@ fn transition_bid_status_impl(
         .map_err(translate_domain_error)?;
 
     // Get current timestamp
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|e| ApiError::Internal {
-            message: format!("System time error: {e}"),
-        })?
-        .as_secs();
     let transitioned_at =
-        time::OffsetDateTime::from_unix_timestamp(now.to_i64().ok_or_else(|| {
-            ApiError::Internal {
-                message: String::from("Timestamp conversion failed"),
-            }
-        })?)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Invalid timestamp: {e}"),
-        })?
-        .format(&time::format_description::well_known::Rfc3339)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to format timestamp: {e}"),
-        })?;
+        time::OffsetDateTime::from_unix_timestamp(persistence.now().unix_timestamp())
+            .map_err(|e| ApiError::Internal {
+                message: format!("Invalid timestamp: {e}"),
+            })?
+            .format(&time::format_description::well_known::Rfc3339)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to format timestamp: {e}"),
+            })?;
 
     // Update bid status
     // Parse operator_id from actor.id (string) to i64
@@ -7731,7 +10713,7 @@ pub fn bulk_update_bid_status(
 fn bulk_update_bid_status_impl(
     persistence: &mut SqlitePersistence,
     actor: &AuthenticatedActor,
-    _operator: &OperatorData,
+    operator: &OperatorData,
     bid_year_id: i64,
     area_id: i64,
     user_ids: &[i64],
@@ -7747,6 +10729,15 @@ fn bulk_update_bid_status_impl(
         });
     }
 
+    AuthorizationService::authorize_area_bidding(
+        persistence,
+        actor,
+        operator,
+        area_id,
+        "bulk_update_bid_status",
+    )?;
+    require_not_frozen(persistence, bid_year_id, Some(area_id))?;
+
     // Validate notes length
     if notes.len() < 10 {
         return Err(ApiError::InvalidInput {
@@ -7760,25 +10751,15 @@ fn bulk_update_bid_status_impl(
         zab_bid_domain::BidStatus::from_str(new_status_str).map_err(translate_domain_error)?;
 
     // Get current timestamp
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|e| ApiError::Internal {
-            message: format!("System time error: {e}"),
-        })?
-        .as_secs();
     let transitioned_at =
-        time::OffsetDateTime::from_unix_timestamp(now.to_i64().ok_or_else(|| {
-            ApiError::Internal {
-                message: String::from("Timestamp conversion failed"),
-            }
-        })?)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Invalid timestamp: {e}"),
-        })?
-        .format(&time::format_description::well_known::Rfc3339)
-        .map_err(|e| ApiError::Internal {
-            message: format!("Failed to format timestamp: {e}"),
-        })?;
+        time::OffsetDateTime::from_unix_timestamp(persistence.now().unix_timestamp())
+            .map_err(|e| ApiError::Internal {
+                message: format!("Invalid timestamp: {e}"),
+            })?
+            .format(&time::format_description::well_known::Rfc3339)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to format timestamp: {e}"),
+            })?;
 
     // Validate all transitions before updating
     let mut status_records = Vec::new();
@@ -7857,3 +10838,181 @@ fn bulk_update_bid_status_impl(
         ),
     })
 }
+
+/// Default page size for audit timeline requests.
+const DEFAULT_AUDIT_TIMELINE_LIMIT: u32 = 50;
+
+/// Maximum page size for audit timeline requests.
+const MAX_AUDIT_TIMELINE_LIMIT: u32 = 500;
+
+/// Retrieves a page of the audit timeline for a scope.
+///
+/// This is a read-only operation that requires no authorization.
+/// Each entry carries the actor, action, creation timestamp, and a summary of
+/// the fields that changed between the before and after snapshots.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `scope` - Global, bid year, or area scope
+/// * `filter` - Optional action, actor, and time-range filters
+/// * `page` - Cursor and page size
+///
+/// # Returns
+///
+/// * `Ok(GetAuditTimelineResponse)` containing the page and the next cursor
+/// * `Err(ApiError)` on invalid input or query failure
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The requested limit is zero or exceeds the maximum
+/// - Database query fails
+pub fn get_audit_timeline(
+    persistence: &mut SqlitePersistence,
+    scope: AuditTimelineScope,
+    filter: &AuditTimelineFilter,
+    page: AuditTimelinePageRequest,
+) -> Result<GetAuditTimelineResponse, ApiError> {
+    let limit: u32 = page.limit.unwrap_or(DEFAULT_AUDIT_TIMELINE_LIMIT);
+    if limit == 0 || limit > MAX_AUDIT_TIMELINE_LIMIT {
+        return Err(ApiError::InvalidInput {
+            field: String::from("limit"),
+            message: format!("Limit must be between 1 and {MAX_AUDIT_TIMELINE_LIMIT}"),
+        });
+    }
+
+    let persistence_scope: zab_bid_persistence::AuditTimelineScope = match scope {
+        AuditTimelineScope::Global => zab_bid_persistence::AuditTimelineScope::Global,
+        AuditTimelineScope::BidYear { bid_year_id } => {
+            zab_bid_persistence::AuditTimelineScope::BidYear { bid_year_id }
+        }
+        AuditTimelineScope::Area {
+            bid_year_id,
+            area_id,
+        } => zab_bid_persistence::AuditTimelineScope::Area {
+            bid_year_id,
+            area_id,
+        },
+    };
+    // A user is followed by ID, and under every initials they have held
+    // for events recorded before events carried a user ID
+    let user_id: Option<i64> = match (&filter.user_initials, persistence_scope) {
+        (None, _) => None,
+        (
+            Some(initials),
+            zab_bid_persistence::AuditTimelineScope::BidYear { bid_year_id }
+            | zab_bid_persistence::AuditTimelineScope::Area { bid_year_id, .. },
+        ) => Some(resolve_initials_user(persistence, bid_year_id, initials)?),
+        (Some(_), _) => {
+            return Err(ApiError::InvalidInput {
+                field: String::from("user_initials"),
+                message: String::from("Filtering by user initials requires a bid year"),
+            });
+        }
+    };
+    let user_initials: Vec<String> = match user_id {
+        Some(user_id) => user_initials_history(persistence, user_id)?,
+        None => Vec::new(),
+    };
+    let persistence_filter: zab_bid_persistence::AuditTimelineFilter =
+        zab_bid_persistence::AuditTimelineFilter {
+            action_name: filter.action_name.clone(),
+            actor_operator_id: filter.actor_operator_id,
+            created_from: filter.from.clone(),
+            created_to: filter.to.clone(),
+            user_id,
+            user_initials,
+        };
+
+    let timeline_page: zab_bid_persistence::AuditTimelinePage = persistence
+        .get_audit_timeline_page(
+            persistence_scope,
+            &persistence_filter,
+            page.after_event_id,
+            limit,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to retrieve audit timeline: {e}"),
+        })?;
+
+    let event_ids: Vec<i64> = timeline_page
+        .entries
+        .iter()
+        .filter_map(|entry| entry.event.event_id)
+        .collect();
+    let mut annotations: BTreeMap<i64, Vec<AuditAnnotationInfo>> =
+        annotations_by_event(persistence, &event_ids)?;
+
+    let entries: Vec<AuditTimelineEntryInfo> = timeline_page
+        .entries
+        .into_iter()
+        .map(|entry| {
+            let event: AuditEvent = entry.event;
+            let event_id: i64 = event.event_id.ok_or_else(|| ApiError::Internal {
+                message: String::from("persisted audit event missing ID"),
+            })?;
+            Ok(AuditTimelineEntryInfo {
+                event_id,
+                created_at: entry.created_at,
+                annotations: annotations.remove(&event_id).unwrap_or_default(),
+                diff_summary: summarize_snapshot_diff(&event.before.data, &event.after.data),
+                actor: AuditActorInfo {
+                    actor_type: event.actor.actor_type,
+                    operator_id: event.actor.operator_id,
+                    login_name: event.actor.operator_login_name,
+                    display_name: event.actor.operator_display_name,
+                },
+                action_name: event.action.name,
+                action_details: event.action.details,
+                cause_description: event.cause.description,
+                bid_year: event.bid_year.as_ref().map(BidYear::year),
+                area_code: event.area.as_ref().map(|a| a.id().to_string()),
+            })
+        })
+        .collect::<Result<Vec<_>, ApiError>>()?;
+
+    Ok(GetAuditTimelineResponse {
+        entries,
+        next_cursor: timeline_page.next_cursor,
+    })
+}
+
+/// Summarizes the fields that differ between two `key=value,...` snapshots.
+///
+/// Snapshot segments without an `=` are ignored. Fields are reported in the
+/// order they first appear in the before snapshot, then the after snapshot.
+fn summarize_snapshot_diff(before: &str, after: &str) -> Vec<AuditFieldChange> {
+    fn parse(snapshot: &str) -> Vec<(&str, &str)> {
+        snapshot
+            .split(',')
+            .filter_map(|segment| segment.split_once('='))
+            .collect()
+    }
+
+    let before_fields: Vec<(&str, &str)> = parse(before);
+    let after_fields: Vec<(&str, &str)> = parse(after);
+    let lookup = |fields: &[(&str, &str)], key: &str| -> Option<String> {
+        fields
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| (*v).to_string())
+    };
+
+    let mut changes: Vec<AuditFieldChange> = Vec::new();
+    for (key, _) in before_fields.iter().chain(after_fields.iter()) {
+        if changes.iter().any(|c| c.field == *key) {
+            continue;
+        }
+        let before_value: Option<String> = lookup(&before_fields, key);
+        let after_value: Option<String> = lookup(&after_fields, key);
+        if before_value != after_value {
+            changes.push(AuditFieldChange {
+                field: (*key).to_string(),
+                before: before_value,
+                after: after_value,
+            });
+        }
+    }
+    changes
+}
@@ -0,0 +1,955 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Leave bid entry handlers.
+//!
+//! Controllers rarely enter their own bids: an operator takes the bid by
+//! phone, in person, or in writing and enters it on the controller's
+//! behalf. Every entry goes through the core `EnterLeaveBid` command, so
+//! the controller and how the bid was received are recorded both in the
+//! audit event and on each leave bid.
+//!
+//! Controllers may also hand in a ranked preference list before their
+//! window opens. When the window opens, the highest-ranked preference that
+//! still fits the round's slot inventory is converted into leave bids
+//! through the core `ApplyBidPreference` command.
+//!
+//! Once a round is signed off in an area, further leave can only be
+//! entered there by an Admin giving an override reason.
+//!
+//! An operator who is also a controller may not enter their own leave
+//! alone; it goes through a self-service request that a second operator
+//! confirms (see `self_bids`).
+
+use std::collections::BTreeSet;
+use time::Date;
+use time::format_description::well_known::Iso8601;
+use zab_bid::{BidRule, BootstrapMetadata, BootstrapResult, Command, State, apply_bootstrap};
+use zab_bid_audit::{Actor, AuditEvent, Cause};
+use zab_bid_domain::{
+    Area, BidAmendment, BidAmendmentPolicy, BidPreference, BidReceiptMethod, BidYear,
+    BidYearLifecycle, Crew, DomainError, Initials, SlotInventory, User, select_bid_preference,
+};
+use zab_bid_persistence::{
+    BidPreferenceData, BidPreferenceSpecData, CurrentBidderStateData, LeaveBidData, NewLeaveBids,
+    OperatorData, PersistenceError, RoundBidderData, SqlitePersistence,
+};
+
+use crate::amendment_policies::{bid_amendment, load_amendment_policy};
+use crate::auth::{AuthenticatedActor, AuthorizationService, Role};
+use crate::bid_rules::{enforce_bid_rules, load_bid_rules};
+use crate::error::{ApiError, translate_core_error, translate_domain_error};
+use crate::request_response::{
+    BidPreferenceInfo, EnterLeaveBidRequest, EnterLeaveBidResponse, ListBidPreferencesResponse,
+    SubmitBidPreferencesRequest, SubmitBidPreferencesResponse,
+};
+use crate::round_sign_offs::is_round_signed_off;
+use crate::slot_inventory::load_slot_inventory;
+use crate::webhooks::require_admin;
+
+/// Finds an area and its bid year in the metadata by canonical area ID.
+pub fn resolve_area(
+    metadata: &BootstrapMetadata,
+    area_id: i64,
+) -> Result<(&BidYear, &Area), ApiError> {
+    metadata
+        .areas
+        .iter()
+        .find(|(_, area)| area.area_id() == Some(area_id))
+        .map(|(bid_year, area)| (bid_year, area))
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("Area"),
+            message: format!("Area with ID {area_id} not found"),
+        })
+}
+
+/// Parses the requested leave dates.
+pub fn parse_leave_dates(leave_dates: &[String]) -> Result<Vec<Date>, ApiError> {
+    leave_dates
+        .iter()
+        .map(|date| {
+            Date::parse(date, &Iso8601::DEFAULT).map_err(|_| ApiError::InvalidInput {
+                field: String::from("leave_dates"),
+                message: format!("Invalid date format: {date}"),
+            })
+        })
+        .collect()
+}
+
+/// Loads and parses a bid year's lifecycle state.
+pub fn load_lifecycle_state(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<BidYearLifecycle, ApiError> {
+    persistence
+        .get_lifecycle_state(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get lifecycle state: {e}"),
+        })?
+        .parse()
+        .map_err(translate_domain_error)
+}
+
+/// Ensures a round belongs to the bid year.
+pub fn require_round(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    round_id: i64,
+) -> Result<(), ApiError> {
+    let bid_year_rounds: Vec<(i64, String)> = persistence
+        .list_all_rounds_for_bid_year(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list rounds: {e}"),
+        })?;
+    if bid_year_rounds.iter().any(|(id, _)| *id == round_id) {
+        Ok(())
+    } else {
+        Err(translate_domain_error(DomainError::RoundNotFound {
+            round_id,
+        }))
+    }
+}
+
+/// Finds a controller in the area by initials and returns their user ID.
+pub fn resolve_user(
+    persistence: &mut SqlitePersistence,
+    bid_year: &BidYear,
+    area: &Area,
+    initials: &Initials,
+) -> Result<i64, ApiError> {
+    let state: State =
+        persistence
+            .get_current_state(bid_year, area)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to load area state: {e}"),
+            })?;
+    let user: &User = state
+        .users
+        .get(initials)
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("User"),
+            message: format!(
+                "User '{}' not found in area {}",
+                initials.value(),
+                area.area_code()
+            ),
+        })?;
+    user.user_id.ok_or_else(|| ApiError::Internal {
+        message: format!("User '{}' has no ID", initials.value()),
+    })
+}
+
+/// Finds the current initials of a user in an area.
+pub fn resolve_user_initials(
+    persistence: &mut SqlitePersistence,
+    bid_year: &BidYear,
+    area: &Area,
+    user_id: i64,
+) -> Result<Initials, ApiError> {
+    let state: State =
+        persistence
+            .get_current_state(bid_year, area)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to load area state: {e}"),
+            })?;
+    state
+        .find_user_by_id(user_id)
+        .map(|user| user.initials.clone())
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("User"),
+            message: format!("User {user_id} not found in area {}", area.area_code()),
+        })
+}
+
+/// Finds the crew number of a user, for crew-partitioned slot checks.
+pub fn resolve_user_crew(
+    persistence: &mut SqlitePersistence,
+    bid_year: &BidYear,
+    area: &Area,
+    user_id: i64,
+) -> Result<Option<u8>, ApiError> {
+    let state: State =
+        persistence
+            .get_current_state(bid_year, area)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to load area state: {e}"),
+            })?;
+    state
+        .find_user_by_id(user_id)
+        .map(|user| user.crew.as_ref().map(Crew::number))
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("User"),
+            message: format!("User {user_id} not found in area {}", area.area_code()),
+        })
+}
+
+/// Lists a user's leave bids in a round.
+pub fn list_user_leave_bids(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    area_id: i64,
+    round_id: i64,
+    user_id: i64,
+) -> Result<Vec<LeaveBidData>, ApiError> {
+    Ok(persistence
+        .list_leave_bids(bid_year_id, area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list leave bids: {e}"),
+        })?
+        .into_iter()
+        .filter(|bid| bid.user_id == user_id && bid.round_id == round_id)
+        .collect())
+}
+
+/// Records one leave bid per day and returns their IDs in date order.
+#[allow(clippy::too_many_arguments)]
+fn record_leave_days(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    area_id: i64,
+    user_id: i64,
+    round_id: i64,
+    leave_dates: &[Date],
+    hours: i32,
+    on_behalf_of: &str,
+    received_via: &str,
+) -> Result<Vec<i64>, ApiError> {
+    let mut sorted_dates: Vec<Date> = leave_dates.to_vec();
+    sorted_dates.sort_unstable();
+    sorted_dates
+        .iter()
+        .map(|date| {
+            persistence
+                .insert_leave_bid(
+                    bid_year_id,
+                    area_id,
+                    user_id,
+                    round_id,
+                    &date.to_string(),
+                    hours,
+                    on_behalf_of,
+                    received_via,
+                )
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to record leave bid: {e}"),
+                })
+        })
+        .collect()
+}
+
+/// Enters leave bid days for a controller on their behalf.
+///
+/// One leave bid is recorded per day, each carrying the controller's
+/// initials and how the bid was received.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The controller, round, and leave days
+/// * `authenticated_actor` - The authenticated actor entering the bid
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin or Bidder
+/// - The operator is the controller the bid is for
+/// - The receipt method or a leave date is invalid
+/// - The area, round, or controller does not exist
+/// - The bid year is not `BiddingActive`
+/// - The round is signed off in the area and no override reason is given,
+///   or a non-Admin gives one
+/// - The controller already holds leave on a requested day in the round
+/// - The controller already bid in the round and the bid year's amendment
+///   window has passed
+/// - The bid breaks one of the bid year's validation rules
+/// - A requested day has no leave slots remaining in the round
+/// - The database operation fails
+pub fn enter_leave_bid(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &EnterLeaveBidRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<EnterLeaveBidResponse, ApiError> {
+    let (response, _) = record_leave_bid(
+        persistence,
+        metadata,
+        request,
+        authenticated_actor,
+        operator,
+        cause,
+    )?;
+    Ok(response)
+}
+
+/// Enters leave bid days as [`enter_leave_bid`] does, also returning the
+/// ID of the audit event that recorded the entry.
+///
+/// # Errors
+///
+/// Returns the same errors as [`enter_leave_bid`].
+#[allow(clippy::too_many_lines)]
+pub fn record_leave_bid(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &EnterLeaveBidRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<(EnterLeaveBidResponse, i64), ApiError> {
+    if !matches!(authenticated_actor.role, Role::Admin | Role::Bidder) {
+        return Err(ApiError::Unauthorized {
+            action: String::from("enter leave bid"),
+            required_role: String::from("Admin or Bidder"),
+        });
+    }
+
+    if request.override_reason.is_some() {
+        require_admin(authenticated_actor, "override round sign-off")?;
+    }
+
+    let on_behalf_of: Initials = Initials::new(request.on_behalf_of.trim());
+
+    let received_via: BidReceiptMethod = request
+        .received_via
+        .parse()
+        .map_err(translate_domain_error)?;
+    let leave_dates: Vec<Date> = parse_leave_dates(&request.leave_dates)?;
+    let hours: i32 = i32::try_from(request.hours).map_err(|_| ApiError::InvalidInput {
+        field: String::from("hours"),
+        message: format!("Leave hours {} are out of range", request.hours),
+    })?;
+
+    let (bid_year, area) = resolve_area(metadata, request.area_id)?;
+    AuthorizationService::authorize_area_bidding(
+        persistence,
+        authenticated_actor,
+        operator,
+        request.area_id,
+        "enter leave bid",
+    )?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, bid_year_id)?;
+    if lifecycle_state != BidYearLifecycle::BiddingActive {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("enter leave bid"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+    require_round(persistence, bid_year_id, request.round_id)?;
+    let signed_off: bool = is_round_signed_off(persistence, request.area_id, request.round_id)?;
+
+    let user_id: i64 = resolve_user(persistence, bid_year, area, &on_behalf_of)?;
+
+    // Each day may only be held once per user and round, withdrawn or not
+    let user_bids: Vec<LeaveBidData> = list_user_leave_bids(
+        persistence,
+        bid_year_id,
+        request.area_id,
+        request.round_id,
+        user_id,
+    )?;
+    let held: BTreeSet<String> = user_bids.iter().map(|bid| bid.leave_date.clone()).collect();
+    if let Some(date) = leave_dates
+        .iter()
+        .find(|date| held.contains(&date.to_string()))
+    {
+        return Err(translate_domain_error(DomainError::InvalidLeaveBid {
+            reason: format!(
+                "User '{}' already holds a leave bid on {date} in round {}",
+                on_behalf_of.value(),
+                request.round_id
+            ),
+        }));
+    }
+
+    // Further leave in a round amends the controller's submitted bid
+    let policy: BidAmendmentPolicy = load_amendment_policy(persistence, bid_year_id)?;
+    let amendment: Option<BidAmendment> = bid_amendment(
+        persistence,
+        policy,
+        request.area_id,
+        request.round_id,
+        user_id,
+        &user_bids,
+        persistence.now(),
+    )?;
+
+    // Only approved leave counts toward the bid year's rules
+    let approved: BTreeSet<Date> = user_bids
+        .iter()
+        .filter(|bid| bid.status == LeaveBidData::STATUS_APPROVED)
+        .map(|bid| {
+            Date::parse(&bid.leave_date, &Iso8601::DEFAULT).map_err(|_| ApiError::Internal {
+                message: format!("Leave bid {} has an invalid date", bid.leave_bid_id),
+            })
+        })
+        .collect::<Result<BTreeSet<Date>, ApiError>>()?;
+    let rules: Vec<BidRule> = load_bid_rules(persistence, bid_year_id)?;
+    enforce_bid_rules(&rules, &approved, &leave_dates)?;
+
+    // The inventory gives each day's capacity; the approved leave filling
+    // it is counted when the days are recorded
+    let mut sorted_dates: Vec<Date> = leave_dates.clone();
+    sorted_dates.sort_unstable();
+    let mut days: Vec<(String, u32)> = Vec::with_capacity(sorted_dates.len());
+    let mut slot_crew: Option<i32> = None;
+    if let (Some(first), Some(last)) = (sorted_dates.first(), sorted_dates.last()) {
+        let crew: Option<u8> = resolve_user_crew(persistence, bid_year, area, user_id)?;
+        let inventory: SlotInventory =
+            load_slot_inventory(persistence, bid_year_id, *first, *last)?;
+        if inventory.is_crew_partitioned(request.round_id) {
+            slot_crew = crew.map(i32::from);
+        }
+        days = sorted_dates
+            .iter()
+            .map(|date| {
+                let capacity: u32 = inventory
+                    .slots_for_crew(request.area_id, request.round_id, *date, crew)
+                    .capacity();
+                (date.to_string(), capacity)
+            })
+            .collect();
+    }
+
+    let command: Command = Command::EnterLeaveBid {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id: request.round_id,
+        user_id,
+        on_behalf_of: on_behalf_of.clone(),
+        received_via,
+        leave_dates,
+        hours: request.hours,
+        signed_off,
+        override_reason: request.override_reason.clone(),
+        amendment,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    // Check the slots, record the days, and audit the entry atomically
+    let bids: NewLeaveBids = NewLeaveBids {
+        bid_year_id,
+        area_id: request.area_id,
+        user_id,
+        round_id: request.round_id,
+        days,
+        hours,
+        on_behalf_of: on_behalf_of.value().to_string(),
+        received_via: received_via.as_str().to_string(),
+        slot_crew,
+    };
+    let (leave_bid_ids, entry_event_id): (Vec<i64>, i64) = persistence
+        .record_leave_bids(&result.audit_event, &bids)
+        .map_err(|e| match e {
+            PersistenceError::LeaveSlotsExhausted {
+                round_id,
+                leave_date,
+            } => translate_domain_error(DomainError::InvalidLeaveBid {
+                reason: format!("No leave slots remain on {leave_date} in round {round_id}"),
+            }),
+            _ => ApiError::Internal {
+                message: format!("Failed to record leave bid: {e}"),
+            },
+        })?;
+
+    let response: EnterLeaveBidResponse = EnterLeaveBidResponse {
+        area_id: request.area_id,
+        round_id: request.round_id,
+        user_id,
+        on_behalf_of: on_behalf_of.value().to_string(),
+        received_via: received_via.as_str().to_string(),
+        message: format!(
+            "Entered {} leave day(s) for '{}' (received via {})",
+            leave_bid_ids.len(),
+            on_behalf_of.value(),
+            received_via.as_str()
+        ),
+        leave_bid_ids,
+    };
+    Ok((response, entry_event_id))
+}
+
+/// Returns whether a user's window in a round has already opened.
+///
+/// A window has opened once the user has been the current bidder, or once
+/// the round has completed.
+fn window_opened(
+    persistence: &mut SqlitePersistence,
+    area_id: i64,
+    round_id: i64,
+    user_id: i64,
+) -> Result<bool, ApiError> {
+    let state: Option<CurrentBidderStateData> = persistence
+        .get_current_bidder_state(area_id, round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get current bidder: {e}"),
+        })?;
+    let Some(state) = state else {
+        return Ok(false);
+    };
+    let Some(current_user_id) = state.user_id else {
+        return Ok(true);
+    };
+
+    let bidders: Vec<RoundBidderData> =
+        persistence
+            .list_round_bidders(area_id, round_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list round bidders: {e}"),
+            })?;
+    let position = |id: i64| bidders.iter().position(|bidder| bidder.user_id == id);
+
+    Ok(match (position(user_id), position(current_user_id)) {
+        (Some(user_position), Some(current_position)) => user_position <= current_position,
+        _ => false,
+    })
+}
+
+/// Converts a stored preference into a domain preference.
+fn to_domain_preference(preference: &BidPreferenceData) -> Result<BidPreference, ApiError> {
+    let invalid = |field: &str| ApiError::Internal {
+        message: format!(
+            "Stored bid preference {} has an invalid {field}",
+            preference.bid_preference_id
+        ),
+    };
+    let rank: u32 = u32::try_from(preference.preference_rank).map_err(|_| invalid("rank"))?;
+    let hours: u32 = u32::try_from(preference.hours).map_err(|_| invalid("hours"))?;
+    let leave_dates: Vec<Date> = preference
+        .leave_dates
+        .iter()
+        .map(|date| Date::parse(date, &Iso8601::DEFAULT).map_err(|_| invalid("leave date")))
+        .collect::<Result<Vec<Date>, ApiError>>()?;
+
+    BidPreference::new(rank, leave_dates, hours).map_err(translate_domain_error)
+}
+
+/// Submits a controller's ranked leave preferences for a round.
+///
+/// Preferences are ranked in request order and replace any pending list.
+/// They are accepted until the controller's window in the round opens.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The controller, round, and ranked preferences
+/// * `authenticated_actor` - The authenticated actor entering the list
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin or Bidder
+/// - The receipt method, a leave date, or a preference is invalid
+/// - The area, round, or controller does not exist
+/// - The operator is the controller the preferences are for
+/// - The bid year is not `Canonicalized` or `BiddingActive`
+/// - The controller's window in the round has already opened
+/// - The database operation fails
+#[allow(clippy::too_many_lines)]
+pub fn submit_bid_preferences(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SubmitBidPreferencesRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SubmitBidPreferencesResponse, ApiError> {
+    if !matches!(authenticated_actor.role, Role::Admin | Role::Bidder) {
+        return Err(ApiError::Unauthorized {
+            action: String::from("submit bid preferences"),
+            required_role: String::from("Admin or Bidder"),
+        });
+    }
+
+    let received_via: BidReceiptMethod = request
+        .received_via
+        .parse()
+        .map_err(translate_domain_error)?;
+    let preferences: Vec<BidPreference> = request
+        .preferences
+        .iter()
+        .zip(1..)
+        .map(|(entry, rank)| {
+            BidPreference::new(rank, parse_leave_dates(&entry.leave_dates)?, entry.hours)
+                .map_err(translate_domain_error)
+        })
+        .collect::<Result<Vec<BidPreference>, ApiError>>()?;
+    let specs: Vec<BidPreferenceSpecData> = preferences
+        .iter()
+        .map(|preference| {
+            Ok(BidPreferenceSpecData {
+                preference_rank: i32::try_from(preference.rank()).map_err(|_| {
+                    ApiError::InvalidInput {
+                        field: String::from("preferences"),
+                        message: String::from("Too many preferences"),
+                    }
+                })?,
+                leave_dates: preference
+                    .leave_dates()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+                hours: i32::try_from(preference.hours()).map_err(|_| ApiError::InvalidInput {
+                    field: String::from("hours"),
+                    message: format!("Leave hours {} are out of range", preference.hours()),
+                })?,
+            })
+        })
+        .collect::<Result<Vec<BidPreferenceSpecData>, ApiError>>()?;
+
+    let (bid_year, area) = resolve_area(metadata, request.area_id)?;
+    AuthorizationService::authorize_area_bidding(
+        persistence,
+        authenticated_actor,
+        operator,
+        request.area_id,
+        "submit bid preferences",
+    )?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, bid_year_id)?;
+    if !matches!(
+        lifecycle_state,
+        BidYearLifecycle::Canonicalized | BidYearLifecycle::BiddingActive
+    ) {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("submit bid preferences"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+    require_round(persistence, bid_year_id, request.round_id)?;
+
+    let on_behalf_of: Initials = Initials::new(request.on_behalf_of.trim());
+    let user_id: i64 = resolve_user(persistence, bid_year, area, &on_behalf_of)?;
+
+    if window_opened(persistence, request.area_id, request.round_id, user_id)? {
+        return Err(translate_domain_error(DomainError::InvalidBidPreference {
+            reason: format!(
+                "The bid window for '{}' in round {} has already opened",
+                on_behalf_of.value(),
+                request.round_id
+            ),
+        }));
+    }
+
+    let command: Command = Command::SubmitBidPreferences {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id: request.round_id,
+        user_id,
+        on_behalf_of: on_behalf_of.clone(),
+        received_via,
+        preferences,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    persistence
+        .submit_bid_preferences(
+            &result.audit_event,
+            bid_year_id,
+            request.area_id,
+            user_id,
+            request.round_id,
+            on_behalf_of.value(),
+            received_via.as_str(),
+            &specs,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record bid preferences: {e}"),
+        })?;
+
+    Ok(SubmitBidPreferencesResponse {
+        area_id: request.area_id,
+        round_id: request.round_id,
+        user_id,
+        on_behalf_of: on_behalf_of.value().to_string(),
+        received_via: received_via.as_str().to_string(),
+        preference_count: specs.len(),
+        message: format!(
+            "Recorded {} ranked preference(s) for '{}' (received via {})",
+            specs.len(),
+            on_behalf_of.value(),
+            received_via.as_str()
+        ),
+    })
+}
+
+/// Lists the ranked leave preferences for a round in an area.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `area_id` - The canonical area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the area does not exist or the query fails.
+pub fn list_bid_preferences(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    area_id: i64,
+    round_id: i64,
+) -> Result<ListBidPreferencesResponse, ApiError> {
+    resolve_area(metadata, area_id)?;
+
+    let preferences: Vec<BidPreferenceInfo> = persistence
+        .list_bid_preferences(area_id, round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list bid preferences: {e}"),
+        })?
+        .into_iter()
+        .map(|preference| BidPreferenceInfo {
+            bid_preference_id: preference.bid_preference_id,
+            user_id: preference.user_id,
+            preference_rank: preference.preference_rank,
+            leave_dates: preference.leave_dates,
+            hours: preference.hours,
+            on_behalf_of: preference.on_behalf_of,
+            received_via: preference.received_via,
+            status: preference.status,
+        })
+        .collect();
+
+    Ok(ListBidPreferencesResponse {
+        area_id,
+        round_id,
+        preferences,
+    })
+}
+
+/// A user's pending preferences, checked and ready to convert into leave
+/// bids by [`record_bid_preferences`].
+pub struct PreparedBidPreferences {
+    bid_year_id: i64,
+    area_id: i64,
+    round_id: i64,
+    user_id: i64,
+    pending: Vec<BidPreferenceData>,
+    preferences: Vec<BidPreference>,
+    applied_rank: Option<u32>,
+    passed_over: Vec<u32>,
+    audit_event: AuditEvent,
+}
+
+/// Chooses which of a user's preferences to convert into leave bids,
+/// without writing anything.
+///
+/// Called when the user's window in a round opens. A preference fits when
+/// every day has a slot remaining in the slot inventory and the user does
+/// not already hold leave on it. Preferences ranked above the applied
+/// one are passed over and those below it unused; [`record_bid_preferences`]
+/// writes the outcome.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `area_id` - The canonical area ID
+/// * `round_id` - The round whose window opened
+/// * `user_id` - The user whose window opened
+/// * `actor` - The actor the application is attributed to
+/// * `cause` - The cause for this action
+///
+/// # Returns
+///
+/// The checked preferences, or `None` if the user has none pending.
+///
+/// # Errors
+///
+/// Returns an error if the area or round does not exist, the actor's
+/// operator is the controller, or a query fails.
+pub fn prepare_bid_preferences(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    area_id: i64,
+    round_id: i64,
+    user_id: i64,
+    actor: Actor,
+    cause: Cause,
+) -> Result<Option<PreparedBidPreferences>, ApiError> {
+    let pending: Vec<BidPreferenceData> = persistence
+        .list_bid_preferences(area_id, round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list bid preferences: {e}"),
+        })?
+        .into_iter()
+        .filter(|preference| {
+            preference.user_id == user_id && preference.status == BidPreferenceData::STATUS_PENDING
+        })
+        .collect();
+    if pending.is_empty() {
+        return Ok(None);
+    }
+
+    let (bid_year, area) = resolve_area(metadata, area_id)?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+    let preferences: Vec<BidPreference> = pending
+        .iter()
+        .map(to_domain_preference)
+        .collect::<Result<Vec<BidPreference>, ApiError>>()?;
+
+    let dates = || preferences.iter().flat_map(BidPreference::leave_dates);
+    let inventory: SlotInventory = match (dates().min(), dates().max()) {
+        (Some(first), Some(last)) => load_slot_inventory(persistence, bid_year_id, *first, *last)?,
+        _ => SlotInventory::new(),
+    };
+
+    let held: BTreeSet<Date> =
+        list_user_leave_bids(persistence, bid_year_id, area_id, round_id, user_id)?
+            .into_iter()
+            .map(|bid| {
+                Date::parse(&bid.leave_date, &Iso8601::DEFAULT).map_err(|_| ApiError::Internal {
+                    message: format!("Leave bid {} has an invalid date", bid.leave_bid_id),
+                })
+            })
+            .collect::<Result<BTreeSet<Date>, ApiError>>()?;
+
+    let crew: Option<u8> = resolve_user_crew(persistence, bid_year, area, user_id)?;
+    let applied: Option<BidPreference> = select_bid_preference(
+        &preferences,
+        |date| {
+            inventory
+                .slots_for_crew(area_id, round_id, date, crew)
+                .remaining()
+        },
+        &held,
+    )
+    .cloned();
+    let applied_rank: Option<u32> = applied.as_ref().map(BidPreference::rank);
+    let mut passed_over: Vec<u32> = preferences
+        .iter()
+        .map(BidPreference::rank)
+        .filter(|rank| applied_rank.is_none_or(|applied_rank| *rank < applied_rank))
+        .collect();
+    passed_over.sort_unstable();
+
+    let on_behalf_of: Initials = resolve_user_initials(persistence, bid_year, area, user_id)?;
+    let command: Command = Command::ApplyBidPreference {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id,
+        user_id,
+        on_behalf_of,
+        applied,
+        passed_over: passed_over.clone(),
+    };
+    let result: BootstrapResult =
+        apply_bootstrap(metadata, bid_year, command, actor, cause).map_err(translate_core_error)?;
+
+    Ok(Some(PreparedBidPreferences {
+        bid_year_id,
+        area_id,
+        round_id,
+        user_id,
+        pending,
+        preferences,
+        applied_rank,
+        passed_over,
+        audit_event: result.audit_event,
+    }))
+}
+
+/// Writes the leave bids, preference statuses, and audit event of prepared
+/// preferences.
+///
+/// # Returns
+///
+/// The rank of the applied preference, or `None` if none was applied.
+///
+/// # Errors
+///
+/// Returns an error if persistence fails.
+pub fn record_bid_preferences(
+    persistence: &mut SqlitePersistence,
+    prepared: PreparedBidPreferences,
+) -> Result<Option<u32>, ApiError> {
+    let PreparedBidPreferences {
+        bid_year_id,
+        area_id,
+        round_id,
+        user_id,
+        pending,
+        preferences,
+        applied_rank,
+        passed_over,
+        audit_event,
+    } = prepared;
+
+    if let Some((stored, preference)) = pending
+        .iter()
+        .zip(&preferences)
+        .find(|(_, preference)| Some(preference.rank()) == applied_rank)
+    {
+        record_leave_days(
+            persistence,
+            bid_year_id,
+            area_id,
+            user_id,
+            round_id,
+            preference.leave_dates(),
+            stored.hours,
+            &stored.on_behalf_of,
+            &stored.received_via,
+        )?;
+    }
+
+    for (stored, preference) in pending.iter().zip(&preferences) {
+        let status: &str = if Some(preference.rank()) == applied_rank {
+            BidPreferenceData::STATUS_APPLIED
+        } else if passed_over.contains(&preference.rank()) {
+            BidPreferenceData::STATUS_PASSED_OVER
+        } else {
+            BidPreferenceData::STATUS_UNUSED
+        };
+        persistence
+            .set_bid_preference_status(stored.bid_preference_id, status)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to update bid preference: {e}"),
+            })?;
+    }
+
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(applied_rank)
+}
@@ -0,0 +1,498 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Annual leave carryover cap handlers.
+//!
+//! Each bid year records how much annual leave may carry into the next
+//! year, and each controller's balance carried in from the prior year.
+//! Together with the year's accrual and the leave awarded so far, these
+//! project every controller's end-of-year balance and flag the hours above
+//! the cap that will be lost unless they are used, as well as controllers
+//! whose pending bids ask for more leave than they will have.
+
+use std::collections::HashMap;
+
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{
+    AccrualRates, Area, BidYear, CanonicalBidYear, Facility, HoursCategory, Initials,
+    LeaveBalanceProjection, LeaveCapPolicy, User, project_annual_accrual, project_leave_balance,
+};
+use zab_bid_persistence::{LeaveBidData, OperatorData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_domain_error};
+use crate::facility::{load_accrual_rates, load_facility};
+use crate::leave_bids::{resolve_area, resolve_user};
+use crate::request_response::{
+    GetLeaveCapResponse, LeaveProjectionInfo, ListLeaveProjectionsResponse, SetLeaveCapRequest,
+    SetLeaveCapResponse, SetLeaveCarryoverRequest, SetLeaveCarryoverResponse,
+};
+use crate::webhooks::require_admin;
+
+/// One controller's projected end-of-year balance.
+pub struct UserLeaveProjection {
+    pub area_code: String,
+    pub user_id: i64,
+    pub initials: String,
+    pub name: String,
+    pub hours_category: HoursCategory,
+    pub projection: LeaveBalanceProjection,
+}
+
+impl From<&UserLeaveProjection> for LeaveProjectionInfo {
+    fn from(row: &UserLeaveProjection) -> Self {
+        Self {
+            area_code: row.area_code.clone(),
+            user_id: row.user_id,
+            initials: row.initials.clone(),
+            name: row.name.clone(),
+            hours_category: row.hours_category.as_str().to_string(),
+            carried_in_hours: row.projection.carried_in_hours,
+            forfeited_carryover_hours: row.projection.forfeited_carryover_hours,
+            accrued_hours: row.projection.accrued_hours,
+            awarded_hours: row.projection.awarded_hours,
+            pending_hours: row.projection.pending_hours,
+            projected_balance_hours: row.projection.projected_balance_hours,
+            use_or_lose_hours: row.projection.use_or_lose_hours,
+            overdrawn_hours: row.projection.overdrawn_hours,
+        }
+    }
+}
+
+/// Loads a bid year's carryover cap.
+///
+/// A bid year without a stored cap inherits the facility's cap, or the
+/// default of 240 hours if the facility is not configured.
+///
+/// # Errors
+///
+/// Returns an error if the cap cannot be read or the stored cap is invalid.
+pub fn load_leave_cap_policy(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<LeaveCapPolicy, ApiError> {
+    let Some(cap) = persistence
+        .get_leave_cap(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get leave cap: {e}"),
+        })?
+    else {
+        return Ok(load_facility(persistence)?
+            .as_ref()
+            .map_or_else(LeaveCapPolicy::default, Facility::leave_cap));
+    };
+
+    u16::try_from(cap)
+        .ok()
+        .and_then(|cap| LeaveCapPolicy::new(cap).ok())
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Stored leave cap of bid year {bid_year_id} is invalid"),
+        })
+}
+
+/// Sums the leave hours each user requests in pending bid preferences,
+/// keyed by user ID.
+///
+/// Only the top-ranked pending preference of each round counts: it is the
+/// leave the user receives if it fits.
+fn load_pending_hours(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<HashMap<i64, u32>, ApiError> {
+    let mut pending: HashMap<i64, u32> = HashMap::new();
+    let mut counted_rounds: Vec<(i64, i64)> = Vec::new();
+    for preference in persistence
+        .list_pending_bid_preferences(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load bid preferences: {e}"),
+        })?
+    {
+        // Preferences arrive ranked, so the first of each round is the top
+        if counted_rounds.contains(&(preference.user_id, preference.round_id)) {
+            continue;
+        }
+        counted_rounds.push((preference.user_id, preference.round_id));
+        let days: u32 = u32::try_from(preference.leave_dates.len()).unwrap_or(0);
+        *pending.entry(preference.user_id).or_default() +=
+            u32::try_from(preference.hours).unwrap_or(0) * days;
+    }
+
+    Ok(pending)
+}
+
+/// Projects the end-of-year balance of every controller in a bid year.
+///
+/// Accrual uses the facility's accrual rates and the hours category each
+/// controller holds over the pay period calendar. Controllers excluded
+/// from leave calculation, or whose accrual cannot be computed, are
+/// omitted. Awarded hours are the controller's approved leave bids;
+/// withdrawn leave no longer counts. Pending hours come from the
+/// controller's top-ranked pending preferences.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - Limits the projection to one area, if set
+///
+/// # Errors
+///
+/// Returns an error if the bid year or area does not exist or the database
+/// cannot be queried.
+pub fn project_leave_balances(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+    area_id: Option<i64>,
+) -> Result<Vec<UserLeaveProjection>, ApiError> {
+    let year: u16 = resolve_bid_year(metadata, bid_year_id)?;
+    let areas: Vec<(&BidYear, &Area)> = match area_id {
+        Some(area_id) => {
+            let (bid_year, area) = resolve_area(metadata, area_id)?;
+            if bid_year.year() != year {
+                return Err(ApiError::InvalidInput {
+                    field: String::from("area_id"),
+                    message: format!("Area '{}' is not part of bid year {year}", area.area_code()),
+                });
+            }
+            vec![(bid_year, area)]
+        }
+        None => metadata
+            .areas
+            .iter()
+            .filter(|(by, area)| by.year() == year && !area.is_system_area())
+            .map(|(by, area)| (by, area))
+            .collect(),
+    };
+
+    let canonical_bid_year: CanonicalBidYear = persistence
+        .list_bid_years()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load bid years: {e}"),
+        })?
+        .into_iter()
+        .find(|by| by.year() == year)
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Bid year {year} exists in metadata but not in storage"),
+        })?;
+    let policy: LeaveCapPolicy = load_leave_cap_policy(persistence, bid_year_id)?;
+    let rates: AccrualRates = load_accrual_rates(persistence)?;
+    let carryovers: HashMap<i64, i32> = persistence
+        .list_leave_carryovers(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load leave carryovers: {e}"),
+        })?
+        .into_iter()
+        .map(|carryover| (carryover.user_id, carryover.carryover_hours))
+        .collect();
+    let pending: HashMap<i64, u32> = load_pending_hours(persistence, bid_year_id)?;
+
+    let mut projections: Vec<UserLeaveProjection> = Vec::new();
+    for (bid_year, area) in areas {
+        let Some(area_id) = area.area_id() else {
+            continue;
+        };
+        let users: Vec<User> = persistence
+            .list_users_with_routing(bid_year_id, area_id, bid_year, area)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to load users: {e}"),
+            })?;
+        let mut awarded: HashMap<i64, u32> = HashMap::new();
+        for bid in persistence
+            .list_leave_bids(bid_year_id, area_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to load leave bids: {e}"),
+            })?
+            .into_iter()
+            .filter(|bid| bid.status == LeaveBidData::STATUS_APPROVED)
+        {
+            *awarded.entry(bid.user_id).or_default() += u32::try_from(bid.hours).unwrap_or(0);
+        }
+
+        for user in users
+            .iter()
+            .filter(|user| !user.excluded_from_leave_calculation)
+        {
+            let Some(user_id) = user.user_id else {
+                continue;
+            };
+            let Ok(annual) = project_annual_accrual(user, &canonical_bid_year, rates) else {
+                continue;
+            };
+            let prior_balance_hours: u16 = carryovers
+                .get(&user_id)
+                .and_then(|hours| u16::try_from(*hours).ok())
+                .unwrap_or(0);
+            projections.push(UserLeaveProjection {
+                area_code: area.area_code().to_string(),
+                user_id,
+                initials: user.initials.value().to_string(),
+                name: user.name.clone(),
+                hours_category: annual.ending_category,
+                projection: project_leave_balance(
+                    policy,
+                    prior_balance_hours,
+                    &annual.accrual,
+                    awarded.get(&user_id).copied().unwrap_or(0),
+                    pending.get(&user_id).copied().unwrap_or(0),
+                ),
+            });
+        }
+    }
+
+    Ok(projections)
+}
+
+/// Readiness warnings raised by controllers' projected leave balances.
+pub struct LeaveBalanceWarnings {
+    /// One warning per controller over the use-or-lose threshold.
+    pub use_or_lose: Vec<String>,
+    /// One warning per controller whose awarded and pending leave exceeds
+    /// their projected accrual.
+    pub overdrawn: Vec<String>,
+}
+
+/// Builds the readiness warnings for controllers' projected leave balances.
+///
+/// # Errors
+///
+/// Returns an error if the projections cannot be computed.
+pub fn leave_balance_warnings(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<LeaveBalanceWarnings, ApiError> {
+    let projections: Vec<UserLeaveProjection> =
+        project_leave_balances(persistence, metadata, bid_year_id, None)?;
+
+    Ok(LeaveBalanceWarnings {
+        use_or_lose: projections
+            .iter()
+            .filter(|row| row.projection.is_over_threshold())
+            .map(|row| {
+                format!(
+                    "Area '{}': '{}' is projected to lose {} hours of annual leave",
+                    row.area_code, row.initials, row.projection.use_or_lose_hours
+                )
+            })
+            .collect(),
+        overdrawn: projections
+            .iter()
+            .filter(|row| row.projection.exceeds_accrual())
+            .map(|row| {
+                format!(
+                    "Area '{}': '{}' has bid {} hours more annual leave than projected to accrue",
+                    row.area_code, row.initials, row.projection.overdrawn_hours
+                )
+            })
+            .collect(),
+    })
+}
+
+/// Gets a bid year's carryover cap.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn get_leave_cap(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<GetLeaveCapResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+    let policy: LeaveCapPolicy = load_leave_cap_policy(persistence, bid_year_id)?;
+
+    Ok(GetLeaveCapResponse {
+        bid_year_id,
+        carryover_cap_hours: policy.carryover_cap_hours(),
+    })
+}
+
+/// Sets a bid year's carryover cap.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year and its new cap
+/// * `authenticated_actor` - The authenticated actor setting the cap
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist
+/// - The cap is zero
+/// - The database operation fails
+pub fn set_leave_cap(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetLeaveCapRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetLeaveCapResponse, ApiError> {
+    require_admin(authenticated_actor, "set leave carryover cap")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let policy: LeaveCapPolicy =
+        LeaveCapPolicy::new(request.carryover_cap_hours).map_err(translate_domain_error)?;
+
+    let previous: LeaveCapPolicy = load_leave_cap_policy(persistence, request.bid_year_id)?;
+    persistence
+        .set_leave_cap(request.bid_year_id, i32::from(policy.carryover_cap_hours()))
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to store leave cap: {e}"),
+        })?;
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("SetLeaveCap"),
+        Some(format!(
+            "Set annual leave carryover cap for bid year {year} to {} hours",
+            policy.carryover_cap_hours()
+        )),
+    );
+    let before: StateSnapshot = StateSnapshot::new(format!(
+        "carryover_cap_hours={}",
+        previous.carryover_cap_hours()
+    ));
+    let after: StateSnapshot = StateSnapshot::new(format!(
+        "carryover_cap_hours={}",
+        policy.carryover_cap_hours()
+    ));
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetLeaveCapResponse {
+        bid_year_id: request.bid_year_id,
+        carryover_cap_hours: policy.carryover_cap_hours(),
+        message: format!(
+            "Bid year {year} carryover cap set to {} hours",
+            policy.carryover_cap_hours()
+        ),
+    })
+}
+
+/// Sets the annual leave a controller carries into a bid year.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The controller and their carried-in hours
+/// * `authenticated_actor` - The authenticated actor recording the balance
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The area or controller does not exist
+/// - The database operation fails
+pub fn set_leave_carryover(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetLeaveCarryoverRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetLeaveCarryoverResponse, ApiError> {
+    require_admin(authenticated_actor, "set leave carryover")?;
+
+    let (bid_year, area) = resolve_area(metadata, request.area_id)?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+    let initials: Initials = Initials::new(request.initials.trim());
+    let user_id: i64 = resolve_user(persistence, bid_year, area, &initials)?;
+
+    let previous: i32 = persistence
+        .list_leave_carryovers(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load leave carryovers: {e}"),
+        })?
+        .into_iter()
+        .find(|carryover| carryover.user_id == user_id)
+        .map_or(0, |carryover| carryover.carryover_hours);
+    persistence
+        .set_leave_carryover(bid_year_id, user_id, i32::from(request.carryover_hours))
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to store leave carryover: {e}"),
+        })?;
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("SetLeaveCarryover"),
+        Some(format!(
+            "Set leave carried into {} for '{}' to {} hours",
+            bid_year.year(),
+            initials.value(),
+            request.carryover_hours
+        )),
+    );
+    let before: StateSnapshot = StateSnapshot::new(format!("carryover_hours={previous}"));
+    let after: StateSnapshot =
+        StateSnapshot::new(format!("carryover_hours={}", request.carryover_hours));
+    let audit_event: AuditEvent = AuditEvent::new(
+        actor,
+        cause,
+        action,
+        before,
+        after,
+        bid_year.clone(),
+        area.clone(),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetLeaveCarryoverResponse {
+        bid_year_id,
+        user_id,
+        carryover_hours: request.carryover_hours,
+        message: format!(
+            "'{}' carries {} hours into {}",
+            initials.value(),
+            request.carryover_hours,
+            bid_year.year()
+        ),
+    })
+}
+
+/// Lists every controller's projected end-of-year leave balance.
+///
+/// # Errors
+///
+/// Returns an error if the bid year or area does not exist or the database
+/// cannot be queried.
+pub fn list_leave_projections(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+    area_id: Option<i64>,
+) -> Result<ListLeaveProjectionsResponse, ApiError> {
+    let policy: LeaveCapPolicy = load_leave_cap_policy(persistence, bid_year_id)?;
+    let projections: Vec<UserLeaveProjection> =
+        project_leave_balances(persistence, metadata, bid_year_id, area_id)?;
+
+    Ok(ListLeaveProjectionsResponse {
+        bid_year_id,
+        carryover_cap_hours: policy.carryover_cap_hours(),
+        projections: projections.iter().map(LeaveProjectionInfo::from).collect(),
+    })
+}
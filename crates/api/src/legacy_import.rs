@@ -0,0 +1,854 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Legacy bid tracker import.
+//!
+//! Facilities moving from the Access/Excel bid tracker bring their history
+//! with them by importing its bid log export into a bid year. The export is
+//! a CSV with one row per leave day, repeating the controller's roster
+//! details on every row:
+//!
+//! ```text
+//! Init,Name,Area,Type,Crew,SCD,EOD,NATCA BU,Cum NATCA BU,Lottery,Round,Round Name,Leave Date,Hours
+//! AB,Alice Baker,North,CPC,1,1/15/2010,1/15/2010,6/1/2010,6/1/2010,,1,Prime Time,7/4/2025,8
+//! ```
+//!
+//! Headers are matched case-insensitively. Dates may be written `M/D/YYYY`,
+//! as the tracker exports them, or `YYYY-MM-DD`. A row without a leave date
+//! carries only roster details.
+//!
+//! Rows map onto the bid year as follows:
+//!
+//! - **users** are matched by initials, and registered from the row's
+//!   roster details when missing
+//! - **rounds** are matched by number within the area's round group, and
+//!   created when missing, sized to hold the imported bids
+//! - **bids** become approved leave bids received via `legacy_import`, so
+//!   imported history is always told apart from bids entered here
+//!
+//! [`preview_legacy_import`] reports what an import would do, listing each
+//! row that cannot be mapped with the reason. [`import_legacy_bids`]
+//! imports the rows that can be mapped and skips the rest. Leave already
+//! recorded is reported rather than imported again, so an export can be
+//! re-imported once its unmappable rows are fixed.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use csv::StringRecord;
+use time::format_description::well_known::Iso8601;
+use time::{Date, Month};
+use zab_bid::{BootstrapMetadata, State, TransitionResult, apply};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{Area, BidYear, BidYearLifecycle, Crew, Round, User, UserType};
+use zab_bid_persistence::{LeaveBidData, OperatorData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::auto_checkpoints::{
+    AutoCheckpoints, record_bid_year_auto_checkpoints, record_operation_events,
+};
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_core_error};
+use crate::handlers::build_register_user_command;
+use crate::leave_bids::load_lifecycle_state;
+use crate::request_response::{
+    LegacyImportReport, LegacyImportRequest, LegacyImportResponse, LegacyRoundInfo,
+    RegisterUserRequest, UnmappableLegacyRow,
+};
+use crate::webhooks::require_admin;
+
+// Normalized headers of the tracker's bid log export
+const INITIALS: &str = "init";
+const NAME: &str = "name";
+const AREA: &str = "area";
+const TYPE: &str = "type";
+const CREW: &str = "crew";
+const SCD: &str = "scd";
+const EOD: &str = "eod";
+const NATCA_BU: &str = "natca_bu";
+const CUM_NATCA_BU: &str = "cum_natca_bu";
+const LOTTERY: &str = "lottery";
+const ROUND: &str = "round";
+const ROUND_NAME: &str = "round_name";
+const LEAVE_DATE: &str = "leave_date";
+const HOURS: &str = "hours";
+
+/// Headers every export must have.
+const REQUIRED_HEADERS: &[&str] = &[INITIALS, AREA];
+
+/// Normalizes a header, so `Cum. NATCA BU` matches `cum_natca_bu`.
+fn normalize_header(header: &str) -> String {
+    header
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join("_")
+}
+
+/// Returns a normalized header as the tracker writes it, for messages.
+fn display_header(header: &str) -> String {
+    header.replace('_', " ").to_uppercase()
+}
+
+/// Parses a tracker date, written `M/D/YYYY` or `YYYY-MM-DD`.
+fn parse_legacy_date(text: &str) -> Option<Date> {
+    if let Ok(date) = Date::parse(text, &Iso8601::DEFAULT) {
+        return Some(date);
+    }
+    let parts: Vec<&str> = text.split('/').map(str::trim).collect();
+    let [month, day, year] = parts.as_slice() else {
+        return None;
+    };
+    let month: Month = month
+        .parse::<u8>()
+        .ok()
+        .and_then(|m| Month::try_from(m).ok())?;
+    Date::from_calendar_date(year.parse().ok()?, month, day.parse().ok()?).ok()
+}
+
+/// Parses leave hours, which the tracker may write as `8.0`.
+fn parse_hours(text: &str) -> Option<u32> {
+    let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+    if !fraction.chars().all(|c| c == '0') {
+        return None;
+    }
+    whole.parse().ok().filter(|hours| *hours > 0)
+}
+
+/// Parses a user type, ignoring case.
+fn parse_user_type(text: &str) -> Option<UserType> {
+    [
+        UserType::CPC,
+        UserType::CpcIt,
+        UserType::DevR,
+        UserType::DevD,
+    ]
+    .into_iter()
+    .find(|user_type| user_type.as_str().eq_ignore_ascii_case(text))
+}
+
+/// Builds the registration of a controller missing from the roster.
+fn legacy_registration(
+    initials: &str,
+    area: &Area,
+    field: &impl Fn(&str) -> Option<String>,
+) -> Result<RegisterUserRequest, String> {
+    if initials.chars().count() != 2 {
+        return Err(format!(
+            "Cannot register '{initials}': initials must be exactly 2 characters"
+        ));
+    }
+    let missing: Vec<String> = [NAME, TYPE, SCD, EOD]
+        .into_iter()
+        .filter(|header| field(header).is_none())
+        .map(display_header)
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "Cannot register '{initials}': missing {}",
+            missing.join(", ")
+        ));
+    }
+
+    let date = |header: &str| -> Result<String, String> {
+        field(header).map_or_else(
+            || Ok(String::new()),
+            |text| {
+                parse_legacy_date(&text)
+                    .map(|date| date.to_string())
+                    .ok_or_else(|| format!("Invalid {} '{text}'", display_header(header)))
+            },
+        )
+    };
+    let user_type: String = field(TYPE)
+        .map(|text| {
+            parse_user_type(&text)
+                .map(|user_type| user_type.as_str().to_string())
+                .ok_or_else(|| format!("Unknown TYPE '{text}'"))
+        })
+        .transpose()?
+        .unwrap_or_default();
+    let crew: Option<u8> = field(CREW)
+        .map(|text| {
+            text.parse::<u8>()
+                .ok()
+                .filter(|crew| Crew::new(*crew).is_ok())
+                .ok_or_else(|| format!("Invalid CREW '{text}'"))
+        })
+        .transpose()?;
+    let lottery_value: Option<u32> = field(LOTTERY)
+        .map(|text| {
+            text.parse::<u32>()
+                .map_err(|_| format!("Invalid LOTTERY '{text}'"))
+        })
+        .transpose()?;
+
+    Ok(RegisterUserRequest {
+        initials: initials.to_string(),
+        name: field(NAME).unwrap_or_default(),
+        area: area.area_code().to_string(),
+        user_type,
+        crew,
+        cumulative_natca_bu_date: date(CUM_NATCA_BU)?,
+        natca_bu_date: date(NATCA_BU)?,
+        eod_faa_date: date(EOD)?,
+        service_computation_date: date(SCD)?,
+        lottery_value,
+    })
+}
+
+/// A controller registered by the import.
+struct Registration {
+    request: RegisterUserRequest,
+    /// The first row naming the controller.
+    row_number: usize,
+    /// Every row naming the controller.
+    row_numbers: Vec<usize>,
+}
+
+/// A leave day the import records.
+struct PlannedBid {
+    area: Area,
+    initials: String,
+    round_group_id: i64,
+    round_number: u32,
+    round_name: String,
+    leave_date: Date,
+    hours: u32,
+}
+
+/// What importing an export does to a bid year.
+struct ImportPlan {
+    bid_year: BidYear,
+    bid_year_id: i64,
+    /// Areas of the bid year, keyed by area code.
+    areas: BTreeMap<String, Area>,
+    /// Users already on the roster: their area code and ID, by initials.
+    roster: HashMap<String, (String, i64)>,
+    /// Existing rounds by round group and number.
+    rounds: HashMap<(i64, u32), i64>,
+    /// Leave already recorded, as user, round, and date.
+    recorded: BTreeSet<(i64, i64, String)>,
+    total_rows: usize,
+    registrations: BTreeMap<String, Registration>,
+    /// Controllers whose registration row could not be mapped, with that row.
+    unregistrable: HashMap<String, usize>,
+    /// Leave listed in the export, with the row listing it.
+    listed: HashMap<(String, i64, u32, Date), usize>,
+    bids: Vec<PlannedBid>,
+    unmappable: Vec<UnmappableLegacyRow>,
+}
+
+impl ImportPlan {
+    /// Loads the bid year's roster, rounds, and recorded leave.
+    fn load(
+        persistence: &mut SqlitePersistence,
+        metadata: &BootstrapMetadata,
+        bid_year_id: i64,
+    ) -> Result<Self, ApiError> {
+        let year: u16 = resolve_bid_year(metadata, bid_year_id)?;
+        let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, bid_year_id)?;
+        if lifecycle_state.is_locked() {
+            return Err(ApiError::DomainRuleViolation {
+                rule: String::from("legacy_import_lifecycle"),
+                message: format!(
+                    "Cannot import legacy bids in state '{lifecycle_state}': structural changes locked after confirmation"
+                ),
+            });
+        }
+
+        let bid_year: BidYear = BidYear::with_id(bid_year_id, year);
+        let areas: BTreeMap<String, Area> = metadata
+            .areas
+            .iter()
+            .filter(|(by, area)| by.year() == year && !area.is_system_area())
+            .map(|(_, area)| (area.area_code().to_uppercase(), area.clone()))
+            .collect();
+
+        let mut plan: Self = Self {
+            bid_year,
+            bid_year_id,
+            areas,
+            roster: HashMap::new(),
+            rounds: HashMap::new(),
+            recorded: BTreeSet::new(),
+            total_rows: 0,
+            registrations: BTreeMap::new(),
+            unregistrable: HashMap::new(),
+            listed: HashMap::new(),
+            bids: Vec::new(),
+            unmappable: Vec::new(),
+        };
+        plan.load_roster(persistence)?;
+
+        let round_group_ids: BTreeSet<i64> = plan
+            .areas
+            .values()
+            .filter_map(Area::round_group_id)
+            .collect();
+        for round_group_id in round_group_ids {
+            let rounds: Vec<Round> =
+                persistence
+                    .list_rounds(round_group_id)
+                    .map_err(|e| ApiError::Internal {
+                        message: format!("Failed to list rounds: {e}"),
+                    })?;
+            for round in rounds {
+                if let Some(round_id) = round.round_id() {
+                    plan.rounds
+                        .insert((round_group_id, round.round_number()), round_id);
+                }
+            }
+        }
+
+        for area_id in plan.areas.values().filter_map(Area::area_id) {
+            let bids: Vec<LeaveBidData> = persistence
+                .list_leave_bids(bid_year_id, area_id)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to list leave bids: {e}"),
+                })?;
+            plan.recorded.extend(
+                bids.into_iter()
+                    .filter(|bid| bid.status == LeaveBidData::STATUS_APPROVED)
+                    .map(|bid| (bid.user_id, bid.round_id, bid.leave_date)),
+            );
+        }
+
+        Ok(plan)
+    }
+
+    /// Reads the bid year's roster, keyed by initials.
+    fn load_roster(&mut self, persistence: &mut SqlitePersistence) -> Result<(), ApiError> {
+        self.roster.clear();
+        for (area_code, area) in &self.areas {
+            let users: Vec<User> =
+                persistence
+                    .list_users(&self.bid_year, area)
+                    .map_err(|e| ApiError::Internal {
+                        message: format!("Failed to list users: {e}"),
+                    })?;
+            for user in users {
+                if let Some(user_id) = user.user_id {
+                    self.roster.insert(
+                        user.initials.value().to_string(),
+                        (area_code.clone(), user_id),
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Maps every row of an export.
+    fn map_export(&mut self, csv_content: &str) -> Result<(), ApiError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(true)
+            .flexible(true)
+            .from_reader(csv_content.as_bytes());
+        let headers: StringRecord = reader
+            .headers()
+            .map_err(|e| ApiError::InvalidCsvFormat {
+                reason: format!("Failed to read CSV headers: {e}"),
+            })?
+            .clone();
+        let columns: HashMap<String, usize> = headers
+            .iter()
+            .enumerate()
+            .map(|(idx, header)| (normalize_header(header), idx))
+            .collect();
+        let missing: Vec<String> = REQUIRED_HEADERS
+            .iter()
+            .filter(|header| !columns.contains_key(**header))
+            .map(|header| display_header(header))
+            .collect();
+        if !missing.is_empty() {
+            return Err(ApiError::InvalidCsvFormat {
+                reason: format!("Missing required headers: {}", missing.join(", ")),
+            });
+        }
+
+        for (idx, record) in reader.records().enumerate() {
+            let row_number: usize = idx + 1;
+            self.total_rows += 1;
+            let record: StringRecord = match record {
+                Ok(record) => record,
+                Err(e) => {
+                    self.reject(row_number, None, format!("CSV parse error: {e}"));
+                    continue;
+                }
+            };
+            let field = |header: &str| -> Option<String> {
+                columns
+                    .get(header)
+                    .and_then(|&idx| record.get(idx))
+                    .map(|value| value.trim().to_string())
+                    .filter(|value| !value.is_empty())
+            };
+            let initials: Option<String> = field(INITIALS).map(|value| value.to_uppercase());
+            if let Err(reason) = self.map_row(row_number, initials.as_deref(), &field) {
+                self.reject(row_number, initials, reason);
+            }
+        }
+        Ok(())
+    }
+
+    fn reject(&mut self, row_number: usize, initials: Option<String>, reason: String) {
+        self.unmappable.push(UnmappableLegacyRow {
+            row_number,
+            initials,
+            reason,
+        });
+    }
+
+    /// Maps one row onto the bid year.
+    fn map_row(
+        &mut self,
+        row_number: usize,
+        initials: Option<&str>,
+        field: &impl Fn(&str) -> Option<String>,
+    ) -> Result<(), String> {
+        let initials: &str = initials.ok_or_else(|| String::from("Missing INIT"))?;
+        let area_code: String = field(AREA)
+            .ok_or_else(|| String::from("Missing AREA"))?
+            .to_uppercase();
+        let area: Area = self.areas.get(&area_code).cloned().ok_or_else(|| {
+            format!(
+                "Area '{area_code}' does not exist in bid year {}",
+                self.bid_year.year()
+            )
+        })?;
+
+        if let Some((roster_area, _)) = self.roster.get(initials) {
+            if *roster_area != area_code {
+                return Err(format!(
+                    "'{initials}' is registered in area {roster_area}, not {area_code}"
+                ));
+            }
+        } else if let Some(registration) = self.registrations.get_mut(initials) {
+            if registration.request.area != area_code {
+                return Err(format!(
+                    "'{initials}' is listed in area {} on row {}",
+                    registration.request.area, registration.row_number
+                ));
+            }
+            registration.row_numbers.push(row_number);
+        } else if let Some(row) = self.unregistrable.get(initials) {
+            return Err(format!("'{initials}' could not be registered (row {row})"));
+        } else {
+            match legacy_registration(initials, &area, field) {
+                Ok(request) => {
+                    self.registrations.insert(
+                        initials.to_string(),
+                        Registration {
+                            request,
+                            row_number,
+                            row_numbers: vec![row_number],
+                        },
+                    );
+                }
+                Err(reason) => {
+                    self.unregistrable.insert(initials.to_string(), row_number);
+                    return Err(reason);
+                }
+            }
+        }
+
+        // Rows without leave carry only roster details
+        let Some(date_text) = field(LEAVE_DATE) else {
+            return Ok(());
+        };
+        let leave_date: Date = parse_legacy_date(&date_text)
+            .ok_or_else(|| format!("Invalid LEAVE DATE '{date_text}'"))?;
+        let round_number: u32 = field(ROUND)
+            .and_then(|text| text.parse().ok())
+            .filter(|number| *number > 0)
+            .ok_or_else(|| String::from("Missing or invalid ROUND"))?;
+        let hours: u32 = field(HOURS)
+            .as_deref()
+            .and_then(parse_hours)
+            .ok_or_else(|| String::from("Missing or invalid HOURS"))?;
+        let round_group_id: i64 = area
+            .round_group_id()
+            .ok_or_else(|| format!("Area {area_code} has no round group to import rounds into"))?;
+
+        let key = (
+            initials.to_string(),
+            round_group_id,
+            round_number,
+            leave_date,
+        );
+        if let Some(row) = self.listed.get(&key) {
+            return Err(format!(
+                "Leave on {leave_date} in round {round_number} is already listed on row {row}"
+            ));
+        }
+        let recorded: bool = self
+            .roster
+            .get(initials)
+            .zip(self.rounds.get(&(round_group_id, round_number)))
+            .is_some_and(|((_, user_id), round_id)| {
+                self.recorded
+                    .contains(&(*user_id, *round_id, leave_date.to_string()))
+            });
+        if recorded {
+            return Err(format!(
+                "Leave on {leave_date} in round {round_number} is already recorded for '{initials}'"
+            ));
+        }
+        self.listed.insert(key, row_number);
+
+        self.bids.push(PlannedBid {
+            area,
+            initials: initials.to_string(),
+            round_group_id,
+            round_number,
+            round_name: field(ROUND_NAME).unwrap_or_else(|| format!("Round {round_number}")),
+            leave_date,
+            hours,
+        });
+        Ok(())
+    }
+
+    /// Drops a controller whose registration failed, with their leave.
+    fn drop_registration(&mut self, initials: &str, reason: &str) {
+        let Some(registration) = self.registrations.remove(initials) else {
+            return;
+        };
+        self.bids.retain(|bid| bid.initials != initials);
+        for row_number in registration.row_numbers {
+            self.unmappable.retain(|row| row.row_number != row_number);
+            self.reject(row_number, Some(initials.to_string()), reason.to_string());
+        }
+    }
+
+    /// Sizes the rounds the planned bids need but the bid year lacks.
+    fn new_rounds(&self) -> Vec<LegacyRoundInfo> {
+        let mut needed: BTreeMap<(i64, u32), Vec<&PlannedBid>> = BTreeMap::new();
+        for bid in &self.bids {
+            let key: (i64, u32) = (bid.round_group_id, bid.round_number);
+            if !self.rounds.contains_key(&key) {
+                needed.entry(key).or_default().push(bid);
+            }
+        }
+
+        needed
+            .into_iter()
+            .map(|((round_group_id, round_number), bids)| {
+                let mut per_day: HashMap<(&str, Date), u32> = HashMap::new();
+                let mut per_user: HashMap<&str, (u32, u32)> = HashMap::new();
+                for bid in &bids {
+                    *per_day
+                        .entry((bid.area.area_code(), bid.leave_date))
+                        .or_default() += 1;
+                    let (days, hours) = per_user.entry(bid.initials.as_str()).or_default();
+                    *days += 1;
+                    *hours += bid.hours;
+                }
+                LegacyRoundInfo {
+                    round_group_id,
+                    round_number,
+                    name: bids
+                        .first()
+                        .map(|bid| bid.round_name.clone())
+                        .unwrap_or_default(),
+                    slots_per_day: per_day.into_values().max().unwrap_or(1),
+                    max_groups: per_user.values().map(|(days, _)| *days).max().unwrap_or(1),
+                    max_total_hours: per_user
+                        .values()
+                        .map(|(_, hours)| *hours)
+                        .max()
+                        .unwrap_or(1),
+                }
+            })
+            .collect()
+    }
+
+    fn report(&self) -> LegacyImportReport {
+        let mut unmappable: Vec<UnmappableLegacyRow> = self.unmappable.clone();
+        unmappable.sort_by_key(|row| row.row_number);
+        LegacyImportReport {
+            bid_year_id: self.bid_year_id,
+            bid_year: self.bid_year.year(),
+            total_rows: self.total_rows,
+            mapped_rows: self.total_rows - unmappable.len(),
+            users_to_register: self.registrations.keys().cloned().collect(),
+            rounds_to_create: self.new_rounds(),
+            bids_to_import: self.bids.len(),
+            unmappable,
+        }
+    }
+}
+
+/// Maps an export onto its bid year without changing anything.
+fn plan(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &LegacyImportRequest,
+) -> Result<ImportPlan, ApiError> {
+    let mut plan: ImportPlan = ImportPlan::load(persistence, metadata, request.bid_year_id)?;
+    plan.map_export(&request.csv_content)?;
+    Ok(plan)
+}
+
+/// Reports what importing a legacy bid tracker export would do.
+///
+/// Nothing is changed. Every row that cannot be mapped onto the bid year
+/// is listed with the reason.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year and the export
+/// * `authenticated_actor` - The authenticated actor previewing the import
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist, or is locked after confirmation
+/// - The export has no `Init` or `Area` column, or cannot be read
+pub fn preview_legacy_import(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &LegacyImportRequest,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<LegacyImportReport, ApiError> {
+    require_admin(authenticated_actor, "preview legacy import")?;
+    Ok(plan(persistence, metadata, request)?.report())
+}
+
+/// Registers a controller through the core `RegisterUser` command,
+/// returning the registration's audit event ID.
+fn register(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year: &BidYear,
+    request: &RegisterUserRequest,
+    actor: &Actor,
+    cause: &Cause,
+) -> Result<i64, ApiError> {
+    let area: Area = Area::new(&request.area);
+    let state: State = persistence
+        .get_current_state(bid_year, &area)
+        .unwrap_or_else(|_| State::new(bid_year.clone(), area.clone()));
+    let result: TransitionResult = apply(
+        metadata,
+        &state,
+        bid_year,
+        build_register_user_command(request)?,
+        actor.clone(),
+        cause.clone(),
+    )
+    .map_err(translate_core_error)?;
+    persistence
+        .persist_transition(&result)
+        .map(|persisted| persisted.event_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to register user: {e}"),
+        })
+}
+
+/// Leave imported into one area.
+struct AreaImport<'a> {
+    area: Area,
+    bids: usize,
+    users: BTreeSet<&'a str>,
+    round_ids: BTreeSet<i64>,
+}
+
+/// Records the planned bids, returning what was imported per area.
+fn record_bids<'a>(
+    persistence: &mut SqlitePersistence,
+    plan: &'a ImportPlan,
+) -> Result<BTreeMap<String, AreaImport<'a>>, ApiError> {
+    let mut imported: BTreeMap<String, AreaImport<'a>> = BTreeMap::new();
+    for bid in &plan.bids {
+        let user_id: i64 = plan
+            .roster
+            .get(&bid.initials)
+            .map(|(_, user_id)| *user_id)
+            .ok_or_else(|| ApiError::Internal {
+                message: format!("User '{}' was not registered", bid.initials),
+            })?;
+        let round_id: i64 = plan.rounds[&(bid.round_group_id, bid.round_number)];
+        let area_id: i64 = bid.area.area_id().ok_or_else(|| ApiError::Internal {
+            message: format!("Area '{}' has no ID", bid.area.area_code()),
+        })?;
+        let hours: i32 = i32::try_from(bid.hours).map_err(|_| ApiError::InvalidInput {
+            field: String::from("hours"),
+            message: format!("Leave hours {} are out of range", bid.hours),
+        })?;
+        persistence
+            .insert_leave_bid(
+                plan.bid_year_id,
+                area_id,
+                user_id,
+                round_id,
+                &bid.leave_date.to_string(),
+                hours,
+                &bid.initials,
+                LeaveBidData::RECEIVED_VIA_LEGACY_IMPORT,
+            )
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to record leave bid: {e}"),
+            })?;
+
+        let area: &mut AreaImport<'a> = imported
+            .entry(bid.area.area_code().to_string())
+            .or_insert_with(|| AreaImport {
+                area: bid.area.clone(),
+                bids: 0,
+                users: BTreeSet::new(),
+                round_ids: BTreeSet::new(),
+            });
+        area.bids += 1;
+        area.users.insert(bid.initials.as_str());
+        area.round_ids.insert(round_id);
+    }
+    Ok(imported)
+}
+
+/// Imports a legacy bid tracker export into a bid year.
+///
+/// Missing controllers are registered first, each through the core
+/// `RegisterUser` command. Missing rounds are then created, and every
+/// mappable leave day is recorded as an approved leave bid received via
+/// `legacy_import`. One audit event per area records the imported bids.
+///
+/// Unmappable rows are skipped and reported. A controller whose
+/// registration is rejected is skipped with all of their rows.
+///
+/// An automatic checkpoint labeled `auto-before-legacy-import` is recorded
+/// in every area of the bid year before anything is imported.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year and the export
+/// * `authenticated_actor` - The authenticated actor performing the import
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist, or is locked after confirmation
+/// - The export has no `Init` or `Area` column, or cannot be read
+/// - An automatic checkpoint cannot be recorded
+/// - A round, leave bid, or audit event cannot be recorded
+pub fn import_legacy_bids(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &LegacyImportRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: &Cause,
+) -> Result<LegacyImportResponse, ApiError> {
+    require_admin(authenticated_actor, "import legacy bids")?;
+    let mut plan: ImportPlan = plan(persistence, metadata, request)?;
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let checkpoints: AutoCheckpoints = record_bid_year_auto_checkpoints(
+        persistence,
+        metadata,
+        plan.bid_year.year(),
+        "legacy-import",
+        &actor,
+        cause,
+    )?;
+    let mut audit_event_ids: Vec<i64> = Vec::new();
+
+    let pending: Vec<(String, RegisterUserRequest)> = plan
+        .registrations
+        .iter()
+        .map(|(initials, registration)| (initials.clone(), registration.request.clone()))
+        .collect();
+    let mut registration_event_ids: Vec<i64> = Vec::new();
+    for (initials, registration) in pending {
+        match register(
+            persistence,
+            metadata,
+            &plan.bid_year,
+            &registration,
+            &actor,
+            cause,
+        ) {
+            Ok(event_id) => registration_event_ids.push(event_id),
+            Err(e) => {
+                plan.drop_registration(&initials, &format!("Cannot register '{initials}': {e}"));
+            }
+        }
+    }
+    plan.load_roster(persistence)?;
+
+    let report: LegacyImportReport = plan.report();
+    for round in &report.rounds_to_create {
+        let round_id: i64 = persistence
+            .insert_round(
+                round.round_group_id,
+                round.round_number,
+                &round.name,
+                round.slots_per_day,
+                round.max_groups,
+                round.max_total_hours,
+                false,
+                false,
+            )
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to create round: {e}"),
+            })?;
+        plan.rounds
+            .insert((round.round_group_id, round.round_number), round_id);
+    }
+
+    for imported in record_bids(persistence, &plan)?.into_values() {
+        let area_code: String = imported.area.area_code().to_string();
+        let round_ids: String = imported
+            .round_ids
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join("|");
+        let event: AuditEvent = AuditEvent::new(
+            actor.clone(),
+            cause.clone(),
+            Action::new(
+                String::from("LegacyBidsImported"),
+                Some(format!(
+                    "Imported {} leave day(s) for {} controller(s) from the legacy bid tracker",
+                    imported.bids,
+                    imported.users.len()
+                )),
+            ),
+            StateSnapshot::new(format!("area={area_code},legacy_bids=0")),
+            StateSnapshot::new(format!(
+                "area={area_code},legacy_bids={},round_ids={round_ids}",
+                imported.bids
+            )),
+            plan.bid_year.clone(),
+            imported.area,
+        );
+        audit_event_ids.push(persistence.persist_audit_event(&event).map_err(|e| {
+            ApiError::Internal {
+                message: format!("Failed to persist audit event: {e}"),
+            }
+        })?);
+    }
+
+    let correlated: Vec<i64> = registration_event_ids
+        .iter()
+        .chain(&audit_event_ids)
+        .copied()
+        .collect();
+    record_operation_events(persistence, &correlated, &checkpoints.correlation_id)?;
+
+    Ok(LegacyImportResponse {
+        report,
+        audit_event_ids,
+        correlation_id: checkpoints.correlation_id,
+    })
+}
@@ -19,17 +19,81 @@
 #![allow(deprecated)]
 #![allow(clippy::multiple_crate_versions)]
 
+mod amendment_policies;
+mod anonymization;
+mod area_delegations;
+mod audit_annotations;
+mod audit_compaction;
 mod auth;
+mod auto_checkpoints;
+mod bid_rules;
+mod bidding_progress;
 mod capabilities;
+mod chat;
 mod csv_preview;
+mod current_bidder;
+mod dashboards;
+mod eligibility;
 mod error;
+mod facility;
+mod feature_flags;
+pub mod free_text;
 mod handlers;
+mod leave_bids;
+mod leave_caps;
+mod legacy_import;
+mod notifications;
+mod operator_profile;
+mod overbids;
+mod override_import;
 mod password_policy;
+mod pdf;
+mod portal;
+mod prime_dates;
+mod reports;
 mod request_response;
+mod rollback;
+mod roster_sync;
+mod round_bid_order;
+mod round_eligibility;
+mod round_sign_offs;
+mod saved_audit_queries;
+mod scope_freezes;
+mod self_bids;
+mod slot_inventory;
+mod user_initials;
+mod user_merges;
+pub mod v1;
+mod versioning;
+mod waitlist;
+mod webhooks;
+mod xlsx;
 
 #[cfg(test)]
 mod tests;
 
+// Re-export public functions from amendment_policies module
+pub use amendment_policies::{get_bid_amendment_policy, set_bid_amendment_policy};
+
+// Re-export public functions from anonymization module
+pub use anonymization::{DEFAULT_RETENTION_DAYS, anonymize_user};
+
+// Re-export public functions from area_delegations module
+pub use area_delegations::{
+    MAX_DELEGATION_DAYS, create_area_delegation, list_area_delegations, revoke_area_delegation,
+};
+
+// Re-export public functions from audit_annotations module
+pub use audit_annotations::annotate_audit_event;
+
+// Re-export public functions from audit_compaction module
+pub use audit_compaction::{compact_audit_log, get_audit_compaction};
+
+// Re-export public functions from auto_checkpoints module
+pub use auto_checkpoints::{
+    AUTO_CHECKPOINT_LABEL_PREFIX, AutoCheckpoints, auto_checkpoint_label, record_operation_events,
+};
+
 // Re-export public types and functions from auth module
 pub use auth::{
     AuthenticatedActor, AuthenticationService, AuthorizationService, Role, authenticate_stub,
@@ -38,48 +102,258 @@ pub use auth::{
 // Re-export public types from error module
 pub use error::{ApiError, AuthError, translate_core_error, translate_domain_error};
 
+// Re-export public types from versioning module
+pub use versioning::{ApiVersion, FromCurrent, IntoCurrent};
+
 // Re-export public types from password_policy module
 pub use password_policy::{PasswordPolicy, PasswordPolicyError};
 
 // Re-export public types from request_response module
 pub use request_response::{
-    AdjustBidOrderRequest, AdjustBidOrderResponse, AdjustBidWindowRequest, AdjustBidWindowResponse,
-    AreaCompletenessInfo, AreaInfo, AreaStatusInfo, BidOrderAdjustment, BidScheduleInfo,
-    BidStatusHistoryInfo, BidStatusInfo, BidYearCompletenessInfo, BidYearInfo, BidYearStatusInfo,
+    AcceptWaitlistOfferRequest, AdjustBidOrderRequest, AdjustBidOrderResponse,
+    AdjustBidWindowRequest, AdjustBidWindowResponse, AdjustSlotInventoryRequest,
+    AdjustSlotInventoryResponse, AdminActivitySummary, AdvanceBidderRequest, AdvanceBidderResponse,
+    AnnotateAuditEventRequest, AnnotateAuditEventResponse, AnonymizeUserRequest,
+    AnonymizeUserResponse, AppliedRosterSyncOperation, ApplyRosterSyncRequest,
+    ApplyRosterSyncResponse, ApplyRoundGroupTemplateRequest, ApplyRoundGroupTemplateResponse,
+    ApproveOverbidRequest, AreaBidScheduleInfo, AreaBootstrapStatusInfo, AreaCompletenessInfo,
+    AreaDelegationInfo, AreaInfo, AreaProgressInfo, AreaStatusInfo, AuditActorInfo,
+    AuditAnnotationInfo, AuditArchiveVerificationInfo, AuditCompactionInfo, AuditFieldChange,
+    AuditTimelineEntryInfo, AuditTimelineFilter, AuditTimelinePageRequest, AuditTimelineScope,
+    BidEventInfo, BidOrderAdjustment, BidPreferenceEntry, BidPreferenceInfo, BidRuleInfo,
+    BidScheduleInfo, BidStatusHistoryInfo, BidStatusInfo, BidYearCompletenessInfo, BidYearInfo,
+    BidYearStatusInfo, BiddingProgressRoundInfo, BlackoutDateInfo, BlackoutDateResponse,
     BlockingReason, BootstrapAuthStatusResponse, BootstrapLoginRequest, BootstrapLoginResponse,
-    BootstrapStatusResponse, BulkUpdateBidStatusRequest, BulkUpdateBidStatusResponse, Capability,
-    ChangePasswordRequest, ChangePasswordResponse, ConfirmReadyToBidRequest,
-    ConfirmReadyToBidResponse, CreateAreaRequest, CreateAreaResponse, CreateBidYearRequest,
-    CreateBidYearResponse, CreateFirstAdminRequest, CreateFirstAdminResponse,
-    CreateOperatorRequest, CreateOperatorResponse, CreateRoundGroupRequest,
-    CreateRoundGroupResponse, CreateRoundRequest, CreateRoundResponse, CsvImportRowResult,
-    CsvImportRowStatus, CsvRowPreview, CsvRowStatus, DeleteOperatorRequest, DeleteOperatorResponse,
-    DeleteRoundGroupResponse, DeleteRoundResponse, DisableOperatorRequest, DisableOperatorResponse,
-    EnableOperatorRequest, EnableOperatorResponse, GetActiveBidYearResponse,
-    GetBidOrderPreviewResponse, GetBidScheduleResponse, GetBidStatusForAreaRequest,
-    GetBidStatusForAreaResponse, GetBidStatusRequest, GetBidStatusResponse,
-    GetBidYearReadinessResponse, GetBootstrapCompletenessResponse, GetLeaveAvailabilityRequest,
-    GetLeaveAvailabilityResponse, GlobalCapabilities, ImportCsvUsersRequest,
-    ImportCsvUsersResponse, ListAreasRequest, ListAreasResponse, ListBidYearsResponse,
-    ListOperatorsResponse, ListRoundGroupsResponse, ListRoundsResponse, ListUsersRequest,
-    ListUsersResponse, LoginRequest, LoginResponse, OperatorCapabilities, OperatorInfo,
-    OverrideAreaAssignmentRequest, OverrideAreaAssignmentResponse, OverrideBidOrderRequest,
-    OverrideBidOrderResponse, OverrideBidWindowRequest, OverrideBidWindowResponse,
-    OverrideEligibilityRequest, OverrideEligibilityResponse, PreviewCsvUsersRequest,
-    PreviewCsvUsersResponse, RecalculateBidWindowsRequest, RecalculateBidWindowsResponse,
-    RegisterUserRequest, RegisterUserResponse, ResetPasswordRequest, ResetPasswordResponse,
-    ReviewNoBidUserResponse, RoundGroupInfo, RoundInfo, SetActiveBidYearRequest,
-    SetActiveBidYearResponse, SetBidScheduleRequest, SetBidScheduleResponse,
-    SetExpectedAreaCountRequest, SetExpectedAreaCountResponse, SetExpectedUserCountRequest,
-    SetExpectedUserCountResponse, TransitionBidStatusRequest, TransitionBidStatusResponse,
-    TransitionToBiddingActiveRequest, TransitionToBiddingActiveResponse,
-    TransitionToBiddingClosedRequest, TransitionToBiddingClosedResponse,
-    TransitionToBootstrapCompleteRequest, TransitionToBootstrapCompleteResponse,
-    TransitionToCanonicalizedRequest, TransitionToCanonicalizedResponse, UpdateAreaRequest,
+    BootstrapStatusResponse, BulkRegisterRowResult, BulkRegisterRowStatus,
+    BulkUpdateBidStatusRequest, BulkUpdateBidStatusResponse, Capability, ChangeInitialsRequest,
+    ChangeInitialsResponse, ChangeOperatorRoleRequest, ChangeOperatorRoleResponse,
+    ChangeOwnPasswordResponse, ChangePasswordRequest, ChangePasswordResponse, ChatChannelInfo,
+    ChatNotificationInfo, CheckpointInfo, CheckpointRequest, ClearAreaBidScheduleResponse,
+    CompactAuditLogRequest, CompactAuditLogResponse, ConfirmReadyToBidRequest,
+    ConfirmReadyToBidResponse, ConfirmSelfBidEntryRequest, ConfirmSelfBidEntryResponse,
+    CopyRoundConfigRequest, CopyRoundConfigResponse, CreateAreaDelegationRequest,
+    CreateAreaDelegationResponse, CreateAreaRequest, CreateAreaResponse, CreateBidYearRequest,
+    CreateBidYearResponse, CreateBlackoutDateRequest, CreateChatChannelRequest,
+    CreateChatChannelResponse, CreateFirstAdminRequest, CreateFirstAdminResponse,
+    CreateOperatorRequest, CreateOperatorResponse, CreatePrimePeriodRequest,
+    CreateRoundGroupRequest, CreateRoundGroupResponse, CreateRoundGroupTemplateRequest,
+    CreateRoundGroupTemplateResponse, CreateRoundRequest, CreateRoundResponse,
+    CreateSavedAuditQueryRequest, CreateWebhookRequest, CreateWebhookResponse, CrewSlotsInfo,
+    CsvImportRowResult, CsvImportRowStatus, CsvRowPreview, CsvRowStatus, CurrentBidderInfo,
+    DashboardDayInfo, DeclineWaitlistOfferRequest, DeleteBlackoutDateResponse,
+    DeleteChatChannelRequest, DeleteChatChannelResponse, DeleteOperatorRequest,
+    DeleteOperatorResponse, DeletePrimePeriodResponse, DeleteRoundGroupResponse,
+    DeleteRoundGroupTemplateResponse, DeleteRoundResponse, DeleteSavedAuditQueryRequest,
+    DeleteSavedAuditQueryResponse, DeleteWebhookRequest, DeleteWebhookResponse, DenyOverbidRequest,
+    DependentEventInfo, DirectoryMember, DisableOperatorRequest, DisableOperatorResponse,
+    EligibilityExceptionInfo, EnableOperatorRequest, EnableOperatorResponse, EnterLeaveBidRequest,
+    EnterLeaveBidResponse, FacilityInfo, FeatureFlagInfo, FreezeScopeRequest, FreezeScopeResponse,
+    GetActiveBidYearResponse, GetAdminActivityReportRequest, GetAreaDashboardRequest,
+    GetAreaDashboardResponse, GetAuditCompactionResponse, GetAuditTimelineResponse,
+    GetBidAmendmentPolicyResponse, GetBidOrderPreviewResponse, GetBidPacketsRequest,
+    GetBidScheduleResponse, GetBidStatusForAreaRequest, GetBidStatusForAreaResponse,
+    GetBidStatusRequest, GetBidStatusResponse, GetBidYearBootstrapStatusResponse,
+    GetBidYearDashboardResponse, GetBidYearReadinessResponse, GetBiddingProgressRequest,
+    GetBiddingProgressResponse, GetBootstrapCompletenessResponse, GetCoverageReportRequest,
+    GetCurrentBidderResponse, GetFacilityResponse, GetFeatureFlagsResponse,
+    GetInitialsHistoryResponse, GetLeaveAvailabilityRequest, GetLeaveAvailabilityResponse,
+    GetLeaveCapResponse, GetPortalBalanceResponse, GetPortalBidsResponse,
+    GetPortalSeniorityResponse, GetPortalWindowsResponse, GetRoundBidOrderResponse,
+    GetRoundResultsReportRequest, GetSeniorityReportRequest, GetSlotInventoryRequest,
+    GetSlotInventoryResponse, GetUseOrLoseReportRequest, GetUserContactResponse,
+    GlobalCapabilities, ImportCsvUsersRequest, ImportCsvUsersResponse, InitialsAliasInfo,
+    InvalidOverrideRow, IssuePortalLinkRequest, IssuePortalLinkResponse, LeaveProjectionInfo,
+    LegacyImportReport, LegacyImportRequest, LegacyImportResponse, LegacyRoundInfo,
+    ListAreaDelegationsResponse, ListAreasRequest, ListAreasResponse, ListBidPreferencesResponse,
+    ListBidRulesResponse, ListBidYearsResponse, ListBlackoutDatesResponse,
+    ListChatChannelsResponse, ListChatNotificationsResponse, ListCheckpointsResponse,
+    ListEligibilityExceptionsResponse, ListLeaveProjectionsResponse, ListOperatorsResponse,
+    ListOverbidRequestsResponse, ListOverridesResponse, ListPrimeDatesResponse,
+    ListRoundCrewSlotsResponse, ListRoundGroupTemplatesResponse, ListRoundGroupsResponse,
+    ListRoundSignOffsResponse, ListRoundsResponse, ListSavedAuditQueriesResponse,
+    ListScopeFreezesResponse, ListSelfBidRequestsResponse, ListUnreviewedNoBidUsersResponse,
+    ListUserEligibilityResponse, ListUserMergesResponse, ListUserNotificationsResponse,
+    ListUsersRequest, ListUsersResponse, ListWaitlistResponse, ListWebhookDeadLettersResponse,
+    ListWebhooksResponse, LoginRequest, LoginResponse, MergeUsersRequest, MergeUsersResponse,
+    NotificationInfo, OperatorActivityInfo, OperatorAreaScopeInfo, OperatorCapabilities,
+    OperatorInfo, OverbidDecisionResponse, OverbidRequestInfo, OverrideAreaAssignmentRequest,
+    OverrideAreaAssignmentResponse, OverrideBidOrderRequest, OverrideBidOrderResponse,
+    OverrideBidWindowRequest, OverrideBidWindowResponse, OverrideEligibilityRequest,
+    OverrideEligibilityResponse, OverrideImportChange, OverrideImportReport, OverrideImportRequest,
+    OverrideImportResponse, OverrideInfo, PortalLeaveBidInfo, PortalWindowInfo,
+    PreviewCsvUsersRequest, PreviewCsvUsersResponse, PrimePeriodInfo, PrimePeriodResponse,
+    RecalculateBidWindowsRequest, RecalculateBidWindowsResponse, RegisterUserRequest,
+    RegisterUserResponse, RegisterUsersBulkRequest, RegisterUsersBulkResponse,
+    ReopenBidYearRequest, ReopenBidYearResponse, ReorderRoundsRequest, ReorderRoundsResponse,
+    RequestOverbidRequest, RequestOverbidResponse, RequestSelfBidEntryRequest,
+    RequestSelfBidEntryResponse, ResetPasswordRequest, ResetPasswordResponse,
+    RevertOverrideResponse, RevertUserMergeResponse, ReviewNoBidUserRequest,
+    ReviewNoBidUserResponse, ReviewNoBidUsersRequest, ReviewNoBidUsersResponse,
+    RevokeAreaDelegationRequest, RevokeAreaDelegationResponse, RevokeOperatorSessionsRequest,
+    RevokeOperatorSessionsResponse, RevokePortalLinkRequest, RevokePortalLinkResponse,
+    RollbackLeaveBidInfo, RollbackPreviewResponse, RollbackUserChange, RosterSyncAction,
+    RosterSyncOperation, RosterSyncPlanResponse, RoundBidOrderEntryInfo, RoundCrewSlotsInfo,
+    RoundEligibilityInfo, RoundGroupInfo, RoundGroupTemplateInfo, RoundInfo, RoundPrimeCapInfo,
+    RoundProgressInfo, RoundSignOffInfo, RoundTemplateSpec, SavedAuditQueryInfo,
+    SavedAuditQueryResponse, ScheduleSavedAuditQueryRequest, ScopeFreezeInfo, SelfBidRequestInfo,
+    SetActiveBidYearRequest, SetActiveBidYearResponse, SetAreaBidScheduleRequest,
+    SetAreaBidScheduleResponse, SetBidAmendmentPolicyRequest, SetBidAmendmentPolicyResponse,
+    SetBidRulesRequest, SetBidRulesResponse, SetBidScheduleRequest, SetBidScheduleResponse,
+    SetEligibilityExceptionsRequest, SetEligibilityExceptionsResponse, SetExpectedAreaCountRequest,
+    SetExpectedAreaCountResponse, SetExpectedUserCountRequest, SetExpectedUserCountResponse,
+    SetFacilityRequest, SetFacilityResponse, SetFeatureFlagRequest, SetFeatureFlagResponse,
+    SetLeaveCapRequest, SetLeaveCapResponse, SetLeaveCarryoverRequest, SetLeaveCarryoverResponse,
+    SetOperatorAreaScopesRequest, SetOperatorAreaScopesResponse, SetOperatorControllerRequest,
+    SetOperatorControllerResponse, SetRoundCrewSlotsRequest, SetRoundCrewSlotsResponse,
+    SetRoundEligibilityRequest, SetRoundEligibilityResponse, SetRoundGroupRotationRequest,
+    SetRoundGroupRotationResponse, SetRoundPrimeCapRequest, SetRoundPrimeCapResponse,
+    SetUserContactRequest, SetUserContactResponse, SignOffRoundRequest, SignOffRoundResponse,
+    SkippedRosterSyncOperation, SlotInventoryDayInfo, SubmitBidPreferencesRequest,
+    SubmitBidPreferencesResponse, SupersededEventInfo, TransitionBidStatusRequest,
+    TransitionBidStatusResponse, TransitionToBiddingActiveRequest,
+    TransitionToBiddingActiveResponse, TransitionToBiddingClosedRequest,
+    TransitionToBiddingClosedResponse, TransitionToBootstrapCompleteRequest,
+    TransitionToBootstrapCompleteResponse, TransitionToCanonicalizedRequest,
+    TransitionToCanonicalizedResponse, UnfreezeScopeRequest, UnfreezeScopeResponse,
+    UnmappableLegacyRow, UnreviewedNoBidUserInfo, UpcomingBidderInfo, UpdateAreaRequest,
     UpdateAreaResponse, UpdateBidYearMetadataRequest, UpdateBidYearMetadataResponse,
-    UpdateRoundGroupRequest, UpdateRoundGroupResponse, UpdateRoundRequest, UpdateRoundResponse,
+    UpdateBlackoutDateRequest, UpdateChatChannelRequest, UpdateChatChannelResponse,
+    UpdateOwnProfileRequest, UpdateOwnProfileResponse, UpdateRoundGroupRequest,
+    UpdateRoundGroupResponse, UpdateRoundRequest, UpdateRoundResponse,
     UpdateUserParticipationRequest, UpdateUserParticipationResponse, UpdateUserRequest,
-    UpdateUserResponse, UserCapabilities, UserInfo, WhoAmIResponse,
+    UpdateUserResponse, UpdateWebhookRequest, UpdateWebhookResponse, UserAwardInfo,
+    UserCapabilities, UserContactInfo, UserEligibilityInfo, UserInfo, UserMergeInfo,
+    WaitlistOfferInfo, WaitlistOfferResponse, WaitlistSlotInfo, WebhookDeadLetterInfo, WebhookInfo,
+    WhoAmIResponse, WithdrawLeaveBidRequest, WithdrawLeaveBidResponse,
+};
+
+// Re-export public functions from bid_rules module
+pub use bid_rules::{list_bid_rules, set_bid_rules};
+
+// Re-export public functions from bidding_progress module
+pub use bidding_progress::{
+    BID_EVENT_ACTIONS, DEFAULT_UPCOMING_BIDDERS, MAX_UPCOMING_BIDDERS, get_bidding_progress,
+};
+
+// Re-export public functions from chat module
+pub use chat::{
+    create_chat_channel, delete_chat_channel, list_chat_channels, list_chat_notifications,
+    update_chat_channel,
+};
+
+// Re-export public functions from current_bidder module
+pub use current_bidder::{
+    ElapsedWindowAdvance, MissedWindowPolicy, advance_bidder, advance_elapsed_bidder,
+    get_current_bidder,
+};
+
+// Re-export public functions from dashboards module
+pub use dashboards::{
+    DASHBOARD_PROJECTION, ProjectionRefresh, get_area_dashboard, get_bid_year_dashboard,
+    refresh_dashboard_projections,
+};
+
+// Re-export public functions from eligibility module
+pub use eligibility::{
+    list_eligibility_exceptions, list_user_eligibility, set_eligibility_exceptions,
+};
+
+// Re-export public functions from facility module
+pub use facility::{get_facility, load_accrual_rates, set_facility};
+
+// Re-export public functions from feature_flags module
+pub use feature_flags::{get_feature_flags, is_feature_enabled, set_feature_flag};
+
+// Re-export public functions from leave_bids module
+pub use leave_bids::{enter_leave_bid, list_bid_preferences, submit_bid_preferences};
+
+// Re-export public functions from leave_caps module
+pub use leave_caps::{get_leave_cap, list_leave_projections, set_leave_cap, set_leave_carryover};
+
+// Re-export public functions from legacy_import module
+pub use legacy_import::{import_legacy_bids, preview_legacy_import};
+
+// Re-export public functions from notifications module
+pub use notifications::{get_user_contact, list_user_notifications, set_user_contact};
+
+// Re-export public functions from operator_profile module
+pub use operator_profile::{change_own_password, update_own_profile};
+
+// Re-export public functions from overbids module
+pub use overbids::{approve_overbid, deny_overbid, list_overbid_requests, request_overbid};
+
+// Re-export public functions from override_import module
+pub use override_import::{apply_override_import, preview_override_import};
+
+// Re-export public functions from portal module
+pub use portal::{
+    DEFAULT_PORTAL_LINK_DAYS, MAX_PORTAL_LINK_DAYS, PortalIdentity, authenticate_portal,
+    get_portal_balance, get_portal_bids, get_portal_seniority, get_portal_windows,
+    issue_portal_link, revoke_portal_link,
+};
+
+// Re-export public functions from prime_dates module
+pub use prime_dates::{
+    create_prime_period, delete_prime_period, list_prime_dates, set_round_prime_cap,
+};
+
+// Re-export public functions from reports module
+pub use reports::{
+    RenderedReport, ReportFormat, get_admin_activity_report, get_bid_packets, get_coverage_report,
+    get_round_results_report, get_seniority_report, get_use_or_lose_report,
+    summarize_admin_activity,
+};
+
+// Re-export public functions from rollback module
+pub use rollback::{RollbackResult, RollbackTarget, list_checkpoints, preview_rollback, rollback};
+
+// Re-export public functions from roster_sync module
+pub use roster_sync::{apply_roster_sync, plan_roster_sync};
+
+// Re-export public functions from round_bid_order module
+pub use round_bid_order::{get_round_bid_order, set_round_group_rotation};
+
+// Re-export public functions from round_eligibility module
+pub use round_eligibility::set_round_eligibility;
+
+// Re-export public functions from round_sign_offs module
+pub use round_sign_offs::{list_round_sign_offs, sign_off_round};
+
+// Re-export public functions from saved_audit_queries module
+pub use saved_audit_queries::{
+    MAX_EXPORT_EVENTS, SavedAuditQueryExport, create_saved_audit_query, delete_saved_audit_query,
+    export_saved_audit_query, list_saved_audit_queries, render_saved_audit_query,
+    schedule_saved_audit_query,
+};
+
+// Re-export public functions from scope_freezes module
+pub use scope_freezes::{freeze_scope, list_scope_freezes, unfreeze_scope};
+
+// Re-export public functions from self_bids module
+pub use self_bids::{
+    confirm_self_bid_entry, list_self_bid_requests, request_self_bid_entry, set_operator_controller,
+};
+
+// Re-export public functions from slot_inventory module
+pub use slot_inventory::{
+    adjust_slot_inventory, get_slot_inventory, list_round_crew_slots, set_round_crew_slots,
+};
+
+// Re-export public functions from user_initials module
+pub use user_initials::{change_initials, get_initials_history};
+
+// Re-export public functions from user_merges module
+pub use user_merges::{list_user_merges, merge_users, revert_user_merge};
+
+// Re-export public functions from waitlist module
+pub use waitlist::{
+    DEFAULT_WAITLIST_OFFER_HOURS, accept_waitlist_offer, decline_waitlist_offer,
+    expire_waitlist_offers, list_waitlist, withdraw_leave_bid,
+};
+
+// Re-export public functions from webhooks module
+pub use webhooks::{
+    create_webhook, delete_webhook, list_webhook_dead_letters, list_webhooks, update_webhook,
 };
 
 // Re-export public functions from capabilities module
@@ -89,20 +363,26 @@ pub use capabilities::{
 
 // Re-export public functions from handlers module
 pub use handlers::{
-    ApiResult, RegisterUserResult, adjust_bid_order, adjust_bid_window, bootstrap_login,
-    bulk_update_bid_status, change_password, check_bootstrap_status, checkpoint,
-    confirm_ready_to_bid, create_area, create_bid_year, create_first_admin, create_operator,
-    create_round, create_round_group, delete_operator, delete_round, delete_round_group,
-    disable_operator, enable_operator, finalize, get_active_bid_year, get_bid_order_preview,
-    get_bid_schedule, get_bid_status, get_bid_status_for_area, get_bid_year_readiness,
+    ApiResult, RegisterUserResult, adjust_bid_order, adjust_bid_window, apply_round_group_template,
+    bootstrap_login, bulk_update_bid_status, change_operator_role, change_password,
+    check_bootstrap_status, checkpoint, clear_area_bid_schedule, confirm_ready_to_bid,
+    copy_round_config, create_area, create_bid_year, create_blackout_date, create_first_admin,
+    create_operator, create_round, create_round_group, create_round_group_template,
+    delete_blackout_date, delete_operator, delete_round, delete_round_group,
+    delete_round_group_template, disable_operator, enable_operator, finalize, get_active_bid_year,
+    get_audit_timeline, get_bid_order_preview, get_bid_schedule, get_bid_status,
+    get_bid_status_for_area, get_bid_year_bootstrap_status, get_bid_year_readiness,
     get_bootstrap_completeness, get_bootstrap_status, get_current_state, get_historical_state,
-    get_leave_availability, import_csv_users, list_areas, list_bid_years, list_operators,
-    list_round_groups, list_rounds, list_users, login, logout, override_area_assignment,
+    get_leave_availability, import_csv_users, list_areas, list_bid_years, list_blackout_dates,
+    list_operators, list_overrides, list_round_group_templates, list_round_groups, list_rounds,
+    list_unreviewed_no_bid_users, list_users, login, logout, override_area_assignment,
     override_bid_order, override_bid_window, override_eligibility, preview_csv_users,
-    recalculate_bid_windows, register_user, reset_password, review_no_bid_user, rollback,
-    set_active_bid_year, set_bid_schedule, set_expected_area_count, set_expected_user_count,
+    recalculate_bid_windows, register_user, register_users_bulk, reopen_bid_year, reorder_rounds,
+    reset_password, revert_override, review_no_bid_user, review_no_bid_users,
+    revoke_operator_sessions, set_active_bid_year, set_area_bid_schedule, set_bid_schedule,
+    set_expected_area_count, set_expected_user_count, set_operator_area_scopes,
     transition_bid_status, transition_to_bidding_active, transition_to_bidding_closed,
     transition_to_bootstrap_complete, transition_to_canonicalized, update_area,
-    update_bid_year_metadata, update_round, update_round_group, update_user,
+    update_bid_year_metadata, update_blackout_date, update_round, update_round_group, update_user,
     update_user_participation, whoami,
 };
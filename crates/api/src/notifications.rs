@@ -0,0 +1,241 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Notification contact and log handlers.
+//!
+//! Users are emailed when their bid window opens or is about to close, and
+//! when a bid is entered or amended on their behalf. These handlers manage
+//! the per-user contact details the notifier reads and expose the log of
+//! notifications sent. Only Admin actors may use them; contact changes are
+//! recorded as global audit events.
+
+use zab_bid_audit::{Action, AuditEvent, Cause, StateSnapshot};
+use zab_bid_persistence::{NotificationLogData, OperatorData, SqlitePersistence, UserContactData};
+
+use crate::auth::AuthenticatedActor;
+use crate::error::ApiError;
+use crate::request_response::{
+    GetUserContactResponse, ListUserNotificationsResponse, NotificationInfo, SetUserContactRequest,
+    SetUserContactResponse, UserContactInfo,
+};
+use crate::webhooks::{operator_actor, require_admin};
+
+/// Maximum length of an email address (RFC 5321 path limit).
+const MAX_EMAIL_LENGTH: usize = 320;
+
+/// Default number of notifications returned.
+const DEFAULT_NOTIFICATION_LIMIT: u32 = 100;
+
+/// Maximum number of notifications returned.
+const MAX_NOTIFICATION_LIMIT: u32 = 1000;
+
+/// Performs a structural check of an email address.
+///
+/// Deliverability is only known once the mail server accepts the message.
+pub fn validate_email(email: &str) -> Result<(), ApiError> {
+    let valid: bool = email.len() <= MAX_EMAIL_LENGTH
+        && !email.chars().any(char::is_whitespace)
+        && email.split_once('@').is_some_and(|(local, domain)| {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+        });
+    if !valid {
+        return Err(ApiError::InvalidInput {
+            field: String::from("email"),
+            message: format!("Invalid email address: '{email}'"),
+        });
+    }
+    Ok(())
+}
+
+/// Formats contact details for audit snapshots.
+fn contact_snapshot(contact: Option<&UserContactData>) -> String {
+    contact.map_or_else(
+        || String::from("contact_does_not_exist"),
+        |c| format!("email={},email_enabled={}", c.email, c.email_enabled),
+    )
+}
+
+/// Loads a user's initials or returns `ResourceNotFound`.
+fn load_user_initials(
+    persistence: &mut SqlitePersistence,
+    user_id: i64,
+) -> Result<String, ApiError> {
+    persistence
+        .get_user_details(user_id)
+        .map(|(_, initials)| initials)
+        .map_err(|_| ApiError::ResourceNotFound {
+            resource_type: String::from("User"),
+            message: format!("User with ID {user_id} not found"),
+        })
+}
+
+/// Loads a user's contact details.
+fn load_contact(
+    persistence: &mut SqlitePersistence,
+    user_id: i64,
+) -> Result<Option<UserContactData>, ApiError> {
+    persistence
+        .get_user_contact(user_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get user contact: {e}"),
+        })
+}
+
+impl From<UserContactData> for UserContactInfo {
+    fn from(contact: UserContactData) -> Self {
+        Self {
+            user_id: contact.user_id,
+            email: contact.email,
+            email_enabled: contact.email_enabled,
+            updated_at: contact.updated_at,
+        }
+    }
+}
+
+impl From<NotificationLogData> for NotificationInfo {
+    fn from(entry: NotificationLogData) -> Self {
+        Self {
+            notification_id: entry.notification_id,
+            user_id: entry.user_id,
+            kind: entry.kind,
+            reference_id: entry.reference_id,
+            recipient: entry.recipient,
+            subject: entry.subject,
+            delivered: entry.delivered,
+            error: entry.error,
+            sent_at: entry.sent_at,
+        }
+    }
+}
+
+/// Sets a user's notification contact details.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The contact details
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The email address is invalid
+/// - The user does not exist
+/// - The database operation fails
+pub fn set_user_contact(
+    persistence: &mut SqlitePersistence,
+    request: &SetUserContactRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetUserContactResponse, ApiError> {
+    require_admin(authenticated_actor, "set_user_contact")?;
+    let email: &str = request.email.trim();
+    validate_email(email)?;
+
+    let initials: String = load_user_initials(persistence, request.user_id)?;
+    let previous: Option<UserContactData> = load_contact(persistence, request.user_id)?;
+
+    persistence
+        .set_user_contact(request.user_id, email, request.email_enabled)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to set user contact: {e}"),
+        })?;
+
+    let contact: UserContactData =
+        load_contact(persistence, request.user_id)?.ok_or_else(|| ApiError::Internal {
+            message: String::from("User contact missing after update"),
+        })?;
+
+    let audit_event: AuditEvent = AuditEvent::new_global(
+        operator_actor(operator),
+        cause,
+        Action::new(
+            String::from("SetUserContact"),
+            Some(format!(
+                "Set contact for user {} ('{initials}')",
+                request.user_id
+            )),
+        ),
+        StateSnapshot::new(contact_snapshot(previous.as_ref())),
+        StateSnapshot::new(contact_snapshot(Some(&contact))),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetUserContactResponse {
+        contact: UserContactInfo::from(contact),
+    })
+}
+
+/// Retrieves a user's notification contact details.
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin, the user does not exist,
+/// or the query fails.
+pub fn get_user_contact(
+    persistence: &mut SqlitePersistence,
+    user_id: i64,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<GetUserContactResponse, ApiError> {
+    require_admin(authenticated_actor, "get_user_contact")?;
+    load_user_initials(persistence, user_id)?;
+
+    Ok(GetUserContactResponse {
+        contact: load_contact(persistence, user_id)?.map(UserContactInfo::from),
+    })
+}
+
+/// Lists notifications sent to a user, newest first.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `user_id` - The canonical user ID
+/// * `limit` - Maximum entries to return (default 100, max 1000)
+/// * `authenticated_actor` - The authenticated actor performing this action
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin, the limit is out of
+/// range, or the query fails.
+pub fn list_user_notifications(
+    persistence: &mut SqlitePersistence,
+    user_id: i64,
+    limit: Option<u32>,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<ListUserNotificationsResponse, ApiError> {
+    require_admin(authenticated_actor, "list_user_notifications")?;
+
+    let limit: u32 = limit.unwrap_or(DEFAULT_NOTIFICATION_LIMIT);
+    if limit == 0 || limit > MAX_NOTIFICATION_LIMIT {
+        return Err(ApiError::InvalidInput {
+            field: String::from("limit"),
+            message: format!("limit must be between 1 and {MAX_NOTIFICATION_LIMIT}"),
+        });
+    }
+
+    let notifications: Vec<NotificationInfo> = persistence
+        .list_notifications_for_user(user_id, limit)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list notifications: {e}"),
+        })?
+        .into_iter()
+        .map(NotificationInfo::from)
+        .collect();
+
+    Ok(ListUserNotificationsResponse { notifications })
+}
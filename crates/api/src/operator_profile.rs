@@ -0,0 +1,202 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Operator self-service handlers.
+//!
+//! Any authenticated operator, Admin or Bidder, may change their own
+//! password and display name. Both changes are attributed to the operator
+//! making them and emit a global audit event.
+
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_persistence::{OperatorData, SecretToken, SqlitePersistence};
+
+use crate::error::ApiError;
+use crate::password_policy::PasswordPolicy;
+use crate::request_response::{
+    ChangeOwnPasswordResponse, ChangePasswordRequest, UpdateOwnProfileRequest,
+    UpdateOwnProfileResponse,
+};
+
+/// Maximum length of a display name, in characters.
+const MAX_DISPLAY_NAME_LENGTH: usize = 100;
+
+/// Builds the audit actor for an operator acting on their own account.
+fn self_actor(operator: &OperatorData) -> Actor {
+    Actor::with_operator(
+        operator.operator_id.to_string(),
+        String::from("operator"),
+        operator.operator_id,
+        operator.login_name.clone(),
+        operator.display_name.clone(),
+    )
+}
+
+/// Changes the calling operator's own password.
+///
+/// The current password must be supplied and the new password must meet
+/// the password policy. The session the change is made from stays signed
+/// in; every other session of the operator is invalidated so that other
+/// devices must sign in again with the new password.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The current password and the new password
+/// * `current_session_token` - The token of the session making the change
+/// * `operator` - The operator changing their password
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The current password is incorrect
+/// - The new password does not meet policy requirements
+/// - The password confirmation does not match
+/// - Database operations fail
+pub fn change_own_password(
+    persistence: &mut SqlitePersistence,
+    request: &ChangePasswordRequest,
+    current_session_token: &SecretToken,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<ChangeOwnPasswordResponse, ApiError> {
+    let password_valid: bool = persistence
+        .verify_password(&request.current_password, &operator.password_hash)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Password verification failed: {e}"),
+        })?;
+
+    if !password_valid {
+        return Err(ApiError::AuthenticationFailed {
+            reason: String::from("Current password is incorrect"),
+        });
+    }
+
+    let policy: PasswordPolicy = PasswordPolicy::default();
+    policy.validate(
+        &request.new_password,
+        &request.new_password_confirmation,
+        &operator.login_name,
+        &operator.display_name,
+    )?;
+
+    persistence
+        .update_password(operator.operator_id, &request.new_password)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to update password: {e}"),
+        })?;
+
+    let sessions_invalidated: usize = persistence
+        .delete_other_sessions_for_operator(operator.operator_id, current_session_token)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to invalidate sessions: {e}"),
+        })?;
+
+    let action: Action = Action::new(
+        String::from("ChangePassword"),
+        Some(format!(
+            "Operator {} changed their own password; {sessions_invalidated} other session(s) signed out",
+            operator.login_name
+        )),
+    );
+
+    let operator_id = operator.operator_id;
+    let before: StateSnapshot = StateSnapshot::new(format!("operator_id={operator_id}"));
+    let after: StateSnapshot =
+        StateSnapshot::new(format!("operator_id={operator_id},password_changed"));
+
+    let audit_event: AuditEvent =
+        AuditEvent::new_global(self_actor(operator), cause, action, before, after);
+
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(ChangeOwnPasswordResponse {
+        sessions_invalidated,
+        message: format!(
+            "Password changed successfully. {sessions_invalidated} session(s) on other devices have been signed out."
+        ),
+    })
+}
+
+/// Updates the calling operator's own profile.
+///
+/// Only the display name may be changed; the login name and role are
+/// managed by administrators.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The new display name
+/// * `operator` - The operator updating their profile
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The display name is empty or too long
+/// - Database operations fail
+pub fn update_own_profile(
+    persistence: &mut SqlitePersistence,
+    request: &UpdateOwnProfileRequest,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<UpdateOwnProfileResponse, ApiError> {
+    let display_name: &str = request.display_name.trim();
+    if display_name.is_empty() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("display_name"),
+            message: String::from("Display name must not be empty"),
+        });
+    }
+    if display_name.chars().count() > MAX_DISPLAY_NAME_LENGTH {
+        return Err(ApiError::InvalidInput {
+            field: String::from("display_name"),
+            message: format!("Display name must be at most {MAX_DISPLAY_NAME_LENGTH} characters"),
+        });
+    }
+
+    persistence
+        .update_display_name(operator.operator_id, display_name)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to update display name: {e}"),
+        })?;
+
+    let action: Action = Action::new(
+        String::from("UpdateProfile"),
+        Some(format!(
+            "Operator {} changed their display name to {display_name}",
+            operator.login_name
+        )),
+    );
+
+    let operator_id = operator.operator_id;
+    let before: StateSnapshot = StateSnapshot::new(format!(
+        "operator_id={operator_id},display_name={}",
+        operator.display_name
+    ));
+    let after: StateSnapshot = StateSnapshot::new(format!(
+        "operator_id={operator_id},display_name={display_name}"
+    ));
+
+    let audit_event: AuditEvent =
+        AuditEvent::new_global(self_actor(operator), cause, action, before, after);
+
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(UpdateOwnProfileResponse {
+        operator_id,
+        login_name: operator.login_name.clone(),
+        display_name: display_name.to_string(),
+        message: String::from("Profile updated successfully"),
+    })
+}
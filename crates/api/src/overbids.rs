@@ -0,0 +1,557 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Overbid request and approval handlers.
+//!
+//! In a round that allows overbids, a controller may ask for leave on a
+//! day whose slots are all taken. The request waits for an Admin. Approval
+//! grows the day's slot inventory by one and grants the leave; denial
+//! records why. Each row links to the audit event that recorded the
+//! request and the one that recorded the decision, and the decision event
+//! names the request event in its snapshots.
+
+use time::Date;
+use time::format_description::well_known::Iso8601;
+use zab_bid::{BootstrapMetadata, BootstrapResult, Command, apply_bootstrap};
+use zab_bid_audit::Cause;
+use zab_bid_domain::{
+    Area, BidReceiptMethod, BidYear, BidYearLifecycle, DomainError, Initials, Round, SlotInventory,
+};
+use zab_bid_persistence::{OperatorData, OverbidRequestData, SqlitePersistence};
+
+use crate::auth::{AuthenticatedActor, AuthorizationService, Role};
+use crate::error::{ApiError, translate_core_error, translate_domain_error};
+use crate::leave_bids::{
+    list_user_leave_bids, load_lifecycle_state, require_round, resolve_area, resolve_user,
+    resolve_user_crew,
+};
+use crate::request_response::{
+    ApproveOverbidRequest, DenyOverbidRequest, ListOverbidRequestsResponse,
+    OverbidDecisionResponse, OverbidRequestInfo, RequestOverbidRequest, RequestOverbidResponse,
+};
+use crate::round_sign_offs::require_round_open;
+use crate::slot_inventory::load_slot_inventory;
+use crate::webhooks::require_admin;
+
+/// Parses an overbid leave date.
+fn parse_leave_date(value: &str) -> Result<Date, ApiError> {
+    Date::parse(value, &Iso8601::DEFAULT).map_err(|_| ApiError::InvalidInput {
+        field: String::from("leave_date"),
+        message: format!("Invalid date format: {value}"),
+    })
+}
+
+/// Ensures the bid year is `BiddingActive`.
+fn require_bidding_active(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    operation: &str,
+) -> Result<(), ApiError> {
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, bid_year_id)?;
+    if lifecycle_state == BidYearLifecycle::BiddingActive {
+        Ok(())
+    } else {
+        Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: operation.to_string(),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ))
+    }
+}
+
+/// Returns whether a user holds leave on a day in a round.
+fn holds_leave_on(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    request: &OverbidRequestData,
+) -> Result<bool, ApiError> {
+    Ok(list_user_leave_bids(
+        persistence,
+        bid_year_id,
+        request.area_id,
+        request.round_id,
+        request.user_id,
+    )?
+    .iter()
+    .any(|bid| bid.leave_date == request.leave_date))
+}
+
+/// Loads an overbid request that is still waiting for a decision.
+fn load_pending_request(
+    persistence: &mut SqlitePersistence,
+    overbid_request_id: i64,
+) -> Result<(OverbidRequestData, i64), ApiError> {
+    let request: OverbidRequestData = persistence
+        .get_overbid_request(overbid_request_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get overbid request: {e}"),
+        })?
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("OverbidRequest"),
+            message: format!("Overbid request with ID {overbid_request_id} not found"),
+        })?;
+    if request.status != OverbidRequestData::STATUS_PENDING {
+        return Err(translate_domain_error(DomainError::InvalidOverbid {
+            reason: format!(
+                "Overbid request {overbid_request_id} is already {}",
+                request.status
+            ),
+        }));
+    }
+    let request_event_id: i64 = request.request_event_id.ok_or_else(|| ApiError::Internal {
+        message: format!("Overbid request {overbid_request_id} has no request event"),
+    })?;
+
+    Ok((request, request_event_id))
+}
+
+/// Records a request for leave on a day whose slots are all taken.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The controller, round, and leave day
+/// * `authenticated_actor` - The authenticated actor entering the request
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin or Bidder
+/// - The receipt method, leave date, or hours are invalid
+/// - The area, round, or controller does not exist
+/// - The operator is the controller the request is for
+/// - The bid year is not `BiddingActive`
+/// - The round is signed off in the area
+/// - The round does not allow overbids
+/// - The day still has slots remaining, the controller already holds leave
+///   on it, or an overbid for it is already pending
+/// - The database operation fails
+#[allow(clippy::too_many_lines)]
+pub fn request_overbid(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &RequestOverbidRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<RequestOverbidResponse, ApiError> {
+    if !matches!(authenticated_actor.role, Role::Admin | Role::Bidder) {
+        return Err(ApiError::Unauthorized {
+            action: String::from("request overbid"),
+            required_role: String::from("Admin or Bidder"),
+        });
+    }
+
+    let received_via: BidReceiptMethod = request
+        .received_via
+        .parse()
+        .map_err(translate_domain_error)?;
+    let leave_date: Date = parse_leave_date(&request.leave_date)?;
+    let hours: i32 = i32::try_from(request.hours).map_err(|_| ApiError::InvalidInput {
+        field: String::from("hours"),
+        message: format!("Leave hours {} are out of range", request.hours),
+    })?;
+
+    let (bid_year, area) = resolve_area(metadata, request.area_id)?;
+    AuthorizationService::authorize_area_bidding(
+        persistence,
+        authenticated_actor,
+        operator,
+        request.area_id,
+        "request overbid",
+    )?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+    require_bidding_active(persistence, bid_year_id, "request overbid")?;
+    require_round(persistence, bid_year_id, request.round_id)?;
+    require_round_open(persistence, request.area_id, request.round_id)?;
+
+    let round: Round = persistence
+        .get_round(request.round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get round: {e}"),
+        })?;
+    if !round.allow_overbid() {
+        return Err(translate_domain_error(DomainError::InvalidOverbid {
+            reason: format!("Round {} does not allow overbids", request.round_id),
+        }));
+    }
+
+    let on_behalf_of: Initials = Initials::new(request.on_behalf_of.trim());
+    let user_id: i64 = resolve_user(persistence, bid_year, area, &on_behalf_of)?;
+
+    let crew: Option<u8> = resolve_user_crew(persistence, bid_year, area, user_id)?;
+    let inventory: SlotInventory =
+        load_slot_inventory(persistence, bid_year_id, leave_date, leave_date)?;
+    if !inventory
+        .slots_for_crew(request.area_id, request.round_id, leave_date, crew)
+        .is_exhausted()
+    {
+        return Err(translate_domain_error(DomainError::InvalidOverbid {
+            reason: format!("Slots remain on {leave_date}; enter a leave bid instead"),
+        }));
+    }
+
+    let day: String = leave_date.to_string();
+    if list_user_leave_bids(
+        persistence,
+        bid_year_id,
+        request.area_id,
+        request.round_id,
+        user_id,
+    )?
+    .iter()
+    .any(|bid| bid.leave_date == day)
+    {
+        return Err(translate_domain_error(DomainError::InvalidOverbid {
+            reason: format!(
+                "User '{}' already holds a leave bid on {day} in round {}",
+                on_behalf_of.value(),
+                request.round_id
+            ),
+        }));
+    }
+    let already_pending: bool = persistence
+        .list_overbid_requests(request.area_id, request.round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list overbid requests: {e}"),
+        })?
+        .iter()
+        .any(|pending| {
+            pending.user_id == user_id
+                && pending.leave_date == day
+                && pending.status == OverbidRequestData::STATUS_PENDING
+        });
+    if already_pending {
+        return Err(translate_domain_error(DomainError::InvalidOverbid {
+            reason: format!(
+                "User '{}' already has an overbid pending on {day}",
+                on_behalf_of.value()
+            ),
+        }));
+    }
+
+    let command: Command = Command::RequestOverbid {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id: request.round_id,
+        user_id,
+        on_behalf_of: on_behalf_of.clone(),
+        received_via,
+        leave_date,
+        hours: request.hours,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    let overbid_request_id: i64 = persistence
+        .insert_overbid_request(
+            bid_year_id,
+            request.area_id,
+            user_id,
+            request.round_id,
+            &day,
+            hours,
+            on_behalf_of.value(),
+            received_via.as_str(),
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record overbid request: {e}"),
+        })?;
+
+    let request_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    persistence
+        .set_overbid_request_event(overbid_request_id, request_event_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to link overbid request: {e}"),
+        })?;
+
+    Ok(RequestOverbidResponse {
+        overbid_request_id,
+        area_id: request.area_id,
+        round_id: request.round_id,
+        user_id,
+        leave_date: day.clone(),
+        request_event_id,
+        message: format!(
+            "Overbid for '{}' on {day} is awaiting Admin approval",
+            on_behalf_of.value()
+        ),
+    })
+}
+
+/// Approves a pending overbid request.
+///
+/// The day's staffing adjustment grows by one and the requested leave is
+/// recorded with the request's attribution.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The overbid request to approve
+/// * `authenticated_actor` - The authenticated actor approving the request
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The overbid request does not exist or is not pending
+/// - The bid year is not `BiddingActive`
+/// - The controller has since been granted leave on the day
+/// - The database operation fails
+pub fn approve_overbid(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &ApproveOverbidRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<OverbidDecisionResponse, ApiError> {
+    require_admin(authenticated_actor, "approve overbid")?;
+
+    let (pending, request_event_id) =
+        load_pending_request(persistence, request.overbid_request_id)?;
+    let leave_date: Date = parse_leave_date(&pending.leave_date)?;
+    let (bid_year, area): (&BidYear, &Area) = resolve_area(metadata, pending.area_id)?;
+    require_bidding_active(persistence, pending.bid_year_id, "approve overbid")?;
+    if holds_leave_on(persistence, pending.bid_year_id, &pending)? {
+        return Err(translate_domain_error(DomainError::InvalidOverbid {
+            reason: format!(
+                "User '{}' already holds a leave bid on {leave_date}",
+                pending.on_behalf_of
+            ),
+        }));
+    }
+
+    let previous_adjustment: i32 = persistence
+        .get_slot_adjustment(pending.area_id, pending.round_id, &pending.leave_date)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get slot adjustment: {e}"),
+        })?;
+
+    let command: Command = Command::ApproveOverbid {
+        year: bid_year.year(),
+        area: area.clone(),
+        overbid_request_id: pending.overbid_request_id,
+        request_event_id,
+        round_id: pending.round_id,
+        user_id: pending.user_id,
+        leave_date,
+        previous_adjustment,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    persistence
+        .set_slot_adjustment(
+            pending.bid_year_id,
+            pending.area_id,
+            pending.round_id,
+            &pending.leave_date,
+            previous_adjustment + 1,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record slot adjustment: {e}"),
+        })?;
+    let leave_bid_id: i64 = persistence
+        .insert_leave_bid(
+            pending.bid_year_id,
+            pending.area_id,
+            pending.user_id,
+            pending.round_id,
+            &pending.leave_date,
+            pending.hours,
+            &pending.on_behalf_of,
+            &pending.received_via,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record leave bid: {e}"),
+        })?;
+
+    let decision_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    persistence
+        .decide_overbid_request(
+            pending.overbid_request_id,
+            OverbidRequestData::STATUS_APPROVED,
+            None,
+            decision_event_id,
+            Some(leave_bid_id),
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record overbid decision: {e}"),
+        })?;
+
+    Ok(OverbidDecisionResponse {
+        overbid_request_id: pending.overbid_request_id,
+        status: String::from(OverbidRequestData::STATUS_APPROVED),
+        request_event_id,
+        decision_event_id,
+        leave_bid_id: Some(leave_bid_id),
+        message: format!(
+            "Approved overbid for '{}' on {leave_date}",
+            pending.on_behalf_of
+        ),
+    })
+}
+
+/// Denies a pending overbid request.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The overbid request to deny and why
+/// * `authenticated_actor` - The authenticated actor denying the request
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The overbid request does not exist or is not pending
+/// - The reason is empty
+/// - The database operation fails
+pub fn deny_overbid(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &DenyOverbidRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<OverbidDecisionResponse, ApiError> {
+    require_admin(authenticated_actor, "deny overbid")?;
+
+    let (pending, request_event_id) =
+        load_pending_request(persistence, request.overbid_request_id)?;
+    let leave_date: Date = parse_leave_date(&pending.leave_date)?;
+    let (bid_year, area): (&BidYear, &Area) = resolve_area(metadata, pending.area_id)?;
+
+    let command: Command = Command::DenyOverbid {
+        year: bid_year.year(),
+        area: area.clone(),
+        overbid_request_id: pending.overbid_request_id,
+        request_event_id,
+        round_id: pending.round_id,
+        user_id: pending.user_id,
+        leave_date,
+        reason: request.reason.clone(),
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    let decision_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    persistence
+        .decide_overbid_request(
+            pending.overbid_request_id,
+            OverbidRequestData::STATUS_DENIED,
+            Some(request.reason.trim()),
+            decision_event_id,
+            None,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record overbid decision: {e}"),
+        })?;
+
+    Ok(OverbidDecisionResponse {
+        overbid_request_id: pending.overbid_request_id,
+        status: String::from(OverbidRequestData::STATUS_DENIED),
+        request_event_id,
+        decision_event_id,
+        leave_bid_id: None,
+        message: format!(
+            "Denied overbid for '{}' on {leave_date}",
+            pending.on_behalf_of
+        ),
+    })
+}
+
+/// Lists the overbid requests for a round in an area.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `area_id` - The canonical area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the area does not exist or the query fails.
+pub fn list_overbid_requests(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    area_id: i64,
+    round_id: i64,
+) -> Result<ListOverbidRequestsResponse, ApiError> {
+    resolve_area(metadata, area_id)?;
+
+    let requests: Vec<OverbidRequestInfo> = persistence
+        .list_overbid_requests(area_id, round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list overbid requests: {e}"),
+        })?
+        .into_iter()
+        .map(|request| OverbidRequestInfo {
+            overbid_request_id: request.overbid_request_id,
+            user_id: request.user_id,
+            leave_date: request.leave_date,
+            hours: request.hours,
+            on_behalf_of: request.on_behalf_of,
+            received_via: request.received_via,
+            status: request.status,
+            decision_reason: request.decision_reason,
+            request_event_id: request.request_event_id,
+            decision_event_id: request.decision_event_id,
+            leave_bid_id: request.leave_bid_id,
+            created_at: request.created_at,
+            decided_at: request.decided_at,
+        })
+        .collect();
+
+    Ok(ListOverbidRequestsResponse {
+        area_id,
+        round_id,
+        requests,
+    })
+}
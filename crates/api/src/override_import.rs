@@ -0,0 +1,501 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Bulk override import.
+//!
+//! National-level corrections to eligibility and bid order sometimes arrive
+//! as a spreadsheet. Its CSV export lists one override per row:
+//!
+//! ```text
+//! Init,Area,Field,Value,Reason
+//! AB,North,eligibility,false,National review found lapsed certification
+//! CD,North,bid_order,3,Corrected SCD per national seniority audit
+//! ```
+//!
+//! Headers are matched case-insensitively. `Field` is `eligibility`, with a
+//! value of `true`/`false` (or `yes`/`no`), or `bid_order`, with a positive
+//! value or a blank one to clear it. Every row carries its own reason,
+//! recorded with its override.
+//!
+//! [`preview_override_import`] validates every row against the bid year's
+//! canonical data and reports the diff: the overrides that change a value,
+//! the rows that already match, and the rows that are invalid with the
+//! reason. [`apply_override_import`] applies the overrides only when no row
+//! is invalid. Each is recorded like a single override, in the ledger and
+//! with its own audit event, and the batch's audit events share a
+//! correlation ID.
+
+use std::collections::HashMap;
+
+use csv::StringRecord;
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::Ulid;
+use zab_bid_domain::{Area, BidYear, BidYearLifecycle, DomainError, User};
+use zab_bid_persistence::{OperatorData, SqlitePersistence, UserEligibilityData};
+
+use crate::auth::AuthenticatedActor;
+use crate::auto_checkpoints::record_operation_events;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_domain_error};
+use crate::handlers::{override_bid_order, override_eligibility};
+use crate::leave_bids::load_lifecycle_state;
+use crate::request_response::{
+    InvalidOverrideRow, OverrideBidOrderRequest, OverrideEligibilityRequest, OverrideImportChange,
+    OverrideImportReport, OverrideImportRequest, OverrideImportResponse,
+};
+use crate::webhooks::require_admin;
+
+// Normalized headers of the override CSV
+const INITIALS: &str = "init";
+const AREA: &str = "area";
+const FIELD: &str = "field";
+const VALUE: &str = "value";
+const REASON: &str = "reason";
+
+/// Headers every CSV must have.
+const REQUIRED_HEADERS: &[&str] = &[INITIALS, AREA, FIELD, VALUE, REASON];
+
+// Overridable fields
+const ELIGIBILITY: &str = "eligibility";
+const BID_ORDER: &str = "bid_order";
+
+/// Shortest override reason accepted, as for a single override.
+const MIN_REASON_LENGTH: usize = 10;
+
+/// Normalizes a header, so `Bid Order` matches `bid_order`.
+fn normalize_header(header: &str) -> String {
+    header
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join("_")
+}
+
+/// An override value read from a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OverrideTarget {
+    Eligibility(bool),
+    BidOrder(Option<i32>),
+}
+
+impl OverrideTarget {
+    /// Parses a row's field and value.
+    fn parse(field: &str, value: &str) -> Result<Self, String> {
+        match normalize_header(field).as_str() {
+            ELIGIBILITY => match value.to_lowercase().as_str() {
+                "true" | "yes" => Ok(Self::Eligibility(true)),
+                "false" | "no" => Ok(Self::Eligibility(false)),
+                _ => Err(format!("Invalid eligibility '{value}': use true or false")),
+            },
+            BID_ORDER if value.is_empty() => Ok(Self::BidOrder(None)),
+            BID_ORDER => value
+                .parse::<i32>()
+                .ok()
+                .filter(|order| *order > 0)
+                .map(|order| Self::BidOrder(Some(order)))
+                .ok_or_else(|| format!("Invalid bid order '{value}': must be positive")),
+            _ => Err(format!(
+                "Unknown field '{field}': use {ELIGIBILITY} or {BID_ORDER}"
+            )),
+        }
+    }
+
+    const fn field(self) -> &'static str {
+        match self {
+            Self::Eligibility(_) => ELIGIBILITY,
+            Self::BidOrder(_) => BID_ORDER,
+        }
+    }
+
+    fn describe(self) -> String {
+        match self {
+            Self::Eligibility(can_bid) => format!("can_bid={can_bid}"),
+            Self::BidOrder(bid_order) => format!("bid_order={bid_order:?}"),
+        }
+    }
+}
+
+/// An override the import applies.
+struct PlannedOverride {
+    user_id: i64,
+    target: OverrideTarget,
+    reason: String,
+}
+
+/// A bid year's canonical data and the diff a CSV makes to it.
+struct OverridePlan {
+    bid_year_id: i64,
+    year: u16,
+    /// Users of the bid year: their area code and ID, by initials.
+    roster: HashMap<String, (String, i64)>,
+    eligibility: HashMap<i64, bool>,
+    bid_orders: HashMap<i64, Option<i32>>,
+    total_rows: usize,
+    /// Users and fields already overridden, with the row doing so.
+    listed: HashMap<(i64, &'static str), usize>,
+    overrides: Vec<PlannedOverride>,
+    changes: Vec<OverrideImportChange>,
+    unchanged_rows: Vec<usize>,
+    invalid_rows: Vec<InvalidOverrideRow>,
+}
+
+impl OverridePlan {
+    /// Loads the bid year's roster, eligibility, and bid order.
+    fn load(
+        persistence: &mut SqlitePersistence,
+        metadata: &BootstrapMetadata,
+        bid_year_id: i64,
+    ) -> Result<Self, ApiError> {
+        let year: u16 = resolve_bid_year(metadata, bid_year_id)?;
+        let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, bid_year_id)?;
+        if !matches!(
+            lifecycle_state,
+            BidYearLifecycle::Canonicalized
+                | BidYearLifecycle::BiddingActive
+                | BidYearLifecycle::BiddingClosed
+        ) {
+            return Err(translate_domain_error(
+                DomainError::CannotOverrideBeforeCanonicalization {
+                    current_state: lifecycle_state.as_str().to_string(),
+                },
+            ));
+        }
+
+        let bid_year: BidYear = BidYear::with_id(bid_year_id, year);
+        let mut roster: HashMap<String, (String, i64)> = HashMap::new();
+        let areas: Vec<&Area> = metadata
+            .areas
+            .iter()
+            .filter(|(by, area)| by.year() == year && !area.is_system_area())
+            .map(|(_, area)| area)
+            .collect();
+        for area in areas {
+            let users: Vec<User> =
+                persistence
+                    .list_users(&bid_year, area)
+                    .map_err(|e| ApiError::Internal {
+                        message: format!("Failed to list users: {e}"),
+                    })?;
+            for user in users {
+                if let Some(user_id) = user.user_id {
+                    roster.insert(
+                        user.initials.value().to_string(),
+                        (area.area_code().to_uppercase(), user_id),
+                    );
+                }
+            }
+        }
+
+        let eligibility: HashMap<i64, bool> = persistence
+            .list_user_eligibility(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list eligibility: {e}"),
+            })?
+            .into_iter()
+            .map(|row: UserEligibilityData| (row.user_id, row.can_bid))
+            .collect();
+        let bid_orders: HashMap<i64, Option<i32>> = persistence
+            .list_canonical_bid_orders(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list bid order: {e}"),
+            })?
+            .into_iter()
+            .collect();
+
+        Ok(Self {
+            bid_year_id,
+            year,
+            roster,
+            eligibility,
+            bid_orders,
+            total_rows: 0,
+            listed: HashMap::new(),
+            overrides: Vec::new(),
+            changes: Vec::new(),
+            unchanged_rows: Vec::new(),
+            invalid_rows: Vec::new(),
+        })
+    }
+
+    /// Validates every row of a CSV.
+    fn map_csv(&mut self, csv_content: &str) -> Result<(), ApiError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(true)
+            .flexible(true)
+            .from_reader(csv_content.as_bytes());
+        let headers: StringRecord = reader
+            .headers()
+            .map_err(|e| ApiError::InvalidCsvFormat {
+                reason: format!("Failed to read CSV headers: {e}"),
+            })?
+            .clone();
+        let columns: HashMap<String, usize> = headers
+            .iter()
+            .enumerate()
+            .map(|(idx, header)| (normalize_header(header), idx))
+            .collect();
+        let missing: Vec<String> = REQUIRED_HEADERS
+            .iter()
+            .filter(|header| !columns.contains_key(**header))
+            .map(|header| header.to_uppercase())
+            .collect();
+        if !missing.is_empty() {
+            return Err(ApiError::InvalidCsvFormat {
+                reason: format!("Missing required headers: {}", missing.join(", ")),
+            });
+        }
+
+        for (idx, record) in reader.records().enumerate() {
+            let row_number: usize = idx + 1;
+            self.total_rows += 1;
+            let record: StringRecord = match record {
+                Ok(record) => record,
+                Err(e) => {
+                    self.reject(row_number, None, format!("CSV parse error: {e}"));
+                    continue;
+                }
+            };
+            let field = |header: &str| -> String {
+                columns
+                    .get(header)
+                    .and_then(|&idx| record.get(idx))
+                    .map(|value| value.trim().to_string())
+                    .unwrap_or_default()
+            };
+            let initials: String = field(INITIALS).to_uppercase();
+            if let Err(reason) = self.map_row(row_number, &initials, &field) {
+                let initials: Option<String> = Some(initials).filter(|i| !i.is_empty());
+                self.reject(row_number, initials, reason);
+            }
+        }
+        Ok(())
+    }
+
+    fn reject(&mut self, row_number: usize, initials: Option<String>, reason: String) {
+        self.invalid_rows.push(InvalidOverrideRow {
+            row_number,
+            initials,
+            reason,
+        });
+    }
+
+    /// Validates one row against canonical data.
+    fn map_row(
+        &mut self,
+        row_number: usize,
+        initials: &str,
+        field: &impl Fn(&str) -> String,
+    ) -> Result<(), String> {
+        if initials.is_empty() {
+            return Err(String::from("Missing INIT"));
+        }
+        let area_code: String = field(AREA).to_uppercase();
+        if area_code.is_empty() {
+            return Err(String::from("Missing AREA"));
+        }
+        let (roster_area, user_id) = self
+            .roster
+            .get(initials)
+            .cloned()
+            .ok_or_else(|| format!("'{initials}' is not on the {} roster", self.year))?;
+        if roster_area != area_code {
+            return Err(format!(
+                "'{initials}' is in area {roster_area}, not {area_code}"
+            ));
+        }
+
+        let target: OverrideTarget = OverrideTarget::parse(&field(FIELD), &field(VALUE))?;
+        let reason: String = field(REASON);
+        if reason.len() < MIN_REASON_LENGTH {
+            return Err(format!(
+                "REASON must be at least {MIN_REASON_LENGTH} characters"
+            ));
+        }
+        if let Some(row) = self.listed.get(&(user_id, target.field())) {
+            return Err(format!(
+                "{} for '{initials}' is already overridden on row {row}",
+                target.field()
+            ));
+        }
+
+        let current: OverrideTarget = match target {
+            OverrideTarget::Eligibility(_) => OverrideTarget::Eligibility(
+                *self
+                    .eligibility
+                    .get(&user_id)
+                    .ok_or_else(|| format!("'{initials}' has no canonical eligibility"))?,
+            ),
+            OverrideTarget::BidOrder(_) => OverrideTarget::BidOrder(
+                *self
+                    .bid_orders
+                    .get(&user_id)
+                    .ok_or_else(|| format!("'{initials}' has no canonical bid order"))?,
+            ),
+        };
+        self.listed.insert((user_id, target.field()), row_number);
+
+        if current == target {
+            self.unchanged_rows.push(row_number);
+            return Ok(());
+        }
+        self.changes.push(OverrideImportChange {
+            row_number,
+            user_id,
+            initials: initials.to_string(),
+            area_code,
+            field: target.field().to_string(),
+            current_value: current.describe(),
+            new_value: target.describe(),
+            reason: reason.clone(),
+        });
+        self.overrides.push(PlannedOverride {
+            user_id,
+            target,
+            reason,
+        });
+        Ok(())
+    }
+
+    fn report(&self) -> OverrideImportReport {
+        let mut invalid_rows: Vec<InvalidOverrideRow> = self.invalid_rows.clone();
+        invalid_rows.sort_by_key(|row| row.row_number);
+        OverrideImportReport {
+            bid_year_id: self.bid_year_id,
+            bid_year: self.year,
+            total_rows: self.total_rows,
+            changes: self.changes.clone(),
+            unchanged_rows: self.unchanged_rows.clone(),
+            invalid_rows,
+        }
+    }
+}
+
+/// Validates a CSV against its bid year without changing anything.
+fn plan(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &OverrideImportRequest,
+) -> Result<OverridePlan, ApiError> {
+    let mut plan: OverridePlan = OverridePlan::load(persistence, metadata, request.bid_year_id)?;
+    plan.map_csv(&request.csv_content)?;
+    Ok(plan)
+}
+
+/// Reports the diff a bulk override import makes to canonical data.
+///
+/// Nothing is changed. Every invalid row is listed with the reason.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year and the CSV
+/// * `authenticated_actor` - The authenticated actor previewing the import
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist, or is not yet canonicalized
+/// - The CSV lacks a required column, or cannot be read
+pub fn preview_override_import(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &OverrideImportRequest,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<OverrideImportReport, ApiError> {
+    require_admin(authenticated_actor, "preview override import")?;
+    Ok(plan(persistence, metadata, request)?.report())
+}
+
+/// Applies a bulk override import as one correlated batch.
+///
+/// Nothing is applied unless every row is valid. Rows that already match
+/// canonical data are skipped.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year and the CSV
+/// * `authenticated_actor` - The authenticated actor applying the import
+/// * `operator` - The operator data for audit attribution
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist, or is not yet canonicalized
+/// - The CSV lacks a required column, or cannot be read
+/// - A row is invalid
+/// - An override or audit event cannot be recorded
+pub fn apply_override_import(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &OverrideImportRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<OverrideImportResponse, ApiError> {
+    require_admin(authenticated_actor, "apply override import")?;
+    let plan: OverridePlan = plan(persistence, metadata, request)?;
+    let report: OverrideImportReport = plan.report();
+    if let Some(first) = report.invalid_rows.first() {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("override_import_invalid_rows"),
+            message: format!(
+                "{} row(s) are invalid; nothing was applied (row {}: {})",
+                report.invalid_rows.len(),
+                first.row_number,
+                first.reason
+            ),
+        });
+    }
+
+    let mut override_ids: Vec<i64> = Vec::with_capacity(plan.overrides.len());
+    let mut audit_event_ids: Vec<i64> = Vec::with_capacity(plan.overrides.len());
+    for planned in &plan.overrides {
+        let (override_id, audit_event_id) = match planned.target {
+            OverrideTarget::Eligibility(can_bid) => {
+                let response = override_eligibility(
+                    persistence,
+                    &OverrideEligibilityRequest {
+                        user_id: planned.user_id,
+                        can_bid,
+                        reason: planned.reason.clone(),
+                    },
+                    authenticated_actor,
+                    operator,
+                )?;
+                (response.override_id, response.audit_event_id)
+            }
+            OverrideTarget::BidOrder(bid_order) => {
+                let response = override_bid_order(
+                    persistence,
+                    &OverrideBidOrderRequest {
+                        user_id: planned.user_id,
+                        bid_order,
+                        reason: planned.reason.clone(),
+                    },
+                    authenticated_actor,
+                    operator,
+                )?;
+                (response.override_id, response.audit_event_id)
+            }
+        };
+        override_ids.push(override_id);
+        audit_event_ids.push(audit_event_id);
+    }
+
+    let correlation_id: String = Ulid::new().to_string();
+    record_operation_events(persistence, &audit_event_ids, &correlation_id)?;
+
+    Ok(OverrideImportResponse {
+        report,
+        override_ids,
+        audit_event_ids,
+        correlation_id,
+    })
+}
@@ -0,0 +1,189 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Minimal PDF writer for tabular reports.
+//!
+//! Produces uncompressed PDF 1.4 documents that use only the standard
+//! Helvetica fonts, which every viewer provides without embedding. Text is
+//! encoded as `WinAnsiEncoding`; characters outside Latin-1 are replaced
+//! with `?`.
+
+use std::fmt::Write;
+
+/// Page width in points (US Letter, landscape).
+pub const PAGE_WIDTH: f32 = 792.0;
+
+/// Page height in points (US Letter, landscape).
+pub const PAGE_HEIGHT: f32 = 612.0;
+
+/// Average Helvetica glyph width as a fraction of the font size.
+///
+/// Used to estimate text width for truncation and right alignment.
+const AVERAGE_GLYPH_WIDTH: f32 = 0.55;
+
+/// The fonts available on a page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Font {
+    /// Helvetica.
+    Regular,
+    /// Helvetica-Bold.
+    Bold,
+}
+
+impl Font {
+    /// Returns the resource name used in content streams.
+    const fn resource(self) -> &'static str {
+        match self {
+            Self::Regular => "F1",
+            Self::Bold => "F2",
+        }
+    }
+}
+
+/// One page of drawing operations.
+#[derive(Debug, Clone, Default)]
+pub struct PdfPage {
+    content: Vec<u8>,
+}
+
+impl PdfPage {
+    /// Creates an empty page.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            content: Vec::new(),
+        }
+    }
+
+    /// Draws `text` with its baseline starting at (`x`, `y`).
+    pub fn text(&mut self, font: Font, size: f32, x: f32, y: f32, text: &str) {
+        let mut operators: String = String::new();
+        let _ = write!(
+            operators,
+            "BT /{} {size:.1} Tf {x:.2} {y:.2} Td (",
+            font.resource()
+        );
+        self.content.extend_from_slice(operators.as_bytes());
+        self.content.extend(encode_text(text));
+        self.content.extend_from_slice(b") Tj ET\n");
+    }
+
+    /// Draws `text` so that it ends at `right`.
+    pub fn text_right(&mut self, font: Font, size: f32, right: f32, y: f32, text: &str) {
+        self.text(font, size, right - estimate_width(text, size), y, text);
+    }
+
+    /// Draws a half-point line from (`x1`, `y1`) to (`x2`, `y2`).
+    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32) {
+        let mut operators: String = String::new();
+        let _ = writeln!(operators, "0.5 w {x1:.2} {y1:.2} m {x2:.2} {y2:.2} l S");
+        self.content.extend_from_slice(operators.as_bytes());
+    }
+}
+
+/// Estimates the rendered width of `text` in points.
+#[must_use]
+pub fn estimate_width(text: &str, size: f32) -> f32 {
+    let chars: f32 = f32::from(u16::try_from(text.chars().count()).unwrap_or(u16::MAX));
+    chars * AVERAGE_GLYPH_WIDTH * size
+}
+
+/// Shortens `text` with a trailing `...` until it fits in `width` points.
+#[must_use]
+pub fn fit(text: &str, size: f32, width: f32) -> String {
+    if estimate_width(text, size) <= width {
+        return text.to_string();
+    }
+    let mut shortened: String = text.to_string();
+    while !shortened.is_empty() && estimate_width(&format!("{shortened}..."), size) > width {
+        shortened.pop();
+    }
+    format!("{}...", shortened.trim_end())
+}
+
+/// Encodes `text` as an escaped PDF literal string body.
+fn encode_text(text: &str) -> Vec<u8> {
+    let mut encoded: Vec<u8> = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        let byte: u8 = match u8::try_from(u32::from(c)) {
+            Ok(b) if (0x20..0x7F).contains(&b) || b >= 0xA0 => b,
+            _ => b'?',
+        };
+        if matches!(byte, b'(' | b')' | b'\\') {
+            encoded.push(b'\\');
+        }
+        encoded.push(byte);
+    }
+    encoded
+}
+
+/// Assembles `pages` into a complete PDF document.
+///
+/// # Arguments
+///
+/// * `title` - The document title stored in the info dictionary
+/// * `pages` - The pages, in order
+#[must_use]
+pub fn render(title: &str, pages: &[PdfPage]) -> Vec<u8> {
+    // Objects 1-5 are fixed; each page adds a page object and its content stream.
+    let mut objects: Vec<Vec<u8>> = Vec::with_capacity(5 + pages.len() * 2);
+    let kids: String = (0..pages.len())
+        .map(|i| format!("{} 0 R", 6 + i * 2))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+    objects.push(format!("<< /Type /Pages /Kids [{kids}] /Count {} >>", pages.len()).into_bytes());
+    objects.push(
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_vec(),
+    );
+    objects.push(
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_vec(),
+    );
+    let mut info: Vec<u8> = b"<< /Title (".to_vec();
+    info.extend(encode_text(title));
+    info.extend_from_slice(b") /Producer (Zabbid) >>");
+    objects.push(info);
+
+    for (i, page) in pages.iter().enumerate() {
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH:.0} {PAGE_HEIGHT:.0}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                7 + i * 2
+            )
+            .into_bytes(),
+        );
+        let mut stream: Vec<u8> =
+            format!("<< /Length {} >>\nstream\n", page.content.len()).into_bytes();
+        stream.extend_from_slice(&page.content);
+        stream.extend_from_slice(b"endstream");
+        objects.push(stream);
+    }
+
+    let mut document: Vec<u8> = b"%PDF-1.4\n".to_vec();
+    let mut offsets: Vec<usize> = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(document.len());
+        document.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+        document.extend_from_slice(object);
+        document.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref_offset: usize = document.len();
+    let mut xref: String = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        let _ = writeln!(xref, "{offset:010} 00000 n ");
+    }
+    let _ = write!(
+        xref,
+        "trailer\n<< /Size {} /Root 1 0 R /Info 5 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        objects.len() + 1
+    );
+    document.extend_from_slice(xref.as_bytes());
+    document
+}
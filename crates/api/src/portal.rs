@@ -0,0 +1,377 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Controller self-service portal.
+//!
+//! Controllers read their own standing — seniority position, bid windows,
+//! submitted bids, and projected leave balance — without an operator
+//! account. An Admin issues each controller a portal link carrying a random
+//! token; the token identifies exactly one user, only its digest is stored,
+//! and it expires. Every portal read is scoped to the link's user, so a
+//! link can never show another controller's data. Issuing and revoking
+//! links are recorded as global audit events.
+
+use time::format_description::well_known::Rfc3339;
+use time::{Duration, OffsetDateTime};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, Cause, StateSnapshot};
+use zab_bid_persistence::{
+    LeaveBidData, OperatorData, PortalLinkData, SecretToken, SeniorityListEntryData,
+    SqlitePersistence, UserBidWindowData,
+};
+
+use crate::auth::AuthenticatedActor;
+use crate::error::ApiError;
+use crate::leave_bids::resolve_area;
+use crate::leave_caps::{UserLeaveProjection, project_leave_balances};
+use crate::request_response::{
+    GetPortalBalanceResponse, GetPortalBidsResponse, GetPortalSeniorityResponse,
+    GetPortalWindowsResponse, IssuePortalLinkRequest, IssuePortalLinkResponse, LeaveProjectionInfo,
+    PortalLeaveBidInfo, PortalWindowInfo, RevokePortalLinkRequest, RevokePortalLinkResponse,
+};
+use crate::webhooks::{operator_actor, require_admin};
+
+/// How many days a portal link works for when the request does not say.
+pub const DEFAULT_PORTAL_LINK_DAYS: u32 = 30;
+
+/// The most days a portal link may work for.
+pub const MAX_PORTAL_LINK_DAYS: u32 = 366;
+
+/// The controller a portal link was issued to.
+///
+/// Obtained only from [`authenticate_portal`]; every portal read takes it
+/// in place of a user ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortalIdentity {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// The user's initials.
+    pub initials: String,
+    /// The canonical bid year ID.
+    pub bid_year_id: i64,
+    /// The user's canonical area ID.
+    pub area_id: i64,
+}
+
+/// Loads a user's bid year ID and initials or returns `ResourceNotFound`.
+fn load_user(persistence: &mut SqlitePersistence, user_id: i64) -> Result<(i64, String), ApiError> {
+    persistence
+        .get_user_details(user_id)
+        .map_err(|_| ApiError::ResourceNotFound {
+            resource_type: String::from("User"),
+            message: format!("User with ID {user_id} not found"),
+        })
+}
+
+/// Issues a controller a portal link, replacing any link they already hold.
+///
+/// The token is returned once and cannot be read back; issuing a new link
+/// is the only way to replace a lost one.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The user and how long the link works for
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The validity is zero or longer than [`MAX_PORTAL_LINK_DAYS`]
+/// - The user does not exist
+/// - The database operation fails
+pub fn issue_portal_link(
+    persistence: &mut SqlitePersistence,
+    request: &IssuePortalLinkRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<IssuePortalLinkResponse, ApiError> {
+    require_admin(authenticated_actor, "issue_portal_link")?;
+    let valid_days: u32 = request.valid_days.unwrap_or(DEFAULT_PORTAL_LINK_DAYS);
+    if valid_days == 0 || valid_days > MAX_PORTAL_LINK_DAYS {
+        return Err(ApiError::InvalidInput {
+            field: String::from("valid_days"),
+            message: format!("Portal links work for 1 to {MAX_PORTAL_LINK_DAYS} days"),
+        });
+    }
+
+    let (bid_year_id, initials): (i64, String) = load_user(persistence, request.user_id)?;
+    let expires_at: String = (persistence.now() + Duration::days(i64::from(valid_days)))
+        .format(&Rfc3339)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to format expiry: {e}"),
+        })?;
+
+    let portal_token: SecretToken = SecretToken::generate();
+    persistence
+        .create_portal_link(&portal_token, request.user_id, bid_year_id, &expires_at)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to store portal link: {e}"),
+        })?;
+
+    let audit_event: AuditEvent = AuditEvent::new_global(
+        operator_actor(operator),
+        cause,
+        Action::new(
+            String::from("IssuePortalLink"),
+            Some(format!(
+                "Issued portal link to user {} ('{initials}')",
+                request.user_id
+            )),
+        ),
+        StateSnapshot::new(String::new()),
+        StateSnapshot::new(format!(
+            "user_id={},expires_at={expires_at}",
+            request.user_id
+        )),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(IssuePortalLinkResponse {
+        user_id: request.user_id,
+        message: format!("Issued portal link to '{initials}', valid until {expires_at}"),
+        initials,
+        portal_token: portal_token.expose().to_owned(),
+        expires_at,
+    })
+}
+
+/// Revokes a controller's portal link.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The user whose link is revoked
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin, the user does not exist,
+/// or the database operation fails.
+pub fn revoke_portal_link(
+    persistence: &mut SqlitePersistence,
+    request: &RevokePortalLinkRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<RevokePortalLinkResponse, ApiError> {
+    require_admin(authenticated_actor, "revoke_portal_link")?;
+    let (_, initials): (i64, String) = load_user(persistence, request.user_id)?;
+
+    let revoked: bool = persistence
+        .delete_portal_link(request.user_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to revoke portal link: {e}"),
+        })?;
+    if !revoked {
+        return Ok(RevokePortalLinkResponse {
+            user_id: request.user_id,
+            revoked,
+            message: format!("'{initials}' holds no portal link"),
+        });
+    }
+
+    let audit_event: AuditEvent = AuditEvent::new_global(
+        operator_actor(operator),
+        cause,
+        Action::new(
+            String::from("RevokePortalLink"),
+            Some(format!(
+                "Revoked portal link of user {} ('{initials}')",
+                request.user_id
+            )),
+        ),
+        StateSnapshot::new(format!("user_id={}", request.user_id)),
+        StateSnapshot::new(String::new()),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(RevokePortalLinkResponse {
+        user_id: request.user_id,
+        revoked,
+        message: format!("Revoked portal link of '{initials}'"),
+    })
+}
+
+/// Identifies the controller a portal link token was issued to.
+///
+/// # Errors
+///
+/// Returns `AuthenticationFailed` if the token is unknown or the link has
+/// expired, or an error if the database cannot be queried.
+pub fn authenticate_portal(
+    persistence: &mut SqlitePersistence,
+    portal_token: &SecretToken,
+) -> Result<PortalIdentity, ApiError> {
+    let link: PortalLinkData = persistence
+        .get_portal_link_by_token(portal_token)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to look up portal link: {e}"),
+        })?
+        .ok_or_else(|| ApiError::AuthenticationFailed {
+            reason: String::from("Invalid portal link"),
+        })?;
+
+    let expires_at: OffsetDateTime =
+        OffsetDateTime::parse(&link.expires_at, &Rfc3339).map_err(|e| ApiError::Internal {
+            message: format!("Invalid portal link expiry '{}': {e}", link.expires_at),
+        })?;
+    if persistence.now() >= expires_at {
+        return Err(ApiError::AuthenticationFailed {
+            reason: String::from("Portal link has expired"),
+        });
+    }
+
+    let (bid_year_id, initials): (i64, String) = load_user(persistence, link.user_id)?;
+    let area_id: i64 =
+        persistence
+            .get_user_area_id(link.user_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to fetch user area: {e}"),
+            })?;
+
+    Ok(PortalIdentity {
+        user_id: link.user_id,
+        initials,
+        bid_year_id,
+        area_id,
+    })
+}
+
+/// Gets the portal user's position in their area's bid order.
+///
+/// # Errors
+///
+/// Returns an error if the user's area no longer exists or the database
+/// cannot be queried.
+pub fn get_portal_seniority(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    identity: &PortalIdentity,
+) -> Result<GetPortalSeniorityResponse, ApiError> {
+    let (_, area) = resolve_area(metadata, identity.area_id)?;
+    let entries: Vec<SeniorityListEntryData> = persistence
+        .list_seniority_list(identity.bid_year_id, identity.area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list seniority: {e}"),
+        })?;
+    let bid_order: Option<u32> = entries
+        .iter()
+        .find(|entry| entry.user_id == identity.user_id)
+        .and_then(|entry| u32::try_from(entry.bid_order).ok());
+
+    Ok(GetPortalSeniorityResponse {
+        user_id: identity.user_id,
+        initials: identity.initials.clone(),
+        area_code: area.area_code().to_string(),
+        bid_order,
+        area_size: u32::try_from(entries.len()).unwrap_or(u32::MAX),
+    })
+}
+
+/// Lists the portal user's bid windows.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be queried.
+pub fn get_portal_windows(
+    persistence: &mut SqlitePersistence,
+    identity: &PortalIdentity,
+) -> Result<GetPortalWindowsResponse, ApiError> {
+    let windows: Vec<UserBidWindowData> = persistence
+        .list_user_bid_windows(identity.user_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list bid windows: {e}"),
+        })?;
+
+    Ok(GetPortalWindowsResponse {
+        user_id: identity.user_id,
+        windows: windows
+            .into_iter()
+            .map(|window| PortalWindowInfo {
+                round_id: window.round_id,
+                round_number: u32::try_from(window.round_number).unwrap_or_default(),
+                round_name: window.round_name,
+                window_start_datetime: window.window_start_datetime,
+                window_end_datetime: window.window_end_datetime,
+            })
+            .collect(),
+    })
+}
+
+/// Lists the portal user's submitted leave bids.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be queried.
+pub fn get_portal_bids(
+    persistence: &mut SqlitePersistence,
+    identity: &PortalIdentity,
+) -> Result<GetPortalBidsResponse, ApiError> {
+    let mut bids: Vec<LeaveBidData> = persistence
+        .list_leave_bids(identity.bid_year_id, identity.area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list leave bids: {e}"),
+        })?
+        .into_iter()
+        .filter(|bid| bid.user_id == identity.user_id)
+        .collect();
+    bids.sort_by(|a, b| (&a.leave_date, a.leave_bid_id).cmp(&(&b.leave_date, b.leave_bid_id)));
+
+    Ok(GetPortalBidsResponse {
+        user_id: identity.user_id,
+        bids: bids
+            .into_iter()
+            .map(|bid| PortalLeaveBidInfo {
+                leave_bid_id: bid.leave_bid_id,
+                round_id: bid.round_id,
+                leave_date: bid.leave_date,
+                hours: bid.hours,
+                status: bid.status,
+            })
+            .collect(),
+    })
+}
+
+/// Gets the portal user's projected year-end leave balance.
+///
+/// # Errors
+///
+/// Returns an error if the bid year or area no longer exists or the
+/// database cannot be queried.
+pub fn get_portal_balance(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    identity: &PortalIdentity,
+) -> Result<GetPortalBalanceResponse, ApiError> {
+    let projections: Vec<UserLeaveProjection> = project_leave_balances(
+        persistence,
+        metadata,
+        identity.bid_year_id,
+        Some(identity.area_id),
+    )?;
+
+    Ok(GetPortalBalanceResponse {
+        user_id: identity.user_id,
+        balance: projections
+            .iter()
+            .find(|projection| projection.user_id == identity.user_id)
+            .map(LeaveProjectionInfo::from),
+    })
+}
@@ -0,0 +1,389 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Prime date handlers.
+//!
+//! A bid year designates prime periods (spring break, Christmas week) on
+//! which leave is in high demand. Each round may cap its slots on prime
+//! dates separately from its normal `slots_per_day`. The caps are applied
+//! through the slot inventory, so bid validation and the coverage report
+//! both honour them.
+
+use time::Date;
+use time::format_description::well_known::Iso8601;
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{BidYearLifecycle, DomainError};
+use zab_bid_persistence::{
+    OperatorData, PersistenceError, PrimePeriodData, RoundPrimeCapData, SqlitePersistence,
+};
+
+use crate::auth::AuthenticatedActor;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_domain_error};
+use crate::leave_bids::{load_lifecycle_state, require_round};
+use crate::request_response::{
+    CreatePrimePeriodRequest, DeletePrimePeriodResponse, ListPrimeDatesResponse, PrimePeriodInfo,
+    PrimePeriodResponse, RoundPrimeCapInfo, SetRoundPrimeCapRequest, SetRoundPrimeCapResponse,
+};
+use crate::webhooks::require_admin;
+
+/// Converts a persisted prime period to its API representation.
+fn prime_period_info(data: PrimePeriodData) -> PrimePeriodInfo {
+    PrimePeriodInfo {
+        prime_period_id: data.prime_period_id,
+        label: data.label,
+        start_date: data.start_date,
+        end_date: data.end_date,
+    }
+}
+
+/// Parses a `YYYY-MM-DD` prime date.
+fn parse_prime_date(field: &str, value: &str) -> Result<Date, ApiError> {
+    Date::parse(value, &Iso8601::DEFAULT).map_err(|_| ApiError::InvalidInput {
+        field: field.to_string(),
+        message: format!("Invalid date format: {value}"),
+    })
+}
+
+/// Ensures a bid year's prime dates may still be changed.
+///
+/// Prime caps decide which leave is awarded, so they are fixed once
+/// bidding starts.
+fn require_prime_dates_editable(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    operation: &str,
+) -> Result<(), ApiError> {
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, bid_year_id)?;
+    if matches!(
+        lifecycle_state,
+        BidYearLifecycle::BiddingActive | BidYearLifecycle::BiddingClosed
+    ) {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: operation.to_string(),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+    Ok(())
+}
+
+/// Persists a global audit event for a prime date change.
+fn persist_prime_audit_event(
+    persistence: &mut SqlitePersistence,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+    action: Action,
+    before: String,
+    after: String,
+) -> Result<(), ApiError> {
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let audit_event: AuditEvent = AuditEvent::new_global(
+        actor,
+        cause,
+        action,
+        StateSnapshot::new(before),
+        StateSnapshot::new(after),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    Ok(())
+}
+
+/// Lists a bid year's prime periods and the rounds that cap them.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn list_prime_dates(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<ListPrimeDatesResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+
+    let prime_periods: Vec<PrimePeriodInfo> = persistence
+        .list_prime_periods(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list prime periods: {e}"),
+        })?
+        .into_iter()
+        .map(prime_period_info)
+        .collect();
+    let round_caps: Vec<RoundPrimeCapInfo> = persistence
+        .list_round_prime_caps(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list prime caps: {e}"),
+        })?
+        .into_iter()
+        .map(|cap| RoundPrimeCapInfo {
+            round_id: cap.round_id,
+            prime_slots_per_day: u32::try_from(cap.prime_slots_per_day).unwrap_or(0),
+        })
+        .collect();
+
+    Ok(ListPrimeDatesResponse {
+        bid_year_id,
+        prime_periods,
+        round_caps,
+    })
+}
+
+/// Designates a prime period for a bid year.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year, label, and date range
+/// * `authenticated_actor` - The authenticated actor designating the period
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist
+/// - Bidding has already started
+/// - The label is empty or the date range is invalid
+/// - The database operation fails
+pub fn create_prime_period(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &CreatePrimePeriodRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<PrimePeriodResponse, ApiError> {
+    require_admin(authenticated_actor, "create prime period")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    require_prime_dates_editable(persistence, request.bid_year_id, "create prime period")?;
+
+    let label: &str = request.label.trim();
+    if label.is_empty() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("label"),
+            message: String::from("Prime period label cannot be empty"),
+        });
+    }
+    let start_date: Date = parse_prime_date("start_date", &request.start_date)?;
+    let end_date: Date = parse_prime_date("end_date", &request.end_date)?;
+    if start_date > end_date {
+        return Err(ApiError::InvalidInput {
+            field: String::from("end_date"),
+            message: format!("End date {end_date} is before start date {start_date}"),
+        });
+    }
+
+    let start: String = start_date.to_string();
+    let end: String = end_date.to_string();
+    let prime_period_id: i64 = persistence
+        .insert_prime_period(request.bid_year_id, label, &start, &end)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to create prime period: {e}"),
+        })?;
+
+    persist_prime_audit_event(
+        persistence,
+        authenticated_actor,
+        operator,
+        cause,
+        Action::new(
+            String::from("CreatePrimePeriod"),
+            Some(format!(
+                "Designated {label} ({start} to {end}) as prime for bid year {year}"
+            )),
+        ),
+        String::from("null"),
+        format!("prime_period_id={prime_period_id},start_date={start},end_date={end}"),
+    )?;
+
+    Ok(PrimePeriodResponse {
+        bid_year_id: request.bid_year_id,
+        prime_period: PrimePeriodInfo {
+            prime_period_id,
+            label: label.to_string(),
+            start_date: start,
+            end_date: end,
+        },
+        message: format!("Prime period {label} created"),
+    })
+}
+
+/// Removes a prime period.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `prime_period_id` - The prime period to remove
+/// * `authenticated_actor` - The authenticated actor removing the period
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The prime period does not exist
+/// - Bidding has already started
+/// - The database operation fails
+pub fn delete_prime_period(
+    persistence: &mut SqlitePersistence,
+    prime_period_id: i64,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<DeletePrimePeriodResponse, ApiError> {
+    require_admin(authenticated_actor, "delete prime period")?;
+
+    let existing: PrimePeriodData =
+        persistence
+            .get_prime_period(prime_period_id)
+            .map_err(|e| match e {
+                PersistenceError::NotFound(_) => ApiError::ResourceNotFound {
+                    resource_type: String::from("PrimePeriod"),
+                    message: format!("Prime period {prime_period_id} not found"),
+                },
+                _ => ApiError::Internal {
+                    message: format!("Failed to get prime period: {e}"),
+                },
+            })?;
+    require_prime_dates_editable(persistence, existing.bid_year_id, "delete prime period")?;
+
+    persistence
+        .delete_prime_period(prime_period_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to delete prime period: {e}"),
+        })?;
+
+    persist_prime_audit_event(
+        persistence,
+        authenticated_actor,
+        operator,
+        cause,
+        Action::new(
+            String::from("DeletePrimePeriod"),
+            Some(format!(
+                "Removed prime period {} ({} to {})",
+                existing.label, existing.start_date, existing.end_date
+            )),
+        ),
+        format!(
+            "prime_period_id={prime_period_id},start_date={},end_date={}",
+            existing.start_date, existing.end_date
+        ),
+        String::from("null"),
+    )?;
+
+    Ok(DeletePrimePeriodResponse {
+        prime_period_id,
+        message: format!("Prime period {} deleted", existing.label),
+    })
+}
+
+/// Sets or clears a round's cap on prime dates.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year, round, and new cap
+/// * `authenticated_actor` - The authenticated actor setting the cap
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year or round does not exist
+/// - Bidding has already started
+/// - The database operation fails
+pub fn set_round_prime_cap(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetRoundPrimeCapRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetRoundPrimeCapResponse, ApiError> {
+    require_admin(authenticated_actor, "set round prime cap")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    require_prime_dates_editable(persistence, request.bid_year_id, "set round prime cap")?;
+    require_round(persistence, request.bid_year_id, request.round_id)?;
+
+    let prime_slots_per_day: Option<i32> = request
+        .prime_slots_per_day
+        .map(i32::try_from)
+        .transpose()
+        .map_err(|_| ApiError::InvalidInput {
+            field: String::from("prime_slots_per_day"),
+            message: String::from("Prime slots per day is out of range"),
+        })?;
+
+    let previous: Option<i32> = persistence
+        .list_round_prime_caps(request.bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list prime caps: {e}"),
+        })?
+        .into_iter()
+        .find(|cap: &RoundPrimeCapData| cap.round_id == request.round_id)
+        .map(|cap| cap.prime_slots_per_day);
+
+    persistence
+        .set_round_prime_cap(request.bid_year_id, request.round_id, prime_slots_per_day)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to set round prime cap: {e}"),
+        })?;
+
+    let describe = |cap: Option<i32>| {
+        cap.map_or_else(
+            || String::from("null"),
+            |cap| format!("round_id={},prime_slots_per_day={cap}", request.round_id),
+        )
+    };
+    let message: String = request.prime_slots_per_day.map_or_else(
+        || {
+            format!(
+                "Round {} now offers its normal slots on prime dates",
+                request.round_id
+            )
+        },
+        |cap| {
+            format!(
+                "Round {} now offers {cap} slot(s) per prime date",
+                request.round_id
+            )
+        },
+    );
+    persist_prime_audit_event(
+        persistence,
+        authenticated_actor,
+        operator,
+        cause,
+        Action::new(
+            String::from("SetRoundPrimeCap"),
+            Some(format!("{message} in bid year {year}")),
+        ),
+        describe(previous),
+        describe(prime_slots_per_day),
+    )?;
+
+    Ok(SetRoundPrimeCapResponse {
+        bid_year_id: request.bid_year_id,
+        round_id: request.round_id,
+        prime_slots_per_day: request.prime_slots_per_day,
+        message,
+    })
+}
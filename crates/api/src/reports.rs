@@ -0,0 +1,2028 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Seniority list and round results reports.
+//!
+//! Renders an area's seniority list as PDF or HTML for posting and
+//! distribution. Rows come from the canonical tables, so the printed order
+//! is exactly the bid order bidding uses, overrides included. Reports are
+//! only available once the bid year has been confirmed.
+//!
+//! Round results reports list every user's outcome for one closed round,
+//! with their leave balance, as PDF, CSV, or XLSX. Awarded leave is not yet
+//! recorded, so the remaining balance is earned leave less recorded usage,
+//! and no awarded slot columns are shown. Generating one is audited.
+//!
+//! Coverage reports count the controllers with approved leave on each day
+//! of a date range, per area, round, and crew, against each round's
+//! `slots_per_day`, and flag the days that are full.
+//!
+//! Use-or-lose reports list the controllers whose awarded leave still
+//! leaves them with a projected end-of-year balance above the carryover
+//! cap, and how many hours they stand to lose.
+//!
+//! Bid packets give each controller in an area the pages they need before
+//! bidding: their seniority standing, their bid windows in local time, the
+//! rules of each round, their projected leave balance, and a blank
+//! preference worksheet to fill in by hand. Like the seniority list, they
+//! are only available once the bid year has been confirmed.
+//!
+//! Admin activity reports count, per operator, the commands recorded in the
+//! audit log over a date range, the overrides issued and rollbacks
+//! performed among them, and those recorded after hours: before 07:00 or
+//! from 18:00 on weekdays, or at any time on a weekend, in the requested
+//! timezone. Report exports are not counted as commands.
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::fmt::Write;
+
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime, PrimitiveDateTime};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{
+    AccrualRates, Area, BidStatus, BidYear, BidYearLifecycle, CanonicalBidYear, DailySlots,
+    DomainError, LeaveBalanceProjection, LeaveUsage, Round, SlotInventory, User,
+    calculate_leave_accrual_with_rates, calculate_leave_availability, is_after_hours,
+    parse_timezone, utc_to_local,
+};
+use zab_bid_persistence::{
+    AuditEventHeader, AuditEventHeaderPage, AuditTimelineFilter, AuditTimelineScope,
+    DailyLeaveCountData, OperatorData, PersistenceError, RoundResultEntryData,
+    SeniorityListEntryData, SqlitePersistence, UserBidWindowData,
+};
+
+use crate::auth::{AuthenticatedActor, Role};
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_domain_error};
+use crate::facility::{load_accrual_rates, load_facility};
+use crate::leave_caps::{UserLeaveProjection, load_leave_cap_policy, project_leave_balances};
+use crate::pdf::{self, Font, PAGE_HEIGHT, PAGE_WIDTH, PdfPage};
+use crate::request_response::{
+    AdminActivitySummary, GetAdminActivityReportRequest, GetBidPacketsRequest,
+    GetCoverageReportRequest, GetRoundResultsReportRequest, GetSeniorityReportRequest,
+    GetUseOrLoseReportRequest, OperatorActivityInfo,
+};
+use crate::slot_inventory::load_slot_inventory;
+use crate::xlsx::{self, Cell};
+
+/// Maximum length of each header and footer customization.
+const MAX_CUSTOM_TEXT_LENGTH: usize = 200;
+
+/// Note printed when any position was set by an override.
+const OVERRIDE_NOTE: &str = "* Position set by override.";
+
+/// Seniority list column headings, in display order.
+const SENIORITY_COLUMNS: [&str; 8] = [
+    "#",
+    "Initials",
+    "Name",
+    "Cum. NATCA BU",
+    "NATCA BU",
+    "EOD/FAA",
+    "SCD",
+    "Lottery",
+];
+
+/// Left edge of each seniority list PDF column, in points.
+const SENIORITY_COLUMN_X: [f32; 8] = [36.0, 80.0, 140.0, 380.0, 460.0, 540.0, 620.0, 700.0];
+
+/// Round results column headings, in display order.
+const ROUND_RESULTS_COLUMNS: [&str; 8] = [
+    "#",
+    "Initials",
+    "Name",
+    "Window Start",
+    "Window End",
+    "Status",
+    "Earned Hrs",
+    "Remaining Hrs",
+];
+
+/// Left edge of each round results PDF column, in points.
+const ROUND_RESULTS_COLUMN_X: [f32; 8] = [36.0, 70.0, 120.0, 300.0, 400.0, 500.0, 630.0, 700.0];
+
+/// Column headings of the coverage report.
+const COVERAGE_COLUMNS: [&str; 7] = [
+    "Date", "Area", "Round", "On Leave", "Cap", "By Crew", "Status",
+];
+
+/// Left edge of each coverage PDF column, in points.
+const COVERAGE_COLUMN_X: [f32; 7] = [36.0, 110.0, 190.0, 330.0, 400.0, 450.0, 700.0];
+
+/// Column headings of the use-or-lose report.
+const USE_OR_LOSE_COLUMNS: [&str; 8] = [
+    "Area",
+    "Initials",
+    "Name",
+    "Carried In",
+    "Accrued",
+    "Awarded",
+    "Projected",
+    "Use or Lose",
+];
+
+/// Left edge of each use-or-lose PDF column, in points.
+const USE_OR_LOSE_COLUMN_X: [f32; 8] = [36.0, 100.0, 160.0, 360.0, 440.0, 520.0, 600.0, 680.0];
+
+/// Column headings of the admin activity report.
+const ADMIN_ACTIVITY_COLUMNS: [&str; 6] = [
+    "Operator",
+    "ID",
+    "Commands",
+    "Overrides",
+    "Rollbacks",
+    "After Hours",
+];
+
+/// Left edge of each admin activity PDF column, in points.
+const ADMIN_ACTIVITY_COLUMN_X: [f32; 6] = [36.0, 240.0, 320.0, 420.0, 520.0, 620.0];
+
+/// Column headings of the bid packet preference worksheet.
+const WORKSHEET_COLUMNS: [&str; 5] = ["Choice", "Start Date", "End Date", "Hours", "Notes"];
+
+/// Left edge of each preference worksheet column, in points.
+const WORKSHEET_COLUMN_X: [f32; 5] = [36.0, 90.0, 210.0, 330.0, 400.0];
+
+/// Blank lines on the preference worksheet.
+const WORKSHEET_ROWS: u8 = 18;
+
+/// Height of each preference worksheet line, in points.
+const WORKSHEET_ROW_HEIGHT: f32 = 24.0;
+
+/// Audited actions that read data rather than change it.
+const READ_ONLY_ACTIONS: [&str; 1] = ["GenerateRoundResultsReport"];
+
+/// Audit event headers read per query while summarizing activity.
+const ACTIVITY_PAGE_SIZE: u32 = 500;
+
+/// Longest date range an admin activity report may span, in days.
+const MAX_ACTIVITY_DAYS: i64 = 366;
+
+/// Longest date range a coverage report may span, in days.
+const MAX_COVERAGE_DAYS: i64 = 366;
+
+/// Page margin, in points.
+const PDF_MARGIN: f32 = 36.0;
+
+/// Row height, in points.
+const PDF_ROW_HEIGHT: f32 = 13.0;
+
+/// Body font size, in points.
+const PDF_FONT_SIZE: f32 = 9.0;
+
+/// Lowest baseline a table row may use before the footer.
+const PDF_TABLE_BOTTOM: f32 = 56.0;
+
+/// Output formats for reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Portable Document Format.
+    Pdf,
+    /// A standalone HTML page.
+    Html,
+    /// Comma-separated values.
+    Csv,
+    /// An Excel workbook.
+    Xlsx,
+}
+
+impl ReportFormat {
+    /// Parses a format name, accepting only the `supported` formats.
+    ///
+    /// Matching is case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError::InvalidInput` for any other value.
+    pub fn parse(value: &str, supported: &[Self]) -> Result<Self, ApiError> {
+        supported
+            .iter()
+            .copied()
+            .find(|format| value.eq_ignore_ascii_case(format.extension()))
+            .ok_or_else(|| ApiError::InvalidInput {
+                field: String::from("format"),
+                message: format!(
+                    "Unsupported report format '{value}' (expected {})",
+                    supported
+                        .iter()
+                        .map(|format| format.extension())
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                ),
+            })
+    }
+
+    /// Returns the MIME type of the rendered document.
+    #[must_use]
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Pdf => "application/pdf",
+            Self::Html => "text/html; charset=utf-8",
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+
+    /// Returns the file extension of the rendered document.
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Pdf => "pdf",
+            Self::Html => "html",
+            Self::Csv => "csv",
+            Self::Xlsx => "xlsx",
+        }
+    }
+}
+
+/// A rendered report document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedReport {
+    /// The MIME type of `body`.
+    pub content_type: &'static str,
+    /// Suggested download file name.
+    pub filename: String,
+    /// The document bytes.
+    pub body: Vec<u8>,
+}
+
+/// A titled table laid out for printing.
+struct TableDocument<'a> {
+    title: String,
+    facility_name: Option<String>,
+    header_text: Option<String>,
+    footer_text: Option<String>,
+    columns: &'a [&'a str],
+    column_x: &'a [f32],
+    rows: Vec<Vec<String>>,
+    /// Lines printed after the table.
+    notes: Vec<String>,
+}
+
+/// Returns the display cells of one seniority list row.
+fn seniority_row_cells(entry: &SeniorityListEntryData) -> Vec<String> {
+    let marker: &str = if entry.is_overridden { "*" } else { "" };
+    vec![
+        format!("{}{marker}", entry.bid_order),
+        entry.initials.clone(),
+        entry.name.clone(),
+        entry.cumulative_natca_bu_date.clone(),
+        entry.natca_bu_date.clone(),
+        entry.eod_faa_date.clone(),
+        entry.service_computation_date.clone(),
+        entry
+            .lottery_value
+            .map_or_else(String::new, |value| value.to_string()),
+    ]
+}
+
+/// Validates an optional header or footer customization.
+///
+/// Surrounding whitespace is trimmed and blank values are treated as absent.
+fn validate_custom_text(field: &str, value: Option<&str>) -> Result<Option<String>, ApiError> {
+    let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    if value.chars().count() > MAX_CUSTOM_TEXT_LENGTH {
+        return Err(ApiError::InvalidInput {
+            field: field.to_string(),
+            message: format!("Must be at most {MAX_CUSTOM_TEXT_LENGTH} characters"),
+        });
+    }
+    if value.chars().any(char::is_control) {
+        return Err(ApiError::InvalidInput {
+            field: field.to_string(),
+            message: String::from("Must be a single line of text"),
+        });
+    }
+    Ok(Some(value.to_string()))
+}
+
+/// Escapes text for inclusion in HTML content or attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped: String = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Renders the document as a standalone HTML page.
+fn render_html(report: &TableDocument) -> String {
+    let mut html: String =
+        String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    // Writing to a String cannot fail
+    let _ = writeln!(html, "<title>{}</title>", escape_html(&report.title));
+    html.push_str(
+        "<style>\n\
+         @page { size: letter landscape; margin: 0.5in; }\n\
+         body { font-family: Helvetica, Arial, sans-serif; font-size: 10pt; }\n\
+         .facility { font-size: 14pt; font-weight: bold; margin: 0; }\n\
+         h1 { font-size: 12pt; margin: 4pt 0; }\n\
+         table { border-collapse: collapse; width: 100%; }\n\
+         thead { display: table-header-group; }\n\
+         th, td { text-align: left; padding: 2pt 6pt; border-bottom: 0.5pt solid #999; }\n\
+         footer { margin-top: 12pt; font-size: 9pt; }\n\
+         </style>\n</head>\n<body>\n<header>\n",
+    );
+    if let Some(facility) = &report.facility_name {
+        let _ = writeln!(html, "<p class=\"facility\">{}</p>", escape_html(facility));
+    }
+    let _ = writeln!(html, "<h1>{}</h1>", escape_html(&report.title));
+    if let Some(header) = &report.header_text {
+        let _ = writeln!(html, "<p>{}</p>", escape_html(header));
+    }
+    html.push_str("</header>\n<table>\n<thead>\n<tr>");
+    for column in report.columns {
+        let _ = write!(html, "<th>{}</th>", escape_html(column));
+    }
+    html.push_str("</tr>\n</thead>\n<tbody>\n");
+    for row in &report.rows {
+        html.push_str("<tr>");
+        for cell in row {
+            let _ = write!(html, "<td>{}</td>", escape_html(cell));
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</tbody>\n</table>\n");
+    for note in &report.notes {
+        let _ = writeln!(html, "<p>{}</p>", escape_html(note));
+    }
+    if let Some(footer) = &report.footer_text {
+        let _ = writeln!(html, "<footer>{}</footer>", escape_html(footer));
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Draws the page header and column headings; returns the first row baseline.
+fn draw_pdf_header(page: &mut PdfPage, report: &TableDocument) -> f32 {
+    let mut y: f32 = PAGE_HEIGHT - PDF_MARGIN;
+    if let Some(facility) = &report.facility_name {
+        y -= 14.0;
+        page.text(Font::Bold, 14.0, PDF_MARGIN, y, facility);
+        y -= 6.0;
+    }
+    y -= 12.0;
+    page.text(Font::Bold, 12.0, PDF_MARGIN, y, &report.title);
+    y -= 4.0;
+    if let Some(header) = &report.header_text {
+        y -= 11.0;
+        page.text(Font::Regular, PDF_FONT_SIZE, PDF_MARGIN, y, header);
+    }
+    y -= 20.0;
+    for (column, x) in report.columns.iter().zip(report.column_x) {
+        page.text(Font::Bold, PDF_FONT_SIZE, *x, y, column);
+    }
+    y -= 4.0;
+    page.line(PDF_MARGIN, y, PAGE_WIDTH - PDF_MARGIN, y);
+    y - PDF_ROW_HEIGHT
+}
+
+/// Renders the document as a paginated PDF.
+///
+/// The header and column headings repeat on every page; the footer text and
+/// page numbers are drawn at the bottom of each page.
+fn render_pdf(report: &TableDocument) -> Vec<u8> {
+    let mut pages: Vec<PdfPage> = Vec::new();
+    let mut page: PdfPage = PdfPage::new();
+    let mut y: f32 = draw_pdf_header(&mut page, report);
+
+    for row in &report.rows {
+        if y < PDF_TABLE_BOTTOM {
+            pages.push(page);
+            page = PdfPage::new();
+            y = draw_pdf_header(&mut page, report);
+        }
+        for (i, (cell, x)) in row.iter().zip(report.column_x).enumerate() {
+            let right: f32 = report
+                .column_x
+                .get(i + 1)
+                .copied()
+                .unwrap_or(PAGE_WIDTH - PDF_MARGIN);
+            let text: String = pdf::fit(cell, PDF_FONT_SIZE, right - *x - 6.0);
+            page.text(Font::Regular, PDF_FONT_SIZE, *x, y, &text);
+        }
+        y -= PDF_ROW_HEIGHT;
+    }
+    for note in &report.notes {
+        if y < PDF_TABLE_BOTTOM {
+            pages.push(page);
+            page = PdfPage::new();
+            y = draw_pdf_header(&mut page, report);
+        }
+        page.text(Font::Regular, PDF_FONT_SIZE, PDF_MARGIN, y - 4.0, note);
+        y -= PDF_ROW_HEIGHT;
+    }
+    pages.push(page);
+
+    let page_count: usize = pages.len();
+    for (i, page) in pages.iter_mut().enumerate() {
+        if let Some(footer) = &report.footer_text {
+            let width: f32 = PAGE_WIDTH - PDF_MARGIN - PDF_MARGIN - 80.0;
+            page.text(
+                Font::Regular,
+                8.0,
+                PDF_MARGIN,
+                24.0,
+                &pdf::fit(footer, 8.0, width),
+            );
+        }
+        page.text_right(
+            Font::Regular,
+            8.0,
+            PAGE_WIDTH - PDF_MARGIN,
+            24.0,
+            &format!("Page {} of {page_count}", i + 1),
+        );
+    }
+
+    pdf::render(&report.title, &pages)
+}
+
+/// Returns `area_code` reduced to characters safe in a file name.
+fn filename_component(area_code: &str) -> String {
+    area_code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+        .collect()
+}
+
+/// Resolves the bid year and non-system area a report is for.
+fn find_report_area(
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+    area_id: i64,
+) -> Result<(&BidYear, &Area), ApiError> {
+    let bid_year: &BidYear = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.bid_year_id() == Some(bid_year_id))
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("BidYear"),
+            message: format!("Bid year with ID {bid_year_id} not found"),
+        })?;
+    let year: u16 = bid_year.year();
+
+    let area: &Area = metadata
+        .areas
+        .iter()
+        .find(|(by, a)| by.year() == year && a.area_id() == Some(area_id))
+        .map(|(_, a)| a)
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("Area"),
+            message: format!("Area with ID {area_id} not found in bid year {year}"),
+        })?;
+    if area.is_system_area() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("area_id"),
+            message: format!(
+                "Reports are not available for system area '{}'",
+                area.area_code()
+            ),
+        });
+    }
+
+    Ok((bid_year, area))
+}
+
+/// Loads a bid year's lifecycle state.
+fn load_lifecycle_state(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<BidYearLifecycle, ApiError> {
+    persistence
+        .get_lifecycle_state(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get lifecycle state: {e}"),
+        })
+        .and_then(|s| {
+            s.parse::<BidYearLifecycle>()
+                .map_err(translate_domain_error)
+        })
+}
+
+/// Renders an area's canonical seniority list.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The report request
+///
+/// # Returns
+///
+/// The rendered document with its content type and a suggested file name.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The format or a customization is invalid
+/// - The bid year or area does not exist, or the area is a system area
+/// - The bid year has not been confirmed
+/// - The database cannot be queried
+pub fn get_seniority_report(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &GetSeniorityReportRequest,
+) -> Result<RenderedReport, ApiError> {
+    let format: ReportFormat =
+        ReportFormat::parse(&request.format, &[ReportFormat::Pdf, ReportFormat::Html])?;
+    let facility_name: Option<String> =
+        validate_custom_text("facility_name", request.facility_name.as_deref())?;
+    let header_text: Option<String> =
+        validate_custom_text("header_text", request.header_text.as_deref())?;
+    let footer_text: Option<String> =
+        validate_custom_text("footer_text", request.footer_text.as_deref())?;
+
+    let (bid_year, area) = find_report_area(metadata, request.bid_year_id, request.area_id)?;
+    let year: u16 = bid_year.year();
+
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    if !lifecycle_state.is_locked() {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("seniority_report_requires_confirmation"),
+            message: format!(
+                "The seniority list for {year} is available once the bid year is confirmed (currently {lifecycle_state})"
+            ),
+        });
+    }
+
+    let entries: Vec<SeniorityListEntryData> = persistence
+        .list_seniority_list(request.bid_year_id, request.area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load seniority list: {e}"),
+        })?;
+
+    let area_label: &str = area.area_name().unwrap_or_else(|| area.area_code());
+    let notes: Vec<String> = if entries.iter().any(|entry| entry.is_overridden) {
+        vec![String::from(OVERRIDE_NOTE)]
+    } else {
+        Vec::new()
+    };
+    let report: TableDocument = TableDocument {
+        title: format!("{year} Seniority List - {area_label}"),
+        facility_name,
+        header_text,
+        footer_text,
+        columns: &SENIORITY_COLUMNS,
+        column_x: &SENIORITY_COLUMN_X,
+        rows: entries.iter().map(seniority_row_cells).collect(),
+        notes,
+    };
+
+    let body: Vec<u8> = match format {
+        ReportFormat::Html => render_html(&report).into_bytes(),
+        // `parse` only accepts PDF and HTML for seniority lists.
+        ReportFormat::Pdf | ReportFormat::Csv | ReportFormat::Xlsx => render_pdf(&report),
+    };
+
+    Ok(RenderedReport {
+        content_type: format.content_type(),
+        filename: format!(
+            "seniority-{year}-{}.{}",
+            filename_component(area.area_code()),
+            format.extension()
+        ),
+        body,
+    })
+}
+
+/// One user's line in a round results report.
+struct RoundResultRow {
+    entry: RoundResultEntryData,
+    /// `None` when the stored status is not a known `BidStatus`.
+    status: Option<BidStatus>,
+    earned_hours: Option<u16>,
+    remaining_hours: Option<i32>,
+}
+
+/// Returns the printed label for a user's status in a closed round.
+///
+/// Users who missed or declined their window were skipped; users who
+/// completed after their window closed were deferred.
+fn status_label(row: &RoundResultRow) -> String {
+    match (row.status, row.entry.status.as_deref()) {
+        (_, None) => String::from("No status"),
+        (Some(BidStatus::NotStartedPreWindow | BidStatus::NotStartedInWindow), _) => {
+            String::from("Not started")
+        }
+        (Some(BidStatus::InProgress), _) => String::from("In progress"),
+        (Some(BidStatus::CompletedOnTime), _) => String::from("Completed"),
+        (Some(BidStatus::CompletedLate), _) => String::from("Deferred (completed late)"),
+        (Some(BidStatus::Missed), _) => String::from("Skipped (missed)"),
+        (Some(BidStatus::VoluntarilyNotBidding), _) => String::from("Skipped (declined)"),
+        (Some(BidStatus::Proxy), _) => String::from("Completed by proxy"),
+        (None, Some(raw)) => raw.to_string(),
+    }
+}
+
+/// Formats an RFC 3339 window bound for print, falling back to the raw value.
+fn display_datetime(value: Option<&str>) -> String {
+    let Some(value) = value else {
+        return String::new();
+    };
+    OffsetDateTime::parse(value, &Rfc3339)
+        .ok()
+        .and_then(|datetime| {
+            datetime
+                .format(format_description!("[year]-[month]-[day] [hour]:[minute]"))
+                .ok()
+        })
+        .unwrap_or_else(|| value.to_string())
+}
+
+/// Returns the summary line printed below the round results table.
+fn round_results_summary(rows: &[RoundResultRow]) -> String {
+    let count = |statuses: &[BidStatus]| -> usize {
+        rows.iter()
+            .filter(|row| row.status.is_some_and(|status| statuses.contains(&status)))
+            .count()
+    };
+    let completed: usize = count(&[BidStatus::CompletedOnTime, BidStatus::Proxy]);
+    let deferred: usize = count(&[BidStatus::CompletedLate]);
+    let skipped: usize = count(&[BidStatus::Missed, BidStatus::VoluntarilyNotBidding]);
+    format!(
+        "Completed: {completed}   Deferred: {deferred}   Skipped: {skipped}   Other: {}",
+        rows.len() - completed - deferred - skipped
+    )
+}
+
+/// Renders round results as CSV.
+///
+/// Window bounds are written as stored (RFC 3339) and statuses as their
+/// API codes, so the file can be joined with other exports.
+fn render_round_results_csv(rows: &[RoundResultRow]) -> Result<Vec<u8>, ApiError> {
+    let csv_error = |e: csv::Error| ApiError::Internal {
+        message: format!("Failed to write CSV report: {e}"),
+    };
+    let mut writer: csv::Writer<Vec<u8>> = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record([
+            "bid_order",
+            "initials",
+            "name",
+            "window_start",
+            "window_end",
+            "status",
+            "status_label",
+            "earned_hours",
+            "remaining_hours",
+        ])
+        .map_err(csv_error)?;
+    for row in rows {
+        writer
+            .write_record([
+                row.entry.bid_order.to_string(),
+                row.entry.initials.clone(),
+                row.entry.name.clone(),
+                row.entry.window_start_datetime.clone().unwrap_or_default(),
+                row.entry.window_end_datetime.clone().unwrap_or_default(),
+                row.entry.status.clone().unwrap_or_default(),
+                status_label(row),
+                row.earned_hours
+                    .map_or_else(String::new, |hours| hours.to_string()),
+                row.remaining_hours
+                    .map_or_else(String::new, |hours| hours.to_string()),
+            ])
+            .map_err(csv_error)?;
+    }
+    writer.into_inner().map_err(|e| ApiError::Internal {
+        message: format!("Failed to write CSV report: {e}"),
+    })
+}
+
+/// Renders round results as a single-sheet XLSX workbook.
+fn render_round_results_xlsx(sheet: &str, rows: &[RoundResultRow]) -> Vec<u8> {
+    let optional_text =
+        |value: Option<&String>| value.map_or(Cell::Empty, |text| Cell::Text(text.clone()));
+    let cells: Vec<Vec<Cell>> = rows
+        .iter()
+        .map(|row| {
+            vec![
+                Cell::Number(i64::from(row.entry.bid_order)),
+                Cell::Text(row.entry.initials.clone()),
+                Cell::Text(row.entry.name.clone()),
+                optional_text(row.entry.window_start_datetime.as_ref()),
+                optional_text(row.entry.window_end_datetime.as_ref()),
+                Cell::Text(status_label(row)),
+                row.earned_hours
+                    .map_or(Cell::Empty, |hours| Cell::Number(i64::from(hours))),
+                row.remaining_hours
+                    .map_or(Cell::Empty, |hours| Cell::Number(i64::from(hours))),
+            ]
+        })
+        .collect();
+    xlsx::render(sheet, &ROUND_RESULTS_COLUMNS, &cells)
+}
+
+/// Returns whether bidding in a round has finished for an area.
+///
+/// A round is closed once the bid year's bidding has closed, or while
+/// bidding is active once every window scheduled for the round has ended.
+fn round_is_closed(
+    lifecycle_state: BidYearLifecycle,
+    entries: &[RoundResultEntryData],
+    now: OffsetDateTime,
+) -> bool {
+    match lifecycle_state {
+        BidYearLifecycle::BiddingClosed => true,
+        BidYearLifecycle::BiddingActive => {
+            let mut window_ends = entries
+                .iter()
+                .filter_map(|entry| entry.window_end_datetime.as_deref())
+                .peekable();
+            window_ends.peek().is_some()
+                && window_ends
+                    .all(|end| OffsetDateTime::parse(end, &Rfc3339).is_ok_and(|end| end <= now))
+        }
+        _ => false,
+    }
+}
+
+/// Computes each user's earned and remaining leave hours, keyed by user ID.
+///
+/// Accrual uses the facility's accrual rates. Users excluded from leave
+/// calculation, or whose accrual cannot be computed, are omitted. No leave
+/// usage is persisted yet, so the remaining balance equals the earned
+/// balance.
+fn leave_balances(
+    users: &[User],
+    canonical_bid_year: &CanonicalBidYear,
+    rates: AccrualRates,
+) -> Vec<(i64, u16, i32)> {
+    users
+        .iter()
+        .filter(|user| !user.excluded_from_leave_calculation)
+        .filter_map(|user| {
+            let user_id: i64 = user.user_id?;
+            let accrual =
+                calculate_leave_accrual_with_rates(user, canonical_bid_year, rates).ok()?;
+            let availability =
+                calculate_leave_availability(&accrual, std::iter::empty::<LeaveUsage>()).ok()?;
+            Some((
+                user_id,
+                availability.earned_hours,
+                availability.remaining_hours,
+            ))
+        })
+        .collect()
+}
+
+/// Renders the results of one closed round for an area and records an
+/// audit event for the export.
+///
+/// Lists every user on the area's seniority list, in bid order, with their
+/// bid window, their status for the round (skipped and deferred users are
+/// labelled as such), and their earned and remaining leave hours.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The report request
+/// * `authenticated_actor` - The authenticated actor generating the report
+/// * `operator` - The operator data for the authenticated actor
+///
+/// # Returns
+///
+/// The rendered document with its content type and a suggested file name.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The format is invalid
+/// - The bid year, area, or round does not exist, the area is a system
+///   area, or the round belongs to another bid year
+/// - Bidding in the round has not closed
+/// - The database cannot be queried or the audit event cannot be recorded
+#[allow(clippy::too_many_lines)]
+pub fn get_round_results_report(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &GetRoundResultsReportRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<RenderedReport, ApiError> {
+    let format: ReportFormat = ReportFormat::parse(
+        &request.format,
+        &[ReportFormat::Pdf, ReportFormat::Csv, ReportFormat::Xlsx],
+    )?;
+
+    let (bid_year, area) = find_report_area(metadata, request.bid_year_id, request.area_id)?;
+    let year: u16 = bid_year.year();
+
+    let round: Round = persistence
+        .get_round(request.round_id)
+        .map_err(|e| match e {
+            PersistenceError::NotFound(_) => translate_domain_error(DomainError::RoundNotFound {
+                round_id: request.round_id,
+            }),
+            _ => ApiError::Internal {
+                message: format!("Failed to get round: {e}"),
+            },
+        })?;
+    let bid_year_rounds: Vec<(i64, String)> = persistence
+        .list_all_rounds_for_bid_year(request.bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list rounds for bid year {year}: {e}"),
+        })?;
+    if !bid_year_rounds
+        .iter()
+        .any(|(round_id, _)| *round_id == request.round_id)
+    {
+        return Err(ApiError::InvalidInput {
+            field: String::from("round_id"),
+            message: format!("Round '{}' is not part of bid year {year}", round.name()),
+        });
+    }
+
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    let entries: Vec<RoundResultEntryData> = persistence
+        .list_round_results(request.bid_year_id, request.area_id, request.round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load round results: {e}"),
+        })?;
+    if !round_is_closed(lifecycle_state, &entries, persistence.now()) {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("round_results_require_closed_round"),
+            message: format!(
+                "Results for '{}' in area '{}' are available once every bid window in the round has closed",
+                round.name(),
+                area.area_code()
+            ),
+        });
+    }
+
+    let canonical_bid_year: CanonicalBidYear = persistence
+        .list_bid_years()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load bid years: {e}"),
+        })?
+        .into_iter()
+        .find(|by| by.year() == year)
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Bid year {year} exists in metadata but not in storage"),
+        })?;
+    let users: Vec<User> = persistence
+        .list_users_with_routing(request.bid_year_id, request.area_id, bid_year, area)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load users: {e}"),
+        })?;
+    let rates: AccrualRates = load_accrual_rates(persistence)?;
+    let balances: Vec<(i64, u16, i32)> = leave_balances(&users, &canonical_bid_year, rates);
+
+    let rows: Vec<RoundResultRow> = entries
+        .into_iter()
+        .map(|entry| {
+            let balance: Option<&(i64, u16, i32)> = balances
+                .iter()
+                .find(|(user_id, _, _)| *user_id == entry.user_id);
+            RoundResultRow {
+                status: entry
+                    .status
+                    .as_deref()
+                    .and_then(|status| status.parse::<BidStatus>().ok()),
+                earned_hours: balance.map(|(_, earned, _)| *earned),
+                remaining_hours: balance.map(|(_, _, remaining)| *remaining),
+                entry,
+            }
+        })
+        .collect();
+
+    let area_label: &str = area.area_name().unwrap_or_else(|| area.area_code());
+    let body: Vec<u8> = match format {
+        ReportFormat::Csv => render_round_results_csv(&rows)?,
+        ReportFormat::Xlsx => render_round_results_xlsx(round.name(), &rows),
+        // `parse` only accepts PDF, CSV, and XLSX for round results.
+        ReportFormat::Pdf | ReportFormat::Html => render_pdf(&TableDocument {
+            title: format!("{year} {} Results - {area_label}", round.name()),
+            facility_name: None,
+            header_text: None,
+            footer_text: None,
+            columns: &ROUND_RESULTS_COLUMNS,
+            column_x: &ROUND_RESULTS_COLUMN_X,
+            rows: rows
+                .iter()
+                .map(|row| {
+                    vec![
+                        row.entry.bid_order.to_string(),
+                        row.entry.initials.clone(),
+                        row.entry.name.clone(),
+                        display_datetime(row.entry.window_start_datetime.as_deref()),
+                        display_datetime(row.entry.window_end_datetime.as_deref()),
+                        status_label(row),
+                        row.earned_hours
+                            .map_or_else(String::new, |hours| hours.to_string()),
+                        row.remaining_hours
+                            .map_or_else(String::new, |hours| hours.to_string()),
+                    ]
+                })
+                .collect(),
+            notes: vec![round_results_summary(&rows)],
+        }),
+    };
+
+    let audit_event: AuditEvent = AuditEvent::new(
+        authenticated_actor.to_audit_actor(operator),
+        Cause::new(
+            String::from("operator_action"),
+            String::from("Round results report export"),
+        ),
+        Action::new(
+            String::from("GenerateRoundResultsReport"),
+            Some(format!(
+                "Generated {} results report for '{}' in area '{}' ({} users)",
+                format.extension().to_ascii_uppercase(),
+                round.name(),
+                area.area_code(),
+                rows.len()
+            )),
+        ),
+        StateSnapshot::new(format!("lifecycle_state={lifecycle_state}")),
+        StateSnapshot::new(format!("lifecycle_state={lifecycle_state}")),
+        bid_year.clone(),
+        area.clone(),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(RenderedReport {
+        content_type: format.content_type(),
+        filename: format!(
+            "round-results-{year}-{}-round-{}.{}",
+            filename_component(area.area_code()),
+            round.round_number(),
+            format.extension()
+        ),
+        body,
+    })
+}
+
+/// One area's approved leave on one date within one round.
+struct CoverageRow {
+    leave_date: Date,
+    area_code: String,
+    round_id: i64,
+    round_number: u32,
+    round_name: String,
+    /// The day's slot inventory capacity.
+    capacity: u32,
+    /// Whether the day is a prime date.
+    prime: bool,
+    approved: i64,
+    /// Approved leave per crew, users without a crew first.
+    crews: Vec<(Option<i32>, i64)>,
+}
+
+impl CoverageRow {
+    /// Returns whether every slot in the day's inventory is taken.
+    fn is_exhausted(&self) -> bool {
+        self.approved >= i64::from(self.capacity)
+    }
+
+    /// Returns the status shown in rendered reports.
+    fn status(&self) -> String {
+        let mut flags: Vec<&str> = Vec::new();
+        if self.prime {
+            flags.push("PRIME");
+        }
+        if self.is_exhausted() {
+            flags.push("FULL");
+        }
+        flags.join(" ")
+    }
+
+    /// Returns the per-crew counts as `crew=count` pairs.
+    fn crew_summary(&self, separator: &str) -> String {
+        self.crews
+            .iter()
+            .map(|(crew, approved)| {
+                crew.map_or_else(
+                    || format!("none={approved}"),
+                    |crew| format!("{crew}={approved}"),
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(separator)
+    }
+}
+
+/// Parses a `YYYY-MM-DD` report date.
+fn parse_report_date(field: &str, value: &str) -> Result<Date, ApiError> {
+    Date::parse(value, format_description!("[year]-[month]-[day]")).map_err(|e| {
+        ApiError::InvalidInput {
+            field: field.to_string(),
+            message: format!("Invalid date '{value}': {e}"),
+        }
+    })
+}
+
+/// Writes coverage rows as CSV, one line per date, area, and round.
+fn render_coverage_csv(rows: &[CoverageRow]) -> Result<Vec<u8>, ApiError> {
+    let csv_error = |e: csv::Error| ApiError::Internal {
+        message: format!("Failed to write CSV report: {e}"),
+    };
+    let mut writer: csv::Writer<Vec<u8>> = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record([
+            "date",
+            "area_code",
+            "round_number",
+            "round_name",
+            "approved",
+            "capacity",
+            "prime",
+            "exhausted",
+            "crew_counts",
+        ])
+        .map_err(csv_error)?;
+    for row in rows {
+        writer
+            .write_record([
+                row.leave_date.to_string(),
+                row.area_code.clone(),
+                row.round_number.to_string(),
+                row.round_name.clone(),
+                row.approved.to_string(),
+                row.capacity.to_string(),
+                row.prime.to_string(),
+                row.is_exhausted().to_string(),
+                row.crew_summary(";"),
+            ])
+            .map_err(csv_error)?;
+    }
+    writer.into_inner().map_err(|e| ApiError::Internal {
+        message: format!("Failed to write CSV report: {e}"),
+    })
+}
+
+/// Renders daily leave coverage over a date range.
+///
+/// Each row counts the controllers holding approved leave on one date in
+/// one area and round, broken down by crew, against the day's capacity in
+/// the slot inventory. Rows on prime dates are flagged as prime, and rows
+/// whose slots are all taken are flagged as full. Dates on which an area has no approved leave are omitted.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The report request
+///
+/// # Returns
+///
+/// The rendered document with its content type and a suggested file name.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The format or date range is invalid
+/// - The bid year or area does not exist, or the area is a system area
+/// - The database cannot be queried
+#[allow(clippy::too_many_lines)]
+pub fn get_coverage_report(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &GetCoverageReportRequest,
+) -> Result<RenderedReport, ApiError> {
+    let format: ReportFormat = ReportFormat::parse(
+        &request.format,
+        &[ReportFormat::Pdf, ReportFormat::Html, ReportFormat::Csv],
+    )?;
+    let start_date: Date = parse_report_date("start_date", &request.start_date)?;
+    let end_date: Date = parse_report_date("end_date", &request.end_date)?;
+    if start_date > end_date {
+        return Err(ApiError::InvalidInput {
+            field: String::from("end_date"),
+            message: format!("End date {end_date} is before start date {start_date}"),
+        });
+    }
+    if (end_date - start_date).whole_days() >= MAX_COVERAGE_DAYS {
+        return Err(ApiError::InvalidInput {
+            field: String::from("end_date"),
+            message: format!("Coverage reports span at most {MAX_COVERAGE_DAYS} days"),
+        });
+    }
+
+    let (bid_year, areas): (&BidYear, Vec<&Area>) = if let Some(area_id) = request.area_id {
+        let (bid_year, area) = find_report_area(metadata, request.bid_year_id, area_id)?;
+        (bid_year, vec![area])
+    } else {
+        let bid_year: &BidYear = metadata
+            .bid_years
+            .iter()
+            .find(|by| by.bid_year_id() == Some(request.bid_year_id))
+            .ok_or_else(|| ApiError::ResourceNotFound {
+                resource_type: String::from("BidYear"),
+                message: format!("Bid year with ID {} not found", request.bid_year_id),
+            })?;
+        let areas: Vec<&Area> = metadata
+            .areas
+            .iter()
+            .filter(|(by, a)| by.year() == bid_year.year() && !a.is_system_area())
+            .map(|(_, a)| a)
+            .collect();
+        (bid_year, areas)
+    };
+    let year: u16 = bid_year.year();
+
+    let counts: Vec<DailyLeaveCountData> = persistence
+        .list_daily_leave_counts(
+            request.bid_year_id,
+            &start_date.to_string(),
+            &end_date.to_string(),
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load leave counts: {e}"),
+        })?;
+
+    let inventory: SlotInventory =
+        load_slot_inventory(persistence, request.bid_year_id, start_date, end_date)?;
+    let mut rounds: BTreeMap<i64, Round> = BTreeMap::new();
+    let mut rows: Vec<CoverageRow> = Vec::new();
+    for count in counts {
+        let Some(area) = areas.iter().find(|a| a.area_id() == Some(count.area_id)) else {
+            continue;
+        };
+        let leave_date: Date = parse_report_date("leave_date", &count.leave_date)?;
+        let round: &Round = match rounds.entry(count.round_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                entry.insert(persistence.get_round(count.round_id).map_err(|e| {
+                    ApiError::Internal {
+                        message: format!("Failed to get round {}: {e}", count.round_id),
+                    }
+                })?)
+            }
+        };
+
+        match rows.last_mut() {
+            Some(row)
+                if row.leave_date == leave_date
+                    && row.area_code == area.area_code()
+                    && row.round_id == count.round_id =>
+            {
+                row.approved += count.approved;
+                row.crews.push((count.crew, count.approved));
+            }
+            _ => {
+                let slots: DailySlots = inventory.slots(count.area_id, count.round_id, leave_date);
+                rows.push(CoverageRow {
+                    leave_date,
+                    area_code: area.area_code().to_string(),
+                    round_id: count.round_id,
+                    round_number: round.round_number(),
+                    round_name: round.name().to_string(),
+                    capacity: slots.capacity(),
+                    prime: slots.prime,
+                    approved: count.approved,
+                    crews: vec![(count.crew, count.approved)],
+                });
+            }
+        }
+    }
+
+    let single_area: Option<&Area> = request.area_id.and_then(|_| areas.first().copied());
+    let area_label: &str = single_area.map_or("All Areas", |a| {
+        a.area_name().unwrap_or_else(|| a.area_code())
+    });
+    let full_days: usize = rows.iter().filter(|row| row.is_exhausted()).count();
+    let prime_days: usize = rows.iter().filter(|row| row.prime).count();
+    let report: TableDocument = TableDocument {
+        title: format!("{year} Leave Coverage - {area_label}"),
+        facility_name: None,
+        header_text: Some(format!("{start_date} through {end_date}")),
+        footer_text: None,
+        columns: &COVERAGE_COLUMNS,
+        column_x: &COVERAGE_COLUMN_X,
+        rows: rows
+            .iter()
+            .map(|row| {
+                vec![
+                    row.leave_date.to_string(),
+                    row.area_code.clone(),
+                    row.round_name.clone(),
+                    row.approved.to_string(),
+                    row.capacity.to_string(),
+                    row.crew_summary(" "),
+                    row.status(),
+                ]
+            })
+            .collect(),
+        notes: vec![format!(
+            "Days shown: {}   Full: {full_days}   Prime: {prime_days}   Crew counts are crew=controllers on leave.",
+            rows.len()
+        )],
+    };
+
+    let body: Vec<u8> = match format {
+        ReportFormat::Csv => render_coverage_csv(&rows)?,
+        ReportFormat::Html => render_html(&report).into_bytes(),
+        // `parse` only accepts PDF, HTML, and CSV for coverage reports.
+        ReportFormat::Pdf | ReportFormat::Xlsx => render_pdf(&report),
+    };
+
+    let scope: String = single_area.map_or_else(
+        || String::from("all"),
+        |a| filename_component(a.area_code()),
+    );
+    Ok(RenderedReport {
+        content_type: format.content_type(),
+        filename: format!(
+            "coverage-{year}-{scope}-{start_date}-{end_date}.{}",
+            format.extension()
+        ),
+        body,
+    })
+}
+
+/// Writes use-or-lose rows as CSV, one line per controller.
+fn render_use_or_lose_csv(rows: &[&UserLeaveProjection]) -> Result<Vec<u8>, ApiError> {
+    let csv_error = |e: csv::Error| ApiError::Internal {
+        message: format!("Failed to write CSV report: {e}"),
+    };
+    let mut writer: csv::Writer<Vec<u8>> = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record([
+            "area_code",
+            "initials",
+            "name",
+            "carried_in_hours",
+            "forfeited_carryover_hours",
+            "accrued_hours",
+            "awarded_hours",
+            "projected_balance_hours",
+            "use_or_lose_hours",
+        ])
+        .map_err(csv_error)?;
+    for row in rows {
+        writer
+            .write_record([
+                row.area_code.clone(),
+                row.initials.clone(),
+                row.name.clone(),
+                row.projection.carried_in_hours.to_string(),
+                row.projection.forfeited_carryover_hours.to_string(),
+                row.projection.accrued_hours.to_string(),
+                row.projection.awarded_hours.to_string(),
+                row.projection.projected_balance_hours.to_string(),
+                row.projection.use_or_lose_hours.to_string(),
+            ])
+            .map_err(csv_error)?;
+    }
+    writer.into_inner().map_err(|e| ApiError::Internal {
+        message: format!("Failed to write CSV report: {e}"),
+    })
+}
+
+/// Renders the controllers projected to lose annual leave at year end.
+///
+/// Each row is a controller whose carried-in and accrued leave, less the
+/// leave awarded so far, still exceeds the bid year's carryover cap.
+/// Controllers under the cap are omitted.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The report request
+///
+/// # Returns
+///
+/// The rendered document with its content type and a suggested file name.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The format is invalid
+/// - The bid year or area does not exist, or the area is a system area
+/// - The database cannot be queried
+pub fn get_use_or_lose_report(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &GetUseOrLoseReportRequest,
+) -> Result<RenderedReport, ApiError> {
+    let format: ReportFormat = ReportFormat::parse(
+        &request.format,
+        &[ReportFormat::Pdf, ReportFormat::Html, ReportFormat::Csv],
+    )?;
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let single_area: Option<&Area> = match request.area_id {
+        Some(area_id) => Some(find_report_area(metadata, request.bid_year_id, area_id)?.1),
+        None => None,
+    };
+    let projections: Vec<UserLeaveProjection> =
+        project_leave_balances(persistence, metadata, request.bid_year_id, request.area_id)?;
+    let cap: u16 = load_leave_cap_policy(persistence, request.bid_year_id)?.carryover_cap_hours();
+
+    let rows: Vec<&UserLeaveProjection> = projections
+        .iter()
+        .filter(|row| row.projection.is_over_threshold())
+        .collect();
+    let at_risk_hours: u64 = rows
+        .iter()
+        .map(|row| u64::from(row.projection.use_or_lose_hours))
+        .sum();
+
+    let area_label: &str = single_area.map_or("All Areas", |a| {
+        a.area_name().unwrap_or_else(|| a.area_code())
+    });
+    let report: TableDocument = TableDocument {
+        title: format!("{year} Use or Lose - {area_label}"),
+        facility_name: None,
+        header_text: Some(format!("Carryover cap: {cap} hours")),
+        footer_text: None,
+        columns: &USE_OR_LOSE_COLUMNS,
+        column_x: &USE_OR_LOSE_COLUMN_X,
+        rows: rows
+            .iter()
+            .map(|row| {
+                vec![
+                    row.area_code.clone(),
+                    row.initials.clone(),
+                    row.name.clone(),
+                    row.projection.carried_in_hours.to_string(),
+                    row.projection.accrued_hours.to_string(),
+                    row.projection.awarded_hours.to_string(),
+                    row.projection.projected_balance_hours.to_string(),
+                    row.projection.use_or_lose_hours.to_string(),
+                ]
+            })
+            .collect(),
+        notes: vec![format!(
+            "Controllers over the cap: {} of {}   Hours at risk: {at_risk_hours}",
+            rows.len(),
+            projections.len()
+        )],
+    };
+
+    let body: Vec<u8> = match format {
+        ReportFormat::Csv => render_use_or_lose_csv(&rows)?,
+        ReportFormat::Html => render_html(&report).into_bytes(),
+        // `parse` only accepts PDF, HTML, and CSV for use-or-lose reports.
+        ReportFormat::Pdf | ReportFormat::Xlsx => render_pdf(&report),
+    };
+
+    let scope: String = single_area.map_or_else(
+        || String::from("all"),
+        |a| filename_component(a.area_code()),
+    );
+    Ok(RenderedReport {
+        content_type: format.content_type(),
+        filename: format!("use-or-lose-{year}-{scope}.{}", format.extension()),
+        body,
+    })
+}
+
+/// Resolves the timezone bid packets print window times in.
+///
+/// Uses the bid year's schedule timezone, falling back to the facility's
+/// default timezone and then UTC.
+fn packet_timezone(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<String, ApiError> {
+    let (schedule_timezone, ..) =
+        persistence
+            .get_bid_schedule(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to load bid schedule: {e}"),
+            })?;
+    let timezone: String = match schedule_timezone {
+        Some(timezone) => timezone,
+        None => load_facility(persistence)?.map_or_else(
+            || String::from("UTC"),
+            |facility| facility.default_timezone().to_string(),
+        ),
+    };
+    parse_timezone(&timezone).map_err(translate_domain_error)?;
+    Ok(timezone)
+}
+
+/// Formats an RFC 3339 window bound in `timezone`, falling back to the raw
+/// value.
+fn display_local_datetime(timezone: &str, value: &str) -> String {
+    OffsetDateTime::parse(value, &Rfc3339)
+        .ok()
+        .and_then(|instant| utc_to_local(timezone, instant).ok())
+        .and_then(|local| {
+            local
+                .format(format_description!("[year]-[month]-[day] [hour]:[minute]"))
+                .ok()
+        })
+        .unwrap_or_else(|| value.to_string())
+}
+
+/// Returns the printed summary of one round's bidding rules.
+fn round_rules_line(round: &Round) -> String {
+    format!(
+        "Round {} - {}: {} slot(s) per day, up to {} group(s) and {} hours, holidays {}, overbidding {}",
+        round.round_number(),
+        round.name(),
+        round.slots_per_day(),
+        round.max_groups(),
+        round.max_total_hours(),
+        if round.include_holidays() {
+            "included"
+        } else {
+            "excluded"
+        },
+        if round.allow_overbid() {
+            "allowed"
+        } else {
+            "not allowed"
+        },
+    )
+}
+
+/// Returns the printed leave balance lines of one controller.
+fn balance_lines(projection: Option<&LeaveBalanceProjection>) -> Vec<String> {
+    let Some(projection) = projection else {
+        return vec![String::from(
+            "Not calculated: excluded from leave calculation.",
+        )];
+    };
+    vec![
+        format!(
+            "Carried in: {} hours   Accrued this year: {} hours   Awarded: {} hours",
+            projection.carried_in_hours, projection.accrued_hours, projection.awarded_hours
+        ),
+        format!(
+            "Remaining: {} hours   Requested and pending: {} hours",
+            projection.projected_balance_hours, projection.pending_hours
+        ),
+    ]
+}
+
+/// Lays out one controller's bid packet across as many pages as it needs.
+struct PacketPages<'a> {
+    title: &'a str,
+    controller: String,
+    pages: Vec<PdfPage>,
+    page: PdfPage,
+    y: f32,
+}
+
+impl<'a> PacketPages<'a> {
+    /// Starts a packet on a fresh page.
+    fn new(title: &'a str, controller: String) -> Self {
+        let mut packet: Self = Self {
+            title,
+            controller,
+            pages: Vec::new(),
+            page: PdfPage::new(),
+            y: 0.0,
+        };
+        packet.y = packet.draw_header();
+        packet
+    }
+
+    /// Draws the title and controller on the current page; returns the
+    /// first body baseline.
+    fn draw_header(&mut self) -> f32 {
+        let mut y: f32 = PAGE_HEIGHT - PDF_MARGIN - 12.0;
+        self.page.text(Font::Bold, 12.0, PDF_MARGIN, y, self.title);
+        y -= 16.0;
+        self.page
+            .text(Font::Bold, 11.0, PDF_MARGIN, y, &self.controller);
+        y -= 4.0;
+        self.page.line(PDF_MARGIN, y, PAGE_WIDTH - PDF_MARGIN, y);
+        y - 20.0
+    }
+
+    /// Moves to a new page.
+    fn break_page(&mut self) {
+        self.pages
+            .push(std::mem::replace(&mut self.page, PdfPage::new()));
+        self.y = self.draw_header();
+    }
+
+    /// Breaks the page unless `height` points remain above the footer.
+    fn reserve(&mut self, height: f32) {
+        if self.y - height < PDF_TABLE_BOTTOM {
+            self.break_page();
+        }
+    }
+
+    /// Draws a section heading, keeping it with its first line.
+    fn heading(&mut self, text: &str) {
+        self.reserve(PDF_ROW_HEIGHT * 3.0);
+        self.page.text(Font::Bold, 10.0, PDF_MARGIN, self.y, text);
+        self.y -= PDF_ROW_HEIGHT + 2.0;
+    }
+
+    /// Draws one line of body text.
+    fn body(&mut self, text: &str) {
+        self.reserve(PDF_ROW_HEIGHT);
+        let width: f32 = PAGE_WIDTH - PDF_MARGIN - PDF_MARGIN - 12.0;
+        self.page.text(
+            Font::Regular,
+            PDF_FONT_SIZE,
+            PDF_MARGIN + 12.0,
+            self.y,
+            &pdf::fit(text, PDF_FONT_SIZE, width),
+        );
+        self.y -= PDF_ROW_HEIGHT;
+    }
+
+    /// Ends a section with some space.
+    fn gap(&mut self) {
+        self.y -= 8.0;
+    }
+
+    /// Draws the blank preference worksheet, starting on its own page.
+    fn worksheet(&mut self) {
+        self.break_page();
+        self.heading("Preference Worksheet");
+        self.body("List your leave choices in order of preference. Bring this sheet to your bid.");
+        self.y -= 8.0;
+        for (column, x) in WORKSHEET_COLUMNS.iter().zip(WORKSHEET_COLUMN_X) {
+            self.page.text(Font::Bold, PDF_FONT_SIZE, x, self.y, column);
+        }
+        for choice in 1..=WORKSHEET_ROWS {
+            if self.y - WORKSHEET_ROW_HEIGHT < PDF_TABLE_BOTTOM {
+                break;
+            }
+            self.y -= WORKSHEET_ROW_HEIGHT;
+            self.page.text(
+                Font::Regular,
+                PDF_FONT_SIZE,
+                PDF_MARGIN,
+                self.y + 4.0,
+                &choice.to_string(),
+            );
+            self.page
+                .line(PDF_MARGIN, self.y, PAGE_WIDTH - PDF_MARGIN, self.y);
+        }
+    }
+
+    /// Finishes the packet, numbering its pages.
+    fn finish(mut self) -> Vec<PdfPage> {
+        self.pages.push(self.page);
+        let page_count: usize = self.pages.len();
+        for (i, page) in self.pages.iter_mut().enumerate() {
+            page.text(Font::Regular, 8.0, PDF_MARGIN, 24.0, &self.controller);
+            page.text_right(
+                Font::Regular,
+                8.0,
+                PAGE_WIDTH - PDF_MARGIN,
+                24.0,
+                &format!("Page {} of {page_count}", i + 1),
+            );
+        }
+        self.pages
+    }
+}
+
+/// Renders the bid packets of an area's controllers, or of one controller.
+///
+/// Each packet starts on a new page and is numbered on its own, so a batch
+/// printed for an area can be split and handed out. Packets follow the
+/// canonical bid order. A packet lists the controller's bid position and
+/// seniority dates, their bid windows in the bid year's timezone (or the
+/// facility's default timezone when the bid year has none), the rules of
+/// each round in the area's round group, their projected leave balance,
+/// and a blank preference worksheet.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The report request
+///
+/// # Returns
+///
+/// The rendered PDF with a suggested file name.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The bid year or area does not exist, or the area is a system area
+/// - The requested user is not on the area's seniority list
+/// - The bid year has not been confirmed
+/// - The timezone is invalid
+/// - The database cannot be queried
+#[allow(clippy::too_many_lines)]
+pub fn get_bid_packets(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &GetBidPacketsRequest,
+) -> Result<RenderedReport, ApiError> {
+    let (bid_year, area) = find_report_area(metadata, request.bid_year_id, request.area_id)?;
+    let year: u16 = bid_year.year();
+
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    if !lifecycle_state.is_locked() {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("bid_packets_require_confirmation"),
+            message: format!(
+                "Bid packets for {year} are available once the bid year is confirmed (currently {lifecycle_state})"
+            ),
+        });
+    }
+
+    let entries: Vec<SeniorityListEntryData> = persistence
+        .list_seniority_list(request.bid_year_id, request.area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load seniority list: {e}"),
+        })?;
+    let area_size: usize = entries.len();
+    let packet_entries: Vec<&SeniorityListEntryData> = match request.user_id {
+        Some(user_id) => vec![
+            entries
+                .iter()
+                .find(|entry| entry.user_id == user_id)
+                .ok_or_else(|| ApiError::ResourceNotFound {
+                    resource_type: String::from("User"),
+                    message: format!(
+                        "User with ID {user_id} is not on the seniority list of area {}",
+                        area.area_code()
+                    ),
+                })?,
+        ],
+        None => entries.iter().collect(),
+    };
+
+    let timezone: String = packet_timezone(persistence, request.bid_year_id)?;
+    let mut rounds: Vec<Round> = match area.round_group_id() {
+        Some(round_group_id) => {
+            persistence
+                .list_rounds(round_group_id)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to list rounds: {e}"),
+                })?
+        }
+        None => Vec::new(),
+    };
+    rounds.sort_by_key(Round::round_number);
+    let projections: Vec<UserLeaveProjection> = project_leave_balances(
+        persistence,
+        metadata,
+        request.bid_year_id,
+        Some(request.area_id),
+    )?;
+
+    let area_label: &str = area.area_name().unwrap_or_else(|| area.area_code());
+    let title: String = format!("{year} Bid Packet - {area_label}");
+    let mut pages: Vec<PdfPage> = Vec::new();
+    for entry in &packet_entries {
+        let windows: Vec<UserBidWindowData> = persistence
+            .list_user_bid_windows(entry.user_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list bid windows: {e}"),
+            })?;
+        let projection: Option<&LeaveBalanceProjection> = projections
+            .iter()
+            .find(|row| row.user_id == entry.user_id)
+            .map(|row| &row.projection);
+
+        let mut packet: PacketPages =
+            PacketPages::new(&title, format!("{} - {}", entry.initials, entry.name));
+
+        packet.heading("Seniority Standing");
+        let marker: &str = if entry.is_overridden {
+            " (set by override)"
+        } else {
+            ""
+        };
+        packet.body(&format!(
+            "Bid position {} of {area_size}{marker}",
+            entry.bid_order
+        ));
+        packet.body(&format!(
+            "Cum. NATCA BU: {}   NATCA BU: {}   EOD/FAA: {}   SCD: {}",
+            entry.cumulative_natca_bu_date,
+            entry.natca_bu_date,
+            entry.eod_faa_date,
+            entry.service_computation_date
+        ));
+        packet.gap();
+
+        packet.heading(&format!("Bid Windows (times in {timezone})"));
+        if windows.is_empty() {
+            packet.body("No bid windows have been scheduled yet.");
+        }
+        for window in &windows {
+            packet.body(&format!(
+                "Round {} - {}: {} to {}",
+                window.round_number,
+                window.round_name,
+                display_local_datetime(&timezone, &window.window_start_datetime),
+                display_local_datetime(&timezone, &window.window_end_datetime)
+            ));
+        }
+        packet.gap();
+
+        packet.heading("Round Rules");
+        if rounds.is_empty() {
+            packet.body("No rounds have been configured for this area.");
+        }
+        for round in &rounds {
+            packet.body(&round_rules_line(round));
+        }
+        packet.gap();
+
+        packet.heading("Leave Balance");
+        for line in balance_lines(projection) {
+            packet.body(&line);
+        }
+
+        packet.worksheet();
+        pages.extend(packet.finish());
+    }
+    if pages.is_empty() {
+        let mut packet: PacketPages = PacketPages::new(&title, String::new());
+        packet.body("No controllers are on this area's seniority list.");
+        pages.extend(packet.finish());
+    }
+
+    let area_component: String = filename_component(area.area_code());
+    let filename: String = match (request.user_id, packet_entries.first()) {
+        (Some(_), Some(entry)) => format!(
+            "bid-packet-{year}-{area_component}-{}.pdf",
+            filename_component(&entry.initials)
+        ),
+        _ => format!("bid-packets-{year}-{area_component}.pdf"),
+    };
+    Ok(RenderedReport {
+        content_type: ReportFormat::Pdf.content_type(),
+        filename,
+        body: pdf::render(&title, &pages),
+    })
+}
+
+/// Parses a stored `YYYY-MM-DD HH:MM:SS` audit timestamp, which is UTC.
+fn parse_audit_timestamp(value: &str) -> Option<OffsetDateTime> {
+    PrimitiveDateTime::parse(
+        value,
+        format_description!("[year]-[month]-[day] [hour]:[minute]:[second]"),
+    )
+    .ok()
+    .map(PrimitiveDateTime::assume_utc)
+}
+
+/// Counts one audit event against its operator, if it is a command
+/// recorded between `start_date` and `end_date` in `timezone`.
+fn tally_activity(
+    operators: &mut BTreeMap<(String, i64), OperatorActivityInfo>,
+    header: &AuditEventHeader,
+    start_date: Date,
+    end_date: Date,
+    timezone: &str,
+) -> Result<(), ApiError> {
+    if READ_ONLY_ACTIONS.contains(&header.action_name.as_str()) {
+        return Ok(());
+    }
+    let Some(created_at) = header.created_at.as_deref().and_then(parse_audit_timestamp) else {
+        return Ok(());
+    };
+    let local: PrimitiveDateTime =
+        utc_to_local(timezone, created_at).map_err(translate_domain_error)?;
+    if local.date() < start_date || local.date() > end_date {
+        return Ok(());
+    }
+
+    let activity: &mut OperatorActivityInfo = operators
+        .entry((header.actor_login_name.clone(), header.actor_operator_id))
+        .or_insert_with(|| OperatorActivityInfo {
+            operator_id: header.actor_operator_id,
+            login_name: header.actor_login_name.clone(),
+            commands: 0,
+            overrides: 0,
+            rollbacks: 0,
+            after_hours: 0,
+        });
+    activity.commands += 1;
+    if header.action_name.ends_with("Overridden") {
+        activity.overrides += 1;
+    }
+    if header.action_name == "Rollback" {
+        activity.rollbacks += 1;
+    }
+    if is_after_hours(local) {
+        activity.after_hours += 1;
+    }
+    Ok(())
+}
+
+/// Summarizes each operator's commands between two dates.
+///
+/// Dates are read in `timezone`, as are business hours when deciding
+/// which commands were recorded after hours.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `start_date` - The first date to include
+/// * `end_date` - The last date to include
+/// * `timezone` - The IANA timezone to read dates and times in
+///
+/// # Errors
+///
+/// Returns an error if the timezone is invalid or the audit log cannot be
+/// read.
+pub fn summarize_admin_activity(
+    persistence: &mut SqlitePersistence,
+    start_date: Date,
+    end_date: Date,
+    timezone: &str,
+) -> Result<AdminActivitySummary, ApiError> {
+    parse_timezone(timezone).map_err(translate_domain_error)?;
+
+    // Stored timestamps are UTC, so a day either side covers every offset
+    let filter: AuditTimelineFilter = AuditTimelineFilter {
+        created_from: Some(format!(
+            "{} 00:00:00",
+            start_date.previous_day().unwrap_or(start_date)
+        )),
+        created_to: Some(format!(
+            "{} 23:59:59",
+            end_date.next_day().unwrap_or(end_date)
+        )),
+        ..AuditTimelineFilter::default()
+    };
+    let mut operators: BTreeMap<(String, i64), OperatorActivityInfo> = BTreeMap::new();
+    let mut cursor: Option<i64> = None;
+    loop {
+        let page: AuditEventHeaderPage = persistence
+            .get_audit_event_headers_page(
+                AuditTimelineScope::All,
+                &filter,
+                cursor,
+                ACTIVITY_PAGE_SIZE,
+            )
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to read audit log: {e}"),
+            })?;
+        for header in &page.headers {
+            tally_activity(&mut operators, header, start_date, end_date, timezone)?;
+        }
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    Ok(AdminActivitySummary {
+        start_date: start_date.to_string(),
+        end_date: end_date.to_string(),
+        timezone: timezone.to_string(),
+        operators: operators.into_values().collect(),
+    })
+}
+
+/// Writes admin activity as CSV, one line per operator.
+fn render_admin_activity_csv(summary: &AdminActivitySummary) -> Result<Vec<u8>, ApiError> {
+    let csv_error = |e: csv::Error| ApiError::Internal {
+        message: format!("Failed to write CSV report: {e}"),
+    };
+    let mut writer: csv::Writer<Vec<u8>> = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record([
+            "operator_id",
+            "login_name",
+            "commands",
+            "overrides",
+            "rollbacks",
+            "after_hours",
+        ])
+        .map_err(csv_error)?;
+    for operator in &summary.operators {
+        writer
+            .write_record([
+                operator.operator_id.to_string(),
+                operator.login_name.clone(),
+                operator.commands.to_string(),
+                operator.overrides.to_string(),
+                operator.rollbacks.to_string(),
+                operator.after_hours.to_string(),
+            ])
+            .map_err(csv_error)?;
+    }
+    writer.into_inner().map_err(|e| ApiError::Internal {
+        message: format!("Failed to write CSV report: {e}"),
+    })
+}
+
+/// Renders each operator's activity between two dates, for oversight
+/// review.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The report request
+/// * `authenticated_actor` - The authenticated operator
+///
+/// # Returns
+///
+/// The rendered document with its content type and a suggested file name.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The operator is not an admin
+/// - The format, a date, or the timezone is invalid
+/// - The range is reversed or longer than a year
+/// - The audit log cannot be read
+pub fn get_admin_activity_report(
+    persistence: &mut SqlitePersistence,
+    request: &GetAdminActivityReportRequest,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<RenderedReport, ApiError> {
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: String::from("view admin activity report"),
+            required_role: String::from("Admin"),
+        });
+    }
+    let format: ReportFormat = ReportFormat::parse(
+        &request.format,
+        &[ReportFormat::Pdf, ReportFormat::Html, ReportFormat::Csv],
+    )?;
+    let start_date: Date = parse_report_date("start_date", &request.start_date)?;
+    let end_date: Date = parse_report_date("end_date", &request.end_date)?;
+    if start_date > end_date {
+        return Err(ApiError::InvalidInput {
+            field: String::from("end_date"),
+            message: format!("End date {end_date} is before start date {start_date}"),
+        });
+    }
+    if (end_date - start_date).whole_days() >= MAX_ACTIVITY_DAYS {
+        return Err(ApiError::InvalidInput {
+            field: String::from("end_date"),
+            message: format!("Admin activity reports span at most {MAX_ACTIVITY_DAYS} days"),
+        });
+    }
+    let timezone: &str = request.timezone.as_deref().unwrap_or("UTC");
+
+    let summary: AdminActivitySummary =
+        summarize_admin_activity(persistence, start_date, end_date, timezone)?;
+
+    let total = |count: fn(&OperatorActivityInfo) -> u32| -> u32 {
+        summary.operators.iter().map(count).sum()
+    };
+    let report: TableDocument = TableDocument {
+        title: format!("Admin Activity {start_date} to {end_date}"),
+        facility_name: None,
+        header_text: Some(format!(
+            "Times in {timezone}. After hours: weekdays before 07:00 or from 18:00, and weekends."
+        )),
+        footer_text: None,
+        columns: &ADMIN_ACTIVITY_COLUMNS,
+        column_x: &ADMIN_ACTIVITY_COLUMN_X,
+        rows: summary
+            .operators
+            .iter()
+            .map(|operator| {
+                vec![
+                    operator.login_name.clone(),
+                    operator.operator_id.to_string(),
+                    operator.commands.to_string(),
+                    operator.overrides.to_string(),
+                    operator.rollbacks.to_string(),
+                    operator.after_hours.to_string(),
+                ]
+            })
+            .collect(),
+        notes: vec![format!(
+            "Operators: {}   Commands: {}   Overrides: {}   Rollbacks: {}   After hours: {}",
+            summary.operators.len(),
+            total(|o| o.commands),
+            total(|o| o.overrides),
+            total(|o| o.rollbacks),
+            total(|o| o.after_hours)
+        )],
+    };
+
+    let body: Vec<u8> = match format {
+        ReportFormat::Csv => render_admin_activity_csv(&summary)?,
+        ReportFormat::Html => render_html(&report).into_bytes(),
+        // `parse` only accepts PDF, HTML, and CSV for admin activity reports.
+        ReportFormat::Pdf | ReportFormat::Xlsx => render_pdf(&report),
+    };
+
+    Ok(RenderedReport {
+        content_type: format.content_type(),
+        filename: format!(
+            "admin-activity-{start_date}-{end_date}.{}",
+            format.extension()
+        ),
+        body,
+    })
+}
@@ -105,6 +105,60 @@ pub struct RegisterUserResponse {
     pub event_id: i64,
 }
 
+/// API request to register many users in one operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterUsersBulkRequest {
+    /// The users to register, in order.
+    pub users: Vec<RegisterUserRequest>,
+    /// If `true`, nothing is registered unless every row is valid.
+    /// If `false`, the valid rows are registered and invalid rows are reported.
+    pub all_or_nothing: bool,
+}
+
+/// Outcome of a single row in a bulk user registration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkRegisterRowStatus {
+    /// The user was registered.
+    Registered,
+    /// The row was valid but not applied because another row failed.
+    Skipped,
+    /// The row failed validation.
+    Failed,
+}
+
+/// Result of a single row in a bulk user registration.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BulkRegisterRowResult {
+    /// The row index (0-based) in the request.
+    pub row_index: usize,
+    /// The initials from this row.
+    pub initials: String,
+    /// The outcome of this row.
+    pub status: BulkRegisterRowStatus,
+    /// The user's canonical identifier, if registered.
+    pub user_id: Option<i64>,
+    /// The audit event ID, if registered.
+    pub event_id: Option<i64>,
+    /// Error message if the row failed.
+    pub error: Option<String>,
+}
+
+/// API response for a bulk user registration.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RegisterUsersBulkResponse {
+    /// The bid year users were registered into.
+    pub bid_year: u16,
+    /// Total number of rows in the request.
+    pub total: usize,
+    /// Number of rows registered.
+    pub registered_count: usize,
+    /// Number of rows that failed validation.
+    pub failed_count: usize,
+    /// Per-row results, in request order.
+    pub results: Vec<BulkRegisterRowResult>,
+}
+
 /// Bid schedule information for a bid year.
 ///
 /// Phase 29C: Defines when and how bidding occurs.
@@ -345,6 +399,7 @@ pub struct GetLeaveAvailabilityResponse {
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct LoginRequest {
     /// The operator login name.
+    #[serde(deserialize_with = "crate::free_text::name")]
     pub login_name: String,
     /// The operator password.
     pub password: String,
@@ -384,8 +439,10 @@ pub struct WhoAmIResponse {
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct CreateOperatorRequest {
     /// The operator login name.
+    #[serde(deserialize_with = "crate::free_text::name")]
     pub login_name: String,
     /// The operator display name.
+    #[serde(deserialize_with = "crate::free_text::name")]
     pub display_name: String,
     /// The operator role (Admin or Bidder).
     pub role: String,
@@ -425,10 +482,29 @@ pub struct OperatorInfo {
     pub created_at: String,
     /// Last login timestamp (ISO 8601, optional).
     pub last_login_at: Option<String>,
+    /// Number of sessions that have not yet expired.
+    pub active_session_count: usize,
+    /// Consecutive failed sign-in attempts since the last successful one.
+    pub failed_login_count: u32,
+    /// Areas the operator is assigned to look after.
+    pub area_scopes: Vec<OperatorAreaScopeInfo>,
     /// Target-specific capabilities for this operator instance.
     pub capabilities: OperatorCapabilities,
 }
 
+/// An area an operator is assigned to look after.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OperatorAreaScopeInfo {
+    /// The area ID.
+    pub area_id: i64,
+    /// The bid year ID the area belongs to.
+    pub bid_year_id: i64,
+    /// The bid year.
+    pub year: u16,
+    /// The area code.
+    pub area_code: String,
+}
+
 /// API request to change an operator's own password.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct ChangePasswordRequest {
@@ -447,6 +523,36 @@ pub struct ChangePasswordResponse {
     pub message: String,
 }
 
+/// API response for an operator changing their own password.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChangeOwnPasswordResponse {
+    /// The number of sessions on other devices that were signed out.
+    pub sessions_invalidated: usize,
+    /// Success message.
+    pub message: String,
+}
+
+/// API request for an operator to update their own profile.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UpdateOwnProfileRequest {
+    /// The new display name.
+    #[serde(deserialize_with = "crate::free_text::name")]
+    pub display_name: String,
+}
+
+/// API response for an operator updating their own profile.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UpdateOwnProfileResponse {
+    /// The operator's ID.
+    pub operator_id: i64,
+    /// The operator's login name.
+    pub login_name: String,
+    /// The updated display name.
+    pub display_name: String,
+    /// Success message.
+    pub message: String,
+}
+
 /// API request to reset another operator's password (admin only).
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct ResetPasswordRequest {
@@ -516,6 +622,156 @@ pub struct DeleteOperatorResponse {
     pub message: String,
 }
 
+/// API request for changing an operator's role.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChangeOperatorRoleRequest {
+    /// The operator ID whose role changes.
+    pub operator_id: i64,
+    /// The new role (Admin or Bidder).
+    pub role: String,
+}
+
+/// API response for changing an operator's role.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChangeOperatorRoleResponse {
+    /// The operator ID whose role changed.
+    pub operator_id: i64,
+    /// The new role.
+    pub role: String,
+    /// Confirmation message.
+    pub message: String,
+}
+
+/// API request for replacing the areas an operator is assigned to.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetOperatorAreaScopesRequest {
+    /// The operator ID.
+    pub operator_id: i64,
+    /// The areas to assign; empty clears all scopes.
+    pub area_ids: Vec<i64>,
+}
+
+/// API response for replacing the areas an operator is assigned to.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetOperatorAreaScopesResponse {
+    /// The operator ID.
+    pub operator_id: i64,
+    /// The assigned areas.
+    pub area_ids: Vec<i64>,
+    /// Confirmation message.
+    pub message: String,
+}
+
+/// API request for linking an operator to the controller they are.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetOperatorControllerRequest {
+    /// The operator ID.
+    pub operator_id: i64,
+    /// The controller's initials, or `None` to clear the link.
+    pub initials: Option<String>,
+}
+
+/// API response for linking an operator to the controller they are.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetOperatorControllerResponse {
+    /// The operator ID.
+    pub operator_id: i64,
+    /// The linked controller's initials, if any.
+    pub initials: Option<String>,
+    /// Confirmation message.
+    pub message: String,
+}
+
+/// API request for delegating an area rep's bidding authority.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateAreaDelegationRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The area rep handing over authority.
+    pub delegator_operator_id: i64,
+    /// The operator receiving it.
+    pub delegate_operator_id: i64,
+    /// The first day the delegation applies (`YYYY-MM-DD`).
+    pub start_date: String,
+    /// The last day the delegation applies (`YYYY-MM-DD`).
+    pub end_date: String,
+}
+
+/// An area delegation.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AreaDelegationInfo {
+    /// The area delegation ID.
+    pub area_delegation_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The area code.
+    pub area_code: String,
+    /// The area rep handing over authority.
+    pub delegator_operator_id: i64,
+    /// The delegator's login name.
+    pub delegator_login_name: String,
+    /// The operator receiving it.
+    pub delegate_operator_id: i64,
+    /// The delegate's login name.
+    pub delegate_login_name: String,
+    /// The first day the delegation applies (`YYYY-MM-DD`).
+    pub start_date: String,
+    /// The last day the delegation applies (`YYYY-MM-DD`).
+    pub end_date: String,
+    /// `scheduled`, `active`, or `expired`, as of today.
+    pub status: String,
+}
+
+/// API response for delegating an area rep's bidding authority.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateAreaDelegationResponse {
+    /// The new delegation.
+    pub delegation: AreaDelegationInfo,
+    /// Confirmation message.
+    pub message: String,
+}
+
+/// API request for revoking an area delegation before it expires.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RevokeAreaDelegationRequest {
+    /// The area delegation ID.
+    pub area_delegation_id: i64,
+}
+
+/// API response for revoking an area delegation.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RevokeAreaDelegationResponse {
+    /// The revoked area delegation ID.
+    pub area_delegation_id: i64,
+    /// Confirmation message.
+    pub message: String,
+}
+
+/// API response listing area delegations.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListAreaDelegationsResponse {
+    /// Every delegation, ordered by start date.
+    pub delegations: Vec<AreaDelegationInfo>,
+}
+
+/// API request for revoking all sessions of an operator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RevokeOperatorSessionsRequest {
+    /// The operator ID whose sessions are revoked.
+    pub operator_id: i64,
+}
+
+/// API response for revoking all sessions of an operator.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RevokeOperatorSessionsResponse {
+    /// The operator ID whose sessions were revoked.
+    pub operator_id: i64,
+    /// The number of sessions revoked.
+    pub sessions_revoked: usize,
+    /// Confirmation message.
+    pub message: String,
+}
+
 /// API response for checking bootstrap status.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct BootstrapAuthStatusResponse {
@@ -545,8 +801,10 @@ pub struct BootstrapLoginResponse {
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct CreateFirstAdminRequest {
     /// The new admin login name.
+    #[serde(deserialize_with = "crate::free_text::name")]
     pub login_name: String,
     /// The new admin display name.
+    #[serde(deserialize_with = "crate::free_text::name")]
     pub display_name: String,
     /// The password for the new admin.
     pub password: String,
@@ -650,6 +908,7 @@ pub struct UpdateAreaRequest {
     /// The canonical area identifier.
     pub area_id: i64,
     /// The new display name (optional).
+    #[serde(default, deserialize_with = "crate::free_text::optional_name")]
     pub area_name: Option<String>,
 }
 
@@ -678,6 +937,7 @@ pub struct UpdateUserRequest {
     /// The user's initials (unique per bid year, mutable).
     pub initials: String,
     /// The user's name.
+    #[serde(deserialize_with = "crate::free_text::name")]
     pub name: String,
     /// The canonical area identifier.
     pub area_id: i64,
@@ -1070,6 +1330,9 @@ pub struct ImportCsvUsersResponse {
     pub failed_count: usize,
     /// Per-row import results.
     pub results: Vec<CsvImportRowResult>,
+    /// The correlation ID linking the import to the automatic checkpoints
+    /// recorded before it.
+    pub correlation_id: String,
 }
 
 // ========================================================================
@@ -1239,6 +1502,9 @@ pub struct TransitionToCanonicalizedResponse {
     pub lifecycle_state: String,
     /// A success message.
     pub message: String,
+    /// The correlation ID linking canonicalization to the automatic
+    /// checkpoints recorded before it.
+    pub correlation_id: String,
 }
 
 /// API request to transition a bid year to `BiddingActive` state.
@@ -1281,14 +1547,43 @@ pub struct TransitionToBiddingClosedResponse {
     pub message: String,
 }
 
+/// API request to reopen a canonicalized bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct ReopenBidYearRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// Why the bid year is reopened (at least 20 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
+    pub justification: String,
+}
+
+/// API response for reopening a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReopenBidYearResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The year value.
+    pub year: u16,
+    /// The new lifecycle state.
+    pub lifecycle_state: String,
+    /// The audit event recording the reopen.
+    pub audit_event_id: i64,
+    /// The number of canonical and derived rows cleared.
+    pub rows_cleared: usize,
+    /// A success message.
+    pub message: String,
+}
+
 /// API request to update bid year metadata (label and notes).
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 pub struct UpdateBidYearMetadataRequest {
     /// The canonical bid year identifier.
     pub bid_year_id: i64,
     /// Optional display label (max 100 characters).
+    #[serde(default, deserialize_with = "crate::free_text::optional_name")]
     pub label: Option<String>,
     /// Optional notes for operational context (max 2000 characters).
+    #[serde(default, deserialize_with = "crate::free_text::optional_note")]
     pub notes: Option<String>,
 }
 
@@ -1309,13 +1604,15 @@ pub struct UpdateBidYearMetadataResponse {
 
 /// API request to set the bid schedule for a bid year.
 ///
-/// Phase 29C: All fields must be provided together.
+/// Phase 29C: All fields except the timezone must be provided together.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 pub struct SetBidScheduleRequest {
     /// The canonical bid year identifier.
     pub bid_year_id: i64,
     /// IANA timezone identifier (e.g., `"America/New_York"`).
-    pub timezone: String,
+    ///
+    /// Defaults to the facility's timezone when omitted.
+    pub timezone: Option<String>,
     /// Bid start date (ISO 8601 format, must be a Monday).
     pub start_date: String,
     /// Daily bid window start time (HH:MM:SS format).
@@ -1348,6 +1645,133 @@ pub struct GetBidScheduleResponse {
     pub year: u16,
     /// The bid schedule if configured, None otherwise.
     pub bid_schedule: Option<BidScheduleInfo>,
+    /// Per-area schedule overrides, ordered by area code.
+    pub area_overrides: Vec<AreaBidScheduleInfo>,
+}
+
+/// A per-area bid schedule override.
+///
+/// Unset fields fall back to the bid year's schedule.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AreaBidScheduleInfo {
+    /// The area's canonical identifier.
+    pub area_id: i64,
+    /// The area code.
+    pub area_code: String,
+    /// Overridden bid start date (ISO 8601 format).
+    pub start_date: Option<String>,
+    /// Overridden daily bid window start time (HH:MM:SS format).
+    pub window_start_time: Option<String>,
+    /// Overridden daily bid window end time (HH:MM:SS format).
+    pub window_end_time: Option<String>,
+    /// Overridden number of bidders per day.
+    pub bidders_per_day: Option<u32>,
+    /// The schedule that applies to the area, if the bid year's schedule is configured.
+    pub effective_schedule: Option<BidScheduleInfo>,
+}
+
+/// API request to set a per-area bid schedule override.
+///
+/// Fields left unset fall back to the bid year's schedule. At least one
+/// field must be set.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct SetAreaBidScheduleRequest {
+    /// The area's canonical identifier.
+    pub area_id: i64,
+    /// Bid start date (ISO 8601 format, must be a Monday).
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// Daily bid window start time (HH:MM:SS format).
+    #[serde(default)]
+    pub window_start_time: Option<String>,
+    /// Daily bid window end time (HH:MM:SS format).
+    #[serde(default)]
+    pub window_end_time: Option<String>,
+    /// Number of bidders per day (must be > 0).
+    #[serde(default)]
+    pub bidders_per_day: Option<u32>,
+}
+
+/// API response for setting a per-area bid schedule override.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetAreaBidScheduleResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The stored override and the resulting effective schedule.
+    pub area_schedule: AreaBidScheduleInfo,
+    /// A success message.
+    pub message: String,
+}
+
+/// API response for clearing a per-area bid schedule override.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ClearAreaBidScheduleResponse {
+    /// The area's canonical identifier.
+    pub area_id: i64,
+    /// A success message.
+    pub message: String,
+}
+
+/// A blackout date on which no bid windows are laid out.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlackoutDateInfo {
+    /// The blackout date identifier.
+    pub blackout_date_id: i64,
+    /// The blackout date (ISO 8601 format).
+    pub date: String,
+    /// Why no bidding occurs on the date.
+    pub reason: String,
+}
+
+/// API request to create a blackout date.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateBlackoutDateRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The blackout date (ISO 8601 format, must be a weekday).
+    pub date: String,
+    /// Why no bidding occurs on the date (e.g. training day).
+    #[serde(deserialize_with = "crate::free_text::reason")]
+    pub reason: String,
+}
+
+/// API request to update a blackout date.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UpdateBlackoutDateRequest {
+    /// The new blackout date (ISO 8601 format, must be a weekday).
+    pub date: String,
+    /// The new reason.
+    #[serde(deserialize_with = "crate::free_text::reason")]
+    pub reason: String,
+}
+
+/// API response for creating or updating a blackout date.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlackoutDateResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The stored blackout date.
+    pub blackout_date: BlackoutDateInfo,
+    /// A success message.
+    pub message: String,
+}
+
+/// API response for listing a bid year's blackout dates.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListBlackoutDatesResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The blackout dates, ordered by date.
+    pub blackout_dates: Vec<BlackoutDateInfo>,
+}
+
+/// API response for deleting a blackout date.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeleteBlackoutDateResponse {
+    /// The deleted blackout date identifier.
+    pub blackout_date_id: i64,
+    /// A success message.
+    pub message: String,
 }
 
 /// API request to override a user's area assignment.
@@ -1358,6 +1782,7 @@ pub struct OverrideAreaAssignmentRequest {
     /// The new area ID to assign.
     pub new_area_id: i64,
     /// The reason for the override (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
     pub reason: String,
 }
 
@@ -1366,6 +1791,8 @@ pub struct OverrideAreaAssignmentRequest {
 pub struct OverrideAreaAssignmentResponse {
     /// The audit event ID.
     pub audit_event_id: i64,
+    /// The override ledger ID, used to revert the override.
+    pub override_id: i64,
     /// Success message.
     pub message: String,
 }
@@ -1378,6 +1805,7 @@ pub struct OverrideEligibilityRequest {
     /// The new eligibility status.
     pub can_bid: bool,
     /// The reason for the override (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
     pub reason: String,
 }
 
@@ -1386,6 +1814,8 @@ pub struct OverrideEligibilityRequest {
 pub struct OverrideEligibilityResponse {
     /// The audit event ID.
     pub audit_event_id: i64,
+    /// The override ledger ID, used to revert the override.
+    pub override_id: i64,
     /// Success message.
     pub message: String,
 }
@@ -1398,6 +1828,7 @@ pub struct OverrideBidOrderRequest {
     /// The new bid order (or null to clear).
     pub bid_order: Option<i32>,
     /// The reason for the override (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
     pub reason: String,
 }
 
@@ -1406,6 +1837,8 @@ pub struct OverrideBidOrderRequest {
 pub struct OverrideBidOrderResponse {
     /// The audit event ID.
     pub audit_event_id: i64,
+    /// The override ledger ID, used to revert the override.
+    pub override_id: i64,
     /// Success message.
     pub message: String,
 }
@@ -1420,6 +1853,7 @@ pub struct OverrideBidWindowRequest {
     /// The new window end date (or null to clear).
     pub window_end: Option<String>,
     /// The reason for the override (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
     pub reason: String,
 }
 
@@ -1428,10 +1862,132 @@ pub struct OverrideBidWindowRequest {
 pub struct OverrideBidWindowResponse {
     /// The audit event ID.
     pub audit_event_id: i64,
+    /// The override ledger ID, used to revert the override.
+    pub override_id: i64,
     /// A success message.
     pub message: String,
 }
 
+/// An override recorded against a bid year's canonical data.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OverrideInfo {
+    /// The override ledger ID.
+    pub override_id: i64,
+    /// The overridden user's canonical identifier.
+    pub user_id: i64,
+    /// The overridden user's initials.
+    pub user_initials: String,
+    /// The override kind (`area_assignment`, `eligibility`, `bid_order`, `bid_window`).
+    pub override_kind: String,
+    /// The canonical value before the override.
+    pub previous_value: String,
+    /// The canonical value after the override.
+    pub new_value: String,
+    /// The reason given for the override.
+    pub reason: String,
+    /// The audit event that recorded the override.
+    pub audit_event_id: i64,
+    /// Whether the override has been reverted.
+    pub is_reverted: bool,
+    /// Whether the override can be reverted now.
+    pub is_revertible: bool,
+    /// When the override was recorded.
+    pub created_at: String,
+}
+
+/// API response listing the overrides for a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListOverridesResponse {
+    /// The canonical bid year ID.
+    pub bid_year_id: i64,
+    /// The bid year.
+    pub year: u16,
+    /// Overrides, oldest first.
+    pub overrides: Vec<OverrideInfo>,
+}
+
+/// API response for reverting an override.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RevertOverrideResponse {
+    /// The reverted override ledger ID.
+    pub override_id: i64,
+    /// The audit event ID recording the revert.
+    pub audit_event_id: i64,
+    /// Success message.
+    pub message: String,
+}
+
+/// API request to preview or apply a bulk override import.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OverrideImportRequest {
+    /// The canonical identifier of the bid year to override.
+    pub bid_year_id: i64,
+    /// The overrides, as CSV with one override per row.
+    pub csv_content: String,
+}
+
+/// An override a bulk import applies.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OverrideImportChange {
+    /// The row number (1-based, excluding header).
+    pub row_number: usize,
+    /// The user's canonical identifier.
+    pub user_id: i64,
+    /// The user's initials.
+    pub initials: String,
+    /// The user's area code.
+    pub area_code: String,
+    /// The overridden field (`eligibility` or `bid_order`).
+    pub field: String,
+    /// The current canonical value.
+    pub current_value: String,
+    /// The value the override sets.
+    pub new_value: String,
+    /// The reason recorded with the override.
+    pub reason: String,
+}
+
+/// A bulk override import row that fails validation.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct InvalidOverrideRow {
+    /// The row number (1-based, excluding header).
+    pub row_number: usize,
+    /// The initials from this row, if present.
+    pub initials: Option<String>,
+    /// Why the row is invalid.
+    pub reason: String,
+}
+
+/// Diff of a bulk override import against canonical data.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OverrideImportReport {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The bid year (display value).
+    pub bid_year: u16,
+    /// Number of rows in the CSV.
+    pub total_rows: usize,
+    /// Overrides that change canonical data, in row order.
+    pub changes: Vec<OverrideImportChange>,
+    /// Row numbers whose value already matches canonical data.
+    pub unchanged_rows: Vec<usize>,
+    /// Rows that fail validation, in row order.
+    pub invalid_rows: Vec<InvalidOverrideRow>,
+}
+
+/// API response for applying a bulk override import.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OverrideImportResponse {
+    /// What was applied.
+    pub report: OverrideImportReport,
+    /// The override ledger IDs, in row order.
+    pub override_ids: Vec<i64>,
+    /// The audit events recording the overrides, in row order.
+    pub audit_event_ids: Vec<i64>,
+    /// The correlation ID the overrides' audit events are recorded under.
+    pub correlation_id: String,
+}
+
 // ============================================================================
 // Phase 29G: Post-Confirmation Bid Order Adjustments
 // ============================================================================
@@ -1453,6 +2009,7 @@ pub struct AdjustBidOrderRequest {
     /// List of bid order adjustments to apply.
     pub adjustments: Vec<BidOrderAdjustment>,
     /// The reason for the adjustments (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
     pub reason: String,
 }
 
@@ -1481,6 +2038,7 @@ pub struct AdjustBidWindowRequest {
     /// The new window end datetime (ISO 8601 format).
     pub new_window_end: String,
     /// The reason for the adjustment (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
     pub reason: String,
 }
 
@@ -1503,6 +2061,7 @@ pub struct RecalculateBidWindowsRequest {
     /// List of round IDs to recalculate windows for.
     pub rounds: Vec<i64>,
     /// The reason for the recalculation (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
     pub reason: String,
 }
 
@@ -1526,6 +2085,7 @@ pub struct RecalculateBidWindowsResponse {
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct CreateRoundGroupRequest {
     /// The name of the round group (must be unique within bid year).
+    #[serde(deserialize_with = "crate::free_text::name")]
     pub name: String,
     /// Whether editing is enabled for this round group.
     pub editing_enabled: bool,
@@ -1552,6 +2112,7 @@ pub struct UpdateRoundGroupRequest {
     /// The round group ID to update.
     pub round_group_id: i64,
     /// The new name (must be unique within bid year).
+    #[serde(deserialize_with = "crate::free_text::name")]
     pub name: String,
     /// Whether editing is enabled.
     pub editing_enabled: bool,
@@ -1609,6 +2170,7 @@ pub struct CreateRoundRequest {
     /// The round number (must be unique within area).
     pub round_number: u32,
     /// The display name for this round.
+    #[serde(deserialize_with = "crate::free_text::name")]
     pub name: String,
     /// Maximum number of slots per day.
     pub slots_per_day: u32,
@@ -1647,6 +2209,7 @@ pub struct UpdateRoundRequest {
     /// The round number (must be unique within area).
     pub round_number: u32,
     /// The display name.
+    #[serde(deserialize_with = "crate::free_text::name")]
     pub name: String,
     /// Maximum number of slots per day.
     pub slots_per_day: u32,
@@ -1696,6 +2259,18 @@ pub struct RoundInfo {
     pub include_holidays: bool,
     /// Whether overbidding is allowed in this round.
     pub allow_overbid: bool,
+    /// The criteria limiting who bids in this round, or `None` if every
+    /// bidder in the area takes part.
+    pub eligibility: Option<RoundEligibilityInfo>,
+}
+
+/// The criteria limiting a round to a subset of an area's bidders.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RoundEligibilityInfo {
+    /// The projected leave balance, in hours, a bidder needs to take part.
+    pub min_remaining_hours: Option<u32>,
+    /// The user types taking part; empty admits every user type.
+    pub user_types: Vec<String>,
 }
 
 /// API response for listing rounds.
@@ -1714,10 +2289,152 @@ pub struct DeleteRoundResponse {
     pub message: String,
 }
 
-/// API response for bid year readiness evaluation.
+/// API request to renumber the rounds of a round group.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[allow(dead_code)] // Phase 29D: Will be used when wired up in server
-pub struct GetBidYearReadinessResponse {
+pub struct ReorderRoundsRequest {
+    /// The round group whose rounds are renumbered.
+    pub round_group_id: i64,
+    /// Every round ID in the round group, in the new order.
+    pub round_ids: Vec<i64>,
+}
+
+/// API response for a successful round renumbering.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReorderRoundsResponse {
+    /// The round group ID.
+    pub round_group_id: i64,
+    /// The rounds with their new numbers, ordered by round number.
+    pub rounds: Vec<RoundInfo>,
+    /// A success message.
+    pub message: String,
+}
+
+/// A round definition within a round group template.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RoundTemplateSpec {
+    /// The round number (must be unique within the template).
+    pub round_number: u32,
+    /// The display name for this round.
+    pub name: String,
+    /// Maximum number of slots per day.
+    pub slots_per_day: u32,
+    /// Maximum number of groups.
+    pub max_groups: u32,
+    /// Maximum total hours.
+    pub max_total_hours: u32,
+    /// Whether holidays are included in groups.
+    pub include_holidays: bool,
+    /// Whether overbidding is allowed.
+    pub allow_overbid: bool,
+}
+
+/// API request to create a round group template.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateRoundGroupTemplateRequest {
+    /// The template name (must be unique across all templates).
+    #[serde(deserialize_with = "crate::free_text::name")]
+    pub name: String,
+    /// Whether editing is enabled for round groups created from this template.
+    pub editing_enabled: bool,
+    /// The rounds created with each instantiated round group.
+    pub rounds: Vec<RoundTemplateSpec>,
+}
+
+/// API response for a successful round group template creation.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateRoundGroupTemplateResponse {
+    /// The template identifier.
+    pub template_id: i64,
+    /// The template name.
+    pub name: String,
+    /// The number of rounds in the template.
+    pub round_count: usize,
+    /// A success message.
+    pub message: String,
+}
+
+/// Information about a round group template.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RoundGroupTemplateInfo {
+    /// The template identifier.
+    pub template_id: i64,
+    /// The template name.
+    pub name: String,
+    /// Whether editing is enabled for round groups created from this template.
+    pub editing_enabled: bool,
+    /// The template's rounds, ordered by round number.
+    pub rounds: Vec<RoundTemplateSpec>,
+    /// When the template was created.
+    pub created_at: String,
+}
+
+/// API response for listing round group templates.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListRoundGroupTemplatesResponse {
+    /// All templates, ordered by name.
+    pub templates: Vec<RoundGroupTemplateInfo>,
+}
+
+/// API response for deleting a round group template.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeleteRoundGroupTemplateResponse {
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to instantiate a round group template in a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ApplyRoundGroupTemplateRequest {
+    /// The template to instantiate.
+    pub template_id: i64,
+    /// The name of the new round group (defaults to the template name).
+    #[serde(default, deserialize_with = "crate::free_text::optional_name")]
+    pub name: Option<String>,
+}
+
+/// API response for a successful template instantiation.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ApplyRoundGroupTemplateResponse {
+    /// The new round group identifier.
+    pub round_group_id: i64,
+    /// The bid year ID the round group was created in.
+    pub bid_year_id: i64,
+    /// The round group name.
+    pub name: String,
+    /// The number of rounds created.
+    pub round_count: usize,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to copy round configuration between bid years.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CopyRoundConfigRequest {
+    /// The bid year to copy round groups and rounds from.
+    pub from_bid_year_id: i64,
+    /// The bid year to copy round groups and rounds into.
+    pub to_bid_year_id: i64,
+}
+
+/// API response for a successful round configuration copy.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CopyRoundConfigResponse {
+    /// The source bid year ID.
+    pub from_bid_year_id: i64,
+    /// The target bid year ID.
+    pub to_bid_year_id: i64,
+    /// The new round group IDs in the target bid year.
+    pub round_group_ids: Vec<i64>,
+    /// The number of rounds created.
+    pub round_count: usize,
+    /// A success message.
+    pub message: String,
+}
+
+/// API response for bid year readiness evaluation.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[allow(dead_code)] // Phase 29D: Will be used when wired up in server
+pub struct GetBidYearReadinessResponse {
     /// The bid year ID being evaluated.
     pub bid_year_id: i64,
     /// The bid year value (for display).
@@ -1728,6 +2445,9 @@ pub struct GetBidYearReadinessResponse {
     pub blocking_reasons: Vec<String>,
     /// Detailed breakdown per criterion.
     pub details: ReadinessDetailsInfo,
+    /// Non-blocking issues worth reviewing, such as controllers projected
+    /// to lose annual leave at year end.
+    pub warnings: Vec<String>,
 }
 
 /// Detailed readiness breakdown.
@@ -1744,18 +2464,132 @@ pub struct ReadinessDetailsInfo {
     pub seniority_conflicts: usize,
     /// Whether bid schedule is set and valid.
     pub bid_schedule_set: bool,
+    /// Number of users whose awarded leave still leaves them over the
+    /// use-or-lose threshold.
+    pub users_over_use_or_lose: usize,
+    /// Number of users whose awarded and pending leave exceeds their
+    /// projected accrual.
+    pub users_exceeding_accrual: usize,
+}
+
+/// Bootstrap progress for one area of a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AreaBootstrapStatusInfo {
+    /// The canonical area identifier.
+    pub area_id: i64,
+    /// The area code (display value).
+    pub area_code: String,
+    /// Expected user count, if set.
+    pub expected_user_count: Option<u32>,
+    /// Actual user count.
+    pub actual_user_count: usize,
+    /// Whether the area's user count is complete.
+    pub is_complete: bool,
+    /// Blocking reasons preventing completeness.
+    pub blocking_reasons: Vec<BlockingReason>,
+    /// Whether the area is flagged as having no rounds configured.
+    pub is_missing_rounds: bool,
+    /// Seniority conflicts detected among the area's users.
+    pub seniority_conflicts: Vec<String>,
+}
+
+/// API response combining a bid year's completeness, readiness, and
+/// lifecycle state.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetBidYearBootstrapStatusResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The bid year (display value).
+    pub year: u16,
+    /// Whether this bid year is active.
+    pub is_active: bool,
+    /// The lifecycle state of the bid year.
+    pub lifecycle_state: String,
+    /// Expected area count, if set.
+    pub expected_area_count: Option<u32>,
+    /// Actual area count.
+    pub actual_area_count: usize,
+    /// Whether the bid year's area count is complete.
+    pub is_complete: bool,
+    /// Completeness blocking reasons for the bid year, including system-wide
+    /// blockers such as a missing active bid year.
+    pub blocking_reasons: Vec<BlockingReason>,
+    /// Bootstrap progress per area.
+    pub areas: Vec<AreaBootstrapStatusInfo>,
+    /// Readiness evaluation for confirmation.
+    pub readiness: GetBidYearReadinessResponse,
+}
+
+/// API request to review a No Bid user.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct ReviewNoBidUserRequest {
+    /// The user's canonical identifier.
+    pub user_id: i64,
+    /// The reason for the review (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
+    pub reason: String,
 }
 
 /// API response for reviewing a No Bid user.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-#[allow(dead_code)] // Phase 29D: Will be used when wired up in server
 pub struct ReviewNoBidUserResponse {
     /// The user ID that was reviewed.
     pub user_id: i64,
+    /// The audit event ID recording the review.
+    pub audit_event_id: i64,
+    /// Success message.
+    pub message: String,
+}
+
+/// API request to review several No Bid users with a shared reason.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct ReviewNoBidUsersRequest {
+    /// The canonical identifiers of the users to review.
+    pub user_ids: Vec<i64>,
+    /// The reason for the review (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
+    pub reason: String,
+}
+
+/// API response for a batch No Bid review.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReviewNoBidUsersResponse {
+    /// One entry per reviewed user, in request order.
+    pub reviewed: Vec<ReviewNoBidUserResponse>,
     /// Success message.
     pub message: String,
 }
 
+/// An unreviewed user in the No Bid system area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UnreviewedNoBidUserInfo {
+    /// The user's canonical identifier.
+    pub user_id: i64,
+    /// The user's initials.
+    pub initials: String,
+    /// The user's name.
+    pub name: String,
+    /// The audit action that placed the user in No Bid, if recorded.
+    pub placement_action: Option<String>,
+    /// The audit event that placed the user in No Bid, if recorded.
+    pub placement_audit_event_id: Option<i64>,
+    /// Why the user landed in No Bid.
+    pub placement_reason: String,
+}
+
+/// API response listing unreviewed No Bid users for a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListUnreviewedNoBidUsersResponse {
+    /// The canonical bid year ID.
+    pub bid_year_id: i64,
+    /// The bid year.
+    pub year: u16,
+    /// The No Bid area ID, or `None` if the bid year has no system area.
+    pub area_id: Option<i64>,
+    /// Users awaiting review.
+    pub users: Vec<UnreviewedNoBidUserInfo>,
+}
+
 /// API response for bid order preview.
 ///
 /// This is a read-only preview of the derived bid order that will be frozen at confirmation.
@@ -1903,6 +2737,7 @@ pub struct TransitionBidStatusRequest {
     /// The new status value.
     pub new_status: String,
     /// Required notes explaining the transition (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::note")]
     pub notes: String,
 }
 
@@ -1940,6 +2775,7 @@ pub struct BulkUpdateBidStatusRequest {
     /// The new status value.
     pub new_status: String,
     /// Required notes explaining the bulk update (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::note")]
     pub notes: String,
 }
 
@@ -1953,3 +2789,2700 @@ pub struct BulkUpdateBidStatusResponse {
     /// Success message.
     pub message: String,
 }
+
+/// Scope selector for an audit timeline request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuditTimelineScope {
+    /// Events not tied to any bid year or area (operator management, system events).
+    Global,
+    /// All events within a bid year, including area-scoped events.
+    BidYear {
+        /// The canonical bid year identifier.
+        bid_year_id: i64,
+    },
+    /// Events within a single area.
+    Area {
+        /// The canonical bid year identifier.
+        bid_year_id: i64,
+        /// The canonical area identifier.
+        area_id: i64,
+    },
+}
+
+/// Optional filters for an audit timeline request.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AuditTimelineFilter {
+    /// Only include events with this action name (e.g., `RegisterUser`).
+    pub action_name: Option<String>,
+    /// Only include events performed by this operator.
+    pub actor_operator_id: Option<i64>,
+    /// Only include events created at or after this timestamp (`YYYY-MM-DD HH:MM:SS`).
+    pub from: Option<String>,
+    /// Only include events created at or before this timestamp (`YYYY-MM-DD HH:MM:SS`).
+    pub to: Option<String>,
+    /// Only include events about the user who holds or held these initials,
+    /// under any initials they have held. Requires a bid year scope.
+    pub user_initials: Option<String>,
+}
+
+/// Pagination parameters for an audit timeline request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+pub struct AuditTimelinePageRequest {
+    /// Cursor returned as `next_cursor` by the previous page.
+    pub after_event_id: Option<i64>,
+    /// Maximum number of entries to return (defaults to 50, capped at 500).
+    pub limit: Option<u32>,
+}
+
+/// The actor recorded on an audit timeline entry.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AuditActorInfo {
+    /// The actor type (e.g., "admin", "bidder").
+    pub actor_type: String,
+    /// The operator ID, if the event was performed by an operator.
+    pub operator_id: Option<i64>,
+    /// The operator login name at the time of the event.
+    pub login_name: Option<String>,
+    /// The operator display name at the time of the event.
+    pub display_name: Option<String>,
+}
+
+/// A single field that differs between an event's before and after snapshots.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AuditFieldChange {
+    /// The snapshot field name.
+    pub field: String,
+    /// The value before the event, if present.
+    pub before: Option<String>,
+    /// The value after the event, if present.
+    pub after: Option<String>,
+}
+
+/// A single entry in the audit timeline.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AuditTimelineEntryInfo {
+    /// The audit event identifier.
+    pub event_id: i64,
+    /// When the event was recorded.
+    pub created_at: Option<String>,
+    /// Who performed the action.
+    pub actor: AuditActorInfo,
+    /// The action name.
+    pub action_name: String,
+    /// Optional action details.
+    pub action_details: Option<String>,
+    /// The cause description supplied with the action.
+    pub cause_description: String,
+    /// The bid year (absent for global events).
+    pub bid_year: Option<u16>,
+    /// The area code (absent for global and bid-year-level events).
+    pub area_code: Option<String>,
+    /// Fields that changed between the before and after snapshots.
+    pub diff_summary: Vec<AuditFieldChange>,
+    /// Notes operators attached to the event, oldest first.
+    pub annotations: Vec<AuditAnnotationInfo>,
+}
+
+/// A note an operator attached to an audit event after the fact.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AuditAnnotationInfo {
+    /// The annotation identifier.
+    pub annotation_id: i64,
+    /// The operator who wrote the note.
+    pub operator_id: i64,
+    /// The operator's login name.
+    pub login_name: String,
+    /// The operator's display name.
+    pub display_name: String,
+    /// The note text.
+    pub note: String,
+    /// When the note was written.
+    pub created_at: String,
+}
+
+/// API request for annotating an audit event.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AnnotateAuditEventRequest {
+    /// The audit event to annotate.
+    pub event_id: i64,
+    /// The note explaining the event.
+    #[serde(deserialize_with = "crate::free_text::note")]
+    pub note: String,
+}
+
+/// API response for annotating an audit event.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AnnotateAuditEventResponse {
+    /// The new annotation identifier.
+    pub annotation_id: i64,
+    /// The annotated audit event.
+    pub event_id: i64,
+    /// A success message.
+    pub message: String,
+}
+
+/// API response for an audit timeline request.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetAuditTimelineResponse {
+    /// Entries in ascending event order.
+    pub entries: Vec<AuditTimelineEntryInfo>,
+    /// Cursor for the next page, if more entries exist.
+    pub next_cursor: Option<i64>,
+}
+
+/// API request for saving a named audit query.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateSavedAuditQueryRequest {
+    /// The unique query name (e.g., "All overrides 2026").
+    pub name: String,
+    /// The timeline scope to search.
+    pub scope: AuditTimelineScope,
+    /// The timeline filters to apply.
+    #[serde(default)]
+    pub filter: AuditTimelineFilter,
+    /// Addresses to email the results to each week. Empty for none.
+    #[serde(default)]
+    pub weekly_recipients: Vec<String>,
+}
+
+/// API request for changing a saved audit query's weekly recipients.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ScheduleSavedAuditQueryRequest {
+    /// The saved query ID.
+    pub saved_query_id: i64,
+    /// Addresses to email the results to each week. Empty stops delivery.
+    pub weekly_recipients: Vec<String>,
+}
+
+/// API request for deleting a saved audit query.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeleteSavedAuditQueryRequest {
+    /// The saved query ID to delete.
+    pub saved_query_id: i64,
+}
+
+/// API response for deleting a saved audit query.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeleteSavedAuditQueryResponse {
+    /// Confirmation message.
+    pub message: String,
+}
+
+/// A saved audit query and its weekly delivery schedule.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SavedAuditQueryInfo {
+    /// The saved query ID.
+    pub saved_query_id: i64,
+    /// The unique query name.
+    pub name: String,
+    /// The timeline scope searched.
+    pub scope: AuditTimelineScope,
+    /// The timeline filters applied.
+    pub filter: AuditTimelineFilter,
+    /// Addresses the results are emailed to each week; empty when the
+    /// query is not scheduled.
+    pub weekly_recipients: Vec<String>,
+    /// The operator who saved the query.
+    pub created_by_operator_id: i64,
+    /// Created timestamp.
+    pub created_at: String,
+    /// When the results were last emailed (RFC 3339).
+    pub last_delivered_at: Option<String>,
+}
+
+/// API response for saving or rescheduling an audit query.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SavedAuditQueryResponse {
+    /// The saved query.
+    pub saved_query: SavedAuditQueryInfo,
+}
+
+/// API response for listing saved audit queries.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListSavedAuditQueriesResponse {
+    /// The saved queries, ordered by name.
+    pub saved_queries: Vec<SavedAuditQueryInfo>,
+}
+
+/// An audit event a rollback would supersede.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SupersededEventInfo {
+    /// The audit event identifier.
+    pub event_id: i64,
+    /// The action name.
+    pub action_name: String,
+    /// The login name of the operator who performed the action.
+    pub actor_login_name: String,
+    /// When the event was recorded.
+    pub created_at: Option<String>,
+}
+
+/// An event outside the rolled back area that depends on a superseded event.
+///
+/// A rollback supersedes its dependents too, and is refused unless the
+/// cascade is confirmed.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DependentEventInfo {
+    /// The dependent audit event identifier.
+    pub event_id: i64,
+    /// The dependent event's action name.
+    pub action_name: String,
+    /// The superseded or dependent event it was derived from.
+    pub depends_on_event_id: i64,
+    /// When the dependent event was recorded.
+    pub created_at: Option<String>,
+}
+
+/// How a rollback would change one user.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RollbackUserChange {
+    /// The user's canonical identifier, if the user has one.
+    pub user_id: Option<i64>,
+    /// The user's initials.
+    pub initials: String,
+    /// `removed` (added since the target), `restored` (removed since the
+    /// target), or `reverted` (details changed since the target).
+    pub change: String,
+}
+
+/// A leave bid entered after a rollback's target event.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RollbackLeaveBidInfo {
+    /// The leave bid identifier.
+    pub leave_bid_id: i64,
+    /// The bidding user's canonical identifier.
+    pub user_id: i64,
+    /// The round the leave was bid in.
+    pub round_id: i64,
+    /// The day of leave (`YYYY-MM-DD`).
+    pub leave_date: String,
+}
+
+/// API response previewing a rollback before it is confirmed.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RollbackPreviewResponse {
+    /// The event the area would be rolled back to.
+    pub target_event_id: i64,
+    /// The target event's action name.
+    pub target_action_name: String,
+    /// The bid year of the rolled back area.
+    pub bid_year: u16,
+    /// The rolled back area's code.
+    pub area_code: String,
+    /// The snapshot event the area's users would be restored from: the
+    /// target itself, or the last snapshot before it.
+    pub snapshot_event_id: i64,
+    /// Events in the area after the target, oldest first.
+    pub superseded_events: Vec<SupersededEventInfo>,
+    /// Users whose records the rollback would change, by initials.
+    pub affected_users: Vec<RollbackUserChange>,
+    /// Approved leave bids entered in the area after the target.
+    pub affected_leave_bids: Vec<RollbackLeaveBidInfo>,
+    /// Unreverted overrides recorded by superseded events.
+    pub affected_overrides: Vec<OverrideInfo>,
+    /// Events elsewhere derived from superseded events, directly or through
+    /// another dependent, that the rollback would also supersede.
+    pub dependent_events: Vec<DependentEventInfo>,
+    /// Whether the bid year's canonical data was derived from users the
+    /// rollback changes, so the rollback regenerates it.
+    pub requires_canonical_regeneration: bool,
+}
+
+/// API request for creating a checkpoint.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CheckpointRequest {
+    /// A name to find the checkpoint by, or `None` for an anonymous
+    /// checkpoint.
+    pub label: Option<String>,
+    /// A longer note about the checkpoint. Requires a label.
+    pub description: Option<String>,
+}
+
+/// A checkpoint in an area's audit log.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CheckpointInfo {
+    /// The checkpoint's audit event identifier.
+    pub event_id: i64,
+    /// The area's code.
+    pub area_code: String,
+    /// The label, or `None` for an anonymous checkpoint.
+    pub label: Option<String>,
+    /// The description, if the checkpoint has one.
+    pub description: Option<String>,
+    /// The login name of the operator who created the checkpoint.
+    pub actor_login_name: String,
+    /// When the checkpoint was recorded.
+    pub created_at: Option<String>,
+    /// Whether a rollback has superseded the checkpoint. A superseded
+    /// checkpoint can only be rolled back to if it was recorded
+    /// automatically before that rollback.
+    pub superseded: bool,
+    /// For an automatic checkpoint, the correlation ID of the operation it
+    /// was recorded before.
+    pub correlation_id: Option<String>,
+}
+
+/// API response listing checkpoints, newest first.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListCheckpointsResponse {
+    /// The bid year.
+    pub bid_year: u16,
+    /// The checkpoints.
+    pub checkpoints: Vec<CheckpointInfo>,
+}
+
+/// API request for creating a webhook.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateWebhookRequest {
+    /// The delivery URL (http or https).
+    pub url: String,
+    /// The signing secret. Generated if omitted.
+    pub secret: Option<String>,
+    /// Audit action names to deliver, or `["*"]` for every action.
+    pub event_filter: Vec<String>,
+}
+
+/// API response for creating a webhook.
+///
+/// This is the only response that includes the signing secret.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateWebhookResponse {
+    /// The created webhook.
+    pub webhook: WebhookInfo,
+    /// The signing secret, shown once.
+    pub secret: String,
+}
+
+/// API request for updating a webhook.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UpdateWebhookRequest {
+    /// The webhook ID to update.
+    pub webhook_id: i64,
+    /// The delivery URL (http or https).
+    pub url: String,
+    /// Audit action names to deliver, or `["*"]` for every action.
+    pub event_filter: Vec<String>,
+    /// Whether deliveries are active.
+    pub is_enabled: bool,
+}
+
+/// API response for updating a webhook.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UpdateWebhookResponse {
+    /// The updated webhook.
+    pub webhook: WebhookInfo,
+}
+
+/// API request for deleting a webhook.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeleteWebhookRequest {
+    /// The webhook ID to delete.
+    pub webhook_id: i64,
+}
+
+/// API response for deleting a webhook.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeleteWebhookResponse {
+    /// Confirmation message.
+    pub message: String,
+}
+
+/// Webhook information for listing. Never includes the signing secret.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WebhookInfo {
+    /// The webhook ID.
+    pub webhook_id: i64,
+    /// The delivery URL.
+    pub url: String,
+    /// Audit action names delivered to this webhook.
+    pub event_filter: Vec<String>,
+    /// Whether deliveries are active.
+    pub is_enabled: bool,
+    /// Created timestamp.
+    pub created_at: String,
+}
+
+/// API response for listing webhooks.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListWebhooksResponse {
+    /// The configured webhooks.
+    pub webhooks: Vec<WebhookInfo>,
+}
+
+/// A webhook delivery that exhausted its retries.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WebhookDeadLetterInfo {
+    /// The dead-letter entry ID.
+    pub dead_letter_id: i64,
+    /// The webhook the delivery was for.
+    pub webhook_id: i64,
+    /// The audit event that was being delivered.
+    pub audit_event_id: i64,
+    /// The JSON body that was sent.
+    pub payload: String,
+    /// The number of delivery attempts made.
+    pub attempts: i32,
+    /// The error from the final attempt.
+    pub last_error: String,
+    /// When the delivery was abandoned.
+    pub created_at: String,
+}
+
+/// API response for listing dead-lettered webhook deliveries.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListWebhookDeadLettersResponse {
+    /// Entries, newest first.
+    pub dead_letters: Vec<WebhookDeadLetterInfo>,
+}
+
+/// API request for setting a user's notification contact details.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetUserContactRequest {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// The email address to notify.
+    pub email: String,
+    /// Whether email notifications are sent.
+    pub email_enabled: bool,
+}
+
+/// A user's notification contact details.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UserContactInfo {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// The email address to notify.
+    pub email: String,
+    /// Whether email notifications are sent.
+    pub email_enabled: bool,
+    /// Last updated timestamp.
+    pub updated_at: String,
+}
+
+/// API response for setting a user's contact details.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetUserContactResponse {
+    /// The stored contact details.
+    pub contact: UserContactInfo,
+}
+
+/// API response for retrieving a user's contact details.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetUserContactResponse {
+    /// The contact details, if any are stored.
+    pub contact: Option<UserContactInfo>,
+}
+
+/// API request to issue a controller a portal link.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct IssuePortalLinkRequest {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// How many days the link works for. Defaults to 30.
+    #[serde(default)]
+    pub valid_days: Option<u32>,
+}
+
+/// API response for issuing a portal link.
+///
+/// The token is only ever returned here; it cannot be read back later.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct IssuePortalLinkResponse {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// The user's initials.
+    pub initials: String,
+    /// The link token to send to the controller.
+    pub portal_token: String,
+    /// When the link stops working (RFC 3339).
+    pub expires_at: String,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to revoke a controller's portal link.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RevokePortalLinkRequest {
+    /// The canonical user ID.
+    pub user_id: i64,
+}
+
+/// API response for revoking a portal link.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RevokePortalLinkResponse {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// Whether the user held a link.
+    pub revoked: bool,
+    /// A success message.
+    pub message: String,
+}
+
+/// Portal response with a controller's place in the bid order.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetPortalSeniorityResponse {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// The user's initials.
+    pub initials: String,
+    /// The user's area code.
+    pub area_code: String,
+    /// The user's position in the area's bid order, or `None` until the
+    /// bid year is canonicalized.
+    pub bid_order: Option<u32>,
+    /// How many users are in the area's bid order.
+    pub area_size: u32,
+}
+
+/// One of a controller's bid windows.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PortalWindowInfo {
+    /// The round ID.
+    pub round_id: i64,
+    /// The round number.
+    pub round_number: u32,
+    /// The round name.
+    pub round_name: String,
+    /// Window start (UTC, ISO 8601).
+    pub window_start_datetime: String,
+    /// Window end (UTC, ISO 8601).
+    pub window_end_datetime: String,
+}
+
+/// Portal response listing a controller's bid windows.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetPortalWindowsResponse {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// The user's windows in start order. Empty until windows are
+    /// calculated.
+    pub windows: Vec<PortalWindowInfo>,
+}
+
+/// One of a controller's submitted leave bids.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PortalLeaveBidInfo {
+    /// The leave bid identifier.
+    pub leave_bid_id: i64,
+    /// The round the leave was bid in.
+    pub round_id: i64,
+    /// The day of leave (`YYYY-MM-DD`).
+    pub leave_date: String,
+    /// Hours of leave taken on the day.
+    pub hours: i32,
+    /// `approved` or `withdrawn`.
+    pub status: String,
+}
+
+/// Portal response listing a controller's submitted leave bids.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetPortalBidsResponse {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// The user's leave bids in date order.
+    pub bids: Vec<PortalLeaveBidInfo>,
+}
+
+/// Portal response with a controller's projected leave balance.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetPortalBalanceResponse {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// The user's projected year-end balance, or `None` when the user is
+    /// excluded from leave calculation or accrual cannot be computed.
+    pub balance: Option<LeaveProjectionInfo>,
+}
+
+/// A notification sent (or attempted) to a user.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NotificationInfo {
+    /// The notification log ID.
+    pub notification_id: i64,
+    /// The user who was notified.
+    pub user_id: i64,
+    /// The notification kind (e.g. `window_opened`).
+    pub kind: String,
+    /// The bid window or bid status history ID the notification is about.
+    pub reference_id: i64,
+    /// The address the notification was sent to.
+    pub recipient: String,
+    /// The subject line.
+    pub subject: String,
+    /// Whether the mail server accepted the message.
+    pub delivered: bool,
+    /// The delivery error, if any.
+    pub error: Option<String>,
+    /// When the notification was sent.
+    pub sent_at: String,
+}
+
+/// API response for listing a user's notifications.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListUserNotificationsResponse {
+    /// Notifications, newest first.
+    pub notifications: Vec<NotificationInfo>,
+}
+
+/// API request for creating a chat channel.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateChatChannelRequest {
+    /// The bid year announced to this channel.
+    pub bid_year_id: i64,
+    /// The area announced to this channel, or `None` for every area.
+    pub area_id: Option<i64>,
+    /// The incoming webhook provider (`slack` or `teams`).
+    pub provider: String,
+    /// The incoming webhook URL (https).
+    pub url: String,
+}
+
+/// API response for creating a chat channel.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreateChatChannelResponse {
+    /// The created channel.
+    pub channel: ChatChannelInfo,
+}
+
+/// API request for updating a chat channel.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UpdateChatChannelRequest {
+    /// The chat channel ID to update.
+    pub chat_channel_id: i64,
+    /// The incoming webhook provider (`slack` or `teams`).
+    pub provider: String,
+    /// The new incoming webhook URL, or `None` to keep the current one.
+    pub url: Option<String>,
+    /// Whether announcements are posted.
+    pub is_enabled: bool,
+}
+
+/// API response for updating a chat channel.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UpdateChatChannelResponse {
+    /// The updated channel.
+    pub channel: ChatChannelInfo,
+}
+
+/// API request for deleting a chat channel.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeleteChatChannelRequest {
+    /// The chat channel ID to delete.
+    pub chat_channel_id: i64,
+}
+
+/// API response for deleting a chat channel.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeleteChatChannelResponse {
+    /// Confirmation message.
+    pub message: String,
+}
+
+/// Chat channel information for listing.
+///
+/// Incoming webhook URLs grant posting access, so only the host is shown.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChatChannelInfo {
+    /// The chat channel ID.
+    pub chat_channel_id: i64,
+    /// The bid year announced to this channel.
+    pub bid_year_id: i64,
+    /// The area announced to this channel, or `None` for every area.
+    pub area_id: Option<i64>,
+    /// The incoming webhook provider.
+    pub provider: String,
+    /// The host of the incoming webhook URL.
+    pub url_host: String,
+    /// Whether announcements are posted.
+    pub is_enabled: bool,
+    /// Created timestamp.
+    pub created_at: String,
+}
+
+/// API response for listing chat channels.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListChatChannelsResponse {
+    /// The configured channels.
+    pub channels: Vec<ChatChannelInfo>,
+}
+
+/// A recorded chat announcement.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChatNotificationInfo {
+    /// The log entry ID.
+    pub chat_notification_id: i64,
+    /// The channel the announcement was posted to.
+    pub chat_channel_id: i64,
+    /// The announcement kind.
+    pub kind: String,
+    /// What the announcement was about.
+    pub reference_key: String,
+    /// The posted text.
+    pub message: String,
+    /// Whether the provider accepted the announcement.
+    pub delivered: bool,
+    /// The delivery error, if any.
+    pub error: Option<String>,
+    /// When the announcement was attempted.
+    pub sent_at: String,
+}
+
+/// API response for listing chat announcements.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListChatNotificationsResponse {
+    /// Announcements, newest first.
+    pub notifications: Vec<ChatNotificationInfo>,
+}
+
+/// API request to render an area's seniority list.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetSeniorityReportRequest {
+    /// The canonical bid year ID.
+    pub bid_year_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The output format (`pdf` or `html`).
+    pub format: String,
+    /// Facility name printed above the title.
+    #[serde(default, deserialize_with = "crate::free_text::optional_name")]
+    pub facility_name: Option<String>,
+    /// Additional line printed below the title.
+    #[serde(default, deserialize_with = "crate::free_text::optional_note")]
+    pub header_text: Option<String>,
+    /// Text printed at the bottom of every page.
+    #[serde(default, deserialize_with = "crate::free_text::optional_note")]
+    pub footer_text: Option<String>,
+}
+
+/// API request to render daily leave coverage over a date range.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetCoverageReportRequest {
+    /// The canonical bid year ID.
+    pub bid_year_id: i64,
+    /// Restrict the report to one canonical area, or `None` for every area.
+    pub area_id: Option<i64>,
+    /// The first date to include (`YYYY-MM-DD`).
+    pub start_date: String,
+    /// The last date to include (`YYYY-MM-DD`).
+    pub end_date: String,
+    /// The output format (`pdf`, `html`, or `csv`).
+    pub format: String,
+}
+
+/// API request to render one round's results for an area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetRoundResultsReportRequest {
+    /// The canonical bid year ID.
+    pub bid_year_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The output format (`pdf`, `csv`, or `xlsx`).
+    pub format: String,
+}
+
+/// The bidder whose window is active now in an area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CurrentBidderInfo {
+    /// The canonical bid year ID.
+    pub bid_year_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round in progress.
+    pub round_id: i64,
+    /// The round number.
+    pub round_number: i32,
+    /// The round display name.
+    pub round_name: String,
+    /// The current bidder's canonical user ID.
+    pub user_id: i64,
+    /// The current bidder's initials.
+    pub initials: String,
+    /// The bidder's window start (UTC, ISO 8601), if a window was calculated.
+    pub window_start_datetime: Option<String>,
+    /// The bidder's window end (UTC, ISO 8601), if a window was calculated.
+    pub window_end_datetime: Option<String>,
+    /// When the user became the current bidder (ISO 8601).
+    pub became_current_at: String,
+}
+
+/// API response for the current bidder of an area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetCurrentBidderResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The current bidder, or `None` if no round is in progress.
+    pub current_bidder: Option<CurrentBidderInfo>,
+}
+
+/// API request for the live bidding progress of an area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetBiddingProgressRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// How many upcoming bidders to list. Defaults to 5.
+    pub upcoming_count: Option<u32>,
+}
+
+/// A round as shown on the live bidding display.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BiddingProgressRoundInfo {
+    /// The round ID.
+    pub round_id: i64,
+    /// The round number.
+    pub round_number: u32,
+    /// The round display name.
+    pub round_name: String,
+    /// Whether the round has a current bidder yet.
+    pub started: bool,
+}
+
+/// A bidder waiting for their window in the current round.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UpcomingBidderInfo {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// The user's initials.
+    pub initials: String,
+    /// Window start (UTC, ISO 8601).
+    pub window_start_datetime: String,
+    /// Window end (UTC, ISO 8601).
+    pub window_end_datetime: String,
+}
+
+/// A recent bid event in an area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BidEventInfo {
+    /// The audit event ID.
+    pub event_id: i64,
+    /// The action name (e.g. `EnterLeaveBid`).
+    pub action: String,
+    /// The action details.
+    pub details: Option<String>,
+    /// When the event was recorded, if known.
+    pub created_at: Option<String>,
+}
+
+/// API response with the live bidding progress of an area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetBiddingProgressResponse {
+    /// The canonical bid year ID.
+    pub bid_year_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round in progress, or the next round to open if none is.
+    pub current_round: Option<BiddingProgressRoundInfo>,
+    /// The bidder whose window is active now.
+    pub current_bidder: Option<CurrentBidderInfo>,
+    /// The bidders after the current one in the current round, in order.
+    pub upcoming_bidders: Vec<UpcomingBidderInfo>,
+    /// Bid statuses that are final, across every round.
+    pub completed: u32,
+    /// Bid statuses across every round.
+    pub total: u32,
+    /// Whole percentage of bid statuses that are final.
+    pub percent_complete: u32,
+    /// The latest bid events in the area, newest first.
+    pub recent_events: Vec<BidEventInfo>,
+}
+
+/// API request to advance a round to its next bidder.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AdvanceBidderRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round to advance.
+    pub round_id: i64,
+}
+
+/// API response for advancing a round to its next bidder.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AdvanceBidderResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The advanced round.
+    pub round_id: i64,
+    /// The user whose window ended, or `None` if the round just started.
+    pub previous_user_id: Option<i64>,
+    /// The user whose window is now active, or `None` if the round is complete.
+    pub next_user_id: Option<i64>,
+    /// The area's current bidder after the advance.
+    pub current_bidder: Option<CurrentBidderInfo>,
+    /// The rank of the next bidder's pre-submitted preference converted
+    /// into leave, if any.
+    pub applied_preference_rank: Option<u32>,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to enter leave bid days on behalf of a controller.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EnterLeaveBidRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round the leave is bid in.
+    pub round_id: i64,
+    /// The initials of the controller the bid is entered for.
+    pub on_behalf_of: String,
+    /// How the bid was received (`phone`, `in_person`, or `written`).
+    pub received_via: String,
+    /// The leave days bid (ISO 8601 dates).
+    pub leave_dates: Vec<String>,
+    /// The leave hours charged per day.
+    pub hours: u32,
+    /// Why leave is entered after the round was signed off. Admin only.
+    #[serde(default, deserialize_with = "crate::free_text::optional_reason")]
+    pub override_reason: Option<String>,
+}
+
+/// API response for entering leave bid days.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EnterLeaveBidResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round the leave was bid in.
+    pub round_id: i64,
+    /// The controller's canonical user ID.
+    pub user_id: i64,
+    /// The initials of the controller the bid was entered for.
+    pub on_behalf_of: String,
+    /// How the bid was received.
+    pub received_via: String,
+    /// The created leave bid IDs, in date order.
+    pub leave_bid_ids: Vec<i64>,
+    /// A success message.
+    pub message: String,
+}
+
+/// One ranked leave choice in a preference list.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BidPreferenceEntry {
+    /// The leave days (ISO 8601 dates).
+    pub leave_dates: Vec<String>,
+    /// The leave hours charged per day.
+    pub hours: u32,
+}
+
+/// API request to submit a controller's ranked leave preferences.
+///
+/// Preferences are ranked in list order, the first being the most preferred.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SubmitBidPreferencesRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round the preferences are for.
+    pub round_id: i64,
+    /// The initials of the controller the preferences are entered for.
+    pub on_behalf_of: String,
+    /// How the preferences were received (`phone`, `in_person`, or `written`).
+    pub received_via: String,
+    /// The preferences, most preferred first.
+    pub preferences: Vec<BidPreferenceEntry>,
+}
+
+/// API response for submitting ranked leave preferences.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SubmitBidPreferencesResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round the preferences are for.
+    pub round_id: i64,
+    /// The controller's canonical user ID.
+    pub user_id: i64,
+    /// The initials of the controller the preferences were entered for.
+    pub on_behalf_of: String,
+    /// How the preferences were received.
+    pub received_via: String,
+    /// The number of preferences recorded.
+    pub preference_count: usize,
+    /// A success message.
+    pub message: String,
+}
+
+/// A stored ranked leave preference.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BidPreferenceInfo {
+    /// The preference ID.
+    pub bid_preference_id: i64,
+    /// The controller's canonical user ID.
+    pub user_id: i64,
+    /// The rank, 1 being the most preferred.
+    pub preference_rank: i32,
+    /// The leave days (ISO 8601 dates).
+    pub leave_dates: Vec<String>,
+    /// The leave hours charged per day.
+    pub hours: i32,
+    /// The initials of the controller the preference was entered for.
+    pub on_behalf_of: String,
+    /// How the preference was received.
+    pub received_via: String,
+    /// `pending`, `applied`, `passed_over`, or `unused`.
+    pub status: String,
+}
+
+/// API response listing the ranked leave preferences for a round.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListBidPreferencesResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The preferences, by user then rank.
+    pub preferences: Vec<BidPreferenceInfo>,
+}
+
+/// API request to read the leave slot inventory of a round over a date range.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetSlotInventoryRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The first date to include (`YYYY-MM-DD`).
+    pub start_date: String,
+    /// The last date to include (`YYYY-MM-DD`).
+    pub end_date: String,
+}
+
+/// The leave slots on one day of a round.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SlotInventoryDayInfo {
+    /// The date (`YYYY-MM-DD`).
+    pub date: String,
+    /// The round's configured `slots_per_day`.
+    pub slots_per_day: u32,
+    /// Whether this day is a prime date.
+    pub prime: bool,
+    /// The round's cap on prime dates, when it applies on this day.
+    pub prime_slots_per_day: Option<u32>,
+    /// The staffing adjustment applied on this day.
+    pub staffing_adjustment: i32,
+    /// The day's capacity after the staffing adjustment.
+    pub capacity: u32,
+    /// The approved leave already held on this day.
+    pub used: u32,
+    /// The slots still open on this day.
+    pub remaining: u32,
+}
+
+/// API response with the leave slot inventory of a round.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetSlotInventoryResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// One entry per day in the range, in date order.
+    pub days: Vec<SlotInventoryDayInfo>,
+}
+
+/// API request to adjust the leave slots of one day of a round for staffing.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AdjustSlotInventoryRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The date to adjust (`YYYY-MM-DD`).
+    pub date: String,
+    /// The change to the round's `slots_per_day` on this day.
+    pub staffing_adjustment: i32,
+}
+
+/// API response for a slot inventory adjustment.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AdjustSlotInventoryResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The adjusted day after the change.
+    pub day: SlotInventoryDayInfo,
+    /// A success message.
+    pub message: String,
+}
+
+/// The daily leave slots allocated to one crew.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CrewSlotsInfo {
+    /// The crew number (1-7).
+    pub crew: u8,
+    /// The slots the crew is offered per day.
+    pub slots_per_day: u32,
+}
+
+/// A round that allocates its daily slots per crew.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RoundCrewSlotsInfo {
+    /// The round ID.
+    pub round_id: i64,
+    /// Each crew's slots, ordered by crew.
+    pub crew_slots: Vec<CrewSlotsInfo>,
+}
+
+/// API request to switch a round between area-wide and per-crew slots.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetRoundCrewSlotsRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// Whether the round allocates its slots per crew.
+    pub crew_partitioned: bool,
+    /// Each crew's slots. Must be empty unless `crew_partitioned` is set.
+    #[serde(default)]
+    pub crew_slots: Vec<CrewSlotsInfo>,
+}
+
+/// API response for switching a round between area-wide and per-crew slots.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetRoundCrewSlotsResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// Whether the round allocates its slots per crew.
+    pub crew_partitioned: bool,
+    /// Each crew's slots, ordered by crew.
+    pub crew_slots: Vec<CrewSlotsInfo>,
+    /// A success message.
+    pub message: String,
+}
+
+/// API response listing a bid year's crew-partitioned rounds.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListRoundCrewSlotsResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The rounds that allocate slots per crew, ordered by round.
+    pub rounds: Vec<RoundCrewSlotsInfo>,
+}
+
+/// API request to sign off a round in an area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SignOffRoundRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round to sign off.
+    pub round_id: i64,
+}
+
+/// API response for signing off a round.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SignOffRoundResponse {
+    /// The sign-off's identifier.
+    pub round_sign_off_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The signed-off round.
+    pub round_id: i64,
+    /// The audit event that recorded the sign-off.
+    pub audit_event_id: i64,
+    /// Eligible users who bid, themselves or by proxy.
+    pub bid: usize,
+    /// Eligible users skipped after missing their window.
+    pub skipped: usize,
+    /// Eligible users who waived bidding.
+    pub waived: usize,
+    /// A success message.
+    pub message: String,
+    /// The correlation ID linking the sign-off to the automatic checkpoint
+    /// recorded before it.
+    pub correlation_id: String,
+}
+
+/// A round signed off in an area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RoundSignOffInfo {
+    /// The sign-off's identifier.
+    pub round_sign_off_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The signed-off round.
+    pub round_id: i64,
+    /// The audit event that recorded the sign-off.
+    pub audit_event_id: i64,
+    /// When the round was signed off.
+    pub signed_off_at: String,
+}
+
+/// API response listing a bid year's round sign-offs.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListRoundSignOffsResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The sign-offs, ordered by area then round.
+    pub sign_offs: Vec<RoundSignOffInfo>,
+}
+
+/// API request to freeze an area against every change.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FreezeScopeRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// Why the area is frozen (at least 10 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
+    pub reason: String,
+}
+
+/// API response for freezing an area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FreezeScopeResponse {
+    /// The freeze's identifier.
+    pub scope_freeze_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The audit event that recorded the freeze.
+    pub audit_event_id: i64,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to lift an area's freeze.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UnfreezeScopeRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+}
+
+/// API response for lifting an area's freeze.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UnfreezeScopeResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The audit event that recorded the unfreeze.
+    pub audit_event_id: i64,
+    /// A success message.
+    pub message: String,
+}
+
+/// A frozen area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ScopeFreezeInfo {
+    /// The freeze's identifier.
+    pub scope_freeze_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// Why the area is frozen.
+    pub reason: String,
+    /// The audit event that recorded the freeze.
+    pub audit_event_id: i64,
+    /// When the area was frozen.
+    pub frozen_at: String,
+}
+
+/// API response listing a bid year's frozen areas.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListScopeFreezesResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The frozen areas, ordered by area.
+    pub freezes: Vec<ScopeFreezeInfo>,
+}
+
+/// API response for a bid year's amendment policy.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetBidAmendmentPolicyResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// `unrestricted`, `not_allowed`, `until_window_closes`, or
+    /// `within_hours`.
+    pub policy: String,
+    /// The amendment window in hours, for `within_hours`.
+    pub window_hours: Option<u32>,
+}
+
+/// API request to set a bid year's amendment policy.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetBidAmendmentPolicyRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// `unrestricted`, `not_allowed`, `until_window_closes`, or
+    /// `within_hours`.
+    pub policy: String,
+    /// The amendment window in hours (required for `within_hours` only).
+    #[serde(default)]
+    pub window_hours: Option<u32>,
+}
+
+/// API response for setting a bid year's amendment policy.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetBidAmendmentPolicyResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The stored policy.
+    pub policy: String,
+    /// The stored amendment window in hours, for `within_hours`.
+    pub window_hours: Option<u32>,
+    /// A success message.
+    pub message: String,
+}
+
+/// A feature flag's setting in a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FeatureFlagInfo {
+    /// `waitlists` or `auto_advance`.
+    pub flag: String,
+    /// Whether the feature is enabled.
+    pub enabled: bool,
+    /// Whether the setting is the flag's default rather than a stored one.
+    pub is_default: bool,
+}
+
+/// API response for a bid year's feature flags.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetFeatureFlagsResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// Every feature flag, in display order.
+    pub flags: Vec<FeatureFlagInfo>,
+}
+
+/// API request to enable or disable a feature in a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetFeatureFlagRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// `waitlists` or `auto_advance`.
+    pub flag: String,
+    /// Whether the feature is enabled.
+    pub enabled: bool,
+}
+
+/// API response for setting a feature flag.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetFeatureFlagResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The flag that was set.
+    pub flag: String,
+    /// Whether the feature is now enabled.
+    pub enabled: bool,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to withdraw an approved leave bid.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WithdrawLeaveBidRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The leave bid to withdraw.
+    pub leave_bid_id: i64,
+    /// How many hours each waitlist offer of the freed slot stays open.
+    /// Defaults to 24.
+    #[serde(default)]
+    pub offer_hours: Option<u32>,
+}
+
+/// API response for withdrawing a leave bid.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WithdrawLeaveBidResponse {
+    /// The withdrawn leave bid.
+    pub leave_bid_id: i64,
+    /// The audit event that recorded the withdrawal.
+    pub release_event_id: i64,
+    /// The waitlist slot, when the round had already closed.
+    pub waitlist_slot_id: Option<i64>,
+    /// The controller first offered the slot, if anyone.
+    pub offered_to_user_id: Option<i64>,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to accept a pending waitlist offer.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AcceptWaitlistOfferRequest {
+    /// The waitlist offer ID.
+    pub waitlist_offer_id: i64,
+    /// How the acceptance was received (`phone`, `in_person`, `written`).
+    pub received_via: String,
+}
+
+/// API request to decline a pending waitlist offer.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeclineWaitlistOfferRequest {
+    /// The waitlist offer ID.
+    pub waitlist_offer_id: i64,
+}
+
+/// API response for answering a waitlist offer.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WaitlistOfferResponse {
+    /// The waitlist offer ID.
+    pub waitlist_offer_id: i64,
+    /// The waitlist slot ID.
+    pub waitlist_slot_id: i64,
+    /// The answer (`accepted` or `declined`).
+    pub status: String,
+    /// The audit event that recorded the answer.
+    pub response_event_id: i64,
+    /// The leave granted on acceptance.
+    pub leave_bid_id: Option<i64>,
+    /// The controller the slot was offered to next, after a decline.
+    pub offered_to_user_id: Option<i64>,
+    /// A success message.
+    pub message: String,
+}
+
+/// An offer of a waitlisted slot.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WaitlistOfferInfo {
+    /// The waitlist offer ID.
+    pub waitlist_offer_id: i64,
+    /// The controller offered the slot.
+    pub user_id: i64,
+    /// `pending`, `accepted`, `declined`, or `expired`.
+    pub status: String,
+    /// When the offer was made (RFC 3339).
+    pub offered_at: String,
+    /// When the offer lapses (RFC 3339).
+    pub expires_at: String,
+    /// When the offer was answered or lapsed (RFC 3339).
+    pub responded_at: Option<String>,
+    /// The audit event that recorded the offer.
+    pub offer_event_id: i64,
+    /// The audit event that recorded the response.
+    pub response_event_id: Option<i64>,
+    /// The leave granted on acceptance.
+    pub leave_bid_id: Option<i64>,
+}
+
+/// A leave slot freed after its round closed, with its offers.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WaitlistSlotInfo {
+    /// The waitlist slot ID.
+    pub waitlist_slot_id: i64,
+    /// The freed leave day.
+    pub leave_date: String,
+    /// The leave hours charged for the day.
+    pub hours: i32,
+    /// The crew the slot belongs to, in crew-partitioned rounds.
+    pub crew: Option<i32>,
+    /// How many hours each offer stays open.
+    pub offer_hours: i32,
+    /// The withdrawn leave bid that freed the slot.
+    pub released_leave_bid_id: i64,
+    /// The audit event that recorded the withdrawal.
+    pub release_event_id: i64,
+    /// `open`, `filled`, or `unclaimed`.
+    pub status: String,
+    /// When the slot was freed.
+    pub created_at: String,
+    /// The offers made, in the order they were made.
+    pub offers: Vec<WaitlistOfferInfo>,
+}
+
+/// API response listing the waitlisted slots of a round.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListWaitlistResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The slots, by leave date then when they were freed.
+    pub slots: Vec<WaitlistSlotInfo>,
+}
+
+/// API request to ask for leave on a day whose slots are all taken.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RequestOverbidRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round the leave is requested in.
+    pub round_id: i64,
+    /// The initials of the controller the request is entered for.
+    pub on_behalf_of: String,
+    /// How the request was received (`phone`, `in_person`, `written`).
+    pub received_via: String,
+    /// The requested leave day (`YYYY-MM-DD`).
+    pub leave_date: String,
+    /// The leave hours charged for the day.
+    pub hours: u32,
+}
+
+/// API response for an overbid request.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RequestOverbidResponse {
+    /// The overbid request ID.
+    pub overbid_request_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round the leave is requested in.
+    pub round_id: i64,
+    /// The controller's canonical user ID.
+    pub user_id: i64,
+    /// The requested leave day.
+    pub leave_date: String,
+    /// The audit event that recorded the request.
+    pub request_event_id: i64,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to approve a pending overbid request.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ApproveOverbidRequest {
+    /// The overbid request ID.
+    pub overbid_request_id: i64,
+}
+
+/// API request to deny a pending overbid request.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DenyOverbidRequest {
+    /// The overbid request ID.
+    pub overbid_request_id: i64,
+    /// Why the request is denied.
+    #[serde(deserialize_with = "crate::free_text::reason")]
+    pub reason: String,
+}
+
+/// API response for an overbid decision.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OverbidDecisionResponse {
+    /// The overbid request ID.
+    pub overbid_request_id: i64,
+    /// The decision (`approved` or `denied`).
+    pub status: String,
+    /// The audit event that recorded the request.
+    pub request_event_id: i64,
+    /// The audit event that recorded the decision.
+    pub decision_event_id: i64,
+    /// The leave granted on approval.
+    pub leave_bid_id: Option<i64>,
+    /// A success message.
+    pub message: String,
+}
+
+/// An overbid request and its decision.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OverbidRequestInfo {
+    /// The overbid request ID.
+    pub overbid_request_id: i64,
+    /// The controller's canonical user ID.
+    pub user_id: i64,
+    /// The requested leave day.
+    pub leave_date: String,
+    /// The leave hours charged for the day.
+    pub hours: i32,
+    /// The initials of the controller the request was entered for.
+    pub on_behalf_of: String,
+    /// How the request was received.
+    pub received_via: String,
+    /// `pending`, `approved`, or `denied`.
+    pub status: String,
+    /// Why the request was denied, if it was.
+    pub decision_reason: Option<String>,
+    /// The audit event that recorded the request.
+    pub request_event_id: Option<i64>,
+    /// The audit event that recorded the decision.
+    pub decision_event_id: Option<i64>,
+    /// The leave granted on approval.
+    pub leave_bid_id: Option<i64>,
+    /// When the request was made.
+    pub created_at: String,
+    /// When the request was decided.
+    pub decided_at: Option<String>,
+}
+
+/// API response listing the overbid requests for a round.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListOverbidRequestsResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The requests, by leave date then request order.
+    pub requests: Vec<OverbidRequestInfo>,
+}
+
+/// API request to ask for leave for the operator's own controller record.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RequestSelfBidEntryRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round the leave is bid in.
+    pub round_id: i64,
+    /// How the bid was received (`phone`, `in_person`, `written`).
+    pub received_via: String,
+    /// The leave days bid (ISO 8601 dates).
+    pub leave_dates: Vec<String>,
+    /// The leave hours charged per day.
+    pub hours: u32,
+}
+
+/// API response for a self-service bid request.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RequestSelfBidEntryResponse {
+    /// The self-service bid request ID.
+    pub self_bid_request_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round the leave is bid in.
+    pub round_id: i64,
+    /// The controller's canonical user ID.
+    pub user_id: i64,
+    /// The audit event that recorded the request.
+    pub request_event_id: i64,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to confirm a pending self-service bid request.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ConfirmSelfBidEntryRequest {
+    /// The self-service bid request ID.
+    pub self_bid_request_id: i64,
+}
+
+/// API response for confirming a self-service bid request.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ConfirmSelfBidEntryResponse {
+    /// The self-service bid request ID.
+    pub self_bid_request_id: i64,
+    /// The audit event that recorded the request.
+    pub request_event_id: i64,
+    /// The audit event that recorded the leave entry.
+    pub entry_event_id: i64,
+    /// The audit event that recorded the confirmation.
+    pub confirm_event_id: i64,
+    /// The created leave bid IDs, in date order.
+    pub leave_bid_ids: Vec<i64>,
+    /// A success message.
+    pub message: String,
+}
+
+/// A self-service bid request and its confirmation.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SelfBidRequestInfo {
+    /// The self-service bid request ID.
+    pub self_bid_request_id: i64,
+    /// The controller's canonical user ID.
+    pub user_id: i64,
+    /// The initials of the controller.
+    pub on_behalf_of: String,
+    /// The operator who is also the controller.
+    pub requested_by_operator_id: i64,
+    /// The leave days requested.
+    pub leave_dates: Vec<String>,
+    /// The leave hours charged per day.
+    pub hours: i32,
+    /// How the bid was received.
+    pub received_via: String,
+    /// `pending` or `confirmed`.
+    pub status: String,
+    /// The audit event that recorded the request.
+    pub request_event_id: Option<i64>,
+    /// The operator who confirmed the request.
+    pub confirmed_by_operator_id: Option<i64>,
+    /// The audit event that recorded the confirmation.
+    pub confirm_event_id: Option<i64>,
+    /// When the request was made.
+    pub created_at: String,
+    /// When the request was confirmed.
+    pub confirmed_at: Option<String>,
+}
+
+/// API response listing the self-service bid requests for a round.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListSelfBidRequestsResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The requests, in request order.
+    pub requests: Vec<SelfBidRequestInfo>,
+}
+
+/// A bid validation rule configured for a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BidRuleInfo {
+    /// `min_consecutive_days`, `max_splits_per_round`, or `holiday_pairing`.
+    pub kind: String,
+    /// The numeric parameter, for rules that take one.
+    #[serde(default)]
+    pub value: Option<u32>,
+    /// The dates parameter (ISO 8601 format), for rules that take one.
+    #[serde(default)]
+    pub dates: Vec<String>,
+}
+
+/// API request to replace a bid year's validation rules.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetBidRulesRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The rules in evaluation order. An empty list removes every rule.
+    pub rules: Vec<BidRuleInfo>,
+}
+
+/// API response for replacing a bid year's validation rules.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetBidRulesResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The stored rules in evaluation order.
+    pub rules: Vec<BidRuleInfo>,
+    /// A success message.
+    pub message: String,
+}
+
+/// API response listing a bid year's validation rules.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListBidRulesResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The rules in evaluation order.
+    pub rules: Vec<BidRuleInfo>,
+}
+
+/// An eligibility exception configured for a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EligibilityExceptionInfo {
+    /// `CPC`, `CPC-IT`, `Dev-R`, or `Dev-D`.
+    pub user_type: String,
+    /// The area the exception is limited to, or `None` for the whole bid year.
+    #[serde(default)]
+    pub area_id: Option<i64>,
+    /// Whether users of the type may bid.
+    pub can_bid: bool,
+}
+
+/// API request to replace a bid year's eligibility exceptions.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetEligibilityExceptionsRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The new exceptions. An empty list removes every exception.
+    pub exceptions: Vec<EligibilityExceptionInfo>,
+}
+
+/// API response for replacing a bid year's eligibility exceptions.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetEligibilityExceptionsResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The stored exceptions.
+    pub exceptions: Vec<EligibilityExceptionInfo>,
+    /// The number of users whose derived eligibility changed.
+    pub users_changed: usize,
+    /// A success message.
+    pub message: String,
+}
+
+/// API response listing a bid year's eligibility exceptions.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListEligibilityExceptionsResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The exceptions, bid-year-wide ones first.
+    pub exceptions: Vec<EligibilityExceptionInfo>,
+}
+
+/// A user's canonical eligibility.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UserEligibilityInfo {
+    /// The canonical user identifier.
+    pub user_id: i64,
+    /// The user's initials.
+    pub initials: String,
+    /// The eligibility derived from the user's type, flags, and area.
+    pub derived_can_bid: bool,
+    /// The effective eligibility, which differs only when overridden.
+    pub can_bid: bool,
+    /// Whether an override is in force.
+    pub is_overridden: bool,
+    /// The reason for the override in force, if any.
+    pub override_reason: Option<String>,
+}
+
+/// API response listing the canonical eligibility of a bid year's users.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListUserEligibilityResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// Every canonical user, by initials.
+    pub users: Vec<UserEligibilityInfo>,
+}
+
+/// API request to merge a duplicate user record into the record that
+/// survives.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MergeUsersRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The surviving user's canonical identifier.
+    pub keep_user_id: i64,
+    /// The duplicate user's canonical identifier.
+    pub merge_user_id: i64,
+    /// Why the records are merged (min 10 characters).
+    #[serde(deserialize_with = "crate::free_text::reason")]
+    pub reason: String,
+}
+
+/// API response for merging user records.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MergeUsersResponse {
+    /// The merge's ID in the user merge ledger.
+    pub user_merge_id: i64,
+    /// The audit event ID recording the merge.
+    pub audit_event_id: i64,
+    /// The surviving user's canonical identifier.
+    pub kept_user_id: i64,
+    /// The duplicate user's canonical identifier.
+    pub merged_user_id: i64,
+    /// The number of bids, overrides, and balances reassigned.
+    pub rows_moved: usize,
+    /// Success message.
+    pub message: String,
+    /// The correlation ID linking the merge to the automatic checkpoint
+    /// recorded before it.
+    pub correlation_id: String,
+}
+
+/// A merge recorded in the user merge ledger.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UserMergeInfo {
+    /// The merge's ID in the user merge ledger.
+    pub user_merge_id: i64,
+    /// The surviving user's canonical identifier.
+    pub kept_user_id: i64,
+    /// The duplicate user's canonical identifier.
+    pub merged_user_id: i64,
+    /// Why the records were merged.
+    pub reason: String,
+    /// The number of bids and overrides reassigned.
+    pub rows_moved: usize,
+    /// The audit event that recorded the merge.
+    pub merge_event_id: i64,
+    /// The audit event that recorded the revert, if the merge was reverted.
+    pub revert_event_id: Option<i64>,
+    /// When the merge was recorded.
+    pub created_at: String,
+}
+
+/// API response listing the user merges of a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListUserMergesResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// Merges, oldest first.
+    pub merges: Vec<UserMergeInfo>,
+}
+
+/// API response for reverting a user merge.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RevertUserMergeResponse {
+    /// The reverted merge's ID in the user merge ledger.
+    pub user_merge_id: i64,
+    /// The audit event ID recording the revert.
+    pub audit_event_id: i64,
+    /// Success message.
+    pub message: String,
+}
+
+/// A controller as listed in the facility's directory.
+///
+/// Read from directory group membership: the group a member belongs to
+/// names their area. Attributes the directory does not carry are `None`
+/// and are left as they are on the roster.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DirectoryMember {
+    /// The controller's operating initials.
+    pub initials: String,
+    /// The controller's name.
+    pub name: String,
+    /// The code of the area the controller's group maps to.
+    pub area_code: String,
+    /// The controller's type classification (CPC, CPC-IT, Dev-R, Dev-D).
+    pub user_type: Option<String>,
+    /// The controller's crew number (1-7).
+    pub crew: Option<u8>,
+    /// Cumulative NATCA bargaining unit date (ISO 8601).
+    pub cumulative_natca_bu_date: Option<String>,
+    /// NATCA bargaining unit date (ISO 8601).
+    pub natca_bu_date: Option<String>,
+    /// Entry on Duty / FAA date (ISO 8601).
+    pub eod_faa_date: Option<String>,
+    /// Service Computation Date (ISO 8601).
+    pub service_computation_date: Option<String>,
+}
+
+/// What a roster sync operation does to the roster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RosterSyncAction {
+    /// Registers a controller who is in the directory but not on the roster.
+    Add,
+    /// Brings a user's name, area, type, or crew in line with the directory.
+    Update,
+    /// Excludes a user who is no longer in the directory from bidding and
+    /// leave calculation.
+    Remove,
+}
+
+/// One change a roster sync proposes.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RosterSyncOperation {
+    /// Identifies the operation and the exact change it makes. An admin
+    /// approves operations by this ID; it changes whenever the proposed
+    /// change does.
+    pub operation_id: String,
+    /// What the operation does.
+    pub action: RosterSyncAction,
+    /// The user's canonical identifier, for updates and removals.
+    pub user_id: Option<i64>,
+    /// The controller's initials.
+    pub initials: String,
+    /// The controller's name, as it will be after the operation.
+    pub name: String,
+    /// The code of the controller's area, as it will be after the operation.
+    pub area_code: String,
+    /// Human-readable descriptions of each field the operation changes.
+    pub changes: Vec<String>,
+    /// Why the operation cannot be applied, if it cannot.
+    pub blocked_reason: Option<String>,
+}
+
+/// API response describing the changes a roster sync proposes.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RosterSyncPlanResponse {
+    /// The canonical identifier of the active bid year the plan is for.
+    pub bid_year_id: i64,
+    /// The active bid year (display value).
+    pub bid_year: u16,
+    /// The number of controllers read from the directory.
+    pub directory_members: usize,
+    /// Proposed operations: additions, then updates, then removals.
+    pub operations: Vec<RosterSyncOperation>,
+}
+
+/// API request to apply approved roster sync operations.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ApplyRosterSyncRequest {
+    /// The IDs of the approved operations.
+    pub operation_ids: Vec<String>,
+}
+
+/// A roster sync operation that was applied.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AppliedRosterSyncOperation {
+    /// The operation's ID.
+    pub operation_id: String,
+    /// What the operation did.
+    pub action: RosterSyncAction,
+    /// The canonical identifier of the user added, updated, or removed.
+    pub user_id: i64,
+}
+
+/// An approved roster sync operation that was not applied.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SkippedRosterSyncOperation {
+    /// The operation's ID.
+    pub operation_id: String,
+    /// Why the operation was not applied.
+    pub reason: String,
+}
+
+/// API response for applying approved roster sync operations.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ApplyRosterSyncResponse {
+    /// Operations that were applied, in the order they were approved.
+    pub applied: Vec<AppliedRosterSyncOperation>,
+    /// Operations that were not applied.
+    pub skipped: Vec<SkippedRosterSyncOperation>,
+}
+
+/// API request to preview or import a legacy bid tracker export.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LegacyImportRequest {
+    /// The canonical identifier of the bid year to import into.
+    pub bid_year_id: i64,
+    /// The tracker's bid log export, as CSV.
+    pub csv_content: String,
+}
+
+/// A legacy export row that could not be mapped onto the bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UnmappableLegacyRow {
+    /// The row number (1-based, excluding header).
+    pub row_number: usize,
+    /// The initials from this row, if present.
+    pub initials: Option<String>,
+    /// Why the row could not be mapped.
+    pub reason: String,
+}
+
+/// A round a legacy import creates.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LegacyRoundInfo {
+    /// The round group the round is created in.
+    pub round_group_id: i64,
+    /// The round number.
+    pub round_number: u32,
+    /// The round name.
+    pub name: String,
+    /// The most imported bids held on one day in one area.
+    pub slots_per_day: u32,
+    /// The most imported leave days held by one controller.
+    pub max_groups: u32,
+    /// The most imported leave hours held by one controller.
+    pub max_total_hours: u32,
+}
+
+/// Validation report for a legacy bid tracker export.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LegacyImportReport {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The bid year (display value).
+    pub bid_year: u16,
+    /// Number of rows in the export.
+    pub total_rows: usize,
+    /// Number of rows that map onto the bid year.
+    pub mapped_rows: usize,
+    /// Initials of the controllers registered from the export.
+    pub users_to_register: Vec<String>,
+    /// Rounds created for the imported bids.
+    pub rounds_to_create: Vec<LegacyRoundInfo>,
+    /// Number of leave days imported.
+    pub bids_to_import: usize,
+    /// Rows that cannot be mapped, in row order.
+    pub unmappable: Vec<UnmappableLegacyRow>,
+}
+
+/// API response for importing a legacy bid tracker export.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LegacyImportResponse {
+    /// What was imported, and the rows that were skipped.
+    pub report: LegacyImportReport,
+    /// The audit events recording the imported bids, one per area.
+    pub audit_event_ids: Vec<i64>,
+    /// The correlation ID linking the import to the automatic checkpoints
+    /// recorded before it.
+    pub correlation_id: String,
+}
+
+/// API request to change a user's operating initials.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChangeInitialsRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The user's current initials.
+    pub from: String,
+    /// The user's new initials.
+    pub to: String,
+    /// The date the new initials take effect (`YYYY-MM-DD`).
+    pub effective_date: String,
+}
+
+/// API response for changing a user's initials.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChangeInitialsResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The user's canonical identifier.
+    pub user_id: i64,
+    /// The initials the user held before the change.
+    pub previous_initials: String,
+    /// The user's new initials.
+    pub initials: String,
+    /// The date the new initials take effect (`YYYY-MM-DD`).
+    pub effective_date: String,
+    /// The audit event ID recording the change.
+    pub audit_event_id: i64,
+    /// Success message.
+    pub message: String,
+}
+
+/// Initials a user held before an initials change.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct InitialsAliasInfo {
+    /// The retired initials.
+    pub initials: String,
+    /// The date the replacement initials took effect (`YYYY-MM-DD`).
+    pub effective_date: String,
+    /// The audit event that recorded the change.
+    pub change_event_id: i64,
+}
+
+/// API response describing the initials a user holds and has held.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetInitialsHistoryResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The user's canonical identifier.
+    pub user_id: i64,
+    /// The user's current initials.
+    pub initials: String,
+    /// Initials the user held before, oldest first.
+    pub aliases: Vec<InitialsAliasInfo>,
+}
+
+/// API request for erasing a former employee's personal data.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AnonymizeUserRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The user's canonical identifier.
+    pub user_id: i64,
+}
+
+/// API response for erasing a former employee's personal data.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AnonymizeUserResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The user's canonical identifier.
+    pub user_id: i64,
+    /// The user's initials, which are kept.
+    pub initials: String,
+    /// How many stored state snapshots held the user and were rewritten.
+    pub snapshots_rewritten: usize,
+    /// The audit event recording the erasure.
+    pub audit_event_id: i64,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request for compacting a completed bid year's audit log.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CompactAuditLogRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+}
+
+/// An audit log compaction of a completed bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AuditCompactionInfo {
+    /// The compaction identifier.
+    pub compaction_id: i64,
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// How many events were moved to the archive.
+    pub archived_event_count: usize,
+    /// The first archived event, if any were archived.
+    pub first_event_id: Option<i64>,
+    /// The last archived event, if any were archived.
+    pub last_event_id: Option<i64>,
+    /// The SHA-256 chain hash of the last archived event.
+    pub chain_head: String,
+    /// When the compaction was recorded.
+    pub created_at: String,
+}
+
+/// API response for compacting a completed bid year's audit log.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CompactAuditLogResponse {
+    /// The recorded compaction.
+    pub compaction: AuditCompactionInfo,
+    /// The `AuditLogCompacted` digest events, one per area, each with a
+    /// sealed snapshot of the area's final state.
+    pub digest_event_ids: Vec<i64>,
+    /// A success message.
+    pub message: String,
+}
+
+/// The result of checking a compaction's archive against its hash chain.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AuditArchiveVerificationInfo {
+    /// How many archived events were read.
+    pub events_checked: usize,
+    /// The chain head recomputed from the archived events.
+    pub recomputed_head: String,
+    /// Archived events whose stored chain hash no longer matches.
+    pub broken_event_ids: Vec<i64>,
+    /// Whether the archive still matches the compaction's chain head.
+    pub is_intact: bool,
+}
+
+/// API response for a bid year's audit log compaction.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetAuditCompactionResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The compaction, or `None` if the bid year has not been compacted.
+    pub compaction: Option<AuditCompactionInfo>,
+    /// The archive check, present when the bid year has been compacted.
+    pub verification: Option<AuditArchiveVerificationInfo>,
+}
+
+/// A prime (high-demand) period of a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PrimePeriodInfo {
+    /// The prime period identifier.
+    pub prime_period_id: i64,
+    /// The period's name (e.g. Christmas week).
+    pub label: String,
+    /// The first prime date (ISO 8601 format).
+    pub start_date: String,
+    /// The last prime date (ISO 8601 format).
+    pub end_date: String,
+}
+
+/// A round's leave slots on prime dates.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RoundPrimeCapInfo {
+    /// The round ID.
+    pub round_id: i64,
+    /// The slots offered per prime date, in place of `slots_per_day`.
+    pub prime_slots_per_day: u32,
+}
+
+/// API request to designate a prime period.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CreatePrimePeriodRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The period's name (e.g. spring break).
+    #[serde(deserialize_with = "crate::free_text::name")]
+    pub label: String,
+    /// The first prime date (ISO 8601 format).
+    pub start_date: String,
+    /// The last prime date (ISO 8601 format).
+    pub end_date: String,
+}
+
+/// API response for designating a prime period.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PrimePeriodResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The stored prime period.
+    pub prime_period: PrimePeriodInfo,
+    /// A success message.
+    pub message: String,
+}
+
+/// API response for deleting a prime period.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeletePrimePeriodResponse {
+    /// The deleted prime period identifier.
+    pub prime_period_id: i64,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to set or clear a round's cap on prime dates.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetRoundPrimeCapRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The slots offered per prime date, or `None` to use the round's
+    /// normal `slots_per_day`.
+    #[serde(default)]
+    pub prime_slots_per_day: Option<u32>,
+}
+
+/// API response for setting a round's cap on prime dates.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetRoundPrimeCapResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The stored cap, if any.
+    pub prime_slots_per_day: Option<u32>,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to set or clear a round's eligibility criteria.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetRoundEligibilityRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The projected leave balance, in hours, a bidder needs to take part.
+    #[serde(default)]
+    pub min_remaining_hours: Option<u32>,
+    /// The user types taking part; empty admits every user type.
+    #[serde(default)]
+    pub user_types: Vec<String>,
+}
+
+/// API response for setting a round's eligibility criteria.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetRoundEligibilityResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The round ID.
+    pub round_id: i64,
+    /// The stored criteria, or `None` if the round admits every bidder.
+    pub eligibility: Option<RoundEligibilityInfo>,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to set a round group's bid order rotation.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetRoundGroupRotationRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The round group ID.
+    pub round_group_id: i64,
+    /// The rotation: `forward` or `reverse_even_rounds`.
+    pub rotation: String,
+}
+
+/// API response for setting a round group's bid order rotation.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetRoundGroupRotationResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The round group ID.
+    pub round_group_id: i64,
+    /// The stored rotation.
+    pub rotation: String,
+    /// A success message.
+    pub message: String,
+}
+
+/// One user's place in a round's bidding sequence.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RoundBidOrderEntryInfo {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// The 1-based position within the round and area.
+    pub position: u32,
+}
+
+/// API response with a round's bidding sequence.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetRoundBidOrderResponse {
+    /// The round ID.
+    pub round_id: i64,
+    /// The round group's rotation.
+    pub rotation: String,
+    /// One entry per bidder, by area, then position. Empty until the bid
+    /// year is confirmed ready to bid.
+    pub entries: Vec<RoundBidOrderEntryInfo>,
+}
+
+/// API response listing a bid year's prime periods and round caps.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListPrimeDatesResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The prime periods, ordered by start date.
+    pub prime_periods: Vec<PrimePeriodInfo>,
+    /// The rounds that cap prime dates, ordered by round.
+    pub round_caps: Vec<RoundPrimeCapInfo>,
+}
+
+/// API response for a bid year's annual leave carryover cap.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetLeaveCapResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The most annual leave, in hours, that may carry into the next year.
+    pub carryover_cap_hours: u16,
+}
+
+/// API request to set a bid year's annual leave carryover cap.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetLeaveCapRequest {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The most annual leave, in hours, that may carry into the next year.
+    pub carryover_cap_hours: u16,
+}
+
+/// API response for setting a bid year's annual leave carryover cap.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetLeaveCapResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The stored cap in hours.
+    pub carryover_cap_hours: u16,
+    /// A success message.
+    pub message: String,
+}
+
+/// The facility's configuration.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FacilityInfo {
+    /// The facility identifier (e.g., `"ZAB"`).
+    pub identifier: String,
+    /// The facility's display name.
+    pub name: String,
+    /// IANA timezone bid schedules default to.
+    pub default_timezone: String,
+    /// Hours accrued per pay period with less than 3 years of service.
+    pub junior_accrual_hours: u8,
+    /// Hours accrued per pay period with 3 to 14 years of service.
+    pub mid_accrual_hours: u8,
+    /// Hours accrued per pay period with 15 or more years of service.
+    pub senior_accrual_hours: u8,
+    /// The carryover cap of bid years that do not set their own.
+    pub max_carryover_hours: u16,
+    /// When the configuration last changed (UTC).
+    pub updated_at: String,
+}
+
+/// API response for the facility's configuration.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetFacilityResponse {
+    /// The configuration, or `None` if the facility has not been configured.
+    pub facility: Option<FacilityInfo>,
+}
+
+/// API request to set the facility's configuration.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetFacilityRequest {
+    /// The facility identifier (3 or 4 uppercase letters or digits).
+    pub identifier: String,
+    /// The facility's display name.
+    pub name: String,
+    /// IANA timezone bid schedules default to.
+    pub default_timezone: String,
+    /// Hours accrued per pay period with less than 3 years of service.
+    pub junior_accrual_hours: u8,
+    /// Hours accrued per pay period with 3 to 14 years of service.
+    pub mid_accrual_hours: u8,
+    /// Hours accrued per pay period with 15 or more years of service.
+    pub senior_accrual_hours: u8,
+    /// The carryover cap of bid years that do not set their own.
+    pub max_carryover_hours: u16,
+}
+
+/// API response for setting the facility's configuration.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetFacilityResponse {
+    /// The stored configuration.
+    pub facility: FacilityInfo,
+    /// A success message.
+    pub message: String,
+}
+
+/// API request to record the annual leave a controller carries into a bid
+/// year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetLeaveCarryoverRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The controller's initials.
+    pub initials: String,
+    /// Hours carried in from the prior year, before the cap is applied.
+    pub carryover_hours: u16,
+}
+
+/// API response for recording a controller's carried-in leave.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SetLeaveCarryoverResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The controller's user ID.
+    pub user_id: i64,
+    /// The stored carried-in hours.
+    pub carryover_hours: u16,
+    /// A success message.
+    pub message: String,
+}
+
+/// One controller's projected end-of-year annual leave balance.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LeaveProjectionInfo {
+    /// The controller's area code.
+    pub area_code: String,
+    /// The controller's user ID.
+    pub user_id: i64,
+    /// The controller's initials.
+    pub initials: String,
+    /// The controller's name.
+    pub name: String,
+    /// The hours category the controller accrues in by year end
+    /// (`junior`, `mid`, or `senior`).
+    pub hours_category: String,
+    /// Hours carried in from the prior year, after applying the cap.
+    pub carried_in_hours: u16,
+    /// Prior-year hours above the cap that did not carry in.
+    pub forfeited_carryover_hours: u16,
+    /// Hours accrued during the bid year.
+    pub accrued_hours: u16,
+    /// Hours of leave awarded so far.
+    pub awarded_hours: u32,
+    /// Hours of leave requested in top-ranked pending preferences.
+    pub pending_hours: u32,
+    /// Hours left at year end (negative when overdrawn).
+    pub projected_balance_hours: i64,
+    /// Projected hours above the cap that will be lost unless used.
+    pub use_or_lose_hours: u32,
+    /// Hours of awarded and pending leave beyond the projected accrual.
+    pub overdrawn_hours: u32,
+}
+
+/// API response listing projected end-of-year leave balances.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ListLeaveProjectionsResponse {
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// The bid year's carryover cap in hours.
+    pub carryover_cap_hours: u16,
+    /// One projection per controller, grouped by area.
+    pub projections: Vec<LeaveProjectionInfo>,
+}
+
+/// API request to render the controllers projected to lose annual leave.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetUseOrLoseReportRequest {
+    /// The canonical bid year ID.
+    pub bid_year_id: i64,
+    /// Restrict the report to one canonical area, or `None` for every area.
+    pub area_id: Option<i64>,
+    /// The output format (`pdf`, `html`, or `csv`).
+    pub format: String,
+}
+
+/// API request to render printable bid packets for an area.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetBidPacketsRequest {
+    /// The canonical bid year ID.
+    pub bid_year_id: i64,
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// Render only this user's packet, or `None` for every controller in
+    /// the area.
+    pub user_id: Option<i64>,
+}
+
+/// API request to render operator activity over a date range.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetAdminActivityReportRequest {
+    /// The first date to include (`YYYY-MM-DD`).
+    pub start_date: String,
+    /// The last date to include (`YYYY-MM-DD`).
+    pub end_date: String,
+    /// IANA timezone dates and business hours are read in, or `None` for UTC.
+    pub timezone: Option<String>,
+    /// The output format (`pdf`, `html`, or `csv`).
+    pub format: String,
+}
+
+/// Operator activity over a date range.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AdminActivitySummary {
+    /// The first date included (`YYYY-MM-DD`).
+    pub start_date: String,
+    /// The last date included (`YYYY-MM-DD`).
+    pub end_date: String,
+    /// The IANA timezone dates and business hours were read in.
+    pub timezone: String,
+    /// One entry per operator with activity, by login name.
+    pub operators: Vec<OperatorActivityInfo>,
+}
+
+/// One operator's activity within an admin activity summary.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OperatorActivityInfo {
+    /// The operator ID, or 0 for system actors.
+    pub operator_id: i64,
+    /// The operator's login name when the commands were recorded.
+    pub login_name: String,
+    /// Commands recorded, including the overrides and rollbacks.
+    pub commands: u32,
+    /// Overrides issued.
+    pub overrides: u32,
+    /// Rollbacks performed.
+    pub rollbacks: u32,
+    /// Commands recorded outside business hours.
+    pub after_hours: u32,
+}
+
+/// Bid status counts of one round in an area, as projected for dashboards.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RoundProgressInfo {
+    /// The round ID.
+    pub round_id: i64,
+    /// Users with a bid status in the round.
+    pub bidders: u32,
+    /// Bidders whose window has not started or who have not started bidding.
+    pub not_started: u32,
+    /// Bidders who have started but not completed their bids.
+    pub in_progress: u32,
+    /// Bidders whose status is final (completed, missed, or not bidding).
+    pub completed: u32,
+}
+
+/// Bid progress of one area, as projected for dashboards.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AreaProgressInfo {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The area code.
+    pub area_code: String,
+    /// One entry per round with bid statuses, in round ID order.
+    pub rounds: Vec<RoundProgressInfo>,
+}
+
+/// API response with the bid progress of every area in a bid year.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetBidYearDashboardResponse {
+    /// The canonical bid year ID.
+    pub bid_year_id: i64,
+    /// The last audit event the projections reflect, or `None` before they
+    /// are first built.
+    pub projected_through_event_id: Option<i64>,
+    /// One entry per area, in area ID order.
+    pub areas: Vec<AreaProgressInfo>,
+}
+
+/// API request to read an area's dashboard.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetAreaDashboardRequest {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The first date of remaining slots to include (`YYYY-MM-DD`).
+    pub start_date: String,
+    /// The last date of remaining slots to include (`YYYY-MM-DD`).
+    pub end_date: String,
+}
+
+/// A user's approved leave, as projected for dashboards.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UserAwardInfo {
+    /// The canonical user ID.
+    pub user_id: i64,
+    /// Days of approved leave.
+    pub awarded_days: u32,
+    /// Hours of approved leave.
+    pub awarded_hours: u32,
+}
+
+/// The leave slots on one day of a round, as projected for dashboards.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DashboardDayInfo {
+    /// The round ID.
+    pub round_id: i64,
+    /// The date (`YYYY-MM-DD`).
+    pub date: String,
+    /// The day's capacity after prime caps and staffing adjustments.
+    pub capacity: u32,
+    /// The approved leave held on this day.
+    pub used: u32,
+    /// The slots still open on this day.
+    pub remaining: u32,
+}
+
+/// API response with an area's bid progress, awarded leave, and remaining
+/// slots.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GetAreaDashboardResponse {
+    /// The canonical area ID.
+    pub area_id: i64,
+    /// The last audit event the projections reflect, or `None` before they
+    /// are first built.
+    pub projected_through_event_id: Option<i64>,
+    /// One entry per round with bid statuses, in round ID order.
+    pub progress: Vec<RoundProgressInfo>,
+    /// One entry per user with approved leave, in user ID order.
+    pub awards: Vec<UserAwardInfo>,
+    /// One entry per round and day in the range, by round, then date.
+    pub days: Vec<DashboardDayInfo>,
+}
@@ -0,0 +1,715 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Rollback handlers.
+//!
+//! A rollback returns one area to its state as of an earlier audit event.
+//! Before an Admin confirms one, [`preview_rollback`] reports what it would
+//! undo: the area's events after the target, the users whose records would
+//! change, the leave bids and overrides entered since, and whether the bid
+//! year's canonical data will be regenerated.
+//!
+//! An area's users are restored from state snapshots, so the state a
+//! rollback restores is that of the target event's snapshot, or of the last
+//! snapshot before it when the target has none.
+//!
+//! [`rollback`] records the `Rollback` event; persisting it restores the
+//! area and marks the superseded events inactive in one transaction.
+//! Superseded events stay in the audit log but cannot be rolled back to,
+//! and a later rollback does not list them again.
+//!
+//! Some events are derived from other areas' state; canonicalization, for
+//! one, depends on the latest event in every area. Rolling an area back
+//! past such a dependency supersedes the dependent event too, so the
+//! preview lists dependents and a rollback with any is refused unless the
+//! cascade is confirmed.
+//!
+//! Checkpoints may carry a label. [`list_checkpoints`] browses them, and a
+//! rollback may target the area's latest unsuperseded checkpoint with a
+//! label instead of an event ID.
+//!
+//! Every rollback first records an automatic checkpoint in the area. The
+//! rollback supersedes it, but because both share a correlation ID it can
+//! still be rolled back to, which undoes the rollback.
+
+use zab_bid::{BootstrapMetadata, Command, State, TransitionResult, apply};
+use zab_bid_audit::{Actor, Cause};
+use zab_bid_domain::{Area, BidYear, BidYearLifecycle, User};
+use zab_bid_persistence::{
+    AuditEventDependency, AuditEventHeaderPage, AuditTimelineEntry, AuditTimelineFilter,
+    AuditTimelineScope, CheckpointData, LeaveBidData, OperatorData, PersistenceError,
+    SqlitePersistence,
+};
+
+use crate::auth::{AuthenticatedActor, AuthorizationService};
+use crate::auto_checkpoints::{AutoCheckpoints, record_with_auto_checkpoints};
+use crate::error::{ApiError, translate_core_error};
+use crate::handlers::{list_overrides, resolve_active_bid_year};
+use crate::leave_bids::load_lifecycle_state;
+use crate::request_response::{
+    CheckpointInfo, DependentEventInfo, ListCheckpointsResponse, OverrideInfo,
+    RollbackLeaveBidInfo, RollbackPreviewResponse, RollbackUserChange, SupersededEventInfo,
+};
+
+/// Audit events read per page while listing superseded events.
+const SUPERSEDED_PAGE_SIZE: u32 = 500;
+
+/// Actions that rewrite canonical data derived from an area's users.
+const CANONICAL_DATA_ACTIONS: [&str; 3] = [
+    "BulkBidOrderAdjustment",
+    "BulkBidWindowRecalculation",
+    "BidWindowAdjusted",
+];
+
+/// The event a rollback returns an area to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RollbackTarget {
+    /// An audit event, by ID.
+    EventId(i64),
+    /// The area's latest unsuperseded checkpoint with this label.
+    Checkpoint(String),
+}
+
+/// A rollback, with the automatic checkpoint recorded before it.
+#[derive(Debug, Clone)]
+pub struct RollbackResult {
+    /// The persisted `Rollback` transition.
+    pub transition_result: TransitionResult,
+    /// The `Rollback` event's audit event identifier.
+    pub event_id: i64,
+    /// The correlation ID the rollback and its checkpoint are recorded under.
+    pub correlation_id: String,
+    /// The automatic checkpoint's audit event identifier.
+    pub checkpoint_event_id: i64,
+}
+
+/// The area an audit event belongs to, with the canonical IDs to query it by.
+struct EventScope {
+    bid_year: BidYear,
+    area: Area,
+    bid_year_id: i64,
+    area_id: i64,
+}
+
+/// Returns whether a superseded event is the automatic checkpoint recorded
+/// before the rollback that superseded it.
+///
+/// Rolling back to such a checkpoint undoes that rollback.
+fn undoes_rollback(persistence: &mut SqlitePersistence, event_id: i64) -> Result<bool, ApiError> {
+    let correlation = |persistence: &mut SqlitePersistence, event_id: i64| {
+        persistence
+            .get_event_correlation_id(event_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to read event correlation: {e}"),
+            })
+    };
+    let Some(rollback_event_id) = persistence
+        .get_superseding_event_id(event_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read superseded events: {e}"),
+        })?
+    else {
+        return Ok(false);
+    };
+    let Some(correlation_id) = correlation(persistence, event_id)? else {
+        return Ok(false);
+    };
+    Ok(correlation(persistence, rollback_event_id)?.as_deref() == Some(correlation_id.as_str()))
+}
+
+/// Loads a rollback target, which must be an event recorded against an area
+/// that no rollback has superseded, or the automatic checkpoint recorded
+/// before the rollback that superseded it.
+fn load_target(
+    persistence: &mut SqlitePersistence,
+    target_event_id: i64,
+) -> Result<(AuditTimelineEntry, EventScope), ApiError> {
+    let entry: AuditTimelineEntry = persistence
+        .get_audit_timeline_entries(&[target_event_id])
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read audit event: {e}"),
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("AuditEvent"),
+            message: format!("Audit event {target_event_id} not found"),
+        })?;
+
+    let (Some(bid_year), Some(area)) = (entry.event.bid_year.clone(), entry.event.area.clone())
+    else {
+        return Err(ApiError::InvalidInput {
+            field: String::from("target_event_id"),
+            message: format!(
+                "Audit event {target_event_id} is not recorded against an area and cannot be rolled back to"
+            ),
+        });
+    };
+    let (Some(bid_year_id), Some(area_id)) = (bid_year.bid_year_id(), area.area_id()) else {
+        return Err(ApiError::Internal {
+            message: format!("Audit event {target_event_id} has no canonical scope"),
+        });
+    };
+
+    let superseded: Vec<i64> = persistence
+        .list_superseded_event_ids(&[target_event_id])
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read superseded events: {e}"),
+        })?;
+    if !superseded.is_empty() && !undoes_rollback(persistence, target_event_id)? {
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("rollback_target_superseded"),
+            message: format!(
+                "Audit event {target_event_id} was superseded by an earlier rollback and cannot be rolled back to"
+            ),
+        });
+    }
+
+    Ok((
+        entry,
+        EventScope {
+            bid_year,
+            area,
+            bid_year_id,
+            area_id,
+        },
+    ))
+}
+
+/// Lists the area's events after the target that a rollback would
+/// supersede, oldest first.
+///
+/// Events an earlier rollback superseded are skipped.
+fn list_superseded_events(
+    persistence: &mut SqlitePersistence,
+    scope: &EventScope,
+    target_event_id: i64,
+) -> Result<Vec<SupersededEventInfo>, ApiError> {
+    let mut events: Vec<SupersededEventInfo> = Vec::new();
+    let mut cursor: Option<i64> = Some(target_event_id);
+    while let Some(after_event_id) = cursor {
+        let page: AuditEventHeaderPage = persistence
+            .get_audit_event_headers_page(
+                AuditTimelineScope::Area {
+                    bid_year_id: scope.bid_year_id,
+                    area_id: scope.area_id,
+                },
+                &AuditTimelineFilter::default(),
+                Some(after_event_id),
+                SUPERSEDED_PAGE_SIZE,
+            )
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to read audit log: {e}"),
+            })?;
+        let page_ids: Vec<i64> = page.headers.iter().map(|header| header.event_id).collect();
+        let already_superseded: Vec<i64> = persistence
+            .list_superseded_event_ids(&page_ids)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to read superseded events: {e}"),
+            })?;
+        events.extend(
+            page.headers
+                .into_iter()
+                .filter(|header| !already_superseded.contains(&header.event_id))
+                .map(|header| SupersededEventInfo {
+                    event_id: header.event_id,
+                    action_name: header.action_name,
+                    actor_login_name: header.actor_login_name,
+                    created_at: header.created_at,
+                }),
+        );
+        cursor = page.next_cursor;
+    }
+    Ok(events)
+}
+
+/// Lists the events that depend on superseded events, following dependents
+/// of dependents, in the order they are found.
+///
+/// Dependents an earlier rollback superseded are skipped.
+fn list_dependent_events(
+    persistence: &mut SqlitePersistence,
+    superseded_events: &[SupersededEventInfo],
+) -> Result<Vec<DependentEventInfo>, ApiError> {
+    let mut dependencies: Vec<AuditEventDependency> = Vec::new();
+    let mut frontier: Vec<i64> = superseded_events
+        .iter()
+        .map(|event| event.event_id)
+        .collect();
+    while !frontier.is_empty() {
+        let found: Vec<AuditEventDependency> = persistence
+            .list_event_dependents(&frontier)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to read event dependencies: {e}"),
+            })?;
+        frontier = Vec::new();
+        for dependency in found {
+            let seen: bool = dependencies
+                .iter()
+                .any(|known| known.event_id == dependency.event_id);
+            if !seen && !frontier.contains(&dependency.event_id) {
+                frontier.push(dependency.event_id);
+                dependencies.push(dependency);
+            }
+        }
+    }
+
+    let event_ids: Vec<i64> = dependencies.iter().map(|dep| dep.event_id).collect();
+    let entries: Vec<AuditTimelineEntry> = persistence
+        .get_audit_timeline_entries(&event_ids)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read audit events: {e}"),
+        })?;
+    Ok(dependencies
+        .into_iter()
+        .filter_map(|dependency| {
+            let entry: &AuditTimelineEntry = entries
+                .iter()
+                .find(|entry| entry.event.event_id == Some(dependency.event_id))?;
+            Some(DependentEventInfo {
+                event_id: dependency.event_id,
+                action_name: entry.event.action.name.clone(),
+                depends_on_event_id: dependency.depends_on_event_id,
+                created_at: entry.created_at.clone(),
+            })
+        })
+        .collect())
+}
+
+/// Returns whether two user records are the same user.
+///
+/// Users restored from older snapshots may not carry an ID, so those are
+/// matched by initials.
+fn same_user(a: &User, b: &User) -> bool {
+    match (a.user_id, b.user_id) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.initials == b.initials,
+    }
+}
+
+/// Returns whether two records of the same user hold the same details.
+fn same_details(a: &User, b: &User) -> bool {
+    a.initials == b.initials
+        && a.name == b.name
+        && a.user_type == b.user_type
+        && a.crew == b.crew
+        && a.seniority_data == b.seniority_data
+        && a.excluded_from_bidding == b.excluded_from_bidding
+        && a.excluded_from_leave_calculation == b.excluded_from_leave_calculation
+        && a.no_bid_reviewed == b.no_bid_reviewed
+}
+
+/// Lists how restoring `restored` over `current` would change each user.
+fn diff_users(current: &State, restored: &State) -> Vec<RollbackUserChange> {
+    let change = |user: &User, change: &str| RollbackUserChange {
+        user_id: user.user_id,
+        initials: user.initials.value().to_string(),
+        change: change.to_string(),
+    };
+
+    let mut changes: Vec<RollbackUserChange> = Vec::new();
+    for user in current.users.values() {
+        match restored.users.values().find(|old| same_user(old, user)) {
+            None => changes.push(change(user, "removed")),
+            Some(old) if !same_details(old, user) => changes.push(change(user, "reverted")),
+            Some(_) => {}
+        }
+    }
+    for old in restored.users.values() {
+        if !current.users.values().any(|user| same_user(old, user)) {
+            changes.push(change(old, "restored"));
+        }
+    }
+    changes.sort_by(|a, b| a.initials.cmp(&b.initials));
+    changes
+}
+
+/// Previews rolling an area back to an earlier audit event.
+///
+/// Nothing is changed. The target's area is the area rolled back; the
+/// preview lists:
+///
+/// - every event recorded in the area after the target
+/// - the users whose records would be removed, restored, or reverted
+/// - the approved leave bids entered since the first superseded event
+/// - the unreverted overrides recorded by superseded events
+/// - the events elsewhere that depend on superseded events
+///
+/// Canonical data is regenerated when the bid year has been
+/// canonicalized and the rollback changes users, or supersedes an event
+/// that rewrote bid order or bid windows.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `target_event_id` - The event to roll back to
+/// * `authenticated_actor` - The authenticated actor previewing the rollback
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The target event does not exist
+/// - The target event is not recorded against an area
+/// - The target event was superseded by an earlier rollback
+/// - The area has no snapshot at or before the target
+/// - The database operation fails
+pub fn preview_rollback(
+    persistence: &mut SqlitePersistence,
+    target_event_id: i64,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<RollbackPreviewResponse, ApiError> {
+    AuthorizationService::authorize_rollback(authenticated_actor)?;
+    build_preview(persistence, target_event_id)
+}
+
+/// Works out what rolling back to the target would undo.
+fn build_preview(
+    persistence: &mut SqlitePersistence,
+    target_event_id: i64,
+) -> Result<RollbackPreviewResponse, ApiError> {
+    let (target, scope) = load_target(persistence, target_event_id)?;
+    let superseded_events: Vec<SupersededEventInfo> =
+        list_superseded_events(persistence, &scope, target_event_id)?;
+
+    let (restored, snapshot_event_id): (State, i64) = persistence
+        .get_snapshot_at_or_before_event(&scope.bid_year, &scope.area, target_event_id)
+        .map_err(|e| match e {
+            PersistenceError::SnapshotNotFound { .. } => ApiError::DomainRuleViolation {
+                rule: String::from("rollback_requires_snapshot"),
+                message: format!(
+                    "Area {} has no snapshot at or before event {target_event_id}",
+                    scope.area.id()
+                ),
+            },
+            _ => ApiError::Internal {
+                message: format!("Failed to read snapshot: {e}"),
+            },
+        })?;
+    let current: State = persistence
+        .get_current_state(&scope.bid_year, &scope.area)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to read current state: {e}"),
+        })?;
+    let affected_users: Vec<RollbackUserChange> = diff_users(&current, &restored);
+
+    // Leave bids are recorded just before the event that enters them
+    let affected_leave_bids: Vec<RollbackLeaveBidInfo> = match superseded_events
+        .first()
+        .and_then(|event| event.created_at.clone())
+    {
+        Some(since) => persistence
+            .list_leave_bids(scope.bid_year_id, scope.area_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list leave bids: {e}"),
+            })?
+            .into_iter()
+            .filter(|bid| bid.status == LeaveBidData::STATUS_APPROVED && bid.created_at >= since)
+            .map(|bid| RollbackLeaveBidInfo {
+                leave_bid_id: bid.leave_bid_id,
+                user_id: bid.user_id,
+                round_id: bid.round_id,
+                leave_date: bid.leave_date,
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let affected_overrides: Vec<OverrideInfo> = list_overrides(persistence, scope.bid_year_id)?
+        .overrides
+        .into_iter()
+        .filter(|record| {
+            !record.is_reverted
+                && superseded_events
+                    .iter()
+                    .any(|event| event.event_id == record.audit_event_id)
+        })
+        .collect();
+
+    let dependent_events: Vec<DependentEventInfo> =
+        list_dependent_events(persistence, &superseded_events)?;
+
+    let lifecycle: BidYearLifecycle = load_lifecycle_state(persistence, scope.bid_year_id)?;
+    let rewrites_canonical_data: bool = superseded_events
+        .iter()
+        .any(|event| CANONICAL_DATA_ACTIONS.contains(&event.action_name.as_str()));
+    let requires_canonical_regeneration: bool =
+        lifecycle.is_locked() && (!affected_users.is_empty() || rewrites_canonical_data);
+
+    Ok(RollbackPreviewResponse {
+        target_event_id,
+        target_action_name: target.event.action.name,
+        bid_year: scope.bid_year.year(),
+        area_code: scope.area.id().to_string(),
+        snapshot_event_id,
+        superseded_events,
+        affected_users,
+        affected_leave_bids,
+        affected_overrides,
+        dependent_events,
+        requires_canonical_regeneration,
+    })
+}
+
+/// Lists the checkpoints recorded in a bid year, or in one of its areas,
+/// newest first.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The current bootstrap metadata
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID, or `None` for every area
+/// * `authenticated_actor` - The authenticated actor listing checkpoints
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year or area does not exist
+/// - The database operation fails
+pub fn list_checkpoints(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+    area_id: Option<i64>,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<ListCheckpointsResponse, ApiError> {
+    AuthorizationService::authorize_rollback(authenticated_actor)?;
+
+    let bid_year: &BidYear = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.bid_year_id() == Some(bid_year_id))
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("BidYear"),
+            message: format!("Bid year with ID {bid_year_id} not found"),
+        })?;
+    let scope: AuditTimelineScope = match area_id {
+        Some(area_id) => {
+            if !metadata.areas.iter().any(|(by, area)| {
+                by.bid_year_id() == Some(bid_year_id) && area.area_id() == Some(area_id)
+            }) {
+                return Err(ApiError::ResourceNotFound {
+                    resource_type: String::from("Area"),
+                    message: format!(
+                        "Area with ID {area_id} not found in bid year {}",
+                        bid_year.year()
+                    ),
+                });
+            }
+            AuditTimelineScope::Area {
+                bid_year_id,
+                area_id,
+            }
+        }
+        None => AuditTimelineScope::BidYear { bid_year_id },
+    };
+
+    let checkpoints: Vec<CheckpointInfo> = persistence
+        .list_checkpoints(scope)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list checkpoints: {e}"),
+        })?
+        .into_iter()
+        .map(|checkpoint| CheckpointInfo {
+            event_id: checkpoint.event_id,
+            area_code: checkpoint.area_code,
+            label: checkpoint.label,
+            description: checkpoint.description,
+            actor_login_name: checkpoint.actor_login_name,
+            created_at: checkpoint.created_at,
+            superseded: checkpoint.superseded,
+            correlation_id: checkpoint.correlation_id,
+        })
+        .collect();
+
+    Ok(ListCheckpointsResponse {
+        bid_year: bid_year.year(),
+        checkpoints,
+    })
+}
+
+/// Finds the area being rolled back in the metadata, which carries its
+/// canonical IDs.
+fn resolve_area<'a>(
+    metadata: &'a BootstrapMetadata,
+    state: &State,
+) -> Result<&'a (BidYear, Area), ApiError> {
+    metadata
+        .areas
+        .iter()
+        .find(|(bid_year, area)| {
+            bid_year.year() == state.bid_year.year() && area.id() == state.area.id()
+        })
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("Area"),
+            message: format!(
+                "Area {} not found in bid year {}",
+                state.area.id(),
+                state.bid_year.year()
+            ),
+        })
+}
+
+/// Resolves a rollback target to an event ID, looking a labeled checkpoint
+/// up in the area being rolled back.
+fn resolve_target(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    state: &State,
+    target: &RollbackTarget,
+) -> Result<i64, ApiError> {
+    let label: &str = match target {
+        RollbackTarget::EventId(event_id) => return Ok(*event_id),
+        RollbackTarget::Checkpoint(label) => label.trim(),
+    };
+    let (bid_year, area) = resolve_area(metadata, state)?;
+    let (Some(bid_year_id), Some(area_id)) = (bid_year.bid_year_id(), area.area_id()) else {
+        return Err(ApiError::Internal {
+            message: format!("Area {} has no canonical scope", area.id()),
+        });
+    };
+
+    let checkpoints: Vec<CheckpointData> = persistence
+        .list_checkpoints(AuditTimelineScope::Area {
+            bid_year_id,
+            area_id,
+        })
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list checkpoints: {e}"),
+        })?;
+    for checkpoint in checkpoints {
+        if checkpoint.label.as_deref() == Some(label)
+            && (!checkpoint.superseded || undoes_rollback(persistence, checkpoint.event_id)?)
+        {
+            return Ok(checkpoint.event_id);
+        }
+    }
+    Err(ApiError::ResourceNotFound {
+        resource_type: String::from("Checkpoint"),
+        message: format!(
+            "No checkpoint labeled '{label}' can be rolled back to in area {}",
+            state.area.id()
+        ),
+    })
+}
+
+/// Rolls an area back to an earlier audit event.
+///
+/// The target must be an event recorded against the area being rolled
+/// back, must not have been superseded, and must have a snapshot at or
+/// before it. Once the bid year is canonicalized, persisting the rollback
+/// regenerates the area's canonical rows from the restored users. A
+/// rollback past events that others depend on is refused unless
+/// `confirm_cascade` is set, in which case the dependents are superseded
+/// with it.
+///
+/// An automatic checkpoint labeled `auto-before-rollback` is recorded in
+/// the area first. The checkpoint and the `Rollback` event are persisted
+/// in one transaction under a shared correlation ID, restoring the area
+/// as [`preview_rollback`] describes; rolling back to the checkpoint
+/// undoes the rollback.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The current bootstrap metadata
+/// * `state` - The current state of the area to roll back
+/// * `target` - The event, or labeled checkpoint, to roll back to
+/// * `confirm_cascade` - Whether dependent events may be superseded too
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for the authenticated actor
+/// * `cause` - The cause or reason for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The target event does not exist, or belongs to another area
+/// - No unsuperseded checkpoint in the area has the target label
+/// - The target event was superseded by an earlier rollback
+/// - The area has no snapshot at or before the target
+/// - Events elsewhere depend on superseded events and the cascade is not
+///   confirmed
+/// - The command execution fails
+/// - The checkpoint or the rollback cannot be persisted
+#[allow(clippy::too_many_arguments)]
+pub fn rollback(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    state: &State,
+    target: &RollbackTarget,
+    confirm_cascade: bool,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<RollbackResult, ApiError> {
+    AuthorizationService::authorize_rollback(authenticated_actor)?;
+
+    let target_event_id: i64 = resolve_target(persistence, metadata, state, target)?;
+    let preview: RollbackPreviewResponse = build_preview(persistence, target_event_id)?;
+    if preview.bid_year != state.bid_year.year() || preview.area_code != state.area.id() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("target_event_id"),
+            message: format!(
+                "Audit event {target_event_id} belongs to area {} in bid year {}, not the area being rolled back",
+                preview.area_code, preview.bid_year
+            ),
+        });
+    }
+    if !preview.dependent_events.is_empty() && !confirm_cascade {
+        let event_ids: Vec<String> = preview
+            .dependent_events
+            .iter()
+            .map(|event| event.event_id.to_string())
+            .collect();
+        return Err(ApiError::DomainRuleViolation {
+            rule: String::from("rollback_has_dependent_events"),
+            message: format!(
+                "Rolling area {} back to event {target_event_id} would also supersede dependent events {}; confirm the cascade to proceed",
+                preview.area_code,
+                event_ids.join(", ")
+            ),
+        });
+    }
+
+    let active_bid_year: BidYear = resolve_active_bid_year(persistence)?;
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let command: Command = Command::RollbackToEventId { target_event_id };
+    let transition_result: TransitionResult =
+        apply(metadata, state, &active_bid_year, command, actor, cause)
+            .map_err(translate_core_error)?;
+    let (checkpoints, event_ids): (AutoCheckpoints, Vec<i64>) = record_with_auto_checkpoints(
+        persistence,
+        metadata,
+        std::slice::from_ref(resolve_area(metadata, state)?),
+        "rollback",
+        &transition_result.audit_event.actor,
+        &transition_result.audit_event.cause,
+        std::slice::from_ref(&transition_result),
+    )?;
+    let checkpoint_event_id: i64 =
+        checkpoints
+            .event_ids
+            .first()
+            .copied()
+            .ok_or_else(|| ApiError::Internal {
+                message: String::from("No automatic checkpoint was recorded before the rollback"),
+            })?;
+    let event_id: i64 = event_ids
+        .first()
+        .copied()
+        .ok_or_else(|| ApiError::Internal {
+            message: String::from("The rollback event was not recorded"),
+        })?;
+
+    Ok(RollbackResult {
+        transition_result,
+        event_id,
+        correlation_id: checkpoints.correlation_id,
+        checkpoint_event_id,
+    })
+}
@@ -0,0 +1,624 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Directory roster sync.
+//!
+//! Facilities that keep their controller roster in LDAP or Active Directory
+//! can keep the bid roster in step with it. The server reads group
+//! membership into [`DirectoryMember`]s, and [`plan_roster_sync`] compares
+//! them with the active bid year's roster, proposing operations without
+//! changing anything:
+//!
+//! - **add** registers a member who is not on the roster
+//! - **update** brings a user's name, area, type, or crew in line with the
+//!   directory; seniority dates are never overwritten
+//! - **remove** excludes a user who has left the directory from bidding
+//!   and leave calculation. Users are never deleted, so their history stays.
+//!
+//! Members are matched to users by initials. A user already excluded from
+//! bidding is neither removed again nor restored when they reappear.
+//!
+//! An admin reviews the plan and approves operations by ID.
+//! [`apply_roster_sync`] plans again from a fresh directory read and
+//! applies only approved operations that are still proposed unchanged,
+//! each through the same command a manual change would use, recorded as
+//! the `system` actor `roster_sync` under the approving admin's operator.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use sha2::{Digest, Sha256};
+use zab_bid::{BootstrapMetadata, State, TransitionResult, apply};
+use zab_bid_audit::{Actor, Cause};
+use zab_bid_domain::{Area, BidYear, BidYearLifecycle, User, UserType};
+use zab_bid_persistence::{OperatorData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::error::{ApiError, translate_core_error};
+use crate::handlers::{
+    apply_update_user, build_register_user_command, ensure_user_registration_allowed,
+    resolve_active_bid_year, update_user_participation,
+};
+use crate::leave_bids::load_lifecycle_state;
+use crate::request_response::{
+    AppliedRosterSyncOperation, ApplyRosterSyncRequest, ApplyRosterSyncResponse, DirectoryMember,
+    RegisterUserRequest, RosterSyncAction, RosterSyncOperation, RosterSyncPlanResponse,
+    SkippedRosterSyncOperation, UpdateUserParticipationRequest, UpdateUserRequest,
+};
+use crate::webhooks::require_admin;
+
+/// The audit actor ID approved roster sync changes are recorded under.
+const SYSTEM_ACTOR_ID: &str = "roster_sync";
+
+/// A proposed operation, with what applying it takes.
+struct PlannedOperation {
+    operation: RosterSyncOperation,
+    change: Change,
+}
+
+/// The change an operation makes when applied.
+enum Change {
+    /// Registers a new user.
+    Add(RegisterUserRequest),
+    /// Updates a user, who is currently in `current_area`.
+    Update {
+        request: UpdateUserRequest,
+        current_area: Area,
+    },
+    /// Excludes a user from bidding and leave calculation.
+    Remove,
+}
+
+/// A user on the active bid year's roster.
+struct RosterUser {
+    user: User,
+    user_id: i64,
+    area: Area,
+}
+
+/// The active bid year and its areas, keyed by area code.
+struct ActiveBidYear {
+    bid_year: BidYear,
+    bid_year_id: i64,
+    areas: BTreeMap<String, Area>,
+}
+
+/// Resolves the active bid year and its areas from metadata.
+fn active_bid_year(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+) -> Result<ActiveBidYear, ApiError> {
+    let year: u16 = resolve_active_bid_year(persistence)?.year();
+    let bid_year_id: i64 = metadata
+        .bid_years
+        .iter()
+        .find(|by| by.year() == year)
+        .and_then(BidYear::bid_year_id)
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Active bid year {year} has no ID in metadata"),
+        })?;
+    let areas: BTreeMap<String, Area> = metadata
+        .areas
+        .iter()
+        .filter(|(by, _)| by.year() == year)
+        .map(|(_, area)| (area.area_code().to_uppercase(), area.clone()))
+        .collect();
+
+    Ok(ActiveBidYear {
+        bid_year: BidYear::new(year),
+        bid_year_id,
+        areas,
+    })
+}
+
+/// Loads every user of the active bid year, keyed by initials.
+fn load_roster(
+    persistence: &mut SqlitePersistence,
+    active: &ActiveBidYear,
+) -> Result<BTreeMap<String, RosterUser>, ApiError> {
+    let mut roster: BTreeMap<String, RosterUser> = BTreeMap::new();
+    for area in active.areas.values() {
+        let users: Vec<User> = persistence
+            .list_users(&active.bid_year, area)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list users: {e}"),
+            })?;
+        for user in users {
+            let user_id: i64 = user.user_id.ok_or_else(|| ApiError::Internal {
+                message: format!("User '{}' has no canonical ID", user.initials.value()),
+            })?;
+            roster.insert(
+                user.initials.value().to_string(),
+                RosterUser {
+                    user,
+                    user_id,
+                    area: area.clone(),
+                },
+            );
+        }
+    }
+    Ok(roster)
+}
+
+/// Derives an operation ID from everything the operation changes, so an
+/// approval never applies a change other than the one reviewed.
+fn operation_id(action: RosterSyncAction, subject: &str, parts: &[&str]) -> String {
+    let label: &str = match action {
+        RosterSyncAction::Add => "add",
+        RosterSyncAction::Update => "update",
+        RosterSyncAction::Remove => "remove",
+    };
+    let mut hasher: Sha256 = Sha256::new().chain_update(label).chain_update(subject);
+    for part in parts {
+        hasher.update([0]);
+        hasher.update(part);
+    }
+    format!("{label}:{subject}:{}", hex::encode(&hasher.finalize()[..4]))
+}
+
+/// Names the seniority dates a directory member is missing.
+fn missing_seniority(member: &DirectoryMember) -> Vec<&'static str> {
+    [
+        ("cumulative_natca_bu_date", &member.cumulative_natca_bu_date),
+        ("natca_bu_date", &member.natca_bu_date),
+        ("eod_faa_date", &member.eod_faa_date),
+        ("service_computation_date", &member.service_computation_date),
+    ]
+    .into_iter()
+    .filter(|(_, value)| value.as_deref().is_none_or(|v| v.trim().is_empty()))
+    .map(|(name, _)| name)
+    .collect()
+}
+
+/// Proposes registering a directory member who is not on the roster.
+fn plan_add(
+    member: &DirectoryMember,
+    initials: &str,
+    area: Option<&Area>,
+    conflict: Option<String>,
+) -> PlannedOperation {
+    let area_code: String = area.map_or_else(
+        || member.area_code.to_uppercase(),
+        |area| area.area_code().to_string(),
+    );
+    let missing: Vec<&str> = missing_seniority(member);
+    let blocked_reason: Option<String> = conflict.or_else(|| {
+        if area.is_none() {
+            Some(format!(
+                "Area '{area_code}' does not exist in the active bid year"
+            ))
+        } else if member.user_type.is_none() {
+            Some(String::from("Directory has no user type"))
+        } else if missing.is_empty() {
+            None
+        } else {
+            Some(format!("Directory has no {}", missing.join(", ")))
+        }
+    });
+
+    let request: RegisterUserRequest = RegisterUserRequest {
+        initials: initials.to_string(),
+        name: member.name.clone(),
+        area: area_code.clone(),
+        user_type: member.user_type.clone().unwrap_or_default(),
+        crew: member.crew,
+        cumulative_natca_bu_date: member.cumulative_natca_bu_date.clone().unwrap_or_default(),
+        natca_bu_date: member.natca_bu_date.clone().unwrap_or_default(),
+        eod_faa_date: member.eod_faa_date.clone().unwrap_or_default(),
+        service_computation_date: member.service_computation_date.clone().unwrap_or_default(),
+        lottery_value: None,
+    };
+
+    let mut changes: Vec<String> = vec![
+        format!("name: '{}'", request.name),
+        format!("area: {area_code}"),
+    ];
+    if let Some(user_type) = &member.user_type {
+        changes.push(format!("type: {user_type}"));
+    }
+    if let Some(crew) = member.crew {
+        changes.push(format!("crew: {crew}"));
+    }
+    changes.push(format!(
+        "seniority: {} / {} / {} / {}",
+        request.cumulative_natca_bu_date,
+        request.natca_bu_date,
+        request.eod_faa_date,
+        request.service_computation_date
+    ));
+
+    let mut parts: Vec<&str> = changes.iter().map(String::as_str).collect();
+    parts.extend(blocked_reason.as_deref());
+    PlannedOperation {
+        operation: RosterSyncOperation {
+            operation_id: operation_id(RosterSyncAction::Add, initials, &parts),
+            action: RosterSyncAction::Add,
+            user_id: None,
+            initials: initials.to_string(),
+            name: request.name.clone(),
+            area_code,
+            changes,
+            blocked_reason,
+        },
+        change: Change::Add(request),
+    }
+}
+
+/// Proposes bringing a user in line with their directory entry, or returns
+/// `None` if they already match.
+fn plan_update(
+    member: &DirectoryMember,
+    existing: &RosterUser,
+    area: Option<&Area>,
+    conflict: Option<String>,
+) -> Option<PlannedOperation> {
+    let user: &User = &existing.user;
+    let mut changes: Vec<String> = Vec::new();
+    let mut blocked_reason: Option<String> = conflict;
+
+    if member.name != user.name {
+        changes.push(format!("name: '{}' -> '{}'", user.name, member.name));
+    }
+
+    if area.is_none() {
+        blocked_reason.get_or_insert_with(|| {
+            format!(
+                "Area '{}' does not exist in the active bid year",
+                member.area_code.to_uppercase()
+            )
+        });
+    }
+    let target_area: &Area = area.unwrap_or(&existing.area);
+    if target_area.area_code() != existing.area.area_code() {
+        changes.push(format!(
+            "area: {} -> {}",
+            existing.area.area_code(),
+            target_area.area_code()
+        ));
+    }
+
+    let mut user_type: &str = user.user_type.as_str();
+    if let Some(directory_type) = &member.user_type {
+        match UserType::parse(directory_type) {
+            Ok(parsed) if parsed != user.user_type => {
+                changes.push(format!("type: {} -> {}", user_type, parsed.as_str()));
+                user_type = parsed.as_str();
+            }
+            Ok(_) => {}
+            Err(_) => {
+                blocked_reason.get_or_insert_with(|| {
+                    format!("Directory user type '{directory_type}' is not valid")
+                });
+            }
+        }
+    }
+
+    let current_crew: Option<u8> = user.crew.map(|crew| crew.number());
+    let crew: Option<u8> = member.crew.or(current_crew);
+    if crew != current_crew {
+        changes.push(format!(
+            "crew: {} -> {}",
+            current_crew.map_or_else(|| String::from("none"), |c| c.to_string()),
+            crew.map_or_else(|| String::from("none"), |c| c.to_string())
+        ));
+    }
+
+    if changes.is_empty() && blocked_reason.is_none() {
+        return None;
+    }
+
+    let seniority = &user.seniority_data;
+    let request: UpdateUserRequest = UpdateUserRequest {
+        user_id: existing.user_id,
+        initials: user.initials.value().to_string(),
+        name: member.name.clone(),
+        area_id: target_area.area_id().unwrap_or_default(),
+        user_type: user_type.to_string(),
+        crew,
+        cumulative_natca_bu_date: seniority.cumulative_natca_bu_date.clone(),
+        natca_bu_date: seniority.natca_bu_date.clone(),
+        eod_faa_date: seniority.eod_faa_date.clone(),
+        service_computation_date: seniority.service_computation_date.clone(),
+        lottery_value: seniority.lottery_value,
+    };
+
+    let subject: String = existing.user_id.to_string();
+    let mut parts: Vec<&str> = changes.iter().map(String::as_str).collect();
+    parts.extend(blocked_reason.as_deref());
+    Some(PlannedOperation {
+        operation: RosterSyncOperation {
+            operation_id: operation_id(RosterSyncAction::Update, &subject, &parts),
+            action: RosterSyncAction::Update,
+            user_id: Some(existing.user_id),
+            initials: request.initials.clone(),
+            name: request.name.clone(),
+            area_code: target_area.area_code().to_string(),
+            changes,
+            blocked_reason,
+        },
+        change: Change::Update {
+            request,
+            current_area: existing.area.clone(),
+        },
+    })
+}
+
+/// Proposes excluding a user who is no longer in the directory.
+fn plan_remove(existing: &RosterUser) -> PlannedOperation {
+    let changes: Vec<String> = vec![String::from("excluded from bidding and leave calculation")];
+    let subject: String = existing.user_id.to_string();
+    let parts: Vec<&str> = changes.iter().map(String::as_str).collect();
+    PlannedOperation {
+        operation: RosterSyncOperation {
+            operation_id: operation_id(RosterSyncAction::Remove, &subject, &parts),
+            action: RosterSyncAction::Remove,
+            user_id: Some(existing.user_id),
+            initials: existing.user.initials.value().to_string(),
+            name: existing.user.name.clone(),
+            area_code: existing.area.area_code().to_string(),
+            changes,
+            blocked_reason: None,
+        },
+        change: Change::Remove,
+    }
+}
+
+/// Compares the directory with the roster.
+fn build_plan(
+    active: &ActiveBidYear,
+    roster: &BTreeMap<String, RosterUser>,
+    members: &[DirectoryMember],
+) -> Vec<PlannedOperation> {
+    let mut by_initials: BTreeMap<String, Vec<&DirectoryMember>> = BTreeMap::new();
+    for member in members {
+        by_initials
+            .entry(member.initials.trim().to_uppercase())
+            .or_default()
+            .push(member);
+    }
+
+    let mut adds: Vec<PlannedOperation> = Vec::new();
+    let mut updates: Vec<PlannedOperation> = Vec::new();
+    for (initials, entries) in &by_initials {
+        let member: &DirectoryMember = entries[0];
+        let area_codes: BTreeSet<String> = entries
+            .iter()
+            .map(|entry| entry.area_code.to_uppercase())
+            .collect();
+        let conflict: Option<String> = (area_codes.len() > 1).then(|| {
+            format!(
+                "Listed in more than one area group: {}",
+                area_codes
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        });
+        let area: Option<&Area> = active.areas.get(&member.area_code.to_uppercase());
+
+        match roster.get(initials) {
+            None => adds.push(plan_add(member, initials, area, conflict)),
+            Some(existing) if existing.user.excluded_from_bidding => {}
+            Some(existing) => updates.extend(plan_update(member, existing, area, conflict)),
+        }
+    }
+
+    let removals = roster
+        .iter()
+        .filter(|(initials, existing)| {
+            !existing.user.excluded_from_bidding && !by_initials.contains_key(*initials)
+        })
+        .map(|(_, existing)| plan_remove(existing));
+
+    adds.into_iter().chain(updates).chain(removals).collect()
+}
+
+/// Plans a sync of the active bid year's roster with the directory.
+fn plan(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    members: &[DirectoryMember],
+) -> Result<(ActiveBidYear, Vec<PlannedOperation>), ApiError> {
+    let active: ActiveBidYear = active_bid_year(persistence, metadata)?;
+    let roster: BTreeMap<String, RosterUser> = load_roster(persistence, &active)?;
+    let planned: Vec<PlannedOperation> = build_plan(&active, &roster, members);
+    Ok((active, planned))
+}
+
+/// Proposes the changes that bring the active bid year's roster in line
+/// with the directory, without changing anything.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `members` - Every controller read from the directory
+///
+/// # Errors
+///
+/// Returns an error if there is no active bid year or the roster cannot
+/// be read.
+pub fn plan_roster_sync(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    members: &[DirectoryMember],
+) -> Result<RosterSyncPlanResponse, ApiError> {
+    let (active, planned) = plan(persistence, metadata, members)?;
+    Ok(RosterSyncPlanResponse {
+        bid_year_id: active.bid_year_id,
+        bid_year: active.bid_year.year(),
+        directory_members: members.len(),
+        operations: planned.into_iter().map(|p| p.operation).collect(),
+    })
+}
+
+/// Applies one planned operation, returning the user it changed.
+fn apply_operation(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    active: &ActiveBidYear,
+    change: &Change,
+    user_id: Option<i64>,
+    actor: &Actor,
+    cause: &Cause,
+) -> Result<i64, ApiError> {
+    match change {
+        Change::Add(request) => {
+            ensure_user_registration_allowed(persistence, metadata, &active.bid_year)?;
+            let area: Area = Area::new(&request.area);
+            let state: State = persistence
+                .get_current_state(&active.bid_year, &area)
+                .unwrap_or_else(|_| State::new(active.bid_year.clone(), area.clone()));
+            let result: TransitionResult = apply(
+                metadata,
+                &state,
+                &active.bid_year,
+                build_register_user_command(request)?,
+                actor.clone(),
+                cause.clone(),
+            )
+            .map_err(translate_core_error)?;
+            persistence
+                .persist_transition(&result)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to register user: {e}"),
+                })?
+                .user_id
+                .ok_or_else(|| ApiError::Internal {
+                    message: String::from("RegisterUser transition did not return user_id"),
+                })
+        }
+        Change::Update {
+            request,
+            current_area,
+        } => {
+            let state: State = persistence
+                .get_current_state(&active.bid_year, current_area)
+                .map_err(|e| ApiError::Internal {
+                    message: format!("Failed to load area state: {e}"),
+                })?;
+            apply_update_user(
+                persistence,
+                metadata,
+                &state,
+                request,
+                actor.clone(),
+                cause.clone(),
+            )?;
+            Ok(request.user_id)
+        }
+        Change::Remove => {
+            let user_id: i64 = user_id.ok_or_else(|| ApiError::Internal {
+                message: String::from("Removal has no user"),
+            })?;
+            let lifecycle_state: BidYearLifecycle =
+                load_lifecycle_state(persistence, active.bid_year_id)?;
+            update_user_participation(
+                metadata,
+                persistence,
+                &UpdateUserParticipationRequest {
+                    user_id,
+                    excluded_from_bidding: true,
+                    excluded_from_leave_calculation: true,
+                },
+                actor,
+                lifecycle_state,
+            )?;
+            Ok(user_id)
+        }
+    }
+}
+
+/// Applies approved roster sync operations.
+///
+/// The directory is compared with the roster again, and only approved
+/// operations that are still proposed, and not blocked, are applied, in
+/// the order they were approved. Each is applied through the same command
+/// as the equivalent manual change and recorded as the `system` actor
+/// `roster_sync` under the approving operator. An operation that fails is
+/// reported as skipped and does not stop the others.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `members` - Every controller read from the directory, freshly read
+/// * `request` - The IDs of the approved operations
+/// * `authenticated_actor` - The authenticated actor approving the operations
+/// * `operator` - The operator data for audit attribution
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin, there is no active bid
+/// year, or the roster cannot be read.
+pub fn apply_roster_sync(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    members: &[DirectoryMember],
+    request: &ApplyRosterSyncRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<ApplyRosterSyncResponse, ApiError> {
+    require_admin(authenticated_actor, "apply roster sync")?;
+
+    let (active, planned) = plan(persistence, metadata, members)?;
+
+    let actor: Actor = Actor::with_operator(
+        String::from(SYSTEM_ACTOR_ID),
+        String::from("system"),
+        operator.operator_id,
+        operator.login_name.clone(),
+        operator.display_name.clone(),
+    );
+    let cause: Cause = Cause::new(
+        String::from("roster_sync"),
+        String::from("Approved directory roster sync"),
+    );
+
+    let mut applied: Vec<AppliedRosterSyncOperation> = Vec::new();
+    let mut skipped: Vec<SkippedRosterSyncOperation> = Vec::new();
+    let mut seen: BTreeSet<&str> = BTreeSet::new();
+    for operation_id in &request.operation_ids {
+        if !seen.insert(operation_id) {
+            continue;
+        }
+        let skip = |reason: String| SkippedRosterSyncOperation {
+            operation_id: operation_id.clone(),
+            reason,
+        };
+        let Some(planned) = planned
+            .iter()
+            .find(|p| &p.operation.operation_id == operation_id)
+        else {
+            skipped.push(skip(String::from(
+                "No longer proposed: the directory or roster changed since it was reviewed",
+            )));
+            continue;
+        };
+        if let Some(reason) = &planned.operation.blocked_reason {
+            skipped.push(skip(reason.clone()));
+            continue;
+        }
+
+        match apply_operation(
+            persistence,
+            metadata,
+            &active,
+            &planned.change,
+            planned.operation.user_id,
+            &actor,
+            &cause,
+        ) {
+            Ok(user_id) => applied.push(AppliedRosterSyncOperation {
+                operation_id: operation_id.clone(),
+                action: planned.operation.action,
+                user_id,
+            }),
+            Err(e) => skipped.push(skip(e.to_string())),
+        }
+    }
+
+    Ok(ApplyRosterSyncResponse { applied, skipped })
+}
@@ -0,0 +1,304 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Per-round bid order handlers.
+//!
+//! Every round bids in its own sequence, derived by core from the area's
+//! canonical bid order when the bid year is confirmed ready to bid. Users
+//! the round's eligibility criteria exclude are skipped, and the round
+//! group's rotation decides whether the round bids front to back or back
+//! to front. The sequences and the bid windows scheduled from them are
+//! stored per round.
+
+use zab_bid::{BidOrderRotation, BootstrapMetadata, sequence_round_bid_order};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{
+    BidOrderPosition, BidSchedule, BidWindow, BidYearLifecycle, DomainError, Round,
+    calculate_bid_windows_with_blackouts,
+};
+use zab_bid_persistence::{
+    OperatorData, PersistenceError, RoundBidOrderData, RoundSequencingData, SqlitePersistence,
+};
+
+use crate::auth::AuthenticatedActor;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_domain_error};
+use crate::leave_bids::load_lifecycle_state;
+use crate::request_response::{
+    GetRoundBidOrderResponse, RoundBidOrderEntryInfo, SetRoundGroupRotationRequest,
+    SetRoundGroupRotationResponse,
+};
+use crate::round_eligibility::RoundEligibilityRules;
+use crate::webhooks::require_admin;
+
+/// A round's place in its round group and the group's rotation.
+pub struct RoundSequence {
+    /// The round ID.
+    pub round_id: i64,
+    /// The round's number within its round group.
+    pub round_number: u32,
+    /// The round group's rotation.
+    pub rotation: BidOrderRotation,
+}
+
+/// The bidding sequences of an area's rounds and the windows scheduled
+/// from them.
+pub struct AreaRoundOrders {
+    /// One entry per round, in the order the rounds were given.
+    pub sequences: Vec<(i64, Vec<BidOrderPosition>)>,
+    /// The bid windows of every round.
+    pub bid_windows: Vec<BidWindow>,
+}
+
+/// Parses a stored rotation, treating a missing one as forward.
+fn parse_rotation(rotation: Option<&str>) -> Result<BidOrderRotation, ApiError> {
+    rotation.map_or(Ok(BidOrderRotation::Forward), |rotation| {
+        BidOrderRotation::parse(rotation).map_err(|e| ApiError::Internal {
+            message: format!("Stored bid order rotation is invalid: {e}"),
+        })
+    })
+}
+
+/// Loads a bid year's rounds with their round group's rotation.
+///
+/// # Errors
+///
+/// Returns an error if the rounds cannot be read or a stored rotation is
+/// invalid.
+pub fn load_round_sequences(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<Vec<RoundSequence>, ApiError> {
+    persistence
+        .list_round_sequencing(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list rounds: {e}"),
+        })?
+        .into_iter()
+        .map(|round: RoundSequencingData| {
+            Ok(RoundSequence {
+                round_id: round.round_id,
+                round_number: u32::try_from(round.round_number).unwrap_or(0),
+                rotation: parse_rotation(round.rotation.as_deref())?,
+            })
+        })
+        .collect()
+}
+
+/// Derives each round's bidding sequence for an area and schedules its
+/// bid windows.
+///
+/// # Arguments
+///
+/// * `bid_order` - The area's canonical bid order
+/// * `rounds` - The bid year's rounds
+/// * `eligibility` - The bid year's round eligibility criteria
+/// * `schedule` - The area's bid schedule
+/// * `blackout_dates` - Dates on which no bidding takes place
+///
+/// # Errors
+///
+/// Returns an error if a bid window cannot be calculated.
+pub fn build_area_round_orders(
+    bid_order: &[BidOrderPosition],
+    rounds: &[RoundSequence],
+    eligibility: &RoundEligibilityRules,
+    schedule: &BidSchedule,
+    blackout_dates: &[time::Date],
+) -> Result<AreaRoundOrders, ApiError> {
+    let mut orders: AreaRoundOrders = AreaRoundOrders {
+        sequences: Vec::with_capacity(rounds.len()),
+        bid_windows: Vec::new(),
+    };
+    for round in rounds {
+        let sequence: Vec<BidOrderPosition> = sequence_round_bid_order(
+            bid_order,
+            round.round_number,
+            round.rotation,
+            eligibility.criteria(round.round_id),
+            eligibility.subjects(),
+        );
+        let user_positions: Vec<(i64, usize)> = sequence
+            .iter()
+            .map(|position| (position.user_id, position.position))
+            .collect();
+        orders.bid_windows.extend(
+            calculate_bid_windows_with_blackouts(
+                &user_positions,
+                &[round.round_id],
+                schedule,
+                blackout_dates,
+            )
+            .map_err(translate_domain_error)?,
+        );
+        orders.sequences.push((round.round_id, sequence));
+    }
+    Ok(orders)
+}
+
+/// Gets a round's bidding sequence.
+///
+/// # Errors
+///
+/// Returns an error if the round does not exist or the database cannot be
+/// queried.
+pub fn get_round_bid_order(
+    persistence: &mut SqlitePersistence,
+    round_id: i64,
+) -> Result<GetRoundBidOrderResponse, ApiError> {
+    let round: Round = persistence.get_round(round_id).map_err(|e| match e {
+        PersistenceError::NotFound(_) => {
+            translate_domain_error(DomainError::RoundNotFound { round_id })
+        }
+        _ => ApiError::Internal {
+            message: format!("Failed to get round: {e}"),
+        },
+    })?;
+    let round_group_id: i64 =
+        round
+            .round_group()
+            .round_group_id()
+            .ok_or_else(|| ApiError::Internal {
+                message: String::from("persisted round group missing ID"),
+            })?;
+    let rotation: BidOrderRotation = parse_rotation(
+        persistence
+            .get_round_group_rotation(round_group_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get round group rotation: {e}"),
+            })?
+            .as_deref(),
+    )?;
+
+    let entries: Vec<RoundBidOrderEntryInfo> = persistence
+        .list_round_bid_order(round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list round bid order: {e}"),
+        })?
+        .into_iter()
+        .map(|entry: RoundBidOrderData| RoundBidOrderEntryInfo {
+            area_id: entry.area_id,
+            user_id: entry.user_id,
+            position: u32::try_from(entry.position).unwrap_or(0),
+        })
+        .collect();
+
+    Ok(GetRoundBidOrderResponse {
+        round_id,
+        rotation: rotation.as_str().to_string(),
+        entries,
+    })
+}
+
+/// Sets a round group's bid order rotation.
+///
+/// The rotation takes effect when the bid year is confirmed ready to bid,
+/// so it is fixed once the bid year is canonicalized.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The round group and its rotation
+/// * `authenticated_actor` - The authenticated actor setting the rotation
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year or round group does not exist
+/// - The bid year has been canonicalized
+/// - The rotation is unknown
+/// - The database operation fails
+pub fn set_round_group_rotation(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetRoundGroupRotationRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetRoundGroupRotationResponse, ApiError> {
+    require_admin(authenticated_actor, "set round group rotation")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    if lifecycle_state.is_locked() {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("set round group rotation"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+    let in_bid_year: bool = persistence
+        .list_round_groups(request.bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list round groups: {e}"),
+        })?
+        .iter()
+        .any(|group| group.round_group_id() == Some(request.round_group_id));
+    if !in_bid_year {
+        return Err(translate_domain_error(DomainError::RoundGroupNotFound {
+            round_group_id: request.round_group_id,
+        }));
+    }
+
+    let rotation: BidOrderRotation =
+        BidOrderRotation::parse(&request.rotation).map_err(translate_domain_error)?;
+    let previous: BidOrderRotation = parse_rotation(
+        persistence
+            .get_round_group_rotation(request.round_group_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get round group rotation: {e}"),
+            })?
+            .as_deref(),
+    )?;
+
+    let stored: Option<&str> = (rotation != BidOrderRotation::Forward).then_some(rotation.as_str());
+    persistence
+        .set_round_group_rotation(request.bid_year_id, request.round_group_id, stored)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to set round group rotation: {e}"),
+        })?;
+
+    let message: String = format!(
+        "Round group {} now uses the {} bid order rotation",
+        request.round_group_id,
+        rotation.as_str()
+    );
+    let describe = |rotation: BidOrderRotation| {
+        format!(
+            "round_group_id={},rotation={}",
+            request.round_group_id,
+            rotation.as_str()
+        )
+    };
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("SetRoundGroupRotation"),
+        Some(format!("{message} in bid year {year}")),
+    );
+    let audit_event: AuditEvent = AuditEvent::new_global(
+        actor,
+        cause,
+        action,
+        StateSnapshot::new(describe(previous)),
+        StateSnapshot::new(describe(rotation)),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetRoundGroupRotationResponse {
+        bid_year_id: request.bid_year_id,
+        round_group_id: request.round_group_id,
+        rotation: rotation.as_str().to_string(),
+        message,
+    })
+}
@@ -0,0 +1,311 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Round eligibility handlers.
+//!
+//! A round may be limited to a subset of an area's bidders, such as those
+//! with a projected leave balance above a threshold or of certain user
+//! types. The criteria are evaluated by core when a round's bid order is
+//! built at confirmation, so they are fixed once the bid year is
+//! canonicalized. Bidders a round excludes get no window or bid status in
+//! it.
+
+use std::collections::{BTreeMap, HashMap};
+
+use zab_bid::{
+    BootstrapMetadata, RoundEligibility, RoundSubject, is_eligible_for_round,
+    validate_round_eligibility,
+};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{BidYearLifecycle, DomainError, User, UserType};
+use zab_bid_persistence::{OperatorData, RoundEligibilityData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_domain_error};
+use crate::leave_bids::{load_lifecycle_state, require_round};
+use crate::leave_caps::project_leave_balances;
+use crate::request_response::{
+    RoundEligibilityInfo, SetRoundEligibilityRequest, SetRoundEligibilityResponse,
+};
+use crate::webhooks::require_admin;
+
+/// Converts stored criteria into their core representation.
+fn to_round_eligibility(data: &RoundEligibilityData) -> Result<RoundEligibility, ApiError> {
+    let user_types: Vec<UserType> = data
+        .user_types
+        .iter()
+        .map(|user_type| UserType::parse(user_type))
+        .collect::<Result<Vec<UserType>, DomainError>>()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Stored round eligibility is invalid: {e}"),
+        })?;
+
+    Ok(RoundEligibility {
+        min_remaining_hours: data
+            .min_remaining_hours
+            .and_then(|hours| u32::try_from(hours).ok()),
+        user_types,
+    })
+}
+
+/// Converts core criteria into their API representation.
+fn to_round_eligibility_info(criteria: &RoundEligibility) -> RoundEligibilityInfo {
+    RoundEligibilityInfo {
+        min_remaining_hours: criteria.min_remaining_hours,
+        user_types: criteria
+            .user_types
+            .iter()
+            .map(|user_type| user_type.as_str().to_string())
+            .collect(),
+    }
+}
+
+/// Describes a round's criteria for audit snapshots.
+fn describe_round_eligibility(round_id: i64, criteria: Option<&RoundEligibility>) -> String {
+    criteria.map_or_else(
+        || format!("round_id={round_id},eligibility=all"),
+        |criteria| {
+            let user_types: Vec<&str> = criteria.user_types.iter().map(UserType::as_str).collect();
+            format!(
+                "round_id={round_id},min_remaining_hours={},user_types={}",
+                criteria
+                    .min_remaining_hours
+                    .map_or_else(|| String::from("none"), |hours| hours.to_string()),
+                user_types.join("|")
+            )
+        },
+    )
+}
+
+/// Gets a round's eligibility criteria for the round detail API.
+///
+/// # Errors
+///
+/// Returns an error if the criteria cannot be read or are invalid.
+pub fn get_round_eligibility_info(
+    persistence: &mut SqlitePersistence,
+    round_id: i64,
+) -> Result<Option<RoundEligibilityInfo>, ApiError> {
+    persistence
+        .get_round_eligibility(round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get round eligibility: {e}"),
+        })?
+        .map(|data| {
+            to_round_eligibility(&data).map(|criteria| to_round_eligibility_info(&criteria))
+        })
+        .transpose()
+}
+
+/// The round criteria of a bid year and the facts they are evaluated
+/// against.
+pub struct RoundEligibilityRules {
+    criteria: HashMap<i64, RoundEligibility>,
+    subjects: BTreeMap<i64, RoundSubject>,
+}
+
+impl RoundEligibilityRules {
+    /// Loads a bid year's round criteria and, if any round is restricted,
+    /// each user's type and projected leave balance.
+    ///
+    /// Users whose balance cannot be projected count as having none left.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the criteria or balances cannot be loaded.
+    pub fn load<'a>(
+        persistence: &mut SqlitePersistence,
+        metadata: &BootstrapMetadata,
+        bid_year_id: i64,
+        users: impl IntoIterator<Item = &'a User>,
+    ) -> Result<Self, ApiError> {
+        let criteria: HashMap<i64, RoundEligibility> = persistence
+            .list_round_eligibility(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list round eligibility: {e}"),
+            })?
+            .iter()
+            .map(|data| Ok((data.round_id, to_round_eligibility(data)?)))
+            .collect::<Result<HashMap<i64, RoundEligibility>, ApiError>>()?;
+        if criteria.is_empty() {
+            return Ok(Self {
+                criteria,
+                subjects: BTreeMap::new(),
+            });
+        }
+
+        let remaining: HashMap<i64, i64> =
+            project_leave_balances(persistence, metadata, bid_year_id, None)?
+                .into_iter()
+                .map(|row| (row.user_id, row.projection.projected_balance_hours))
+                .collect();
+        let subjects: BTreeMap<i64, RoundSubject> = users
+            .into_iter()
+            .filter_map(|user| {
+                let user_id: i64 = user.user_id?;
+                Some((
+                    user_id,
+                    RoundSubject {
+                        user_type: user.user_type,
+                        remaining_hours: remaining.get(&user_id).copied().unwrap_or(0),
+                    },
+                ))
+            })
+            .collect();
+
+        Ok(Self { criteria, subjects })
+    }
+
+    /// Returns a round's criteria, if it is restricted.
+    #[must_use]
+    pub fn criteria(&self, round_id: i64) -> Option<&RoundEligibility> {
+        self.criteria.get(&round_id)
+    }
+
+    /// Returns the facts each user is evaluated against, keyed by user ID.
+    #[must_use]
+    pub const fn subjects(&self) -> &BTreeMap<i64, RoundSubject> {
+        &self.subjects
+    }
+
+    /// Returns whether a user takes part in a round.
+    #[must_use]
+    pub fn admits(&self, user_id: i64, round_id: i64) -> bool {
+        self.criteria.get(&round_id).is_none_or(|criteria| {
+            criteria.is_unrestricted()
+                || self
+                    .subjects
+                    .get(&user_id)
+                    .is_some_and(|subject| is_eligible_for_round(criteria, subject))
+        })
+    }
+}
+
+/// Sets or clears a round's eligibility criteria.
+///
+/// Criteria that set nothing are cleared, so every bidder in the area
+/// takes part in the round.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The round and its criteria
+/// * `authenticated_actor` - The authenticated actor setting the criteria
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year or round does not exist
+/// - The bid year has been canonicalized
+/// - A user type is unknown or listed twice
+/// - The database operation fails
+pub fn set_round_eligibility(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetRoundEligibilityRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetRoundEligibilityResponse, ApiError> {
+    require_admin(authenticated_actor, "set round eligibility")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    if lifecycle_state.is_locked() {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("set round eligibility"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+    require_round(persistence, request.bid_year_id, request.round_id)?;
+
+    let user_types: Vec<UserType> = request
+        .user_types
+        .iter()
+        .map(|user_type| UserType::parse(user_type))
+        .collect::<Result<Vec<UserType>, DomainError>>()
+        .map_err(translate_domain_error)?;
+    let criteria: RoundEligibility = RoundEligibility {
+        min_remaining_hours: request.min_remaining_hours,
+        user_types,
+    };
+    validate_round_eligibility(&criteria).map_err(translate_domain_error)?;
+    let min_remaining_hours: Option<i32> = criteria
+        .min_remaining_hours
+        .map(i32::try_from)
+        .transpose()
+        .map_err(|_| ApiError::InvalidInput {
+            field: String::from("min_remaining_hours"),
+            message: String::from("Minimum remaining hours is out of range"),
+        })?;
+    let criteria: Option<RoundEligibility> = Some(criteria).filter(|c| !c.is_unrestricted());
+
+    let previous: Option<RoundEligibility> = persistence
+        .get_round_eligibility(request.round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get round eligibility: {e}"),
+        })?
+        .as_ref()
+        .map(to_round_eligibility)
+        .transpose()?;
+
+    let data: Option<RoundEligibilityData> =
+        criteria.as_ref().map(|criteria| RoundEligibilityData {
+            round_id: request.round_id,
+            min_remaining_hours,
+            user_types: criteria
+                .user_types
+                .iter()
+                .map(|user_type| user_type.as_str().to_string())
+                .collect(),
+        });
+    persistence
+        .set_round_eligibility(request.bid_year_id, request.round_id, data.as_ref())
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to set round eligibility: {e}"),
+        })?;
+
+    let message: String = if criteria.is_some() {
+        format!(
+            "Round {} is now limited to eligible bidders",
+            request.round_id
+        )
+    } else {
+        format!("Round {} is now open to every bidder", request.round_id)
+    };
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("SetRoundEligibility"),
+        Some(format!("{message} in bid year {year}")),
+    );
+    let before: StateSnapshot = StateSnapshot::new(describe_round_eligibility(
+        request.round_id,
+        previous.as_ref(),
+    ));
+    let after: StateSnapshot = StateSnapshot::new(describe_round_eligibility(
+        request.round_id,
+        criteria.as_ref(),
+    ));
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetRoundEligibilityResponse {
+        bid_year_id: request.bid_year_id,
+        round_id: request.round_id,
+        eligibility: criteria.as_ref().map(to_round_eligibility_info),
+        message,
+    })
+}
@@ -0,0 +1,243 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Round sign-off handlers.
+//!
+//! Once every eligible user in an area has bid, been skipped, or waived
+//! their turn in a round, an Admin signs the round off through the core
+//! `SignOffRound` command. The sign-off records a summary audit event and
+//! locks the round's bids in that area: further leave can only be entered
+//! by an Admin giving an override reason, and overbids can no longer be
+//! requested.
+
+use zab_bid::{BootstrapMetadata, BootstrapResult, Command, apply_bootstrap};
+use zab_bid_audit::Cause;
+use zab_bid_domain::{BidStatus, BidYearLifecycle, DomainError, Initials};
+use zab_bid_persistence::{
+    OperatorData, RoundResultEntryData, RoundSignOffData, SqlitePersistence,
+};
+
+use crate::auth::AuthenticatedActor;
+use crate::auto_checkpoints::{AutoCheckpoints, record_auto_checkpoints, record_operation_events};
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_core_error, translate_domain_error};
+use crate::leave_bids::{load_lifecycle_state, require_round, resolve_area};
+use crate::request_response::{
+    ListRoundSignOffsResponse, RoundSignOffInfo, SignOffRoundRequest, SignOffRoundResponse,
+};
+use crate::webhooks::require_admin;
+
+/// Returns whether a round has been signed off in an area.
+///
+/// # Errors
+///
+/// Returns an error if the sign-off cannot be read.
+pub fn is_round_signed_off(
+    persistence: &mut SqlitePersistence,
+    area_id: i64,
+    round_id: i64,
+) -> Result<bool, ApiError> {
+    Ok(persistence
+        .get_round_sign_off(area_id, round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get round sign-off: {e}"),
+        })?
+        .is_some())
+}
+
+/// Ensures a round has not been signed off in an area.
+///
+/// # Errors
+///
+/// Returns an error if the round has been signed off or the sign-off
+/// cannot be read.
+pub fn require_round_open(
+    persistence: &mut SqlitePersistence,
+    area_id: i64,
+    round_id: i64,
+) -> Result<(), ApiError> {
+    if is_round_signed_off(persistence, area_id, round_id)? {
+        return Err(translate_domain_error(DomainError::RoundSignedOff {
+            round_id,
+        }));
+    }
+    Ok(())
+}
+
+/// Converts a stored sign-off into its API representation.
+fn to_sign_off_info(data: RoundSignOffData) -> RoundSignOffInfo {
+    RoundSignOffInfo {
+        round_sign_off_id: data.round_sign_off_id,
+        area_id: data.area_id,
+        round_id: data.round_id,
+        audit_event_id: data.audit_event_id,
+        signed_off_at: data.signed_off_at,
+    }
+}
+
+/// Signs off a round in an area once every eligible user is done.
+///
+/// Eligible users are those in the area's bid order. Each must have bid
+/// (on time, late, or by proxy), been skipped after missing their window,
+/// or waived bidding.
+///
+/// An automatic checkpoint labeled `auto-before-round-sign-off` is recorded
+/// in the area first.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The area and round to sign off
+/// * `authenticated_actor` - The authenticated actor signing off
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The area or round does not exist
+/// - The bid year is not `BiddingActive` or `BiddingClosed`
+/// - The round is already signed off in the area
+/// - An eligible user has not bid, been skipped, or waived
+/// - An automatic checkpoint cannot be recorded
+/// - The database operation fails
+pub fn sign_off_round(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SignOffRoundRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SignOffRoundResponse, ApiError> {
+    require_admin(authenticated_actor, "sign off round")?;
+
+    let (bid_year, area) = resolve_area(metadata, request.area_id)?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, bid_year_id)?;
+    if !matches!(
+        lifecycle_state,
+        BidYearLifecycle::BiddingActive | BidYearLifecycle::BiddingClosed
+    ) {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("sign off round"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+    require_round(persistence, bid_year_id, request.round_id)?;
+    require_round_open(persistence, request.area_id, request.round_id)?;
+
+    let entries: Vec<RoundResultEntryData> = persistence
+        .list_round_results(bid_year_id, request.area_id, request.round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load round results: {e}"),
+        })?;
+    let mut bid: usize = 0;
+    let mut skipped: usize = 0;
+    let mut waived: usize = 0;
+    let mut outstanding: Vec<Initials> = Vec::new();
+    for entry in &entries {
+        match entry.status.as_deref().map(str::parse::<BidStatus>) {
+            Some(Ok(BidStatus::CompletedOnTime | BidStatus::CompletedLate | BidStatus::Proxy)) => {
+                bid += 1;
+            }
+            Some(Ok(BidStatus::Missed)) => skipped += 1,
+            Some(Ok(BidStatus::VoluntarilyNotBidding)) => waived += 1,
+            _ => outstanding.push(Initials::new(&entry.initials)),
+        }
+    }
+
+    let command: Command = Command::SignOffRound {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id: request.round_id,
+        bid,
+        skipped,
+        waived,
+        outstanding,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    let checkpoints: AutoCheckpoints = record_auto_checkpoints(
+        persistence,
+        metadata,
+        &[(bid_year.clone(), area.clone())],
+        "round-sign-off",
+        &result.audit_event.actor,
+        &result.audit_event.cause,
+    )?;
+    let audit_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    record_operation_events(persistence, &[audit_event_id], &checkpoints.correlation_id)?;
+    let round_sign_off_id: i64 = persistence
+        .insert_round_sign_off(
+            bid_year_id,
+            request.area_id,
+            request.round_id,
+            audit_event_id,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record round sign-off: {e}"),
+        })?;
+
+    Ok(SignOffRoundResponse {
+        round_sign_off_id,
+        area_id: request.area_id,
+        round_id: request.round_id,
+        audit_event_id,
+        bid,
+        skipped,
+        waived,
+        message: format!(
+            "Signed off round {} in area '{}': {bid} bid, {skipped} skipped, {waived} waived",
+            request.round_id,
+            area.id()
+        ),
+        correlation_id: checkpoints.correlation_id,
+    })
+}
+
+/// Lists the round sign-offs of a bid year.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn list_round_sign_offs(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<ListRoundSignOffsResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+
+    let sign_offs: Vec<RoundSignOffInfo> = persistence
+        .list_round_sign_offs(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list round sign-offs: {e}"),
+        })?
+        .into_iter()
+        .map(to_sign_off_info)
+        .collect();
+
+    Ok(ListRoundSignOffsResponse {
+        bid_year_id,
+        sign_offs,
+    })
+}
@@ -0,0 +1,578 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Saved audit queries.
+//!
+//! Admins save audit timeline searches they run often under a name, such
+//! as "All overrides 2026": a timeline scope and the same filters the
+//! timeline accepts. A saved query can be scheduled for weekly delivery to
+//! a list of addresses; the server's `saved_audit_query_delivery` job
+//! emails each scheduled query's results as a CSV attachment.
+//!
+//! Only Admin actors may manage or export saved queries. Saving,
+//! rescheduling, and deleting are recorded as global audit events.
+//! Exports hold at most [`MAX_EXPORT_EVENTS`] events, oldest first.
+
+use zab_bid_audit::{Action, AuditEvent, Cause, StateSnapshot};
+use zab_bid_persistence::{OperatorData, SavedAuditQueryData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::error::ApiError;
+use crate::handlers::get_audit_timeline;
+use crate::notifications::validate_email;
+use crate::reports::{RenderedReport, ReportFormat};
+use crate::request_response::{
+    AuditTimelineEntryInfo, AuditTimelineFilter, AuditTimelinePageRequest, AuditTimelineScope,
+    CreateSavedAuditQueryRequest, DeleteSavedAuditQueryRequest, DeleteSavedAuditQueryResponse,
+    GetAuditTimelineResponse, ListSavedAuditQueriesResponse, SavedAuditQueryInfo,
+    SavedAuditQueryResponse, ScheduleSavedAuditQueryRequest,
+};
+use crate::webhooks::{operator_actor, require_admin};
+
+/// Maximum length of a saved query name.
+const MAX_NAME_LENGTH: usize = 100;
+
+/// Maximum number of weekly recipients per saved query.
+const MAX_RECIPIENTS: usize = 20;
+
+/// Maximum number of audit events in one export.
+pub const MAX_EXPORT_EVENTS: usize = 10_000;
+
+/// Number of audit events read per timeline page while exporting.
+const EXPORT_PAGE_SIZE: u32 = 500;
+
+/// A saved query's results rendered as CSV.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedAuditQueryExport {
+    /// The saved query name.
+    pub name: String,
+    /// The CSV document.
+    pub report: RenderedReport,
+    /// The number of audit events exported.
+    pub event_count: usize,
+    /// Whether more events matched than [`MAX_EXPORT_EVENTS`].
+    pub truncated: bool,
+}
+
+/// Validates and normalizes a saved query name.
+fn validate_name(name: &str) -> Result<String, ApiError> {
+    let name: &str = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(ApiError::InvalidInput {
+            field: String::from("name"),
+            message: format!("Saved query name must be 1 to {MAX_NAME_LENGTH} characters"),
+        });
+    }
+    Ok(name.to_string())
+}
+
+/// Validates and normalizes weekly recipient addresses.
+fn validate_recipients(recipients: &[String]) -> Result<Vec<String>, ApiError> {
+    if recipients.len() > MAX_RECIPIENTS {
+        return Err(ApiError::InvalidInput {
+            field: String::from("weekly_recipients"),
+            message: format!("At most {MAX_RECIPIENTS} recipients may be scheduled"),
+        });
+    }
+    let mut normalized: Vec<String> = Vec::with_capacity(recipients.len());
+    for recipient in recipients {
+        let recipient: &str = recipient.trim();
+        // Recipients are stored comma-separated
+        if recipient.contains(',') || validate_email(recipient).is_err() {
+            return Err(ApiError::InvalidInput {
+                field: String::from("weekly_recipients"),
+                message: format!("Invalid email address: '{recipient}'"),
+            });
+        }
+        if !normalized
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(recipient))
+        {
+            normalized.push(recipient.to_string());
+        }
+    }
+    Ok(normalized)
+}
+
+/// Formats a saved query for audit snapshots.
+fn saved_query_snapshot(
+    saved_query_id: i64,
+    name: &str,
+    scope_json: &str,
+    filter_json: &str,
+    weekly_recipients: &[String],
+) -> String {
+    format!(
+        "saved_query_id={saved_query_id},name={name},scope={scope_json},filter={filter_json},weekly_recipients={}",
+        weekly_recipients.join("|")
+    )
+}
+
+/// Formats a stored saved query for audit snapshots.
+fn stored_snapshot(query: &SavedAuditQueryData) -> String {
+    saved_query_snapshot(
+        query.saved_query_id,
+        &query.name,
+        &query.scope_json,
+        &query.filter_json,
+        &query.weekly_recipients,
+    )
+}
+
+/// Records a global audit event for a saved query change.
+fn persist_saved_query_audit_event(
+    persistence: &mut SqlitePersistence,
+    operator: &OperatorData,
+    cause: Cause,
+    action: Action,
+    before: String,
+    after: String,
+) -> Result<(), ApiError> {
+    let audit_event: AuditEvent = AuditEvent::new_global(
+        operator_actor(operator),
+        cause,
+        action,
+        StateSnapshot::new(before),
+        StateSnapshot::new(after),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    Ok(())
+}
+
+/// Loads a saved query or returns `ResourceNotFound`.
+fn load_saved_query(
+    persistence: &mut SqlitePersistence,
+    saved_query_id: i64,
+) -> Result<SavedAuditQueryData, ApiError> {
+    persistence
+        .get_saved_audit_query(saved_query_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get saved audit query: {e}"),
+        })?
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("SavedAuditQuery"),
+            message: format!("Saved audit query with ID {saved_query_id} not found"),
+        })
+}
+
+/// Decodes a stored query's scope and filters.
+fn decode_query(
+    query: &SavedAuditQueryData,
+) -> Result<(AuditTimelineScope, AuditTimelineFilter), ApiError> {
+    let decode_error = |e: serde_json::Error| ApiError::Internal {
+        message: format!(
+            "Saved audit query {} is not valid JSON: {e}",
+            query.saved_query_id
+        ),
+    };
+    let scope: AuditTimelineScope =
+        serde_json::from_str(&query.scope_json).map_err(decode_error)?;
+    let filter: AuditTimelineFilter =
+        serde_json::from_str(&query.filter_json).map_err(decode_error)?;
+    Ok((scope, filter))
+}
+
+/// Converts a stored query for responses.
+fn saved_query_info(query: SavedAuditQueryData) -> Result<SavedAuditQueryInfo, ApiError> {
+    let (scope, filter) = decode_query(&query)?;
+    Ok(SavedAuditQueryInfo {
+        saved_query_id: query.saved_query_id,
+        name: query.name,
+        scope,
+        filter,
+        weekly_recipients: query.weekly_recipients,
+        created_by_operator_id: query.created_by_operator_id,
+        created_at: query.created_at,
+        last_delivered_at: query.last_delivered_at,
+    })
+}
+
+/// Returns a file name for a saved query's export.
+fn export_filename(name: &str) -> String {
+    let slug: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>()
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-");
+    format!("audit-{slug}.{}", ReportFormat::Csv.extension())
+}
+
+/// Writes timeline entries as CSV, one line per audit event.
+fn render_entries_csv(entries: &[AuditTimelineEntryInfo]) -> Result<Vec<u8>, ApiError> {
+    let csv_error = |e: csv::Error| ApiError::Internal {
+        message: format!("Failed to write CSV report: {e}"),
+    };
+    let mut writer: csv::Writer<Vec<u8>> = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record([
+            "event_id",
+            "created_at",
+            "actor",
+            "action",
+            "details",
+            "cause",
+            "bid_year",
+            "area",
+            "changes",
+        ])
+        .map_err(csv_error)?;
+    for entry in entries {
+        let changes: String = entry
+            .diff_summary
+            .iter()
+            .map(|change| {
+                format!(
+                    "{}: {} -> {}",
+                    change.field,
+                    change.before.as_deref().unwrap_or("(none)"),
+                    change.after.as_deref().unwrap_or("(none)")
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("; ");
+        writer
+            .write_record([
+                entry.event_id.to_string(),
+                entry.created_at.clone().unwrap_or_default(),
+                entry
+                    .actor
+                    .login_name
+                    .clone()
+                    .unwrap_or_else(|| entry.actor.actor_type.clone()),
+                entry.action_name.clone(),
+                entry.action_details.clone().unwrap_or_default(),
+                entry.cause_description.clone(),
+                entry
+                    .bid_year
+                    .map(|year| year.to_string())
+                    .unwrap_or_default(),
+                entry.area_code.clone().unwrap_or_default(),
+                changes,
+            ])
+            .map_err(csv_error)?;
+    }
+    writer.into_inner().map_err(|e| ApiError::Internal {
+        message: format!("Failed to write CSV report: {e}"),
+    })
+}
+
+/// Saves a named audit query.
+///
+/// The scope and filters are checked by running the query once, so a
+/// query that cannot run is never saved.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The query and its weekly recipients
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The name is empty, too long, or already taken
+/// - A recipient address is invalid
+/// - The scope or filters are invalid
+/// - The database operation fails
+pub fn create_saved_audit_query(
+    persistence: &mut SqlitePersistence,
+    request: &CreateSavedAuditQueryRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SavedAuditQueryResponse, ApiError> {
+    require_admin(authenticated_actor, "create_saved_audit_query")?;
+    let name: String = validate_name(&request.name)?;
+    let weekly_recipients: Vec<String> = validate_recipients(&request.weekly_recipients)?;
+
+    let name_taken: bool = persistence
+        .list_saved_audit_queries()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list saved audit queries: {e}"),
+        })?
+        .iter()
+        .any(|query| query.name.eq_ignore_ascii_case(&name));
+    if name_taken {
+        return Err(ApiError::InvalidInput {
+            field: String::from("name"),
+            message: format!("A saved audit query named '{name}' already exists"),
+        });
+    }
+    get_audit_timeline(
+        persistence,
+        request.scope,
+        &request.filter,
+        AuditTimelinePageRequest {
+            after_event_id: None,
+            limit: Some(1),
+        },
+    )?;
+
+    let encode_error = |e: serde_json::Error| ApiError::Internal {
+        message: format!("Failed to encode saved audit query: {e}"),
+    };
+    let scope_json: String = serde_json::to_string(&request.scope).map_err(encode_error)?;
+    let filter_json: String = serde_json::to_string(&request.filter).map_err(encode_error)?;
+
+    let saved_query_id: i64 = persistence
+        .create_saved_audit_query(
+            &name,
+            &scope_json,
+            &filter_json,
+            &weekly_recipients,
+            operator.operator_id,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to save audit query: {e}"),
+        })?;
+
+    persist_saved_query_audit_event(
+        persistence,
+        operator,
+        cause,
+        Action::new(
+            String::from("SaveAuditQuery"),
+            Some(format!("Saved audit query '{name}'")),
+        ),
+        String::from("saved_query_does_not_exist"),
+        saved_query_snapshot(
+            saved_query_id,
+            &name,
+            &scope_json,
+            &filter_json,
+            &weekly_recipients,
+        ),
+    )?;
+
+    let saved_query: SavedAuditQueryData = load_saved_query(persistence, saved_query_id)?;
+    Ok(SavedAuditQueryResponse {
+        saved_query: saved_query_info(saved_query)?,
+    })
+}
+
+/// Lists all saved audit queries, ordered by name.
+///
+/// Only Admin actors may list saved queries.
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin or the query fails.
+pub fn list_saved_audit_queries(
+    persistence: &mut SqlitePersistence,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<ListSavedAuditQueriesResponse, ApiError> {
+    require_admin(authenticated_actor, "list_saved_audit_queries")?;
+
+    let saved_queries: Vec<SavedAuditQueryInfo> = persistence
+        .list_saved_audit_queries()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list saved audit queries: {e}"),
+        })?
+        .into_iter()
+        .map(saved_query_info)
+        .collect::<Result<Vec<SavedAuditQueryInfo>, ApiError>>()?;
+
+    Ok(ListSavedAuditQueriesResponse { saved_queries })
+}
+
+/// Sets the addresses a saved audit query is emailed to each week.
+///
+/// An empty list stops the weekly delivery. A query's first delivery is
+/// sent on the delivery job's next run after it is scheduled.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The saved query and its new recipients
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The saved query does not exist
+/// - A recipient address is invalid
+/// - The database operation fails
+pub fn schedule_saved_audit_query(
+    persistence: &mut SqlitePersistence,
+    request: &ScheduleSavedAuditQueryRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SavedAuditQueryResponse, ApiError> {
+    require_admin(authenticated_actor, "schedule_saved_audit_query")?;
+    let weekly_recipients: Vec<String> = validate_recipients(&request.weekly_recipients)?;
+
+    let existing: SavedAuditQueryData = load_saved_query(persistence, request.saved_query_id)?;
+
+    persistence
+        .set_saved_audit_query_recipients(request.saved_query_id, &weekly_recipients)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to schedule saved audit query: {e}"),
+        })?;
+
+    let details: String = if weekly_recipients.is_empty() {
+        format!("Stopped weekly delivery of audit query '{}'", existing.name)
+    } else {
+        format!(
+            "Scheduled audit query '{}' weekly to {} recipients",
+            existing.name,
+            weekly_recipients.len()
+        )
+    };
+    persist_saved_query_audit_event(
+        persistence,
+        operator,
+        cause,
+        Action::new(String::from("ScheduleAuditQuery"), Some(details)),
+        stored_snapshot(&existing),
+        saved_query_snapshot(
+            existing.saved_query_id,
+            &existing.name,
+            &existing.scope_json,
+            &existing.filter_json,
+            &weekly_recipients,
+        ),
+    )?;
+
+    let saved_query: SavedAuditQueryData = load_saved_query(persistence, request.saved_query_id)?;
+    Ok(SavedAuditQueryResponse {
+        saved_query: saved_query_info(saved_query)?,
+    })
+}
+
+/// Deletes a saved audit query, ending any weekly delivery.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The saved query to delete
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The saved query does not exist
+/// - The database operation fails
+pub fn delete_saved_audit_query(
+    persistence: &mut SqlitePersistence,
+    request: DeleteSavedAuditQueryRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<DeleteSavedAuditQueryResponse, ApiError> {
+    require_admin(authenticated_actor, "delete_saved_audit_query")?;
+
+    let existing: SavedAuditQueryData = load_saved_query(persistence, request.saved_query_id)?;
+
+    persistence
+        .delete_saved_audit_query(request.saved_query_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to delete saved audit query: {e}"),
+        })?;
+
+    persist_saved_query_audit_event(
+        persistence,
+        operator,
+        cause,
+        Action::new(
+            String::from("DeleteAuditQuery"),
+            Some(format!("Deleted audit query '{}'", existing.name)),
+        ),
+        stored_snapshot(&existing),
+        String::from("saved_query_deleted"),
+    )?;
+
+    Ok(DeleteSavedAuditQueryResponse {
+        message: format!("Saved audit query '{}' deleted", existing.name),
+    })
+}
+
+/// Runs a saved audit query and renders its results as CSV.
+///
+/// This performs no authorization; it serves the weekly delivery job.
+/// Operators export through [`export_saved_audit_query`].
+///
+/// # Errors
+///
+/// Returns an error if the saved query does not exist, its scope or
+/// filters no longer run, or the audit log cannot be read.
+pub fn render_saved_audit_query(
+    persistence: &mut SqlitePersistence,
+    saved_query_id: i64,
+) -> Result<SavedAuditQueryExport, ApiError> {
+    let query: SavedAuditQueryData = load_saved_query(persistence, saved_query_id)?;
+    let (scope, filter) = decode_query(&query)?;
+
+    let mut entries: Vec<AuditTimelineEntryInfo> = Vec::new();
+    let mut after_event_id: Option<i64> = None;
+    let truncated: bool = loop {
+        let page: GetAuditTimelineResponse = get_audit_timeline(
+            persistence,
+            scope,
+            &filter,
+            AuditTimelinePageRequest {
+                after_event_id,
+                limit: Some(EXPORT_PAGE_SIZE),
+            },
+        )?;
+        entries.extend(page.entries);
+        match page.next_cursor {
+            None => break entries.len() > MAX_EXPORT_EVENTS,
+            Some(_) if entries.len() >= MAX_EXPORT_EVENTS => break true,
+            Some(cursor) => after_event_id = Some(cursor),
+        }
+    };
+    entries.truncate(MAX_EXPORT_EVENTS);
+
+    Ok(SavedAuditQueryExport {
+        report: RenderedReport {
+            content_type: ReportFormat::Csv.content_type(),
+            filename: export_filename(&query.name),
+            body: render_entries_csv(&entries)?,
+        },
+        name: query.name,
+        event_count: entries.len(),
+        truncated,
+    })
+}
+
+/// Exports a saved audit query's results as CSV.
+///
+/// Only Admin actors may export saved queries.
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin, the saved query does not
+/// exist, or it cannot be run.
+pub fn export_saved_audit_query(
+    persistence: &mut SqlitePersistence,
+    saved_query_id: i64,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<RenderedReport, ApiError> {
+    require_admin(authenticated_actor, "export_saved_audit_query")?;
+    render_saved_audit_query(persistence, saved_query_id).map(|export| export.report)
+}
@@ -0,0 +1,247 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Scope freeze handlers.
+//!
+//! An Admin can freeze an area, for example while a grievance about its
+//! bidding is heard, through the core `FreezeScope` command. A freeze is
+//! independent of the bid year's lifecycle: while it holds, core refuses
+//! every command targeting the area and every lifecycle transition of its
+//! bid year, whatever state the bid year is in. The `Unfreeze` command
+//! lifts it. Handlers that write without core, such as overrides and
+//! round configuration, check the freeze with [`require_not_frozen`].
+
+use zab_bid::{BootstrapMetadata, BootstrapResult, Command, apply_bootstrap};
+use zab_bid_audit::Cause;
+use zab_bid_domain::DomainError;
+use zab_bid_persistence::{OperatorData, ScopeFreezeData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_core_error, translate_domain_error};
+use crate::leave_bids::resolve_area;
+use crate::request_response::{
+    FreezeScopeRequest, FreezeScopeResponse, ListScopeFreezesResponse, ScopeFreezeInfo,
+    UnfreezeScopeRequest, UnfreezeScopeResponse,
+};
+use crate::webhooks::require_admin;
+
+/// Converts a stored freeze into its API representation.
+fn to_scope_freeze_info(data: ScopeFreezeData) -> ScopeFreezeInfo {
+    ScopeFreezeInfo {
+        scope_freeze_id: data.scope_freeze_id,
+        area_id: data.area_id,
+        reason: data.reason,
+        audit_event_id: data.audit_event_id,
+        frozen_at: data.frozen_at,
+    }
+}
+
+/// Refuses a change to a frozen area for handlers that write without
+/// going through core's `apply`.
+///
+/// With an area, only that area's freeze counts. Without one the change
+/// touches every area in the bid year, such as its round configuration,
+/// and is refused while any of them is frozen.
+///
+/// # Errors
+///
+/// Returns an error if the area is frozen or the freezes cannot be read.
+pub fn require_not_frozen(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    area_id: Option<i64>,
+) -> Result<(), ApiError> {
+    let frozen: Option<ScopeFreezeData> = persistence
+        .list_scope_freezes(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list area freezes: {e}"),
+        })?
+        .into_iter()
+        .find(|freeze| area_id.is_none_or(|area_id| freeze.area_id == area_id));
+    let Some(frozen) = frozen else {
+        return Ok(());
+    };
+
+    let year: u16 =
+        persistence
+            .get_bid_year_from_id(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get bid year: {e}"),
+            })?;
+    let (area_code, _): (String, Option<String>) = persistence
+        .get_area_details(frozen.area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get area: {e}"),
+        })?;
+    Err(translate_domain_error(DomainError::ScopeFrozen {
+        bid_year: year,
+        area: area_code,
+    }))
+}
+
+/// Freezes an area against every change.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The area to freeze and why
+/// * `authenticated_actor` - The authenticated actor freezing the area
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The area does not exist
+/// - The area is already frozen
+/// - The reason is shorter than 10 characters
+/// - The database operation fails
+pub fn freeze_scope(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &FreezeScopeRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<FreezeScopeResponse, ApiError> {
+    require_admin(authenticated_actor, "freeze area")?;
+
+    let (bid_year, area) = resolve_area(metadata, request.area_id)?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+
+    let command: Command = Command::FreezeScope {
+        bid_year: bid_year.clone(),
+        area: area.clone(),
+        reason: request.reason.clone(),
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    let audit_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    let scope_freeze_id: i64 = persistence
+        .insert_scope_freeze(
+            bid_year_id,
+            request.area_id,
+            request.reason.trim(),
+            audit_event_id,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record area freeze: {e}"),
+        })?;
+
+    Ok(FreezeScopeResponse {
+        scope_freeze_id,
+        area_id: request.area_id,
+        audit_event_id,
+        message: format!("Froze area '{}' in bid year {}", area.id(), bid_year.year()),
+    })
+}
+
+/// Lifts an area's freeze.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The area to unfreeze
+/// * `authenticated_actor` - The authenticated actor unfreezing the area
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The area does not exist
+/// - The area is not frozen
+/// - The database operation fails
+pub fn unfreeze_scope(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &UnfreezeScopeRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<UnfreezeScopeResponse, ApiError> {
+    require_admin(authenticated_actor, "unfreeze area")?;
+
+    let (bid_year, area) = resolve_area(metadata, request.area_id)?;
+
+    let command: Command = Command::Unfreeze {
+        bid_year: bid_year.clone(),
+        area: area.clone(),
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    let audit_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    persistence
+        .delete_scope_freeze(request.area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to lift area freeze: {e}"),
+        })?;
+
+    Ok(UnfreezeScopeResponse {
+        area_id: request.area_id,
+        audit_event_id,
+        message: format!(
+            "Unfroze area '{}' in bid year {}",
+            area.id(),
+            bid_year.year()
+        ),
+    })
+}
+
+/// Lists the frozen areas of a bid year.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn list_scope_freezes(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<ListScopeFreezesResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+
+    let freezes: Vec<ScopeFreezeInfo> = persistence
+        .list_scope_freezes(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list area freezes: {e}"),
+        })?
+        .into_iter()
+        .map(to_scope_freeze_info)
+        .collect();
+
+    Ok(ListScopeFreezesResponse {
+        bid_year_id,
+        freezes,
+    })
+}
@@ -0,0 +1,496 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Self-service bid handlers.
+//!
+//! An operator may also be a controller in the roster. An Admin links the
+//! operator to the controller's initials, and from then on the operator
+//! cannot enter or amend that controller's leave alone. Instead they
+//! request the entry, which records a `RequestSelfBidEntry` event and
+//! waits. A second operator confirms it: the leave goes through the usual
+//! `EnterLeaveBid` checks under the confirming operator, and a
+//! `ConfirmSelfBidEntry` event names both the request event and the entry
+//! event. Each request row links to the request and confirmation events.
+
+use time::Date;
+use zab_bid::{BootstrapMetadata, BootstrapResult, Command, apply_bootstrap};
+use zab_bid_audit::{Action, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{BidReceiptMethod, BidYear, BidYearLifecycle, DomainError, Initials};
+use zab_bid_persistence::{OperatorData, SelfBidRequestData, SqlitePersistence};
+
+use crate::auth::{AuthenticatedActor, AuthorizationService, Role};
+use crate::error::{ApiError, translate_core_error, translate_domain_error};
+use crate::leave_bids::{
+    load_lifecycle_state, parse_leave_dates, record_leave_bid, require_round, resolve_area,
+    resolve_user,
+};
+use crate::request_response::{
+    ConfirmSelfBidEntryRequest, ConfirmSelfBidEntryResponse, EnterLeaveBidRequest,
+    EnterLeaveBidResponse, ListSelfBidRequestsResponse, RequestSelfBidEntryRequest,
+    RequestSelfBidEntryResponse, SelfBidRequestInfo, SetOperatorControllerRequest,
+    SetOperatorControllerResponse,
+};
+use crate::webhooks::{operator_actor, require_admin};
+
+/// Loads the initials of the controller an operator is, if any.
+fn load_operator_controller(
+    persistence: &mut SqlitePersistence,
+    operator_id: i64,
+) -> Result<Option<Initials>, ApiError> {
+    Ok(persistence
+        .get_operator_controller(operator_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get operator controller: {e}"),
+        })?
+        .map(|initials| Initials::new(&initials)))
+}
+
+/// Loads a self-service bid request that is still waiting for confirmation.
+fn load_pending_request(
+    persistence: &mut SqlitePersistence,
+    self_bid_request_id: i64,
+) -> Result<(SelfBidRequestData, i64), ApiError> {
+    let request: SelfBidRequestData = persistence
+        .get_self_bid_request(self_bid_request_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get self-service bid request: {e}"),
+        })?
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("SelfBidRequest"),
+            message: format!("Self-service bid request with ID {self_bid_request_id} not found"),
+        })?;
+    if request.status != SelfBidRequestData::STATUS_PENDING {
+        return Err(translate_domain_error(DomainError::InvalidSelfBid {
+            reason: format!(
+                "Self-service bid request {self_bid_request_id} is already {}",
+                request.status
+            ),
+        }));
+    }
+    let request_event_id: i64 = request.request_event_id.ok_or_else(|| ApiError::Internal {
+        message: format!("Self-service bid request {self_bid_request_id} has no request event"),
+    })?;
+
+    Ok((request, request_event_id))
+}
+
+/// Links an operator to the controller they are in the roster, or clears
+/// the link (admin only).
+///
+/// Emits an audit event on success.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The operator and the controller's initials
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The operator does not exist
+/// - The initials are blank
+/// - The database operation fails
+pub fn set_operator_controller(
+    persistence: &mut SqlitePersistence,
+    request: &SetOperatorControllerRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetOperatorControllerResponse, ApiError> {
+    require_admin(authenticated_actor, "set_operator_controller")?;
+
+    let operator_id: i64 = request.operator_id;
+    let target_operator: OperatorData = persistence
+        .get_operator_by_id(operator_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get operator: {e}"),
+        })?
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("Operator"),
+            message: format!("Operator with ID {operator_id} not found"),
+        })?;
+
+    let initials: Option<Initials> = match request.initials.as_deref().map(str::trim) {
+        Some("") => {
+            return Err(ApiError::InvalidInput {
+                field: String::from("initials"),
+                message: String::from("Initials must not be blank"),
+            });
+        }
+        Some(value) => Some(Initials::new(value)),
+        None => None,
+    };
+    let previous: Option<Initials> = load_operator_controller(persistence, operator_id)?;
+
+    persistence
+        .set_operator_controller(operator_id, initials.as_ref().map(Initials::value))
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to set operator controller: {e}"),
+        })?;
+
+    let target_login: &str = &target_operator.login_name;
+    let message: String = initials.as_ref().map_or_else(
+        || format!("Operator {target_login} is no longer linked to a controller"),
+        |initials| {
+            format!(
+                "Operator {target_login} is controller '{}'",
+                initials.value()
+            )
+        },
+    );
+    let audit_event: AuditEvent = AuditEvent::new_global(
+        operator_actor(operator),
+        cause,
+        Action::new(String::from("SetOperatorController"), Some(message.clone())),
+        StateSnapshot::new(format!(
+            "operator_id={operator_id},login_name={target_login},controller={}",
+            previous.as_ref().map_or("none", Initials::value)
+        )),
+        StateSnapshot::new(format!(
+            "operator_id={operator_id},login_name={target_login},controller={}",
+            initials.as_ref().map_or("none", Initials::value)
+        )),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetOperatorControllerResponse {
+        operator_id,
+        initials: initials.map(|initials| initials.value().to_string()),
+        message,
+    })
+}
+
+/// Records an operator's request to enter leave for their own controller
+/// record.
+///
+/// The leave is not entered until a second operator confirms the request
+/// with [`confirm_self_bid_entry`]; the bid year's rules and slot limits
+/// are checked then.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The round and leave days
+/// * `authenticated_actor` - The authenticated actor making the request
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin or Bidder
+/// - The operator is not linked to a controller
+/// - The receipt method, a leave date, or the hours are invalid
+/// - The area, round, or controller does not exist
+/// - The bid year is not `BiddingActive`
+/// - A request for the controller in the round is already pending
+/// - The database operation fails
+#[allow(clippy::too_many_lines)]
+pub fn request_self_bid_entry(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &RequestSelfBidEntryRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<RequestSelfBidEntryResponse, ApiError> {
+    if !matches!(authenticated_actor.role, Role::Admin | Role::Bidder) {
+        return Err(ApiError::Unauthorized {
+            action: String::from("request self-service bid"),
+            required_role: String::from("Admin or Bidder"),
+        });
+    }
+
+    let on_behalf_of: Initials = load_operator_controller(persistence, operator.operator_id)?
+        .ok_or_else(|| {
+            translate_domain_error(DomainError::InvalidSelfBid {
+                reason: format!(
+                    "Operator {} is not linked to a controller",
+                    operator.login_name
+                ),
+            })
+        })?;
+
+    let received_via: BidReceiptMethod = request
+        .received_via
+        .parse()
+        .map_err(translate_domain_error)?;
+    let mut leave_dates: Vec<Date> = parse_leave_dates(&request.leave_dates)?;
+    leave_dates.sort_unstable();
+    leave_dates.dedup();
+    let hours: i32 = i32::try_from(request.hours).map_err(|_| ApiError::InvalidInput {
+        field: String::from("hours"),
+        message: format!("Leave hours {} are out of range", request.hours),
+    })?;
+
+    let (bid_year, area) = resolve_area(metadata, request.area_id)?;
+    AuthorizationService::authorize_area_bidding(
+        persistence,
+        authenticated_actor,
+        operator,
+        request.area_id,
+        "request self-service bid",
+    )?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, bid_year_id)?;
+    if lifecycle_state != BidYearLifecycle::BiddingActive {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("request self-service bid"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+    require_round(persistence, bid_year_id, request.round_id)?;
+    let user_id: i64 = resolve_user(persistence, bid_year, area, &on_behalf_of)?;
+
+    let already_pending: bool = persistence
+        .list_self_bid_requests(request.area_id, request.round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list self-service bid requests: {e}"),
+        })?
+        .iter()
+        .any(|pending| {
+            pending.user_id == user_id && pending.status == SelfBidRequestData::STATUS_PENDING
+        });
+    if already_pending {
+        return Err(translate_domain_error(DomainError::InvalidSelfBid {
+            reason: format!(
+                "User '{}' already has a self-service bid pending in round {}",
+                on_behalf_of.value(),
+                request.round_id
+            ),
+        }));
+    }
+
+    let command: Command = Command::RequestSelfBidEntry {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id: request.round_id,
+        user_id,
+        on_behalf_of: on_behalf_of.clone(),
+        received_via,
+        leave_dates: leave_dates.clone(),
+        hours: request.hours,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    let days: Vec<String> = leave_dates.iter().map(ToString::to_string).collect();
+    let (self_bid_request_id, request_event_id): (i64, i64) = persistence
+        .record_self_bid_request(
+            &result.audit_event,
+            bid_year_id,
+            request.area_id,
+            user_id,
+            request.round_id,
+            operator.operator_id,
+            &days,
+            hours,
+            on_behalf_of.value(),
+            received_via.as_str(),
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record self-service bid request: {e}"),
+        })?;
+
+    Ok(RequestSelfBidEntryResponse {
+        self_bid_request_id,
+        area_id: request.area_id,
+        round_id: request.round_id,
+        user_id,
+        request_event_id,
+        message: format!(
+            "{} leave day(s) for '{}' are awaiting confirmation by a second operator",
+            days.len(),
+            on_behalf_of.value()
+        ),
+    })
+}
+
+/// Confirms a pending self-service bid request and enters the leave.
+///
+/// The leave is entered through [`crate::enter_leave_bid`]'s checks with
+/// the confirming operator as the actor, then the confirmation is
+/// recorded naming the request and entry events.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The self-service bid request to confirm
+/// * `authenticated_actor` - The authenticated actor confirming the request
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin or Bidder
+/// - The request does not exist or is not pending
+/// - The confirming operator made the request or is the controller
+/// - The leave can no longer be entered (see [`crate::enter_leave_bid`])
+/// - The database operation fails
+pub fn confirm_self_bid_entry(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &ConfirmSelfBidEntryRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<ConfirmSelfBidEntryResponse, ApiError> {
+    if !matches!(authenticated_actor.role, Role::Admin | Role::Bidder) {
+        return Err(ApiError::Unauthorized {
+            action: String::from("confirm self-service bid"),
+            required_role: String::from("Admin or Bidder"),
+        });
+    }
+
+    let (pending, request_event_id) =
+        load_pending_request(persistence, request.self_bid_request_id)?;
+    if pending.requested_by_operator_id == operator.operator_id {
+        return Err(translate_domain_error(DomainError::InvalidSelfBid {
+            reason: String::from("A self-service bid must be confirmed by a different operator"),
+        }));
+    }
+    let (bid_year, area): (&BidYear, _) = resolve_area(metadata, pending.area_id)?;
+
+    let entry_request: EnterLeaveBidRequest = EnterLeaveBidRequest {
+        area_id: pending.area_id,
+        round_id: pending.round_id,
+        on_behalf_of: pending.on_behalf_of.clone(),
+        received_via: pending.received_via.clone(),
+        leave_dates: pending.leave_dates.clone(),
+        hours: u32::try_from(pending.hours).map_err(|_| ApiError::Internal {
+            message: format!(
+                "Self-service bid request {} has invalid hours",
+                pending.self_bid_request_id
+            ),
+        })?,
+        override_reason: None,
+    };
+    let (entry, entry_event_id): (EnterLeaveBidResponse, i64) = record_leave_bid(
+        persistence,
+        metadata,
+        &entry_request,
+        authenticated_actor,
+        operator,
+        cause.clone(),
+    )?;
+
+    let command: Command = Command::ConfirmSelfBidEntry {
+        year: bid_year.year(),
+        area: area.clone(),
+        self_bid_request_id: pending.self_bid_request_id,
+        request_event_id,
+        entry_event_id,
+        requested_by_operator_id: pending.requested_by_operator_id,
+        round_id: pending.round_id,
+        user_id: pending.user_id,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    let confirm_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    persistence
+        .confirm_self_bid_request(
+            pending.self_bid_request_id,
+            operator.operator_id,
+            confirm_event_id,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record self-service bid confirmation: {e}"),
+        })?;
+
+    Ok(ConfirmSelfBidEntryResponse {
+        self_bid_request_id: pending.self_bid_request_id,
+        request_event_id,
+        entry_event_id,
+        confirm_event_id,
+        message: format!(
+            "Confirmed and entered {} leave day(s) for '{}'",
+            entry.leave_bid_ids.len(),
+            pending.on_behalf_of
+        ),
+        leave_bid_ids: entry.leave_bid_ids,
+    })
+}
+
+/// Lists the self-service bid requests for a round in an area.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `area_id` - The canonical area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the area does not exist or the query fails.
+pub fn list_self_bid_requests(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    area_id: i64,
+    round_id: i64,
+) -> Result<ListSelfBidRequestsResponse, ApiError> {
+    resolve_area(metadata, area_id)?;
+
+    let requests: Vec<SelfBidRequestInfo> = persistence
+        .list_self_bid_requests(area_id, round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list self-service bid requests: {e}"),
+        })?
+        .into_iter()
+        .map(|request| SelfBidRequestInfo {
+            self_bid_request_id: request.self_bid_request_id,
+            user_id: request.user_id,
+            on_behalf_of: request.on_behalf_of,
+            requested_by_operator_id: request.requested_by_operator_id,
+            leave_dates: request.leave_dates,
+            hours: request.hours,
+            received_via: request.received_via,
+            status: request.status,
+            request_event_id: request.request_event_id,
+            confirmed_by_operator_id: request.confirmed_by_operator_id,
+            confirm_event_id: request.confirm_event_id,
+            created_at: request.created_at,
+            confirmed_at: request.confirmed_at,
+        })
+        .collect();
+
+    Ok(ListSelfBidRequestsResponse {
+        area_id,
+        round_id,
+        requests,
+    })
+}
@@ -0,0 +1,590 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Leave slot inventory handlers.
+//!
+//! The slot inventory is the single source of daily leave capacity. It
+//! combines each round's `slots_per_day` or, for crew-partitioned rounds,
+//! each crew's slots, the bid year's prime dates and each round's cap on
+//! them, the staffing adjustments stored per area, round, and date, and the
+//! approved leave already held. Leave
+//! bid entry, bid preference application, and the coverage report all read
+//! capacity through [`load_slot_inventory`].
+
+use std::collections::BTreeSet;
+
+use time::format_description::well_known::Iso8601;
+use time::{Date, Duration};
+use zab_bid::{BootstrapMetadata, BootstrapResult, Command, apply_bootstrap};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{BidYearLifecycle, Crew, DailySlots, DomainError, SlotInventory};
+use zab_bid_persistence::{
+    DailyLeaveCountData, OperatorData, PrimePeriodData, RoundCrewSlotsData, RoundPrimeCapData,
+    SlotAdjustmentData, SqlitePersistence,
+};
+
+use crate::auth::AuthenticatedActor;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_core_error, translate_domain_error};
+use crate::leave_bids::{load_lifecycle_state, require_round, resolve_area};
+use crate::request_response::{
+    AdjustSlotInventoryRequest, AdjustSlotInventoryResponse, CrewSlotsInfo,
+    GetSlotInventoryRequest, GetSlotInventoryResponse, ListRoundCrewSlotsResponse,
+    RoundCrewSlotsInfo, SetRoundCrewSlotsRequest, SetRoundCrewSlotsResponse, SlotInventoryDayInfo,
+};
+use crate::webhooks::require_admin;
+
+/// Longest date range an inventory query may span, in days.
+const MAX_INVENTORY_DAYS: i64 = 366;
+
+/// Parses a `YYYY-MM-DD` inventory date.
+fn parse_slot_date(field: &str, value: &str) -> Result<Date, ApiError> {
+    Date::parse(value, &Iso8601::DEFAULT).map_err(|_| ApiError::InvalidInput {
+        field: field.to_string(),
+        message: format!("Invalid date format: {value}"),
+    })
+}
+
+/// Parses the `YYYY-MM-DD` bounds of an inventory query.
+///
+/// # Errors
+///
+/// Returns an error if either date is invalid, the range is reversed, or
+/// it is longer than a year.
+pub fn parse_inventory_range(start_date: &str, end_date: &str) -> Result<(Date, Date), ApiError> {
+    let start_date: Date = parse_slot_date("start_date", start_date)?;
+    let end_date: Date = parse_slot_date("end_date", end_date)?;
+    if start_date > end_date {
+        return Err(ApiError::InvalidInput {
+            field: String::from("end_date"),
+            message: format!("End date {end_date} is before start date {start_date}"),
+        });
+    }
+    if (end_date - start_date).whole_days() >= MAX_INVENTORY_DAYS {
+        return Err(ApiError::InvalidInput {
+            field: String::from("end_date"),
+            message: format!("Slot inventory queries span at most {MAX_INVENTORY_DAYS} days"),
+        });
+    }
+    Ok((start_date, end_date))
+}
+
+/// One crew's stored slot allocation in a round.
+struct CrewSlots {
+    round_id: i64,
+    crew: u8,
+    slots_per_day: u32,
+}
+
+/// Loads the per-crew slot allocations of a bid year's rounds.
+fn load_crew_slots(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+) -> Result<Vec<CrewSlots>, ApiError> {
+    persistence
+        .list_round_crew_slots(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load crew slots: {e}"),
+        })?
+        .into_iter()
+        .map(|data: RoundCrewSlotsData| {
+            let invalid = || ApiError::Internal {
+                message: format!(
+                    "Stored crew slots for crew {} in round {} are invalid",
+                    data.crew, data.round_id
+                ),
+            };
+            Ok(CrewSlots {
+                round_id: data.round_id,
+                crew: u8::try_from(data.crew).map_err(|_| invalid())?,
+                slots_per_day: u32::try_from(data.slots_per_day).map_err(|_| invalid())?,
+            })
+        })
+        .collect()
+}
+
+/// Loads the slot inventory of every round in a bid year over a date range.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `bid_year_id` - The canonical bid year ID
+/// * `start_date` - The first date to include
+/// * `end_date` - The last date to include
+///
+/// # Errors
+///
+/// Returns an error if the rounds, crew slots, prime dates, adjustments,
+/// or leave counts cannot be read.
+pub fn load_slot_inventory(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    start_date: Date,
+    end_date: Date,
+) -> Result<SlotInventory, ApiError> {
+    let mut inventory: SlotInventory = SlotInventory::new();
+
+    let rounds: Vec<(i64, String)> = persistence
+        .list_all_rounds_for_bid_year(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list rounds: {e}"),
+        })?;
+    for (round_id, _) in rounds {
+        let slots_per_day: u32 = persistence
+            .get_round(round_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to get round {round_id}: {e}"),
+            })?
+            .slots_per_day();
+        inventory.set_round_slots(round_id, slots_per_day);
+    }
+
+    let partitioned: Vec<i64> = persistence
+        .list_crew_partitioned_rounds(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load crew-partitioned rounds: {e}"),
+        })?;
+    for round_id in partitioned {
+        inventory.set_crew_partitioned(round_id);
+    }
+    for crew_slots in load_crew_slots(persistence, bid_year_id)? {
+        inventory.set_crew_slots(
+            crew_slots.round_id,
+            crew_slots.crew,
+            crew_slots.slots_per_day,
+        );
+    }
+
+    let caps: Vec<RoundPrimeCapData> =
+        persistence
+            .list_round_prime_caps(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to load prime caps: {e}"),
+            })?;
+    for cap in caps {
+        inventory.set_prime_cap(
+            cap.round_id,
+            u32::try_from(cap.prime_slots_per_day).unwrap_or(0),
+        );
+    }
+
+    let periods: Vec<PrimePeriodData> =
+        persistence
+            .list_prime_periods(bid_year_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to load prime periods: {e}"),
+            })?;
+    for period in periods {
+        let mut date: Date = parse_slot_date("start_date", &period.start_date)?.max(start_date);
+        let last: Date = parse_slot_date("end_date", &period.end_date)?.min(end_date);
+        while date <= last {
+            inventory.add_prime_date(date);
+            date += Duration::days(1);
+        }
+    }
+
+    let start: String = start_date.to_string();
+    let end: String = end_date.to_string();
+    let adjustments: Vec<SlotAdjustmentData> = persistence
+        .list_slot_adjustments(bid_year_id, &start, &end)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load slot adjustments: {e}"),
+        })?;
+    for adjustment in adjustments {
+        let date: Date = parse_slot_date("slot_date", &adjustment.slot_date)?;
+        inventory.set_adjustment(
+            adjustment.area_id,
+            adjustment.round_id,
+            date,
+            adjustment.staffing_adjustment,
+        );
+    }
+
+    let counts: Vec<DailyLeaveCountData> = persistence
+        .list_daily_leave_counts(bid_year_id, &start, &end)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load leave counts: {e}"),
+        })?;
+    for count in counts {
+        let date: Date = parse_slot_date("leave_date", &count.leave_date)?;
+        inventory.add_used(
+            count.area_id,
+            count.round_id,
+            date,
+            count.crew.and_then(|crew| u8::try_from(crew).ok()),
+            u32::try_from(count.approved).unwrap_or(u32::MAX),
+        );
+    }
+
+    Ok(inventory)
+}
+
+/// Converts one day of the inventory into its API representation.
+fn to_day_info(date: Date, slots: DailySlots) -> SlotInventoryDayInfo {
+    SlotInventoryDayInfo {
+        date: date.to_string(),
+        slots_per_day: slots.slots_per_day,
+        prime: slots.prime,
+        prime_slots_per_day: slots.prime_slots_per_day,
+        staffing_adjustment: slots.staffing_adjustment,
+        capacity: slots.capacity(),
+        used: slots.used,
+        remaining: slots.remaining(),
+    }
+}
+
+/// Reads the leave slots of a round in an area, one entry per day.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The area, round, and date range
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The date range is invalid or longer than a year
+/// - The area or round does not exist
+/// - The database cannot be queried
+pub fn get_slot_inventory(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &GetSlotInventoryRequest,
+) -> Result<GetSlotInventoryResponse, ApiError> {
+    let (start_date, end_date): (Date, Date) =
+        parse_inventory_range(&request.start_date, &request.end_date)?;
+
+    let (bid_year, _) = resolve_area(metadata, request.area_id)?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+    require_round(persistence, bid_year_id, request.round_id)?;
+
+    let inventory: SlotInventory =
+        load_slot_inventory(persistence, bid_year_id, start_date, end_date)?;
+    let mut days: Vec<SlotInventoryDayInfo> = Vec::new();
+    let mut date: Date = start_date;
+    while date <= end_date {
+        days.push(to_day_info(
+            date,
+            inventory.slots(request.area_id, request.round_id, date),
+        ));
+        date += Duration::days(1);
+    }
+
+    Ok(GetSlotInventoryResponse {
+        area_id: request.area_id,
+        round_id: request.round_id,
+        days,
+    })
+}
+
+/// Adjusts the leave slots of one day of a round for staffing.
+///
+/// The adjustment replaces any previous adjustment for the day. Approved
+/// leave already held is not withdrawn when capacity drops below it.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The area, round, date, and new adjustment
+/// * `authenticated_actor` - The authenticated actor making the adjustment
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The date is invalid
+/// - The area or round does not exist
+/// - The bid year is `BiddingClosed`
+/// - The database operation fails
+pub fn adjust_slot_inventory(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &AdjustSlotInventoryRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<AdjustSlotInventoryResponse, ApiError> {
+    require_admin(authenticated_actor, "adjust slot inventory")?;
+
+    let date: Date = parse_slot_date("date", &request.date)?;
+    let (bid_year, area) = resolve_area(metadata, request.area_id)?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, bid_year_id)?;
+    if lifecycle_state == BidYearLifecycle::BiddingClosed {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("adjust slot inventory"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+    require_round(persistence, bid_year_id, request.round_id)?;
+
+    let slot_date: String = date.to_string();
+    let previous_adjustment: i32 = persistence
+        .get_slot_adjustment(request.area_id, request.round_id, &slot_date)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get slot adjustment: {e}"),
+        })?;
+
+    let command: Command = Command::AdjustSlotInventory {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id: request.round_id,
+        date,
+        previous_adjustment,
+        staffing_adjustment: request.staffing_adjustment,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    persistence
+        .set_slot_adjustment(
+            bid_year_id,
+            request.area_id,
+            request.round_id,
+            &slot_date,
+            request.staffing_adjustment,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record slot adjustment: {e}"),
+        })?;
+
+    persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    let inventory: SlotInventory = load_slot_inventory(persistence, bid_year_id, date, date)?;
+    let day: SlotInventoryDayInfo = to_day_info(
+        date,
+        inventory.slots(request.area_id, request.round_id, date),
+    );
+
+    Ok(AdjustSlotInventoryResponse {
+        area_id: request.area_id,
+        round_id: request.round_id,
+        message: format!(
+            "Leave slots in round {} on {slot_date} adjusted to {} ({} remaining)",
+            request.round_id, day.capacity, day.remaining
+        ),
+        day,
+    })
+}
+
+/// Describes a round's slot partitioning for an audit snapshot.
+fn describe_crew_slots(round_id: i64, crew_slots: Option<&[CrewSlotsInfo]>) -> String {
+    crew_slots.map_or_else(
+        || format!("round_id={round_id},crew_partitioned=false"),
+        |crew_slots| {
+            let described: Vec<String> = crew_slots
+                .iter()
+                .map(|slots| format!("{}={}", slots.crew, slots.slots_per_day))
+                .collect();
+            format!(
+                "round_id={round_id},crew_partitioned=true,crew_slots=[{}]",
+                described.join(";")
+            )
+        },
+    )
+}
+
+/// Lists the rounds of a bid year that allocate slots per crew.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn list_round_crew_slots(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<ListRoundCrewSlotsResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+
+    let partitioned: Vec<i64> = persistence
+        .list_crew_partitioned_rounds(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list crew-partitioned rounds: {e}"),
+        })?;
+    let crew_slots: Vec<CrewSlots> = load_crew_slots(persistence, bid_year_id)?;
+    let rounds: Vec<RoundCrewSlotsInfo> = partitioned
+        .into_iter()
+        .map(|round_id| RoundCrewSlotsInfo {
+            round_id,
+            crew_slots: crew_slots
+                .iter()
+                .filter(|slots| slots.round_id == round_id)
+                .map(|slots| CrewSlotsInfo {
+                    crew: slots.crew,
+                    slots_per_day: slots.slots_per_day,
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(ListRoundCrewSlotsResponse {
+        bid_year_id,
+        rounds,
+    })
+}
+
+/// Switches a round between area-wide and per-crew leave slots.
+///
+/// A crew-partitioned round offers each listed crew its own daily slots
+/// in every area, and bids are checked against the bidder's crew only.
+/// Crews left out of the list, and users without a crew, get no slots.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The round and its crew allocation
+/// * `authenticated_actor` - The authenticated actor making the change
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year or round does not exist
+/// - Bidding has already started
+/// - A crew is invalid or listed twice, or crews are listed for an
+///   area-wide round
+/// - The database operation fails
+#[allow(clippy::too_many_lines)]
+pub fn set_round_crew_slots(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &SetRoundCrewSlotsRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<SetRoundCrewSlotsResponse, ApiError> {
+    require_admin(authenticated_actor, "set round crew slots")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    if matches!(
+        lifecycle_state,
+        BidYearLifecycle::BiddingActive | BidYearLifecycle::BiddingClosed
+    ) {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("set round crew slots"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+    require_round(persistence, request.bid_year_id, request.round_id)?;
+
+    if !request.crew_partitioned && !request.crew_slots.is_empty() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("crew_slots"),
+            message: String::from("Crew slots can only be set on a crew-partitioned round"),
+        });
+    }
+    let mut crew_slots: Vec<CrewSlotsInfo> = request.crew_slots.clone();
+    crew_slots.sort_by_key(|slots| slots.crew);
+    let mut seen: BTreeSet<u8> = BTreeSet::new();
+    let mut stored: Vec<(i32, i32)> = Vec::new();
+    for slots in &crew_slots {
+        Crew::new(slots.crew).map_err(translate_domain_error)?;
+        if !seen.insert(slots.crew) {
+            return Err(ApiError::InvalidInput {
+                field: String::from("crew_slots"),
+                message: format!("Crew {} is listed more than once", slots.crew),
+            });
+        }
+        let slots_per_day: i32 =
+            i32::try_from(slots.slots_per_day).map_err(|_| ApiError::InvalidInput {
+                field: String::from("crew_slots"),
+                message: format!("Slots for crew {} are out of range", slots.crew),
+            })?;
+        stored.push((i32::from(slots.crew), slots_per_day));
+    }
+
+    let was_partitioned: bool = persistence
+        .list_crew_partitioned_rounds(request.bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list crew-partitioned rounds: {e}"),
+        })?
+        .contains(&request.round_id);
+    let previous: Vec<CrewSlotsInfo> = load_crew_slots(persistence, request.bid_year_id)?
+        .into_iter()
+        .filter(|slots| slots.round_id == request.round_id)
+        .map(|slots| CrewSlotsInfo {
+            crew: slots.crew,
+            slots_per_day: slots.slots_per_day,
+        })
+        .collect();
+
+    persistence
+        .set_round_crew_slots(
+            request.bid_year_id,
+            request.round_id,
+            request.crew_partitioned,
+            &stored,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to set round crew slots: {e}"),
+        })?;
+
+    let message: String = if request.crew_partitioned {
+        format!(
+            "Round {} now allocates slots to {} crew(s)",
+            request.round_id,
+            crew_slots.len()
+        )
+    } else {
+        format!(
+            "Round {} now shares its slots across the area",
+            request.round_id
+        )
+    };
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let action: Action = Action::new(
+        String::from("SetRoundCrewSlots"),
+        Some(format!("{message} in bid year {year}")),
+    );
+    let before: StateSnapshot = StateSnapshot::new(describe_crew_slots(
+        request.round_id,
+        was_partitioned.then_some(previous.as_slice()),
+    ));
+    let after: StateSnapshot = StateSnapshot::new(describe_crew_slots(
+        request.round_id,
+        request.crew_partitioned.then_some(crew_slots.as_slice()),
+    ));
+    let audit_event: AuditEvent = AuditEvent::new_global(actor, cause, action, before, after);
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    Ok(SetRoundCrewSlotsResponse {
+        bid_year_id: request.bid_year_id,
+        round_id: request.round_id,
+        crew_partitioned: request.crew_partitioned,
+        crew_slots,
+        message,
+    })
+}
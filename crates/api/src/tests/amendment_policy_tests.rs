@@ -0,0 +1,165 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the bid amendment policy and its enforcement on leave entry.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_admin, create_test_admin_operator,
+    create_test_bidder, create_test_bidder_operator, create_test_cause,
+};
+use crate::{
+    EnterLeaveBidRequest, GetBidAmendmentPolicyResponse, SetBidAmendmentPolicyRequest,
+    SetBidAmendmentPolicyResponse, enter_leave_bid, get_bid_amendment_policy,
+    set_bid_amendment_policy,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with users AA and AB and one round offering two
+/// slots per day. The bid year is left in `Draft` so the amendment policy
+/// can still be set.
+fn setup() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(2)
+        .with_rounds(1)
+        .persist()
+        .unwrap();
+    // Bids are entered as the test bidder operator, which audit events reference
+    create_persisted_bidder_operator(&mut fixture.persistence).unwrap();
+    fixture
+        .persistence
+        .update_round(fixture.round_ids[0], "Round 1", 2, 3, 200, false, false)
+        .unwrap();
+    fixture
+}
+
+fn set_policy(
+    fixture: &mut PersistedFixture,
+    policy: &str,
+    window_hours: Option<u32>,
+) -> Result<SetBidAmendmentPolicyResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    set_bid_amendment_policy(
+        &mut fixture.persistence,
+        &metadata,
+        &SetBidAmendmentPolicyRequest {
+            bid_year_id: fixture.bid_year_id,
+            policy: String::from(policy),
+            window_hours,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn enter(fixture: &mut PersistedFixture, initials: &str, date: &str) -> Result<(), ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: EnterLeaveBidRequest = EnterLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from(initials),
+        received_via: String::from("phone"),
+        leave_dates: vec![String::from(date)],
+        hours: 8,
+        override_reason: None,
+    };
+    enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+    .map(|_| ())
+}
+
+fn start_bidding(fixture: &mut PersistedFixture) {
+    fixture
+        .persistence
+        .update_lifecycle_state(fixture.bid_year_id, "BiddingActive")
+        .unwrap();
+}
+
+#[test]
+fn test_amendment_policy_is_set_before_bidding() {
+    let mut fixture: PersistedFixture = setup();
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let policy: GetBidAmendmentPolicyResponse =
+        get_bid_amendment_policy(&mut fixture.persistence, &metadata, fixture.bid_year_id).unwrap();
+    assert_eq!(
+        (policy.policy.as_str(), policy.window_hours),
+        ("unrestricted", None)
+    );
+
+    set_policy(&mut fixture, "within_hours", Some(24)).unwrap();
+    let policy: GetBidAmendmentPolicyResponse =
+        get_bid_amendment_policy(&mut fixture.persistence, &metadata, fixture.bid_year_id).unwrap();
+    assert_eq!(
+        (policy.policy.as_str(), policy.window_hours),
+        ("within_hours", Some(24))
+    );
+
+    // A within_hours policy needs its window, and other policies take none
+    assert!(matches!(
+        set_policy(&mut fixture, "within_hours", None),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "policy"
+    ));
+    assert!(matches!(
+        set_policy(&mut fixture, "until_window_closes", Some(4)),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "policy"
+    ));
+
+    // Only Admins may set the policy
+    let result: Result<SetBidAmendmentPolicyResponse, ApiError> = set_bid_amendment_policy(
+        &mut fixture.persistence,
+        &metadata,
+        &SetBidAmendmentPolicyRequest {
+            bid_year_id: fixture.bid_year_id,
+            policy: String::from("not_allowed"),
+            window_hours: None,
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    // The policy is fixed once bidding starts
+    start_bidding(&mut fixture);
+    assert!(matches!(
+        set_policy(&mut fixture, "not_allowed", None),
+        Err(ApiError::DomainRuleViolation { .. })
+    ));
+}
+
+#[test]
+fn test_amendment_refused_once_window_has_passed() {
+    let mut fixture: PersistedFixture = setup();
+    set_policy(&mut fixture, "not_allowed", None).unwrap();
+    start_bidding(&mut fixture);
+
+    enter(&mut fixture, "AA", "2026-07-01").unwrap();
+    let result: Result<(), ApiError> = enter(&mut fixture, "AA", "2026-07-02");
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "amendment_window"
+    ));
+
+    // A first submission is never an amendment
+    enter(&mut fixture, "AB", "2026-07-02").unwrap();
+}
+
+#[test]
+fn test_amendment_allowed_within_window() {
+    let mut fixture: PersistedFixture = setup();
+    set_policy(&mut fixture, "within_hours", Some(24)).unwrap();
+    start_bidding(&mut fixture);
+
+    enter(&mut fixture, "AA", "2026-07-01").unwrap();
+    enter(&mut fixture, "AA", "2026-07-02").unwrap();
+}
@@ -0,0 +1,214 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for erasing former employees' personal data.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    AnonymizeUserRequest, AnonymizeUserResponse, CheckpointRequest, anonymize_user, checkpoint,
+};
+use time::{Date, Month};
+use zab_bid::{BootstrapMetadata, State, TransitionResult};
+use zab_bid_domain::{Area, BidYear, Initials, User};
+use zab_bid_persistence::{ANONYMIZED_DATE, ANONYMIZED_NAME, SnapshotEncoding, SqlitePersistence};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+const RETENTION_DAYS: u32 = 365;
+
+fn snapshot(persistence: &mut SqlitePersistence) {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let state: State = persistence
+        .get_current_state(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    let result: TransitionResult = checkpoint(
+        persistence,
+        &metadata,
+        &state,
+        &CheckpointRequest::default(),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    persistence.persist_transition(&result).unwrap();
+}
+
+/// Creates a closed 2026/North with users AA and AB, snapshotted in both
+/// encodings. Returns the bid year ID and AA's user ID.
+fn setup_closed() -> (SqlitePersistence, i64, i64) {
+    let PersistedFixture {
+        mut persistence,
+        bid_year_id,
+        ..
+    } = BidYearFixture::new(2026).with_users(2).persist().unwrap();
+    snapshot(&mut persistence);
+    persistence.set_snapshot_encoding(SnapshotEncoding::Postcard);
+    snapshot(&mut persistence);
+
+    persistence
+        .update_lifecycle_state(bid_year_id, "BiddingClosed")
+        .unwrap();
+    let user_id: i64 = user(&mut persistence, "AA").user_id.unwrap();
+    persistence
+        .set_user_contact(user_id, "aa@example.com", true)
+        .unwrap();
+
+    (persistence, bid_year_id, user_id)
+}
+
+fn user(persistence: &mut SqlitePersistence, initials: &str) -> User {
+    persistence
+        .list_users(&BidYear::new(2026), &Area::new("North"))
+        .unwrap()
+        .into_iter()
+        .find(|user| user.initials.value() == initials)
+        .unwrap()
+}
+
+fn anonymize(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    user_id: i64,
+    today: Date,
+) -> Result<AnonymizeUserResponse, ApiError> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    anonymize_user(
+        persistence,
+        &metadata,
+        &AnonymizeUserRequest {
+            bid_year_id,
+            user_id,
+        },
+        RETENTION_DAYS,
+        today,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn long_after_close() -> Date {
+    Date::from_calendar_date(2028, Month::June, 1).unwrap()
+}
+
+fn assert_scrubbed(user: &User) {
+    assert_eq!(user.name, ANONYMIZED_NAME);
+    assert_eq!(
+        user.seniority_data.cumulative_natca_bu_date,
+        ANONYMIZED_DATE
+    );
+    assert_eq!(user.seniority_data.natca_bu_date, ANONYMIZED_DATE);
+    assert_eq!(user.seniority_data.eod_faa_date, ANONYMIZED_DATE);
+    assert_eq!(
+        user.seniority_data.service_computation_date,
+        ANONYMIZED_DATE
+    );
+}
+
+#[test]
+fn test_anonymize_erases_personal_data_but_keeps_initials() {
+    let (mut persistence, bid_year_id, user_id) = setup_closed();
+
+    let response: AnonymizeUserResponse =
+        anonymize(&mut persistence, bid_year_id, user_id, long_after_close()).unwrap();
+
+    assert_eq!(response.initials, "AA");
+    assert_eq!(response.snapshots_rewritten, 2);
+    let anonymized: User = user(&mut persistence, "AA");
+    assert_eq!(anonymized.user_id, Some(user_id));
+    assert_scrubbed(&anonymized);
+    assert_eq!(anonymized.seniority_data.lottery_value, Some(1));
+    assert_eq!(user(&mut persistence, "AB").name, "Controller AB");
+    assert!(persistence.get_user_contact(user_id).unwrap().is_none());
+
+    let (latest, _) = persistence
+        .get_latest_snapshot(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    assert_scrubbed(latest.find_user_by_id(user_id).unwrap());
+    assert_eq!(
+        latest.users.get(&Initials::new("AB")).unwrap().name,
+        "Controller AB"
+    );
+
+    let recorded = persistence
+        .get_user_anonymization(user_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(recorded.event_id, response.audit_event_id);
+    assert_eq!(
+        persistence
+            .get_audit_event(response.audit_event_id)
+            .unwrap()
+            .action
+            .name,
+        "AnonymizeUser"
+    );
+}
+
+#[test]
+fn test_anonymize_waits_for_close_and_retention_period() {
+    let (mut persistence, bid_year_id, user_id) = setup_closed();
+
+    // The bid year ends in early January 2027
+    let within_retention: Result<AnonymizeUserResponse, ApiError> = anonymize(
+        &mut persistence,
+        bid_year_id,
+        user_id,
+        Date::from_calendar_date(2027, Month::December, 1).unwrap(),
+    );
+    assert!(matches!(
+        within_retention,
+        Err(ApiError::DomainRuleViolation { .. })
+    ));
+
+    persistence
+        .update_lifecycle_state(bid_year_id, "BiddingActive")
+        .unwrap();
+    let still_open: Result<AnonymizeUserResponse, ApiError> =
+        anonymize(&mut persistence, bid_year_id, user_id, long_after_close());
+    assert!(matches!(
+        still_open,
+        Err(ApiError::DomainRuleViolation { .. })
+    ));
+
+    assert_eq!(user(&mut persistence, "AA").name, "Controller AA");
+}
+
+#[test]
+fn test_user_is_anonymized_only_once() {
+    let (mut persistence, bid_year_id, user_id) = setup_closed();
+    anonymize(&mut persistence, bid_year_id, user_id, long_after_close()).unwrap();
+
+    let again: Result<AnonymizeUserResponse, ApiError> =
+        anonymize(&mut persistence, bid_year_id, user_id, long_after_close());
+
+    assert!(matches!(again, Err(ApiError::DomainRuleViolation { .. })));
+}
+
+#[test]
+fn test_bidder_cannot_anonymize_users() {
+    let (mut persistence, bid_year_id, user_id) = setup_closed();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+
+    let result: Result<AnonymizeUserResponse, ApiError> = anonymize_user(
+        &mut persistence,
+        &metadata,
+        &AnonymizeUserRequest {
+            bid_year_id,
+            user_id,
+        },
+        RETENTION_DAYS,
+        long_after_close(),
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
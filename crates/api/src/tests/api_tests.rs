@@ -7,16 +7,17 @@
 
 use zab_bid::{BootstrapMetadata, BootstrapResult, State, TransitionResult};
 use zab_bid_audit::{Actor, Cause};
-use zab_bid_domain::{Area, BidYear};
+use zab_bid_domain::{AccrualRates, Area, BidYear};
 use zab_bid_persistence::SqlitePersistence;
 
 use crate::{
-    ApiError, ApiResult, AuthError, AuthenticatedActor, CreateAreaRequest, CreateBidYearRequest,
-    GetLeaveAvailabilityResponse, ImportCsvUsersRequest, ListAreasRequest, ListAreasResponse,
-    ListBidYearsResponse, ListUsersResponse, RegisterUserRequest, RegisterUserResult, Role,
-    UpdateUserRequest, checkpoint, create_area, create_bid_year, finalize, get_current_state,
-    get_historical_state, get_leave_availability, import_csv_users, list_areas, list_bid_years,
-    list_users, register_user, rollback, update_user,
+    ApiError, ApiResult, AuthError, AuthenticatedActor, CheckpointRequest, CreateAreaRequest,
+    CreateBidYearRequest, GetLeaveAvailabilityResponse, ImportCsvUsersRequest, ListAreasRequest,
+    ListAreasResponse, ListBidYearsResponse, ListUsersResponse, RegisterUserRequest,
+    RegisterUserResult, Role, RollbackResult, RollbackTarget, UpdateUserRequest, checkpoint,
+    create_area, create_bid_year, finalize, get_current_state, get_historical_state,
+    get_leave_availability, import_csv_users, list_areas, list_bid_years, list_users,
+    register_user, rollback, update_user,
 };
 
 use super::helpers::{
@@ -183,6 +184,7 @@ fn test_admin_can_create_checkpoint() {
         &mut persistence,
         &metadata,
         &state,
+        &CheckpointRequest::default(),
         &admin,
         &create_test_admin_operator(),
         cause,
@@ -207,6 +209,7 @@ fn test_bidder_cannot_create_checkpoint() {
         &mut persistence,
         &metadata,
         &state,
+        &CheckpointRequest::default(),
         &bidder,
         &create_test_bidder_operator(),
         cause,
@@ -286,19 +289,34 @@ fn test_admin_can_rollback() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
     let admin: AuthenticatedActor = create_test_admin();
     let cause: Cause = create_test_cause();
+    let checkpoint_result: TransitionResult = checkpoint(
+        &mut persistence,
+        &metadata,
+        &state,
+        &CheckpointRequest::default(),
+        &admin,
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    let checkpoint_id: i64 = persistence
+        .persist_transition(&checkpoint_result)
+        .unwrap()
+        .event_id;
 
-    let result: Result<TransitionResult, ApiError> = rollback(
+    let result: Result<RollbackResult, ApiError> = rollback(
         &mut persistence,
         &metadata,
         &state,
-        1,
+        &RollbackTarget::EventId(checkpoint_id),
+        false,
         &admin,
         &create_test_admin_operator(),
         cause,
     );
 
     assert!(result.is_ok());
-    let transition: TransitionResult = result.unwrap();
+    let transition: TransitionResult = result.unwrap().transition_result;
     assert_eq!(transition.audit_event.action.name, "Rollback");
     assert_eq!(transition.audit_event.actor.id, "admin-123");
     assert_eq!(transition.audit_event.actor.actor_type, "admin");
@@ -312,11 +330,12 @@ fn test_bidder_cannot_rollback() {
     let bidder: AuthenticatedActor = create_test_bidder();
     let cause: Cause = create_test_cause();
 
-    let result: Result<TransitionResult, ApiError> = rollback(
+    let result: Result<RollbackResult, ApiError> = rollback(
         &mut persistence,
         &metadata,
         &state,
-        1,
+        &RollbackTarget::EventId(1),
+        false,
         &bidder,
         &create_test_bidder_operator(),
         cause,
@@ -431,7 +450,17 @@ fn test_valid_api_request_returns_new_state() {
     assert!(result.is_ok());
     let api_result: ApiResult<RegisterUserResult> = result.unwrap();
     assert_eq!(api_result.new_state.users.len(), 1);
-    assert_eq!(api_result.new_state.users[0].initials.value(), "AB");
+    assert_eq!(
+        api_result
+            .new_state
+            .users
+            .values()
+            .next()
+            .unwrap()
+            .initials
+            .value(),
+        "AB"
+    );
 }
 
 #[test]
@@ -717,7 +746,10 @@ fn test_successful_api_call_updates_state() {
 
     // New state has the user
     assert_eq!(api_result.new_state.users.len(), 1);
-    assert_eq!(api_result.new_state.users[0].name, "John Doe");
+    assert_eq!(
+        api_result.new_state.users.values().next().unwrap().name,
+        "John Doe"
+    );
 
     // Original state is unchanged
     assert_eq!(state.users.len(), 0);
@@ -1462,6 +1494,7 @@ fn test_list_users_empty() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     )
     .unwrap();
 
@@ -1571,6 +1604,7 @@ fn test_list_users_with_users() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     )
     .unwrap();
 
@@ -1664,6 +1698,7 @@ fn test_list_users_with_no_crew() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     )
     .unwrap();
 
@@ -1696,6 +1731,7 @@ fn test_list_users_nonexistent_bid_year() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     );
 
     assert!(result.is_err());
@@ -1734,6 +1770,7 @@ fn test_list_users_nonexistent_area() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     );
 
     assert!(result.is_err());
@@ -1926,8 +1963,14 @@ fn test_get_leave_availability_zero_usage() {
     let initials = zab_bid_domain::Initials::new("AB");
 
     // Get leave availability
-    let result: Result<GetLeaveAvailabilityResponse, ApiError> =
-        get_leave_availability(&metadata, canonical_bid_year, &area, &initials, &new_state);
+    let result: Result<GetLeaveAvailabilityResponse, ApiError> = get_leave_availability(
+        &metadata,
+        canonical_bid_year,
+        &area,
+        &initials,
+        &new_state,
+        AccrualRates::default(),
+    );
 
     assert!(result.is_ok());
     let response: GetLeaveAvailabilityResponse = result.unwrap();
@@ -1962,8 +2005,14 @@ fn test_get_leave_availability_user_not_found() {
 
     let initials = zab_bid_domain::Initials::new("XY");
 
-    let result: Result<GetLeaveAvailabilityResponse, ApiError> =
-        get_leave_availability(&metadata, canonical_bid_year, &area, &initials, &state);
+    let result: Result<GetLeaveAvailabilityResponse, ApiError> = get_leave_availability(
+        &metadata,
+        canonical_bid_year,
+        &area,
+        &initials,
+        &state,
+        AccrualRates::default(),
+    );
 
     assert!(result.is_err());
     let err: ApiError = result.unwrap_err();
@@ -1991,6 +2040,7 @@ fn test_get_leave_availability_area_not_found() {
         &wrong_area,
         &initials,
         &state,
+        AccrualRates::default(),
     );
 
     assert!(result.is_err());
@@ -2043,8 +2093,14 @@ fn test_get_leave_availability_explanation_text() {
         .expect("Failed to reload state");
     let initials = zab_bid_domain::Initials::new("AB");
 
-    let result: Result<GetLeaveAvailabilityResponse, ApiError> =
-        get_leave_availability(&metadata, canonical_bid_year, &area, &initials, &new_state);
+    let result: Result<GetLeaveAvailabilityResponse, ApiError> = get_leave_availability(
+        &metadata,
+        canonical_bid_year,
+        &area,
+        &initials,
+        &new_state,
+        AccrualRates::default(),
+    );
 
     assert!(result.is_ok());
     let response: GetLeaveAvailabilityResponse = result.unwrap();
@@ -2107,8 +2163,14 @@ fn test_get_leave_availability_different_service_tiers() {
         .expect("Failed to reload state");
     let initials1 = zab_bid_domain::Initials::new("U1");
 
-    let result1: Result<GetLeaveAvailabilityResponse, ApiError> =
-        get_leave_availability(&metadata, canonical_bid_year, &area, &initials1, &state1);
+    let result1: Result<GetLeaveAvailabilityResponse, ApiError> = get_leave_availability(
+        &metadata,
+        canonical_bid_year,
+        &area,
+        &initials1,
+        &state1,
+        AccrualRates::default(),
+    );
     assert!(result1.is_ok());
     let response1: GetLeaveAvailabilityResponse = result1.unwrap();
 
@@ -2148,8 +2210,14 @@ fn test_get_leave_availability_different_service_tiers() {
         .expect("Failed to reload state");
     let initials2 = zab_bid_domain::Initials::new("U2");
 
-    let result2: Result<GetLeaveAvailabilityResponse, ApiError> =
-        get_leave_availability(&metadata, canonical_bid_year, &area, &initials2, &state2);
+    let result2: Result<GetLeaveAvailabilityResponse, ApiError> = get_leave_availability(
+        &metadata,
+        canonical_bid_year,
+        &area,
+        &initials2,
+        &state2,
+        AccrualRates::default(),
+    );
     assert!(result2.is_ok());
     let response2: GetLeaveAvailabilityResponse = result2.unwrap();
 
@@ -2275,7 +2343,7 @@ SB,Steve Barnes,EAST,3,CPC,2010-04-01,2010-04-01,2010-08-05,2010-08-05,";
     );
 
     // Verify user_ids are assigned
-    for user in &east_state.users {
+    for user in east_state.users.values() {
         assert!(user.user_id.is_some(), "User should have user_id assigned");
         assert!(user.user_id.unwrap() > 0, "User ID should be positive");
     }
@@ -2283,7 +2351,7 @@ SB,Steve Barnes,EAST,3,CPC,2010-04-01,2010-04-01,2010-08-05,2010-08-05,";
     // Verify correct initials
     let initials_set: std::collections::HashSet<String> = east_state
         .users
-        .iter()
+        .values()
         .map(|u| u.initials.value().to_string())
         .collect();
     assert!(initials_set.contains("CS"), "Should contain CS user");
@@ -2398,7 +2466,7 @@ PU,Pam User,EAST,3,CPC,2012-04-01,2012-04-01,2012-08-05,2012-08-05,";
     // Verify all three users exist
     let initials_set: std::collections::HashSet<String> = east_state_final
         .users
-        .iter()
+        .values()
         .map(|u| u.initials.value().to_string())
         .collect();
     assert!(initials_set.contains("CS"), "CS should exist");
@@ -2611,6 +2679,7 @@ fn test_user_id_is_canonical_identifier() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     )
     .unwrap();
 
@@ -2754,6 +2823,7 @@ fn test_duplicate_initials_allowed_across_areas() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     )
     .unwrap();
 
@@ -2769,6 +2839,7 @@ fn test_duplicate_initials_allowed_across_areas() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     )
     .unwrap();
 
@@ -2838,6 +2909,7 @@ fn test_user_updates_preserve_canonical_id() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     )
     .unwrap();
 
@@ -2890,6 +2962,7 @@ fn test_user_updates_preserve_canonical_id() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     )
     .unwrap();
 
@@ -2964,6 +3037,7 @@ fn test_register_user_creates_user_with_user_id() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     )
     .unwrap();
 
@@ -3019,6 +3093,7 @@ fn test_update_user_uses_user_id_from_request() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     )
     .unwrap();
 
@@ -3076,6 +3151,7 @@ fn test_update_user_uses_user_id_from_request() {
         &actor,
         &operator,
         zab_bid_domain::BidYearLifecycle::Draft,
+        AccrualRates::default(),
     )
     .unwrap();
 
@@ -3140,7 +3216,11 @@ fn test_update_user_participation_successful() {
 
     // Get the registered user's ID
     let state_after_register = persistence.get_current_state(&bid_year, &area).unwrap();
-    let user_id = state_after_register.users[0]
+    let user_id = state_after_register
+        .users
+        .values()
+        .next()
+        .unwrap()
         .user_id
         .expect("User should have ID");
 
@@ -3165,6 +3245,12 @@ fn test_update_user_participation_successful() {
     assert_eq!(response.initials, "AB");
     assert!(response.excluded_from_bidding);
     assert!(!response.excluded_from_leave_calculation);
+
+    // The flags are stored with the user
+    let state_after_update = persistence.get_current_state(&bid_year, &area).unwrap();
+    let user = state_after_update.find_user_by_id(user_id).unwrap();
+    assert!(user.excluded_from_bidding);
+    assert!(!user.excluded_from_leave_calculation);
 }
 
 #[test]
@@ -3217,7 +3303,11 @@ fn test_update_user_participation_invariant_violation() {
 
     // Get the registered user's ID
     let state_after_register = persistence.get_current_state(&bid_year, &area).unwrap();
-    let user_id = state_after_register.users[0]
+    let user_id = state_after_register
+        .users
+        .values()
+        .next()
+        .unwrap()
         .user_id
         .expect("User should have ID");
 
@@ -3326,7 +3416,11 @@ fn test_update_user_participation_lifecycle_constraint() {
 
     // Get the registered user's ID
     let state_after_register = persistence.get_current_state(&bid_year, &area).unwrap();
-    let user_id = state_after_register.users[0]
+    let user_id = state_after_register
+        .users
+        .values()
+        .next()
+        .unwrap()
         .user_id
         .expect("User should have ID");
 
@@ -0,0 +1,221 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for per-area bid schedule overrides.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause, setup_test_persistence,
+};
+use crate::{
+    AreaBidScheduleInfo, BidScheduleInfo, GetBidScheduleResponse, SetAreaBidScheduleRequest,
+    SetBidScheduleRequest, clear_area_bid_schedule, get_bid_schedule, set_area_bid_schedule,
+    set_bid_schedule,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_persistence::SqlitePersistence;
+
+/// Configures the 2026 bid year schedule and returns `(bid_year_id, area_id)`.
+fn setup_with_schedule(persistence: &mut SqlitePersistence) -> (i64, i64) {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    let area_id: i64 = metadata
+        .areas
+        .iter()
+        .find_map(|(_, a)| a.area_id())
+        .unwrap();
+
+    set_bid_schedule(
+        persistence,
+        &metadata,
+        &SetBidScheduleRequest {
+            bid_year_id,
+            timezone: Some(String::from("America/New_York")),
+            start_date: String::from("2026-03-02"),
+            window_start_time: String::from("08:00:00"),
+            window_end_time: String::from("17:00:00"),
+            bidders_per_day: 5,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    (bid_year_id, area_id)
+}
+
+fn override_request(area_id: i64) -> SetAreaBidScheduleRequest {
+    SetAreaBidScheduleRequest {
+        area_id,
+        start_date: None,
+        window_start_time: None,
+        window_end_time: None,
+        bidders_per_day: None,
+    }
+}
+
+fn set_override(
+    persistence: &mut SqlitePersistence,
+    request: &SetAreaBidScheduleRequest,
+) -> Result<crate::SetAreaBidScheduleResponse, ApiError> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    set_area_bid_schedule(
+        persistence,
+        &metadata,
+        request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+#[test]
+fn test_area_override_falls_back_to_bid_year_schedule() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let (bid_year_id, area_id) = setup_with_schedule(&mut persistence);
+
+    let mut request: SetAreaBidScheduleRequest = override_request(area_id);
+    request.start_date = Some(String::from("2026-03-09"));
+    request.bidders_per_day = Some(3);
+    let response = set_override(&mut persistence, &request).unwrap();
+    assert_eq!(response.bid_year_id, bid_year_id);
+
+    let expected_effective: BidScheduleInfo = BidScheduleInfo {
+        timezone: String::from("America/New_York"),
+        start_date: String::from("2026-03-09"),
+        window_start_time: String::from("08:00:00"),
+        window_end_time: String::from("17:00:00"),
+        bidders_per_day: 3,
+    };
+    assert_eq!(
+        response.area_schedule.effective_schedule,
+        Some(expected_effective.clone())
+    );
+
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let schedule: GetBidScheduleResponse =
+        get_bid_schedule(&mut persistence, &metadata, bid_year_id).unwrap();
+    assert_eq!(
+        schedule.bid_schedule.map(|s| s.start_date),
+        Some(String::from("2026-03-02"))
+    );
+    assert_eq!(schedule.area_overrides.len(), 1);
+    let area_override: &AreaBidScheduleInfo = &schedule.area_overrides[0];
+    assert_eq!(area_override.area_id, area_id);
+    assert_eq!(area_override.start_date.as_deref(), Some("2026-03-09"));
+    assert_eq!(area_override.window_start_time, None);
+    assert_eq!(area_override.bidders_per_day, Some(3));
+    assert_eq!(area_override.effective_schedule, Some(expected_effective));
+}
+
+#[test]
+fn test_area_override_replaces_previous_override() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let (bid_year_id, area_id) = setup_with_schedule(&mut persistence);
+
+    let mut request: SetAreaBidScheduleRequest = override_request(area_id);
+    request.start_date = Some(String::from("2026-03-09"));
+    set_override(&mut persistence, &request).unwrap();
+
+    let mut request: SetAreaBidScheduleRequest = override_request(area_id);
+    request.window_start_time = Some(String::from("09:00:00"));
+    set_override(&mut persistence, &request).unwrap();
+
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let schedule: GetBidScheduleResponse =
+        get_bid_schedule(&mut persistence, &metadata, bid_year_id).unwrap();
+    let area_override: &AreaBidScheduleInfo = &schedule.area_overrides[0];
+    assert_eq!(area_override.start_date, None);
+    let effective: &BidScheduleInfo = area_override.effective_schedule.as_ref().unwrap();
+    assert_eq!(effective.start_date, "2026-03-02");
+    assert_eq!(effective.window_start_time, "09:00:00");
+}
+
+#[test]
+fn test_area_override_is_validated_against_merged_schedule() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let (_bid_year_id, area_id) = setup_with_schedule(&mut persistence);
+
+    let result = set_override(&mut persistence, &override_request(area_id));
+    assert!(
+        matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "area_bid_schedule")
+    );
+
+    let mut not_monday: SetAreaBidScheduleRequest = override_request(area_id);
+    not_monday.start_date = Some(String::from("2026-03-10"));
+    assert!(set_override(&mut persistence, &not_monday).is_err());
+
+    let mut zero_bidders: SetAreaBidScheduleRequest = override_request(area_id);
+    zero_bidders.bidders_per_day = Some(0);
+    assert!(set_override(&mut persistence, &zero_bidders).is_err());
+
+    // A start time after the bid year's end time yields an invalid window
+    let mut late_start: SetAreaBidScheduleRequest = override_request(area_id);
+    late_start.window_start_time = Some(String::from("18:00:00"));
+    assert!(set_override(&mut persistence, &late_start).is_err());
+
+    assert!(
+        persistence
+            .get_area_bid_schedule_override(area_id)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_area_override_requires_admin() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let (_bid_year_id, area_id) = setup_with_schedule(&mut persistence);
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+
+    let mut request: SetAreaBidScheduleRequest = override_request(area_id);
+    request.bidders_per_day = Some(2);
+    let result = set_area_bid_schedule(
+        &mut persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
+
+#[test]
+fn test_clear_area_override_restores_bid_year_schedule() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let (bid_year_id, area_id) = setup_with_schedule(&mut persistence);
+
+    let mut request: SetAreaBidScheduleRequest = override_request(area_id);
+    request.start_date = Some(String::from("2026-03-09"));
+    set_override(&mut persistence, &request).unwrap();
+
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    clear_area_bid_schedule(
+        &mut persistence,
+        &metadata,
+        area_id,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let schedule: GetBidScheduleResponse =
+        get_bid_schedule(&mut persistence, &metadata, bid_year_id).unwrap();
+    assert!(schedule.area_overrides.is_empty());
+
+    let result = clear_area_bid_schedule(
+        &mut persistence,
+        &metadata,
+        area_id,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
@@ -0,0 +1,514 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for area rep delegations and area-scoped bidding authority.
+
+use std::sync::Arc;
+
+use time::Duration;
+use time::macros::datetime;
+use zab_bid::ManualClock;
+use zab_bid_audit::{Action, AuditEvent, StateSnapshot};
+use zab_bid_persistence::OperatorData;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+use crate::auth::{AuthenticatedActor, AuthorizationService, Role};
+use crate::error::ApiError;
+use crate::tests::helpers::{create_test_admin, create_test_bidder, create_test_cause};
+use crate::{
+    AcceptWaitlistOfferRequest, CreateAreaDelegationRequest, CreateAreaDelegationResponse,
+    DeclineWaitlistOfferRequest, ListAreaDelegationsResponse, RevokeAreaDelegationRequest,
+    SetOperatorAreaScopesRequest, WaitlistOfferResponse, WithdrawLeaveBidRequest,
+    WithdrawLeaveBidResponse, accept_waitlist_offer, create_area_delegation,
+    decline_waitlist_offer, list_area_delegations, list_waitlist, revoke_area_delegation,
+    set_operator_area_scopes, withdraw_leave_bid,
+};
+
+/// A North rep, a South rep, and a Bidder with no areas, with the clock
+/// stopped on 2026-03-01. Each area has three users and one round offering
+/// one slot per day.
+struct Setup {
+    fixture: PersistedFixture,
+    clock: Arc<ManualClock>,
+    admin: OperatorData,
+    north_rep: OperatorData,
+    south_rep: OperatorData,
+    unassigned: OperatorData,
+}
+
+fn create_operator(fixture: &mut PersistedFixture, login: &str, role: &str) -> OperatorData {
+    let operator_id: i64 = fixture
+        .persistence
+        .create_operator(login, login, "password", role)
+        .unwrap();
+    fixture
+        .persistence
+        .get_operator_by_id(operator_id)
+        .unwrap()
+        .unwrap()
+}
+
+fn assign(setup: &mut Setup, operator_id: i64, area_code: &str) {
+    let area_id: i64 = setup.fixture.area_id(area_code);
+    set_operator_area_scopes(
+        &mut setup.fixture.persistence,
+        &setup.fixture.metadata,
+        &SetOperatorAreaScopesRequest {
+            operator_id,
+            area_ids: vec![area_id],
+        },
+        &create_test_admin(),
+        &setup.admin,
+        create_test_cause(),
+    )
+    .unwrap();
+}
+
+fn setup() -> Setup {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_areas(&["North", "South"])
+        .with_users(3)
+        .with_rounds(1)
+        .with_slots_per_day(1)
+        .persist()
+        .unwrap();
+    let clock: Arc<ManualClock> = Arc::new(ManualClock::new(datetime!(2026-03-01 12:00 UTC)));
+    fixture.persistence.set_clock(clock.clone());
+
+    let admin: OperatorData = fixture
+        .persistence
+        .get_operator_by_id(fixture.operator_id)
+        .unwrap()
+        .unwrap();
+    let north_rep: OperatorData = create_operator(&mut fixture, "north-rep", "Bidder");
+    let south_rep: OperatorData = create_operator(&mut fixture, "south-rep", "Bidder");
+    let unassigned: OperatorData = create_operator(&mut fixture, "unassigned", "Bidder");
+
+    let mut setup = Setup {
+        fixture,
+        clock,
+        admin,
+        north_rep,
+        south_rep,
+        unassigned,
+    };
+    let north_rep_id: i64 = setup.north_rep.operator_id;
+    let south_rep_id: i64 = setup.south_rep.operator_id;
+    assign(&mut setup, north_rep_id, "North");
+    assign(&mut setup, south_rep_id, "South");
+    setup
+}
+
+fn delegate_north(
+    setup: &mut Setup,
+    delegate_operator_id: i64,
+    start_date: &str,
+    end_date: &str,
+) -> Result<CreateAreaDelegationResponse, ApiError> {
+    let request = CreateAreaDelegationRequest {
+        area_id: setup.fixture.area_id("North"),
+        delegator_operator_id: setup.north_rep.operator_id,
+        delegate_operator_id,
+        start_date: start_date.to_string(),
+        end_date: end_date.to_string(),
+    };
+    create_area_delegation(
+        &mut setup.fixture.persistence,
+        &setup.fixture.metadata,
+        &request,
+        &create_test_admin(),
+        &setup.admin,
+        create_test_cause(),
+    )
+}
+
+fn may_bid_in(setup: &mut Setup, operator: &OperatorData, area_code: &str) -> bool {
+    let area_id: i64 = setup.fixture.area_id(area_code);
+    AuthorizationService::authorize_area_bidding(
+        &mut setup.fixture.persistence,
+        &create_test_bidder(),
+        operator,
+        area_id,
+        "enter leave bid",
+    )
+    .is_ok()
+}
+
+/// Orders North's users AA, AB, and AC, grants AA leave on 2026-07-01, and
+/// closes bidding so a withdrawn slot is offered down the waitlist.
+///
+/// Returns the ID of AA's leave bid.
+fn hold_north_leave(setup: &mut Setup) -> i64 {
+    let bid_year_id: i64 = setup.fixture.bid_year_id;
+    let area_id: i64 = setup.fixture.area_id("North");
+    let round_id: i64 = setup.fixture.round_ids[0];
+    let event: AuditEvent = AuditEvent::new_global(
+        BidYearFixture::actor(setup.fixture.operator_id),
+        BidYearFixture::cause(),
+        Action::new(String::from("CanonicalizeBidYear"), None),
+        StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+    );
+    setup
+        .fixture
+        .persistence
+        .canonicalize_bid_year(bid_year_id, &event)
+        .unwrap();
+    for (order, initials) in (1..).zip(["AA", "AB", "AC"]) {
+        let user_id: i64 = setup.fixture.user_id(initials);
+        setup
+            .fixture
+            .persistence
+            .override_bid_order(bid_year_id, user_id, Some(order), "Test order")
+            .unwrap();
+    }
+
+    let user_id: i64 = setup.fixture.user_id("AA");
+    let leave_bid_id: i64 = setup
+        .fixture
+        .persistence
+        .insert_leave_bid(
+            bid_year_id,
+            area_id,
+            user_id,
+            round_id,
+            "2026-07-01",
+            8,
+            "AA",
+            "phone",
+        )
+        .unwrap();
+    setup
+        .fixture
+        .persistence
+        .update_lifecycle_state(bid_year_id, "BiddingClosed")
+        .unwrap();
+    leave_bid_id
+}
+
+fn withdraw_as(
+    setup: &mut Setup,
+    operator: &OperatorData,
+    leave_bid_id: i64,
+) -> Result<WithdrawLeaveBidResponse, ApiError> {
+    let request = WithdrawLeaveBidRequest {
+        area_id: setup.fixture.area_id("North"),
+        leave_bid_id,
+        offer_hours: None,
+    };
+    withdraw_leave_bid(
+        &mut setup.fixture.persistence,
+        &setup.fixture.metadata,
+        &request,
+        &create_test_bidder(),
+        operator,
+        &create_test_cause(),
+    )
+}
+
+/// Withdraws AA's leave as the North rep and returns the ID of the offer
+/// made to AB.
+fn offer_north_slot(setup: &mut Setup) -> i64 {
+    let leave_bid_id: i64 = hold_north_leave(setup);
+    let north_rep: OperatorData = setup.north_rep.clone();
+    withdraw_as(setup, &north_rep, leave_bid_id).unwrap();
+
+    let area_id: i64 = setup.fixture.area_id("North");
+    let round_id: i64 = setup.fixture.round_ids[0];
+    list_waitlist(
+        &mut setup.fixture.persistence,
+        &setup.fixture.metadata,
+        area_id,
+        round_id,
+    )
+    .unwrap()
+    .slots[0]
+        .offers[0]
+        .waitlist_offer_id
+}
+
+fn accept_as(
+    setup: &mut Setup,
+    operator: &OperatorData,
+    waitlist_offer_id: i64,
+) -> Result<WaitlistOfferResponse, ApiError> {
+    accept_waitlist_offer(
+        &mut setup.fixture.persistence,
+        &setup.fixture.metadata,
+        &AcceptWaitlistOfferRequest {
+            waitlist_offer_id,
+            received_via: String::from("phone"),
+        },
+        &create_test_bidder(),
+        operator,
+        create_test_cause(),
+    )
+}
+
+fn decline_as(
+    setup: &mut Setup,
+    operator: &OperatorData,
+    waitlist_offer_id: i64,
+) -> Result<WaitlistOfferResponse, ApiError> {
+    decline_waitlist_offer(
+        &mut setup.fixture.persistence,
+        &setup.fixture.metadata,
+        &DeclineWaitlistOfferRequest { waitlist_offer_id },
+        &create_test_bidder(),
+        operator,
+        &create_test_cause(),
+    )
+}
+
+#[test]
+fn test_area_reps_bid_only_in_their_areas() {
+    let mut setup = setup();
+    let north_rep: OperatorData = setup.north_rep.clone();
+    let unassigned: OperatorData = setup.unassigned.clone();
+
+    assert!(may_bid_in(&mut setup, &north_rep, "North"));
+    assert!(!may_bid_in(&mut setup, &north_rep, "South"));
+    assert!(may_bid_in(&mut setup, &unassigned, "North"));
+    assert!(may_bid_in(&mut setup, &unassigned, "South"));
+
+    // Admins are never area-scoped
+    let admin_actor = AuthenticatedActor::new(String::from("admin"), Role::Admin);
+    let south_id: i64 = setup.fixture.area_id("South");
+    AuthorizationService::authorize_area_bidding(
+        &mut setup.fixture.persistence,
+        &admin_actor,
+        &north_rep,
+        south_id,
+        "enter leave bid",
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_delegation_grants_authority_only_between_its_dates() {
+    let mut setup = setup();
+    let south_rep: OperatorData = setup.south_rep.clone();
+
+    let response: CreateAreaDelegationResponse = delegate_north(
+        &mut setup,
+        south_rep.operator_id,
+        "2026-03-05",
+        "2026-03-10",
+    )
+    .unwrap();
+    assert_eq!(response.delegation.area_code, "NORTH");
+    assert_eq!(response.delegation.delegator_login_name, "NORTH-REP");
+    assert_eq!(response.delegation.delegate_login_name, "SOUTH-REP");
+    assert_eq!(response.delegation.status, "scheduled");
+
+    assert!(!may_bid_in(&mut setup, &south_rep, "North"));
+
+    setup.clock.advance(Duration::days(4));
+    assert!(may_bid_in(&mut setup, &south_rep, "North"));
+    assert!(may_bid_in(&mut setup, &south_rep, "South"));
+
+    setup.clock.advance(Duration::days(5));
+    assert!(may_bid_in(&mut setup, &south_rep, "North"));
+
+    // The delegation lapses the day after its end date
+    setup.clock.advance(Duration::days(1));
+    assert!(!may_bid_in(&mut setup, &south_rep, "North"));
+
+    let listed: ListAreaDelegationsResponse = list_area_delegations(
+        &mut setup.fixture.persistence,
+        &setup.fixture.metadata,
+        &create_test_admin(),
+    )
+    .unwrap();
+    assert_eq!(listed.delegations.len(), 1);
+    assert_eq!(listed.delegations[0].status, "expired");
+}
+
+#[test]
+fn test_revoked_delegation_no_longer_grants_authority() {
+    let mut setup = setup();
+    let south_rep: OperatorData = setup.south_rep.clone();
+
+    let created: CreateAreaDelegationResponse = delegate_north(
+        &mut setup,
+        south_rep.operator_id,
+        "2026-03-01",
+        "2026-03-31",
+    )
+    .unwrap();
+    assert_eq!(created.delegation.status, "active");
+    assert!(may_bid_in(&mut setup, &south_rep, "North"));
+
+    let area_delegation_id: i64 = created.delegation.area_delegation_id;
+    let admin: OperatorData = setup.admin.clone();
+    revoke_area_delegation(
+        &mut setup.fixture.persistence,
+        &RevokeAreaDelegationRequest { area_delegation_id },
+        &create_test_admin(),
+        &admin,
+        create_test_cause(),
+    )
+    .unwrap();
+    assert!(!may_bid_in(&mut setup, &south_rep, "North"));
+
+    let events: Vec<AuditEvent> = setup.fixture.persistence.get_global_audit_events().unwrap();
+    let names: Vec<&str> = events
+        .iter()
+        .map(|event| event.action.name.as_str())
+        .filter(|name| name.ends_with("AreaDelegation"))
+        .collect();
+    assert_eq!(names, vec!["CreateAreaDelegation", "RevokeAreaDelegation"]);
+
+    let result = revoke_area_delegation(
+        &mut setup.fixture.persistence,
+        &RevokeAreaDelegationRequest { area_delegation_id },
+        &create_test_admin(),
+        &admin,
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
+
+#[test]
+fn test_create_delegation_validation() {
+    let mut setup = setup();
+    let south_rep_id: i64 = setup.south_rep.operator_id;
+    let north_rep_id: i64 = setup.north_rep.operator_id;
+
+    // Only the area's rep can hand over its authority
+    let request = CreateAreaDelegationRequest {
+        area_id: setup.fixture.area_id("North"),
+        delegator_operator_id: south_rep_id,
+        delegate_operator_id: setup.unassigned.operator_id,
+        start_date: String::from("2026-03-01"),
+        end_date: String::from("2026-03-02"),
+    };
+    let result = create_area_delegation(
+        &mut setup.fixture.persistence,
+        &setup.fixture.metadata,
+        &request,
+        &create_test_admin(),
+        &setup.admin,
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "delegator_must_be_area_rep"
+    ));
+
+    // Delegates must be Bidders other than the rep
+    let admin_id: i64 = setup.admin.operator_id;
+    assert!(matches!(
+        delegate_north(&mut setup, admin_id, "2026-03-01", "2026-03-02"),
+        Err(ApiError::InvalidInput { .. })
+    ));
+    assert!(matches!(
+        delegate_north(&mut setup, north_rep_id, "2026-03-01", "2026-03-02"),
+        Err(ApiError::InvalidInput { .. })
+    ));
+
+    // Dates must be ordered, bounded, and not already over
+    assert!(matches!(
+        delegate_north(&mut setup, south_rep_id, "2026-03-10", "2026-03-05"),
+        Err(ApiError::InvalidInput { .. })
+    ));
+    assert!(matches!(
+        delegate_north(&mut setup, south_rep_id, "2026-03-01", "2027-06-01"),
+        Err(ApiError::InvalidInput { .. })
+    ));
+    assert!(matches!(
+        delegate_north(&mut setup, south_rep_id, "2026-02-01", "2026-02-28"),
+        Err(ApiError::InvalidInput { .. })
+    ));
+    assert!(matches!(
+        delegate_north(&mut setup, south_rep_id, "March 5", "2026-03-10"),
+        Err(ApiError::InvalidInput { .. })
+    ));
+
+    // Admin only
+    let request = CreateAreaDelegationRequest {
+        area_id: setup.fixture.area_id("North"),
+        delegator_operator_id: north_rep_id,
+        delegate_operator_id: south_rep_id,
+        start_date: String::from("2026-03-01"),
+        end_date: String::from("2026-03-02"),
+    };
+    let result = create_area_delegation(
+        &mut setup.fixture.persistence,
+        &setup.fixture.metadata,
+        &request,
+        &create_test_bidder(),
+        &setup.north_rep,
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
+
+#[test]
+fn test_withdrawing_leave_requires_area_authority() {
+    let mut setup = setup();
+    let leave_bid_id: i64 = hold_north_leave(&mut setup);
+    let south_rep: OperatorData = setup.south_rep.clone();
+
+    let result = withdraw_as(&mut setup, &south_rep, leave_bid_id);
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    delegate_north(
+        &mut setup,
+        south_rep.operator_id,
+        "2026-03-01",
+        "2026-03-31",
+    )
+    .unwrap();
+    let response: WithdrawLeaveBidResponse =
+        withdraw_as(&mut setup, &south_rep, leave_bid_id).unwrap();
+    assert_eq!(
+        response.offered_to_user_id,
+        Some(setup.fixture.user_id("AB"))
+    );
+}
+
+#[test]
+fn test_accepting_waitlist_offer_requires_area_authority() {
+    let mut setup = setup();
+    let offer_id: i64 = offer_north_slot(&mut setup);
+    let south_rep: OperatorData = setup.south_rep.clone();
+
+    let result = accept_as(&mut setup, &south_rep, offer_id);
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    delegate_north(
+        &mut setup,
+        south_rep.operator_id,
+        "2026-03-01",
+        "2026-03-31",
+    )
+    .unwrap();
+    let response: WaitlistOfferResponse = accept_as(&mut setup, &south_rep, offer_id).unwrap();
+    assert!(response.leave_bid_id.is_some());
+}
+
+#[test]
+fn test_declining_waitlist_offer_requires_area_authority() {
+    let mut setup = setup();
+    let offer_id: i64 = offer_north_slot(&mut setup);
+    let south_rep: OperatorData = setup.south_rep.clone();
+
+    let result = decline_as(&mut setup, &south_rep, offer_id);
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    delegate_north(
+        &mut setup,
+        south_rep.operator_id,
+        "2026-03-01",
+        "2026-03-31",
+    )
+    .unwrap();
+    let response: WaitlistOfferResponse = decline_as(&mut setup, &south_rep, offer_id).unwrap();
+    assert_eq!(
+        response.offered_to_user_id,
+        Some(setup.fixture.user_id("AC"))
+    );
+}
@@ -0,0 +1,238 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for compacting a closed bid year's audit log.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_cause,
+};
+use crate::{
+    CheckpointRequest, CompactAuditLogRequest, CompactAuditLogResponse, GetAuditCompactionResponse,
+    checkpoint, compact_audit_log, get_audit_compaction,
+};
+use zab_bid::{BootstrapMetadata, State, TransitionResult};
+use zab_bid_audit::AuditEvent;
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_persistence::{OutboxEntryData, SqlitePersistence};
+use zab_bid_test_support::BidYearFixture;
+
+/// Creates 2026/North with users AA and AB.
+fn setup() -> SqlitePersistence {
+    BidYearFixture::new(2026)
+        .with_users(2)
+        .persist()
+        .unwrap()
+        .persistence
+}
+
+/// Returns the IDs of the fixture's user registration events.
+fn registrations(persistence: &mut SqlitePersistence) -> Vec<i64> {
+    persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+        .unwrap()
+        .iter()
+        .filter(|event| event.action.name == "RegisterUser")
+        .filter_map(|event| event.event_id)
+        .collect()
+}
+
+fn snapshot(persistence: &mut SqlitePersistence) -> i64 {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let state: State = persistence
+        .get_current_state(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    let result: TransitionResult = checkpoint(
+        persistence,
+        &metadata,
+        &state,
+        &CheckpointRequest::default(),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    persistence.persist_transition(&result).unwrap().event_id
+}
+
+/// Marks every pending outbox row delivered, as the relay would, except the
+/// rows for the `held` events.
+fn deliver_outbox(persistence: &mut SqlitePersistence, held: &[i64]) {
+    let pending: Vec<OutboxEntryData> = persistence.list_pending_outbox_entries(10_000).unwrap();
+    let outbox_ids: Vec<i64> = pending
+        .iter()
+        .filter(|entry| !held.contains(&entry.audit_event_id))
+        .map(|entry| entry.outbox_id)
+        .collect();
+    persistence
+        .mark_outbox_entries_delivered(&outbox_ids)
+        .unwrap();
+}
+
+fn bid_year_id(persistence: &mut SqlitePersistence) -> i64 {
+    persistence
+        .get_bootstrap_metadata()
+        .unwrap()
+        .bid_years
+        .iter()
+        .find(|by| by.year() == 2026)
+        .and_then(BidYear::bid_year_id)
+        .unwrap()
+}
+
+fn close(persistence: &mut SqlitePersistence) {
+    let bid_year_id: i64 = bid_year_id(persistence);
+    persistence
+        .update_lifecycle_state(bid_year_id, "BiddingClosed")
+        .unwrap();
+}
+
+fn compact(persistence: &mut SqlitePersistence) -> Result<CompactAuditLogResponse, ApiError> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year_id: i64 = bid_year_id(persistence);
+    compact_audit_log(
+        persistence,
+        &metadata,
+        &CompactAuditLogRequest { bid_year_id },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        &create_test_cause(),
+    )
+}
+
+fn timeline_event_ids(persistence: &mut SqlitePersistence) -> Vec<i64> {
+    persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+        .unwrap()
+        .iter()
+        .filter_map(|event| event.event_id)
+        .collect()
+}
+
+#[test]
+fn test_compaction_archives_unreferenced_events_and_seals_state() {
+    let mut persistence: SqlitePersistence = setup();
+    let registered: Vec<i64> = registrations(&mut persistence);
+    let checkpoint_event_id: i64 = snapshot(&mut persistence);
+    deliver_outbox(&mut persistence, &[]);
+    close(&mut persistence);
+
+    let response: CompactAuditLogResponse = compact(&mut persistence).unwrap();
+
+    let live: Vec<i64> = timeline_event_ids(&mut persistence);
+    assert!(registered.iter().all(|event_id| !live.contains(event_id)));
+    assert!(live.contains(&checkpoint_event_id));
+    assert!(response.compaction.archived_event_count >= registered.len());
+    assert_eq!(response.compaction.chain_head.len(), 64);
+
+    let digest_event_id: i64 = *response.digest_event_ids.first().unwrap();
+    let digest: AuditEvent = persistence.get_audit_event(digest_event_id).unwrap();
+    assert_eq!(digest.action.name, "AuditLogCompacted");
+    assert!(
+        digest
+            .action
+            .details
+            .as_deref()
+            .unwrap()
+            .contains("RegisterUser x2")
+    );
+    let (sealed, sealed_event_id): (State, i64) = persistence
+        .get_latest_snapshot(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    assert_eq!(sealed_event_id, digest_event_id);
+    assert_eq!(sealed.users.len(), 2);
+}
+
+#[test]
+fn test_undelivered_events_stay_in_live_log() {
+    let mut persistence: SqlitePersistence = setup();
+    let registered: Vec<i64> = registrations(&mut persistence);
+    let (delivered, pending): (i64, i64) = (registered[0], registered[1]);
+    deliver_outbox(&mut persistence, &[pending]);
+    close(&mut persistence);
+
+    compact(&mut persistence).unwrap();
+
+    let live: Vec<i64> = timeline_event_ids(&mut persistence);
+    assert!(!live.contains(&delivered));
+    assert!(live.contains(&pending));
+}
+
+#[test]
+fn test_compaction_requires_closed_bid_year() {
+    let mut persistence: SqlitePersistence = setup();
+
+    let result: Result<CompactAuditLogResponse, ApiError> = compact(&mut persistence);
+
+    assert!(matches!(result, Err(ApiError::DomainRuleViolation { .. })));
+}
+
+#[test]
+fn test_bid_year_is_compacted_only_once() {
+    let mut persistence: SqlitePersistence = setup();
+    close(&mut persistence);
+    compact(&mut persistence).unwrap();
+
+    let again: Result<CompactAuditLogResponse, ApiError> = compact(&mut persistence);
+
+    assert!(matches!(again, Err(ApiError::DomainRuleViolation { .. })));
+}
+
+#[test]
+fn test_get_compaction_verifies_archive() {
+    let mut persistence: SqlitePersistence = setup();
+    deliver_outbox(&mut persistence, &[]);
+    close(&mut persistence);
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year_id: i64 = bid_year_id(&mut persistence);
+
+    let before: GetAuditCompactionResponse = get_audit_compaction(
+        &mut persistence,
+        &metadata,
+        bid_year_id,
+        &create_test_admin(),
+    )
+    .unwrap();
+    let compacted: CompactAuditLogResponse = compact(&mut persistence).unwrap();
+    let after: GetAuditCompactionResponse = get_audit_compaction(
+        &mut persistence,
+        &metadata,
+        bid_year_id,
+        &create_test_admin(),
+    )
+    .unwrap();
+
+    assert_eq!(before.compaction, None);
+    assert_eq!(after.compaction, Some(compacted.compaction.clone()));
+    let verification = after.verification.unwrap();
+    assert!(verification.is_intact);
+    assert_eq!(
+        verification.events_checked,
+        compacted.compaction.archived_event_count
+    );
+    assert_eq!(
+        verification.recomputed_head,
+        compacted.compaction.chain_head
+    );
+}
+
+#[test]
+fn test_bidder_cannot_compact_audit_log() {
+    let mut persistence: SqlitePersistence = setup();
+    close(&mut persistence);
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year_id: i64 = bid_year_id(&mut persistence);
+
+    let result: Result<CompactAuditLogResponse, ApiError> = compact_audit_log(
+        &mut persistence,
+        &metadata,
+        &CompactAuditLogRequest { bid_year_id },
+        &create_test_bidder(),
+        &create_test_admin_operator(),
+        &create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
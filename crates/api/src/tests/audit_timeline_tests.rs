@@ -0,0 +1,222 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the paged audit timeline API.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    bootstrap_with_ids, create_test_admin, create_test_cause, setup_test_persistence,
+};
+use crate::{
+    AnnotateAuditEventRequest, AuditTimelineFilter, AuditTimelinePageRequest, AuditTimelineScope,
+    DisableOperatorRequest, GetAuditTimelineResponse, annotate_audit_event, disable_operator,
+    get_audit_timeline,
+};
+use zab_bid_persistence::{OperatorData, SqlitePersistence};
+
+#[test]
+fn test_get_audit_timeline_global_includes_actor_and_diff_summary() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    let admin_id: i64 = persistence
+        .create_operator("admin1", "Admin One", "password", "Admin")
+        .unwrap();
+    let admin_operator: OperatorData = persistence.get_operator_by_id(admin_id).unwrap().unwrap();
+    let target_id: i64 = persistence
+        .create_operator("target", "Target Operator", "password", "Bidder")
+        .unwrap();
+
+    disable_operator(
+        &mut persistence,
+        DisableOperatorRequest {
+            operator_id: target_id,
+        },
+        &create_test_admin(),
+        &admin_operator,
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let response: GetAuditTimelineResponse = get_audit_timeline(
+        &mut persistence,
+        AuditTimelineScope::Global,
+        &AuditTimelineFilter::default(),
+        AuditTimelinePageRequest::default(),
+    )
+    .unwrap();
+
+    assert_eq!(response.entries.len(), 1);
+    assert!(response.next_cursor.is_none());
+
+    let entry = &response.entries[0];
+    assert_eq!(entry.action_name, "DisableOperator");
+    assert_eq!(entry.actor.operator_id, Some(admin_id));
+    assert_eq!(entry.actor.login_name.as_deref(), Some("ADMIN1"));
+    assert!(entry.created_at.is_some());
+    assert!(entry.bid_year.is_none());
+    assert_eq!(entry.diff_summary.len(), 1);
+    assert_eq!(entry.diff_summary[0].field, "is_disabled");
+    assert_eq!(entry.diff_summary[0].before.as_deref(), Some("false"));
+    assert_eq!(entry.diff_summary[0].after.as_deref(), Some("true"));
+}
+
+#[test]
+fn test_get_audit_timeline_pages_bid_year_scope() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let operator_id: i64 = persistence
+        .create_operator("pager", "Pager", "password", "Admin")
+        .unwrap();
+    let ids = bootstrap_with_ids(&mut persistence, 2027, "South", operator_id).unwrap();
+    let scope: AuditTimelineScope = AuditTimelineScope::BidYear {
+        bid_year_id: ids.bid_year_id,
+    };
+
+    let first: GetAuditTimelineResponse = get_audit_timeline(
+        &mut persistence,
+        scope,
+        &AuditTimelineFilter::default(),
+        AuditTimelinePageRequest {
+            after_event_id: None,
+            limit: Some(1),
+        },
+    )
+    .unwrap();
+    assert_eq!(first.entries.len(), 1);
+    assert!(first.next_cursor.is_some());
+
+    let second: GetAuditTimelineResponse = get_audit_timeline(
+        &mut persistence,
+        scope,
+        &AuditTimelineFilter::default(),
+        AuditTimelinePageRequest {
+            after_event_id: first.next_cursor,
+            limit: Some(1),
+        },
+    )
+    .unwrap();
+    assert_eq!(second.entries.len(), 1);
+    assert!(second.entries[0].event_id > first.entries[0].event_id);
+    assert_eq!(second.entries[0].area_code.as_deref(), Some("SOUTH"));
+}
+
+#[test]
+fn test_get_audit_timeline_rejects_invalid_limit() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+
+    for limit in [0, 501] {
+        let result = get_audit_timeline(
+            &mut persistence,
+            AuditTimelineScope::Global,
+            &AuditTimelineFilter::default(),
+            AuditTimelinePageRequest {
+                after_event_id: None,
+                limit: Some(limit),
+            },
+        );
+
+        match result {
+            Err(ApiError::InvalidInput { field, .. }) => assert_eq!(field, "limit"),
+            other => panic!("Expected InvalidInput error, got: {other:?}"),
+        }
+    }
+}
+
+/// Creates an admin and one global audit event, returning the admin and the
+/// event ID.
+fn setup_global_event(persistence: &mut SqlitePersistence) -> (OperatorData, i64) {
+    let admin_id: i64 = persistence
+        .create_operator("admin1", "Admin One", "password", "Admin")
+        .unwrap();
+    let admin_operator: OperatorData = persistence.get_operator_by_id(admin_id).unwrap().unwrap();
+    let target_id: i64 = persistence
+        .create_operator("target", "Target Operator", "password", "Bidder")
+        .unwrap();
+    disable_operator(
+        persistence,
+        DisableOperatorRequest {
+            operator_id: target_id,
+        },
+        &create_test_admin(),
+        &admin_operator,
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let response: GetAuditTimelineResponse = get_audit_timeline(
+        persistence,
+        AuditTimelineScope::Global,
+        &AuditTimelineFilter::default(),
+        AuditTimelinePageRequest::default(),
+    )
+    .unwrap();
+    (admin_operator, response.entries[0].event_id)
+}
+
+#[test]
+fn test_annotations_are_listed_inline_without_changing_the_event() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    let (admin_operator, event_id) = setup_global_event(&mut persistence);
+    let before: GetAuditTimelineResponse = get_audit_timeline(
+        &mut persistence,
+        AuditTimelineScope::Global,
+        &AuditTimelineFilter::default(),
+        AuditTimelinePageRequest::default(),
+    )
+    .unwrap();
+    assert!(before.entries[0].annotations.is_empty());
+
+    for note in ["  Left the facility  ", "Confirmed with the union"] {
+        annotate_audit_event(
+            &mut persistence,
+            &AnnotateAuditEventRequest {
+                event_id,
+                note: String::from(note),
+            },
+            &admin_operator,
+        )
+        .unwrap();
+    }
+
+    let after: GetAuditTimelineResponse = get_audit_timeline(
+        &mut persistence,
+        AuditTimelineScope::Global,
+        &AuditTimelineFilter::default(),
+        AuditTimelinePageRequest::default(),
+    )
+    .unwrap();
+    let entry = &after.entries[0];
+    assert_eq!(entry.annotations.len(), 2);
+    assert_eq!(entry.annotations[0].note, "Left the facility");
+    assert_eq!(entry.annotations[0].operator_id, admin_operator.operator_id);
+    assert_eq!(entry.annotations[0].login_name, "ADMIN1");
+    assert_eq!(entry.annotations[1].note, "Confirmed with the union");
+    assert_eq!(entry.action_details, before.entries[0].action_details);
+    assert_eq!(entry.diff_summary, before.entries[0].diff_summary);
+}
+
+#[test]
+fn test_annotate_audit_event_rejects_blank_notes_and_unknown_events() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    let (admin_operator, event_id) = setup_global_event(&mut persistence);
+
+    let blank = annotate_audit_event(
+        &mut persistence,
+        &AnnotateAuditEventRequest {
+            event_id,
+            note: String::from("   "),
+        },
+        &admin_operator,
+    );
+    assert!(matches!(blank, Err(ApiError::InvalidInput { .. })));
+
+    let unknown = annotate_audit_event(
+        &mut persistence,
+        &AnnotateAuditEventRequest {
+            event_id: event_id + 100,
+            note: String::from("Why"),
+        },
+        &admin_operator,
+    );
+    assert!(matches!(unknown, Err(ApiError::ResourceNotFound { .. })));
+}
@@ -10,8 +10,8 @@
 use zab_bid::BootstrapMetadata;
 
 use crate::{
-    ApiError, CreateAreaRequest, CreateBidYearRequest, SetActiveBidYearRequest,
-    TransitionToBiddingActiveRequest, TransitionToBiddingClosedRequest,
+    ApiError, CheckpointRequest, CreateAreaRequest, CreateBidYearRequest, RollbackTarget,
+    SetActiveBidYearRequest, TransitionToBiddingActiveRequest, TransitionToBiddingClosedRequest,
     TransitionToBootstrapCompleteRequest, TransitionToCanonicalizedRequest, UpdateAreaRequest,
     UpdateBidYearMetadataRequest, UpdateUserRequest, checkpoint, create_area, create_bid_year,
     finalize, rollback, set_active_bid_year, transition_to_bidding_active,
@@ -395,6 +395,7 @@ fn test_checkpoint_rejects_bidder() {
         &mut persistence,
         &metadata,
         &state,
+        &CheckpointRequest::default(),
         &bidder,
         &operator,
         cause,
@@ -475,7 +476,8 @@ fn test_rollback_rejects_bidder() {
         &mut persistence,
         &metadata,
         &state,
-        1,
+        &RollbackTarget::EventId(1),
+        false,
         &bidder,
         &operator,
         cause,
@@ -0,0 +1,304 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for pre-submitted bid preference lists.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_admin, create_test_admin_operator,
+    create_test_bidder, create_test_bidder_operator, create_test_cause,
+};
+use crate::{
+    AdvanceBidderRequest, AdvanceBidderResponse, BidPreferenceEntry, EnterLeaveBidRequest,
+    ListBidPreferencesResponse, SubmitBidPreferencesRequest, SubmitBidPreferencesResponse,
+    advance_bidder, enter_leave_bid, list_bid_preferences, submit_bid_preferences,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::AuditEvent;
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_persistence::{LeaveBidData, NewBidWindow};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates a `BiddingActive` 2026/North with AA and AB bidding in that
+/// order in one round with a single slot per day.
+fn setup_bidding() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(2)
+        .with_rounds(1)
+        .with_slots_per_day(1)
+        .with_lifecycle_state("BiddingActive")
+        .persist()
+        .unwrap();
+    // Bids are entered as the test bidder operator, which audit events reference
+    create_persisted_bidder_operator(&mut fixture.persistence).unwrap();
+
+    let windows: Vec<NewBidWindow> = ["AA", "AB"]
+        .iter()
+        .enumerate()
+        .map(|(hour, initials)| NewBidWindow {
+            bid_year_id: fixture.bid_year_id,
+            area_id: fixture.area_id("North"),
+            user_id: fixture.user_id(initials),
+            round_id: fixture.round_ids[0],
+            window_start_datetime: format!("2026-03-02T{:02}:00:00Z", 13 + hour),
+            window_end_datetime: format!("2026-03-02T{:02}:00:00Z", 14 + hour),
+        })
+        .collect();
+    fixture
+        .persistence
+        .bulk_insert_bid_windows(&windows)
+        .unwrap();
+
+    fixture
+}
+
+fn request(
+    fixture: &PersistedFixture,
+    initials: &str,
+    choices: &[&[&str]],
+) -> SubmitBidPreferencesRequest {
+    SubmitBidPreferencesRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: initials.to_string(),
+        received_via: String::from("written"),
+        preferences: choices
+            .iter()
+            .map(|dates| BidPreferenceEntry {
+                leave_dates: dates.iter().map(ToString::to_string).collect(),
+                hours: 8,
+            })
+            .collect(),
+    }
+}
+
+fn submit(
+    fixture: &mut PersistedFixture,
+    request: &SubmitBidPreferencesRequest,
+) -> Result<SubmitBidPreferencesResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    submit_bid_preferences(
+        &mut fixture.persistence,
+        &metadata,
+        request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+}
+
+fn advance(fixture: &mut PersistedFixture) -> AdvanceBidderResponse {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: AdvanceBidderRequest = AdvanceBidderRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+    };
+    advance_bidder(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap()
+}
+
+fn preferences(fixture: &mut PersistedFixture) -> ListBidPreferencesResponse {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let area_id: i64 = fixture.area_id("North");
+    let round_id: i64 = fixture.round_ids[0];
+    list_bid_preferences(&mut fixture.persistence, &metadata, area_id, round_id).unwrap()
+}
+
+#[test]
+fn test_window_opening_applies_first_preference_that_fits() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let ab_preferences: SubmitBidPreferencesRequest = request(
+        &fixture,
+        "AB",
+        &[
+            &["2026-06-01", "2026-06-02"],
+            &["2026-07-01"],
+            &["2026-08-01"],
+        ],
+    );
+    let response: SubmitBidPreferencesResponse = submit(&mut fixture, &ab_preferences).unwrap();
+    assert_eq!(response.preference_count, 3);
+    let ab: i64 = response.user_id;
+
+    // AA's window opens and AA takes the only slot on June 1
+    assert_eq!(advance(&mut fixture).applied_preference_rank, None);
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let leave_bid: EnterLeaveBidRequest = EnterLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from("AA"),
+        received_via: String::from("phone"),
+        leave_dates: vec![String::from("2026-06-01")],
+        hours: 8,
+        override_reason: None,
+    };
+    enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &leave_bid,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    // AB's window opens; the June choice no longer fits
+    let advanced: AdvanceBidderResponse = advance(&mut fixture);
+    assert_eq!(advanced.next_user_id, Some(ab));
+    assert_eq!(advanced.applied_preference_rank, Some(2));
+
+    let statuses: Vec<(i32, String)> = preferences(&mut fixture)
+        .preferences
+        .into_iter()
+        .map(|p| (p.preference_rank, p.status))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            (1, String::from("passed_over")),
+            (2, String::from("applied")),
+            (3, String::from("unused")),
+        ]
+    );
+
+    let ab_leave: Vec<LeaveBidData> = fixture
+        .persistence
+        .list_leave_bids(fixture.bid_year_id, fixture.area_id("North"))
+        .unwrap()
+        .into_iter()
+        .filter(|bid| bid.user_id == ab)
+        .collect();
+    assert_eq!(ab_leave.len(), 1);
+    assert_eq!(ab_leave[0].leave_date, "2026-07-01");
+    assert_eq!(ab_leave[0].on_behalf_of.as_deref(), Some("AB"));
+    assert_eq!(ab_leave[0].received_via.as_deref(), Some("written"));
+
+    let timeline: Vec<AuditEvent> = fixture
+        .persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    let applied: &AuditEvent = timeline
+        .iter()
+        .find(|e| e.action.name == "ApplyBidPreference")
+        .unwrap();
+    assert!(
+        applied
+            .after
+            .data
+            .ends_with("applied=2:2026-07-01@8,passed_over=1")
+    );
+}
+
+#[test]
+fn test_resubmitting_replaces_pending_preferences() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let first: SubmitBidPreferencesRequest =
+        request(&fixture, "AB", &[&["2026-06-01"], &["2026-07-01"]]);
+    submit(&mut fixture, &first).unwrap();
+    let second: SubmitBidPreferencesRequest = request(&fixture, "AB", &[&["2026-09-01"]]);
+    submit(&mut fixture, &second).unwrap();
+
+    let stored: ListBidPreferencesResponse = preferences(&mut fixture);
+    assert_eq!(stored.preferences.len(), 1);
+    assert_eq!(stored.preferences[0].leave_dates, vec!["2026-09-01"]);
+    assert_eq!(stored.preferences[0].status, "pending");
+}
+
+#[test]
+fn test_preferences_rejected_once_window_opened() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    advance(&mut fixture);
+
+    let opened: SubmitBidPreferencesRequest = request(&fixture, "AA", &[&["2026-06-01"]]);
+    let result = submit(&mut fixture, &opened);
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "bid_preference"
+    ));
+
+    // AB's window has not opened yet
+    let waiting: SubmitBidPreferencesRequest = request(&fixture, "AB", &[&["2026-06-01"]]);
+    assert!(submit(&mut fixture, &waiting).is_ok());
+}
+
+#[test]
+fn test_invalid_preference_lists_are_rejected() {
+    let mut fixture: PersistedFixture = setup_bidding();
+
+    for choices in [
+        Vec::new(),
+        vec![["2026-06-01", "2026-06-01"].as_slice()],
+        vec![[].as_slice()],
+    ] {
+        let invalid: SubmitBidPreferencesRequest = request(&fixture, "AB", &choices);
+        let result = submit(&mut fixture, &invalid);
+        assert!(matches!(
+            result,
+            Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "bid_preference"
+        ));
+    }
+    assert!(preferences(&mut fixture).preferences.is_empty());
+}
+
+#[test]
+fn test_operator_cannot_submit_own_preferences() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    fixture
+        .persistence
+        .set_operator_controller(create_test_bidder_operator().operator_id, Some("AB"))
+        .unwrap();
+
+    let ab_preferences: SubmitBidPreferencesRequest = request(&fixture, "AB", &[&["2026-06-01"]]);
+    assert!(matches!(
+        submit(&mut fixture, &ab_preferences),
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "self_bid"
+    ));
+    assert!(preferences(&mut fixture).preferences.is_empty());
+}
+
+#[test]
+fn test_operator_cannot_apply_own_preferences() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let ab_preferences: SubmitBidPreferencesRequest = request(&fixture, "AB", &[&["2026-06-01"]]);
+    submit(&mut fixture, &ab_preferences).unwrap();
+    fixture
+        .persistence
+        .set_operator_controller(create_test_admin_operator().operator_id, Some("AB"))
+        .unwrap();
+    advance(&mut fixture);
+
+    // Opening AB's window would turn AB's preferences into leave
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: AdvanceBidderRequest = AdvanceBidderRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+    };
+    let result: Result<AdvanceBidderResponse, ApiError> = advance_bidder(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "self_bid"
+    ));
+    assert!(
+        fixture
+            .persistence
+            .list_leave_bids(fixture.bid_year_id, fixture.area_id("North"))
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -0,0 +1,165 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for per-bid-year bid validation rules.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_admin, create_test_admin_operator,
+    create_test_bidder, create_test_bidder_operator, create_test_cause,
+};
+use crate::{
+    BidRuleInfo, EnterLeaveBidRequest, ListBidRulesResponse, SetBidRulesRequest,
+    SetBidRulesResponse, enter_leave_bid, list_bid_rules, set_bid_rules,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with user AA and one round offering two slots per
+/// day. The bid year is left in `Draft` so rules can still be set.
+fn setup() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(1)
+        .with_rounds(1)
+        .persist()
+        .unwrap();
+    // Bids are entered as the test bidder operator, which audit events reference
+    create_persisted_bidder_operator(&mut fixture.persistence).unwrap();
+    fixture
+        .persistence
+        .update_round(fixture.round_ids[0], "Round 1", 2, 3, 200, false, false)
+        .unwrap();
+    fixture
+}
+
+fn rule(kind: &str, value: Option<u32>, dates: &[&str]) -> BidRuleInfo {
+    BidRuleInfo {
+        kind: String::from(kind),
+        value,
+        dates: dates.iter().map(|date| String::from(*date)).collect(),
+    }
+}
+
+fn set(
+    fixture: &mut PersistedFixture,
+    rules: Vec<BidRuleInfo>,
+) -> Result<SetBidRulesResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    set_bid_rules(
+        &mut fixture.persistence,
+        &metadata,
+        &SetBidRulesRequest {
+            bid_year_id: fixture.bid_year_id,
+            rules,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn enter(fixture: &mut PersistedFixture, dates: &[&str]) -> Result<(), ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: EnterLeaveBidRequest = EnterLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from("AA"),
+        received_via: String::from("phone"),
+        leave_dates: dates.iter().map(|date| String::from(*date)).collect(),
+        hours: 8,
+        override_reason: None,
+    };
+    enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+    .map(|_| ())
+}
+
+fn start_bidding(fixture: &mut PersistedFixture) {
+    fixture
+        .persistence
+        .update_lifecycle_state(fixture.bid_year_id, "BiddingActive")
+        .unwrap();
+}
+
+#[test]
+fn test_rules_are_stored_and_listed_in_order() {
+    let mut fixture: PersistedFixture = setup();
+    set(
+        &mut fixture,
+        vec![
+            rule("min_consecutive_days", Some(2), &[]),
+            rule("holiday_pairing", None, &["2026-12-25", "2026-07-03"]),
+        ],
+    )
+    .unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let listed: ListBidRulesResponse =
+        list_bid_rules(&mut fixture.persistence, &metadata, fixture.bid_year_id).unwrap();
+    assert_eq!(
+        listed.rules,
+        vec![
+            rule("min_consecutive_days", Some(2), &[]),
+            rule("holiday_pairing", None, &["2026-07-03", "2026-12-25"]),
+        ]
+    );
+
+    // Unknown kinds and missing parameters are refused
+    for invalid in [
+        rule("max_hours", Some(40), &[]),
+        rule("max_splits_per_round", None, &[]),
+    ] {
+        let result: Result<SetBidRulesResponse, ApiError> = set(&mut fixture, vec![invalid]);
+        assert!(matches!(
+            result,
+            Err(ApiError::InvalidInput { ref field, .. }) if field == "rules"
+        ));
+    }
+
+    // Rules are fixed once bidding starts
+    start_bidding(&mut fixture);
+    assert!(matches!(
+        set(&mut fixture, Vec::new()),
+        Err(ApiError::DomainRuleViolation { .. })
+    ));
+}
+
+#[test]
+fn test_leave_bid_checked_against_rules() {
+    let mut fixture: PersistedFixture = setup();
+    set(
+        &mut fixture,
+        vec![
+            rule("min_consecutive_days", Some(2), &[]),
+            rule("max_splits_per_round", Some(1), &[]),
+        ],
+    )
+    .unwrap();
+    start_bidding(&mut fixture);
+
+    let result: Result<(), ApiError> = enter(&mut fixture, &["2026-06-01"]);
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "min_consecutive_days"
+    ));
+
+    enter(&mut fixture, &["2026-06-01", "2026-06-02"]).unwrap();
+
+    // A second run breaks the split limit even though it is long enough
+    let result: Result<(), ApiError> = enter(&mut fixture, &["2026-06-10", "2026-06-11"]);
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "max_splits_per_round"
+    ));
+
+    // Extending the held run is still one run
+    enter(&mut fixture, &["2026-06-03"]).unwrap();
+}
@@ -0,0 +1,177 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the live bidding progress of an area.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{create_test_admin, create_test_admin_operator, create_test_cause};
+use crate::{
+    AdvanceBidderRequest, GetBiddingProgressRequest, GetBiddingProgressResponse, advance_bidder,
+    get_bidding_progress,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_persistence::{NewBidStatus, NewBidWindow};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Fixture users, in window order.
+const BIDDERS: [&str; 3] = ["AA", "AB", "AC"];
+
+/// Creates a `BiddingActive` 2026/North with one round and three
+/// consecutive one-hour windows starting 2026-03-02 13:00 UTC.
+fn setup_bidding() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(3)
+        .with_rounds(1)
+        .with_lifecycle_state("BiddingActive")
+        .persist()
+        .unwrap();
+
+    let windows: Vec<NewBidWindow> = BIDDERS
+        .iter()
+        .enumerate()
+        .map(|(hour, initials)| NewBidWindow {
+            bid_year_id: fixture.bid_year_id,
+            area_id: fixture.area_id("North"),
+            user_id: fixture.user_id(initials),
+            round_id: fixture.round_ids[0],
+            window_start_datetime: format!("2026-03-02T{:02}:00:00Z", 13 + hour),
+            window_end_datetime: format!("2026-03-02T{:02}:00:00Z", 14 + hour),
+        })
+        .collect();
+    fixture
+        .persistence
+        .bulk_insert_bid_windows(&windows)
+        .unwrap();
+    fixture
+}
+
+fn advance(fixture: &mut PersistedFixture) {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: AdvanceBidderRequest = AdvanceBidderRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+    };
+    advance_bidder(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+}
+
+/// Seeds a bid status for each fixture user, in window order.
+fn seed_statuses(fixture: &mut PersistedFixture, statuses: &[&str]) {
+    let rows: Vec<NewBidStatus> = BIDDERS
+        .iter()
+        .zip(statuses)
+        .map(|(initials, status)| NewBidStatus {
+            bid_year_id: fixture.bid_year_id,
+            area_id: fixture.area_id("North"),
+            user_id: fixture.user_id(initials),
+            round_id: fixture.round_ids[0],
+            status: (*status).to_string(),
+            updated_at: String::from("2026-03-01T00:00:00Z"),
+            updated_by: 1,
+            notes: None,
+        })
+        .collect();
+    fixture.persistence.bulk_insert_bid_status(&rows).unwrap();
+}
+
+fn progress(
+    fixture: &mut PersistedFixture,
+    area_id: i64,
+    upcoming_count: Option<u32>,
+) -> Result<GetBiddingProgressResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    get_bidding_progress(
+        &mut fixture.persistence,
+        &metadata,
+        &GetBiddingProgressRequest {
+            area_id,
+            upcoming_count,
+        },
+    )
+}
+
+fn initials(response: &GetBiddingProgressResponse) -> Vec<&str> {
+    response
+        .upcoming_bidders
+        .iter()
+        .map(|bidder| bidder.initials.as_str())
+        .collect()
+}
+
+#[test]
+fn test_progress_before_round_starts_lists_first_bidders() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let area_id: i64 = fixture.area_id("North");
+    seed_statuses(&mut fixture, &["not_started_pre_window"; 3]);
+
+    let response: GetBiddingProgressResponse = progress(&mut fixture, area_id, None).unwrap();
+    let round = response.current_round.clone().unwrap();
+    assert_eq!(round.round_id, fixture.round_ids[0]);
+    assert_eq!(round.round_name, "Round 1");
+    assert!(!round.started);
+    assert_eq!(response.current_bidder, None);
+    assert_eq!(initials(&response), vec!["AA", "AB", "AC"]);
+    assert_eq!(
+        response.upcoming_bidders[0].window_start_datetime,
+        "2026-03-02T13:00:00Z"
+    );
+    assert_eq!((response.completed, response.total), (0, 3));
+    assert_eq!(response.percent_complete, 0);
+    assert!(response.recent_events.is_empty());
+}
+
+#[test]
+fn test_progress_follows_the_current_bidder() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let area_id: i64 = fixture.area_id("North");
+    seed_statuses(
+        &mut fixture,
+        &["completed_on_time", "in_progress", "not_started_pre_window"],
+    );
+    advance(&mut fixture);
+    advance(&mut fixture);
+
+    let response: GetBiddingProgressResponse = progress(&mut fixture, area_id, None).unwrap();
+    assert!(response.current_round.as_ref().unwrap().started);
+    assert_eq!(response.current_bidder.as_ref().unwrap().initials, "AB");
+    assert_eq!(initials(&response), vec!["AC"]);
+    assert_eq!((response.completed, response.total), (1, 3));
+    assert_eq!(response.percent_complete, 33);
+
+    // Newest first
+    assert_eq!(response.recent_events.len(), 2);
+    assert!(
+        response
+            .recent_events
+            .iter()
+            .all(|event| event.action == "AdvanceBidder")
+    );
+    assert!(response.recent_events[0].event_id > response.recent_events[1].event_id);
+}
+
+#[test]
+fn test_progress_limits_upcoming_bidders() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let area_id: i64 = fixture.area_id("North");
+
+    let response: GetBiddingProgressResponse = progress(&mut fixture, area_id, Some(1)).unwrap();
+    assert_eq!(initials(&response), vec!["AA"]);
+
+    assert!(matches!(
+        progress(&mut fixture, area_id, Some(51)),
+        Err(ApiError::InvalidInput { .. })
+    ));
+    assert!(matches!(
+        progress(&mut fixture, area_id + 100, None),
+        Err(ApiError::ResourceNotFound { .. })
+    ));
+}
@@ -0,0 +1,189 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for bid year blackout dates.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause, setup_test_persistence,
+};
+use crate::{
+    BlackoutDateResponse, CreateBlackoutDateRequest, ListBlackoutDatesResponse,
+    UpdateBlackoutDateRequest, create_blackout_date, delete_blackout_date, list_blackout_dates,
+    update_blackout_date,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_persistence::SqlitePersistence;
+
+fn create(
+    persistence: &mut SqlitePersistence,
+    date: &str,
+    reason: &str,
+) -> Result<BlackoutDateResponse, ApiError> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    create_blackout_date(
+        persistence,
+        &metadata,
+        &CreateBlackoutDateRequest {
+            bid_year_id,
+            date: String::from(date),
+            reason: String::from(reason),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn list(persistence: &mut SqlitePersistence) -> ListBlackoutDatesResponse {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    list_blackout_dates(persistence, &metadata, bid_year_id).unwrap()
+}
+
+#[test]
+fn test_create_and_list_blackout_dates() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+
+    create(
+        &mut persistence,
+        "2026-07-03",
+        "Independence Day (observed)",
+    )
+    .unwrap();
+    let response: BlackoutDateResponse =
+        create(&mut persistence, "2026-05-25", "  Memorial Day  ").unwrap();
+    assert_eq!(response.blackout_date.reason, "Memorial Day");
+
+    let listed: ListBlackoutDatesResponse = list(&mut persistence);
+    let dates: Vec<&str> = listed
+        .blackout_dates
+        .iter()
+        .map(|b| b.date.as_str())
+        .collect();
+    assert_eq!(dates, vec!["2026-05-25", "2026-07-03"]);
+}
+
+#[test]
+fn test_create_blackout_date_validation() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+
+    let invalid = create(&mut persistence, "2026-13-01", "Bad date");
+    assert!(matches!(invalid, Err(ApiError::InvalidInput { ref field, .. }) if field == "date"));
+
+    let weekend = create(&mut persistence, "2026-05-23", "Saturday");
+    assert!(matches!(weekend, Err(ApiError::InvalidInput { ref field, .. }) if field == "date"));
+
+    let no_reason = create(&mut persistence, "2026-05-25", "   ");
+    assert!(
+        matches!(no_reason, Err(ApiError::InvalidInput { ref field, .. }) if field == "reason")
+    );
+
+    create(&mut persistence, "2026-05-25", "Memorial Day").unwrap();
+    let duplicate = create(&mut persistence, "2026-05-25", "Memorial Day");
+    assert!(matches!(duplicate, Err(ApiError::InvalidInput { .. })));
+
+    assert_eq!(list(&mut persistence).blackout_dates.len(), 1);
+}
+
+#[test]
+fn test_blackout_dates_require_admin() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+
+    let result = create_blackout_date(
+        &mut persistence,
+        &metadata,
+        &CreateBlackoutDateRequest {
+            bid_year_id,
+            date: String::from("2026-05-25"),
+            reason: String::from("Memorial Day"),
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    let created: BlackoutDateResponse =
+        create(&mut persistence, "2026-05-25", "Memorial Day").unwrap();
+    let result = delete_blackout_date(
+        &mut persistence,
+        created.blackout_date.blackout_date_id,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
+
+#[test]
+fn test_update_and_delete_blackout_date() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let first: i64 = create(&mut persistence, "2026-05-25", "Memorial Day")
+        .unwrap()
+        .blackout_date
+        .blackout_date_id;
+    create(
+        &mut persistence,
+        "2026-07-03",
+        "Independence Day (observed)",
+    )
+    .unwrap();
+
+    // Moving onto another blackout date is rejected
+    let conflict = update_blackout_date(
+        &mut persistence,
+        first,
+        &UpdateBlackoutDateRequest {
+            date: String::from("2026-07-03"),
+            reason: String::from("Moved"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(conflict, Err(ApiError::InvalidInput { .. })));
+
+    let updated: BlackoutDateResponse = update_blackout_date(
+        &mut persistence,
+        first,
+        &UpdateBlackoutDateRequest {
+            date: String::from("2026-09-07"),
+            reason: String::from("Labor Day"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(updated.blackout_date.date, "2026-09-07");
+    assert_eq!(updated.blackout_date.reason, "Labor Day");
+
+    delete_blackout_date(
+        &mut persistence,
+        first,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let listed: ListBlackoutDatesResponse = list(&mut persistence);
+    assert_eq!(listed.blackout_dates.len(), 1);
+    assert_eq!(listed.blackout_dates[0].date, "2026-07-03");
+
+    let missing = delete_blackout_date(
+        &mut persistence,
+        first,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(missing, Err(ApiError::ResourceNotFound { .. })));
+}
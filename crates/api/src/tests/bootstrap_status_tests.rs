@@ -0,0 +1,104 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the per-bid-year bootstrap status endpoint.
+
+use crate::error::ApiError;
+use crate::tests::helpers::setup_test_persistence;
+use crate::{
+    AreaBootstrapStatusInfo, BlockingReason, GetBidYearBootstrapStatusResponse,
+    get_bid_year_bootstrap_status,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_domain::BidYear;
+use zab_bid_persistence::SqlitePersistence;
+
+fn bid_year_id(metadata: &BootstrapMetadata) -> i64 {
+    metadata
+        .bid_years
+        .iter()
+        .find(|by| by.year() == 2026)
+        .and_then(BidYear::bid_year_id)
+        .unwrap()
+}
+
+#[test]
+fn test_bootstrap_status_combines_completeness_and_readiness() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year_id: i64 = bid_year_id(&metadata);
+
+    let status: GetBidYearBootstrapStatusResponse =
+        get_bid_year_bootstrap_status(&mut persistence, &metadata, bid_year_id).unwrap();
+
+    assert_eq!(status.year, 2026);
+    assert!(status.is_active);
+    assert_eq!(status.lifecycle_state, "Draft");
+    assert!(!status.is_complete);
+    assert!(
+        status
+            .blocking_reasons
+            .contains(&BlockingReason::ExpectedAreaCountNotSet {
+                bid_year_id,
+                bid_year: 2026,
+            })
+    );
+
+    let north: &AreaBootstrapStatusInfo = status
+        .areas
+        .iter()
+        .find(|area| area.area_code == "NORTH")
+        .unwrap();
+    assert!(north.is_missing_rounds);
+    assert!(north.seniority_conflicts.is_empty());
+    assert_eq!(north.expected_user_count, None);
+    assert!(!north.is_complete);
+
+    assert_eq!(status.readiness.bid_year_id, bid_year_id);
+    assert!(!status.readiness.is_ready);
+    assert!(!status.readiness.details.bid_schedule_set);
+    assert_eq!(
+        status.readiness.details.areas_missing_rounds,
+        vec![String::from("NORTH")]
+    );
+}
+
+#[test]
+fn test_bootstrap_status_reflects_expected_counts() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year_id: i64 = bid_year_id(&metadata);
+
+    let before: GetBidYearBootstrapStatusResponse =
+        get_bid_year_bootstrap_status(&mut persistence, &metadata, bid_year_id).unwrap();
+    persistence
+        .set_expected_area_count(&BidYear::new(2026), before.actual_area_count)
+        .unwrap();
+
+    let after: GetBidYearBootstrapStatusResponse =
+        get_bid_year_bootstrap_status(&mut persistence, &metadata, bid_year_id).unwrap();
+    assert_eq!(
+        after.expected_area_count,
+        Some(u32::try_from(before.actual_area_count).unwrap())
+    );
+    assert!(after.is_complete);
+    assert!(
+        !after
+            .blocking_reasons
+            .iter()
+            .any(|reason| matches!(reason, BlockingReason::ExpectedAreaCountNotSet { .. }))
+    );
+}
+
+#[test]
+fn test_bootstrap_status_unknown_bid_year() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+
+    let result: Result<GetBidYearBootstrapStatusResponse, ApiError> =
+        get_bid_year_bootstrap_status(&mut persistence, &metadata, 9999);
+
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for bulk user registration.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause, create_valid_request, setup_test_persistence,
+};
+use crate::{
+    BulkRegisterRowStatus, RegisterUserRequest, RegisterUsersBulkRequest,
+    RegisterUsersBulkResponse, register_users_bulk,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_persistence::SqlitePersistence;
+
+fn request_with_initials(initials: &str) -> RegisterUserRequest {
+    RegisterUserRequest {
+        initials: String::from(initials),
+        ..create_valid_request()
+    }
+}
+
+fn registered_user_count(persistence: &mut SqlitePersistence) -> usize {
+    persistence
+        .list_users(&BidYear::new(2026), &Area::new("North"))
+        .unwrap()
+        .len()
+}
+
+#[test]
+fn test_register_users_bulk_registers_all_valid_rows() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let request: RegisterUsersBulkRequest = RegisterUsersBulkRequest {
+        users: vec![request_with_initials("AB"), request_with_initials("CD")],
+        all_or_nothing: true,
+    };
+
+    let response: RegisterUsersBulkResponse = register_users_bulk(
+        &mut persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        &create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(response.total, 2);
+    assert_eq!(response.registered_count, 2);
+    assert_eq!(response.failed_count, 0);
+    assert!(
+        response
+            .results
+            .iter()
+            .all(|r| r.user_id.is_some() && r.event_id.is_some())
+    );
+    assert_eq!(registered_user_count(&mut persistence), 2);
+}
+
+#[test]
+fn test_register_users_bulk_partial_mode_applies_valid_subset() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let mut bad_crew: RegisterUserRequest = request_with_initials("EF");
+    bad_crew.crew = Some(99);
+    let request: RegisterUsersBulkRequest = RegisterUsersBulkRequest {
+        users: vec![
+            request_with_initials("AB"),
+            request_with_initials("AB"),
+            bad_crew,
+        ],
+        all_or_nothing: false,
+    };
+
+    let response: RegisterUsersBulkResponse = register_users_bulk(
+        &mut persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        &create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(response.registered_count, 1);
+    assert_eq!(response.failed_count, 2);
+    assert_eq!(
+        response.results[0].status,
+        BulkRegisterRowStatus::Registered
+    );
+    assert_eq!(response.results[1].status, BulkRegisterRowStatus::Failed);
+    assert_eq!(response.results[2].status, BulkRegisterRowStatus::Failed);
+    assert!(response.results[1].error.is_some());
+    assert_eq!(registered_user_count(&mut persistence), 1);
+}
+
+#[test]
+fn test_register_users_bulk_all_or_nothing_rejects_entire_batch() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let request: RegisterUsersBulkRequest = RegisterUsersBulkRequest {
+        users: vec![request_with_initials("AB"), request_with_initials("AB")],
+        all_or_nothing: true,
+    };
+
+    let response: RegisterUsersBulkResponse = register_users_bulk(
+        &mut persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        &create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(response.registered_count, 0);
+    assert_eq!(response.failed_count, 1);
+    assert_eq!(response.results[0].status, BulkRegisterRowStatus::Skipped);
+    assert!(response.results[0].user_id.is_none());
+    assert_eq!(registered_user_count(&mut persistence), 0);
+}
+
+#[test]
+fn test_register_users_bulk_requires_admin() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let request: RegisterUsersBulkRequest = RegisterUsersBulkRequest {
+        users: vec![create_valid_request()],
+        all_or_nothing: false,
+    };
+
+    let result: Result<RegisterUsersBulkResponse, ApiError> = register_users_bulk(
+        &mut persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        &create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+    assert_eq!(registered_user_count(&mut persistence), 0);
+}
@@ -0,0 +1,253 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for chat channel management handlers.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_cause,
+    setup_test_persistence,
+};
+use crate::{
+    CreateChatChannelRequest, CreateChatChannelResponse, DeleteChatChannelRequest,
+    ListChatChannelsResponse, ListChatNotificationsResponse, UpdateChatChannelRequest,
+    UpdateChatChannelResponse, create_chat_channel, delete_chat_channel, list_chat_channels,
+    list_chat_notifications, update_chat_channel,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_persistence::{AuditTimelineFilter, AuditTimelineScope, SqlitePersistence};
+
+/// Returns the IDs of the 2026 bid year and its North area.
+fn scope(metadata: &BootstrapMetadata) -> (i64, i64) {
+    let (bid_year, area) = metadata
+        .areas
+        .iter()
+        .find(|(_, area)| area.area_code() == "NORTH")
+        .unwrap();
+    (bid_year.bid_year_id().unwrap(), area.area_id().unwrap())
+}
+
+fn channel_request(bid_year_id: i64, area_id: Option<i64>) -> CreateChatChannelRequest {
+    CreateChatChannelRequest {
+        bid_year_id,
+        area_id,
+        provider: String::from("slack"),
+        url: String::from("https://hooks.slack.test/services/T000/B000/secret"),
+    }
+}
+
+/// Creates a channel for the North area and returns it.
+fn create_area_channel(persistence: &mut SqlitePersistence) -> CreateChatChannelResponse {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let (bid_year_id, area_id) = scope(&metadata);
+    create_chat_channel(
+        persistence,
+        &metadata,
+        &channel_request(bid_year_id, Some(area_id)),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap()
+}
+
+fn latest_global_action(persistence: &mut SqlitePersistence) -> zab_bid_audit::AuditEvent {
+    let page = persistence
+        .get_audit_timeline_page(
+            AuditTimelineScope::Global,
+            &AuditTimelineFilter::default(),
+            None,
+            100,
+        )
+        .unwrap();
+    page.entries.last().unwrap().event.clone()
+}
+
+#[test]
+fn test_create_chat_channel_hides_url_path() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+
+    let response: CreateChatChannelResponse = create_area_channel(&mut persistence);
+    assert_eq!(response.channel.provider, "slack");
+    assert_eq!(response.channel.url_host, "hooks.slack.test");
+    assert!(response.channel.is_enabled);
+    assert!(response.channel.area_id.is_some());
+
+    let event = latest_global_action(&mut persistence);
+    assert_eq!(event.action.name, "CreateChatChannel");
+    assert_eq!(event.before.data, "chat_channel_does_not_exist");
+    assert!(event.after.data.contains("url_host=hooks.slack.test"));
+    assert!(!event.after.data.contains("secret"));
+
+    let listed: ListChatChannelsResponse =
+        list_chat_channels(&mut persistence, None, &create_test_admin()).unwrap();
+    assert_eq!(listed.channels, vec![response.channel]);
+}
+
+#[test]
+fn test_create_chat_channel_rejects_invalid_input() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let (bid_year_id, area_id) = scope(&metadata);
+
+    let mut bad_provider: CreateChatChannelRequest = channel_request(bid_year_id, None);
+    bad_provider.provider = String::from("discord");
+    let mut plain_http: CreateChatChannelRequest = channel_request(bid_year_id, None);
+    plain_http.url = String::from("http://hooks.slack.test/services/x");
+
+    for (request, field) in [(bad_provider, "provider"), (plain_http, "url")] {
+        let result: Result<CreateChatChannelResponse, ApiError> = create_chat_channel(
+            &mut persistence,
+            &metadata,
+            &request,
+            &create_test_admin(),
+            &create_test_admin_operator(),
+            create_test_cause(),
+        );
+        assert!(
+            matches!(result, Err(ApiError::InvalidInput { field: ref f, .. }) if f == field),
+            "expected {field} to be rejected"
+        );
+    }
+
+    for request in [
+        channel_request(bid_year_id + 100, None),
+        channel_request(bid_year_id, Some(area_id + 100)),
+    ] {
+        let result: Result<CreateChatChannelResponse, ApiError> = create_chat_channel(
+            &mut persistence,
+            &metadata,
+            &request,
+            &create_test_admin(),
+            &create_test_admin_operator(),
+            create_test_cause(),
+        );
+        assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+    }
+}
+
+#[test]
+fn test_update_chat_channel_keeps_url_when_omitted() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let created: CreateChatChannelResponse = create_area_channel(&mut persistence);
+    let chat_channel_id: i64 = created.channel.chat_channel_id;
+
+    let response: UpdateChatChannelResponse = update_chat_channel(
+        &mut persistence,
+        &UpdateChatChannelRequest {
+            chat_channel_id,
+            provider: String::from("teams"),
+            url: None,
+            is_enabled: false,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(response.channel.provider, "teams");
+    assert_eq!(response.channel.url_host, "hooks.slack.test");
+    assert!(!response.channel.is_enabled);
+
+    let stored = persistence
+        .get_chat_channel(chat_channel_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        stored.url,
+        "https://hooks.slack.test/services/T000/B000/secret"
+    );
+    assert_eq!(
+        latest_global_action(&mut persistence).action.name,
+        "UpdateChatChannel"
+    );
+}
+
+#[test]
+fn test_delete_chat_channel() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let created: CreateChatChannelResponse = create_area_channel(&mut persistence);
+    let request: DeleteChatChannelRequest = DeleteChatChannelRequest {
+        chat_channel_id: created.channel.chat_channel_id,
+    };
+
+    delete_chat_channel(
+        &mut persistence,
+        request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let event = latest_global_action(&mut persistence);
+    assert_eq!(event.action.name, "DeleteChatChannel");
+    assert_eq!(event.after.data, "chat_channel_deleted");
+
+    let again = delete_chat_channel(
+        &mut persistence,
+        request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(again, Err(ApiError::ResourceNotFound { .. })));
+}
+
+#[test]
+fn test_chat_handlers_require_admin() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let (bid_year_id, _) = scope(&metadata);
+
+    let create: Result<CreateChatChannelResponse, ApiError> = create_chat_channel(
+        &mut persistence,
+        &metadata,
+        &channel_request(bid_year_id, None),
+        &create_test_bidder(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(create, Err(ApiError::Unauthorized { .. })));
+
+    let list: Result<ListChatChannelsResponse, ApiError> =
+        list_chat_channels(&mut persistence, None, &create_test_bidder());
+    assert!(matches!(list, Err(ApiError::Unauthorized { .. })));
+
+    let log: Result<ListChatNotificationsResponse, ApiError> =
+        list_chat_notifications(&mut persistence, None, None, &create_test_bidder());
+    assert!(matches!(log, Err(ApiError::Unauthorized { .. })));
+}
+
+#[test]
+fn test_list_chat_notifications() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let created: CreateChatChannelResponse = create_area_channel(&mut persistence);
+    let chat_channel_id: i64 = created.channel.chat_channel_id;
+    persistence
+        .record_chat_notification(
+            chat_channel_id,
+            "round_opened",
+            "1:1",
+            "Round 1 is open",
+            None,
+        )
+        .unwrap();
+
+    let response: ListChatNotificationsResponse = list_chat_notifications(
+        &mut persistence,
+        Some(chat_channel_id),
+        None,
+        &create_test_admin(),
+    )
+    .unwrap();
+    assert_eq!(response.notifications.len(), 1);
+    assert_eq!(response.notifications[0].kind, "round_opened");
+    assert!(response.notifications[0].delivered);
+
+    let out_of_range: Result<ListChatNotificationsResponse, ApiError> =
+        list_chat_notifications(&mut persistence, None, Some(1001), &create_test_admin());
+    assert!(matches!(out_of_range, Err(ApiError::InvalidInput { .. })));
+}
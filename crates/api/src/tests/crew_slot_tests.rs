@@ -0,0 +1,211 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for crew-partitioned leave slots.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_admin, create_test_admin_operator,
+    create_test_bidder, create_test_bidder_operator, create_test_cause,
+};
+use crate::{
+    CrewSlotsInfo, EnterLeaveBidRequest, ListRoundCrewSlotsResponse, RoundCrewSlotsInfo,
+    SetRoundCrewSlotsRequest, SetRoundCrewSlotsResponse, enter_leave_bid, list_round_crew_slots,
+    set_round_crew_slots,
+};
+use zab_bid::{BootstrapMetadata, Command, State, TransitionResult, apply};
+use zab_bid_domain::User;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with users AA and AH on crew 1, AB on crew 2, and
+/// AC without a crew, and one round offering two slots per day. The bid
+/// year is left in `Draft` so the round's crew slots can still be set.
+fn setup() -> PersistedFixture {
+    let builder: BidYearFixture = BidYearFixture::new(2026).with_users(8).with_rounds(1);
+    let mut fixture: PersistedFixture = builder.persist().unwrap();
+    // Bids are entered as the test bidder operator, which audit events reference
+    create_persisted_bidder_operator(&mut fixture.persistence).unwrap();
+    fixture
+        .persistence
+        .update_round(fixture.round_ids[0], "Round 1", 2, 3, 200, false, false)
+        .unwrap();
+
+    let user: User = builder.users("North")[2].clone();
+    let state: State = fixture.state("North").unwrap();
+    let result: TransitionResult = apply(
+        &fixture.metadata,
+        &state,
+        &builder.bid_year(),
+        Command::UpdateUser {
+            user_id: fixture.user_id("AC"),
+            initials: user.initials,
+            name: user.name,
+            area: user.area,
+            user_type: user.user_type,
+            crew: None,
+            seniority_data: user.seniority_data,
+        },
+        BidYearFixture::actor(fixture.operator_id),
+        BidYearFixture::cause(),
+    )
+    .unwrap();
+    fixture.persistence.persist_transition(&result).unwrap();
+
+    fixture
+}
+
+fn set_crew_slots(
+    fixture: &mut PersistedFixture,
+    crew_partitioned: bool,
+    crew_slots: &[(u8, u32)],
+) -> Result<SetRoundCrewSlotsResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: SetRoundCrewSlotsRequest = SetRoundCrewSlotsRequest {
+        bid_year_id: fixture.bid_year_id,
+        round_id: fixture.round_ids[0],
+        crew_partitioned,
+        crew_slots: crew_slots
+            .iter()
+            .map(|&(crew, slots_per_day)| CrewSlotsInfo {
+                crew,
+                slots_per_day,
+            })
+            .collect(),
+    };
+    set_round_crew_slots(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn enter(fixture: &mut PersistedFixture, initials: &str, dates: &[&str]) -> Result<(), ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: EnterLeaveBidRequest = EnterLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from(initials),
+        received_via: String::from("phone"),
+        leave_dates: dates.iter().map(|date| String::from(*date)).collect(),
+        hours: 8,
+        override_reason: None,
+    };
+    enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+    .map(|_| ())
+}
+
+fn start_bidding(fixture: &mut PersistedFixture) {
+    fixture
+        .persistence
+        .update_lifecycle_state(fixture.bid_year_id, "BiddingActive")
+        .unwrap();
+}
+
+#[test]
+fn test_crew_slots_are_validated_and_listed() {
+    let mut fixture: PersistedFixture = setup();
+    set_crew_slots(&mut fixture, true, &[(2, 1), (1, 1)]).unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let listed: ListRoundCrewSlotsResponse =
+        list_round_crew_slots(&mut fixture.persistence, &metadata, fixture.bid_year_id).unwrap();
+    assert_eq!(
+        listed.rounds,
+        vec![RoundCrewSlotsInfo {
+            round_id: fixture.round_ids[0],
+            crew_slots: vec![
+                CrewSlotsInfo {
+                    crew: 1,
+                    slots_per_day: 1,
+                },
+                CrewSlotsInfo {
+                    crew: 2,
+                    slots_per_day: 1,
+                },
+            ],
+        }]
+    );
+
+    // Invalid or repeated crews are refused
+    assert!(matches!(
+        set_crew_slots(&mut fixture, true, &[(8, 1)]),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "crew"
+    ));
+    assert!(matches!(
+        set_crew_slots(&mut fixture, true, &[(1, 1), (1, 2)]),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "crew_slots"
+    ));
+
+    // Area-wide rounds take no crew slots
+    assert!(matches!(
+        set_crew_slots(&mut fixture, false, &[(1, 1)]),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "crew_slots"
+    ));
+    set_crew_slots(&mut fixture, false, &[]).unwrap();
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let listed: ListRoundCrewSlotsResponse =
+        list_round_crew_slots(&mut fixture.persistence, &metadata, fixture.bid_year_id).unwrap();
+    assert!(listed.rounds.is_empty());
+
+    // Only Admins may partition slots, and only before bidding starts
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: SetRoundCrewSlotsRequest = SetRoundCrewSlotsRequest {
+        bid_year_id: fixture.bid_year_id,
+        round_id: fixture.round_ids[0],
+        crew_partitioned: true,
+        crew_slots: Vec::new(),
+    };
+    let result: Result<SetRoundCrewSlotsResponse, ApiError> = set_round_crew_slots(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    start_bidding(&mut fixture);
+    assert!(matches!(
+        set_crew_slots(&mut fixture, true, &[(1, 1)]),
+        Err(ApiError::DomainRuleViolation { .. })
+    ));
+}
+
+#[test]
+fn test_crew_partitioned_round_checks_bidder_crew() {
+    let mut fixture: PersistedFixture = setup();
+    set_crew_slots(&mut fixture, true, &[(1, 1), (2, 1)]).unwrap();
+    start_bidding(&mut fixture);
+
+    enter(&mut fixture, "AA", &["2026-07-01"]).unwrap();
+
+    // Crew 1's single slot is taken even though the round offers two
+    let result: Result<(), ApiError> = enter(&mut fixture, "AH", &["2026-07-01"]);
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "leave_bid"
+    ));
+
+    // Crew 2 still has its own slot
+    enter(&mut fixture, "AB", &["2026-07-01"]).unwrap();
+
+    // Users without a crew have no slots in a partitioned round
+    let result: Result<(), ApiError> = enter(&mut fixture, "AC", &["2026-07-02"]);
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "leave_bid"
+    ));
+}
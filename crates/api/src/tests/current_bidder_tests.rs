@@ -0,0 +1,370 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for current bidder tracking and advancement.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    AdvanceBidderRequest, AdvanceBidderResponse, ElapsedWindowAdvance, GetCurrentBidderResponse,
+    MissedWindowPolicy, advance_bidder, advance_elapsed_bidder, get_current_bidder,
+};
+use time::OffsetDateTime;
+use time::macros::datetime;
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::AuditEvent;
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_persistence::{NewBidStatus, NewBidWindow};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// The fixture users, in window order.
+const BIDDERS: [&str; 3] = ["AA", "AB", "AC"];
+
+/// Creates a `BiddingActive` 2026/North with one round and three
+/// consecutive one-hour windows starting 2026-03-02 13:00 UTC.
+fn setup_bidding() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(BIDDERS.len())
+        .with_rounds(1)
+        .with_lifecycle_state("BiddingActive")
+        .persist()
+        .unwrap();
+
+    let windows: Vec<NewBidWindow> = BIDDERS
+        .iter()
+        .enumerate()
+        .map(|(hour, initials)| NewBidWindow {
+            bid_year_id: fixture.bid_year_id,
+            area_id: fixture.area_id("North"),
+            user_id: fixture.user_id(initials),
+            round_id: fixture.round_ids[0],
+            window_start_datetime: format!("2026-03-02T{:02}:00:00Z", 13 + hour),
+            window_end_datetime: format!("2026-03-02T{:02}:00:00Z", 14 + hour),
+        })
+        .collect();
+    fixture
+        .persistence
+        .bulk_insert_bid_windows(&windows)
+        .unwrap();
+
+    fixture
+}
+
+fn advance(fixture: &mut PersistedFixture) -> Result<AdvanceBidderResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: AdvanceBidderRequest = AdvanceBidderRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+    };
+    advance_bidder(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn advance_if_elapsed(
+    fixture: &mut PersistedFixture,
+    policy: MissedWindowPolicy,
+    now: OffsetDateTime,
+) -> Option<ElapsedWindowAdvance> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let area_id: i64 = fixture.area_id("North");
+    let round_id: i64 = fixture.round_ids[0];
+    advance_elapsed_bidder(
+        &mut fixture.persistence,
+        &metadata,
+        area_id,
+        round_id,
+        &create_test_admin_operator(),
+        policy,
+        now,
+    )
+    .unwrap()
+}
+
+/// Seeds a bid status for each fixture user, in window order.
+fn seed_statuses(fixture: &mut PersistedFixture, statuses: &[&str]) {
+    let rows: Vec<NewBidStatus> = BIDDERS
+        .iter()
+        .zip(statuses)
+        .map(|(initials, status)| NewBidStatus {
+            bid_year_id: fixture.bid_year_id,
+            area_id: fixture.area_id("North"),
+            user_id: fixture.user_id(initials),
+            round_id: fixture.round_ids[0],
+            status: (*status).to_string(),
+            updated_at: String::from("2026-03-01T00:00:00Z"),
+            updated_by: 1,
+            notes: None,
+        })
+        .collect();
+    fixture.persistence.bulk_insert_bid_status(&rows).unwrap();
+}
+
+fn status_of(fixture: &mut PersistedFixture, initials: &str) -> String {
+    let bid_year_id: i64 = fixture.bid_year_id;
+    let area_id: i64 = fixture.area_id("North");
+    let user_id: i64 = fixture.user_id(initials);
+    let round_id: i64 = fixture.round_ids[0];
+    fixture
+        .persistence
+        .get_bid_status_for_user_and_round(bid_year_id, area_id, user_id, round_id)
+        .unwrap()
+        .status
+}
+
+fn timeline(fixture: &mut PersistedFixture) -> Vec<AuditEvent> {
+    fixture
+        .persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+        .unwrap()
+}
+
+fn current(fixture: &mut PersistedFixture) -> GetCurrentBidderResponse {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let area_id: i64 = fixture.area_id("North");
+    get_current_bidder(&mut fixture.persistence, &metadata, area_id).unwrap()
+}
+
+#[test]
+fn test_manual_advance_walks_round_in_window_order() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    assert_eq!(current(&mut fixture).current_bidder, None);
+
+    let started: AdvanceBidderResponse = advance(&mut fixture).unwrap();
+    assert_eq!(started.previous_user_id, None);
+    assert_eq!(started.next_user_id, Some(fixture.user_id("AA")));
+    let bidder = started.current_bidder.unwrap();
+    assert_eq!(bidder.initials, "AA");
+    assert_eq!(bidder.round_number, 1);
+    assert_eq!(
+        bidder.window_end_datetime.as_deref(),
+        Some("2026-03-02T14:00:00Z")
+    );
+
+    let second: AdvanceBidderResponse = advance(&mut fixture).unwrap();
+    assert_eq!(second.previous_user_id, Some(fixture.user_id("AA")));
+    assert_eq!(second.next_user_id, Some(fixture.user_id("AB")));
+    assert_eq!(
+        current(&mut fixture).current_bidder.map(|b| b.initials),
+        Some(String::from("AB"))
+    );
+
+    advance(&mut fixture).unwrap();
+    let completed: AdvanceBidderResponse = advance(&mut fixture).unwrap();
+    assert_eq!(completed.previous_user_id, Some(fixture.user_id("AC")));
+    assert_eq!(completed.next_user_id, None);
+    assert_eq!(completed.current_bidder, None);
+
+    // A completed round cannot advance further
+    let result = advance(&mut fixture);
+    assert!(
+        matches!(result, Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "bidder_advance")
+    );
+}
+
+#[test]
+fn test_advance_is_audited_in_area_scope() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    advance(&mut fixture).unwrap();
+    advance(&mut fixture).unwrap();
+
+    let events: Vec<AuditEvent> = timeline(&mut fixture);
+    let advances: Vec<&AuditEvent> = events
+        .iter()
+        .filter(|e| e.action.name == "AdvanceBidder")
+        .collect();
+    assert_eq!(advances.len(), 2);
+    assert_eq!(
+        advances[1].after.data,
+        format!(
+            "round_id={},current_user_id={}",
+            fixture.round_ids[0],
+            fixture.user_id("AB")
+        )
+    );
+}
+
+#[test]
+fn test_advance_requires_admin_and_bidding_active() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: AdvanceBidderRequest = AdvanceBidderRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+    };
+
+    let result = advance_bidder(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    let bid_year_id: i64 = fixture.bid_year_id;
+    fixture
+        .persistence
+        .update_lifecycle_state(bid_year_id, "BiddingClosed")
+        .unwrap();
+    assert!(matches!(
+        advance(&mut fixture),
+        Err(ApiError::DomainRuleViolation { .. })
+    ));
+}
+
+#[test]
+fn test_elapsed_windows_advance_automatically() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let policy: MissedWindowPolicy = MissedWindowPolicy::MarkMissed;
+
+    // Nothing happens before the first window opens
+    assert!(advance_if_elapsed(&mut fixture, policy, datetime!(2026-03-02 12:59 UTC)).is_none());
+
+    let started: ElapsedWindowAdvance =
+        advance_if_elapsed(&mut fixture, policy, datetime!(2026-03-02 13:00 UTC)).unwrap();
+    assert_eq!(started.expired_user_id, None);
+    assert_eq!(started.advance.next_user_id, Some(fixture.user_id("AA")));
+
+    // AA's window is still open
+    assert!(advance_if_elapsed(&mut fixture, policy, datetime!(2026-03-02 13:30 UTC)).is_none());
+
+    let handed_over: ElapsedWindowAdvance =
+        advance_if_elapsed(&mut fixture, policy, datetime!(2026-03-02 14:00 UTC)).unwrap();
+    assert_eq!(
+        handed_over.advance.next_user_id,
+        Some(fixture.user_id("AB"))
+    );
+
+    let bidder = current(&mut fixture).current_bidder.unwrap();
+    assert_eq!(bidder.initials, "AB");
+    assert_eq!(bidder.became_current_at, "2026-03-02T14:00:00Z");
+
+    let events: Vec<AuditEvent> = timeline(&mut fixture);
+    let last: &AuditEvent = events
+        .iter()
+        .rev()
+        .find(|e| e.action.name == "AdvanceBidder")
+        .unwrap();
+    assert_eq!(last.actor.actor_type, "system");
+    assert!(
+        last.action
+            .details
+            .as_deref()
+            .is_some_and(|d| d.ends_with("(window elapsed)"))
+    );
+}
+
+#[test]
+fn test_expired_window_marks_bidder_missed() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    seed_statuses(
+        &mut fixture,
+        &["not_started_in_window", "completed_on_time", "in_progress"],
+    );
+    let policy: MissedWindowPolicy = MissedWindowPolicy::MarkMissed;
+
+    advance_if_elapsed(&mut fixture, policy, datetime!(2026-03-02 13:00 UTC)).unwrap();
+    let expired: ElapsedWindowAdvance =
+        advance_if_elapsed(&mut fixture, policy, datetime!(2026-03-02 14:00 UTC)).unwrap();
+    assert_eq!(expired.expired_user_id, Some(fixture.user_id("AA")));
+    assert!(expired.marked_missed);
+    assert_eq!(status_of(&mut fixture, "AA"), "missed");
+
+    // AB submitted in time, so their window simply hands over
+    let submitted: ElapsedWindowAdvance =
+        advance_if_elapsed(&mut fixture, policy, datetime!(2026-03-02 15:00 UTC)).unwrap();
+    assert_eq!(submitted.expired_user_id, None);
+    assert_eq!(submitted.advance.next_user_id, Some(fixture.user_id("AC")));
+
+    // AC started bidding, so the expiry is recorded without marking them missed
+    let in_progress: ElapsedWindowAdvance =
+        advance_if_elapsed(&mut fixture, policy, datetime!(2026-03-02 16:00 UTC)).unwrap();
+    assert_eq!(in_progress.expired_user_id, Some(fixture.user_id("AC")));
+    assert!(!in_progress.marked_missed);
+    assert_eq!(in_progress.advance.next_user_id, None);
+    assert_eq!(status_of(&mut fixture, "AC"), "in_progress");
+
+    let expiries: Vec<String> = timeline(&mut fixture)
+        .into_iter()
+        .filter(|e| e.action.name == "WindowExpired")
+        .map(|e| e.after.data)
+        .collect();
+    assert_eq!(
+        expiries,
+        vec![
+            format!(
+                "round_id={},user_id={},window=expired,marked_missed=true",
+                fixture.round_ids[0],
+                fixture.user_id("AA")
+            ),
+            format!(
+                "round_id={},user_id={},window=expired,marked_missed=false",
+                fixture.round_ids[0],
+                fixture.user_id("AC")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_leave_status_policy_only_records_expiry() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    seed_statuses(&mut fixture, &["not_started_in_window"]);
+    let policy: MissedWindowPolicy = MissedWindowPolicy::LeaveStatus;
+
+    advance_if_elapsed(&mut fixture, policy, datetime!(2026-03-02 13:00 UTC)).unwrap();
+    let expired: ElapsedWindowAdvance =
+        advance_if_elapsed(&mut fixture, policy, datetime!(2026-03-02 14:00 UTC)).unwrap();
+    assert_eq!(expired.expired_user_id, Some(fixture.user_id("AA")));
+    assert!(!expired.marked_missed);
+    assert_eq!(status_of(&mut fixture, "AA"), "not_started_in_window");
+}
+
+#[test]
+fn test_auto_advance_disabled_leaves_round_unchanged() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let bid_year_id: i64 = fixture.bid_year_id;
+    fixture
+        .persistence
+        .set_feature_flag(bid_year_id, "auto_advance", false)
+        .unwrap();
+    let policy: MissedWindowPolicy = MissedWindowPolicy::MarkMissed;
+
+    assert!(advance_if_elapsed(&mut fixture, policy, datetime!(2026-03-02 14:00 UTC)).is_none());
+    assert_eq!(current(&mut fixture).current_bidder, None);
+
+    // A manual advance still works
+    let started: AdvanceBidderResponse = advance(&mut fixture).unwrap();
+    assert_eq!(started.next_user_id, Some(fixture.user_id("AA")));
+}
+
+#[test]
+fn test_missed_window_policy_parses() {
+    for policy in [
+        MissedWindowPolicy::MarkMissed,
+        MissedWindowPolicy::LeaveStatus,
+    ] {
+        assert_eq!(policy.as_str().parse::<MissedWindowPolicy>(), Ok(policy));
+    }
+    assert!("skip".parse::<MissedWindowPolicy>().is_err());
+}
+
+#[test]
+fn test_get_current_bidder_unknown_area() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let result = get_current_bidder(&mut fixture.persistence, &metadata, 9999);
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
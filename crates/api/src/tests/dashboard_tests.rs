@@ -0,0 +1,168 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the dashboard read projections.
+
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    DashboardDayInfo, EnterLeaveBidRequest, GetAreaDashboardRequest, GetAreaDashboardResponse,
+    GetBidYearDashboardResponse, ProjectionRefresh, RoundProgressInfo, UserAwardInfo,
+    enter_leave_bid, get_area_dashboard, get_bid_year_dashboard, refresh_dashboard_projections,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_persistence::NewBidStatus;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+const UPDATED_AT: &str = "2026-06-01T12:00:00Z";
+
+/// Creates a `BiddingActive` 2026/North with users AA and AB, one round
+/// offering two slots per day, and a bid status for each user.
+fn setup_bidding() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(2)
+        .with_rounds(1)
+        .with_lifecycle_state("BiddingActive")
+        .persist()
+        .unwrap();
+    // Bids are entered as the test bidder operator, which audit events reference
+    let operator_id: i64 = create_persisted_bidder_operator(&mut fixture.persistence).unwrap();
+
+    let statuses: Vec<NewBidStatus> = [("AA", "in_progress"), ("AB", "not_started_in_window")]
+        .iter()
+        .map(|(initials, status)| NewBidStatus {
+            bid_year_id: fixture.bid_year_id,
+            area_id: fixture.area_id("North"),
+            user_id: fixture.user_id(initials),
+            round_id: fixture.round_ids[0],
+            status: String::from(*status),
+            updated_at: String::from(UPDATED_AT),
+            updated_by: operator_id,
+            notes: None,
+        })
+        .collect();
+    fixture
+        .persistence
+        .bulk_insert_bid_status(&statuses)
+        .unwrap();
+    fixture
+}
+
+fn enter(fixture: &mut PersistedFixture, initials: &str, date: &str) {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: EnterLeaveBidRequest = EnterLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from(initials),
+        received_via: String::from("phone"),
+        leave_dates: vec![String::from(date)],
+        hours: 8,
+        override_reason: None,
+    };
+    enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+}
+
+fn refresh(fixture: &mut PersistedFixture) -> ProjectionRefresh {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    refresh_dashboard_projections(&mut fixture.persistence, &metadata, UPDATED_AT).unwrap()
+}
+
+fn area_dashboard(fixture: &mut PersistedFixture) -> GetAreaDashboardResponse {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: GetAreaDashboardRequest = GetAreaDashboardRequest {
+        area_id: fixture.area_id("North"),
+        start_date: String::from("2026-06-01"),
+        end_date: String::from("2026-06-02"),
+    };
+    get_area_dashboard(&mut fixture.persistence, &metadata, &request).unwrap()
+}
+
+#[test]
+fn test_first_refresh_projects_slots_awards_and_progress() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    enter(&mut fixture, "AA", "2026-06-01");
+
+    let refreshed: ProjectionRefresh = refresh(&mut fixture);
+    assert!(refreshed.areas_refreshed >= 1);
+
+    let dashboard: GetAreaDashboardResponse = area_dashboard(&mut fixture);
+    assert_eq!(
+        dashboard.projected_through_event_id,
+        refreshed.last_event_id
+    );
+    assert_eq!(
+        dashboard.days,
+        vec![
+            DashboardDayInfo {
+                round_id: fixture.round_ids[0],
+                date: String::from("2026-06-01"),
+                capacity: 2,
+                used: 1,
+                remaining: 1,
+            },
+            DashboardDayInfo {
+                round_id: fixture.round_ids[0],
+                date: String::from("2026-06-02"),
+                capacity: 2,
+                used: 0,
+                remaining: 2,
+            },
+        ]
+    );
+    assert_eq!(
+        dashboard.awards,
+        vec![UserAwardInfo {
+            user_id: fixture.user_id("AA"),
+            awarded_days: 1,
+            awarded_hours: 8,
+        }]
+    );
+    let progress: RoundProgressInfo = RoundProgressInfo {
+        round_id: fixture.round_ids[0],
+        bidders: 2,
+        not_started: 1,
+        in_progress: 1,
+        completed: 0,
+    };
+    assert_eq!(dashboard.progress, vec![progress.clone()]);
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let bid_year: GetBidYearDashboardResponse =
+        get_bid_year_dashboard(&mut fixture.persistence, &metadata, fixture.bid_year_id).unwrap();
+    let north = bid_year
+        .areas
+        .iter()
+        .find(|area| area.area_id == fixture.area_id("North"))
+        .unwrap();
+    assert_eq!(north.area_code, "NORTH");
+    assert_eq!(north.rounds, vec![progress]);
+}
+
+#[test]
+fn test_refresh_rebuilds_only_after_new_audit_events() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    refresh(&mut fixture);
+    assert_eq!(refresh(&mut fixture).areas_refreshed, 0);
+
+    enter(&mut fixture, "AA", "2026-06-01");
+    enter(&mut fixture, "AB", "2026-06-01");
+    // Dashboards reflect the last refresh until the next one
+    assert_eq!(area_dashboard(&mut fixture).days[0].remaining, 2);
+
+    assert_eq!(refresh(&mut fixture).areas_refreshed, 1);
+    let dashboard: GetAreaDashboardResponse = area_dashboard(&mut fixture);
+    assert_eq!(dashboard.days[0].remaining, 0);
+    assert_eq!(dashboard.awards.len(), 2);
+}
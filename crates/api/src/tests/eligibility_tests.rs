@@ -0,0 +1,193 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for derived eligibility and eligibility exceptions.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    EligibilityExceptionInfo, ListUserEligibilityResponse, OverrideEligibilityRequest,
+    SetEligibilityExceptionsRequest, SetEligibilityExceptionsResponse, UserEligibilityInfo,
+    list_user_eligibility, override_eligibility, set_eligibility_exceptions,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, StateSnapshot};
+use zab_bid_persistence::SqlitePersistence;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with CPC users AA and AB, CPC-IT user AC, and Dev-R
+/// user AD, and canonicalizes it.
+fn setup_canonicalized() -> (SqlitePersistence, i64) {
+    let PersistedFixture {
+        mut persistence,
+        operator_id,
+        bid_year_id,
+        ..
+    } = BidYearFixture::new(2026).with_users(4).persist().unwrap();
+
+    let event: AuditEvent = AuditEvent::new_global(
+        BidYearFixture::actor(operator_id),
+        BidYearFixture::cause(),
+        Action::new(String::from("CanonicalizeBidYear"), None),
+        StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+    );
+    persistence
+        .canonicalize_bid_year(bid_year_id, &event)
+        .unwrap();
+    persistence
+        .update_lifecycle_state(bid_year_id, "Canonicalized")
+        .unwrap();
+
+    (persistence, bid_year_id)
+}
+
+fn exception(user_type: &str, can_bid: bool) -> EligibilityExceptionInfo {
+    EligibilityExceptionInfo {
+        user_type: String::from(user_type),
+        area_id: None,
+        can_bid,
+    }
+}
+
+fn set_exceptions(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    exceptions: Vec<EligibilityExceptionInfo>,
+) -> Result<SetEligibilityExceptionsResponse, ApiError> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    set_eligibility_exceptions(
+        persistence,
+        &metadata,
+        &SetEligibilityExceptionsRequest {
+            bid_year_id,
+            exceptions,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn eligibility_of(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    initials: &str,
+) -> UserEligibilityInfo {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let response: ListUserEligibilityResponse =
+        list_user_eligibility(persistence, &metadata, bid_year_id).unwrap();
+    response
+        .users
+        .into_iter()
+        .find(|user| user.initials == initials)
+        .unwrap()
+}
+
+#[test]
+fn test_exception_recomputes_derived_eligibility() {
+    let (mut persistence, bid_year_id) = setup_canonicalized();
+    assert!(eligibility_of(&mut persistence, bid_year_id, "AD").can_bid);
+
+    let response: SetEligibilityExceptionsResponse = set_exceptions(
+        &mut persistence,
+        bid_year_id,
+        vec![exception("Dev-R", false)],
+    )
+    .unwrap();
+
+    assert_eq!(response.users_changed, 1);
+    let developmental: UserEligibilityInfo = eligibility_of(&mut persistence, bid_year_id, "AD");
+    assert!(!developmental.derived_can_bid);
+    assert!(!developmental.can_bid);
+    assert!(eligibility_of(&mut persistence, bid_year_id, "AA").can_bid);
+}
+
+#[test]
+fn test_override_stays_layered_over_derived_eligibility() {
+    let (mut persistence, bid_year_id) = setup_canonicalized();
+    let user_id: i64 = eligibility_of(&mut persistence, bid_year_id, "AD").user_id;
+    override_eligibility(
+        &mut persistence,
+        &OverrideEligibilityRequest {
+            user_id,
+            can_bid: true,
+            reason: String::from("Certified early this year"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+
+    set_exceptions(
+        &mut persistence,
+        bid_year_id,
+        vec![exception("Dev-R", false)],
+    )
+    .unwrap();
+
+    let developmental: UserEligibilityInfo = eligibility_of(&mut persistence, bid_year_id, "AD");
+    assert!(!developmental.derived_can_bid);
+    assert!(developmental.can_bid);
+    assert!(developmental.is_overridden);
+}
+
+#[test]
+fn test_duplicate_exceptions_are_rejected() {
+    let (mut persistence, bid_year_id) = setup_canonicalized();
+
+    let result: Result<SetEligibilityExceptionsResponse, ApiError> = set_exceptions(
+        &mut persistence,
+        bid_year_id,
+        vec![exception("Dev-R", false), exception("Dev-R", true)],
+    );
+
+    assert!(matches!(result, Err(ApiError::InvalidInput { .. })));
+}
+
+#[test]
+fn test_exceptions_are_fixed_once_bidding_starts() {
+    let (mut persistence, bid_year_id) = setup_canonicalized();
+    persistence
+        .update_lifecycle_state(bid_year_id, "BiddingActive")
+        .unwrap();
+
+    let result: Result<SetEligibilityExceptionsResponse, ApiError> = set_exceptions(
+        &mut persistence,
+        bid_year_id,
+        vec![exception("Dev-R", false)],
+    );
+
+    assert!(result.is_err());
+    assert!(
+        persistence
+            .list_eligibility_exceptions(bid_year_id)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_bidder_cannot_set_exceptions() {
+    let (mut persistence, bid_year_id) = setup_canonicalized();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+
+    let result: Result<SetEligibilityExceptionsResponse, ApiError> = set_eligibility_exceptions(
+        &mut persistence,
+        &metadata,
+        &SetEligibilityExceptionsRequest {
+            bid_year_id,
+            exceptions: vec![exception("Dev-R", false)],
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
@@ -0,0 +1,160 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the facility configuration and the defaults bid years inherit
+//! from it.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause, setup_test_persistence,
+};
+use crate::{
+    GetFacilityResponse, SetBidScheduleRequest, SetBidScheduleResponse, SetFacilityRequest,
+    SetFacilityResponse, SetLeaveCapRequest, get_facility, get_leave_cap, set_bid_schedule,
+    set_facility, set_leave_cap,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_persistence::SqlitePersistence;
+
+fn facility_request() -> SetFacilityRequest {
+    SetFacilityRequest {
+        identifier: String::from("ZAB"),
+        name: String::from("Albuquerque Center"),
+        default_timezone: String::from("America/Denver"),
+        junior_accrual_hours: 4,
+        mid_accrual_hours: 6,
+        senior_accrual_hours: 8,
+        max_carryover_hours: 360,
+    }
+}
+
+fn configure(
+    persistence: &mut SqlitePersistence,
+    request: &SetFacilityRequest,
+) -> Result<SetFacilityResponse, ApiError> {
+    set_facility(
+        persistence,
+        request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn set_schedule(
+    persistence: &mut SqlitePersistence,
+    timezone: Option<&str>,
+) -> Result<SetBidScheduleResponse, ApiError> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    set_bid_schedule(
+        persistence,
+        &metadata,
+        &SetBidScheduleRequest {
+            bid_year_id,
+            timezone: timezone.map(str::to_string),
+            start_date: String::from("2026-03-02"),
+            window_start_time: String::from("08:00:00"),
+            window_end_time: String::from("17:00:00"),
+            bidders_per_day: 5,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+#[test]
+fn test_set_facility_requires_admin_and_valid_fields() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    assert_eq!(
+        get_facility(&mut persistence).unwrap(),
+        GetFacilityResponse { facility: None }
+    );
+
+    let result: Result<SetFacilityResponse, ApiError> = set_facility(
+        &mut persistence,
+        &facility_request(),
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    let mut request: SetFacilityRequest = facility_request();
+    request.senior_accrual_hours = 5;
+    assert!(matches!(
+        configure(&mut persistence, &request),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "accrual_rates"
+    ));
+
+    let mut request: SetFacilityRequest = facility_request();
+    request.default_timezone = String::from("Mountain Time");
+    assert!(matches!(
+        configure(&mut persistence, &request),
+        Err(ApiError::InvalidInput { .. })
+    ));
+
+    let response: SetFacilityResponse = configure(&mut persistence, &facility_request()).unwrap();
+    assert_eq!(response.facility.identifier, "ZAB");
+    assert_eq!(
+        get_facility(&mut persistence).unwrap().facility,
+        Some(response.facility)
+    );
+}
+
+#[test]
+fn test_bid_year_inherits_facility_carryover_cap() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    configure(&mut persistence, &facility_request()).unwrap();
+
+    assert_eq!(
+        get_leave_cap(&mut persistence, &metadata, bid_year_id)
+            .unwrap()
+            .carryover_cap_hours,
+        360
+    );
+
+    // A cap set on the bid year overrides the facility's
+    set_leave_cap(
+        &mut persistence,
+        &metadata,
+        &SetLeaveCapRequest {
+            bid_year_id,
+            carryover_cap_hours: 200,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(
+        get_leave_cap(&mut persistence, &metadata, bid_year_id)
+            .unwrap()
+            .carryover_cap_hours,
+        200
+    );
+}
+
+#[test]
+fn test_bid_schedule_inherits_facility_timezone() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+
+    assert!(matches!(
+        set_schedule(&mut persistence, None),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "timezone"
+    ));
+
+    configure(&mut persistence, &facility_request()).unwrap();
+    let inherited: SetBidScheduleResponse = set_schedule(&mut persistence, None).unwrap();
+    assert_eq!(inherited.bid_schedule.timezone, "America/Denver");
+
+    let explicit: SetBidScheduleResponse =
+        set_schedule(&mut persistence, Some("America/New_York")).unwrap();
+    assert_eq!(explicit.bid_schedule.timezone, "America/New_York");
+}
@@ -0,0 +1,133 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for per-bid-year feature flags.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause, setup_test_persistence,
+};
+use crate::{
+    GetFeatureFlagsResponse, SetFeatureFlagRequest, SetFeatureFlagResponse, get_feature_flags,
+    is_feature_enabled, set_feature_flag,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::AuditEvent;
+use zab_bid_domain::FeatureFlag;
+use zab_bid_persistence::SqlitePersistence;
+
+/// Returns a persistence layer with bid year 2026 and its ID.
+fn setup() -> (SqlitePersistence, i64) {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    (persistence, bid_year_id)
+}
+
+fn set_flag(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    flag: &str,
+    enabled: bool,
+) -> Result<SetFeatureFlagResponse, ApiError> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    set_feature_flag(
+        persistence,
+        &metadata,
+        &SetFeatureFlagRequest {
+            bid_year_id,
+            flag: flag.to_string(),
+            enabled,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn flags(persistence: &mut SqlitePersistence, bid_year_id: i64) -> Vec<(String, bool, bool)> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let response: GetFeatureFlagsResponse =
+        get_feature_flags(persistence, &metadata, bid_year_id).unwrap();
+    response
+        .flags
+        .into_iter()
+        .map(|info| (info.flag, info.enabled, info.is_default))
+        .collect()
+}
+
+#[test]
+fn test_flags_default_until_set() {
+    let (mut persistence, bid_year_id) = setup();
+    assert_eq!(
+        flags(&mut persistence, bid_year_id),
+        vec![
+            (String::from("waitlists"), true, true),
+            (String::from("auto_advance"), true, true),
+        ]
+    );
+
+    set_flag(&mut persistence, bid_year_id, "waitlists", false).unwrap();
+    assert!(!is_feature_enabled(&mut persistence, bid_year_id, FeatureFlag::Waitlists).unwrap());
+    assert_eq!(
+        flags(&mut persistence, bid_year_id),
+        vec![
+            (String::from("waitlists"), false, false),
+            (String::from("auto_advance"), true, true),
+        ]
+    );
+
+    // Setting a flag back to its default still stores it
+    set_flag(&mut persistence, bid_year_id, "waitlists", true).unwrap();
+    assert_eq!(
+        flags(&mut persistence, bid_year_id)[0],
+        (String::from("waitlists"), true, false)
+    );
+}
+
+#[test]
+fn test_flag_change_is_audited() {
+    let (mut persistence, bid_year_id) = setup();
+    set_flag(&mut persistence, bid_year_id, "auto_advance", false).unwrap();
+
+    let event: AuditEvent = persistence
+        .get_global_audit_events()
+        .unwrap()
+        .into_iter()
+        .rfind(|e| e.action.name == "SetFeatureFlag")
+        .unwrap();
+    assert_eq!(event.before.data, "feature_flag=auto_advance,enabled=true");
+    assert_eq!(event.after.data, "feature_flag=auto_advance,enabled=false");
+}
+
+#[test]
+fn test_set_flag_rejects_unknown_flag_and_non_admin() {
+    let (mut persistence, bid_year_id) = setup();
+    assert!(matches!(
+        set_flag(&mut persistence, bid_year_id, "teleportation", true),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "flag"
+    ));
+
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let result: Result<SetFeatureFlagResponse, ApiError> = set_feature_flag(
+        &mut persistence,
+        &metadata,
+        &SetFeatureFlagRequest {
+            bid_year_id,
+            flag: String::from("waitlists"),
+            enabled: false,
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+    assert!(is_feature_enabled(&mut persistence, bid_year_id, FeatureFlag::Waitlists).unwrap());
+
+    assert!(matches!(
+        set_flag(&mut persistence, bid_year_id + 100, "waitlists", false),
+        Err(ApiError::ResourceNotFound { .. })
+    ));
+}
@@ -0,0 +1,70 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for free-text limits enforced while deserializing requests.
+
+use crate::free_text::{MAX_NAME_LENGTH, MAX_NOTE_LENGTH, MAX_REASON_LENGTH};
+use crate::request_response::{
+    DenyOverbidRequest, UpdateBidYearMetadataRequest, UpdateOwnProfileRequest,
+};
+
+fn profile(display_name: &str) -> Result<UpdateOwnProfileRequest, serde_json::Error> {
+    serde_json::from_value(serde_json::json!({ "display_name": display_name }))
+}
+
+fn deny(reason: &str) -> Result<DenyOverbidRequest, serde_json::Error> {
+    serde_json::from_value(serde_json::json!({ "overbid_request_id": 1, "reason": reason }))
+}
+
+fn metadata(notes: Option<&str>) -> Result<UpdateBidYearMetadataRequest, serde_json::Error> {
+    let mut body = serde_json::json!({ "bid_year_id": 1 });
+    if let Some(notes) = notes {
+        body["notes"] = serde_json::Value::from(notes);
+    }
+    serde_json::from_value(body)
+}
+
+#[test]
+fn test_names_at_the_limit_are_accepted() {
+    let name = "x".repeat(MAX_NAME_LENGTH);
+    assert_eq!(profile(&name).unwrap().display_name, name);
+}
+
+#[test]
+fn test_name_length_counts_characters_not_bytes() {
+    let name = "é".repeat(MAX_NAME_LENGTH);
+    assert!(profile(&name).is_ok());
+}
+
+#[test]
+fn test_overlong_fields_are_rejected() {
+    let err = profile(&"x".repeat(MAX_NAME_LENGTH + 1)).unwrap_err();
+    assert!(err.to_string().contains("must be at most 100 characters"));
+    assert!(deny(&"x".repeat(MAX_REASON_LENGTH + 1)).is_err());
+    assert!(metadata(Some(&"x".repeat(MAX_NOTE_LENGTH + 1))).is_err());
+}
+
+#[test]
+fn test_control_characters_are_rejected() {
+    let err = profile("Test\u{7}Operator").unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("must not contain control characters")
+    );
+    assert!(profile("Test\nOperator").is_err());
+    assert!(deny("Reason\u{0}").is_err());
+    assert!(metadata(Some("Note\u{1b}[2J")).is_err());
+}
+
+#[test]
+fn test_reasons_and_notes_may_span_lines() {
+    assert!(deny("First line\r\nSecond line\twith a tab").is_ok());
+    assert!(metadata(Some("First line\nSecond line")).is_ok());
+}
+
+#[test]
+fn test_omitted_optional_fields_stay_none() {
+    assert_eq!(metadata(None).unwrap().notes, None);
+}
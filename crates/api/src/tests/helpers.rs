@@ -9,7 +9,8 @@ use time::Date;
 use zab_bid::BootstrapMetadata;
 use zab_bid_audit::Cause;
 use zab_bid_domain::{Area, BidYear, CanonicalBidYear};
-use zab_bid_persistence::{OperatorData, SqlitePersistence};
+use zab_bid_persistence::{OperatorData, SecretToken, SqlitePersistence};
+use zab_bid_test_support::BidYearFixture;
 
 use crate::{AuthenticatedActor, RegisterUserRequest, Role};
 
@@ -186,7 +187,11 @@ pub fn create_admin_session(
     let session_token: String = format!("admin-session-{operator_id}");
     let expires_at: String = String::from("2026-12-31T23:59:59Z");
 
-    persistence.create_session(&session_token, operator_id, &expires_at)?;
+    persistence.create_session(
+        &SecretToken::new(session_token.clone()),
+        operator_id,
+        &expires_at,
+    )?;
 
     Ok(TestSession {
         session_token,
@@ -211,7 +216,11 @@ pub fn create_bidder_session(
     let session_token: String = format!("bidder-session-{operator_id}");
     let expires_at: String = String::from("2026-12-31T23:59:59Z");
 
-    persistence.create_session(&session_token, operator_id, &expires_at)?;
+    persistence.create_session(
+        &SecretToken::new(session_token.clone()),
+        operator_id,
+        &expires_at,
+    )?;
 
     Ok(TestSession {
         session_token,
@@ -238,7 +247,11 @@ pub fn create_custom_session(
     let session_token: String = format!("session-{operator_id}");
     let expires_at: String = String::from("2026-12-31T23:59:59Z");
 
-    persistence.create_session(&session_token, operator_id, &expires_at)?;
+    persistence.create_session(
+        &SecretToken::new(session_token.clone()),
+        operator_id,
+        &expires_at,
+    )?;
 
     Ok(TestSession {
         session_token,
@@ -249,74 +262,15 @@ pub fn create_custom_session(
 
 /// Creates a test persistence instance with an active bid year and area set up.
 ///
-/// This helper creates an in-memory `SQLite` database, initializes it, creates a bid year,
-/// creates an area, and sets the bid year as active.
+/// This helper creates an in-memory `SQLite` database with a test operator, bid year
+/// 2026, and area `North`, and sets the bid year as active.
 ///
 /// # Errors
 ///
 /// Returns an error if database initialization fails.
 pub fn setup_test_persistence() -> Result<SqlitePersistence, zab_bid_persistence::PersistenceError>
 {
-    use zab_bid::{BootstrapMetadata, BootstrapResult, Command, apply_bootstrap};
-    use zab_bid_audit::{Actor, Cause};
-
-    let mut persistence = SqlitePersistence::new_in_memory()?;
-
-    // Create a test operator (required for foreign keys)
-    let operator_id = persistence
-        .create_operator("test-operator", "Test Operator", "password", "Admin")
-        .map_err(|e| {
-            zab_bid_persistence::PersistenceError::Other(format!("Failed to create operator: {e}"))
-        })?;
-
-    let mut metadata = BootstrapMetadata::new();
-
-    // Create a canonical bid year
-    let create_bid_year_cmd = Command::CreateBidYear {
-        year: 2026,
-        start_date: create_test_start_date(),
-        num_pay_periods: create_test_pay_periods(),
-    };
-
-    let placeholder_bid_year = BidYear::new(2026);
-    let actor = Actor::with_operator(
-        String::from("test-admin"),
-        String::from("admin"),
-        operator_id,
-        String::from("test-operator"),
-        String::from("Test Operator"),
-    );
-    let cause = Cause::new(String::from("test-setup"), String::from("Test setup"));
-
-    let bid_year_result: BootstrapResult = apply_bootstrap(
-        &metadata,
-        &placeholder_bid_year,
-        create_bid_year_cmd,
-        actor.clone(),
-        cause.clone(),
-    )
-    .map_err(|e| zab_bid_persistence::PersistenceError::Other(format!("Bootstrap failed: {e}")))?;
-
-    persistence.persist_bootstrap(&bid_year_result)?;
-    metadata = bid_year_result.new_metadata;
-
-    // Create an area
-    let create_area_cmd = Command::CreateArea {
-        area_id: String::from("North"),
-    };
-
-    let active_bid_year = BidYear::new(2026);
-    let area_result: BootstrapResult =
-        apply_bootstrap(&metadata, &active_bid_year, create_area_cmd, actor, cause).map_err(
-            |e| zab_bid_persistence::PersistenceError::Other(format!("Bootstrap failed: {e}")),
-        )?;
-
-    persistence.persist_bootstrap(&area_result)?;
-
-    // Set the bid year as active
-    persistence.set_active_bid_year(&active_bid_year)?;
-
-    Ok(persistence)
+    Ok(BidYearFixture::new(2026).persist()?.persistence)
 }
 
 /// Bootstraps a bid year and area in persistence and returns the updated metadata.
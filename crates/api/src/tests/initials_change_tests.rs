@@ -0,0 +1,270 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for initials changes and the aliases they leave behind.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_admin, create_test_admin_operator,
+    create_test_bidder, create_test_bidder_operator, create_test_cause,
+};
+use crate::{
+    AuditTimelineFilter, AuditTimelinePageRequest, AuditTimelineScope, ChangeInitialsRequest,
+    ChangeInitialsResponse, EnterLeaveBidRequest, GetAuditTimelineResponse,
+    GetInitialsHistoryResponse, change_initials, enter_leave_bid, get_audit_timeline,
+    get_initials_history,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, StateSnapshot};
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_persistence::SqlitePersistence;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with users AA and AB and one round.
+fn setup() -> PersistedFixture {
+    BidYearFixture::new(2026)
+        .with_users(2)
+        .with_rounds(1)
+        .persist()
+        .unwrap()
+}
+
+fn change(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    from: &str,
+    to: &str,
+    effective_date: &str,
+) -> Result<ChangeInitialsResponse, ApiError> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    change_initials(
+        persistence,
+        &metadata,
+        &ChangeInitialsRequest {
+            bid_year_id,
+            from: String::from(from),
+            to: String::from(to),
+            effective_date: String::from(effective_date),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn history(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    initials: &str,
+) -> GetInitialsHistoryResponse {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    get_initials_history(persistence, &metadata, bid_year_id, initials).unwrap()
+}
+
+fn user_actions(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    initials: &str,
+) -> Vec<String> {
+    let response: GetAuditTimelineResponse = get_audit_timeline(
+        persistence,
+        AuditTimelineScope::BidYear { bid_year_id },
+        &AuditTimelineFilter {
+            user_initials: Some(String::from(initials)),
+            ..AuditTimelineFilter::default()
+        },
+        AuditTimelinePageRequest::default(),
+    )
+    .unwrap();
+    response
+        .entries
+        .into_iter()
+        .map(|entry| entry.action_name)
+        .collect()
+}
+
+#[test]
+fn test_either_initials_resolve_to_the_same_user() {
+    let PersistedFixture {
+        mut persistence,
+        bid_year_id,
+        ..
+    } = setup();
+
+    let response: ChangeInitialsResponse =
+        change(&mut persistence, bid_year_id, "aa", "cd", "2026-03-01").unwrap();
+
+    assert_eq!(response.previous_initials, "AA");
+    assert_eq!(response.initials, "CD");
+    let by_old: GetInitialsHistoryResponse = history(&mut persistence, bid_year_id, "AA");
+    let by_new: GetInitialsHistoryResponse = history(&mut persistence, bid_year_id, "CD");
+    assert_eq!(by_old, by_new);
+    assert_eq!(by_old.user_id, response.user_id);
+    assert_eq!(by_old.initials, "CD");
+    assert_eq!(by_old.aliases.len(), 1);
+    assert_eq!(by_old.aliases[0].initials, "AA");
+    assert_eq!(by_old.aliases[0].effective_date, "2026-03-01");
+
+    let expected: Vec<String> = vec![String::from("RegisterUser"), String::from("ChangeInitials")];
+    assert_eq!(user_actions(&mut persistence, bid_year_id, "AA"), expected);
+    assert_eq!(user_actions(&mut persistence, bid_year_id, "CD"), expected);
+    assert_eq!(
+        user_actions(&mut persistence, bid_year_id, "AB"),
+        vec![String::from("RegisterUser")]
+    );
+}
+
+#[test]
+fn test_leave_bids_follow_the_user_through_initials_changes() {
+    let PersistedFixture {
+        mut persistence,
+        bid_year_id,
+        area_ids,
+        round_ids,
+        ..
+    } = setup();
+    create_persisted_bidder_operator(&mut persistence).unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    persistence
+        .update_lifecycle_state(bid_year_id, "BiddingActive")
+        .unwrap();
+
+    enter_leave_bid(
+        &mut persistence,
+        &metadata,
+        &EnterLeaveBidRequest {
+            area_id: area_ids["NORTH"],
+            round_id: round_ids[0],
+            on_behalf_of: String::from("AA"),
+            received_via: String::from("phone"),
+            leave_dates: vec![String::from("2026-06-01")],
+            hours: 8,
+            override_reason: None,
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    change(&mut persistence, bid_year_id, "AA", "CD", "2026-07-01").unwrap();
+
+    let expected: Vec<String> = vec![
+        String::from("RegisterUser"),
+        String::from("EnterLeaveBid"),
+        String::from("ChangeInitials"),
+    ];
+    assert_eq!(user_actions(&mut persistence, bid_year_id, "AA"), expected);
+    assert_eq!(user_actions(&mut persistence, bid_year_id, "CD"), expected);
+    assert_eq!(
+        user_actions(&mut persistence, bid_year_id, "AB"),
+        vec![String::from("RegisterUser")]
+    );
+}
+
+#[test]
+fn test_events_recorded_without_a_user_match_by_initials() {
+    let PersistedFixture {
+        mut persistence,
+        bid_year_id,
+        operator_id,
+        ..
+    } = setup();
+
+    // Events recorded before events carried a user ID name the user only
+    // in their action
+    let legacy: AuditEvent = AuditEvent::new(
+        BidYearFixture::actor(operator_id),
+        BidYearFixture::cause(),
+        Action::new(
+            String::from("LegacyLeaveEntry"),
+            Some(String::from("Entered leave for 'AA'")),
+        ),
+        StateSnapshot::new(String::from("leave=none")),
+        StateSnapshot::new(String::from("leave=2026-06-01")),
+        BidYear::new(2026),
+        Area::new("North"),
+    );
+    assert_eq!(legacy.user_id, None);
+    persistence.persist_audit_event(&legacy).unwrap();
+    change(&mut persistence, bid_year_id, "AA", "CD", "2026-03-01").unwrap();
+
+    let expected: Vec<String> = vec![
+        String::from("RegisterUser"),
+        String::from("LegacyLeaveEntry"),
+        String::from("ChangeInitials"),
+    ];
+    assert_eq!(user_actions(&mut persistence, bid_year_id, "CD"), expected);
+    assert_eq!(
+        user_actions(&mut persistence, bid_year_id, "AB"),
+        vec![String::from("RegisterUser")]
+    );
+}
+
+#[test]
+fn test_initials_held_by_another_user_are_rejected() {
+    let PersistedFixture {
+        mut persistence,
+        bid_year_id,
+        ..
+    } = setup();
+
+    let taken: Result<ChangeInitialsResponse, ApiError> =
+        change(&mut persistence, bid_year_id, "AA", "AB", "2026-03-01");
+    assert!(matches!(taken, Err(ApiError::DomainRuleViolation { .. })));
+
+    // Once retired, initials stay with the user who held them
+    change(&mut persistence, bid_year_id, "AA", "CD", "2026-03-01").unwrap();
+    let retired: Result<ChangeInitialsResponse, ApiError> =
+        change(&mut persistence, bid_year_id, "AB", "AA", "2026-04-01");
+    assert!(matches!(retired, Err(ApiError::DomainRuleViolation { .. })));
+
+    change(&mut persistence, bid_year_id, "CD", "AA", "2026-04-01").unwrap();
+    assert_eq!(history(&mut persistence, bid_year_id, "CD").initials, "AA");
+}
+
+#[test]
+fn test_effective_date_must_fall_in_the_bid_year() {
+    let PersistedFixture {
+        mut persistence,
+        bid_year_id,
+        ..
+    } = setup();
+
+    let result: Result<ChangeInitialsResponse, ApiError> =
+        change(&mut persistence, bid_year_id, "AA", "CD", "2025-12-01");
+
+    assert!(matches!(result, Err(ApiError::InvalidInput { .. })));
+    assert!(
+        history(&mut persistence, bid_year_id, "AA")
+            .aliases
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_bidder_cannot_change_initials() {
+    let PersistedFixture {
+        mut persistence,
+        bid_year_id,
+        ..
+    } = setup();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+
+    let result: Result<ChangeInitialsResponse, ApiError> = change_initials(
+        &mut persistence,
+        &metadata,
+        &ChangeInitialsRequest {
+            bid_year_id,
+            from: String::from("AA"),
+            to: String::from("CD"),
+            effective_date: String::from("2026-03-01"),
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
@@ -0,0 +1,178 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for proxied leave bid entry.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_admin, create_test_admin_operator,
+    create_test_bidder, create_test_bidder_operator, create_test_cause,
+};
+use crate::{EnterLeaveBidRequest, EnterLeaveBidResponse, enter_leave_bid};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::AuditEvent;
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_persistence::LeaveBidData;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates a `BiddingActive` 2026/North with user AA and one round.
+fn setup_bidding() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(1)
+        .with_rounds(1)
+        .with_lifecycle_state("BiddingActive")
+        .persist()
+        .unwrap();
+    // Bids are entered as the test bidder operator, which audit events reference
+    create_persisted_bidder_operator(&mut fixture.persistence).unwrap();
+    fixture
+}
+
+fn request(fixture: &PersistedFixture, leave_dates: &[&str]) -> EnterLeaveBidRequest {
+    EnterLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from("aa"),
+        received_via: String::from("phone"),
+        leave_dates: leave_dates.iter().map(ToString::to_string).collect(),
+        hours: 8,
+        override_reason: None,
+    }
+}
+
+fn enter(
+    fixture: &mut PersistedFixture,
+    request: &EnterLeaveBidRequest,
+) -> Result<EnterLeaveBidResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+}
+
+#[test]
+fn test_leave_bid_records_proxy_attribution() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let request: EnterLeaveBidRequest = request(&fixture, &["2026-06-02", "2026-06-01"]);
+
+    let response: EnterLeaveBidResponse = enter(&mut fixture, &request).unwrap();
+    assert_eq!(response.on_behalf_of, "AA");
+    assert_eq!(response.received_via, "phone");
+    assert_eq!(response.leave_bid_ids.len(), 2);
+
+    let bids: Vec<LeaveBidData> = fixture
+        .persistence
+        .list_leave_bids(fixture.bid_year_id, fixture.area_id("North"))
+        .unwrap();
+    assert_eq!(
+        bids.iter()
+            .map(|b| b.leave_date.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["2026-06-01", "2026-06-02"]
+    );
+    assert!(bids.iter().all(|b| b.user_id == response.user_id
+        && b.on_behalf_of.as_deref() == Some("AA")
+        && b.received_via.as_deref() == Some("phone")));
+
+    let timeline: Vec<AuditEvent> = fixture
+        .persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    let event: &AuditEvent = timeline
+        .iter()
+        .find(|e| e.action.name == "EnterLeaveBid")
+        .unwrap();
+    assert_eq!(event.actor.actor_type, "bidder");
+    assert!(
+        event
+            .after
+            .data
+            .contains("on_behalf_of=AA,received_via=phone")
+    );
+}
+
+#[test]
+fn test_leave_bid_rejects_unknown_controller_and_method() {
+    let mut fixture: PersistedFixture = setup_bidding();
+
+    let mut unknown_user: EnterLeaveBidRequest = request(&fixture, &["2026-06-01"]);
+    unknown_user.on_behalf_of = String::from("ZZ");
+    assert!(matches!(
+        enter(&mut fixture, &unknown_user),
+        Err(ApiError::ResourceNotFound { .. })
+    ));
+
+    let mut bad_method: EnterLeaveBidRequest = request(&fixture, &["2026-06-01"]);
+    bad_method.received_via = String::from("email");
+    assert!(matches!(
+        enter(&mut fixture, &bad_method),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "received_via"
+    ));
+
+    let bad_date: EnterLeaveBidRequest = request(&fixture, &["June 1"]);
+    assert!(matches!(
+        enter(&mut fixture, &bad_date),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "leave_dates"
+    ));
+
+    assert!(
+        fixture
+            .persistence
+            .list_leave_bids(fixture.bid_year_id, fixture.area_id("North"))
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_leave_bid_rejects_day_already_held() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let first: EnterLeaveBidRequest = request(&fixture, &["2026-06-01"]);
+    enter(&mut fixture, &first).unwrap();
+
+    let overlapping: EnterLeaveBidRequest = request(&fixture, &["2026-06-02", "2026-06-01"]);
+    let result = enter(&mut fixture, &overlapping);
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "leave_bid"
+    ));
+    assert_eq!(
+        fixture
+            .persistence
+            .list_leave_bids(fixture.bid_year_id, fixture.area_id("North"))
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_leave_bid_requires_bidding_active() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    fixture
+        .persistence
+        .update_lifecycle_state(fixture.bid_year_id, "Canonicalized")
+        .unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let leave_bid: EnterLeaveBidRequest = request(&fixture, &["2026-06-01"]);
+    let result = enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &leave_bid,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "operation_allowed_in_state"
+    ));
+}
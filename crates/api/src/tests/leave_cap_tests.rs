@@ -0,0 +1,238 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for carryover caps, projected leave balances, overdrawn leave
+//! requests, and the use-or-lose report.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    GetBidYearReadinessResponse, GetUseOrLoseReportRequest, LeaveProjectionInfo,
+    ListLeaveProjectionsResponse, RenderedReport, SetLeaveCapRequest, SetLeaveCapResponse,
+    SetLeaveCarryoverRequest, SetLeaveCarryoverResponse, get_bid_year_readiness, get_leave_cap,
+    get_use_or_lose_report, list_leave_projections, set_leave_cap, set_leave_carryover,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_persistence::BidPreferenceSpecData;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with users AA and AB and one round.
+fn setup() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(2)
+        .with_rounds(1)
+        .persist()
+        .unwrap();
+    fixture
+        .persistence
+        .update_round(fixture.round_ids[0], "Round 1", 1, 3, 200, false, false)
+        .unwrap();
+    fixture
+}
+
+fn carry_over(fixture: &mut PersistedFixture, initials: &str, carryover_hours: u16) {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: SetLeaveCarryoverRequest = SetLeaveCarryoverRequest {
+        area_id: fixture.area_id("North"),
+        initials: initials.to_string(),
+        carryover_hours,
+    };
+    let response: SetLeaveCarryoverResponse = set_leave_carryover(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(response.carryover_hours, carryover_hours);
+}
+
+fn projection(fixture: &mut PersistedFixture, initials: &str) -> LeaveProjectionInfo {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let response: ListLeaveProjectionsResponse = list_leave_projections(
+        &mut fixture.persistence,
+        &metadata,
+        fixture.bid_year_id,
+        None,
+    )
+    .unwrap();
+    response
+        .projections
+        .into_iter()
+        .find(|projection| projection.initials == initials)
+        .unwrap()
+}
+
+#[test]
+fn test_set_leave_cap_requires_admin_and_positive_cap() {
+    let mut fixture: PersistedFixture = setup();
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+
+    assert_eq!(
+        get_leave_cap(&mut fixture.persistence, &metadata, fixture.bid_year_id)
+            .unwrap()
+            .carryover_cap_hours,
+        240
+    );
+
+    let result: Result<SetLeaveCapResponse, ApiError> = set_leave_cap(
+        &mut fixture.persistence,
+        &metadata,
+        &SetLeaveCapRequest {
+            bid_year_id: fixture.bid_year_id,
+            carryover_cap_hours: 200,
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    let result: Result<SetLeaveCapResponse, ApiError> = set_leave_cap(
+        &mut fixture.persistence,
+        &metadata,
+        &SetLeaveCapRequest {
+            bid_year_id: fixture.bid_year_id,
+            carryover_cap_hours: 0,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "carryover_cap_hours"
+    ));
+
+    set_leave_cap(
+        &mut fixture.persistence,
+        &metadata,
+        &SetLeaveCapRequest {
+            bid_year_id: fixture.bid_year_id,
+            carryover_cap_hours: 200,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(
+        get_leave_cap(&mut fixture.persistence, &metadata, fixture.bid_year_id)
+            .unwrap()
+            .carryover_cap_hours,
+        200
+    );
+}
+
+#[test]
+fn test_awarded_leave_reduces_use_or_lose() {
+    let mut fixture: PersistedFixture = setup();
+    carry_over(&mut fixture, "AA", 300);
+
+    let before: LeaveProjectionInfo = projection(&mut fixture, "AA");
+    assert_eq!(before.carried_in_hours, 240);
+    assert_eq!(before.forfeited_carryover_hours, 60);
+    assert_eq!(before.use_or_lose_hours, u32::from(before.accrued_hours));
+
+    let aa: i64 = before.user_id;
+    fixture
+        .persistence
+        .insert_leave_bid(
+            fixture.bid_year_id,
+            fixture.area_id("North"),
+            aa,
+            fixture.round_ids[0],
+            "2026-07-01",
+            8,
+            "AA",
+            "phone",
+        )
+        .unwrap();
+
+    let after: LeaveProjectionInfo = projection(&mut fixture, "AA");
+    assert_eq!(after.awarded_hours, 8);
+    assert_eq!(after.use_or_lose_hours, before.use_or_lose_hours - 8);
+
+    // AB carries nothing in and stays under the cap
+    assert_eq!(projection(&mut fixture, "AB").use_or_lose_hours, 0);
+}
+
+#[test]
+fn test_users_over_threshold_are_reported_and_warned() {
+    let mut fixture: PersistedFixture = setup();
+    carry_over(&mut fixture, "AA", 240);
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: GetUseOrLoseReportRequest = GetUseOrLoseReportRequest {
+        bid_year_id: fixture.bid_year_id,
+        area_id: Some(fixture.area_id("North")),
+        format: String::from("csv"),
+    };
+    let readiness: GetBidYearReadinessResponse =
+        get_bid_year_readiness(&mut fixture.persistence, &metadata, fixture.bid_year_id).unwrap();
+    assert_eq!(readiness.details.users_over_use_or_lose, 1);
+    assert_eq!(readiness.warnings.len(), 1);
+    assert!(readiness.warnings[0].contains("'AA'"));
+
+    let report: RenderedReport =
+        get_use_or_lose_report(&mut fixture.persistence, &metadata, &request).unwrap();
+    assert_eq!(report.filename, "use-or-lose-2026-NORTH.csv");
+    let csv: String = String::from_utf8(report.body).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[1].starts_with("NORTH,AA,Controller AA,240,0,"));
+}
+
+#[test]
+fn test_pending_preferences_beyond_accrual_are_flagged() {
+    let mut fixture: PersistedFixture = setup();
+    let before: LeaveProjectionInfo = projection(&mut fixture, "AA");
+    // Over fifteen years of service: 26 PPs * 8 hours
+    assert_eq!(before.hours_category, "senior");
+    assert_eq!(before.accrued_hours, 208);
+
+    // The top-ranked choice asks for 27 days; the fallback is not counted
+    let top_choice: Vec<String> = (1..=27).map(|day| format!("2026-07-{day:02}")).collect();
+    fixture
+        .persistence
+        .replace_bid_preferences(
+            fixture.bid_year_id,
+            fixture.area_id("North"),
+            before.user_id,
+            fixture.round_ids[0],
+            "AA",
+            "phone",
+            &[
+                BidPreferenceSpecData {
+                    preference_rank: 1,
+                    leave_dates: top_choice,
+                    hours: 8,
+                },
+                BidPreferenceSpecData {
+                    preference_rank: 2,
+                    leave_dates: vec![String::from("2026-08-03")],
+                    hours: 8,
+                },
+            ],
+        )
+        .unwrap();
+
+    let after: LeaveProjectionInfo = projection(&mut fixture, "AA");
+    assert_eq!(after.pending_hours, 216);
+    assert_eq!(after.overdrawn_hours, 8);
+    assert_eq!(projection(&mut fixture, "AB").overdrawn_hours, 0);
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let readiness: GetBidYearReadinessResponse =
+        get_bid_year_readiness(&mut fixture.persistence, &metadata, fixture.bid_year_id).unwrap();
+    assert_eq!(readiness.details.users_exceeding_accrual, 1);
+    assert_eq!(readiness.details.users_over_use_or_lose, 0);
+    assert!(readiness.warnings[0].contains("'AA' has bid 8 hours more"));
+}
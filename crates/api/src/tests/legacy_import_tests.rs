@@ -0,0 +1,285 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for importing the legacy bid tracker's bid log export.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    LegacyImportReport, LegacyImportRequest, LegacyImportResponse, LegacyRoundInfo,
+    UnmappableLegacyRow, import_legacy_bids, preview_legacy_import,
+};
+use zab_bid_audit::AuditEvent;
+use zab_bid_domain::{Area, BidYear, Round, User};
+use zab_bid_persistence::LeaveBidData;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+const HEADER: &str = "Init,Name,Area,Type,Crew,SCD,EOD,NATCA BU,Cum NATCA BU,Lottery,Round,Round Name,Leave Date,Hours";
+
+/// An export covering every way a row maps, or fails to.
+const EXPORT: &[&str] = &[
+    // Leave for an existing controller, in a round that does not exist yet
+    "AA,,North,,,,,,,,1,Prime Time,7/4/2026,8",
+    "AA,,North,,,,,,,,1,Prime Time,7/5/2026,8.0",
+    // A controller missing from the roster, registered from the row
+    "zz,Zed Zulu,north,cpc,2,1/15/2010,1/15/2010,,,,1,Prime Time,7/4/2026,10",
+    "ZZ,Zed Zulu,North,CPC,2,1/15/2010,1/15/2010,,,,2,,2026-08-01,8",
+    // Unmappable rows
+    "XY,Xavier Young,East,CPC,1,1/15/2010,1/15/2010,,,,1,,7/4/2026,8",
+    "QQ,,North,,,,,,,,1,,7/4/2026,8",
+    "AA,,North,,,,,,,,1,,13/40/2026,8",
+    "AA,,North,,,,,,,,1,,7/4/2026,8",
+    "AB,,South,,,,,,,,1,,7/4/2026,8",
+    "AA,,South,,,,,,,,1,,7/6/2026,8",
+];
+
+/// `AA` in North, which has a round group without rounds, and `AB` in
+/// South, which has no round group.
+fn setup() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_areas(&["North", "South"])
+        .with_users(1)
+        .persist()
+        .unwrap();
+    let round_group_id: i64 = fixture
+        .persistence
+        .insert_round_group(fixture.bid_year_id, "Default", true)
+        .unwrap();
+    fixture
+        .persistence
+        .update_area_round_group(fixture.area_id("North"), Some(round_group_id))
+        .unwrap();
+    fixture.metadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    fixture
+}
+
+fn request(fixture: &PersistedFixture, rows: &[&str]) -> LegacyImportRequest {
+    let mut csv_content: String = format!("{HEADER}\n");
+    for row in rows {
+        csv_content.push_str(row);
+        csv_content.push('\n');
+    }
+    LegacyImportRequest {
+        bid_year_id: fixture.bid_year_id,
+        csv_content,
+    }
+}
+
+fn preview(fixture: &mut PersistedFixture, rows: &[&str]) -> LegacyImportReport {
+    let request: LegacyImportRequest = request(fixture, rows);
+    preview_legacy_import(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &request,
+        &create_test_admin(),
+    )
+    .unwrap()
+}
+
+fn import(fixture: &mut PersistedFixture, rows: &[&str]) -> LegacyImportResponse {
+    let request: LegacyImportRequest = request(fixture, rows);
+    import_legacy_bids(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        &create_test_cause(),
+    )
+    .unwrap()
+}
+
+fn reasons(report: &LegacyImportReport) -> Vec<(usize, &str)> {
+    report
+        .unmappable
+        .iter()
+        .map(|row: &UnmappableLegacyRow| (row.row_number, row.reason.as_str()))
+        .collect()
+}
+
+#[test]
+fn test_preview_reports_mapping_without_changes() {
+    let mut fixture: PersistedFixture = setup();
+
+    let report: LegacyImportReport = preview(&mut fixture, EXPORT);
+
+    assert_eq!(report.bid_year, 2026);
+    assert_eq!(report.total_rows, 10);
+    assert_eq!(report.mapped_rows, 4);
+    assert_eq!(report.users_to_register, vec![String::from("ZZ")]);
+    assert_eq!(report.bids_to_import, 4);
+    assert_eq!(
+        reasons(&report),
+        vec![
+            (5, "Area 'EAST' does not exist in bid year 2026"),
+            (6, "Cannot register 'QQ': missing NAME, TYPE, SCD, EOD"),
+            (7, "Invalid LEAVE DATE '13/40/2026'"),
+            (
+                8,
+                "Leave on 2026-07-04 in round 1 is already listed on row 1"
+            ),
+            (9, "Area SOUTH has no round group to import rounds into"),
+            (10, "'AA' is registered in area NORTH, not SOUTH"),
+        ]
+    );
+
+    // Rounds are sized to hold the imported bids
+    let rounds: Vec<(u32, &str, u32, u32, u32)> = report
+        .rounds_to_create
+        .iter()
+        .map(|round: &LegacyRoundInfo| {
+            (
+                round.round_number,
+                round.name.as_str(),
+                round.slots_per_day,
+                round.max_groups,
+                round.max_total_hours,
+            )
+        })
+        .collect();
+    assert_eq!(
+        rounds,
+        vec![(1, "Prime Time", 2, 2, 16), (2, "Round 2", 1, 1, 8)]
+    );
+
+    // Nothing was changed
+    let users: Vec<User> = fixture
+        .persistence
+        .list_users(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    assert_eq!(users.len(), 1);
+}
+
+#[test]
+fn test_import_registers_users_and_records_legacy_bids() {
+    let mut fixture: PersistedFixture = setup();
+
+    let response: LegacyImportResponse = import(&mut fixture, EXPORT);
+
+    assert_eq!(response.report.bids_to_import, 4);
+    assert_eq!(response.audit_event_ids.len(), 1);
+
+    let users: Vec<User> = fixture
+        .persistence
+        .list_users(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    let registered: &User = users
+        .iter()
+        .find(|user| user.initials.value() == "ZZ")
+        .unwrap();
+    assert_eq!(registered.name, "Zed Zulu");
+    assert_eq!(registered.seniority_data.eod_faa_date, "2010-01-15");
+
+    let round_group_id: i64 = fixture.metadata.areas[0].1.round_group_id().unwrap();
+    let rounds: Vec<Round> = fixture.persistence.list_rounds(round_group_id).unwrap();
+    assert_eq!(rounds.len(), 2);
+    assert_eq!(rounds[0].name(), "Prime Time");
+
+    let bids: Vec<LeaveBidData> = fixture
+        .persistence
+        .list_leave_bids(fixture.bid_year_id, fixture.area_id("North"))
+        .unwrap();
+    assert_eq!(bids.len(), 4);
+    assert!(bids.iter().all(|bid| {
+        bid.received_via.as_deref() == Some(LeaveBidData::RECEIVED_VIA_LEGACY_IMPORT)
+    }));
+    assert_eq!(
+        bids.iter().map(|bid| bid.hours).sum::<i32>(),
+        8 + 8 + 10 + 8
+    );
+
+    let events: Vec<AuditEvent> = fixture
+        .persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    let event: &AuditEvent = events
+        .iter()
+        .find(|e| e.action.name == "LegacyBidsImported")
+        .unwrap();
+    assert_eq!(
+        event.action.details.as_deref(),
+        Some("Imported 4 leave day(s) for 2 controller(s) from the legacy bid tracker")
+    );
+}
+
+#[test]
+fn test_reimport_reports_recorded_leave() {
+    let mut fixture: PersistedFixture = setup();
+    import(&mut fixture, &EXPORT[..4]);
+
+    let response: LegacyImportResponse = import(&mut fixture, &EXPORT[..4]);
+
+    assert!(response.report.users_to_register.is_empty());
+    assert!(response.report.rounds_to_create.is_empty());
+    assert_eq!(response.report.bids_to_import, 0);
+    assert!(response.audit_event_ids.is_empty());
+    assert_eq!(
+        reasons(&response.report)[0],
+        (
+            1,
+            "Leave on 2026-07-04 in round 1 is already recorded for 'AA'"
+        )
+    );
+    assert_eq!(response.report.unmappable.len(), 4);
+}
+
+#[test]
+fn test_export_without_required_headers_is_rejected() {
+    let mut fixture: PersistedFixture = setup();
+
+    let result: Result<LegacyImportReport, ApiError> = preview_legacy_import(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &LegacyImportRequest {
+            bid_year_id: fixture.bid_year_id,
+            csv_content: String::from("Name,Leave Date\nAlice Baker,7/4/2026\n"),
+        },
+        &create_test_admin(),
+    );
+
+    assert!(matches!(result, Err(ApiError::InvalidCsvFormat { .. })));
+}
+
+#[test]
+fn test_import_requires_admin() {
+    let mut fixture: PersistedFixture = setup();
+    let request: LegacyImportRequest = request(&fixture, EXPORT);
+
+    let result: Result<LegacyImportResponse, ApiError> = import_legacy_bids(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        &create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
+
+#[test]
+fn test_import_rejected_once_bid_year_is_locked() {
+    let mut fixture: PersistedFixture = setup();
+    fixture
+        .persistence
+        .update_lifecycle_state(fixture.bid_year_id, "BiddingActive")
+        .unwrap();
+    let request: LegacyImportRequest = request(&fixture, EXPORT);
+
+    let result: Result<LegacyImportReport, ApiError> = preview_legacy_import(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &request,
+        &create_test_admin(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "legacy_import_lifecycle"
+    ));
+}
@@ -15,11 +15,13 @@ use zab_bid_persistence::SqlitePersistence;
 
 use crate::{
     ApiError, AuthenticatedActor, CreateAreaRequest, RegisterUserRequest, Role,
-    UpdateUserParticipationRequest, create_area, register_user, update_user_participation,
+    TransitionToBiddingActiveRequest, UpdateUserParticipationRequest, create_area, register_user,
+    transition_to_bidding_active, update_user_participation,
 };
 
 use super::helpers::{
     bootstrap_with_ids, create_test_admin, create_test_admin_operator, create_test_cause,
+    setup_test_persistence,
 };
 
 /// Test that area creation is blocked after `Canonicalized` state.
@@ -315,3 +317,43 @@ fn test_area_creation_allowed_in_bootstrap_complete() {
     // Verify the operation is allowed in `BootstrapComplete` state
     assert!(result.is_ok());
 }
+
+/// Test that bidding cannot be activated while readiness blockers exist.
+#[test]
+fn test_bidding_activation_refused_while_not_ready() {
+    let mut persistence = setup_test_persistence().expect("Failed to setup test persistence");
+    let metadata: BootstrapMetadata = persistence
+        .get_bootstrap_metadata()
+        .expect("Failed to get metadata");
+    let bid_year_id: i64 = metadata.bid_years[0]
+        .bid_year_id()
+        .expect("Bid year ID not found");
+
+    persistence
+        .update_lifecycle_state(bid_year_id, "Canonicalized")
+        .expect("Failed to update lifecycle state");
+
+    let result = transition_to_bidding_active(
+        &mut persistence,
+        &metadata,
+        &TransitionToBiddingActiveRequest { bid_year_id },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+
+    match result {
+        Err(ApiError::DomainRuleViolation { rule, message }) => {
+            assert_eq!(rule, "bidding_requires_readiness");
+            assert!(message.contains("Area 'NORTH' has no rounds configured"));
+            assert!(message.contains("Bid schedule is not set"));
+        }
+        other => panic!("Expected DomainRuleViolation, got: {other:?}"),
+    }
+    assert_eq!(
+        persistence
+            .get_lifecycle_state(bid_year_id)
+            .expect("Failed to get lifecycle state"),
+        "Canonicalized"
+    );
+}
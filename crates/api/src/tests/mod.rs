@@ -7,10 +7,58 @@
 
 #![allow(clippy::expect_used, clippy::unwrap_used)]
 
+mod amendment_policy_tests;
+mod anonymization_tests;
 mod api_tests;
+mod area_bid_schedule_tests;
+mod area_delegation_tests;
+mod audit_compaction_tests;
+mod audit_timeline_tests;
 mod authorization_tests;
+mod bid_preference_tests;
+mod bid_rule_tests;
+mod bidding_progress_tests;
+mod blackout_date_tests;
+mod bootstrap_status_tests;
+mod bulk_register_tests;
+mod chat_tests;
+mod crew_slot_tests;
+mod current_bidder_tests;
+mod dashboard_tests;
+mod eligibility_tests;
+mod facility_tests;
+mod feature_flag_tests;
+mod free_text_tests;
 mod helpers;
+mod initials_change_tests;
+mod leave_bid_tests;
+mod leave_cap_tests;
+mod legacy_import_tests;
 mod lifecycle_enforcement_tests;
+mod no_bid_review_tests;
+mod notification_tests;
+mod operator_profile_tests;
 mod operator_tests;
+mod overbid_tests;
+mod override_import_tests;
+mod override_tests;
 mod password_tests;
+mod portal_tests;
+mod prime_date_tests;
+mod reopen_tests;
+mod report_tests;
+mod rollback_tests;
+mod roster_sync_tests;
+mod round_bid_order_tests;
+mod round_eligibility_tests;
+mod round_sign_off_tests;
+mod round_template_tests;
 mod round_tests;
+mod saved_audit_query_tests;
+mod scope_freeze_tests;
+mod self_bid_tests;
+mod slot_inventory_tests;
+mod user_merge_tests;
+mod versioning_tests;
+mod waitlist_tests;
+mod webhook_tests;
@@ -0,0 +1,281 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the No Bid review workflow.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    setup_test_persistence,
+};
+use crate::{
+    ListUnreviewedNoBidUsersResponse, ReviewNoBidUserRequest, ReviewNoBidUserResponse,
+    ReviewNoBidUsersRequest, ReviewNoBidUsersResponse, list_unreviewed_no_bid_users,
+    review_no_bid_user, review_no_bid_users,
+};
+use zab_bid::{BootstrapMetadata, Command, State, TransitionResult, apply};
+use zab_bid_audit::{Actor, AuditEvent, Cause};
+use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, UserType};
+use zab_bid_persistence::SqlitePersistence;
+
+const REASON: &str = "Confirmed on extended medical leave";
+
+fn test_actor() -> Actor {
+    Actor::with_operator(
+        String::from("test-admin"),
+        String::from("admin"),
+        1,
+        String::from("test-operator"),
+        String::from("Test Operator"),
+    )
+}
+
+/// Registers a user in the given 2026 area and returns its ID.
+fn register(persistence: &mut SqlitePersistence, area_code: &str, initials: &str) -> i64 {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year: BidYear = BidYear::new(2026);
+    let area: Area = Area::new(area_code);
+
+    let result: TransitionResult = apply(
+        &metadata,
+        &persistence.get_current_state(&bid_year, &area).unwrap(),
+        &bid_year,
+        Command::RegisterUser {
+            initials: Initials::new(initials),
+            name: format!("User {initials}"),
+            area: area.clone(),
+            user_type: UserType::CPC,
+            crew: Some(Crew::new(1).unwrap()),
+            seniority_data: SeniorityData::new(
+                String::from("2019-01-15"),
+                String::from("2019-06-01"),
+                String::from("2020-01-15"),
+                String::from("2020-01-15"),
+                Some(3),
+            ),
+        },
+        test_actor(),
+        Cause::new(
+            String::from("csv-import"),
+            String::from("Imported without an area"),
+        ),
+    )
+    .unwrap();
+    persistence.persist_transition(&result).unwrap();
+
+    let state: State = persistence.get_current_state(&bid_year, &area).unwrap();
+    state
+        .users
+        .values()
+        .find(|u| u.initials.value() == initials)
+        .and_then(|u| u.user_id)
+        .unwrap()
+}
+
+/// Creates the No Bid area for 2026 and returns `(bid_year_id, area_id)`.
+fn setup_no_bid(persistence: &mut SqlitePersistence) -> (i64, i64) {
+    let bid_year_id: i64 = persistence
+        .get_bootstrap_metadata()
+        .unwrap()
+        .bid_years
+        .iter()
+        .find(|by| by.year() == 2026)
+        .and_then(BidYear::bid_year_id)
+        .unwrap();
+    let area_id: i64 = persistence
+        .create_system_area(bid_year_id, Area::NO_BID_AREA_CODE)
+        .unwrap();
+    (bid_year_id, area_id)
+}
+
+#[test]
+fn test_listing_pairs_users_with_placement_reason() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let (bid_year_id, area_id) = setup_no_bid(&mut persistence);
+    let ab: i64 = register(&mut persistence, Area::NO_BID_AREA_CODE, "AB");
+    register(&mut persistence, "North", "CD");
+
+    let listing: ListUnreviewedNoBidUsersResponse =
+        list_unreviewed_no_bid_users(&mut persistence, bid_year_id).unwrap();
+
+    assert_eq!(listing.area_id, Some(area_id));
+    assert_eq!(listing.users.len(), 1);
+    let user = &listing.users[0];
+    assert_eq!(user.user_id, ab);
+    assert_eq!(user.initials, "AB");
+    assert_eq!(user.placement_action.as_deref(), Some("RegisterUser"));
+    assert!(user.placement_audit_event_id.is_some());
+    assert_eq!(
+        user.placement_reason,
+        "Registered directly into No Bid: Imported without an area"
+    );
+}
+
+#[test]
+fn test_review_records_reason_and_clears_listing() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let (bid_year_id, _area_id) = setup_no_bid(&mut persistence);
+    let ab: i64 = register(&mut persistence, Area::NO_BID_AREA_CODE, "AB");
+
+    let response: ReviewNoBidUserResponse = review_no_bid_user(
+        &mut persistence,
+        &ReviewNoBidUserRequest {
+            user_id: ab,
+            reason: REASON.to_string(),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+    assert_eq!(response.user_id, ab);
+
+    let listing: ListUnreviewedNoBidUsersResponse =
+        list_unreviewed_no_bid_users(&mut persistence, bid_year_id).unwrap();
+    assert!(listing.users.is_empty());
+    assert_eq!(
+        persistence
+            .count_unreviewed_no_bid_users(bid_year_id)
+            .unwrap(),
+        0
+    );
+
+    let timeline: Vec<AuditEvent> = persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new(Area::NO_BID_AREA_CODE))
+        .unwrap();
+    let event: &AuditEvent = timeline
+        .iter()
+        .find(|e| e.event_id == Some(response.audit_event_id))
+        .unwrap();
+    assert_eq!(event.action.name, "ReviewNoBidUser");
+    assert!(event.action.details.as_deref().unwrap().contains(REASON));
+    assert_eq!(event.before.data, "no_bid_reviewed=false");
+    assert_eq!(event.after.data, "no_bid_reviewed=true");
+}
+
+#[test]
+fn test_review_rejects_invalid_requests() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    setup_no_bid(&mut persistence);
+    let ab: i64 = register(&mut persistence, Area::NO_BID_AREA_CODE, "AB");
+    let cd: i64 = register(&mut persistence, "North", "CD");
+
+    let request = |user_id: i64, reason: &str| ReviewNoBidUserRequest {
+        user_id,
+        reason: reason.to_string(),
+    };
+
+    let result = review_no_bid_user(
+        &mut persistence,
+        &request(ab, "  short   "),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "reason"));
+
+    let result = review_no_bid_user(
+        &mut persistence,
+        &request(ab, REASON),
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    let result = review_no_bid_user(
+        &mut persistence,
+        &request(cd, REASON),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. })
+            if rule == "no_bid_review_requires_system_area"
+    ));
+
+    let result = review_no_bid_user(
+        &mut persistence,
+        &request(9999, REASON),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
+
+#[test]
+fn test_batch_review_is_all_or_nothing() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let (bid_year_id, _area_id) = setup_no_bid(&mut persistence);
+    let ab: i64 = register(&mut persistence, Area::NO_BID_AREA_CODE, "AB");
+    let ef: i64 = register(&mut persistence, Area::NO_BID_AREA_CODE, "EF");
+    let cd: i64 = register(&mut persistence, "North", "CD");
+
+    let batch = |user_ids: Vec<i64>| ReviewNoBidUsersRequest {
+        user_ids,
+        reason: REASON.to_string(),
+    };
+
+    let result = review_no_bid_users(
+        &mut persistence,
+        &batch(vec![ab, cd]),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(matches!(result, Err(ApiError::DomainRuleViolation { .. })));
+    assert_eq!(
+        persistence
+            .count_unreviewed_no_bid_users(bid_year_id)
+            .unwrap(),
+        2
+    );
+
+    for user_ids in [Vec::new(), vec![ab, ab]] {
+        let result = review_no_bid_users(
+            &mut persistence,
+            &batch(user_ids),
+            &create_test_admin(),
+            &create_test_admin_operator(),
+        );
+        assert!(
+            matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "user_ids")
+        );
+    }
+
+    let response: ReviewNoBidUsersResponse = review_no_bid_users(
+        &mut persistence,
+        &batch(vec![ef, ab]),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+    let reviewed: Vec<i64> = response.reviewed.iter().map(|r| r.user_id).collect();
+    assert_eq!(reviewed, vec![ef, ab]);
+    assert_eq!(
+        persistence
+            .count_unreviewed_no_bid_users(bid_year_id)
+            .unwrap(),
+        0
+    );
+}
+
+#[test]
+fn test_listing_without_system_area_is_empty() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let bid_year_id: i64 = persistence
+        .get_bootstrap_metadata()
+        .unwrap()
+        .bid_years
+        .iter()
+        .find(|by| by.year() == 2026)
+        .and_then(BidYear::bid_year_id)
+        .unwrap();
+
+    let listing: ListUnreviewedNoBidUsersResponse =
+        list_unreviewed_no_bid_users(&mut persistence, bid_year_id).unwrap();
+    assert_eq!(listing.area_id, None);
+    assert!(listing.users.is_empty());
+
+    let result = list_unreviewed_no_bid_users(&mut persistence, 9999);
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
@@ -0,0 +1,210 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for notification contact and log handlers.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_cause,
+    setup_test_persistence,
+};
+use crate::{
+    GetUserContactResponse, ListUserNotificationsResponse, SetUserContactRequest,
+    SetUserContactResponse, get_user_contact, list_user_notifications, set_user_contact,
+};
+use zab_bid::{BootstrapMetadata, Command, State, TransitionResult, apply};
+use zab_bid_audit::{Actor, Cause};
+use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, UserType};
+use zab_bid_persistence::SqlitePersistence;
+
+/// Creates persistence with 2026/North and one registered user; returns the user ID.
+fn setup_with_user() -> (SqlitePersistence, i64) {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year: BidYear = BidYear::new(2026);
+    let area: Area = Area::new("North");
+
+    let result: TransitionResult = apply(
+        &metadata,
+        &State::new(bid_year.clone(), area.clone()),
+        &bid_year,
+        Command::RegisterUser {
+            initials: Initials::new("AB"),
+            name: String::from("Alice Baker"),
+            area: area.clone(),
+            user_type: UserType::CPC,
+            crew: Some(Crew::new(1).unwrap()),
+            seniority_data: SeniorityData::new(
+                String::from("2019-01-15"),
+                String::from("2019-06-01"),
+                String::from("2020-01-15"),
+                String::from("2020-01-15"),
+                Some(1),
+            ),
+        },
+        Actor::with_operator(
+            String::from("test-admin"),
+            String::from("admin"),
+            1,
+            String::from("test-operator"),
+            String::from("Test Operator"),
+        ),
+        Cause::new(String::from("test"), String::from("Test")),
+    )
+    .unwrap();
+    persistence.persist_transition(&result).unwrap();
+
+    let state: State = persistence.get_current_state(&bid_year, &area).unwrap();
+    let user_id: i64 = state.users.values().next().unwrap().user_id.unwrap();
+    (persistence, user_id)
+}
+
+fn contact_request(user_id: i64, email: &str) -> SetUserContactRequest {
+    SetUserContactRequest {
+        user_id,
+        email: email.to_string(),
+        email_enabled: true,
+    }
+}
+
+#[test]
+fn test_set_user_contact_stores_and_audits() {
+    let (mut persistence, user_id) = setup_with_user();
+
+    let response: SetUserContactResponse = set_user_contact(
+        &mut persistence,
+        &contact_request(user_id, "  alice@example.test "),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(response.contact.user_id, user_id);
+    assert_eq!(response.contact.email, "alice@example.test");
+    assert!(response.contact.email_enabled);
+
+    let page = persistence
+        .get_audit_timeline_page(
+            zab_bid_persistence::AuditTimelineScope::Global,
+            &zab_bid_persistence::AuditTimelineFilter::default(),
+            None,
+            10,
+        )
+        .unwrap();
+    let event = &page.entries.last().unwrap().event;
+    assert_eq!(event.action.name, "SetUserContact");
+    assert_eq!(event.before.data, "contact_does_not_exist");
+    assert!(event.after.data.contains("alice@example.test"));
+}
+
+#[test]
+fn test_get_user_contact_round_trip() {
+    let (mut persistence, user_id) = setup_with_user();
+
+    let empty: GetUserContactResponse =
+        get_user_contact(&mut persistence, user_id, &create_test_admin()).unwrap();
+    assert_eq!(empty.contact, None);
+
+    set_user_contact(
+        &mut persistence,
+        &contact_request(user_id, "alice@example.test"),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let stored: GetUserContactResponse =
+        get_user_contact(&mut persistence, user_id, &create_test_admin()).unwrap();
+    assert_eq!(stored.contact.unwrap().email, "alice@example.test");
+}
+
+#[test]
+fn test_set_user_contact_rejects_invalid_email() {
+    let (mut persistence, user_id) = setup_with_user();
+
+    for email in [
+        "",
+        "alice",
+        "alice@",
+        "@example.test",
+        "alice@localhost",
+        "a b@example.test",
+    ] {
+        let result: Result<SetUserContactResponse, ApiError> = set_user_contact(
+            &mut persistence,
+            &contact_request(user_id, email),
+            &create_test_admin(),
+            &create_test_admin_operator(),
+            create_test_cause(),
+        );
+        assert!(
+            matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "email"),
+            "expected '{email}' to be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_set_user_contact_unknown_user() {
+    let (mut persistence, _) = setup_with_user();
+
+    let result: Result<SetUserContactResponse, ApiError> = set_user_contact(
+        &mut persistence,
+        &contact_request(9999, "alice@example.test"),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
+
+#[test]
+fn test_contact_handlers_require_admin() {
+    let (mut persistence, user_id) = setup_with_user();
+
+    let set: Result<SetUserContactResponse, ApiError> = set_user_contact(
+        &mut persistence,
+        &contact_request(user_id, "alice@example.test"),
+        &create_test_bidder(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(set, Err(ApiError::Unauthorized { .. })));
+
+    let get: Result<GetUserContactResponse, ApiError> =
+        get_user_contact(&mut persistence, user_id, &create_test_bidder());
+    assert!(matches!(get, Err(ApiError::Unauthorized { .. })));
+
+    let list: Result<ListUserNotificationsResponse, ApiError> =
+        list_user_notifications(&mut persistence, user_id, None, &create_test_bidder());
+    assert!(matches!(list, Err(ApiError::Unauthorized { .. })));
+}
+
+#[test]
+fn test_list_user_notifications() {
+    let (mut persistence, user_id) = setup_with_user();
+    persistence
+        .record_notification(
+            user_id,
+            "window_opened",
+            1,
+            "alice@example.test",
+            "Your bid window is open",
+            None,
+        )
+        .unwrap();
+
+    let response: ListUserNotificationsResponse =
+        list_user_notifications(&mut persistence, user_id, None, &create_test_admin()).unwrap();
+    assert_eq!(response.notifications.len(), 1);
+    assert_eq!(response.notifications[0].kind, "window_opened");
+    assert!(response.notifications[0].delivered);
+
+    let out_of_range: Result<ListUserNotificationsResponse, ApiError> =
+        list_user_notifications(&mut persistence, user_id, Some(0), &create_test_admin());
+    assert!(matches!(out_of_range, Err(ApiError::InvalidInput { .. })));
+}
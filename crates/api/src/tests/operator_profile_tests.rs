@@ -0,0 +1,153 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for operator self-service password and profile changes.
+
+use crate::ApiError;
+use crate::request_response::{
+    ChangeOwnPasswordResponse, ChangePasswordRequest, UpdateOwnProfileRequest,
+    UpdateOwnProfileResponse,
+};
+use crate::tests::helpers::create_test_cause;
+use crate::{change_own_password, update_own_profile};
+use zab_bid_persistence::{OperatorData, SecretToken, SqlitePersistence};
+
+const EXPIRES_AT: &str = "2099-12-31T23:59:59Z";
+
+fn token(value: &str) -> SecretToken {
+    SecretToken::new(String::from(value))
+}
+
+/// Creates a Bidder operator signed in on two devices.
+fn setup_operator() -> (SqlitePersistence, OperatorData) {
+    let mut persistence = SqlitePersistence::new_in_memory().unwrap();
+    let operator_id = persistence
+        .create_operator("testop", "Test Operator", "OldPassword123!", "Bidder")
+        .unwrap();
+    persistence
+        .create_session(&token("laptop_token"), operator_id, EXPIRES_AT)
+        .unwrap();
+    persistence
+        .create_session(&token("phone_token"), operator_id, EXPIRES_AT)
+        .unwrap();
+
+    let operator = persistence
+        .get_operator_by_id(operator_id)
+        .unwrap()
+        .unwrap();
+    (persistence, operator)
+}
+
+fn password_request(current: &str, new: &str) -> ChangePasswordRequest {
+    ChangePasswordRequest {
+        current_password: String::from(current),
+        new_password: String::from(new),
+        new_password_confirmation: String::from(new),
+    }
+}
+
+#[test]
+fn test_change_own_password_signs_out_other_devices_only() {
+    let (mut persistence, operator) = setup_operator();
+
+    let response: ChangeOwnPasswordResponse = change_own_password(
+        &mut persistence,
+        &password_request("OldPassword123!", "NewPassword456!"),
+        &token("laptop_token"),
+        &operator,
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(response.sessions_invalidated, 1);
+    assert!(
+        persistence
+            .get_session_by_token(&token("laptop_token"))
+            .unwrap()
+            .is_some()
+    );
+    assert!(
+        persistence
+            .get_session_by_token(&token("phone_token"))
+            .unwrap()
+            .is_none()
+    );
+
+    let updated = persistence
+        .get_operator_by_id(operator.operator_id)
+        .unwrap()
+        .unwrap();
+    assert!(
+        persistence
+            .verify_password("NewPassword456!", &updated.password_hash)
+            .unwrap()
+    );
+
+    let events = persistence.get_global_audit_events().unwrap();
+    let last_event = events.last().unwrap();
+    assert_eq!(last_event.action.name, "ChangePassword");
+    assert_eq!(last_event.actor.operator_id, Some(operator.operator_id));
+}
+
+#[test]
+fn test_change_own_password_requires_current_password() {
+    let (mut persistence, operator) = setup_operator();
+
+    let result: Result<ChangeOwnPasswordResponse, ApiError> = change_own_password(
+        &mut persistence,
+        &password_request("WrongPassword123!", "NewPassword456!"),
+        &token("laptop_token"),
+        &operator,
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::AuthenticationFailed { .. })));
+    assert!(
+        persistence
+            .get_session_by_token(&token("phone_token"))
+            .unwrap()
+            .is_some()
+    );
+}
+
+#[test]
+fn test_update_own_profile_changes_display_name() {
+    let (mut persistence, operator) = setup_operator();
+
+    let response: UpdateOwnProfileResponse = update_own_profile(
+        &mut persistence,
+        &UpdateOwnProfileRequest {
+            display_name: String::from("  Renamed Operator "),
+        },
+        &operator,
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(response.display_name, "Renamed Operator");
+    let updated = persistence
+        .get_operator_by_id(operator.operator_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(updated.display_name, "Renamed Operator");
+    assert_eq!(updated.login_name, operator.login_name);
+    assert_eq!(updated.role, operator.role);
+}
+
+#[test]
+fn test_update_own_profile_rejects_blank_display_name() {
+    let (mut persistence, operator) = setup_operator();
+
+    let result: Result<UpdateOwnProfileResponse, ApiError> = update_own_profile(
+        &mut persistence,
+        &UpdateOwnProfileRequest {
+            display_name: String::from("   "),
+        },
+        &operator,
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::InvalidInput { .. })));
+}
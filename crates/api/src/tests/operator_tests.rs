@@ -7,14 +7,18 @@
 
 use crate::auth::{AuthenticatedActor, Role};
 use crate::error::ApiError;
-use crate::tests::helpers::create_test_bidder_operator;
+use crate::tests::helpers::{create_test_bidder_operator, setup_test_persistence};
 use crate::{
-    DeleteOperatorRequest, DeleteOperatorResponse, DisableOperatorRequest, DisableOperatorResponse,
-    EnableOperatorRequest, EnableOperatorResponse, ListOperatorsResponse, create_operator,
-    delete_operator, disable_operator, enable_operator, list_operators,
+    ChangeOperatorRoleRequest, DeleteOperatorRequest, DeleteOperatorResponse,
+    DisableOperatorRequest, DisableOperatorResponse, EnableOperatorRequest, EnableOperatorResponse,
+    ListOperatorsResponse, LoginRequest, OperatorInfo, RevokeOperatorSessionsRequest,
+    SetOperatorAreaScopesRequest, change_operator_role, create_operator, delete_operator,
+    disable_operator, enable_operator, list_operators, login, revoke_operator_sessions,
+    set_operator_area_scopes,
 };
+use zab_bid::BootstrapMetadata;
 use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
-use zab_bid_persistence::SqlitePersistence;
+use zab_bid_persistence::{SecretToken, SqlitePersistence};
 
 fn create_test_admin() -> AuthenticatedActor {
     AuthenticatedActor {
@@ -785,3 +789,301 @@ fn test_can_delete_disabled_admin_when_no_active_admins() {
 
     assert!(result.is_ok());
 }
+
+#[test]
+fn test_cannot_demote_last_active_admin() {
+    let mut persistence = SqlitePersistence::new_in_memory().unwrap();
+    let admin = create_test_admin();
+
+    let admin_op_id = persistence
+        .create_operator("admin1", "Admin One", "password", "Admin")
+        .unwrap();
+    let admin_operator = persistence
+        .get_operator_by_id(admin_op_id)
+        .unwrap()
+        .unwrap();
+
+    let request = ChangeOperatorRoleRequest {
+        operator_id: admin_op_id,
+        role: String::from("Bidder"),
+    };
+
+    let result = change_operator_role(
+        &mut persistence,
+        &request,
+        &admin,
+        &admin_operator,
+        create_test_cause(),
+    );
+
+    match result.unwrap_err() {
+        ApiError::DomainRuleViolation { rule, .. } => assert_eq!(rule, "last_active_admin"),
+        other => panic!("Expected DomainRuleViolation error, got: {other:?}"),
+    }
+    let unchanged = persistence
+        .get_operator_by_id(admin_op_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(unchanged.role, "Admin");
+}
+
+#[test]
+fn test_admin_can_promote_bidder_and_demote_another_admin() {
+    let mut persistence = SqlitePersistence::new_in_memory().unwrap();
+    let admin = create_test_admin();
+
+    let admin_op_id = persistence
+        .create_operator("admin1", "Admin One", "password", "Admin")
+        .unwrap();
+    let admin_operator = persistence
+        .get_operator_by_id(admin_op_id)
+        .unwrap()
+        .unwrap();
+    let bidder_op_id = persistence
+        .create_operator("bidder1", "Bidder One", "password", "Bidder")
+        .unwrap();
+
+    let promote = ChangeOperatorRoleRequest {
+        operator_id: bidder_op_id,
+        role: String::from("Admin"),
+    };
+    change_operator_role(
+        &mut persistence,
+        &promote,
+        &admin,
+        &admin_operator,
+        create_test_cause(),
+    )
+    .unwrap();
+
+    // With two active admins, demoting the first one is allowed
+    let demote = ChangeOperatorRoleRequest {
+        operator_id: admin_op_id,
+        role: String::from("Bidder"),
+    };
+    change_operator_role(
+        &mut persistence,
+        &demote,
+        &admin,
+        &admin_operator,
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let promoted = persistence
+        .get_operator_by_id(bidder_op_id)
+        .unwrap()
+        .unwrap();
+    let demoted = persistence
+        .get_operator_by_id(admin_op_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(promoted.role, "Admin");
+    assert_eq!(demoted.role, "Bidder");
+
+    let events = persistence.get_global_audit_events().unwrap();
+    let last_event = events.last().unwrap();
+    assert_eq!(last_event.action.name, "ChangeOperatorRole");
+}
+
+#[test]
+fn test_change_operator_role_requires_admin() {
+    let mut persistence = SqlitePersistence::new_in_memory().unwrap();
+    let bidder = create_test_bidder();
+    let bidder_operator = create_test_bidder_operator();
+
+    let request = ChangeOperatorRoleRequest {
+        operator_id: 1,
+        role: String::from("Admin"),
+    };
+
+    let result = change_operator_role(
+        &mut persistence,
+        &request,
+        &bidder,
+        &bidder_operator,
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
+
+#[test]
+fn test_admin_can_revoke_operator_sessions() {
+    let mut persistence = SqlitePersistence::new_in_memory().unwrap();
+    let admin = create_test_admin();
+
+    let admin_op_id = persistence
+        .create_operator("admin1", "Admin One", "password", "Admin")
+        .unwrap();
+    let admin_operator = persistence
+        .get_operator_by_id(admin_op_id)
+        .unwrap()
+        .unwrap();
+    let bidder_op_id = persistence
+        .create_operator("bidder1", "Bidder One", "password", "Bidder")
+        .unwrap();
+    persistence
+        .create_session(
+            &SecretToken::new(String::from("bidder_token_1")),
+            bidder_op_id,
+            "2099-12-31T23:59:59Z",
+        )
+        .unwrap();
+    persistence
+        .create_session(
+            &SecretToken::new(String::from("bidder_token_2")),
+            bidder_op_id,
+            "2099-12-31T23:59:59Z",
+        )
+        .unwrap();
+
+    let request = RevokeOperatorSessionsRequest {
+        operator_id: bidder_op_id,
+    };
+    let response = revoke_operator_sessions(
+        &mut persistence,
+        request,
+        &admin,
+        &admin_operator,
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(response.sessions_revoked, 2);
+    assert!(
+        persistence
+            .get_session_by_token(&SecretToken::new(String::from("bidder_token_1")))
+            .unwrap()
+            .is_none()
+    );
+    let bidder = persistence
+        .get_operator_by_id(bidder_op_id)
+        .unwrap()
+        .unwrap();
+    assert!(!bidder.is_disabled);
+}
+
+fn listed_operator(
+    persistence: &mut SqlitePersistence,
+    admin_operator_id: i64,
+    login_name: &str,
+) -> OperatorInfo {
+    let admin_operator = persistence
+        .get_operator_by_id(admin_operator_id)
+        .unwrap()
+        .unwrap();
+    list_operators(persistence, &create_test_admin(), &admin_operator)
+        .unwrap()
+        .operators
+        .into_iter()
+        .find(|op| op.login_name.eq_ignore_ascii_case(login_name))
+        .unwrap()
+}
+
+fn attempt_login(persistence: &mut SqlitePersistence, password: &str) -> bool {
+    login(
+        persistence,
+        &LoginRequest {
+            login_name: String::from("bidder1"),
+            password: String::from(password),
+        },
+    )
+    .is_ok()
+}
+
+#[test]
+fn test_list_operators_includes_activity_and_area_scopes() {
+    let mut persistence = setup_test_persistence().unwrap();
+    let admin_operator_id = persistence
+        .create_operator("admin1", "Admin One", "password", "Admin")
+        .unwrap();
+    let admin_operator = persistence
+        .get_operator_by_id(admin_operator_id)
+        .unwrap()
+        .unwrap();
+    let bidder_operator_id = persistence
+        .create_operator("bidder1", "Bidder One", "password", "Bidder")
+        .unwrap();
+
+    assert!(!attempt_login(&mut persistence, "wrong"));
+    assert!(!attempt_login(&mut persistence, "also wrong"));
+    persistence
+        .create_session(
+            &SecretToken::new(String::from("bidder_token")),
+            bidder_operator_id,
+            "2099-12-31T23:59:59Z",
+        )
+        .unwrap();
+    persistence
+        .create_session(
+            &SecretToken::new(String::from("stale_token")),
+            bidder_operator_id,
+            "2000-01-01T00:00:00Z",
+        )
+        .unwrap();
+
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let north_id: i64 = metadata
+        .areas
+        .iter()
+        .find(|(_, area)| area.id() == "NORTH")
+        .and_then(|(_, area)| area.area_id())
+        .unwrap();
+    set_operator_area_scopes(
+        &mut persistence,
+        &metadata,
+        &SetOperatorAreaScopesRequest {
+            operator_id: bidder_operator_id,
+            area_ids: vec![north_id, north_id],
+        },
+        &create_test_admin(),
+        &admin_operator,
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let bidder: OperatorInfo = listed_operator(&mut persistence, admin_operator_id, "bidder1");
+    assert_eq!(bidder.failed_login_count, 2);
+    assert_eq!(bidder.active_session_count, 1);
+    assert_eq!(bidder.area_scopes.len(), 1);
+    assert_eq!(bidder.area_scopes[0].area_id, north_id);
+    assert_eq!(bidder.area_scopes[0].year, 2026);
+
+    let admin: OperatorInfo = listed_operator(&mut persistence, admin_operator_id, "admin1");
+    assert_eq!(admin.failed_login_count, 0);
+    assert!(admin.area_scopes.is_empty());
+
+    // A successful sign-in clears the failed count
+    assert!(attempt_login(&mut persistence, "password"));
+    let bidder: OperatorInfo = listed_operator(&mut persistence, admin_operator_id, "bidder1");
+    assert_eq!(bidder.failed_login_count, 0);
+    assert_eq!(bidder.active_session_count, 2);
+}
+
+#[test]
+fn test_set_operator_area_scopes_rejects_unknown_area() {
+    let mut persistence = setup_test_persistence().unwrap();
+    let admin_operator_id = persistence
+        .create_operator("admin1", "Admin One", "password", "Admin")
+        .unwrap();
+    let admin_operator = persistence
+        .get_operator_by_id(admin_operator_id)
+        .unwrap()
+        .unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+
+    let result = set_operator_area_scopes(
+        &mut persistence,
+        &metadata,
+        &SetOperatorAreaScopesRequest {
+            operator_id: admin_operator_id,
+            area_ids: vec![9999],
+        },
+        &create_test_admin(),
+        &admin_operator,
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
@@ -0,0 +1,266 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for overbid requests and their Admin approval workflow.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_admin, create_test_admin_operator,
+    create_test_bidder, create_test_bidder_operator, create_test_cause,
+};
+use crate::{
+    ApproveOverbidRequest, DenyOverbidRequest, EnterLeaveBidRequest, ListOverbidRequestsResponse,
+    OverbidDecisionResponse, OverbidRequestInfo, RequestOverbidRequest, RequestOverbidResponse,
+    approve_overbid, deny_overbid, enter_leave_bid, list_overbid_requests, request_overbid,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::AuditEvent;
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates a `BiddingActive` 2026/North with users AA and AB and one
+/// round offering one slot per day.
+fn setup_bidding(allow_overbid: bool) -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(2)
+        .with_rounds(1)
+        .with_slots_per_day(1)
+        .with_lifecycle_state("BiddingActive")
+        .persist()
+        .unwrap();
+    // Bids are entered as the test bidder operator, which audit events reference
+    create_persisted_bidder_operator(&mut fixture.persistence).unwrap();
+    fixture
+        .persistence
+        .update_round(
+            fixture.round_ids[0],
+            "Round 1",
+            1,
+            1,
+            80,
+            false,
+            allow_overbid,
+        )
+        .unwrap();
+    fixture
+}
+
+fn enter(fixture: &mut PersistedFixture, initials: &str, date: &str) -> Result<(), ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let body: EnterLeaveBidRequest = EnterLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from(initials),
+        received_via: String::from("phone"),
+        leave_dates: vec![String::from(date)],
+        hours: 8,
+        override_reason: None,
+    };
+    enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &body,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+    .map(|_| ())
+}
+
+fn request(
+    fixture: &mut PersistedFixture,
+    initials: &str,
+    date: &str,
+) -> Result<RequestOverbidResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let body: RequestOverbidRequest = RequestOverbidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from(initials),
+        received_via: String::from("in_person"),
+        leave_date: String::from(date),
+        hours: 8,
+    };
+    request_overbid(
+        &mut fixture.persistence,
+        &metadata,
+        &body,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+}
+
+fn list(fixture: &mut PersistedFixture) -> ListOverbidRequestsResponse {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let area_id: i64 = fixture.area_id("North");
+    let round_id: i64 = fixture.round_ids[0];
+    list_overbid_requests(&mut fixture.persistence, &metadata, area_id, round_id).unwrap()
+}
+
+#[test]
+fn test_approved_overbid_grants_leave_and_links_audit_events() {
+    let mut fixture: PersistedFixture = setup_bidding(true);
+    enter(&mut fixture, "AA", "2026-06-01").unwrap();
+
+    let requested: RequestOverbidResponse = request(&mut fixture, "AB", "2026-06-01").unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let decision: OverbidDecisionResponse = approve_overbid(
+        &mut fixture.persistence,
+        &metadata,
+        &ApproveOverbidRequest {
+            overbid_request_id: requested.overbid_request_id,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(decision.status, "approved");
+    assert_eq!(decision.request_event_id, requested.request_event_id);
+    assert!(decision.leave_bid_id.is_some());
+
+    // The day grew by one slot, which the approved leave now holds
+    assert_eq!(
+        fixture
+            .persistence
+            .get_slot_adjustment(fixture.area_id("North"), fixture.round_ids[0], "2026-06-01")
+            .unwrap(),
+        1
+    );
+
+    let rows: Vec<OverbidRequestInfo> = list(&mut fixture).requests;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].status, "approved");
+    assert_eq!(rows[0].request_event_id, Some(requested.request_event_id));
+    assert_eq!(rows[0].decision_event_id, Some(decision.decision_event_id));
+    assert_eq!(rows[0].leave_bid_id, decision.leave_bid_id);
+
+    let timeline: Vec<AuditEvent> = fixture
+        .persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    let approval: &AuditEvent = timeline
+        .iter()
+        .find(|event| event.action.name == "ApproveOverbid")
+        .unwrap();
+    assert!(
+        approval
+            .after
+            .data
+            .contains(&format!("request_event_id={}", requested.request_event_id))
+    );
+
+    // A decided request cannot be decided again
+    let result: Result<OverbidDecisionResponse, ApiError> = deny_overbid(
+        &mut fixture.persistence,
+        &metadata,
+        &DenyOverbidRequest {
+            overbid_request_id: requested.overbid_request_id,
+            reason: String::from("Too late"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "overbid"
+    ));
+}
+
+#[test]
+fn test_denied_overbid_records_reason_without_leave() {
+    let mut fixture: PersistedFixture = setup_bidding(true);
+    enter(&mut fixture, "AA", "2026-06-01").unwrap();
+    let requested: RequestOverbidResponse = request(&mut fixture, "AB", "2026-06-01").unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let unauthorized: Result<OverbidDecisionResponse, ApiError> = approve_overbid(
+        &mut fixture.persistence,
+        &metadata,
+        &ApproveOverbidRequest {
+            overbid_request_id: requested.overbid_request_id,
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(unauthorized, Err(ApiError::Unauthorized { .. })));
+
+    let decision: OverbidDecisionResponse = deny_overbid(
+        &mut fixture.persistence,
+        &metadata,
+        &DenyOverbidRequest {
+            overbid_request_id: requested.overbid_request_id,
+            reason: String::from("Staffing too thin"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(decision.status, "denied");
+    assert_eq!(decision.leave_bid_id, None);
+
+    let rows: Vec<OverbidRequestInfo> = list(&mut fixture).requests;
+    assert_eq!(
+        rows[0].decision_reason.as_deref(),
+        Some("Staffing too thin")
+    );
+    assert_eq!(
+        fixture
+            .persistence
+            .get_slot_adjustment(fixture.area_id("North"), fixture.round_ids[0], "2026-06-01")
+            .unwrap(),
+        0
+    );
+}
+
+#[test]
+fn test_overbid_request_rejected_when_not_needed_or_not_allowed() {
+    // Slots remain, so an ordinary leave bid is required
+    let mut fixture: PersistedFixture = setup_bidding(true);
+    let result: Result<RequestOverbidResponse, ApiError> =
+        request(&mut fixture, "AB", "2026-06-01");
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "overbid"
+    ));
+
+    // A pending request for the same day is not duplicated
+    enter(&mut fixture, "AA", "2026-06-01").unwrap();
+    request(&mut fixture, "AB", "2026-06-01").unwrap();
+    assert!(request(&mut fixture, "AB", "2026-06-01").is_err());
+
+    // Rounds without overbids refuse the request outright
+    let mut closed: PersistedFixture = setup_bidding(false);
+    enter(&mut closed, "AA", "2026-06-01").unwrap();
+    let result: Result<RequestOverbidResponse, ApiError> = request(&mut closed, "AB", "2026-06-01");
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "overbid"
+    ));
+    assert!(list(&mut closed).requests.is_empty());
+}
+
+#[test]
+fn test_operator_cannot_request_own_overbid() {
+    let mut fixture: PersistedFixture = setup_bidding(true);
+    enter(&mut fixture, "AA", "2026-06-01").unwrap();
+    fixture
+        .persistence
+        .set_operator_controller(create_test_bidder_operator().operator_id, Some("AB"))
+        .unwrap();
+
+    let result: Result<RequestOverbidResponse, ApiError> =
+        request(&mut fixture, "AB", "2026-06-01");
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "self_bid"
+    ));
+    assert!(list(&mut fixture).requests.is_empty());
+}
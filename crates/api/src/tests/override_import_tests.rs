@@ -0,0 +1,177 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for bulk override import.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{create_test_admin, create_test_admin_operator, create_test_bidder};
+use crate::{
+    ListOverridesResponse, OverrideImportReport, OverrideImportRequest, OverrideImportResponse,
+    apply_override_import, list_overrides, preview_override_import,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, StateSnapshot};
+use zab_bid_persistence::SqlitePersistence;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with users AA and AB and canonicalizes it.
+///
+/// Returns the persistence and `bid_year_id`.
+fn setup_canonicalized() -> (SqlitePersistence, i64) {
+    let PersistedFixture {
+        mut persistence,
+        operator_id,
+        bid_year_id,
+        ..
+    } = BidYearFixture::new(2026).with_users(2).persist().unwrap();
+
+    let event: AuditEvent = AuditEvent::new_global(
+        BidYearFixture::actor(operator_id),
+        BidYearFixture::cause(),
+        Action::new(String::from("CanonicalizeBidYear"), None),
+        StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+    );
+    persistence
+        .canonicalize_bid_year(bid_year_id, &event)
+        .unwrap();
+    persistence
+        .update_lifecycle_state(bid_year_id, "Canonicalized")
+        .unwrap();
+
+    (persistence, bid_year_id)
+}
+
+fn import_request(bid_year_id: i64, rows: &str) -> OverrideImportRequest {
+    OverrideImportRequest {
+        bid_year_id,
+        csv_content: format!("Init,Area,Field,Value,Reason\n{rows}"),
+    }
+}
+
+#[test]
+fn test_preview_reports_diff_without_changing_anything() {
+    let (mut persistence, bid_year_id) = setup_canonicalized();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let request: OverrideImportRequest = import_request(
+        bid_year_id,
+        "aa,North,eligibility,false,National review found lapsed certification\n\
+         AB,North,eligibility,true,Confirmed eligible by national review\n\
+         AB,North,Bid Order,7,Corrected SCD per national seniority audit\n\
+         ZZ,North,eligibility,false,No such controller on the roster\n\
+         AA,South,bid_order,2,Area does not match the roster\n\
+         AA,North,bid_order,0,Bid order must be positive\n\
+         AA,North,eligibility,true,Second eligibility row for AA\n\
+         AB,North,bid_order,2,short",
+    );
+
+    let report: OverrideImportReport =
+        preview_override_import(&mut persistence, &metadata, &request, &create_test_admin())
+            .unwrap();
+
+    assert_eq!(report.total_rows, 8);
+    assert_eq!(report.changes.len(), 2);
+    assert_eq!(report.changes[0].row_number, 1);
+    assert_eq!(report.changes[0].initials, "AA");
+    assert_eq!(report.changes[0].current_value, "can_bid=true");
+    assert_eq!(report.changes[0].new_value, "can_bid=false");
+    assert_eq!(report.changes[1].field, "bid_order");
+    assert_eq!(report.changes[1].new_value, "bid_order=Some(7)");
+    assert_eq!(report.unchanged_rows, vec![2]);
+    assert_eq!(
+        report
+            .invalid_rows
+            .iter()
+            .map(|row| row.row_number)
+            .collect::<Vec<usize>>(),
+        vec![4, 5, 6, 7, 8]
+    );
+
+    let overrides: ListOverridesResponse = list_overrides(&mut persistence, bid_year_id).unwrap();
+    assert!(overrides.overrides.is_empty());
+}
+
+#[test]
+fn test_apply_records_overrides_under_one_correlation() {
+    let (mut persistence, bid_year_id) = setup_canonicalized();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let request: OverrideImportRequest = import_request(
+        bid_year_id,
+        "AA,North,eligibility,false,National review found lapsed certification\n\
+         AB,North,bid_order,7,Corrected SCD per national seniority audit",
+    );
+
+    let response: OverrideImportResponse = apply_override_import(
+        &mut persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+
+    assert_eq!(response.override_ids.len(), 2);
+    for event_id in &response.audit_event_ids {
+        assert_eq!(
+            persistence.get_event_correlation_id(*event_id).unwrap(),
+            Some(response.correlation_id.clone())
+        );
+    }
+
+    let overrides: ListOverridesResponse = list_overrides(&mut persistence, bid_year_id).unwrap();
+    let reasons: Vec<&str> = overrides
+        .overrides
+        .iter()
+        .map(|o| o.reason.as_str())
+        .collect();
+    assert_eq!(
+        reasons,
+        vec![
+            "National review found lapsed certification",
+            "Corrected SCD per national seniority audit"
+        ]
+    );
+}
+
+#[test]
+fn test_apply_refuses_batch_with_invalid_rows() {
+    let (mut persistence, bid_year_id) = setup_canonicalized();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let request: OverrideImportRequest = import_request(
+        bid_year_id,
+        "AA,North,eligibility,false,National review found lapsed certification\n\
+         ZZ,North,eligibility,false,No such controller on the roster",
+    );
+
+    let result = apply_override_import(
+        &mut persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "override_import_invalid_rows"
+    ));
+    let overrides: ListOverridesResponse = list_overrides(&mut persistence, bid_year_id).unwrap();
+    assert!(overrides.overrides.is_empty());
+}
+
+#[test]
+fn test_override_import_requires_admin() {
+    let (mut persistence, bid_year_id) = setup_canonicalized();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let request: OverrideImportRequest = import_request(
+        bid_year_id,
+        "AA,North,eligibility,false,National review found lapsed certification",
+    );
+
+    let result =
+        preview_override_import(&mut persistence, &metadata, &request, &create_test_bidder());
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
@@ -0,0 +1,249 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for override listing and revert.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    setup_test_persistence,
+};
+use crate::{
+    ListOverridesResponse, OverrideBidOrderRequest, OverrideEligibilityRequest,
+    OverrideEligibilityResponse, RevertOverrideResponse, list_overrides, override_bid_order,
+    override_eligibility, revert_override,
+};
+use zab_bid::{BootstrapMetadata, Command, State, TransitionResult, apply};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, UserType};
+use zab_bid_persistence::SqlitePersistence;
+
+fn test_actor() -> Actor {
+    Actor::with_operator(
+        String::from("test-admin"),
+        String::from("admin"),
+        1,
+        String::from("test-operator"),
+        String::from("Test Operator"),
+    )
+}
+
+/// Creates 2026/North with user AB and canonicalizes it.
+///
+/// Returns the persistence, `bid_year_id`, and AB's user ID.
+fn setup_canonicalized() -> (SqlitePersistence, i64, i64) {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year: BidYear = BidYear::new(2026);
+    let area: Area = Area::new("North");
+
+    let result: TransitionResult = apply(
+        &metadata,
+        &persistence.get_current_state(&bid_year, &area).unwrap(),
+        &bid_year,
+        Command::RegisterUser {
+            initials: Initials::new("AB"),
+            name: String::from("Alice Baker"),
+            area: area.clone(),
+            user_type: UserType::CPC,
+            crew: Some(Crew::new(1).unwrap()),
+            seniority_data: SeniorityData::new(
+                String::from("2019-01-15"),
+                String::from("2019-06-01"),
+                String::from("2020-01-15"),
+                String::from("2020-01-15"),
+                Some(3),
+            ),
+        },
+        test_actor(),
+        Cause::new(String::from("test"), String::from("Test")),
+    )
+    .unwrap();
+    persistence.persist_transition(&result).unwrap();
+
+    let state: State = persistence.get_current_state(&bid_year, &area).unwrap();
+    let user_id: i64 = state.users.values().next().unwrap().user_id.unwrap();
+    let bid_year_id: i64 = persistence
+        .get_bootstrap_metadata()
+        .unwrap()
+        .bid_years
+        .iter()
+        .find(|by| by.year() == 2026)
+        .and_then(BidYear::bid_year_id)
+        .unwrap();
+
+    let event: AuditEvent = AuditEvent::new_global(
+        test_actor(),
+        Cause::new(String::from("test"), String::from("Test")),
+        Action::new(String::from("CanonicalizeBidYear"), None),
+        StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+    );
+    persistence
+        .canonicalize_bid_year(bid_year_id, &event)
+        .unwrap();
+    persistence
+        .update_lifecycle_state(bid_year_id, "Canonicalized")
+        .unwrap();
+
+    (persistence, bid_year_id, user_id)
+}
+
+fn override_can_bid(
+    persistence: &mut SqlitePersistence,
+    user_id: i64,
+    can_bid: bool,
+    reason: &str,
+) -> OverrideEligibilityResponse {
+    override_eligibility(
+        persistence,
+        &OverrideEligibilityRequest {
+            user_id,
+            can_bid,
+            reason: reason.to_string(),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_overrides_are_listed_with_reasons() {
+    let (mut persistence, bid_year_id, user_id) = setup_canonicalized();
+
+    let eligibility = override_can_bid(&mut persistence, user_id, false, "Medical hold this year");
+    override_bid_order(
+        &mut persistence,
+        &OverrideBidOrderRequest {
+            user_id,
+            bid_order: Some(4),
+            reason: String::from("Settlement of grievance"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+
+    let listing: ListOverridesResponse = list_overrides(&mut persistence, bid_year_id).unwrap();
+    assert_eq!(listing.year, 2026);
+    assert_eq!(listing.overrides.len(), 2);
+
+    let first = &listing.overrides[0];
+    assert_eq!(first.override_id, eligibility.override_id);
+    assert_eq!(first.audit_event_id, eligibility.audit_event_id);
+    assert_eq!(first.user_initials, "AB");
+    assert_eq!(first.override_kind, "eligibility");
+    assert_eq!(first.previous_value, "can_bid=true");
+    assert_eq!(first.new_value, "can_bid=false");
+    assert_eq!(first.reason, "Medical hold this year");
+    assert!(first.is_revertible);
+
+    let second = &listing.overrides[1];
+    assert_eq!(second.override_kind, "bid_order");
+    assert_eq!(second.new_value, "bid_order=Some(4)");
+
+    let result = list_overrides(&mut persistence, 9999);
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
+
+#[test]
+fn test_revert_unwinds_stacked_overrides() {
+    let (mut persistence, bid_year_id, user_id) = setup_canonicalized();
+
+    let first = override_can_bid(&mut persistence, user_id, false, "Medical hold this year");
+    let second = override_can_bid(&mut persistence, user_id, true, "Medical hold lifted early");
+
+    let result = revert_override(
+        &mut persistence,
+        first.override_id,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "override_superseded"
+    ));
+
+    let reverted: RevertOverrideResponse = revert_override(
+        &mut persistence,
+        second.override_id,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+    assert_eq!(reverted.override_id, second.override_id);
+
+    let listing: ListOverridesResponse = list_overrides(&mut persistence, bid_year_id).unwrap();
+    assert!(listing.overrides[0].is_revertible);
+    assert!(listing.overrides[1].is_reverted);
+    assert!(!listing.overrides[1].is_revertible);
+
+    revert_override(
+        &mut persistence,
+        first.override_id,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+
+    // Probing with a fresh override reports the restored, unflagged value
+    let (previous_can_bid, was_overridden) = persistence
+        .override_eligibility(bid_year_id, user_id, true, "Probe restored state")
+        .unwrap();
+    assert!(previous_can_bid);
+    assert!(!was_overridden);
+
+    let result = revert_override(
+        &mut persistence,
+        first.override_id,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "override_already_reverted"
+    ));
+}
+
+#[test]
+fn test_revert_is_audited_with_stored_reason() {
+    let (mut persistence, _bid_year_id, user_id) = setup_canonicalized();
+    let applied = override_can_bid(&mut persistence, user_id, false, "Medical hold this year");
+
+    let result = revert_override(
+        &mut persistence,
+        applied.override_id,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    let result = revert_override(
+        &mut persistence,
+        9999,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+
+    let reverted: RevertOverrideResponse = revert_override(
+        &mut persistence,
+        applied.override_id,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+
+    let event: AuditEvent = persistence
+        .get_audit_event(reverted.audit_event_id)
+        .unwrap();
+    assert_eq!(event.action.name, "OverrideReverted");
+    let details: &str = event.action.details.as_deref().unwrap();
+    assert!(details.contains("reason=Medical hold this year"));
+    assert!(details.contains(&format!("original_audit_event={}", applied.audit_event_id)));
+    assert_eq!(event.before.data, "can_bid=false");
+    assert_eq!(event.after.data, "can_bid=true");
+}
@@ -10,7 +10,7 @@ use crate::auth::{AuthenticatedActor, Role};
 use crate::handlers::{change_password, create_operator, reset_password};
 use crate::request_response::{ChangePasswordRequest, CreateOperatorRequest, ResetPasswordRequest};
 use crate::tests::helpers::create_test_cause;
-use zab_bid_persistence::SqlitePersistence;
+use zab_bid_persistence::{SecretToken, SqlitePersistence};
 
 #[test]
 fn test_operator_can_change_own_password() {
@@ -123,12 +123,16 @@ fn test_change_password_invalidates_sessions() {
     // Create a session for the operator
     let expires_at = "2026-12-31T23:59:59Z";
     persistence
-        .create_session("session_token_123", operator_id, expires_at)
+        .create_session(
+            &SecretToken::new(String::from("session_token_123")),
+            operator_id,
+            expires_at,
+        )
         .unwrap();
 
     // Verify session exists
     let session = persistence
-        .get_session_by_token("session_token_123")
+        .get_session_by_token(&SecretToken::new(String::from("session_token_123")))
         .unwrap();
     assert!(session.is_some());
 
@@ -154,7 +158,7 @@ fn test_change_password_invalidates_sessions() {
 
     // Verify session was invalidated
     let session = persistence
-        .get_session_by_token("session_token_123")
+        .get_session_by_token(&SecretToken::new(String::from("session_token_123")))
         .unwrap();
     assert!(session.is_none());
 }
@@ -286,12 +290,16 @@ fn test_reset_password_invalidates_target_sessions() {
     // Create session for target
     let expires_at = "2026-12-31T23:59:59Z";
     persistence
-        .create_session("target_session_123", target_id, expires_at)
+        .create_session(
+            &SecretToken::new(String::from("target_session_123")),
+            target_id,
+            expires_at,
+        )
         .unwrap();
 
     // Verify session exists
     let session = persistence
-        .get_session_by_token("target_session_123")
+        .get_session_by_token(&SecretToken::new(String::from("target_session_123")))
         .unwrap();
     assert!(session.is_some());
 
@@ -308,7 +316,7 @@ fn test_reset_password_invalidates_target_sessions() {
 
     // Verify target's session was invalidated
     let session = persistence
-        .get_session_by_token("target_session_123")
+        .get_session_by_token(&SecretToken::new(String::from("target_session_123")))
         .unwrap();
     assert!(session.is_none());
 }
@@ -0,0 +1,231 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for controller portal links and portal reads.
+
+use std::sync::Arc;
+
+use time::Duration;
+use time::macros::datetime;
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    GetPortalBalanceResponse, GetPortalBidsResponse, GetPortalSeniorityResponse,
+    GetPortalWindowsResponse, IssuePortalLinkRequest, IssuePortalLinkResponse, PortalIdentity,
+    RevokePortalLinkRequest, RevokePortalLinkResponse, authenticate_portal, get_portal_balance,
+    get_portal_bids, get_portal_seniority, get_portal_windows, issue_portal_link,
+    revoke_portal_link,
+};
+use zab_bid::{BootstrapMetadata, ManualClock};
+use zab_bid_persistence::{NewBidWindow, SecretToken};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with users AA and AB, each holding a window and a
+/// leave bid in one round, and returns it with the clock its persistence
+/// now reads.
+fn setup() -> (PersistedFixture, Arc<ManualClock>) {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(2)
+        .with_rounds(1)
+        .persist()
+        .unwrap();
+    let clock: Arc<ManualClock> = Arc::new(ManualClock::new(datetime!(2026-02-01 12:00 UTC)));
+    fixture.persistence.set_clock(clock.clone());
+
+    let bid_year_id: i64 = fixture.bid_year_id;
+    let area_id: i64 = fixture.area_id("North");
+    let round_id: i64 = fixture.round_ids[0];
+    let windows: Vec<NewBidWindow> = ["AA", "AB"]
+        .iter()
+        .enumerate()
+        .map(|(hour, initials)| NewBidWindow {
+            bid_year_id,
+            area_id,
+            user_id: fixture.user_id(initials),
+            round_id,
+            window_start_datetime: format!("2026-03-02T{:02}:00:00Z", 13 + hour),
+            window_end_datetime: format!("2026-03-02T{:02}:00:00Z", 14 + hour),
+        })
+        .collect();
+    fixture
+        .persistence
+        .bulk_insert_bid_windows(&windows)
+        .unwrap();
+    for (initials, leave_date) in [("AA", "2026-06-01"), ("AB", "2026-06-02")] {
+        let user_id: i64 = fixture.user_id(initials);
+        fixture
+            .persistence
+            .insert_leave_bid(
+                bid_year_id,
+                area_id,
+                user_id,
+                round_id,
+                leave_date,
+                8,
+                "Test Operator",
+                "phone",
+            )
+            .unwrap();
+    }
+
+    (fixture, clock)
+}
+
+fn issue(
+    fixture: &mut PersistedFixture,
+    user_id: i64,
+    valid_days: Option<u32>,
+) -> Result<IssuePortalLinkResponse, ApiError> {
+    issue_portal_link(
+        &mut fixture.persistence,
+        &IssuePortalLinkRequest {
+            user_id,
+            valid_days,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn authenticate(fixture: &mut PersistedFixture, token: &str) -> Result<PortalIdentity, ApiError> {
+    authenticate_portal(
+        &mut fixture.persistence,
+        &SecretToken::new(token.to_string()),
+    )
+}
+
+#[test]
+fn test_portal_link_shows_only_its_own_user() {
+    let (mut fixture, _clock): (PersistedFixture, Arc<ManualClock>) = setup();
+    let user_id: i64 = fixture.user_id("AA");
+
+    let link: IssuePortalLinkResponse = issue(&mut fixture, user_id, None).unwrap();
+    assert_eq!(link.initials, "AA");
+    assert_eq!(link.expires_at, "2026-03-03T12:00:00Z");
+
+    let identity: PortalIdentity = authenticate(&mut fixture, &link.portal_token).unwrap();
+    assert_eq!(identity.user_id, user_id);
+    assert_eq!(identity.area_id, fixture.area_id("North"));
+
+    let windows: GetPortalWindowsResponse =
+        get_portal_windows(&mut fixture.persistence, &identity).unwrap();
+    assert_eq!(windows.windows.len(), 1);
+    assert_eq!(windows.windows[0].round_id, fixture.round_ids[0]);
+    assert_eq!(windows.windows[0].round_number, 1);
+    assert_eq!(
+        windows.windows[0].window_start_datetime,
+        "2026-03-02T13:00:00Z"
+    );
+
+    let bids: GetPortalBidsResponse = get_portal_bids(&mut fixture.persistence, &identity).unwrap();
+    assert_eq!(bids.bids.len(), 1);
+    assert_eq!(bids.bids[0].leave_date, "2026-06-01");
+
+    // Seniority positions only exist once the bid year is canonicalized
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let seniority: GetPortalSeniorityResponse =
+        get_portal_seniority(&mut fixture.persistence, &metadata, &identity).unwrap();
+    assert_eq!(seniority.area_code, "NORTH");
+    assert_eq!(seniority.bid_order, None);
+
+    let balance: GetPortalBalanceResponse =
+        get_portal_balance(&mut fixture.persistence, &metadata, &identity).unwrap();
+    assert_eq!(balance.user_id, user_id);
+    assert_eq!(balance.balance.unwrap().awarded_hours, 8);
+
+    let other_user_id: i64 = fixture.user_id("AB");
+    let other: IssuePortalLinkResponse = issue(&mut fixture, other_user_id, None).unwrap();
+    assert_ne!(other.portal_token, link.portal_token);
+    let other_identity: PortalIdentity = authenticate(&mut fixture, &other.portal_token).unwrap();
+    let other_bids: GetPortalBidsResponse =
+        get_portal_bids(&mut fixture.persistence, &other_identity).unwrap();
+    assert_eq!(other_bids.bids[0].leave_date, "2026-06-02");
+}
+
+#[test]
+fn test_issue_portal_link_rejects_invalid_requests() {
+    let (mut fixture, _clock): (PersistedFixture, Arc<ManualClock>) = setup();
+    let user_id: i64 = fixture.user_id("AA");
+
+    let result: Result<IssuePortalLinkResponse, ApiError> = issue_portal_link(
+        &mut fixture.persistence,
+        &IssuePortalLinkRequest {
+            user_id,
+            valid_days: None,
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    assert!(matches!(
+        issue(&mut fixture, user_id, Some(0)),
+        Err(ApiError::InvalidInput { .. })
+    ));
+    assert!(matches!(
+        issue(&mut fixture, user_id, Some(crate::MAX_PORTAL_LINK_DAYS + 1)),
+        Err(ApiError::InvalidInput { .. })
+    ));
+    assert!(matches!(
+        issue(&mut fixture, user_id + 100, None),
+        Err(ApiError::ResourceNotFound { .. })
+    ));
+    assert!(matches!(
+        authenticate(&mut fixture, "not-a-portal-token"),
+        Err(ApiError::AuthenticationFailed { .. })
+    ));
+}
+
+#[test]
+fn test_portal_link_expires_and_can_be_replaced_or_revoked() {
+    let (mut fixture, clock): (PersistedFixture, Arc<ManualClock>) = setup();
+    let user_id: i64 = fixture.user_id("AA");
+
+    let first: IssuePortalLinkResponse = issue(&mut fixture, user_id, Some(1)).unwrap();
+    clock.advance(Duration::days(1) - Duration::seconds(1));
+    assert!(authenticate(&mut fixture, &first.portal_token).is_ok());
+    clock.advance(Duration::seconds(1));
+    let Err(ApiError::AuthenticationFailed { reason }) =
+        authenticate(&mut fixture, &first.portal_token)
+    else {
+        panic!("Expected AuthenticationFailed");
+    };
+    assert_eq!(reason, "Portal link has expired");
+
+    // Issuing again replaces the expired link
+    let second: IssuePortalLinkResponse = issue(&mut fixture, user_id, None).unwrap();
+    assert!(authenticate(&mut fixture, &second.portal_token).is_ok());
+    assert!(authenticate(&mut fixture, &first.portal_token).is_err());
+
+    let revoked: RevokePortalLinkResponse = revoke_portal_link(
+        &mut fixture.persistence,
+        &RevokePortalLinkRequest { user_id },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert!(revoked.revoked);
+    assert!(matches!(
+        authenticate(&mut fixture, &second.portal_token),
+        Err(ApiError::AuthenticationFailed { .. })
+    ));
+
+    let again: RevokePortalLinkResponse = revoke_portal_link(
+        &mut fixture.persistence,
+        &RevokePortalLinkRequest { user_id },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert!(!again.revoked);
+}
@@ -0,0 +1,222 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for prime date periods and their per-round caps.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_admin, create_test_admin_operator,
+    create_test_bidder, create_test_bidder_operator, create_test_cause,
+};
+use crate::{
+    CreatePrimePeriodRequest, DeletePrimePeriodResponse, EnterLeaveBidRequest,
+    GetCoverageReportRequest, ListPrimeDatesResponse, PrimePeriodResponse, RenderedReport,
+    RoundPrimeCapInfo, SetRoundPrimeCapRequest, SetRoundPrimeCapResponse, create_prime_period,
+    delete_prime_period, enter_leave_bid, get_coverage_report, list_prime_dates,
+    set_round_prime_cap,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with AA on crew 1, AB on crew 2, and one round
+/// offering two slots per day. The bid year is left in `Draft` so prime
+/// dates can still be set.
+fn setup() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(2)
+        .with_rounds(1)
+        .persist()
+        .unwrap();
+    // Bids are entered as the test bidder operator, which audit events reference
+    create_persisted_bidder_operator(&mut fixture.persistence).unwrap();
+    fixture
+        .persistence
+        .update_round(fixture.round_ids[0], "Round 1", 2, 3, 200, false, false)
+        .unwrap();
+    fixture
+}
+
+fn create(
+    fixture: &mut PersistedFixture,
+    label: &str,
+    start_date: &str,
+    end_date: &str,
+) -> Result<PrimePeriodResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    create_prime_period(
+        &mut fixture.persistence,
+        &metadata,
+        &CreatePrimePeriodRequest {
+            bid_year_id: fixture.bid_year_id,
+            label: String::from(label),
+            start_date: String::from(start_date),
+            end_date: String::from(end_date),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn set_cap(
+    fixture: &mut PersistedFixture,
+    prime_slots_per_day: Option<u32>,
+) -> Result<SetRoundPrimeCapResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: SetRoundPrimeCapRequest = SetRoundPrimeCapRequest {
+        bid_year_id: fixture.bid_year_id,
+        round_id: fixture.round_ids[0],
+        prime_slots_per_day,
+    };
+    set_round_prime_cap(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn enter(fixture: &mut PersistedFixture, initials: &str, dates: &[&str]) -> Result<(), ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: EnterLeaveBidRequest = EnterLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from(initials),
+        received_via: String::from("phone"),
+        leave_dates: dates.iter().map(|date| String::from(*date)).collect(),
+        hours: 8,
+        override_reason: None,
+    };
+    enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+    .map(|_| ())
+}
+
+fn start_bidding(fixture: &mut PersistedFixture) {
+    fixture
+        .persistence
+        .update_lifecycle_state(fixture.bid_year_id, "BiddingActive")
+        .unwrap();
+}
+
+#[test]
+fn test_prime_periods_and_caps_are_managed_before_bidding() {
+    let mut fixture: PersistedFixture = setup();
+    let christmas: i64 = create(&mut fixture, "Christmas week", "2026-12-21", "2026-12-27")
+        .unwrap()
+        .prime_period
+        .prime_period_id;
+    create(&mut fixture, "Spring break", "2026-03-16", "2026-03-20").unwrap();
+    set_cap(&mut fixture, Some(1)).unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let listed: ListPrimeDatesResponse =
+        list_prime_dates(&mut fixture.persistence, &metadata, fixture.bid_year_id).unwrap();
+    assert_eq!(
+        listed
+            .prime_periods
+            .iter()
+            .map(|p| p.label.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["Spring break", "Christmas week"]
+    );
+    assert_eq!(
+        listed.round_caps,
+        vec![RoundPrimeCapInfo {
+            round_id: fixture.round_ids[0],
+            prime_slots_per_day: 1,
+        }]
+    );
+
+    // Empty labels and reversed ranges are refused
+    assert!(matches!(
+        create(&mut fixture, " ", "2026-07-01", "2026-07-03"),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "label"
+    ));
+    assert!(matches!(
+        create(&mut fixture, "July 4th", "2026-07-05", "2026-07-03"),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "end_date"
+    ));
+
+    // Only Admins may designate prime dates
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let result: Result<PrimePeriodResponse, ApiError> = create_prime_period(
+        &mut fixture.persistence,
+        &metadata,
+        &CreatePrimePeriodRequest {
+            bid_year_id: fixture.bid_year_id,
+            label: String::from("July 4th"),
+            start_date: String::from("2026-07-03"),
+            end_date: String::from("2026-07-05"),
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    // Prime dates are fixed once bidding starts
+    start_bidding(&mut fixture);
+    assert!(matches!(
+        set_cap(&mut fixture, None),
+        Err(ApiError::DomainRuleViolation { .. })
+    ));
+    let result: Result<DeletePrimePeriodResponse, ApiError> = delete_prime_period(
+        &mut fixture.persistence,
+        christmas,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::DomainRuleViolation { .. })));
+}
+
+#[test]
+fn test_prime_cap_enforced_and_reported() {
+    let mut fixture: PersistedFixture = setup();
+    create(&mut fixture, "Christmas week", "2026-12-21", "2026-12-27").unwrap();
+    set_cap(&mut fixture, Some(1)).unwrap();
+    start_bidding(&mut fixture);
+
+    enter(&mut fixture, "AA", &["2026-12-24"]).unwrap();
+
+    // The prime cap, not the round's two slots, limits Christmas Eve
+    let result: Result<(), ApiError> = enter(&mut fixture, "AB", &["2026-12-24"]);
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "leave_bid"
+    ));
+
+    // Ordinary days keep the round's normal slots
+    enter(&mut fixture, "AA", &["2026-12-28"]).unwrap();
+    enter(&mut fixture, "AB", &["2026-12-28"]).unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: GetCoverageReportRequest = GetCoverageReportRequest {
+        bid_year_id: fixture.bid_year_id,
+        area_id: Some(fixture.area_id("North")),
+        start_date: String::from("2026-12-01"),
+        end_date: String::from("2026-12-31"),
+        format: String::from("csv"),
+    };
+    let report: RenderedReport =
+        get_coverage_report(&mut fixture.persistence, &metadata, &request).unwrap();
+    let body: String = String::from_utf8(report.body).unwrap();
+    assert_eq!(
+        body.lines().skip(1).collect::<Vec<&str>>(),
+        vec![
+            "2026-12-24,NORTH,1,Round 1,1,1,true,true,1=1",
+            "2026-12-28,NORTH,1,Round 1,2,2,false,true,1=1;2=1",
+        ]
+    );
+}
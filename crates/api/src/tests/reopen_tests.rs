@@ -0,0 +1,203 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for reopening a canonicalized bid year.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    OverrideEligibilityRequest, ReopenBidYearRequest, ReopenBidYearResponse, override_eligibility,
+    reopen_bid_year,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, StateSnapshot};
+use zab_bid_persistence::SqlitePersistence;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+const JUSTIFICATION: &str = "AA was registered in the wrong area";
+
+fn canonicalize_event(operator_id: i64) -> AuditEvent {
+    AuditEvent::new_global(
+        BidYearFixture::actor(operator_id),
+        BidYearFixture::cause(),
+        Action::new(String::from("CanonicalizeBidYear"), None),
+        StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+    )
+}
+
+/// Creates 2026/North with user AA and one round, and canonicalizes it.
+///
+/// Returns the fixture and the canonicalization event ID.
+fn setup_canonicalized() -> (PersistedFixture, i64) {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(1)
+        .with_rounds(1)
+        .persist()
+        .unwrap();
+    let event_id: i64 = fixture
+        .persistence
+        .canonicalize_bid_year(
+            fixture.bid_year_id,
+            &canonicalize_event(fixture.operator_id),
+        )
+        .unwrap();
+    fixture
+        .persistence
+        .update_lifecycle_state(fixture.bid_year_id, "Canonicalized")
+        .unwrap();
+
+    (fixture, event_id)
+}
+
+fn reopen(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    justification: &str,
+) -> Result<ReopenBidYearResponse, ApiError> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    reopen_bid_year(
+        persistence,
+        &metadata,
+        &ReopenBidYearRequest {
+            bid_year_id,
+            justification: String::from(justification),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+#[test]
+fn test_reopen_clears_canonical_data_and_returns_to_bootstrap_complete() {
+    let (mut fixture, canonicalized_event_id) = setup_canonicalized();
+    let bid_year_id: i64 = fixture.bid_year_id;
+    let user_id: i64 = fixture.user_id("AA");
+    let persistence: &mut SqlitePersistence = &mut fixture.persistence;
+    override_eligibility(
+        persistence,
+        &OverrideEligibilityRequest {
+            user_id,
+            can_bid: false,
+            reason: String::from("Medical hold this year"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+
+    let response: ReopenBidYearResponse = reopen(persistence, bid_year_id, JUSTIFICATION).unwrap();
+
+    assert_eq!(response.lifecycle_state, "BootstrapComplete");
+    assert!(response.rows_cleared >= 5);
+    assert_eq!(
+        persistence.get_lifecycle_state(bid_year_id).unwrap(),
+        "BootstrapComplete"
+    );
+    assert!(persistence.list_overrides(bid_year_id).unwrap().is_empty());
+
+    let event: AuditEvent = persistence
+        .get_audit_event(response.audit_event_id)
+        .unwrap();
+    assert_eq!(event.action.name, "ReopenBidYear");
+    assert!(event.action.details.unwrap().contains(JUSTIFICATION));
+
+    // Canonicalizing again builds fresh rows instead of finding the old ones
+    let recanonicalized_event_id: i64 = persistence
+        .canonicalize_bid_year(bid_year_id, &canonicalize_event(fixture.operator_id))
+        .unwrap();
+    assert!(recanonicalized_event_id > canonicalized_event_id);
+}
+
+#[test]
+fn test_reopen_is_refused_once_a_bid_is_entered() {
+    let (mut fixture, _) = setup_canonicalized();
+    let bid_year_id: i64 = fixture.bid_year_id;
+    fixture
+        .persistence
+        .insert_leave_bid(
+            bid_year_id,
+            fixture.area_id("North"),
+            fixture.user_id("AA"),
+            fixture.round_ids[0],
+            "2026-06-01",
+            8,
+            "AA",
+            "phone",
+        )
+        .unwrap();
+    let persistence: &mut SqlitePersistence = &mut fixture.persistence;
+
+    let result: Result<ReopenBidYearResponse, ApiError> =
+        reopen(persistence, bid_year_id, JUSTIFICATION);
+
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "reopen_requires_no_bids"
+    ));
+    assert_eq!(
+        persistence.get_lifecycle_state(bid_year_id).unwrap(),
+        "Canonicalized"
+    );
+}
+
+#[test]
+fn test_reopen_requires_a_justification() {
+    let (mut fixture, _) = setup_canonicalized();
+    let bid_year_id: i64 = fixture.bid_year_id;
+    let persistence: &mut SqlitePersistence = &mut fixture.persistence;
+
+    let result: Result<ReopenBidYearResponse, ApiError> =
+        reopen(persistence, bid_year_id, "   typo   ");
+
+    assert!(matches!(
+        result,
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "justification"
+    ));
+}
+
+#[test]
+fn test_reopen_requires_canonicalized_state() {
+    let (mut fixture, _) = setup_canonicalized();
+    let bid_year_id: i64 = fixture.bid_year_id;
+    let persistence: &mut SqlitePersistence = &mut fixture.persistence;
+    persistence
+        .update_lifecycle_state(bid_year_id, "BiddingActive")
+        .unwrap();
+
+    let result: Result<ReopenBidYearResponse, ApiError> =
+        reopen(persistence, bid_year_id, JUSTIFICATION);
+
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "valid_lifecycle_transition"
+    ));
+}
+
+#[test]
+fn test_bidder_cannot_reopen() {
+    let (mut fixture, _) = setup_canonicalized();
+    let bid_year_id: i64 = fixture.bid_year_id;
+    let persistence: &mut SqlitePersistence = &mut fixture.persistence;
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+
+    let result: Result<ReopenBidYearResponse, ApiError> = reopen_bid_year(
+        persistence,
+        &metadata,
+        &ReopenBidYearRequest {
+            bid_year_id,
+            justification: String::from(JUSTIFICATION),
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
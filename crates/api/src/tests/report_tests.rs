@@ -0,0 +1,938 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for seniority list, round results, coverage, bid packet, and admin
+//! activity reports.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, setup_test_persistence,
+};
+use crate::{
+    AdminActivitySummary, GetAdminActivityReportRequest, GetBidPacketsRequest,
+    GetCoverageReportRequest, GetRoundResultsReportRequest, GetSeniorityReportRequest,
+    OperatorActivityInfo, RenderedReport, get_admin_activity_report, get_bid_packets,
+    get_coverage_report, get_round_results_report, get_seniority_report, summarize_admin_activity,
+};
+use time::{Date, OffsetDateTime};
+use zab_bid::{BootstrapMetadata, Command, State, TransitionResult, apply};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, UserType};
+use zab_bid_persistence::{NewBidStatus, NewBidWindow, SeniorityListEntryData, SqlitePersistence};
+
+fn test_actor() -> Actor {
+    Actor::with_operator(
+        String::from("test-admin"),
+        String::from("admin"),
+        1,
+        String::from("test-operator"),
+        String::from("Test Operator"),
+    )
+}
+
+/// Registers a user in 2026/North and returns its ID.
+fn register(persistence: &mut SqlitePersistence, initials: &str, name: &str) -> i64 {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let bid_year: BidYear = BidYear::new(2026);
+    let area: Area = Area::new("North");
+
+    let result: TransitionResult = apply(
+        &metadata,
+        &persistence.get_current_state(&bid_year, &area).unwrap(),
+        &bid_year,
+        Command::RegisterUser {
+            initials: Initials::new(initials),
+            name: name.to_string(),
+            area,
+            user_type: UserType::CPC,
+            crew: Some(Crew::new(1).unwrap()),
+            seniority_data: SeniorityData::new(
+                String::from("2019-01-15"),
+                String::from("2019-06-01"),
+                String::from("2020-01-15"),
+                String::from("2020-01-15"),
+                Some(3),
+            ),
+        },
+        test_actor(),
+        Cause::new(String::from("test"), String::from("Test")),
+    )
+    .unwrap();
+    persistence.persist_transition(&result).unwrap();
+
+    let state: State = persistence
+        .get_current_state(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    state
+        .users
+        .values()
+        .find(|u| u.initials.value() == initials)
+        .and_then(|u| u.user_id)
+        .unwrap()
+}
+
+/// Creates 2026/North with users AB and CD, canonicalized with CD first.
+///
+/// Returns the persistence, metadata, and `(bid_year_id, area_id)`.
+fn setup_canonicalized() -> (SqlitePersistence, BootstrapMetadata, i64, i64) {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let ab: i64 = register(&mut persistence, "AB", "Alice <Baker>");
+    let cd: i64 = register(&mut persistence, "CD", "Carol Diaz");
+
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let (bid_year, area) = metadata
+        .areas
+        .iter()
+        .find(|(_, area)| area.area_code() == "NORTH")
+        .unwrap();
+    let bid_year_id: i64 = bid_year.bid_year_id().unwrap();
+    let area_id: i64 = area.area_id().unwrap();
+
+    let event: AuditEvent = AuditEvent::new_global(
+        test_actor(),
+        Cause::new(String::from("test"), String::from("Test")),
+        Action::new(String::from("CanonicalizeBidYear"), None),
+        StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+    );
+    persistence
+        .canonicalize_bid_year(bid_year_id, &event)
+        .unwrap();
+    persistence
+        .update_lifecycle_state(bid_year_id, "Canonicalized")
+        .unwrap();
+    persistence
+        .override_bid_order(bid_year_id, cd, Some(1), "Test order")
+        .unwrap();
+    persistence
+        .override_bid_order(bid_year_id, ab, Some(2), "Test order")
+        .unwrap();
+
+    (persistence, metadata, bid_year_id, area_id)
+}
+
+fn report_request(bid_year_id: i64, area_id: i64, format: &str) -> GetSeniorityReportRequest {
+    GetSeniorityReportRequest {
+        bid_year_id,
+        area_id,
+        format: format.to_string(),
+        facility_name: Some(String::from("Example ARTCC")),
+        header_text: None,
+        footer_text: Some(String::from("Posted for review")),
+    }
+}
+
+#[test]
+fn test_html_report_follows_canonical_order() {
+    let (mut persistence, metadata, bid_year_id, area_id) = setup_canonicalized();
+
+    let report: RenderedReport = get_seniority_report(
+        &mut persistence,
+        &metadata,
+        &report_request(bid_year_id, area_id, "html"),
+    )
+    .unwrap();
+
+    assert_eq!(report.content_type, "text/html; charset=utf-8");
+    assert_eq!(report.filename, "seniority-2026-NORTH.html");
+    let html: String = String::from_utf8(report.body).unwrap();
+    assert!(html.contains("<p class=\"facility\">Example ARTCC</p>"));
+    assert!(html.contains("<footer>Posted for review</footer>"));
+    assert!(html.contains("Alice &lt;Baker&gt;"));
+    assert!(html.contains("* Position set by override."));
+    let cd: usize = html.find("<td>CD</td>").unwrap();
+    let ab: usize = html.find("<td>AB</td>").unwrap();
+    assert!(cd < ab);
+}
+
+#[test]
+fn test_pdf_report_is_well_formed() {
+    let (mut persistence, metadata, bid_year_id, area_id) = setup_canonicalized();
+
+    let report: RenderedReport = get_seniority_report(
+        &mut persistence,
+        &metadata,
+        &report_request(bid_year_id, area_id, "PDF"),
+    )
+    .unwrap();
+
+    assert_eq!(report.content_type, "application/pdf");
+    assert_eq!(report.filename, "seniority-2026-NORTH.pdf");
+    let body: String = String::from_utf8_lossy(&report.body).into_owned();
+    assert!(body.starts_with("%PDF-1.4\n"));
+    assert!(body.ends_with("%%EOF\n"));
+    assert!(body.contains("(Example ARTCC)"));
+    assert!(body.contains("(Page 1 of 1)"));
+    assert!(body.contains("/Count 1"));
+
+    // The xref table must point at each object header.
+    let startxref: usize = body[body.rfind("startxref\n").unwrap() + 10..]
+        .trim_end_matches("\n%%EOF\n")
+        .parse()
+        .unwrap();
+    assert!(body[startxref..].starts_with("xref\n"));
+    let first_offset: usize = body[startxref..].lines().nth(3).unwrap()[..10]
+        .parse()
+        .unwrap();
+    assert!(body[first_offset..].starts_with("1 0 obj\n"));
+}
+
+#[test]
+fn test_report_requires_confirmed_bid_year() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let (bid_year, area) = metadata
+        .areas
+        .iter()
+        .find(|(_, area)| area.area_code() == "NORTH")
+        .unwrap();
+
+    let result: Result<RenderedReport, ApiError> = get_seniority_report(
+        &mut persistence,
+        &metadata,
+        &report_request(
+            bid_year.bid_year_id().unwrap(),
+            area.area_id().unwrap(),
+            "pdf",
+        ),
+    );
+
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. })
+            if rule == "seniority_report_requires_confirmation"
+    ));
+}
+
+#[test]
+fn test_report_rejects_invalid_input() {
+    let (mut persistence, metadata, bid_year_id, area_id) = setup_canonicalized();
+
+    let bad_format: GetSeniorityReportRequest = report_request(bid_year_id, area_id, "docx");
+    let mut long_footer: GetSeniorityReportRequest = report_request(bid_year_id, area_id, "pdf");
+    long_footer.footer_text = Some("x".repeat(201));
+    let mut multiline: GetSeniorityReportRequest = report_request(bid_year_id, area_id, "pdf");
+    multiline.header_text = Some(String::from("line one\nline two"));
+
+    for (request, field) in [
+        (bad_format, "format"),
+        (long_footer, "footer_text"),
+        (multiline, "header_text"),
+    ] {
+        let result: Result<RenderedReport, ApiError> =
+            get_seniority_report(&mut persistence, &metadata, &request);
+        assert!(
+            matches!(result, Err(ApiError::InvalidInput { field: ref f, .. }) if f == field),
+            "expected {field} to be rejected"
+        );
+    }
+
+    let missing_area: Result<RenderedReport, ApiError> = get_seniority_report(
+        &mut persistence,
+        &metadata,
+        &report_request(bid_year_id, area_id + 100, "pdf"),
+    );
+    assert!(matches!(
+        missing_area,
+        Err(ApiError::ResourceNotFound { .. })
+    ));
+}
+
+/// Adds "Round 1" to the canonicalized bid year with one window per user
+/// ending at `window_end`, marks CD missed and AB completed late, and moves
+/// the bid year to `BiddingActive`.
+///
+/// Returns the persistence, metadata, and `(bid_year_id, area_id, round_id)`.
+fn setup_round(window_end: &str) -> (SqlitePersistence, BootstrapMetadata, i64, i64, i64) {
+    let (mut persistence, metadata, bid_year_id, area_id) = setup_canonicalized();
+    let round_group_id: i64 = persistence
+        .insert_round_group(bid_year_id, "Default", true)
+        .unwrap();
+    let round_id: i64 = persistence
+        .insert_round(round_group_id, 1, "Round 1", 2, 1, 80, false, false)
+        .unwrap();
+
+    let list: Vec<SeniorityListEntryData> = persistence
+        .list_seniority_list(bid_year_id, area_id)
+        .unwrap();
+    let windows: Vec<NewBidWindow> = list
+        .iter()
+        .map(|entry| NewBidWindow {
+            bid_year_id,
+            area_id,
+            user_id: entry.user_id,
+            round_id,
+            window_start_datetime: String::from("2026-03-02T13:00:00+00:00"),
+            window_end_datetime: window_end.to_string(),
+        })
+        .collect();
+    persistence.bulk_insert_bid_windows(&windows).unwrap();
+
+    let statuses: Vec<NewBidStatus> = list
+        .iter()
+        .map(|entry| NewBidStatus {
+            bid_year_id,
+            area_id,
+            user_id: entry.user_id,
+            round_id,
+            status: String::from(if entry.initials == "CD" {
+                "missed"
+            } else {
+                "completed_late"
+            }),
+            updated_at: String::from("2026-03-02T21:00:00+00:00"),
+            updated_by: 1,
+            notes: None,
+        })
+        .collect();
+    persistence.bulk_insert_bid_status(&statuses).unwrap();
+    persistence
+        .update_lifecycle_state(bid_year_id, "BiddingActive")
+        .unwrap();
+
+    (persistence, metadata, bid_year_id, area_id, round_id)
+}
+
+fn round_results_request(
+    bid_year_id: i64,
+    area_id: i64,
+    round_id: i64,
+    format: &str,
+) -> GetRoundResultsReportRequest {
+    GetRoundResultsReportRequest {
+        bid_year_id,
+        area_id,
+        round_id,
+        format: format.to_string(),
+    }
+}
+
+#[test]
+fn test_round_results_csv_reports_status_and_balance() {
+    let (mut persistence, metadata, bid_year_id, area_id, round_id) =
+        setup_round("2026-03-02T21:00:00+00:00");
+
+    let report: RenderedReport = get_round_results_report(
+        &mut persistence,
+        &metadata,
+        &round_results_request(bid_year_id, area_id, round_id, "csv"),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+
+    assert_eq!(report.content_type, "text/csv; charset=utf-8");
+    assert_eq!(report.filename, "round-results-2026-NORTH-round-1.csv");
+    let csv: String = String::from_utf8(report.body).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        "bid_order,initials,name,window_start,window_end,status,status_label,earned_hours,remaining_hours"
+    );
+    assert!(lines[1].starts_with(
+        "1,CD,Carol Diaz,2026-03-02T13:00:00+00:00,2026-03-02T21:00:00+00:00,missed,Skipped (missed),"
+    ));
+    assert!(lines[2].starts_with("2,AB,Alice <Baker>,"));
+    assert!(lines[2].contains(",completed_late,Deferred (completed late),"));
+
+    // No leave usage is recorded, so the remaining balance is the earned balance.
+    let balance: Vec<&str> = lines[1].rsplitn(3, ',').collect();
+    assert!(balance[0].parse::<u16>().unwrap() > 0);
+    assert_eq!(balance[0], balance[1]);
+
+    let timeline: Vec<AuditEvent> = persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    let event: &AuditEvent = timeline.last().unwrap();
+    assert_eq!(event.action.name, "GenerateRoundResultsReport");
+    assert_eq!(event.actor.operator_id, Some(1));
+}
+
+#[test]
+fn test_round_results_pdf_and_xlsx_are_well_formed() {
+    let (mut persistence, metadata, bid_year_id, area_id, round_id) =
+        setup_round("2026-03-02T21:00:00+00:00");
+
+    let pdf: RenderedReport = get_round_results_report(
+        &mut persistence,
+        &metadata,
+        &round_results_request(bid_year_id, area_id, round_id, "pdf"),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+    let body: String = String::from_utf8_lossy(&pdf.body).into_owned();
+    assert!(body.starts_with("%PDF-1.4\n"));
+    assert!(body.contains("(2026 Round 1 Results - NORTH)"));
+    assert!(body.contains("(2026-03-02 21:00)"));
+    assert!(body.contains("(Completed: 0   Deferred: 1   Skipped: 1   Other: 0)"));
+
+    let xlsx: RenderedReport = get_round_results_report(
+        &mut persistence,
+        &metadata,
+        &round_results_request(bid_year_id, area_id, round_id, "XLSX"),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+    assert_eq!(
+        xlsx.content_type,
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+    );
+    assert!(xlsx.body.starts_with(b"PK\x03\x04"));
+    // The archive is stored uncompressed, so its parts are readable.
+    let body: String = String::from_utf8_lossy(&xlsx.body).into_owned();
+    assert!(body.contains("xl/worksheets/sheet1.xml"));
+    assert!(body.contains("<sheet name=\"Round 1\""));
+    assert!(body.contains("Alice &lt;Baker&gt;"));
+    assert!(body.contains("Deferred (completed late)"));
+    assert!(body.contains("<c r=\"A2\"><v>1</v></c>"));
+}
+
+#[test]
+fn test_xlsx_crc32_matches_reference() {
+    assert_eq!(crate::xlsx::crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(crate::xlsx::crc32(b""), 0);
+}
+
+#[test]
+fn test_round_results_require_closed_round() {
+    let (mut persistence, metadata, bid_year_id, area_id, round_id) =
+        setup_round("2099-03-02T21:00:00+00:00");
+    let request: GetRoundResultsReportRequest =
+        round_results_request(bid_year_id, area_id, round_id, "csv");
+
+    let open: Result<RenderedReport, ApiError> = get_round_results_report(
+        &mut persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(matches!(
+        open,
+        Err(ApiError::DomainRuleViolation { ref rule, .. })
+            if rule == "round_results_require_closed_round"
+    ));
+
+    // Once bidding closes for the bid year, every round is closed.
+    persistence
+        .update_lifecycle_state(bid_year_id, "BiddingClosed")
+        .unwrap();
+    assert!(
+        get_round_results_report(
+            &mut persistence,
+            &metadata,
+            &request,
+            &create_test_admin(),
+            &create_test_admin_operator(),
+        )
+        .is_ok()
+    );
+}
+
+#[test]
+fn test_round_results_reject_invalid_input() {
+    let (mut persistence, metadata, bid_year_id, area_id, round_id) =
+        setup_round("2026-03-02T21:00:00+00:00");
+
+    let html: Result<RenderedReport, ApiError> = get_round_results_report(
+        &mut persistence,
+        &metadata,
+        &round_results_request(bid_year_id, area_id, round_id, "html"),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(matches!(html, Err(ApiError::InvalidInput { ref field, .. }) if field == "format"));
+
+    let missing_round: Result<RenderedReport, ApiError> = get_round_results_report(
+        &mut persistence,
+        &metadata,
+        &round_results_request(bid_year_id, area_id, round_id + 100, "csv"),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(matches!(
+        missing_round,
+        Err(ApiError::ResourceNotFound { .. })
+    ));
+}
+
+#[test]
+fn test_bid_packets_cover_each_controller_in_local_time() {
+    let (mut persistence, _, bid_year_id, area_id, round_id) =
+        setup_round("2026-03-02T21:00:00+00:00");
+    let round_group_id: i64 = persistence
+        .get_round(round_id)
+        .unwrap()
+        .round_group()
+        .round_group_id()
+        .unwrap();
+    persistence
+        .update_area_round_group(area_id, Some(round_group_id))
+        .unwrap();
+    persistence
+        .update_bid_schedule(
+            bid_year_id,
+            Some("America/New_York"),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+
+    let request: GetBidPacketsRequest = GetBidPacketsRequest {
+        bid_year_id,
+        area_id,
+        user_id: None,
+    };
+    let report: RenderedReport = get_bid_packets(&mut persistence, &metadata, &request).unwrap();
+
+    assert_eq!(report.content_type, "application/pdf");
+    assert_eq!(report.filename, "bid-packets-2026-NORTH.pdf");
+    let body: String = String::from_utf8_lossy(&report.body).into_owned();
+    assert!(body.starts_with("%PDF-1.4\n"));
+    // Two pages per controller: the summary and the worksheet.
+    assert!(body.contains("/Count 4"));
+    assert!(body.contains("(Page 2 of 2)"));
+    assert!(body.contains("(Bid position 1 of 2 \\(set by override\\))"));
+    assert!(body.contains("(Bid Windows \\(times in America/New_York\\))"));
+    assert!(body.contains("(Round 1 - Round 1: 2026-03-02 08:00 to 2026-03-02 16:00)"));
+    assert!(body.contains("(Preference Worksheet)"));
+    let cd: usize = body.find("(CD - Carol Diaz)").unwrap();
+    let ab: usize = body.find("(AB - Alice <Baker>)").unwrap();
+    assert!(cd < ab);
+
+    let ab_id: i64 = persistence
+        .list_seniority_list(bid_year_id, area_id)
+        .unwrap()
+        .iter()
+        .find(|entry| entry.initials == "AB")
+        .map(|entry| entry.user_id)
+        .unwrap();
+    let single: RenderedReport = get_bid_packets(
+        &mut persistence,
+        &metadata,
+        &GetBidPacketsRequest {
+            user_id: Some(ab_id),
+            ..request
+        },
+    )
+    .unwrap();
+    assert_eq!(single.filename, "bid-packet-2026-NORTH-AB.pdf");
+    let body: String = String::from_utf8_lossy(&single.body).into_owned();
+    assert!(body.contains("/Count 2"));
+    assert!(!body.contains("(CD - Carol Diaz)"));
+}
+
+#[test]
+fn test_bid_packets_require_confirmation_and_listed_user() {
+    let (mut persistence, metadata, bid_year_id, area_id) = setup_canonicalized();
+    let missing_user: Result<RenderedReport, ApiError> = get_bid_packets(
+        &mut persistence,
+        &metadata,
+        &GetBidPacketsRequest {
+            bid_year_id,
+            area_id,
+            user_id: Some(9999),
+        },
+    );
+    assert!(matches!(
+        missing_user,
+        Err(ApiError::ResourceNotFound { .. })
+    ));
+
+    persistence
+        .update_lifecycle_state(bid_year_id, "BootstrapComplete")
+        .unwrap();
+    let unconfirmed: Result<RenderedReport, ApiError> = get_bid_packets(
+        &mut persistence,
+        &metadata,
+        &GetBidPacketsRequest {
+            bid_year_id,
+            area_id,
+            user_id: None,
+        },
+    );
+    assert!(matches!(
+        unconfirmed,
+        Err(ApiError::DomainRuleViolation { ref rule, .. })
+            if rule == "bid_packets_require_confirmation"
+    ));
+}
+
+/// Sets up `setup_round` with AB and CD on leave on 2026-06-01 (filling
+/// both slots) and AB on leave on 2026-06-02.
+fn setup_coverage() -> (SqlitePersistence, BootstrapMetadata, i64, i64) {
+    let (mut persistence, metadata, bid_year_id, area_id, round_id) =
+        setup_round("2026-03-02T21:00:00+00:00");
+    let list: Vec<SeniorityListEntryData> = persistence
+        .list_seniority_list(bid_year_id, area_id)
+        .unwrap();
+    for entry in &list {
+        persistence
+            .insert_leave_bid(
+                bid_year_id,
+                area_id,
+                entry.user_id,
+                round_id,
+                "2026-06-01",
+                8,
+                &entry.initials,
+                "in_person",
+            )
+            .unwrap();
+    }
+    let ab: i64 = list.iter().find(|e| e.initials == "AB").unwrap().user_id;
+    persistence
+        .insert_leave_bid(
+            bid_year_id,
+            area_id,
+            ab,
+            round_id,
+            "2026-06-02",
+            8,
+            "AB",
+            "phone",
+        )
+        .unwrap();
+
+    (persistence, metadata, bid_year_id, area_id)
+}
+
+fn coverage_request(
+    bid_year_id: i64,
+    area_id: Option<i64>,
+    start_date: &str,
+    end_date: &str,
+    format: &str,
+) -> GetCoverageReportRequest {
+    GetCoverageReportRequest {
+        bid_year_id,
+        area_id,
+        start_date: start_date.to_string(),
+        end_date: end_date.to_string(),
+        format: format.to_string(),
+    }
+}
+
+#[test]
+fn test_coverage_csv_flags_full_days() {
+    let (mut persistence, metadata, bid_year_id, area_id) = setup_coverage();
+
+    let report: RenderedReport = get_coverage_report(
+        &mut persistence,
+        &metadata,
+        &coverage_request(
+            bid_year_id,
+            Some(area_id),
+            "2026-06-01",
+            "2026-06-30",
+            "csv",
+        ),
+    )
+    .unwrap();
+
+    assert_eq!(report.content_type, "text/csv; charset=utf-8");
+    assert_eq!(
+        report.filename,
+        "coverage-2026-NORTH-2026-06-01-2026-06-30.csv"
+    );
+    let body: String = String::from_utf8(report.body).unwrap();
+    let lines: Vec<&str> = body.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "date,area_code,round_number,round_name,approved,capacity,prime,exhausted,crew_counts",
+            "2026-06-01,NORTH,1,Round 1,2,2,false,true,1=2",
+            "2026-06-02,NORTH,1,Round 1,1,2,false,false,1=1",
+        ]
+    );
+}
+
+#[test]
+fn test_coverage_capacity_follows_slot_inventory() {
+    let (mut persistence, metadata, bid_year_id, area_id) = setup_coverage();
+    let round_id: i64 = persistence.list_leave_bids(bid_year_id, area_id).unwrap()[0].round_id;
+    persistence
+        .set_slot_adjustment(bid_year_id, area_id, round_id, "2026-06-01", 1)
+        .unwrap();
+    persistence
+        .set_slot_adjustment(bid_year_id, area_id, round_id, "2026-06-02", -1)
+        .unwrap();
+
+    let report: RenderedReport = get_coverage_report(
+        &mut persistence,
+        &metadata,
+        &coverage_request(
+            bid_year_id,
+            Some(area_id),
+            "2026-06-01",
+            "2026-06-30",
+            "csv",
+        ),
+    )
+    .unwrap();
+
+    let body: String = String::from_utf8(report.body).unwrap();
+    assert_eq!(
+        body.lines().skip(1).collect::<Vec<&str>>(),
+        vec![
+            "2026-06-01,NORTH,1,Round 1,2,3,false,false,1=2",
+            "2026-06-02,NORTH,1,Round 1,1,1,false,true,1=1",
+        ]
+    );
+}
+
+#[test]
+fn test_coverage_range_and_withdrawn_leave() {
+    let (mut persistence, metadata, bid_year_id, area_id) = setup_coverage();
+    let withdrawn: i64 = persistence.list_leave_bids(bid_year_id, area_id).unwrap()[0].leave_bid_id;
+    persistence.withdraw_leave_bid(withdrawn).unwrap();
+
+    let report: RenderedReport = get_coverage_report(
+        &mut persistence,
+        &metadata,
+        &coverage_request(bid_year_id, None, "2026-06-01", "2026-06-01", "html"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        report.filename,
+        "coverage-2026-all-2026-06-01-2026-06-01.html"
+    );
+    let html: String = String::from_utf8(report.body).unwrap();
+    assert!(html.contains("2026 Leave Coverage - All Areas"));
+    assert!(html.contains("1=1"));
+    assert!(!html.contains("FULL"));
+    assert!(!html.contains("2026-06-02"));
+
+    let pdf: RenderedReport = get_coverage_report(
+        &mut persistence,
+        &metadata,
+        &coverage_request(
+            bid_year_id,
+            Some(area_id),
+            "2026-06-01",
+            "2026-06-02",
+            "pdf",
+        ),
+    )
+    .unwrap();
+    assert!(pdf.body.starts_with(b"%PDF-"));
+}
+
+#[test]
+fn test_coverage_rejects_invalid_range() {
+    let (mut persistence, metadata, bid_year_id, area_id) = setup_coverage();
+
+    for (start_date, end_date, field) in [
+        ("2026-06-31", "2026-07-01", "start_date"),
+        ("2026-07-01", "2026-06-01", "end_date"),
+        ("2026-01-01", "2027-01-02", "end_date"),
+    ] {
+        let result: Result<RenderedReport, ApiError> = get_coverage_report(
+            &mut persistence,
+            &metadata,
+            &coverage_request(bid_year_id, Some(area_id), start_date, end_date, "csv"),
+        );
+        assert!(
+            matches!(result, Err(ApiError::InvalidInput { field: ref f, .. }) if f == field),
+            "{start_date}..{end_date}: {result:?}"
+        );
+    }
+
+    let xlsx: Result<RenderedReport, ApiError> = get_coverage_report(
+        &mut persistence,
+        &metadata,
+        &coverage_request(
+            bid_year_id,
+            Some(area_id),
+            "2026-06-01",
+            "2026-06-02",
+            "xlsx",
+        ),
+    );
+    assert!(matches!(xlsx, Err(ApiError::InvalidInput { ref field, .. }) if field == "format"));
+}
+
+/// Records a global audit event performed by `actor`.
+fn record(persistence: &mut SqlitePersistence, actor: Actor, action_name: &str) {
+    let event: AuditEvent = AuditEvent::new_global(
+        actor,
+        Cause::new(String::from("test"), String::from("Test")),
+        Action::new(action_name.to_string(), None),
+        StateSnapshot::new(String::new()),
+        StateSnapshot::new(String::new()),
+    );
+    persistence.persist_audit_event(&event).unwrap();
+}
+
+/// Registers two users as `test-operator`, who bootstrapped the bid year,
+/// and records an override, a rollback, and a report export, plus one
+/// command by `second-operator`.
+fn setup_activity() -> SqlitePersistence {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    register(&mut persistence, "AB", "Alice Baker");
+    register(&mut persistence, "CD", "Carol Diaz");
+    record(&mut persistence, test_actor(), "UserBidOrderOverridden");
+    record(&mut persistence, test_actor(), "Rollback");
+    record(&mut persistence, test_actor(), "GenerateRoundResultsReport");
+    let second_id: i64 = persistence
+        .create_operator("second-operator", "Second Operator", "password", "Admin")
+        .unwrap();
+    let second: Actor = Actor::with_operator(
+        String::from("second-admin"),
+        String::from("admin"),
+        second_id,
+        String::from("second-operator"),
+        String::from("Second Operator"),
+    );
+    record(&mut persistence, second, "SetFeatureFlag");
+    persistence
+}
+
+/// Returns the UTC dates either side of today, which cover every event
+/// recorded by a test.
+fn around_today() -> (Date, Date) {
+    let today: Date = OffsetDateTime::now_utc().date();
+    (today.previous_day().unwrap(), today.next_day().unwrap())
+}
+
+fn activity_request(
+    start_date: Date,
+    end_date: Date,
+    format: &str,
+) -> GetAdminActivityReportRequest {
+    GetAdminActivityReportRequest {
+        start_date: start_date.to_string(),
+        end_date: end_date.to_string(),
+        timezone: None,
+        format: format.to_string(),
+    }
+}
+
+#[test]
+fn test_admin_activity_counts_commands_by_operator() {
+    let mut persistence: SqlitePersistence = setup_activity();
+    let (start_date, end_date) = around_today();
+
+    let summary: AdminActivitySummary =
+        summarize_admin_activity(&mut persistence, start_date, end_date, "America/New_York")
+            .unwrap();
+
+    assert_eq!(summary.timezone, "America/New_York");
+    let operator = |login_name: &str| -> &OperatorActivityInfo {
+        summary
+            .operators
+            .iter()
+            .find(|o| o.login_name == login_name)
+            .unwrap()
+    };
+    let first: &OperatorActivityInfo = operator("test-operator");
+    assert_eq!(first.operator_id, 1);
+    // Creating the bid year and area, two registrations, the override, and
+    // the rollback; the report export is not a command
+    assert_eq!(first.commands, 6);
+    assert_eq!(first.overrides, 1);
+    assert_eq!(first.rollbacks, 1);
+    // Every event is recorded within moments of the others
+    assert!(first.after_hours == 0 || first.after_hours == first.commands);
+
+    let second: &OperatorActivityInfo = operator("second-operator");
+    assert_eq!(
+        (second.commands, second.overrides, second.rollbacks),
+        (1, 0, 0)
+    );
+    assert_ne!(second.operator_id, first.operator_id);
+
+    // Nothing was recorded a week ago
+    let last_week: Date = start_date - time::Duration::days(7);
+    let empty: AdminActivitySummary =
+        summarize_admin_activity(&mut persistence, last_week, last_week, "UTC").unwrap();
+    assert!(empty.operators.is_empty());
+}
+
+#[test]
+fn test_admin_activity_report_formats() {
+    let mut persistence: SqlitePersistence = setup_activity();
+    let (start_date, end_date) = around_today();
+
+    let csv: RenderedReport = get_admin_activity_report(
+        &mut persistence,
+        &activity_request(start_date, end_date, "csv"),
+        &create_test_admin(),
+    )
+    .unwrap();
+    assert_eq!(
+        csv.filename,
+        format!("admin-activity-{start_date}-{end_date}.csv")
+    );
+    let body: String = String::from_utf8(csv.body).unwrap();
+    let mut lines = body.lines();
+    assert_eq!(
+        lines.next(),
+        Some("operator_id,login_name,commands,overrides,rollbacks,after_hours")
+    );
+    assert!(body.contains(",second-operator,1,0,0,"));
+
+    let pdf: RenderedReport = get_admin_activity_report(
+        &mut persistence,
+        &activity_request(start_date, end_date, "PDF"),
+        &create_test_admin(),
+    )
+    .unwrap();
+    assert_eq!(pdf.content_type, "application/pdf");
+    let body: String = String::from_utf8_lossy(&pdf.body).into_owned();
+    assert!(body.starts_with("%PDF-"));
+    assert!(body.contains("(second-operator)"));
+}
+
+#[test]
+fn test_admin_activity_report_requires_admin() {
+    let mut persistence: SqlitePersistence = setup_activity();
+    let (start_date, end_date) = around_today();
+
+    let result: Result<RenderedReport, ApiError> = get_admin_activity_report(
+        &mut persistence,
+        &activity_request(start_date, end_date, "pdf"),
+        &create_test_bidder(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
+
+#[test]
+fn test_admin_activity_report_rejects_invalid_input() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+
+    for (start_date, end_date, timezone, field) in [
+        ("2026-07-01", "2026-06-01", None, "end_date"),
+        ("2026-01-01", "2027-01-02", None, "end_date"),
+        (
+            "2026-01-01",
+            "2026-12-31",
+            Some("Mars/Olympus_Mons"),
+            "timezone",
+        ),
+    ] {
+        let result: Result<RenderedReport, ApiError> = get_admin_activity_report(
+            &mut persistence,
+            &GetAdminActivityReportRequest {
+                start_date: start_date.to_string(),
+                end_date: end_date.to_string(),
+                timezone: timezone.map(str::to_string),
+                format: String::from("csv"),
+            },
+            &create_test_admin(),
+        );
+        assert!(
+            matches!(result, Err(ApiError::InvalidInput { field: ref f, .. }) if f == field),
+            "{start_date}..{end_date}: {result:?}"
+        );
+    }
+}
@@ -0,0 +1,676 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for previewing and executing rollbacks.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_cause,
+};
+use crate::{
+    ListCheckpointsResponse, ListOverridesResponse, OverrideEligibilityRequest,
+    RollbackPreviewResponse, RollbackResult, RollbackTarget, list_checkpoints, list_overrides,
+    override_eligibility, preview_rollback, rollback,
+};
+use zab_bid::{Command, State, TransitionResult, apply};
+use zab_bid_audit::{Action, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{Area, BidYear, Crew, Initials, UserType};
+use zab_bid_persistence::{AuditTimelineFilter, AuditTimelineScope, UserEligibilityData};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Applies a command to 2026/North and persists it, returning its event ID.
+fn record(fixture: &mut PersistedFixture, command: Command) -> i64 {
+    let state: State = fixture.state("North").unwrap();
+    let result: TransitionResult = apply(
+        &fixture.metadata,
+        &state,
+        &BidYear::new(2026),
+        command,
+        BidYearFixture::actor(fixture.operator_id),
+        BidYearFixture::cause(),
+    )
+    .unwrap();
+    fixture
+        .persistence
+        .persist_transition(&result)
+        .unwrap()
+        .event_id
+}
+
+fn register(initials: &str) -> Command {
+    Command::RegisterUser {
+        initials: Initials::new(initials),
+        name: String::from("Late Addition"),
+        area: Area::new("North"),
+        user_type: UserType::CPC,
+        crew: Some(Crew::new(2).unwrap()),
+        seniority_data: zab_bid_domain::SeniorityData::new(
+            String::from("2021-03-01"),
+            String::from("2021-03-01"),
+            String::from("2021-03-01"),
+            String::from("2021-03-01"),
+            Some(7),
+        ),
+    }
+}
+
+/// Canonicalizes 2026, returning the canonicalization's event ID.
+fn canonicalize(fixture: &mut PersistedFixture) -> i64 {
+    let event: AuditEvent = AuditEvent::new_global(
+        BidYearFixture::actor(fixture.operator_id),
+        Cause::new(String::from("test"), String::from("Test")),
+        Action::new(String::from("CanonicalizeBidYear"), None),
+        StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+    );
+    let event_id: i64 = fixture
+        .persistence
+        .canonicalize_bid_year(fixture.bid_year_id, &event)
+        .unwrap();
+    fixture
+        .persistence
+        .update_lifecycle_state(fixture.bid_year_id, "Canonicalized")
+        .unwrap();
+    event_id
+}
+
+/// Rolls 2026/North back to the target, returning the rollback's event ID.
+fn roll_back(
+    fixture: &mut PersistedFixture,
+    target: &RollbackTarget,
+    confirm_cascade: bool,
+) -> Result<i64, ApiError> {
+    let state: State = fixture.state("North").unwrap();
+    let result: RollbackResult = rollback(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &state,
+        target,
+        confirm_cascade,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )?;
+    Ok(result.event_id)
+}
+
+/// Returns the ID of the first event with the given action.
+fn event_id_of(fixture: &mut PersistedFixture, action_name: &str) -> i64 {
+    fixture
+        .persistence
+        .get_audit_event_headers_page(
+            AuditTimelineScope::All,
+            &AuditTimelineFilter {
+                action_name: Some(action_name.to_string()),
+                ..AuditTimelineFilter::default()
+            },
+            None,
+            1,
+        )
+        .unwrap()
+        .headers[0]
+        .event_id
+}
+
+#[test]
+fn test_preview_lists_superseded_events_and_users() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(2).persist().unwrap();
+    let checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    let registered: i64 = record(&mut fixture, register("ZZ"));
+
+    let preview: RollbackPreviewResponse =
+        preview_rollback(&mut fixture.persistence, checkpoint, &create_test_admin()).unwrap();
+
+    assert_eq!(preview.target_action_name, "Checkpoint");
+    assert_eq!(preview.bid_year, 2026);
+    assert_eq!(preview.area_code, "NORTH");
+    assert_eq!(preview.snapshot_event_id, checkpoint);
+    assert_eq!(preview.superseded_events.len(), 1);
+    assert_eq!(preview.superseded_events[0].event_id, registered);
+    assert_eq!(preview.superseded_events[0].action_name, "RegisterUser");
+    assert_eq!(preview.affected_users.len(), 1);
+    assert_eq!(preview.affected_users[0].initials, "ZZ");
+    assert_eq!(preview.affected_users[0].change, "removed");
+    assert!(preview.affected_leave_bids.is_empty());
+    assert!(preview.affected_overrides.is_empty());
+    assert!(!preview.requires_canonical_regeneration);
+
+    // Previewing changes nothing
+    assert_eq!(fixture.state("North").unwrap().users.len(), 3);
+}
+
+#[test]
+fn test_preview_restores_from_last_snapshot_before_target() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(2).persist().unwrap();
+    let first_registration: i64 = event_id_of(&mut fixture, "RegisterUser");
+
+    let preview: RollbackPreviewResponse = preview_rollback(
+        &mut fixture.persistence,
+        first_registration,
+        &create_test_admin(),
+    )
+    .unwrap();
+
+    // Registrations are not snapshotted, so the area's creation is restored
+    assert_eq!(
+        preview.snapshot_event_id,
+        event_id_of(&mut fixture, "CreateArea")
+    );
+    assert_eq!(preview.superseded_events.len(), 1);
+    assert_eq!(preview.affected_users.len(), 2);
+    assert!(
+        preview
+            .affected_users
+            .iter()
+            .all(|user| user.change == "removed" && user.user_id.is_some())
+    );
+}
+
+#[test]
+fn test_preview_lists_superseded_overrides() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(2).persist().unwrap();
+    let checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    canonicalize(&mut fixture);
+
+    let user_id: i64 = fixture
+        .state("North")
+        .unwrap()
+        .users
+        .values()
+        .next()
+        .unwrap()
+        .user_id
+        .unwrap();
+    override_eligibility(
+        &mut fixture.persistence,
+        &OverrideEligibilityRequest {
+            user_id,
+            can_bid: false,
+            reason: String::from("Medical clearance pending"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+
+    let preview: RollbackPreviewResponse =
+        preview_rollback(&mut fixture.persistence, checkpoint, &create_test_admin()).unwrap();
+
+    assert_eq!(preview.superseded_events.len(), 1);
+    assert_eq!(
+        preview.superseded_events[0].action_name,
+        "UserEligibilityOverridden"
+    );
+    assert_eq!(preview.affected_overrides.len(), 1);
+    assert_eq!(preview.affected_overrides[0].user_id, user_id);
+    assert!(preview.affected_users.is_empty());
+    assert!(!preview.requires_canonical_regeneration);
+}
+
+#[test]
+fn test_preview_rejects_bidders_and_unscoped_events() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(1).persist().unwrap();
+    let checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+
+    let result = preview_rollback(&mut fixture.persistence, checkpoint, &create_test_bidder());
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    let bid_year_event: i64 = event_id_of(&mut fixture, "CreateBidYear");
+    let result = preview_rollback(
+        &mut fixture.persistence,
+        bid_year_event,
+        &create_test_admin(),
+    );
+    assert!(matches!(result, Err(ApiError::InvalidInput { .. })));
+
+    let result = preview_rollback(&mut fixture.persistence, 9_999, &create_test_admin());
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
+
+#[test]
+fn test_preview_lists_dependent_events() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(1).persist().unwrap();
+    let checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    let later_checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    let canonicalized: i64 = canonicalize(&mut fixture);
+
+    let preview: RollbackPreviewResponse =
+        preview_rollback(&mut fixture.persistence, checkpoint, &create_test_admin()).unwrap();
+
+    // Canonicalization was derived from the area's latest event
+    assert_eq!(preview.superseded_events.len(), 1);
+    assert_eq!(preview.dependent_events.len(), 1);
+    assert_eq!(preview.dependent_events[0].event_id, canonicalized);
+    assert_eq!(
+        preview.dependent_events[0].action_name,
+        "CanonicalizeBidYear"
+    );
+    assert_eq!(
+        preview.dependent_events[0].depends_on_event_id,
+        later_checkpoint
+    );
+
+    // Rolling back to the event it depends on leaves it in place
+    let preview: RollbackPreviewResponse = preview_rollback(
+        &mut fixture.persistence,
+        later_checkpoint,
+        &create_test_admin(),
+    )
+    .unwrap();
+    assert!(preview.dependent_events.is_empty());
+}
+
+#[test]
+fn test_rollback_restores_users_and_supersedes_events() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(2).persist().unwrap();
+    let checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    let registered: i64 = record(&mut fixture, register("ZZ"));
+    assert_eq!(fixture.state("North").unwrap().users.len(), 3);
+
+    let rollback_id: i64 =
+        roll_back(&mut fixture, &RollbackTarget::EventId(checkpoint), false).unwrap();
+
+    let state: State = fixture.state("North").unwrap();
+    assert_eq!(state.users.len(), 2);
+    assert!(
+        !state
+            .users
+            .values()
+            .any(|user| user.initials.value() == "ZZ")
+    );
+
+    // The registration stays in the audit log, marked superseded
+    assert_eq!(
+        fixture
+            .persistence
+            .get_audit_timeline_entries(&[registered])
+            .unwrap()
+            .len(),
+        1
+    );
+    assert_eq!(
+        fixture
+            .persistence
+            .list_superseded_event_ids(&[checkpoint, registered, rollback_id])
+            .unwrap(),
+        vec![registered]
+    );
+
+    // The restored state is snapshotted at the rollback
+    let (snapshot, snapshot_event_id): (State, i64) = fixture
+        .persistence
+        .get_snapshot_at_or_before_event(&BidYear::new(2026), &Area::new("North"), rollback_id)
+        .unwrap();
+    assert_eq!(snapshot_event_id, rollback_id);
+    assert_eq!(snapshot.users.len(), 2);
+
+    // A later preview no longer lists the superseded registration
+    let preview: RollbackPreviewResponse =
+        preview_rollback(&mut fixture.persistence, checkpoint, &create_test_admin()).unwrap();
+    assert_eq!(preview.superseded_events.len(), 1);
+    assert_eq!(preview.superseded_events[0].event_id, rollback_id);
+    assert!(preview.affected_users.is_empty());
+}
+
+#[test]
+fn test_rollback_refuses_superseded_target() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(1).persist().unwrap();
+    let checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    let later_checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    roll_back(&mut fixture, &RollbackTarget::EventId(checkpoint), false).unwrap();
+
+    let result = roll_back(
+        &mut fixture,
+        &RollbackTarget::EventId(later_checkpoint),
+        false,
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "rollback_target_superseded"
+    ));
+}
+
+#[test]
+fn test_rollback_reverts_superseded_overrides() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(2).persist().unwrap();
+    let checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    canonicalize(&mut fixture);
+
+    let user_id: i64 = fixture
+        .state("North")
+        .unwrap()
+        .users
+        .values()
+        .next()
+        .unwrap()
+        .user_id
+        .unwrap();
+    override_eligibility(
+        &mut fixture.persistence,
+        &OverrideEligibilityRequest {
+            user_id,
+            can_bid: false,
+            reason: String::from("Medical clearance pending"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+
+    roll_back(&mut fixture, &RollbackTarget::EventId(checkpoint), false).unwrap();
+
+    let overrides: ListOverridesResponse =
+        list_overrides(&mut fixture.persistence, fixture.bid_year_id).unwrap();
+    assert_eq!(overrides.overrides.len(), 1);
+    assert!(overrides.overrides[0].is_reverted);
+    assert_eq!(fixture.state("North").unwrap().users.len(), 2);
+}
+
+#[test]
+fn test_rollback_regenerates_canonical_rows() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(2).persist().unwrap();
+    let checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    record(&mut fixture, register("ZZ"));
+    canonicalize(&mut fixture);
+
+    let preview: RollbackPreviewResponse =
+        preview_rollback(&mut fixture.persistence, checkpoint, &create_test_admin()).unwrap();
+    assert!(preview.requires_canonical_regeneration);
+
+    // The removed user's canonical rows go with them
+    roll_back(&mut fixture, &RollbackTarget::EventId(checkpoint), true).unwrap();
+    let eligibility: Vec<UserEligibilityData> = fixture
+        .persistence
+        .list_user_eligibility(fixture.bid_year_id)
+        .unwrap();
+    assert_eq!(eligibility.len(), 2);
+    assert!(!eligibility.iter().any(|user| user.initials == "ZZ"));
+
+    // Undoing the rollback brings the user back with regenerated rows
+    roll_back(
+        &mut fixture,
+        &RollbackTarget::Checkpoint(String::from("auto-before-rollback")),
+        false,
+    )
+    .unwrap();
+    let eligibility: Vec<UserEligibilityData> = fixture
+        .persistence
+        .list_user_eligibility(fixture.bid_year_id)
+        .unwrap();
+    assert_eq!(eligibility.len(), 3);
+    let restored: &UserEligibilityData = eligibility
+        .iter()
+        .find(|user| user.initials == "ZZ")
+        .unwrap();
+    assert!(restored.can_bid);
+    assert!(!restored.is_overridden);
+}
+
+#[test]
+fn test_rollback_rejects_target_in_another_area() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(1).persist().unwrap();
+    let checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    let other: State = State::new(BidYear::new(2026), Area::new("South"));
+
+    let result = rollback(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &other,
+        &RollbackTarget::EventId(checkpoint),
+        false,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::InvalidInput { .. })));
+}
+
+#[test]
+fn test_rollback_with_dependents_requires_confirmed_cascade() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(1).persist().unwrap();
+    let checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    let later_checkpoint: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    let canonicalized: i64 = canonicalize(&mut fixture);
+
+    let result = roll_back(&mut fixture, &RollbackTarget::EventId(checkpoint), false);
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "rollback_has_dependent_events"
+    ));
+    assert!(
+        fixture
+            .persistence
+            .list_superseded_event_ids(&[later_checkpoint, canonicalized])
+            .unwrap()
+            .is_empty()
+    );
+
+    roll_back(&mut fixture, &RollbackTarget::EventId(checkpoint), true).unwrap();
+    assert_eq!(
+        fixture
+            .persistence
+            .list_superseded_event_ids(&[later_checkpoint, canonicalized])
+            .unwrap(),
+        vec![later_checkpoint, canonicalized]
+    );
+}
+
+/// Records a checkpoint in 2026/North with a label.
+fn labeled_checkpoint(fixture: &mut PersistedFixture, label: &str) -> i64 {
+    record(
+        fixture,
+        Command::Checkpoint {
+            label: Some(label.to_string()),
+            description: Some(String::from("Before round 3 opens")),
+        },
+    )
+}
+
+#[test]
+fn test_list_checkpoints_shows_labels_newest_first() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(1).persist().unwrap();
+    let anonymous: i64 = record(
+        &mut fixture,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    let labeled: i64 = labeled_checkpoint(&mut fixture, "before-round-3");
+
+    let response: ListCheckpointsResponse = list_checkpoints(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        fixture.bid_year_id,
+        None,
+        &create_test_admin(),
+    )
+    .unwrap();
+
+    assert_eq!(response.bid_year, 2026);
+    assert_eq!(response.checkpoints.len(), 2);
+    assert_eq!(response.checkpoints[0].event_id, labeled);
+    assert_eq!(response.checkpoints[0].area_code, "NORTH");
+    assert_eq!(
+        response.checkpoints[0].label.as_deref(),
+        Some("before-round-3")
+    );
+    assert_eq!(
+        response.checkpoints[0].description.as_deref(),
+        Some("Before round 3 opens")
+    );
+    assert!(!response.checkpoints[0].superseded);
+    assert_eq!(response.checkpoints[1].event_id, anonymous);
+    assert_eq!(response.checkpoints[1].label, None);
+
+    let result = list_checkpoints(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        fixture.bid_year_id,
+        None,
+        &create_test_bidder(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
+
+#[test]
+fn test_rollback_to_labeled_checkpoint() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(2).persist().unwrap();
+    labeled_checkpoint(&mut fixture, "before-round-3");
+    record(&mut fixture, register("YY"));
+    let latest: i64 = labeled_checkpoint(&mut fixture, "before-round-3");
+    let registered: i64 = record(&mut fixture, register("ZZ"));
+
+    // The latest checkpoint with the label is the target
+    let target: RollbackTarget = RollbackTarget::Checkpoint(String::from("before-round-3"));
+    roll_back(&mut fixture, &target, false).unwrap();
+    assert_eq!(fixture.state("North").unwrap().users.len(), 3);
+    assert_eq!(
+        fixture
+            .persistence
+            .list_superseded_event_ids(&[latest, registered])
+            .unwrap(),
+        vec![registered]
+    );
+
+    let result = roll_back(
+        &mut fixture,
+        &RollbackTarget::Checkpoint(String::from("after-round-3")),
+        false,
+    );
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
+
+#[test]
+fn test_rollback_records_automatic_checkpoint_that_undoes_it() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).with_users(2).persist().unwrap();
+    let checkpoint: i64 = labeled_checkpoint(&mut fixture, "before-round-3");
+    let registered: i64 = record(&mut fixture, register("ZZ"));
+
+    let rollback_event_id: i64 =
+        roll_back(&mut fixture, &RollbackTarget::EventId(checkpoint), false).unwrap();
+    assert_eq!(fixture.state("North").unwrap().users.len(), 2);
+
+    // The checkpoint recorded before the rollback is superseded by it, and
+    // both carry the same correlation ID
+    let response: ListCheckpointsResponse = list_checkpoints(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        fixture.bid_year_id,
+        None,
+        &create_test_admin(),
+    )
+    .unwrap();
+    let automatic = &response.checkpoints[0];
+    assert_eq!(automatic.label.as_deref(), Some("auto-before-rollback"));
+    assert!(automatic.event_id > registered);
+    assert!(automatic.superseded);
+    assert!(automatic.correlation_id.is_some());
+    assert_eq!(
+        fixture
+            .persistence
+            .get_event_correlation_id(rollback_event_id)
+            .unwrap(),
+        automatic.correlation_id
+    );
+
+    // Rolling back to it undoes the rollback
+    roll_back(
+        &mut fixture,
+        &RollbackTarget::Checkpoint(String::from("auto-before-rollback")),
+        false,
+    )
+    .unwrap();
+    assert_eq!(fixture.state("North").unwrap().users.len(), 3);
+    assert_eq!(
+        fixture
+            .persistence
+            .list_superseded_event_ids(&[rollback_event_id])
+            .unwrap(),
+        vec![rollback_event_id]
+    );
+
+    // Any other superseded event still cannot be rolled back to
+    let result = roll_back(&mut fixture, &RollbackTarget::EventId(registered), false);
+    assert!(matches!(result, Err(ApiError::DomainRuleViolation { .. })));
+}
@@ -0,0 +1,274 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for syncing the roster with a directory.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+};
+use crate::{
+    ApplyRosterSyncRequest, ApplyRosterSyncResponse, DirectoryMember, RosterSyncAction,
+    RosterSyncOperation, RosterSyncPlanResponse, apply_roster_sync, plan_roster_sync,
+};
+use zab_bid_audit::AuditEvent;
+use zab_bid_domain::{Area, BidYear, User};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Two areas with two users each: `AA` and `AB` in North, `AC` and `AD`
+/// in South.
+fn setup() -> (PersistedFixture, Vec<DirectoryMember>) {
+    let fixture: BidYearFixture = BidYearFixture::new(2026)
+        .with_areas(&["North", "South"])
+        .with_users(2);
+    let members: Vec<DirectoryMember> = ["North", "South"]
+        .iter()
+        .flat_map(|area| fixture.users(area))
+        .map(|user| member(&user))
+        .collect();
+    (fixture.persist().unwrap(), members)
+}
+
+/// The directory entry matching a roster user exactly.
+fn member(user: &User) -> DirectoryMember {
+    DirectoryMember {
+        initials: user.initials.value().to_string(),
+        name: user.name.clone(),
+        area_code: user.area.area_code().to_string(),
+        user_type: Some(user.user_type.as_str().to_string()),
+        crew: user.crew.map(|crew| crew.number()),
+        cumulative_natca_bu_date: Some(user.seniority_data.cumulative_natca_bu_date.clone()),
+        natca_bu_date: Some(user.seniority_data.natca_bu_date.clone()),
+        eod_faa_date: Some(user.seniority_data.eod_faa_date.clone()),
+        service_computation_date: Some(user.seniority_data.service_computation_date.clone()),
+    }
+}
+
+fn new_member(initials: &str, area_code: &str) -> DirectoryMember {
+    DirectoryMember {
+        initials: initials.to_string(),
+        name: format!("New Hire {initials}"),
+        area_code: area_code.to_string(),
+        user_type: Some(String::from("Dev-R")),
+        crew: Some(2),
+        cumulative_natca_bu_date: Some(String::from("2025-06-01")),
+        natca_bu_date: Some(String::from("2025-06-01")),
+        eod_faa_date: Some(String::from("2025-06-01")),
+        service_computation_date: Some(String::from("2025-06-01")),
+    }
+}
+
+fn find_member<'a>(members: &'a mut [DirectoryMember], initials: &str) -> &'a mut DirectoryMember {
+    members.iter_mut().find(|m| m.initials == initials).unwrap()
+}
+
+fn plan(fixture: &mut PersistedFixture, members: &[DirectoryMember]) -> RosterSyncPlanResponse {
+    plan_roster_sync(&mut fixture.persistence, &fixture.metadata, members).unwrap()
+}
+
+fn apply_ids(
+    fixture: &mut PersistedFixture,
+    members: &[DirectoryMember],
+    operation_ids: Vec<String>,
+) -> ApplyRosterSyncResponse {
+    apply_roster_sync(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        members,
+        &ApplyRosterSyncRequest { operation_ids },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap()
+}
+
+fn operation<'a>(plan: &'a RosterSyncPlanResponse, initials: &str) -> &'a RosterSyncOperation {
+    plan.operations
+        .iter()
+        .find(|op| op.initials == initials)
+        .unwrap()
+}
+
+#[test]
+fn test_matching_directory_proposes_nothing() {
+    let (mut fixture, members) = setup();
+
+    let plan: RosterSyncPlanResponse = plan(&mut fixture, &members);
+
+    assert_eq!(plan.bid_year, 2026);
+    assert_eq!(plan.directory_members, 4);
+    assert!(plan.operations.is_empty());
+}
+
+#[test]
+fn test_plan_proposes_adds_updates_and_removals() {
+    let (mut fixture, mut members) = setup();
+    find_member(&mut members, "AA").name = String::from("Renamed Controller");
+    find_member(&mut members, "AB").area_code = String::from("South");
+    members.retain(|m| m.initials != "AC");
+    members.push(new_member("ZZ", "north"));
+
+    let plan: RosterSyncPlanResponse = plan(&mut fixture, &members);
+
+    let actions: Vec<(RosterSyncAction, &str)> = plan
+        .operations
+        .iter()
+        .map(|op| (op.action, op.initials.as_str()))
+        .collect();
+    assert_eq!(
+        actions,
+        vec![
+            (RosterSyncAction::Add, "ZZ"),
+            (RosterSyncAction::Update, "AA"),
+            (RosterSyncAction::Update, "AB"),
+            (RosterSyncAction::Remove, "AC"),
+        ]
+    );
+    assert!(plan.operations.iter().all(|op| op.blocked_reason.is_none()));
+
+    let added: &RosterSyncOperation = operation(&plan, "ZZ");
+    assert_eq!(added.user_id, None);
+    assert_eq!(added.area_code, "NORTH");
+    assert_eq!(
+        operation(&plan, "AA").changes,
+        vec![String::from(
+            "name: 'Controller AA' -> 'Renamed Controller'"
+        )]
+    );
+    assert_eq!(
+        operation(&plan, "AB").changes,
+        vec![String::from("area: NORTH -> SOUTH")]
+    );
+    assert!(operation(&plan, "AC").operation_id.starts_with("remove:"));
+}
+
+#[test]
+fn test_incomplete_or_conflicting_members_are_blocked() {
+    let (mut fixture, mut members) = setup();
+    let mut no_dates: DirectoryMember = new_member("YY", "North");
+    no_dates.eod_faa_date = None;
+    no_dates.service_computation_date = Some(String::new());
+    members.push(no_dates);
+    members.push(new_member("XX", "East"));
+    let mut second_group: DirectoryMember = find_member(&mut members, "AD").clone();
+    second_group.area_code = String::from("North");
+    members.push(second_group);
+
+    let plan: RosterSyncPlanResponse = plan(&mut fixture, &members);
+
+    assert_eq!(
+        operation(&plan, "YY").blocked_reason.as_deref(),
+        Some("Directory has no eod_faa_date, service_computation_date")
+    );
+    assert_eq!(
+        operation(&plan, "XX").blocked_reason.as_deref(),
+        Some("Area 'EAST' does not exist in the active bid year")
+    );
+    assert_eq!(
+        operation(&plan, "AD").blocked_reason.as_deref(),
+        Some("Listed in more than one area group: NORTH, SOUTH")
+    );
+
+    // Blocked operations are never applied, even when approved
+    let ids: Vec<String> = plan
+        .operations
+        .iter()
+        .map(|op| op.operation_id.clone())
+        .collect();
+    let response: ApplyRosterSyncResponse = apply_ids(&mut fixture, &members, ids);
+    assert!(response.applied.is_empty());
+    assert_eq!(response.skipped.len(), 3);
+}
+
+#[test]
+fn test_apply_records_changes_as_system_actor() {
+    let (mut fixture, mut members) = setup();
+    find_member(&mut members, "AA").name = String::from("Renamed Controller");
+    members.retain(|m| m.initials != "AC");
+    members.push(new_member("ZZ", "North"));
+
+    let proposed: RosterSyncPlanResponse = plan(&mut fixture, &members);
+    let ids: Vec<String> = proposed
+        .operations
+        .iter()
+        .map(|op| op.operation_id.clone())
+        .collect();
+    let response: ApplyRosterSyncResponse = apply_ids(&mut fixture, &members, ids);
+
+    assert!(response.skipped.is_empty());
+    let applied: Vec<RosterSyncAction> = response.applied.iter().map(|a| a.action).collect();
+    assert_eq!(
+        applied,
+        vec![
+            RosterSyncAction::Add,
+            RosterSyncAction::Update,
+            RosterSyncAction::Remove,
+        ]
+    );
+
+    // The roster now matches; the removed user stays on record, excluded
+    assert!(plan(&mut fixture, &members).operations.is_empty());
+
+    let events: Vec<AuditEvent> = fixture
+        .persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    for action in ["RegisterUser", "UpdateUser"] {
+        let event: &AuditEvent = events
+            .iter()
+            .rev()
+            .find(|e| e.action.name == action)
+            .unwrap();
+        assert_eq!(event.actor.id, "roster_sync");
+        assert_eq!(event.actor.actor_type, "system");
+        assert_eq!(event.cause.id, "roster_sync");
+    }
+}
+
+#[test]
+fn test_apply_skips_changes_that_are_stale_or_not_approved() {
+    let (mut fixture, mut members) = setup();
+    find_member(&mut members, "AA").name = String::from("Reviewed Name");
+    members.retain(|m| m.initials != "AC");
+
+    let reviewed: RosterSyncPlanResponse = plan(&mut fixture, &members);
+    let rename_id: String = operation(&reviewed, "AA").operation_id.clone();
+
+    // The directory changes again between review and approval
+    find_member(&mut members, "AA").name = String::from("Different Name");
+    let response: ApplyRosterSyncResponse =
+        apply_ids(&mut fixture, &members, vec![rename_id.clone()]);
+
+    assert!(response.applied.is_empty());
+    assert_eq!(response.skipped.len(), 1);
+    assert_eq!(response.skipped[0].operation_id, rename_id);
+    assert!(response.skipped[0].reason.starts_with("No longer proposed"));
+
+    // Neither the rename nor the unapproved removal was applied
+    let after: RosterSyncPlanResponse = plan(&mut fixture, &members);
+    assert_eq!(
+        operation(&after, "AA").changes,
+        vec![String::from("name: 'Controller AA' -> 'Different Name'")]
+    );
+    assert_eq!(operation(&after, "AC").action, RosterSyncAction::Remove);
+}
+
+#[test]
+fn test_apply_requires_admin() {
+    let (mut fixture, members) = setup();
+
+    let result: Result<ApplyRosterSyncResponse, ApiError> = apply_roster_sync(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &members,
+        &ApplyRosterSyncRequest {
+            operation_ids: Vec::new(),
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for round group rotations and per-round bid order.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    GetRoundBidOrderResponse, SetRoundGroupRotationRequest, SetRoundGroupRotationResponse,
+    get_round_bid_order, set_round_group_rotation,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with one user and a round group holding two rounds.
+/// The bid year is left in `Draft` so the rotation can be set.
+fn setup() -> PersistedFixture {
+    BidYearFixture::new(2026)
+        .with_users(1)
+        .with_rounds(2)
+        .persist()
+        .unwrap()
+}
+
+fn set_rotation(
+    fixture: &mut PersistedFixture,
+    round_group_id: i64,
+    rotation: &str,
+) -> Result<SetRoundGroupRotationResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    set_round_group_rotation(
+        &mut fixture.persistence,
+        &metadata,
+        &SetRoundGroupRotationRequest {
+            bid_year_id: fixture.bid_year_id,
+            round_group_id,
+            rotation: String::from(rotation),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+#[test]
+fn test_round_group_rotation_is_shown_with_the_round_bid_order() {
+    let mut fixture: PersistedFixture = setup();
+    let round_group_id: i64 = fixture.round_group_id.unwrap();
+
+    let before: GetRoundBidOrderResponse =
+        get_round_bid_order(&mut fixture.persistence, fixture.round_ids[1]).unwrap();
+    assert_eq!(before.rotation, "forward");
+    // The sequence is only derived once the bid year is confirmed
+    assert!(before.entries.is_empty());
+
+    let response: SetRoundGroupRotationResponse =
+        set_rotation(&mut fixture, round_group_id, "reverse_even_rounds").unwrap();
+    assert_eq!(response.rotation, "reverse_even_rounds");
+    let after: GetRoundBidOrderResponse =
+        get_round_bid_order(&mut fixture.persistence, fixture.round_ids[1]).unwrap();
+    assert_eq!(after.rotation, "reverse_even_rounds");
+
+    set_rotation(&mut fixture, round_group_id, "forward").unwrap();
+    let reset: GetRoundBidOrderResponse =
+        get_round_bid_order(&mut fixture.persistence, fixture.round_ids[1]).unwrap();
+    assert_eq!(reset.rotation, "forward");
+
+    assert!(matches!(
+        get_round_bid_order(&mut fixture.persistence, fixture.round_ids[1] + 100),
+        Err(ApiError::ResourceNotFound { .. })
+    ));
+}
+
+#[test]
+fn test_set_round_group_rotation_rejects_invalid_requests() {
+    let mut fixture: PersistedFixture = setup();
+    let round_group_id: i64 = fixture.round_group_id.unwrap();
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+
+    let result: Result<SetRoundGroupRotationResponse, ApiError> = set_round_group_rotation(
+        &mut fixture.persistence,
+        &metadata,
+        &SetRoundGroupRotationRequest {
+            bid_year_id: fixture.bid_year_id,
+            round_group_id,
+            rotation: String::from("reverse_even_rounds"),
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    assert!(matches!(
+        set_rotation(&mut fixture, round_group_id, "sideways"),
+        Err(ApiError::InvalidInput { .. })
+    ));
+    assert!(matches!(
+        set_rotation(&mut fixture, round_group_id + 100, "forward"),
+        Err(ApiError::ResourceNotFound { .. })
+    ));
+
+    fixture
+        .persistence
+        .update_lifecycle_state(fixture.bid_year_id, "Canonicalized")
+        .unwrap();
+    assert!(matches!(
+        set_rotation(&mut fixture, round_group_id, "reverse_even_rounds"),
+        Err(ApiError::DomainRuleViolation { .. })
+    ));
+}
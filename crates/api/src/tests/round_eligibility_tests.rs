@@ -0,0 +1,142 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the criteria limiting a round to a subset of bidders.
+
+use crate::error::ApiError;
+use crate::round_eligibility::RoundEligibilityRules;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    ListRoundsResponse, RoundEligibilityInfo, SetRoundEligibilityRequest,
+    SetRoundEligibilityResponse, list_rounds, set_round_eligibility,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_domain::User;
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with CPCs AA and AB, a CPC-IT (AC), a Dev-R (AD),
+/// and one round. The bid year is left in `Draft` so the round's criteria
+/// can be set.
+fn setup() -> PersistedFixture {
+    BidYearFixture::new(2026)
+        .with_users(4)
+        .with_rounds(1)
+        .persist()
+        .unwrap()
+}
+
+fn set_criteria(
+    fixture: &mut PersistedFixture,
+    min_remaining_hours: Option<u32>,
+    user_types: &[&str],
+) -> Result<SetRoundEligibilityResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: SetRoundEligibilityRequest = SetRoundEligibilityRequest {
+        bid_year_id: fixture.bid_year_id,
+        round_id: fixture.round_ids[0],
+        min_remaining_hours,
+        user_types: user_types.iter().map(|t| String::from(*t)).collect(),
+    };
+    set_round_eligibility(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+#[test]
+fn test_round_eligibility_is_shown_with_the_round() {
+    let mut fixture: PersistedFixture = setup();
+
+    let response: SetRoundEligibilityResponse =
+        set_criteria(&mut fixture, Some(40), &["CPC"]).unwrap();
+    let expected: RoundEligibilityInfo = RoundEligibilityInfo {
+        min_remaining_hours: Some(40),
+        user_types: vec![String::from("CPC")],
+    };
+    assert_eq!(response.eligibility, Some(expected.clone()));
+
+    let rounds: ListRoundsResponse = list_rounds(
+        &mut fixture.persistence,
+        fixture.round_group_id.unwrap(),
+        &create_test_admin(),
+    )
+    .unwrap();
+    assert_eq!(rounds.rounds[0].eligibility, Some(expected));
+
+    // Criteria that set nothing open the round to every bidder
+    let cleared: SetRoundEligibilityResponse = set_criteria(&mut fixture, None, &[]).unwrap();
+    assert_eq!(cleared.eligibility, None);
+    let rounds: ListRoundsResponse = list_rounds(
+        &mut fixture.persistence,
+        fixture.round_group_id.unwrap(),
+        &create_test_admin(),
+    )
+    .unwrap();
+    assert_eq!(rounds.rounds[0].eligibility, None);
+}
+
+#[test]
+fn test_set_round_eligibility_rejects_invalid_requests() {
+    let mut fixture: PersistedFixture = setup();
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+
+    let result: Result<SetRoundEligibilityResponse, ApiError> = set_round_eligibility(
+        &mut fixture.persistence,
+        &metadata,
+        &SetRoundEligibilityRequest {
+            bid_year_id: fixture.bid_year_id,
+            round_id: fixture.round_ids[0],
+            min_remaining_hours: Some(40),
+            user_types: Vec::new(),
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    assert!(matches!(
+        set_criteria(&mut fixture, None, &["Tower"]),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "user_type"
+    ));
+    assert!(matches!(
+        set_criteria(&mut fixture, None, &["CPC", "CPC"]),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "round_configuration"
+    ));
+}
+
+#[test]
+fn test_restricted_round_admits_only_matching_users() {
+    let mut fixture: PersistedFixture = setup();
+    set_criteria(&mut fixture, None, &["CPC"]).unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let (bid_year, area) = metadata.areas.first().unwrap();
+    let area_id: i64 = area.area_id().unwrap();
+    let users: Vec<User> = fixture
+        .persistence
+        .list_users_with_routing(fixture.bid_year_id, area_id, bid_year, area)
+        .unwrap();
+
+    let rules: RoundEligibilityRules = RoundEligibilityRules::load(
+        &mut fixture.persistence,
+        &metadata,
+        fixture.bid_year_id,
+        &users,
+    )
+    .unwrap();
+    let round_id: i64 = fixture.round_ids[0];
+    assert!(rules.admits(fixture.user_id("AA"), round_id));
+    assert!(!rules.admits(fixture.user_id("AD"), round_id));
+    // Rounds without criteria admit everyone
+    assert!(rules.admits(fixture.user_id("AD"), round_id + 1));
+}
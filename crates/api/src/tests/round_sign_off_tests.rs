@@ -0,0 +1,234 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for round sign-off and the lock it places on a round's bids.
+
+use crate::auth::AuthenticatedActor;
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    EnterLeaveBidRequest, ListRoundSignOffsResponse, SignOffRoundRequest, SignOffRoundResponse,
+    enter_leave_bid, list_round_sign_offs, sign_off_round,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, StateSnapshot};
+use zab_bid_persistence::{BidStatusRow, NewBidStatus, OperatorData};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with users AA, AB, and AC in that bid order and one
+/// round with two slots per day. AA has bid, AB missed their window, and AC
+/// is still bidding. The bid year is `BiddingActive`.
+fn setup() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(3)
+        .with_rounds(1)
+        .persist()
+        .unwrap();
+    let bid_year_id: i64 = fixture.bid_year_id;
+    let area_id: i64 = fixture.area_id("North");
+    let round_id: i64 = fixture.round_ids[0];
+    fixture
+        .persistence
+        .update_round(round_id, "Round 1", 2, 3, 200, false, false)
+        .unwrap();
+
+    let event: AuditEvent = AuditEvent::new_global(
+        BidYearFixture::actor(fixture.operator_id),
+        BidYearFixture::cause(),
+        Action::new(String::from("CanonicalizeBidYear"), None),
+        StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+    );
+    fixture
+        .persistence
+        .canonicalize_bid_year(bid_year_id, &event)
+        .unwrap();
+    let users: Vec<(&str, i64)> = ["AA", "AB", "AC"]
+        .into_iter()
+        .map(|initials| (initials, fixture.user_id(initials)))
+        .collect();
+    for (order, (_, user_id)) in (1..).zip(&users) {
+        fixture
+            .persistence
+            .override_bid_order(bid_year_id, *user_id, Some(order), "Test order")
+            .unwrap();
+    }
+
+    let statuses: Vec<NewBidStatus> = users
+        .iter()
+        .map(|(initials, user_id)| NewBidStatus {
+            bid_year_id,
+            area_id,
+            user_id: *user_id,
+            round_id,
+            status: String::from(match *initials {
+                "AA" => "completed_on_time",
+                "AB" => "missed",
+                _ => "in_progress",
+            }),
+            updated_at: String::from("2026-03-02T21:00:00+00:00"),
+            updated_by: 1,
+            notes: None,
+        })
+        .collect();
+    fixture
+        .persistence
+        .bulk_insert_bid_status(&statuses)
+        .unwrap();
+    fixture
+        .persistence
+        .update_lifecycle_state(bid_year_id, "BiddingActive")
+        .unwrap();
+
+    fixture
+}
+
+fn sign_off(fixture: &mut PersistedFixture) -> Result<SignOffRoundResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: SignOffRoundRequest = SignOffRoundRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+    };
+    sign_off_round(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+/// Marks AC as having waived bidding in the round.
+fn waive_ac(fixture: &mut PersistedFixture) {
+    let bid_year_id: i64 = fixture.bid_year_id;
+    let area_id: i64 = fixture.area_id("North");
+    let user_id: i64 = fixture.user_id("AC");
+    let round_id: i64 = fixture.round_ids[0];
+    let row: BidStatusRow = fixture
+        .persistence
+        .get_bid_status_for_user_and_round(bid_year_id, area_id, user_id, round_id)
+        .unwrap();
+    fixture
+        .persistence
+        .update_bid_status(
+            row.bid_status_id,
+            "voluntarily_not_bidding",
+            "2026-03-03T13:00:00+00:00",
+            1,
+            None,
+        )
+        .unwrap();
+}
+
+fn enter(
+    fixture: &mut PersistedFixture,
+    override_reason: Option<&str>,
+    actor: &AuthenticatedActor,
+    operator: &OperatorData,
+) -> Result<(), ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: EnterLeaveBidRequest = EnterLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from("AA"),
+        received_via: String::from("phone"),
+        leave_dates: vec![String::from("2026-07-01")],
+        hours: 8,
+        override_reason: override_reason.map(String::from),
+    };
+    enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        actor,
+        operator,
+        create_test_cause(),
+    )
+    .map(|_| ())
+}
+
+#[test]
+fn test_sign_off_requires_every_user_done() {
+    let mut fixture: PersistedFixture = setup();
+
+    let result: Result<SignOffRoundResponse, ApiError> = sign_off(&mut fixture);
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, ref message })
+            if rule == "round_sign_off" && message.contains("AC")
+    ));
+
+    // Only Admins may sign off a round
+    waive_ac(&mut fixture);
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: SignOffRoundRequest = SignOffRoundRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+    };
+    let result: Result<SignOffRoundResponse, ApiError> = sign_off_round(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    let response: SignOffRoundResponse = sign_off(&mut fixture).unwrap();
+    assert_eq!((response.bid, response.skipped, response.waived), (1, 1, 1));
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let listed: ListRoundSignOffsResponse =
+        list_round_sign_offs(&mut fixture.persistence, &metadata, fixture.bid_year_id).unwrap();
+    assert_eq!(listed.sign_offs.len(), 1);
+    assert_eq!(listed.sign_offs[0].round_id, fixture.round_ids[0]);
+    assert_eq!(listed.sign_offs[0].audit_event_id, response.audit_event_id);
+
+    // A round is signed off once per area
+    assert!(matches!(
+        sign_off(&mut fixture),
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "round_signed_off"
+    ));
+}
+
+#[test]
+fn test_signed_off_round_locks_bids_without_override() {
+    let mut fixture: PersistedFixture = setup();
+    waive_ac(&mut fixture);
+    sign_off(&mut fixture).unwrap();
+
+    let result: Result<(), ApiError> = enter(
+        &mut fixture,
+        None,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "round_signed_off"
+    ));
+
+    // Only Admins may override a sign-off
+    let result: Result<(), ApiError> = enter(
+        &mut fixture,
+        Some("Grievance settlement"),
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    enter(
+        &mut fixture,
+        Some("Grievance settlement"),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+}
@@ -0,0 +1,285 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for round group templates and copying round configuration.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    bootstrap_bid_year_only, create_test_admin, create_test_bidder, setup_test_persistence,
+};
+use crate::{
+    ApplyRoundGroupTemplateRequest, CopyRoundConfigRequest, CreateRoundGroupRequest,
+    CreateRoundGroupTemplateRequest, CreateRoundGroupTemplateResponse, RoundTemplateSpec,
+    apply_round_group_template, copy_round_config, create_round_group, create_round_group_template,
+    delete_round_group_template, list_round_group_templates, list_round_groups, list_rounds,
+};
+use zab_bid_persistence::SqlitePersistence;
+
+fn round_spec(round_number: u32, name: &str) -> RoundTemplateSpec {
+    RoundTemplateSpec {
+        round_number,
+        name: name.to_string(),
+        slots_per_day: 3,
+        max_groups: 2,
+        max_total_hours: 80,
+        include_holidays: false,
+        allow_overbid: true,
+    }
+}
+
+fn create_standard_template(
+    persistence: &mut SqlitePersistence,
+) -> CreateRoundGroupTemplateResponse {
+    create_round_group_template(
+        persistence,
+        &CreateRoundGroupTemplateRequest {
+            name: String::from("Standard"),
+            editing_enabled: true,
+            rounds: vec![round_spec(1, "Round 1"), round_spec(2, "Round 2")],
+        },
+        &create_test_admin(),
+    )
+    .unwrap()
+}
+
+/// Returns the IDs of bid years 2026 (from the test setup) and 2027.
+fn setup_two_bid_years(persistence: &mut SqlitePersistence) -> (i64, i64) {
+    let bid_year_2026: i64 = persistence.get_bid_year_id(2026).unwrap();
+    let bid_year_2027: i64 = bootstrap_bid_year_only(persistence, 2027, 1).unwrap();
+    (bid_year_2026, bid_year_2027)
+}
+
+#[test]
+fn test_template_is_listed_with_rounds() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let created = create_standard_template(&mut persistence);
+    assert_eq!(created.round_count, 2);
+
+    let listing = list_round_group_templates(&mut persistence, &create_test_admin()).unwrap();
+    assert_eq!(listing.templates.len(), 1);
+    let template = &listing.templates[0];
+    assert_eq!(template.template_id, created.template_id);
+    assert_eq!(template.name, "Standard");
+    assert_eq!(
+        template.rounds,
+        vec![round_spec(1, "Round 1"), round_spec(2, "Round 2")]
+    );
+
+    delete_round_group_template(&mut persistence, created.template_id, &create_test_admin())
+        .unwrap();
+    let listing = list_round_group_templates(&mut persistence, &create_test_admin()).unwrap();
+    assert!(listing.templates.is_empty());
+
+    let result =
+        delete_round_group_template(&mut persistence, created.template_id, &create_test_admin());
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
+
+#[test]
+fn test_template_creation_is_validated() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    create_standard_template(&mut persistence);
+
+    let request = |name: &str, rounds: Vec<RoundTemplateSpec>| CreateRoundGroupTemplateRequest {
+        name: name.to_string(),
+        editing_enabled: true,
+        rounds,
+    };
+
+    let result = create_round_group_template(
+        &mut persistence,
+        &request("Other", vec![round_spec(1, "A"), round_spec(1, "B")]),
+        &create_test_admin(),
+    );
+    assert!(matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "rounds"));
+
+    let mut zero_slots: RoundTemplateSpec = round_spec(1, "A");
+    zero_slots.slots_per_day = 0;
+    let result = create_round_group_template(
+        &mut persistence,
+        &request("Other", vec![zero_slots]),
+        &create_test_admin(),
+    );
+    assert!(
+        matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "slots_per_day")
+    );
+
+    let result = create_round_group_template(
+        &mut persistence,
+        &request("  ", Vec::new()),
+        &create_test_admin(),
+    );
+    assert!(matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "name"));
+
+    let result = create_round_group_template(
+        &mut persistence,
+        &request("Standard", Vec::new()),
+        &create_test_admin(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. })
+            if rule == "unique_round_group_template_name"
+    ));
+
+    let result = create_round_group_template(
+        &mut persistence,
+        &request("Other", Vec::new()),
+        &create_test_bidder(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
+
+#[test]
+fn test_apply_template_creates_group_and_rounds() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    let template = create_standard_template(&mut persistence);
+
+    let request = |name: Option<&str>| ApplyRoundGroupTemplateRequest {
+        template_id: template.template_id,
+        name: name.map(str::to_string),
+    };
+
+    let applied = apply_round_group_template(
+        &mut persistence,
+        bid_year_id,
+        &request(None),
+        &create_test_admin(),
+    )
+    .unwrap();
+    assert_eq!(applied.name, "Standard");
+    assert_eq!(applied.round_count, 2);
+
+    let rounds = list_rounds(
+        &mut persistence,
+        applied.round_group_id,
+        &create_test_admin(),
+    )
+    .unwrap()
+    .rounds;
+    let numbers: Vec<u32> = rounds.iter().map(|r| r.round_number).collect();
+    assert_eq!(numbers, vec![1, 2]);
+    assert_eq!(rounds[0].slots_per_day, 3);
+    assert!(rounds[1].allow_overbid);
+
+    let result = apply_round_group_template(
+        &mut persistence,
+        bid_year_id,
+        &request(None),
+        &create_test_admin(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "unique_round_group_name"
+    ));
+
+    let renamed = apply_round_group_template(
+        &mut persistence,
+        bid_year_id,
+        &request(Some("Standard (Nights)")),
+        &create_test_admin(),
+    )
+    .unwrap();
+    assert_eq!(renamed.name, "Standard (Nights)");
+
+    let result = apply_round_group_template(
+        &mut persistence,
+        bid_year_id,
+        &ApplyRoundGroupTemplateRequest {
+            template_id: 9999,
+            name: None,
+        },
+        &create_test_admin(),
+    );
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
+
+#[test]
+fn test_copy_round_config_between_bid_years() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let (bid_year_2026, bid_year_2027) = setup_two_bid_years(&mut persistence);
+    let template = create_standard_template(&mut persistence);
+    apply_round_group_template(
+        &mut persistence,
+        bid_year_2026,
+        &ApplyRoundGroupTemplateRequest {
+            template_id: template.template_id,
+            name: None,
+        },
+        &create_test_admin(),
+    )
+    .unwrap();
+    create_round_group(
+        &mut persistence,
+        bid_year_2026,
+        &CreateRoundGroupRequest {
+            name: String::from("Empty"),
+            editing_enabled: false,
+        },
+        &create_test_admin(),
+    )
+    .unwrap();
+
+    let request = CopyRoundConfigRequest {
+        from_bid_year_id: bid_year_2026,
+        to_bid_year_id: bid_year_2027,
+    };
+    let copied = copy_round_config(&mut persistence, &request, &create_test_admin()).unwrap();
+    assert_eq!(copied.round_group_ids.len(), 2);
+    assert_eq!(copied.round_count, 2);
+
+    let groups = list_round_groups(&mut persistence, bid_year_2027, &create_test_admin())
+        .unwrap()
+        .round_groups;
+    let mut names: Vec<&str> = groups.iter().map(|g| g.name.as_str()).collect();
+    names.sort_unstable();
+    assert_eq!(names, vec!["Empty", "Standard"]);
+
+    // A second copy collides on every name and creates nothing
+    let result = copy_round_config(&mut persistence, &request, &create_test_admin());
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "unique_round_group_name"
+    ));
+    let groups = list_round_groups(&mut persistence, bid_year_2027, &create_test_admin())
+        .unwrap()
+        .round_groups;
+    assert_eq!(groups.len(), 2);
+}
+
+#[test]
+fn test_copy_round_config_rejects_invalid_targets() {
+    let mut persistence: SqlitePersistence = setup_test_persistence().unwrap();
+    let (bid_year_2026, bid_year_2027) = setup_two_bid_years(&mut persistence);
+
+    let copy = |persistence: &mut SqlitePersistence, from: i64, to: i64| {
+        copy_round_config(
+            persistence,
+            &CopyRoundConfigRequest {
+                from_bid_year_id: from,
+                to_bid_year_id: to,
+            },
+            &create_test_admin(),
+        )
+    };
+
+    let result = copy(&mut persistence, bid_year_2026, bid_year_2026);
+    assert!(
+        matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "to_bid_year_id")
+    );
+
+    let result = copy(&mut persistence, bid_year_2026, 9999);
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+
+    persistence
+        .update_lifecycle_state(bid_year_2027, "Canonicalized")
+        .unwrap();
+    let result = copy(&mut persistence, bid_year_2026, bid_year_2027);
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "round_group_lifecycle"
+    ));
+}
@@ -7,8 +7,9 @@
 
 use crate::{
     ApiError, AuthenticatedActor, CreateRoundGroupRequest, CreateRoundRequest,
-    UpdateRoundGroupRequest, UpdateRoundRequest, create_round, create_round_group, delete_round,
-    delete_round_group, list_round_groups, list_rounds, update_round, update_round_group,
+    ReorderRoundsRequest, UpdateRoundGroupRequest, UpdateRoundRequest, create_round,
+    create_round_group, delete_round, delete_round_group, list_round_groups, list_rounds,
+    reorder_rounds, update_round, update_round_group,
 };
 
 use super::helpers::{create_test_admin, create_test_bidder, setup_test_persistence};
@@ -642,3 +643,115 @@ fn test_bidder_cannot_create_round() {
 
     assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
 }
+
+// ============================================================================
+// Round Reordering Tests
+// ============================================================================
+
+/// Creates a round group with rounds numbered as given and returns
+/// `(round_group_id, round_ids)`.
+fn setup_numbered_rounds(
+    persistence: &mut zab_bid_persistence::SqlitePersistence,
+    round_numbers: &[u32],
+) -> (i64, Vec<i64>) {
+    let admin: AuthenticatedActor = create_test_admin();
+    let bid_year_id = persistence
+        .get_bid_year_id(2026)
+        .expect("Failed to get bid year ID");
+
+    let round_group = create_round_group(
+        persistence,
+        bid_year_id,
+        &CreateRoundGroupRequest {
+            name: String::from("Regular Round"),
+            editing_enabled: true,
+        },
+        &admin,
+    )
+    .expect("Failed to create round group");
+
+    let round_ids = round_numbers
+        .iter()
+        .map(|&round_number| {
+            let request = CreateRoundRequest {
+                round_group_id: round_group.round_group_id,
+                round_number,
+                name: format!("Round {round_number}"),
+                slots_per_day: 10,
+                max_groups: 5,
+                max_total_hours: 80,
+                include_holidays: false,
+                allow_overbid: false,
+            };
+            create_round(persistence, round_group.round_group_id, &request, &admin)
+                .expect("Failed to create round")
+                .round_id
+        })
+        .collect();
+
+    (round_group.round_group_id, round_ids)
+}
+
+#[test]
+fn test_reorder_rounds_inserts_round_mid_sequence() {
+    let mut persistence = setup_test_persistence().expect("Failed to setup test persistence");
+    let admin: AuthenticatedActor = create_test_admin();
+
+    // Rounds 1, 2, 3 exist; a new round is added at the end as 4
+    let (round_group_id, ids) = setup_numbered_rounds(&mut persistence, &[1, 2, 3, 4]);
+
+    // Move the new round into position 2
+    let request = ReorderRoundsRequest {
+        round_group_id,
+        round_ids: vec![ids[0], ids[3], ids[1], ids[2]],
+    };
+    let response = reorder_rounds(&mut persistence, &request, &admin).expect("Failed to reorder");
+
+    let renumbered: Vec<(i64, u32)> = response
+        .rounds
+        .iter()
+        .map(|r| (r.round_id, r.round_number))
+        .collect();
+    assert_eq!(
+        renumbered,
+        vec![(ids[0], 1), (ids[3], 2), (ids[1], 3), (ids[2], 4)]
+    );
+    assert_eq!(response.rounds[1].name, "Round 4");
+}
+
+#[test]
+fn test_reorder_rounds_requires_every_round_once() {
+    let mut persistence = setup_test_persistence().expect("Failed to setup test persistence");
+    let admin: AuthenticatedActor = create_test_admin();
+    let (round_group_id, ids) = setup_numbered_rounds(&mut persistence, &[1, 2, 3]);
+
+    for round_ids in [
+        vec![ids[0], ids[1]],
+        vec![ids[0], ids[1], ids[1]],
+        vec![ids[0], ids[1], ids[2], 9999],
+    ] {
+        let request = ReorderRoundsRequest {
+            round_group_id,
+            round_ids,
+        };
+        let result = reorder_rounds(&mut persistence, &request, &admin);
+        assert!(
+            matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "round_ids")
+        );
+    }
+
+    let numbers: Vec<u32> = list_rounds(&mut persistence, round_group_id, &admin)
+        .expect("Failed to list rounds")
+        .rounds
+        .iter()
+        .map(|r| r.round_number)
+        .collect();
+    assert_eq!(numbers, vec![1, 2, 3]);
+
+    let request = ReorderRoundsRequest {
+        round_group_id,
+        round_ids: ids,
+    };
+    let result = reorder_rounds(&mut persistence, &request, &create_test_bidder());
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
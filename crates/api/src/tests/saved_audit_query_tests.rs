@@ -0,0 +1,220 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for saved audit queries and their exports.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_cause,
+};
+use crate::{
+    AuditTimelineFilter, AuditTimelineScope, CreateSavedAuditQueryRequest,
+    DeleteSavedAuditQueryRequest, ListSavedAuditQueriesResponse, SavedAuditQueryExport,
+    SavedAuditQueryResponse, ScheduleSavedAuditQueryRequest, create_saved_audit_query,
+    delete_saved_audit_query, export_saved_audit_query, list_saved_audit_queries,
+    render_saved_audit_query, schedule_saved_audit_query,
+};
+use zab_bid_persistence::SqlitePersistence;
+
+/// Creates persistence with the admin operator (ID 1) used for audit attribution.
+fn setup() -> SqlitePersistence {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    persistence
+        .create_operator("ADMIN-123", "Test Admin", "password", "Admin")
+        .unwrap();
+    persistence
+}
+
+fn create_request(name: &str, action_name: &str) -> CreateSavedAuditQueryRequest {
+    CreateSavedAuditQueryRequest {
+        name: name.to_string(),
+        scope: AuditTimelineScope::Global,
+        filter: AuditTimelineFilter {
+            action_name: Some(action_name.to_string()),
+            ..AuditTimelineFilter::default()
+        },
+        weekly_recipients: Vec::new(),
+    }
+}
+
+fn save(
+    persistence: &mut SqlitePersistence,
+    request: &CreateSavedAuditQueryRequest,
+) -> Result<SavedAuditQueryResponse, ApiError> {
+    create_saved_audit_query(
+        persistence,
+        request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn schedule(
+    persistence: &mut SqlitePersistence,
+    saved_query_id: i64,
+    recipients: &[&str],
+) -> Result<SavedAuditQueryResponse, ApiError> {
+    schedule_saved_audit_query(
+        persistence,
+        &ScheduleSavedAuditQueryRequest {
+            saved_query_id,
+            weekly_recipients: recipients.iter().map(|r| (*r).to_string()).collect(),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+#[test]
+fn test_saved_query_round_trips_scope_filter_and_recipients() {
+    let mut persistence: SqlitePersistence = setup();
+    let mut request: CreateSavedAuditQueryRequest =
+        create_request("  Schedule changes ", "ScheduleAuditQuery");
+    request.weekly_recipients = vec![
+        String::from("union@example.test"),
+        String::from(" UNION@example.test "),
+        String::from("manager@example.test"),
+    ];
+
+    let saved: SavedAuditQueryResponse = save(&mut persistence, &request).unwrap();
+
+    assert_eq!(saved.saved_query.name, "Schedule changes");
+    assert_eq!(saved.saved_query.scope, AuditTimelineScope::Global);
+    assert_eq!(saved.saved_query.filter, request.filter);
+    assert_eq!(
+        saved.saved_query.weekly_recipients,
+        vec!["union@example.test", "manager@example.test"]
+    );
+    assert_eq!(saved.saved_query.created_by_operator_id, 1);
+    assert_eq!(saved.saved_query.last_delivered_at, None);
+
+    let listed: ListSavedAuditQueriesResponse =
+        list_saved_audit_queries(&mut persistence, &create_test_admin()).unwrap();
+    assert_eq!(listed.saved_queries, vec![saved.saved_query]);
+}
+
+#[test]
+fn test_invalid_saved_queries_are_rejected() {
+    let mut persistence: SqlitePersistence = setup();
+    save(
+        &mut persistence,
+        &create_request("Overrides", "OverrideEligibility"),
+    )
+    .unwrap();
+
+    let duplicate = save(&mut persistence, &create_request("overrides", "Rollback"));
+    assert!(matches!(
+        duplicate,
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "name"
+    ));
+
+    let mut bad_recipient: CreateSavedAuditQueryRequest = create_request("Rollbacks", "Rollback");
+    bad_recipient.weekly_recipients = vec![String::from("a@example.test,b@example.test")];
+    assert!(matches!(
+        save(&mut persistence, &bad_recipient),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "weekly_recipients"
+    ));
+
+    // Filtering by initials needs a bid year, so the query cannot run
+    let mut needs_bid_year: CreateSavedAuditQueryRequest = create_request("AB history", "Rollback");
+    needs_bid_year.filter.user_initials = Some(String::from("AB"));
+    assert!(matches!(
+        save(&mut persistence, &needs_bid_year),
+        Err(ApiError::InvalidInput { ref field, .. }) if field == "user_initials"
+    ));
+
+    let listed: ListSavedAuditQueriesResponse =
+        list_saved_audit_queries(&mut persistence, &create_test_admin()).unwrap();
+    assert_eq!(listed.saved_queries.len(), 1);
+}
+
+#[test]
+fn test_export_renders_matching_events_as_csv() {
+    let mut persistence: SqlitePersistence = setup();
+    let saved: SavedAuditQueryResponse = save(
+        &mut persistence,
+        &create_request("Weekly schedules", "ScheduleAuditQuery"),
+    )
+    .unwrap();
+    let saved_query_id: i64 = saved.saved_query.saved_query_id;
+
+    let scheduled: SavedAuditQueryResponse =
+        schedule(&mut persistence, saved_query_id, &["union@example.test"]).unwrap();
+    assert_eq!(
+        scheduled.saved_query.weekly_recipients,
+        vec!["union@example.test"]
+    );
+    schedule(&mut persistence, saved_query_id, &[]).unwrap();
+
+    let export: SavedAuditQueryExport =
+        render_saved_audit_query(&mut persistence, saved_query_id).unwrap();
+    assert_eq!(export.name, "Weekly schedules");
+    assert_eq!(export.event_count, 2);
+    assert!(!export.truncated);
+    assert_eq!(export.report.filename, "audit-weekly-schedules.csv");
+    assert_eq!(export.report.content_type, "text/csv; charset=utf-8");
+
+    let csv: String = String::from_utf8(export.report.body).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        "event_id,created_at,actor,action,details,cause,bid_year,area,changes"
+    );
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].contains("Scheduled audit query 'Weekly schedules' weekly to 1 recipients"));
+    assert!(lines[2].contains("Stopped weekly delivery of audit query 'Weekly schedules'"));
+    // The save itself is not a ScheduleAuditQuery event
+    assert!(!csv.contains("SaveAuditQuery"));
+}
+
+#[test]
+fn test_deleted_query_can_no_longer_be_exported() {
+    let mut persistence: SqlitePersistence = setup();
+    let saved: SavedAuditQueryResponse =
+        save(&mut persistence, &create_request("Rollbacks", "Rollback")).unwrap();
+    let saved_query_id: i64 = saved.saved_query.saved_query_id;
+
+    delete_saved_audit_query(
+        &mut persistence,
+        DeleteSavedAuditQueryRequest { saved_query_id },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert!(matches!(
+        export_saved_audit_query(&mut persistence, saved_query_id, &create_test_admin()),
+        Err(ApiError::ResourceNotFound { .. })
+    ));
+    assert!(
+        list_saved_audit_queries(&mut persistence, &create_test_admin())
+            .unwrap()
+            .saved_queries
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_saved_queries_require_admin() {
+    let mut persistence: SqlitePersistence = setup();
+    let saved: SavedAuditQueryResponse =
+        save(&mut persistence, &create_request("Rollbacks", "Rollback")).unwrap();
+
+    assert!(matches!(
+        list_saved_audit_queries(&mut persistence, &create_test_bidder()),
+        Err(ApiError::Unauthorized { .. })
+    ));
+    assert!(matches!(
+        export_saved_audit_query(
+            &mut persistence,
+            saved.saved_query.saved_query_id,
+            &create_test_bidder()
+        ),
+        Err(ApiError::Unauthorized { .. })
+    ));
+}
@@ -0,0 +1,356 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for freezing and unfreezing an area.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    AdjustBidOrderRequest, BidOrderAdjustment, CreateRoundGroupRequest, FreezeScopeRequest,
+    FreezeScopeResponse, ListScopeFreezesResponse, OverrideAreaAssignmentRequest,
+    OverrideBidOrderRequest, OverrideBidWindowRequest, OverrideEligibilityRequest,
+    OverrideEligibilityResponse, UnfreezeScopeRequest, adjust_bid_order, create_round_group,
+    freeze_scope, list_scope_freezes, override_area_assignment, override_bid_order,
+    override_bid_window, override_eligibility, revert_override, unfreeze_scope,
+};
+use zab_bid::{BootstrapMetadata, Command, State, apply};
+use zab_bid_audit::{Action, AuditEvent, StateSnapshot};
+use zab_bid_domain::{Area, BidYear, User};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+fn freeze_request(area_id: i64) -> FreezeScopeRequest {
+    FreezeScopeRequest {
+        area_id,
+        reason: String::from("Grievance filed over round 1"),
+    }
+}
+
+/// Returns whether core accepts registering a user in 2026/North.
+fn can_register(fixture: &mut PersistedFixture) -> bool {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let state: State = fixture.state("North").unwrap();
+    let user: User = BidYearFixture::new(2026).with_users(1).users("North")[0].clone();
+    apply(
+        &metadata,
+        &state,
+        &BidYear::new(2026),
+        Command::RegisterUser {
+            initials: user.initials,
+            name: user.name,
+            area: user.area,
+            user_type: user.user_type,
+            crew: user.crew,
+            seniority_data: user.seniority_data,
+        },
+        BidYearFixture::actor(fixture.operator_id),
+        BidYearFixture::cause(),
+    )
+    .is_ok()
+}
+
+#[test]
+fn test_freeze_is_persisted_and_enforced() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).persist().unwrap();
+    let bid_year_id: i64 = fixture.bid_year_id;
+    let area_id: i64 = fixture.area_id("North");
+
+    let response: FreezeScopeResponse = freeze_scope(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &freeze_request(area_id),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(response.area_id, area_id);
+
+    let listed: ListScopeFreezesResponse =
+        list_scope_freezes(&mut fixture.persistence, &fixture.metadata, bid_year_id).unwrap();
+    assert_eq!(listed.freezes.len(), 1);
+    assert_eq!(listed.freezes[0].reason, "Grievance filed over round 1");
+    assert_eq!(listed.freezes[0].audit_event_id, response.audit_event_id);
+
+    // The freeze survives a reload and core refuses changes to the area
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    assert!(metadata.is_frozen(&BidYear::new(2026), &Area::new("North")));
+    assert!(!can_register(&mut fixture));
+
+    unfreeze_scope(
+        &mut fixture.persistence,
+        &metadata,
+        &UnfreezeScopeRequest { area_id },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    assert!(!metadata.is_frozen(&BidYear::new(2026), &Area::new("North")));
+    assert!(can_register(&mut fixture));
+    let listed: ListScopeFreezesResponse =
+        list_scope_freezes(&mut fixture.persistence, &metadata, bid_year_id).unwrap();
+    assert!(listed.freezes.is_empty());
+}
+
+#[test]
+fn test_frozen_area_error_names_the_rule() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).persist().unwrap();
+    freeze_area(&mut fixture, "North");
+    let area_id: i64 = fixture.area_id("North");
+
+    let result = freeze_scope(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &freeze_request(area_id),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "scope_frozen"
+    ));
+}
+
+#[test]
+fn test_bidder_cannot_freeze_area() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).persist().unwrap();
+    let area_id: i64 = fixture.area_id("North");
+
+    let result = freeze_scope(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &freeze_request(area_id),
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
+
+#[test]
+fn test_unfreezing_an_unfrozen_area_fails() {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026).persist().unwrap();
+    let area_id: i64 = fixture.area_id("North");
+
+    let result = unfreeze_scope(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &UnfreezeScopeRequest { area_id },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "scope_not_frozen"
+    ));
+}
+
+/// Persists 2026 with one user in each of North and South.
+fn setup_two_areas() -> PersistedFixture {
+    BidYearFixture::new(2026)
+        .with_areas(&["North", "South"])
+        .with_users(1)
+        .persist()
+        .unwrap()
+}
+
+/// Canonicalizes the fixture's bid year so overrides are allowed.
+fn canonicalize(fixture: &mut PersistedFixture) {
+    let event: AuditEvent = AuditEvent::new_global(
+        BidYearFixture::actor(fixture.operator_id),
+        BidYearFixture::cause(),
+        Action::new(String::from("CanonicalizeBidYear"), None),
+        StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+    );
+    fixture
+        .persistence
+        .canonicalize_bid_year(fixture.bid_year_id, &event)
+        .unwrap();
+    fixture
+        .persistence
+        .update_lifecycle_state(fixture.bid_year_id, "Canonicalized")
+        .unwrap();
+}
+
+/// Freezes an area of the fixture and reloads its metadata.
+fn freeze_area(fixture: &mut PersistedFixture, area_code: &str) {
+    let area_id: i64 = fixture.area_id(area_code);
+    freeze_scope(
+        &mut fixture.persistence,
+        &fixture.metadata,
+        &freeze_request(area_id),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    fixture.metadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+}
+
+/// Returns the canonical ID of the only user in an area.
+fn only_user_id(fixture: &mut PersistedFixture, area_code: &str) -> i64 {
+    fixture
+        .state(area_code)
+        .unwrap()
+        .users
+        .values()
+        .next()
+        .and_then(|user| user.user_id)
+        .unwrap()
+}
+
+fn is_scope_frozen<T>(result: &Result<T, ApiError>) -> bool {
+    matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { rule, .. }) if rule == "scope_frozen"
+    )
+}
+
+#[test]
+fn test_overrides_fail_on_frozen_area() {
+    let mut fixture: PersistedFixture = setup_two_areas();
+    canonicalize(&mut fixture);
+    let north_user: i64 = only_user_id(&mut fixture, "North");
+    let south_user: i64 = only_user_id(&mut fixture, "South");
+    let north_area: i64 = fixture.area_id("North");
+    freeze_area(&mut fixture, "North");
+    let reason: String = String::from("Correcting the roster");
+
+    let result = override_eligibility(
+        &mut fixture.persistence,
+        &OverrideEligibilityRequest {
+            user_id: north_user,
+            can_bid: false,
+            reason: reason.clone(),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(is_scope_frozen(&result));
+
+    let result = override_bid_order(
+        &mut fixture.persistence,
+        &OverrideBidOrderRequest {
+            user_id: north_user,
+            bid_order: Some(4),
+            reason: reason.clone(),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(is_scope_frozen(&result));
+
+    let result = override_bid_window(
+        &mut fixture.persistence,
+        &OverrideBidWindowRequest {
+            user_id: north_user,
+            window_start: Some(String::from("2026-02-01")),
+            window_end: Some(String::from("2026-02-05")),
+            reason: reason.clone(),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(is_scope_frozen(&result));
+
+    // Moving a user into a frozen area changes it too
+    let result = override_area_assignment(
+        &mut fixture.persistence,
+        &OverrideAreaAssignmentRequest {
+            user_id: south_user,
+            new_area_id: north_area,
+            reason: reason.clone(),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(is_scope_frozen(&result));
+
+    let result = adjust_bid_order(
+        &mut fixture.persistence,
+        fixture.bid_year_id,
+        north_area,
+        &AdjustBidOrderRequest {
+            adjustments: vec![BidOrderAdjustment {
+                user_id: north_user,
+                new_bid_order: 2,
+            }],
+            reason,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(is_scope_frozen(&result));
+
+    // The unfrozen area is unaffected
+    let result = override_eligibility(
+        &mut fixture.persistence,
+        &OverrideEligibilityRequest {
+            user_id: south_user,
+            can_bid: false,
+            reason: String::from("Correcting the roster"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_override_revert_fails_on_frozen_area() {
+    let mut fixture: PersistedFixture = setup_two_areas();
+    canonicalize(&mut fixture);
+    let north_user: i64 = only_user_id(&mut fixture, "North");
+    let response: OverrideEligibilityResponse = override_eligibility(
+        &mut fixture.persistence,
+        &OverrideEligibilityRequest {
+            user_id: north_user,
+            can_bid: false,
+            reason: String::from("Medical hold this year"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+    freeze_area(&mut fixture, "North");
+
+    let result = revert_override(
+        &mut fixture.persistence,
+        response.override_id,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    );
+
+    assert!(is_scope_frozen(&result));
+}
+
+#[test]
+fn test_round_configuration_fails_while_an_area_is_frozen() {
+    let mut fixture: PersistedFixture = setup_two_areas();
+    freeze_area(&mut fixture, "South");
+
+    let result = create_round_group(
+        &mut fixture.persistence,
+        fixture.bid_year_id,
+        &CreateRoundGroupRequest {
+            name: String::from("Prime time"),
+            editing_enabled: true,
+        },
+        &create_test_admin(),
+    );
+
+    assert!(is_scope_frozen(&result));
+}
@@ -0,0 +1,225 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the conflict-of-interest guard on operators' own bids.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_admin, create_test_admin_operator,
+    create_test_bidder, create_test_bidder_operator, create_test_cause,
+};
+use crate::{
+    ConfirmSelfBidEntryRequest, ConfirmSelfBidEntryResponse, EnterLeaveBidRequest,
+    RequestSelfBidEntryRequest, RequestSelfBidEntryResponse, SelfBidRequestInfo,
+    SetOperatorControllerRequest, confirm_self_bid_entry, enter_leave_bid, list_self_bid_requests,
+    request_self_bid_entry, set_operator_controller,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::AuditEvent;
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates a `BiddingActive` 2026/North with user AA and one round, links
+/// the test bidder operator to AA, and returns it with that operator's ID.
+fn setup_bidding() -> (PersistedFixture, i64) {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(1)
+        .with_rounds(1)
+        .with_slots_per_day(1)
+        .with_lifecycle_state("BiddingActive")
+        .persist()
+        .unwrap();
+    let bidder_operator_id: i64 =
+        create_persisted_bidder_operator(&mut fixture.persistence).unwrap();
+
+    set_operator_controller(
+        &mut fixture.persistence,
+        &SetOperatorControllerRequest {
+            operator_id: bidder_operator_id,
+            initials: Some(String::from("aa")),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    (fixture, bidder_operator_id)
+}
+
+fn request(fixture: &mut PersistedFixture) -> Result<RequestSelfBidEntryResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: RequestSelfBidEntryRequest = RequestSelfBidEntryRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        received_via: String::from("in_person"),
+        leave_dates: vec![String::from("2026-06-02"), String::from("2026-06-01")],
+        hours: 8,
+    };
+    request_self_bid_entry(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+}
+
+fn confirm_as_admin(
+    fixture: &mut PersistedFixture,
+    self_bid_request_id: i64,
+) -> Result<ConfirmSelfBidEntryResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    confirm_self_bid_entry(
+        &mut fixture.persistence,
+        &metadata,
+        &ConfirmSelfBidEntryRequest {
+            self_bid_request_id,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+#[test]
+fn test_operator_cannot_enter_own_bid_directly() {
+    let (mut fixture, _): (PersistedFixture, i64) = setup_bidding();
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: EnterLeaveBidRequest = EnterLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from("AA"),
+        received_via: String::from("phone"),
+        leave_dates: vec![String::from("2026-06-01")],
+        hours: 8,
+        override_reason: None,
+    };
+
+    let result = enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "self_bid"
+    ));
+}
+
+#[test]
+fn test_confirmed_self_bid_enters_leave_and_links_audit_events() {
+    let (mut fixture, bidder_operator_id): (PersistedFixture, i64) = setup_bidding();
+    let area_id: i64 = fixture.area_id("North");
+    let round_id: i64 = fixture.round_ids[0];
+
+    let requested: RequestSelfBidEntryResponse = request(&mut fixture).unwrap();
+    // Nothing is entered until a second operator confirms
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let rows: Vec<SelfBidRequestInfo> =
+        list_self_bid_requests(&mut fixture.persistence, &metadata, area_id, round_id)
+            .unwrap()
+            .requests;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].status, "pending");
+    assert_eq!(rows[0].on_behalf_of, "AA");
+    assert_eq!(rows[0].requested_by_operator_id, bidder_operator_id);
+    assert_eq!(rows[0].leave_dates, vec!["2026-06-01", "2026-06-02"]);
+
+    let confirmed: ConfirmSelfBidEntryResponse =
+        confirm_as_admin(&mut fixture, requested.self_bid_request_id).unwrap();
+    assert_eq!(confirmed.request_event_id, requested.request_event_id);
+    assert_eq!(confirmed.leave_bid_ids.len(), 2);
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let rows: Vec<SelfBidRequestInfo> =
+        list_self_bid_requests(&mut fixture.persistence, &metadata, area_id, round_id)
+            .unwrap()
+            .requests;
+    assert_eq!(rows[0].status, "confirmed");
+    assert_eq!(rows[0].confirmed_by_operator_id, Some(1));
+    assert_eq!(rows[0].confirm_event_id, Some(confirmed.confirm_event_id));
+
+    let timeline: Vec<AuditEvent> = fixture
+        .persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    let confirmation: &AuditEvent = timeline
+        .iter()
+        .find(|event| event.action.name == "ConfirmSelfBidEntry")
+        .unwrap();
+    assert!(
+        confirmation
+            .after
+            .data
+            .contains(&format!("request_event_id={}", requested.request_event_id))
+    );
+    assert!(
+        confirmation
+            .after
+            .data
+            .contains(&format!("entry_event_id={}", confirmed.entry_event_id))
+    );
+
+    // A confirmed request cannot be confirmed again
+    assert!(confirm_as_admin(&mut fixture, requested.self_bid_request_id).is_err());
+}
+
+#[test]
+fn test_requesting_operator_cannot_confirm_own_request() {
+    let (mut fixture, _): (PersistedFixture, i64) = setup_bidding();
+    let requested: RequestSelfBidEntryResponse = request(&mut fixture).unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let result = confirm_self_bid_entry(
+        &mut fixture.persistence,
+        &metadata,
+        &ConfirmSelfBidEntryRequest {
+            self_bid_request_id: requested.self_bid_request_id,
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "self_bid"
+    ));
+}
+
+#[test]
+fn test_second_pending_request_for_round_is_rejected() {
+    let (mut fixture, _): (PersistedFixture, i64) = setup_bidding();
+    request(&mut fixture).unwrap();
+
+    assert!(matches!(
+        request(&mut fixture),
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "self_bid"
+    ));
+}
+
+#[test]
+fn test_only_admin_can_link_operator_to_controller() {
+    let (mut fixture, bidder_operator_id): (PersistedFixture, i64) = setup_bidding();
+
+    let result = set_operator_controller(
+        &mut fixture.persistence,
+        &SetOperatorControllerRequest {
+            operator_id: bidder_operator_id,
+            initials: None,
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
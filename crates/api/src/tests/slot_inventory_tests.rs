@@ -0,0 +1,179 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the leave slot inventory and the bid validation that reads it.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_admin, create_test_admin_operator,
+    create_test_bidder, create_test_bidder_operator, create_test_cause,
+};
+use crate::{
+    AdjustSlotInventoryRequest, AdjustSlotInventoryResponse, EnterLeaveBidRequest,
+    GetSlotInventoryRequest, GetSlotInventoryResponse, SlotInventoryDayInfo, adjust_slot_inventory,
+    enter_leave_bid, get_slot_inventory,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::AuditEvent;
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates a `BiddingActive` 2026/North with users AA and AB and one
+/// round offering two slots per day.
+fn setup_bidding() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(2)
+        .with_rounds(1)
+        .with_lifecycle_state("BiddingActive")
+        .persist()
+        .unwrap();
+    // Bids are entered as the test bidder operator, which audit events reference
+    create_persisted_bidder_operator(&mut fixture.persistence).unwrap();
+    fixture
+}
+
+fn adjust(
+    fixture: &mut PersistedFixture,
+    date: &str,
+    staffing_adjustment: i32,
+) -> Result<AdjustSlotInventoryResponse, ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: AdjustSlotInventoryRequest = AdjustSlotInventoryRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        date: String::from(date),
+        staffing_adjustment,
+    };
+    adjust_slot_inventory(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn enter(fixture: &mut PersistedFixture, initials: &str, date: &str) -> Result<(), ApiError> {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: EnterLeaveBidRequest = EnterLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        on_behalf_of: String::from(initials),
+        received_via: String::from("phone"),
+        leave_dates: vec![String::from(date)],
+        hours: 8,
+        override_reason: None,
+    };
+    enter_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+    .map(|_| ())
+}
+
+fn inventory(
+    fixture: &mut PersistedFixture,
+    start_date: &str,
+    end_date: &str,
+) -> GetSlotInventoryResponse {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: GetSlotInventoryRequest = GetSlotInventoryRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        start_date: String::from(start_date),
+        end_date: String::from(end_date),
+    };
+    get_slot_inventory(&mut fixture.persistence, &metadata, &request).unwrap()
+}
+
+#[test]
+fn test_inventory_combines_round_slots_adjustments_and_leave() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    enter(&mut fixture, "AA", "2026-06-01").unwrap();
+    let response: AdjustSlotInventoryResponse = adjust(&mut fixture, "2026-06-02", 1).unwrap();
+    assert_eq!(response.day.capacity, 3);
+    assert_eq!(response.day.remaining, 3);
+
+    let days: Vec<SlotInventoryDayInfo> = inventory(&mut fixture, "2026-06-01", "2026-06-03").days;
+    assert_eq!(
+        days.iter()
+            .map(|day| (
+                day.date.as_str(),
+                day.staffing_adjustment,
+                day.capacity,
+                day.used,
+                day.remaining
+            ))
+            .collect::<Vec<(&str, i32, u32, u32, u32)>>(),
+        vec![
+            ("2026-06-01", 0, 2, 1, 1),
+            ("2026-06-02", 1, 3, 0, 3),
+            ("2026-06-03", 0, 2, 0, 2),
+        ]
+    );
+
+    let timeline: Vec<AuditEvent> = fixture
+        .persistence
+        .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    let event: &AuditEvent = timeline
+        .iter()
+        .find(|event| event.action.name == "AdjustSlotInventory")
+        .unwrap();
+    assert!(event.before.data.ends_with("staffing_adjustment=0"));
+    assert!(event.after.data.ends_with("staffing_adjustment=1"));
+}
+
+#[test]
+fn test_leave_bid_rejected_when_inventory_exhausted() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    adjust(&mut fixture, "2026-06-01", -1).unwrap();
+
+    enter(&mut fixture, "AA", "2026-06-01").unwrap();
+    let result: Result<(), ApiError> = enter(&mut fixture, "AB", "2026-06-01");
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "leave_bid"
+    ));
+
+    // Restoring the staffing frees the slot again
+    adjust(&mut fixture, "2026-06-01", 0).unwrap();
+    enter(&mut fixture, "AB", "2026-06-01").unwrap();
+    assert_eq!(
+        inventory(&mut fixture, "2026-06-01", "2026-06-01").days[0].remaining,
+        0
+    );
+}
+
+#[test]
+fn test_only_admin_may_adjust_inventory() {
+    let mut fixture: PersistedFixture = setup_bidding();
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: AdjustSlotInventoryRequest = AdjustSlotInventoryRequest {
+        area_id: fixture.area_id("North"),
+        round_id: fixture.round_ids[0],
+        date: String::from("2026-06-01"),
+        staffing_adjustment: 1,
+    };
+
+    let result: Result<AdjustSlotInventoryResponse, ApiError> = adjust_slot_inventory(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+    assert_eq!(
+        inventory(&mut fixture, "2026-06-01", "2026-06-01").days[0].capacity,
+        2
+    );
+}
@@ -0,0 +1,262 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for merging duplicate user records.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_bidder_operator,
+    create_test_cause,
+};
+use crate::{
+    ListUserEligibilityResponse, MergeUsersRequest, MergeUsersResponse, OverrideEligibilityRequest,
+    RevertUserMergeResponse, UserEligibilityInfo, list_user_eligibility, list_user_merges,
+    merge_users, override_eligibility, revert_user_merge,
+};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, StateSnapshot};
+use zab_bid_domain::{Area, BidYear, User};
+use zab_bid_persistence::{
+    AuditTimelineScope, CheckpointData, LeaveCarryoverData, SqlitePersistence,
+};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with user AA and its duplicate AB, and
+/// canonicalizes it.
+fn setup_duplicates() -> (SqlitePersistence, i64, i64, i64) {
+    let PersistedFixture {
+        mut persistence,
+        operator_id,
+        bid_year_id,
+        ..
+    } = BidYearFixture::new(2026).with_users(2).persist().unwrap();
+
+    let event: AuditEvent = AuditEvent::new_global(
+        BidYearFixture::actor(operator_id),
+        BidYearFixture::cause(),
+        Action::new(String::from("CanonicalizeBidYear"), None),
+        StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+    );
+    persistence
+        .canonicalize_bid_year(bid_year_id, &event)
+        .unwrap();
+    persistence
+        .update_lifecycle_state(bid_year_id, "Canonicalized")
+        .unwrap();
+
+    let keep: i64 = eligibility_of(&mut persistence, bid_year_id, "AA").user_id;
+    let merge: i64 = eligibility_of(&mut persistence, bid_year_id, "AB").user_id;
+    (persistence, bid_year_id, keep, merge)
+}
+
+fn eligibility_of(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    initials: &str,
+) -> UserEligibilityInfo {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let response: ListUserEligibilityResponse =
+        list_user_eligibility(persistence, &metadata, bid_year_id).unwrap();
+    response
+        .users
+        .into_iter()
+        .find(|user| user.initials == initials)
+        .unwrap()
+}
+
+fn carryover_of(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    user_id: i64,
+) -> Option<i32> {
+    persistence
+        .list_leave_carryovers(bid_year_id)
+        .unwrap()
+        .iter()
+        .find(|carryover: &&LeaveCarryoverData| carryover.user_id == user_id)
+        .map(|carryover| carryover.carryover_hours)
+}
+
+fn is_excluded(persistence: &mut SqlitePersistence, initials: &str) -> bool {
+    let user: User = persistence
+        .list_users(&BidYear::new(2026), &Area::new("North"))
+        .unwrap()
+        .into_iter()
+        .find(|user| user.initials.value() == initials)
+        .unwrap();
+    user.excluded_from_bidding && user.excluded_from_leave_calculation
+}
+
+fn override_can_bid(persistence: &mut SqlitePersistence, user_id: i64, can_bid: bool) {
+    override_eligibility(
+        persistence,
+        &OverrideEligibilityRequest {
+            user_id,
+            can_bid,
+            reason: String::from("Medical clearance pending"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+    )
+    .unwrap();
+}
+
+fn merge(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    keep: i64,
+    merge: i64,
+) -> Result<MergeUsersResponse, ApiError> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    merge_users(
+        persistence,
+        &metadata,
+        &MergeUsersRequest {
+            bid_year_id,
+            keep_user_id: keep,
+            merge_user_id: merge,
+            reason: String::from("Registered twice under two sets of initials"),
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+fn revert(
+    persistence: &mut SqlitePersistence,
+    user_merge_id: i64,
+) -> Result<RevertUserMergeResponse, ApiError> {
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    revert_user_merge(
+        persistence,
+        &metadata,
+        user_merge_id,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+}
+
+#[test]
+fn test_merge_moves_balance_and_override_and_revert_restores_them() {
+    let (mut persistence, bid_year_id, keep, duplicate) = setup_duplicates();
+    persistence
+        .set_leave_carryover(bid_year_id, keep, 8)
+        .unwrap();
+    persistence
+        .set_leave_carryover(bid_year_id, duplicate, 40)
+        .unwrap();
+    override_can_bid(&mut persistence, duplicate, false);
+
+    let response: MergeUsersResponse =
+        merge(&mut persistence, bid_year_id, keep, duplicate).unwrap();
+
+    assert_eq!(response.rows_moved, 2);
+    assert_eq!(carryover_of(&mut persistence, bid_year_id, keep), Some(48));
+    assert_eq!(carryover_of(&mut persistence, bid_year_id, duplicate), None);
+    let kept: UserEligibilityInfo = eligibility_of(&mut persistence, bid_year_id, "AA");
+    assert!(kept.is_overridden);
+    assert!(!kept.can_bid);
+    assert!(is_excluded(&mut persistence, "AB"));
+    assert!(!is_excluded(&mut persistence, "AA"));
+
+    revert(&mut persistence, response.user_merge_id).unwrap();
+
+    assert_eq!(carryover_of(&mut persistence, bid_year_id, keep), Some(8));
+    assert_eq!(
+        carryover_of(&mut persistence, bid_year_id, duplicate),
+        Some(40)
+    );
+    let kept: UserEligibilityInfo = eligibility_of(&mut persistence, bid_year_id, "AA");
+    assert!(!kept.is_overridden);
+    assert!(kept.can_bid);
+    assert!(eligibility_of(&mut persistence, bid_year_id, "AB").is_overridden);
+    assert!(!is_excluded(&mut persistence, "AB"));
+
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+    let merges = list_user_merges(&mut persistence, &metadata, bid_year_id)
+        .unwrap()
+        .merges;
+    assert_eq!(merges.len(), 1);
+    assert!(merges[0].revert_event_id.is_some());
+}
+
+#[test]
+fn test_merge_records_automatic_checkpoint_first() {
+    let (mut persistence, bid_year_id, keep, duplicate) = setup_duplicates();
+
+    let response: MergeUsersResponse =
+        merge(&mut persistence, bid_year_id, keep, duplicate).unwrap();
+
+    let checkpoints: Vec<CheckpointData> = persistence
+        .list_checkpoints(AuditTimelineScope::BidYear { bid_year_id })
+        .unwrap();
+    assert_eq!(checkpoints.len(), 1);
+    assert_eq!(
+        checkpoints[0].label.as_deref(),
+        Some("auto-before-user-merge")
+    );
+    assert!(checkpoints[0].event_id < response.audit_event_id);
+    assert_eq!(
+        checkpoints[0].correlation_id.as_deref(),
+        Some(response.correlation_id.as_str())
+    );
+    assert_eq!(
+        persistence
+            .get_event_correlation_id(response.audit_event_id)
+            .unwrap()
+            .as_deref(),
+        Some(response.correlation_id.as_str())
+    );
+}
+
+#[test]
+fn test_colliding_overrides_block_the_merge() {
+    let (mut persistence, bid_year_id, keep, duplicate) = setup_duplicates();
+    override_can_bid(&mut persistence, keep, true);
+    override_can_bid(&mut persistence, duplicate, false);
+
+    let result: Result<MergeUsersResponse, ApiError> =
+        merge(&mut persistence, bid_year_id, keep, duplicate);
+
+    assert!(matches!(result, Err(ApiError::DomainRuleViolation { .. })));
+    assert!(!is_excluded(&mut persistence, "AB"));
+}
+
+#[test]
+fn test_merged_record_cannot_be_merged_again_or_reverted_twice() {
+    let (mut persistence, bid_year_id, keep, duplicate) = setup_duplicates();
+    let response: MergeUsersResponse =
+        merge(&mut persistence, bid_year_id, keep, duplicate).unwrap();
+
+    assert!(merge(&mut persistence, bid_year_id, keep, duplicate).is_err());
+
+    revert(&mut persistence, response.user_merge_id).unwrap();
+    assert!(revert(&mut persistence, response.user_merge_id).is_err());
+}
+
+#[test]
+fn test_bidder_cannot_merge_users() {
+    let (mut persistence, bid_year_id, keep, duplicate) = setup_duplicates();
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+
+    let result: Result<MergeUsersResponse, ApiError> = merge_users(
+        &mut persistence,
+        &metadata,
+        &MergeUsersRequest {
+            bid_year_id,
+            keep_user_id: keep,
+            merge_user_id: duplicate,
+            reason: String::from("Registered twice under two sets of initials"),
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+}
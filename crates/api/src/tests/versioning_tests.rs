@@ -0,0 +1,108 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for API versioning and v1 conversion shims.
+
+use crate::error::ApiError;
+use crate::{ApiVersion, FromCurrent, IntoCurrent, v1};
+use time::{Date, Month};
+
+#[test]
+fn test_current_version_is_supported() {
+    assert!(ApiVersion::SUPPORTED.contains(&ApiVersion::CURRENT));
+    assert_eq!(v1::VERSION, ApiVersion::V1);
+}
+
+#[test]
+fn test_v1_serves_the_current_dtos() {
+    // v1 has no copies yet, so each of its DTOs is the current shape
+    let request: v1::ReopenBidYearRequest = crate::ReopenBidYearRequest {
+        bid_year_id: 1,
+        justification: String::from("Roster correction"),
+    };
+    assert_eq!(request.bid_year_id, 1);
+}
+
+#[test]
+fn test_parse_version_round_trips() {
+    for version in ApiVersion::SUPPORTED {
+        assert_eq!(ApiVersion::parse(version.as_str()).unwrap(), *version);
+        assert_eq!(version.to_string(), version.as_str());
+    }
+}
+
+#[test]
+fn test_parse_unknown_version_is_rejected() {
+    let result: Result<ApiVersion, ApiError> = ApiVersion::parse("v0");
+    assert!(matches!(result, Err(ApiError::InvalidInput { field, .. }) if field == "api_version"));
+}
+
+#[test]
+fn test_v1_date_shim_round_trips() {
+    let date: Date = Date::from_calendar_date(2026, Month::January, 4).unwrap();
+    let wire: String = v1::shims::format_date(date);
+    assert_eq!(wire, "2026-01-04");
+    assert_eq!(v1::shims::parse_date("start_date", &wire).unwrap(), date);
+}
+
+#[test]
+fn test_v1_date_shim_reports_field_on_error() {
+    let result: Result<Date, ApiError> = v1::shims::parse_date("start_date", "01/04/2026");
+    assert!(matches!(result, Err(ApiError::InvalidInput { field, .. }) if field == "start_date"));
+}
+
+#[test]
+fn test_v1_optional_date_shim_passes_through_none() {
+    assert_eq!(
+        v1::shims::parse_optional_date("window_start", None).unwrap(),
+        None
+    );
+    assert!(v1::shims::parse_optional_date("window_start", Some("bad")).is_err());
+}
+
+/// A legacy request that sends its date as a string.
+struct LegacyWindowRequest {
+    start: String,
+}
+
+/// The structured shape a future version would use.
+#[derive(Debug, PartialEq, Eq)]
+struct StructuredWindowRequest {
+    start: Date,
+}
+
+impl IntoCurrent for LegacyWindowRequest {
+    type Current = StructuredWindowRequest;
+
+    fn into_current(self) -> Result<Self::Current, ApiError> {
+        Ok(StructuredWindowRequest {
+            start: v1::shims::parse_date("start", &self.start)?,
+        })
+    }
+}
+
+impl FromCurrent for LegacyWindowRequest {
+    type Current = StructuredWindowRequest;
+
+    fn from_current(current: Self::Current) -> Self {
+        Self {
+            start: v1::shims::format_date(current.start),
+        }
+    }
+}
+
+#[test]
+fn test_conversion_traits_round_trip_through_shims() {
+    let legacy: LegacyWindowRequest = LegacyWindowRequest {
+        start: String::from("2026-03-15"),
+    };
+    let current: StructuredWindowRequest = legacy.into_current().unwrap();
+    assert_eq!(
+        current.start,
+        Date::from_calendar_date(2026, Month::March, 15).unwrap()
+    );
+    let back: LegacyWindowRequest = LegacyWindowRequest::from_current(current);
+    assert_eq!(back.start, "2026-03-15");
+}
@@ -0,0 +1,369 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for withdrawing leave and offering freed slots from the waitlist.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_persisted_bidder_operator, create_test_admin, create_test_admin_operator,
+    create_test_bidder, create_test_bidder_operator, create_test_cause,
+};
+use crate::{
+    AcceptWaitlistOfferRequest, DeclineWaitlistOfferRequest, ListWaitlistResponse,
+    WaitlistOfferResponse, WithdrawLeaveBidRequest, WithdrawLeaveBidResponse,
+    accept_waitlist_offer, decline_waitlist_offer, expire_waitlist_offers, list_waitlist,
+    withdraw_leave_bid,
+};
+use time::{Duration, OffsetDateTime};
+use zab_bid::BootstrapMetadata;
+use zab_bid_audit::{Action, AuditEvent, StateSnapshot};
+use zab_bid_persistence::{LeaveBidData, OperatorData};
+use zab_bid_test_support::{BidYearFixture, PersistedFixture};
+
+/// Creates 2026/North with users AA, AB, and AC in that bid order and one
+/// round with one slot per day. AA holds leave on 2026-07-01. The bid year
+/// is `BiddingActive`.
+fn setup() -> PersistedFixture {
+    let mut fixture: PersistedFixture = BidYearFixture::new(2026)
+        .with_users(3)
+        .with_rounds(1)
+        .persist()
+        .unwrap();
+    // Bids are entered as the test bidder operator, which audit events reference
+    create_persisted_bidder_operator(&mut fixture.persistence).unwrap();
+    let bid_year_id: i64 = fixture.bid_year_id;
+    let area_id: i64 = fixture.area_id("North");
+    let round_id: i64 = fixture.round_ids[0];
+    fixture
+        .persistence
+        .update_round(round_id, "Round 1", 1, 3, 200, false, false)
+        .unwrap();
+
+    let event: AuditEvent = AuditEvent::new_global(
+        BidYearFixture::actor(fixture.operator_id),
+        BidYearFixture::cause(),
+        Action::new(String::from("CanonicalizeBidYear"), None),
+        StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+    );
+    fixture
+        .persistence
+        .canonicalize_bid_year(bid_year_id, &event)
+        .unwrap();
+    let users: Vec<i64> = ["AA", "AB", "AC"]
+        .into_iter()
+        .map(|initials| fixture.user_id(initials))
+        .collect();
+    for (order, user_id) in (1..).zip(&users) {
+        fixture
+            .persistence
+            .override_bid_order(bid_year_id, *user_id, Some(order), "Test order")
+            .unwrap();
+    }
+
+    fixture
+        .persistence
+        .insert_leave_bid(
+            bid_year_id,
+            area_id,
+            users[0],
+            round_id,
+            "2026-07-01",
+            8,
+            "AA",
+            "phone",
+        )
+        .unwrap();
+    fixture
+        .persistence
+        .update_lifecycle_state(bid_year_id, "BiddingActive")
+        .unwrap();
+
+    fixture
+}
+
+/// Returns the ID of AA's leave on 2026-07-01.
+fn held_leave(fixture: &mut PersistedFixture) -> i64 {
+    let area_id: i64 = fixture.area_id("North");
+    fixture
+        .persistence
+        .list_leave_bids(fixture.bid_year_id, area_id)
+        .unwrap()[0]
+        .leave_bid_id
+}
+
+fn withdraw(fixture: &mut PersistedFixture, offer_hours: Option<u32>) -> WithdrawLeaveBidResponse {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: WithdrawLeaveBidRequest = WithdrawLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        leave_bid_id: held_leave(fixture),
+        offer_hours,
+    };
+    withdraw_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        &create_test_cause(),
+    )
+    .unwrap()
+}
+
+fn waitlist(fixture: &mut PersistedFixture) -> ListWaitlistResponse {
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let area_id: i64 = fixture.area_id("North");
+    let round_id: i64 = fixture.round_ids[0];
+    list_waitlist(&mut fixture.persistence, &metadata, area_id, round_id).unwrap()
+}
+
+/// Returns the ID of the slot's pending offer.
+fn pending_offer(fixture: &mut PersistedFixture) -> i64 {
+    waitlist(fixture).slots[0]
+        .offers
+        .iter()
+        .find(|offer| offer.status == "pending")
+        .map(|offer| offer.waitlist_offer_id)
+        .unwrap()
+}
+
+fn close_bidding(fixture: &mut PersistedFixture) {
+    fixture
+        .persistence
+        .update_lifecycle_state(fixture.bid_year_id, "BiddingClosed")
+        .unwrap();
+}
+
+#[test]
+fn test_withdraw_before_close_frees_slot_without_waitlist() {
+    let mut fixture: PersistedFixture = setup();
+
+    let response: WithdrawLeaveBidResponse = withdraw(&mut fixture, None);
+    assert_eq!(response.waitlist_slot_id, None);
+    assert_eq!(response.offered_to_user_id, None);
+    assert!(waitlist(&mut fixture).slots.is_empty());
+
+    let bids: Vec<LeaveBidData> = fixture
+        .persistence
+        .list_leave_bids(fixture.bid_year_id, fixture.area_id("North"))
+        .unwrap();
+    assert_eq!(bids[0].status, LeaveBidData::STATUS_WITHDRAWN);
+
+    // A withdrawn bid cannot be withdrawn again
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: WithdrawLeaveBidRequest = WithdrawLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        leave_bid_id: held_leave(&mut fixture),
+        offer_hours: None,
+    };
+    let result: Result<WithdrawLeaveBidResponse, ApiError> = withdraw_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        &create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::InvalidInput { .. })));
+}
+
+#[test]
+fn test_freed_slot_offered_down_bid_order() {
+    let mut fixture: PersistedFixture = setup();
+    close_bidding(&mut fixture);
+
+    let response: WithdrawLeaveBidResponse = withdraw(&mut fixture, None);
+    assert!(response.waitlist_slot_id.is_some());
+    assert_eq!(response.offered_to_user_id, Some(fixture.user_id("AB")));
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let first_offer: i64 = pending_offer(&mut fixture);
+    let declined: WaitlistOfferResponse = decline_waitlist_offer(
+        &mut fixture.persistence,
+        &metadata,
+        &DeclineWaitlistOfferRequest {
+            waitlist_offer_id: first_offer,
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        &create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(declined.offered_to_user_id, Some(fixture.user_id("AC")));
+
+    // A declined offer can no longer be accepted
+    let result: Result<WaitlistOfferResponse, ApiError> = accept_waitlist_offer(
+        &mut fixture.persistence,
+        &metadata,
+        &AcceptWaitlistOfferRequest {
+            waitlist_offer_id: first_offer,
+            received_via: String::from("phone"),
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "waitlist_offer"
+    ));
+
+    let second_offer: i64 = pending_offer(&mut fixture);
+    let accepted: WaitlistOfferResponse = accept_waitlist_offer(
+        &mut fixture.persistence,
+        &metadata,
+        &AcceptWaitlistOfferRequest {
+            waitlist_offer_id: second_offer,
+            received_via: String::from("phone"),
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let granted: LeaveBidData = fixture
+        .persistence
+        .list_leave_bids(fixture.bid_year_id, fixture.area_id("North"))
+        .unwrap()
+        .into_iter()
+        .find(|bid| Some(bid.leave_bid_id) == accepted.leave_bid_id)
+        .unwrap();
+    assert_eq!(granted.user_id, fixture.user_id("AC"));
+    assert_eq!(granted.leave_date, "2026-07-01");
+    assert_eq!(granted.on_behalf_of.as_deref(), Some("AC"));
+
+    let listed: ListWaitlistResponse = waitlist(&mut fixture);
+    assert_eq!(listed.slots[0].status, "filled");
+    assert_eq!(
+        listed.slots[0]
+            .offers
+            .iter()
+            .map(|offer| (offer.user_id, offer.status.as_str()))
+            .collect::<Vec<(i64, &str)>>(),
+        vec![
+            (fixture.user_id("AB"), "declined"),
+            (fixture.user_id("AC"), "accepted")
+        ]
+    );
+}
+
+#[test]
+fn test_waitlists_disabled_returns_slot_to_inventory() {
+    let mut fixture: PersistedFixture = setup();
+    fixture
+        .persistence
+        .set_feature_flag(fixture.bid_year_id, "waitlists", false)
+        .unwrap();
+    close_bidding(&mut fixture);
+
+    let response: WithdrawLeaveBidResponse = withdraw(&mut fixture, None);
+    assert_eq!(response.waitlist_slot_id, None);
+    assert_eq!(response.offered_to_user_id, None);
+    assert!(waitlist(&mut fixture).slots.is_empty());
+}
+
+#[test]
+fn test_expired_offers_pass_down_the_list() {
+    let mut fixture: PersistedFixture = setup();
+    close_bidding(&mut fixture);
+    withdraw(&mut fixture, Some(2));
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let operator: OperatorData = create_test_admin_operator();
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    assert_eq!(
+        expire_waitlist_offers(&mut fixture.persistence, &metadata, &operator, now).unwrap(),
+        0
+    );
+
+    let later: OffsetDateTime = now + Duration::hours(3);
+    assert_eq!(
+        expire_waitlist_offers(&mut fixture.persistence, &metadata, &operator, later).unwrap(),
+        1
+    );
+    let listed: ListWaitlistResponse = waitlist(&mut fixture);
+    assert_eq!(listed.slots[0].offers[0].status, "expired");
+    assert_eq!(listed.slots[0].offers[1].user_id, fixture.user_id("AC"));
+
+    // Nobody is left once AC's offer lapses; AA gave the slot up
+    assert_eq!(
+        expire_waitlist_offers(
+            &mut fixture.persistence,
+            &metadata,
+            &operator,
+            later + Duration::hours(3),
+        )
+        .unwrap(),
+        1
+    );
+    assert_eq!(waitlist(&mut fixture).slots[0].status, "unclaimed");
+}
+
+#[test]
+fn test_operator_cannot_withdraw_own_leave() {
+    let mut fixture: PersistedFixture = setup();
+    fixture
+        .persistence
+        .set_operator_controller(create_test_bidder_operator().operator_id, Some("AA"))
+        .unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let request: WithdrawLeaveBidRequest = WithdrawLeaveBidRequest {
+        area_id: fixture.area_id("North"),
+        leave_bid_id: held_leave(&mut fixture),
+        offer_hours: None,
+    };
+    let result: Result<WithdrawLeaveBidResponse, ApiError> = withdraw_leave_bid(
+        &mut fixture.persistence,
+        &metadata,
+        &request,
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        &create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "self_bid"
+    ));
+
+    let bids: Vec<LeaveBidData> = fixture
+        .persistence
+        .list_leave_bids(fixture.bid_year_id, fixture.area_id("North"))
+        .unwrap();
+    assert_eq!(bids[0].status, LeaveBidData::STATUS_APPROVED);
+}
+
+#[test]
+fn test_operator_cannot_accept_own_waitlist_offer() {
+    let mut fixture: PersistedFixture = setup();
+    close_bidding(&mut fixture);
+    withdraw(&mut fixture, None);
+    fixture
+        .persistence
+        .set_operator_controller(create_test_bidder_operator().operator_id, Some("AB"))
+        .unwrap();
+
+    let metadata: BootstrapMetadata = fixture.persistence.get_bootstrap_metadata().unwrap();
+    let offer: i64 = pending_offer(&mut fixture);
+    let result: Result<WaitlistOfferResponse, ApiError> = accept_waitlist_offer(
+        &mut fixture.persistence,
+        &metadata,
+        &AcceptWaitlistOfferRequest {
+            waitlist_offer_id: offer,
+            received_via: String::from("phone"),
+        },
+        &create_test_bidder(),
+        &create_test_bidder_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(ApiError::DomainRuleViolation { ref rule, .. }) if rule == "self_bid"
+    ));
+
+    // The offer waits for another operator
+    assert_eq!(pending_offer(&mut fixture), offer);
+}
@@ -0,0 +1,267 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for webhook management handlers.
+
+use crate::error::ApiError;
+use crate::tests::helpers::{
+    create_test_admin, create_test_admin_operator, create_test_bidder, create_test_cause,
+};
+use crate::{
+    CreateWebhookRequest, CreateWebhookResponse, DeleteWebhookRequest, ListWebhooksResponse,
+    UpdateWebhookRequest, UpdateWebhookResponse, create_webhook, delete_webhook,
+    list_webhook_dead_letters, list_webhooks, update_webhook,
+};
+use zab_bid_persistence::SqlitePersistence;
+
+/// Creates persistence with the admin operator (ID 1) used for audit attribution.
+fn setup() -> SqlitePersistence {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    persistence
+        .create_operator("ADMIN-123", "Test Admin", "password", "Admin")
+        .unwrap();
+    persistence
+}
+
+fn create_request(url: &str, event_filter: &[&str]) -> CreateWebhookRequest {
+    CreateWebhookRequest {
+        url: url.to_string(),
+        secret: None,
+        event_filter: event_filter.iter().map(|e| (*e).to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_create_webhook_generates_secret_and_audits() {
+    let mut persistence: SqlitePersistence = setup();
+
+    let response: CreateWebhookResponse = create_webhook(
+        &mut persistence,
+        create_request("https://tools.example.test/bids", &["Finalize"]),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(response.secret.len(), 64);
+    assert!(response.webhook.is_enabled);
+    assert_eq!(
+        response.webhook.event_filter,
+        vec![String::from("Finalize")]
+    );
+
+    let page = persistence
+        .get_audit_timeline_page(
+            zab_bid_persistence::AuditTimelineScope::Global,
+            &zab_bid_persistence::AuditTimelineFilter::default(),
+            None,
+            10,
+        )
+        .unwrap();
+    let event = &page.entries.last().unwrap().event;
+    assert_eq!(event.action.name, "CreateWebhook");
+    assert!(!event.after.data.contains(&response.secret));
+}
+
+#[test]
+fn test_create_webhook_keeps_supplied_secret() {
+    let mut persistence: SqlitePersistence = setup();
+    let mut request: CreateWebhookRequest = create_request("https://example.test/h", &["*"]);
+    request.secret = Some(String::from("a-long-enough-secret"));
+
+    let response: CreateWebhookResponse = create_webhook(
+        &mut persistence,
+        request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(response.secret, "a-long-enough-secret");
+}
+
+#[test]
+fn test_create_webhook_rejects_short_secret() {
+    let mut persistence: SqlitePersistence = setup();
+    let mut request: CreateWebhookRequest = create_request("https://example.test/h", &["*"]);
+    request.secret = Some(String::from("short"));
+
+    let result: Result<CreateWebhookResponse, ApiError> = create_webhook(
+        &mut persistence,
+        request,
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::InvalidInput { field, .. }) if field == "secret"));
+}
+
+#[test]
+fn test_create_webhook_rejects_invalid_url() {
+    let mut persistence: SqlitePersistence = setup();
+
+    for url in [
+        "ftp://example.test/h",
+        "https://",
+        "example.test/h",
+        "https://a b",
+    ] {
+        let result: Result<CreateWebhookResponse, ApiError> = create_webhook(
+            &mut persistence,
+            create_request(url, &["*"]),
+            &create_test_admin(),
+            &create_test_admin_operator(),
+            create_test_cause(),
+        );
+        assert!(
+            matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "url"),
+            "{url} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_create_webhook_rejects_bad_event_filter() {
+    let mut persistence: SqlitePersistence = setup();
+
+    for filter in [&[][..], &["Register User"][..], &[""][..]] {
+        let result: Result<CreateWebhookResponse, ApiError> = create_webhook(
+            &mut persistence,
+            create_request("https://example.test/h", filter),
+            &create_test_admin(),
+            &create_test_admin_operator(),
+            create_test_cause(),
+        );
+        assert!(
+            matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "event_filter")
+        );
+    }
+}
+
+#[test]
+fn test_webhook_management_requires_admin() {
+    let mut persistence: SqlitePersistence = setup();
+
+    let result: Result<CreateWebhookResponse, ApiError> = create_webhook(
+        &mut persistence,
+        create_request("https://example.test/h", &["*"]),
+        &create_test_bidder(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+    assert!(matches!(result, Err(ApiError::Unauthorized { .. })));
+
+    let list_result: Result<ListWebhooksResponse, ApiError> =
+        list_webhooks(&mut persistence, &create_test_bidder());
+    assert!(matches!(list_result, Err(ApiError::Unauthorized { .. })));
+
+    let dead_letter_result =
+        list_webhook_dead_letters(&mut persistence, None, None, &create_test_bidder());
+    assert!(matches!(
+        dead_letter_result,
+        Err(ApiError::Unauthorized { .. })
+    ));
+}
+
+#[test]
+fn test_update_and_list_webhooks() {
+    let mut persistence: SqlitePersistence = setup();
+    let created: CreateWebhookResponse = create_webhook(
+        &mut persistence,
+        create_request("https://example.test/a", &["Finalize"]),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let updated: UpdateWebhookResponse = update_webhook(
+        &mut persistence,
+        &UpdateWebhookRequest {
+            webhook_id: created.webhook.webhook_id,
+            url: String::from("https://example.test/b"),
+            event_filter: vec![String::from("RegisterUser"), String::from("Finalize")],
+            is_enabled: false,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(updated.webhook.url, "https://example.test/b");
+    assert!(!updated.webhook.is_enabled);
+
+    let listed: ListWebhooksResponse =
+        list_webhooks(&mut persistence, &create_test_admin()).unwrap();
+    assert_eq!(listed.webhooks, vec![updated.webhook]);
+}
+
+#[test]
+fn test_update_missing_webhook_is_not_found() {
+    let mut persistence: SqlitePersistence = setup();
+
+    let result: Result<UpdateWebhookResponse, ApiError> = update_webhook(
+        &mut persistence,
+        &UpdateWebhookRequest {
+            webhook_id: 42,
+            url: String::from("https://example.test/b"),
+            event_filter: vec![String::from("*")],
+            is_enabled: true,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(result, Err(ApiError::ResourceNotFound { .. })));
+}
+
+#[test]
+fn test_delete_webhook_removes_it() {
+    let mut persistence: SqlitePersistence = setup();
+    let created: CreateWebhookResponse = create_webhook(
+        &mut persistence,
+        create_request("https://example.test/a", &["*"]),
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    delete_webhook(
+        &mut persistence,
+        DeleteWebhookRequest {
+            webhook_id: created.webhook.webhook_id,
+        },
+        &create_test_admin(),
+        &create_test_admin_operator(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let listed: ListWebhooksResponse =
+        list_webhooks(&mut persistence, &create_test_admin()).unwrap();
+    assert!(listed.webhooks.is_empty());
+}
+
+#[test]
+fn test_list_dead_letters_validates_limit() {
+    let mut persistence: SqlitePersistence = setup();
+
+    for limit in [0, 1001] {
+        let result =
+            list_webhook_dead_letters(&mut persistence, None, Some(limit), &create_test_admin());
+        assert!(
+            matches!(result, Err(ApiError::InvalidInput { ref field, .. }) if field == "limit")
+        );
+    }
+
+    let empty =
+        list_webhook_dead_letters(&mut persistence, None, None, &create_test_admin()).unwrap();
+    assert!(empty.dead_letters.is_empty());
+}
@@ -0,0 +1,288 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Initials change handlers.
+//!
+//! Controllers occasionally change operating initials mid-year. The user
+//! keeps their canonical identity and the initials they held before are
+//! kept as aliases, so history recorded under any of them resolves to the
+//! same user.
+
+use time::Date;
+use time::format_description::well_known::Iso8601;
+use zab_bid::{BootstrapMetadata, Command, State, TransitionResult, apply};
+use zab_bid_audit::{Actor, Cause};
+use zab_bid_domain::{Area, BidYear, CanonicalBidYear, DomainError, Initials};
+use zab_bid_persistence::{InitialsAliasData, OperatorData, SqlitePersistence};
+
+use crate::auth::AuthenticatedActor;
+use crate::bid_rules::resolve_bid_year;
+use crate::error::{ApiError, translate_core_error, translate_domain_error};
+use crate::request_response::{
+    ChangeInitialsRequest, ChangeInitialsResponse, GetInitialsHistoryResponse, InitialsAliasInfo,
+};
+use crate::user_merges::user_area;
+use crate::webhooks::require_admin;
+
+/// Resolves current or retired initials to the user of a bid year who
+/// holds or held them.
+fn resolve_user(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    initials: &Initials,
+) -> Result<Option<i64>, ApiError> {
+    persistence
+        .resolve_user_initials(bid_year_id, initials.value())
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to resolve initials: {e}"),
+        })
+}
+
+/// Lists every initials a user has held in their bid year, current first.
+fn initials_held(
+    persistence: &mut SqlitePersistence,
+    user_id: i64,
+) -> Result<(String, Vec<InitialsAliasData>), ApiError> {
+    let (_bid_year_id, current): (i64, String) =
+        persistence
+            .get_user_details(user_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to fetch user: {e}"),
+            })?;
+    let aliases: Vec<InitialsAliasData> =
+        persistence
+            .list_initials_aliases(user_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list initials aliases: {e}"),
+            })?;
+    Ok((current, aliases))
+}
+
+/// Resolves initials to the user who holds or held them in a bid year, for
+/// filtering audit history by user.
+///
+/// # Errors
+///
+/// Returns an error if nobody in the bid year holds or held the initials,
+/// or the query fails.
+pub fn resolve_initials_user(
+    persistence: &mut SqlitePersistence,
+    bid_year_id: i64,
+    initials: &str,
+) -> Result<i64, ApiError> {
+    let initials: Initials = Initials::new(initials.trim());
+    resolve_user(persistence, bid_year_id, &initials)?.ok_or_else(|| ApiError::ResourceNotFound {
+        resource_type: String::from("User"),
+        message: format!(
+            "No user in bid year {bid_year_id} holds or held initials '{}'",
+            initials.value()
+        ),
+    })
+}
+
+/// Lists every initials a user has held in their bid year, current first,
+/// without repeats.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn user_initials_history(
+    persistence: &mut SqlitePersistence,
+    user_id: i64,
+) -> Result<Vec<String>, ApiError> {
+    let (current, aliases): (String, Vec<InitialsAliasData>) = initials_held(persistence, user_id)?;
+
+    let mut held: Vec<String> = vec![current];
+    for alias in aliases {
+        if !held.contains(&alias.initials) {
+            held.push(alias.initials);
+        }
+    }
+    Ok(held)
+}
+
+/// Describes the initials a user of a bid year holds and has held.
+///
+/// The user may be looked up by current or retired initials.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist, nobody in it holds or
+/// held the initials, or the query fails.
+pub fn get_initials_history(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+    initials: &str,
+) -> Result<GetInitialsHistoryResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+
+    let initials: Initials = Initials::new(initials.trim());
+    let user_id: i64 = resolve_user(persistence, bid_year_id, &initials)?.ok_or_else(|| {
+        ApiError::ResourceNotFound {
+            resource_type: String::from("User"),
+            message: format!(
+                "No user in bid year {bid_year_id} holds or held initials '{}'",
+                initials.value()
+            ),
+        }
+    })?;
+    let (current, aliases): (String, Vec<InitialsAliasData>) = initials_held(persistence, user_id)?;
+
+    Ok(GetInitialsHistoryResponse {
+        bid_year_id,
+        user_id,
+        initials: current,
+        aliases: aliases
+            .into_iter()
+            .map(|alias| InitialsAliasInfo {
+                initials: alias.initials,
+                effective_date: alias.effective_date,
+                change_event_id: alias.change_event_id,
+            })
+            .collect(),
+    })
+}
+
+/// Changes a user's operating initials.
+///
+/// The new initials must not be held, now or before, by anyone else in the
+/// bid year, so audit history under any initials names a single user. A
+/// user may return to initials they held before.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year, current and new initials, and effective date
+/// * `authenticated_actor` - The authenticated actor changing the initials
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year does not exist, or nobody in it holds `from`
+/// - The effective date is malformed or outside the bid year
+/// - The new initials are invalid, or held now or before by another user
+/// - The database operation fails
+pub fn change_initials(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &ChangeInitialsRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<ChangeInitialsResponse, ApiError> {
+    require_admin(authenticated_actor, "change user initials")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let effective_date: Date =
+        Date::parse(&request.effective_date, &Iso8601::DEFAULT).map_err(|_| {
+            ApiError::InvalidInput {
+                field: String::from("effective_date"),
+                message: format!("Invalid date format: {}", request.effective_date),
+            }
+        })?;
+    let canonical_bid_year: CanonicalBidYear = persistence
+        .list_bid_years()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load bid years: {e}"),
+        })?
+        .into_iter()
+        .find(|by| by.year() == year)
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Bid year {year} exists in metadata but not in storage"),
+        })?;
+    let end_date: Date = canonical_bid_year
+        .end_date()
+        .map_err(translate_domain_error)?;
+    if effective_date < canonical_bid_year.start_date() || effective_date > end_date {
+        return Err(ApiError::InvalidInput {
+            field: String::from("effective_date"),
+            message: format!(
+                "Effective date {effective_date} is outside bid year {year} ({} to {end_date})",
+                canonical_bid_year.start_date()
+            ),
+        });
+    }
+
+    let from: Initials = Initials::new(request.from.trim());
+    let to: Initials = Initials::new(request.to.trim());
+
+    // Only the current holder of `from` can change it, not a user who
+    // retired it
+    let not_held = || ApiError::ResourceNotFound {
+        resource_type: String::from("User"),
+        message: format!(
+            "No user in bid year {year} currently holds initials '{}'",
+            from.value()
+        ),
+    };
+    let user_id: i64 =
+        resolve_user(persistence, request.bid_year_id, &from)?.ok_or_else(not_held)?;
+    if initials_held(persistence, user_id)?.0 != from.value() {
+        return Err(not_held());
+    }
+
+    let holder: Option<i64> = resolve_user(persistence, request.bid_year_id, &to)?;
+    if holder.is_some_and(|holder| holder != user_id) {
+        return Err(translate_domain_error(DomainError::DuplicateInitials {
+            bid_year: BidYear::with_id(request.bid_year_id, year),
+            initials: to,
+        }));
+    }
+
+    let area: Area = user_area(persistence, metadata, request.bid_year_id, user_id)?;
+    let bid_year: BidYear = BidYear::with_id(request.bid_year_id, year);
+    let state: State = persistence
+        .get_current_state(&bid_year, &area)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load area state: {e}"),
+        })?;
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let result: TransitionResult = apply(
+        metadata,
+        &state,
+        &bid_year,
+        Command::ChangeInitials {
+            from: from.clone(),
+            to: to.clone(),
+            effective_date,
+        },
+        actor,
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    let audit_event_id: i64 = persistence
+        .change_user_initials(
+            &result.audit_event,
+            request.bid_year_id,
+            user_id,
+            from.value(),
+            to.value(),
+            &effective_date.to_string(),
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to change user initials: {e}"),
+        })?;
+
+    Ok(ChangeInitialsResponse {
+        bid_year_id: request.bid_year_id,
+        user_id,
+        previous_initials: from.value().to_string(),
+        initials: to.value().to_string(),
+        effective_date: effective_date.to_string(),
+        audit_event_id,
+        message: format!(
+            "Changed initials '{}' to '{}' effective {effective_date}",
+            from.value(),
+            to.value()
+        ),
+    })
+}
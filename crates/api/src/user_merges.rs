@@ -0,0 +1,408 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! User merge handlers.
+//!
+//! A controller registered twice in one bid year ends up with two user
+//! records, each holding part of their bids and overrides. Merging moves
+//! everything the duplicate holds onto the surviving record and retires
+//! the duplicate, which stays on record but is excluded from bidding and
+//! leave calculation. Every merge is kept in a ledger so it can be
+//! reverted.
+
+use zab_bid::{BootstrapMetadata, Command, State, TransitionResult, apply};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::{Area, BidYear, BidYearLifecycle, DomainError};
+use zab_bid_persistence::{OperatorData, PersistenceError, SqlitePersistence, UserMergeData};
+
+use crate::auth::AuthenticatedActor;
+use crate::auto_checkpoints::{AutoCheckpoints, record_auto_checkpoints, record_operation_events};
+use crate::bid_rules::resolve_bid_year;
+use crate::eligibility::refresh_derived_eligibility;
+use crate::error::{ApiError, translate_core_error, translate_domain_error};
+use crate::leave_bids::load_lifecycle_state;
+use crate::request_response::{
+    ListUserMergesResponse, MergeUsersRequest, MergeUsersResponse, RevertUserMergeResponse,
+    UserMergeInfo,
+};
+use crate::webhooks::require_admin;
+
+/// Converts a ledger entry into its API representation.
+fn to_merge_info(data: UserMergeData) -> UserMergeInfo {
+    UserMergeInfo {
+        user_merge_id: data.user_merge_id,
+        kept_user_id: data.kept_user_id,
+        merged_user_id: data.merged_user_id,
+        reason: data.reason,
+        rows_moved: data.rows_moved,
+        merge_event_id: data.merge_event_id,
+        revert_event_id: data.revert_event_id,
+        created_at: data.created_at,
+    }
+}
+
+/// Resolves the area a user of the given bid year is assigned to.
+pub fn user_area(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+    user_id: i64,
+) -> Result<Area, ApiError> {
+    let (user_bid_year_id, _initials): (i64, String) =
+        persistence.get_user_details(user_id).map_err(|e| match e {
+            PersistenceError::NotFound(_) => ApiError::ResourceNotFound {
+                resource_type: String::from("User"),
+                message: format!("User with ID {user_id} not found"),
+            },
+            _ => ApiError::Internal {
+                message: format!("Failed to fetch user: {e}"),
+            },
+        })?;
+    if user_bid_year_id != bid_year_id {
+        return Err(ApiError::ResourceNotFound {
+            resource_type: String::from("User"),
+            message: format!("User with ID {user_id} not found in bid year {bid_year_id}"),
+        });
+    }
+
+    let area_id: i64 = persistence
+        .get_user_area_id(user_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to fetch user area: {e}"),
+        })?;
+    metadata
+        .areas
+        .iter()
+        .find(|(bid_year, area)| {
+            bid_year.bid_year_id() == Some(bid_year_id) && area.area_id() == Some(area_id)
+        })
+        .map(|(_, area)| area.clone())
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Area {area_id} of user {user_id} is missing from metadata"),
+        })
+}
+
+/// Lists the active merges a user takes part in.
+fn active_merges(
+    persistence: &mut SqlitePersistence,
+    user_id: i64,
+) -> Result<Vec<UserMergeData>, ApiError> {
+    persistence
+        .list_active_user_merges_for_user(user_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list user merges: {e}"),
+        })
+}
+
+/// Lists a bid year's user merges, including reverted ones.
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or the query fails.
+pub fn list_user_merges(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    bid_year_id: i64,
+) -> Result<ListUserMergesResponse, ApiError> {
+    resolve_bid_year(metadata, bid_year_id)?;
+
+    let merges: Vec<UserMergeInfo> = persistence
+        .list_user_merges(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list user merges: {e}"),
+        })?
+        .into_iter()
+        .map(to_merge_info)
+        .collect();
+
+    Ok(ListUserMergesResponse {
+        bid_year_id,
+        merges,
+    })
+}
+
+/// Merges a duplicate user record into the record that survives.
+///
+/// The duplicate's leave bids, bid preferences, overbid requests, waitlist
+/// offers, overrides, and leave carryover move to the surviving record,
+/// and the duplicate is excluded from bidding and leave calculation. The
+/// merge is refused while the two records hold colliding bids or
+/// overrides, which an admin must resolve first.
+///
+/// An automatic checkpoint labeled `auto-before-user-merge` is recorded in
+/// the records' area first.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The bid year, both records, and the reason
+/// * `authenticated_actor` - The authenticated actor merging the records
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The bid year or either user does not exist
+/// - Bidding has closed for the bid year
+/// - The records are in different areas, or the reason is too short
+/// - Either record already takes part in a merge in force
+/// - The records hold colliding bids or overrides
+/// - An automatic checkpoint cannot be recorded
+/// - The database operation fails
+#[allow(clippy::too_many_lines)]
+pub fn merge_users(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &MergeUsersRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<MergeUsersResponse, ApiError> {
+    require_admin(authenticated_actor, "merge users")?;
+
+    let year: u16 = resolve_bid_year(metadata, request.bid_year_id)?;
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, request.bid_year_id)?;
+    if lifecycle_state == BidYearLifecycle::BiddingClosed {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("merge users"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+
+    let area: Area = user_area(
+        persistence,
+        metadata,
+        request.bid_year_id,
+        request.keep_user_id,
+    )?;
+    user_area(
+        persistence,
+        metadata,
+        request.bid_year_id,
+        request.merge_user_id,
+    )?;
+
+    // A retired record cannot take part in another merge until the merge
+    // that retired it is reverted
+    for user_id in [request.keep_user_id, request.merge_user_id] {
+        if let Some(existing) = active_merges(persistence, user_id)?
+            .iter()
+            .find(|merge| merge.merged_user_id == user_id)
+        {
+            return Err(translate_domain_error(DomainError::InvalidUserMerge {
+                reason: format!(
+                    "user_id={user_id} was already merged into user_id={} (merge {})",
+                    existing.kept_user_id, existing.user_merge_id
+                ),
+            }));
+        }
+    }
+
+    let conflicts: Vec<String> = persistence
+        .find_user_merge_conflicts(request.keep_user_id, request.merge_user_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to check merge conflicts: {e}"),
+        })?;
+    if !conflicts.is_empty() {
+        return Err(translate_domain_error(DomainError::InvalidUserMerge {
+            reason: conflicts.join("; "),
+        }));
+    }
+
+    let bid_year: BidYear = BidYear::with_id(request.bid_year_id, year);
+    let state: State = persistence
+        .get_current_state(&bid_year, &area)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load area state: {e}"),
+        })?;
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let result: TransitionResult = apply(
+        metadata,
+        &state,
+        &bid_year,
+        Command::MergeUsers {
+            keep: request.keep_user_id,
+            merge: request.merge_user_id,
+            reason: request.reason.clone(),
+        },
+        actor,
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    let checkpoints: AutoCheckpoints = record_auto_checkpoints(
+        persistence,
+        metadata,
+        &[(bid_year, area)],
+        "user-merge",
+        &result.audit_event.actor,
+        &result.audit_event.cause,
+    )?;
+    let (audit_event_id, user_merge_id): (i64, i64) = persistence
+        .merge_users(
+            &result.audit_event,
+            request.bid_year_id,
+            request.keep_user_id,
+            request.merge_user_id,
+            request.reason.trim(),
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to merge users: {e}"),
+        })?;
+    record_operation_events(persistence, &[audit_event_id], &checkpoints.correlation_id)?;
+
+    // The retired record no longer bids
+    refresh_derived_eligibility(persistence, request.bid_year_id)?;
+
+    let rows_moved: usize = persistence
+        .get_user_merge(user_merge_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to fetch user merge: {e}"),
+        })?
+        .rows_moved;
+
+    Ok(MergeUsersResponse {
+        user_merge_id,
+        audit_event_id,
+        kept_user_id: request.keep_user_id,
+        merged_user_id: request.merge_user_id,
+        rows_moved,
+        message: format!(
+            "Merged user {} into user {}",
+            request.merge_user_id, request.keep_user_id
+        ),
+        correlation_id: checkpoints.correlation_id,
+    })
+}
+
+/// Reverts a user merge.
+///
+/// Everything the merge moved returns to the duplicate record, whose
+/// exclusion flags and leave carryover are restored. Merges are reverted
+/// newest first: a merge cannot be reverted while a later merge in force
+/// involves either record.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `user_merge_id` - The merge to revert
+/// * `authenticated_actor` - The authenticated actor reverting the merge
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The merge does not exist or was already reverted
+/// - A later merge in force involves either record
+/// - Bidding has closed for the bid year
+/// - The database operation fails
+pub fn revert_user_merge(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    user_merge_id: i64,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<RevertUserMergeResponse, ApiError> {
+    require_admin(authenticated_actor, "revert user merges")?;
+
+    let record: UserMergeData = persistence
+        .get_user_merge(user_merge_id)
+        .map_err(|e| match e {
+            PersistenceError::NotFound(_) => ApiError::ResourceNotFound {
+                resource_type: String::from("UserMerge"),
+                message: format!("User merge with ID {user_merge_id} not found"),
+            },
+            _ => ApiError::Internal {
+                message: format!("Failed to fetch user merge: {e}"),
+            },
+        })?;
+    if record.revert_event_id.is_some() {
+        return Err(translate_domain_error(DomainError::InvalidUserMerge {
+            reason: format!("merge {user_merge_id} was already reverted"),
+        }));
+    }
+
+    let year: u16 = resolve_bid_year(metadata, record.bid_year_id)?;
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, record.bid_year_id)?;
+    if lifecycle_state == BidYearLifecycle::BiddingClosed {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("revert user merges"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+
+    for user_id in [record.kept_user_id, record.merged_user_id] {
+        if let Some(later) = active_merges(persistence, user_id)?
+            .iter()
+            .find(|merge| merge.user_merge_id > user_merge_id)
+        {
+            return Err(translate_domain_error(DomainError::InvalidUserMerge {
+                reason: format!(
+                    "merge {} also involves user_id={user_id} and must be reverted first",
+                    later.user_merge_id
+                ),
+            }));
+        }
+    }
+
+    let area: Area = user_area(
+        persistence,
+        metadata,
+        record.bid_year_id,
+        record.kept_user_id,
+    )?;
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let event: AuditEvent = AuditEvent::new(
+        actor,
+        cause,
+        Action::new(
+            String::from("UserMergeReverted"),
+            Some(format!(
+                "Reverted merge {user_merge_id}: user_id={} split from user_id={}",
+                record.merged_user_id, record.kept_user_id
+            )),
+        ),
+        StateSnapshot::new(format!(
+            "user_merge_id={user_merge_id},merged_user_id={},kept_user_id={},merged=true",
+            record.merged_user_id, record.kept_user_id
+        )),
+        StateSnapshot::new(format!(
+            "user_merge_id={user_merge_id},merged_user_id={},kept_user_id={},merged=false",
+            record.merged_user_id, record.kept_user_id
+        )),
+        BidYear::with_id(record.bid_year_id, year),
+        area,
+    )
+    .with_user_id(Some(record.kept_user_id));
+
+    let audit_event_id: i64 = persistence
+        .revert_user_merge(&record, &event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to revert user merge: {e}"),
+        })?;
+
+    refresh_derived_eligibility(persistence, record.bid_year_id)?;
+
+    Ok(RevertUserMergeResponse {
+        user_merge_id,
+        audit_event_id,
+        message: format!(
+            "Reverted merge of user {} into user {}",
+            record.merged_user_id, record.kept_user_id
+        ),
+    })
+}
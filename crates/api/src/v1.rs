@@ -0,0 +1,92 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Version 1 of the API wire contract.
+//!
+//! These are the request and response types served under `/api/v1` (and the
+//! legacy unversioned `/api` prefix, which is an alias for v1). Frontends
+//! deployed against v1 must keep working for the remainder of a bid season,
+//! so the shapes re-exported here are frozen.
+//!
+//! # Compatibility Policy
+//!
+//! Within v1, the following changes are permitted:
+//!
+//! - Adding a new endpoint or a new DTO
+//! - Adding an `Option` field to a request (absent means the old behavior)
+//! - Adding a field to a response (clients ignore unknown fields)
+//!
+//! Everything else requires a new version module:
+//!
+//! - Removing or renaming a field
+//! - Changing a field's type or serialized representation
+//!   (e.g. replacing an ISO 8601 date string with a structured date)
+//! - Making an optional request field required
+//! - Changing enum variant names or tagging
+//!
+//! Every DTO is re-exported, so a new one joins v1 without being listed.
+//! When a DTO must break, the new shape is introduced in the next version
+//! module and the v1 type is kept here as a copy, which shadows the
+//! re-exported current shape. The v1 type then implements
+//! [`IntoCurrent`](crate::IntoCurrent) / [`FromCurrent`](crate::FromCurrent)
+//! so that handlers only ever operate on the current shape. The helpers in
+//! [`shims`] cover the representation changes v1 is expected to need.
+
+pub use crate::request_response::*;
+
+/// The version served by this module.
+pub const VERSION: crate::ApiVersion = crate::ApiVersion::V1;
+
+/// Conversion helpers for v1 wire representations.
+pub mod shims {
+    use crate::ApiError;
+    use time::Date;
+
+    /// The v1 wire format for calendar dates.
+    const DATE_FORMAT: &[time::format_description::FormatItem<'_>] =
+        time::macros::format_description!("[year]-[month]-[day]");
+
+    /// Parses a v1 ISO 8601 date string (`YYYY-MM-DD`).
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The request field name, for error reporting
+    /// * `value` - The date string as sent by a v1 client
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError::InvalidInput` if the string is not a valid date.
+    pub fn parse_date(field: &str, value: &str) -> Result<Date, ApiError> {
+        Date::parse(value, DATE_FORMAT).map_err(|_| ApiError::InvalidInput {
+            field: field.to_string(),
+            message: format!("Invalid date format: {value}"),
+        })
+    }
+
+    /// Formats a date in the v1 ISO 8601 wire format (`YYYY-MM-DD`).
+    #[must_use]
+    pub fn format_date(date: Date) -> String {
+        format!(
+            "{:04}-{:02}-{:02}",
+            date.year(),
+            u8::from(date.month()),
+            date.day()
+        )
+    }
+
+    /// Parses an optional v1 date string, treating `None` as absent.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The request field name, for error reporting
+    /// * `value` - The optional date string as sent by a v1 client
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError::InvalidInput` if the string is present but not a valid date.
+    pub fn parse_optional_date(field: &str, value: Option<&str>) -> Result<Option<Date>, ApiError> {
+        value.map(|v| parse_date(field, v)).transpose()
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! API version identifiers and DTO conversion traits.
+//!
+//! Handlers always operate on the current DTO shapes. Older wire versions are
+//! adapted at the edge by implementing [`IntoCurrent`] for their requests and
+//! [`FromCurrent`] for their responses.
+
+use crate::ApiError;
+
+/// A published version of the API wire contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ApiVersion {
+    /// The initial versioned contract.
+    V1,
+}
+
+impl ApiVersion {
+    /// The version new clients should target.
+    pub const CURRENT: Self = Self::V1;
+
+    /// Every version the server still accepts, oldest first.
+    pub const SUPPORTED: &'static [Self] = &[Self::V1];
+
+    /// Returns the path segment for this version (e.g. `"v1"`).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::V1 => "v1",
+        }
+    }
+
+    /// Parses a version path segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The version segment, e.g. `"v1"`
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError::InvalidInput` if the version is unknown.
+    pub fn parse(value: &str) -> Result<Self, ApiError> {
+        Self::SUPPORTED
+            .iter()
+            .copied()
+            .find(|version| version.as_str() == value)
+            .ok_or_else(|| ApiError::InvalidInput {
+                field: String::from("api_version"),
+                message: format!("Unsupported API version: {value}"),
+            })
+    }
+}
+
+impl std::fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Converts a request from an older API version into the current shape.
+pub trait IntoCurrent {
+    /// The current DTO this request corresponds to.
+    type Current;
+
+    /// Performs the conversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError::InvalidInput` if a legacy field cannot be represented
+    /// in the current shape.
+    fn into_current(self) -> Result<Self::Current, ApiError>;
+}
+
+/// Converts a current response into an older API version's shape.
+pub trait FromCurrent: Sized {
+    /// The current DTO this response is derived from.
+    type Current;
+
+    /// Performs the conversion.
+    fn from_current(current: Self::Current) -> Self;
+}
@@ -0,0 +1,801 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Waitlist handlers for cancelled leave slots.
+//!
+//! A controller may withdraw an approved leave bid at any time during
+//! bidding. Before the round closes the slot simply returns to the
+//! inventory. Once the round has closed in the area — it is signed off or
+//! the bid year is `BiddingClosed` — the freed slot is instead offered to
+//! the remaining controllers one at a time, in bid order. Each offer stays
+//! open for the slot's offer window; a decline or an expiry passes the
+//! slot to the next controller, and an acceptance grants the leave. A slot
+//! nobody takes is marked unclaimed. In a bid year with the `waitlists`
+//! feature disabled, freed slots always return to the inventory.
+//!
+//! Every withdrawal, offer, and response is recorded as an audit event,
+//! and each waitlist row links to the event that created or answered it.
+
+use std::collections::BTreeSet;
+use time::format_description::well_known::{Iso8601, Rfc3339};
+use time::{Date, Duration, OffsetDateTime};
+use zab_bid::{BootstrapMetadata, BootstrapResult, Command, State, apply_bootstrap};
+use zab_bid_audit::{Actor, Cause};
+use zab_bid_domain::{
+    Area, BidReceiptMethod, BidYear, BidYearLifecycle, DomainError, FeatureFlag, Initials,
+};
+use zab_bid_persistence::{
+    LeaveBidData, OperatorData, SqlitePersistence, WaitlistOfferData, WaitlistSlotData,
+};
+
+use crate::auth::{AuthenticatedActor, AuthorizationService, Role};
+use crate::error::{ApiError, translate_core_error, translate_domain_error};
+use crate::feature_flags::is_feature_enabled;
+use crate::leave_bids::{
+    load_lifecycle_state, resolve_area, resolve_user_crew, resolve_user_initials,
+};
+use crate::request_response::{
+    AcceptWaitlistOfferRequest, DeclineWaitlistOfferRequest, ListWaitlistResponse,
+    WaitlistOfferInfo, WaitlistOfferResponse, WaitlistSlotInfo, WithdrawLeaveBidRequest,
+    WithdrawLeaveBidResponse,
+};
+use crate::round_sign_offs::is_round_signed_off;
+
+/// How long a waitlist offer stays open when the withdrawal does not say.
+pub const DEFAULT_WAITLIST_OFFER_HOURS: u32 = 24;
+
+/// Ensures the actor may enter leave on a controller's behalf.
+fn require_admin_or_bidder(
+    authenticated_actor: &AuthenticatedActor,
+    action: &str,
+) -> Result<(), ApiError> {
+    if matches!(authenticated_actor.role, Role::Admin | Role::Bidder) {
+        Ok(())
+    } else {
+        Err(ApiError::Unauthorized {
+            action: action.to_string(),
+            required_role: String::from("Admin or Bidder"),
+        })
+    }
+}
+
+/// Parses a stored leave date.
+fn parse_leave_date(value: &str) -> Result<Date, ApiError> {
+    Date::parse(value, &Iso8601::DEFAULT).map_err(|e| ApiError::Internal {
+        message: format!("Failed to parse leave date '{value}': {e}"),
+    })
+}
+
+/// Parses a stored offer timestamp (RFC 3339).
+fn parse_instant(value: &str) -> Result<OffsetDateTime, ApiError> {
+    OffsetDateTime::parse(value, &Rfc3339).map_err(|e| ApiError::Internal {
+        message: format!("Failed to parse waitlist timestamp '{value}': {e}"),
+    })
+}
+
+/// Formats an offer timestamp for storage (RFC 3339).
+fn format_instant(instant: OffsetDateTime) -> Result<String, ApiError> {
+    instant.format(&Rfc3339).map_err(|e| ApiError::Internal {
+        message: format!("Failed to format waitlist timestamp: {e}"),
+    })
+}
+
+/// Loads the current state of an area.
+fn load_state(
+    persistence: &mut SqlitePersistence,
+    bid_year: &BidYear,
+    area: &Area,
+) -> Result<State, ApiError> {
+    persistence
+        .get_current_state(bid_year, area)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load area state: {e}"),
+        })
+}
+
+/// Loads a waitlist slot.
+fn load_slot(
+    persistence: &mut SqlitePersistence,
+    waitlist_slot_id: i64,
+) -> Result<WaitlistSlotData, ApiError> {
+    persistence
+        .get_waitlist_slot(waitlist_slot_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get waitlist slot: {e}"),
+        })?
+        .ok_or_else(|| ApiError::Internal {
+            message: format!("Waitlist slot {waitlist_slot_id} not found"),
+        })
+}
+
+/// Loads a waitlist offer that is still waiting for an answer, with its
+/// slot.
+fn load_pending_offer(
+    persistence: &mut SqlitePersistence,
+    waitlist_offer_id: i64,
+) -> Result<(WaitlistOfferData, WaitlistSlotData), ApiError> {
+    let offer: WaitlistOfferData = persistence
+        .get_waitlist_offer(waitlist_offer_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get waitlist offer: {e}"),
+        })?
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("WaitlistOffer"),
+            message: format!("Waitlist offer with ID {waitlist_offer_id} not found"),
+        })?;
+    if offer.status != WaitlistOfferData::STATUS_PENDING {
+        return Err(translate_domain_error(DomainError::InvalidWaitlistOffer {
+            reason: format!(
+                "Waitlist offer {waitlist_offer_id} is already {}",
+                offer.status
+            ),
+        }));
+    }
+    let slot: WaitlistSlotData = load_slot(persistence, offer.waitlist_slot_id)?;
+
+    Ok((offer, slot))
+}
+
+/// Finds the next controller in bid order who may be offered a slot.
+///
+/// Controllers who were already offered the slot, or who hold or withdrew
+/// leave on its day in the round, are skipped. A crew-partitioned slot is
+/// only offered within its crew.
+fn next_candidate(
+    persistence: &mut SqlitePersistence,
+    bid_year: &BidYear,
+    area: &Area,
+    slot: &WaitlistSlotData,
+) -> Result<Option<i64>, ApiError> {
+    let mut skipped: BTreeSet<i64> = persistence
+        .list_waitlist_offers(slot.waitlist_slot_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list waitlist offers: {e}"),
+        })?
+        .into_iter()
+        .map(|offer| offer.user_id)
+        .collect();
+    skipped.extend(
+        persistence
+            .list_leave_bids(slot.bid_year_id, slot.area_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list leave bids: {e}"),
+            })?
+            .into_iter()
+            .filter(|bid| bid.round_id == slot.round_id && bid.leave_date == slot.leave_date)
+            .map(|bid| bid.user_id),
+    );
+    if let Some(crew) = slot.crew {
+        skipped.extend(
+            load_state(persistence, bid_year, area)?
+                .users
+                .values()
+                .filter(|user| user.crew.as_ref().map(|c| i32::from(c.number())) != Some(crew))
+                .filter_map(|user| user.user_id),
+        );
+    }
+
+    Ok(persistence
+        .list_round_results(slot.bid_year_id, slot.area_id, slot.round_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list round results: {e}"),
+        })?
+        .into_iter()
+        .map(|entry| entry.user_id)
+        .find(|user_id| !skipped.contains(user_id)))
+}
+
+/// Offers a slot to the next controller in bid order.
+///
+/// The slot is marked unclaimed when nobody is left to offer it to.
+///
+/// # Returns
+///
+/// The user ID of the controller offered the slot, if any.
+fn offer_next(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    slot: &WaitlistSlotData,
+    actor: &Actor,
+    cause: &Cause,
+    now: OffsetDateTime,
+) -> Result<Option<i64>, ApiError> {
+    let (bid_year, area): (&BidYear, &Area) = resolve_area(metadata, slot.area_id)?;
+    let Some(user_id) = next_candidate(persistence, bid_year, area, slot)? else {
+        persistence
+            .set_waitlist_slot_status(slot.waitlist_slot_id, WaitlistSlotData::STATUS_UNCLAIMED)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to update waitlist slot: {e}"),
+            })?;
+        return Ok(None);
+    };
+
+    let expires_at: OffsetDateTime = now + Duration::hours(i64::from(slot.offer_hours));
+    let command: Command = Command::OfferWaitlistSlot {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id: slot.round_id,
+        waitlist_slot_id: slot.waitlist_slot_id,
+        user_id,
+        leave_date: parse_leave_date(&slot.leave_date)?,
+        offered_at: now,
+        expires_at,
+    };
+    let result: BootstrapResult =
+        apply_bootstrap(metadata, bid_year, command, actor.clone(), cause.clone())
+            .map_err(translate_core_error)?;
+
+    let offer_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    persistence
+        .insert_waitlist_offer(
+            slot.waitlist_slot_id,
+            user_id,
+            &format_instant(now)?,
+            &format_instant(expires_at)?,
+            offer_event_id,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record waitlist offer: {e}"),
+        })?;
+
+    Ok(Some(user_id))
+}
+
+/// Withdraws an approved leave bid.
+///
+/// Once the round has closed in the area the freed slot is put on the
+/// waitlist and offered to the first eligible controller in bid order.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The leave bid to withdraw and the offer window
+/// * `authenticated_actor` - The authenticated actor entering the withdrawal
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin or Bidder
+/// - The actor is an area rep who may not bid in the area
+/// - The area or leave bid does not exist
+/// - The operator is the controller the leave bid is for
+/// - The leave bid is not approved
+/// - The bid year is not `BiddingActive` or `BiddingClosed`
+/// - The offer window is zero hours
+/// - The database operation fails
+#[allow(clippy::too_many_lines)]
+pub fn withdraw_leave_bid(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &WithdrawLeaveBidRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: &Cause,
+) -> Result<WithdrawLeaveBidResponse, ApiError> {
+    require_admin_or_bidder(authenticated_actor, "withdraw leave bid")?;
+
+    let offer_hours: u32 = request.offer_hours.unwrap_or(DEFAULT_WAITLIST_OFFER_HOURS);
+    let offer_hours: i32 = match i32::try_from(offer_hours) {
+        Ok(hours) if hours > 0 => hours,
+        _ => {
+            return Err(translate_domain_error(DomainError::InvalidWaitlistOffer {
+                reason: format!("An offer window of {offer_hours} hours is not allowed"),
+            }));
+        }
+    };
+
+    let (bid_year, area): (&BidYear, &Area) = resolve_area(metadata, request.area_id)?;
+    AuthorizationService::authorize_area_bidding(
+        persistence,
+        authenticated_actor,
+        operator,
+        request.area_id,
+        "withdraw leave bid",
+    )?;
+    let bid_year_id: i64 = bid_year.bid_year_id().ok_or_else(|| ApiError::Internal {
+        message: format!("Bid year {} has no ID", bid_year.year()),
+    })?;
+    let bid: LeaveBidData = persistence
+        .list_leave_bids(bid_year_id, request.area_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list leave bids: {e}"),
+        })?
+        .into_iter()
+        .find(|bid| bid.leave_bid_id == request.leave_bid_id)
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("LeaveBid"),
+            message: format!(
+                "Leave bid with ID {} not found in area {}",
+                request.leave_bid_id,
+                area.area_code()
+            ),
+        })?;
+    if bid.status != LeaveBidData::STATUS_APPROVED {
+        return Err(ApiError::InvalidInput {
+            field: String::from("leave_bid_id"),
+            message: format!("Leave bid {} is already {}", bid.leave_bid_id, bid.status),
+        });
+    }
+
+    let lifecycle_state: BidYearLifecycle = load_lifecycle_state(persistence, bid_year_id)?;
+    if !matches!(
+        lifecycle_state,
+        BidYearLifecycle::BiddingActive | BidYearLifecycle::BiddingClosed
+    ) {
+        return Err(translate_domain_error(
+            DomainError::OperationNotAllowedInState {
+                operation: String::from("withdraw leave bid"),
+                state: lifecycle_state.as_str().to_string(),
+            },
+        ));
+    }
+    let round_closed: bool = lifecycle_state == BidYearLifecycle::BiddingClosed
+        || is_round_signed_off(persistence, request.area_id, bid.round_id)?;
+    // With waitlists disabled a freed slot always returns to the inventory
+    let to_waitlist: bool =
+        round_closed && is_feature_enabled(persistence, bid_year_id, FeatureFlag::Waitlists)?;
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let command: Command = Command::WithdrawLeaveBid {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id: bid.round_id,
+        user_id: bid.user_id,
+        on_behalf_of: resolve_user_initials(persistence, bid_year, area, bid.user_id)?,
+        leave_bid_id: bid.leave_bid_id,
+        leave_date: parse_leave_date(&bid.leave_date)?,
+        round_closed: to_waitlist,
+    };
+    let result: BootstrapResult =
+        apply_bootstrap(metadata, bid_year, command, actor.clone(), cause.clone())
+            .map_err(translate_core_error)?;
+
+    persistence
+        .withdraw_leave_bid(bid.leave_bid_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to withdraw leave bid: {e}"),
+        })?;
+    let release_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+
+    if !to_waitlist {
+        return Ok(WithdrawLeaveBidResponse {
+            leave_bid_id: bid.leave_bid_id,
+            release_event_id,
+            waitlist_slot_id: None,
+            offered_to_user_id: None,
+            message: format!(
+                "Withdrew leave on {}; the slot is open for bidding",
+                bid.leave_date
+            ),
+        });
+    }
+
+    let crew_partitioned: bool = persistence
+        .list_crew_partitioned_rounds(bid_year_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to load crew-partitioned rounds: {e}"),
+        })?
+        .contains(&bid.round_id);
+    let crew: Option<i32> = if crew_partitioned {
+        resolve_user_crew(persistence, bid_year, area, bid.user_id)?.map(i32::from)
+    } else {
+        None
+    };
+
+    let mut slot: WaitlistSlotData = WaitlistSlotData {
+        waitlist_slot_id: 0,
+        bid_year_id,
+        area_id: request.area_id,
+        round_id: bid.round_id,
+        leave_date: bid.leave_date.clone(),
+        hours: bid.hours,
+        crew,
+        offer_hours,
+        released_leave_bid_id: bid.leave_bid_id,
+        release_event_id,
+        status: String::from(WaitlistSlotData::STATUS_OPEN),
+        created_at: String::new(),
+    };
+    slot.waitlist_slot_id =
+        persistence
+            .insert_waitlist_slot(&slot)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to record waitlist slot: {e}"),
+            })?;
+    let offered_to_user_id: Option<i64> = offer_next(
+        persistence,
+        metadata,
+        &slot,
+        &actor,
+        cause,
+        persistence.now(),
+    )?;
+
+    Ok(WithdrawLeaveBidResponse {
+        leave_bid_id: bid.leave_bid_id,
+        release_event_id,
+        waitlist_slot_id: Some(slot.waitlist_slot_id),
+        offered_to_user_id,
+        message: match offered_to_user_id {
+            Some(user_id) => format!(
+                "Withdrew leave on {}; the slot is offered to user {user_id}",
+                bid.leave_date
+            ),
+            None => format!(
+                "Withdrew leave on {}; nobody on the waitlist can take the slot",
+                bid.leave_date
+            ),
+        },
+    })
+}
+
+/// Accepts a pending waitlist offer, granting the freed leave.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The offer to accept and how the answer was received
+/// * `authenticated_actor` - The authenticated actor entering the answer
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin or Bidder
+/// - The receipt method is invalid
+/// - The actor is an area rep who may not bid in the slot's area
+/// - The offer does not exist, is not pending, or has expired
+/// - The operator is the controller the offer is for
+/// - The database operation fails
+pub fn accept_waitlist_offer(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &AcceptWaitlistOfferRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<WaitlistOfferResponse, ApiError> {
+    require_admin_or_bidder(authenticated_actor, "accept waitlist offer")?;
+
+    let received_via: BidReceiptMethod = request
+        .received_via
+        .parse()
+        .map_err(translate_domain_error)?;
+    let (offer, slot) = load_pending_offer(persistence, request.waitlist_offer_id)?;
+    let (bid_year, area): (&BidYear, &Area) = resolve_area(metadata, slot.area_id)?;
+    AuthorizationService::authorize_area_bidding(
+        persistence,
+        authenticated_actor,
+        operator,
+        slot.area_id,
+        "accept waitlist offer",
+    )?;
+    let on_behalf_of: Initials = resolve_user_initials(persistence, bid_year, area, offer.user_id)?;
+    let now: OffsetDateTime = persistence.now();
+
+    let command: Command = Command::AcceptWaitlistOffer {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id: slot.round_id,
+        waitlist_offer_id: offer.waitlist_offer_id,
+        user_id: offer.user_id,
+        on_behalf_of: on_behalf_of.clone(),
+        received_via,
+        leave_date: parse_leave_date(&slot.leave_date)?,
+        expires_at: parse_instant(&offer.expires_at)?,
+        accepted_at: now,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        metadata,
+        bid_year,
+        command,
+        authenticated_actor.to_audit_actor(operator),
+        cause,
+    )
+    .map_err(translate_core_error)?;
+
+    let leave_bid_id: i64 = persistence
+        .insert_leave_bid(
+            slot.bid_year_id,
+            slot.area_id,
+            offer.user_id,
+            slot.round_id,
+            &slot.leave_date,
+            slot.hours,
+            on_behalf_of.value(),
+            received_via.as_str(),
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record leave bid: {e}"),
+        })?;
+    let response_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    persistence
+        .respond_to_waitlist_offer(
+            offer.waitlist_offer_id,
+            WaitlistOfferData::STATUS_ACCEPTED,
+            &format_instant(now)?,
+            response_event_id,
+            Some(leave_bid_id),
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record waitlist offer response: {e}"),
+        })?;
+    persistence
+        .set_waitlist_slot_status(slot.waitlist_slot_id, WaitlistSlotData::STATUS_FILLED)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to update waitlist slot: {e}"),
+        })?;
+
+    Ok(WaitlistOfferResponse {
+        waitlist_offer_id: offer.waitlist_offer_id,
+        waitlist_slot_id: slot.waitlist_slot_id,
+        status: String::from(WaitlistOfferData::STATUS_ACCEPTED),
+        response_event_id,
+        leave_bid_id: Some(leave_bid_id),
+        offered_to_user_id: None,
+        message: format!(
+            "Granted leave on {} to '{}' from the waitlist",
+            slot.leave_date,
+            on_behalf_of.value()
+        ),
+    })
+}
+
+/// Declines a pending waitlist offer and offers the slot to the next
+/// controller in bid order.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `request` - The offer to decline
+/// * `authenticated_actor` - The authenticated actor entering the answer
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin or Bidder
+/// - The offer does not exist or is not pending
+/// - The actor is an area rep who may not bid in the slot's area
+/// - The database operation fails
+pub fn decline_waitlist_offer(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    request: &DeclineWaitlistOfferRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: &Cause,
+) -> Result<WaitlistOfferResponse, ApiError> {
+    require_admin_or_bidder(authenticated_actor, "decline waitlist offer")?;
+
+    let (offer, slot) = load_pending_offer(persistence, request.waitlist_offer_id)?;
+    let (bid_year, area): (&BidYear, &Area) = resolve_area(metadata, slot.area_id)?;
+    AuthorizationService::authorize_area_bidding(
+        persistence,
+        authenticated_actor,
+        operator,
+        slot.area_id,
+        "decline waitlist offer",
+    )?;
+    let now: OffsetDateTime = persistence.now();
+
+    let actor: Actor = authenticated_actor.to_audit_actor(operator);
+    let command: Command = Command::DeclineWaitlistOffer {
+        year: bid_year.year(),
+        area: area.clone(),
+        round_id: slot.round_id,
+        waitlist_offer_id: offer.waitlist_offer_id,
+        user_id: offer.user_id,
+        leave_date: parse_leave_date(&slot.leave_date)?,
+    };
+    let result: BootstrapResult =
+        apply_bootstrap(metadata, bid_year, command, actor.clone(), cause.clone())
+            .map_err(translate_core_error)?;
+
+    let response_event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    persistence
+        .respond_to_waitlist_offer(
+            offer.waitlist_offer_id,
+            WaitlistOfferData::STATUS_DECLINED,
+            &format_instant(now)?,
+            response_event_id,
+            None,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to record waitlist offer response: {e}"),
+        })?;
+    let offered_to_user_id: Option<i64> =
+        offer_next(persistence, metadata, &slot, &actor, cause, now)?;
+
+    Ok(WaitlistOfferResponse {
+        waitlist_offer_id: offer.waitlist_offer_id,
+        waitlist_slot_id: slot.waitlist_slot_id,
+        status: String::from(WaitlistOfferData::STATUS_DECLINED),
+        response_event_id,
+        leave_bid_id: None,
+        offered_to_user_id,
+        message: format!("Declined waitlist offer for {}", slot.leave_date),
+    })
+}
+
+/// Expires every pending waitlist offer whose window has passed at `now`
+/// and offers each slot to the next controller in bid order.
+///
+/// Every change is attributed to the scheduler acting under the given
+/// operator.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `operator` - The operator the scheduler runs as
+/// * `now` - The current instant
+///
+/// # Returns
+///
+/// The number of offers expired.
+///
+/// # Errors
+///
+/// Returns an error if the offers cannot be read or persistence fails.
+pub fn expire_waitlist_offers(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    operator: &OperatorData,
+    now: OffsetDateTime,
+) -> Result<usize, ApiError> {
+    let pending: Vec<WaitlistOfferData> =
+        persistence
+            .list_pending_waitlist_offers()
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list waitlist offers: {e}"),
+            })?;
+
+    let actor: Actor = Actor::with_operator(
+        String::from("scheduler"),
+        String::from("system"),
+        operator.operator_id,
+        operator.login_name.clone(),
+        operator.display_name.clone(),
+    );
+    let cause: Cause = Cause::new(
+        String::from("offer_expired"),
+        String::from("Waitlist offer lapsed without an answer"),
+    );
+
+    let mut expired: usize = 0;
+    for offer in pending {
+        let expires_at: OffsetDateTime = parse_instant(&offer.expires_at)?;
+        if expires_at > now {
+            continue;
+        }
+        let slot: WaitlistSlotData = load_slot(persistence, offer.waitlist_slot_id)?;
+        let (bid_year, area): (&BidYear, &Area) = resolve_area(metadata, slot.area_id)?;
+
+        let command: Command = Command::ExpireWaitlistOffer {
+            year: bid_year.year(),
+            area: area.clone(),
+            round_id: slot.round_id,
+            waitlist_offer_id: offer.waitlist_offer_id,
+            user_id: offer.user_id,
+            leave_date: parse_leave_date(&slot.leave_date)?,
+            expires_at,
+        };
+        let result: BootstrapResult =
+            apply_bootstrap(metadata, bid_year, command, actor.clone(), cause.clone())
+                .map_err(translate_core_error)?;
+
+        let response_event_id: i64 = persistence
+            .persist_audit_event(&result.audit_event)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to persist audit event: {e}"),
+            })?;
+        persistence
+            .respond_to_waitlist_offer(
+                offer.waitlist_offer_id,
+                WaitlistOfferData::STATUS_EXPIRED,
+                &format_instant(now)?,
+                response_event_id,
+                None,
+            )
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to record waitlist offer response: {e}"),
+            })?;
+        offer_next(persistence, metadata, &slot, &actor, &cause, now)?;
+        expired += 1;
+    }
+
+    Ok(expired)
+}
+
+/// Lists the waitlisted slots of a round in an area with their offers.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `metadata` - The bootstrap metadata
+/// * `area_id` - The canonical area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the area does not exist or the query fails.
+pub fn list_waitlist(
+    persistence: &mut SqlitePersistence,
+    metadata: &BootstrapMetadata,
+    area_id: i64,
+    round_id: i64,
+) -> Result<ListWaitlistResponse, ApiError> {
+    resolve_area(metadata, area_id)?;
+
+    let slots: Vec<WaitlistSlotData> =
+        persistence
+            .list_waitlist_slots(area_id, round_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list waitlist slots: {e}"),
+            })?;
+    let mut infos: Vec<WaitlistSlotInfo> = Vec::with_capacity(slots.len());
+    for slot in slots {
+        let offers: Vec<WaitlistOfferInfo> = persistence
+            .list_waitlist_offers(slot.waitlist_slot_id)
+            .map_err(|e| ApiError::Internal {
+                message: format!("Failed to list waitlist offers: {e}"),
+            })?
+            .into_iter()
+            .map(|offer| WaitlistOfferInfo {
+                waitlist_offer_id: offer.waitlist_offer_id,
+                user_id: offer.user_id,
+                status: offer.status,
+                offered_at: offer.offered_at,
+                expires_at: offer.expires_at,
+                responded_at: offer.responded_at,
+                offer_event_id: offer.offer_event_id,
+                response_event_id: offer.response_event_id,
+                leave_bid_id: offer.leave_bid_id,
+            })
+            .collect();
+        infos.push(WaitlistSlotInfo {
+            waitlist_slot_id: slot.waitlist_slot_id,
+            leave_date: slot.leave_date,
+            hours: slot.hours,
+            crew: slot.crew,
+            offer_hours: slot.offer_hours,
+            released_leave_bid_id: slot.released_leave_bid_id,
+            release_event_id: slot.release_event_id,
+            status: slot.status,
+            created_at: slot.created_at,
+            offers,
+        });
+    }
+
+    Ok(ListWaitlistResponse {
+        area_id,
+        round_id,
+        slots: infos,
+    })
+}
@@ -0,0 +1,450 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Webhook management handlers.
+//!
+//! Webhooks deliver selected audit events to facility tooling. Only Admin
+//! actors may manage them. Every configuration change is recorded as a
+//! global audit event; signing secrets never appear in audit records or
+//! listing responses.
+
+use std::fmt::Write as _;
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_persistence::{OperatorData, SqlitePersistence, WebhookData, WebhookDeadLetterData};
+
+use crate::auth::{AuthenticatedActor, Role};
+use crate::error::ApiError;
+use crate::request_response::{
+    CreateWebhookRequest, CreateWebhookResponse, DeleteWebhookRequest, DeleteWebhookResponse,
+    ListWebhookDeadLettersResponse, ListWebhooksResponse, UpdateWebhookRequest,
+    UpdateWebhookResponse, WebhookDeadLetterInfo, WebhookInfo,
+};
+
+/// Minimum length of a caller-supplied signing secret.
+const MIN_SECRET_LENGTH: usize = 16;
+
+/// Number of random bytes in a generated signing secret.
+const GENERATED_SECRET_BYTES: usize = 32;
+
+/// Default number of dead-letter entries returned.
+const DEFAULT_DEAD_LETTER_LIMIT: u32 = 100;
+
+/// Maximum number of dead-letter entries returned.
+const MAX_DEAD_LETTER_LIMIT: u32 = 1000;
+
+/// Rejects non-Admin actors.
+pub fn require_admin(
+    authenticated_actor: &AuthenticatedActor,
+    action: &str,
+) -> Result<(), ApiError> {
+    if authenticated_actor.role != Role::Admin {
+        return Err(ApiError::Unauthorized {
+            action: action.to_string(),
+            required_role: String::from("Admin"),
+        });
+    }
+    Ok(())
+}
+
+/// Validates a webhook delivery URL.
+fn validate_url(url: &str) -> Result<(), ApiError> {
+    let has_host: bool = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .is_some_and(|rest| !rest.is_empty() && !rest.starts_with('/'));
+    if !has_host || url.chars().any(char::is_whitespace) {
+        return Err(ApiError::InvalidInput {
+            field: String::from("url"),
+            message: format!("Webhook URL must be an absolute http or https URL: {url}"),
+        });
+    }
+    Ok(())
+}
+
+/// Validates an event filter: non-empty, each entry `*` or an action name.
+fn validate_event_filter(event_filter: &[String]) -> Result<(), ApiError> {
+    if event_filter.is_empty() {
+        return Err(ApiError::InvalidInput {
+            field: String::from("event_filter"),
+            message: String::from("At least one audit action name (or \"*\") is required"),
+        });
+    }
+    for entry in event_filter {
+        let valid: bool = entry == WebhookData::ALL_EVENTS
+            || (!entry.is_empty() && entry.chars().all(|c| c.is_ascii_alphanumeric()));
+        if !valid {
+            return Err(ApiError::InvalidInput {
+                field: String::from("event_filter"),
+                message: format!("Invalid audit action name: '{entry}'"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Generates a random hex-encoded signing secret.
+fn generate_secret() -> String {
+    let bytes: [u8; GENERATED_SECRET_BYTES] = rand::random();
+    bytes.iter().fold(
+        String::with_capacity(GENERATED_SECRET_BYTES * 2),
+        |mut out, byte| {
+            let _ = write!(out, "{byte:02x}");
+            out
+        },
+    )
+}
+
+/// Builds the audit actor for the acting operator.
+pub fn operator_actor(operator: &OperatorData) -> Actor {
+    Actor::with_operator(
+        operator.operator_id.to_string(),
+        String::from("operator"),
+        operator.operator_id,
+        operator.login_name.clone(),
+        operator.display_name.clone(),
+    )
+}
+
+/// Formats a webhook's non-secret configuration for audit snapshots.
+fn webhook_snapshot(
+    webhook_id: i64,
+    url: &str,
+    event_filter: &[String],
+    is_enabled: bool,
+) -> String {
+    format!(
+        "webhook_id={webhook_id},url={url},event_filter={},is_enabled={is_enabled}",
+        event_filter.join("|")
+    )
+}
+
+/// Records a global audit event for a webhook configuration change.
+fn persist_webhook_audit_event(
+    persistence: &mut SqlitePersistence,
+    operator: &OperatorData,
+    cause: Cause,
+    action: Action,
+    before: String,
+    after: String,
+) -> Result<(), ApiError> {
+    let audit_event: AuditEvent = AuditEvent::new_global(
+        operator_actor(operator),
+        cause,
+        action,
+        StateSnapshot::new(before),
+        StateSnapshot::new(after),
+    );
+    persistence
+        .persist_audit_event(&audit_event)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to persist audit event: {e}"),
+        })?;
+    Ok(())
+}
+
+/// Loads a webhook or returns `ResourceNotFound`.
+fn load_webhook(
+    persistence: &mut SqlitePersistence,
+    webhook_id: i64,
+) -> Result<WebhookData, ApiError> {
+    persistence
+        .get_webhook(webhook_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to get webhook: {e}"),
+        })?
+        .ok_or_else(|| ApiError::ResourceNotFound {
+            resource_type: String::from("Webhook"),
+            message: format!("Webhook with ID {webhook_id} not found"),
+        })
+}
+
+impl From<WebhookData> for WebhookInfo {
+    fn from(webhook: WebhookData) -> Self {
+        Self {
+            webhook_id: webhook.webhook_id,
+            url: webhook.url,
+            event_filter: webhook.event_filter,
+            is_enabled: webhook.is_enabled,
+            created_at: webhook.created_at,
+        }
+    }
+}
+
+impl From<WebhookDeadLetterData> for WebhookDeadLetterInfo {
+    fn from(entry: WebhookDeadLetterData) -> Self {
+        Self {
+            dead_letter_id: entry.dead_letter_id,
+            webhook_id: entry.webhook_id,
+            audit_event_id: entry.audit_event_id,
+            payload: entry.payload,
+            attempts: entry.attempts,
+            last_error: entry.last_error,
+            created_at: entry.created_at,
+        }
+    }
+}
+
+/// Creates a webhook.
+///
+/// Only Admin actors may create webhooks.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The webhook configuration
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Returns
+///
+/// The created webhook and its signing secret (shown only once).
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The URL, secret, or event filter is invalid
+/// - The database operation fails
+pub fn create_webhook(
+    persistence: &mut SqlitePersistence,
+    request: CreateWebhookRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<CreateWebhookResponse, ApiError> {
+    require_admin(authenticated_actor, "create_webhook")?;
+    validate_url(&request.url)?;
+    validate_event_filter(&request.event_filter)?;
+
+    let secret: String = match request.secret {
+        Some(secret) if secret.chars().count() < MIN_SECRET_LENGTH => {
+            return Err(ApiError::InvalidInput {
+                field: String::from("secret"),
+                message: format!("Webhook secret must be at least {MIN_SECRET_LENGTH} characters"),
+            });
+        }
+        Some(secret) => secret,
+        None => generate_secret(),
+    };
+
+    let webhook_id: i64 = persistence
+        .create_webhook(&request.url, &secret, &request.event_filter)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to create webhook: {e}"),
+        })?;
+
+    persist_webhook_audit_event(
+        persistence,
+        operator,
+        cause,
+        Action::new(
+            String::from("CreateWebhook"),
+            Some(format!("Created webhook {webhook_id} for {}", request.url)),
+        ),
+        String::from("webhook_does_not_exist"),
+        webhook_snapshot(webhook_id, &request.url, &request.event_filter, true),
+    )?;
+
+    let webhook: WebhookData = load_webhook(persistence, webhook_id)?;
+
+    Ok(CreateWebhookResponse {
+        webhook: WebhookInfo::from(webhook),
+        secret,
+    })
+}
+
+/// Lists all webhooks. Signing secrets are never included.
+///
+/// Only Admin actors may list webhooks.
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin or the query fails.
+pub fn list_webhooks(
+    persistence: &mut SqlitePersistence,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<ListWebhooksResponse, ApiError> {
+    require_admin(authenticated_actor, "list_webhooks")?;
+
+    let webhooks: Vec<WebhookInfo> = persistence
+        .list_webhooks()
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list webhooks: {e}"),
+        })?
+        .into_iter()
+        .map(WebhookInfo::from)
+        .collect();
+
+    Ok(ListWebhooksResponse { webhooks })
+}
+
+/// Updates a webhook's URL, event filter, and enabled flag.
+///
+/// The signing secret is unchanged.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The new configuration
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The webhook does not exist
+/// - The URL or event filter is invalid
+/// - The database operation fails
+pub fn update_webhook(
+    persistence: &mut SqlitePersistence,
+    request: &UpdateWebhookRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<UpdateWebhookResponse, ApiError> {
+    require_admin(authenticated_actor, "update_webhook")?;
+    validate_url(&request.url)?;
+    validate_event_filter(&request.event_filter)?;
+
+    let existing: WebhookData = load_webhook(persistence, request.webhook_id)?;
+
+    persistence
+        .update_webhook(
+            request.webhook_id,
+            &request.url,
+            &request.event_filter,
+            request.is_enabled,
+        )
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to update webhook: {e}"),
+        })?;
+
+    persist_webhook_audit_event(
+        persistence,
+        operator,
+        cause,
+        Action::new(
+            String::from("UpdateWebhook"),
+            Some(format!("Updated webhook {}", request.webhook_id)),
+        ),
+        webhook_snapshot(
+            existing.webhook_id,
+            &existing.url,
+            &existing.event_filter,
+            existing.is_enabled,
+        ),
+        webhook_snapshot(
+            request.webhook_id,
+            &request.url,
+            &request.event_filter,
+            request.is_enabled,
+        ),
+    )?;
+
+    let webhook: WebhookData = load_webhook(persistence, request.webhook_id)?;
+
+    Ok(UpdateWebhookResponse {
+        webhook: WebhookInfo::from(webhook),
+    })
+}
+
+/// Deletes a webhook and its dead-letter log.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `request` - The webhook to delete
+/// * `authenticated_actor` - The authenticated actor performing this action
+/// * `operator` - The operator data for audit attribution
+/// * `cause` - The cause for this action
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The actor is not an Admin
+/// - The webhook does not exist
+/// - The database operation fails
+pub fn delete_webhook(
+    persistence: &mut SqlitePersistence,
+    request: DeleteWebhookRequest,
+    authenticated_actor: &AuthenticatedActor,
+    operator: &OperatorData,
+    cause: Cause,
+) -> Result<DeleteWebhookResponse, ApiError> {
+    require_admin(authenticated_actor, "delete_webhook")?;
+
+    let existing: WebhookData = load_webhook(persistence, request.webhook_id)?;
+
+    persistence
+        .delete_webhook(request.webhook_id)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to delete webhook: {e}"),
+        })?;
+
+    persist_webhook_audit_event(
+        persistence,
+        operator,
+        cause,
+        Action::new(
+            String::from("DeleteWebhook"),
+            Some(format!("Deleted webhook {}", request.webhook_id)),
+        ),
+        webhook_snapshot(
+            existing.webhook_id,
+            &existing.url,
+            &existing.event_filter,
+            existing.is_enabled,
+        ),
+        String::from("webhook_deleted"),
+    )?;
+
+    Ok(DeleteWebhookResponse {
+        message: format!("Webhook {} deleted", request.webhook_id),
+    })
+}
+
+/// Lists dead-lettered webhook deliveries, newest first.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `webhook_id` - Restrict to one webhook, or `None` for all
+/// * `limit` - Maximum entries to return (default 100, max 1000)
+/// * `authenticated_actor` - The authenticated actor performing this action
+///
+/// # Errors
+///
+/// Returns an error if the actor is not an Admin, the limit is out of
+/// range, or the query fails.
+pub fn list_webhook_dead_letters(
+    persistence: &mut SqlitePersistence,
+    webhook_id: Option<i64>,
+    limit: Option<u32>,
+    authenticated_actor: &AuthenticatedActor,
+) -> Result<ListWebhookDeadLettersResponse, ApiError> {
+    require_admin(authenticated_actor, "list_webhook_dead_letters")?;
+
+    let limit: u32 = limit.unwrap_or(DEFAULT_DEAD_LETTER_LIMIT);
+    if limit == 0 || limit > MAX_DEAD_LETTER_LIMIT {
+        return Err(ApiError::InvalidInput {
+            field: String::from("limit"),
+            message: format!("limit must be between 1 and {MAX_DEAD_LETTER_LIMIT}"),
+        });
+    }
+
+    let dead_letters: Vec<WebhookDeadLetterInfo> = persistence
+        .list_webhook_dead_letters(webhook_id, limit)
+        .map_err(|e| ApiError::Internal {
+            message: format!("Failed to list webhook dead letters: {e}"),
+        })?
+        .into_iter()
+        .map(WebhookDeadLetterInfo::from)
+        .collect();
+
+    Ok(ListWebhookDeadLettersResponse { dead_letters })
+}
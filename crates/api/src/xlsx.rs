@@ -0,0 +1,281 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Minimal XLSX writer for tabular reports.
+//!
+//! Produces a single-sheet Office Open XML workbook packaged as an
+//! uncompressed (stored) ZIP archive. Strings are written inline, so no
+//! shared string table is needed. The first row is styled bold and frozen
+//! as a header.
+
+use std::fmt::Write;
+
+/// One worksheet cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    /// A text cell.
+    Text(String),
+    /// A whole-number cell.
+    Number(i64),
+    /// A cell with no value.
+    Empty,
+}
+
+/// CRC-32 (IEEE) lookup table used by the ZIP container.
+const CRC_TABLE: [u32; 256] = {
+    let mut table: [u32; 256] = [0; 256];
+    let mut n: u32 = 0;
+    while n < 256 {
+        let mut c: u32 = n;
+        let mut k: u32 = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n as usize] = c;
+        n += 1;
+    }
+    table
+};
+
+/// Computes the CRC-32 checksum of `data`.
+#[must_use]
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for &byte in data {
+        let index: usize = usize::try_from((crc ^ u32::from(byte)) & 0xFF).unwrap_or(0);
+        crc = CRC_TABLE[index] ^ (crc >> 8);
+    }
+    !crc
+}
+
+/// Returns the spreadsheet column name for a zero-based index (`A`, `B`, ..., `AA`).
+fn column_name(index: usize) -> String {
+    let mut name: Vec<char> = Vec::new();
+    let mut remaining: usize = index + 1;
+    while remaining > 0 {
+        let offset: u8 = u8::try_from((remaining - 1) % 26).unwrap_or(0);
+        name.push(char::from(b'A' + offset));
+        remaining = (remaining - 1) / 26;
+    }
+    name.iter().rev().collect()
+}
+
+/// Escapes text for XML, dropping characters XML cannot represent.
+fn escape_xml(text: &str) -> String {
+    let mut escaped: String = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Reduces `name` to a valid worksheet name (at most 31 characters, no `[]:*?/\`).
+fn sheet_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !matches!(c, '[' | ']' | ':' | '*' | '?' | '/' | '\\'))
+        .take(31)
+        .collect();
+    if cleaned.trim().is_empty() {
+        String::from("Sheet1")
+    } else {
+        cleaned
+    }
+}
+
+/// Builds the worksheet XML.
+fn worksheet_xml(header: &[&str], rows: &[Vec<Cell>]) -> String {
+    let mut xml: String = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+         <worksheet xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\">\
+         <sheetViews><sheetView workbookViewId=\"0\">\
+         <pane ySplit=\"1\" topLeftCell=\"A2\" activePane=\"bottomLeft\" state=\"frozen\"/>\
+         </sheetView></sheetViews><sheetData>",
+    );
+
+    xml.push_str("<row r=\"1\">");
+    for (col, heading) in header.iter().enumerate() {
+        let _ = write!(
+            xml,
+            "<c r=\"{}1\" t=\"inlineStr\" s=\"1\"><is><t>{}</t></is></c>",
+            column_name(col),
+            escape_xml(heading)
+        );
+    }
+    xml.push_str("</row>");
+
+    for (i, row) in rows.iter().enumerate() {
+        let row_number: usize = i + 2;
+        let _ = write!(xml, "<row r=\"{row_number}\">");
+        for (col, cell) in row.iter().enumerate() {
+            let reference: String = format!("{}{row_number}", column_name(col));
+            match cell {
+                Cell::Text(text) => {
+                    let _ = write!(
+                        xml,
+                        "<c r=\"{reference}\" t=\"inlineStr\"><is><t xml:space=\"preserve\">{}</t></is></c>",
+                        escape_xml(text)
+                    );
+                }
+                Cell::Number(value) => {
+                    let _ = write!(xml, "<c r=\"{reference}\"><v>{value}</v></c>");
+                }
+                Cell::Empty => {}
+            }
+        }
+        xml.push_str("</row>");
+    }
+
+    xml.push_str("</sheetData></worksheet>");
+    xml
+}
+
+/// Packages `files` as a stored (uncompressed) ZIP archive.
+fn zip(files: &[(&str, Vec<u8>)]) -> Vec<u8> {
+    // DOS date for 1980-01-01, the earliest representable date.
+    const DOS_DATE: u16 = 0x0021;
+
+    let mut archive: Vec<u8> = Vec::new();
+    let mut central_directory: Vec<u8> = Vec::new();
+
+    for (name, data) in files {
+        let offset: u32 = u32::try_from(archive.len()).unwrap_or(u32::MAX);
+        let crc: u32 = crc32(data);
+        let size: u32 = u32::try_from(data.len()).unwrap_or(u32::MAX);
+        let name_length: u16 = u16::try_from(name.len()).unwrap_or(u16::MAX);
+
+        archive.extend_from_slice(&0x0403_4B50_u32.to_le_bytes());
+        archive.extend_from_slice(&20_u16.to_le_bytes()); // version needed
+        archive.extend_from_slice(&0_u16.to_le_bytes()); // flags
+        archive.extend_from_slice(&0_u16.to_le_bytes()); // method: stored
+        archive.extend_from_slice(&0_u16.to_le_bytes()); // time
+        archive.extend_from_slice(&DOS_DATE.to_le_bytes());
+        archive.extend_from_slice(&crc.to_le_bytes());
+        archive.extend_from_slice(&size.to_le_bytes()); // compressed size
+        archive.extend_from_slice(&size.to_le_bytes()); // uncompressed size
+        archive.extend_from_slice(&name_length.to_le_bytes());
+        archive.extend_from_slice(&0_u16.to_le_bytes()); // extra field length
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(data);
+
+        central_directory.extend_from_slice(&0x0201_4B50_u32.to_le_bytes());
+        central_directory.extend_from_slice(&20_u16.to_le_bytes()); // version made by
+        central_directory.extend_from_slice(&20_u16.to_le_bytes()); // version needed
+        central_directory.extend_from_slice(&0_u16.to_le_bytes()); // flags
+        central_directory.extend_from_slice(&0_u16.to_le_bytes()); // method: stored
+        central_directory.extend_from_slice(&0_u16.to_le_bytes()); // time
+        central_directory.extend_from_slice(&DOS_DATE.to_le_bytes());
+        central_directory.extend_from_slice(&crc.to_le_bytes());
+        central_directory.extend_from_slice(&size.to_le_bytes());
+        central_directory.extend_from_slice(&size.to_le_bytes());
+        central_directory.extend_from_slice(&name_length.to_le_bytes());
+        central_directory.extend_from_slice(&0_u16.to_le_bytes()); // extra field length
+        central_directory.extend_from_slice(&0_u16.to_le_bytes()); // comment length
+        central_directory.extend_from_slice(&0_u16.to_le_bytes()); // disk number
+        central_directory.extend_from_slice(&0_u16.to_le_bytes()); // internal attributes
+        central_directory.extend_from_slice(&0_u32.to_le_bytes()); // external attributes
+        central_directory.extend_from_slice(&offset.to_le_bytes());
+        central_directory.extend_from_slice(name.as_bytes());
+    }
+
+    let directory_offset: u32 = u32::try_from(archive.len()).unwrap_or(u32::MAX);
+    let directory_size: u32 = u32::try_from(central_directory.len()).unwrap_or(u32::MAX);
+    let entries: u16 = u16::try_from(files.len()).unwrap_or(u16::MAX);
+    archive.extend_from_slice(&central_directory);
+    archive.extend_from_slice(&0x0605_4B50_u32.to_le_bytes());
+    archive.extend_from_slice(&0_u16.to_le_bytes()); // this disk
+    archive.extend_from_slice(&0_u16.to_le_bytes()); // directory disk
+    archive.extend_from_slice(&entries.to_le_bytes());
+    archive.extend_from_slice(&entries.to_le_bytes());
+    archive.extend_from_slice(&directory_size.to_le_bytes());
+    archive.extend_from_slice(&directory_offset.to_le_bytes());
+    archive.extend_from_slice(&0_u16.to_le_bytes()); // comment length
+    archive
+}
+
+/// Renders a single-sheet workbook.
+///
+/// # Arguments
+///
+/// * `name` - The worksheet name; invalid characters are removed
+/// * `header` - The column headings, written bold in the first row
+/// * `rows` - The data rows, in order
+#[must_use]
+pub fn render(name: &str, header: &[&str], rows: &[Vec<Cell>]) -> Vec<u8> {
+    let workbook: String = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+         <workbook xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\" \
+         xmlns:r=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\">\
+         <sheets><sheet name=\"{}\" sheetId=\"1\" r:id=\"rId1\"/></sheets></workbook>",
+        escape_xml(&sheet_name(name))
+    );
+
+    let files: [(&str, Vec<u8>); 6] = [
+        (
+            "[Content_Types].xml",
+            b"<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+              <Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">\
+              <Default Extension=\"rels\" ContentType=\"application/vnd.openxmlformats-package.relationships+xml\"/>\
+              <Default Extension=\"xml\" ContentType=\"application/xml\"/>\
+              <Override PartName=\"/xl/workbook.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml\"/>\
+              <Override PartName=\"/xl/worksheets/sheet1.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml\"/>\
+              <Override PartName=\"/xl/styles.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml\"/>\
+              </Types>"
+                .to_vec(),
+        ),
+        (
+            "_rels/.rels",
+            b"<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+              <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+              <Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument\" Target=\"xl/workbook.xml\"/>\
+              </Relationships>"
+                .to_vec(),
+        ),
+        ("xl/workbook.xml", workbook.into_bytes()),
+        (
+            "xl/_rels/workbook.xml.rels",
+            b"<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+              <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+              <Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet\" Target=\"worksheets/sheet1.xml\"/>\
+              <Relationship Id=\"rId2\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles\" Target=\"styles.xml\"/>\
+              </Relationships>"
+                .to_vec(),
+        ),
+        (
+            "xl/styles.xml",
+            b"<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+              <styleSheet xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\">\
+              <fonts count=\"2\"><font><sz val=\"11\"/><name val=\"Calibri\"/></font>\
+              <font><b/><sz val=\"11\"/><name val=\"Calibri\"/></font></fonts>\
+              <fills count=\"2\"><fill><patternFill patternType=\"none\"/></fill>\
+              <fill><patternFill patternType=\"gray125\"/></fill></fills>\
+              <borders count=\"1\"><border><left/><right/><top/><bottom/><diagonal/></border></borders>\
+              <cellStyleXfs count=\"1\"><xf numFmtId=\"0\" fontId=\"0\" fillId=\"0\" borderId=\"0\"/></cellStyleXfs>\
+              <cellXfs count=\"2\"><xf numFmtId=\"0\" fontId=\"0\" fillId=\"0\" borderId=\"0\" xfId=\"0\"/>\
+              <xf numFmtId=\"0\" fontId=\"1\" fillId=\"0\" borderId=\"0\" xfId=\"0\" applyFont=\"1\"/></cellXfs>\
+              </styleSheet>"
+                .to_vec(),
+        ),
+        (
+            "xl/worksheets/sheet1.xml",
+            worksheet_xml(header, rows).into_bytes(),
+        ),
+    ];
+
+    zip(&files)
+}
@@ -20,6 +20,7 @@
 #[cfg(test)]
 mod tests;
 
+pub use ulid::Ulid;
 use zab_bid_domain::{Area, BidYear};
 
 /// Represents the entity performing an action.
@@ -170,6 +171,8 @@ impl StateSnapshot {
 /// - The bid year scope (`bid_year`) - optional for global events like operator management
 /// - The area scope (`area`) - optional for global events like operator management
 /// - An optional event ID assigned by persistence (`event_id`)
+/// - An optional globally unique ULID assigned by persistence (`event_ulid`)
+/// - The user the event concerns, if any (`user_id`)
 ///
 /// Phase 23B: `bid_year` and `area` are now optional to support operator-management
 /// and other global audit events that are not scoped to a specific bid year or area.
@@ -178,6 +181,11 @@ pub struct AuditEvent {
     /// Optional event ID assigned when persisted.
     /// None when created in-memory, Some(id) after persistence.
     pub event_id: Option<i64>,
+    /// Globally unique identifier, assigned when the event is persisted.
+    ///
+    /// Unlike `event_id`, it is the same in every database the event is
+    /// exported to. None for events recorded before ULIDs existed.
+    pub event_ulid: Option<Ulid>,
     /// The actor who initiated this state change.
     pub actor: Actor,
     /// The cause or reason for this state change.
@@ -194,6 +202,12 @@ pub struct AuditEvent {
     /// The area this event is scoped to.
     /// None for global events or bid-year-only events.
     pub area: Option<Area>,
+    /// The canonical ID of the user this event concerns.
+    ///
+    /// Recorded by ID rather than initials so the event stays attached to
+    /// the user through initials changes. None for events that do not
+    /// concern a single user, or whose user is not persisted yet.
+    pub user_id: Option<i64>,
 }
 
 impl AuditEvent {
@@ -224,6 +238,7 @@ impl AuditEvent {
     ) -> Self {
         Self {
             event_id: None,
+            event_ulid: None,
             actor,
             cause,
             action,
@@ -231,6 +246,7 @@ impl AuditEvent {
             after,
             bid_year: Some(bid_year),
             area: Some(area),
+            user_id: None,
         }
     }
 
@@ -255,6 +271,7 @@ impl AuditEvent {
     ) -> Self {
         Self {
             event_id: None,
+            event_ulid: None,
             actor,
             cause,
             action,
@@ -262,6 +279,7 @@ impl AuditEvent {
             after,
             bid_year: None,
             area: None,
+            user_id: None,
         }
     }
 
@@ -293,6 +311,7 @@ impl AuditEvent {
     ) -> Self {
         Self {
             event_id: Some(event_id),
+            event_ulid: None,
             actor,
             cause,
             action,
@@ -300,6 +319,29 @@ impl AuditEvent {
             after,
             bid_year: Some(bid_year),
             area: Some(area),
+            user_id: None,
         }
     }
+
+    /// Returns this event with its globally unique identifier set.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_ulid` - The event's ULID, or None if it has none
+    #[must_use]
+    pub const fn with_ulid(mut self, event_ulid: Option<Ulid>) -> Self {
+        self.event_ulid = event_ulid;
+        self
+    }
+
+    /// Returns this event attributed to the user it concerns.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The canonical ID of the user, or None if there is none
+    #[must_use]
+    pub const fn with_user_id(mut self, user_id: Option<i64>) -> Self {
+        self.user_id = user_id;
+        self
+    }
 }
@@ -0,0 +1,353 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! The guided bid-entry form of the live bidding screen.
+//!
+//! The form is validated on every keystroke against the current bidder and
+//! the slot inventory on screen, so the area rep sees problems before the
+//! bid is sent. The server still makes the final decision.
+
+use std::collections::BTreeSet;
+
+use time::Date;
+use time::macros::format_description;
+use zab_bid_api::{CurrentBidderInfo, SlotInventoryDayInfo};
+use zab_bid_domain::BidReceiptMethod;
+
+/// Leave hours charged per day when the form opens.
+const DEFAULT_HOURS: &str = "8";
+
+/// The form field that receives keystrokes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Initials,
+    Dates,
+    Hours,
+    ReceivedVia,
+}
+
+impl Field {
+    /// Returns the field after this one, wrapping around.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Initials => Self::Dates,
+            Self::Dates => Self::Hours,
+            Self::Hours => Self::ReceivedVia,
+            Self::ReceivedVia => Self::Initials,
+        }
+    }
+
+    /// Returns the field before this one, wrapping around.
+    #[must_use]
+    pub const fn previous(self) -> Self {
+        match self {
+            Self::Initials => Self::ReceivedVia,
+            Self::Dates => Self::Initials,
+            Self::Hours => Self::Dates,
+            Self::ReceivedVia => Self::Hours,
+        }
+    }
+}
+
+/// A bid that passed client-side validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidBid {
+    /// The initials of the controller the bid is entered for.
+    pub on_behalf_of: String,
+    /// The leave days bid (`YYYY-MM-DD`), in date order.
+    pub leave_dates: Vec<String>,
+    /// The leave hours charged per day.
+    pub hours: u32,
+    /// How the bid was received.
+    pub received_via: BidReceiptMethod,
+}
+
+/// The bid-entry form.
+#[derive(Debug, Clone)]
+pub struct BidForm {
+    /// The controller's initials.
+    pub initials: String,
+    /// Leave dates separated by commas or spaces.
+    pub dates: String,
+    /// Leave hours charged per day.
+    pub hours: String,
+    /// How the bid was received.
+    pub received_via: BidReceiptMethod,
+    /// The focused field.
+    pub focus: Field,
+}
+
+impl Default for BidForm {
+    fn default() -> Self {
+        Self {
+            initials: String::new(),
+            dates: String::new(),
+            hours: String::from(DEFAULT_HOURS),
+            received_via: BidReceiptMethod::Phone,
+            focus: Field::Dates,
+        }
+    }
+}
+
+impl BidForm {
+    /// Types a character into the focused field.
+    ///
+    /// Characters a field cannot hold are ignored. On the receipt method
+    /// field, space cycles to the next method.
+    pub fn input(&mut self, c: char) {
+        match self.focus {
+            Field::Initials if c.is_ascii_alphabetic() => {
+                self.initials.push(c.to_ascii_uppercase());
+            }
+            Field::Dates if c.is_ascii_digit() || matches!(c, '-' | ',' | ' ') => {
+                self.dates.push(c);
+            }
+            Field::Hours if c.is_ascii_digit() => self.hours.push(c),
+            Field::ReceivedVia if c == ' ' => self.cycle_received_via(),
+            _ => {}
+        }
+    }
+
+    /// Deletes the last character of the focused field.
+    pub fn backspace(&mut self) {
+        match self.focus {
+            Field::Initials => {
+                self.initials.pop();
+            }
+            Field::Dates => {
+                self.dates.pop();
+            }
+            Field::Hours => {
+                self.hours.pop();
+            }
+            Field::ReceivedVia => {}
+        }
+    }
+
+    /// Switches to the next receipt method.
+    pub const fn cycle_received_via(&mut self) {
+        self.received_via = match self.received_via {
+            BidReceiptMethod::Phone => BidReceiptMethod::InPerson,
+            BidReceiptMethod::InPerson => BidReceiptMethod::Written,
+            BidReceiptMethod::Written => BidReceiptMethod::Phone,
+        };
+    }
+
+    /// Fills in a new current bidder's initials.
+    ///
+    /// The initials are only replaced when the field is empty or still holds
+    /// the previous bidder's initials, so a rep's own edit is never lost.
+    pub fn follow_bidder(&mut self, previous: Option<&str>, current: &str) {
+        if self.initials.is_empty() || previous == Some(self.initials.as_str()) {
+            self.initials = current.to_string();
+        }
+    }
+
+    /// Clears the dates after a bid was entered.
+    pub fn clear_dates(&mut self) {
+        self.dates.clear();
+        self.focus = Field::Dates;
+    }
+
+    /// Returns the dates typed so far that parse, in date order.
+    #[must_use]
+    pub fn parsed_dates(&self) -> BTreeSet<Date> {
+        date_tokens(&self.dates).filter_map(parse_date).collect()
+    }
+
+    /// Validates the form against the current bidder and slot inventory.
+    ///
+    /// # Errors
+    ///
+    /// Returns every problem found, in field order.
+    pub fn validate(
+        &self,
+        current_bidder: Option<&CurrentBidderInfo>,
+        days: &[SlotInventoryDayInfo],
+    ) -> Result<ValidBid, Vec<String>> {
+        let mut errors: Vec<String> = Vec::new();
+
+        match current_bidder {
+            None => errors.push(String::from("No round is in progress")),
+            Some(_) if self.initials.is_empty() => {
+                errors.push(String::from("Enter the controller's initials"));
+            }
+            Some(bidder) if bidder.initials != self.initials => errors.push(format!(
+                "{} is not the current bidder ({})",
+                self.initials, bidder.initials
+            )),
+            Some(_) => {}
+        }
+
+        let mut dates: BTreeSet<Date> = BTreeSet::new();
+        for token in date_tokens(&self.dates) {
+            match parse_date(token) {
+                Some(date) if !dates.insert(date) => {
+                    errors.push(format!("{token} is entered more than once"));
+                }
+                Some(date) => {
+                    let date: String = format_date(date);
+                    if let Some(day) = days.iter().find(|day| day.date == date)
+                        && day.remaining == 0
+                    {
+                        errors.push(format!("No slots remain on {date}"));
+                    }
+                }
+                None => errors.push(format!("{token} is not a YYYY-MM-DD date")),
+            }
+        }
+        if date_tokens(&self.dates).next().is_none() {
+            errors.push(String::from("Enter at least one leave date"));
+        }
+
+        let hours: u32 = match self.hours.parse::<u32>() {
+            Ok(hours) if hours > 0 => hours,
+            _ => {
+                errors.push(String::from("Hours per day must be a positive number"));
+                0
+            }
+        };
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(ValidBid {
+            on_behalf_of: self.initials.clone(),
+            leave_dates: dates.into_iter().map(format_date).collect(),
+            hours,
+            received_via: self.received_via,
+        })
+    }
+}
+
+/// Splits the dates field into its entries.
+fn date_tokens(dates: &str) -> impl Iterator<Item = &str> {
+    dates
+        .split([',', ' '])
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+}
+
+fn parse_date(token: &str) -> Option<Date> {
+    Date::parse(token, format_description!("[year]-[month]-[day]")).ok()
+}
+
+/// Formats a date as `YYYY-MM-DD`, matching the slot inventory.
+#[must_use]
+pub fn format_date(date: Date) -> String {
+    date.format(format_description!("[year]-[month]-[day]"))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn bidder(initials: &str) -> CurrentBidderInfo {
+        CurrentBidderInfo {
+            bid_year_id: 1,
+            area_id: 1,
+            round_id: 1,
+            round_number: 1,
+            round_name: String::from("Round 1"),
+            user_id: 1,
+            initials: initials.to_string(),
+            window_start_datetime: None,
+            window_end_datetime: None,
+            became_current_at: String::from("2026-03-02T08:00:00Z"),
+        }
+    }
+
+    fn day(date: &str, remaining: u32) -> SlotInventoryDayInfo {
+        SlotInventoryDayInfo {
+            date: date.to_string(),
+            slots_per_day: 2,
+            prime: false,
+            prime_slots_per_day: None,
+            staffing_adjustment: 0,
+            capacity: 2,
+            used: 2 - remaining,
+            remaining,
+        }
+    }
+
+    fn form(initials: &str, dates: &str) -> BidForm {
+        BidForm {
+            initials: initials.to_string(),
+            dates: dates.to_string(),
+            ..BidForm::default()
+        }
+    }
+
+    #[test]
+    fn test_valid_bid_is_sorted_and_deduplicated_by_date() {
+        let bid: ValidBid = form("AB", "2026-07-02, 2026-07-01")
+            .validate(Some(&bidder("AB")), &[day("2026-07-01", 1)])
+            .unwrap();
+        assert_eq!(bid.leave_dates, vec!["2026-07-01", "2026-07-02"]);
+        assert_eq!(bid.hours, 8);
+        assert_eq!(bid.received_via, BidReceiptMethod::Phone);
+    }
+
+    #[test]
+    fn test_full_day_and_wrong_bidder_are_reported() {
+        let errors: Vec<String> = form("CD", "2026-07-01")
+            .validate(Some(&bidder("AB")), &[day("2026-07-01", 0)])
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                String::from("CD is not the current bidder (AB)"),
+                String::from("No slots remain on 2026-07-01"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed_and_duplicate_dates_are_reported() {
+        let errors: Vec<String> = form("AB", "2026-07-01 2026-7 2026-07-01")
+            .validate(Some(&bidder("AB")), &[])
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                String::from("2026-7 is not a YYYY-MM-DD date"),
+                String::from("2026-07-01 is entered more than once"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_round_and_empty_form_are_reported() {
+        let mut empty: BidForm = form("", "");
+        empty.hours.clear();
+        let errors: Vec<String> = empty.validate(None, &[]).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                String::from("No round is in progress"),
+                String::from("Enter at least one leave date"),
+                String::from("Hours per day must be a positive number"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_initials_follow_bidder_unless_edited() {
+        let mut form: BidForm = BidForm::default();
+        form.follow_bidder(None, "AB");
+        assert_eq!(form.initials, "AB");
+
+        form.follow_bidder(Some("AB"), "CD");
+        assert_eq!(form.initials, "CD");
+
+        form.initials = String::from("EF");
+        form.follow_bidder(Some("CD"), "GH");
+        assert_eq!(form.initials, "EF");
+    }
+}
@@ -0,0 +1,374 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Direct database access.
+//!
+//! Commands run in-process against a `Persistence` handle, acting as a named
+//! operator. Each method mirrors the matching server handler without the
+//! HTTP layer, so the same authorization rules apply and the same audit
+//! events are recorded.
+
+use std::path::Path;
+
+use color_eyre::{
+    Result,
+    eyre::{bail, eyre},
+};
+use serde_json::Value;
+use tracing::info;
+use zab_bid::{BootstrapMetadata, BootstrapResult, State};
+use zab_bid_api::{
+    AuditTimelineFilter, AuditTimelinePageRequest, AuditTimelineScope, AuthenticatedActor,
+    CreateAreaRequest, CreateBidYearRequest, CreateOperatorRequest, CsvRowStatus,
+    DisableOperatorRequest, EnableOperatorRequest, ImportCsvUsersRequest, ListUsersResponse,
+    PreviewCsvUsersRequest, PreviewCsvUsersResponse, Role, SetBidScheduleRequest, create_area,
+    create_bid_year, create_operator, disable_operator, enable_operator, get_audit_timeline,
+    get_bid_schedule, get_bid_year_readiness, import_csv_users, list_operators, list_users,
+    load_accrual_rates, preview_csv_users, set_bid_schedule,
+};
+use zab_bid_audit::Cause;
+use zab_bid_domain::{Area, BidYear, BidYearLifecycle, CanonicalBidYear};
+use zab_bid_persistence::{BidYearBundle, OperatorData, Persistence};
+
+/// A persistence handle paired with the operator the commands act as.
+pub struct DirectSession {
+    persistence: Persistence,
+    actor: AuthenticatedActor,
+    operator: OperatorData,
+}
+
+impl DirectSession {
+    /// Opens a session acting as the operator with the given login name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the operator does not exist, is disabled, or has
+    /// an unknown role.
+    pub fn open(mut persistence: Persistence, login_name: &str) -> Result<Self> {
+        let operator: OperatorData = persistence
+            .get_operator_by_login(login_name)?
+            .ok_or_else(|| eyre!("Operator '{login_name}' not found"))?;
+
+        if operator.is_disabled {
+            bail!("Operator '{login_name}' is disabled");
+        }
+
+        let role: Role = match operator.role.as_str() {
+            "Admin" => Role::Admin,
+            "Bidder" => Role::Bidder,
+            other => bail!("Operator '{login_name}' has unknown role '{other}'"),
+        };
+        let actor: AuthenticatedActor = AuthenticatedActor::new(operator.login_name.clone(), role);
+
+        Ok(Self {
+            persistence,
+            actor,
+            operator,
+        })
+    }
+
+    /// Creates a bid year together with its No Bid system area.
+    pub fn create_bid_year(
+        &mut self,
+        year: u16,
+        start_date: time::Date,
+        num_pay_periods: u8,
+        cause: Cause,
+    ) -> Result<Value> {
+        let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+        let request: CreateBidYearRequest = CreateBidYearRequest {
+            year,
+            start_date,
+            num_pay_periods,
+        };
+        let result: BootstrapResult =
+            create_bid_year(&metadata, &request, &self.actor, &self.operator, cause)?;
+        let event_id: i64 = self.persistence.persist_bootstrap(&result)?;
+
+        let bid_year_id: i64 = self
+            .persistence
+            .get_bootstrap_metadata()?
+            .bid_years
+            .iter()
+            .find(|by| by.year() == year)
+            .and_then(BidYear::bid_year_id)
+            .ok_or_else(|| eyre!("Failed to retrieve bid_year_id for year {year}"))?;
+        let no_bid_area_id: i64 = self
+            .persistence
+            .create_system_area(bid_year_id, Area::NO_BID_AREA_CODE)?;
+
+        info!(event_id, bid_year_id, no_bid_area_id, "Created bid year");
+
+        Ok(serde_json::json!({
+            "bid_year_id": bid_year_id,
+            "year": year,
+            "event_id": event_id,
+        }))
+    }
+
+    /// Creates an area in the active bid year.
+    pub fn create_area(&mut self, area_code: &str, cause: Cause) -> Result<Value> {
+        let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+        let request: CreateAreaRequest = CreateAreaRequest {
+            area_id: area_code.to_string(),
+        };
+        let result: BootstrapResult = create_area(
+            &mut self.persistence,
+            &metadata,
+            &request,
+            &self.actor,
+            &self.operator,
+            cause,
+        )?;
+        let event_id: i64 = self.persistence.persist_bootstrap(&result)?;
+
+        info!(event_id, area_code, "Created area");
+
+        Ok(serde_json::json!({
+            "area_code": area_code.to_uppercase(),
+            "event_id": event_id,
+        }))
+    }
+
+    /// Lists the users in an area.
+    pub fn list_users(&mut self, area_id: i64) -> Result<ListUsersResponse> {
+        let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+        let (bid_year, area): (BidYear, Area) = metadata
+            .areas
+            .iter()
+            .find(|(_, a)| a.area_id() == Some(area_id))
+            .map(|(by, a)| (by.clone(), a.clone()))
+            .ok_or_else(|| eyre!("Area with ID {area_id} not found"))?;
+        let bid_year_id: i64 = bid_year
+            .bid_year_id()
+            .ok_or_else(|| eyre!("Bid year {} has no ID", bid_year.year()))?;
+
+        let lifecycle_state: BidYearLifecycle = self
+            .persistence
+            .get_lifecycle_state(bid_year_id)?
+            .parse()
+            .map_err(|e| eyre!("Failed to parse lifecycle state: {e}"))?;
+        let canonical_bid_years: Vec<CanonicalBidYear> = self.persistence.list_bid_years()?;
+        let state: State = self
+            .persistence
+            .get_current_state(&bid_year, &area)
+            .unwrap_or_else(|_| State::new(bid_year.clone(), area.clone()));
+
+        Ok(list_users(
+            &metadata,
+            &canonical_bid_years,
+            &bid_year,
+            &area,
+            &state,
+            &self.actor,
+            &self.operator,
+            lifecycle_state,
+            load_accrual_rates(&mut self.persistence)?,
+        )?)
+    }
+
+    /// Imports every valid row of a roster CSV into the active bid year.
+    pub fn import_roster(&mut self, csv_content: String, cause: &Cause) -> Result<Value> {
+        let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+        let preview: PreviewCsvUsersResponse = preview_csv_users(
+            &metadata,
+            &mut self.persistence,
+            &PreviewCsvUsersRequest {
+                csv_content: csv_content.clone(),
+            },
+            &self.actor,
+        )?;
+
+        let active_year: u16 = self.persistence.get_active_bid_year()?;
+        let bid_year: BidYear = metadata
+            .bid_years
+            .iter()
+            .find(|by| by.year() == active_year)
+            .cloned()
+            .ok_or_else(|| eyre!("Active year {active_year} not found"))?;
+        let state: State = metadata.areas.first().map_or_else(
+            || State::new(bid_year.clone(), Area::new("DUMMY")),
+            |(by, area)| {
+                self.persistence
+                    .get_current_state(by, area)
+                    .unwrap_or_else(|_| State::new(by.clone(), area.clone()))
+            },
+        );
+
+        let request: ImportCsvUsersRequest = ImportCsvUsersRequest {
+            csv_content,
+            selected_row_indices: valid_row_indices(&preview),
+        };
+        let response = import_csv_users(
+            &metadata,
+            &state,
+            &mut self.persistence,
+            &request,
+            &self.actor,
+            &self.operator,
+            cause,
+        )?;
+
+        Ok(serde_json::to_value(response)?)
+    }
+
+    /// Lists all operators.
+    pub fn list_operators(&mut self) -> Result<Value> {
+        let response = list_operators(&mut self.persistence, &self.actor, &self.operator)?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    /// Creates an operator.
+    pub fn create_operator(
+        &mut self,
+        request: CreateOperatorRequest,
+        cause: Cause,
+    ) -> Result<Value> {
+        let response = create_operator(
+            &mut self.persistence,
+            request,
+            &self.actor,
+            &self.operator,
+            cause,
+        )?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    /// Disables an operator.
+    pub fn disable_operator(&mut self, operator_id: i64, cause: Cause) -> Result<Value> {
+        let response = disable_operator(
+            &mut self.persistence,
+            DisableOperatorRequest { operator_id },
+            &self.actor,
+            &self.operator,
+            cause,
+        )?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    /// Re-enables a disabled operator.
+    pub fn enable_operator(&mut self, operator_id: i64, cause: Cause) -> Result<Value> {
+        let response = enable_operator(
+            &mut self.persistence,
+            EnableOperatorRequest { operator_id },
+            &self.actor,
+            &self.operator,
+            cause,
+        )?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    /// Gets a bid year's bid schedule.
+    pub fn get_bid_schedule(&mut self, bid_year_id: i64) -> Result<Value> {
+        let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+        let response = get_bid_schedule(&mut self.persistence, &metadata, bid_year_id)?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    /// Sets a bid year's bid schedule.
+    pub fn set_bid_schedule(
+        &mut self,
+        request: &SetBidScheduleRequest,
+        cause: Cause,
+    ) -> Result<Value> {
+        let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+        let response = set_bid_schedule(
+            &mut self.persistence,
+            &metadata,
+            request,
+            &self.actor,
+            &self.operator,
+            cause,
+        )?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    /// Evaluates a bid year's readiness to bid.
+    pub fn get_readiness(&mut self, bid_year_id: i64) -> Result<Value> {
+        let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+        let response = get_bid_year_readiness(&mut self.persistence, &metadata, bid_year_id)?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    /// Queries one page of the audit timeline.
+    pub fn get_audit_timeline(
+        &mut self,
+        scope: AuditTimelineScope,
+        filter: &AuditTimelineFilter,
+        page: AuditTimelinePageRequest,
+    ) -> Result<Value> {
+        let response = get_audit_timeline(&mut self.persistence, scope, filter, page)?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    /// Writes a consistent copy of the database to `destination`.
+    ///
+    /// Only admins may take backups.
+    pub fn backup(&mut self, destination: &Path) -> Result<Value> {
+        if self.actor.role != Role::Admin {
+            bail!("Only admins may back up the database");
+        }
+        self.persistence.backup_to(destination)?;
+
+        info!(destination = %destination.display(), "Database backed up");
+
+        Ok(serde_json::json!({
+            "destination": destination.display().to_string(),
+        }))
+    }
+
+    /// Writes `year` as a bid year bundle to `destination`.
+    ///
+    /// Only admins may export bid years.
+    pub fn export_bid_year(&mut self, year: u16, destination: &Path) -> Result<Value> {
+        if self.actor.role != Role::Admin {
+            bail!("Only admins may export bid years");
+        }
+        let exported_at: String = time::OffsetDateTime::now_utc()
+            .format(&time::format_description::well_known::Rfc3339)?;
+        let bundle: BidYearBundle = self.persistence.export_bid_year(year, &exported_at)?;
+        std::fs::write(destination, serde_json::to_vec(&bundle)?)?;
+
+        info!(year, destination = %destination.display(), "Bid year exported");
+
+        Ok(serde_json::json!({
+            "year": year,
+            "destination": destination.display().to_string(),
+            "audit_events": bundle.audit_events.len(),
+            "snapshots": bundle.snapshots.len(),
+        }))
+    }
+
+    /// Imports the bid year bundle at `source` as a new, inactive bid year.
+    ///
+    /// Only admins may import bid years.
+    pub fn import_bid_year(&mut self, source: &Path) -> Result<Value> {
+        if self.actor.role != Role::Admin {
+            bail!("Only admins may import bid years");
+        }
+        let bundle: BidYearBundle = serde_json::from_slice(&std::fs::read(source)?)?;
+        let bid_year_id: i64 = self.persistence.import_bid_year(&bundle)?;
+
+        info!(
+            year = bundle.manifest.year,
+            bid_year_id, "Bid year imported"
+        );
+
+        Ok(serde_json::json!({
+            "year": bundle.manifest.year,
+            "bid_year_id": bid_year_id,
+        }))
+    }
+}
+
+/// Returns the 0-based indices of the rows a CSV preview found valid.
+pub fn valid_row_indices(preview: &PreviewCsvUsersResponse) -> Vec<usize> {
+    preview
+        .rows
+        .iter()
+        .filter(|row| row.status == CsvRowStatus::Valid)
+        .map(|row| row.row_number - 1)
+        .collect()
+}
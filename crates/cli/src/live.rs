@@ -0,0 +1,388 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Live bid entry screen.
+//!
+//! A terminal UI for the area rep entering bids during live bidding. It
+//! shows the current bidder, the remaining slots on each day of the round,
+//! and a bid-entry form that is validated as it is typed. The screen polls
+//! the server so it follows the round as bidders advance.
+
+use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
+
+use color_eyre::{Result, eyre::bail};
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Row, Table, Wrap},
+};
+use serde_json::Value;
+use time::Date;
+use zab_bid_api::{
+    CurrentBidderInfo, EnterLeaveBidResponse, GetCurrentBidderResponse, GetSlotInventoryResponse,
+    SlotInventoryDayInfo,
+};
+use zab_bid_audit::Cause;
+
+use crate::bid_form::{BidForm, Field, ValidBid, format_date};
+use crate::remote::RemoteClient;
+
+/// How often the screen re-reads the current bidder and slot inventory.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long to wait for a keystroke before redrawing.
+const INPUT_POLL: Duration = Duration::from_millis(250);
+
+/// The day range and area the screen follows.
+pub struct LiveOptions {
+    /// The area ID.
+    pub area_id: i64,
+    /// First day of the slot inventory shown (`YYYY-MM-DD`).
+    pub from: String,
+    /// Last day of the slot inventory shown (`YYYY-MM-DD`).
+    pub to: String,
+}
+
+/// What a keystroke asks the event loop to do.
+enum Action {
+    None,
+    Submit,
+    Refresh,
+    Quit,
+}
+
+/// The screen's state.
+struct LiveApp {
+    options: LiveOptions,
+    current_bidder: Option<CurrentBidderInfo>,
+    days: Vec<SlotInventoryDayInfo>,
+    form: BidForm,
+    scroll: usize,
+    status: Line<'static>,
+    last_refresh: Instant,
+}
+
+/// Runs the live bid entry screen until the rep quits.
+///
+/// # Errors
+///
+/// Returns an error if the terminal cannot be set up or drawn to. Server
+/// errors are shown on screen instead.
+pub async fn run(client: &RemoteClient, options: LiveOptions, cause: &Cause) -> Result<()> {
+    let mut app: LiveApp = LiveApp {
+        options,
+        current_bidder: None,
+        days: Vec::new(),
+        form: BidForm::default(),
+        scroll: 0,
+        status: Line::from("Loading..."),
+        last_refresh: Instant::now(),
+    };
+    app.refresh(client).await;
+
+    let mut terminal: DefaultTerminal = ratatui::try_init()?;
+    let result: Result<()> = event_loop(&mut terminal, &mut app, client, cause).await;
+    ratatui::restore();
+    result
+}
+
+async fn event_loop(
+    terminal: &mut DefaultTerminal,
+    app: &mut LiveApp,
+    client: &RemoteClient,
+    cause: &Cause,
+) -> Result<()> {
+    loop {
+        terminal.draw(|frame| render(frame, app))?;
+
+        if event::poll(INPUT_POLL)?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            match handle_key(app, key) {
+                Action::None => {}
+                Action::Submit => app.submit(client, cause).await,
+                Action::Refresh => app.refresh(client).await,
+                Action::Quit => return Ok(()),
+            }
+        }
+
+        if app.last_refresh.elapsed() >= REFRESH_INTERVAL {
+            app.refresh(client).await;
+        }
+    }
+}
+
+fn handle_key(app: &mut LiveApp, key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Esc => return Action::Quit,
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            return Action::Quit;
+        }
+        KeyCode::Enter => return Action::Submit,
+        KeyCode::F(5) => return Action::Refresh,
+        KeyCode::Tab | KeyCode::Down => app.form.focus = app.form.focus.next(),
+        KeyCode::BackTab | KeyCode::Up => app.form.focus = app.form.focus.previous(),
+        KeyCode::PageDown => {
+            app.scroll = (app.scroll + 10).min(app.days.len().saturating_sub(1));
+        }
+        KeyCode::PageUp => app.scroll = app.scroll.saturating_sub(10),
+        KeyCode::Backspace => app.form.backspace(),
+        KeyCode::Char(c) => app.form.input(c),
+        _ => {}
+    }
+    Action::None
+}
+
+impl LiveApp {
+    /// Re-reads the current bidder and, when a round is in progress, its
+    /// slot inventory.
+    async fn refresh(&mut self, client: &RemoteClient) {
+        self.last_refresh = Instant::now();
+        if let Err(e) = self.load(client).await {
+            self.status = Line::from(format!("Refresh failed: {e}")).red();
+        }
+    }
+
+    async fn load(&mut self, client: &RemoteClient) -> Result<()> {
+        let area_id: String = self.options.area_id.to_string();
+        let response: GetCurrentBidderResponse = serde_json::from_value(
+            client
+                .get("/current-bidder", &[("area_id", area_id)])
+                .await?,
+        )?;
+
+        let previous: Option<String> = self
+            .current_bidder
+            .as_ref()
+            .map(|bidder| bidder.initials.clone());
+        self.current_bidder = response.current_bidder;
+
+        let Some(bidder) = &self.current_bidder else {
+            self.days.clear();
+            return Ok(());
+        };
+        self.form
+            .follow_bidder(previous.as_deref(), bidder.initials.as_str());
+
+        let query: [(&str, String); 4] = [
+            ("area_id", self.options.area_id.to_string()),
+            ("round_id", bidder.round_id.to_string()),
+            ("start_date", self.options.from.clone()),
+            ("end_date", self.options.to.clone()),
+        ];
+        let inventory: GetSlotInventoryResponse =
+            serde_json::from_value(client.get("/slot-inventory", &query).await?)?;
+        self.days = inventory.days;
+        self.scroll = self.scroll.min(self.days.len().saturating_sub(1));
+
+        Ok(())
+    }
+
+    /// Sends the form to the server if it passes validation.
+    async fn submit(&mut self, client: &RemoteClient, cause: &Cause) {
+        let (bid, bidder): (ValidBid, &CurrentBidderInfo) = match (
+            self.form.validate(self.current_bidder.as_ref(), &self.days),
+            self.current_bidder.as_ref(),
+        ) {
+            (Ok(bid), Some(bidder)) => (bid, bidder),
+            (Err(errors), _) => {
+                self.status = Line::from(errors.join("; ")).red();
+                return;
+            }
+            (Ok(_), None) => return,
+        };
+
+        let body: Value = serde_json::json!({
+            "cause_id": cause.id,
+            "cause_description": cause.description,
+            "area_id": self.options.area_id,
+            "round_id": bidder.round_id,
+            "on_behalf_of": bid.on_behalf_of,
+            "received_via": bid.received_via.as_str(),
+            "leave_dates": bid.leave_dates,
+            "hours": bid.hours,
+        });
+
+        match enter_leave_bid(client, &body).await {
+            Ok(response) => {
+                self.status = Line::from(response.message).green();
+                self.form.clear_dates();
+                self.refresh(client).await;
+            }
+            Err(e) => self.status = Line::from(format!("Bid rejected: {e}")).red(),
+        }
+    }
+}
+
+async fn enter_leave_bid(client: &RemoteClient, body: &Value) -> Result<EnterLeaveBidResponse> {
+    Ok(serde_json::from_value(
+        client.post("/leave-bids", body).await?,
+    )?)
+}
+
+fn render(frame: &mut Frame, app: &LiveApp) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(8),
+        Constraint::Length(3),
+    ])
+    .areas(frame.area());
+    let [slots, form] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(body);
+
+    render_current_bidder(frame, header, app.current_bidder.as_ref());
+    render_slots(frame, slots, app);
+    render_form(frame, form, app);
+
+    let help: Line<'_> = Line::from(
+        "Tab/↑↓ field · Space change method · Enter submit · PgUp/PgDn scroll · F5 refresh · Esc quit",
+    )
+    .dim();
+    frame.render_widget(
+        Paragraph::new(vec![app.status.clone(), help]).block(Block::bordered()),
+        footer,
+    );
+}
+
+fn render_current_bidder(frame: &mut Frame, area: Rect, bidder: Option<&CurrentBidderInfo>) {
+    let line: Line<'_> = bidder.map_or_else(
+        || Line::from("No round is in progress").yellow(),
+        |bidder| {
+            Line::from(vec![
+                Span::raw("Now bidding: "),
+                Span::raw(bidder.initials.as_str()).bold().green(),
+                Span::raw(format!(
+                    "  ·  {} (round {})  ·  window {} – {}",
+                    bidder.round_name,
+                    bidder.round_number,
+                    bidder.window_start_datetime.as_deref().unwrap_or("-"),
+                    bidder.window_end_datetime.as_deref().unwrap_or("-"),
+                )),
+            ])
+        },
+    );
+    frame.render_widget(
+        Paragraph::new(line).block(Block::bordered().title("Current bidder")),
+        area,
+    );
+}
+
+fn render_slots(frame: &mut Frame, area: Rect, app: &LiveApp) {
+    let selected: BTreeSet<String> = app
+        .form
+        .parsed_dates()
+        .into_iter()
+        .map(format_date)
+        .collect();
+
+    let rows: Vec<Row<'_>> = app
+        .days
+        .iter()
+        .skip(app.scroll)
+        .map(|day| {
+            let color: Color = match day.remaining {
+                0 => Color::Red,
+                1 => Color::Yellow,
+                _ => Color::Green,
+            };
+            let mut style: Style = Style::default().fg(color);
+            if selected.contains(&day.date) {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            Row::new(vec![
+                day.date.clone(),
+                if day.prime {
+                    String::from("prime")
+                } else {
+                    String::new()
+                },
+                format!("{}/{}", day.used, day.capacity),
+                day.remaining.to_string(),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let table: Table<'_> = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(5),
+            Constraint::Length(7),
+            Constraint::Length(9),
+        ],
+    )
+    .header(Row::new(vec!["Date", "", "Used", "Remaining"]).bold())
+    .block(Block::bordered().title(format!("Slots {} – {}", app.options.from, app.options.to)));
+    frame.render_widget(table, area);
+}
+
+fn render_form(frame: &mut Frame, area: Rect, app: &LiveApp) {
+    let form: &BidForm = &app.form;
+    let field_line = |field: Field, label: &'static str, value: String| -> Line<'static> {
+        let value: Span<'static> = if form.focus == field {
+            Span::raw(format!("{value}▏")).reversed()
+        } else {
+            Span::raw(value)
+        };
+        Line::from(vec![Span::raw(label).bold(), value])
+    };
+
+    let mut lines: Vec<Line<'_>> = vec![
+        field_line(Field::Initials, "Initials:     ", form.initials.clone()),
+        field_line(Field::Dates, "Dates:        ", form.dates.clone()),
+        field_line(Field::Hours, "Hours/day:    ", form.hours.clone()),
+        field_line(
+            Field::ReceivedVia,
+            "Received via: ",
+            form.received_via.as_str().to_string(),
+        ),
+        Line::default(),
+    ];
+
+    match form.validate(app.current_bidder.as_ref(), &app.days) {
+        Ok(bid) => lines.push(
+            Line::from(format!(
+                "✓ Ready: {} day(s) for {}",
+                bid.leave_dates.len(),
+                bid.on_behalf_of
+            ))
+            .green(),
+        ),
+        Err(errors) => lines.extend(
+            errors
+                .into_iter()
+                .map(|error| Line::from(format!("✗ {error}")).red()),
+        ),
+    }
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::bordered().title("Enter bid")),
+        area,
+    );
+}
+
+/// Parses the `--from`/`--to` options so bad ranges fail before the screen
+/// opens.
+///
+/// # Errors
+///
+/// Returns an error if either date is malformed or `to` precedes `from`.
+pub fn validate_range(from: &str, to: &str) -> Result<()> {
+    let format = time::macros::format_description!("[year]-[month]-[day]");
+    let from_date: Date = Date::parse(from, format)?;
+    let to_date: Date = Date::parse(to, format)?;
+    if to_date < from_date {
+        bail!("--to must not be before --from");
+    }
+    Ok(())
+}
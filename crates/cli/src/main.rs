@@ -0,0 +1,725 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! # zabbid-cli - Command-line administration
+//!
+//! Wraps the API for scripted and emergency administration. Every command
+//! runs in one of two modes:
+//!
+//! - **Direct**: `--database` (or `--database-url`) plus `--operator`. The
+//!   command runs in-process against the database, acting as that operator.
+//! - **Remote**: `--server` plus `--token`. The command is sent to a running
+//!   server with an operator session token.
+//!
+//! Both modes go through the same API functions, so authorization and audit
+//! behave exactly as they do for the web UI. Results are printed as JSON.
+//!
+//! `restore-from-s3` is the exception: it runs before any database exists,
+//! so it needs neither mode, only the S3 settings the server uploads with.
+
+#![deny(
+    clippy::pedantic,
+    clippy::cargo,
+    clippy::nursery,
+    clippy::style,
+    clippy::correctness,
+    clippy::all,
+    clippy::suspicious,
+    clippy::complexity,
+    clippy::perf,
+    clippy::unwrap_used,
+    clippy::expect_used
+)]
+#![allow(clippy::multiple_crate_versions)]
+
+mod bid_form;
+mod direct;
+mod live;
+mod remote;
+
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use clap::{Args as ClapArgs, Parser, Subcommand};
+use color_eyre::{
+    Result,
+    eyre::{WrapErr, bail, eyre},
+};
+use serde_json::Value;
+use zab_bid_api::{
+    AuditTimelineFilter, AuditTimelinePageRequest, AuditTimelineScope, CreateOperatorRequest,
+    ListUsersResponse, PreviewCsvUsersResponse, SetBidScheduleRequest,
+};
+use zab_bid_audit::Cause;
+use zab_bid_offsite::{DATABASE_DIR, OffsiteBackups, S3Args};
+use zab_bid_persistence::Persistence;
+
+use crate::direct::DirectSession;
+use crate::remote::RemoteClient;
+
+/// ZAB Bid CLI - Administration tool for the ZAB Bidding System
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Path to the `SQLite` database file (direct mode)
+    #[arg(short, long, global = true, conflicts_with_all = ["database_url", "server"])]
+    database: Option<PathBuf>,
+
+    /// `MySQL` database URL (direct mode)
+    #[arg(long, global = true, conflicts_with = "server")]
+    database_url: Option<String>,
+
+    /// Login name of the operator to act as (direct mode)
+    #[arg(long, global = true)]
+    operator: Option<String>,
+
+    /// Base URL of a running server, e.g. `http://localhost:8080` (remote mode)
+    #[arg(long, global = true)]
+    server: Option<String>,
+
+    /// Operator session token (remote mode)
+    #[arg(long, global = true)]
+    token: Option<String>,
+
+    /// Cause identifier recorded on audit events
+    #[arg(long, global = true, default_value = "cli")]
+    cause_id: String,
+
+    /// Cause description recorded on audit events
+    #[arg(
+        long,
+        global = true,
+        default_value = "Administrative change via zabbid-cli"
+    )]
+    cause_description: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Create bid years and areas
+    #[command(subcommand)]
+    Bootstrap(BootstrapCommand),
+    /// Import or export an area roster
+    #[command(subcommand)]
+    Roster(RosterCommand),
+    /// Manage operators
+    #[command(subcommand)]
+    Operator(OperatorCommand),
+    /// Show or configure a bid year's bid schedule
+    #[command(subcommand)]
+    Schedule(ScheduleCommand),
+    /// Evaluate whether a bid year is ready to bid
+    Readiness {
+        /// The bid year ID
+        bid_year_id: i64,
+    },
+    /// Write a consistent copy of the `SQLite` database (direct mode only)
+    Backup {
+        /// Path of the backup file to create
+        output: PathBuf,
+    },
+    /// Export a bid year as a `.zab` bundle (direct mode only)
+    Export {
+        /// The bid year to export
+        year: u16,
+        /// Path of the bundle file to create
+        output: PathBuf,
+    },
+    /// Import a `.zab` bundle as a new, inactive bid year (direct mode only)
+    Import {
+        /// Path of the bundle file to import
+        bundle: PathBuf,
+    },
+    /// Download and decrypt an offsite backup (needs no database)
+    RestoreFromS3(RestoreArgs),
+    /// Query the audit timeline
+    Audit(AuditArgs),
+    /// Open the live bid entry screen for an area (remote mode only)
+    Live {
+        /// The area ID
+        area_id: i64,
+        /// First day of the slot inventory to show (YYYY-MM-DD)
+        #[arg(long)]
+        from: String,
+        /// Last day of the slot inventory to show (YYYY-MM-DD)
+        #[arg(long)]
+        to: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum BootstrapCommand {
+    /// Create a bid year
+    CreateYear {
+        /// The calendar year
+        year: u16,
+        /// First day of the bid year (YYYY-MM-DD)
+        #[arg(long)]
+        start_date: String,
+        /// Number of pay periods (26 or 27)
+        #[arg(long, default_value_t = 26)]
+        num_pay_periods: u8,
+    },
+    /// Create an area in the active bid year
+    CreateArea {
+        /// The area code
+        area_code: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum RosterCommand {
+    /// Import every valid row of a roster CSV into the active bid year
+    Import {
+        /// Path of the CSV file
+        file: PathBuf,
+    },
+    /// Export an area's roster as CSV in the import format
+    Export {
+        /// The area ID
+        area_id: i64,
+        /// Path to write the CSV to; defaults to stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum OperatorCommand {
+    /// List all operators
+    List,
+    /// Create an operator; the password is read from stdin
+    Create {
+        /// Login name
+        login_name: String,
+        /// Display name
+        #[arg(long)]
+        display_name: String,
+        /// Role (Admin or Bidder)
+        #[arg(long, default_value = "Bidder")]
+        role: String,
+    },
+    /// Disable an operator
+    Disable {
+        /// The operator ID
+        operator_id: i64,
+    },
+    /// Re-enable a disabled operator
+    Enable {
+        /// The operator ID
+        operator_id: i64,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ScheduleCommand {
+    /// Show a bid year's bid schedule
+    Show {
+        /// The bid year ID
+        bid_year_id: i64,
+    },
+    /// Set a bid year's bid schedule
+    Set {
+        /// The bid year ID
+        bid_year_id: i64,
+        /// IANA timezone, e.g. `America/New_York` (defaults to the facility's)
+        #[arg(long)]
+        timezone: Option<String>,
+        /// First bidding day (YYYY-MM-DD, must be a Monday)
+        #[arg(long)]
+        start_date: String,
+        /// Daily window start time (HH:MM:SS)
+        #[arg(long)]
+        window_start_time: String,
+        /// Daily window end time (HH:MM:SS)
+        #[arg(long)]
+        window_end_time: String,
+        /// Number of bidders per day
+        #[arg(long)]
+        bidders_per_day: u32,
+    },
+}
+
+#[derive(ClapArgs, Debug)]
+struct AuditArgs {
+    /// Restrict to a bid year
+    #[arg(long)]
+    bid_year_id: Option<i64>,
+    /// Restrict to an area (requires --bid-year-id)
+    #[arg(long, requires = "bid_year_id")]
+    area_id: Option<i64>,
+    /// Only events with this action name
+    #[arg(long)]
+    action_name: Option<String>,
+    /// Only events recorded by this operator
+    #[arg(long)]
+    actor_operator_id: Option<i64>,
+    /// Only events at or after this timestamp
+    #[arg(long)]
+    from: Option<String>,
+    /// Only events at or before this timestamp
+    #[arg(long)]
+    to: Option<String>,
+    /// Only events about the user who holds or held these initials
+    /// (requires --bid-year-id)
+    #[arg(long, requires = "bid_year_id")]
+    user_initials: Option<String>,
+    /// Resume after this event ID
+    #[arg(long)]
+    after_event_id: Option<i64>,
+    /// Page size
+    #[arg(long)]
+    limit: Option<u32>,
+}
+
+#[derive(ClapArgs, Debug)]
+struct RestoreArgs {
+    #[command(flatten)]
+    s3: S3Args,
+    /// Backup to restore, e.g. `bundles/2026/zabbid-2026-20260127T090000Z.zab.enc`;
+    /// the newest database backup when omitted
+    #[arg(long)]
+    backup: Option<String>,
+    /// List the stored backups instead of restoring one
+    #[arg(long, conflicts_with = "backup")]
+    list: bool,
+    /// Path of the file to write; it must not already exist
+    #[arg(required_unless_present = "list")]
+    output: Option<PathBuf>,
+}
+
+/// How commands reach the system.
+enum Backend {
+    Direct(Box<DirectSession>),
+    Remote(RemoteClient),
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    color_eyre::install()?;
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
+        .init();
+
+    let args: Args = Args::parse();
+    // Restoring happens before there is a database to connect to
+    if let Command::RestoreFromS3(restore) = &args.command {
+        let output: Value = run_restore_from_s3(restore).await?;
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+    let cause: Cause = Cause::new(args.cause_id.clone(), args.cause_description.clone());
+    let mut backend: Backend = connect(&args)?;
+
+    let output: Value = match args.command {
+        Command::Bootstrap(command) => run_bootstrap(&mut backend, command, cause).await?,
+        Command::Roster(command) => run_roster(&mut backend, command, &cause).await?,
+        Command::Operator(command) => run_operator(&mut backend, command, cause).await?,
+        Command::Schedule(command) => run_schedule(&mut backend, command, cause).await?,
+        Command::Readiness { bid_year_id } => match &mut backend {
+            Backend::Direct(session) => session.get_readiness(bid_year_id)?,
+            Backend::Remote(client) => {
+                client
+                    .get(&format!("/readiness/{bid_year_id}"), &[])
+                    .await?
+            }
+        },
+        Command::Backup { output } => match &mut backend {
+            Backend::Direct(session) => session.backup(&output)?,
+            Backend::Remote(_) => bail!("Backups require direct mode (--database)"),
+        },
+        Command::Export { year, output } => match &mut backend {
+            Backend::Direct(session) => session.export_bid_year(year, &output)?,
+            Backend::Remote(_) => bail!("Exports require direct mode (--database)"),
+        },
+        Command::Import { bundle } => match &mut backend {
+            Backend::Direct(session) => session.import_bid_year(&bundle)?,
+            Backend::Remote(_) => bail!("Imports require direct mode (--database)"),
+        },
+        Command::RestoreFromS3(_) => bail!("Restores from S3 run before connecting to a database"),
+        Command::Audit(audit) => run_audit(&mut backend, audit).await?,
+        Command::Live { area_id, from, to } => match &backend {
+            Backend::Direct(_) => bail!("Live bid entry requires remote mode (--server)"),
+            Backend::Remote(client) => {
+                live::validate_range(&from, &to)?;
+                live::run(client, live::LiveOptions { area_id, from, to }, &cause).await?;
+                Value::Null
+            }
+        },
+    };
+
+    if !output.is_null() {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    }
+
+    Ok(())
+}
+
+/// Opens the backend selected by the global options.
+fn connect(args: &Args) -> Result<Backend> {
+    if let Some(server) = &args.server {
+        let token: String = args
+            .token
+            .clone()
+            .ok_or_else(|| eyre!("--server requires --token"))?;
+        return Ok(Backend::Remote(RemoteClient::new(server, token)));
+    }
+
+    let persistence: Persistence = match (&args.database, &args.database_url) {
+        (Some(path), None) => Persistence::new_with_file(path)?,
+        (None, Some(url)) => Persistence::new_with_mysql(url)?,
+        _ => bail!("Specify either --database, --database-url, or --server"),
+    };
+    let login_name: &str = args
+        .operator
+        .as_deref()
+        .ok_or_else(|| eyre!("Direct mode requires --operator"))?;
+
+    Ok(Backend::Direct(Box::new(DirectSession::open(
+        persistence,
+        login_name,
+    )?)))
+}
+
+/// Adds the audit cause to a remote request body.
+fn with_cause(mut body: Value, cause: &Cause) -> Value {
+    if let Value::Object(fields) = &mut body {
+        fields.insert(String::from("cause_id"), Value::from(cause.id.clone()));
+        fields.insert(
+            String::from("cause_description"),
+            Value::from(cause.description.clone()),
+        );
+    }
+    body
+}
+
+async fn run_bootstrap(
+    backend: &mut Backend,
+    command: BootstrapCommand,
+    cause: Cause,
+) -> Result<Value> {
+    match command {
+        BootstrapCommand::CreateYear {
+            year,
+            start_date,
+            num_pay_periods,
+        } => match backend {
+            Backend::Direct(session) => {
+                let start_date: time::Date = time::Date::parse(
+                    &start_date,
+                    &time::format_description::well_known::Iso8601::DEFAULT,
+                )
+                .wrap_err("Invalid --start-date")?;
+                session.create_bid_year(year, start_date, num_pay_periods, cause)
+            }
+            Backend::Remote(client) => {
+                let body: Value = serde_json::json!({
+                    "year": year,
+                    "start_date": start_date,
+                    "num_pay_periods": num_pay_periods,
+                });
+                client.post("/bid_years", &with_cause(body, &cause)).await
+            }
+        },
+        BootstrapCommand::CreateArea { area_code } => match backend {
+            Backend::Direct(session) => session.create_area(&area_code, cause),
+            Backend::Remote(client) => {
+                let body: Value = serde_json::json!({ "area_id": area_code });
+                client.post("/areas", &with_cause(body, &cause)).await
+            }
+        },
+    }
+}
+
+async fn run_roster(backend: &mut Backend, command: RosterCommand, cause: &Cause) -> Result<Value> {
+    match command {
+        RosterCommand::Import { file } => {
+            let csv_content: String = std::fs::read_to_string(&file)
+                .wrap_err_with(|| format!("Failed to read {}", file.display()))?;
+            match backend {
+                Backend::Direct(session) => session.import_roster(csv_content, cause),
+                Backend::Remote(client) => {
+                    let preview: PreviewCsvUsersResponse = serde_json::from_value(
+                        client
+                            .post(
+                                "/bootstrap/users/csv/preview",
+                                &serde_json::json!({ "csv_content": csv_content }),
+                            )
+                            .await?,
+                    )?;
+                    let body: Value = serde_json::json!({
+                        "csv_content": csv_content,
+                        "selected_row_indices": direct::valid_row_indices(&preview),
+                    });
+                    client.post("/bootstrap/users/csv/import", &body).await
+                }
+            }
+        }
+        RosterCommand::Export { area_id, output } => {
+            let roster: ListUsersResponse = match backend {
+                Backend::Direct(session) => session.list_users(area_id)?,
+                Backend::Remote(client) => serde_json::from_value(
+                    client
+                        .get("/users", &[("area_id", area_id.to_string())])
+                        .await?,
+                )?,
+            };
+            let csv: String = roster_csv(&roster)?;
+            match output {
+                Some(path) => std::fs::write(&path, csv)
+                    .wrap_err_with(|| format!("Failed to write {}", path.display()))?,
+                None => print!("{csv}"),
+            }
+            Ok(Value::Null)
+        }
+    }
+}
+
+/// Renders a roster in the CSV import format.
+fn roster_csv(roster: &ListUsersResponse) -> Result<String> {
+    let mut writer: csv::Writer<Vec<u8>> = csv::Writer::from_writer(Vec::new());
+    writer.write_record([
+        "initials",
+        "name",
+        "area_id",
+        "crew",
+        "user_type",
+        "service_computation_date",
+        "eod_faa_date",
+        "cumulative_natca_bu_date",
+        "natca_bu_date",
+        "lottery_value",
+    ])?;
+
+    for user in &roster.users {
+        writer.write_record([
+            user.initials.clone(),
+            user.name.clone(),
+            roster.area_code.clone(),
+            user.crew.map(|crew| crew.to_string()).unwrap_or_default(),
+            user.user_type.clone(),
+            user.service_computation_date.clone(),
+            user.eod_faa_date.clone(),
+            user.cumulative_natca_bu_date.clone(),
+            user.natca_bu_date.clone(),
+            user.lottery_value
+                .map(|value| value.to_string())
+                .unwrap_or_default(),
+        ])?;
+    }
+
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+async fn run_operator(
+    backend: &mut Backend,
+    command: OperatorCommand,
+    cause: Cause,
+) -> Result<Value> {
+    match command {
+        OperatorCommand::List => match backend {
+            Backend::Direct(session) => session.list_operators(),
+            Backend::Remote(client) => client.get("/operators", &[]).await,
+        },
+        OperatorCommand::Create {
+            login_name,
+            display_name,
+            role,
+        } => {
+            let password: String = read_password()?;
+            let request: CreateOperatorRequest = CreateOperatorRequest {
+                login_name,
+                display_name,
+                role,
+                password: password.clone(),
+                password_confirmation: password,
+            };
+            match backend {
+                Backend::Direct(session) => session.create_operator(request, cause),
+                Backend::Remote(client) => {
+                    let body: Value = serde_json::to_value(&request)?;
+                    client.post("/operators", &with_cause(body, &cause)).await
+                }
+            }
+        }
+        OperatorCommand::Disable { operator_id } => match backend {
+            Backend::Direct(session) => session.disable_operator(operator_id, cause),
+            Backend::Remote(client) => {
+                let body: Value = serde_json::json!({ "operator_id": operator_id });
+                client
+                    .post("/operators/disable", &with_cause(body, &cause))
+                    .await
+            }
+        },
+        OperatorCommand::Enable { operator_id } => match backend {
+            Backend::Direct(session) => session.enable_operator(operator_id, cause),
+            Backend::Remote(client) => {
+                let body: Value = serde_json::json!({ "operator_id": operator_id });
+                client
+                    .post("/operators/enable", &with_cause(body, &cause))
+                    .await
+            }
+        },
+    }
+}
+
+/// Reads a new operator's password from the first line of stdin.
+fn read_password() -> Result<String> {
+    eprint!("Password: ");
+    std::io::stderr().flush()?;
+
+    let mut password: String = String::new();
+    std::io::stdin().lock().read_line(&mut password)?;
+    let password: String = password.trim_end_matches(['\r', '\n']).to_string();
+    if password.is_empty() {
+        bail!("No password provided on stdin");
+    }
+    Ok(password)
+}
+
+async fn run_schedule(
+    backend: &mut Backend,
+    command: ScheduleCommand,
+    cause: Cause,
+) -> Result<Value> {
+    match command {
+        ScheduleCommand::Show { bid_year_id } => match backend {
+            Backend::Direct(session) => session.get_bid_schedule(bid_year_id),
+            Backend::Remote(client) => {
+                client
+                    .get(&format!("/bid-schedule/{bid_year_id}"), &[])
+                    .await
+            }
+        },
+        ScheduleCommand::Set {
+            bid_year_id,
+            timezone,
+            start_date,
+            window_start_time,
+            window_end_time,
+            bidders_per_day,
+        } => {
+            let request: SetBidScheduleRequest = SetBidScheduleRequest {
+                bid_year_id,
+                timezone,
+                start_date,
+                window_start_time,
+                window_end_time,
+                bidders_per_day,
+            };
+            match backend {
+                Backend::Direct(session) => session.set_bid_schedule(&request, cause),
+                Backend::Remote(client) => {
+                    let body: Value = serde_json::json!({
+                        "bid_year_id": request.bid_year_id,
+                        "timezone": request.timezone,
+                        "start_date": request.start_date,
+                        "window_start_time": request.window_start_time,
+                        "window_end_time": request.window_end_time,
+                        "bidders_per_day": request.bidders_per_day,
+                    });
+                    client
+                        .post("/bid-schedule", &with_cause(body, &cause))
+                        .await
+                }
+            }
+        }
+    }
+}
+
+/// Lists offsite backups, or downloads one and writes it decrypted.
+///
+/// Database backups restore to a `SQLite` file that `--database` can open;
+/// bundles restore to a `.zab` file for `import`.
+async fn run_restore_from_s3(restore: &RestoreArgs) -> Result<Value> {
+    let backups: OffsiteBackups = OffsiteBackups::from_args(&restore.s3)
+        .map_err(|e| eyre!(e))?
+        .ok_or_else(|| eyre!("restore-from-s3 requires --s3-endpoint"))?;
+    if restore.list {
+        let names: Vec<String> = backups.list("").await.map_err(|e| eyre!(e))?;
+        return Ok(serde_json::json!({ "backups": names }));
+    }
+
+    let name: String = match &restore.backup {
+        Some(name) => name.clone(),
+        None => backups
+            .list(DATABASE_DIR)
+            .await
+            .map_err(|e| eyre!(e))?
+            .pop()
+            .ok_or_else(|| eyre!("No database backups found"))?,
+    };
+    let output: &PathBuf = restore
+        .output
+        .as_ref()
+        .ok_or_else(|| eyre!("An output path is required"))?;
+    let bytes: Vec<u8> = backups.download(&name).await.map_err(|e| eyre!(e))?;
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(output)
+        .and_then(|mut file| file.write_all(&bytes))
+        .wrap_err_with(|| format!("Cannot write {}", output.display()))?;
+
+    Ok(serde_json::json!({
+        "backup": name,
+        "destination": output.display().to_string(),
+        "bytes": bytes.len(),
+    }))
+}
+
+async fn run_audit(backend: &mut Backend, audit: AuditArgs) -> Result<Value> {
+    match backend {
+        Backend::Direct(session) => {
+            let scope: AuditTimelineScope = match (audit.bid_year_id, audit.area_id) {
+                (None, _) => AuditTimelineScope::Global,
+                (Some(bid_year_id), None) => AuditTimelineScope::BidYear { bid_year_id },
+                (Some(bid_year_id), Some(area_id)) => AuditTimelineScope::Area {
+                    bid_year_id,
+                    area_id,
+                },
+            };
+            let filter: AuditTimelineFilter = AuditTimelineFilter {
+                action_name: audit.action_name,
+                actor_operator_id: audit.actor_operator_id,
+                from: audit.from,
+                to: audit.to,
+                user_initials: audit.user_initials,
+            };
+            let page: AuditTimelinePageRequest = AuditTimelinePageRequest {
+                after_event_id: audit.after_event_id,
+                limit: audit.limit,
+            };
+            session.get_audit_timeline(scope, &filter, page)
+        }
+        Backend::Remote(client) => {
+            let query: Vec<(&str, String)> = [
+                ("bid_year_id", audit.bid_year_id.map(|v| v.to_string())),
+                ("area_id", audit.area_id.map(|v| v.to_string())),
+                ("action_name", audit.action_name),
+                (
+                    "actor_operator_id",
+                    audit.actor_operator_id.map(|v| v.to_string()),
+                ),
+                ("from", audit.from),
+                ("to", audit.to),
+                ("user_initials", audit.user_initials),
+                (
+                    "after_event_id",
+                    audit.after_event_id.map(|v| v.to_string()),
+                ),
+                ("limit", audit.limit.map(|v| v.to_string())),
+            ]
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+            .collect();
+            client.get("/audit/timeline/page", &query).await
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! HTTP access to a running server.
+//!
+//! Commands are sent to the server's `/api` routes with an operator session
+//! token, exactly as the web UI would send them.
+
+use color_eyre::{
+    Result,
+    eyre::{WrapErr, bail},
+};
+use serde_json::Value;
+use tracing::debug;
+
+/// A client for a running server, authenticated with a session token.
+pub struct RemoteClient {
+    client: reqwest::Client,
+    base_url: String,
+    token: String,
+}
+
+impl RemoteClient {
+    /// Creates a client for the server at `server_url`.
+    #[must_use]
+    pub fn new(server_url: &str, token: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: format!("{}/api", server_url.trim_end_matches('/')),
+            token,
+        }
+    }
+
+    /// Sends a GET request to `path` with the given query parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the server rejects it.
+    pub async fn get(&self, path: &str, query: &[(&str, String)]) -> Result<Value> {
+        debug!(path, "GET");
+        let response: reqwest::Response = self
+            .client
+            .get(format!("{}{path}", self.base_url))
+            .bearer_auth(&self.token)
+            .query(query)
+            .send()
+            .await
+            .wrap_err_with(|| format!("GET {path} failed"))?;
+        read_response(response).await
+    }
+
+    /// Sends a POST request to `path` with a JSON body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the server rejects it.
+    pub async fn post(&self, path: &str, body: &Value) -> Result<Value> {
+        debug!(path, "POST");
+        let response: reqwest::Response = self
+            .client
+            .post(format!("{}{path}", self.base_url))
+            .bearer_auth(&self.token)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.to_string())
+            .send()
+            .await
+            .wrap_err_with(|| format!("POST {path} failed"))?;
+        read_response(response).await
+    }
+}
+
+/// Parses a response body, surfacing the server's error message on failure.
+async fn read_response(response: reqwest::Response) -> Result<Value> {
+    let status: reqwest::StatusCode = response.status();
+    let text: String = response.text().await?;
+    let body: Value = if text.is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_str(&text).unwrap_or(Value::String(text))
+    };
+
+    if !status.is_success() {
+        let message: String = body
+            .get("message")
+            .and_then(Value::as_str)
+            .map_or_else(|| body.to_string(), str::to_string);
+        bail!("Server returned {status}: {message}");
+    }
+
+    Ok(body)
+}
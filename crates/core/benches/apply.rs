@@ -0,0 +1,181 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Performance benchmarks for applying commands to area state.
+//!
+//! Every transition validates against the area's users and produces a new
+//! state, so these run against a full area roster to show how that cost
+//! scales.
+//!
+//! Run with `cargo xtask bench`.
+
+#![allow(clippy::expect_used, clippy::unwrap_used, missing_docs)]
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use zab_bid::{
+    BatchTransitionResult, BootstrapMetadata, Command, State, TransitionResult, apply, apply_batch,
+};
+use zab_bid_audit::{Actor, Cause};
+use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, User, UserType};
+
+const YEAR: u16 = 2026;
+const AREA: &str = "North";
+
+/// Users in the area, matching a large facility.
+const ROSTER_SIZE: usize = 400;
+
+fn actor() -> Actor {
+    Actor::with_operator(
+        String::from("bench-actor"),
+        String::from("admin"),
+        1,
+        String::from("bench-operator"),
+        String::from("Bench Operator"),
+    )
+}
+
+fn cause() -> Cause {
+    Cause::new(String::from("bench"), String::from("Benchmark"))
+}
+
+fn metadata() -> BootstrapMetadata {
+    let mut metadata: BootstrapMetadata = BootstrapMetadata::new();
+    metadata.bid_years.push(BidYear::new(YEAR));
+    metadata.areas.push((BidYear::new(YEAR), Area::new(AREA)));
+    metadata
+}
+
+/// Returns unique two-letter initials for `index`.
+fn initials(index: usize) -> Initials {
+    let letter = |n: usize| char::from(b'A' + u8::try_from(n % 26).unwrap());
+    let value: String = [letter(index / 26), letter(index)].iter().collect();
+    Initials::new(&value)
+}
+
+fn register(index: usize) -> Command {
+    Command::RegisterUser {
+        initials: initials(index),
+        name: format!("Controller {index}"),
+        area: Area::new(AREA),
+        user_type: UserType::CPC,
+        crew: Some(Crew::new(u8::try_from(index % 7).unwrap() + 1).unwrap()),
+        seniority_data: SeniorityData::new(
+            String::from("2015-01-15"),
+            String::from("2015-06-01"),
+            String::from("2016-01-15"),
+            String::from("2016-01-15"),
+            Some(u32::try_from(index).unwrap() + 1),
+        ),
+    }
+}
+
+fn transition(metadata: &BootstrapMetadata, state: &State, command: Command) -> TransitionResult {
+    apply(
+        metadata,
+        state,
+        &BidYear::new(YEAR),
+        command,
+        actor(),
+        cause(),
+    )
+    .unwrap()
+}
+
+/// Builds an area state holding `count` users with assigned IDs.
+fn rostered_state(metadata: &BootstrapMetadata, count: usize) -> State {
+    let mut state: State = State::new(BidYear::new(YEAR), Area::new(AREA));
+    for index in 0..count {
+        state = transition(metadata, &state, register(index)).new_state;
+    }
+    // Persisted users always carry an ID, and updates look them up by it
+    let users: Vec<User> = (1..)
+        .zip(state.users.values())
+        .map(|(id, user)| User {
+            user_id: Some(id),
+            ..user.clone()
+        })
+        .collect();
+    State::with_users(state.bid_year, state.area, users)
+}
+
+fn apply_to_roster(c: &mut Criterion) {
+    let mut group = c.benchmark_group("apply");
+    group.throughput(Throughput::Elements(1));
+
+    let metadata: BootstrapMetadata = metadata();
+    let state: State = rostered_state(&metadata, ROSTER_SIZE);
+
+    group.bench_function(BenchmarkId::new("register_user", ROSTER_SIZE), |b| {
+        b.iter(|| transition(&metadata, black_box(&state), register(ROSTER_SIZE)));
+    });
+
+    let user: User = state.users.values().next_back().unwrap().clone();
+    group.bench_function(BenchmarkId::new("update_user", ROSTER_SIZE), |b| {
+        b.iter(|| {
+            transition(
+                &metadata,
+                black_box(&state),
+                Command::UpdateUser {
+                    user_id: user.user_id.unwrap(),
+                    initials: user.initials.clone(),
+                    name: String::from("Renamed Controller"),
+                    area: user.area.clone(),
+                    user_type: user.user_type,
+                    crew: user.crew,
+                    seniority_data: user.seniority_data.clone(),
+                },
+            )
+        });
+    });
+
+    group.bench_function(BenchmarkId::new("checkpoint", ROSTER_SIZE), |b| {
+        b.iter(|| {
+            transition(
+                &metadata,
+                black_box(&state),
+                Command::Checkpoint {
+                    label: None,
+                    description: None,
+                },
+            )
+        });
+    });
+
+    group.finish();
+}
+
+fn build_roster(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build_roster");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(u64::try_from(ROSTER_SIZE).unwrap()));
+
+    let metadata: BootstrapMetadata = metadata();
+    group.bench_function(BenchmarkId::from_parameter(ROSTER_SIZE), |b| {
+        b.iter(|| rostered_state(&metadata, ROSTER_SIZE));
+    });
+
+    // A roster CSV import applies every row as one batch
+    let empty: State = State::new(BidYear::new(YEAR), Area::new(AREA));
+    group.bench_function(BenchmarkId::new("batch", ROSTER_SIZE), |b| {
+        b.iter(|| {
+            let result: BatchTransitionResult = apply_batch(
+                &metadata,
+                black_box(&empty),
+                &BidYear::new(YEAR),
+                (0..ROSTER_SIZE).map(register).collect(),
+                &actor(),
+                &cause(),
+            );
+            result.final_state
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, apply_to_roster, build_roster);
+criterion_main!(benches);
@@ -5,13 +5,276 @@
 
 use crate::command::Command;
 use crate::error::CoreError;
-use crate::state::{BootstrapMetadata, BootstrapResult, State, TransitionResult};
+use crate::state::{
+    BatchTransitionResult, BootstrapMetadata, BootstrapResult, State, TransitionResult,
+};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
 use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
 use zab_bid_domain::{
-    Area, BidYear, CanonicalBidYear, DomainError, User, validate_bid_year,
-    validate_initials_unique, validate_user_fields,
+    Area, BidPreference, BidYear, CanonicalBidYear, DomainError, Initials, User, validate_bid_year,
+    validate_user_fields,
 };
 
+/// Minimum length of a bid year reopen justification, after trimming.
+const MIN_REOPEN_JUSTIFICATION_LEN: usize = 20;
+
+/// Minimum length of a user merge reason, after trimming.
+const MIN_MERGE_REASON_LEN: usize = 10;
+
+/// Minimum length of a scope freeze reason, after trimming.
+const MIN_FREEZE_REASON_LEN: usize = 10;
+
+/// Prefix of a rollback event's details, followed by the target event ID.
+const ROLLBACK_DETAILS_PREFIX: &str = "Rolled back to event ID ";
+
+/// Maximum length of a checkpoint label, after trimming.
+const MAX_CHECKPOINT_LABEL_LEN: usize = 64;
+
+/// Prefix of a labeled checkpoint's details, followed by the label and, on
+/// the next line, the description.
+const CHECKPOINT_DETAILS_PREFIX: &str = "Checkpoint labeled ";
+
+/// Returns the event a `Rollback` action rolled back to.
+///
+/// Persistence reads the target from the recorded action to restore the
+/// area's state, so the details format is fixed.
+///
+/// # Returns
+///
+/// The target event ID, or `None` if the action is not a rollback.
+#[must_use]
+pub fn rollback_target_event_id(action: &Action) -> Option<i64> {
+    if action.name != "Rollback" {
+        return None;
+    }
+    action
+        .details
+        .as_deref()?
+        .strip_prefix(ROLLBACK_DETAILS_PREFIX)?
+        .parse()
+        .ok()
+}
+
+/// Returns the label and description of a labeled `Checkpoint` action.
+///
+/// Checkpoints are listed and found by label from the recorded action, so
+/// the details format is fixed.
+///
+/// # Returns
+///
+/// The label and the description, if any, or `None` if the action is not a
+/// labeled checkpoint.
+#[must_use]
+pub fn checkpoint_label(action: &Action) -> Option<(&str, Option<&str>)> {
+    if action.name != "Checkpoint" {
+        return None;
+    }
+    let labeled: &str = action
+        .details
+        .as_deref()?
+        .strip_prefix(CHECKPOINT_DETAILS_PREFIX)?;
+    Some(match labeled.split_once('\n') {
+        Some((label, description)) => (label, Some(description)),
+        None => (labeled, None),
+    })
+}
+
+/// Validates a checkpoint's label and description and formats the
+/// checkpoint's action details.
+///
+/// Labels are trimmed, must be non-empty, single-line, and at most
+/// [`MAX_CHECKPOINT_LABEL_LEN`] characters. A blank description is dropped.
+fn checkpoint_details(
+    label: Option<&str>,
+    description: Option<&str>,
+) -> Result<String, DomainError> {
+    let description: Option<&str> = description.map(str::trim).filter(|d| !d.is_empty());
+    let Some(label) = label.map(str::trim) else {
+        if description.is_some() {
+            return Err(DomainError::InvalidCheckpointLabel {
+                reason: String::from("a description requires a label"),
+            });
+        }
+        return Ok(String::from("Explicit checkpoint created"));
+    };
+
+    if label.is_empty() {
+        return Err(DomainError::InvalidCheckpointLabel {
+            reason: String::from("label cannot be empty"),
+        });
+    }
+    if label.chars().count() > MAX_CHECKPOINT_LABEL_LEN {
+        return Err(DomainError::InvalidCheckpointLabel {
+            reason: format!("label cannot exceed {MAX_CHECKPOINT_LABEL_LEN} characters"),
+        });
+    }
+    if label.chars().any(char::is_control) {
+        return Err(DomainError::InvalidCheckpointLabel {
+            reason: String::from("label must be a single line"),
+        });
+    }
+
+    Ok(description.map_or_else(
+        || format!("{CHECKPOINT_DETAILS_PREFIX}{label}"),
+        |description| format!("{CHECKPOINT_DETAILS_PREFIX}{label}\n{description}"),
+    ))
+}
+
+/// Formats an instant for an audit snapshot (RFC 3339).
+fn format_instant(instant: OffsetDateTime) -> String {
+    instant
+        .format(&Rfc3339)
+        .unwrap_or_else(|_| instant.to_string())
+}
+
+/// Formats ranked preferences for an audit snapshot.
+///
+/// Preferences are listed by rank as `rank:date|date@hours`, separated by
+/// semicolons.
+fn format_preferences(preferences: &[BidPreference]) -> String {
+    let mut ranked: Vec<&BidPreference> = preferences.iter().collect();
+    ranked.sort_by_key(|preference| preference.rank());
+    ranked
+        .iter()
+        .map(|preference| {
+            let dates: Vec<String> = preference
+                .leave_dates()
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            format!(
+                "{}:{}@{}",
+                preference.rank(),
+                dates.join("|"),
+                preference.hours()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(";")
+}
+
+/// Refuses a command that would change a frozen area.
+///
+/// Commands targeting an area are refused while that area is frozen.
+/// Lifecycle transitions and the expected area count change every area in
+/// their bid year, so they are refused while any area in it is frozen.
+/// Checkpoints change nothing and are always allowed, as are freezes and
+/// unfreezes themselves. Creating a bid year or an area cannot touch a
+/// frozen area, nor can choosing the active bid year.
+fn ensure_not_frozen(
+    metadata: &BootstrapMetadata,
+    state: Option<&State>,
+    active_bid_year: &BidYear,
+    command: &Command,
+) -> Result<(), CoreError> {
+    let frozen: Option<(&BidYear, &Area)> = match command {
+        Command::Checkpoint { .. }
+        | Command::FreezeScope { .. }
+        | Command::Unfreeze { .. }
+        | Command::CreateBidYear { .. }
+        | Command::CreateArea { .. }
+        | Command::SetActiveBidYear { .. } => None,
+        Command::SetExpectedUserCount { area, .. } => metadata
+            .frozen_scopes
+            .iter()
+            .find(|(y, a)| y.year() == active_bid_year.year() && a == area)
+            .map(|(y, a)| (y, a)),
+        Command::SetExpectedAreaCount { .. } => metadata
+            .frozen_scopes
+            .iter()
+            .find(|(y, _)| y.year() == active_bid_year.year())
+            .map(|(y, a)| (y, a)),
+        Command::AdvanceBidder { year, area, .. }
+        | Command::ExpireBidWindow { year, area, .. }
+        | Command::EnterLeaveBid { year, area, .. }
+        | Command::SubmitBidPreferences { year, area, .. }
+        | Command::ApplyBidPreference { year, area, .. }
+        | Command::AdjustSlotInventory { year, area, .. }
+        | Command::RequestOverbid { year, area, .. }
+        | Command::ApproveOverbid { year, area, .. }
+        | Command::DenyOverbid { year, area, .. }
+        | Command::RequestSelfBidEntry { year, area, .. }
+        | Command::ConfirmSelfBidEntry { year, area, .. }
+        | Command::SignOffRound { year, area, .. }
+        | Command::WithdrawLeaveBid { year, area, .. }
+        | Command::OfferWaitlistSlot { year, area, .. }
+        | Command::AcceptWaitlistOffer { year, area, .. }
+        | Command::DeclineWaitlistOffer { year, area, .. }
+        | Command::ExpireWaitlistOffer { year, area, .. } => metadata
+            .frozen_scopes
+            .iter()
+            .find(|(y, a)| y.year() == *year && a == area)
+            .map(|(y, a)| (y, a)),
+        Command::TransitionToBootstrapComplete { year }
+        | Command::TransitionToCanonicalized { year }
+        | Command::ConfirmReadyToBid { year }
+        | Command::ActivateBidding { year, .. }
+        | Command::TransitionToBiddingClosed { year }
+        | Command::ReopenBidYear { year, .. } => metadata
+            .frozen_scopes
+            .iter()
+            .find(|(y, _)| y.year() == *year)
+            .map(|(y, a)| (y, a)),
+        _ => state.and_then(|state| {
+            // A user moved into a frozen area changes that area too
+            let target: Option<&Area> = match command {
+                Command::RegisterUser { area, .. } | Command::UpdateUser { area, .. } => Some(area),
+                _ => None,
+            };
+            [Some(&state.area), target]
+                .into_iter()
+                .flatten()
+                .find(|area| metadata.is_frozen(&state.bid_year, area))
+                .map(|area| (&state.bid_year, area))
+        }),
+    };
+    match frozen {
+        Some((bid_year, area)) => Err(CoreError::DomainViolation(DomainError::ScopeFrozen {
+            bid_year: bid_year.year(),
+            area: area.id().to_string(),
+        })),
+        None => Ok(()),
+    }
+}
+
+/// Refuses a command that changes the leave of the controller its operator
+/// is.
+///
+/// An operator linked to a controller may only request their own leave,
+/// for a second operator to confirm; every command that creates, withdraws,
+/// or queues leave for the controller is refused when that operator issues
+/// it. Commands issued without an operator, such as the scheduler's, are
+/// unaffected.
+fn ensure_not_own_bid(
+    metadata: &BootstrapMetadata,
+    command: &Command,
+    actor: &Actor,
+) -> Result<(), CoreError> {
+    let on_behalf_of: &Initials = match command {
+        Command::EnterLeaveBid { on_behalf_of, .. }
+        | Command::SubmitBidPreferences { on_behalf_of, .. }
+        | Command::ApplyBidPreference { on_behalf_of, .. }
+        | Command::RequestOverbid { on_behalf_of, .. }
+        | Command::WithdrawLeaveBid { on_behalf_of, .. }
+        | Command::AcceptWaitlistOffer { on_behalf_of, .. } => on_behalf_of,
+        _ => return Ok(()),
+    };
+    let Some(operator_id) = actor.operator_id else {
+        return Ok(());
+    };
+    if metadata.operator_controller(operator_id) == Some(on_behalf_of) {
+        return Err(CoreError::DomainViolation(DomainError::InvalidSelfBid {
+            reason: format!(
+                "Operator {} is controller '{}' and must request their own bid for a second operator to confirm",
+                actor.operator_login_name.as_deref().unwrap_or(&actor.id),
+                on_behalf_of.value()
+            ),
+        }));
+    }
+    Ok(())
+}
+
 /// Applies a bootstrap command to the metadata, producing new metadata and audit event.
 ///
 /// Bootstrap commands (`CreateBidYear`, `CreateArea`) operate on global metadata.
@@ -32,13 +295,29 @@ use zab_bid_domain::{
 ///
 /// Returns an error if:
 /// - The command violates domain rules
-#[allow(clippy::too_many_lines)]
+/// - The command changes the scope of a frozen area
+/// - The actor's operator is the controller whose leave the command changes
 pub fn apply_bootstrap(
     metadata: &BootstrapMetadata,
     active_bid_year: &BidYear,
     command: Command,
     actor: Actor,
     cause: Cause,
+) -> Result<BootstrapResult, CoreError> {
+    ensure_not_frozen(metadata, None, active_bid_year, &command)?;
+    ensure_not_own_bid(metadata, &command, &actor)?;
+
+    bootstrap_transition(metadata, active_bid_year, command, actor, cause)
+}
+
+/// Applies a bootstrap command once the scope is known not to be frozen.
+#[allow(clippy::too_many_lines)]
+fn bootstrap_transition(
+    metadata: &BootstrapMetadata,
+    active_bid_year: &BidYear,
+    command: Command,
+    actor: Actor,
+    cause: Cause,
 ) -> Result<BootstrapResult, CoreError> {
     match command {
         Command::CreateBidYear {
@@ -175,6 +454,7 @@ pub fn apply_bootstrap(
             // SetActiveBidYear is a bid-year-level operation without an area
             let audit_event: AuditEvent = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor,
                 cause,
                 action,
@@ -182,6 +462,7 @@ pub fn apply_bootstrap(
                 after,
                 bid_year: Some(bid_year),
                 area: None,
+                user_id: None,
             };
 
             Ok(BootstrapResult {
@@ -230,6 +511,7 @@ pub fn apply_bootstrap(
             // SetExpectedAreaCount is a bid-year-level operation without an area
             let audit_event: AuditEvent = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor,
                 cause,
                 action,
@@ -237,6 +519,7 @@ pub fn apply_bootstrap(
                 after,
                 bid_year: Some(bid_year.clone()),
                 area: None,
+                user_id: None,
             };
 
             Ok(BootstrapResult {
@@ -330,6 +613,7 @@ pub fn apply_bootstrap(
 
             let audit_event: AuditEvent = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor,
                 cause,
                 action,
@@ -337,6 +621,7 @@ pub fn apply_bootstrap(
                 after,
                 bid_year: Some(bid_year),
                 area: None,
+                user_id: None,
             };
 
             Ok(BootstrapResult {
@@ -373,6 +658,7 @@ pub fn apply_bootstrap(
 
             let audit_event: AuditEvent = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor,
                 cause,
                 action,
@@ -380,6 +666,7 @@ pub fn apply_bootstrap(
                 after,
                 bid_year: Some(bid_year),
                 area: None,
+                user_id: None,
             };
 
             Ok(BootstrapResult {
@@ -417,6 +704,7 @@ pub fn apply_bootstrap(
 
             let audit_event: AuditEvent = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor,
                 cause,
                 action,
@@ -424,6 +712,7 @@ pub fn apply_bootstrap(
                 after,
                 bid_year: Some(bid_year),
                 area: None,
+                user_id: None,
             };
 
             Ok(BootstrapResult {
@@ -432,7 +721,7 @@ pub fn apply_bootstrap(
                 canonical_bid_year: None,
             })
         }
-        Command::TransitionToBiddingActive { year } => {
+        Command::ActivateBidding { year, readiness } => {
             let bid_year = BidYear::new(year);
 
             // Validate bid year exists
@@ -442,24 +731,38 @@ pub fn apply_bootstrap(
                 )));
             }
 
+            // Refuse activation while any readiness criterion is unsatisfied
+            let blocking_reasons: Vec<String> = readiness.blocking_reasons();
+            if !blocking_reasons.is_empty() {
+                return Err(CoreError::DomainViolation(DomainError::BiddingNotReady {
+                    year,
+                    blocking_reasons,
+                }));
+            }
+
             // Create new metadata (unchanged)
             let new_metadata: BootstrapMetadata = metadata.clone();
 
-            // Create audit event recording the transition
+            // Create audit event recording the transition and the readiness
+            // evaluation it was granted on
             let before: StateSnapshot =
                 StateSnapshot::new(String::from("lifecycle_state=Canonicalized"));
-            let after: StateSnapshot =
-                StateSnapshot::new(String::from("lifecycle_state=BiddingActive"));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "lifecycle_state=BiddingActive,{}",
+                readiness.summary()
+            ));
 
             let action: Action = Action::new(
-                String::from("TransitionToBiddingActive"),
+                String::from("ActivateBidding"),
                 Some(format!(
-                    "Transitioned bid year {year} from Canonicalized to BiddingActive"
+                    "Activated bidding for bid year {year} (Canonicalized to BiddingActive); readiness: {}",
+                    readiness.summary()
                 )),
             );
 
             let audit_event: AuditEvent = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor,
                 cause,
                 action,
@@ -467,6 +770,7 @@ pub fn apply_bootstrap(
                 after,
                 bid_year: Some(bid_year),
                 area: None,
+                user_id: None,
             };
 
             Ok(BootstrapResult {
@@ -503,6 +807,7 @@ pub fn apply_bootstrap(
 
             let audit_event: AuditEvent = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor,
                 cause,
                 action,
@@ -510,6 +815,7 @@ pub fn apply_bootstrap(
                 after,
                 bid_year: Some(bid_year),
                 area: None,
+                user_id: None,
             };
 
             Ok(BootstrapResult {
@@ -518,193 +824,1633 @@ pub fn apply_bootstrap(
                 canonical_bid_year: None,
             })
         }
-        _ => {
-            // Non-bootstrap commands should use apply() instead
-            unreachable!("apply_bootstrap called with non-bootstrap command")
-        }
-    }
-}
-
-/// Applies a command to the state, producing a new state and audit event.
-///
-/// Commands are validated and applied atomically. Either they succeed completely
-/// or they fail without side effects.
-///
-/// # Arguments
-///
-/// * `metadata` - The bootstrap metadata (immutable)
-/// * `state` - The current state (immutable)
-/// * `active_bid_year` - The active bid year (must be validated by caller)
-/// * `command` - The command to apply
-/// * `actor` - The actor performing this action
-/// * `cause` - The cause or reason for this action
-///
-/// # Returns
-///
-/// * `Ok(TransitionResult)` containing the new state and audit event
-/// * `Err(CoreError)` if the command is invalid
-///
-/// # Errors
-///
-/// Returns an error if:
-/// - The command violates domain rules
-/// - The user already exists (for `RegisterUser`)
-#[allow(clippy::too_many_lines)]
-pub fn apply(
-    metadata: &BootstrapMetadata,
-    state: &State,
-    active_bid_year: &BidYear,
-    command: Command,
-    actor: Actor,
-    cause: Cause,
-) -> Result<TransitionResult, CoreError> {
-    match command {
-        Command::RegisterUser {
-            initials,
-            name,
-            area,
-            user_type,
-            crew,
-            seniority_data,
+        Command::ReopenBidYear {
+            year,
+            justification,
         } => {
-            // Use the active bid year
-            let bid_year = active_bid_year;
+            let bid_year = BidYear::new(year);
 
             // Validate bid year exists
-            if !metadata.has_bid_year(bid_year) {
+            if !metadata.has_bid_year(&bid_year) {
                 return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
-                    bid_year.year(),
+                    year,
                 )));
             }
 
-            // Validate area exists in bid year
-            if !metadata.has_area(bid_year, &area) {
-                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
-                    bid_year: bid_year.year(),
-                    area: area.id().to_string(),
-                }));
+            let justification: &str = justification.trim();
+            if justification.len() < MIN_REOPEN_JUSTIFICATION_LEN {
+                return Err(CoreError::DomainViolation(
+                    DomainError::InvalidReopenJustification {
+                        justification: justification.to_string(),
+                    },
+                ));
             }
 
-            // Create the user object
-            let user: User = User::new(
-                bid_year.clone(),
-                initials.clone(),
-                name,
-                area,
-                user_type,
-                crew,
-                seniority_data,
-                false, // excluded_from_bidding: default to false
-                false, // excluded_from_leave_calculation: default to false
-                false, // no_bid_reviewed: default to false
-            );
-
-            // Validate user field constraints
-            validate_user_fields(&user)?;
-
-            // Validate initials are unique within the bid year
-            validate_initials_unique(bid_year, &initials, &state.users)?;
-
-            // Capture state before transition
-            let before: StateSnapshot = state.to_snapshot();
-
-            // Create new state with the user added
-            let mut new_users: Vec<User> = state.users.clone();
-            new_users.push(user);
-            let new_state: State = State {
-                bid_year: state.bid_year.clone(),
-                area: state.area.clone(),
-                users: new_users,
-            };
+            // Create new metadata (unchanged - canonical rows live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
 
-            // Capture state after transition
-            let after: StateSnapshot = new_state.to_snapshot();
+            let before: StateSnapshot =
+                StateSnapshot::new(String::from("lifecycle_state=Canonicalized"));
+            let after: StateSnapshot = StateSnapshot::new(String::from(
+                "lifecycle_state=BootstrapComplete,canonical_data_cleared=true",
+            ));
 
-            // Create audit event
             let action: Action = Action::new(
-                String::from("RegisterUser"),
+                String::from("ReopenBidYear"),
                 Some(format!(
-                    "Registered user with initials '{}' for bid year {}",
-                    initials.value(),
-                    bid_year.year()
+                    "REOPENED bid year {year} (Canonicalized to BootstrapComplete), clearing all canonical data. Justification: {justification}"
                 )),
             );
-            let audit_event: AuditEvent = AuditEvent::new(
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
                 actor,
                 cause,
                 action,
                 before,
                 after,
-                state.bid_year.clone(),
-                state.area.clone(),
-            );
+                bid_year: Some(bid_year),
+                area: None,
+                user_id: None,
+            };
 
-            Ok(TransitionResult {
-                new_state,
+            Ok(BootstrapResult {
+                new_metadata,
                 audit_event,
+                canonical_bid_year: None,
             })
         }
-        Command::Checkpoint => {
-            // Checkpoint creates a snapshot without changing state
-            let before: StateSnapshot = state.to_snapshot();
-            let after: StateSnapshot = state.to_snapshot();
+        Command::AdvanceBidder {
+            year,
+            area,
+            round_id,
+            previous_user_id,
+            next_user_id,
+            time_driven,
+        } => {
+            let bid_year = BidYear::new(year);
 
-            let action: Action = Action::new(
-                String::from("Checkpoint"),
-                Some(String::from("Explicit checkpoint created")),
-            );
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
 
-            let audit_event: AuditEvent = AuditEvent::new(
-                actor,
-                cause,
-                action,
-                before,
-                after,
-                state.bid_year.clone(),
-                state.area.clone(),
-            );
+            if previous_user_id.is_none() && next_user_id.is_none() {
+                return Err(CoreError::DomainViolation(
+                    DomainError::InvalidBidderAdvance {
+                        reason: format!("Round {round_id} has no bidders to advance to"),
+                    },
+                ));
+            }
+            if let Some(user_id) = previous_user_id
+                && next_user_id == Some(user_id)
+            {
+                return Err(CoreError::DomainViolation(
+                    DomainError::InvalidBidderAdvance {
+                        reason: format!(
+                            "User {user_id} is already the current bidder in round {round_id}"
+                        ),
+                    },
+                ));
+            }
 
-            Ok(TransitionResult {
-                new_state: state.clone(),
-                audit_event,
-            })
-        }
-        Command::Finalize => {
-            // Finalize marks a milestone without changing state
-            let before: StateSnapshot = state.to_snapshot();
-            let after: StateSnapshot = state.to_snapshot();
+            // Create new metadata (unchanged - current bidder lives in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let describe = |user_id: Option<i64>| {
+                user_id.map_or_else(|| String::from("none"), |id| id.to_string())
+            };
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},current_user_id={}",
+                describe(previous_user_id)
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},current_user_id={}",
+                describe(next_user_id)
+            ));
 
+            let trigger: &str = if time_driven {
+                "window elapsed"
+            } else {
+                "manual"
+            };
             let action: Action = Action::new(
-                String::from("Finalize"),
-                Some(String::from("Milestone finalized")),
+                String::from("AdvanceBidder"),
+                Some(format!(
+                    "Advanced round {round_id} in area {} from user {} to user {} ({trigger})",
+                    area.id(),
+                    describe(previous_user_id),
+                    describe(next_user_id)
+                )),
             );
 
-            let audit_event: AuditEvent = AuditEvent::new(
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
                 actor,
                 cause,
                 action,
                 before,
                 after,
-                state.bid_year.clone(),
-                state.area.clone(),
-            );
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: None,
+            };
 
-            Ok(TransitionResult {
-                new_state: state.clone(),
+            Ok(BootstrapResult {
+                new_metadata,
                 audit_event,
+                canonical_bid_year: None,
             })
         }
-        Command::RollbackToEventId { target_event_id } => {
-            // Rollback creates a new audit event that references a prior event
-            // The actual state reconstruction from the target event would be done
-            // by the persistence layer when replaying events
-            // For now, this just creates the rollback audit event
-            let before: StateSnapshot = state.to_snapshot();
-            let after: StateSnapshot = state.to_snapshot();
+        Command::ExpireBidWindow {
+            year,
+            area,
+            round_id,
+            user_id,
+            marked_missed,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            // Create new metadata (unchanged - bid status lives in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot =
+                StateSnapshot::new(format!("round_id={round_id},user_id={user_id},window=open"));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},user_id={user_id},window=expired,marked_missed={marked_missed}"
+            ));
+
+            let outcome: &str = if marked_missed {
+                "marked missed"
+            } else {
+                "status left unchanged"
+            };
+            let action: Action = Action::new(
+                String::from("WindowExpired"),
+                Some(format!(
+                    "Window for user {user_id} in round {round_id} of area {} elapsed without a submitted bid ({outcome})",
+                    area.id()
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::EnterLeaveBid {
+            year,
+            area,
+            round_id,
+            user_id,
+            on_behalf_of,
+            received_via,
+            leave_dates,
+            hours,
+            signed_off,
+            override_reason,
+            amendment,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            if leave_dates.is_empty() {
+                return Err(CoreError::DomainViolation(DomainError::InvalidLeaveBid {
+                    reason: String::from("At least one leave date is required"),
+                }));
+            }
+            if hours == 0 {
+                return Err(CoreError::DomainViolation(DomainError::InvalidLeaveBid {
+                    reason: String::from("Leave hours must be greater than zero"),
+                }));
+            }
+            let override_reason: Option<String> = override_reason
+                .map(|reason| reason.trim().to_string())
+                .filter(|reason| !reason.is_empty());
+            if signed_off && override_reason.is_none() {
+                return Err(CoreError::DomainViolation(DomainError::RoundSignedOff {
+                    round_id,
+                }));
+            }
+            if let Some(amendment) = &amendment {
+                amendment
+                    .check(round_id)
+                    .map_err(CoreError::DomainViolation)?;
+            }
+            let mut sorted_dates: Vec<time::Date> = leave_dates;
+            sorted_dates.sort_unstable();
+            if let Some(&[date, _]) = sorted_dates
+                .windows(2)
+                .find(|pair| pair.first() == pair.last())
+            {
+                return Err(CoreError::DomainViolation(DomainError::InvalidLeaveBid {
+                    reason: format!("Leave date {date} is listed more than once"),
+                }));
+            }
+
+            // Create new metadata (unchanged - leave bids live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let dates: String = sorted_dates
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join("|");
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},user_id={user_id},on_behalf_of={}",
+                on_behalf_of.value()
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},user_id={user_id},on_behalf_of={},received_via={},leave_dates={dates},hours={hours}",
+                on_behalf_of.value(),
+                received_via.as_str()
+            ));
+
+            let entered: String = format!(
+                "Entered {} leave day(s) in round {round_id} on behalf of {} (received via {})",
+                sorted_dates.len(),
+                on_behalf_of.value(),
+                received_via.as_str()
+            );
+            let details: String = match &override_reason {
+                Some(reason) if signed_off => format!("{entered} overriding sign-off: {reason}"),
+                _ => entered,
+            };
+            let action: Action = Action::new(String::from("EnterLeaveBid"), Some(details));
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::SubmitBidPreferences {
+            year,
+            area,
+            round_id,
+            user_id,
+            on_behalf_of,
+            received_via,
+            preferences,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            if preferences.is_empty() {
+                return Err(CoreError::DomainViolation(
+                    DomainError::InvalidBidPreference {
+                        reason: String::from("At least one preference is required"),
+                    },
+                ));
+            }
+            let mut ranks: Vec<u32> = preferences.iter().map(BidPreference::rank).collect();
+            ranks.sort_unstable();
+            if let Some(&[rank, _]) = ranks.windows(2).find(|pair| pair.first() == pair.last()) {
+                return Err(CoreError::DomainViolation(
+                    DomainError::InvalidBidPreference {
+                        reason: format!("Rank {rank} is used more than once"),
+                    },
+                ));
+            }
+
+            // Create new metadata (unchanged - preferences live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},user_id={user_id},on_behalf_of={}",
+                on_behalf_of.value()
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},user_id={user_id},on_behalf_of={},received_via={},preferences={}",
+                on_behalf_of.value(),
+                received_via.as_str(),
+                format_preferences(&preferences)
+            ));
+
+            let action: Action = Action::new(
+                String::from("SubmitBidPreferences"),
+                Some(format!(
+                    "Submitted {} ranked preference(s) for round {round_id} on behalf of {} (received via {})",
+                    preferences.len(),
+                    on_behalf_of.value(),
+                    received_via.as_str()
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::ApplyBidPreference {
+            year,
+            area,
+            round_id,
+            user_id,
+            on_behalf_of: _,
+            applied,
+            passed_over,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            if let Some(preference) = applied
+                .as_ref()
+                .filter(|preference| passed_over.contains(&preference.rank()))
+            {
+                return Err(CoreError::DomainViolation(
+                    DomainError::InvalidBidPreference {
+                        reason: format!(
+                            "Preference {} cannot be both applied and passed over",
+                            preference.rank()
+                        ),
+                    },
+                ));
+            }
+
+            // Create new metadata (unchanged - leave bids live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let passed_over_ranks: String = passed_over
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join("|");
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},user_id={user_id},preferences=pending"
+            ));
+            let applied_preference: String = applied.as_ref().map_or_else(
+                || String::from("none"),
+                |preference| format_preferences(std::slice::from_ref(preference)),
+            );
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},user_id={user_id},applied={applied_preference},passed_over={passed_over_ranks}"
+            ));
+
+            let details: String = applied.as_ref().map_or_else(
+                || {
+                    format!(
+                        "No preference for user {user_id} in round {round_id} could be applied ({} passed over)",
+                        passed_over.len()
+                    )
+                },
+                |preference| {
+                    format!(
+                        "Applied preference {} for user {user_id} in round {round_id} ({} leave day(s), {} passed over)",
+                        preference.rank(),
+                        preference.leave_dates().len(),
+                        passed_over.len()
+                    )
+                },
+            );
+            let action: Action = Action::new(String::from("ApplyBidPreference"), Some(details));
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::AdjustSlotInventory {
+            year,
+            area,
+            round_id,
+            date,
+            previous_adjustment,
+            staffing_adjustment,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            // Create new metadata (unchanged - slot inventory lives in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},date={date},staffing_adjustment={previous_adjustment}"
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},date={date},staffing_adjustment={staffing_adjustment}"
+            ));
+
+            let action: Action = Action::new(
+                String::from("AdjustSlotInventory"),
+                Some(format!(
+                    "Adjusted leave slots in round {round_id} on {date} by {staffing_adjustment:+} (was {previous_adjustment:+})"
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: None,
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::RequestOverbid {
+            year,
+            area,
+            round_id,
+            user_id,
+            on_behalf_of,
+            received_via,
+            leave_date,
+            hours,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            if hours == 0 {
+                return Err(CoreError::DomainViolation(DomainError::InvalidOverbid {
+                    reason: String::from("Leave hours must be greater than zero"),
+                }));
+            }
+
+            // Create new metadata (unchanged - overbid requests live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},user_id={user_id},leave_date={leave_date},overbid=none"
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},user_id={user_id},leave_date={leave_date},hours={hours},on_behalf_of={},received_via={},overbid=pending",
+                on_behalf_of.value(),
+                received_via.as_str()
+            ));
+
+            let action: Action = Action::new(
+                String::from("RequestOverbid"),
+                Some(format!(
+                    "Requested overbid for {leave_date} in round {round_id} on behalf of {} (received via {})",
+                    on_behalf_of.value(),
+                    received_via.as_str()
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::ApproveOverbid {
+            year,
+            area,
+            overbid_request_id,
+            request_event_id,
+            round_id,
+            user_id,
+            leave_date,
+            previous_adjustment,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            let staffing_adjustment: i32 = previous_adjustment.checked_add(1).ok_or_else(|| {
+                CoreError::DomainViolation(DomainError::InvalidOverbid {
+                    reason: format!("Staffing adjustment on {leave_date} cannot grow further"),
+                })
+            })?;
+
+            // Create new metadata (unchanged - overbid requests live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "overbid_request_id={overbid_request_id},request_event_id={request_event_id},round_id={round_id},user_id={user_id},leave_date={leave_date},overbid=pending,staffing_adjustment={previous_adjustment}"
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "overbid_request_id={overbid_request_id},request_event_id={request_event_id},round_id={round_id},user_id={user_id},leave_date={leave_date},overbid=approved,staffing_adjustment={staffing_adjustment}"
+            ));
+
+            let action: Action = Action::new(
+                String::from("ApproveOverbid"),
+                Some(format!(
+                    "Approved overbid request {overbid_request_id} (event {request_event_id}) for user {user_id} on {leave_date} in round {round_id}"
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::DenyOverbid {
+            year,
+            area,
+            overbid_request_id,
+            request_event_id,
+            round_id,
+            user_id,
+            leave_date,
+            reason,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            if reason.trim().is_empty() {
+                return Err(CoreError::DomainViolation(DomainError::InvalidOverbid {
+                    reason: String::from("A reason is required to deny an overbid"),
+                }));
+            }
+
+            // Create new metadata (unchanged - overbid requests live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "overbid_request_id={overbid_request_id},request_event_id={request_event_id},round_id={round_id},user_id={user_id},leave_date={leave_date},overbid=pending"
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "overbid_request_id={overbid_request_id},request_event_id={request_event_id},round_id={round_id},user_id={user_id},leave_date={leave_date},overbid=denied"
+            ));
+
+            let action: Action = Action::new(
+                String::from("DenyOverbid"),
+                Some(format!(
+                    "Denied overbid request {overbid_request_id} (event {request_event_id}) for user {user_id} on {leave_date} in round {round_id}: {}",
+                    reason.trim()
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::RequestSelfBidEntry {
+            year,
+            area,
+            round_id,
+            user_id,
+            on_behalf_of,
+            received_via,
+            leave_dates,
+            hours,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            if leave_dates.is_empty() {
+                return Err(CoreError::DomainViolation(DomainError::InvalidSelfBid {
+                    reason: String::from("At least one leave date is required"),
+                }));
+            }
+            if hours == 0 {
+                return Err(CoreError::DomainViolation(DomainError::InvalidSelfBid {
+                    reason: String::from("Leave hours must be greater than zero"),
+                }));
+            }
+
+            // Create new metadata (unchanged - self-service bids live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let mut sorted_dates: Vec<time::Date> = leave_dates;
+            sorted_dates.sort_unstable();
+            let dates: String = sorted_dates
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join("|");
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},user_id={user_id},self_bid=none"
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},user_id={user_id},on_behalf_of={},received_via={},leave_dates={dates},hours={hours},self_bid=pending",
+                on_behalf_of.value(),
+                received_via.as_str()
+            ));
+
+            let action: Action = Action::new(
+                String::from("RequestSelfBidEntry"),
+                Some(format!(
+                    "Requested {} leave day(s) in round {round_id} for own initials {} (received via {}); awaiting a second operator",
+                    sorted_dates.len(),
+                    on_behalf_of.value(),
+                    received_via.as_str()
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::ConfirmSelfBidEntry {
+            year,
+            area,
+            self_bid_request_id,
+            request_event_id,
+            entry_event_id,
+            requested_by_operator_id,
+            round_id,
+            user_id,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            if actor.operator_id == Some(requested_by_operator_id) {
+                return Err(CoreError::DomainViolation(DomainError::InvalidSelfBid {
+                    reason: String::from(
+                        "A self-service bid must be confirmed by a different operator",
+                    ),
+                }));
+            }
+
+            // Create new metadata (unchanged - self-service bids live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "self_bid_request_id={self_bid_request_id},request_event_id={request_event_id},requested_by_operator_id={requested_by_operator_id},round_id={round_id},user_id={user_id},self_bid=pending"
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "self_bid_request_id={self_bid_request_id},request_event_id={request_event_id},entry_event_id={entry_event_id},requested_by_operator_id={requested_by_operator_id},round_id={round_id},user_id={user_id},self_bid=confirmed"
+            ));
+
+            let action: Action = Action::new(
+                String::from("ConfirmSelfBidEntry"),
+                Some(format!(
+                    "Confirmed self-service bid request {self_bid_request_id} (event {request_event_id}) by operator {requested_by_operator_id} for user {user_id} in round {round_id}; entered as event {entry_event_id}"
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::SignOffRound {
+            year,
+            area,
+            round_id,
+            bid,
+            skipped,
+            waived,
+            outstanding,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            if !outstanding.is_empty() {
+                return Err(CoreError::DomainViolation(DomainError::RoundNotComplete {
+                    round_id,
+                    outstanding: outstanding
+                        .iter()
+                        .map(|initials| initials.value().to_string())
+                        .collect(),
+                }));
+            }
+
+            // Create new metadata (unchanged - sign-offs live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot =
+                StateSnapshot::new(format!("round_id={round_id},signed_off=false"));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "round_id={round_id},signed_off=true,bid={bid},skipped={skipped},waived={waived}"
+            ));
+
+            let action: Action = Action::new(
+                String::from("SignOffRound"),
+                Some(format!(
+                    "Signed off round {round_id} in area {}: {bid} bid, {skipped} skipped, {waived} waived",
+                    area.id()
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: None,
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::WithdrawLeaveBid {
+            year,
+            area,
+            round_id,
+            user_id,
+            on_behalf_of: _,
+            leave_bid_id,
+            leave_date,
+            round_closed,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            // Create new metadata (unchanged - leave bids live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let waitlist: &str = if round_closed { "open" } else { "none" };
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "leave_bid_id={leave_bid_id},round_id={round_id},user_id={user_id},leave_date={leave_date},status=approved"
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "leave_bid_id={leave_bid_id},round_id={round_id},user_id={user_id},leave_date={leave_date},status=withdrawn,waitlist={waitlist}"
+            ));
+
+            let withdrawn: String = format!(
+                "Withdrew leave bid {leave_bid_id} for user {user_id} on {leave_date} in round {round_id}"
+            );
+            let details: String = if round_closed {
+                format!("{withdrawn}; slot offered to the waitlist")
+            } else {
+                withdrawn
+            };
+            let action: Action = Action::new(String::from("WithdrawLeaveBid"), Some(details));
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::OfferWaitlistSlot {
+            year,
+            area,
+            round_id,
+            waitlist_slot_id,
+            user_id,
+            leave_date,
+            offered_at,
+            expires_at,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            if expires_at <= offered_at {
+                return Err(CoreError::DomainViolation(
+                    DomainError::InvalidWaitlistOffer {
+                        reason: String::from("An offer must expire after it is made"),
+                    },
+                ));
+            }
+
+            // Create new metadata (unchanged - waitlist offers live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "waitlist_slot_id={waitlist_slot_id},round_id={round_id},leave_date={leave_date},offered_to=none"
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "waitlist_slot_id={waitlist_slot_id},round_id={round_id},leave_date={leave_date},offered_to={user_id},expires_at={}",
+                format_instant(expires_at)
+            ));
+
+            let action: Action = Action::new(
+                String::from("OfferWaitlistSlot"),
+                Some(format!(
+                    "Offered freed slot on {leave_date} in round {round_id} to user {user_id} until {}",
+                    format_instant(expires_at)
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::AcceptWaitlistOffer {
+            year,
+            area,
+            round_id,
+            waitlist_offer_id,
+            user_id,
+            on_behalf_of,
+            received_via,
+            leave_date,
+            expires_at,
+            accepted_at,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            if accepted_at > expires_at {
+                return Err(CoreError::DomainViolation(
+                    DomainError::WaitlistOfferExpired {
+                        waitlist_offer_id,
+                        expires_at: format_instant(expires_at),
+                    },
+                ));
+            }
+
+            // Create new metadata (unchanged - waitlist offers live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "waitlist_offer_id={waitlist_offer_id},round_id={round_id},user_id={user_id},leave_date={leave_date},offer=pending"
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "waitlist_offer_id={waitlist_offer_id},round_id={round_id},user_id={user_id},leave_date={leave_date},offer=accepted,on_behalf_of={},received_via={}",
+                on_behalf_of.value(),
+                received_via.as_str()
+            ));
+
+            let action: Action = Action::new(
+                String::from("AcceptWaitlistOffer"),
+                Some(format!(
+                    "Accepted waitlist offer {waitlist_offer_id} for {leave_date} in round {round_id} on behalf of {} (received via {})",
+                    on_behalf_of.value(),
+                    received_via.as_str()
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::DeclineWaitlistOffer {
+            year,
+            area,
+            round_id,
+            waitlist_offer_id,
+            user_id,
+            leave_date,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            // Create new metadata (unchanged - waitlist offers live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "waitlist_offer_id={waitlist_offer_id},round_id={round_id},user_id={user_id},leave_date={leave_date},offer=pending"
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "waitlist_offer_id={waitlist_offer_id},round_id={round_id},user_id={user_id},leave_date={leave_date},offer=declined"
+            ));
+
+            let action: Action = Action::new(
+                String::from("DeclineWaitlistOffer"),
+                Some(format!(
+                    "User {user_id} declined waitlist offer {waitlist_offer_id} for {leave_date} in round {round_id}"
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::ExpireWaitlistOffer {
+            year,
+            area,
+            round_id,
+            waitlist_offer_id,
+            user_id,
+            leave_date,
+            expires_at,
+        } => {
+            let bid_year = BidYear::new(year);
+
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    year,
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: year,
+                    area: area.id().to_string(),
+                }));
+            }
+
+            // Create new metadata (unchanged - waitlist offers live in persistence)
+            let new_metadata: BootstrapMetadata = metadata.clone();
+
+            let before: StateSnapshot = StateSnapshot::new(format!(
+                "waitlist_offer_id={waitlist_offer_id},round_id={round_id},user_id={user_id},leave_date={leave_date},offer=pending"
+            ));
+            let after: StateSnapshot = StateSnapshot::new(format!(
+                "waitlist_offer_id={waitlist_offer_id},round_id={round_id},user_id={user_id},leave_date={leave_date},offer=expired"
+            ));
+
+            let action: Action = Action::new(
+                String::from("ExpireWaitlistOffer"),
+                Some(format!(
+                    "Waitlist offer {waitlist_offer_id} to user {user_id} for {leave_date} in round {round_id} expired at {}",
+                    format_instant(expires_at)
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: Some(user_id),
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::FreezeScope {
+            bid_year,
+            area,
+            reason,
+        } => {
+            // Validate bid year and area exist
+            if !metadata.has_bid_year(&bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    bid_year.year(),
+                )));
+            }
+            if !metadata.has_area(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: bid_year.year(),
+                    area: area.id().to_string(),
+                }));
+            }
+            if metadata.is_frozen(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::ScopeFrozen {
+                    bid_year: bid_year.year(),
+                    area: area.id().to_string(),
+                }));
+            }
+
+            let reason: &str = reason.trim();
+            if reason.len() < MIN_FREEZE_REASON_LEN {
+                return Err(CoreError::DomainViolation(
+                    DomainError::InvalidFreezeReason {
+                        reason: reason.to_string(),
+                    },
+                ));
+            }
+
+            let mut new_metadata: BootstrapMetadata = metadata.clone();
+            new_metadata.freeze_scope(bid_year.clone(), area.clone());
+
+            let before: StateSnapshot = StateSnapshot::new(String::from("frozen=false"));
+            let after: StateSnapshot = StateSnapshot::new(String::from("frozen=true"));
+
+            let action: Action = Action::new(
+                String::from("FreezeScope"),
+                Some(format!(
+                    "Froze area {} in bid year {}. Reason: {reason}",
+                    area.id(),
+                    bid_year.year()
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: None,
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        Command::Unfreeze { bid_year, area } => {
+            if !metadata.is_frozen(&bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::ScopeNotFrozen {
+                    bid_year: bid_year.year(),
+                    area: area.id().to_string(),
+                }));
+            }
+
+            let mut new_metadata: BootstrapMetadata = metadata.clone();
+            new_metadata.unfreeze_scope(&bid_year, &area);
+
+            let before: StateSnapshot = StateSnapshot::new(String::from("frozen=true"));
+            let after: StateSnapshot = StateSnapshot::new(String::from("frozen=false"));
+
+            let action: Action = Action::new(
+                String::from("Unfreeze"),
+                Some(format!(
+                    "Unfroze area {} in bid year {}",
+                    area.id(),
+                    bid_year.year()
+                )),
+            );
+
+            let audit_event: AuditEvent = AuditEvent {
+                event_id: None,
+                event_ulid: None,
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                bid_year: Some(bid_year),
+                area: Some(area),
+                user_id: None,
+            };
+
+            Ok(BootstrapResult {
+                new_metadata,
+                audit_event,
+                canonical_bid_year: None,
+            })
+        }
+        _ => {
+            // Non-bootstrap commands should use apply() instead
+            unreachable!("apply_bootstrap called with non-bootstrap command")
+        }
+    }
+}
+
+/// Applies a command to the state, producing a new state and audit event.
+///
+/// Commands are validated and applied atomically. Either they succeed completely
+/// or they fail without side effects.
+///
+/// # Arguments
+///
+/// * `metadata` - The bootstrap metadata (immutable)
+/// * `state` - The current state (immutable)
+/// * `active_bid_year` - The active bid year (must be validated by caller)
+/// * `command` - The command to apply
+/// * `actor` - The actor performing this action
+/// * `cause` - The cause or reason for this action
+///
+/// # Returns
+///
+/// * `Ok(TransitionResult)` containing the new state and audit event
+/// * `Err(CoreError)` if the command is invalid
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The command violates domain rules
+/// - The user already exists (for `RegisterUser`)
+pub fn apply(
+    metadata: &BootstrapMetadata,
+    state: &State,
+    active_bid_year: &BidYear,
+    command: Command,
+    actor: Actor,
+    cause: Cause,
+) -> Result<TransitionResult, CoreError> {
+    ensure_not_frozen(metadata, Some(state), active_bid_year, &command)?;
+
+    state_transition(metadata, state, active_bid_year, command, actor, cause)
+}
+
+/// Applies a state command once the scope is known not to be frozen.
+#[allow(clippy::too_many_lines)]
+fn state_transition(
+    metadata: &BootstrapMetadata,
+    state: &State,
+    active_bid_year: &BidYear,
+    command: Command,
+    actor: Actor,
+    cause: Cause,
+) -> Result<TransitionResult, CoreError> {
+    match command {
+        Command::RegisterUser {
+            initials,
+            name,
+            area,
+            user_type,
+            crew,
+            seniority_data,
+        } => {
+            // Use the active bid year
+            let bid_year = active_bid_year;
+
+            // Validate bid year exists
+            if !metadata.has_bid_year(bid_year) {
+                return Err(CoreError::DomainViolation(DomainError::BidYearNotFound(
+                    bid_year.year(),
+                )));
+            }
+
+            // Validate area exists in bid year
+            if !metadata.has_area(bid_year, &area) {
+                return Err(CoreError::DomainViolation(DomainError::AreaNotFound {
+                    bid_year: bid_year.year(),
+                    area: area.id().to_string(),
+                }));
+            }
+
+            // Create the user object
+            let user: User = User::new(
+                bid_year.clone(),
+                initials.clone(),
+                name,
+                area,
+                user_type,
+                crew,
+                seniority_data,
+                false, // excluded_from_bidding: default to false
+                false, // excluded_from_leave_calculation: default to false
+                false, // no_bid_reviewed: default to false
+            );
+
+            // Validate user field constraints
+            validate_user_fields(&user)?;
+
+            // Validate initials are unique within the bid year
+            if state.users.contains_key(&initials) {
+                return Err(CoreError::DomainViolation(DomainError::DuplicateInitials {
+                    bid_year: bid_year.clone(),
+                    initials,
+                }));
+            }
+
+            // Capture state before transition
+            let before: StateSnapshot = state.to_snapshot();
+
+            // Create new state with the user added
+            let mut new_state: State = state.clone();
+            new_state.insert_user(user);
+
+            // Capture state after transition
+            let after: StateSnapshot = new_state.to_snapshot();
+
+            // Create audit event
+            let action: Action = Action::new(
+                String::from("RegisterUser"),
+                Some(format!(
+                    "Registered user with initials '{}' for bid year {}",
+                    initials.value(),
+                    bid_year.year()
+                )),
+            );
+            let audit_event: AuditEvent = AuditEvent::new(
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                state.bid_year.clone(),
+                state.area.clone(),
+            );
+
+            Ok(TransitionResult {
+                new_state,
+                audit_event,
+            })
+        }
+        Command::Checkpoint { label, description } => {
+            // Checkpoint creates a snapshot without changing state
+            let details: String = checkpoint_details(label.as_deref(), description.as_deref())?;
+            let before: StateSnapshot = state.to_snapshot();
+            let after: StateSnapshot = state.to_snapshot();
+
+            let action: Action = Action::new(String::from("Checkpoint"), Some(details));
+
+            let audit_event: AuditEvent = AuditEvent::new(
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                state.bid_year.clone(),
+                state.area.clone(),
+            );
+
+            Ok(TransitionResult {
+                new_state: state.clone(),
+                audit_event,
+            })
+        }
+        Command::Finalize => {
+            // Finalize marks a milestone without changing state
+            let before: StateSnapshot = state.to_snapshot();
+            let after: StateSnapshot = state.to_snapshot();
+
+            let action: Action = Action::new(
+                String::from("Finalize"),
+                Some(String::from("Milestone finalized")),
+            );
+
+            let audit_event: AuditEvent = AuditEvent::new(
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                state.bid_year.clone(),
+                state.area.clone(),
+            );
+
+            Ok(TransitionResult {
+                new_state: state.clone(),
+                audit_event,
+            })
+        }
+        Command::RollbackToEventId { target_event_id } => {
+            // Rollback creates a new audit event that references a prior event
+            // The persistence layer restores the area's state as of the target
+            // when it persists this event
+            let before: StateSnapshot = state.to_snapshot();
+            let after: StateSnapshot = state.to_snapshot();
 
             let action: Action = Action::new(
                 String::from("Rollback"),
-                Some(format!("Rolled back to event ID {target_event_id}")),
+                Some(format!("{ROLLBACK_DETAILS_PREFIX}{target_event_id}")),
             );
 
             let audit_event: AuditEvent = AuditEvent::new(
@@ -750,21 +2496,25 @@ pub fn apply(
             }
 
             // Find the user to update by canonical user_id
-            let user_index: Option<usize> = state
-                .users
-                .iter()
-                .position(|u| u.user_id == Some(user_id) && &u.bid_year == bid_year);
-
-            let user_index: usize = user_index.ok_or_else(|| {
-                CoreError::DomainViolation(DomainError::UserNotFound {
-                    bid_year: bid_year.year(),
-                    area: area.id().to_string(),
-                    initials: initials.value().to_string(),
-                })
-            })?;
-
-            // Get existing user to preserve participation flags
-            let existing_user: &User = &state.users[user_index];
+            let existing_user: &User = state
+                .find_user_by_id(user_id)
+                .filter(|u| &u.bid_year == bid_year)
+                .ok_or_else(|| {
+                    CoreError::DomainViolation(DomainError::UserNotFound {
+                        bid_year: bid_year.year(),
+                        area: area.id().to_string(),
+                        initials: initials.value().to_string(),
+                    })
+                })?;
+
+            // Renaming must not collide with another user's initials
+            if existing_user.initials != initials && state.users.contains_key(&initials) {
+                return Err(CoreError::DomainViolation(DomainError::DuplicateInitials {
+                    bid_year: bid_year.clone(),
+                    initials,
+                }));
+            }
+            let previous_initials: Initials = existing_user.initials.clone();
 
             // Create the updated user object (preserve user_id and participation flags)
             let updated_user: User = User::with_id(
@@ -788,13 +2538,9 @@ pub fn apply(
             let before: StateSnapshot = state.to_snapshot();
 
             // Create new state with the user updated
-            let mut new_users: Vec<User> = state.users.clone();
-            new_users[user_index] = updated_user;
-            let new_state: State = State {
-                bid_year: state.bid_year.clone(),
-                area: state.area.clone(),
-                users: new_users,
-            };
+            let mut new_state: State = state.clone();
+            new_state.users.remove(&previous_initials);
+            new_state.insert_user(updated_user);
 
             // Capture state after transition
             let after: StateSnapshot = new_state.to_snapshot();
@@ -817,7 +2563,8 @@ pub fn apply(
                 after,
                 state.bid_year.clone(),
                 state.area.clone(),
-            );
+            )
+            .with_user_id(Some(user_id));
 
             Ok(TransitionResult {
                 new_state,
@@ -834,20 +2581,16 @@ pub fn apply(
             let bid_year = active_bid_year;
 
             // Find the user to update by canonical user_id
-            let user_index: Option<usize> = state
-                .users
-                .iter()
-                .position(|u| u.user_id == Some(user_id) && &u.bid_year == bid_year);
-
-            let user_index: usize = user_index.ok_or_else(|| {
-                CoreError::DomainViolation(DomainError::UserNotFound {
-                    bid_year: bid_year.year(),
-                    area: state.area.id().to_string(),
-                    initials: initials.value().to_string(),
-                })
-            })?;
-
-            let existing_user: &User = &state.users[user_index];
+            let existing_user: &User = state
+                .find_user_by_id(user_id)
+                .filter(|u| &u.bid_year == bid_year)
+                .ok_or_else(|| {
+                    CoreError::DomainViolation(DomainError::UserNotFound {
+                        bid_year: bid_year.year(),
+                        area: state.area.id().to_string(),
+                        initials: initials.value().to_string(),
+                    })
+                })?;
 
             // Create the updated user object with new participation flags (preserve user_id)
             let updated_user: User = User::with_id(
@@ -871,13 +2614,8 @@ pub fn apply(
             let before: StateSnapshot = state.to_snapshot();
 
             // Create new state with the user updated
-            let mut new_users: Vec<User> = state.users.clone();
-            new_users[user_index] = updated_user;
-            let new_state: State = State {
-                bid_year: state.bid_year.clone(),
-                area: state.area.clone(),
-                users: new_users,
-            };
+            let mut new_state: State = state.clone();
+            new_state.insert_user(updated_user);
 
             // Capture state after transition
             let after: StateSnapshot = new_state.to_snapshot();
@@ -901,7 +2639,174 @@ pub fn apply(
                 after,
                 state.bid_year.clone(),
                 state.area.clone(),
+            )
+            .with_user_id(Some(user_id));
+
+            Ok(TransitionResult {
+                new_state,
+                audit_event,
+            })
+        }
+        Command::MergeUsers {
+            keep,
+            merge,
+            reason,
+        } => {
+            // Use the active bid year
+            let bid_year = active_bid_year;
+
+            if keep == merge {
+                return Err(CoreError::DomainViolation(DomainError::InvalidUserMerge {
+                    reason: format!("user_id={keep} cannot be merged into itself"),
+                }));
+            }
+
+            let reason: &str = reason.trim();
+            if reason.len() < MIN_MERGE_REASON_LEN {
+                return Err(CoreError::DomainViolation(DomainError::InvalidUserMerge {
+                    reason: format!(
+                        "a reason of at least {MIN_MERGE_REASON_LEN} characters is required (got: '{reason}')"
+                    ),
+                }));
+            }
+
+            // Both records must be in this area's state
+            let find = |user_id: i64| -> Result<&User, CoreError> {
+                state
+                    .find_user_by_id(user_id)
+                    .filter(|u| &u.bid_year == bid_year)
+                    .ok_or_else(|| {
+                        CoreError::DomainViolation(DomainError::InvalidUserMerge {
+                            reason: format!(
+                                "user_id={user_id} is not in area '{}' of bid year {}",
+                                state.area.id(),
+                                bid_year.year()
+                            ),
+                        })
+                    })
+            };
+            let kept_user: &User = find(keep)?;
+            let merged_user: &User = find(merge)?;
+
+            // The duplicate stays on record but no longer bids or accrues
+            let retired_user: User = User::with_id(
+                merge,
+                merged_user.bid_year.clone(),
+                merged_user.initials.clone(),
+                merged_user.name.clone(),
+                merged_user.area.clone(),
+                merged_user.user_type,
+                merged_user.crew,
+                merged_user.seniority_data.clone(),
+                true,
+                true,
+                merged_user.no_bid_reviewed,
+            );
+
+            // Capture state before transition
+            let before: StateSnapshot = state.to_snapshot();
+
+            let mut new_state: State = state.clone();
+            new_state.insert_user(retired_user);
+
+            // Capture state after transition
+            let after: StateSnapshot = new_state.to_snapshot();
+
+            let action: Action = Action::new(
+                String::from("MergeUsers"),
+                Some(format!(
+                    "Merged user_id={} (initials '{}') into user_id={} (initials '{}') for bid year {}. Reason: {reason}",
+                    merge,
+                    merged_user.initials.value(),
+                    keep,
+                    kept_user.initials.value(),
+                    bid_year.year()
+                )),
+            );
+            let audit_event: AuditEvent = AuditEvent::new(
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                state.bid_year.clone(),
+                state.area.clone(),
+            )
+            .with_user_id(Some(keep));
+
+            Ok(TransitionResult {
+                new_state,
+                audit_event,
+            })
+        }
+        Command::ChangeInitials {
+            from,
+            to,
+            effective_date,
+        } => {
+            // Use the active bid year
+            let bid_year = active_bid_year;
+
+            let existing_user: &User = state
+                .users
+                .get(&from)
+                .filter(|u| &u.bid_year == bid_year)
+                .ok_or_else(|| {
+                CoreError::DomainViolation(DomainError::UserNotFound {
+                    bid_year: bid_year.year(),
+                    area: state.area.id().to_string(),
+                    initials: from.value().to_string(),
+                })
+            })?;
+
+            if to == from {
+                return Err(CoreError::DomainViolation(DomainError::InvalidInitials(
+                    format!("User already has initials '{}'", to.value()),
+                )));
+            }
+            if state.users.contains_key(&to) {
+                return Err(CoreError::DomainViolation(DomainError::DuplicateInitials {
+                    bid_year: bid_year.clone(),
+                    initials: to,
+                }));
+            }
+
+            let mut renamed_user: User = existing_user.clone();
+            renamed_user.initials = to.clone();
+            validate_user_fields(&renamed_user)?;
+
+            // Capture state before transition
+            let before: StateSnapshot = state.to_snapshot();
+
+            let mut new_state: State = state.clone();
+            new_state.users.remove(&from);
+            new_state.insert_user(renamed_user);
+
+            // Capture state after transition
+            let after: StateSnapshot = new_state.to_snapshot();
+
+            let user_id: String = existing_user
+                .user_id
+                .map_or_else(|| String::from("unknown"), |id| id.to_string());
+            let action: Action = Action::new(
+                String::from("ChangeInitials"),
+                Some(format!(
+                    "Changed initials of user_id={user_id} from '{}' to '{}' effective {effective_date} for bid year {}",
+                    from.value(),
+                    to.value(),
+                    bid_year.year()
+                )),
             );
+            let audit_event: AuditEvent = AuditEvent::new(
+                actor,
+                cause,
+                action,
+                before,
+                after,
+                state.bid_year.clone(),
+                state.area.clone(),
+            )
+            .with_user_id(existing_user.user_id);
 
             Ok(TransitionResult {
                 new_state,
@@ -916,8 +2821,28 @@ pub fn apply(
         | Command::TransitionToBootstrapComplete { .. }
         | Command::TransitionToCanonicalized { .. }
         | Command::ConfirmReadyToBid { .. }
-        | Command::TransitionToBiddingActive { .. }
-        | Command::TransitionToBiddingClosed { .. } => {
+        | Command::ActivateBidding { .. }
+        | Command::TransitionToBiddingClosed { .. }
+        | Command::ReopenBidYear { .. }
+        | Command::AdvanceBidder { .. }
+        | Command::ExpireBidWindow { .. }
+        | Command::EnterLeaveBid { .. }
+        | Command::SubmitBidPreferences { .. }
+        | Command::ApplyBidPreference { .. }
+        | Command::AdjustSlotInventory { .. }
+        | Command::RequestOverbid { .. }
+        | Command::ApproveOverbid { .. }
+        | Command::DenyOverbid { .. }
+        | Command::RequestSelfBidEntry { .. }
+        | Command::ConfirmSelfBidEntry { .. }
+        | Command::SignOffRound { .. }
+        | Command::WithdrawLeaveBid { .. }
+        | Command::OfferWaitlistSlot { .. }
+        | Command::AcceptWaitlistOffer { .. }
+        | Command::DeclineWaitlistOffer { .. }
+        | Command::ExpireWaitlistOffer { .. }
+        | Command::FreezeScope { .. }
+        | Command::Unfreeze { .. } => {
             // Bootstrap commands should use apply_bootstrap() instead
             unreachable!("apply called with bootstrap command")
         }
@@ -933,9 +2858,61 @@ pub fn apply(
         | Command::DeleteRoundGroup { .. }
         | Command::CreateRound { .. }
         | Command::UpdateRound { .. }
-        | Command::DeleteRound { .. } => {
+        | Command::DeleteRound { .. }
+        | Command::ReorderRounds { .. } => {
             // Round configuration commands are managed directly in API layer, not through apply()
             unreachable!("apply called with round configuration command")
         }
     }
 }
+
+/// Applies a sequence of commands to a single scope, threading state between them.
+///
+/// Every command is attempted; a failure is recorded in its outcome slot and
+/// does not abort the batch. Callers decide whether to persist the successful
+/// subset or reject the whole batch based on the outcomes.
+///
+/// # Arguments
+///
+/// * `metadata` - The current bootstrap metadata (immutable)
+/// * `state` - The starting state for the scope (immutable)
+/// * `active_bid_year` - The active bid year
+/// * `commands` - The commands to apply, in order
+/// * `actor` - The actor performing these actions
+/// * `cause` - The cause or reason for these actions
+///
+/// # Returns
+///
+/// A `BatchTransitionResult` with one outcome per command and the final state.
+#[must_use]
+pub fn apply_batch(
+    metadata: &BootstrapMetadata,
+    state: &State,
+    active_bid_year: &BidYear,
+    commands: Vec<Command>,
+    actor: &Actor,
+    cause: &Cause,
+) -> BatchTransitionResult {
+    let mut current: State = state.clone();
+    let mut outcomes: Vec<Result<TransitionResult, CoreError>> = Vec::with_capacity(commands.len());
+
+    for command in commands {
+        let outcome: Result<TransitionResult, CoreError> = apply(
+            metadata,
+            &current,
+            active_bid_year,
+            command,
+            actor.clone(),
+            cause.clone(),
+        );
+        if let Ok(transition) = &outcome {
+            current = transition.new_state.clone();
+        }
+        outcomes.push(outcome);
+    }
+
+    BatchTransitionResult {
+        outcomes,
+        final_state: current,
+    }
+}
@@ -3,8 +3,11 @@
 // license that can be found in the LICENSE file or at
 // https://opensource.org/licenses/MIT.
 
-use time::Date;
-use zab_bid_domain::{Area, Crew, Initials, SeniorityData, UserType};
+use time::{Date, OffsetDateTime};
+use zab_bid_domain::{
+    Area, BidAmendment, BidPreference, BidReceiptMethod, BidYear, Crew, Initials,
+    ReadinessEvaluation, SeniorityData, UserType,
+};
 
 /// A command represents user or system intent as data only.
 ///
@@ -67,7 +70,12 @@ pub enum Command {
         seniority_data: SeniorityData,
     },
     /// Create an explicit checkpoint, triggering a full state snapshot.
-    Checkpoint,
+    Checkpoint {
+        /// A name to find the checkpoint by, such as `before-round-3`.
+        label: Option<String>,
+        /// A longer note about the checkpoint. Requires a label.
+        description: Option<String>,
+    },
     /// Mark a milestone as finalized, triggering a full state snapshot.
     Finalize,
     /// Rollback to a specific event ID, establishing it as authoritative going forward.
@@ -132,16 +140,32 @@ pub enum Command {
         /// The year to confirm.
         year: u16,
     },
-    /// Transition a bid year from `Canonicalized` to `BiddingActive`.
-    TransitionToBiddingActive {
+    /// Activate bidding, transitioning a bid year from `Canonicalized` to
+    /// `BiddingActive`.
+    ///
+    /// Refused while the readiness evaluation reports any blocker.
+    ActivateBidding {
         /// The year to transition.
         year: u16,
+        /// The readiness evaluation of the bid year at activation time.
+        readiness: ReadinessEvaluation,
     },
     /// Transition a bid year from `BiddingActive` to `BiddingClosed`.
     TransitionToBiddingClosed {
         /// The year to transition.
         year: u16,
     },
+    /// Reopen a canonicalized bid year, returning it to `BootstrapComplete`.
+    ///
+    /// Refused once any bid has been entered. The caller checks the bids
+    /// and clears the canonical rows in the transaction that records the
+    /// event.
+    ReopenBidYear {
+        /// The year to reopen.
+        year: u16,
+        /// Why the bid year is reopened. Recorded in the audit event.
+        justification: String,
+    },
     /// Override a user's area assignment after canonicalization.
     ///
     /// The user is identified by `user_id` (canonical, immutable).
@@ -218,6 +242,33 @@ pub enum Command {
         /// Whether the user is excluded from leave calculation.
         excluded_from_leave_calculation: bool,
     },
+    /// Merge a duplicate user record into the record that survives.
+    ///
+    /// Both records are identified by `user_id` and must be in the same
+    /// area. The merged record is excluded from bidding and leave
+    /// calculation; the caller reassigns its bids, overrides, and leave
+    /// balance to the surviving record.
+    MergeUsers {
+        /// The canonical identifier of the surviving record.
+        keep: i64,
+        /// The canonical identifier of the duplicate record.
+        merge: i64,
+        /// Why the records are merged (must be non-empty, min 10 chars).
+        reason: String,
+    },
+    /// Change a user's operating initials.
+    ///
+    /// The user keeps their canonical identity; the caller records the
+    /// previous initials as an alias so history recorded under them still
+    /// resolves to the user.
+    ChangeInitials {
+        /// The user's current initials.
+        from: Initials,
+        /// The user's new initials (must be unused in the area).
+        to: Initials,
+        /// The date the new initials take effect.
+        effective_date: Date,
+    },
     /// Create a new round group for a bid year.
     ///
     /// Phase 29B: Round configuration infrastructure.
@@ -303,4 +354,381 @@ pub enum Command {
         /// The round's canonical identifier.
         round_id: i64,
     },
+    /// Renumber the rounds of a round group.
+    ///
+    /// Rounds are renumbered 1..n in the given order, atomically, so a round
+    /// can be inserted mid-sequence without recreating the rounds after it.
+    ReorderRounds {
+        /// The round group's canonical identifier.
+        round_group_id: i64,
+        /// Every round ID in the round group, in the new order.
+        ordered_round_ids: Vec<i64>,
+    },
+    /// Advance an area's round to its next bidder.
+    ///
+    /// The bidder sequence is resolved by the caller from the round's bid
+    /// windows; this command validates the scope and records the handover.
+    AdvanceBidder {
+        /// The bid year containing the area.
+        year: u16,
+        /// The area whose current bidder changes.
+        area: Area,
+        /// The round's canonical identifier.
+        round_id: i64,
+        /// The user whose window is ending (`None` when the round starts).
+        previous_user_id: Option<i64>,
+        /// The user whose window becomes active (`None` when the round is complete).
+        next_user_id: Option<i64>,
+        /// Whether the advance was triggered by the window elapsing rather
+        /// than by an operator.
+        time_driven: bool,
+    },
+    /// Record that a bidder's window elapsed without a submitted bid.
+    ///
+    /// Emitted by the window expiry scheduler before it advances the round.
+    ExpireBidWindow {
+        /// The bid year containing the area.
+        year: u16,
+        /// The area whose bidder's window elapsed.
+        area: Area,
+        /// The round's canonical identifier.
+        round_id: i64,
+        /// The user whose window elapsed.
+        user_id: i64,
+        /// Whether the bidder's status was marked missed.
+        marked_missed: bool,
+    },
+    /// Enter leave bid days for a controller within a round.
+    ///
+    /// Bids are entered by an operator on the controller's behalf; the
+    /// controller and how the bid was received are always recorded.
+    EnterLeaveBid {
+        /// The bid year containing the area.
+        year: u16,
+        /// The controller's area.
+        area: Area,
+        /// The round the leave is bid in.
+        round_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+        /// The controller the bid is entered for.
+        on_behalf_of: Initials,
+        /// How the bid was received from the controller.
+        received_via: BidReceiptMethod,
+        /// The leave days bid, one leave bid per day.
+        leave_dates: Vec<Date>,
+        /// The leave hours charged per day.
+        hours: u32,
+        /// Whether the round has been signed off in the area.
+        signed_off: bool,
+        /// Why bids are being entered after sign-off (required when
+        /// `signed_off` is set).
+        override_reason: Option<String>,
+        /// The amendment context when the controller already holds leave in
+        /// the round (`None` for a first submission).
+        amendment: Option<BidAmendment>,
+    },
+    /// Record a controller's ranked leave preferences ahead of their window.
+    ///
+    /// Submitting replaces any pending preferences for the round.
+    SubmitBidPreferences {
+        /// The bid year containing the area.
+        year: u16,
+        /// The controller's area.
+        area: Area,
+        /// The round the preferences are for.
+        round_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+        /// The controller the preferences are entered for.
+        on_behalf_of: Initials,
+        /// How the preferences were received from the controller.
+        received_via: BidReceiptMethod,
+        /// The ranked preferences.
+        preferences: Vec<BidPreference>,
+    },
+    /// Convert a controller's top fitting preference into a leave bid.
+    ///
+    /// Issued when the controller's window opens. Preferences ranked above
+    /// the applied one were passed over because they no longer fit.
+    ApplyBidPreference {
+        /// The bid year containing the area.
+        year: u16,
+        /// The controller's area.
+        area: Area,
+        /// The round the preferences are for.
+        round_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+        /// The controller the preferences were entered for.
+        on_behalf_of: Initials,
+        /// The preference converted into a bid, if any fit.
+        applied: Option<BidPreference>,
+        /// The ranks passed over because they did not fit.
+        passed_over: Vec<u32>,
+    },
+    /// Adjust the leave slots of one day of a round for staffing.
+    ///
+    /// The day's capacity becomes the round's `slots_per_day` plus the
+    /// adjustment. Approved leave already held is not withdrawn.
+    AdjustSlotInventory {
+        /// The bid year containing the area.
+        year: u16,
+        /// The area the adjustment applies to.
+        area: Area,
+        /// The round the adjustment applies to.
+        round_id: i64,
+        /// The day the adjustment applies to.
+        date: Date,
+        /// The adjustment in effect before this command.
+        previous_adjustment: i32,
+        /// The new adjustment.
+        staffing_adjustment: i32,
+    },
+    /// Ask for leave on a day whose slots are all taken.
+    ///
+    /// Only rounds that allow overbids accept requests. The request waits
+    /// for an Admin to approve or deny it.
+    RequestOverbid {
+        /// The bid year containing the area.
+        year: u16,
+        /// The controller's area.
+        area: Area,
+        /// The round the leave is requested in.
+        round_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+        /// The controller the request is entered for.
+        on_behalf_of: Initials,
+        /// How the request was received from the controller.
+        received_via: BidReceiptMethod,
+        /// The requested leave day.
+        leave_date: Date,
+        /// The leave hours charged for the day.
+        hours: u32,
+    },
+    /// Approve a pending overbid request.
+    ///
+    /// The day's slot inventory grows by one and the leave is granted.
+    ApproveOverbid {
+        /// The bid year containing the area.
+        year: u16,
+        /// The controller's area.
+        area: Area,
+        /// The overbid request being decided.
+        overbid_request_id: i64,
+        /// The audit event that recorded the request.
+        request_event_id: i64,
+        /// The round the leave was requested in.
+        round_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+        /// The requested leave day.
+        leave_date: Date,
+        /// The day's staffing adjustment before approval.
+        previous_adjustment: i32,
+    },
+    /// Deny a pending overbid request.
+    DenyOverbid {
+        /// The bid year containing the area.
+        year: u16,
+        /// The controller's area.
+        area: Area,
+        /// The overbid request being decided.
+        overbid_request_id: i64,
+        /// The audit event that recorded the request.
+        request_event_id: i64,
+        /// The round the leave was requested in.
+        round_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+        /// The requested leave day.
+        leave_date: Date,
+        /// Why the request was denied.
+        reason: String,
+    },
+    /// Ask to enter leave for a controller who is the operator entering it.
+    ///
+    /// The leave is not entered until a second operator confirms the
+    /// request.
+    RequestSelfBidEntry {
+        /// The bid year containing the area.
+        year: u16,
+        /// The controller's area.
+        area: Area,
+        /// The round the leave is bid in.
+        round_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+        /// The controller the bid is entered for.
+        on_behalf_of: Initials,
+        /// How the bid was received from the controller.
+        received_via: BidReceiptMethod,
+        /// The leave days requested.
+        leave_dates: Vec<Date>,
+        /// The leave hours charged per day.
+        hours: u32,
+    },
+    /// Confirm a pending self-service bid request as a second operator.
+    ///
+    /// Issued once the requested leave has been entered; the confirmation
+    /// names both the request event and the entry event.
+    ConfirmSelfBidEntry {
+        /// The bid year containing the area.
+        year: u16,
+        /// The controller's area.
+        area: Area,
+        /// The self-service bid request being confirmed.
+        self_bid_request_id: i64,
+        /// The audit event that recorded the request.
+        request_event_id: i64,
+        /// The audit event that recorded the leave entry.
+        entry_event_id: i64,
+        /// The operator who made the request.
+        requested_by_operator_id: i64,
+        /// The round the leave was bid in.
+        round_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+    },
+    /// Sign off a round in an area once every eligible user is done.
+    ///
+    /// The bid, skip, and waiver counts are resolved by the caller from
+    /// the users' bid statuses. Signing off locks the round's bids.
+    SignOffRound {
+        /// The bid year containing the area.
+        year: u16,
+        /// The area whose round is signed off.
+        area: Area,
+        /// The round's canonical identifier.
+        round_id: i64,
+        /// Eligible users who bid, themselves or by proxy.
+        bid: usize,
+        /// Eligible users skipped after missing their window.
+        skipped: usize,
+        /// Eligible users who waived bidding.
+        waived: usize,
+        /// Initials of eligible users who have not bid, been skipped, or waived.
+        outstanding: Vec<Initials>,
+    },
+    /// Withdraw a controller's leave bid, releasing its slot.
+    ///
+    /// Once the round has closed in the area, the freed slot is offered
+    /// down the seniority list through the waitlist.
+    WithdrawLeaveBid {
+        /// The bid year containing the area.
+        year: u16,
+        /// The controller's area.
+        area: Area,
+        /// The round the leave was bid in.
+        round_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+        /// The controller the withdrawal is entered for.
+        on_behalf_of: Initials,
+        /// The leave bid being withdrawn.
+        leave_bid_id: i64,
+        /// The released leave day.
+        leave_date: Date,
+        /// Whether the round has closed in the area, so the slot goes to
+        /// the waitlist. False when the bid year has waitlists disabled.
+        round_closed: bool,
+    },
+    /// Offer a freed leave slot to the next controller on the waitlist.
+    OfferWaitlistSlot {
+        /// The bid year containing the area.
+        year: u16,
+        /// The area the slot was freed in.
+        area: Area,
+        /// The round the slot was freed in.
+        round_id: i64,
+        /// The freed slot.
+        waitlist_slot_id: i64,
+        /// The controller being offered the slot.
+        user_id: i64,
+        /// The freed leave day.
+        leave_date: Date,
+        /// When the offer is made.
+        offered_at: OffsetDateTime,
+        /// When the offer lapses if unanswered.
+        expires_at: OffsetDateTime,
+    },
+    /// Accept a waitlist offer, granting the freed leave.
+    AcceptWaitlistOffer {
+        /// The bid year containing the area.
+        year: u16,
+        /// The area the slot was freed in.
+        area: Area,
+        /// The round the slot was freed in.
+        round_id: i64,
+        /// The offer being accepted.
+        waitlist_offer_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+        /// The controller the acceptance is entered for.
+        on_behalf_of: Initials,
+        /// How the acceptance was received from the controller.
+        received_via: BidReceiptMethod,
+        /// The freed leave day.
+        leave_date: Date,
+        /// When the offer lapses.
+        expires_at: OffsetDateTime,
+        /// When the offer is accepted.
+        accepted_at: OffsetDateTime,
+    },
+    /// Decline a waitlist offer, passing the slot down the list.
+    DeclineWaitlistOffer {
+        /// The bid year containing the area.
+        year: u16,
+        /// The area the slot was freed in.
+        area: Area,
+        /// The round the slot was freed in.
+        round_id: i64,
+        /// The offer being declined.
+        waitlist_offer_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+        /// The freed leave day.
+        leave_date: Date,
+    },
+    /// Record that a waitlist offer lapsed without an answer.
+    ///
+    /// Emitted by the scheduler before the slot is offered to the next
+    /// controller.
+    ExpireWaitlistOffer {
+        /// The bid year containing the area.
+        year: u16,
+        /// The area the slot was freed in.
+        area: Area,
+        /// The round the slot was freed in.
+        round_id: i64,
+        /// The lapsed offer.
+        waitlist_offer_id: i64,
+        /// The controller's canonical identifier.
+        user_id: i64,
+        /// The freed leave day.
+        leave_date: Date,
+        /// When the offer lapsed.
+        expires_at: OffsetDateTime,
+    },
+    /// Freeze an area against every change, whatever its lifecycle state.
+    ///
+    /// Used to hold an area still, for example while a grievance is heard.
+    /// While frozen, every command targeting the area is refused, as are
+    /// lifecycle transitions of its bid year. Only `Unfreeze` lifts it.
+    FreezeScope {
+        /// The bid year containing the area.
+        bid_year: BidYear,
+        /// The area to freeze.
+        area: Area,
+        /// Why the area is frozen (must be non-empty, min 10 chars).
+        reason: String,
+    },
+    /// Lift a freeze placed by `FreezeScope`.
+    Unfreeze {
+        /// The bid year containing the area.
+        bid_year: BidYear,
+        /// The area to unfreeze.
+        area: Area,
+    },
 }
@@ -0,0 +1,240 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Eligibility rules engine.
+//!
+//! Whether a user may bid is derived from their user type, participation
+//! flags, and area assignment. Users in a system area or excluded from
+//! bidding never bid. Otherwise every user type bids unless the facility
+//! configures an [`EligibilityException`] for it, either across the bid
+//! year or within a single area; an area exception takes precedence.
+//!
+//! The derived value is only the baseline. An eligibility override recorded
+//! against a user is layered on top of it and is left untouched when the
+//! derived value is recomputed.
+//!
+//! A round may further limit itself to a subset of the area's bidders with
+//! [`RoundEligibility`] criteria, such as a carryover round open only to
+//! users with leave remaining. Users the criteria exclude are left out of
+//! that round's bid order.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use zab_bid_domain::{BidOrderPosition, DomainError, UserType};
+
+/// A facility-configured change to a user type's default eligibility.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EligibilityException {
+    /// The user type the exception applies to.
+    pub user_type: UserType,
+    /// The area the exception is limited to, or `None` for the whole bid year.
+    pub area_id: Option<i64>,
+    /// Whether users it applies to may bid.
+    pub can_bid: bool,
+}
+
+/// The facts about a user that eligibility is derived from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EligibilitySubject {
+    /// The user's type.
+    pub user_type: UserType,
+    /// Whether the user is excluded from bidding.
+    pub excluded_from_bidding: bool,
+    /// The user's assigned area.
+    pub area_id: i64,
+    /// Whether the assigned area is a system area.
+    pub in_system_area: bool,
+}
+
+/// What a derived eligibility was decided by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EligibilityBasis {
+    /// The user is assigned to a system area.
+    SystemArea,
+    /// The user is excluded from bidding.
+    ExcludedFromBidding,
+    /// An exception for the user's type in their area.
+    AreaException,
+    /// An exception for the user's type across the bid year.
+    BidYearException,
+    /// No exception applies, so the user type's default.
+    Default,
+}
+
+impl EligibilityBasis {
+    /// Returns the basis as a string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::SystemArea => "system_area",
+            Self::ExcludedFromBidding => "excluded_from_bidding",
+            Self::AreaException => "area_exception",
+            Self::BidYearException => "bid_year_exception",
+            Self::Default => "default",
+        }
+    }
+}
+
+/// A user's eligibility as derived from the rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DerivedEligibility {
+    /// Whether the user may bid.
+    pub can_bid: bool,
+    /// What decided it.
+    pub basis: EligibilityBasis,
+}
+
+/// Checks that a bid year's exceptions do not contradict each other.
+///
+/// # Errors
+///
+/// Returns `DomainError::InvalidEligibilityException` if two exceptions
+/// apply to the same user type in the same scope.
+pub fn validate_exceptions(exceptions: &[EligibilityException]) -> Result<(), DomainError> {
+    let mut seen: BTreeSet<(&str, Option<i64>)> = BTreeSet::new();
+    for exception in exceptions {
+        if !seen.insert((exception.user_type.as_str(), exception.area_id)) {
+            let scope: String = exception
+                .area_id
+                .map_or_else(|| String::from("the bid year"), |id| format!("area {id}"));
+            return Err(DomainError::InvalidEligibilityException {
+                reason: format!(
+                    "More than one exception for {} in {scope}",
+                    exception.user_type.as_str()
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Derives whether a user may bid.
+///
+/// # Arguments
+///
+/// * `subject` - The user's type, flags, and area assignment
+/// * `exceptions` - The bid year's exceptions
+#[must_use]
+pub fn derive_eligibility(
+    subject: &EligibilitySubject,
+    exceptions: &[EligibilityException],
+) -> DerivedEligibility {
+    let decided = |can_bid: bool, basis: EligibilityBasis| DerivedEligibility { can_bid, basis };
+
+    if subject.in_system_area {
+        return decided(false, EligibilityBasis::SystemArea);
+    }
+    if subject.excluded_from_bidding {
+        return decided(false, EligibilityBasis::ExcludedFromBidding);
+    }
+
+    let exception_in = |area_id: Option<i64>| {
+        exceptions
+            .iter()
+            .find(|e| e.user_type == subject.user_type && e.area_id == area_id)
+    };
+    if let Some(exception) = exception_in(Some(subject.area_id)) {
+        return decided(exception.can_bid, EligibilityBasis::AreaException);
+    }
+    if let Some(exception) = exception_in(None) {
+        return decided(exception.can_bid, EligibilityBasis::BidYearException);
+    }
+
+    decided(true, EligibilityBasis::Default)
+}
+
+/// Criteria limiting a round to a subset of an area's bidders.
+///
+/// A user must meet every criterion that is set.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RoundEligibility {
+    /// The leave balance, in hours, a user must have remaining to bid in
+    /// the round.
+    pub min_remaining_hours: Option<u32>,
+    /// The user types that bid in the round, or empty for every type.
+    pub user_types: Vec<UserType>,
+}
+
+impl RoundEligibility {
+    /// Returns whether no criterion is set, so every bidder takes part.
+    #[must_use]
+    pub const fn is_unrestricted(&self) -> bool {
+        self.min_remaining_hours.is_none() && self.user_types.is_empty()
+    }
+}
+
+/// The facts about a user that round eligibility is evaluated against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundSubject {
+    /// The user's type.
+    pub user_type: UserType,
+    /// The user's remaining leave balance, in hours.
+    pub remaining_hours: i64,
+}
+
+/// Checks that a round's eligibility criteria are well formed.
+///
+/// # Errors
+///
+/// Returns `DomainError::InvalidRoundConfiguration` if a user type is listed
+/// more than once.
+pub fn validate_round_eligibility(criteria: &RoundEligibility) -> Result<(), DomainError> {
+    let mut seen: BTreeSet<&str> = BTreeSet::new();
+    for user_type in &criteria.user_types {
+        if !seen.insert(user_type.as_str()) {
+            return Err(DomainError::InvalidRoundConfiguration {
+                reason: format!(
+                    "user type {} is listed more than once in the round's eligibility",
+                    user_type.as_str()
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Evaluates whether a user may bid in a round.
+#[must_use]
+pub fn is_eligible_for_round(criteria: &RoundEligibility, subject: &RoundSubject) -> bool {
+    let meets_balance: bool = criteria
+        .min_remaining_hours
+        .is_none_or(|min| subject.remaining_hours >= i64::from(min));
+    let meets_type: bool =
+        criteria.user_types.is_empty() || criteria.user_types.contains(&subject.user_type);
+
+    meets_balance && meets_type
+}
+
+/// Builds a round's bid order from the area's bid order.
+///
+/// Users the round's criteria exclude are removed; everyone else keeps
+/// their position in the area's bid order. A user with no subject is
+/// excluded from a restricted round, since nothing shows they qualify.
+///
+/// # Arguments
+///
+/// * `bid_order` - The area's bid order
+/// * `criteria` - The round's eligibility criteria, if it has any
+/// * `subjects` - The facts about each user, keyed by user ID
+#[must_use]
+pub fn build_round_bid_order(
+    bid_order: &[BidOrderPosition],
+    criteria: Option<&RoundEligibility>,
+    subjects: &BTreeMap<i64, RoundSubject>,
+) -> Vec<BidOrderPosition> {
+    let Some(criteria) = criteria.filter(|criteria| !criteria.is_unrestricted()) else {
+        return bid_order.to_vec();
+    };
+
+    bid_order
+        .iter()
+        .filter(|position| {
+            subjects
+                .get(&position.user_id)
+                .is_some_and(|subject| is_eligible_for_round(criteria, subject))
+        })
+        .cloned()
+        .collect()
+}
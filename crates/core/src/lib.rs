@@ -18,8 +18,13 @@
 )]
 
 mod apply;
+mod clock;
 mod command;
+mod eligibility;
 mod error;
+mod ids;
+mod round_order;
+mod rules;
 mod state;
 
 #[cfg(test)]
@@ -28,10 +33,21 @@ mod tests;
 use zab_bid_domain::{Area, BidYear, DomainError};
 
 // Re-export public types and functions
-pub use apply::{apply, apply_bootstrap};
+pub use apply::{apply, apply_batch, apply_bootstrap, checkpoint_label, rollback_target_event_id};
+pub use clock::{Clock, ManualClock, SystemClock};
 pub use command::Command;
+pub use eligibility::{
+    DerivedEligibility, EligibilityBasis, EligibilityException, EligibilitySubject,
+    RoundEligibility, RoundSubject, build_round_bid_order, derive_eligibility,
+    is_eligible_for_round, validate_exceptions, validate_round_eligibility,
+};
 pub use error::CoreError;
-pub use state::{BootstrapMetadata, BootstrapResult, State, TransitionResult};
+pub use ids::{IdGenerator, SequentialIdGenerator, UlidGenerator};
+pub use round_order::{BidOrderRotation, sequence_round_bid_order};
+pub use rules::{BidRule, RuleViolation, evaluate_rules};
+pub use state::{
+    BatchTransitionResult, BootstrapMetadata, BootstrapResult, State, TransitionResult,
+};
 
 /// Validates that a bid year exists in the metadata.
 ///
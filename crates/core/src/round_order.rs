@@ -0,0 +1,97 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Round bid order sequencing.
+//!
+//! Each round bids in its own sequence derived from the area's canonical
+//! bid order. Users the round's eligibility criteria exclude are skipped
+//! and everyone behind them moves up, so a round's positions always run
+//! from 1 without gaps. The round group's [`BidOrderRotation`] then decides
+//! whether the round bids front to back or back to front.
+
+use std::collections::BTreeMap;
+
+use zab_bid_domain::{BidOrderPosition, DomainError};
+
+use crate::eligibility::{RoundEligibility, RoundSubject, build_round_bid_order};
+
+/// How a round group rotates its bid order from round to round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BidOrderRotation {
+    /// Every round bids in canonical bid order.
+    #[default]
+    Forward,
+    /// Even-numbered rounds bid in reverse, so the last bidder of one round
+    /// opens the next.
+    ReverseEvenRounds,
+}
+
+impl BidOrderRotation {
+    /// Parses a rotation from its stored name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidRoundConfiguration` if the name is not
+    /// recognized.
+    pub fn parse(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "forward" => Ok(Self::Forward),
+            "reverse_even_rounds" => Ok(Self::ReverseEvenRounds),
+            _ => Err(DomainError::InvalidRoundConfiguration {
+                reason: format!("Unknown bid order rotation: {s}"),
+            }),
+        }
+    }
+
+    /// Returns the stored name of this rotation.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Forward => "forward",
+            Self::ReverseEvenRounds => "reverse_even_rounds",
+        }
+    }
+
+    /// Returns whether a round bids in reverse under this rotation.
+    #[must_use]
+    pub const fn reverses(&self, round_number: u32) -> bool {
+        match self {
+            Self::Forward => false,
+            Self::ReverseEvenRounds => round_number.is_multiple_of(2),
+        }
+    }
+}
+
+/// Derives a round's bidding sequence from the area's bid order.
+///
+/// Ineligible users are removed, the rotation is applied, and positions are
+/// renumbered from 1 in the order the round bids.
+///
+/// # Arguments
+///
+/// * `bid_order` - The area's canonical bid order
+/// * `round_number` - The round's number within its round group
+/// * `rotation` - The round group's rotation
+/// * `criteria` - The round's eligibility criteria, if it has any
+/// * `subjects` - The facts about each user, keyed by user ID
+#[must_use]
+pub fn sequence_round_bid_order(
+    bid_order: &[BidOrderPosition],
+    round_number: u32,
+    rotation: BidOrderRotation,
+    criteria: Option<&RoundEligibility>,
+    subjects: &BTreeMap<i64, RoundSubject>,
+) -> Vec<BidOrderPosition> {
+    let mut sequence: Vec<BidOrderPosition> = build_round_bid_order(bid_order, criteria, subjects);
+    sequence.sort_by_key(|position| position.position);
+    if rotation.reverses(round_number) {
+        sequence.reverse();
+    }
+
+    for (index, position) in sequence.iter_mut().enumerate() {
+        position.position = index + 1;
+    }
+    sequence
+}
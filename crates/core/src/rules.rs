@@ -0,0 +1,229 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Bid validation rule engine.
+//!
+//! Facilities layer their own contract rules on top of the round limits.
+//! Rules are data: each bid year stores a list of [`BidRule`]s, and
+//! [`evaluate_rules`] checks a candidate bid against them, returning every
+//! [`RuleViolation`] rather than stopping at the first.
+//!
+//! A candidate bid is the leave a user already holds in a round together
+//! with the days being requested. Leave is grouped into runs of
+//! consecutive calendar days.
+
+use std::collections::BTreeSet;
+
+use time::Date;
+use zab_bid_domain::DomainError;
+
+/// A bid validation rule configured for a bid year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BidRule {
+    /// Every run of leave must span at least `days` consecutive days.
+    MinConsecutiveDays {
+        /// The shortest run allowed.
+        days: u32,
+    },
+    /// A user may hold at most `splits` separate runs of leave per round.
+    MaxSplitsPerRound {
+        /// The most runs allowed.
+        splits: u32,
+    },
+    /// Leave on a holiday must also take the day before or after it.
+    HolidayPairing {
+        /// The holidays the rule applies to.
+        holidays: BTreeSet<Date>,
+    },
+}
+
+impl BidRule {
+    /// The kind of the minimum consecutive days rule.
+    pub const MIN_CONSECUTIVE_DAYS: &'static str = "min_consecutive_days";
+    /// The kind of the maximum splits per round rule.
+    pub const MAX_SPLITS_PER_ROUND: &'static str = "max_splits_per_round";
+    /// The kind of the holiday pairing rule.
+    pub const HOLIDAY_PAIRING: &'static str = "holiday_pairing";
+
+    /// Builds a rule from its stored kind and parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The rule kind
+    /// * `value` - The numeric parameter, for rules that take one
+    /// * `dates` - The dates parameter, for rules that take one
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::InvalidBidRule` if the kind is unknown or its
+    /// parameters are missing or out of range.
+    pub fn new(kind: &str, value: Option<u32>, dates: &[Date]) -> Result<Self, DomainError> {
+        let invalid = |reason: String| DomainError::InvalidBidRule { reason };
+        let positive = |name: &str| match value {
+            Some(value) if value > 0 => Ok(value),
+            _ => Err(invalid(format!(
+                "'{kind}' requires a {name} greater than 0"
+            ))),
+        };
+
+        match kind {
+            Self::MIN_CONSECUTIVE_DAYS => Ok(Self::MinConsecutiveDays {
+                days: positive("number of days")?,
+            }),
+            Self::MAX_SPLITS_PER_ROUND => Ok(Self::MaxSplitsPerRound {
+                splits: positive("number of splits")?,
+            }),
+            Self::HOLIDAY_PAIRING => {
+                if dates.is_empty() {
+                    return Err(invalid(format!("'{kind}' requires at least one holiday")));
+                }
+                Ok(Self::HolidayPairing {
+                    holidays: dates.iter().copied().collect(),
+                })
+            }
+            _ => Err(invalid(format!("Unknown rule kind '{kind}'"))),
+        }
+    }
+
+    /// Returns the rule's kind.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::MinConsecutiveDays { .. } => Self::MIN_CONSECUTIVE_DAYS,
+            Self::MaxSplitsPerRound { .. } => Self::MAX_SPLITS_PER_ROUND,
+            Self::HolidayPairing { .. } => Self::HOLIDAY_PAIRING,
+        }
+    }
+
+    /// Returns the rule's numeric parameter, if it takes one.
+    #[must_use]
+    pub const fn value(&self) -> Option<u32> {
+        match self {
+            Self::MinConsecutiveDays { days } => Some(*days),
+            Self::MaxSplitsPerRound { splits } => Some(*splits),
+            Self::HolidayPairing { .. } => None,
+        }
+    }
+
+    /// Returns the rule's dates parameter in ascending order.
+    #[must_use]
+    pub fn dates(&self) -> Vec<Date> {
+        match self {
+            Self::HolidayPairing { holidays } => holidays.iter().copied().collect(),
+            Self::MinConsecutiveDays { .. } | Self::MaxSplitsPerRound { .. } => Vec::new(),
+        }
+    }
+
+    /// Checks a candidate bid's leave against this rule.
+    fn check(&self, leave: &BTreeSet<Date>, requested: &BTreeSet<Date>) -> Option<RuleViolation> {
+        let runs: Vec<Vec<Date>> = leave_runs(leave);
+        let violation = |dates: Vec<Date>, message: String| RuleViolation {
+            rule: self.kind(),
+            dates,
+            message,
+        };
+
+        match self {
+            Self::MinConsecutiveDays { days } => {
+                // Only runs the request touches are judged, so leave held
+                // before the rule was configured is not re-litigated
+                let short: Vec<Date> = runs
+                    .iter()
+                    .filter(|run| run.iter().any(|date| requested.contains(date)))
+                    .filter(|run| u32::try_from(run.len()).unwrap_or(u32::MAX) < *days)
+                    .flatten()
+                    .copied()
+                    .collect();
+                (!short.is_empty()).then(|| {
+                    violation(
+                        short,
+                        format!("Leave must be taken in runs of at least {days} consecutive days"),
+                    )
+                })
+            }
+            Self::MaxSplitsPerRound { splits } => {
+                let count: u32 = u32::try_from(runs.len()).unwrap_or(u32::MAX);
+                (count > *splits).then(|| {
+                    violation(
+                        requested.iter().copied().collect(),
+                        format!(
+                            "Leave would be split into {count} runs; at most {splits} allowed per round"
+                        ),
+                    )
+                })
+            }
+            Self::HolidayPairing { holidays } => {
+                let unpaired: Vec<Date> = requested
+                    .iter()
+                    .filter(|date| holidays.contains(date))
+                    .filter(|date| {
+                        ![date.previous_day(), date.next_day()]
+                            .iter()
+                            .flatten()
+                            .any(|adjacent| leave.contains(adjacent))
+                    })
+                    .copied()
+                    .collect();
+                (!unpaired.is_empty()).then(|| {
+                    violation(
+                        unpaired,
+                        String::from("Leave on a holiday must include the day before or after it"),
+                    )
+                })
+            }
+        }
+    }
+}
+
+/// One way a candidate bid breaks a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleViolation {
+    /// The kind of rule that was broken.
+    pub rule: &'static str,
+    /// The leave days that break the rule.
+    pub dates: Vec<Date>,
+    /// A human-readable description of the violation.
+    pub message: String,
+}
+
+/// Groups leave days into runs of consecutive days.
+fn leave_runs(leave: &BTreeSet<Date>) -> Vec<Vec<Date>> {
+    let mut runs: Vec<Vec<Date>> = Vec::new();
+    for date in leave {
+        match runs.last_mut() {
+            Some(run) if run.last().and_then(|last| last.next_day()) == Some(*date) => {
+                run.push(*date);
+            }
+            _ => runs.push(vec![*date]),
+        }
+    }
+    runs
+}
+
+/// Evaluates a candidate bid against a bid year's rules.
+///
+/// # Arguments
+///
+/// * `rules` - The bid year's rules
+/// * `held` - The leave the user already holds in the round
+/// * `requested` - The leave days being bid
+///
+/// # Returns
+///
+/// Every violation, in rule order. An empty list means the bid passes.
+#[must_use]
+pub fn evaluate_rules(
+    rules: &[BidRule],
+    held: &BTreeSet<Date>,
+    requested: &[Date],
+) -> Vec<RuleViolation> {
+    let requested: BTreeSet<Date> = requested.iter().copied().collect();
+    let leave: BTreeSet<Date> = held.union(&requested).copied().collect();
+
+    rules
+        .iter()
+        .filter_map(|rule| rule.check(&leave, &requested))
+        .collect()
+}
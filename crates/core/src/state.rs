@@ -3,8 +3,10 @@
 // license that can be found in the LICENSE file or at
 // https://opensource.org/licenses/MIT.
 
+use crate::error::CoreError;
+use imbl::OrdMap;
 use zab_bid_audit::{AuditEvent, StateSnapshot};
-use zab_bid_domain::{Area, BidYear, CanonicalBidYear, User};
+use zab_bid_domain::{Area, BidYear, CanonicalBidYear, Initials, User};
 
 /// Bootstrap metadata tracking which bid years and areas exist.
 ///
@@ -15,6 +17,16 @@ pub struct BootstrapMetadata {
     pub bid_years: Vec<BidYear>,
     /// All valid areas per bid year.
     pub areas: Vec<(BidYear, Area)>,
+    /// Areas frozen against every change, per bid year.
+    ///
+    /// A freeze is independent of the bid year's lifecycle state and lasts
+    /// until the area is explicitly unfrozen.
+    pub frozen_scopes: Vec<(BidYear, Area)>,
+    /// The controller each linked operator is in the roster, by operator ID.
+    ///
+    /// An operator may not change their own controller's leave; see
+    /// `Command::RequestSelfBidEntry`.
+    pub operator_controllers: Vec<(i64, Initials)>,
 }
 
 impl BootstrapMetadata {
@@ -24,6 +36,8 @@ impl BootstrapMetadata {
         Self {
             bid_years: Vec::new(),
             areas: Vec::new(),
+            frozen_scopes: Vec::new(),
+            operator_controllers: Vec::new(),
         }
     }
 
@@ -39,6 +53,32 @@ impl BootstrapMetadata {
         self.areas.iter().any(|(y, a)| y == bid_year && a == area)
     }
 
+    /// Checks if an area in a bid year is frozen.
+    #[must_use]
+    pub fn is_frozen(&self, bid_year: &BidYear, area: &Area) -> bool {
+        self.frozen_scopes
+            .iter()
+            .any(|(y, a)| y == bid_year && a == area)
+    }
+
+    /// Returns the first frozen area in a bid year, if any.
+    #[must_use]
+    pub fn first_frozen_area(&self, bid_year: &BidYear) -> Option<&Area> {
+        self.frozen_scopes
+            .iter()
+            .find(|(y, _)| y == bid_year)
+            .map(|(_, a)| a)
+    }
+
+    /// Returns the initials of the controller an operator is, if any.
+    #[must_use]
+    pub fn operator_controller(&self, operator_id: i64) -> Option<&Initials> {
+        self.operator_controllers
+            .iter()
+            .find(|(id, _)| *id == operator_id)
+            .map(|(_, initials)| initials)
+    }
+
     /// Adds a bid year.
     pub(crate) fn add_bid_year(&mut self, bid_year: BidYear) {
         self.bid_years.push(bid_year);
@@ -48,6 +88,17 @@ impl BootstrapMetadata {
     pub(crate) fn add_area(&mut self, bid_year: BidYear, area: Area) {
         self.areas.push((bid_year, area));
     }
+
+    /// Freezes an area in a bid year.
+    pub(crate) fn freeze_scope(&mut self, bid_year: BidYear, area: Area) {
+        self.frozen_scopes.push((bid_year, area));
+    }
+
+    /// Unfreezes an area in a bid year.
+    pub(crate) fn unfreeze_scope(&mut self, bid_year: &BidYear, area: &Area) {
+        self.frozen_scopes
+            .retain(|(y, a)| !(y == bid_year && a == area));
+    }
 }
 
 impl Default for BootstrapMetadata {
@@ -66,8 +117,22 @@ pub struct State {
     pub bid_year: BidYear,
     /// The area this state is scoped to.
     pub area: Area,
-    /// All registered users for this `(bid_year, area)`.
-    pub users: Vec<User>,
+    /// All registered users for this `(bid_year, area)`, keyed by initials.
+    ///
+    /// Initials are unique within a bid year, so duplicate checks and
+    /// lookups by initials are logarithmic, and iteration is in initials
+    /// order regardless of registration order.
+    ///
+    /// The key is an in-memory index only, and an exception to initials
+    /// being display metadata: a user registered by a transition has no
+    /// `user_id` until persistence assigns one, so the map cannot be keyed
+    /// by it. Users are still identified by `user_id` wherever one exists
+    /// (see [`Self::find_user_by_id`]), and changing a user's initials
+    /// moves them to the new key.
+    ///
+    /// The map is persistent: cloning a state shares the tree, and a
+    /// transition copies only the path to the user it changes.
+    pub users: OrdMap<Initials, User>,
 }
 
 impl State {
@@ -78,14 +143,53 @@ impl State {
     /// * `bid_year` - The bid year this state is scoped to
     /// * `area` - The area this state is scoped to
     #[must_use]
-    pub const fn new(bid_year: BidYear, area: Area) -> Self {
+    pub fn new(bid_year: BidYear, area: Area) -> Self {
         Self {
             bid_year,
             area,
-            users: Vec::new(),
+            users: OrdMap::new(),
         }
     }
 
+    /// Creates a state for a given bid year and area holding `users`.
+    ///
+    /// A later user replaces an earlier one with the same initials.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year` - The bid year this state is scoped to
+    /// * `area` - The area this state is scoped to
+    /// * `users` - The users registered in the area
+    #[must_use]
+    pub fn with_users(
+        bid_year: BidYear,
+        area: Area,
+        users: impl IntoIterator<Item = User>,
+    ) -> Self {
+        let mut state: Self = Self::new(bid_year, area);
+        for user in users {
+            state.insert_user(user);
+        }
+        state
+    }
+
+    /// Adds a user, keyed by their initials.
+    ///
+    /// # Returns
+    ///
+    /// The user previously registered with the same initials, if any.
+    pub fn insert_user(&mut self, user: User) -> Option<User> {
+        self.users.insert(user.initials.clone(), user)
+    }
+
+    /// Finds a user by their canonical ID.
+    #[must_use]
+    pub fn find_user_by_id(&self, user_id: i64) -> Option<&User> {
+        self.users
+            .values()
+            .find(|user| user.user_id == Some(user_id))
+    }
+
     /// Converts the state to a snapshot for audit purposes.
     #[must_use]
     pub fn to_snapshot(&self) -> StateSnapshot {
@@ -109,6 +213,28 @@ pub struct TransitionResult {
     pub audit_event: AuditEvent,
 }
 
+/// The result of applying a batch of commands to a single scope.
+///
+/// Each command is applied against the state produced by the previous
+/// successful command, so later commands observe earlier ones (e.g. duplicate
+/// initials within the same batch are rejected). Failed commands leave the
+/// threaded state untouched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchTransitionResult {
+    /// Per-command outcomes, in input order.
+    pub outcomes: Vec<Result<TransitionResult, CoreError>>,
+    /// The state after all successful commands have been applied.
+    pub final_state: State,
+}
+
+impl BatchTransitionResult {
+    /// Returns `true` if every command in the batch succeeded.
+    #[must_use]
+    pub fn all_succeeded(&self) -> bool {
+        self.outcomes.iter().all(Result::is_ok)
+    }
+}
+
 /// The result of a bootstrap operation.
 ///
 /// Bootstrap operations modify metadata, not scoped state.
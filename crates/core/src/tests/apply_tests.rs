@@ -6,8 +6,12 @@
 use crate::tests::helpers::{
     create_test_actor, create_test_cause, create_test_metadata, create_test_seniority_data,
 };
-use crate::{BootstrapMetadata, Command, CoreError, State, TransitionResult, apply};
-use zab_bid_audit::{Actor, Cause};
+use crate::{
+    BootstrapMetadata, Command, CoreError, State, TransitionResult, apply, checkpoint_label,
+    rollback_target_event_id,
+};
+use time::{Date, Month};
+use zab_bid_audit::{Action, Actor, Cause};
 use zab_bid_domain::{Area, BidYear, Crew, DomainError, Initials, User, UserType};
 
 #[test]
@@ -32,8 +36,21 @@ fn test_valid_command_returns_new_state() {
     assert!(result.is_ok());
     let transition: TransitionResult = result.unwrap();
     assert_eq!(transition.new_state.users.len(), 1);
-    assert_eq!(transition.new_state.users[0].initials.value(), "AB");
-    assert_eq!(transition.new_state.users[0].name, "John Doe");
+    assert_eq!(
+        transition
+            .new_state
+            .users
+            .values()
+            .next()
+            .unwrap()
+            .initials
+            .value(),
+        "AB"
+    );
+    assert_eq!(
+        transition.new_state.users.values().next().unwrap().name,
+        "John Doe"
+    );
 }
 
 #[test]
@@ -278,7 +295,16 @@ fn test_user_with_no_crew_is_valid() {
     assert!(result.is_ok());
     let transition: TransitionResult = result.unwrap();
     assert_eq!(transition.new_state.users.len(), 1);
-    assert!(transition.new_state.users[0].crew.is_none());
+    assert!(
+        transition
+            .new_state
+            .users
+            .values()
+            .next()
+            .unwrap()
+            .crew
+            .is_none()
+    );
 }
 
 #[test]
@@ -490,7 +516,10 @@ fn test_checkpoint_on_empty_state() {
     let metadata: BootstrapMetadata = create_test_metadata();
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
     let active_bid_year: BidYear = BidYear::new(2026);
-    let command: Command = Command::Checkpoint;
+    let command: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let actor: Actor = create_test_actor();
     let cause: Cause = create_test_cause();
 
@@ -561,6 +590,85 @@ fn test_rollback_creates_audit_event() {
             .unwrap()
             .contains("42")
     );
+    assert_eq!(
+        rollback_target_event_id(&transition.audit_event.action),
+        Some(target_event_id)
+    );
+}
+
+#[test]
+fn test_rollback_target_event_id_ignores_other_actions() {
+    let checkpoint: Action = Action::new(String::from("Checkpoint"), None);
+    assert_eq!(rollback_target_event_id(&checkpoint), None);
+
+    let malformed: Action = Action::new(
+        String::from("Rollback"),
+        Some(String::from("Rolled back to event ID abc")),
+    );
+    assert_eq!(rollback_target_event_id(&malformed), None);
+}
+
+#[test]
+fn test_labeled_checkpoint_records_label_and_description() {
+    let metadata: BootstrapMetadata = create_test_metadata();
+    let state: State = State::new(BidYear::new(2026), Area::new("North"));
+    let command: Command = Command::Checkpoint {
+        label: Some(String::from("  before-round-3 ")),
+        description: Some(String::from("Before round 3 opens")),
+    };
+
+    let transition: TransitionResult = apply(
+        &metadata,
+        &state,
+        &BidYear::new(2026),
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        checkpoint_label(&transition.audit_event.action),
+        Some(("before-round-3", Some("Before round 3 opens")))
+    );
+
+    let anonymous: Action = Action::new(
+        String::from("Checkpoint"),
+        Some(String::from("Explicit checkpoint created")),
+    );
+    assert_eq!(checkpoint_label(&anonymous), None);
+}
+
+#[test]
+fn test_checkpoint_rejects_invalid_labels() {
+    let metadata: BootstrapMetadata = create_test_metadata();
+    let state: State = State::new(BidYear::new(2026), Area::new("North"));
+    let invalid: [(Option<&str>, Option<&str>); 4] = [
+        (Some("   "), None),
+        (Some("line one\nline two"), None),
+        (Some(&"x".repeat(65)), None),
+        (None, Some("A description without a label")),
+    ];
+
+    for (label, description) in invalid {
+        let result: Result<TransitionResult, CoreError> = apply(
+            &metadata,
+            &state,
+            &BidYear::new(2026),
+            Command::Checkpoint {
+                label: label.map(str::to_string),
+                description: description.map(str::to_string),
+            },
+            create_test_actor(),
+            create_test_cause(),
+        );
+        assert!(matches!(
+            result,
+            Err(CoreError::DomainViolation(
+                DomainError::InvalidCheckpointLabel { .. }
+            ))
+        ));
+    }
 }
 
 /// `PHASE_27H.9`: Test checkpoint operation on state with many users
@@ -572,7 +680,7 @@ fn test_checkpoint_on_state_with_multiple_users() {
     // Add multiple users to state
     for i in 1..=10 {
         let initials_str = format!("U{i:02}");
-        state.users.push(User::new(
+        state.insert_user(User::new(
             BidYear::new(2026),
             Initials::new(&initials_str),
             format!("User {i}"),
@@ -587,7 +695,10 @@ fn test_checkpoint_on_state_with_multiple_users() {
     }
 
     let active_bid_year: BidYear = BidYear::new(2026);
-    let command: Command = Command::Checkpoint;
+    let command: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let actor: Actor = create_test_actor();
     let cause: Cause = create_test_cause();
 
@@ -612,7 +723,7 @@ fn test_finalize_preserves_existing_state() {
     let mut state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Add a user to state
-    state.users.push(User::new(
+    state.insert_user(User::new(
         BidYear::new(2026),
         Initials::new("AB"),
         String::from("Alice Blue"),
@@ -638,7 +749,17 @@ fn test_finalize_preserves_existing_state() {
 
     // State should be unchanged
     assert_eq!(transition.new_state.users.len(), 1);
-    assert_eq!(transition.new_state.users[0].initials.value(), "AB");
+    assert_eq!(
+        transition
+            .new_state
+            .users
+            .values()
+            .next()
+            .unwrap()
+            .initials
+            .value(),
+        "AB"
+    );
 
     // Audit event should be created
     assert_eq!(transition.audit_event.action.name, "Finalize");
@@ -709,6 +830,90 @@ fn test_update_user_on_empty_state_fails() {
     ));
 }
 
+fn persisted_user(user_id: i64, initials: &str) -> User {
+    User::with_id(
+        user_id,
+        BidYear::new(2026),
+        Initials::new(initials),
+        format!("User {initials}"),
+        Area::new("North"),
+        UserType::CPC,
+        Some(Crew::new(1).unwrap()),
+        create_test_seniority_data(),
+        false, // excluded_from_bidding
+        false, // excluded_from_leave_calculation
+        false, // no_bid_reviewed
+    )
+}
+
+fn rename_command(user_id: i64, initials: &str) -> Command {
+    Command::UpdateUser {
+        user_id,
+        initials: Initials::new(initials),
+        name: format!("User {initials}"),
+        area: Area::new("North"),
+        user_type: UserType::CPC,
+        crew: Some(Crew::new(1).unwrap()),
+        seniority_data: create_test_seniority_data(),
+    }
+}
+
+#[test]
+fn test_update_user_rename_rekeys_user() {
+    let metadata: BootstrapMetadata = create_test_metadata();
+    let state: State = State::with_users(
+        BidYear::new(2026),
+        Area::new("North"),
+        [persisted_user(1, "AB"), persisted_user(2, "CD")],
+    );
+
+    let result: Result<TransitionResult, CoreError> = apply(
+        &metadata,
+        &state,
+        &BidYear::new(2026),
+        rename_command(1, "ZZ"),
+        create_test_actor(),
+        create_test_cause(),
+    );
+
+    let transition: TransitionResult = result.unwrap();
+    let initials: Vec<&str> = transition
+        .new_state
+        .users
+        .keys()
+        .map(Initials::value)
+        .collect();
+    assert_eq!(initials, vec!["CD", "ZZ"]);
+    assert_eq!(
+        transition.new_state.users[&Initials::new("ZZ")].user_id,
+        Some(1)
+    );
+}
+
+#[test]
+fn test_update_user_rename_to_existing_initials_fails() {
+    let metadata: BootstrapMetadata = create_test_metadata();
+    let state: State = State::with_users(
+        BidYear::new(2026),
+        Area::new("North"),
+        [persisted_user(1, "AB"), persisted_user(2, "CD")],
+    );
+
+    let result: Result<TransitionResult, CoreError> = apply(
+        &metadata,
+        &state,
+        &BidYear::new(2026),
+        rename_command(1, "CD"),
+        create_test_actor(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(
+        result.unwrap_err(),
+        CoreError::DomainViolation(DomainError::DuplicateInitials { .. })
+    ));
+}
+
 #[test]
 fn test_update_user_participation_successful() {
     let metadata: BootstrapMetadata = create_test_metadata();
@@ -729,11 +934,7 @@ fn test_update_user_participation_successful() {
         false, // excluded_from_leave_calculation: starts false
         false, // no_bid_reviewed
     );
-    let state: State = State {
-        bid_year,
-        area: Area::new("North"),
-        users: vec![user],
-    };
+    let state: State = State::with_users(bid_year, Area::new("North"), [user]);
 
     // Update participation flags
     let update_command: Command = Command::UpdateUserParticipation {
@@ -757,8 +958,24 @@ fn test_update_user_participation_successful() {
     assert!(result.is_ok());
     let transition: TransitionResult = result.unwrap();
     assert_eq!(transition.new_state.users.len(), 1);
-    assert!(transition.new_state.users[0].excluded_from_bidding);
-    assert!(!transition.new_state.users[0].excluded_from_leave_calculation);
+    assert!(
+        transition
+            .new_state
+            .users
+            .values()
+            .next()
+            .unwrap()
+            .excluded_from_bidding
+    );
+    assert!(
+        !transition
+            .new_state
+            .users
+            .values()
+            .next()
+            .unwrap()
+            .excluded_from_leave_calculation
+    );
 }
 
 #[test]
@@ -781,11 +998,7 @@ fn test_update_user_participation_invariant_violation() {
         false, // excluded_from_leave_calculation
         false, // no_bid_reviewed
     );
-    let state: State = State {
-        bid_year,
-        area: Area::new("North"),
-        users: vec![user],
-    };
+    let state: State = State::with_users(bid_year, Area::new("North"), [user]);
 
     // Attempt to update with invalid flags (excluded from leave but not bidding)
     let invalid_command: Command = Command::UpdateUserParticipation {
@@ -858,11 +1071,7 @@ fn test_update_user_participation_preserves_other_fields() {
         false, // excluded_from_leave_calculation
         false, // no_bid_reviewed
     );
-    let state: State = State {
-        bid_year,
-        area: Area::new("North"),
-        users: vec![user],
-    };
+    let state: State = State::with_users(bid_year, Area::new("North"), [user]);
 
     // Update participation flags
     let update_command: Command = Command::UpdateUserParticipation {
@@ -885,7 +1094,7 @@ fn test_update_user_participation_preserves_other_fields() {
 
     assert!(result.is_ok());
     let transition: TransitionResult = result.unwrap();
-    let updated_user: &User = &transition.new_state.users[0];
+    let updated_user: &User = transition.new_state.users.values().next().unwrap();
 
     // Verify participation flags are updated
     assert!(updated_user.excluded_from_bidding);
@@ -899,3 +1108,141 @@ fn test_update_user_participation_preserves_other_fields() {
     assert_eq!(updated_user.user_type, UserType::CPC);
     assert_eq!(updated_user.crew, Some(Crew::new(1).unwrap()));
 }
+
+fn merge_command(keep: i64, merge: i64, reason: &str) -> Command {
+    Command::MergeUsers {
+        keep,
+        merge,
+        reason: String::from(reason),
+    }
+}
+
+#[test]
+fn test_merge_users_retires_duplicate_record() {
+    let metadata: BootstrapMetadata = create_test_metadata();
+    let state: State = State::with_users(
+        BidYear::new(2026),
+        Area::new("North"),
+        [persisted_user(1, "AB"), persisted_user(2, "AX")],
+    );
+
+    let transition: TransitionResult = apply(
+        &metadata,
+        &state,
+        &BidYear::new(2026),
+        merge_command(1, 2, "AX was imported twice"),
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let kept: &User = transition.new_state.find_user_by_id(1).unwrap();
+    let merged: &User = transition.new_state.find_user_by_id(2).unwrap();
+    assert!(!kept.excluded_from_bidding);
+    assert!(merged.excluded_from_bidding);
+    assert!(merged.excluded_from_leave_calculation);
+    assert_eq!(transition.audit_event.action.name, "MergeUsers");
+}
+
+#[test]
+fn test_merge_users_rejects_invalid_merges() {
+    let metadata: BootstrapMetadata = create_test_metadata();
+    let state: State = State::with_users(
+        BidYear::new(2026),
+        Area::new("North"),
+        [persisted_user(1, "AB"), persisted_user(2, "AX")],
+    );
+
+    for command in [
+        merge_command(1, 1, "AB was imported twice"),
+        merge_command(1, 3, "AB was imported twice"),
+        merge_command(1, 2, "dupe"),
+    ] {
+        let result: Result<TransitionResult, CoreError> = apply(
+            &metadata,
+            &state,
+            &BidYear::new(2026),
+            command,
+            create_test_actor(),
+            create_test_cause(),
+        );
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CoreError::DomainViolation(DomainError::InvalidUserMerge { .. })
+        ));
+    }
+}
+
+fn change_initials_command(from: &str, to: &str) -> Command {
+    Command::ChangeInitials {
+        from: Initials::new(from),
+        to: Initials::new(to),
+        effective_date: Date::from_calendar_date(2026, Month::March, 1).unwrap(),
+    }
+}
+
+#[test]
+fn test_change_initials_keeps_user_identity() {
+    let metadata: BootstrapMetadata = create_test_metadata();
+    let state: State = State::with_users(
+        BidYear::new(2026),
+        Area::new("North"),
+        [persisted_user(1, "AB"), persisted_user(2, "AX")],
+    );
+
+    let transition: TransitionResult = apply(
+        &metadata,
+        &state,
+        &BidYear::new(2026),
+        change_initials_command("ab", "CD"),
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    let renamed: &User = transition.new_state.find_user_by_id(1).unwrap();
+    assert_eq!(renamed.initials, Initials::new("CD"));
+    assert!(
+        !transition
+            .new_state
+            .users
+            .contains_key(&Initials::new("AB"))
+    );
+    assert_eq!(transition.new_state.users.len(), 2);
+    assert_eq!(transition.audit_event.action.name, "ChangeInitials");
+}
+
+#[test]
+fn test_change_initials_rejects_taken_or_unknown_initials() {
+    let metadata: BootstrapMetadata = create_test_metadata();
+    let state: State = State::with_users(
+        BidYear::new(2026),
+        Area::new("North"),
+        [persisted_user(1, "AB"), persisted_user(2, "AX")],
+    );
+
+    for (command, duplicate) in [
+        (change_initials_command("AB", "AX"), true),
+        (change_initials_command("ZZ", "CD"), false),
+        (change_initials_command("AB", "ABC"), false),
+    ] {
+        let result: Result<TransitionResult, CoreError> = apply(
+            &metadata,
+            &state,
+            &BidYear::new(2026),
+            command,
+            create_test_actor(),
+            create_test_cause(),
+        );
+
+        let error: CoreError = result.unwrap_err();
+        assert_eq!(
+            matches!(
+                error,
+                CoreError::DomainViolation(DomainError::DuplicateInitials { .. })
+            ),
+            duplicate
+        );
+    }
+}
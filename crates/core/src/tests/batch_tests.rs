@@ -0,0 +1,72 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+use crate::tests::helpers::{
+    create_test_actor, create_test_cause, create_test_metadata, create_test_seniority_data,
+};
+use crate::{BatchTransitionResult, BootstrapMetadata, Command, CoreError, State, apply_batch};
+use zab_bid_domain::{Area, BidYear, Crew, DomainError, Initials, UserType};
+
+fn register_command(initials: &str) -> Command {
+    Command::RegisterUser {
+        initials: Initials::new(initials),
+        name: format!("User {initials}"),
+        area: Area::new("North"),
+        user_type: UserType::CPC,
+        crew: Some(Crew::new(1).unwrap()),
+        seniority_data: create_test_seniority_data(),
+    }
+}
+
+#[test]
+fn test_apply_batch_threads_state_between_commands() {
+    let metadata: BootstrapMetadata = create_test_metadata();
+    let state: State = State::new(BidYear::new(2026), Area::new("North"));
+
+    let result: BatchTransitionResult = apply_batch(
+        &metadata,
+        &state,
+        &BidYear::new(2026),
+        vec![register_command("AB"), register_command("CD")],
+        &create_test_actor(),
+        &create_test_cause(),
+    );
+
+    assert!(result.all_succeeded());
+    assert_eq!(result.outcomes.len(), 2);
+    assert_eq!(result.final_state.users.len(), 2);
+    let second = result.outcomes[1].as_ref().unwrap();
+    assert_eq!(second.new_state.users.len(), 2);
+}
+
+#[test]
+fn test_apply_batch_records_failures_without_aborting() {
+    let metadata: BootstrapMetadata = create_test_metadata();
+    let state: State = State::new(BidYear::new(2026), Area::new("North"));
+
+    let result: BatchTransitionResult = apply_batch(
+        &metadata,
+        &state,
+        &BidYear::new(2026),
+        vec![
+            register_command("AB"),
+            register_command("AB"),
+            register_command("EF"),
+        ],
+        &create_test_actor(),
+        &create_test_cause(),
+    );
+
+    assert!(!result.all_succeeded());
+    assert!(result.outcomes[0].is_ok());
+    assert!(matches!(
+        result.outcomes[1],
+        Err(CoreError::DomainViolation(
+            DomainError::DuplicateInitials { .. }
+        ))
+    ));
+    assert!(result.outcomes[2].is_ok());
+    assert_eq!(result.final_state.users.len(), 2);
+}
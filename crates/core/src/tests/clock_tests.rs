@@ -0,0 +1,30 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the controllable clock.
+
+use crate::{Clock, ManualClock};
+
+use time::Duration;
+use time::macros::datetime;
+
+#[test]
+fn test_manual_clock_stands_still_until_moved() {
+    let clock: ManualClock = ManualClock::new(datetime!(2026-03-01 12:00 UTC));
+
+    assert_eq!(clock.now(), datetime!(2026-03-01 12:00 UTC));
+    assert_eq!(clock.now(), datetime!(2026-03-01 12:00 UTC));
+}
+
+#[test]
+fn test_manual_clock_advances_and_sets() {
+    let clock: ManualClock = ManualClock::new(datetime!(2026-03-01 12:00 UTC));
+
+    clock.advance(Duration::minutes(90));
+    assert_eq!(clock.now(), datetime!(2026-03-01 13:30 UTC));
+
+    clock.set(datetime!(2026-01-01 00:00 UTC));
+    assert_eq!(clock.now(), datetime!(2026-01-01 00:00 UTC));
+}
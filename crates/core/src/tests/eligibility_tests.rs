@@ -0,0 +1,187 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the eligibility rules engine and round eligibility criteria.
+
+use std::collections::BTreeMap;
+
+use zab_bid_domain::{BidOrderPosition, DomainError, SeniorityInputs, UserType};
+
+use crate::{
+    EligibilityBasis, EligibilityException, EligibilitySubject, RoundEligibility, RoundSubject,
+    build_round_bid_order, derive_eligibility, validate_exceptions, validate_round_eligibility,
+};
+
+fn subject(user_type: UserType) -> EligibilitySubject {
+    EligibilitySubject {
+        user_type,
+        excluded_from_bidding: false,
+        area_id: 1,
+        in_system_area: false,
+    }
+}
+
+fn exception(user_type: UserType, area_id: Option<i64>, can_bid: bool) -> EligibilityException {
+    EligibilityException {
+        user_type,
+        area_id,
+        can_bid,
+    }
+}
+
+#[test]
+fn test_every_user_type_bids_by_default() {
+    for user_type in [
+        UserType::CPC,
+        UserType::CpcIt,
+        UserType::DevR,
+        UserType::DevD,
+    ] {
+        let derived = derive_eligibility(&subject(user_type), &[]);
+        assert!(derived.can_bid);
+        assert_eq!(derived.basis, EligibilityBasis::Default);
+    }
+}
+
+#[test]
+fn test_system_area_and_exclusion_outrank_exceptions() {
+    let exceptions: Vec<EligibilityException> = vec![exception(UserType::CPC, None, true)];
+
+    let mut no_bid: EligibilitySubject = subject(UserType::CPC);
+    no_bid.in_system_area = true;
+    let derived = derive_eligibility(&no_bid, &exceptions);
+    assert!(!derived.can_bid);
+    assert_eq!(derived.basis, EligibilityBasis::SystemArea);
+
+    let mut excluded: EligibilitySubject = subject(UserType::CPC);
+    excluded.excluded_from_bidding = true;
+    let derived = derive_eligibility(&excluded, &exceptions);
+    assert!(!derived.can_bid);
+    assert_eq!(derived.basis, EligibilityBasis::ExcludedFromBidding);
+}
+
+#[test]
+fn test_area_exception_takes_precedence_over_bid_year_exception() {
+    let exceptions: Vec<EligibilityException> = vec![
+        exception(UserType::DevD, None, false),
+        exception(UserType::DevD, Some(1), true),
+    ];
+
+    let derived = derive_eligibility(&subject(UserType::DevD), &exceptions);
+    assert!(derived.can_bid);
+    assert_eq!(derived.basis, EligibilityBasis::AreaException);
+
+    let mut elsewhere: EligibilitySubject = subject(UserType::DevD);
+    elsewhere.area_id = 2;
+    let derived = derive_eligibility(&elsewhere, &exceptions);
+    assert!(!derived.can_bid);
+    assert_eq!(derived.basis, EligibilityBasis::BidYearException);
+
+    // Other user types are unaffected
+    let derived = derive_eligibility(&subject(UserType::DevR), &exceptions);
+    assert_eq!(derived.basis, EligibilityBasis::Default);
+}
+
+#[test]
+fn test_duplicate_exceptions_are_rejected() {
+    assert!(
+        validate_exceptions(&[
+            exception(UserType::DevR, None, false),
+            exception(UserType::DevR, Some(1), true),
+        ])
+        .is_ok()
+    );
+    assert!(matches!(
+        validate_exceptions(&[
+            exception(UserType::DevR, Some(1), false),
+            exception(UserType::DevR, Some(1), true),
+        ]),
+        Err(DomainError::InvalidEligibilityException { .. })
+    ));
+}
+
+fn position(user_id: i64, position: usize) -> BidOrderPosition {
+    BidOrderPosition {
+        user_id,
+        initials: format!("U{user_id}"),
+        position,
+        seniority_inputs: SeniorityInputs {
+            cumulative_natca_bu_date: String::from("2020-01-01"),
+            natca_bu_date: String::from("2020-01-01"),
+            eod_faa_date: String::from("2020-01-01"),
+            service_computation_date: String::from("2020-01-01"),
+            lottery_value: None,
+        },
+    }
+}
+
+#[test]
+fn test_round_bid_order_keeps_only_eligible_users() {
+    let bid_order: Vec<BidOrderPosition> = vec![position(1, 1), position(2, 2), position(3, 3)];
+    let subjects: BTreeMap<i64, RoundSubject> = BTreeMap::from([
+        (
+            1,
+            RoundSubject {
+                user_type: UserType::CPC,
+                remaining_hours: 40,
+            },
+        ),
+        (
+            2,
+            RoundSubject {
+                user_type: UserType::CPC,
+                remaining_hours: 8,
+            },
+        ),
+        (
+            3,
+            RoundSubject {
+                user_type: UserType::DevR,
+                remaining_hours: 80,
+            },
+        ),
+    ]);
+
+    // Unrestricted rounds keep the whole bid order
+    assert_eq!(
+        build_round_bid_order(&bid_order, Some(&RoundEligibility::default()), &subjects),
+        bid_order
+    );
+
+    let carryover: RoundEligibility = RoundEligibility {
+        min_remaining_hours: Some(16),
+        user_types: Vec::new(),
+    };
+    let round: Vec<usize> = build_round_bid_order(&bid_order, Some(&carryover), &subjects)
+        .iter()
+        .map(|p| p.position)
+        .collect();
+    assert_eq!(round, vec![1, 3]);
+
+    let cpc_carryover: RoundEligibility = RoundEligibility {
+        min_remaining_hours: Some(16),
+        user_types: vec![UserType::CPC],
+    };
+    let round: Vec<i64> = build_round_bid_order(&bid_order, Some(&cpc_carryover), &subjects)
+        .iter()
+        .map(|p| p.user_id)
+        .collect();
+    assert_eq!(round, vec![1]);
+
+    // Users without known facts cannot qualify for a restricted round
+    assert!(build_round_bid_order(&bid_order, Some(&carryover), &BTreeMap::new()).is_empty());
+}
+
+#[test]
+fn test_duplicate_round_user_types_are_rejected() {
+    let criteria: RoundEligibility = RoundEligibility {
+        min_remaining_hours: None,
+        user_types: vec![UserType::CPC, UserType::CPC],
+    };
+    assert!(matches!(
+        validate_round_eligibility(&criteria),
+        Err(DomainError::InvalidRoundConfiguration { .. })
+    ));
+}
@@ -0,0 +1,256 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for freezing and unfreezing an area.
+
+use crate::{BootstrapMetadata, Command, CoreError, State, apply, apply_bootstrap};
+
+use zab_bid_domain::{Area, BidYear, Crew, DomainError, Initials, UserType};
+
+use super::helpers::{
+    create_test_actor, create_test_cause, create_test_metadata, create_test_seniority_data,
+};
+
+fn freeze(metadata: &BootstrapMetadata, area: &str) -> Result<BootstrapMetadata, CoreError> {
+    apply_bootstrap(
+        metadata,
+        &BidYear::new(2026),
+        Command::FreezeScope {
+            bid_year: BidYear::new(2026),
+            area: Area::new(area),
+            reason: String::from("Grievance filed over round 1"),
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .map(|result| result.new_metadata)
+}
+
+fn unfreeze(metadata: &BootstrapMetadata, area: &str) -> Result<BootstrapMetadata, CoreError> {
+    apply_bootstrap(
+        metadata,
+        &BidYear::new(2026),
+        Command::Unfreeze {
+            bid_year: BidYear::new(2026),
+            area: Area::new(area),
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .map(|result| result.new_metadata)
+}
+
+fn sign_off(metadata: &BootstrapMetadata, area: &str) -> Result<(), CoreError> {
+    apply_bootstrap(
+        metadata,
+        &BidYear::new(2026),
+        Command::SignOffRound {
+            year: 2026,
+            area: Area::new(area),
+            round_id: 1,
+            bid: 0,
+            skipped: 0,
+            waived: 0,
+            outstanding: Vec::new(),
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .map(|_| ())
+}
+
+fn register_user(metadata: &BootstrapMetadata, initials: &str) -> Result<(), CoreError> {
+    let state: State = State::new(BidYear::new(2026), Area::new("North"));
+    apply(
+        metadata,
+        &state,
+        &BidYear::new(2026),
+        Command::RegisterUser {
+            initials: Initials::new(initials),
+            name: String::from("John Doe"),
+            area: Area::new("North"),
+            user_type: UserType::CPC,
+            crew: Some(Crew::new(1).unwrap()),
+            seniority_data: create_test_seniority_data(),
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .map(|_| ())
+}
+
+fn is_scope_frozen(result: &Result<(), CoreError>) -> bool {
+    matches!(
+        result,
+        Err(CoreError::DomainViolation(DomainError::ScopeFrozen { .. }))
+    )
+}
+
+#[test]
+fn test_freeze_records_audit_event_and_marks_area_frozen() {
+    let metadata: BootstrapMetadata = create_test_metadata();
+
+    let result = apply_bootstrap(
+        &metadata,
+        &BidYear::new(2026),
+        Command::FreezeScope {
+            bid_year: BidYear::new(2026),
+            area: Area::new("North"),
+            reason: String::from("  Grievance filed over round 1  "),
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert!(
+        result
+            .new_metadata
+            .is_frozen(&BidYear::new(2026), &Area::new("North"))
+    );
+    assert_eq!(result.audit_event.action.name, "FreezeScope");
+    assert_eq!(
+        result.audit_event.action.details.as_deref(),
+        Some("Froze area NORTH in bid year 2026. Reason: Grievance filed over round 1")
+    );
+    assert_eq!(result.audit_event.area, Some(Area::new("North")));
+}
+
+#[test]
+fn test_frozen_area_refuses_commands_whatever_the_command() {
+    let metadata: BootstrapMetadata = freeze(&create_test_metadata(), "North").unwrap();
+
+    assert!(is_scope_frozen(&sign_off(&metadata, "North")));
+    assert!(is_scope_frozen(&register_user(&metadata, "AB")));
+}
+
+#[test]
+fn test_freeze_leaves_other_areas_alone() {
+    let mut metadata: BootstrapMetadata = create_test_metadata();
+    metadata
+        .areas
+        .push((BidYear::new(2026), Area::new("South")));
+    let metadata: BootstrapMetadata = freeze(&metadata, "North").unwrap();
+
+    assert!(sign_off(&metadata, "South").is_ok());
+}
+
+#[test]
+fn test_frozen_area_blocks_bid_year_lifecycle_transitions() {
+    let metadata: BootstrapMetadata = freeze(&create_test_metadata(), "North").unwrap();
+
+    let result = apply_bootstrap(
+        &metadata,
+        &BidYear::new(2026),
+        Command::TransitionToBiddingClosed { year: 2026 },
+        create_test_actor(),
+        create_test_cause(),
+    );
+
+    assert!(is_scope_frozen(&result.map(|_| ())));
+}
+
+#[test]
+fn test_frozen_area_blocks_expected_counts() {
+    let metadata: BootstrapMetadata = freeze(&create_test_metadata(), "North").unwrap();
+    let expected = |command: Command| {
+        apply_bootstrap(
+            &metadata,
+            &BidYear::new(2026),
+            command,
+            create_test_actor(),
+            create_test_cause(),
+        )
+        .map(|_| ())
+    };
+
+    assert!(is_scope_frozen(&expected(Command::SetExpectedUserCount {
+        area: Area::new("North"),
+        expected_count: 12,
+    })));
+    assert!(is_scope_frozen(&expected(Command::SetExpectedAreaCount {
+        expected_count: 3,
+    })));
+}
+
+#[test]
+fn test_checkpoint_is_allowed_while_frozen() {
+    let metadata: BootstrapMetadata = freeze(&create_test_metadata(), "North").unwrap();
+    let state: State = State::new(BidYear::new(2026), Area::new("North"));
+
+    let result = apply(
+        &metadata,
+        &state,
+        &BidYear::new(2026),
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+        create_test_actor(),
+        create_test_cause(),
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_unfreeze_restores_mutations() {
+    let frozen: BootstrapMetadata = freeze(&create_test_metadata(), "North").unwrap();
+    let metadata: BootstrapMetadata = unfreeze(&frozen, "North").unwrap();
+
+    assert!(!metadata.is_frozen(&BidYear::new(2026), &Area::new("North")));
+    assert!(register_user(&metadata, "AB").is_ok());
+}
+
+#[test]
+fn test_freezing_a_frozen_area_fails() {
+    let metadata: BootstrapMetadata = freeze(&create_test_metadata(), "North").unwrap();
+
+    assert!(is_scope_frozen(&freeze(&metadata, "North").map(|_| ())));
+}
+
+#[test]
+fn test_unfreezing_an_unfrozen_area_fails() {
+    let result = unfreeze(&create_test_metadata(), "North");
+
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(
+            DomainError::ScopeNotFrozen { .. }
+        ))
+    ));
+}
+
+#[test]
+fn test_freeze_requires_a_reason() {
+    let result = apply_bootstrap(
+        &create_test_metadata(),
+        &BidYear::new(2026),
+        Command::FreezeScope {
+            bid_year: BidYear::new(2026),
+            area: Area::new("North"),
+            reason: String::from("  short  "),
+        },
+        create_test_actor(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(
+            DomainError::InvalidFreezeReason { .. }
+        ))
+    ));
+}
+
+#[test]
+fn test_freeze_requires_existing_area() {
+    let result = freeze(&create_test_metadata(), "Nowhere");
+
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(DomainError::AreaNotFound { .. }))
+    ));
+}
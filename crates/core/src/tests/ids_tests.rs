@@ -0,0 +1,36 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for ULID generators.
+
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use crate::{IdGenerator, ManualClock, SequentialIdGenerator, UlidGenerator};
+
+use time::macros::datetime;
+use ulid::Ulid;
+
+#[test]
+fn test_sequential_generator_counts_from_one() {
+    let generator: SequentialIdGenerator = SequentialIdGenerator::new();
+
+    assert_eq!(generator.next_ulid(), Ulid::from_parts(0, 1));
+    assert_eq!(generator.next_ulid(), Ulid::from_parts(0, 2));
+}
+
+#[test]
+fn test_ulid_generator_uses_clock_and_increases_within_a_millisecond() {
+    let clock: Arc<ManualClock> = Arc::new(ManualClock::new(datetime!(2026-03-01 12:00 UTC)));
+    let generator: UlidGenerator = UlidGenerator::new(clock);
+
+    let first: Ulid = generator.next_ulid();
+    let second: Ulid = generator.next_ulid();
+
+    assert!(second > first);
+    let expected: SystemTime = datetime!(2026-03-01 12:00 UTC).into();
+    assert_eq!(first.datetime(), expected);
+    assert_eq!(second.datetime(), expected);
+}
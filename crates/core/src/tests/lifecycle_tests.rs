@@ -10,7 +10,12 @@
 
 use crate::{BootstrapMetadata, BootstrapResult, Command, CoreError, apply_bootstrap};
 
-use zab_bid_domain::{Area, BidYear, DomainError, validate_bid_year};
+use zab_bid_domain::{
+    Area, BidAmendment, BidAmendmentPolicy, BidPreference, BidReceiptMethod, BidYear, DomainError,
+    Initials, ReadinessEvaluation, validate_bid_year,
+};
+
+use zab_bid_audit::Actor;
 
 use super::helpers::{create_test_actor, create_test_cause};
 
@@ -21,6 +26,17 @@ fn create_metadata_with_bid_year(year: u16) -> BootstrapMetadata {
     metadata
 }
 
+/// Helper to create a readiness evaluation with no blockers.
+fn ready_evaluation() -> ReadinessEvaluation {
+    ReadinessEvaluation {
+        areas_missing_rounds: Vec::new(),
+        no_bid_users_pending_review: 0,
+        participation_flag_violations: 0,
+        seniority_conflicts: Vec::new(),
+        bid_schedule_set: true,
+    }
+}
+
 /// Helper to create metadata with bid year and areas.
 fn create_metadata_with_areas(year: u16, area_codes: &[&str]) -> BootstrapMetadata {
     let mut metadata = create_metadata_with_bid_year(year);
@@ -272,13 +288,16 @@ fn test_transition_to_canonicalized_fails_for_nonexistent_year() {
 }
 
 #[test]
-fn test_transition_to_bidding_active_fails_for_nonexistent_year() {
+fn test_activate_bidding_fails_for_nonexistent_year() {
     let metadata = BootstrapMetadata::new();
     let active_bid_year = BidYear::new(2026);
     let actor = create_test_actor();
     let cause = create_test_cause();
 
-    let command = Command::TransitionToBiddingActive { year: 2026 };
+    let command = Command::ActivateBidding {
+        year: 2026,
+        readiness: ready_evaluation(),
+    };
 
     let result = apply_bootstrap(&metadata, &active_bid_year, command, actor, cause);
 
@@ -470,17 +489,55 @@ fn test_transition_to_canonicalized_succeeds_for_existing_year() {
 }
 
 #[test]
-fn test_transition_to_bidding_active_succeeds_for_existing_year() {
+fn test_activate_bidding_succeeds_for_ready_year() {
+    let metadata = create_metadata_with_bid_year(2026);
+    let active_bid_year = BidYear::new(2026);
+    let actor = create_test_actor();
+    let cause = create_test_cause();
+
+    let command = Command::ActivateBidding {
+        year: 2026,
+        readiness: ready_evaluation(),
+    };
+
+    let result: BootstrapResult =
+        apply_bootstrap(&metadata, &active_bid_year, command, actor, cause).unwrap();
+
+    assert_eq!(result.audit_event.action.name, "ActivateBidding");
+    assert_eq!(
+        result.audit_event.after.data,
+        "lifecycle_state=BiddingActive,areas_missing_rounds=[],no_bid_users_pending_review=0,participation_flag_violations=0,seniority_conflicts=0,bid_schedule_set=true"
+    );
+}
+
+#[test]
+fn test_activate_bidding_refused_while_blocked() {
     let metadata = create_metadata_with_bid_year(2026);
     let active_bid_year = BidYear::new(2026);
     let actor = create_test_actor();
     let cause = create_test_cause();
 
-    let command = Command::TransitionToBiddingActive { year: 2026 };
+    let command = Command::ActivateBidding {
+        year: 2026,
+        readiness: ReadinessEvaluation {
+            areas_missing_rounds: vec![String::from("NORTH")],
+            bid_schedule_set: false,
+            ..ready_evaluation()
+        },
+    };
 
     let result = apply_bootstrap(&metadata, &active_bid_year, command, actor, cause);
 
-    assert!(result.is_ok());
+    assert!(matches!(
+        result.unwrap_err(),
+        CoreError::DomainViolation(DomainError::BiddingNotReady {
+            year: 2026,
+            ref blocking_reasons,
+        }) if blocking_reasons == &vec![
+            String::from("Area 'NORTH' has no rounds configured"),
+            String::from("Bid schedule is not set"),
+        ]
+    ));
 }
 
 #[test]
@@ -496,3 +553,1030 @@ fn test_transition_to_bidding_closed_succeeds_for_existing_year() {
 
     assert!(result.is_ok());
 }
+
+fn reopen(justification: &str) -> Command {
+    Command::ReopenBidYear {
+        year: 2026,
+        justification: String::from(justification),
+    }
+}
+
+#[test]
+fn test_reopen_bid_year_records_justification() {
+    let metadata = create_metadata_with_bid_year(2026);
+    let active_bid_year = BidYear::new(2026);
+
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        reopen("  Two controllers were assigned to the wrong area  "),
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(result.audit_event.action.name, "ReopenBidYear");
+    assert!(
+        result
+            .audit_event
+            .action
+            .details
+            .unwrap()
+            .ends_with("Justification: Two controllers were assigned to the wrong area")
+    );
+    assert_eq!(
+        result.audit_event.after.data,
+        "lifecycle_state=BootstrapComplete,canonical_data_cleared=true"
+    );
+}
+
+#[test]
+fn test_reopen_bid_year_rejects_short_justification() {
+    let metadata = create_metadata_with_bid_year(2026);
+    let active_bid_year = BidYear::new(2026);
+
+    let result = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        reopen("  wrong area   "),
+        create_test_actor(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(
+        result.unwrap_err(),
+        CoreError::DomainViolation(DomainError::InvalidReopenJustification { ref justification })
+            if justification == "wrong area"
+    ));
+}
+
+fn advance_bidder(previous_user_id: Option<i64>, next_user_id: Option<i64>) -> Command {
+    Command::AdvanceBidder {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        previous_user_id,
+        next_user_id,
+        time_driven: false,
+    }
+}
+
+#[test]
+fn test_advance_bidder_records_scoped_handover() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        advance_bidder(Some(1), Some(2)),
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(result.new_metadata, metadata);
+    assert_eq!(result.audit_event.action.name, "AdvanceBidder");
+    assert_eq!(
+        result.audit_event.before.data,
+        "round_id=7,current_user_id=1"
+    );
+    assert_eq!(
+        result.audit_event.after.data,
+        "round_id=7,current_user_id=2"
+    );
+    assert_eq!(result.audit_event.area, Some(Area::new("NORTH")));
+    assert!(
+        result
+            .audit_event
+            .action
+            .details
+            .as_deref()
+            .is_some_and(|d| d.ends_with("(manual)"))
+    );
+}
+
+#[test]
+fn test_advance_bidder_time_driven_and_round_completion() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let command = Command::AdvanceBidder {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        previous_user_id: Some(2),
+        next_user_id: None,
+        time_driven: true,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        result.audit_event.after.data,
+        "round_id=7,current_user_id=none"
+    );
+    assert!(
+        result
+            .audit_event
+            .action
+            .details
+            .as_deref()
+            .is_some_and(|d| d.ends_with("(window elapsed)"))
+    );
+}
+
+#[test]
+fn test_advance_bidder_rejects_invalid_handover() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    for command in [advance_bidder(None, None), advance_bidder(Some(3), Some(3))] {
+        let result = apply_bootstrap(
+            &metadata,
+            &active_bid_year,
+            command,
+            create_test_actor(),
+            create_test_cause(),
+        );
+        assert!(matches!(
+            result,
+            Err(CoreError::DomainViolation(
+                DomainError::InvalidBidderAdvance { .. }
+            ))
+        ));
+    }
+}
+
+#[test]
+fn test_advance_bidder_requires_existing_area() {
+    let metadata = create_metadata_with_areas(2026, &["SOUTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let result = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        advance_bidder(None, Some(1)),
+        create_test_actor(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(DomainError::AreaNotFound { .. }))
+    ));
+}
+
+#[test]
+fn test_expire_bid_window_records_scoped_expiry() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let command = Command::ExpireBidWindow {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        user_id: 3,
+        marked_missed: true,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(result.new_metadata, metadata);
+    assert_eq!(result.audit_event.action.name, "WindowExpired");
+    assert_eq!(
+        result.audit_event.before.data,
+        "round_id=7,user_id=3,window=open"
+    );
+    assert_eq!(
+        result.audit_event.after.data,
+        "round_id=7,user_id=3,window=expired,marked_missed=true"
+    );
+    assert_eq!(result.audit_event.area, Some(Area::new("NORTH")));
+}
+
+#[test]
+fn test_expire_bid_window_requires_existing_area() {
+    let metadata = create_metadata_with_areas(2026, &["SOUTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let command = Command::ExpireBidWindow {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        user_id: 3,
+        marked_missed: false,
+    };
+    let result = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(DomainError::AreaNotFound { .. }))
+    ));
+}
+
+fn enter_leave_bid(leave_dates: Vec<time::Date>, hours: u32) -> Command {
+    Command::EnterLeaveBid {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        user_id: 3,
+        on_behalf_of: Initials::new("AB"),
+        received_via: BidReceiptMethod::Phone,
+        leave_dates,
+        hours,
+        signed_off: false,
+        override_reason: None,
+        amendment: None,
+    }
+}
+
+#[test]
+fn test_enter_leave_bid_records_proxy_attribution() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        enter_leave_bid(
+            vec![
+                time::macros::date!(2026 - 06 - 02),
+                time::macros::date!(2026 - 06 - 01),
+            ],
+            8,
+        ),
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(result.new_metadata, metadata);
+    assert_eq!(result.audit_event.action.name, "EnterLeaveBid");
+    assert_eq!(
+        result.audit_event.after.data,
+        "round_id=7,user_id=3,on_behalf_of=AB,received_via=phone,leave_dates=2026-06-01|2026-06-02,hours=8"
+    );
+    assert_eq!(
+        result.audit_event.action.details.as_deref(),
+        Some("Entered 2 leave day(s) in round 7 on behalf of AB (received via phone)")
+    );
+    assert_eq!(result.audit_event.area, Some(Area::new("NORTH")));
+}
+
+#[test]
+fn test_enter_leave_bid_rejects_invalid_entries() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+    let day = time::macros::date!(2026 - 06 - 01);
+
+    for command in [
+        enter_leave_bid(Vec::new(), 8),
+        enter_leave_bid(vec![day], 0),
+        enter_leave_bid(vec![day, day], 8),
+    ] {
+        let result = apply_bootstrap(
+            &metadata,
+            &active_bid_year,
+            command,
+            create_test_actor(),
+            create_test_cause(),
+        );
+        assert!(matches!(
+            result,
+            Err(CoreError::DomainViolation(
+                DomainError::InvalidLeaveBid { .. }
+            ))
+        ));
+    }
+}
+
+#[test]
+fn test_enter_leave_bid_after_sign_off_requires_override() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+    let day = time::macros::date!(2026 - 06 - 01);
+    let after_sign_off = |override_reason: Option<&str>| Command::EnterLeaveBid {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        user_id: 3,
+        on_behalf_of: Initials::new("AB"),
+        received_via: BidReceiptMethod::Phone,
+        leave_dates: vec![day],
+        hours: 8,
+        signed_off: true,
+        override_reason: override_reason.map(String::from),
+        amendment: None,
+    };
+
+    for override_reason in [None, Some("  ")] {
+        let result = apply_bootstrap(
+            &metadata,
+            &active_bid_year,
+            after_sign_off(override_reason),
+            create_test_actor(),
+            create_test_cause(),
+        );
+        assert!(matches!(
+            result,
+            Err(CoreError::DomainViolation(DomainError::RoundSignedOff {
+                round_id: 7
+            }))
+        ));
+    }
+
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        after_sign_off(Some("Grievance settlement")),
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(
+        result.audit_event.action.details.as_deref(),
+        Some(
+            "Entered 1 leave day(s) in round 7 on behalf of AB (received via phone) overriding sign-off: Grievance settlement"
+        )
+    );
+}
+
+#[test]
+fn test_enter_leave_bid_enforces_amendment_window() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+    let amend = |policy: BidAmendmentPolicy| Command::EnterLeaveBid {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        user_id: 3,
+        on_behalf_of: Initials::new("AB"),
+        received_via: BidReceiptMethod::Phone,
+        leave_dates: vec![time::macros::date!(2026 - 06 - 01)],
+        hours: 8,
+        signed_off: false,
+        override_reason: None,
+        amendment: Some(BidAmendment {
+            policy,
+            first_submitted_at: time::macros::datetime!(2026-03-02 14:00 UTC),
+            window_closes_at: Some(time::macros::datetime!(2026-03-02 22:00 UTC)),
+            amended_at: time::macros::datetime!(2026-03-03 09:00 UTC),
+        }),
+    };
+
+    for policy in [
+        BidAmendmentPolicy::NotAllowed,
+        BidAmendmentPolicy::UntilWindowCloses,
+        BidAmendmentPolicy::WithinHours(12),
+    ] {
+        let result = apply_bootstrap(
+            &metadata,
+            &active_bid_year,
+            amend(policy),
+            create_test_actor(),
+            create_test_cause(),
+        );
+        assert!(matches!(
+            result,
+            Err(CoreError::DomainViolation(
+                DomainError::AmendmentWindowPassed { round_id: 7, .. }
+            ))
+        ));
+    }
+
+    for policy in [
+        BidAmendmentPolicy::Unrestricted,
+        BidAmendmentPolicy::WithinHours(24),
+    ] {
+        apply_bootstrap(
+            &metadata,
+            &active_bid_year,
+            amend(policy),
+            create_test_actor(),
+            create_test_cause(),
+        )
+        .unwrap();
+    }
+}
+
+fn preference(rank: u32, leave_dates: &[time::Date]) -> BidPreference {
+    BidPreference::new(rank, leave_dates.to_vec(), 8).unwrap()
+}
+
+#[test]
+fn test_submit_bid_preferences_records_ranked_list() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let command = Command::SubmitBidPreferences {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        user_id: 3,
+        on_behalf_of: Initials::new("AB"),
+        received_via: BidReceiptMethod::Written,
+        preferences: vec![
+            preference(2, &[time::macros::date!(2026 - 07 - 01)]),
+            preference(
+                1,
+                &[
+                    time::macros::date!(2026 - 06 - 02),
+                    time::macros::date!(2026 - 06 - 01),
+                ],
+            ),
+        ],
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(result.new_metadata, metadata);
+    assert_eq!(result.audit_event.action.name, "SubmitBidPreferences");
+    assert_eq!(
+        result.audit_event.after.data,
+        "round_id=7,user_id=3,on_behalf_of=AB,received_via=written,preferences=1:2026-06-01|2026-06-02@8;2:2026-07-01@8"
+    );
+}
+
+#[test]
+fn test_submit_bid_preferences_rejects_duplicate_ranks() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+    let day = time::macros::date!(2026 - 06 - 01);
+
+    for preferences in [
+        Vec::new(),
+        vec![preference(1, &[day]), preference(1, &[day])],
+    ] {
+        let command = Command::SubmitBidPreferences {
+            year: 2026,
+            area: Area::new("NORTH"),
+            round_id: 7,
+            user_id: 3,
+            on_behalf_of: Initials::new("AB"),
+            received_via: BidReceiptMethod::Phone,
+            preferences,
+        };
+        let result = apply_bootstrap(
+            &metadata,
+            &active_bid_year,
+            command,
+            create_test_actor(),
+            create_test_cause(),
+        );
+        assert!(matches!(
+            result,
+            Err(CoreError::DomainViolation(
+                DomainError::InvalidBidPreference { .. }
+            ))
+        ));
+    }
+}
+
+#[test]
+fn test_apply_bid_preference_records_passed_over_ranks() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let command = Command::ApplyBidPreference {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        user_id: 3,
+        on_behalf_of: Initials::new("AB"),
+        applied: Some(preference(3, &[time::macros::date!(2026 - 08 - 03)])),
+        passed_over: vec![1, 2],
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(result.audit_event.action.name, "ApplyBidPreference");
+    assert_eq!(
+        result.audit_event.before.data,
+        "round_id=7,user_id=3,preferences=pending"
+    );
+    assert_eq!(
+        result.audit_event.after.data,
+        "round_id=7,user_id=3,applied=3:2026-08-03@8,passed_over=1|2"
+    );
+
+    let command = Command::ApplyBidPreference {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        user_id: 3,
+        on_behalf_of: Initials::new("AB"),
+        applied: None,
+        passed_over: vec![1],
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(
+        result.audit_event.after.data,
+        "round_id=7,user_id=3,applied=none,passed_over=1"
+    );
+}
+
+#[test]
+fn test_adjust_slot_inventory_records_previous_adjustment() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let command = Command::AdjustSlotInventory {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        date: time::macros::date!(2026 - 06 - 01),
+        previous_adjustment: 1,
+        staffing_adjustment: -2,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(result.audit_event.action.name, "AdjustSlotInventory");
+    assert_eq!(
+        result.audit_event.before.data,
+        "round_id=7,date=2026-06-01,staffing_adjustment=1"
+    );
+    assert_eq!(
+        result.audit_event.after.data,
+        "round_id=7,date=2026-06-01,staffing_adjustment=-2"
+    );
+
+    let command = Command::AdjustSlotInventory {
+        year: 2026,
+        area: Area::new("SOUTH"),
+        round_id: 7,
+        date: time::macros::date!(2026 - 06 - 01),
+        previous_adjustment: 0,
+        staffing_adjustment: 1,
+    };
+    let result = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(DomainError::AreaNotFound { .. }))
+    ));
+}
+
+#[test]
+fn test_overbid_decision_links_to_request_event() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let command = Command::RequestOverbid {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        user_id: 3,
+        on_behalf_of: Initials::new("AB"),
+        received_via: BidReceiptMethod::Phone,
+        leave_date: time::macros::date!(2026 - 06 - 01),
+        hours: 8,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(result.audit_event.action.name, "RequestOverbid");
+    assert_eq!(
+        result.audit_event.after.data,
+        "round_id=7,user_id=3,leave_date=2026-06-01,hours=8,on_behalf_of=AB,received_via=phone,overbid=pending"
+    );
+
+    let command = Command::ApproveOverbid {
+        year: 2026,
+        area: Area::new("NORTH"),
+        overbid_request_id: 11,
+        request_event_id: 42,
+        round_id: 7,
+        user_id: 3,
+        leave_date: time::macros::date!(2026 - 06 - 01),
+        previous_adjustment: -1,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(result.audit_event.action.name, "ApproveOverbid");
+    assert_eq!(
+        result.audit_event.after.data,
+        "overbid_request_id=11,request_event_id=42,round_id=7,user_id=3,leave_date=2026-06-01,overbid=approved,staffing_adjustment=0"
+    );
+}
+
+#[test]
+fn test_deny_overbid_requires_reason() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+    let deny = |reason: &str| Command::DenyOverbid {
+        year: 2026,
+        area: Area::new("NORTH"),
+        overbid_request_id: 11,
+        request_event_id: 42,
+        round_id: 7,
+        user_id: 3,
+        leave_date: time::macros::date!(2026 - 06 - 01),
+        reason: String::from(reason),
+    };
+
+    let result = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        deny("  "),
+        create_test_actor(),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(
+            DomainError::InvalidOverbid { .. }
+        ))
+    ));
+
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        deny("Minimum staffing"),
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(result.audit_event.action.name, "DenyOverbid");
+    assert!(
+        result
+            .audit_event
+            .action
+            .details
+            .as_deref()
+            .unwrap()
+            .ends_with("Minimum staffing")
+    );
+}
+
+#[test]
+fn test_self_bid_confirmation_requires_second_operator() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let command = Command::RequestSelfBidEntry {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        user_id: 3,
+        on_behalf_of: Initials::new("AB"),
+        received_via: BidReceiptMethod::InPerson,
+        leave_dates: vec![
+            time::macros::date!(2026 - 06 - 02),
+            time::macros::date!(2026 - 06 - 01),
+        ],
+        hours: 8,
+    };
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        command,
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(result.audit_event.action.name, "RequestSelfBidEntry");
+    assert_eq!(
+        result.audit_event.after.data,
+        "round_id=7,user_id=3,on_behalf_of=AB,received_via=in_person,leave_dates=2026-06-01|2026-06-02,hours=8,self_bid=pending"
+    );
+
+    let confirm = || Command::ConfirmSelfBidEntry {
+        year: 2026,
+        area: Area::new("NORTH"),
+        self_bid_request_id: 5,
+        request_event_id: 42,
+        entry_event_id: 43,
+        requested_by_operator_id: 9,
+        round_id: 7,
+        user_id: 3,
+    };
+    let operator = |operator_id: i64| {
+        Actor::with_operator(
+            operator_id.to_string(),
+            String::from("operator"),
+            operator_id,
+            format!("OP{operator_id}"),
+            format!("Operator {operator_id}"),
+        )
+    };
+
+    let result = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        confirm(),
+        operator(9),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(
+            DomainError::InvalidSelfBid { .. }
+        ))
+    ));
+
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        confirm(),
+        operator(10),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(result.audit_event.action.name, "ConfirmSelfBidEntry");
+    assert_eq!(
+        result.audit_event.after.data,
+        "self_bid_request_id=5,request_event_id=42,entry_event_id=43,requested_by_operator_id=9,round_id=7,user_id=3,self_bid=confirmed"
+    );
+}
+
+#[test]
+fn test_operator_cannot_change_own_leave() {
+    let mut metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    metadata.operator_controllers = vec![(7, Initials::new("AB"))];
+    let active_bid_year = BidYear::new(2026);
+    let operator = |operator_id: i64| {
+        Actor::with_operator(
+            operator_id.to_string(),
+            String::from("operator"),
+            operator_id,
+            format!("OP{operator_id}"),
+            format!("Operator {operator_id}"),
+        )
+    };
+
+    let result = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        enter_leave_bid(vec![time::macros::date!(2026 - 06 - 01)], 8),
+        operator(7),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(
+            DomainError::InvalidSelfBid { .. }
+        ))
+    ));
+
+    let withdraw = Command::WithdrawLeaveBid {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        user_id: 3,
+        on_behalf_of: Initials::new("AB"),
+        leave_bid_id: 11,
+        leave_date: time::macros::date!(2026 - 07 - 01),
+        round_closed: true,
+    };
+    let result = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        withdraw,
+        operator(7),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(
+            DomainError::InvalidSelfBid { .. }
+        ))
+    ));
+
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        enter_leave_bid(vec![time::macros::date!(2026 - 06 - 01)], 8),
+        operator(8),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(result.audit_event.action.name, "EnterLeaveBid");
+}
+
+fn sign_off_round(outstanding: &[&str]) -> Command {
+    Command::SignOffRound {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        bid: 3,
+        skipped: 1,
+        waived: 1,
+        outstanding: outstanding.iter().map(|i| Initials::new(i)).collect(),
+    }
+}
+
+#[test]
+fn test_sign_off_round_records_summary() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        sign_off_round(&[]),
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(result.new_metadata, metadata);
+    assert_eq!(result.audit_event.action.name, "SignOffRound");
+    assert_eq!(
+        result.audit_event.before.data,
+        "round_id=7,signed_off=false"
+    );
+    assert_eq!(
+        result.audit_event.after.data,
+        "round_id=7,signed_off=true,bid=3,skipped=1,waived=1"
+    );
+    assert_eq!(result.audit_event.area, Some(Area::new("NORTH")));
+}
+
+#[test]
+fn test_sign_off_round_requires_every_user_done() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let result = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        sign_off_round(&["CD", "EF"]),
+        create_test_actor(),
+        create_test_cause(),
+    );
+
+    match result {
+        Err(CoreError::DomainViolation(DomainError::RoundNotComplete {
+            round_id,
+            outstanding,
+        })) => {
+            assert_eq!(round_id, 7);
+            assert_eq!(outstanding, vec![String::from("CD"), String::from("EF")]);
+        }
+        other => panic!("Expected RoundNotComplete, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_withdraw_leave_bid_after_close_opens_waitlist() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        Command::WithdrawLeaveBid {
+            year: 2026,
+            area: Area::new("NORTH"),
+            round_id: 7,
+            user_id: 3,
+            on_behalf_of: Initials::new("AB"),
+            leave_bid_id: 11,
+            leave_date: time::macros::date!(2026 - 07 - 01),
+            round_closed: true,
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+
+    assert_eq!(result.new_metadata, metadata);
+    assert_eq!(result.audit_event.action.name, "WithdrawLeaveBid");
+    assert_eq!(
+        result.audit_event.after.data,
+        "leave_bid_id=11,round_id=7,user_id=3,leave_date=2026-07-01,status=withdrawn,waitlist=open"
+    );
+}
+
+#[test]
+fn test_waitlist_offer_expiry_is_enforced() {
+    let metadata = create_metadata_with_areas(2026, &["NORTH"]);
+    let active_bid_year = BidYear::new(2026);
+    let offered_at = time::macros::datetime!(2026-07-01 12:00 UTC);
+    let expires_at = time::macros::datetime!(2026-07-02 12:00 UTC);
+
+    // An offer must lapse after it is made
+    let result = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        Command::OfferWaitlistSlot {
+            year: 2026,
+            area: Area::new("NORTH"),
+            round_id: 7,
+            waitlist_slot_id: 2,
+            user_id: 4,
+            leave_date: time::macros::date!(2026 - 07 - 01),
+            offered_at,
+            expires_at: offered_at,
+        },
+        create_test_actor(),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(
+            DomainError::InvalidWaitlistOffer { .. }
+        ))
+    ));
+
+    let accept = |accepted_at| Command::AcceptWaitlistOffer {
+        year: 2026,
+        area: Area::new("NORTH"),
+        round_id: 7,
+        waitlist_offer_id: 5,
+        user_id: 4,
+        on_behalf_of: Initials::new("CD"),
+        received_via: BidReceiptMethod::Phone,
+        leave_date: time::macros::date!(2026 - 07 - 01),
+        expires_at,
+        accepted_at,
+    };
+    let result = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        accept(time::macros::datetime!(2026-07-02 12:01 UTC)),
+        create_test_actor(),
+        create_test_cause(),
+    );
+    assert!(matches!(
+        result,
+        Err(CoreError::DomainViolation(
+            DomainError::WaitlistOfferExpired {
+                waitlist_offer_id: 5,
+                ..
+            }
+        ))
+    ));
+
+    let result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &active_bid_year,
+        accept(time::macros::datetime!(2026-07-02 11:59 UTC)),
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    assert_eq!(result.audit_event.action.name, "AcceptWaitlistOffer");
+    assert!(result.audit_event.after.data.contains("offer=accepted"));
+}
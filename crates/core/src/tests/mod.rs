@@ -6,8 +6,15 @@
 #![allow(clippy::expect_used, clippy::unwrap_used)]
 
 mod apply_tests;
+mod batch_tests;
 mod bootstrap_tests;
+mod clock_tests;
 mod command_identity_tests;
+mod eligibility_tests;
+mod freeze_tests;
 mod helpers;
+mod ids_tests;
 mod lifecycle_tests;
+mod round_order_tests;
+mod rules_tests;
 mod validation_tests;
@@ -0,0 +1,113 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for per-round bid order sequencing.
+
+use std::collections::BTreeMap;
+
+use zab_bid_domain::{BidOrderPosition, DomainError, SeniorityInputs, UserType};
+
+use crate::{BidOrderRotation, RoundEligibility, RoundSubject, sequence_round_bid_order};
+
+fn position(user_id: i64, position: usize) -> BidOrderPosition {
+    BidOrderPosition {
+        user_id,
+        initials: format!("U{user_id}"),
+        position,
+        seniority_inputs: SeniorityInputs {
+            cumulative_natca_bu_date: String::from("2020-01-01"),
+            natca_bu_date: String::from("2020-01-01"),
+            eod_faa_date: String::from("2020-01-01"),
+            service_computation_date: String::from("2020-01-01"),
+            lottery_value: None,
+        },
+    }
+}
+
+fn sequence(positions: &[BidOrderPosition]) -> Vec<(i64, usize)> {
+    positions.iter().map(|p| (p.user_id, p.position)).collect()
+}
+
+fn bid_order() -> Vec<BidOrderPosition> {
+    vec![
+        position(1, 1),
+        position(2, 2),
+        position(3, 3),
+        position(4, 4),
+    ]
+}
+
+#[test]
+fn test_reverse_even_rounds_rotation() {
+    let round_one: Vec<BidOrderPosition> = sequence_round_bid_order(
+        &bid_order(),
+        1,
+        BidOrderRotation::ReverseEvenRounds,
+        None,
+        &BTreeMap::new(),
+    );
+    assert_eq!(sequence(&round_one), vec![(1, 1), (2, 2), (3, 3), (4, 4)]);
+
+    let round_two: Vec<BidOrderPosition> = sequence_round_bid_order(
+        &bid_order(),
+        2,
+        BidOrderRotation::ReverseEvenRounds,
+        None,
+        &BTreeMap::new(),
+    );
+    assert_eq!(sequence(&round_two), vec![(4, 1), (3, 2), (2, 3), (1, 4)]);
+
+    let forward: Vec<BidOrderPosition> = sequence_round_bid_order(
+        &bid_order(),
+        2,
+        BidOrderRotation::Forward,
+        None,
+        &BTreeMap::new(),
+    );
+    assert_eq!(sequence(&forward), sequence(&bid_order()));
+}
+
+#[test]
+fn test_skipped_users_close_the_gap() {
+    let subjects: BTreeMap<i64, RoundSubject> = (1..=4)
+        .map(|user_id| {
+            (
+                user_id,
+                RoundSubject {
+                    user_type: UserType::CPC,
+                    // Users 2 and 3 have nothing left to carry over
+                    remaining_hours: if user_id == 2 || user_id == 3 { 0 } else { 24 },
+                },
+            )
+        })
+        .collect();
+    let carryover: RoundEligibility = RoundEligibility {
+        min_remaining_hours: Some(8),
+        user_types: Vec::new(),
+    };
+
+    let round: Vec<BidOrderPosition> = sequence_round_bid_order(
+        &bid_order(),
+        4,
+        BidOrderRotation::ReverseEvenRounds,
+        Some(&carryover),
+        &subjects,
+    );
+    assert_eq!(sequence(&round), vec![(4, 1), (1, 2)]);
+}
+
+#[test]
+fn test_rotation_names_round_trip() {
+    for rotation in [
+        BidOrderRotation::Forward,
+        BidOrderRotation::ReverseEvenRounds,
+    ] {
+        assert_eq!(BidOrderRotation::parse(rotation.as_str()), Ok(rotation));
+    }
+    assert!(matches!(
+        BidOrderRotation::parse("sideways"),
+        Err(DomainError::InvalidRoundConfiguration { .. })
+    ));
+}
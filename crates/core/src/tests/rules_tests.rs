@@ -0,0 +1,89 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the bid validation rule engine.
+
+use std::collections::BTreeSet;
+
+use time::Date;
+use time::macros::date;
+use zab_bid_domain::DomainError;
+
+use crate::{BidRule, RuleViolation, evaluate_rules};
+
+#[test]
+fn test_rule_round_trips_through_stored_parts() {
+    let rules: Vec<BidRule> = vec![
+        BidRule::MinConsecutiveDays { days: 3 },
+        BidRule::MaxSplitsPerRound { splits: 2 },
+        BidRule::HolidayPairing {
+            holidays: BTreeSet::from([date!(2026 - 12 - 25)]),
+        },
+    ];
+
+    for rule in rules {
+        let rebuilt: BidRule = BidRule::new(rule.kind(), rule.value(), &rule.dates()).unwrap();
+        assert_eq!(rebuilt, rule);
+    }
+}
+
+#[test]
+fn test_rule_rejects_unknown_kind_and_missing_parameters() {
+    for (kind, value, dates) in [
+        ("max_hours", Some(40), Vec::new()),
+        (BidRule::MIN_CONSECUTIVE_DAYS, None, Vec::new()),
+        (BidRule::MAX_SPLITS_PER_ROUND, Some(0), Vec::new()),
+        (BidRule::HOLIDAY_PAIRING, None, Vec::<Date>::new()),
+    ] {
+        assert!(matches!(
+            BidRule::new(kind, value, &dates),
+            Err(DomainError::InvalidBidRule { .. })
+        ));
+    }
+}
+
+#[test]
+fn test_min_consecutive_days_counts_held_leave() {
+    let rules: Vec<BidRule> = vec![BidRule::MinConsecutiveDays { days: 3 }];
+    let held: BTreeSet<Date> = BTreeSet::from([date!(2026 - 06 - 01), date!(2026 - 06 - 02)]);
+
+    // Extending a held run to three days passes
+    assert!(evaluate_rules(&rules, &held, &[date!(2026 - 06 - 03)]).is_empty());
+
+    // A lone day elsewhere does not
+    let violations: Vec<RuleViolation> = evaluate_rules(&rules, &held, &[date!(2026 - 06 - 10)]);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].rule, BidRule::MIN_CONSECUTIVE_DAYS);
+    assert_eq!(violations[0].dates, vec![date!(2026 - 06 - 10)]);
+}
+
+#[test]
+fn test_max_splits_and_holiday_pairing_report_every_violation() {
+    let rules: Vec<BidRule> = vec![
+        BidRule::MaxSplitsPerRound { splits: 1 },
+        BidRule::HolidayPairing {
+            holidays: BTreeSet::from([date!(2026 - 07 - 03)]),
+        },
+    ];
+    let held: BTreeSet<Date> = BTreeSet::from([date!(2026 - 06 - 01)]);
+
+    let violations: Vec<RuleViolation> = evaluate_rules(&rules, &held, &[date!(2026 - 07 - 03)]);
+    assert_eq!(
+        violations
+            .iter()
+            .map(|violation| violation.rule)
+            .collect::<Vec<&str>>(),
+        vec![BidRule::MAX_SPLITS_PER_ROUND, BidRule::HOLIDAY_PAIRING]
+    );
+
+    // Pairing the holiday with the next day satisfies the pairing rule only
+    let violations: Vec<RuleViolation> = evaluate_rules(
+        &rules,
+        &held,
+        &[date!(2026 - 07 - 03), date!(2026 - 07 - 04)],
+    );
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].rule, BidRule::MAX_SPLITS_PER_ROUND);
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Property-based tests for core transitions.
+//!
+//! Each property runs against randomly generated command sequences that are
+//! valid for their starting state. They check the invariants every
+//! transition must preserve, whatever the order of commands.
+
+#![allow(clippy::unwrap_used)]
+
+use std::collections::HashSet;
+
+use proptest::prelude::*;
+use zab_bid::{
+    BatchTransitionResult, Command, CoreError, State, TransitionResult, apply, apply_batch,
+};
+use zab_bid_audit::{Actor, Cause};
+use zab_bid_domain::{BidYear, DomainError};
+use zab_bid_test_support::strategies::{self, BID_YEAR, Scenario};
+
+fn actor() -> Actor {
+    Actor::new(String::from("admin-123"), String::from("admin"))
+}
+
+fn cause() -> Cause {
+    Cause::new(String::from("proptest"), String::from("Generated sequence"))
+}
+
+/// Applies every command in order, failing on the first rejection.
+fn run(scenario: &Scenario) -> Vec<TransitionResult> {
+    let mut state: State = scenario.initial_state.clone();
+    let mut results: Vec<TransitionResult> = Vec::with_capacity(scenario.commands.len());
+    for command in &scenario.commands {
+        let result: TransitionResult = apply(
+            &strategies::metadata(),
+            &state,
+            &BidYear::new(BID_YEAR),
+            command.clone(),
+            actor(),
+            cause(),
+        )
+        .unwrap();
+        state = result.new_state.clone();
+        results.push(result);
+    }
+    results
+}
+
+/// Returns the audit action name a successful command must record.
+fn action_name(command: &Command) -> &'static str {
+    match command {
+        Command::RegisterUser { .. } => "RegisterUser",
+        Command::UpdateUser { .. } => "UpdateUser",
+        Command::UpdateUserParticipation { .. } => "UpdateUserParticipation",
+        Command::Checkpoint { .. } => "Checkpoint",
+        Command::Finalize => "Finalize",
+        Command::RollbackToEventId { .. } => "Rollback",
+        _ => unreachable!("scenarios only generate user and milestone commands"),
+    }
+}
+
+proptest! {
+    #[test]
+    fn prop_replay_reproduces_final_state(scenario in strategies::scenario(24)) {
+        let results: Vec<TransitionResult> = run(&scenario);
+        let final_state: State = results
+            .last()
+            .map_or_else(|| scenario.initial_state.clone(), |r| r.new_state.clone());
+
+        let replay: BatchTransitionResult = apply_batch(
+            &strategies::metadata(),
+            &scenario.initial_state,
+            &BidYear::new(BID_YEAR),
+            scenario.commands.clone(),
+            &actor(),
+            &cause(),
+        );
+        prop_assert_eq!(&replay.final_state, &final_state);
+        for (outcome, result) in replay.outcomes.iter().zip(&results) {
+            prop_assert_eq!(outcome.as_ref().unwrap(), result);
+        }
+    }
+
+    #[test]
+    fn prop_audit_trail_is_continuous(scenario in strategies::scenario(24)) {
+        let results: Vec<TransitionResult> = run(&scenario);
+
+        let mut previous: State = scenario.initial_state.clone();
+        for result in &results {
+            prop_assert_eq!(&result.audit_event.before, &previous.to_snapshot());
+            prop_assert_eq!(&result.audit_event.after, &result.new_state.to_snapshot());
+            previous = result.new_state.clone();
+        }
+    }
+
+    #[test]
+    fn prop_initials_stay_unique(scenario in strategies::scenario(24)) {
+        for result in run(&scenario) {
+            let mut seen: HashSet<&str> = HashSet::new();
+            for user in result.new_state.users.values() {
+                prop_assert!(
+                    seen.insert(user.initials.value()),
+                    "duplicate initials {}",
+                    user.initials.value()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn prop_every_success_is_audited(scenario in strategies::scenario(24)) {
+        let results: Vec<TransitionResult> = run(&scenario);
+        prop_assert_eq!(results.len(), scenario.commands.len());
+
+        for (command, result) in scenario.commands.iter().zip(&results) {
+            let event = &result.audit_event;
+            prop_assert_eq!(event.action.name.as_str(), action_name(command));
+            prop_assert_eq!(event.event_id, None);
+            prop_assert_eq!(event.bid_year.as_ref(), Some(&result.new_state.bid_year));
+            prop_assert_eq!(event.area.as_ref(), Some(&result.new_state.area));
+        }
+    }
+
+    #[test]
+    fn prop_duplicate_registration_is_rejected(
+        scenario in strategies::scenario(24),
+        fields in strategies::user_fields(),
+    ) {
+        let results: Vec<TransitionResult> = run(&scenario);
+        let state: State = results
+            .last()
+            .map_or_else(|| scenario.initial_state.clone(), |r| r.new_state.clone());
+        prop_assume!(!state.users.is_empty());
+
+        let outcome: Result<TransitionResult, CoreError> = apply(
+            &strategies::metadata(),
+            &state,
+            &BidYear::new(BID_YEAR),
+            Command::RegisterUser {
+                initials: state.users.keys().next().unwrap().clone(),
+                name: fields.name,
+                area: state.area.clone(),
+                user_type: fields.user_type,
+                crew: fields.crew,
+                seniority_data: fields.seniority_data,
+            },
+            actor(),
+            cause(),
+        );
+        let rejected: bool = matches!(
+            outcome,
+            Err(CoreError::DomainViolation(DomainError::DuplicateInitials { .. }))
+        );
+        prop_assert!(rejected, "expected duplicate initials rejection, got {:?}", outcome);
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Bid amendment policy.
+//!
+//! A controller amends a submitted bid by entering further leave in a
+//! round where they already hold leave. Each facility decides whether
+//! amendments are allowed and for how long after the first submission.
+
+use crate::error::DomainError;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{Duration, OffsetDateTime};
+
+/// How long a controller may amend a submitted bid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BidAmendmentPolicy {
+    /// Submitted bids may be amended at any time.
+    #[default]
+    Unrestricted,
+    /// Submitted bids may not be amended.
+    NotAllowed,
+    /// Submitted bids may be amended until the controller's bid window
+    /// closes.
+    UntilWindowCloses,
+    /// Submitted bids may be amended for this many hours after the first
+    /// submission.
+    WithinHours(u32),
+}
+
+impl BidAmendmentPolicy {
+    /// Returns the string representation of the policy kind.
+    ///
+    /// This is used for persistence and API serialization.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unrestricted => "unrestricted",
+            Self::NotAllowed => "not_allowed",
+            Self::UntilWindowCloses => "until_window_closes",
+            Self::WithinHours(_) => "within_hours",
+        }
+    }
+
+    /// Returns the amendment window in hours for `WithinHours` policies.
+    #[must_use]
+    pub const fn window_hours(&self) -> Option<u32> {
+        match self {
+            Self::WithinHours(hours) => Some(*hours),
+            _ => None,
+        }
+    }
+
+    /// Builds a policy from its stored kind and window length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the kind is unknown, or if the window length is
+    /// missing, zero, or given for a kind that does not take one.
+    pub fn from_parts(policy: &str, window_hours: Option<u32>) -> Result<Self, DomainError> {
+        let invalid = |reason: &str| DomainError::InvalidBidAmendmentPolicy {
+            reason: reason.to_string(),
+        };
+        match (policy, window_hours) {
+            ("within_hours", Some(0) | None) => Err(invalid(
+                "A within_hours policy requires a window of at least one hour",
+            )),
+            ("within_hours", Some(hours)) => Ok(Self::WithinHours(hours)),
+            (_, Some(_)) => Err(invalid(
+                "A window length is only allowed for a within_hours policy",
+            )),
+            ("unrestricted", None) => Ok(Self::Unrestricted),
+            ("not_allowed", None) => Ok(Self::NotAllowed),
+            ("until_window_closes", None) => Ok(Self::UntilWindowCloses),
+            (unknown, None) => Err(invalid(&format!("Unknown amendment policy '{unknown}'"))),
+        }
+    }
+}
+
+/// An attempt to amend a bid the controller has already submitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BidAmendment {
+    /// The facility's amendment policy for the bid year.
+    pub policy: BidAmendmentPolicy,
+    /// When the controller's first leave in the round was entered.
+    pub first_submitted_at: OffsetDateTime,
+    /// When the controller's bid window in the round closes, if scheduled.
+    pub window_closes_at: Option<OffsetDateTime>,
+    /// When the amendment is being entered.
+    pub amended_at: OffsetDateTime,
+}
+
+impl BidAmendment {
+    /// Returns the instant after which the bid may no longer be amended.
+    ///
+    /// `None` means the policy sets no deadline, including an
+    /// `UntilWindowCloses` policy for a controller with no scheduled
+    /// window.
+    #[must_use]
+    pub fn deadline(&self) -> Option<OffsetDateTime> {
+        match self.policy {
+            BidAmendmentPolicy::Unrestricted => None,
+            BidAmendmentPolicy::NotAllowed => Some(self.first_submitted_at),
+            BidAmendmentPolicy::UntilWindowCloses => self.window_closes_at,
+            BidAmendmentPolicy::WithinHours(hours) => {
+                Some(self.first_submitted_at + Duration::hours(i64::from(hours)))
+            }
+        }
+    }
+
+    /// Checks that the amendment falls within the policy's window.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_id` - The round the bid is amended in
+    ///
+    /// # Errors
+    ///
+    /// Returns `AmendmentWindowPassed` if amendments are not allowed or the
+    /// deadline has passed.
+    pub fn check(&self, round_id: i64) -> Result<(), DomainError> {
+        match self.deadline() {
+            Some(deadline)
+                if self.policy == BidAmendmentPolicy::NotAllowed || self.amended_at > deadline =>
+            {
+                Err(DomainError::AmendmentWindowPassed {
+                    round_id,
+                    policy: self.policy.as_str().to_string(),
+                    deadline: deadline
+                        .format(&Rfc3339)
+                        .unwrap_or_else(|_| deadline.to_string()),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    fn amendment(policy: BidAmendmentPolicy, amended_at: OffsetDateTime) -> BidAmendment {
+        BidAmendment {
+            policy,
+            first_submitted_at: datetime!(2026-03-02 14:00 UTC),
+            window_closes_at: Some(datetime!(2026-03-02 22:00 UTC)),
+            amended_at,
+        }
+    }
+
+    #[test]
+    fn test_policy_parts_round_trip() {
+        for policy in [
+            BidAmendmentPolicy::Unrestricted,
+            BidAmendmentPolicy::NotAllowed,
+            BidAmendmentPolicy::UntilWindowCloses,
+            BidAmendmentPolicy::WithinHours(24),
+        ] {
+            assert_eq!(
+                BidAmendmentPolicy::from_parts(policy.as_str(), policy.window_hours()),
+                Ok(policy)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_policy_parts() {
+        for (policy, hours) in [
+            ("within_hours", None),
+            ("within_hours", Some(0)),
+            ("not_allowed", Some(4)),
+            ("forever", None),
+        ] {
+            assert!(matches!(
+                BidAmendmentPolicy::from_parts(policy, hours),
+                Err(DomainError::InvalidBidAmendmentPolicy { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_amendment_deadlines() {
+        let evening: OffsetDateTime = datetime!(2026-03-02 21:00 UTC);
+        let next_day: OffsetDateTime = datetime!(2026-03-03 13:00 UTC);
+
+        assert!(
+            amendment(BidAmendmentPolicy::Unrestricted, next_day)
+                .check(1)
+                .is_ok()
+        );
+        assert!(
+            amendment(BidAmendmentPolicy::NotAllowed, evening)
+                .check(1)
+                .is_err()
+        );
+        assert!(
+            amendment(BidAmendmentPolicy::UntilWindowCloses, evening)
+                .check(1)
+                .is_ok()
+        );
+        assert!(matches!(
+            amendment(BidAmendmentPolicy::UntilWindowCloses, next_day).check(1),
+            Err(DomainError::AmendmentWindowPassed { round_id: 1, .. })
+        ));
+        assert!(
+            amendment(BidAmendmentPolicy::WithinHours(24), next_day)
+                .check(1)
+                .is_ok()
+        );
+        assert!(
+            amendment(BidAmendmentPolicy::WithinHours(4), evening)
+                .check(1)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_until_window_closes_without_window_has_no_deadline() {
+        let unscheduled: BidAmendment = BidAmendment {
+            window_closes_at: None,
+            ..amendment(
+                BidAmendmentPolicy::UntilWindowCloses,
+                datetime!(2026-03-09 13:00 UTC),
+            )
+        };
+        assert_eq!(unscheduled.deadline(), None);
+        assert!(unscheduled.check(1).is_ok());
+    }
+}
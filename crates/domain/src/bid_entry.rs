@@ -0,0 +1,78 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Bid entry attribution.
+//!
+//! Bids are entered by operators on behalf of controllers. Every entry
+//! records whose bid it is and how the bid reached the operator.
+
+use crate::error::DomainError;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// How a proxied bid was received from the controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BidReceiptMethod {
+    /// The controller phoned the bid in.
+    Phone,
+    /// The controller gave the bid in person.
+    InPerson,
+    /// The controller submitted the bid in writing.
+    Written,
+}
+
+impl BidReceiptMethod {
+    /// Returns the string representation of the method.
+    ///
+    /// This is used for persistence and API serialization.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Phone => "phone",
+            Self::InPerson => "in_person",
+            Self::Written => "written",
+        }
+    }
+}
+
+impl FromStr for BidReceiptMethod {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "phone" => Ok(Self::Phone),
+            "in_person" => Ok(Self::InPerson),
+            "written" => Ok(Self::Written),
+            _ => Err(DomainError::InvalidBidReceiptMethod {
+                method: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_receipt_method_string_round_trip() {
+        for method in [
+            BidReceiptMethod::Phone,
+            BidReceiptMethod::InPerson,
+            BidReceiptMethod::Written,
+        ] {
+            assert_eq!(method.as_str().parse::<BidReceiptMethod>(), Ok(method));
+        }
+    }
+
+    #[test]
+    fn test_invalid_receipt_method() {
+        assert!(matches!(
+            "email".parse::<BidReceiptMethod>(),
+            Err(DomainError::InvalidBidReceiptMethod { .. })
+        ));
+    }
+}
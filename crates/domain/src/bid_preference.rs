@@ -0,0 +1,200 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Pre-submitted bid preferences.
+//!
+//! A controller may hand in a ranked list of leave choices before their
+//! bid window opens. When the window opens, the highest-ranked choice that
+//! still fits is converted into a leave bid. A choice fits when every one
+//! of its days has a slot remaining in the round's slot inventory and the
+//! controller does not already hold leave on it; choices that do not fit
+//! are passed over in rank order.
+
+use std::collections::BTreeSet;
+
+use time::Date;
+
+use crate::error::DomainError;
+
+/// One ranked leave choice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidPreference {
+    /// The rank, 1 being the most preferred.
+    rank: u32,
+    /// The leave days, sorted ascending.
+    leave_dates: Vec<Date>,
+    /// The leave hours charged per day.
+    hours: u32,
+}
+
+impl BidPreference {
+    /// Creates a validated preference.
+    ///
+    /// # Arguments
+    ///
+    /// * `rank` - The rank, starting at 1
+    /// * `leave_dates` - The leave days, in any order
+    /// * `hours` - The leave hours charged per day
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rank is 0, no days are given, a day is
+    /// listed twice, or the hours are 0.
+    pub fn new(rank: u32, mut leave_dates: Vec<Date>, hours: u32) -> Result<Self, DomainError> {
+        if rank == 0 {
+            return Err(DomainError::InvalidBidPreference {
+                reason: String::from("Preference ranks start at 1"),
+            });
+        }
+        if leave_dates.is_empty() {
+            return Err(DomainError::InvalidBidPreference {
+                reason: format!("Preference {rank} has no leave dates"),
+            });
+        }
+        if hours == 0 {
+            return Err(DomainError::InvalidBidPreference {
+                reason: format!("Preference {rank} must charge more than zero hours"),
+            });
+        }
+        leave_dates.sort_unstable();
+        if let Some(&[date, _]) = leave_dates
+            .windows(2)
+            .find(|pair| pair.first() == pair.last())
+        {
+            return Err(DomainError::InvalidBidPreference {
+                reason: format!("Preference {rank} lists {date} more than once"),
+            });
+        }
+
+        Ok(Self {
+            rank,
+            leave_dates,
+            hours,
+        })
+    }
+
+    /// Returns the rank, 1 being the most preferred.
+    #[must_use]
+    pub const fn rank(&self) -> u32 {
+        self.rank
+    }
+
+    /// Returns the leave days, sorted ascending.
+    #[must_use]
+    pub fn leave_dates(&self) -> &[Date] {
+        &self.leave_dates
+    }
+
+    /// Returns the leave hours charged per day.
+    #[must_use]
+    pub const fn hours(&self) -> u32 {
+        self.hours
+    }
+
+    /// Returns whether every day has a free slot and is not already held.
+    ///
+    /// # Arguments
+    ///
+    /// * `remaining` - The slots remaining on a day
+    /// * `held` - The days the controller already holds leave on
+    #[must_use]
+    pub fn fits(&self, remaining: impl Fn(Date) -> u32, held: &BTreeSet<Date>) -> bool {
+        self.leave_dates
+            .iter()
+            .all(|date| !held.contains(date) && remaining(*date) > 0)
+    }
+}
+
+/// Selects the highest-ranked preference that fits.
+///
+/// # Arguments
+///
+/// * `preferences` - The controller's preferences, in any order
+/// * `remaining` - The slots remaining on a day
+/// * `held` - The days the controller already holds leave on
+///
+/// # Returns
+///
+/// The preference to convert into a bid, or `None` if none fits.
+#[must_use]
+pub fn select_bid_preference<'a>(
+    preferences: &'a [BidPreference],
+    remaining: impl Fn(Date) -> u32,
+    held: &BTreeSet<Date>,
+) -> Option<&'a BidPreference> {
+    preferences
+        .iter()
+        .filter(|preference| preference.fits(&remaining, held))
+        .min_by_key(|preference| preference.rank)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use time::macros::date;
+
+    fn preference(rank: u32, leave_dates: &[Date]) -> BidPreference {
+        BidPreference::new(rank, leave_dates.to_vec(), 8).unwrap()
+    }
+
+    #[test]
+    fn test_new_sorts_dates_and_rejects_duplicates() {
+        let sorted: BidPreference = preference(1, &[date!(2026 - 06 - 02), date!(2026 - 06 - 01)]);
+        assert_eq!(
+            sorted.leave_dates(),
+            &[date!(2026 - 06 - 01), date!(2026 - 06 - 02)]
+        );
+
+        for (rank, dates, hours) in [
+            (0, vec![date!(2026 - 06 - 01)], 8),
+            (1, vec![], 8),
+            (1, vec![date!(2026 - 06 - 01)], 0),
+            (1, vec![date!(2026 - 06 - 01), date!(2026 - 06 - 01)], 8),
+        ] {
+            assert!(matches!(
+                BidPreference::new(rank, dates, hours),
+                Err(DomainError::InvalidBidPreference { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_select_falls_through_full_days() {
+        let preferences: Vec<BidPreference> = vec![
+            preference(3, &[date!(2026 - 08 - 01)]),
+            preference(1, &[date!(2026 - 06 - 01), date!(2026 - 06 - 02)]),
+            preference(2, &[date!(2026 - 07 - 01)]),
+        ];
+        let held: BTreeSet<Date> = BTreeSet::new();
+        let remaining = |taken: &BTreeMap<Date, u32>, date: Date| {
+            2_u32.saturating_sub(taken.get(&date).copied().unwrap_or(0))
+        };
+
+        let open: BTreeMap<Date, u32> = BTreeMap::from([(date!(2026 - 06 - 02), 1)]);
+        assert_eq!(
+            select_bid_preference(&preferences, |date| remaining(&open, date), &held)
+                .map(BidPreference::rank),
+            Some(1)
+        );
+
+        let june_full: BTreeMap<Date, u32> = BTreeMap::from([(date!(2026 - 06 - 02), 2)]);
+        assert_eq!(
+            select_bid_preference(&preferences, |date| remaining(&june_full, date), &held)
+                .map(BidPreference::rank),
+            Some(2)
+        );
+
+        let july_held: BTreeSet<Date> = BTreeSet::from([date!(2026 - 07 - 01)]);
+        assert_eq!(
+            select_bid_preference(&preferences, |date| remaining(&june_full, date), &july_held)
+                .map(BidPreference::rank),
+            Some(3)
+        );
+
+        assert_eq!(select_bid_preference(&preferences, |_| 0, &held), None);
+    }
+}
@@ -6,8 +6,9 @@
 //! Bid status tracking and transition logic.
 //!
 //! This module defines bid status states and valid transitions.
-//! Status transitions are operator-initiated only; the system never
-//! advances status based on time alone.
+//! Status transitions are operator-initiated, except that the window
+//! expiry scheduler may mark a bidder who never started as missed once
+//! their window elapses.
 
 use crate::error::DomainError;
 use serde::{Deserialize, Serialize};
@@ -17,8 +17,10 @@
 //! - Bid windows are calculated only after confirmation
 //! - Windows are stored as UTC timestamps (ISO 8601)
 //! - Bidding occurs Monday-Friday only (weekends are skipped)
+//! - Blackout dates are skipped like weekends, shifting later bidders
 //! - All times are wall-clock times in the declared timezone
-//! - DST transitions do not make users early or late (nominal labels are stable)
+//! - DST transitions do not make users early or late (nominal labels are stable);
+//!   see the `schedule` module for how skipped and repeated times resolve
 //!
 //! ## Usage
 //!
@@ -27,12 +29,16 @@
 //! - Post-confirmation adjustments (to recalculate windows)
 
 use crate::error::DomainError;
+use crate::schedule::{
+    UtcRange, local_window_to_utc, parse_timezone, to_naive_date, to_naive_time,
+};
 use crate::types::BidSchedule;
-use chrono::{Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Weekday};
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Weekday};
 use chrono_tz::Tz;
+use std::collections::BTreeSet;
 
 /// Parameters for calculating a single bid window.
-struct WindowCalculationParams {
+struct WindowCalculationParams<'a> {
     user_id: i64,
     round_id: i64,
     position: usize,
@@ -41,6 +47,7 @@ struct WindowCalculationParams {
     window_end_time: NaiveTime,
     bidders_per_day: u32,
     tz: Tz,
+    blackout_dates: &'a BTreeSet<NaiveDate>,
 }
 
 /// Represents a calculated bid window for a user in a specific round.
@@ -106,43 +113,44 @@ pub fn calculate_bid_windows(
     round_ids: &[i64],
     schedule: &BidSchedule,
 ) -> Result<Vec<BidWindow>, DomainError> {
-    // Parse timezone
-    let tz: Tz = schedule
-        .timezone()
-        .parse()
-        .map_err(|_| DomainError::InvalidTimezone(schedule.timezone().to_string()))?;
-
-    // Convert time::Date to chrono::NaiveDate
-    let start_date = NaiveDate::from_ymd_opt(
-        schedule.start_date().year(),
-        schedule.start_date().month() as u32,
-        u32::from(schedule.start_date().day()),
-    )
-    .ok_or_else(|| DomainError::InvalidBidSchedule {
-        reason: format!("Invalid bid start date: {}", schedule.start_date()),
-    })?;
-
-    // Convert time::Time to chrono::NaiveTime
-    let window_start_time = NaiveTime::from_hms_opt(
-        u32::from(schedule.window_start_time().hour()),
-        u32::from(schedule.window_start_time().minute()),
-        u32::from(schedule.window_start_time().second()),
-    )
-    .ok_or_else(|| DomainError::InvalidBidSchedule {
-        reason: format!(
-            "Invalid window start time: {}",
-            schedule.window_start_time()
-        ),
-    })?;
-
-    let window_end_time = NaiveTime::from_hms_opt(
-        u32::from(schedule.window_end_time().hour()),
-        u32::from(schedule.window_end_time().minute()),
-        u32::from(schedule.window_end_time().second()),
-    )
-    .ok_or_else(|| DomainError::InvalidBidSchedule {
-        reason: format!("Invalid window end time: {}", schedule.window_end_time()),
-    })?;
+    calculate_bid_windows_with_blackouts(user_positions, round_ids, schedule, &[])
+}
+
+/// Calculates bid windows, skipping blackout dates.
+///
+/// Behaves like [`calculate_bid_windows`], except that no windows are laid
+/// out on a blackout date (training days, facility events). Bidders who
+/// would have bid on a blackout date, and every bidder after them, shift to
+/// the next bidding day. A blackout on the bid start date moves the first
+/// bidding day forward.
+///
+/// # Arguments
+///
+/// * `user_positions` - User IDs and their 1-based bid order positions
+/// * `round_ids` - Round IDs to generate windows for
+/// * `schedule` - Bid schedule parameters (from `BidYear`)
+/// * `blackout_dates` - Dates on which no bidding occurs
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Timezone is invalid
+/// - Date/time conversion fails
+pub fn calculate_bid_windows_with_blackouts(
+    user_positions: &[(i64, usize)],
+    round_ids: &[i64],
+    schedule: &BidSchedule,
+    blackout_dates: &[time::Date],
+) -> Result<Vec<BidWindow>, DomainError> {
+    let tz: Tz = parse_timezone(schedule.timezone())?;
+    let start_date: NaiveDate = to_naive_date(schedule.start_date())?;
+    let window_start_time: NaiveTime = to_naive_time(schedule.window_start_time())?;
+    let window_end_time: NaiveTime = to_naive_time(schedule.window_end_time())?;
+
+    let blackout_dates: BTreeSet<NaiveDate> = blackout_dates
+        .iter()
+        .map(|date| to_naive_date(*date))
+        .collect::<Result<BTreeSet<NaiveDate>, DomainError>>()?;
 
     // Calculate windows for each (user, round) combination
     let mut windows = Vec::new();
@@ -158,6 +166,7 @@ pub fn calculate_bid_windows(
                 window_end_time,
                 bidders_per_day: schedule.bidders_per_day(),
                 tz,
+                blackout_dates: &blackout_dates,
             };
             let window = calculate_window_for_position(&params)?;
             windows.push(window);
@@ -175,42 +184,23 @@ fn calculate_window_for_position(
     let day_offset = calculate_weekday_offset(params.position, params.bidders_per_day);
 
     // Calculate the actual calendar date
-    let bid_date = add_weekdays(params.start_date, day_offset);
-
-    // Construct wall-clock datetime in declared timezone
-    let naive_start = bid_date.and_time(params.window_start_time);
-    let naive_end = bid_date.and_time(params.window_end_time);
-
-    let start_dt = params.tz
-        .from_local_datetime(&naive_start)
-        .single()
-        .ok_or_else(|| DomainError::InvalidBidSchedule {
-            reason: format!(
-                "Could not resolve timezone for date {bid_date} at time {} (ambiguous or non-existent due to DST)",
-                params.window_start_time
-            ),
-        })?;
-
-    let end_dt = params.tz
-        .from_local_datetime(&naive_end)
-        .single()
-        .ok_or_else(|| DomainError::InvalidBidSchedule {
-            reason: format!(
-                "Could not resolve timezone for date {bid_date} at time {} (ambiguous or non-existent due to DST)",
-                params.window_end_time
-            ),
-        })?;
-
-    // Convert to UTC and format as ISO 8601
-    let start_utc = start_dt.with_timezone(&chrono::Utc).to_rfc3339();
-    let end_utc = end_dt.with_timezone(&chrono::Utc).to_rfc3339();
+    let first_bid_date = next_bidding_day(params.start_date, params.blackout_dates);
+    let bid_date = add_weekdays(first_bid_date, day_offset, params.blackout_dates);
+
+    // Resolve the wall-clock window in the declared timezone to UTC
+    let range: UtcRange = local_window_to_utc(
+        params.tz,
+        bid_date,
+        params.window_start_time,
+        params.window_end_time,
+    )?;
 
     Ok(BidWindow {
         user_id: params.user_id,
         round_id: params.round_id,
         position: params.position,
-        window_start_datetime: start_utc,
-        window_end_datetime: end_utc,
+        window_start_datetime: range.start.to_rfc3339(),
+        window_end_datetime: range.end.to_rfc3339(),
     })
 }
 
@@ -229,16 +219,37 @@ const fn calculate_weekday_offset(position: usize, bidders_per_day: u32) -> i64
     days as i64
 }
 
-/// Adds a number of weekdays (Mon-Fri) to a date, skipping weekends.
-fn add_weekdays(start: NaiveDate, weekdays: i64) -> NaiveDate {
+/// Returns whether bidding takes place on a date.
+///
+/// Weekends and blackout dates are not bidding days.
+fn is_bidding_day(date: NaiveDate, blackout_dates: &BTreeSet<NaiveDate>) -> bool {
+    date.weekday() != Weekday::Sat
+        && date.weekday() != Weekday::Sun
+        && !blackout_dates.contains(&date)
+}
+
+/// Returns the first bidding day on or after a date.
+fn next_bidding_day(date: NaiveDate, blackout_dates: &BTreeSet<NaiveDate>) -> NaiveDate {
+    let mut current = date;
+    while !is_bidding_day(current, blackout_dates) {
+        current += Duration::days(1);
+    }
+    current
+}
+
+/// Adds a number of bidding days to a date, skipping weekends and blackout dates.
+fn add_weekdays(
+    start: NaiveDate,
+    weekdays: i64,
+    blackout_dates: &BTreeSet<NaiveDate>,
+) -> NaiveDate {
     let mut current = start;
     let mut remaining = weekdays;
 
     while remaining > 0 {
         current += Duration::days(1);
 
-        // Skip weekends
-        if current.weekday() != Weekday::Sat && current.weekday() != Weekday::Sun {
+        if is_bidding_day(current, blackout_dates) {
             remaining -= 1;
         }
     }
@@ -254,21 +265,21 @@ mod tests {
     #[test]
     fn test_add_weekdays_no_offset() {
         let start = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(); // Monday
-        let result = add_weekdays(start, 0);
+        let result = add_weekdays(start, 0, &BTreeSet::new());
         assert_eq!(result, start);
     }
 
     #[test]
     fn test_add_weekdays_within_week() {
         let start = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(); // Monday
-        let result = add_weekdays(start, 2);
+        let result = add_weekdays(start, 2, &BTreeSet::new());
         assert_eq!(result, NaiveDate::from_ymd_opt(2026, 3, 4).unwrap()); // Wednesday
     }
 
     #[test]
     fn test_add_weekdays_skip_weekend() {
         let start = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(); // Monday
-        let result = add_weekdays(start, 5);
+        let result = add_weekdays(start, 5, &BTreeSet::new());
         assert_eq!(result, NaiveDate::from_ymd_opt(2026, 3, 9).unwrap()); // Next Monday
     }
 
@@ -445,4 +456,79 @@ mod tests {
                 .contains("2026-03-03")
         );
     }
+
+    fn standard_schedule() -> BidSchedule {
+        BidSchedule::new(
+            String::from("America/New_York"),
+            time::Date::from_calendar_date(2026, time::Month::March, 2).unwrap(),
+            time::Time::from_hms(8, 0, 0).unwrap(),
+            time::Time::from_hms(18, 0, 0).unwrap(),
+            5,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_add_weekdays_skips_blackout_dates() {
+        let start = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(); // Monday
+        let blackouts: BTreeSet<NaiveDate> =
+            BTreeSet::from([NaiveDate::from_ymd_opt(2026, 3, 3).unwrap()]);
+        let result = add_weekdays(start, 1, &blackouts);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2026, 3, 4).unwrap()); // Wednesday
+    }
+
+    #[test]
+    fn test_blackout_date_shifts_subsequent_bidders() {
+        let user_positions = vec![
+            (1001, 5),  // Monday
+            (1002, 6),  // Tuesday is blacked out, so Wednesday
+            (1003, 21), // Friday becomes next Monday
+        ];
+        let blackouts = [time::Date::from_calendar_date(2026, time::Month::March, 3).unwrap()];
+
+        let windows = calculate_bid_windows_with_blackouts(
+            &user_positions,
+            &[1],
+            &standard_schedule(),
+            &blackouts,
+        )
+        .unwrap();
+
+        assert!(windows[0].window_start_datetime.contains("2026-03-02"));
+        assert!(windows[1].window_start_datetime.contains("2026-03-04"));
+        assert!(windows[2].window_start_datetime.contains("2026-03-09"));
+    }
+
+    #[test]
+    fn test_blackout_on_start_date_moves_first_bidding_day() {
+        let blackouts = [time::Date::from_calendar_date(2026, time::Month::March, 2).unwrap()];
+
+        let windows = calculate_bid_windows_with_blackouts(
+            &[(1001, 1), (1002, 6)],
+            &[1],
+            &standard_schedule(),
+            &blackouts,
+        )
+        .unwrap();
+
+        assert!(windows[0].window_start_datetime.contains("2026-03-03"));
+        assert!(windows[1].window_start_datetime.contains("2026-03-04"));
+    }
+
+    #[test]
+    fn test_weekend_blackout_has_no_effect() {
+        let blackouts = [time::Date::from_calendar_date(2026, time::Month::March, 7).unwrap()];
+
+        let with_blackout = calculate_bid_windows_with_blackouts(
+            &[(1001, 26)],
+            &[1],
+            &standard_schedule(),
+            &blackouts,
+        )
+        .unwrap();
+        let without_blackout =
+            calculate_bid_windows(&[(1001, 26)], &[1], &standard_schedule()).unwrap();
+
+        assert_eq!(with_blackout, without_blackout);
+    }
 }
@@ -134,6 +134,13 @@ pub enum DomainError {
         /// The currently active bid year.
         active_year: u16,
     },
+    /// Bidding cannot be activated while readiness criteria are unsatisfied.
+    BiddingNotReady {
+        /// The bid year.
+        year: u16,
+        /// Every unsatisfied readiness criterion.
+        blocking_reasons: Vec<String>,
+    },
     /// Operation not allowed in current lifecycle state.
     OperationNotAllowedInState {
         /// The operation that was attempted.
@@ -337,6 +344,149 @@ pub enum DomainError {
         /// Description of why the transition is invalid.
         reason: String,
     },
+    /// Invalid advancement of an area's current bidder.
+    InvalidBidderAdvance {
+        /// Description of why the advancement is invalid.
+        reason: String,
+    },
+    /// Invalid bid receipt method string.
+    InvalidBidReceiptMethod {
+        /// The invalid method string.
+        method: String,
+    },
+    /// Invalid leave bid entry.
+    InvalidLeaveBid {
+        /// Description of why the leave bid is invalid.
+        reason: String,
+    },
+    /// Invalid bid preference.
+    InvalidBidPreference {
+        /// Description of why the preference is invalid.
+        reason: String,
+    },
+    /// Invalid overbid request or decision.
+    InvalidOverbid {
+        /// Description of why the overbid is invalid.
+        reason: String,
+    },
+    /// Invalid self-service bid request or confirmation.
+    InvalidSelfBid {
+        /// Description of why the self-service bid is invalid.
+        reason: String,
+    },
+    /// Invalid bid validation rule configuration.
+    InvalidBidRule {
+        /// Description of why the rule is invalid.
+        reason: String,
+    },
+    /// Invalid eligibility exception configuration.
+    InvalidEligibilityException {
+        /// Description of why the exception is invalid.
+        reason: String,
+    },
+    /// A bid breaks one of the bid year's validation rules.
+    BidRuleViolated {
+        /// The kind of rule that was broken.
+        rule: String,
+        /// Description of how the bid breaks the rule.
+        reason: String,
+    },
+    /// A round cannot be signed off while eligible users are outstanding.
+    RoundNotComplete {
+        /// The round being signed off.
+        round_id: i64,
+        /// Initials of the users who have not bid, been skipped, or waived.
+        outstanding: Vec<String>,
+    },
+    /// A signed-off round's bids cannot change without an override.
+    RoundSignedOff {
+        /// The signed-off round.
+        round_id: i64,
+    },
+    /// Invalid bid amendment policy configuration.
+    InvalidBidAmendmentPolicy {
+        /// Description of why the policy is invalid.
+        reason: String,
+    },
+    /// A submitted bid can no longer be amended under the facility's policy.
+    AmendmentWindowPassed {
+        /// The round the bid was submitted in.
+        round_id: i64,
+        /// The amendment policy in force.
+        policy: String,
+        /// When the amendment window closed (RFC 3339).
+        deadline: String,
+    },
+    /// Invalid waitlist offer.
+    InvalidWaitlistOffer {
+        /// Description of why the offer is invalid.
+        reason: String,
+    },
+    /// A waitlist offer lapsed before it was answered.
+    WaitlistOfferExpired {
+        /// The lapsed offer.
+        waitlist_offer_id: i64,
+        /// When the offer expired (RFC 3339).
+        expires_at: String,
+    },
+    /// Invalid annual leave carryover cap.
+    InvalidLeaveCap {
+        /// Description of why the cap is invalid.
+        reason: String,
+    },
+    /// Feature flag name is not recognized.
+    UnknownFeatureFlag {
+        /// The unrecognized flag name.
+        flag: String,
+    },
+    /// Reopen justification is invalid (empty or too short).
+    InvalidReopenJustification {
+        /// The justification provided.
+        justification: String,
+    },
+    /// A bid year cannot be reopened once bids have been entered.
+    BidYearHasBids {
+        /// The bid year.
+        year: u16,
+        /// The number of leave bids and bid preferences entered.
+        bids: usize,
+    },
+    /// Two user records cannot be merged.
+    InvalidUserMerge {
+        /// Description of why the merge is refused.
+        reason: String,
+    },
+    /// An area is frozen, so nothing in it may change until it is unfrozen.
+    ScopeFrozen {
+        /// The bid year.
+        bid_year: u16,
+        /// The frozen area's code.
+        area: String,
+    },
+    /// An area cannot be unfrozen because it is not frozen.
+    ScopeNotFrozen {
+        /// The bid year.
+        bid_year: u16,
+        /// The area's code.
+        area: String,
+    },
+    /// Freeze reason is invalid (empty or too short).
+    InvalidFreezeReason {
+        /// The reason provided.
+        reason: String,
+    },
+    /// Checkpoint label or description is invalid.
+    InvalidCheckpointLabel {
+        /// Description of why the label is invalid.
+        reason: String,
+    },
+    /// Facility configuration is invalid.
+    InvalidFacility {
+        /// The invalid field.
+        field: String,
+        /// Description of why the field is invalid.
+        reason: String,
+    },
 }
 
 impl std::fmt::Display for DomainError {
@@ -456,6 +606,16 @@ impl std::fmt::Display for DomainError {
                     "Cannot activate bid year: year {active_year} is already active"
                 )
             }
+            Self::BiddingNotReady {
+                year,
+                blocking_reasons,
+            } => {
+                write!(
+                    f,
+                    "Cannot activate bidding for bid year {year}: {}",
+                    blocking_reasons.join("; ")
+                )
+            }
             Self::OperationNotAllowedInState { operation, state } => {
                 write!(
                     f,
@@ -633,6 +793,124 @@ impl std::fmt::Display for DomainError {
                     "Invalid status transition from '{from}' to '{to}': {reason}"
                 )
             }
+            Self::InvalidBidderAdvance { reason } => {
+                write!(f, "Invalid bidder advance: {reason}")
+            }
+            Self::InvalidBidReceiptMethod { method } => {
+                write!(
+                    f,
+                    "Invalid bid receipt method: '{method}' (expected phone, in_person, or written)"
+                )
+            }
+            Self::InvalidLeaveBid { reason } => {
+                write!(f, "Invalid leave bid: {reason}")
+            }
+            Self::InvalidBidPreference { reason } => {
+                write!(f, "Invalid bid preference: {reason}")
+            }
+            Self::InvalidOverbid { reason } => {
+                write!(f, "Invalid overbid: {reason}")
+            }
+            Self::InvalidSelfBid { reason } => {
+                write!(f, "Invalid self-service bid: {reason}")
+            }
+            Self::InvalidBidRule { reason } => {
+                write!(f, "Invalid bid rule: {reason}")
+            }
+            Self::InvalidEligibilityException { reason } => {
+                write!(f, "Invalid eligibility exception: {reason}")
+            }
+            Self::BidRuleViolated { rule, reason } => {
+                write!(f, "Bid rule '{rule}' violated: {reason}")
+            }
+            Self::RoundNotComplete {
+                round_id,
+                outstanding,
+            } => {
+                write!(
+                    f,
+                    "Round {round_id} cannot be signed off: {} still to bid ({})",
+                    outstanding.len(),
+                    outstanding.join(", ")
+                )
+            }
+            Self::RoundSignedOff { round_id } => {
+                write!(
+                    f,
+                    "Round {round_id} has been signed off; its bids cannot change without an override"
+                )
+            }
+            Self::InvalidBidAmendmentPolicy { reason } => {
+                write!(f, "Invalid bid amendment policy: {reason}")
+            }
+            Self::AmendmentWindowPassed {
+                round_id,
+                policy,
+                deadline,
+            } => {
+                if policy == "not_allowed" {
+                    write!(f, "Submitted bids in round {round_id} may not be amended")
+                } else {
+                    write!(
+                        f,
+                        "The amendment window for round {round_id} closed at {deadline} ({policy} policy)"
+                    )
+                }
+            }
+            Self::InvalidWaitlistOffer { reason } => {
+                write!(f, "Invalid waitlist offer: {reason}")
+            }
+            Self::WaitlistOfferExpired {
+                waitlist_offer_id,
+                expires_at,
+            } => {
+                write!(
+                    f,
+                    "Waitlist offer {waitlist_offer_id} expired at {expires_at}"
+                )
+            }
+            Self::InvalidLeaveCap { reason } => {
+                write!(f, "Invalid leave carryover cap: {reason}")
+            }
+            Self::UnknownFeatureFlag { flag } => {
+                write!(f, "Unknown feature flag: '{flag}'")
+            }
+            Self::InvalidReopenJustification { justification } => {
+                write!(
+                    f,
+                    "Invalid reopen justification: must be at least 20 characters (got: '{justification}')"
+                )
+            }
+            Self::BidYearHasBids { year, bids } => {
+                write!(
+                    f,
+                    "Bid year {year} cannot be reopened: {bids} bids have been entered"
+                )
+            }
+            Self::InvalidUserMerge { reason } => {
+                write!(f, "Invalid user merge: {reason}")
+            }
+            Self::ScopeFrozen { bid_year, area } => {
+                write!(
+                    f,
+                    "Area '{area}' in bid year {bid_year} is frozen; no changes are allowed until it is unfrozen"
+                )
+            }
+            Self::ScopeNotFrozen { bid_year, area } => {
+                write!(f, "Area '{area}' in bid year {bid_year} is not frozen")
+            }
+            Self::InvalidFreezeReason { reason } => {
+                write!(
+                    f,
+                    "Invalid freeze reason: must be at least 10 characters (got: '{reason}')"
+                )
+            }
+            Self::InvalidCheckpointLabel { reason } => {
+                write!(f, "Invalid checkpoint label: {reason}")
+            }
+            Self::InvalidFacility { field, reason } => {
+                write!(f, "Invalid facility {field}: {reason}")
+            }
         }
     }
 }
@@ -0,0 +1,166 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Facility-level configuration.
+//!
+//! The facility holds the settings that rarely change between bid years:
+//! its identifier and name, the timezone bid schedules are declared in,
+//! and the contract parameters for leave accrual and carryover. Bid years
+//! inherit these defaults unless they configure their own.
+
+use crate::error::DomainError;
+use crate::leave_accrual::AccrualRates;
+use crate::schedule::parse_timezone;
+use crate::use_or_lose::LeaveCapPolicy;
+
+/// The maximum length of a facility name.
+const MAX_NAME_LENGTH: usize = 100;
+
+/// A facility's configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Facility {
+    identifier: String,
+    name: String,
+    default_timezone: String,
+    accrual_rates: AccrualRates,
+    leave_cap: LeaveCapPolicy,
+}
+
+impl Facility {
+    /// Creates a facility configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - The facility identifier (e.g., `"ZAB"`)
+    /// * `name` - The facility's display name
+    /// * `default_timezone` - IANA timezone bid schedules default to
+    /// * `accrual_rates` - Leave accrual rates per service tier
+    /// * `leave_cap` - The default annual leave carryover cap
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The identifier is not 3 or 4 uppercase letters or digits
+    /// - The name is empty or longer than 100 characters
+    /// - The timezone is not a valid IANA identifier
+    pub fn new(
+        identifier: &str,
+        name: &str,
+        default_timezone: &str,
+        accrual_rates: AccrualRates,
+        leave_cap: LeaveCapPolicy,
+    ) -> Result<Self, DomainError> {
+        let identifier: &str = identifier.trim();
+        if !(3..=4).contains(&identifier.len())
+            || !identifier
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        {
+            return Err(DomainError::InvalidFacility {
+                field: String::from("identifier"),
+                reason: format!(
+                    "Facility identifier '{identifier}' must be 3 or 4 uppercase letters or digits"
+                ),
+            });
+        }
+
+        let name: &str = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+            return Err(DomainError::InvalidFacility {
+                field: String::from("name"),
+                reason: format!("Facility name must be 1 to {MAX_NAME_LENGTH} characters"),
+            });
+        }
+
+        parse_timezone(default_timezone)?;
+
+        Ok(Self {
+            identifier: identifier.to_string(),
+            name: name.to_string(),
+            default_timezone: default_timezone.to_string(),
+            accrual_rates,
+            leave_cap,
+        })
+    }
+
+    /// Returns the facility identifier.
+    #[must_use]
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Returns the facility's display name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the IANA timezone bid schedules default to.
+    #[must_use]
+    pub fn default_timezone(&self) -> &str {
+        &self.default_timezone
+    }
+
+    /// Returns the leave accrual rates.
+    #[must_use]
+    pub const fn accrual_rates(&self) -> AccrualRates {
+        self.accrual_rates
+    }
+
+    /// Returns the default annual leave carryover cap.
+    #[must_use]
+    pub const fn leave_cap(&self) -> LeaveCapPolicy {
+        self.leave_cap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facility(identifier: &str, name: &str, timezone: &str) -> Result<Facility, DomainError> {
+        Facility::new(
+            identifier,
+            name,
+            timezone,
+            AccrualRates::default(),
+            LeaveCapPolicy::default(),
+        )
+    }
+
+    #[test]
+    fn test_valid_facility() {
+        let facility: Result<Facility, DomainError> =
+            facility(" ZAB ", "Albuquerque Center", "America/Denver");
+
+        assert!(facility.is_ok_and(|f| f.identifier() == "ZAB"));
+    }
+
+    #[test]
+    fn test_invalid_identifier() {
+        for identifier in ["", "ZA", "ZABQX", "zab", "Z-B"] {
+            assert!(matches!(
+                facility(identifier, "Albuquerque Center", "America/Denver"),
+                Err(DomainError::InvalidFacility { ref field, .. }) if field == "identifier"
+            ));
+        }
+    }
+
+    #[test]
+    fn test_invalid_name() {
+        assert!(matches!(
+            facility("ZAB", "  ", "America/Denver"),
+            Err(DomainError::InvalidFacility { ref field, .. }) if field == "name"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_timezone() {
+        assert!(matches!(
+            facility("ZAB", "Albuquerque Center", "Mars/Olympus"),
+            Err(DomainError::InvalidTimezone(_))
+        ));
+    }
+}
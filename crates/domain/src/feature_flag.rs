@@ -0,0 +1,84 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Feature flags.
+//!
+//! Subsystems that change how bidding runs can be switched on or off per
+//! bid year, so a facility can trial one for a single bid before relying
+//! on it. A bid year without a stored setting uses the flag's default.
+
+use crate::error::DomainError;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// A subsystem that can be enabled per bid year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureFlag {
+    /// Offer slots freed after a round closes to the waitlist.
+    Waitlists,
+    /// Advance the current bidder automatically when their window elapses.
+    AutoAdvance,
+}
+
+impl FeatureFlag {
+    /// Every flag, in display order.
+    pub const ALL: [Self; 2] = [Self::Waitlists, Self::AutoAdvance];
+
+    /// Returns the string representation of the flag.
+    ///
+    /// This is used for persistence and API serialization.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Waitlists => "waitlists",
+            Self::AutoAdvance => "auto_advance",
+        }
+    }
+
+    /// Returns whether the flag is on in a bid year that has not set it.
+    ///
+    /// Waitlists and auto-advance shipped before flags existed, so they
+    /// default to on and existing bid years keep their behavior.
+    #[must_use]
+    pub const fn default_enabled(&self) -> bool {
+        match self {
+            Self::Waitlists | Self::AutoAdvance => true,
+        }
+    }
+}
+
+impl FromStr for FeatureFlag {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|flag| flag.as_str() == s)
+            .ok_or_else(|| DomainError::UnknownFeatureFlag {
+                flag: s.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_flag_string_round_trip() {
+        for flag in FeatureFlag::ALL {
+            assert_eq!(flag.as_str().parse::<FeatureFlag>(), Ok(flag));
+        }
+    }
+
+    #[test]
+    fn test_unknown_feature_flag() {
+        assert!(matches!(
+            "teleportation".parse::<FeatureFlag>(),
+            Err(DomainError::UnknownFeatureFlag { .. })
+        ));
+    }
+}
@@ -15,6 +15,124 @@ use crate::types::User;
 use serde::{Deserialize, Serialize};
 use time::Date;
 
+/// Hours accrued per pay period in each service tier.
+///
+/// The junior tier covers less than 3 years of service, the mid tier 3 to
+/// 14 years, and the senior tier 15 years or more. The mid tier earns a
+/// one-time bonus of 4 hours. The default rates are 4, 6, and 8 hours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccrualRates {
+    junior: u8,
+    mid: u8,
+    senior: u8,
+}
+
+impl Default for AccrualRates {
+    fn default() -> Self {
+        Self {
+            junior: 4,
+            mid: 6,
+            senior: 8,
+        }
+    }
+}
+
+impl AccrualRates {
+    /// Creates a set of accrual rates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a rate is zero, exceeds 8 hours, or is lower
+    /// than the rate of a more junior tier.
+    pub fn new(junior_hours: u8, mid_hours: u8, senior_hours: u8) -> Result<Self, DomainError> {
+        if junior_hours == 0 || senior_hours > 8 {
+            return Err(DomainError::InvalidFacility {
+                field: String::from("accrual_rates"),
+                reason: String::from("Accrual rates must be between 1 and 8 hours per pay period"),
+            });
+        }
+        if mid_hours < junior_hours || senior_hours < mid_hours {
+            return Err(DomainError::InvalidFacility {
+                field: String::from("accrual_rates"),
+                reason: String::from("Accrual rates must not decrease with years of service"),
+            });
+        }
+        Ok(Self {
+            junior: junior_hours,
+            mid: mid_hours,
+            senior: senior_hours,
+        })
+    }
+
+    /// Returns the hours accrued per pay period with less than 3 years of service.
+    #[must_use]
+    pub const fn junior_hours(&self) -> u8 {
+        self.junior
+    }
+
+    /// Returns the hours accrued per pay period with 3 to 14 years of service.
+    #[must_use]
+    pub const fn mid_hours(&self) -> u8 {
+        self.mid
+    }
+
+    /// Returns the hours accrued per pay period with 15 or more years of service.
+    #[must_use]
+    pub const fn senior_hours(&self) -> u8 {
+        self.senior
+    }
+
+    /// Returns the hours accrued per pay period in an hours category.
+    #[must_use]
+    pub const fn hours_for(self, category: HoursCategory) -> u8 {
+        match category {
+            HoursCategory::Junior => self.junior,
+            HoursCategory::Mid => self.mid,
+            HoursCategory::Senior => self.senior,
+        }
+    }
+
+    /// Returns the accrual rate for a number of complete years of service.
+    const fn rate_for(self, years_of_service: u16) -> u8 {
+        self.hours_for(HoursCategory::for_years_of_service(years_of_service))
+    }
+}
+
+/// The leave hours category a user accrues in, set by years of service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum HoursCategory {
+    /// Less than 3 years of service.
+    Junior,
+    /// 3 to 14 years of service.
+    Mid,
+    /// 15 or more years of service.
+    Senior,
+}
+
+impl HoursCategory {
+    /// Returns the category for a number of complete years of service.
+    #[must_use]
+    pub const fn for_years_of_service(years_of_service: u16) -> Self {
+        if years_of_service < 3 {
+            Self::Junior
+        } else if years_of_service < 15 {
+            Self::Mid
+        } else {
+            Self::Senior
+        }
+    }
+
+    /// Returns the string representation of this category.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Junior => "junior",
+            Self::Mid => "mid",
+            Self::Senior => "senior",
+        }
+    }
+}
+
 /// Reason for a specific accrual entry in the breakdown.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AccrualReason {
@@ -24,7 +142,7 @@ pub enum AccrualReason {
     Transition,
     /// The 27th pay period in a 27-PP year.
     TwentySeventhPP,
-    /// Bonus hours for the 6-hour tier.
+    /// Bonus hours for the mid tier.
     Bonus,
     /// Rounding adjustment to reach a full 8-hour day multiple.
     RoundingAdjustment,
@@ -60,19 +178,34 @@ pub struct LeaveAccrualResult {
     pub breakdown: Vec<PayPeriodAccrual>,
 }
 
+/// Calculates leave accrual for a single user within a single bid year,
+/// using the default accrual rates.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as
+/// [`calculate_leave_accrual_with_rates`].
+pub fn calculate_leave_accrual(
+    user: &User,
+    bid_year: &CanonicalBidYear,
+) -> Result<LeaveAccrualResult, DomainError> {
+    calculate_leave_accrual_with_rates(user, bid_year, AccrualRates::default())
+}
+
 /// Calculates leave accrual for a single user within a single bid year.
 ///
 /// This is a pure, deterministic calculation that:
 /// - Uses anniversary-based service thresholds
 /// - Evaluates thresholds at pay period start dates
 /// - Applies the prior rate when a threshold is crossed mid-pay-period
-/// - Adds bonus hours for the 6-hour tier
+/// - Adds bonus hours for the mid tier
 /// - Rounds up to the next multiple of 8 if needed
 ///
 /// # Arguments
 ///
 /// * `user` - The user to calculate accrual for
 /// * `bid_year` - The canonical bid year
+/// * `rates` - The facility's accrual rates
 ///
 /// # Returns
 ///
@@ -85,9 +218,10 @@ pub struct LeaveAccrualResult {
 /// - Date parsing fails
 /// - Pay period derivation fails
 /// - Date arithmetic overflows
-pub fn calculate_leave_accrual(
+pub fn calculate_leave_accrual_with_rates(
     user: &User,
     bid_year: &CanonicalBidYear,
+    rates: AccrualRates,
 ) -> Result<LeaveAccrualResult, DomainError> {
     // Parse the service computation date
     let scd: Date = parse_service_computation_date(user)?;
@@ -102,7 +236,7 @@ pub fn calculate_leave_accrual(
 
     for (idx, period) in pay_periods.iter().enumerate() {
         let years_of_service: u16 = calculate_years_of_service(scd, period.start_date());
-        let rate: u8 = determine_accrual_rate(years_of_service);
+        let rate: u8 = rates.rate_for(years_of_service);
 
         // Determine the reason
         let reason: AccrualReason = if period.index() == 27 {
@@ -111,7 +245,7 @@ pub fn calculate_leave_accrual(
             // Check if this is immediately after a transition
             let prev_period: &PayPeriod = &pay_periods[idx - 1];
             let prev_years: u16 = calculate_years_of_service(scd, prev_period.start_date());
-            let prev_rate: u8 = determine_accrual_rate(prev_years);
+            let prev_rate: u8 = rates.rate_for(prev_years);
 
             if rate == prev_rate {
                 AccrualReason::Normal
@@ -133,8 +267,8 @@ pub fn calculate_leave_accrual(
 
         total_hours += u16::from(rate);
 
-        // Apply bonus hours if in the 6-hour tier and not yet applied
-        if rate == 6 && !applied_bonus {
+        // Apply bonus hours if in the mid tier and not yet applied
+        if (3..15).contains(&years_of_service) && !applied_bonus {
             breakdown.push(PayPeriodAccrual {
                 pay_period_index: None,
                 start_date: None,
@@ -176,6 +310,63 @@ pub fn calculate_leave_accrual(
     })
 }
 
+/// A user's projected accrual over a whole bid year.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnnualAccrualProjection {
+    /// The hours category at the start of the first pay period.
+    pub starting_category: HoursCategory,
+    /// The hours category at the start of the last pay period.
+    pub ending_category: HoursCategory,
+    /// The accrual over every pay period of the bid year.
+    pub accrual: LeaveAccrualResult,
+}
+
+impl AnnualAccrualProjection {
+    /// Returns whether the user moves to a new hours category during the
+    /// bid year.
+    #[must_use]
+    pub fn changes_category(&self) -> bool {
+        self.starting_category != self.ending_category
+    }
+}
+
+/// Projects a user's annual leave accrual over the bid year's pay period
+/// calendar.
+///
+/// The hours category is taken from the user's service computation date
+/// at the start of each pay period, so a user crossing a service threshold
+/// during the year accrues at both rates.
+///
+/// # Arguments
+///
+/// * `user` - The user to project accrual for
+/// * `bid_year` - The canonical bid year
+/// * `rates` - The facility's accrual rates
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as
+/// [`calculate_leave_accrual_with_rates`].
+pub fn project_annual_accrual(
+    user: &User,
+    bid_year: &CanonicalBidYear,
+    rates: AccrualRates,
+) -> Result<AnnualAccrualProjection, DomainError> {
+    let scd: Date = parse_service_computation_date(user)?;
+    let pay_periods: Vec<PayPeriod> = bid_year.pay_periods()?;
+    let category_at = |period: Option<&PayPeriod>| {
+        HoursCategory::for_years_of_service(period.map_or(0, |period| {
+            calculate_years_of_service(scd, period.start_date())
+        }))
+    };
+
+    Ok(AnnualAccrualProjection {
+        starting_category: category_at(pay_periods.first()),
+        ending_category: category_at(pay_periods.last()),
+        accrual: calculate_leave_accrual_with_rates(user, bid_year, rates)?,
+    })
+}
+
 /// Parses the service computation date from a user's seniority data.
 ///
 /// # Arguments
@@ -246,25 +437,6 @@ fn calculate_years_of_service(scd: Date, as_of: Date) -> u16 {
     }
 }
 
-/// Determines the accrual rate based on years of service.
-///
-/// # Arguments
-///
-/// * `years_of_service` - The number of complete years of service
-///
-/// # Returns
-///
-/// The accrual rate in hours per pay period.
-const fn determine_accrual_rate(years_of_service: u16) -> u8 {
-    if years_of_service < 3 {
-        4
-    } else if years_of_service < 15 {
-        6
-    } else {
-        8
-    }
-}
-
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -345,23 +517,94 @@ mod tests {
 
     #[test]
     fn test_determine_accrual_rate_under_3_years() {
-        assert_eq!(determine_accrual_rate(0), 4);
-        assert_eq!(determine_accrual_rate(1), 4);
-        assert_eq!(determine_accrual_rate(2), 4);
+        let rates: AccrualRates = AccrualRates::default();
+        assert_eq!(rates.rate_for(0), 4);
+        assert_eq!(rates.rate_for(1), 4);
+        assert_eq!(rates.rate_for(2), 4);
     }
 
     #[test]
     fn test_determine_accrual_rate_3_to_14_years() {
-        assert_eq!(determine_accrual_rate(3), 6);
-        assert_eq!(determine_accrual_rate(10), 6);
-        assert_eq!(determine_accrual_rate(14), 6);
+        let rates: AccrualRates = AccrualRates::default();
+        assert_eq!(rates.rate_for(3), 6);
+        assert_eq!(rates.rate_for(10), 6);
+        assert_eq!(rates.rate_for(14), 6);
     }
 
     #[test]
     fn test_determine_accrual_rate_15_plus_years() {
-        assert_eq!(determine_accrual_rate(15), 8);
-        assert_eq!(determine_accrual_rate(20), 8);
-        assert_eq!(determine_accrual_rate(30), 8);
+        let rates: AccrualRates = AccrualRates::default();
+        assert_eq!(rates.rate_for(15), 8);
+        assert_eq!(rates.rate_for(20), 8);
+        assert_eq!(rates.rate_for(30), 8);
+    }
+
+    #[test]
+    fn test_accrual_rates_must_not_decrease() {
+        assert!(AccrualRates::new(4, 6, 8).is_ok());
+        assert!(AccrualRates::new(0, 6, 8).is_err());
+        assert!(AccrualRates::new(4, 6, 10).is_err());
+        assert!(AccrualRates::new(6, 4, 8).is_err());
+    }
+
+    #[test]
+    fn test_accrual_with_facility_rates() {
+        let user: User = make_user("2010-01-01");
+        let bid_year: CanonicalBidYear = make_bid_year_26pp();
+        let rates: AccrualRates = AccrualRates::new(4, 6, 7).unwrap();
+
+        let result: LeaveAccrualResult =
+            calculate_leave_accrual_with_rates(&user, &bid_year, rates).unwrap();
+
+        // 26 PPs * 7 hours = 182 hours, rounded up to 184 hours = 23 days
+        assert_eq!(result.total_hours, 184);
+        assert_eq!(result.total_days, 23);
+        assert!(result.rounded_up);
+    }
+
+    #[test]
+    fn test_hours_category_for_years_of_service() {
+        assert_eq!(
+            HoursCategory::for_years_of_service(2),
+            HoursCategory::Junior
+        );
+        assert_eq!(HoursCategory::for_years_of_service(3), HoursCategory::Mid);
+        assert_eq!(
+            HoursCategory::for_years_of_service(15),
+            HoursCategory::Senior
+        );
+        assert_eq!(AccrualRates::default().hours_for(HoursCategory::Mid), 6);
+    }
+
+    #[test]
+    fn test_annual_projection_tracks_category_change() {
+        // SCD 2023-06-15: 2 years at the start of 2026, 3 years by year end
+        let user: User = make_user("2023-06-15");
+        let bid_year: CanonicalBidYear = make_bid_year_26pp();
+
+        let projection: AnnualAccrualProjection =
+            project_annual_accrual(&user, &bid_year, AccrualRates::default()).unwrap();
+
+        assert_eq!(projection.starting_category, HoursCategory::Junior);
+        assert_eq!(projection.ending_category, HoursCategory::Mid);
+        assert!(projection.changes_category());
+        assert_eq!(
+            projection.accrual,
+            calculate_leave_accrual(&user, &bid_year).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_annual_projection_steady_category() {
+        let user: User = make_user("2000-01-01");
+        let bid_year: CanonicalBidYear = make_bid_year_26pp();
+
+        let projection: AnnualAccrualProjection =
+            project_annual_accrual(&user, &bid_year, AccrualRates::default()).unwrap();
+
+        assert_eq!(projection.starting_category, HoursCategory::Senior);
+        assert!(!projection.changes_category());
+        assert_eq!(projection.accrual.total_hours, 208);
     }
 
     #[test]
@@ -17,37 +17,62 @@
     clippy::expect_used
 )]
 
+mod bid_amendment;
+mod bid_entry;
 mod bid_order;
+mod bid_preference;
 mod bid_status;
 mod bid_window;
 mod bid_year;
 mod error;
+mod facility;
+mod feature_flag;
 mod leave_accrual;
 mod leave_availability;
 mod readiness;
+mod schedule;
+mod slot_inventory;
 mod types;
+mod use_or_lose;
 mod validation;
 
 #[cfg(test)]
 mod tests;
 
+pub use bid_amendment::{BidAmendment, BidAmendmentPolicy};
+pub use bid_entry::BidReceiptMethod;
 pub use bid_order::{BidOrderPosition, SeniorityInputs, compute_bid_order};
+pub use bid_preference::{BidPreference, select_bid_preference};
 pub use bid_status::{BidStatus, UserBidStatus};
-pub use bid_window::{BidWindow, calculate_bid_windows};
+pub use bid_window::{BidWindow, calculate_bid_windows, calculate_bid_windows_with_blackouts};
 pub use readiness::{
-    count_participation_flag_violations, count_seniority_conflicts, count_unreviewed_no_bid_users,
-    evaluate_area_readiness,
+    ReadinessEvaluation, count_participation_flag_violations, count_seniority_conflicts,
+    count_unreviewed_no_bid_users, evaluate_area_readiness,
+};
+
+pub use slot_inventory::{DailySlots, SlotInventory};
+
+pub use schedule::{
+    BUSINESS_HOURS_END, BUSINESS_HOURS_START, UtcRange, is_after_hours, local_to_utc,
+    local_window_to_utc, parse_timezone, schedule_window_utc, utc_to_local,
 };
 
 // Re-export public types
 pub use bid_year::{CanonicalBidYear, PayPeriod};
 pub use error::DomainError;
+pub use facility::Facility;
+pub use feature_flag::FeatureFlag;
 pub use leave_accrual::{
-    AccrualReason, LeaveAccrualResult, PayPeriodAccrual, calculate_leave_accrual,
+    AccrualRates, AccrualReason, AnnualAccrualProjection, HoursCategory, LeaveAccrualResult,
+    PayPeriodAccrual, calculate_leave_accrual, calculate_leave_accrual_with_rates,
+    project_annual_accrual,
 };
 pub use leave_availability::{LeaveAvailabilityResult, LeaveUsage, calculate_leave_availability};
 pub use types::{
     Area, BidSchedule, BidYear, BidYearLifecycle, BidYearReadiness, Crew, Initials,
     ReadinessDetails, Round, RoundGroup, SeniorityData, User, UserType,
 };
+pub use use_or_lose::{
+    DEFAULT_CARRYOVER_CAP_HOURS, LeaveBalanceProjection, LeaveCapPolicy, project_leave_balance,
+};
 pub use validation::{validate_bid_year, validate_initials_unique, validate_user_fields};
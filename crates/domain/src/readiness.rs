@@ -131,6 +131,83 @@ pub fn evaluate_area_readiness(
     (blocking_reasons, unreviewed_count, violation_count)
 }
 
+/// The outcome of evaluating every readiness criterion for a bid year.
+///
+/// Built by the caller from persisted state, then checked by the core
+/// before bidding is activated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadinessEvaluation {
+    /// Codes of non-system areas with no rounds configured.
+    pub areas_missing_rounds: Vec<String>,
+    /// Number of users in the No Bid area who have not been reviewed.
+    pub no_bid_users_pending_review: usize,
+    /// Number of users violating the participation flag invariant.
+    pub participation_flag_violations: usize,
+    /// One detailed message per unresolved seniority conflict.
+    pub seniority_conflicts: Vec<String>,
+    /// Whether the bid schedule is set and valid.
+    pub bid_schedule_set: bool,
+}
+
+impl ReadinessEvaluation {
+    /// Returns a message for every unsatisfied criterion.
+    #[must_use]
+    pub fn blocking_reasons(&self) -> Vec<String> {
+        let mut blocking_reasons = Vec::new();
+
+        for area_code in &self.areas_missing_rounds {
+            blocking_reasons.push(format!("Area '{area_code}' has no rounds configured"));
+        }
+
+        if self.no_bid_users_pending_review > 0 {
+            blocking_reasons.push(format!(
+                "{} users in No Bid area have not been reviewed",
+                self.no_bid_users_pending_review
+            ));
+        }
+
+        if self.participation_flag_violations > 0 {
+            blocking_reasons.push(format!(
+                "{} users violate participation flag invariant",
+                self.participation_flag_violations
+            ));
+        }
+
+        if !self.seniority_conflicts.is_empty() {
+            blocking_reasons.push(format!(
+                "{} seniority conflict(s) detected",
+                self.seniority_conflicts.len()
+            ));
+            blocking_reasons.extend(self.seniority_conflicts.iter().cloned());
+        }
+
+        if !self.bid_schedule_set {
+            blocking_reasons.push(String::from("Bid schedule is not set"));
+        }
+
+        blocking_reasons
+    }
+
+    /// Returns whether no criterion blocks bidding.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.blocking_reasons().is_empty()
+    }
+
+    /// Returns the evaluation as `key=value` pairs for audit snapshots.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "areas_missing_rounds=[{}],no_bid_users_pending_review={},participation_flag_violations={},seniority_conflicts={},bid_schedule_set={}",
+            self.areas_missing_rounds.join(";"),
+            self.no_bid_users_pending_review,
+            self.participation_flag_violations,
+            self.seniority_conflicts.len(),
+            self.bid_schedule_set
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,4 +348,46 @@ mod tests {
                 .any(|r| r.contains("participation flag invariant"))
         );
     }
+
+    #[test]
+    fn test_readiness_evaluation_lists_every_blocker() {
+        let evaluation = ReadinessEvaluation {
+            areas_missing_rounds: vec![String::from("NORTH")],
+            no_bid_users_pending_review: 2,
+            participation_flag_violations: 1,
+            seniority_conflicts: vec![String::from("Area 'SOUTH': tie")],
+            bid_schedule_set: false,
+        };
+
+        assert!(!evaluation.is_ready());
+        assert_eq!(
+            evaluation.blocking_reasons(),
+            vec![
+                String::from("Area 'NORTH' has no rounds configured"),
+                String::from("2 users in No Bid area have not been reviewed"),
+                String::from("1 users violate participation flag invariant"),
+                String::from("1 seniority conflict(s) detected"),
+                String::from("Area 'SOUTH': tie"),
+                String::from("Bid schedule is not set"),
+            ]
+        );
+        assert_eq!(
+            evaluation.summary(),
+            "areas_missing_rounds=[NORTH],no_bid_users_pending_review=2,participation_flag_violations=1,seniority_conflicts=1,bid_schedule_set=false"
+        );
+    }
+
+    #[test]
+    fn test_readiness_evaluation_ready() {
+        let evaluation = ReadinessEvaluation {
+            areas_missing_rounds: Vec::new(),
+            no_bid_users_pending_review: 0,
+            participation_flag_violations: 0,
+            seniority_conflicts: Vec::new(),
+            bid_schedule_set: true,
+        };
+
+        assert!(evaluation.is_ready());
+        assert!(evaluation.blocking_reasons().is_empty());
+    }
 }
@@ -0,0 +1,462 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Timezone-aware conversion of configured bid windows to UTC.
+//!
+//! Bid schedules are configured as wall-clock times in an IANA timezone.
+//! This module turns those local windows into concrete UTC ranges.
+//!
+//! ## DST Resolution
+//!
+//! Bid seasons routinely span a DST transition, so some local times are
+//! either skipped or repeated. Local times are resolved as follows:
+//!
+//! - Unambiguous local times map to their single UTC instant
+//! - Repeated local times (clocks fall back) resolve to the earlier
+//!   occurrence, so a window never starts an hour late
+//! - Skipped local times (clocks spring forward) are shifted forward by the
+//!   length of the gap, so `02:30` on a spring-forward night becomes `03:30`
+//!
+//! A window's length therefore follows the wall clock: a window spanning a
+//! transition is an hour shorter or longer than its nominal length.
+
+use crate::error::DomainError;
+use crate::types::BidSchedule;
+use chrono::{
+    DateTime, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc,
+};
+use chrono_tz::Tz;
+
+/// When business hours start each weekday, in local time.
+pub const BUSINESS_HOURS_START: time::Time = time::macros::time!(07:00);
+
+/// When business hours end each weekday, in local time.
+pub const BUSINESS_HOURS_END: time::Time = time::macros::time!(18:00);
+
+/// A concrete window of time in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UtcRange {
+    /// Inclusive start of the range.
+    pub start: DateTime<Utc>,
+    /// Exclusive end of the range.
+    pub end: DateTime<Utc>,
+}
+
+impl UtcRange {
+    /// Returns the elapsed time covered by the range.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    /// Returns whether an instant falls within the range.
+    #[must_use]
+    pub fn contains(&self, instant: DateTime<Utc>) -> bool {
+        self.start <= instant && instant < self.end
+    }
+}
+
+/// Parses an IANA timezone identifier.
+///
+/// # Errors
+///
+/// Returns `DomainError::InvalidTimezone` if the identifier is unknown.
+pub fn parse_timezone(timezone: &str) -> Result<Tz, DomainError> {
+    timezone
+        .parse()
+        .map_err(|_| DomainError::InvalidTimezone(timezone.to_string()))
+}
+
+/// Converts a wall-clock time in a timezone to a UTC instant.
+///
+/// See the module documentation for how DST gaps and overlaps are resolved.
+///
+/// # Errors
+///
+/// Returns an error if the offset surrounding a DST gap cannot be determined.
+pub fn local_to_utc(tz: Tz, local: NaiveDateTime) -> Result<DateTime<Utc>, DomainError> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(resolved) => Ok(resolved.with_timezone(&Utc)),
+        LocalResult::Ambiguous(earliest, _latest) => Ok(earliest.with_timezone(&Utc)),
+        LocalResult::None => {
+            // Apply the offset in effect before the gap, which lands the
+            // instant the same distance past the transition.
+            let offset_before_gap: i32 = tz
+                .offset_from_utc_datetime(&(local - Duration::days(1)))
+                .fix()
+                .local_minus_utc();
+            let utc: NaiveDateTime = local
+                .checked_sub_signed(Duration::seconds(i64::from(offset_before_gap)))
+                .ok_or_else(|| DomainError::InvalidBidSchedule {
+                    reason: format!("Could not resolve {local} in timezone {tz}"),
+                })?;
+            Ok(Utc.from_utc_datetime(&utc))
+        }
+    }
+}
+
+/// Converts a local daily window on a date to a UTC range.
+///
+/// # Errors
+///
+/// Returns an error if the window does not end after it starts once
+/// resolved to UTC.
+pub fn local_window_to_utc(
+    tz: Tz,
+    date: NaiveDate,
+    window_start_time: NaiveTime,
+    window_end_time: NaiveTime,
+) -> Result<UtcRange, DomainError> {
+    let start: DateTime<Utc> = local_to_utc(tz, date.and_time(window_start_time))?;
+    let end: DateTime<Utc> = local_to_utc(tz, date.and_time(window_end_time))?;
+
+    if end <= start {
+        return Err(DomainError::InvalidBidSchedule {
+            reason: format!(
+                "Window {window_start_time}–{window_end_time} on {date} in {tz} does not end after it starts"
+            ),
+        });
+    }
+
+    Ok(UtcRange { start, end })
+}
+
+/// Converts a bid schedule's daily window on a date to a UTC range.
+///
+/// # Errors
+///
+/// Returns an error if the schedule's timezone is invalid or the window
+/// cannot be resolved on the date.
+pub fn schedule_window_utc(
+    schedule: &BidSchedule,
+    date: time::Date,
+) -> Result<UtcRange, DomainError> {
+    let tz: Tz = parse_timezone(schedule.timezone())?;
+    local_window_to_utc(
+        tz,
+        to_naive_date(date)?,
+        to_naive_time(schedule.window_start_time())?,
+        to_naive_time(schedule.window_end_time())?,
+    )
+}
+
+/// Returns the wall-clock date and time in a timezone at a UTC instant.
+///
+/// # Errors
+///
+/// Returns an error if the timezone is invalid or the instant is out of
+/// range.
+pub fn utc_to_local(
+    timezone: &str,
+    instant: time::OffsetDateTime,
+) -> Result<time::PrimitiveDateTime, DomainError> {
+    let tz: Tz = parse_timezone(timezone)?;
+    let out_of_range = || DomainError::InvalidBidSchedule {
+        reason: format!("Instant {instant} is out of range in timezone {tz}"),
+    };
+    let utc: DateTime<Utc> =
+        DateTime::from_timestamp(instant.unix_timestamp(), 0).ok_or_else(out_of_range)?;
+    let offset: time::UtcOffset = time::UtcOffset::from_whole_seconds(
+        tz.offset_from_utc_datetime(&utc.naive_utc())
+            .fix()
+            .local_minus_utc(),
+    )
+    .map_err(|_| out_of_range())?;
+    let local: time::OffsetDateTime = instant.checked_to_offset(offset).ok_or_else(out_of_range)?;
+    Ok(time::PrimitiveDateTime::new(local.date(), local.time()))
+}
+
+/// Returns whether a local time falls outside business hours.
+///
+/// Times before [`BUSINESS_HOURS_START`] or from [`BUSINESS_HOURS_END`] on
+/// a weekday are after hours, as is all of Saturday and Sunday.
+#[must_use]
+pub fn is_after_hours(local: time::PrimitiveDateTime) -> bool {
+    matches!(
+        local.weekday(),
+        time::Weekday::Saturday | time::Weekday::Sunday
+    ) || local.time() < BUSINESS_HOURS_START
+        || local.time() >= BUSINESS_HOURS_END
+}
+
+/// Converts a `time::Date` to a `chrono::NaiveDate`.
+pub fn to_naive_date(date: time::Date) -> Result<NaiveDate, DomainError> {
+    NaiveDate::from_ymd_opt(
+        date.year(),
+        u32::from(u8::from(date.month())),
+        u32::from(date.day()),
+    )
+    .ok_or_else(|| DomainError::InvalidBidSchedule {
+        reason: format!("Invalid date: {date}"),
+    })
+}
+
+/// Converts a `time::Time` to a `chrono::NaiveTime`.
+pub fn to_naive_time(value: time::Time) -> Result<NaiveTime, DomainError> {
+    NaiveTime::from_hms_opt(
+        u32::from(value.hour()),
+        u32::from(value.minute()),
+        u32::from(value.second()),
+    )
+    .ok_or_else(|| DomainError::InvalidBidSchedule {
+        reason: format!("Invalid time: {value}"),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn hms(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.from_utc_datetime(&date(year, month, day).and_time(hms(hour, minute)))
+    }
+
+    fn new_york() -> Tz {
+        parse_timezone("America/New_York").unwrap()
+    }
+
+    #[test]
+    fn test_parse_timezone_rejects_unknown_identifier() {
+        assert!(matches!(
+            parse_timezone("Mars/Olympus_Mons"),
+            Err(DomainError::InvalidTimezone(ref tz)) if tz == "Mars/Olympus_Mons"
+        ));
+    }
+
+    #[test]
+    fn test_standard_time_window() {
+        let range: UtcRange =
+            local_window_to_utc(new_york(), date(2026, 3, 6), hms(8, 0), hms(17, 0)).unwrap();
+        assert_eq!(range.start, utc(2026, 3, 6, 13, 0));
+        assert_eq!(range.end, utc(2026, 3, 6, 22, 0));
+        assert_eq!(range.duration(), Duration::hours(9));
+    }
+
+    #[test]
+    fn test_daylight_time_window() {
+        let range: UtcRange =
+            local_window_to_utc(new_york(), date(2026, 3, 9), hms(8, 0), hms(17, 0)).unwrap();
+        assert_eq!(range.start, utc(2026, 3, 9, 12, 0));
+        assert_eq!(range.end, utc(2026, 3, 9, 21, 0));
+    }
+
+    #[test]
+    fn test_windows_keep_wall_clock_across_spring_forward() {
+        // DST starts in New York on Sunday 2026-03-08
+        let friday: UtcRange =
+            local_window_to_utc(new_york(), date(2026, 3, 6), hms(8, 0), hms(17, 0)).unwrap();
+        let monday: UtcRange =
+            local_window_to_utc(new_york(), date(2026, 3, 9), hms(8, 0), hms(17, 0)).unwrap();
+
+        assert_eq!(monday.start - friday.start, Duration::hours(71));
+        assert_eq!(friday.duration(), monday.duration());
+    }
+
+    #[test]
+    fn test_windows_keep_wall_clock_across_fall_back() {
+        // DST ends in New York on Sunday 2026-11-01
+        let friday: UtcRange =
+            local_window_to_utc(new_york(), date(2026, 10, 30), hms(8, 0), hms(17, 0)).unwrap();
+        let monday: UtcRange =
+            local_window_to_utc(new_york(), date(2026, 11, 2), hms(8, 0), hms(17, 0)).unwrap();
+
+        assert_eq!(friday.start, utc(2026, 10, 30, 12, 0));
+        assert_eq!(monday.start, utc(2026, 11, 2, 13, 0));
+        assert_eq!(monday.start - friday.start, Duration::hours(73));
+    }
+
+    #[test]
+    fn test_skipped_local_time_shifts_forward_by_gap() {
+        let start: DateTime<Utc> =
+            local_to_utc(new_york(), date(2026, 3, 8).and_time(hms(2, 30))).unwrap();
+        assert_eq!(start, utc(2026, 3, 8, 7, 30));
+        assert_eq!(
+            start.with_timezone(&new_york()).time(),
+            hms(3, 30),
+            "02:30 does not exist and resolves to 03:30 EDT"
+        );
+    }
+
+    #[test]
+    fn test_repeated_local_time_resolves_to_earlier_occurrence() {
+        let start: DateTime<Utc> =
+            local_to_utc(new_york(), date(2026, 11, 1).and_time(hms(1, 30))).unwrap();
+        // 01:30 EDT, not 01:30 EST an hour later
+        assert_eq!(start, utc(2026, 11, 1, 5, 30));
+    }
+
+    #[test]
+    fn test_window_spanning_spring_forward_is_an_hour_shorter() {
+        let range: UtcRange =
+            local_window_to_utc(new_york(), date(2026, 3, 8), hms(0, 0), hms(6, 0)).unwrap();
+        assert_eq!(range.start, utc(2026, 3, 8, 5, 0));
+        assert_eq!(range.end, utc(2026, 3, 8, 10, 0));
+        assert_eq!(range.duration(), Duration::hours(5));
+    }
+
+    #[test]
+    fn test_window_spanning_fall_back_is_an_hour_longer() {
+        let range: UtcRange =
+            local_window_to_utc(new_york(), date(2026, 11, 1), hms(0, 0), hms(6, 0)).unwrap();
+        assert_eq!(range.start, utc(2026, 11, 1, 4, 0));
+        assert_eq!(range.end, utc(2026, 11, 1, 11, 0));
+        assert_eq!(range.duration(), Duration::hours(7));
+    }
+
+    #[test]
+    fn test_window_inside_spring_forward_gap_is_rejected() {
+        // Both ends resolve to 03:00 EDT, leaving an empty window
+        let result = local_window_to_utc(new_york(), date(2026, 3, 8), hms(2, 0), hms(3, 0));
+        assert!(matches!(
+            result,
+            Err(DomainError::InvalidBidSchedule { .. })
+        ));
+    }
+
+    #[test]
+    fn test_window_ending_before_start_is_rejected() {
+        let result = local_window_to_utc(new_york(), date(2026, 3, 9), hms(17, 0), hms(8, 0));
+        assert!(matches!(
+            result,
+            Err(DomainError::InvalidBidSchedule { .. })
+        ));
+    }
+
+    #[test]
+    fn test_southern_hemisphere_transitions() {
+        let sydney: Tz = parse_timezone("Australia/Sydney").unwrap();
+
+        // DST ends on 2026-04-05 (UTC+11 -> UTC+10)
+        let before: UtcRange =
+            local_window_to_utc(sydney, date(2026, 4, 3), hms(8, 0), hms(17, 0)).unwrap();
+        let after: UtcRange =
+            local_window_to_utc(sydney, date(2026, 4, 6), hms(8, 0), hms(17, 0)).unwrap();
+        assert_eq!(before.start, utc(2026, 4, 2, 21, 0));
+        assert_eq!(after.start, utc(2026, 4, 5, 22, 0));
+
+        // DST starts on 2026-10-04 (UTC+10 -> UTC+11), skipping 02:00–03:00
+        let skipped: DateTime<Utc> =
+            local_to_utc(sydney, date(2026, 10, 4).and_time(hms(2, 15))).unwrap();
+        assert_eq!(skipped.with_timezone(&sydney).time(), hms(3, 15));
+    }
+
+    #[test]
+    fn test_half_hour_dst_shift() {
+        // Lord Howe Island shifts by thirty minutes; DST starts 2026-10-04
+        let lord_howe: Tz = parse_timezone("Australia/Lord_Howe").unwrap();
+        let range: UtcRange =
+            local_window_to_utc(lord_howe, date(2026, 10, 4), hms(1, 0), hms(3, 0)).unwrap();
+        assert_eq!(range.duration(), Duration::minutes(90));
+
+        let skipped: DateTime<Utc> =
+            local_to_utc(lord_howe, date(2026, 10, 4).and_time(hms(2, 10))).unwrap();
+        assert_eq!(skipped.with_timezone(&lord_howe).time(), hms(2, 40));
+    }
+
+    #[test]
+    fn test_zone_without_dst_is_stable() {
+        let phoenix: Tz = parse_timezone("America/Phoenix").unwrap();
+        for day in [
+            date(2026, 1, 5),
+            date(2026, 3, 9),
+            date(2026, 7, 6),
+            date(2026, 11, 2),
+        ] {
+            let range: UtcRange = local_window_to_utc(phoenix, day, hms(8, 0), hms(17, 0)).unwrap();
+            assert_eq!(
+                range.start,
+                Utc.from_utc_datetime(&day.and_time(hms(15, 0)))
+            );
+            assert_eq!(range.duration(), Duration::hours(9));
+        }
+    }
+
+    #[test]
+    fn test_every_day_of_season_resolves_to_local_window() {
+        // Walk a full year so every transition is crossed
+        let tz: Tz = new_york();
+        let mut day: NaiveDate = date(2026, 1, 1);
+        while day < date(2027, 1, 1) {
+            let range: UtcRange = local_window_to_utc(tz, day, hms(8, 0), hms(17, 0)).unwrap();
+            assert_eq!(
+                range.start.with_timezone(&tz).naive_local(),
+                day.and_time(hms(8, 0))
+            );
+            assert_eq!(
+                range.end.with_timezone(&tz).naive_local(),
+                day.and_time(hms(17, 0))
+            );
+            assert_eq!(range.duration(), Duration::hours(9));
+            day += Duration::days(1);
+        }
+    }
+
+    #[test]
+    fn test_range_contains_is_half_open() {
+        let range: UtcRange =
+            local_window_to_utc(new_york(), date(2026, 3, 9), hms(8, 0), hms(17, 0)).unwrap();
+        assert!(range.contains(range.start));
+        assert!(range.contains(utc(2026, 3, 9, 20, 59)));
+        assert!(!range.contains(range.end));
+        assert!(!range.contains(utc(2026, 3, 9, 11, 59)));
+    }
+
+    #[test]
+    fn test_schedule_window_utc() {
+        let schedule: BidSchedule = BidSchedule::new(
+            String::from("America/New_York"),
+            time::Date::from_calendar_date(2026, time::Month::March, 2).unwrap(),
+            time::Time::from_hms(8, 0, 0).unwrap(),
+            time::Time::from_hms(17, 0, 0).unwrap(),
+            5,
+        )
+        .unwrap();
+
+        let range: UtcRange = schedule_window_utc(
+            &schedule,
+            time::Date::from_calendar_date(2026, time::Month::March, 9).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(range.start, utc(2026, 3, 9, 12, 0));
+        assert_eq!(range.end, utc(2026, 3, 9, 21, 0));
+    }
+
+    #[test]
+    fn test_utc_to_local_follows_dst() {
+        let winter: time::PrimitiveDateTime = utc_to_local(
+            "America/New_York",
+            time::macros::datetime!(2026-03-06 03:30 UTC),
+        )
+        .unwrap();
+        assert_eq!(winter, time::macros::datetime!(2026-03-05 22:30));
+
+        let summer: time::PrimitiveDateTime = utc_to_local(
+            "America/New_York",
+            time::macros::datetime!(2026-07-06 03:30 UTC),
+        )
+        .unwrap();
+        assert_eq!(summer, time::macros::datetime!(2026-07-05 23:30));
+    }
+
+    #[test]
+    fn test_after_hours_covers_evenings_and_weekends() {
+        // 2026-07-06 is a Monday
+        assert!(is_after_hours(time::macros::datetime!(2026-07-06 06:59)));
+        assert!(!is_after_hours(time::macros::datetime!(2026-07-06 07:00)));
+        assert!(!is_after_hours(time::macros::datetime!(2026-07-06 17:59)));
+        assert!(is_after_hours(time::macros::datetime!(2026-07-06 18:00)));
+        assert!(is_after_hours(time::macros::datetime!(2026-07-04 12:00)));
+        assert!(is_after_hours(time::macros::datetime!(2026-07-05 12:00)));
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Daily leave slot inventory.
+//!
+//! Every round offers `slots_per_day` leave slots on each day in each area.
+//! Prime dates (spring break, Christmas week) are designated per bid year;
+//! a round may cap its slots on prime dates separately from its normal
+//! `slots_per_day`. Staffing changes adjust that capacity for a single
+//! area, round, and day, up or down. The slots remaining on a day are the
+//! adjusted capacity less the approved leave already held. Bid validation
+//! and coverage reporting both read capacity from a [`SlotInventory`] so
+//! they can never disagree.
+//!
+//! Some areas allocate a round's slots per crew rather than per area. On a
+//! crew-partitioned round each crew has its own fixed daily slots, and a
+//! bid is checked only against its bidder's crew; prime caps and staffing
+//! adjustments apply to area-wide rounds only.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use time::Date;
+
+/// Identifies one day of one round in one area.
+type SlotKey = (i64, i64, Date);
+
+/// Identifies one crew's share of one day of one round in one area.
+type CrewSlotKey = (i64, i64, Date, Option<u8>);
+
+/// The leave slots on one day of one round in one area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailySlots {
+    /// The round's configured `slots_per_day`.
+    pub slots_per_day: u32,
+    /// Whether this day is a prime date.
+    pub prime: bool,
+    /// The round's cap on prime dates, when this is a prime date and the
+    /// round has one. It replaces `slots_per_day`.
+    pub prime_slots_per_day: Option<u32>,
+    /// The staffing adjustment applied on this day.
+    pub staffing_adjustment: i32,
+    /// The approved leave already held on this day.
+    pub used: u32,
+}
+
+impl DailySlots {
+    /// Returns the day's capacity after the staffing adjustment.
+    ///
+    /// Capacity never drops below zero.
+    #[must_use]
+    pub const fn capacity(&self) -> u32 {
+        let base: u32 = match self.prime_slots_per_day {
+            Some(prime_slots_per_day) => prime_slots_per_day,
+            None => self.slots_per_day,
+        };
+        base.saturating_add_signed(self.staffing_adjustment)
+    }
+
+    /// Returns the slots still open on this day.
+    #[must_use]
+    pub const fn remaining(&self) -> u32 {
+        self.capacity().saturating_sub(self.used)
+    }
+
+    /// Returns whether every slot on this day is taken.
+    #[must_use]
+    pub const fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+}
+
+/// The leave slots for a set of rounds over a date range.
+///
+/// Built from each round's configuration, the bid year's prime dates, the
+/// stored staffing adjustments, and the approved leave counts. Days without
+/// an adjustment or leave fall back to the round's `slots_per_day`, or its
+/// prime cap on a prime date, with nothing used.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SlotInventory {
+    /// `slots_per_day` by round ID.
+    round_slots: BTreeMap<i64, u32>,
+    /// Slots per prime date by round ID, for rounds that cap them.
+    prime_caps: BTreeMap<i64, u32>,
+    /// The bid year's prime dates.
+    prime_dates: BTreeSet<Date>,
+    /// Staffing adjustments by area, round, and day.
+    adjustments: BTreeMap<SlotKey, i32>,
+    /// Approved leave by area, round, and day.
+    used: BTreeMap<SlotKey, u32>,
+    /// The rounds that allocate slots per crew.
+    crew_partitioned: BTreeSet<i64>,
+    /// Daily slots by round ID and crew, for crew-partitioned rounds.
+    crew_slots: BTreeMap<(i64, u8), u32>,
+    /// Approved leave by area, round, day, and crew.
+    crew_used: BTreeMap<CrewSlotKey, u32>,
+}
+
+impl SlotInventory {
+    /// Creates an empty inventory.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a round's configured daily slots.
+    pub fn set_round_slots(&mut self, round_id: i64, slots_per_day: u32) {
+        self.round_slots.insert(round_id, slots_per_day);
+    }
+
+    /// Records a round's cap on prime dates.
+    pub fn set_prime_cap(&mut self, round_id: i64, prime_slots_per_day: u32) {
+        self.prime_caps.insert(round_id, prime_slots_per_day);
+    }
+
+    /// Designates a prime date.
+    pub fn add_prime_date(&mut self, date: Date) {
+        self.prime_dates.insert(date);
+    }
+
+    /// Records the staffing adjustment for one day.
+    pub fn set_adjustment(&mut self, area_id: i64, round_id: i64, date: Date, adjustment: i32) {
+        self.adjustments
+            .insert((area_id, round_id, date), adjustment);
+    }
+
+    /// Marks a round as allocating its slots per crew.
+    pub fn set_crew_partitioned(&mut self, round_id: i64) {
+        self.crew_partitioned.insert(round_id);
+    }
+
+    /// Records one crew's daily slots in a crew-partitioned round.
+    pub fn set_crew_slots(&mut self, round_id: i64, crew: u8, slots_per_day: u32) {
+        self.crew_slots.insert((round_id, crew), slots_per_day);
+    }
+
+    /// Adds approved leave held on one day by users of one crew.
+    pub fn add_used(
+        &mut self,
+        area_id: i64,
+        round_id: i64,
+        date: Date,
+        crew: Option<u8>,
+        count: u32,
+    ) {
+        let used: &mut u32 = self.used.entry((area_id, round_id, date)).or_insert(0);
+        *used = used.saturating_add(count);
+        let crew_used: &mut u32 = self
+            .crew_used
+            .entry((area_id, round_id, date, crew))
+            .or_insert(0);
+        *crew_used = crew_used.saturating_add(count);
+    }
+
+    /// Returns whether a round allocates its slots per crew.
+    #[must_use]
+    pub fn is_crew_partitioned(&self, round_id: i64) -> bool {
+        self.crew_partitioned.contains(&round_id)
+    }
+
+    /// Returns the slots on one day of one round in one area.
+    ///
+    /// A round whose configuration was never recorded has no slots. For a
+    /// crew-partitioned round these are the totals across every crew.
+    #[must_use]
+    pub fn slots(&self, area_id: i64, round_id: i64, date: Date) -> DailySlots {
+        let key: SlotKey = (area_id, round_id, date);
+        let prime: bool = self.prime_dates.contains(&date);
+        if self.is_crew_partitioned(round_id) {
+            return DailySlots {
+                slots_per_day: self
+                    .crew_slots
+                    .range((round_id, u8::MIN)..=(round_id, u8::MAX))
+                    .map(|(_, slots)| *slots)
+                    .fold(0, u32::saturating_add),
+                prime,
+                prime_slots_per_day: None,
+                staffing_adjustment: 0,
+                used: self.used.get(&key).copied().unwrap_or(0),
+            };
+        }
+        DailySlots {
+            slots_per_day: self.round_slots.get(&round_id).copied().unwrap_or(0),
+            prime,
+            prime_slots_per_day: prime
+                .then(|| self.prime_caps.get(&round_id).copied())
+                .flatten(),
+            staffing_adjustment: self.adjustments.get(&key).copied().unwrap_or(0),
+            used: self.used.get(&key).copied().unwrap_or(0),
+        }
+    }
+
+    /// Returns the slots on one day of one round in one area available to
+    /// a user of the given crew.
+    ///
+    /// Area-wide rounds share their slots across crews, so this is the
+    /// same as [`SlotInventory::slots`]. On a crew-partitioned round only
+    /// the crew's own slots and leave count; users without a crew, and
+    /// crews without an allocation, have no slots.
+    #[must_use]
+    pub fn slots_for_crew(
+        &self,
+        area_id: i64,
+        round_id: i64,
+        date: Date,
+        crew: Option<u8>,
+    ) -> DailySlots {
+        if !self.is_crew_partitioned(round_id) {
+            return self.slots(area_id, round_id, date);
+        }
+        DailySlots {
+            slots_per_day: crew
+                .and_then(|crew| self.crew_slots.get(&(round_id, crew)).copied())
+                .unwrap_or(0),
+            prime: self.prime_dates.contains(&date),
+            prime_slots_per_day: None,
+            staffing_adjustment: 0,
+            used: self
+                .crew_used
+                .get(&(area_id, round_id, date, crew))
+                .copied()
+                .unwrap_or(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::date;
+
+    #[test]
+    fn test_unadjusted_day_uses_round_configuration() {
+        let mut inventory: SlotInventory = SlotInventory::new();
+        inventory.set_round_slots(7, 3);
+        inventory.add_used(1, 7, date!(2026 - 06 - 01), Some(1), 2);
+
+        let slots: DailySlots = inventory.slots(1, 7, date!(2026 - 06 - 01));
+        assert_eq!(slots.capacity(), 3);
+        assert_eq!(slots.remaining(), 1);
+        assert!(!slots.is_exhausted());
+
+        // Other areas and days are untouched
+        assert_eq!(inventory.slots(2, 7, date!(2026 - 06 - 01)).remaining(), 3);
+        assert_eq!(inventory.slots(1, 7, date!(2026 - 06 - 02)).remaining(), 3);
+        assert_eq!(inventory.slots(1, 8, date!(2026 - 06 - 01)).capacity(), 0);
+    }
+
+    #[test]
+    fn test_staffing_adjustment_changes_capacity() {
+        let mut inventory: SlotInventory = SlotInventory::new();
+        inventory.set_round_slots(7, 2);
+        inventory.set_adjustment(1, 7, date!(2026 - 06 - 01), 1);
+        inventory.set_adjustment(1, 7, date!(2026 - 06 - 02), -5);
+        inventory.add_used(1, 7, date!(2026 - 06 - 02), None, 1);
+
+        assert_eq!(inventory.slots(1, 7, date!(2026 - 06 - 01)).capacity(), 3);
+
+        let cut: DailySlots = inventory.slots(1, 7, date!(2026 - 06 - 02));
+        assert_eq!(cut.capacity(), 0);
+        assert_eq!(cut.remaining(), 0);
+        assert!(cut.is_exhausted());
+    }
+
+    #[test]
+    fn test_prime_cap_replaces_round_slots_on_prime_dates() {
+        let mut inventory: SlotInventory = SlotInventory::new();
+        inventory.set_round_slots(7, 3);
+        inventory.set_round_slots(8, 3);
+        inventory.set_prime_cap(7, 1);
+        inventory.add_prime_date(date!(2026 - 12 - 24));
+        inventory.set_adjustment(1, 7, date!(2026 - 12 - 24), 1);
+
+        let prime: DailySlots = inventory.slots(1, 7, date!(2026 - 12 - 24));
+        assert!(prime.prime);
+        assert_eq!(prime.prime_slots_per_day, Some(1));
+        assert_eq!(prime.capacity(), 2);
+
+        // Uncapped rounds and ordinary days keep their normal slots
+        let uncapped: DailySlots = inventory.slots(1, 8, date!(2026 - 12 - 24));
+        assert!(uncapped.prime);
+        assert_eq!(uncapped.capacity(), 3);
+        assert!(!inventory.slots(1, 7, date!(2026 - 12 - 23)).prime);
+        assert_eq!(inventory.slots(1, 7, date!(2026 - 12 - 23)).capacity(), 3);
+    }
+
+    #[test]
+    fn test_crew_partitioned_round_checks_each_crew() {
+        let mut inventory: SlotInventory = SlotInventory::new();
+        inventory.set_round_slots(7, 5);
+        inventory.set_crew_partitioned(7);
+        inventory.set_crew_slots(7, 1, 2);
+        inventory.set_crew_slots(7, 2, 1);
+        inventory.set_crew_slots(8, 1, 9);
+        inventory.set_adjustment(1, 7, date!(2026 - 06 - 01), 3);
+        inventory.add_used(1, 7, date!(2026 - 06 - 01), Some(2), 1);
+
+        // Crew 2's single slot is taken while crew 1 still has two
+        let crew_two: DailySlots = inventory.slots_for_crew(1, 7, date!(2026 - 06 - 01), Some(2));
+        assert!(crew_two.is_exhausted());
+        let crew_one: DailySlots = inventory.slots_for_crew(1, 7, date!(2026 - 06 - 01), Some(1));
+        assert_eq!(crew_one.remaining(), 2);
+        assert_eq!(
+            inventory
+                .slots_for_crew(1, 7, date!(2026 - 06 - 01), Some(3))
+                .capacity(),
+            0
+        );
+        assert_eq!(
+            inventory
+                .slots_for_crew(1, 7, date!(2026 - 06 - 01), None)
+                .capacity(),
+            0
+        );
+
+        // The area-wide view totals the crews and ignores the adjustment
+        let total: DailySlots = inventory.slots(1, 7, date!(2026 - 06 - 01));
+        assert_eq!(total.capacity(), 3);
+        assert_eq!(total.used, 1);
+
+        // Rounds that are not partitioned share their slots across crews
+        inventory.set_round_slots(8, 1);
+        inventory.add_used(1, 8, date!(2026 - 06 - 01), Some(1), 1);
+        assert!(
+            inventory
+                .slots_for_crew(1, 8, date!(2026 - 06 - 01), Some(2))
+                .is_exhausted()
+        );
+    }
+}
@@ -315,7 +315,8 @@ impl BidSchedule {
 /// Represents a user's initials.
 ///
 /// Initials are the sole identifier for a user within a bid year.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// They order alphabetically, which is how an area's users are iterated.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Initials {
     /// The initials value (exactly 2 characters).
     value: String,
@@ -0,0 +1,207 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Projected end-of-year leave balances.
+//!
+//! Annual leave above the facility's carryover cap at the end of the leave
+//! year is forfeited ("use or lose"). The projection starts from the hours
+//! carried in from the prior year, adds the year's accrual, and subtracts
+//! the leave already awarded. Whatever remains above the cap is still at
+//! risk of being lost. Leave still being requested is checked against the
+//! same balance: requests the user cannot cover are overdrawn.
+
+use crate::error::DomainError;
+use crate::leave_accrual::LeaveAccrualResult;
+
+/// The default annual leave carryover cap, in hours.
+pub const DEFAULT_CARRYOVER_CAP_HOURS: u16 = 240;
+
+/// A facility's annual leave carryover cap for a bid year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaveCapPolicy {
+    carryover_cap_hours: u16,
+}
+
+impl Default for LeaveCapPolicy {
+    fn default() -> Self {
+        Self {
+            carryover_cap_hours: DEFAULT_CARRYOVER_CAP_HOURS,
+        }
+    }
+}
+
+impl LeaveCapPolicy {
+    /// Creates a carryover cap policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cap is zero.
+    pub fn new(carryover_cap_hours: u16) -> Result<Self, DomainError> {
+        if carryover_cap_hours == 0 {
+            return Err(DomainError::InvalidLeaveCap {
+                reason: String::from("The carryover cap must be at least one hour"),
+            });
+        }
+        Ok(Self {
+            carryover_cap_hours,
+        })
+    }
+
+    /// Returns the most leave, in hours, that may carry into the next year.
+    #[must_use]
+    pub const fn carryover_cap_hours(&self) -> u16 {
+        self.carryover_cap_hours
+    }
+}
+
+/// A user's projected annual leave balance at the end of the bid year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaveBalanceProjection {
+    /// Hours carried in from the prior year, after applying the cap.
+    pub carried_in_hours: u16,
+    /// Prior-year hours above the cap that did not carry in.
+    pub forfeited_carryover_hours: u16,
+    /// Hours accrued during the bid year.
+    pub accrued_hours: u16,
+    /// Hours of leave already awarded.
+    pub awarded_hours: u32,
+    /// Hours of leave requested in pending bids.
+    pub pending_hours: u32,
+    /// Hours left at year end. Negative when more leave was awarded than
+    /// the user will have.
+    pub projected_balance_hours: i64,
+    /// Projected hours above the carryover cap that will be lost unless
+    /// used.
+    pub use_or_lose_hours: u32,
+    /// Hours of awarded and pending leave beyond what the user will have
+    /// carried in and accrued.
+    pub overdrawn_hours: u32,
+}
+
+impl LeaveBalanceProjection {
+    /// Returns whether the user is projected to lose leave at year end.
+    #[must_use]
+    pub const fn is_over_threshold(&self) -> bool {
+        self.use_or_lose_hours > 0
+    }
+
+    /// Returns whether the user's awarded and pending leave exceeds their
+    /// projected accrual.
+    #[must_use]
+    pub const fn exceeds_accrual(&self) -> bool {
+        self.overdrawn_hours > 0
+    }
+}
+
+/// Projects a user's annual leave balance at the end of the bid year.
+///
+/// # Arguments
+///
+/// * `policy` - The facility's carryover cap
+/// * `prior_balance_hours` - The user's balance at the end of the prior year
+/// * `accrual` - The user's accrual for the bid year
+/// * `awarded_hours` - Hours of leave awarded to the user in the bid year
+/// * `pending_hours` - Hours of leave the user has requested but not yet
+///   been awarded
+#[must_use]
+pub fn project_leave_balance(
+    policy: LeaveCapPolicy,
+    prior_balance_hours: u16,
+    accrual: &LeaveAccrualResult,
+    awarded_hours: u32,
+    pending_hours: u32,
+) -> LeaveBalanceProjection {
+    let cap: u16 = policy.carryover_cap_hours();
+    let carried_in_hours: u16 = prior_balance_hours.min(cap);
+    let projected_balance_hours: i64 =
+        i64::from(carried_in_hours) + i64::from(accrual.total_hours) - i64::from(awarded_hours);
+    let use_or_lose_hours: u32 =
+        u32::try_from((projected_balance_hours - i64::from(cap)).max(0)).unwrap_or(u32::MAX);
+    let overdrawn_hours: u32 =
+        u32::try_from((i64::from(pending_hours) - projected_balance_hours).max(0))
+            .unwrap_or(u32::MAX);
+
+    LeaveBalanceProjection {
+        carried_in_hours,
+        forfeited_carryover_hours: prior_balance_hours - carried_in_hours,
+        accrued_hours: accrual.total_hours,
+        awarded_hours,
+        pending_hours,
+        projected_balance_hours,
+        use_or_lose_hours,
+        overdrawn_hours,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn accrual(total_hours: u16) -> LeaveAccrualResult {
+        LeaveAccrualResult {
+            total_hours,
+            total_days: total_hours / 8,
+            rounded_up: false,
+            breakdown: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_zero_cap_is_rejected() {
+        assert!(matches!(
+            LeaveCapPolicy::new(0),
+            Err(DomainError::InvalidLeaveCap { .. })
+        ));
+        assert_eq!(
+            LeaveCapPolicy::default().carryover_cap_hours(),
+            DEFAULT_CARRYOVER_CAP_HOURS
+        );
+    }
+
+    #[test]
+    fn test_prior_balance_above_cap_is_forfeited() {
+        let projection: LeaveBalanceProjection =
+            project_leave_balance(LeaveCapPolicy::default(), 300, &accrual(208), 208, 0);
+        assert_eq!(projection.carried_in_hours, 240);
+        assert_eq!(projection.forfeited_carryover_hours, 60);
+        assert_eq!(projection.projected_balance_hours, 240);
+        assert!(!projection.is_over_threshold());
+    }
+
+    #[test]
+    fn test_unawarded_leave_above_cap_is_use_or_lose() {
+        let policy: LeaveCapPolicy = LeaveCapPolicy::new(160).unwrap();
+        let projection: LeaveBalanceProjection =
+            project_leave_balance(policy, 120, &accrual(208), 120, 0);
+        assert_eq!(projection.projected_balance_hours, 208);
+        assert_eq!(projection.use_or_lose_hours, 48);
+        assert!(projection.is_over_threshold());
+    }
+
+    #[test]
+    fn test_overdrawn_balance_is_negative() {
+        let projection: LeaveBalanceProjection =
+            project_leave_balance(LeaveCapPolicy::default(), 0, &accrual(104), 160, 0);
+        assert_eq!(projection.projected_balance_hours, -56);
+        assert_eq!(projection.use_or_lose_hours, 0);
+        assert_eq!(projection.overdrawn_hours, 56);
+        assert!(projection.exceeds_accrual());
+    }
+
+    #[test]
+    fn test_pending_leave_beyond_balance_is_overdrawn() {
+        let projection: LeaveBalanceProjection =
+            project_leave_balance(LeaveCapPolicy::default(), 16, &accrual(104), 80, 56);
+        assert_eq!(projection.projected_balance_hours, 40);
+        assert_eq!(projection.overdrawn_hours, 16);
+        assert!(projection.exceeds_accrual());
+
+        let projection: LeaveBalanceProjection =
+            project_leave_balance(LeaveCapPolicy::default(), 16, &accrual(104), 80, 40);
+        assert_eq!(projection.overdrawn_hours, 0);
+        assert!(!projection.exceeds_accrual());
+    }
+}
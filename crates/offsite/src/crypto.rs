@@ -0,0 +1,167 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Backup encryption.
+//!
+//! Backups are sealed with AES-256-GCM before they leave the server, so the
+//! bucket operator never sees bidder data and a modified object fails to
+//! decrypt instead of restoring silently. An encrypted backup is laid out
+//! as:
+//!
+//! ```text
+//! ZABBAK01 | 12-byte nonce | ciphertext and 16-byte tag
+//! ```
+//!
+//! The key lives in server configuration and never in the bucket.
+
+use std::fmt;
+
+use aes_gcm::aead::{Aead, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+
+/// Length of a backup key, in bytes.
+pub const BACKUP_KEY_LENGTH: usize = 32;
+
+/// Marks the start of an encrypted backup, and names its format version.
+const MAGIC: &[u8; 8] = b"ZABBAK01";
+
+/// Length of the random nonce stored with each backup, in bytes.
+const NONCE_LENGTH: usize = 12;
+
+/// The key backups are encrypted with.
+///
+/// Redacted from `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BackupKey([u8; BACKUP_KEY_LENGTH]);
+
+impl BackupKey {
+    /// Creates a key from raw bytes.
+    #[must_use]
+    pub const fn new(bytes: [u8; BACKUP_KEY_LENGTH]) -> Self {
+        Self(bytes)
+    }
+
+    /// Encrypts a backup under a fresh random nonce.
+    #[must_use]
+    pub fn seal(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce: [u8; NONCE_LENGTH] = rand::random();
+        let ciphertext: Vec<u8> = self
+            .cipher()
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: plaintext,
+                    aad: MAGIC,
+                },
+            )
+            .unwrap_or_else(|_| unreachable!("AES-GCM encrypts any backup that fits in memory"));
+
+        let mut sealed: Vec<u8> = Vec::with_capacity(MAGIC.len() + NONCE_LENGTH + ciphertext.len());
+        sealed.extend_from_slice(MAGIC);
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(&ciphertext);
+        sealed
+    }
+
+    /// Decrypts a backup produced by [`BackupKey::seal`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not an encrypted backup, was
+    /// encrypted under a different key, or has been modified.
+    pub fn open(&self, sealed: &[u8]) -> Result<Vec<u8>, String> {
+        let body: &[u8] = sealed
+            .strip_prefix(MAGIC.as_slice())
+            .ok_or_else(|| String::from("Not an encrypted zabbid backup"))?;
+        if body.len() < NONCE_LENGTH {
+            return Err(String::from("Encrypted backup is truncated"));
+        }
+        let (nonce, ciphertext): (&[u8], &[u8]) = body.split_at(NONCE_LENGTH);
+
+        self.cipher()
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: MAGIC,
+                },
+            )
+            .map_err(|_| {
+                String::from("Backup could not be decrypted: wrong key or modified object")
+            })
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(&self.0.into())
+    }
+}
+
+impl std::str::FromStr for BackupKey {
+    type Err = String;
+
+    /// Parses a key written as 64 hex digits.
+    ///
+    /// The error never repeats the secret.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes: [u8; BACKUP_KEY_LENGTH] = [0; BACKUP_KEY_LENGTH];
+        hex::decode_to_slice(s, &mut bytes).map_err(|_| {
+            format!(
+                "Backup encryption key must be {} hex digits",
+                BACKUP_KEY_LENGTH * 2
+            )
+        })?;
+        Ok(Self(bytes))
+    }
+}
+
+impl fmt::Debug for BackupKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BackupKey(<redacted>)")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sealed_backup_opens_with_same_key() {
+        let key: BackupKey = BackupKey::new([7; BACKUP_KEY_LENGTH]);
+        let sealed: Vec<u8> = key.seal(b"bid year data");
+
+        assert!(sealed.starts_with(MAGIC));
+        assert_ne!(&sealed[MAGIC.len() + NONCE_LENGTH..], b"bid year data");
+        assert_eq!(key.open(&sealed).unwrap(), b"bid year data");
+    }
+
+    #[test]
+    fn test_wrong_key_or_modified_backup_is_rejected() {
+        let key: BackupKey = BackupKey::new([7; BACKUP_KEY_LENGTH]);
+        let mut sealed: Vec<u8> = key.seal(b"bid year data");
+
+        assert!(
+            BackupKey::new([8; BACKUP_KEY_LENGTH])
+                .open(&sealed)
+                .is_err()
+        );
+
+        let last: usize = sealed.len() - 1;
+        sealed[last] ^= 1;
+        assert!(key.open(&sealed).is_err());
+        assert!(key.open(b"plain sqlite file").is_err());
+    }
+
+    #[test]
+    fn test_key_parses_from_hex_without_echoing_it() {
+        let hex_key: String = "ab".repeat(BACKUP_KEY_LENGTH);
+        let key: BackupKey = hex_key.parse().unwrap();
+        assert_eq!(key, BackupKey::new([0xab; BACKUP_KEY_LENGTH]));
+        assert_eq!(format!("{key:?}"), "BackupKey(<redacted>)");
+
+        let error: String = "abcd".parse::<BackupKey>().unwrap_err();
+        assert!(!error.contains("abcd"));
+    }
+}
@@ -0,0 +1,400 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! # zab-bid-offsite - Encrypted offsite backups
+//!
+//! A single-box deployment keeps the whole bid year on one disk. This crate
+//! copies backups to an S3-compatible bucket (AWS S3, `MinIO`, ...) so the
+//! year survives losing that disk. The server uploads on a schedule and
+//! `zabbid-cli restore-from-s3` brings a backup back.
+//!
+//! Every object is encrypted with a [`BackupKey`] before upload. Objects
+//! are stored under the configured prefix as:
+//!
+//! ```text
+//! database/zabbid-<YYYYMMDDTHHMMSSZ>.sqlite.enc
+//! bundles/<year>/zabbid-<year>-<YYYYMMDDTHHMMSSZ>.zab.enc
+//! ```
+//!
+//! Timestamps sort lexically, so the newest backup in a directory is the
+//! last key, and retention keeps the last N keys of each directory.
+
+#![deny(
+    clippy::pedantic,
+    clippy::cargo,
+    clippy::nursery,
+    clippy::style,
+    clippy::correctness,
+    clippy::all,
+    clippy::suspicious,
+    clippy::complexity,
+    clippy::perf,
+    clippy::unwrap_used,
+    clippy::expect_used
+)]
+#![allow(clippy::multiple_crate_versions)]
+
+mod crypto;
+mod s3;
+
+use futures::future::BoxFuture;
+use time::OffsetDateTime;
+use time::macros::format_description;
+
+pub use crypto::{BACKUP_KEY_LENGTH, BackupKey};
+pub use s3::S3Client;
+
+/// Directory holding database backups.
+pub const DATABASE_DIR: &str = "database/";
+
+/// Returns the directory holding a bid year's bundles.
+#[must_use]
+pub fn bundle_dir(year: u16) -> String {
+    format!("bundles/{year}/")
+}
+
+/// Returns the name of a database backup taken at `at`.
+#[must_use]
+pub fn database_backup_name(at: OffsetDateTime) -> String {
+    format!("{DATABASE_DIR}zabbid-{}.sqlite.enc", timestamp(at))
+}
+
+/// Returns the name of a bid year bundle exported at `at`.
+#[must_use]
+pub fn bundle_backup_name(year: u16, at: OffsetDateTime) -> String {
+    format!(
+        "{}zabbid-{year}-{}.zab.enc",
+        bundle_dir(year),
+        timestamp(at)
+    )
+}
+
+fn timestamp(at: OffsetDateTime) -> String {
+    at.to_offset(time::UtcOffset::UTC)
+        .format(format_description!(
+            "[year][month][day]T[hour][minute][second]Z"
+        ))
+        .unwrap_or_else(|_| unreachable!("a UTC timestamp always formats"))
+}
+
+/// Object storage backups are written to.
+pub trait ObjectStore: Send + Sync {
+    /// Writes an object, replacing any with the same key.
+    fn put<'a>(&'a self, key: &'a str, body: Vec<u8>) -> BoxFuture<'a, Result<(), String>>;
+
+    /// Reads an object.
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Vec<u8>, String>>;
+
+    /// Lists the keys that start with `prefix`.
+    fn list<'a>(&'a self, prefix: &'a str) -> BoxFuture<'a, Result<Vec<String>, String>>;
+
+    /// Deletes an object.
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), String>>;
+}
+
+/// S3 bucket settings.
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct S3Args {
+    /// S3 endpoint URL, e.g. `https://s3.us-east-1.amazonaws.com` or a
+    /// `MinIO` server at `http://minio:9000`. Offsite backups are off when
+    /// omitted.
+    #[arg(long)]
+    pub s3_endpoint: Option<String>,
+
+    /// Bucket backups are stored in (required with --s3-endpoint)
+    #[arg(long)]
+    pub s3_bucket: Option<String>,
+
+    /// Region requests are signed for
+    #[arg(long, default_value = "us-east-1")]
+    pub s3_region: String,
+
+    /// Access key ID (required with --s3-endpoint)
+    #[arg(long)]
+    pub s3_access_key: Option<String>,
+
+    /// Secret access key (required with --s3-endpoint)
+    #[arg(long)]
+    pub s3_secret_key: Option<String>,
+
+    /// Key prefix backups are stored under, e.g. `zabbid/`
+    #[arg(long, default_value = "")]
+    pub s3_prefix: String,
+
+    /// Key backups are encrypted with, as 64 hex digits (required with
+    /// --s3-endpoint). Keep a copy away from the server: backups cannot be
+    /// restored without it.
+    #[arg(long)]
+    pub backup_encryption_key: Option<BackupKey>,
+}
+
+impl S3Args {
+    /// Validates the bucket settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an endpoint is configured without a bucket,
+    /// credentials, or encryption key.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.s3_endpoint.is_none() {
+            return Ok(());
+        }
+        if self.s3_bucket.as_deref().is_none_or(str::is_empty) {
+            return Err(String::from("--s3-endpoint requires --s3-bucket"));
+        }
+        if self.s3_access_key.is_none() || self.s3_secret_key.is_none() {
+            return Err(String::from(
+                "--s3-endpoint requires --s3-access-key and --s3-secret-key",
+            ));
+        }
+        if self.backup_encryption_key.is_none() {
+            return Err(String::from(
+                "--s3-endpoint requires --backup-encryption-key",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Encrypted backups in an object store.
+pub struct OffsiteBackups {
+    store: Box<dyn ObjectStore>,
+    key: BackupKey,
+    prefix: String,
+}
+
+impl OffsiteBackups {
+    /// Stores backups in `store` under `prefix`, encrypted with `key`.
+    #[must_use]
+    pub fn new(store: Box<dyn ObjectStore>, key: BackupKey, prefix: &str) -> Self {
+        Self {
+            store,
+            key,
+            prefix: prefix.to_string(),
+        }
+    }
+
+    /// Connects to the configured S3 bucket.
+    ///
+    /// # Returns
+    ///
+    /// `None` if no S3 endpoint is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the settings are invalid.
+    pub fn from_args(args: &S3Args) -> Result<Option<Self>, String> {
+        args.validate()?;
+        let (Some(endpoint), Some(bucket), Some(access_key), Some(secret_key), Some(key)) = (
+            args.s3_endpoint.as_deref(),
+            args.s3_bucket.as_deref(),
+            args.s3_access_key.as_deref(),
+            args.s3_secret_key.as_deref(),
+            args.backup_encryption_key.clone(),
+        ) else {
+            return Ok(None);
+        };
+        let client: S3Client =
+            S3Client::new(endpoint, bucket, &args.s3_region, access_key, secret_key)?;
+
+        Ok(Some(Self::new(Box::new(client), key, &args.s3_prefix)))
+    }
+
+    /// Encrypts and uploads a backup.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the upload fails.
+    pub async fn upload(&self, name: &str, plaintext: &[u8]) -> Result<(), String> {
+        let key: String = format!("{}{name}", self.prefix);
+        self.store.put(&key, self.key.seal(plaintext)).await
+    }
+
+    /// Downloads and decrypts a backup.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the download fails or the backup cannot be
+    /// decrypted.
+    pub async fn download(&self, name: &str) -> Result<Vec<u8>, String> {
+        let key: String = format!("{}{name}", self.prefix);
+        let sealed: Vec<u8> = self.store.get(&key).await?;
+        self.key.open(&sealed).map_err(|e| format!("{name}: {e}"))
+    }
+
+    /// Lists the backups in `dir`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listing fails.
+    pub async fn list(&self, dir: &str) -> Result<Vec<String>, String> {
+        let keys: Vec<String> = self.store.list(&format!("{}{dir}", self.prefix)).await?;
+        let mut names: Vec<String> = keys
+            .into_iter()
+            .filter_map(|key| key.strip_prefix(&self.prefix).map(str::to_string))
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    /// Deletes all but the newest `keep` backups in `dir`.
+    ///
+    /// # Returns
+    ///
+    /// The number of backups deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listing or a deletion fails.
+    pub async fn rotate(&self, dir: &str, keep: usize) -> Result<usize, String> {
+        let names: Vec<String> = self.list(dir).await?;
+        let expired: &[String] = &names[..names.len().saturating_sub(keep)];
+        for name in expired {
+            self.store.delete(&format!("{}{name}", self.prefix)).await?;
+        }
+        Ok(expired.len())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+    use time::macros::datetime;
+
+    type Objects = std::sync::Arc<Mutex<BTreeMap<String, Vec<u8>>>>;
+
+    /// An in-memory bucket.
+    #[derive(Default)]
+    struct MemoryStore(Objects);
+
+    impl ObjectStore for MemoryStore {
+        fn put<'a>(&'a self, key: &'a str, body: Vec<u8>) -> BoxFuture<'a, Result<(), String>> {
+            self.0.lock().unwrap().insert(key.to_string(), body);
+            Box::pin(async { Ok(()) })
+        }
+
+        fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Vec<u8>, String>> {
+            let body: Option<Vec<u8>> = self.0.lock().unwrap().get(key).cloned();
+            Box::pin(async move { body.ok_or_else(|| format!("{key} not found")) })
+        }
+
+        fn list<'a>(&'a self, prefix: &'a str) -> BoxFuture<'a, Result<Vec<String>, String>> {
+            let keys: Vec<String> = self
+                .0
+                .lock()
+                .unwrap()
+                .keys()
+                .filter(|key| key.starts_with(prefix))
+                .cloned()
+                .collect();
+            Box::pin(async { Ok(keys) })
+        }
+
+        fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), String>> {
+            self.0.lock().unwrap().remove(key);
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    fn backups() -> (OffsiteBackups, Objects) {
+        let store: MemoryStore = MemoryStore::default();
+        let objects = std::sync::Arc::clone(&store.0);
+        let backups: OffsiteBackups = OffsiteBackups::new(
+            Box::new(store),
+            BackupKey::new([1; BACKUP_KEY_LENGTH]),
+            "zabbid/",
+        );
+        (backups, objects)
+    }
+
+    #[test]
+    fn test_backup_names_sort_by_time() {
+        let earlier: String = database_backup_name(datetime!(2026-01-27 09:00:00 UTC));
+        let later: String = database_backup_name(datetime!(2026-01-27 10:00:00 +05:00));
+        assert_eq!(earlier, "database/zabbid-20260127T090000Z.sqlite.enc");
+        assert!(later < earlier);
+        assert_eq!(
+            bundle_backup_name(2026, datetime!(2026-01-27 09:00:00 UTC)),
+            "bundles/2026/zabbid-2026-20260127T090000Z.zab.enc"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_uploads_are_encrypted_under_prefix() {
+        let (backups, objects) = backups();
+        let name: String = database_backup_name(datetime!(2026-01-27 09:00:00 UTC));
+
+        backups.upload(&name, b"sqlite bytes").await.unwrap();
+
+        let stored: Vec<u8> = objects.lock().unwrap()[&format!("zabbid/{name}")].clone();
+        assert!(!stored.windows(6).any(|window| window == b"sqlite"));
+        assert_eq!(backups.download(&name).await.unwrap(), b"sqlite bytes");
+        assert_eq!(backups.list(DATABASE_DIR).await.unwrap(), vec![name]);
+    }
+
+    #[tokio::test]
+    async fn test_rotation_keeps_newest_per_directory() {
+        let (backups, _objects) = backups();
+        for hour in [9, 7, 8] {
+            let at: OffsetDateTime =
+                datetime!(2026-01-27 00:00:00 UTC) + time::Duration::hours(hour);
+            backups
+                .upload(&database_backup_name(at), b"db")
+                .await
+                .unwrap();
+            backups
+                .upload(&bundle_backup_name(2026, at), b"bundle")
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(backups.rotate(DATABASE_DIR, 2).await.unwrap(), 1);
+        assert_eq!(
+            backups.list(DATABASE_DIR).await.unwrap(),
+            vec![
+                "database/zabbid-20260127T080000Z.sqlite.enc",
+                "database/zabbid-20260127T090000Z.sqlite.enc",
+            ]
+        );
+        assert_eq!(backups.list(&bundle_dir(2026)).await.unwrap().len(), 3);
+        assert_eq!(backups.rotate(&bundle_dir(2026), 5).await.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_endpoint_requires_bucket_credentials_and_key() {
+        assert!(S3Args::default().validate().is_ok());
+
+        let mut args: S3Args = S3Args {
+            s3_endpoint: Some(String::from("http://minio:9000")),
+            s3_bucket: Some(String::from("backups")),
+            s3_region: String::from("us-east-1"),
+            s3_access_key: Some(String::from("zabbid")),
+            s3_secret_key: Some(String::from("secret")),
+            s3_prefix: String::new(),
+            backup_encryption_key: Some(BackupKey::new([1; BACKUP_KEY_LENGTH])),
+        };
+        assert!(args.validate().is_ok());
+        assert!(OffsiteBackups::from_args(&args).unwrap().is_some());
+
+        args.backup_encryption_key = None;
+        assert!(args.validate().is_err());
+        args.backup_encryption_key = Some(BackupKey::new([1; BACKUP_KEY_LENGTH]));
+
+        args.s3_secret_key = None;
+        assert!(args.validate().is_err());
+        args.s3_secret_key = Some(String::from("secret"));
+
+        args.s3_bucket = None;
+        assert!(args.validate().is_err());
+        assert!(
+            OffsiteBackups::from_args(&S3Args::default())
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,323 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Performance benchmarks for the persistence layer.
+//!
+//! These cover the paths that carry load during bid season: persisting
+//! transitions, reading the audit timeline of a busy area, reconstructing
+//! state from the latest snapshot, and importing a roster, plus a
+//! comparison of the snapshot encodings. Every benchmark runs against an
+//! in-memory `SQLite` database.
+//!
+//! Run with `cargo xtask bench`.
+
+#![allow(clippy::expect_used, clippy::unwrap_used, missing_docs)]
+
+use std::hint::black_box;
+
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use time::Date;
+use zab_bid::{
+    BootstrapMetadata, BootstrapResult, Command, State, TransitionResult, apply, apply_bootstrap,
+};
+use zab_bid_audit::{Actor, AuditEvent, Cause};
+use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, User, UserType};
+use zab_bid_persistence::{SnapshotEncoding, SqlitePersistence};
+
+const YEAR: u16 = 2026;
+const AREA: &str = "North";
+
+/// Timeline sizes, in audit events.
+const TIMELINE_SIZES: [usize; 2] = [10_000, 100_000];
+
+/// Events recorded after the latest snapshot in the replay benchmark.
+const EVENTS_AFTER_SNAPSHOT: usize = 1_000;
+
+/// Rows in the bulk import benchmark, matching a large facility.
+const ROSTER_SIZE: usize = 400;
+
+fn actor() -> Actor {
+    Actor::with_operator(
+        String::from("bench-actor"),
+        String::from("admin"),
+        1,
+        String::from("bench-operator"),
+        String::from("Bench Operator"),
+    )
+}
+
+fn cause() -> Cause {
+    Cause::new(String::from("bench"), String::from("Benchmark"))
+}
+
+fn metadata() -> BootstrapMetadata {
+    let mut metadata: BootstrapMetadata = BootstrapMetadata::new();
+    metadata.bid_years.push(BidYear::new(YEAR));
+    metadata.areas.push((BidYear::new(YEAR), Area::new(AREA)));
+    metadata
+}
+
+fn empty_state() -> State {
+    State::new(BidYear::new(YEAR), Area::new(AREA))
+}
+
+/// Creates an in-memory database with an operator, the bid year, and the area.
+fn bootstrapped_persistence() -> SqlitePersistence {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    persistence
+        .create_operator("bench-operator", "Bench Operator", "password", "Admin")
+        .unwrap();
+
+    let start_date: Date = Date::from_calendar_date(2026, time::Month::January, 4).unwrap();
+    let bid_year_result: BootstrapResult = apply_bootstrap(
+        &BootstrapMetadata::new(),
+        &BidYear::new(YEAR),
+        Command::CreateBidYear {
+            year: YEAR,
+            start_date,
+            num_pay_periods: 26,
+        },
+        actor(),
+        cause(),
+    )
+    .unwrap();
+    persistence.persist_bootstrap(&bid_year_result).unwrap();
+
+    let mut metadata: BootstrapMetadata = BootstrapMetadata::new();
+    metadata.bid_years.push(BidYear::new(YEAR));
+    let area_result: BootstrapResult = apply_bootstrap(
+        &metadata,
+        &BidYear::new(YEAR),
+        Command::CreateArea {
+            area_id: String::from(AREA),
+        },
+        actor(),
+        cause(),
+    )
+    .unwrap();
+    persistence.persist_bootstrap(&area_result).unwrap();
+
+    persistence
+}
+
+fn transition(state: &State, command: Command) -> TransitionResult {
+    apply(
+        &metadata(),
+        state,
+        &BidYear::new(YEAR),
+        command,
+        actor(),
+        cause(),
+    )
+    .unwrap()
+}
+
+/// Builds `count` chained `RegisterUser` transitions with unique initials.
+fn roster_transitions(count: usize) -> Vec<TransitionResult> {
+    let mut state: State = empty_state();
+    let mut results: Vec<TransitionResult> = Vec::with_capacity(count);
+    for index in 0..count {
+        let letter = |n: usize| char::from(b'A' + u8::try_from(n % 26).unwrap());
+        let initials: String = [letter(index / 26), letter(index)].iter().collect();
+        let result: TransitionResult = transition(
+            &state,
+            Command::RegisterUser {
+                initials: Initials::new(&initials),
+                name: format!("Controller {index}"),
+                area: Area::new(AREA),
+                user_type: UserType::CPC,
+                crew: Some(Crew::new(u8::try_from(index % 7).unwrap() + 1).unwrap()),
+                seniority_data: SeniorityData::new(
+                    String::from("2015-01-15"),
+                    String::from("2015-06-01"),
+                    String::from("2016-01-15"),
+                    String::from("2016-01-15"),
+                    Some(u32::try_from(index).unwrap() + 1),
+                ),
+            },
+        );
+        state = result.new_state.clone();
+        results.push(result);
+    }
+    results
+}
+
+/// Creates a bootstrapped database with a full roster, returning it with
+/// the area's current state.
+fn rostered_persistence() -> (SqlitePersistence, State) {
+    let mut persistence: SqlitePersistence = bootstrapped_persistence();
+    persistence
+        .persist_transitions(&roster_transitions(ROSTER_SIZE))
+        .unwrap();
+    let state: State = persistence
+        .get_current_state(&BidYear::new(YEAR), &Area::new(AREA))
+        .unwrap();
+    (persistence, state)
+}
+
+/// Appends `count` copies of a checkpoint audit event without snapshots.
+fn append_events(persistence: &mut SqlitePersistence, count: usize) {
+    let event: AuditEvent = transition(
+        &empty_state(),
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    )
+    .audit_event;
+    for _ in 0..count {
+        persistence.persist_audit_event(&event).unwrap();
+    }
+}
+
+fn persist_transition(c: &mut Criterion) {
+    let mut group = c.benchmark_group("persist_transition");
+    group.throughput(Throughput::Elements(1));
+
+    let mut persistence: SqlitePersistence = bootstrapped_persistence();
+    let checkpoint: TransitionResult = transition(
+        &empty_state(),
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+    );
+    group.bench_function("checkpoint_with_snapshot", |b| {
+        b.iter(|| {
+            persistence
+                .persist_transition(black_box(&checkpoint))
+                .unwrap()
+        });
+    });
+
+    let (mut persistence, state): (SqlitePersistence, State) = rostered_persistence();
+    let user: User = state.users.values().next().unwrap().clone();
+    let update: TransitionResult = transition(
+        &state,
+        Command::UpdateUser {
+            user_id: user.user_id.unwrap(),
+            initials: user.initials.clone(),
+            name: String::from("Renamed Controller"),
+            area: user.area.clone(),
+            user_type: user.user_type,
+            crew: user.crew,
+            seniority_data: user.seniority_data.clone(),
+        },
+    );
+    group.bench_function(BenchmarkId::new("update_user", ROSTER_SIZE), |b| {
+        b.iter(|| persistence.persist_transition(black_box(&update)).unwrap());
+    });
+
+    group.finish();
+}
+
+fn timeline_reads(c: &mut Criterion) {
+    let mut group = c.benchmark_group("audit_timeline");
+    group.sample_size(10);
+
+    for size in TIMELINE_SIZES {
+        let mut persistence: SqlitePersistence = bootstrapped_persistence();
+        append_events(&mut persistence, size);
+        let bid_year: BidYear = BidYear::new(YEAR);
+        let area: Area = Area::new(AREA);
+
+        group.throughput(Throughput::Elements(u64::try_from(size).unwrap()));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| {
+                let timeline: Vec<AuditEvent> =
+                    persistence.get_audit_timeline(&bid_year, &area).unwrap();
+                black_box(timeline)
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn snapshot_replay(c: &mut Criterion) {
+    let (mut persistence, state): (SqlitePersistence, State) = rostered_persistence();
+    let bid_year: BidYear = BidYear::new(YEAR);
+    let area: Area = Area::new(AREA);
+    persistence
+        .persist_transition(&transition(
+            &state,
+            Command::Checkpoint {
+                label: None,
+                description: None,
+            },
+        ))
+        .unwrap();
+    append_events(&mut persistence, EVENTS_AFTER_SNAPSHOT);
+
+    c.bench_function("snapshot_load_and_replay", |b| {
+        b.iter(|| {
+            let (snapshot, snapshot_event_id): (State, i64) =
+                persistence.get_latest_snapshot(&bid_year, &area).unwrap();
+            let events: Vec<AuditEvent> = persistence
+                .get_events_after(&bid_year, &area, snapshot_event_id)
+                .unwrap();
+            black_box((snapshot, events))
+        });
+    });
+}
+
+/// Writes and reads a full-roster snapshot in each encoding.
+fn snapshot_encodings(c: &mut Criterion) {
+    let mut group = c.benchmark_group("snapshot_encoding");
+    let bid_year: BidYear = BidYear::new(YEAR);
+    let area: Area = Area::new(AREA);
+
+    for encoding in [SnapshotEncoding::Json, SnapshotEncoding::Postcard] {
+        let (mut persistence, state): (SqlitePersistence, State) = rostered_persistence();
+        persistence.set_snapshot_encoding(encoding);
+        let checkpoint: TransitionResult = transition(
+            &state,
+            Command::Checkpoint {
+                label: None,
+                description: None,
+            },
+        );
+
+        group.bench_function(BenchmarkId::new("write", encoding.as_str()), |b| {
+            b.iter(|| {
+                persistence
+                    .persist_transition(black_box(&checkpoint))
+                    .unwrap()
+            });
+        });
+        group.bench_function(BenchmarkId::new("read", encoding.as_str()), |b| {
+            b.iter(|| persistence.get_latest_snapshot(&bid_year, &area).unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+fn bulk_import(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bulk_import");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(u64::try_from(ROSTER_SIZE).unwrap()));
+
+    let transitions: Vec<TransitionResult> = roster_transitions(ROSTER_SIZE);
+    group.bench_function(BenchmarkId::from_parameter(ROSTER_SIZE), |b| {
+        b.iter_batched(
+            bootstrapped_persistence,
+            |mut persistence| persistence.persist_transitions(&transitions).unwrap(),
+            BatchSize::PerIteration,
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    persist_transition,
+    timeline_reads,
+    snapshot_replay,
+    snapshot_encodings,
+    bulk_import
+);
+criterion_main!(benches);
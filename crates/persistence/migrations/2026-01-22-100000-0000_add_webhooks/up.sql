@@ -0,0 +1,25 @@
+-- Outbound webhook subscriptions for audit events
+CREATE TABLE webhooks (
+    webhook_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    event_filter TEXT NOT NULL,
+    is_enabled INTEGER NOT NULL DEFAULT 1 CHECK(is_enabled IN (0, 1)),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Deliveries that exhausted their retries
+CREATE TABLE webhook_dead_letters (
+    dead_letter_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    webhook_id INTEGER NOT NULL,
+    audit_event_id INTEGER NOT NULL,
+    payload TEXT NOT NULL,
+    attempts INTEGER NOT NULL,
+    last_error TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(webhook_id) REFERENCES webhooks(webhook_id),
+    FOREIGN KEY(audit_event_id) REFERENCES audit_events(event_id)
+);
+
+-- Index for listing dead letters per webhook
+CREATE INDEX idx_webhook_dead_letters_webhook ON webhook_dead_letters(webhook_id);
@@ -0,0 +1,6 @@
+-- Drop indexes first
+DROP INDEX IF EXISTS idx_notification_log_user;
+
+-- Drop tables
+DROP TABLE IF EXISTS notification_log;
+DROP TABLE IF EXISTS user_contacts;
@@ -0,0 +1,28 @@
+-- Per-user contact details for outbound notifications
+CREATE TABLE user_contacts (
+    user_id INTEGER PRIMARY KEY NOT NULL,
+    email TEXT NOT NULL,
+    email_enabled INTEGER NOT NULL DEFAULT 1 CHECK(email_enabled IN (0, 1)),
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(user_id) REFERENCES users(user_id)
+);
+
+-- Append-only record of every notification sent (or attempted)
+-- reference_id is the bid_window_id for window notifications and the
+-- bid_status_history history_id for bid entry notifications.
+CREATE TABLE notification_log (
+    notification_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    user_id INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    reference_id INTEGER NOT NULL,
+    recipient TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    delivered INTEGER NOT NULL CHECK(delivered IN (0, 1)),
+    error TEXT,
+    sent_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(kind, reference_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id)
+);
+
+-- Index for listing notifications per user
+CREATE INDEX idx_notification_log_user ON notification_log(user_id);
@@ -0,0 +1,6 @@
+-- Drop indexes first
+DROP INDEX IF EXISTS idx_chat_notification_log_channel;
+
+-- Drop tables (log first due to foreign key)
+DROP TABLE IF EXISTS chat_notification_log;
+DROP TABLE IF EXISTS chat_channels;
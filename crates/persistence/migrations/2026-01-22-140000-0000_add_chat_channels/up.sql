@@ -0,0 +1,31 @@
+-- Incoming-webhook chat channels (Slack or Teams) per bid year
+-- area_id is NULL for a bid-year-wide channel that receives every area's
+-- announcements.
+CREATE TABLE chat_channels (
+    chat_channel_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    area_id INTEGER,
+    provider TEXT NOT NULL CHECK(provider IN ('slack', 'teams')),
+    url TEXT NOT NULL,
+    is_enabled INTEGER NOT NULL DEFAULT 1 CHECK(is_enabled IN (0, 1)),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id)
+);
+
+-- Append-only record of every chat announcement posted (or attempted)
+-- reference_key identifies what the announcement was about and depends on kind.
+CREATE TABLE chat_notification_log (
+    chat_notification_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    chat_channel_id INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    reference_key TEXT NOT NULL,
+    message TEXT NOT NULL,
+    delivered INTEGER NOT NULL CHECK(delivered IN (0, 1)),
+    error TEXT,
+    sent_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(chat_channel_id) REFERENCES chat_channels(chat_channel_id)
+);
+
+-- Index for de-duplicating announcements per channel
+CREATE INDEX idx_chat_notification_log_channel ON chat_notification_log(chat_channel_id, kind, reference_key);
@@ -0,0 +1,4 @@
+-- Drop indexes first
+DROP INDEX IF EXISTS idx_leave_bids_coverage;
+
+DROP TABLE IF EXISTS leave_bids;
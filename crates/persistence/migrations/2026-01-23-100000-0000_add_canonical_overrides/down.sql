@@ -0,0 +1,4 @@
+-- Drop indexes first
+DROP INDEX IF EXISTS idx_canonical_overrides_user;
+
+DROP TABLE IF EXISTS canonical_overrides;
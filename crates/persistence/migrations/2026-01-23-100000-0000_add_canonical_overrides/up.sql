@@ -0,0 +1,23 @@
+-- Ledger of overrides applied to canonical tables
+-- previous_value and new_value hold the JSON-encoded canonical value
+-- before and after the override. is_reverted is set once the override
+-- has been rolled back; overrides for the same user and kind stack, and
+-- only the most recent unreverted one may be reverted.
+CREATE TABLE canonical_overrides (
+    override_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    override_kind TEXT NOT NULL CHECK(override_kind IN ('area_assignment', 'eligibility', 'bid_order', 'bid_window')),
+    previous_value TEXT NOT NULL,
+    new_value TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    audit_event_id INTEGER NOT NULL,
+    is_reverted INTEGER NOT NULL DEFAULT 0 CHECK(is_reverted IN (0, 1)),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(audit_event_id) REFERENCES audit_events(event_id)
+);
+
+-- Index for locating the override stack of a user and kind
+CREATE INDEX idx_canonical_overrides_user ON canonical_overrides(bid_year_id, user_id, override_kind);
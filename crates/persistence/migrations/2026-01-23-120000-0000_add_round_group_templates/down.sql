@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS round_templates;
+DROP TABLE IF EXISTS round_group_templates;
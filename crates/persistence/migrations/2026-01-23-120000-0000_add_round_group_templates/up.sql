@@ -0,0 +1,24 @@
+-- Named round group templates, shared across bid years
+-- A template captures a round group's name and its rounds so the same
+-- structure can be instantiated in any bid year.
+CREATE TABLE round_group_templates (
+    template_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT NOT NULL UNIQUE,
+    editing_enabled INTEGER NOT NULL DEFAULT 1 CHECK(editing_enabled IN (0, 1)),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Rounds belonging to a template, mirroring the rounds table
+CREATE TABLE round_templates (
+    round_template_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    template_id INTEGER NOT NULL,
+    round_number INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slots_per_day INTEGER NOT NULL CHECK(slots_per_day > 0),
+    max_groups INTEGER NOT NULL CHECK(max_groups > 0),
+    max_total_hours INTEGER NOT NULL CHECK(max_total_hours > 0),
+    include_holidays INTEGER NOT NULL DEFAULT 0 CHECK(include_holidays IN (0, 1)),
+    allow_overbid INTEGER NOT NULL DEFAULT 0 CHECK(allow_overbid IN (0, 1)),
+    UNIQUE (template_id, round_number),
+    FOREIGN KEY(template_id) REFERENCES round_group_templates(template_id)
+);
@@ -0,0 +1,11 @@
+-- Per-bid-year blackout dates
+-- No bid windows are laid out on a blackout date (training days,
+-- facility events); later bidders shift to the next bidding day.
+CREATE TABLE bid_year_blackout_dates (
+    blackout_date_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    blackout_date TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    UNIQUE (bid_year_id, blackout_date),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+);
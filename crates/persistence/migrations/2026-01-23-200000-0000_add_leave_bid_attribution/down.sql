@@ -0,0 +1,4 @@
+-- Remove proxy attribution from leave_bids
+
+ALTER TABLE leave_bids DROP COLUMN received_via;
+ALTER TABLE leave_bids DROP COLUMN on_behalf_of;
@@ -0,0 +1,6 @@
+-- Record on whose behalf a leave bid was entered and how it was received
+-- ('phone', 'in_person', or 'written'). Both are NULL for leave recorded
+-- before bids carried proxy attribution.
+
+ALTER TABLE leave_bids ADD COLUMN on_behalf_of TEXT;
+ALTER TABLE leave_bids ADD COLUMN received_via TEXT;
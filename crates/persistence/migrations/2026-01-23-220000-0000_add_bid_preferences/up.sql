@@ -0,0 +1,24 @@
+-- Ranked leave preferences submitted before a user's bid window opens
+-- leave_dates is a comma-separated list of YYYY-MM-DD dates. status is
+-- 'pending' until the window opens, then 'applied' for the preference
+-- converted into leave, 'passed_over' for higher-ranked preferences that
+-- no longer fit, and 'unused' for the rest.
+CREATE TABLE bid_preferences (
+    bid_preference_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    area_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    round_id INTEGER NOT NULL,
+    preference_rank INTEGER NOT NULL CHECK(preference_rank > 0),
+    leave_dates TEXT NOT NULL,
+    hours INTEGER NOT NULL CHECK(hours > 0),
+    on_behalf_of TEXT NOT NULL,
+    received_via TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK(status IN ('pending', 'applied', 'passed_over', 'unused')),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(user_id, round_id, preference_rank),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id)
+);
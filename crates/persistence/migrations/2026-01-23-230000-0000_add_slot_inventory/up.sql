@@ -0,0 +1,16 @@
+-- Staffing adjustments to the daily leave slots of a round
+-- A day's capacity is the round's slots_per_day plus staffing_adjustment,
+-- never less than zero. Days without a row use slots_per_day unchanged.
+CREATE TABLE slot_inventory (
+    slot_inventory_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    area_id INTEGER NOT NULL,
+    round_id INTEGER NOT NULL,
+    slot_date TEXT NOT NULL,
+    staffing_adjustment INTEGER NOT NULL DEFAULT 0,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(area_id, round_id, slot_date),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id)
+);
@@ -0,0 +1,16 @@
+-- Bid validation rules layered on top of round limits, per bid year
+-- rule_kind names the rule (min_consecutive_days, max_splits_per_round,
+-- holiday_pairing). rule_value holds the numeric parameter for rules that
+-- take one, and rule_dates a comma-separated list of YYYY-MM-DD dates for
+-- rules that take dates. Rules are evaluated in rule_position order.
+CREATE TABLE bid_rules (
+    bid_rule_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    rule_position INTEGER NOT NULL CHECK(rule_position > 0),
+    rule_kind TEXT NOT NULL,
+    rule_value INTEGER,
+    rule_dates TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(bid_year_id, rule_position),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+);
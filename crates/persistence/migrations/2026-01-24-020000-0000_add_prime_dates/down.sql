@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS round_prime_caps;
+DROP TABLE IF EXISTS prime_periods;
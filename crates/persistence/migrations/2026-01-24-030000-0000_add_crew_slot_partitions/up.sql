@@ -0,0 +1,15 @@
+-- Crew-partitioned leave slots
+-- A round with crew_partitioned set allocates its daily slots per crew
+-- instead of per area. Each crew's slots come from round_crew_slots;
+-- crews without a row get no slots.
+ALTER TABLE rounds ADD COLUMN crew_partitioned INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE round_crew_slots (
+    round_id INTEGER NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    crew INTEGER NOT NULL CHECK(crew BETWEEN 1 AND 7),
+    slots_per_day INTEGER NOT NULL CHECK(slots_per_day >= 0),
+    PRIMARY KEY (round_id, crew),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+);
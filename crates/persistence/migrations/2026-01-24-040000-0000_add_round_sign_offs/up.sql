@@ -0,0 +1,16 @@
+-- Sign-offs of completed rounds, one per area and round
+-- A signed-off round's bids are locked against further entry unless an
+-- Admin overrides. audit_event_id links the row to the SignOffRound event.
+CREATE TABLE round_sign_offs (
+    round_sign_off_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    area_id INTEGER NOT NULL,
+    round_id INTEGER NOT NULL,
+    audit_event_id INTEGER NOT NULL,
+    signed_off_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(area_id, round_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id),
+    FOREIGN KEY(audit_event_id) REFERENCES audit_events(event_id)
+);
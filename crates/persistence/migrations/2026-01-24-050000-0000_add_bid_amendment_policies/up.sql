@@ -0,0 +1,9 @@
+-- Per-bid-year policy on amending submitted bids
+-- A bid year without a row allows amendments at any time. window_hours is
+-- set only for the within_hours policy.
+CREATE TABLE bid_amendment_policies (
+    bid_year_id INTEGER PRIMARY KEY NOT NULL,
+    policy TEXT NOT NULL,
+    window_hours INTEGER,
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+);
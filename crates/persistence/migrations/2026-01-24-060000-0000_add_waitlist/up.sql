@@ -0,0 +1,52 @@
+-- Leave slots freed by withdrawals after a round closed in an area
+-- The slot is offered down the seniority list. status is 'open' while
+-- offers are outstanding, 'filled' once accepted, and 'unclaimed' when
+-- every eligible user has passed. crew is the crew whose slot was freed in
+-- a crew-partitioned round, NULL otherwise. offer_hours is how long each
+-- offer stays open. release_event_id links the row to the WithdrawLeaveBid
+-- audit event.
+CREATE TABLE waitlist_slots (
+    waitlist_slot_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    area_id INTEGER NOT NULL,
+    round_id INTEGER NOT NULL,
+    leave_date TEXT NOT NULL,
+    hours INTEGER NOT NULL CHECK(hours > 0),
+    crew INTEGER,
+    offer_hours INTEGER NOT NULL CHECK(offer_hours > 0),
+    released_leave_bid_id INTEGER NOT NULL,
+    release_event_id INTEGER NOT NULL,
+    status TEXT NOT NULL DEFAULT 'open' CHECK(status IN ('open', 'filled', 'unclaimed')),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id),
+    FOREIGN KEY(released_leave_bid_id) REFERENCES leave_bids(leave_bid_id),
+    FOREIGN KEY(release_event_id) REFERENCES audit_events(event_id)
+);
+
+-- Offers of a freed slot, one per user
+-- status is 'pending' until the user accepts or declines, or the offer
+-- passes expires_at. Timestamps are RFC 3339 (UTC). leave_bid_id is the
+-- leave granted on acceptance.
+CREATE TABLE waitlist_offers (
+    waitlist_offer_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    waitlist_slot_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK(status IN ('pending', 'accepted', 'declined', 'expired')),
+    offered_at TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    responded_at TEXT,
+    offer_event_id INTEGER NOT NULL,
+    response_event_id INTEGER,
+    leave_bid_id INTEGER,
+    UNIQUE(waitlist_slot_id, user_id),
+    FOREIGN KEY(waitlist_slot_id) REFERENCES waitlist_slots(waitlist_slot_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(offer_event_id) REFERENCES audit_events(event_id),
+    FOREIGN KEY(response_event_id) REFERENCES audit_events(event_id),
+    FOREIGN KEY(leave_bid_id) REFERENCES leave_bids(leave_bid_id)
+);
+
+-- Index for finding offers awaiting an answer
+CREATE INDEX idx_waitlist_offers_status ON waitlist_offers(status);
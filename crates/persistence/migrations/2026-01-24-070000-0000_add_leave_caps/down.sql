@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS leave_carryovers;
+DROP TABLE IF EXISTS leave_caps;
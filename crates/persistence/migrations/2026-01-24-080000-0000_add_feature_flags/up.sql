@@ -0,0 +1,9 @@
+-- Per-bid-year feature flags
+-- A bid year without a row for a flag uses the flag's default.
+CREATE TABLE feature_flags (
+    bid_year_id INTEGER NOT NULL,
+    flag TEXT NOT NULL,
+    enabled INTEGER NOT NULL CHECK(enabled IN (0, 1)),
+    PRIMARY KEY (bid_year_id, flag),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+);
@@ -0,0 +1 @@
+DROP INDEX IF EXISTS idx_leave_bids_by_date;
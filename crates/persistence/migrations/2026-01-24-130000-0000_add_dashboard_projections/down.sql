@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS projected_area_progress;
+DROP TABLE IF EXISTS projected_user_awards;
+DROP TABLE IF EXISTS projected_daily_slots;
+DROP TABLE IF EXISTS projection_cursors;
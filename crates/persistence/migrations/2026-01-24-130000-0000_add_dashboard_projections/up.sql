@@ -0,0 +1,48 @@
+-- Denormalized read tables for dashboards
+-- Every table is derived from the canonical tables and rebuilt one area at
+-- a time whenever the audit log records a change in scope. Rows carry no
+-- foreign keys, so they never block deleting what they summarize.
+-- projection_cursors records the last audit event each projection has
+-- applied. updated_at is RFC 3339 (UTC).
+CREATE TABLE projection_cursors (
+    projection_name TEXT PRIMARY KEY NOT NULL,
+    last_event_id INTEGER NOT NULL CHECK(last_event_id >= 0),
+    updated_at TEXT NOT NULL
+);
+
+-- Leave slots on each day of each round in each area
+CREATE TABLE projected_daily_slots (
+    area_id INTEGER NOT NULL,
+    round_id INTEGER NOT NULL,
+    slot_date TEXT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    capacity INTEGER NOT NULL CHECK(capacity >= 0),
+    used INTEGER NOT NULL CHECK(used >= 0),
+    remaining INTEGER NOT NULL CHECK(remaining >= 0),
+    PRIMARY KEY (area_id, round_id, slot_date)
+);
+
+-- Approved leave per user
+CREATE TABLE projected_user_awards (
+    user_id INTEGER PRIMARY KEY NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    area_id INTEGER NOT NULL,
+    awarded_days INTEGER NOT NULL CHECK(awarded_days >= 0),
+    awarded_hours INTEGER NOT NULL CHECK(awarded_hours >= 0)
+);
+
+CREATE INDEX idx_projected_user_awards_area ON projected_user_awards(area_id);
+
+-- Bid status counts per round in each area
+CREATE TABLE projected_area_progress (
+    area_id INTEGER NOT NULL,
+    round_id INTEGER NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    bidders INTEGER NOT NULL CHECK(bidders >= 0),
+    not_started INTEGER NOT NULL CHECK(not_started >= 0),
+    in_progress INTEGER NOT NULL CHECK(in_progress >= 0),
+    completed INTEGER NOT NULL CHECK(completed >= 0),
+    PRIMARY KEY (area_id, round_id)
+);
+
+CREATE INDEX idx_projected_area_progress_bid_year ON projected_area_progress(bid_year_id);
@@ -0,0 +1,2 @@
+ALTER TABLE canonical_eligibility DROP COLUMN derived_can_bid;
+DROP TABLE IF EXISTS eligibility_exceptions;
@@ -0,0 +1,10 @@
+DROP TABLE IF EXISTS user_merge_rows;
+
+-- Drop indexes first
+DROP INDEX IF EXISTS idx_user_merges_revert_event;
+DROP INDEX IF EXISTS idx_user_merges_merge_event;
+DROP INDEX IF EXISTS idx_user_merges_merged_user;
+DROP INDEX IF EXISTS idx_user_merges_kept_user;
+DROP INDEX IF EXISTS idx_user_merges_bid_year;
+
+DROP TABLE IF EXISTS user_merges;
@@ -0,0 +1,44 @@
+-- Ledger of duplicate user records merged into a surviving record
+-- The merged record stays in users, excluded from bidding and leave
+-- calculation. previous_excluded_* hold its participation flags before the
+-- merge. merged_carryover_hours is the leave carryover moved off the merged
+-- record and kept_carryover_hours the surviving record's carryover before
+-- the merge; NULL means there was no carryover row. A merge is in force
+-- until revert_event_id is set.
+CREATE TABLE user_merges (
+    user_merge_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    kept_user_id INTEGER NOT NULL,
+    merged_user_id INTEGER NOT NULL,
+    reason TEXT NOT NULL,
+    previous_excluded_from_bidding INTEGER NOT NULL CHECK(previous_excluded_from_bidding IN (0, 1)),
+    previous_excluded_from_leave_calculation INTEGER NOT NULL CHECK(previous_excluded_from_leave_calculation IN (0, 1)),
+    merged_carryover_hours INTEGER,
+    kept_carryover_hours INTEGER,
+    merge_event_id INTEGER NOT NULL,
+    revert_event_id INTEGER,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(kept_user_id) REFERENCES users(user_id),
+    FOREIGN KEY(merged_user_id) REFERENCES users(user_id),
+    FOREIGN KEY(merge_event_id) REFERENCES audit_events(event_id),
+    FOREIGN KEY(revert_event_id) REFERENCES audit_events(event_id)
+);
+
+CREATE INDEX idx_user_merges_bid_year ON user_merges(bid_year_id);
+CREATE INDEX idx_user_merges_kept_user ON user_merges(kept_user_id);
+CREATE INDEX idx_user_merges_merged_user ON user_merges(merged_user_id);
+CREATE INDEX idx_user_merges_merge_event ON user_merges(merge_event_id);
+CREATE INDEX idx_user_merges_revert_event ON user_merges(revert_event_id);
+
+-- Rows a merge reassigned from the merged record to the surviving record,
+-- so a revert moves exactly those rows back. For the canonical_* tables
+-- row_id is the merged record's row, whose override was swapped onto the
+-- surviving record's row.
+CREATE TABLE user_merge_rows (
+    user_merge_id INTEGER NOT NULL,
+    source_table TEXT NOT NULL CHECK(source_table IN ('leave_bids', 'bid_preferences', 'overbid_requests', 'waitlist_offers', 'canonical_overrides', 'canonical_area_membership', 'canonical_bid_order', 'canonical_bid_windows', 'canonical_eligibility')),
+    row_id INTEGER NOT NULL,
+    PRIMARY KEY (user_merge_id, source_table, row_id),
+    FOREIGN KEY(user_merge_id) REFERENCES user_merges(user_merge_id)
+);
@@ -0,0 +1,6 @@
+-- Drop indexes first
+DROP INDEX IF EXISTS idx_user_initials_aliases_change_event;
+DROP INDEX IF EXISTS idx_user_initials_aliases_user;
+DROP INDEX IF EXISTS idx_user_initials_aliases_by_initials;
+
+DROP TABLE IF EXISTS user_initials_aliases;
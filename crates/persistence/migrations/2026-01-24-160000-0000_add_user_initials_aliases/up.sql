@@ -0,0 +1,20 @@
+-- Initials a user held before an initials change
+-- Each row records one retired value and the date its replacement took
+-- effect, so audit history recorded under either value resolves to the same
+-- user. change_event_id is the audit event that recorded the change.
+CREATE TABLE user_initials_aliases (
+    alias_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    initials TEXT NOT NULL,
+    effective_date TEXT NOT NULL,
+    change_event_id INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(change_event_id) REFERENCES audit_events(event_id)
+);
+
+CREATE INDEX idx_user_initials_aliases_by_initials ON user_initials_aliases(bid_year_id, initials);
+CREATE INDEX idx_user_initials_aliases_user ON user_initials_aliases(user_id);
+CREATE INDEX idx_user_initials_aliases_change_event ON user_initials_aliases(change_event_id);
@@ -0,0 +1,5 @@
+-- Drop indexes first
+DROP INDEX IF EXISTS idx_audit_event_annotations_operator;
+DROP INDEX IF EXISTS idx_audit_event_annotations_event;
+
+DROP TABLE IF EXISTS audit_event_annotations;
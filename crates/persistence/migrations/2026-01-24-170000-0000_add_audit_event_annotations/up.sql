@@ -0,0 +1,15 @@
+-- Notes operators attach to audit events after the fact
+-- Audit events are immutable; an annotation explains why an event happened
+-- without altering the event itself. Annotations are append-only.
+CREATE TABLE audit_event_annotations (
+    annotation_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    event_id INTEGER NOT NULL,
+    operator_id INTEGER NOT NULL,
+    note TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(event_id) REFERENCES audit_events(event_id),
+    FOREIGN KEY(operator_id) REFERENCES operators(operator_id)
+);
+
+CREATE INDEX idx_audit_event_annotations_event ON audit_event_annotations(event_id);
+CREATE INDEX idx_audit_event_annotations_operator ON audit_event_annotations(operator_id);
@@ -0,0 +1,5 @@
+-- Drop indexes first
+DROP INDEX IF EXISTS idx_user_anonymizations_event;
+DROP INDEX IF EXISTS idx_user_anonymizations_bid_year;
+
+DROP TABLE IF EXISTS user_anonymizations;
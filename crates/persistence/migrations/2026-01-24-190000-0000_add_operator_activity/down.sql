@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_operator_area_scopes_area;
+DROP TABLE IF EXISTS operator_area_scopes;
+ALTER TABLE operators DROP COLUMN failed_login_count;
@@ -0,0 +1,16 @@
+-- Consecutive failed sign-in attempts, reset by a successful sign-in
+ALTER TABLE operators ADD COLUMN failed_login_count INTEGER NOT NULL DEFAULT 0;
+
+-- Areas an operator is assigned to look after
+-- Scopes are informational; they drive the operator-management screen and
+-- do not change what an operator's role permits.
+CREATE TABLE operator_area_scopes (
+    operator_id INTEGER NOT NULL,
+    area_id INTEGER NOT NULL,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY(operator_id, area_id),
+    FOREIGN KEY(operator_id) REFERENCES operators(operator_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id)
+);
+
+CREATE INDEX idx_operator_area_scopes_area ON operator_area_scopes(area_id);
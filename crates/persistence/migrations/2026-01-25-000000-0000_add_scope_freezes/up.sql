@@ -0,0 +1,16 @@
+-- Areas frozen against every change, one row per frozen area
+-- A freeze holds regardless of the bid year's lifecycle state and lasts
+-- until the area is unfrozen, which deletes the row. audit_event_id links
+-- the row to the FreezeScope event.
+CREATE TABLE scope_freezes (
+    scope_freeze_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    area_id INTEGER NOT NULL,
+    reason TEXT NOT NULL,
+    audit_event_id INTEGER NOT NULL,
+    frozen_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(area_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(audit_event_id) REFERENCES audit_events(event_id)
+);
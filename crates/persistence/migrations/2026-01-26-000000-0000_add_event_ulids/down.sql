@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_audit_events_event_ulid;
+ALTER TABLE audit_events DROP COLUMN event_ulid;
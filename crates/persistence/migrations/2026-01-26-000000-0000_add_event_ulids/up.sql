@@ -0,0 +1,5 @@
+-- Globally unique event identifiers, assigned by core
+-- Events recorded before this migration have no ULID.
+ALTER TABLE audit_events ADD COLUMN event_ulid TEXT;
+
+CREATE UNIQUE INDEX idx_audit_events_event_ulid ON audit_events(event_ulid);
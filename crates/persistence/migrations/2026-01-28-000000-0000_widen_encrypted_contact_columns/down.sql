@@ -0,0 +1 @@
+-- No-op migration for SQLite
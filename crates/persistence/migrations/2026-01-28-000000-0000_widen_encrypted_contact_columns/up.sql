@@ -0,0 +1,4 @@
+-- No-op migration for SQLite
+-- user_contacts.email and notification_log.recipient are already TEXT, so
+-- encrypted email addresses fit without a change. Existing plaintext rows
+-- are encrypted by the server at startup once a column key is configured.
@@ -0,0 +1,40 @@
+-- Archived events are moved back into the live log, so reverting a
+-- compaction never loses audit history
+INSERT INTO audit_events (
+    event_id,
+    bid_year_id,
+    area_id,
+    year,
+    area_code,
+    actor_operator_id,
+    actor_login_name,
+    actor_display_name,
+    actor_json,
+    cause_json,
+    action_json,
+    before_snapshot_json,
+    after_snapshot_json,
+    created_at,
+    event_ulid
+)
+SELECT
+    event_id,
+    bid_year_id,
+    area_id,
+    year,
+    area_code,
+    actor_operator_id,
+    actor_login_name,
+    actor_display_name,
+    actor_json,
+    cause_json,
+    action_json,
+    before_snapshot_json,
+    after_snapshot_json,
+    created_at,
+    event_ulid
+FROM archived_audit_events
+ORDER BY event_id;
+
+DROP TABLE IF EXISTS archived_audit_events;
+DROP TABLE IF EXISTS audit_compactions;
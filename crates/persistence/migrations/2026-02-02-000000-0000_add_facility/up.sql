@@ -0,0 +1,15 @@
+-- Facility-level configuration
+-- The single row holds the defaults bid years inherit: the timezone bid
+-- schedules are declared in, the leave accrual rate of each service tier,
+-- and the annual leave carryover cap.
+CREATE TABLE facility (
+    facility_id INTEGER PRIMARY KEY NOT NULL CHECK(facility_id = 1),
+    identifier TEXT NOT NULL,
+    name TEXT NOT NULL,
+    default_timezone TEXT NOT NULL,
+    junior_accrual_hours INTEGER NOT NULL CHECK(junior_accrual_hours > 0),
+    mid_accrual_hours INTEGER NOT NULL CHECK(mid_accrual_hours > 0),
+    senior_accrual_hours INTEGER NOT NULL CHECK(senior_accrual_hours > 0),
+    max_carryover_hours INTEGER NOT NULL CHECK(max_carryover_hours > 0),
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS round_bid_order;
+DROP TABLE IF EXISTS round_group_rotations;
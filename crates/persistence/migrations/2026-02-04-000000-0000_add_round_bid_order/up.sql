@@ -0,0 +1,29 @@
+-- Round group bid order rotation
+-- How a round group rotates its bid order from round to round. Round groups
+-- without a row bid every round in canonical bid order.
+CREATE TABLE round_group_rotations (
+    round_group_id INTEGER PRIMARY KEY NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    rotation TEXT NOT NULL,
+    FOREIGN KEY(round_group_id) REFERENCES round_groups(round_group_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+);
+
+-- Per-round bid order
+-- Each round's bidding sequence, derived at confirmation from the canonical
+-- bid order with ineligible users skipped and the rotation applied.
+-- Positions run from 1 within each round and area.
+CREATE TABLE round_bid_order (
+    round_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    bid_year_id INTEGER NOT NULL,
+    area_id INTEGER NOT NULL,
+    position INTEGER NOT NULL CHECK(position >= 1),
+    PRIMARY KEY(round_id, user_id),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id)
+);
+
+CREATE INDEX idx_round_bid_order_bid_year ON round_bid_order(bid_year_id);
@@ -0,0 +1,14 @@
+-- Controller portal links
+-- Each row lets one controller read their own standing in a bid year
+-- without an operator account. Only the HMAC digest of the link token is
+-- stored. A controller holds at most one link; issuing a new one replaces it.
+CREATE TABLE portal_links (
+    portal_link_id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    user_id INTEGER NOT NULL UNIQUE,
+    bid_year_id INTEGER NOT NULL,
+    expires_at TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_area_delegations_delegate;
+DROP TABLE IF EXISTS area_delegations;
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_self_bid_requests_round;
+DROP TABLE IF EXISTS self_bid_requests;
+DROP TABLE IF EXISTS operator_controllers;
@@ -0,0 +1,8 @@
+-- The user each audit event concerns, by canonical user ID
+-- Recorded by ID so events stay attached to their user through initials
+-- changes. Events that do not concern a single user, and events recorded
+-- before this migration, have no user.
+ALTER TABLE audit_events ADD COLUMN user_id INTEGER;
+ALTER TABLE archived_audit_events ADD COLUMN user_id INTEGER;
+
+CREATE INDEX idx_audit_events_user ON audit_events(user_id, event_id);
@@ -0,0 +1 @@
+DELETE FROM operators WHERE operator_id = 0;
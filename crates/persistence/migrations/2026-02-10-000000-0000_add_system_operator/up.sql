@@ -0,0 +1,5 @@
+-- The built-in system operator
+-- Audit events with no operator behind them are attributed to it. It is
+-- disabled and its password hash matches no password, so it cannot sign in.
+INSERT INTO operators (operator_id, login_name, display_name, password_hash, role, is_disabled)
+VALUES (0, 'SYSTEM', 'System', '!', 'Bidder', 1);
@@ -0,0 +1,6 @@
+-- Drop indexes first
+DROP INDEX idx_webhook_dead_letters_webhook ON webhook_dead_letters;
+
+-- Drop tables (dead letters first due to foreign key)
+DROP TABLE IF EXISTS webhook_dead_letters;
+DROP TABLE IF EXISTS webhooks;
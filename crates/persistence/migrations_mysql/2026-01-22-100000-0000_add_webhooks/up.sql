@@ -0,0 +1,25 @@
+-- Outbound webhook subscriptions for audit events
+CREATE TABLE webhooks (
+    webhook_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    url VARCHAR(2048) NOT NULL,
+    secret VARCHAR(255) NOT NULL,
+    event_filter TEXT NOT NULL,
+    is_enabled TINYINT NOT NULL DEFAULT 1 CHECK(is_enabled IN (0, 1)),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+) ENGINE=InnoDB;
+
+-- Deliveries that exhausted their retries
+CREATE TABLE webhook_dead_letters (
+    dead_letter_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    webhook_id BIGINT NOT NULL,
+    audit_event_id BIGINT NOT NULL,
+    payload TEXT NOT NULL,
+    attempts INT NOT NULL,
+    last_error TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(webhook_id) REFERENCES webhooks(webhook_id),
+    FOREIGN KEY(audit_event_id) REFERENCES audit_events(event_id)
+) ENGINE=InnoDB;
+
+-- Index for listing dead letters per webhook
+CREATE INDEX idx_webhook_dead_letters_webhook ON webhook_dead_letters(webhook_id);
@@ -0,0 +1,6 @@
+-- Drop indexes first
+DROP INDEX idx_notification_log_user ON notification_log;
+
+-- Drop tables
+DROP TABLE IF EXISTS notification_log;
+DROP TABLE IF EXISTS user_contacts;
@@ -0,0 +1,28 @@
+-- Per-user contact details for outbound notifications
+CREATE TABLE user_contacts (
+    user_id BIGINT PRIMARY KEY NOT NULL,
+    email VARCHAR(320) NOT NULL,
+    email_enabled TINYINT NOT NULL DEFAULT 1 CHECK(email_enabled IN (0, 1)),
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(user_id) REFERENCES users(user_id)
+) ENGINE=InnoDB;
+
+-- Append-only record of every notification sent (or attempted)
+-- reference_id is the bid_window_id for window notifications and the
+-- bid_status_history history_id for bid entry notifications.
+CREATE TABLE notification_log (
+    notification_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    user_id BIGINT NOT NULL,
+    kind VARCHAR(32) NOT NULL,
+    reference_id BIGINT NOT NULL,
+    recipient VARCHAR(320) NOT NULL,
+    subject VARCHAR(255) NOT NULL,
+    delivered TINYINT NOT NULL CHECK(delivered IN (0, 1)),
+    error TEXT,
+    sent_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(kind, reference_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id)
+) ENGINE=InnoDB;
+
+-- Index for listing notifications per user
+CREATE INDEX idx_notification_log_user ON notification_log(user_id);
@@ -0,0 +1,6 @@
+-- Drop indexes first
+DROP INDEX idx_chat_notification_log_channel ON chat_notification_log;
+
+-- Drop tables (log first due to foreign key)
+DROP TABLE IF EXISTS chat_notification_log;
+DROP TABLE IF EXISTS chat_channels;
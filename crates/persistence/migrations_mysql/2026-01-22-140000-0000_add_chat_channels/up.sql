@@ -0,0 +1,31 @@
+-- Incoming-webhook chat channels (Slack or Teams) per bid year
+-- area_id is NULL for a bid-year-wide channel that receives every area's
+-- announcements.
+CREATE TABLE chat_channels (
+    chat_channel_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    area_id BIGINT,
+    provider VARCHAR(16) NOT NULL CHECK(provider IN ('slack', 'teams')),
+    url VARCHAR(2048) NOT NULL,
+    is_enabled TINYINT NOT NULL DEFAULT 1 CHECK(is_enabled IN (0, 1)),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id)
+) ENGINE=InnoDB;
+
+-- Append-only record of every chat announcement posted (or attempted)
+-- reference_key identifies what the announcement was about and depends on kind.
+CREATE TABLE chat_notification_log (
+    chat_notification_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    chat_channel_id BIGINT NOT NULL,
+    kind VARCHAR(64) NOT NULL,
+    reference_key VARCHAR(255) NOT NULL,
+    message TEXT NOT NULL,
+    delivered TINYINT NOT NULL CHECK(delivered IN (0, 1)),
+    error TEXT,
+    sent_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(chat_channel_id) REFERENCES chat_channels(chat_channel_id)
+) ENGINE=InnoDB;
+
+-- Index for de-duplicating announcements per channel
+CREATE INDEX idx_chat_notification_log_channel ON chat_notification_log(chat_channel_id, kind, reference_key);
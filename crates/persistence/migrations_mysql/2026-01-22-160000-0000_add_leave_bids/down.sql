@@ -0,0 +1,4 @@
+-- Drop indexes first
+DROP INDEX idx_leave_bids_coverage ON leave_bids;
+
+DROP TABLE IF EXISTS leave_bids;
@@ -0,0 +1,22 @@
+-- Leave awarded to a user for a single day within a round
+-- status is 'approved' while the day counts against the round's
+-- slots_per_day, and 'withdrawn' once the award has been released.
+CREATE TABLE leave_bids (
+    leave_bid_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    area_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    round_id BIGINT NOT NULL,
+    leave_date VARCHAR(10) NOT NULL,
+    hours INT NOT NULL CHECK(hours > 0),
+    status VARCHAR(16) NOT NULL DEFAULT 'approved' CHECK(status IN ('approved', 'withdrawn')),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY unique_leave_bid (user_id, round_id, leave_date),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id)
+) ENGINE=InnoDB;
+
+-- Index for per-day coverage lookups
+CREATE INDEX idx_leave_bids_coverage ON leave_bids(bid_year_id, area_id, leave_date);
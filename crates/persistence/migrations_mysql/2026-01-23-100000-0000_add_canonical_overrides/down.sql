@@ -0,0 +1,4 @@
+-- Drop indexes first
+DROP INDEX idx_canonical_overrides_user ON canonical_overrides;
+
+DROP TABLE IF EXISTS canonical_overrides;
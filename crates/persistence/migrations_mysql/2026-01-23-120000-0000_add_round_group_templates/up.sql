@@ -0,0 +1,24 @@
+-- Named round group templates, shared across bid years
+-- A template captures a round group's name and its rounds so the same
+-- structure can be instantiated in any bid year.
+CREATE TABLE round_group_templates (
+    template_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    name VARCHAR(255) NOT NULL UNIQUE,
+    editing_enabled INT NOT NULL DEFAULT 1 CHECK(editing_enabled IN (0, 1)),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+) ENGINE=InnoDB;
+
+-- Rounds belonging to a template, mirroring the rounds table
+CREATE TABLE round_templates (
+    round_template_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    template_id BIGINT NOT NULL,
+    round_number INT NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    slots_per_day INT NOT NULL CHECK(slots_per_day > 0),
+    max_groups INT NOT NULL CHECK(max_groups > 0),
+    max_total_hours INT NOT NULL CHECK(max_total_hours > 0),
+    include_holidays INT NOT NULL DEFAULT 0 CHECK(include_holidays IN (0, 1)),
+    allow_overbid INT NOT NULL DEFAULT 0 CHECK(allow_overbid IN (0, 1)),
+    UNIQUE KEY unique_round_template_number (template_id, round_number),
+    FOREIGN KEY(template_id) REFERENCES round_group_templates(template_id)
+) ENGINE=InnoDB;
@@ -0,0 +1,11 @@
+-- Per-area bid schedule overrides
+-- Each column is optional; a NULL falls back to the bid year's schedule.
+-- The timezone is always taken from the bid year.
+CREATE TABLE area_bid_schedule_overrides (
+    area_id BIGINT PRIMARY KEY NOT NULL,
+    bid_start_date VARCHAR(10),
+    bid_window_start_time VARCHAR(8),
+    bid_window_end_time VARCHAR(8),
+    bidders_per_area_per_day INT CHECK(bidders_per_area_per_day IS NULL OR bidders_per_area_per_day > 0),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id)
+) ENGINE=InnoDB;
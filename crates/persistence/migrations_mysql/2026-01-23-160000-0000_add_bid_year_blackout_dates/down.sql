@@ -0,0 +1 @@
+DROP TABLE IF EXISTS bid_year_blackout_dates;
@@ -0,0 +1,11 @@
+-- Per-bid-year blackout dates
+-- No bid windows are laid out on a blackout date (training days,
+-- facility events); later bidders shift to the next bidding day.
+CREATE TABLE bid_year_blackout_dates (
+    blackout_date_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    blackout_date VARCHAR(10) NOT NULL,
+    reason VARCHAR(255) NOT NULL,
+    UNIQUE KEY unique_bid_year_blackout_date (bid_year_id, blackout_date),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+) ENGINE=InnoDB;
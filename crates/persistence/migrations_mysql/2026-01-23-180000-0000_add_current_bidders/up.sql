@@ -0,0 +1,16 @@
+-- Current bidder per area and round
+-- Tracks whose bid window is active now so the live view and notifications
+-- know who is up. A NULL user_id marks a round whose bidders are exhausted.
+CREATE TABLE current_bidders (
+    current_bidder_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    area_id BIGINT NOT NULL,
+    round_id BIGINT NOT NULL,
+    user_id BIGINT,
+    became_current_at VARCHAR(64) NOT NULL,
+    UNIQUE KEY unique_current_bidder_area_round (area_id, round_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id)
+) ENGINE=InnoDB;
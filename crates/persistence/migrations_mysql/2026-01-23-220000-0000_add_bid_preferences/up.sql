@@ -0,0 +1,24 @@
+-- Ranked leave preferences submitted before a user's bid window opens
+-- leave_dates is a comma-separated list of YYYY-MM-DD dates. status is
+-- 'pending' until the window opens, then 'applied' for the preference
+-- converted into leave, 'passed_over' for higher-ranked preferences that
+-- no longer fit, and 'unused' for the rest.
+CREATE TABLE bid_preferences (
+    bid_preference_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    area_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    round_id BIGINT NOT NULL,
+    preference_rank INT NOT NULL CHECK(preference_rank > 0),
+    leave_dates TEXT NOT NULL,
+    hours INT NOT NULL CHECK(hours > 0),
+    on_behalf_of VARCHAR(16) NOT NULL,
+    received_via VARCHAR(16) NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'pending' CHECK(status IN ('pending', 'applied', 'passed_over', 'unused')),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY unique_bid_preference_rank (user_id, round_id, preference_rank),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id)
+) ENGINE=InnoDB;
@@ -0,0 +1,31 @@
+-- Requests for leave on days whose slots are all taken
+-- status is 'pending' until an Admin decides, then 'approved' or 'denied'.
+-- request_event_id and decision_event_id link the row to the audit events
+-- that recorded the request and the decision. leave_bid_id is the leave
+-- granted on approval.
+CREATE TABLE overbid_requests (
+    overbid_request_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    area_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    round_id BIGINT NOT NULL,
+    leave_date VARCHAR(10) NOT NULL,
+    hours INT NOT NULL CHECK(hours > 0),
+    on_behalf_of VARCHAR(16) NOT NULL,
+    received_via VARCHAR(16) NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'pending' CHECK(status IN ('pending', 'approved', 'denied')),
+    decision_reason TEXT,
+    request_event_id BIGINT,
+    decision_event_id BIGINT,
+    leave_bid_id BIGINT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    decided_at DATETIME,
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id),
+    FOREIGN KEY(request_event_id) REFERENCES audit_events(event_id),
+    FOREIGN KEY(decision_event_id) REFERENCES audit_events(event_id),
+    FOREIGN KEY(leave_bid_id) REFERENCES leave_bids(leave_bid_id),
+    INDEX idx_overbid_requests_round (area_id, round_id, status)
+) ENGINE=InnoDB;
@@ -0,0 +1,24 @@
+-- Per-bid-year prime (high-demand) periods such as spring break or
+-- Christmas week. Every date from start_date through end_date is prime.
+CREATE TABLE prime_periods (
+    prime_period_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    label VARCHAR(255) NOT NULL,
+    start_date VARCHAR(10) NOT NULL,
+    end_date VARCHAR(10) NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK(start_date <= end_date),
+    INDEX idx_prime_periods_bid_year (bid_year_id, start_date),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+) ENGINE=InnoDB;
+
+-- Per-round leave slots on prime dates
+-- A round with a cap offers prime_slots_per_day slots on each prime date
+-- instead of its normal slots_per_day. Rounds without a row are uncapped.
+CREATE TABLE round_prime_caps (
+    round_id BIGINT PRIMARY KEY NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    prime_slots_per_day INT NOT NULL CHECK(prime_slots_per_day >= 0),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+) ENGINE=InnoDB;
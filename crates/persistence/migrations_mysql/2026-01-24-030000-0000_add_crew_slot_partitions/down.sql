@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS round_crew_slots;
+ALTER TABLE rounds DROP COLUMN crew_partitioned;
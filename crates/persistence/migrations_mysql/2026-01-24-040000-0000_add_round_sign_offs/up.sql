@@ -0,0 +1,16 @@
+-- Sign-offs of completed rounds, one per area and round
+-- A signed-off round's bids are locked against further entry unless an
+-- Admin overrides. audit_event_id links the row to the SignOffRound event.
+CREATE TABLE round_sign_offs (
+    round_sign_off_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    area_id BIGINT NOT NULL,
+    round_id BIGINT NOT NULL,
+    audit_event_id BIGINT NOT NULL,
+    signed_off_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY unique_round_sign_off (area_id, round_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id),
+    FOREIGN KEY(audit_event_id) REFERENCES audit_events(event_id)
+) ENGINE=InnoDB;
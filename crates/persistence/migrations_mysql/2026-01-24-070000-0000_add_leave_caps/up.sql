@@ -0,0 +1,19 @@
+-- Per-bid-year annual leave carryover cap
+-- Leave above the cap at year end is forfeited. A bid year without a row
+-- uses the default cap of 240 hours.
+CREATE TABLE leave_caps (
+    bid_year_id BIGINT PRIMARY KEY NOT NULL,
+    carryover_cap_hours INT NOT NULL CHECK(carryover_cap_hours > 0),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+) ENGINE=InnoDB;
+
+-- Annual leave each user carries into a bid year from the prior year
+-- A user without a row carries in nothing.
+CREATE TABLE leave_carryovers (
+    bid_year_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    carryover_hours INT NOT NULL CHECK(carryover_hours >= 0),
+    PRIMARY KEY(bid_year_id, user_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id)
+) ENGINE=InnoDB;
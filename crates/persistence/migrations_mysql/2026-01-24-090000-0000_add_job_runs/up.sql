@@ -0,0 +1,11 @@
+-- Last run of each server background job, for observability
+-- Timestamps are RFC 3339 (UTC). message is the job's summary when it
+-- succeeded and its error when it failed.
+CREATE TABLE job_runs (
+    job_name VARCHAR(64) PRIMARY KEY NOT NULL,
+    started_at VARCHAR(40) NOT NULL,
+    finished_at VARCHAR(40) NOT NULL,
+    succeeded TINYINT NOT NULL CHECK(succeeded IN (0, 1)),
+    duration_ms BIGINT NOT NULL CHECK(duration_ms >= 0),
+    message TEXT
+) ENGINE=InnoDB;
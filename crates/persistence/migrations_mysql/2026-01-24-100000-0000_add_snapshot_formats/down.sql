@@ -0,0 +1,2 @@
+ALTER TABLE state_snapshots DROP COLUMN state_binary;
+ALTER TABLE state_snapshots DROP COLUMN state_format;
@@ -0,0 +1,6 @@
+-- Full state snapshots may be stored as compact binary instead of JSON
+-- state_format names the encoding. JSON snapshots keep their payload in
+-- state_json; binary snapshots keep it in state_binary and leave
+-- state_json empty.
+ALTER TABLE state_snapshots ADD COLUMN state_format VARCHAR(16) NOT NULL DEFAULT 'json' CHECK(state_format IN ('json', 'postcard'));
+ALTER TABLE state_snapshots ADD COLUMN state_binary LONGBLOB;
@@ -0,0 +1 @@
+DROP INDEX idx_leave_bids_by_date ON leave_bids;
@@ -0,0 +1,5 @@
+-- Slot inventory and coverage count approved leave per day across a
+-- bid year. Keying on status and date turns the date range into an index
+-- range, and carrying round, area and user lets the counts be read from
+-- the index alone.
+CREATE INDEX idx_leave_bids_by_date ON leave_bids(bid_year_id, status, leave_date, round_id, area_id, user_id);
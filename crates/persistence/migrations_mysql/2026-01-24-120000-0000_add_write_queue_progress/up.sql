@@ -0,0 +1,10 @@
+-- Progress of each server write-ahead queue, for exactly-once replay
+-- last_sequence is the highest queue entry whose transition has been
+-- persisted. It is written in the same transaction as the transitions, so
+-- entries at or below it are skipped when the queue file is replayed.
+-- updated_at is RFC 3339 (UTC).
+CREATE TABLE write_queue_progress (
+    queue_name VARCHAR(255) PRIMARY KEY NOT NULL,
+    last_sequence BIGINT NOT NULL CHECK(last_sequence >= 0),
+    updated_at VARCHAR(40) NOT NULL
+) ENGINE=InnoDB;
@@ -0,0 +1,48 @@
+-- Denormalized read tables for dashboards
+-- Every table is derived from the canonical tables and rebuilt one area at
+-- a time whenever the audit log records a change in scope. Rows carry no
+-- foreign keys, so they never block deleting what they summarize.
+-- projection_cursors records the last audit event each projection has
+-- applied. updated_at is RFC 3339 (UTC).
+CREATE TABLE projection_cursors (
+    projection_name VARCHAR(64) PRIMARY KEY NOT NULL,
+    last_event_id BIGINT NOT NULL CHECK(last_event_id >= 0),
+    updated_at VARCHAR(40) NOT NULL
+) ENGINE=InnoDB;
+
+-- Leave slots on each day of each round in each area
+CREATE TABLE projected_daily_slots (
+    area_id BIGINT NOT NULL,
+    round_id BIGINT NOT NULL,
+    slot_date VARCHAR(10) NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    capacity INT NOT NULL CHECK(capacity >= 0),
+    used INT NOT NULL CHECK(used >= 0),
+    remaining INT NOT NULL CHECK(remaining >= 0),
+    PRIMARY KEY (area_id, round_id, slot_date)
+) ENGINE=InnoDB;
+
+-- Approved leave per user
+CREATE TABLE projected_user_awards (
+    user_id BIGINT PRIMARY KEY NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    area_id BIGINT NOT NULL,
+    awarded_days INT NOT NULL CHECK(awarded_days >= 0),
+    awarded_hours INT NOT NULL CHECK(awarded_hours >= 0)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_projected_user_awards_area ON projected_user_awards(area_id);
+
+-- Bid status counts per round in each area
+CREATE TABLE projected_area_progress (
+    area_id BIGINT NOT NULL,
+    round_id BIGINT NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    bidders INT NOT NULL CHECK(bidders >= 0),
+    not_started INT NOT NULL CHECK(not_started >= 0),
+    in_progress INT NOT NULL CHECK(in_progress >= 0),
+    completed INT NOT NULL CHECK(completed >= 0),
+    PRIMARY KEY (area_id, round_id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_projected_area_progress_bid_year ON projected_area_progress(bid_year_id);
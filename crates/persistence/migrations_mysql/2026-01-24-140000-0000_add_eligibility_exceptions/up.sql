@@ -0,0 +1,20 @@
+-- Facility-configured eligibility exceptions, per bid year
+-- Every user type may bid unless an exception says otherwise. An exception
+-- with an area_id applies only in that area and takes precedence over one
+-- without. can_bid is the eligibility the exception grants.
+CREATE TABLE eligibility_exceptions (
+    eligibility_exception_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    user_type VARCHAR(16) NOT NULL,
+    area_id BIGINT,
+    can_bid INT NOT NULL CHECK(can_bid IN (0, 1)),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_eligibility_exceptions_bid_year ON eligibility_exceptions(bid_year_id);
+CREATE INDEX idx_eligibility_exceptions_area ON eligibility_exceptions(area_id);
+
+-- The eligibility the rules derive, beneath any override. can_bid remains
+-- the effective value. Every user was eligible before rules existed.
+ALTER TABLE canonical_eligibility ADD COLUMN derived_can_bid INT NOT NULL DEFAULT 1;
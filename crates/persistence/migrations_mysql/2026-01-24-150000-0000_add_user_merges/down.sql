@@ -0,0 +1,10 @@
+DROP TABLE IF EXISTS user_merge_rows;
+
+-- Drop indexes first
+DROP INDEX idx_user_merges_revert_event ON user_merges;
+DROP INDEX idx_user_merges_merge_event ON user_merges;
+DROP INDEX idx_user_merges_merged_user ON user_merges;
+DROP INDEX idx_user_merges_kept_user ON user_merges;
+DROP INDEX idx_user_merges_bid_year ON user_merges;
+
+DROP TABLE IF EXISTS user_merges;
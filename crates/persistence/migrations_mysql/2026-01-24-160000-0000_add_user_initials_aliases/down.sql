@@ -0,0 +1,6 @@
+-- Drop indexes first
+DROP INDEX idx_user_initials_aliases_change_event ON user_initials_aliases;
+DROP INDEX idx_user_initials_aliases_user ON user_initials_aliases;
+DROP INDEX idx_user_initials_aliases_by_initials ON user_initials_aliases;
+
+DROP TABLE IF EXISTS user_initials_aliases;
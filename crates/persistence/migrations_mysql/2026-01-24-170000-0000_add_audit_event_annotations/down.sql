@@ -0,0 +1,5 @@
+-- Drop indexes first
+DROP INDEX idx_audit_event_annotations_operator ON audit_event_annotations;
+DROP INDEX idx_audit_event_annotations_event ON audit_event_annotations;
+
+DROP TABLE IF EXISTS audit_event_annotations;
@@ -0,0 +1,5 @@
+-- Drop indexes first
+DROP INDEX idx_user_anonymizations_event ON user_anonymizations;
+DROP INDEX idx_user_anonymizations_bid_year ON user_anonymizations;
+
+DROP TABLE IF EXISTS user_anonymizations;
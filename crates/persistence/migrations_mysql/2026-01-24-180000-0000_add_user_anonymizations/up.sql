@@ -0,0 +1,17 @@
+-- Users whose personal data was erased after the retention period
+-- Names and seniority dates are replaced with tombstone values in the users
+-- table and in stored state snapshots; initials and structural history are
+-- kept. event_id is the audit event that recorded the erasure.
+CREATE TABLE user_anonymizations (
+    user_id BIGINT PRIMARY KEY NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    event_id BIGINT NOT NULL,
+    snapshots_rewritten INT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(event_id) REFERENCES audit_events(event_id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_user_anonymizations_bid_year ON user_anonymizations(bid_year_id);
+CREATE INDEX idx_user_anonymizations_event ON user_anonymizations(event_id);
@@ -0,0 +1,5 @@
+-- Drop indexes first
+DROP INDEX idx_operator_area_scopes_area ON operator_area_scopes;
+
+DROP TABLE IF EXISTS operator_area_scopes;
+ALTER TABLE operators DROP COLUMN failed_login_count;
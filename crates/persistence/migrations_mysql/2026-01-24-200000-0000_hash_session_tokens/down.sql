@@ -0,0 +1,4 @@
+-- Digests cannot be turned back into tokens, so sessions are dropped again
+DELETE FROM sessions;
+
+ALTER TABLE sessions RENAME COLUMN session_token_hash TO session_token;
@@ -0,0 +1,6 @@
+-- Store a keyed digest of each session token instead of the token itself
+-- Existing sessions hold plaintext tokens and cannot be converted without
+-- the tokens, so every operator signs in again after this migration.
+DELETE FROM sessions;
+
+ALTER TABLE sessions RENAME COLUMN session_token TO session_token_hash;
@@ -0,0 +1,2 @@
+DROP INDEX idx_audit_events_event_ulid ON audit_events;
+ALTER TABLE audit_events DROP COLUMN event_ulid;
@@ -0,0 +1,2 @@
+DROP INDEX idx_event_outbox_pending ON event_outbox;
+DROP TABLE IF EXISTS event_outbox;
@@ -0,0 +1,14 @@
+-- Transactional outbox of audit events awaiting publication
+-- A row is written in the same transaction as its audit event, so no
+-- committed event can be missed by the relay. delivered_at is set once
+-- the relay has handed the event to every subscriber.
+CREATE TABLE event_outbox (
+    outbox_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    audit_event_id BIGINT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    delivered_at DATETIME NULL,
+    FOREIGN KEY(audit_event_id) REFERENCES audit_events(event_id)
+) ENGINE=InnoDB;
+
+-- Index for reading undelivered rows in order
+CREATE INDEX idx_event_outbox_pending ON event_outbox(delivered_at, outbox_id);
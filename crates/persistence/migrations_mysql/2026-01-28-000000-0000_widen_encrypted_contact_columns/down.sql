@@ -0,0 +1,2 @@
+ALTER TABLE notification_log MODIFY COLUMN recipient VARCHAR(320) NOT NULL;
+ALTER TABLE user_contacts MODIFY COLUMN email VARCHAR(320) NOT NULL;
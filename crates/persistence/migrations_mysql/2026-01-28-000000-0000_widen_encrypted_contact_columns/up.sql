@@ -0,0 +1,4 @@
+-- Email addresses may be stored encrypted, which makes them longer than the
+-- 320 characters an address can have in plaintext
+ALTER TABLE user_contacts MODIFY COLUMN email TEXT NOT NULL;
+ALTER TABLE notification_log MODIFY COLUMN recipient TEXT NOT NULL;
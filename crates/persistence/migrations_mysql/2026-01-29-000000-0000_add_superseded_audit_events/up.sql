@@ -0,0 +1,13 @@
+-- Audit events superseded by a rollback, one row per superseded event
+-- Rolling an area back supersedes its events after the target. The events
+-- themselves are never changed or deleted; a row here marks one inactive.
+-- rollback_event_id links the row to the Rollback event that superseded it.
+CREATE TABLE superseded_audit_events (
+    event_id BIGINT PRIMARY KEY NOT NULL,
+    rollback_event_id BIGINT NOT NULL,
+    superseded_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(event_id) REFERENCES audit_events(event_id),
+    FOREIGN KEY(rollback_event_id) REFERENCES audit_events(event_id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_superseded_audit_events_rollback ON superseded_audit_events(rollback_event_id);
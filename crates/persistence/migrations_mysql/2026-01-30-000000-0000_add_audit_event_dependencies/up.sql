@@ -0,0 +1,14 @@
+-- Dependencies between audit events in different scopes
+-- An event derived from other scopes' state, such as a bid year's
+-- canonicalization deriving bid order from its areas, depends on the latest
+-- event in each scope it read. A rollback that supersedes a depended-on
+-- event invalidates its dependents.
+CREATE TABLE audit_event_dependencies (
+    event_id BIGINT NOT NULL,
+    depends_on_event_id BIGINT NOT NULL,
+    PRIMARY KEY(event_id, depends_on_event_id),
+    FOREIGN KEY(event_id) REFERENCES audit_events(event_id),
+    FOREIGN KEY(depends_on_event_id) REFERENCES audit_events(event_id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_audit_event_dependencies_depends_on ON audit_event_dependencies(depends_on_event_id);
@@ -0,0 +1,11 @@
+-- Correlation IDs linking audit events recorded by one operation
+-- The API records an automatic checkpoint before each destructive
+-- operation; the checkpoints and the operation's own events share a
+-- correlation ID, so the recovery point for an operation can be found.
+CREATE TABLE audit_event_correlations (
+    event_id BIGINT PRIMARY KEY NOT NULL,
+    correlation_id VARCHAR(26) NOT NULL,
+    FOREIGN KEY(event_id) REFERENCES audit_events(event_id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_audit_event_correlations_correlation_id ON audit_event_correlations(correlation_id);
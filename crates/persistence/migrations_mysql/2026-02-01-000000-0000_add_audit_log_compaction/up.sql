@@ -0,0 +1,41 @@
+-- Audit log compactions of completed bid years, one per bid year
+-- Compacting a bid year moves its area events that nothing else references
+-- into archived_audit_events, chained by SHA-256 in event order. chain_head
+-- is the hash of the last archived event, so the archive can be checked
+-- against the compaction that wrote it.
+CREATE TABLE audit_compactions (
+    compaction_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    bid_year_id BIGINT NOT NULL UNIQUE,
+    archived_event_count INT NOT NULL,
+    first_event_id BIGINT,
+    last_event_id BIGINT,
+    chain_head VARCHAR(64) NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+) ENGINE=InnoDB;
+
+-- Audit events moved out of the live log by a compaction
+-- Rows keep their original event IDs and columns. chain_hash is the SHA-256
+-- of the previous row's chain_hash followed by this row's columns.
+CREATE TABLE archived_audit_events (
+    event_id BIGINT PRIMARY KEY NOT NULL,
+    compaction_id BIGINT NOT NULL,
+    bid_year_id BIGINT,
+    area_id BIGINT,
+    year INT NOT NULL,
+    area_code VARCHAR(255) NOT NULL,
+    actor_operator_id BIGINT NOT NULL,
+    actor_login_name VARCHAR(255) NOT NULL,
+    actor_display_name VARCHAR(255) NOT NULL,
+    actor_json TEXT NOT NULL,
+    cause_json TEXT NOT NULL,
+    action_json TEXT NOT NULL,
+    before_snapshot_json TEXT NOT NULL,
+    after_snapshot_json TEXT NOT NULL,
+    created_at DATETIME,
+    event_ulid VARCHAR(26),
+    chain_hash VARCHAR(64) NOT NULL,
+    FOREIGN KEY(compaction_id) REFERENCES audit_compactions(compaction_id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_archived_audit_events_compaction ON archived_audit_events(compaction_id, event_id);
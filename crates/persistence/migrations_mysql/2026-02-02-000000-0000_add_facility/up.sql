@@ -0,0 +1,15 @@
+-- Facility-level configuration
+-- The single row holds the defaults bid years inherit: the timezone bid
+-- schedules are declared in, the leave accrual rate of each service tier,
+-- and the annual leave carryover cap.
+CREATE TABLE facility (
+    facility_id BIGINT PRIMARY KEY NOT NULL CHECK(facility_id = 1),
+    identifier VARCHAR(4) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    default_timezone VARCHAR(64) NOT NULL,
+    junior_accrual_hours INT NOT NULL CHECK(junior_accrual_hours > 0),
+    mid_accrual_hours INT NOT NULL CHECK(mid_accrual_hours > 0),
+    senior_accrual_hours INT NOT NULL CHECK(senior_accrual_hours > 0),
+    max_carryover_hours INT NOT NULL CHECK(max_carryover_hours > 0),
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+) ENGINE=InnoDB;
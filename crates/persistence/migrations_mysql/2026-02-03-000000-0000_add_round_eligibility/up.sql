@@ -0,0 +1,13 @@
+-- Per-round eligibility criteria
+-- A round with a row only admits users meeting every criterion set on it:
+-- at least min_remaining_hours of leave remaining, when set, and one of the
+-- comma-separated user_types, when not empty. Rounds without a row admit
+-- every bidder.
+CREATE TABLE round_eligibility (
+    round_id BIGINT PRIMARY KEY NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    min_remaining_hours INT CHECK(min_remaining_hours >= 0),
+    user_types VARCHAR(255) NOT NULL,
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+) ENGINE=InnoDB;
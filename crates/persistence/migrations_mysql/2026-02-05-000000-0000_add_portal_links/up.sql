@@ -0,0 +1,14 @@
+-- Controller portal links
+-- Each row lets one controller read their own standing in a bid year
+-- without an operator account. Only the HMAC digest of the link token is
+-- stored. A controller holds at most one link; issuing a new one replaces it.
+CREATE TABLE portal_links (
+    portal_link_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    token_hash VARCHAR(255) NOT NULL UNIQUE,
+    user_id BIGINT NOT NULL UNIQUE,
+    bid_year_id BIGINT NOT NULL,
+    expires_at VARCHAR(64) NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id)
+) ENGINE=InnoDB;
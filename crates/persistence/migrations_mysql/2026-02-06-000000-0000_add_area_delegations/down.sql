@@ -0,0 +1,4 @@
+-- Drop indexes first
+DROP INDEX idx_area_delegations_delegate ON area_delegations;
+
+DROP TABLE IF EXISTS area_delegations;
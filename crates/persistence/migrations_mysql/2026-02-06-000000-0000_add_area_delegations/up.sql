@@ -0,0 +1,19 @@
+-- Area rep delegations
+-- An area's rep (an operator assigned to the area) hands their bidding
+-- authority for the area to another operator for a range of dates, for
+-- example while on vacation. Dates are inclusive (YYYY-MM-DD); a
+-- delegation stops applying after its end date without further action.
+CREATE TABLE area_delegations (
+    area_delegation_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    area_id BIGINT NOT NULL,
+    delegator_operator_id BIGINT NOT NULL,
+    delegate_operator_id BIGINT NOT NULL,
+    start_date VARCHAR(10) NOT NULL,
+    end_date VARCHAR(10) NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(delegator_operator_id) REFERENCES operators(operator_id),
+    FOREIGN KEY(delegate_operator_id) REFERENCES operators(operator_id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_area_delegations_delegate ON area_delegations(delegate_operator_id);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS self_bid_requests;
+DROP TABLE IF EXISTS operator_controllers;
@@ -0,0 +1,42 @@
+-- The controller an operator is in the roster, if any
+-- An operator linked to a controller's initials cannot enter that
+-- controller's leave alone; see self_bid_requests.
+CREATE TABLE operator_controllers (
+    operator_id BIGINT PRIMARY KEY NOT NULL,
+    initials VARCHAR(16) NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY(operator_id) REFERENCES operators(operator_id)
+) ENGINE=InnoDB;
+
+-- Leave an operator asked to enter for themselves
+-- status is 'pending' until a second operator confirms it, then
+-- 'confirmed'. leave_dates lists the days as YYYY-MM-DD separated by '|'.
+-- request_event_id and confirm_event_id link the row to the audit events
+-- that recorded the request and the confirmation.
+CREATE TABLE self_bid_requests (
+    self_bid_request_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    bid_year_id BIGINT NOT NULL,
+    area_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    round_id BIGINT NOT NULL,
+    requested_by_operator_id BIGINT NOT NULL,
+    leave_dates TEXT NOT NULL,
+    hours INT NOT NULL CHECK(hours > 0),
+    on_behalf_of VARCHAR(16) NOT NULL,
+    received_via VARCHAR(16) NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'pending' CHECK(status IN ('pending', 'confirmed')),
+    request_event_id BIGINT,
+    confirmed_by_operator_id BIGINT,
+    confirm_event_id BIGINT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    confirmed_at DATETIME,
+    FOREIGN KEY(bid_year_id) REFERENCES bid_years(bid_year_id),
+    FOREIGN KEY(area_id) REFERENCES areas(area_id),
+    FOREIGN KEY(user_id) REFERENCES users(user_id),
+    FOREIGN KEY(round_id) REFERENCES rounds(round_id),
+    FOREIGN KEY(requested_by_operator_id) REFERENCES operators(operator_id),
+    FOREIGN KEY(request_event_id) REFERENCES audit_events(event_id),
+    FOREIGN KEY(confirmed_by_operator_id) REFERENCES operators(operator_id),
+    FOREIGN KEY(confirm_event_id) REFERENCES audit_events(event_id),
+    INDEX idx_self_bid_requests_round (area_id, round_id, status)
+) ENGINE=InnoDB;
@@ -0,0 +1,16 @@
+-- Named audit timeline filters saved by admins
+-- scope_json and filter_json hold the timeline scope and filters as JSON.
+-- weekly_recipients lists the addresses the results are emailed to each
+-- week, separated by ','; the query is not scheduled when it is empty.
+-- last_delivered_at is the RFC 3339 time of the last weekly delivery.
+CREATE TABLE saved_audit_queries (
+    saved_query_id BIGINT PRIMARY KEY AUTO_INCREMENT NOT NULL,
+    name VARCHAR(100) NOT NULL UNIQUE,
+    scope_json TEXT NOT NULL,
+    filter_json TEXT NOT NULL,
+    weekly_recipients TEXT NOT NULL,
+    created_by_operator_id BIGINT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_delivered_at VARCHAR(40),
+    FOREIGN KEY(created_by_operator_id) REFERENCES operators(operator_id)
+) ENGINE=InnoDB;
@@ -0,0 +1,3 @@
+DROP INDEX idx_audit_events_user ON audit_events;
+ALTER TABLE archived_audit_events DROP COLUMN user_id;
+ALTER TABLE audit_events DROP COLUMN user_id;
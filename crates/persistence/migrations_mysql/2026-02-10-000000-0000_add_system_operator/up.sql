@@ -0,0 +1,9 @@
+-- The built-in system operator
+-- Audit events with no operator behind them are attributed to it. It is
+-- disabled and its password hash matches no password, so it cannot sign in.
+-- An explicit zero is only kept as the ID under NO_AUTO_VALUE_ON_ZERO.
+SET @saved_sql_mode = @@SESSION.sql_mode;
+SET SESSION sql_mode = CONCAT_WS(',', NULLIF(@@SESSION.sql_mode, ''), 'NO_AUTO_VALUE_ON_ZERO');
+INSERT INTO operators (operator_id, login_name, display_name, password_hash, role, is_disabled)
+VALUES (0, 'SYSTEM', 'System', '!', 'Bidder', 1);
+SET SESSION sql_mode = @saved_sql_mode;
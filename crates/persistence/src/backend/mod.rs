@@ -32,6 +32,7 @@ pub mod sqlite;
 
 use diesel::{Connection, MysqlConnection, SqliteConnection};
 
+use crate::data_models::MigrationStatus;
 use crate::error::PersistenceError;
 
 /// Trait for backend-specific operations.
@@ -63,6 +64,27 @@ pub trait PersistenceBackend: Connection {
     ///
     /// Returns an error if foreign key enforcement is not enabled.
     fn verify_foreign_key_enforcement(&mut self) -> Result<(), PersistenceError>;
+
+    /// Reports whether foreign key enforcement is enabled, without logging.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the setting cannot be queried.
+    fn foreign_keys_enabled(&mut self) -> Result<bool, PersistenceError>;
+
+    /// Reports pending and applied migrations for this backend.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the migration table cannot be read.
+    fn migration_status(&mut self) -> Result<MigrationStatus, PersistenceError>;
+
+    /// Issues a trivial query to confirm the connection is usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be reached.
+    fn ping(&mut self) -> Result<(), PersistenceError>;
 }
 
 impl PersistenceBackend for SqliteConnection {
@@ -73,6 +95,18 @@ impl PersistenceBackend for SqliteConnection {
     fn verify_foreign_key_enforcement(&mut self) -> Result<(), PersistenceError> {
         sqlite::verify_foreign_key_enforcement(self)
     }
+
+    fn foreign_keys_enabled(&mut self) -> Result<bool, PersistenceError> {
+        sqlite::foreign_keys_enabled(self)
+    }
+
+    fn migration_status(&mut self) -> Result<MigrationStatus, PersistenceError> {
+        sqlite::migration_status(self)
+    }
+
+    fn ping(&mut self) -> Result<(), PersistenceError> {
+        sqlite::ping(self)
+    }
 }
 
 impl PersistenceBackend for MysqlConnection {
@@ -83,4 +117,16 @@ impl PersistenceBackend for MysqlConnection {
     fn verify_foreign_key_enforcement(&mut self) -> Result<(), PersistenceError> {
         mysql::verify_foreign_key_enforcement(self)
     }
+
+    fn foreign_keys_enabled(&mut self) -> Result<bool, PersistenceError> {
+        mysql::foreign_keys_enabled(self)
+    }
+
+    fn migration_status(&mut self) -> Result<MigrationStatus, PersistenceError> {
+        mysql::migration_status(self)
+    }
+
+    fn ping(&mut self) -> Result<(), PersistenceError> {
+        mysql::ping(self)
+    }
 }
@@ -87,6 +87,7 @@ use diesel::{Connection, MysqlConnection, QueryableByName, RunQueryDsl};
 use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
 use tracing::info;
 
+use crate::data_models::MigrationStatus;
 use crate::error::PersistenceError;
 
 /// Result type for foreign key check query.
@@ -175,22 +176,61 @@ pub fn run_migrations(
 ///
 /// Returns an error if verification fails.
 pub fn verify_foreign_key_enforcement(conn: &mut MysqlConnection) -> Result<(), PersistenceError> {
+    if foreign_keys_enabled(conn)? {
+        info!("MySQL foreign key enforcement is enabled");
+        Ok(())
+    } else {
+        Err(PersistenceError::ForeignKeyEnforcementNotEnabled)
+    }
+}
+
+/// Reports whether foreign key enforcement is enabled, without logging.
+///
+/// # Errors
+///
+/// Returns an error if the system variable cannot be queried.
+pub fn foreign_keys_enabled(conn: &mut MysqlConnection) -> Result<bool, PersistenceError> {
     // Query foreign_key_checks system variable
     // NOTE: This is raw SQL (justified - Diesel has no system variable query DSL)
     let result: Result<ForeignKeyCheck, _> =
         diesel::sql_query("SELECT @@foreign_key_checks AS fk_checks").get_result(conn);
 
     match result {
-        Ok(check) => {
-            if check.fk_checks == 1 {
-                info!("MySQL foreign key enforcement is enabled");
-                Ok(())
-            } else {
-                Err(PersistenceError::ForeignKeyEnforcementNotEnabled)
-            }
-        }
+        Ok(check) => Ok(check.fk_checks == 1),
         Err(e) => Err(PersistenceError::QueryFailed(format!(
             "Failed to verify foreign key enforcement: {e}"
         ))),
     }
 }
+
+/// Reports the number of pending migrations and the latest applied version.
+///
+/// # Errors
+///
+/// Returns an error if the migration table cannot be read.
+pub fn migration_status(conn: &mut MysqlConnection) -> Result<MigrationStatus, PersistenceError> {
+    let pending: usize = conn
+        .pending_migrations(MYSQL_MIGRATIONS)
+        .map_err(|e| PersistenceError::MigrationFailed(e.to_string()))?
+        .len();
+    let latest_applied: Option<String> = conn
+        .applied_migrations()
+        .map_err(|e| PersistenceError::MigrationFailed(e.to_string()))?
+        .iter()
+        .map(ToString::to_string)
+        .max();
+    Ok(MigrationStatus {
+        pending,
+        latest_applied,
+    })
+}
+
+/// Issues a trivial query to confirm the connection is usable.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be reached.
+pub fn ping(conn: &mut MysqlConnection) -> Result<(), PersistenceError> {
+    diesel::select(sql::<Integer>("1")).get_result::<i32>(conn)?;
+    Ok(())
+}
@@ -22,11 +22,12 @@
 
 use diesel::dsl::sql;
 use diesel::prelude::*;
-use diesel::sql_types::{BigInt, Integer};
+use diesel::sql_types::{BigInt, Integer, Text};
 use diesel::{Connection, RunQueryDsl, SqliteConnection};
 use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
 use tracing::info;
 
+use crate::data_models::{MigrationStatus, QueryPlanStep};
 use crate::error::PersistenceError;
 
 /// SQLite-specific migrations.
@@ -44,6 +45,24 @@ struct PragmaRow {
     foreign_keys: i32,
 }
 
+/// Helper row struct for `EXPLAIN QUERY PLAN` output.
+#[derive(QueryableByName)]
+struct QueryPlanRow {
+    #[diesel(sql_type = Integer)]
+    id: i32,
+    #[diesel(sql_type = Integer)]
+    parent: i32,
+    #[diesel(sql_type = Text)]
+    detail: String,
+}
+
+/// Helper row struct for the `wal_checkpoint` PRAGMA result.
+#[derive(QueryableByName)]
+struct WalCheckpointRow {
+    #[diesel(sql_type = Integer)]
+    busy: i32,
+}
+
 /// Helper function to get the last inserted row ID.
 ///
 /// `SQLite` doesn't support `RETURNING` clauses in all contexts,
@@ -76,16 +95,64 @@ pub fn get_last_insert_rowid(conn: &mut SqliteConnection) -> Result<i64, Persist
 ///
 /// Returns an error if foreign key enforcement is not enabled.
 pub fn verify_foreign_key_enforcement(conn: &mut SqliteConnection) -> Result<(), PersistenceError> {
+    if !foreign_keys_enabled(conn)? {
+        return Err(PersistenceError::ForeignKeyEnforcementNotEnabled);
+    }
+
+    info!("SQLite foreign key enforcement is enabled");
+    Ok(())
+}
+
+/// Reports whether foreign key enforcement is enabled, without logging.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection to check
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn foreign_keys_enabled(conn: &mut SqliteConnection) -> Result<bool, PersistenceError> {
     // NOTE: PRAGMA is raw SQL (justified - Diesel has no PRAGMA DSL)
     let foreign_keys_enabled: i32 = diesel::sql_query("PRAGMA foreign_keys")
         .get_result::<PragmaRow>(conn)?
         .foreign_keys;
+    Ok(foreign_keys_enabled != 0)
+}
 
-    if foreign_keys_enabled == 0 {
-        return Err(PersistenceError::ForeignKeyEnforcementNotEnabled);
-    }
+/// Reports the number of pending migrations and the latest applied version.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection to check
+///
+/// # Errors
+///
+/// Returns an error if the migration table cannot be read.
+pub fn migration_status(conn: &mut SqliteConnection) -> Result<MigrationStatus, PersistenceError> {
+    let pending: usize = conn
+        .pending_migrations(MIGRATIONS)
+        .map_err(|e| PersistenceError::MigrationFailed(e.to_string()))?
+        .len();
+    let latest_applied: Option<String> = conn
+        .applied_migrations()
+        .map_err(|e| PersistenceError::MigrationFailed(e.to_string()))?
+        .iter()
+        .map(ToString::to_string)
+        .max();
+    Ok(MigrationStatus {
+        pending,
+        latest_applied,
+    })
+}
 
-    info!("SQLite foreign key enforcement is enabled");
+/// Issues a trivial query to confirm the connection is usable.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be reached.
+pub fn ping(conn: &mut SqliteConnection) -> Result<(), PersistenceError> {
+    diesel::select(sql::<Integer>("1")).get_result::<i32>(conn)?;
     Ok(())
 }
 
@@ -119,6 +186,22 @@ pub fn run_migrations(
 ///
 /// Returns an error if connection or migration fails.
 pub fn initialize_database(database_url: &str) -> Result<SqliteConnection, PersistenceError> {
+    let mut conn: SqliteConnection = open_database(database_url)?;
+    run_migrations(&mut conn).map_err(|e| PersistenceError::MigrationFailed(e.to_string()))?;
+    Ok(conn)
+}
+
+/// Open a `SQLite` database with foreign key enforcement, without running
+/// migrations.
+///
+/// # Arguments
+///
+/// * `database_url` - The `SQLite` database URL (e.g., `":memory:"` or file path)
+///
+/// # Errors
+///
+/// Returns an error if the connection fails.
+pub fn open_database(database_url: &str) -> Result<SqliteConnection, PersistenceError> {
     info!("Initializing SQLite database at: {}", database_url);
 
     let mut conn: SqliteConnection = SqliteConnection::establish(database_url)
@@ -130,8 +213,6 @@ pub fn initialize_database(database_url: &str) -> Result<SqliteConnection, Persi
         .execute(&mut conn)
         .map_err(|e| PersistenceError::QueryFailed(e.to_string()))?;
 
-    run_migrations(&mut conn).map_err(|e| PersistenceError::MigrationFailed(e.to_string()))?;
-
     Ok(conn)
 }
 
@@ -154,3 +235,79 @@ pub fn enable_wal_mode(conn: &mut SqliteConnection) -> Result<(), PersistenceErr
         .map_err(|e| PersistenceError::QueryFailed(e.to_string()))?;
     Ok(())
 }
+
+/// Copies every committed WAL frame into the main database file and
+/// truncates the WAL.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the checkpoint cannot be run.
+pub fn checkpoint_wal(conn: &mut SqliteConnection) -> Result<(), PersistenceError> {
+    // NOTE: PRAGMA is raw SQL (justified - Diesel has no PRAGMA DSL)
+    // A busy checkpoint returns a row rather than an error
+    let checkpoint: WalCheckpointRow = diesel::sql_query("PRAGMA wal_checkpoint(TRUNCATE)")
+        .get_result(conn)
+        .map_err(|e| PersistenceError::QueryFailed(e.to_string()))?;
+    if checkpoint.busy != 0 {
+        return Err(PersistenceError::QueryFailed(String::from(
+            "WAL checkpoint blocked by another connection",
+        )));
+    }
+    info!("SQLite WAL checkpointed");
+    Ok(())
+}
+
+/// Reports how `SQLite` would execute a statement, without running it.
+///
+/// Bind parameters in `sql` are left unbound; the plan does not depend on
+/// their values.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `sql` - The statement to explain
+///
+/// # Errors
+///
+/// Returns an error if the statement cannot be prepared.
+pub fn explain_query_plan(
+    conn: &mut SqliteConnection,
+    sql: &str,
+) -> Result<Vec<QueryPlanStep>, PersistenceError> {
+    // NOTE: EXPLAIN is raw SQL (justified - Diesel has no EXPLAIN DSL)
+    let rows: Vec<QueryPlanRow> = diesel::sql_query(format!("EXPLAIN QUERY PLAN {sql}"))
+        .load(conn)
+        .map_err(|e| PersistenceError::QueryFailed(e.to_string()))?;
+    Ok(rows
+        .into_iter()
+        .map(|row| QueryPlanStep {
+            id: row.id,
+            parent: row.parent,
+            detail: row.detail,
+        })
+        .collect())
+}
+
+/// Writes a consistent copy of the database to a new file.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `destination` - Path of the backup file; it must not already exist
+///
+/// # Errors
+///
+/// Returns an error if the backup cannot be written.
+pub fn backup_to(conn: &mut SqliteConnection, destination: &str) -> Result<(), PersistenceError> {
+    // NOTE: VACUUM INTO is raw SQL (justified - Diesel has no backup DSL)
+    diesel::sql_query("VACUUM INTO ?")
+        .bind::<diesel::sql_types::Text, _>(destination)
+        .execute(conn)
+        .map_err(|e| PersistenceError::QueryFailed(e.to_string()))?;
+    info!(destination, "SQLite database backed up");
+    Ok(())
+}
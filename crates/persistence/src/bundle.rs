@@ -0,0 +1,822 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Bid year bundles.
+//!
+//! A bundle is a self-contained document, conventionally written as JSON to
+//! a `.zab` file, holding one bid year's configuration, roster, rounds, bid
+//! schedule, audit log, and state snapshots, along with a manifest of the
+//! format and schema versions it was written with. Bundles move a bid year
+//! between databases, including between `SQLite` and `MySQL`, and archive
+//! completed years offsite.
+//!
+//! Rows keep the IDs they had in the exporting database. Importing assigns
+//! fresh IDs and rewrites every reference to them, including the user IDs
+//! inside snapshots, so a bundle can be imported alongside other bid years.
+//! Audit events also keep their ULIDs, which identify them across databases.
+//!
+//! Format version 1 does not carry canonicalization output, bid windows,
+//! bids, leave, or notification state.
+//!
+//! All functions are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use std::collections::{BTreeSet, HashMap};
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use serde::{Deserialize, Serialize};
+use zab_bid::State;
+use zab_bid_domain::{Area, BidYear, User};
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::SnapshotEncoding;
+use crate::diesel_schema::{
+    area_bid_schedule_overrides, areas, audit_events, bid_amendment_policies, bid_rules,
+    bid_year_blackout_dates, bid_years, eligibility_exceptions, feature_flags, leave_caps,
+    operators, prime_periods, round_crew_slots, round_eligibility, round_group_rotations,
+    round_groups, round_prime_caps, rounds, state_snapshots, users,
+};
+use crate::error::PersistenceError;
+use crate::mutations::audit::encode_state;
+use crate::queries::state::StateSnapshotRow;
+
+/// The bundle format this build writes and reads.
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// A complete bid year, as exported from one database for import into another.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BidYearBundle {
+    pub manifest: BundleManifest,
+    pub bid_year: BundleBidYear,
+    pub feature_flags: Vec<BundleFeatureFlag>,
+    pub bid_rules: Vec<BundleBidRule>,
+    pub blackout_dates: Vec<BundleBlackoutDate>,
+    pub amendment_policy: Option<BundleAmendmentPolicy>,
+    pub prime_periods: Vec<BundlePrimePeriod>,
+    pub leave_cap: Option<BundleLeaveCap>,
+    pub round_groups: Vec<BundleRoundGroup>,
+    /// Absent from bundles written before round groups had bid order
+    /// rotations.
+    #[serde(default)]
+    pub round_group_rotations: Vec<BundleRoundGroupRotation>,
+    pub rounds: Vec<BundleRound>,
+    pub round_prime_caps: Vec<BundleRoundPrimeCap>,
+    pub round_crew_slots: Vec<BundleRoundCrewSlots>,
+    /// Absent from bundles written before rounds had eligibility criteria.
+    #[serde(default)]
+    pub round_eligibility: Vec<BundleRoundEligibility>,
+    pub areas: Vec<BundleArea>,
+    pub area_schedule_overrides: Vec<BundleAreaScheduleOverride>,
+    /// Absent from bundles written before eligibility exceptions existed.
+    #[serde(default)]
+    pub eligibility_exceptions: Vec<BundleEligibilityException>,
+    pub users: Vec<BundleUser>,
+    pub audit_events: Vec<BundleAuditEvent>,
+    pub snapshots: Vec<BundleSnapshot>,
+}
+
+/// What a bundle holds and what wrote it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleManifest {
+    /// The bundle format, see [`BUNDLE_FORMAT_VERSION`].
+    pub format_version: u32,
+    /// The exporting database's latest applied migration.
+    pub schema_version: Option<String>,
+    /// The bundled bid year.
+    pub year: u16,
+    /// When the bundle was written (RFC 3339).
+    pub exported_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = bid_years)]
+pub struct BundleBidYear {
+    pub bid_year_id: i64,
+    pub year: i32,
+    pub start_date: String,
+    pub num_pay_periods: i32,
+    pub is_active: i32,
+    pub expected_area_count: Option<i32>,
+    pub lifecycle_state: String,
+    pub label: Option<String>,
+    pub notes: Option<String>,
+    pub bid_timezone: Option<String>,
+    pub bid_start_date: Option<String>,
+    pub bid_window_start_time: Option<String>,
+    pub bid_window_end_time: Option<String>,
+    pub bidders_per_area_per_day: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = feature_flags)]
+pub struct BundleFeatureFlag {
+    pub flag: String,
+    pub enabled: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = bid_rules)]
+pub struct BundleBidRule {
+    pub rule_position: i32,
+    pub rule_kind: String,
+    pub rule_value: Option<i32>,
+    pub rule_dates: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = bid_year_blackout_dates)]
+pub struct BundleBlackoutDate {
+    pub blackout_date: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = bid_amendment_policies)]
+pub struct BundleAmendmentPolicy {
+    pub policy: String,
+    pub window_hours: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = prime_periods)]
+pub struct BundlePrimePeriod {
+    pub label: String,
+    pub start_date: String,
+    pub end_date: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = leave_caps)]
+pub struct BundleLeaveCap {
+    pub carryover_cap_hours: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = round_groups)]
+pub struct BundleRoundGroup {
+    pub round_group_id: i64,
+    pub name: String,
+    pub editing_enabled: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = round_group_rotations)]
+pub struct BundleRoundGroupRotation {
+    pub round_group_id: i64,
+    pub rotation: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = rounds)]
+pub struct BundleRound {
+    pub round_id: i64,
+    pub round_group_id: i64,
+    pub round_number: i32,
+    pub name: String,
+    pub slots_per_day: i32,
+    pub max_groups: i32,
+    pub max_total_hours: i32,
+    pub include_holidays: i32,
+    pub allow_overbid: i32,
+    pub crew_partitioned: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = round_prime_caps)]
+pub struct BundleRoundPrimeCap {
+    pub round_id: i64,
+    pub prime_slots_per_day: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = round_crew_slots)]
+pub struct BundleRoundCrewSlots {
+    pub round_id: i64,
+    pub crew: i32,
+    pub slots_per_day: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = round_eligibility)]
+pub struct BundleRoundEligibility {
+    pub round_id: i64,
+    pub min_remaining_hours: Option<i32>,
+    pub user_types: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = areas)]
+pub struct BundleArea {
+    pub area_id: i64,
+    pub area_code: String,
+    pub area_name: Option<String>,
+    pub expected_user_count: Option<i32>,
+    pub is_system_area: i32,
+    pub round_group_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = area_bid_schedule_overrides)]
+pub struct BundleAreaScheduleOverride {
+    pub area_id: i64,
+    pub bid_start_date: Option<String>,
+    pub bid_window_start_time: Option<String>,
+    pub bid_window_end_time: Option<String>,
+    pub bidders_per_area_per_day: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = eligibility_exceptions)]
+pub struct BundleEligibilityException {
+    pub user_type: String,
+    pub area_id: Option<i64>,
+    pub can_bid: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = users)]
+pub struct BundleUser {
+    pub user_id: i64,
+    pub area_id: i64,
+    pub initials: String,
+    pub name: String,
+    pub user_type: String,
+    pub crew: Option<i32>,
+    pub cumulative_natca_bu_date: String,
+    pub natca_bu_date: String,
+    pub eod_faa_date: String,
+    pub service_computation_date: String,
+    pub lottery_value: Option<i32>,
+    pub excluded_from_bidding: i32,
+    pub excluded_from_leave_calculation: i32,
+    pub no_bid_reviewed: i32,
+}
+
+/// An audit event scoped to the bundled bid year.
+///
+/// The acting operator is identified by login name; importing requires an
+/// operator with that login to exist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = audit_events)]
+pub struct BundleAuditEvent {
+    pub event_id: i64,
+    pub area_id: Option<i64>,
+    pub year: i32,
+    pub area_code: String,
+    pub actor_login_name: String,
+    pub actor_display_name: String,
+    pub actor_json: String,
+    pub cause_json: String,
+    pub action_json: String,
+    pub before_snapshot_json: String,
+    pub after_snapshot_json: String,
+    pub created_at: Option<String>,
+    /// Kept on import, so the event has the same ULID in both databases.
+    /// Absent from bundles written before events had ULIDs.
+    #[serde(default)]
+    pub event_ulid: Option<String>,
+}
+
+/// A decoded state snapshot.
+///
+/// Snapshots are bundled decoded so that importing can rewrite the user IDs
+/// inside them, and are re-encoded in the importing database's encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleSnapshot {
+    pub area_id: i64,
+    pub event_id: i64,
+    pub created_at: Option<String>,
+    pub users: Vec<User>,
+}
+
+/// Looks up the imported ID for a bundled one.
+fn remap(ids: &HashMap<i64, i64>, id: i64, what: &str) -> Result<i64, PersistenceError> {
+    ids.get(&id).copied().ok_or_else(|| {
+        PersistenceError::ReconstructionError(format!("Bundle references unknown {what} {id}"))
+    })
+}
+
+backend_fn! {
+/// Exports a bid year as a bundle.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `year` - The bid year to export
+/// * `exported_at` - When the bundle is written (RFC 3339)
+///
+/// # Errors
+///
+/// Returns an error if the bid year does not exist or cannot be read.
+#[allow(clippy::too_many_lines)]
+pub fn export_bid_year(
+    conn: &mut _,
+    year: u16,
+    exported_at: &str,
+) -> Result<BidYearBundle, PersistenceError> {
+    let bid_year: BundleBidYear = bid_years::table
+        .filter(bid_years::year.eq(i32::from(year)))
+        .select(BundleBidYear::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or_else(|| PersistenceError::NotFound(format!("Bid year {year} not found")))?;
+    let bid_year_id: i64 = bid_year.bid_year_id;
+
+    let round_groups: Vec<BundleRoundGroup> = round_groups::table
+        .filter(round_groups::bid_year_id.eq(bid_year_id))
+        .order(round_groups::round_group_id.asc())
+        .select(BundleRoundGroup::as_select())
+        .load(conn)?;
+    let round_group_ids: Vec<i64> = round_groups.iter().map(|group| group.round_group_id).collect();
+    let areas: Vec<BundleArea> = areas::table
+        .filter(areas::bid_year_id.eq(bid_year_id))
+        .order(areas::area_id.asc())
+        .select(BundleArea::as_select())
+        .load(conn)?;
+    let area_ids: Vec<i64> = areas.iter().map(|area| area.area_id).collect();
+
+    let snapshots: Vec<BundleSnapshot> = state_snapshots::table
+        .filter(state_snapshots::bid_year_id.eq(bid_year_id))
+        .order(state_snapshots::snapshot_id.asc())
+        .select((
+            state_snapshots::area_id,
+            state_snapshots::created_at,
+            StateSnapshotRow::as_select(),
+        ))
+        .load::<(i64, Option<String>, StateSnapshotRow)>(conn)?
+        .into_iter()
+        .map(|(area_id, created_at, row)| {
+            let (state, event_id): (State, i64) = row.into_state()?;
+            Ok(BundleSnapshot {
+                area_id,
+                event_id,
+                created_at,
+                users: state.users.values().cloned().collect(),
+            })
+        })
+        .collect::<Result<_, PersistenceError>>()?;
+
+    Ok(BidYearBundle {
+        manifest: BundleManifest {
+            format_version: BUNDLE_FORMAT_VERSION,
+            schema_version: conn.migration_status()?.latest_applied,
+            year,
+            exported_at: exported_at.to_string(),
+        },
+        feature_flags: feature_flags::table
+            .filter(feature_flags::bid_year_id.eq(bid_year_id))
+            .order(feature_flags::flag.asc())
+            .select(BundleFeatureFlag::as_select())
+            .load(conn)?,
+        bid_rules: bid_rules::table
+            .filter(bid_rules::bid_year_id.eq(bid_year_id))
+            .order(bid_rules::rule_position.asc())
+            .select(BundleBidRule::as_select())
+            .load(conn)?,
+        blackout_dates: bid_year_blackout_dates::table
+            .filter(bid_year_blackout_dates::bid_year_id.eq(bid_year_id))
+            .order(bid_year_blackout_dates::blackout_date.asc())
+            .select(BundleBlackoutDate::as_select())
+            .load(conn)?,
+        amendment_policy: bid_amendment_policies::table
+            .filter(bid_amendment_policies::bid_year_id.eq(bid_year_id))
+            .select(BundleAmendmentPolicy::as_select())
+            .first(conn)
+            .optional()?,
+        prime_periods: prime_periods::table
+            .filter(prime_periods::bid_year_id.eq(bid_year_id))
+            .order(prime_periods::prime_period_id.asc())
+            .select(BundlePrimePeriod::as_select())
+            .load(conn)?,
+        leave_cap: leave_caps::table
+            .filter(leave_caps::bid_year_id.eq(bid_year_id))
+            .select(BundleLeaveCap::as_select())
+            .first(conn)
+            .optional()?,
+        rounds: rounds::table
+            .filter(rounds::round_group_id.eq_any(&round_group_ids))
+            .order(rounds::round_id.asc())
+            .select(BundleRound::as_select())
+            .load(conn)?,
+        round_prime_caps: round_prime_caps::table
+            .filter(round_prime_caps::bid_year_id.eq(bid_year_id))
+            .order(round_prime_caps::round_id.asc())
+            .select(BundleRoundPrimeCap::as_select())
+            .load(conn)?,
+        round_crew_slots: round_crew_slots::table
+            .filter(round_crew_slots::bid_year_id.eq(bid_year_id))
+            .order((round_crew_slots::round_id.asc(), round_crew_slots::crew.asc()))
+            .select(BundleRoundCrewSlots::as_select())
+            .load(conn)?,
+        round_group_rotations: round_group_rotations::table
+            .filter(round_group_rotations::bid_year_id.eq(bid_year_id))
+            .order(round_group_rotations::round_group_id.asc())
+            .select(BundleRoundGroupRotation::as_select())
+            .load(conn)?,
+        round_eligibility: round_eligibility::table
+            .filter(round_eligibility::bid_year_id.eq(bid_year_id))
+            .order(round_eligibility::round_id.asc())
+            .select(BundleRoundEligibility::as_select())
+            .load(conn)?,
+        area_schedule_overrides: area_bid_schedule_overrides::table
+            .filter(area_bid_schedule_overrides::area_id.eq_any(&area_ids))
+            .order(area_bid_schedule_overrides::area_id.asc())
+            .select(BundleAreaScheduleOverride::as_select())
+            .load(conn)?,
+        eligibility_exceptions: eligibility_exceptions::table
+            .filter(eligibility_exceptions::bid_year_id.eq(bid_year_id))
+            .order(eligibility_exceptions::eligibility_exception_id.asc())
+            .select(BundleEligibilityException::as_select())
+            .load(conn)?,
+        users: users::table
+            .filter(users::bid_year_id.eq(bid_year_id))
+            .order(users::user_id.asc())
+            .select(BundleUser::as_select())
+            .load(conn)?,
+        audit_events: audit_events::table
+            .filter(audit_events::bid_year_id.eq(bid_year_id))
+            .order(audit_events::event_id.asc())
+            .select(BundleAuditEvent::as_select())
+            .load(conn)?,
+        bid_year,
+        round_groups,
+        areas,
+        snapshots,
+    })
+}
+}
+
+backend_fn! {
+/// Imports a bundled bid year under fresh IDs.
+///
+/// The bid year is imported inactive. Callers run this inside a
+/// transaction so a failed import leaves nothing behind.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bundle` - The bundle to import
+/// * `encoding` - How to encode the imported snapshots
+///
+/// # Returns
+///
+/// The imported bid year's ID.
+///
+/// # Errors
+///
+/// Returns an error if the bundle's format is not supported, the bid year
+/// already exists, an acting operator does not exist, or the bundle
+/// references a row it does not contain.
+#[allow(clippy::too_many_lines)]
+pub fn import_bid_year(
+    conn: &mut _,
+    bundle: &BidYearBundle,
+    encoding: SnapshotEncoding,
+) -> Result<i64, PersistenceError> {
+    if bundle.manifest.format_version != BUNDLE_FORMAT_VERSION {
+        return Err(PersistenceError::Other(format!(
+            "Unsupported bundle format version {} (expected {BUNDLE_FORMAT_VERSION})",
+            bundle.manifest.format_version
+        )));
+    }
+    let source: &BundleBidYear = &bundle.bid_year;
+    let existing: i64 = bid_years::table
+        .filter(bid_years::year.eq(source.year))
+        .count()
+        .get_result(conn)?;
+    if existing > 0 {
+        return Err(PersistenceError::Other(format!(
+            "Bid year {} already exists",
+            source.year
+        )));
+    }
+
+    // Operators are matched by login name, which is stored uppercase
+    let logins: BTreeSet<String> = bundle
+        .audit_events
+        .iter()
+        .map(|event| event.actor_login_name.to_uppercase())
+        .collect();
+    let operator_ids: HashMap<String, i64> = operators::table
+        .filter(operators::login_name.eq_any(&logins))
+        .select((operators::login_name, operators::operator_id))
+        .load::<(String, i64)>(conn)?
+        .into_iter()
+        .collect();
+    let missing: Vec<&str> = logins
+        .iter()
+        .filter(|login| !operator_ids.contains_key(*login))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(PersistenceError::OperatorNotFound(missing.join(", ")));
+    }
+
+    diesel::insert_into(bid_years::table)
+        .values((
+            bid_years::year.eq(source.year),
+            bid_years::start_date.eq(&source.start_date),
+            bid_years::num_pay_periods.eq(source.num_pay_periods),
+            bid_years::is_active.eq(0),
+            bid_years::expected_area_count.eq(source.expected_area_count),
+            bid_years::lifecycle_state.eq(&source.lifecycle_state),
+            bid_years::label.eq(&source.label),
+            bid_years::notes.eq(&source.notes),
+            bid_years::bid_timezone.eq(&source.bid_timezone),
+            bid_years::bid_start_date.eq(&source.bid_start_date),
+            bid_years::bid_window_start_time.eq(&source.bid_window_start_time),
+            bid_years::bid_window_end_time.eq(&source.bid_window_end_time),
+            bid_years::bidders_per_area_per_day.eq(source.bidders_per_area_per_day),
+        ))
+        .execute(conn)?;
+    let bid_year_id: i64 = conn.get_last_insert_rowid()?;
+
+    for flag in &bundle.feature_flags {
+        diesel::insert_into(feature_flags::table)
+            .values((
+                feature_flags::bid_year_id.eq(bid_year_id),
+                feature_flags::flag.eq(&flag.flag),
+                feature_flags::enabled.eq(flag.enabled),
+            ))
+            .execute(conn)?;
+    }
+    for rule in &bundle.bid_rules {
+        diesel::insert_into(bid_rules::table)
+            .values((
+                bid_rules::bid_year_id.eq(bid_year_id),
+                bid_rules::rule_position.eq(rule.rule_position),
+                bid_rules::rule_kind.eq(&rule.rule_kind),
+                bid_rules::rule_value.eq(rule.rule_value),
+                bid_rules::rule_dates.eq(&rule.rule_dates),
+                bid_rules::created_at.eq(&rule.created_at),
+            ))
+            .execute(conn)?;
+    }
+    for blackout in &bundle.blackout_dates {
+        diesel::insert_into(bid_year_blackout_dates::table)
+            .values((
+                bid_year_blackout_dates::bid_year_id.eq(bid_year_id),
+                bid_year_blackout_dates::blackout_date.eq(&blackout.blackout_date),
+                bid_year_blackout_dates::reason.eq(&blackout.reason),
+            ))
+            .execute(conn)?;
+    }
+    if let Some(policy) = &bundle.amendment_policy {
+        diesel::insert_into(bid_amendment_policies::table)
+            .values((
+                bid_amendment_policies::bid_year_id.eq(bid_year_id),
+                bid_amendment_policies::policy.eq(&policy.policy),
+                bid_amendment_policies::window_hours.eq(policy.window_hours),
+            ))
+            .execute(conn)?;
+    }
+    for period in &bundle.prime_periods {
+        diesel::insert_into(prime_periods::table)
+            .values((
+                prime_periods::bid_year_id.eq(bid_year_id),
+                prime_periods::label.eq(&period.label),
+                prime_periods::start_date.eq(&period.start_date),
+                prime_periods::end_date.eq(&period.end_date),
+                prime_periods::created_at.eq(&period.created_at),
+            ))
+            .execute(conn)?;
+    }
+    if let Some(cap) = &bundle.leave_cap {
+        diesel::insert_into(leave_caps::table)
+            .values((
+                leave_caps::bid_year_id.eq(bid_year_id),
+                leave_caps::carryover_cap_hours.eq(cap.carryover_cap_hours),
+            ))
+            .execute(conn)?;
+    }
+
+    let mut round_group_ids: HashMap<i64, i64> = HashMap::new();
+    for group in &bundle.round_groups {
+        diesel::insert_into(round_groups::table)
+            .values((
+                round_groups::bid_year_id.eq(bid_year_id),
+                round_groups::name.eq(&group.name),
+                round_groups::editing_enabled.eq(group.editing_enabled),
+            ))
+            .execute(conn)?;
+        round_group_ids.insert(group.round_group_id, conn.get_last_insert_rowid()?);
+    }
+    for rotation in &bundle.round_group_rotations {
+        diesel::insert_into(round_group_rotations::table)
+            .values((
+                round_group_rotations::round_group_id.eq(remap(
+                    &round_group_ids,
+                    rotation.round_group_id,
+                    "round group",
+                )?),
+                round_group_rotations::bid_year_id.eq(bid_year_id),
+                round_group_rotations::rotation.eq(&rotation.rotation),
+            ))
+            .execute(conn)?;
+    }
+    let mut round_ids: HashMap<i64, i64> = HashMap::new();
+    for round in &bundle.rounds {
+        let round_group_id: i64 = remap(&round_group_ids, round.round_group_id, "round group")?;
+        diesel::insert_into(rounds::table)
+            .values((
+                rounds::round_group_id.eq(round_group_id),
+                rounds::round_number.eq(round.round_number),
+                rounds::name.eq(&round.name),
+                rounds::slots_per_day.eq(round.slots_per_day),
+                rounds::max_groups.eq(round.max_groups),
+                rounds::max_total_hours.eq(round.max_total_hours),
+                rounds::include_holidays.eq(round.include_holidays),
+                rounds::allow_overbid.eq(round.allow_overbid),
+                rounds::crew_partitioned.eq(round.crew_partitioned),
+            ))
+            .execute(conn)?;
+        round_ids.insert(round.round_id, conn.get_last_insert_rowid()?);
+    }
+    for cap in &bundle.round_prime_caps {
+        diesel::insert_into(round_prime_caps::table)
+            .values((
+                round_prime_caps::round_id.eq(remap(&round_ids, cap.round_id, "round")?),
+                round_prime_caps::bid_year_id.eq(bid_year_id),
+                round_prime_caps::prime_slots_per_day.eq(cap.prime_slots_per_day),
+            ))
+            .execute(conn)?;
+    }
+    for slots in &bundle.round_crew_slots {
+        diesel::insert_into(round_crew_slots::table)
+            .values((
+                round_crew_slots::round_id.eq(remap(&round_ids, slots.round_id, "round")?),
+                round_crew_slots::bid_year_id.eq(bid_year_id),
+                round_crew_slots::crew.eq(slots.crew),
+                round_crew_slots::slots_per_day.eq(slots.slots_per_day),
+            ))
+            .execute(conn)?;
+    }
+    for criteria in &bundle.round_eligibility {
+        diesel::insert_into(round_eligibility::table)
+            .values((
+                round_eligibility::round_id.eq(remap(&round_ids, criteria.round_id, "round")?),
+                round_eligibility::bid_year_id.eq(bid_year_id),
+                round_eligibility::min_remaining_hours.eq(criteria.min_remaining_hours),
+                round_eligibility::user_types.eq(&criteria.user_types),
+            ))
+            .execute(conn)?;
+    }
+
+    let mut area_ids: HashMap<i64, i64> = HashMap::new();
+    let mut imported_areas: HashMap<String, Area> = HashMap::new();
+    for area in &bundle.areas {
+        let round_group_id: Option<i64> = area
+            .round_group_id
+            .map(|id| remap(&round_group_ids, id, "round group"))
+            .transpose()?;
+        diesel::insert_into(areas::table)
+            .values((
+                areas::bid_year_id.eq(bid_year_id),
+                areas::area_code.eq(&area.area_code),
+                areas::area_name.eq(&area.area_name),
+                areas::expected_user_count.eq(area.expected_user_count),
+                areas::is_system_area.eq(area.is_system_area),
+                areas::round_group_id.eq(round_group_id),
+            ))
+            .execute(conn)?;
+        let area_id: i64 = conn.get_last_insert_rowid()?;
+        area_ids.insert(area.area_id, area_id);
+        imported_areas.insert(
+            area.area_code.clone(),
+            Area::with_id(
+                area_id,
+                &area.area_code,
+                area.area_name.clone(),
+                area.is_system_area != 0,
+                round_group_id,
+            ),
+        );
+    }
+    for schedule in &bundle.area_schedule_overrides {
+        use area_bid_schedule_overrides::dsl as overrides;
+        diesel::insert_into(area_bid_schedule_overrides::table)
+            .values((
+                overrides::area_id.eq(remap(&area_ids, schedule.area_id, "area")?),
+                overrides::bid_start_date.eq(&schedule.bid_start_date),
+                overrides::bid_window_start_time.eq(&schedule.bid_window_start_time),
+                overrides::bid_window_end_time.eq(&schedule.bid_window_end_time),
+                overrides::bidders_per_area_per_day.eq(schedule.bidders_per_area_per_day),
+            ))
+            .execute(conn)?;
+    }
+    for exception in &bundle.eligibility_exceptions {
+        let area_id: Option<i64> = exception
+            .area_id
+            .map(|id| remap(&area_ids, id, "area"))
+            .transpose()?;
+        diesel::insert_into(eligibility_exceptions::table)
+            .values((
+                eligibility_exceptions::bid_year_id.eq(bid_year_id),
+                eligibility_exceptions::user_type.eq(&exception.user_type),
+                eligibility_exceptions::area_id.eq(area_id),
+                eligibility_exceptions::can_bid.eq(exception.can_bid),
+            ))
+            .execute(conn)?;
+    }
+
+    let mut user_ids: HashMap<i64, i64> = HashMap::new();
+    for user in &bundle.users {
+        diesel::insert_into(users::table)
+            .values((
+                users::bid_year_id.eq(bid_year_id),
+                users::area_id.eq(remap(&area_ids, user.area_id, "area")?),
+                users::initials.eq(&user.initials),
+                users::name.eq(&user.name),
+                users::user_type.eq(&user.user_type),
+                users::crew.eq(user.crew),
+                users::cumulative_natca_bu_date.eq(&user.cumulative_natca_bu_date),
+                users::natca_bu_date.eq(&user.natca_bu_date),
+                users::eod_faa_date.eq(&user.eod_faa_date),
+                users::service_computation_date.eq(&user.service_computation_date),
+                users::lottery_value.eq(user.lottery_value),
+                users::excluded_from_bidding.eq(user.excluded_from_bidding),
+                users::excluded_from_leave_calculation.eq(user.excluded_from_leave_calculation),
+                users::no_bid_reviewed.eq(user.no_bid_reviewed),
+            ))
+            .execute(conn)?;
+        user_ids.insert(user.user_id, conn.get_last_insert_rowid()?);
+    }
+
+    let mut event_ids: HashMap<i64, i64> = HashMap::new();
+    for event in &bundle.audit_events {
+        let area_id: Option<i64> = event
+            .area_id
+            .map(|id| remap(&area_ids, id, "area"))
+            .transpose()?;
+        diesel::insert_into(audit_events::table)
+            .values((
+                audit_events::bid_year_id.eq(bid_year_id),
+                audit_events::area_id.eq(area_id),
+                audit_events::year.eq(event.year),
+                audit_events::area_code.eq(&event.area_code),
+                audit_events::actor_operator_id.eq(operator_ids[&event.actor_login_name.to_uppercase()]),
+                audit_events::actor_login_name.eq(&event.actor_login_name),
+                audit_events::actor_display_name.eq(&event.actor_display_name),
+                audit_events::actor_json.eq(&event.actor_json),
+                audit_events::cause_json.eq(&event.cause_json),
+                audit_events::action_json.eq(&event.action_json),
+                audit_events::before_snapshot_json.eq(&event.before_snapshot_json),
+                audit_events::after_snapshot_json.eq(&event.after_snapshot_json),
+                audit_events::created_at.eq(&event.created_at),
+                audit_events::event_ulid.eq(&event.event_ulid),
+            ))
+            .execute(conn)?;
+        event_ids.insert(event.event_id, conn.get_last_insert_rowid()?);
+    }
+
+    let year: u16 = bundle.manifest.year;
+    for snapshot in &bundle.snapshots {
+        let area_code: &str = bundle
+            .areas
+            .iter()
+            .find(|area| area.area_id == snapshot.area_id)
+            .map(|area| area.area_code.as_str())
+            .ok_or_else(|| {
+                PersistenceError::ReconstructionError(format!(
+                    "Bundle references unknown area {}",
+                    snapshot.area_id
+                ))
+            })?;
+        let users: Vec<User> = snapshot
+            .users
+            .iter()
+            .map(|user| {
+                let mut user: User = user.clone();
+                // Users removed since the snapshot have no imported row
+                user.user_id = user.user_id.and_then(|id| user_ids.get(&id).copied());
+                user.bid_year = BidYear::with_id(bid_year_id, year);
+                if let Some(area) = imported_areas.get(user.area.id()) {
+                    user.area = area.clone();
+                }
+                user
+            })
+            .collect();
+        let state: State = State::with_users(BidYear::new(year), Area::new(area_code), users);
+        let (state_json, state_binary): (String, Option<Vec<u8>>) = encode_state(&state, encoding)?;
+
+        diesel::insert_into(state_snapshots::table)
+            .values((
+                state_snapshots::bid_year_id.eq(bid_year_id),
+                state_snapshots::area_id.eq(remap(&area_ids, snapshot.area_id, "area")?),
+                state_snapshots::event_id.eq(remap(&event_ids, snapshot.event_id, "audit event")?),
+                state_snapshots::state_json.eq(state_json),
+                state_snapshots::created_at.eq(&snapshot.created_at),
+                state_snapshots::state_format.eq(encoding.as_str()),
+                state_snapshots::state_binary.eq(state_binary),
+            ))
+            .execute(conn)?;
+    }
+
+    Ok(bid_year_id)
+}
+}
@@ -0,0 +1,242 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Encryption of sensitive columns.
+//!
+//! # Threat Model
+//!
+//! A copied database file or backup must not reveal bidders' personal
+//! contact details. Columns holding them are encrypted with AES-256-GCM
+//! under a [`ColumnKey`] that lives in server configuration (or is injected
+//! by a KMS) and never in the database. Today that covers notification email
+//! addresses: `user_contacts.email` and `notification_log.recipient`.
+//!
+//! Each value is bound to its column and row as associated data, so a
+//! ciphertext copied into another row or column fails to decrypt instead of
+//! revealing one bidder's address as another's.
+//!
+//! # Stored Form
+//!
+//! ```text
+//! enc:v1:<key id>:<hex of 12-byte nonce, ciphertext, and 16-byte tag>
+//! ```
+//!
+//! The key id is a fingerprint of the key, so a value records which key it
+//! was sealed under without revealing it. Values without the `enc:` prefix
+//! are plaintext, written before a key was configured, and are read as-is.
+//!
+//! # Key Rotation
+//!
+//! A [`ColumnKeyring`] holds the current key and, during a rotation, the
+//! previous one. New values are always sealed under the current key, and
+//! values sealed under either key can be read.
+//! [`crate::Persistence::reseal_sensitive_columns`] rewrites plaintext
+//! values and values under the previous key under the current key; once it
+//! has run, the previous key can be dropped.
+
+use std::fmt;
+
+use aes_gcm::aead::{Aead, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use sha2::{Digest, Sha256};
+
+use crate::error::PersistenceError;
+
+/// Length of a column key, in bytes.
+pub const COLUMN_KEY_LENGTH: usize = 32;
+
+/// Marks an encrypted value, and names its format version.
+const PREFIX: &str = "enc:v1:";
+
+/// Length of the random nonce stored with each value, in bytes.
+const NONCE_LENGTH: usize = 12;
+
+/// A key sensitive columns are encrypted with.
+///
+/// Redacted from `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ColumnKey([u8; COLUMN_KEY_LENGTH]);
+
+impl ColumnKey {
+    /// Creates a key from raw bytes.
+    #[must_use]
+    pub const fn new(bytes: [u8; COLUMN_KEY_LENGTH]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the fingerprint stored with each value sealed under the key.
+    fn id(&self) -> String {
+        let digest = Sha256::new()
+            .chain_update(b"zabbid column key")
+            .chain_update(self.0)
+            .finalize();
+        hex::encode(&digest[..4])
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(&self.0.into())
+    }
+
+    /// Encrypts a value for the column and row named by `context`.
+    fn seal(&self, context: &str, plaintext: &str) -> String {
+        let nonce: [u8; NONCE_LENGTH] = rand::random();
+        let ciphertext: Vec<u8> = self
+            .cipher()
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: plaintext.as_bytes(),
+                    aad: context.as_bytes(),
+                },
+            )
+            .unwrap_or_else(|_| unreachable!("AES-GCM encrypts any column value"));
+
+        let mut sealed: Vec<u8> = Vec::with_capacity(NONCE_LENGTH + ciphertext.len());
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(&ciphertext);
+        format!("{PREFIX}{}:{}", self.id(), hex::encode(sealed))
+    }
+
+    /// Decrypts the hex body of a value sealed under this key.
+    fn open(&self, context: &str, body: &str) -> Result<String, PersistenceError> {
+        let sealed: Vec<u8> = hex::decode(body)
+            .ok()
+            .filter(|sealed| sealed.len() >= NONCE_LENGTH)
+            .ok_or_else(|| {
+                PersistenceError::DecryptionFailed(format!("{context}: malformed value"))
+            })?;
+        let (nonce, ciphertext): (&[u8], &[u8]) = sealed.split_at(NONCE_LENGTH);
+
+        let plaintext: Vec<u8> = self
+            .cipher()
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: context.as_bytes(),
+                },
+            )
+            .map_err(|_| {
+                PersistenceError::DecryptionFailed(format!(
+                    "{context}: value was modified or belongs to another row"
+                ))
+            })?;
+        String::from_utf8(plaintext)
+            .map_err(|_| PersistenceError::DecryptionFailed(format!("{context}: not UTF-8")))
+    }
+}
+
+impl std::str::FromStr for ColumnKey {
+    type Err = String;
+
+    /// Parses a key written as 64 hex digits.
+    ///
+    /// The error never repeats the secret.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes: [u8; COLUMN_KEY_LENGTH] = [0; COLUMN_KEY_LENGTH];
+        hex::decode_to_slice(s, &mut bytes).map_err(|_| {
+            format!(
+                "Column encryption key must be {} hex digits",
+                COLUMN_KEY_LENGTH * 2
+            )
+        })?;
+        Ok(Self(bytes))
+    }
+}
+
+impl fmt::Debug for ColumnKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ColumnKey(<redacted>)")
+    }
+}
+
+/// The keys sensitive columns are encrypted with.
+#[derive(Debug, Clone)]
+pub struct ColumnKeyring {
+    current: ColumnKey,
+    previous: Option<ColumnKey>,
+}
+
+impl ColumnKeyring {
+    /// Creates a keyring from the current key and, during a rotation, the
+    /// previous one.
+    #[must_use]
+    pub const fn new(current: ColumnKey, previous: Option<ColumnKey>) -> Self {
+        Self { current, previous }
+    }
+
+    /// Returns whether a previous key is still accepted.
+    #[must_use]
+    pub const fn is_rotating(&self) -> bool {
+        self.previous.is_some()
+    }
+
+    /// Encrypts a value under the current key.
+    ///
+    /// `context` names the column and row the value is stored in, e.g.
+    /// `user_contacts.email:42`.
+    #[must_use]
+    pub fn seal(&self, context: &str, plaintext: &str) -> String {
+        self.current.seal(context, plaintext)
+    }
+
+    /// Decrypts a stored value, passing plaintext values through.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value was sealed under neither key, or has
+    /// been modified or moved from another row.
+    pub fn open(&self, context: &str, stored: &str) -> Result<String, PersistenceError> {
+        let Some((key_id, body)) = split_sealed(stored) else {
+            return Ok(stored.to_string());
+        };
+        let key: &ColumnKey = std::iter::once(&self.current)
+            .chain(&self.previous)
+            .find(|key| key.id() == key_id)
+            .ok_or_else(|| {
+                PersistenceError::DecryptionFailed(format!(
+                    "{context}: sealed under unknown key {key_id}"
+                ))
+            })?;
+        key.open(context, body)
+    }
+
+    /// Returns whether a stored value is not yet sealed under the current key.
+    #[must_use]
+    pub fn needs_reseal(&self, stored: &str) -> bool {
+        split_sealed(stored).is_none_or(|(key_id, _)| key_id != self.current.id())
+    }
+}
+
+/// Splits a sealed value into its key id and hex body, or returns `None`
+/// for a plaintext value.
+fn split_sealed(stored: &str) -> Option<(&str, &str)> {
+    stored.strip_prefix(PREFIX)?.split_once(':')
+}
+
+/// Decrypts a stored value without a keyring, which only succeeds for
+/// plaintext values.
+///
+/// # Errors
+///
+/// Returns an error if the value is encrypted.
+pub fn open_without_key(context: &str, stored: &str) -> Result<String, PersistenceError> {
+    if split_sealed(stored).is_some() {
+        return Err(PersistenceError::DecryptionFailed(format!(
+            "{context}: value is encrypted but no column encryption key is configured"
+        )));
+    }
+    Ok(stored.to_string())
+}
+
+/// Names a `user_contacts.email` value for [`ColumnKeyring::seal`].
+pub fn email_context(user_id: i64) -> String {
+    format!("user_contacts.email:{user_id}")
+}
+
+/// Names a `notification_log.recipient` value for [`ColumnKeyring::seal`].
+pub fn recipient_context(user_id: i64) -> String {
+    format!("notification_log.recipient:{user_id}")
+}
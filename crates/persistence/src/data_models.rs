@@ -4,6 +4,7 @@
 // https://opensource.org/licenses/MIT.
 
 use serde::{Deserialize, Serialize};
+use zab_bid_domain::{Area, BidYear, User};
 
 /// Serializable representation of an Actor.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,6 +41,56 @@ pub struct StateData {
     pub users_json: String,
 }
 
+/// Binary representation of the full State.
+///
+/// Serialized with `postcard`, which encodes fields and enum variants by
+/// position, so fields must only ever be appended. `U` is `&User` when
+/// writing and `User` when reading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BinaryStateData<U> {
+    pub bid_year: u16,
+    pub area: String,
+    pub users: Vec<U>,
+}
+
+/// How full state snapshots are encoded when they are written.
+///
+/// Each snapshot records its own encoding, so snapshots of either kind can
+/// be read whatever the current setting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SnapshotEncoding {
+    /// JSON text, readable when inspecting the database directly.
+    #[default]
+    Json,
+    /// Compact `postcard` binary, smaller and faster to write and parse.
+    Postcard,
+}
+
+impl SnapshotEncoding {
+    /// Returns the `state_format` tag stored with each snapshot.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Postcard => "postcard",
+        }
+    }
+}
+
+impl std::str::FromStr for SnapshotEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "postcard" => Ok(Self::Postcard),
+            _ => Err(format!(
+                "Unknown snapshot encoding: '{s}'. Valid options: json, postcard"
+            )),
+        }
+    }
+}
+
 /// Type alias for audit event row data from `SQLite`.
 ///
 /// Phase 23A: Now includes `bid_year_id` and `area_id` in addition to display values.
@@ -78,11 +129,31 @@ pub struct OperatorData {
     pub last_login_at: Option<String>,
 }
 
+/// An area an operator is assigned to look after.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperatorAreaScopeData {
+    pub area_id: i64,
+    pub bid_year_id: i64,
+    pub year: u16,
+    pub area_code: String,
+}
+
+/// An operator together with their sign-in activity and area scopes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperatorActivityData {
+    pub operator: OperatorData,
+    /// Sessions that have not yet expired.
+    pub active_session_count: usize,
+    /// Consecutive failed sign-in attempts since the last successful one.
+    pub failed_login_count: u32,
+    pub area_scopes: Vec<OperatorAreaScopeData>,
+}
+
 /// Serializable representation of a Session.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionData {
     pub session_id: i64,
-    pub session_token: String,
+    pub session_token_hash: String,
     pub operator_id: i64,
     pub created_at: String,
     pub last_activity_at: String,
@@ -127,6 +198,7 @@ pub struct CanonicalEligibilityRow {
     pub can_bid: i32,
     pub is_overridden: i32,
     pub override_reason: Option<String>,
+    pub derived_can_bid: i32,
 }
 
 /// Canonical eligibility insertable (diesel insertable).
@@ -139,6 +211,7 @@ pub struct NewCanonicalEligibility {
     pub can_bid: i32,
     pub is_overridden: i32,
     pub override_reason: Option<String>,
+    pub derived_can_bid: i32,
 }
 
 /// Canonical bid order row (diesel queryable).
@@ -314,3 +387,1271 @@ pub struct NewBidStatusHistory {
     pub transitioned_by: i64,
     pub notes: Option<String>,
 }
+
+/// Scope selector for paged audit timeline queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditTimelineScope {
+    /// Every event regardless of scope (used by change feeds).
+    All,
+    /// Events with no bid year or area scope (operator management, system events).
+    Global,
+    /// All events recorded against a bid year, including area-scoped events.
+    BidYear { bid_year_id: i64 },
+    /// Events recorded against a single area within a bid year.
+    Area { bid_year_id: i64, area_id: i64 },
+}
+
+/// Optional filters applied to paged audit timeline queries.
+///
+/// All filters are conjunctive. Timestamps are compared against `created_at`
+/// using the database's native `YYYY-MM-DD HH:MM:SS` representation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditTimelineFilter {
+    /// Only include events whose action name matches exactly.
+    pub action_name: Option<String>,
+    /// Only include events performed by this operator.
+    pub actor_operator_id: Option<i64>,
+    /// Only include events created at or after this timestamp.
+    pub created_from: Option<String>,
+    /// Only include events created at or before this timestamp.
+    pub created_to: Option<String>,
+    /// Only include events concerning this user.
+    pub user_id: Option<i64>,
+    /// Every initials the filtered user has held.
+    ///
+    /// Events recorded before audit events carried a user ID have none, so
+    /// with `user_id` set they are matched by these initials in their
+    /// action instead.
+    pub user_initials: Vec<String>,
+}
+
+/// A single audit event together with its persisted creation timestamp.
+#[derive(Debug, Clone)]
+pub struct AuditTimelineEntry {
+    /// The reconstructed audit event.
+    pub event: zab_bid_audit::AuditEvent,
+    /// When the event was persisted, if recorded.
+    pub created_at: Option<String>,
+}
+
+/// A note an operator attached to an audit event after the fact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditAnnotationData {
+    pub annotation_id: i64,
+    /// The annotated audit event.
+    pub event_id: i64,
+    /// The operator who wrote the note.
+    pub operator_id: i64,
+    pub operator_login_name: String,
+    pub operator_display_name: String,
+    pub note: String,
+    pub created_at: String,
+}
+
+/// One page of audit timeline entries.
+#[derive(Debug, Clone)]
+pub struct AuditTimelinePage {
+    /// Entries in ascending `event_id` order.
+    pub entries: Vec<AuditTimelineEntry>,
+    /// Cursor to pass as `after_event_id` for the next page, if more entries exist.
+    pub next_cursor: Option<i64>,
+}
+
+/// An audit event's identifying fields, without its state snapshots.
+///
+/// Reading headers skips the before and after snapshots, which hold nearly
+/// all of an event's bytes. Callers that need the snapshots fetch the full
+/// events by ID afterwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEventHeader {
+    /// The audit event ID.
+    pub event_id: i64,
+    /// The action name (e.g. `RegisterUser`).
+    pub action_name: String,
+    /// The operator who performed the action, or 0 for system actors.
+    pub actor_operator_id: i64,
+    /// The login name of that operator.
+    pub actor_login_name: String,
+    /// The canonical bid year ID, or `None` for global events.
+    pub bid_year_id: Option<i64>,
+    /// The canonical area ID, or `None` for global and bid year events.
+    pub area_id: Option<i64>,
+    /// When the event was persisted, if recorded.
+    pub created_at: Option<String>,
+}
+
+/// A dependency of an audit event on an event in another scope.
+///
+/// The dependent was derived from state the depended-on event left behind,
+/// so superseding the depended-on event invalidates it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditEventDependency {
+    /// The dependent event.
+    pub event_id: i64,
+    /// The event it depends on.
+    pub depends_on_event_id: i64,
+}
+
+/// A `Checkpoint` recorded in the audit log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointData {
+    /// The checkpoint's audit event ID.
+    pub event_id: i64,
+    /// The canonical bid year ID.
+    pub bid_year_id: Option<i64>,
+    /// The canonical area ID.
+    pub area_id: Option<i64>,
+    /// The area's code.
+    pub area_code: String,
+    /// The label, or `None` for an anonymous checkpoint.
+    pub label: Option<String>,
+    /// The description, if the checkpoint has one.
+    pub description: Option<String>,
+    /// The login name of the operator who created the checkpoint.
+    pub actor_login_name: String,
+    /// When the checkpoint was persisted, if recorded.
+    pub created_at: Option<String>,
+    /// Whether a rollback has superseded the checkpoint.
+    pub superseded: bool,
+    /// For an automatic checkpoint, the correlation ID of the operation it
+    /// was recorded before.
+    pub correlation_id: Option<String>,
+}
+
+/// One page of audit event headers.
+#[derive(Debug, Clone)]
+pub struct AuditEventHeaderPage {
+    /// Headers in ascending `event_id` order.
+    pub headers: Vec<AuditEventHeader>,
+    /// Cursor to pass as `after_event_id` for the next page, if more headers exist.
+    pub next_cursor: Option<i64>,
+}
+
+/// Migration state of the connected database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    /// Number of embedded migrations not yet applied.
+    pub pending: usize,
+    /// The newest applied migration version, used as the schema version.
+    pub latest_applied: Option<String>,
+}
+
+/// One step of an `SQLite` query plan, as reported by `EXPLAIN QUERY PLAN`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryPlanStep {
+    /// The step's ID within the plan.
+    pub id: i32,
+    /// The ID of the step this one is nested under, or 0 at the top level.
+    pub parent: i32,
+    /// The planner's description, e.g. `SEARCH users USING INDEX ...`.
+    pub detail: String,
+}
+
+impl QueryPlanStep {
+    /// Returns whether this step reads a whole table rather than searching
+    /// an index.
+    ///
+    /// Scans of a covering index, subqueries, and CTEs are not table scans.
+    #[must_use]
+    pub fn is_table_scan(&self) -> bool {
+        self.detail
+            .strip_prefix("SCAN ")
+            .is_some_and(|rest| !rest.contains(" USING ") && !rest.starts_with("CONSTANT ROW"))
+    }
+}
+
+/// Result of a persistence health probe.
+///
+/// Each check is recorded independently so that a failing probe still
+/// reports which parts of the database are healthy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersistenceHealth {
+    /// The backend name (`sqlite` or `mysql`).
+    pub backend: &'static str,
+    /// Whether a trivial query succeeded.
+    pub database_reachable: bool,
+    /// Migration state, if it could be read.
+    pub migrations: Option<MigrationStatus>,
+    /// Whether foreign key enforcement is active.
+    pub foreign_keys_enforced: bool,
+    /// Error messages from failed checks.
+    pub errors: Vec<String>,
+}
+
+impl PersistenceHealth {
+    /// Returns true if the database is reachable, fully migrated, and
+    /// enforcing foreign keys.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        let migrations_current: bool = self
+            .migrations
+            .as_ref()
+            .is_some_and(|status| status.pending == 0);
+        self.database_reachable && migrations_current && self.foreign_keys_enforced
+    }
+
+    /// Returns the schema version (latest applied migration), if known.
+    #[must_use]
+    pub fn schema_version(&self) -> Option<&str> {
+        self.migrations
+            .as_ref()
+            .and_then(|status| status.latest_applied.as_deref())
+    }
+}
+
+/// One problem found by [`crate::Persistence::verify_integrity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityDiscrepancy {
+    /// The check that found it: `database`, `audit_log`, `snapshots`, or
+    /// `canonical_state`.
+    pub check: &'static str,
+    /// What it concerns, e.g. `audit event 42` or `2026/NORTH`.
+    pub subject: String,
+    /// What is wrong.
+    pub detail: String,
+}
+
+/// Result of a full database integrity check.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// Audit events read and decoded.
+    pub audit_events_checked: usize,
+    /// Snapshots whose scope was checked against their audit event.
+    pub snapshots_checked: usize,
+    /// Areas whose canonical users were compared with their latest snapshot.
+    pub areas_reconciled: usize,
+    /// Archived audit events whose hash chain link was recomputed.
+    pub archived_events_checked: usize,
+    /// Every problem found, in the order the checks ran.
+    pub discrepancies: Vec<IntegrityDiscrepancy>,
+}
+
+impl IntegrityReport {
+    /// Returns true if no check found a problem.
+    #[must_use]
+    pub const fn is_consistent(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+
+    /// Records a problem found by `check`.
+    pub(crate) fn record(
+        &mut self,
+        check: &'static str,
+        subject: impl Into<String>,
+        detail: impl Into<String>,
+    ) {
+        self.discrepancies.push(IntegrityDiscrepancy {
+            check,
+            subject: subject.into(),
+            detail: detail.into(),
+        });
+    }
+}
+
+/// A configured outbound webhook.
+///
+/// `event_filter` lists the audit action names delivered to this webhook.
+/// A filter containing [`WebhookData::ALL_EVENTS`] matches every action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookData {
+    pub webhook_id: i64,
+    pub url: String,
+    pub secret: String,
+    pub event_filter: Vec<String>,
+    pub is_enabled: bool,
+    pub created_at: String,
+}
+
+impl WebhookData {
+    /// Filter entry that matches every audit action.
+    pub const ALL_EVENTS: &'static str = "*";
+
+    /// Returns true if this webhook is enabled and subscribed to `action_name`.
+    #[must_use]
+    pub fn accepts(&self, action_name: &str) -> bool {
+        self.is_enabled
+            && self
+                .event_filter
+                .iter()
+                .any(|entry| entry == Self::ALL_EVENTS || entry == action_name)
+    }
+}
+
+/// A webhook delivery that exhausted its retries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookDeadLetterData {
+    pub dead_letter_id: i64,
+    pub webhook_id: i64,
+    pub audit_event_id: i64,
+    pub payload: String,
+    pub attempts: i32,
+    pub last_error: String,
+    pub created_at: String,
+}
+
+/// An audit event in the transactional outbox.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxEntryData {
+    pub outbox_id: i64,
+    pub audit_event_id: i64,
+    pub created_at: String,
+    pub delivered_at: Option<String>,
+}
+
+/// Contact details used to notify a user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserContactData {
+    pub user_id: i64,
+    pub email: String,
+    pub email_enabled: bool,
+    pub updated_at: String,
+}
+
+/// A bid window belonging to a contactable user in a `BiddingActive` bid year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowNotificationCandidate {
+    pub bid_window_id: i64,
+    pub user_id: i64,
+    pub initials: String,
+    pub name: String,
+    pub email: String,
+    pub year: i32,
+    pub area_code: String,
+    pub round_name: String,
+    pub window_start_datetime: String,
+    pub window_end_datetime: String,
+}
+
+/// A bid status transition for a contactable user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidEntryNotificationCandidate {
+    pub history_id: i64,
+    pub user_id: i64,
+    pub initials: String,
+    pub name: String,
+    pub email: String,
+    pub year: i32,
+    pub area_code: String,
+    pub round_name: String,
+    pub previous_status: Option<String>,
+    pub new_status: String,
+}
+
+/// A recorded notification.
+///
+/// `reference_id` identifies what the notification was about and depends on
+/// `kind` (a bid window ID or a bid status history ID).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationLogData {
+    pub notification_id: i64,
+    pub user_id: i64,
+    pub kind: String,
+    pub reference_id: i64,
+    pub recipient: String,
+    pub subject: String,
+    pub delivered: bool,
+    pub error: Option<String>,
+    pub sent_at: String,
+}
+
+/// A chat channel that receives bid announcements through an incoming webhook.
+///
+/// A channel with no `area_id` covers every area in its bid year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatChannelData {
+    pub chat_channel_id: i64,
+    pub bid_year_id: i64,
+    pub area_id: Option<i64>,
+    pub provider: String,
+    pub url: String,
+    pub is_enabled: bool,
+    pub created_at: String,
+}
+
+impl ChatChannelData {
+    /// Slack incoming webhook provider.
+    pub const PROVIDER_SLACK: &'static str = "slack";
+    /// Microsoft Teams incoming webhook provider.
+    pub const PROVIDER_TEAMS: &'static str = "teams";
+
+    /// Returns true if this channel is enabled and receives announcements for `area_id`.
+    #[must_use]
+    pub fn covers(&self, bid_year_id: i64, area_id: i64) -> bool {
+        self.is_enabled
+            && self.bid_year_id == bid_year_id
+            && self.area_id.is_none_or(|id| id == area_id)
+    }
+}
+
+/// A bid window in a `BiddingActive` bid year, with display names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveBidWindowData {
+    pub bid_window_id: i64,
+    pub bid_year_id: i64,
+    pub year: i32,
+    pub area_id: i64,
+    pub area_code: String,
+    pub round_id: i64,
+    pub round_number: i32,
+    pub round_name: String,
+    pub initials: String,
+    pub window_start_datetime: String,
+    pub window_end_datetime: String,
+}
+
+/// A recorded chat announcement.
+///
+/// `reference_key` identifies what the announcement was about and depends
+/// on `kind`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatNotificationLogData {
+    pub chat_notification_id: i64,
+    pub chat_channel_id: i64,
+    pub kind: String,
+    pub reference_key: String,
+    pub message: String,
+    pub delivered: bool,
+    pub error: Option<String>,
+    pub sent_at: String,
+}
+
+/// One row of an area's canonical seniority list.
+///
+/// `bid_order` is the materialized position used for bidding;
+/// `is_overridden` is set when that position was changed by an override.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeniorityListEntryData {
+    pub bid_order: i32,
+    pub user_id: i64,
+    pub initials: String,
+    pub name: String,
+    pub user_type: String,
+    pub crew: Option<i32>,
+    pub cumulative_natca_bu_date: String,
+    pub natca_bu_date: String,
+    pub eod_faa_date: String,
+    pub service_computation_date: String,
+    pub lottery_value: Option<i32>,
+    pub is_overridden: bool,
+}
+
+/// One user's row in a round results report.
+///
+/// `status` and the window bounds are `None` when the user has no bid
+/// status or bid window for the round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundResultEntryData {
+    pub bid_order: i32,
+    pub user_id: i64,
+    pub initials: String,
+    pub name: String,
+    pub status: Option<String>,
+    pub window_start_datetime: Option<String>,
+    pub window_end_datetime: Option<String>,
+}
+
+/// A day of leave awarded to a user within a round.
+///
+/// `on_behalf_of` and `received_via` are `None` for leave recorded before
+/// bids carried proxy attribution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaveBidData {
+    pub leave_bid_id: i64,
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub user_id: i64,
+    pub round_id: i64,
+    pub leave_date: String,
+    pub hours: i32,
+    pub status: String,
+    pub created_at: String,
+    pub on_behalf_of: Option<String>,
+    pub received_via: Option<String>,
+}
+
+impl LeaveBidData {
+    /// Leave that counts against the round's daily slots.
+    pub const STATUS_APPROVED: &'static str = "approved";
+    /// Leave that has been released and no longer occupies a slot.
+    pub const STATUS_WITHDRAWN: &'static str = "withdrawn";
+    /// How leave imported from the legacy bid tracker was received.
+    pub const RECEIVED_VIA_LEGACY_IMPORT: &'static str = "legacy_import";
+}
+
+/// One ranked leave preference submitted ahead of a user's bid window.
+///
+/// `leave_dates` holds `YYYY-MM-DD` dates in ascending order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidPreferenceData {
+    pub bid_preference_id: i64,
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub user_id: i64,
+    pub round_id: i64,
+    pub preference_rank: i32,
+    pub leave_dates: Vec<String>,
+    pub hours: i32,
+    pub on_behalf_of: String,
+    pub received_via: String,
+    pub status: String,
+    pub created_at: String,
+}
+
+impl BidPreferenceData {
+    /// A preference waiting for the user's window to open.
+    pub const STATUS_PENDING: &'static str = "pending";
+    /// The preference converted into leave when the window opened.
+    pub const STATUS_APPLIED: &'static str = "applied";
+    /// A higher-ranked preference that no longer fit when the window opened.
+    pub const STATUS_PASSED_OVER: &'static str = "passed_over";
+    /// A lower-ranked preference that was not needed.
+    pub const STATUS_UNUSED: &'static str = "unused";
+}
+
+/// A ranked leave preference to record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidPreferenceSpecData {
+    pub preference_rank: i32,
+    /// `YYYY-MM-DD` dates.
+    pub leave_dates: Vec<String>,
+    pub hours: i32,
+}
+
+/// A bid validation rule configured for a bid year.
+///
+/// `rule_dates` holds `YYYY-MM-DD` dates in ascending order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidRuleData {
+    pub bid_rule_id: i64,
+    pub bid_year_id: i64,
+    pub rule_position: i32,
+    pub rule_kind: String,
+    pub rule_value: Option<i32>,
+    pub rule_dates: Vec<String>,
+    pub created_at: String,
+}
+
+/// A bid validation rule to record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidRuleSpecData {
+    pub rule_kind: String,
+    pub rule_value: Option<i32>,
+    /// `YYYY-MM-DD` dates.
+    pub rule_dates: Vec<String>,
+}
+
+/// A facility-configured eligibility exception.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EligibilityExceptionData {
+    pub eligibility_exception_id: i64,
+    pub bid_year_id: i64,
+    pub user_type: String,
+    /// The area the exception is limited to, or `None` for the bid year.
+    pub area_id: Option<i64>,
+    pub can_bid: bool,
+}
+
+/// An eligibility exception to record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EligibilityExceptionSpecData {
+    pub user_type: String,
+    pub area_id: Option<i64>,
+    pub can_bid: bool,
+}
+
+/// A user's canonical eligibility, with the derived value beneath it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserEligibilityData {
+    pub user_id: i64,
+    pub initials: String,
+    /// The eligibility the rules derive.
+    pub derived_can_bid: bool,
+    /// The effective eligibility, which differs only when overridden.
+    pub can_bid: bool,
+    pub is_overridden: bool,
+    pub override_reason: Option<String>,
+}
+
+/// A request for leave on a day whose slots are all taken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverbidRequestData {
+    pub overbid_request_id: i64,
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub user_id: i64,
+    pub round_id: i64,
+    pub leave_date: String,
+    pub hours: i32,
+    pub on_behalf_of: String,
+    pub received_via: String,
+    pub status: String,
+    /// Why the request was denied, if it was.
+    pub decision_reason: Option<String>,
+    /// The audit event that recorded the request.
+    pub request_event_id: Option<i64>,
+    /// The audit event that recorded the decision.
+    pub decision_event_id: Option<i64>,
+    /// The leave granted on approval.
+    pub leave_bid_id: Option<i64>,
+    pub created_at: String,
+    pub decided_at: Option<String>,
+}
+
+impl OverbidRequestData {
+    /// A request waiting for an Admin decision.
+    pub const STATUS_PENDING: &'static str = "pending";
+    /// A request granted by an Admin.
+    pub const STATUS_APPROVED: &'static str = "approved";
+    /// A request refused by an Admin.
+    pub const STATUS_DENIED: &'static str = "denied";
+}
+
+/// Leave an operator asked to enter for themselves as a controller.
+///
+/// The leave is entered only once a second operator confirms it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfBidRequestData {
+    pub self_bid_request_id: i64,
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub user_id: i64,
+    pub round_id: i64,
+    /// The operator who is also the controller.
+    pub requested_by_operator_id: i64,
+    /// The leave days (`YYYY-MM-DD`), in date order.
+    pub leave_dates: Vec<String>,
+    pub hours: i32,
+    pub on_behalf_of: String,
+    pub received_via: String,
+    pub status: String,
+    /// The audit event that recorded the request.
+    pub request_event_id: Option<i64>,
+    /// The operator who confirmed the request.
+    pub confirmed_by_operator_id: Option<i64>,
+    /// The audit event that recorded the confirmation.
+    pub confirm_event_id: Option<i64>,
+    pub created_at: String,
+    pub confirmed_at: Option<String>,
+}
+
+impl SelfBidRequestData {
+    /// A request waiting for a second operator.
+    pub const STATUS_PENDING: &'static str = "pending";
+    /// A request confirmed and entered.
+    pub const STATUS_CONFIRMED: &'static str = "confirmed";
+}
+
+/// The number of approved leave days on one date for one area, round, and crew.
+///
+/// `crew` is `None` for users without a crew assignment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DailyLeaveCountData {
+    pub leave_date: String,
+    pub area_id: i64,
+    pub round_id: i64,
+    pub crew: Option<i32>,
+    pub approved: i64,
+}
+
+/// Leave days to record for one controller in one round.
+///
+/// Each day carries the leave slots it offers the controller. The days are
+/// recorded only if every one still has a slot once the approved leave
+/// already held is counted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewLeaveBids {
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub user_id: i64,
+    pub round_id: i64,
+    /// Each leave date (`YYYY-MM-DD`) and its slot capacity, in the order
+    /// the bids are recorded.
+    pub days: Vec<(String, u32)>,
+    pub hours: i32,
+    pub on_behalf_of: String,
+    pub received_via: String,
+    /// The crew whose approved leave fills the slots on a crew-partitioned
+    /// round. `None` counts all approved leave in the area and round.
+    pub slot_crew: Option<i32>,
+}
+
+/// The staffing adjustment to the leave slots of one day of a round in an area.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotAdjustmentData {
+    pub area_id: i64,
+    pub round_id: i64,
+    pub slot_date: String,
+    pub staffing_adjustment: i32,
+}
+
+/// A canonical value captured before or after an override.
+///
+/// Stored as JSON in the `canonical_overrides` ledger so that a revert can
+/// restore the exact value the override replaced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OverrideValue {
+    /// A user's canonical area membership.
+    AreaAssignment { area_id: i64 },
+    /// A user's canonical eligibility.
+    Eligibility { can_bid: bool },
+    /// A user's canonical bid order position.
+    BidOrder { bid_order: Option<i32> },
+    /// A user's canonical bid window.
+    BidWindow {
+        window_start: Option<String>,
+        window_end: Option<String>,
+    },
+}
+
+impl OverrideValue {
+    /// Returns the `override_kind` stored alongside this value.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::AreaAssignment { .. } => "area_assignment",
+            Self::Eligibility { .. } => "eligibility",
+            Self::BidOrder { .. } => "bid_order",
+            Self::BidWindow { .. } => "bid_window",
+        }
+    }
+}
+
+/// An override recorded in the canonical override ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanonicalOverrideData {
+    pub override_id: i64,
+    pub bid_year_id: i64,
+    pub user_id: i64,
+    pub override_kind: String,
+    pub previous_value: OverrideValue,
+    pub new_value: OverrideValue,
+    pub reason: String,
+    pub audit_event_id: i64,
+    pub is_reverted: bool,
+    pub created_at: String,
+}
+
+/// A duplicate user record merged into a surviving record, from the user
+/// merge ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserMergeData {
+    pub user_merge_id: i64,
+    pub bid_year_id: i64,
+    pub kept_user_id: i64,
+    pub merged_user_id: i64,
+    pub reason: String,
+    pub previous_excluded_from_bidding: bool,
+    pub previous_excluded_from_leave_calculation: bool,
+    pub merged_carryover_hours: Option<i32>,
+    pub kept_carryover_hours: Option<i32>,
+    pub merge_event_id: i64,
+    pub revert_event_id: Option<i64>,
+    /// The number of rows the merge reassigned to the surviving record.
+    pub rows_moved: usize,
+    pub created_at: String,
+}
+
+/// A user whose personal data was erased after the retention period.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserAnonymizationData {
+    pub user_id: i64,
+    pub bid_year_id: i64,
+    /// The audit event that recorded the erasure.
+    pub event_id: i64,
+    /// How many stored state snapshots held the user and were rewritten.
+    pub snapshots_rewritten: usize,
+    pub created_at: String,
+}
+
+/// An audit log compaction of a completed bid year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditCompactionData {
+    pub compaction_id: i64,
+    pub bid_year_id: i64,
+    /// How many events were moved to the archive.
+    pub archived_event_count: usize,
+    /// The first and last archived events, if any were archived.
+    pub first_event_id: Option<i64>,
+    pub last_event_id: Option<i64>,
+    /// The chain hash of the last archived event, or the genesis hash if
+    /// none were archived.
+    pub chain_head: String,
+    pub created_at: String,
+}
+
+/// A live audit event a compaction can archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivableEventData {
+    pub event_id: i64,
+    /// The area's code.
+    pub area_code: String,
+    /// The event's action name.
+    pub action_name: String,
+}
+
+/// The result of recomputing a compaction's hash chain from its archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditArchiveVerification {
+    pub compaction: AuditCompactionData,
+    /// How many archived events were read.
+    pub events_checked: usize,
+    /// The chain head recomputed from the archived events.
+    pub recomputed_head: String,
+    /// Archived events whose stored chain hash does not match the
+    /// recomputed one, oldest first.
+    pub broken_event_ids: Vec<i64>,
+}
+
+impl AuditArchiveVerification {
+    /// Returns true if the archive still matches the chain the compaction
+    /// recorded.
+    #[must_use]
+    pub fn is_intact(&self) -> bool {
+        self.broken_event_ids.is_empty()
+            && self.events_checked == self.compaction.archived_event_count
+            && self.recomputed_head == self.compaction.chain_head
+    }
+}
+
+/// Initials a user held before an initials change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitialsAliasData {
+    pub alias_id: i64,
+    pub bid_year_id: i64,
+    pub user_id: i64,
+    /// The retired initials.
+    pub initials: String,
+    /// The date the replacement initials took effect (`YYYY-MM-DD`).
+    pub effective_date: String,
+    pub change_event_id: i64,
+    pub created_at: String,
+}
+
+/// A round's configuration, independent of the round group it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundSpecData {
+    pub round_number: u32,
+    pub name: String,
+    pub slots_per_day: u32,
+    pub max_groups: u32,
+    pub max_total_hours: u32,
+    pub include_holidays: bool,
+    pub allow_overbid: bool,
+}
+
+/// A round group and its rounds, ready to be instantiated in a bid year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundGroupSpecData {
+    pub name: String,
+    pub editing_enabled: bool,
+    pub rounds: Vec<RoundSpecData>,
+}
+
+/// A named round group template shared across bid years.
+///
+/// `rounds` is ordered by round number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundGroupTemplateData {
+    pub template_id: i64,
+    pub name: String,
+    pub editing_enabled: bool,
+    pub rounds: Vec<RoundSpecData>,
+    pub created_at: String,
+}
+
+/// A blackout date on which no bid windows are laid out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlackoutDateData {
+    pub blackout_date_id: i64,
+    pub bid_year_id: i64,
+    /// The blackout date (ISO 8601 format).
+    pub blackout_date: String,
+    pub reason: String,
+}
+
+/// A high-demand period of a bid year, such as Christmas week.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrimePeriodData {
+    pub prime_period_id: i64,
+    pub bid_year_id: i64,
+    pub label: String,
+    /// The first prime date (ISO 8601 format).
+    pub start_date: String,
+    /// The last prime date (ISO 8601 format).
+    pub end_date: String,
+    pub created_at: String,
+}
+
+/// A round's leave slots on prime dates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundPrimeCapData {
+    pub round_id: i64,
+    pub prime_slots_per_day: i32,
+}
+
+/// The criteria limiting a round to a subset of an area's bidders.
+///
+/// `user_types` is empty when the round admits every user type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundEligibilityData {
+    pub round_id: i64,
+    pub min_remaining_hours: Option<i32>,
+    pub user_types: Vec<String>,
+}
+
+/// A round's place in its round group, with the group's bid order
+/// rotation.
+///
+/// `rotation` is `None` when the round group bids every round in canonical
+/// bid order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundSequencingData {
+    pub round_id: i64,
+    pub round_group_id: i64,
+    pub round_number: i32,
+    pub rotation: Option<String>,
+}
+
+/// One user's position in a round's bidding sequence.
+#[derive(
+    Debug, Clone, PartialEq, Eq, diesel::Queryable, diesel::Selectable, diesel::Insertable,
+)]
+#[diesel(table_name = crate::diesel_schema::round_bid_order)]
+pub struct RoundBidOrderData {
+    pub round_id: i64,
+    pub user_id: i64,
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub position: i32,
+}
+
+/// The leave slots one crew is allocated per day in a crew-partitioned
+/// round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundCrewSlotsData {
+    pub round_id: i64,
+    pub crew: i32,
+    pub slots_per_day: i32,
+}
+
+/// The sign-off of a completed round in an area.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundSignOffData {
+    pub round_sign_off_id: i64,
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub round_id: i64,
+    /// The audit event that recorded the sign-off.
+    pub audit_event_id: i64,
+    pub signed_off_at: String,
+}
+
+/// A frozen area.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeFreezeData {
+    pub scope_freeze_id: i64,
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub reason: String,
+    /// The audit event that recorded the freeze.
+    pub audit_event_id: i64,
+    pub frozen_at: String,
+}
+
+/// A bid year's policy on amending submitted bids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidAmendmentPolicyData {
+    pub bid_year_id: i64,
+    pub policy: String,
+    /// The amendment window in hours (`within_hours` policies only).
+    pub window_hours: Option<i32>,
+}
+
+/// The facility's configuration.
+///
+/// `updated_at` is the time of the last change (UTC).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FacilityData {
+    pub identifier: String,
+    pub name: String,
+    pub default_timezone: String,
+    pub junior_accrual_hours: i32,
+    pub mid_accrual_hours: i32,
+    pub senior_accrual_hours: i32,
+    pub max_carryover_hours: i32,
+    pub updated_at: String,
+}
+
+/// A feature flag set for a bid year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureFlagData {
+    pub bid_year_id: i64,
+    pub flag: String,
+    pub enabled: bool,
+}
+
+/// The last run of a server background job.
+///
+/// Timestamps are RFC 3339 (UTC).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobRunData {
+    pub job_name: String,
+    pub started_at: String,
+    pub finished_at: String,
+    pub succeeded: bool,
+    pub duration_ms: i64,
+    /// The job's summary when it succeeded, its error when it failed.
+    pub message: Option<String>,
+}
+
+/// The leave slots on one day of a round in an area, as projected for
+/// dashboards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectedDailySlotsData {
+    pub area_id: i64,
+    pub round_id: i64,
+    pub slot_date: String,
+    pub capacity: i32,
+    pub used: i32,
+    pub remaining: i32,
+}
+
+/// A user's approved leave, as projected for dashboards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectedUserAwardData {
+    pub user_id: i64,
+    pub area_id: i64,
+    pub awarded_days: i32,
+    pub awarded_hours: i32,
+}
+
+/// The scope of an audit event: (`bid_year_id`, `area_id`).
+pub type AuditScope = (Option<i64>, Option<i64>);
+
+/// The bid status counts of a round in an area, as projected for
+/// dashboards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectedAreaProgressData {
+    pub area_id: i64,
+    pub round_id: i64,
+    pub bidders: i32,
+    pub not_started: i32,
+    pub in_progress: i32,
+    pub completed: i32,
+}
+
+/// Every dashboard projection row of one area.
+///
+/// Applying it replaces whatever was projected for the area before, so an
+/// empty projection clears the area.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AreaProjectionData {
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub daily_slots: Vec<ProjectedDailySlotsData>,
+    pub user_awards: Vec<ProjectedUserAwardData>,
+    pub progress: Vec<ProjectedAreaProgressData>,
+}
+
+/// A transition waiting in a server write-ahead queue.
+///
+/// Queue files are replayed after a restart, possibly by a newer server,
+/// so fields must only ever be added with serde defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedTransitionData {
+    pub actor_id: String,
+    pub actor_type: String,
+    pub operator_id: Option<i64>,
+    pub operator_login_name: Option<String>,
+    pub operator_display_name: Option<String>,
+    pub cause_id: String,
+    pub cause_description: String,
+    pub action_name: String,
+    pub action_details: Option<String>,
+    pub before: String,
+    pub after: String,
+    pub event_bid_year: Option<BidYear>,
+    pub event_area: Option<Area>,
+    /// Absent from queue files written before events had ULIDs.
+    #[serde(default)]
+    pub event_ulid: Option<String>,
+    /// Absent from queue files written before events recorded their user.
+    #[serde(default)]
+    pub event_user_id: Option<i64>,
+    /// The state after the transition.
+    pub bid_year: BidYear,
+    pub area: Area,
+    pub users: Vec<User>,
+}
+
+/// A leave slot freed by a withdrawal after its round closed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitlistSlotData {
+    pub waitlist_slot_id: i64,
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub round_id: i64,
+    pub leave_date: String,
+    pub hours: i32,
+    /// The crew whose slot was freed in a crew-partitioned round.
+    pub crew: Option<i32>,
+    /// How long each offer of the slot stays open.
+    pub offer_hours: i32,
+    pub released_leave_bid_id: i64,
+    /// The audit event that recorded the withdrawal.
+    pub release_event_id: i64,
+    pub status: String,
+    pub created_at: String,
+}
+
+impl WaitlistSlotData {
+    /// A slot still being offered down the list.
+    pub const STATUS_OPEN: &'static str = "open";
+    /// A slot accepted by a user.
+    pub const STATUS_FILLED: &'static str = "filled";
+    /// A slot every eligible user passed on.
+    pub const STATUS_UNCLAIMED: &'static str = "unclaimed";
+}
+
+/// An offer of a freed leave slot to one user.
+///
+/// Timestamps are RFC 3339 (UTC).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitlistOfferData {
+    pub waitlist_offer_id: i64,
+    pub waitlist_slot_id: i64,
+    pub user_id: i64,
+    pub status: String,
+    pub offered_at: String,
+    pub expires_at: String,
+    pub responded_at: Option<String>,
+    /// The audit event that recorded the offer.
+    pub offer_event_id: i64,
+    /// The audit event that recorded the answer or expiry.
+    pub response_event_id: Option<i64>,
+    /// The leave granted on acceptance.
+    pub leave_bid_id: Option<i64>,
+}
+
+impl WaitlistOfferData {
+    /// An offer waiting for an answer.
+    pub const STATUS_PENDING: &'static str = "pending";
+    /// An offer the user accepted.
+    pub const STATUS_ACCEPTED: &'static str = "accepted";
+    /// An offer the user declined.
+    pub const STATUS_DECLINED: &'static str = "declined";
+    /// An offer that lapsed unanswered.
+    pub const STATUS_EXPIRED: &'static str = "expired";
+}
+
+/// The tracked current bidder of one round in an area.
+///
+/// `user_id` is `None` once every bidder in the round has had their turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrentBidderStateData {
+    pub current_bidder_id: i64,
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub round_id: i64,
+    pub user_id: Option<i64>,
+    /// When the user became the current bidder (ISO 8601 format).
+    pub became_current_at: String,
+}
+
+/// A bidder's place in a round's bidding sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundBidderData {
+    pub user_id: i64,
+    pub initials: String,
+    /// Window start (UTC, ISO 8601).
+    pub window_start_datetime: String,
+    /// Window end (UTC, ISO 8601).
+    pub window_end_datetime: String,
+}
+
+/// The bidder whose window is active now in an area, with display names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrentBidderData {
+    pub bid_year_id: i64,
+    pub area_id: i64,
+    pub round_id: i64,
+    pub round_number: i32,
+    pub round_name: String,
+    pub user_id: i64,
+    pub initials: String,
+    /// The bidder's window start (UTC, ISO 8601), if a window was calculated.
+    pub window_start_datetime: Option<String>,
+    /// The bidder's window end (UTC, ISO 8601), if a window was calculated.
+    pub window_end_datetime: Option<String>,
+    /// When the user became the current bidder (ISO 8601 format).
+    pub became_current_at: String,
+}
+
+/// Annual leave a user carries into a bid year from the prior year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaveCarryoverData {
+    pub bid_year_id: i64,
+    pub user_id: i64,
+    pub carryover_hours: i32,
+}
+
+/// A delegation of an area rep's bidding authority to another operator.
+///
+/// Dates are inclusive and formatted `YYYY-MM-DD`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AreaDelegationData {
+    pub area_delegation_id: i64,
+    pub area_id: i64,
+    /// The area rep handing over authority.
+    pub delegator_operator_id: i64,
+    /// The operator receiving it.
+    pub delegate_operator_id: i64,
+    pub start_date: String,
+    pub end_date: String,
+    pub created_at: String,
+}
+
+/// A controller's portal link.
+///
+/// The link token itself is never stored, only its digest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortalLinkData {
+    pub portal_link_id: i64,
+    pub token_hash: String,
+    pub user_id: i64,
+    pub bid_year_id: i64,
+    /// When the link stops working (RFC 3339).
+    pub expires_at: String,
+    pub created_at: String,
+}
+
+/// One of a user's bid windows, with the round it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserBidWindowData {
+    pub round_id: i64,
+    pub round_number: i32,
+    pub round_name: String,
+    /// Window start (UTC, ISO 8601).
+    pub window_start_datetime: String,
+    /// Window end (UTC, ISO 8601).
+    pub window_end_datetime: String,
+}
+
+/// A named audit timeline filter saved by an admin.
+///
+/// The scope and filters are stored as JSON owned by the API layer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedAuditQueryData {
+    pub saved_query_id: i64,
+    pub name: String,
+    pub scope_json: String,
+    pub filter_json: String,
+    /// Addresses the results are emailed to each week; empty when the
+    /// query is not scheduled.
+    pub weekly_recipients: Vec<String>,
+    pub created_by_operator_id: i64,
+    pub created_at: String,
+    /// When the results were last emailed (RFC 3339).
+    pub last_delivered_at: Option<String>,
+}
@@ -4,6 +4,28 @@
 // license that can be found in the LICENSE file or at
 // https://opensource.org/licenses/MIT.
 
+diesel::table! {
+    area_bid_schedule_overrides (area_id) {
+        area_id -> BigInt,
+        bid_start_date -> Nullable<Text>,
+        bid_window_start_time -> Nullable<Text>,
+        bid_window_end_time -> Nullable<Text>,
+        bidders_per_area_per_day -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    area_delegations (area_delegation_id) {
+        area_delegation_id -> BigInt,
+        area_id -> BigInt,
+        delegator_operator_id -> BigInt,
+        delegate_operator_id -> BigInt,
+        start_date -> Text,
+        end_date -> Text,
+        created_at -> Text,
+    }
+}
+
 diesel::table! {
     areas (area_id) {
         area_id -> BigInt,
@@ -16,6 +38,65 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    archived_audit_events (event_id) {
+        event_id -> BigInt,
+        compaction_id -> BigInt,
+        bid_year_id -> Nullable<BigInt>,
+        area_id -> Nullable<BigInt>,
+        year -> Integer,
+        area_code -> Text,
+        actor_operator_id -> BigInt,
+        actor_login_name -> Text,
+        actor_display_name -> Text,
+        actor_json -> Text,
+        cause_json -> Text,
+        action_json -> Text,
+        before_snapshot_json -> Text,
+        after_snapshot_json -> Text,
+        created_at -> Nullable<Text>,
+        event_ulid -> Nullable<Text>,
+        chain_hash -> Text,
+        user_id -> Nullable<BigInt>,
+    }
+}
+
+diesel::table! {
+    audit_compactions (compaction_id) {
+        compaction_id -> BigInt,
+        bid_year_id -> BigInt,
+        archived_event_count -> Integer,
+        first_event_id -> Nullable<BigInt>,
+        last_event_id -> Nullable<BigInt>,
+        chain_head -> Text,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    audit_event_annotations (annotation_id) {
+        annotation_id -> BigInt,
+        event_id -> BigInt,
+        operator_id -> BigInt,
+        note -> Text,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    audit_event_correlations (event_id) {
+        event_id -> BigInt,
+        correlation_id -> Text,
+    }
+}
+
+diesel::table! {
+    audit_event_dependencies (event_id, depends_on_event_id) {
+        event_id -> BigInt,
+        depends_on_event_id -> BigInt,
+    }
+}
+
 diesel::table! {
     audit_events (event_id) {
         event_id -> BigInt,
@@ -32,6 +113,8 @@ diesel::table! {
         before_snapshot_json -> Text,
         after_snapshot_json -> Text,
         created_at -> Nullable<Text>,
+        event_ulid -> Nullable<Text>,
+        user_id -> Nullable<BigInt>,
     }
 }
 
@@ -54,6 +137,52 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    bid_amendment_policies (bid_year_id) {
+        bid_year_id -> BigInt,
+        policy -> Text,
+        window_hours -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    bid_year_blackout_dates (blackout_date_id) {
+        blackout_date_id -> BigInt,
+        bid_year_id -> BigInt,
+        blackout_date -> Text,
+        reason -> Text,
+    }
+}
+
+diesel::table! {
+    bid_preferences (bid_preference_id) {
+        bid_preference_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> BigInt,
+        user_id -> BigInt,
+        round_id -> BigInt,
+        preference_rank -> Integer,
+        leave_dates -> Text,
+        hours -> Integer,
+        on_behalf_of -> Text,
+        received_via -> Text,
+        status -> Text,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    bid_rules (bid_rule_id) {
+        bid_rule_id -> BigInt,
+        bid_year_id -> BigInt,
+        rule_position -> Integer,
+        rule_kind -> Text,
+        rule_value -> Nullable<Integer>,
+        rule_dates -> Text,
+        created_at -> Text,
+    }
+}
+
 diesel::table! {
     bid_status (bid_status_id) {
         bid_status_id -> BigInt,
@@ -139,6 +268,171 @@ diesel::table! {
         can_bid -> Integer,
         is_overridden -> Integer,
         override_reason -> Nullable<Text>,
+        derived_can_bid -> Integer,
+    }
+}
+
+diesel::table! {
+    canonical_overrides (override_id) {
+        override_id -> BigInt,
+        bid_year_id -> BigInt,
+        user_id -> BigInt,
+        override_kind -> Text,
+        previous_value -> Text,
+        new_value -> Text,
+        reason -> Text,
+        audit_event_id -> BigInt,
+        is_reverted -> Integer,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    chat_channels (chat_channel_id) {
+        chat_channel_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> Nullable<BigInt>,
+        provider -> Text,
+        url -> Text,
+        is_enabled -> Integer,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    chat_notification_log (chat_notification_id) {
+        chat_notification_id -> BigInt,
+        chat_channel_id -> BigInt,
+        kind -> Text,
+        reference_key -> Text,
+        message -> Text,
+        delivered -> Integer,
+        error -> Nullable<Text>,
+        sent_at -> Text,
+    }
+}
+
+diesel::table! {
+    current_bidders (current_bidder_id) {
+        current_bidder_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> BigInt,
+        round_id -> BigInt,
+        user_id -> Nullable<BigInt>,
+        became_current_at -> Text,
+    }
+}
+
+diesel::table! {
+    eligibility_exceptions (eligibility_exception_id) {
+        eligibility_exception_id -> BigInt,
+        bid_year_id -> BigInt,
+        user_type -> Text,
+        area_id -> Nullable<BigInt>,
+        can_bid -> Integer,
+    }
+}
+
+diesel::table! {
+    event_outbox (outbox_id) {
+        outbox_id -> BigInt,
+        audit_event_id -> BigInt,
+        created_at -> Text,
+        delivered_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    facility (facility_id) {
+        facility_id -> BigInt,
+        identifier -> Text,
+        name -> Text,
+        default_timezone -> Text,
+        junior_accrual_hours -> Integer,
+        mid_accrual_hours -> Integer,
+        senior_accrual_hours -> Integer,
+        max_carryover_hours -> Integer,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    feature_flags (bid_year_id, flag) {
+        bid_year_id -> BigInt,
+        flag -> Text,
+        enabled -> Integer,
+    }
+}
+
+diesel::table! {
+    job_runs (job_name) {
+        job_name -> Text,
+        started_at -> Text,
+        finished_at -> Text,
+        succeeded -> Integer,
+        duration_ms -> BigInt,
+        message -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    leave_bids (leave_bid_id) {
+        leave_bid_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> BigInt,
+        user_id -> BigInt,
+        round_id -> BigInt,
+        leave_date -> Text,
+        hours -> Integer,
+        status -> Text,
+        created_at -> Text,
+        on_behalf_of -> Nullable<Text>,
+        received_via -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    leave_caps (bid_year_id) {
+        bid_year_id -> BigInt,
+        carryover_cap_hours -> Integer,
+    }
+}
+
+diesel::table! {
+    leave_carryovers (bid_year_id, user_id) {
+        bid_year_id -> BigInt,
+        user_id -> BigInt,
+        carryover_hours -> Integer,
+    }
+}
+
+diesel::table! {
+    notification_log (notification_id) {
+        notification_id -> BigInt,
+        user_id -> BigInt,
+        kind -> Text,
+        reference_id -> BigInt,
+        recipient -> Text,
+        subject -> Text,
+        delivered -> Integer,
+        error -> Nullable<Text>,
+        sent_at -> Text,
+    }
+}
+
+diesel::table! {
+    operator_area_scopes (operator_id, area_id) {
+        operator_id -> BigInt,
+        area_id -> BigInt,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    operator_controllers (operator_id) {
+        operator_id -> BigInt,
+        initials -> Text,
+        created_at -> Text,
     }
 }
 
@@ -153,6 +447,136 @@ diesel::table! {
         created_at -> Text,
         disabled_at -> Nullable<Text>,
         last_login_at -> Nullable<Text>,
+        failed_login_count -> Integer,
+    }
+}
+
+diesel::table! {
+    overbid_requests (overbid_request_id) {
+        overbid_request_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> BigInt,
+        user_id -> BigInt,
+        round_id -> BigInt,
+        leave_date -> Text,
+        hours -> Integer,
+        on_behalf_of -> Text,
+        received_via -> Text,
+        status -> Text,
+        decision_reason -> Nullable<Text>,
+        request_event_id -> Nullable<BigInt>,
+        decision_event_id -> Nullable<BigInt>,
+        leave_bid_id -> Nullable<BigInt>,
+        created_at -> Text,
+        decided_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    portal_links (portal_link_id) {
+        portal_link_id -> BigInt,
+        token_hash -> Text,
+        user_id -> BigInt,
+        bid_year_id -> BigInt,
+        expires_at -> Text,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    prime_periods (prime_period_id) {
+        prime_period_id -> BigInt,
+        bid_year_id -> BigInt,
+        label -> Text,
+        start_date -> Text,
+        end_date -> Text,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    projected_area_progress (area_id, round_id) {
+        area_id -> BigInt,
+        round_id -> BigInt,
+        bid_year_id -> BigInt,
+        bidders -> Integer,
+        not_started -> Integer,
+        in_progress -> Integer,
+        completed -> Integer,
+    }
+}
+
+diesel::table! {
+    projected_daily_slots (area_id, round_id, slot_date) {
+        area_id -> BigInt,
+        round_id -> BigInt,
+        slot_date -> Text,
+        bid_year_id -> BigInt,
+        capacity -> Integer,
+        used -> Integer,
+        remaining -> Integer,
+    }
+}
+
+diesel::table! {
+    projected_user_awards (user_id) {
+        user_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> BigInt,
+        awarded_days -> Integer,
+        awarded_hours -> Integer,
+    }
+}
+
+diesel::table! {
+    projection_cursors (projection_name) {
+        projection_name -> Text,
+        last_event_id -> BigInt,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    round_crew_slots (round_id, crew) {
+        round_id -> BigInt,
+        bid_year_id -> BigInt,
+        crew -> Integer,
+        slots_per_day -> Integer,
+    }
+}
+
+diesel::table! {
+    round_prime_caps (round_id) {
+        round_id -> BigInt,
+        bid_year_id -> BigInt,
+        prime_slots_per_day -> Integer,
+    }
+}
+
+diesel::table! {
+    round_eligibility (round_id) {
+        round_id -> BigInt,
+        bid_year_id -> BigInt,
+        min_remaining_hours -> Nullable<Integer>,
+        user_types -> Text,
+    }
+}
+
+diesel::table! {
+    round_bid_order (round_id, user_id) {
+        round_id -> BigInt,
+        user_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> BigInt,
+        position -> Integer,
+    }
+}
+
+diesel::table! {
+    round_group_rotations (round_group_id) {
+        round_group_id -> BigInt,
+        bid_year_id -> BigInt,
+        rotation -> Text,
     }
 }
 
@@ -165,6 +589,40 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    round_group_templates (template_id) {
+        template_id -> BigInt,
+        name -> Text,
+        editing_enabled -> Integer,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    round_sign_offs (round_sign_off_id) {
+        round_sign_off_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> BigInt,
+        round_id -> BigInt,
+        audit_event_id -> BigInt,
+        signed_off_at -> Text,
+    }
+}
+
+diesel::table! {
+    round_templates (round_template_id) {
+        round_template_id -> BigInt,
+        template_id -> BigInt,
+        round_number -> Integer,
+        name -> Text,
+        slots_per_day -> Integer,
+        max_groups -> Integer,
+        max_total_hours -> Integer,
+        include_holidays -> Integer,
+        allow_overbid -> Integer,
+    }
+}
+
 diesel::table! {
     rounds (round_id) {
         round_id -> BigInt,
@@ -176,13 +634,59 @@ diesel::table! {
         max_total_hours -> Integer,
         include_holidays -> Integer,
         allow_overbid -> Integer,
+        crew_partitioned -> Integer,
+    }
+}
+
+diesel::table! {
+    saved_audit_queries (saved_query_id) {
+        saved_query_id -> BigInt,
+        name -> Text,
+        scope_json -> Text,
+        filter_json -> Text,
+        weekly_recipients -> Text,
+        created_by_operator_id -> BigInt,
+        created_at -> Text,
+        last_delivered_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    scope_freezes (scope_freeze_id) {
+        scope_freeze_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> BigInt,
+        reason -> Text,
+        audit_event_id -> BigInt,
+        frozen_at -> Text,
+    }
+}
+
+diesel::table! {
+    self_bid_requests (self_bid_request_id) {
+        self_bid_request_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> BigInt,
+        user_id -> BigInt,
+        round_id -> BigInt,
+        requested_by_operator_id -> BigInt,
+        leave_dates -> Text,
+        hours -> Integer,
+        on_behalf_of -> Text,
+        received_via -> Text,
+        status -> Text,
+        request_event_id -> Nullable<BigInt>,
+        confirmed_by_operator_id -> Nullable<BigInt>,
+        confirm_event_id -> Nullable<BigInt>,
+        created_at -> Text,
+        confirmed_at -> Nullable<Text>,
     }
 }
 
 diesel::table! {
     sessions (session_id) {
         session_id -> BigInt,
-        session_token -> Text,
+        session_token_hash -> Text,
         operator_id -> BigInt,
         created_at -> Text,
         last_activity_at -> Text,
@@ -190,6 +694,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    slot_inventory (slot_inventory_id) {
+        slot_inventory_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> BigInt,
+        round_id -> BigInt,
+        slot_date -> Text,
+        staffing_adjustment -> Integer,
+        updated_at -> Text,
+    }
+}
+
 diesel::table! {
     state_snapshots (snapshot_id) {
         snapshot_id -> BigInt,
@@ -198,6 +714,72 @@ diesel::table! {
         event_id -> BigInt,
         state_json -> Text,
         created_at -> Nullable<Text>,
+        state_format -> Text,
+        state_binary -> Nullable<Binary>,
+    }
+}
+
+diesel::table! {
+    superseded_audit_events (event_id) {
+        event_id -> BigInt,
+        rollback_event_id -> BigInt,
+        superseded_at -> Text,
+    }
+}
+
+diesel::table! {
+    user_anonymizations (user_id) {
+        user_id -> BigInt,
+        bid_year_id -> BigInt,
+        event_id -> BigInt,
+        snapshots_rewritten -> Integer,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    user_contacts (user_id) {
+        user_id -> BigInt,
+        email -> Text,
+        email_enabled -> Integer,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    user_initials_aliases (alias_id) {
+        alias_id -> BigInt,
+        bid_year_id -> BigInt,
+        user_id -> BigInt,
+        initials -> Text,
+        effective_date -> Text,
+        change_event_id -> BigInt,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    user_merge_rows (user_merge_id, source_table, row_id) {
+        user_merge_id -> BigInt,
+        source_table -> Text,
+        row_id -> BigInt,
+    }
+}
+
+diesel::table! {
+    user_merges (user_merge_id) {
+        user_merge_id -> BigInt,
+        bid_year_id -> BigInt,
+        kept_user_id -> BigInt,
+        merged_user_id -> BigInt,
+        reason -> Text,
+        previous_excluded_from_bidding -> Integer,
+        previous_excluded_from_leave_calculation -> Integer,
+        merged_carryover_hours -> Nullable<Integer>,
+        kept_carryover_hours -> Nullable<Integer>,
+        merge_event_id -> BigInt,
+        revert_event_id -> Nullable<BigInt>,
+        created_at -> Text,
     }
 }
 
@@ -221,17 +803,95 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    waitlist_offers (waitlist_offer_id) {
+        waitlist_offer_id -> BigInt,
+        waitlist_slot_id -> BigInt,
+        user_id -> BigInt,
+        status -> Text,
+        offered_at -> Text,
+        expires_at -> Text,
+        responded_at -> Nullable<Text>,
+        offer_event_id -> BigInt,
+        response_event_id -> Nullable<BigInt>,
+        leave_bid_id -> Nullable<BigInt>,
+    }
+}
+
+diesel::table! {
+    waitlist_slots (waitlist_slot_id) {
+        waitlist_slot_id -> BigInt,
+        bid_year_id -> BigInt,
+        area_id -> BigInt,
+        round_id -> BigInt,
+        leave_date -> Text,
+        hours -> Integer,
+        crew -> Nullable<Integer>,
+        offer_hours -> Integer,
+        released_leave_bid_id -> BigInt,
+        release_event_id -> BigInt,
+        status -> Text,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    webhook_dead_letters (dead_letter_id) {
+        dead_letter_id -> BigInt,
+        webhook_id -> BigInt,
+        audit_event_id -> BigInt,
+        payload -> Text,
+        attempts -> Integer,
+        last_error -> Text,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    webhooks (webhook_id) {
+        webhook_id -> BigInt,
+        url -> Text,
+        secret -> Text,
+        event_filter -> Text,
+        is_enabled -> Integer,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    write_queue_progress (queue_name) {
+        queue_name -> Text,
+        last_sequence -> BigInt,
+        updated_at -> Text,
+    }
+}
+
+diesel::joinable!(area_bid_schedule_overrides -> areas (area_id));
+diesel::joinable!(area_delegations -> areas (area_id));
 diesel::joinable!(areas -> bid_years (bid_year_id));
 diesel::joinable!(areas -> round_groups (round_group_id));
+diesel::joinable!(archived_audit_events -> audit_compactions (compaction_id));
+diesel::joinable!(audit_compactions -> bid_years (bid_year_id));
 diesel::joinable!(audit_events -> areas (area_id));
 diesel::joinable!(audit_events -> bid_years (bid_year_id));
+diesel::joinable!(audit_event_annotations -> audit_events (event_id));
+diesel::joinable!(audit_event_correlations -> audit_events (event_id));
+diesel::joinable!(audit_event_dependencies -> audit_events (event_id));
+diesel::joinable!(audit_event_annotations -> operators (operator_id));
 diesel::joinable!(audit_events -> operators (actor_operator_id));
+diesel::joinable!(bid_amendment_policies -> bid_years (bid_year_id));
+diesel::joinable!(bid_preferences -> areas (area_id));
+diesel::joinable!(bid_preferences -> bid_years (bid_year_id));
+diesel::joinable!(bid_preferences -> rounds (round_id));
+diesel::joinable!(bid_preferences -> users (user_id));
+diesel::joinable!(bid_rules -> bid_years (bid_year_id));
 diesel::joinable!(bid_status -> areas (area_id));
 diesel::joinable!(bid_status -> bid_years (bid_year_id));
 diesel::joinable!(bid_status -> rounds (round_id));
 diesel::joinable!(bid_status -> users (user_id));
 diesel::joinable!(bid_status_history -> audit_events (audit_event_id));
 diesel::joinable!(bid_status_history -> bid_status (bid_status_id));
+diesel::joinable!(bid_year_blackout_dates -> bid_years (bid_year_id));
 diesel::joinable!(canonical_area_membership -> areas (area_id));
 diesel::joinable!(canonical_area_membership -> audit_events (audit_event_id));
 diesel::joinable!(canonical_area_membership -> bid_years (bid_year_id));
@@ -249,32 +909,166 @@ diesel::joinable!(canonical_bid_windows -> users (user_id));
 diesel::joinable!(canonical_eligibility -> audit_events (audit_event_id));
 diesel::joinable!(canonical_eligibility -> bid_years (bid_year_id));
 diesel::joinable!(canonical_eligibility -> users (user_id));
+diesel::joinable!(canonical_overrides -> audit_events (audit_event_id));
+diesel::joinable!(canonical_overrides -> bid_years (bid_year_id));
+diesel::joinable!(canonical_overrides -> users (user_id));
+diesel::joinable!(chat_channels -> areas (area_id));
+diesel::joinable!(chat_channels -> bid_years (bid_year_id));
+diesel::joinable!(chat_notification_log -> chat_channels (chat_channel_id));
+diesel::joinable!(current_bidders -> areas (area_id));
+diesel::joinable!(current_bidders -> bid_years (bid_year_id));
+diesel::joinable!(current_bidders -> rounds (round_id));
+diesel::joinable!(current_bidders -> users (user_id));
+diesel::joinable!(eligibility_exceptions -> areas (area_id));
+diesel::joinable!(eligibility_exceptions -> bid_years (bid_year_id));
+diesel::joinable!(event_outbox -> audit_events (audit_event_id));
+diesel::joinable!(feature_flags -> bid_years (bid_year_id));
+diesel::joinable!(leave_bids -> areas (area_id));
+diesel::joinable!(leave_bids -> bid_years (bid_year_id));
+diesel::joinable!(leave_bids -> rounds (round_id));
+diesel::joinable!(leave_bids -> users (user_id));
+diesel::joinable!(leave_caps -> bid_years (bid_year_id));
+diesel::joinable!(leave_carryovers -> bid_years (bid_year_id));
+diesel::joinable!(leave_carryovers -> users (user_id));
+diesel::joinable!(notification_log -> users (user_id));
+diesel::joinable!(operator_area_scopes -> areas (area_id));
+diesel::joinable!(operator_area_scopes -> operators (operator_id));
+diesel::joinable!(operator_controllers -> operators (operator_id));
+diesel::joinable!(overbid_requests -> areas (area_id));
+diesel::joinable!(overbid_requests -> bid_years (bid_year_id));
+diesel::joinable!(overbid_requests -> leave_bids (leave_bid_id));
+diesel::joinable!(overbid_requests -> rounds (round_id));
+diesel::joinable!(overbid_requests -> users (user_id));
+diesel::joinable!(portal_links -> bid_years (bid_year_id));
+diesel::joinable!(portal_links -> users (user_id));
+diesel::joinable!(prime_periods -> bid_years (bid_year_id));
+diesel::joinable!(round_bid_order -> areas (area_id));
+diesel::joinable!(round_bid_order -> bid_years (bid_year_id));
+diesel::joinable!(round_bid_order -> rounds (round_id));
+diesel::joinable!(round_bid_order -> users (user_id));
+diesel::joinable!(round_eligibility -> bid_years (bid_year_id));
+diesel::joinable!(round_eligibility -> rounds (round_id));
+diesel::joinable!(round_group_rotations -> bid_years (bid_year_id));
+diesel::joinable!(round_group_rotations -> round_groups (round_group_id));
 diesel::joinable!(round_groups -> bid_years (bid_year_id));
+diesel::joinable!(round_templates -> round_group_templates (template_id));
+diesel::joinable!(round_crew_slots -> bid_years (bid_year_id));
+diesel::joinable!(round_crew_slots -> rounds (round_id));
+diesel::joinable!(round_prime_caps -> bid_years (bid_year_id));
+diesel::joinable!(round_prime_caps -> rounds (round_id));
+diesel::joinable!(round_sign_offs -> areas (area_id));
+diesel::joinable!(round_sign_offs -> audit_events (audit_event_id));
+diesel::joinable!(round_sign_offs -> bid_years (bid_year_id));
+diesel::joinable!(round_sign_offs -> rounds (round_id));
 diesel::joinable!(rounds -> round_groups (round_group_id));
+diesel::joinable!(saved_audit_queries -> operators (created_by_operator_id));
+diesel::joinable!(scope_freezes -> areas (area_id));
+diesel::joinable!(scope_freezes -> audit_events (audit_event_id));
+diesel::joinable!(scope_freezes -> bid_years (bid_year_id));
+diesel::joinable!(self_bid_requests -> areas (area_id));
+diesel::joinable!(self_bid_requests -> bid_years (bid_year_id));
+diesel::joinable!(self_bid_requests -> rounds (round_id));
+diesel::joinable!(self_bid_requests -> users (user_id));
 diesel::joinable!(sessions -> operators (operator_id));
+diesel::joinable!(slot_inventory -> areas (area_id));
+diesel::joinable!(slot_inventory -> bid_years (bid_year_id));
+diesel::joinable!(slot_inventory -> rounds (round_id));
 diesel::joinable!(state_snapshots -> areas (area_id));
 diesel::joinable!(state_snapshots -> audit_events (event_id));
 diesel::joinable!(state_snapshots -> bid_years (bid_year_id));
+diesel::joinable!(superseded_audit_events -> audit_events (event_id));
+diesel::joinable!(user_anonymizations -> audit_events (event_id));
+diesel::joinable!(user_anonymizations -> bid_years (bid_year_id));
+diesel::joinable!(user_anonymizations -> users (user_id));
+diesel::joinable!(user_contacts -> users (user_id));
+diesel::joinable!(user_initials_aliases -> audit_events (change_event_id));
+diesel::joinable!(user_initials_aliases -> bid_years (bid_year_id));
+diesel::joinable!(user_initials_aliases -> users (user_id));
+diesel::joinable!(user_merge_rows -> user_merges (user_merge_id));
+diesel::joinable!(user_merges -> bid_years (bid_year_id));
 diesel::joinable!(users -> areas (area_id));
 diesel::joinable!(users -> bid_years (bid_year_id));
+diesel::joinable!(waitlist_offers -> users (user_id));
+diesel::joinable!(waitlist_offers -> waitlist_slots (waitlist_slot_id));
+diesel::joinable!(waitlist_slots -> areas (area_id));
+diesel::joinable!(waitlist_slots -> bid_years (bid_year_id));
+diesel::joinable!(waitlist_slots -> rounds (round_id));
+diesel::joinable!(webhook_dead_letters -> audit_events (audit_event_id));
+diesel::joinable!(webhook_dead_letters -> webhooks (webhook_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    area_bid_schedule_overrides,
+    area_delegations,
     areas,
+    archived_audit_events,
+    audit_compactions,
+    audit_event_annotations,
+    audit_event_correlations,
+    audit_event_dependencies,
     audit_events,
+    bid_amendment_policies,
+    bid_preferences,
+    bid_rules,
     bid_status,
     bid_status_history,
+    bid_year_blackout_dates,
     bid_years,
     bid_windows,
     canonical_area_membership,
     canonical_bid_order,
     canonical_bid_windows,
     canonical_eligibility,
+    canonical_overrides,
+    chat_channels,
+    chat_notification_log,
+    current_bidders,
+    eligibility_exceptions,
+    event_outbox,
+    facility,
+    feature_flags,
+    job_runs,
+    leave_bids,
+    leave_caps,
+    leave_carryovers,
+    notification_log,
+    operator_area_scopes,
+    operator_controllers,
     operators,
+    overbid_requests,
+    portal_links,
+    prime_periods,
+    projected_area_progress,
+    projected_daily_slots,
+    projected_user_awards,
+    projection_cursors,
+    round_bid_order,
+    round_eligibility,
+    round_group_rotations,
     round_groups,
+    round_group_templates,
+    round_crew_slots,
+    round_prime_caps,
+    round_sign_offs,
+    round_templates,
     rounds,
+    saved_audit_queries,
+    scope_freezes,
+    self_bid_requests,
     sessions,
+    slot_inventory,
     state_snapshots,
+    superseded_audit_events,
+    user_anonymizations,
+    user_contacts,
+    user_initials_aliases,
+    user_merge_rows,
+    user_merges,
     users,
+    waitlist_offers,
+    waitlist_slots,
+    webhook_dead_letters,
+    webhooks,
+    write_queue_progress,
 );
 
 // Allow GROUP BY queries with columns from joined tables
@@ -38,6 +38,12 @@ pub enum PersistenceError {
     NotFound(String),
     /// Canonical data is missing when lifecycle state requires it.
     CanonicalDataMissing { bid_year_id: i64, table: String },
+    /// Bid year cannot be reopened because bids have been entered.
+    BidYearHasBids { bid_year_id: i64, bids: usize },
+    /// Leave cannot be recorded because a day has no leave slots remaining.
+    LeaveSlotsExhausted { round_id: i64, leave_date: String },
+    /// An encrypted column value could not be decrypted.
+    DecryptionFailed(String),
     /// A general error occurred.
     Other(String),
 }
@@ -77,6 +83,22 @@ impl std::fmt::Display for PersistenceError {
                     "Canonical data missing for bid_year_id={bid_year_id}, table={table} (lifecycle state requires canonical tables)"
                 )
             }
+            Self::BidYearHasBids { bid_year_id, bids } => {
+                write!(
+                    f,
+                    "Bid year {bid_year_id} cannot be reopened: {bids} bids have been entered"
+                )
+            }
+            Self::LeaveSlotsExhausted {
+                round_id,
+                leave_date,
+            } => {
+                write!(
+                    f,
+                    "No leave slots remain on {leave_date} in round {round_id}"
+                )
+            }
+            Self::DecryptionFailed(msg) => write!(f, "Decryption failed: {msg}"),
             Self::Other(msg) => write!(f, "{msg}"),
         }
     }
@@ -104,3 +126,9 @@ impl From<serde_json::Error> for PersistenceError {
         Self::SerializationError(err.to_string())
     }
 }
+
+impl From<postcard::Error> for PersistenceError {
+    fn from(err: postcard::Error) -> Self {
+        Self::SerializationError(err.to_string())
+    }
+}
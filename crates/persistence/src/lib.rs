@@ -85,11 +85,17 @@
 )]
 #![allow(clippy::multiple_crate_versions)]
 
+use diesel::connection::{InstrumentationEvent, get_default_instrumentation};
 use diesel::prelude::*;
 use diesel::{MysqlConnection, SqliteConnection};
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
-use zab_bid::{BootstrapMetadata, BootstrapResult, State, TransitionResult};
+use std::sync::{Arc, Mutex, PoisonError};
+use zab_bid::{
+    BootstrapMetadata, BootstrapResult, Clock, IdGenerator, State, SystemClock, TransitionResult,
+    UlidGenerator,
+};
 use zab_bid_audit::AuditEvent;
 use zab_bid_domain::{Area, BidYear, CanonicalBidYear, Initials, Round, RoundGroup, User};
 
@@ -162,23 +168,61 @@ macro_rules! backend_fn {
 }
 
 mod backend;
+mod bundle;
+mod column_encryption;
 pub mod data_models;
 mod diesel_schema;
 mod error;
 mod mutations;
 mod queries;
+mod secrets;
 
 #[cfg(test)]
 mod tests;
 
+pub use bundle::{
+    BUNDLE_FORMAT_VERSION, BidYearBundle, BundleAmendmentPolicy, BundleArea,
+    BundleAreaScheduleOverride, BundleAuditEvent, BundleBidRule, BundleBidYear, BundleBlackoutDate,
+    BundleEligibilityException, BundleFeatureFlag, BundleLeaveCap, BundleManifest,
+    BundlePrimePeriod, BundleRound, BundleRoundCrewSlots, BundleRoundEligibility, BundleRoundGroup,
+    BundleRoundGroupRotation, BundleRoundPrimeCap, BundleSnapshot, BundleUser,
+};
+pub use column_encryption::{COLUMN_KEY_LENGTH, ColumnKey, ColumnKeyring};
 pub use data_models::{
-    BidStatusHistoryRow, BidStatusRow, NewBidStatus, NewBidStatusHistory, NewBidWindow,
-    NewCanonicalBidOrder, OperatorData, SessionData,
+    ActiveBidWindowData, ArchivableEventData, AreaDelegationData, AreaProjectionData,
+    AuditAnnotationData, AuditArchiveVerification, AuditCompactionData, AuditEventDependency,
+    AuditEventHeader, AuditEventHeaderPage, AuditScope, AuditTimelineEntry, AuditTimelineFilter,
+    AuditTimelinePage, AuditTimelineScope, BidAmendmentPolicyData, BidEntryNotificationCandidate,
+    BidPreferenceData, BidPreferenceSpecData, BidRuleData, BidRuleSpecData, BidStatusHistoryRow,
+    BidStatusRow, BlackoutDateData, CanonicalOverrideData, ChatChannelData,
+    ChatNotificationLogData, CheckpointData, CurrentBidderData, CurrentBidderStateData,
+    DailyLeaveCountData, EligibilityExceptionData, EligibilityExceptionSpecData, FacilityData,
+    FeatureFlagData, InitialsAliasData, IntegrityDiscrepancy, IntegrityReport, JobRunData,
+    LeaveBidData, LeaveCarryoverData, MigrationStatus, NewBidStatus, NewBidStatusHistory,
+    NewBidWindow, NewCanonicalBidOrder, NewLeaveBids, NotificationLogData, OperatorActivityData,
+    OperatorAreaScopeData, OperatorData, OutboxEntryData, OverbidRequestData, OverrideValue,
+    PersistenceHealth, PortalLinkData, PrimePeriodData, ProjectedAreaProgressData,
+    ProjectedDailySlotsData, ProjectedUserAwardData, QueryPlanStep, QueuedTransitionData,
+    RoundBidOrderData, RoundBidderData, RoundCrewSlotsData, RoundEligibilityData,
+    RoundGroupSpecData, RoundGroupTemplateData, RoundPrimeCapData, RoundResultEntryData,
+    RoundSequencingData, RoundSignOffData, RoundSpecData, SavedAuditQueryData, ScopeFreezeData,
+    SelfBidRequestData, SeniorityListEntryData, SessionData, SlotAdjustmentData, SnapshotEncoding,
+    UserAnonymizationData, UserBidWindowData, UserContactData, UserEligibilityData, UserMergeData,
+    WaitlistOfferData, WaitlistSlotData, WebhookData, WebhookDeadLetterData,
+    WindowNotificationCandidate,
 };
 pub use error::PersistenceError;
 pub use mutations::PersistTransitionResult;
+pub use mutations::anonymization::{ANONYMIZED_DATE, ANONYMIZED_NAME, ANONYMIZED_RECIPIENT};
+pub use mutations::bootstrap::{
+    AreaBidScheduleOverrideFields, BidScheduleFields, merge_area_bid_schedule,
+};
+pub use queries::operators::SYSTEM_OPERATOR_ID;
+pub use queries::write_queue::{decode_queued_transition, encode_queued_transition};
+pub use secrets::{MIN_SESSION_KEY_LENGTH, SecretToken, SessionKey, SessionKeyring};
 
 use backend::PersistenceBackend;
+use column_encryption::{email_context, recipient_context};
 
 /// Type alias for backward compatibility.
 /// All new code should use `Persistence` directly.
@@ -199,6 +243,20 @@ pub enum BackendConnection {
 /// Backend selection happens once at construction time and is transparent to callers.
 pub struct Persistence {
     pub(crate) conn: BackendConnection,
+    /// The URL the connection was opened with, so read-only workers can
+    /// open connections of their own.
+    database_url: String,
+    /// How new full state snapshots are encoded.
+    snapshot_encoding: SnapshotEncoding,
+    /// Keys session token digests are computed with.
+    session_keyring: SessionKeyring,
+    /// Keys sensitive columns are encrypted with, or `None` to store them
+    /// as plaintext.
+    column_keyring: Option<ColumnKeyring>,
+    /// Where the current time is read from.
+    clock: Arc<dyn Clock>,
+    /// Where audit event ULIDs are drawn from.
+    ulids: Arc<dyn IdGenerator>,
 }
 
 impl Persistence {
@@ -227,6 +285,12 @@ impl Persistence {
 
         Ok(Self {
             conn: BackendConnection::Sqlite(conn),
+            database_url: shared_memory_url,
+            snapshot_encoding: SnapshotEncoding::default(),
+            session_keyring: SessionKeyring::default(),
+            column_keyring: None,
+            clock: Arc::new(SystemClock),
+            ulids: Arc::new(UlidGenerator::default()),
         })
     }
 
@@ -244,17 +308,28 @@ impl Persistence {
             PersistenceError::InitializationError("Invalid database path".to_string())
         })?;
 
-        // Initialize database with Diesel migrations
-        let mut conn: SqliteConnection = backend::sqlite::initialize_database(path_str)?;
+        let mut conn: SqliteConnection = backend::sqlite::open_database(path_str)?;
 
-        // Enable WAL mode for better read concurrency
+        // Enable WAL mode for better read concurrency. Switching after the
+        // migrations makes the connection's first WAL checkpoint fail with
+        // "database table is locked".
         backend::sqlite::enable_wal_mode(&mut conn)?;
 
+        // Initialize database with Diesel migrations
+        backend::sqlite::run_migrations(&mut conn)
+            .map_err(|e| PersistenceError::MigrationFailed(e.to_string()))?;
+
         // Verify foreign key enforcement is active
         backend::sqlite::verify_foreign_key_enforcement(&mut conn)?;
 
         Ok(Self {
             conn: BackendConnection::Sqlite(conn),
+            database_url: path_str.to_string(),
+            snapshot_encoding: SnapshotEncoding::default(),
+            session_keyring: SessionKeyring::default(),
+            column_keyring: None,
+            clock: Arc::new(SystemClock),
+            ulids: Arc::new(UlidGenerator::default()),
         })
     }
 
@@ -276,9 +351,135 @@ impl Persistence {
 
         Ok(Self {
             conn: BackendConnection::Mysql(conn),
+            database_url: database_url.to_string(),
+            snapshot_encoding: SnapshotEncoding::default(),
+            session_keyring: SessionKeyring::default(),
+            column_keyring: None,
+            clock: Arc::new(SystemClock),
+            ulids: Arc::new(UlidGenerator::default()),
         })
     }
 
+    /// Returns how new full state snapshots are encoded.
+    #[must_use]
+    pub const fn snapshot_encoding(&self) -> SnapshotEncoding {
+        self.snapshot_encoding
+    }
+
+    /// Sets how new full state snapshots are encoded.
+    ///
+    /// Existing snapshots are not rewritten; each is read back in the
+    /// encoding it was written with.
+    pub const fn set_snapshot_encoding(&mut self, encoding: SnapshotEncoding) {
+        self.snapshot_encoding = encoding;
+    }
+
+    /// Sets the keys session token digests are computed with.
+    ///
+    /// Sessions stored under a key that is in neither slot of the new
+    /// keyring no longer validate.
+    pub fn set_session_keyring(&mut self, keyring: SessionKeyring) {
+        self.session_keyring = keyring;
+    }
+
+    /// Sets the keys sensitive columns are encrypted with.
+    ///
+    /// Values already stored stay as they are until
+    /// [`Persistence::reseal_sensitive_columns`] rewrites them under the
+    /// current key.
+    pub const fn set_column_keyring(&mut self, keyring: ColumnKeyring) {
+        self.column_keyring = Some(keyring);
+    }
+
+    /// Encrypts a sensitive value, or returns it unchanged when no column
+    /// key is configured.
+    fn seal_column(&self, context: &str, value: &str) -> String {
+        self.column_keyring
+            .as_ref()
+            .map_or_else(|| value.to_string(), |keyring| keyring.seal(context, value))
+    }
+
+    /// Decrypts a stored sensitive value.
+    fn open_column(&self, context: &str, stored: &str) -> Result<String, PersistenceError> {
+        self.column_keyring.as_ref().map_or_else(
+            || column_encryption::open_without_key(context, stored),
+            |keyring| keyring.open(context, stored),
+        )
+    }
+
+    /// Encrypts every sensitive value that is still plaintext or sealed
+    /// under the previous key, so the previous key can be dropped.
+    ///
+    /// Does nothing when no column key is configured.
+    ///
+    /// # Returns
+    ///
+    /// The number of values rewritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a stored value cannot be decrypted or the
+    /// database operation fails. Nothing is rewritten in that case.
+    pub fn reseal_sensitive_columns(&mut self) -> Result<usize, PersistenceError> {
+        let Some(keyring) = self.column_keyring.clone() else {
+            return Ok(0);
+        };
+        let reseal = |context: String, stored: &str| -> Result<Option<String>, PersistenceError> {
+            if !keyring.needs_reseal(stored) {
+                return Ok(None);
+            }
+            let plaintext: String = keyring.open(&context, stored)?;
+            Ok(Some(keyring.seal(&context, &plaintext)))
+        };
+        let reseal_email = |user_id: i64, stored: &str| reseal(email_context(user_id), stored);
+        let reseal_recipient = |user_id: i64, stored: &str| {
+            if stored == ANONYMIZED_RECIPIENT {
+                return Ok(None);
+            }
+            reseal(recipient_context(user_id), stored)
+        };
+
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::reseal_contact_columns_sqlite(conn, &reseal_email, &reseal_recipient)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::reseal_contact_columns_mysql(conn, &reseal_email, &reseal_recipient)
+            }
+        }
+    }
+
+    /// Returns the clock the current time is read from.
+    #[must_use]
+    pub fn clock(&self) -> Arc<dyn Clock> {
+        Arc::clone(&self.clock)
+    }
+
+    /// Sets the clock the current time is read from.
+    ///
+    /// Defaults to the system clock. Callers holding this adapter read the
+    /// time through [`Self::now`], so substituting a controllable clock
+    /// makes expiry and window deadlines deterministic. Audit event ULIDs
+    /// are timestamped by the new clock from then on.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.ulids = Arc::new(UlidGenerator::new(Arc::clone(&clock)));
+        self.clock = clock;
+    }
+
+    /// Sets where the ULIDs of newly recorded audit events are drawn from.
+    ///
+    /// Defaults to a [`UlidGenerator`] reading this adapter's clock.
+    /// Events that already carry a ULID, such as imported ones, keep it.
+    pub fn set_id_generator(&mut self, ulids: Arc<dyn IdGenerator>) {
+        self.ulids = ulids;
+    }
+
+    /// Returns the current time according to this adapter's clock.
+    #[must_use]
+    pub fn now(&self) -> time::OffsetDateTime {
+        self.clock.now()
+    }
+
     /// Verifies that foreign key enforcement is enabled.
     ///
     /// This is a startup-time check required to ensure
@@ -294,1160 +495,1841 @@ impl Persistence {
         }
     }
 
-    // ========================================================================
-    // Transitions & Bootstrap
-    // ========================================================================
+    /// Returns whether the database is `SQLite`, and so supports
+    /// [`Persistence::backup_to`].
+    #[must_use]
+    pub const fn is_sqlite(&self) -> bool {
+        matches!(self.conn, BackendConnection::Sqlite(_))
+    }
 
-    /// Persists a transition result (audit event and optionally a full snapshot).
+    /// Writes a consistent copy of the database to `destination`.
     ///
-    /// # Arguments
+    /// Only supported on the `SQLite` backend; `MySQL` deployments should use
+    /// the server's own dump tooling.
     ///
-    /// * `result` - The transition result to persist
+    /// # Errors
     ///
-    /// # Returns
+    /// Returns an error if the backend is `MySQL`, the path is not valid
+    /// UTF-8, or the backup cannot be written.
+    pub fn backup_to<P: AsRef<Path>>(&mut self, destination: P) -> Result<(), PersistenceError> {
+        let destination: &str = destination
+            .as_ref()
+            .to_str()
+            .ok_or_else(|| PersistenceError::Other(String::from("Invalid backup path")))?;
+
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => backend::sqlite::backup_to(conn, destination),
+            BackendConnection::Mysql(_) => Err(PersistenceError::Other(String::from(
+                "Backups are only supported for SQLite databases",
+            ))),
+        }
+    }
+
+    /// Flushes the `SQLite` write-ahead log into the main database file.
     ///
-    /// A `PersistTransitionResult` containing the event ID and optionally the user ID
-    /// (for `RegisterUser` transitions).
+    /// Run during shutdown so the database file alone holds every committed
+    /// write. A no-op on `MySQL`, which manages its own logs.
     ///
     /// # Errors
     ///
-    /// Returns an error if persistence fails.
-    pub fn persist_transition(
-        &mut self,
-        result: &TransitionResult,
-    ) -> Result<mutations::PersistTransitionResult, PersistenceError> {
-        let should_snapshot = queries::state::should_snapshot(&result.audit_event.action.name);
+    /// Returns an error if the checkpoint cannot be run.
+    pub fn checkpoint_wal(&mut self) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => backend::sqlite::checkpoint_wal(conn),
+            BackendConnection::Mysql(_) => Ok(()),
+        }
+    }
+
+    /// Runs `f` and returns the SQL of every statement it issued, in order.
+    ///
+    /// Bind parameters are left as placeholders. Intended for tooling that
+    /// audits the queries behind an operation, such as query plan checks.
+    pub fn record_queries<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> (T, Vec<String>) {
+        let statements: Arc<Mutex<Vec<String>>> = Arc::default();
+        let sink: Arc<Mutex<Vec<String>>> = Arc::clone(&statements);
+        let recorder = move |event: InstrumentationEvent<'_>| {
+            if let InstrumentationEvent::StartQuery { query, .. } = event {
+                // Debug output appends the bind values after the SQL
+                let text: String = query.to_string();
+                let sql: &str = text
+                    .split_once(" -- binds: ")
+                    .map_or(text.as_str(), |(sql, _)| sql);
+                sink.lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(sql.to_string());
+            }
+        };
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.set_instrumentation(recorder),
+            BackendConnection::Mysql(conn) => conn.set_instrumentation(recorder),
+        }
+
+        let result: T = f(self);
+
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::persist_transition_sqlite(conn, result, should_snapshot)
+                conn.set_instrumentation(get_default_instrumentation());
             }
             BackendConnection::Mysql(conn) => {
-                mutations::persist_transition_mysql(conn, result, should_snapshot)
+                conn.set_instrumentation(get_default_instrumentation());
             }
         }
+        let sql: Vec<String> =
+            std::mem::take(&mut *statements.lock().unwrap_or_else(PoisonError::into_inner));
+        (result, sql)
     }
 
-    /// Persists an audit event.
-    ///
-    /// # Arguments
+    /// Reports how the database would execute a statement, without running it.
     ///
-    /// * `event` - The audit event to persist
+    /// Only supported on the `SQLite` backend, where it runs
+    /// `EXPLAIN QUERY PLAN`.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// The event ID assigned to the persisted audit event.
+    /// * `sql` - The statement to explain; bind parameters may be left unbound
     ///
     /// # Errors
     ///
-    /// Returns an error if persistence fails.
-    pub fn persist_audit_event(&mut self, event: &AuditEvent) -> Result<i64, PersistenceError> {
+    /// Returns an error if the backend is `MySQL` or the statement cannot be
+    /// prepared.
+    pub fn explain_query_plan(
+        &mut self,
+        sql: &str,
+    ) -> Result<Vec<QueryPlanStep>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::persist_audit_event_sqlite(conn, event),
-            BackendConnection::Mysql(conn) => mutations::persist_audit_event_mysql(conn, event),
+            BackendConnection::Sqlite(conn) => backend::sqlite::explain_query_plan(conn, sql),
+            BackendConnection::Mysql(_) => Err(PersistenceError::Other(String::from(
+                "Query plans are only supported for SQLite databases",
+            ))),
         }
     }
 
-    /// Persists a bootstrap result (audit event for bid year/area creation).
-    ///
-    /// # Arguments
+    /// Probes database connectivity, migration state, and foreign key enforcement.
+    ///
+    /// Intended for readiness checks. Never fails; each check's outcome is
+    /// recorded in the returned report.
+    pub fn health(&mut self) -> PersistenceHealth {
+        #[allow(clippy::type_complexity)]
+        let (backend, ping, migrations, foreign_keys): (
+            &'static str,
+            Result<(), PersistenceError>,
+            Result<MigrationStatus, PersistenceError>,
+            Result<bool, PersistenceError>,
+        ) = match &mut self.conn {
+            BackendConnection::Sqlite(conn) => (
+                "sqlite",
+                conn.ping(),
+                conn.migration_status(),
+                conn.foreign_keys_enabled(),
+            ),
+            BackendConnection::Mysql(conn) => (
+                "mysql",
+                conn.ping(),
+                conn.migration_status(),
+                conn.foreign_keys_enabled(),
+            ),
+        };
+
+        let mut errors: Vec<String> = Vec::new();
+        let database_reachable: bool = match ping {
+            Ok(()) => true,
+            Err(e) => {
+                errors.push(format!("connectivity: {e}"));
+                false
+            }
+        };
+        let migrations: Option<MigrationStatus> = match migrations {
+            Ok(status) => {
+                if status.pending > 0 {
+                    errors.push(format!("migrations: {} pending", status.pending));
+                }
+                Some(status)
+            }
+            Err(e) => {
+                errors.push(format!("migrations: {e}"));
+                None
+            }
+        };
+        let foreign_keys_enforced: bool = match foreign_keys {
+            Ok(true) => true,
+            Ok(false) => {
+                errors.push(String::from("foreign keys: enforcement is disabled"));
+                false
+            }
+            Err(e) => {
+                errors.push(format!("foreign keys: {e}"));
+                false
+            }
+        };
+
+        PersistenceHealth {
+            backend,
+            database_reachable,
+            migrations,
+            foreign_keys_enforced,
+            errors,
+        }
+    }
+
+    /// Checks the whole database for internal inconsistencies.
     ///
-    /// * `result` - The bootstrap result to persist
+    /// Runs, in order:
     ///
-    /// # Returns
+    /// - `database`: the [`Self::health`] probe (migrations applied, foreign
+    ///   keys enforced)
+    /// - `audit_log`: every audit event decodes, is scoped consistently, and
+    ///   is no older than the event before it
+    /// - `snapshots`: every snapshot is scoped like the event it was taken at
+    /// - `canonical_state`: each area whose latest audit event is its latest
+    ///   snapshot has exactly the snapshot's users in the users table
+    /// - `audit_archive`: each compacted bid year's archived events still
+    ///   hash to the chain head its compaction recorded
     ///
-    /// The event ID assigned to the persisted audit event.
+    /// Areas changed since their latest snapshot are not reconciled, as the
+    /// audit log does not record enough to replay those changes.
     ///
     /// # Errors
     ///
-    /// Returns an error if persistence fails.
-    pub fn persist_bootstrap(&mut self, result: &BootstrapResult) -> Result<i64, PersistenceError> {
-        match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::persist_bootstrap_sqlite(conn, result),
-            BackendConnection::Mysql(conn) => mutations::persist_bootstrap_mysql(conn, result),
+    /// Returns an error if the database cannot be read. Problems found in
+    /// the data are recorded in the report instead.
+    pub fn verify_integrity(&mut self) -> Result<IntegrityReport, PersistenceError> {
+        const PAGE_SIZE: i64 = 500;
+
+        let mut report: IntegrityReport = IntegrityReport::default();
+        for error in self.health().errors {
+            report.record("database", "database", error);
+        }
+
+        let mut after_event_id: i64 = 0;
+        let mut previous_created_at: Option<String> = None;
+        loop {
+            let rows: Vec<queries::integrity::AuditIntegrityRow> = match &mut self.conn {
+                BackendConnection::Sqlite(conn) => {
+                    queries::integrity::list_audit_integrity_rows_sqlite(
+                        conn,
+                        after_event_id,
+                        PAGE_SIZE,
+                    )?
+                }
+                BackendConnection::Mysql(conn) => {
+                    queries::integrity::list_audit_integrity_rows_mysql(
+                        conn,
+                        after_event_id,
+                        PAGE_SIZE,
+                    )?
+                }
+            };
+            let Some(last) = rows.last() else { break };
+            after_event_id = last.event_id;
+            for row in &rows {
+                row.check(previous_created_at.as_deref(), &mut report);
+                if row.created_at.is_some() {
+                    previous_created_at.clone_from(&row.created_at);
+                }
+            }
+            report.audit_events_checked += rows.len();
+        }
+
+        let mut after_snapshot_id: i64 = 0;
+        loop {
+            let rows: Vec<queries::integrity::SnapshotScopeRow> = match &mut self.conn {
+                BackendConnection::Sqlite(conn) => queries::integrity::list_snapshot_scopes_sqlite(
+                    conn,
+                    after_snapshot_id,
+                    PAGE_SIZE,
+                )?,
+                BackendConnection::Mysql(conn) => queries::integrity::list_snapshot_scopes_mysql(
+                    conn,
+                    after_snapshot_id,
+                    PAGE_SIZE,
+                )?,
+            };
+            let Some(last) = rows.last() else { break };
+            after_snapshot_id = last.snapshot_id;
+            for row in &rows {
+                row.check(&mut report);
+            }
+            report.snapshots_checked += rows.len();
+        }
+
+        self.reconcile_canonical_state(&mut report)?;
+
+        let compactions: Vec<AuditCompactionData> = match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::compaction::list_audit_compactions_sqlite(conn)?
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::compaction::list_audit_compactions_mysql(conn)?
+            }
+        };
+        for compaction in &compactions {
+            let verification: AuditArchiveVerification = match &mut self.conn {
+                BackendConnection::Sqlite(conn) => {
+                    queries::compaction::verify_audit_archive_sqlite(conn, compaction)?
+                }
+                BackendConnection::Mysql(conn) => {
+                    queries::compaction::verify_audit_archive_mysql(conn, compaction)?
+                }
+            };
+            report.archived_events_checked += verification.events_checked;
+            if !verification.is_intact() {
+                report.record(
+                    "audit_archive",
+                    format!("compaction {}", compaction.compaction_id),
+                    format!(
+                        "{} of {} archived events hash to chain head {}, recorded {}; broken links at events {:?}",
+                        verification.events_checked,
+                        compaction.archived_event_count,
+                        verification.recomputed_head,
+                        compaction.chain_head,
+                        verification.broken_event_ids
+                    ),
+                );
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Compares each area's canonical users with its latest snapshot, for
+    /// areas unchanged since that snapshot.
+    fn reconcile_canonical_state(
+        &mut self,
+        report: &mut IntegrityReport,
+    ) -> Result<(), PersistenceError> {
+        const RECONCILE_CONNECTIONS: usize = 4;
+
+        for canonical_bid_year in self.list_bid_years()? {
+            let bid_year: BidYear = BidYear::new(canonical_bid_year.year());
+            for canonical in self.load_bid_year_states(&bid_year, RECONCILE_CONNECTIONS)? {
+                let subject: String = format!("{}/{}", bid_year.year(), canonical.area.id());
+                let (Some(bid_year_id), Some(area_id)) =
+                    (canonical.bid_year.bid_year_id(), canonical.area.area_id())
+                else {
+                    continue;
+                };
+                let (snapshot, latest_event_id): (
+                    Result<(State, i64), PersistenceError>,
+                    Option<i64>,
+                ) = match &mut self.conn {
+                    BackendConnection::Sqlite(conn) => (
+                        queries::get_latest_snapshot_sqlite(conn, bid_year_id, area_id),
+                        queries::integrity::get_latest_area_event_id_sqlite(
+                            conn,
+                            bid_year_id,
+                            area_id,
+                        )?,
+                    ),
+                    BackendConnection::Mysql(conn) => (
+                        queries::get_latest_snapshot_mysql(conn, bid_year_id, area_id),
+                        queries::integrity::get_latest_area_event_id_mysql(
+                            conn,
+                            bid_year_id,
+                            area_id,
+                        )?,
+                    ),
+                };
+                match snapshot {
+                    Ok((snapshot, event_id)) if Some(event_id) == latest_event_id => {
+                        queries::integrity::reconcile_area(&subject, &snapshot, &canonical, report);
+                        report.areas_reconciled += 1;
+                    }
+                    Ok(_) => {}
+                    Err(e) => report.record("snapshots", subject, format!("latest snapshot: {e}")),
+                }
+            }
         }
+        Ok(())
     }
 
     // ========================================================================
-    // Audit Event Queries
+    // Bid Year Bundles
     // ========================================================================
 
-    /// Retrieves an audit event by ID.
+    /// Exports a bid year as a [`BidYearBundle`].
     ///
     /// # Arguments
     ///
-    /// * `event_id` - The event ID to retrieve
+    /// * `year` - The bid year to export
+    /// * `exported_at` - When the bundle is written (RFC 3339)
     ///
     /// # Errors
     ///
-    /// Returns an error if the event is not found or cannot be deserialized.
-    pub fn get_audit_event(&mut self, event_id: i64) -> Result<AuditEvent, PersistenceError> {
+    /// Returns an error if the bid year does not exist or cannot be read.
+    pub fn export_bid_year(
+        &mut self,
+        year: u16,
+        exported_at: &str,
+    ) -> Result<BidYearBundle, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::audit::get_audit_event_sqlite(conn, event_id)
+                bundle::export_bid_year_sqlite(conn, year, exported_at)
+            }
+            BackendConnection::Mysql(conn) => {
+                bundle::export_bid_year_mysql(conn, year, exported_at)
             }
-            BackendConnection::Mysql(conn) => queries::audit::get_audit_event_mysql(conn, event_id),
         }
     }
 
-    /// Retrieves the most recent state snapshot for a `(BidYear, Area)` scope.
+    /// Imports a [`BidYearBundle`] as a new, inactive bid year.
+    ///
+    /// The import is all-or-nothing. Snapshots are re-encoded in this
+    /// database's snapshot encoding.
     ///
     /// # Arguments
     ///
-    /// * `bid_year` - The bid year
-    /// * `area` - The area
+    /// * `bundle` - The bundle to import
+    ///
+    /// # Returns
+    ///
+    /// The imported bid year's ID.
     ///
     /// # Errors
     ///
-    /// Returns an error if no snapshot exists or cannot be deserialized.
-    pub fn get_latest_snapshot(
-        &mut self,
-        bid_year: &BidYear,
-        area: &Area,
-    ) -> Result<(State, i64), PersistenceError> {
+    /// Returns an error if the bundle's format is not supported, the bid
+    /// year already exists, an operator who acted in the bundled audit log
+    /// does not exist here, or the bundle is inconsistent.
+    pub fn import_bid_year(&mut self, bundle: &BidYearBundle) -> Result<i64, PersistenceError> {
+        let encoding: SnapshotEncoding = self.snapshot_encoding;
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                let bid_year_id = queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                let area_id = queries::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
-                queries::get_latest_snapshot_sqlite(conn, bid_year_id, area_id)
+                conn.transaction(|conn| bundle::import_bid_year_sqlite(conn, bundle, encoding))
             }
             BackendConnection::Mysql(conn) => {
-                let bid_year_id = queries::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                let area_id = queries::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
-                queries::get_latest_snapshot_mysql(conn, bid_year_id, area_id)
+                conn.transaction(|conn| bundle::import_bid_year_mysql(conn, bundle, encoding))
             }
         }
     }
 
-    /// Retrieves all audit events for a `(BidYear, Area)` scope after a given event ID.
+    // ========================================================================
+    // Transitions & Bootstrap
+    // ========================================================================
+
+    /// Persists a transition result (audit event and optionally a full snapshot).
+    ///
+    /// The audit event and the canonical changes it records are written in
+    /// a single database transaction. A `Rollback` also restores its area
+    /// as of the target event within that transaction.
     ///
     /// # Arguments
     ///
-    /// * `bid_year` - The bid year
-    /// * `area` - The area
-    /// * `after_event_id` - Only return events after this ID (exclusive)
+    /// * `result` - The transition result to persist
+    ///
+    /// # Returns
+    ///
+    /// A `PersistTransitionResult` containing the event ID and optionally the user ID
+    /// (for `RegisterUser` transitions).
     ///
     /// # Errors
     ///
-    /// Returns an error if events cannot be retrieved or deserialized.
-    pub fn get_events_after(
+    /// Returns an error if persistence fails.
+    pub fn persist_transition(
         &mut self,
-        bid_year: &BidYear,
-        area: &Area,
-        after_event_id: i64,
-    ) -> Result<Vec<AuditEvent>, PersistenceError> {
+        result: &TransitionResult,
+    ) -> Result<mutations::PersistTransitionResult, PersistenceError> {
+        let should_snapshot = queries::state::should_snapshot(&result.audit_event.action.name);
+        let encoding: SnapshotEncoding = self.snapshot_encoding;
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                let bid_year_id = queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                let area_id = queries::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
-                queries::get_events_after_sqlite(conn, bid_year_id, area_id, after_event_id)
-            }
-            BackendConnection::Mysql(conn) => {
-                let bid_year_id = queries::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                let area_id = queries::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
-                queries::get_events_after_mysql(conn, bid_year_id, area_id, after_event_id)
-            }
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                mutations::persist_transition_sqlite(conn, result, should_snapshot, encoding, ulids)
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                mutations::persist_transition_mysql(conn, result, should_snapshot, encoding, ulids)
+            }),
         }
     }
 
-    /// Retrieves the current effective state for a given `(BidYear, Area)` scope.
+    /// Persists a batch of transition results atomically.
+    ///
+    /// All transitions are written inside a single database transaction; if
+    /// any one fails, none of them are persisted.
     ///
     /// # Arguments
     ///
-    /// * `bid_year` - The bid year
-    /// * `area` - The area
+    /// * `results` - The transition results to persist, in order
+    ///
+    /// # Returns
+    ///
+    /// One `PersistTransitionResult` per input, in the same order.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn get_current_state(
+    /// Returns an error if any transition fails to persist. The whole batch is
+    /// rolled back in that case.
+    pub fn persist_transitions(
         &mut self,
-        bid_year: &BidYear,
-        area: &Area,
-    ) -> Result<State, PersistenceError> {
+        results: &[TransitionResult],
+    ) -> Result<Vec<mutations::PersistTransitionResult>, PersistenceError> {
+        let encoding: SnapshotEncoding = self.snapshot_encoding;
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                let bid_year_id = queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                let area_id = queries::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
-                queries::get_current_state_sqlite(conn, bid_year_id, area_id, bid_year, area)
-            }
-            BackendConnection::Mysql(conn) => {
-                let bid_year_id = queries::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                let area_id = queries::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
-                queries::get_current_state_mysql(conn, bid_year_id, area_id, bid_year, area)
-            }
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                results
+                    .iter()
+                    .map(|result| {
+                        let should_snapshot: bool =
+                            queries::state::should_snapshot(&result.audit_event.action.name);
+                        mutations::persist_transition_sqlite(
+                            conn,
+                            result,
+                            should_snapshot,
+                            encoding,
+                            ulids,
+                        )
+                    })
+                    .collect()
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                results
+                    .iter()
+                    .map(|result| {
+                        let should_snapshot: bool =
+                            queries::state::should_snapshot(&result.audit_event.action.name);
+                        mutations::persist_transition_mysql(
+                            conn,
+                            result,
+                            should_snapshot,
+                            encoding,
+                            ulids,
+                        )
+                    })
+                    .collect()
+            }),
         }
     }
 
-    /// Retrieves the effective state for a given `(BidYear, Area)` scope at a specific timestamp.
+    /// Persists a batch of transition results atomically, recording every
+    /// resulting audit event under one correlation ID.
+    ///
+    /// The transitions and their correlations are written inside a single
+    /// database transaction, so an operation's automatic checkpoints are
+    /// never left without the operation, or the operation without them.
     ///
     /// # Arguments
     ///
-    /// * `bid_year` - The bid year
-    /// * `area` - The area
-    /// * `timestamp` - The target timestamp (ISO 8601 format)
+    /// * `results` - The transition results to persist, in order
+    /// * `correlation_id` - The operation's correlation ID
+    ///
+    /// # Returns
+    ///
+    /// One `PersistTransitionResult` per input, in the same order.
     ///
     /// # Errors
     ///
-    /// Returns an error if no snapshot exists before the timestamp.
-    pub fn get_historical_state(
+    /// Returns an error if any transition or correlation fails to persist.
+    /// The whole batch is rolled back in that case.
+    pub fn persist_correlated_transitions(
         &mut self,
-        bid_year: &BidYear,
-        area: &Area,
-        timestamp: &str,
-    ) -> Result<State, PersistenceError> {
+        results: &[TransitionResult],
+        correlation_id: &str,
+    ) -> Result<Vec<mutations::PersistTransitionResult>, PersistenceError> {
+        let encoding: SnapshotEncoding = self.snapshot_encoding;
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                let bid_year_id = queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                let area_id = queries::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
-                queries::get_historical_state_sqlite(conn, bid_year_id, area_id, timestamp)
-            }
-            BackendConnection::Mysql(conn) => {
-                let bid_year_id = queries::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                let area_id = queries::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
-                queries::get_historical_state_mysql(conn, bid_year_id, area_id, timestamp)
-            }
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                let persisted: Vec<mutations::PersistTransitionResult> = results
+                    .iter()
+                    .map(|result| {
+                        let should_snapshot: bool =
+                            queries::state::should_snapshot(&result.audit_event.action.name);
+                        mutations::persist_transition_sqlite(
+                            conn,
+                            result,
+                            should_snapshot,
+                            encoding,
+                            ulids,
+                        )
+                    })
+                    .collect::<Result<_, _>>()?;
+                let event_ids: Vec<i64> = persisted.iter().map(|p| p.event_id).collect();
+                mutations::record_event_correlations_sqlite(conn, &event_ids, correlation_id)?;
+                Ok(persisted)
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                let persisted: Vec<mutations::PersistTransitionResult> = results
+                    .iter()
+                    .map(|result| {
+                        let should_snapshot: bool =
+                            queries::state::should_snapshot(&result.audit_event.action.name);
+                        mutations::persist_transition_mysql(
+                            conn,
+                            result,
+                            should_snapshot,
+                            encoding,
+                            ulids,
+                        )
+                    })
+                    .collect::<Result<_, _>>()?;
+                let event_ids: Vec<i64> = persisted.iter().map(|p| p.event_id).collect();
+                mutations::record_event_correlations_mysql(conn, &event_ids, correlation_id)?;
+                Ok(persisted)
+            }),
         }
     }
 
-    /// Retrieves the ordered audit event timeline for a given `(BidYear, Area)` scope.
+    /// Persists transitions taken from a write-ahead queue, exactly once.
+    ///
+    /// The transitions and the queue's progress are written in a single
+    /// database transaction. Entries at or below the recorded progress were
+    /// persisted by an earlier flush and are skipped.
     ///
     /// # Arguments
     ///
-    /// * `bid_year` - The bid year
-    /// * `area` - The area
+    /// * `queue_name` - The queue's name
+    /// * `entries` - Sequence numbers and transitions, in ascending sequence order
+    /// * `updated_at` - When the flush happened (RFC 3339)
+    ///
+    /// # Returns
+    ///
+    /// One `PersistTransitionResult` per entry that was not already persisted.
     ///
     /// # Errors
     ///
-    /// Returns an error if events cannot be retrieved or deserialized.
-    pub fn get_audit_timeline(
+    /// Returns an error if any transition fails to persist. None of the
+    /// entries are persisted in that case.
+    pub fn persist_queued_transitions(
         &mut self,
-        bid_year: &BidYear,
-        area: &Area,
-    ) -> Result<Vec<AuditEvent>, PersistenceError> {
+        queue_name: &str,
+        entries: &[(i64, TransitionResult)],
+        updated_at: &str,
+    ) -> Result<Vec<mutations::PersistTransitionResult>, PersistenceError> {
+        let Some(last_sequence) = entries.last().map(|(sequence, _)| *sequence) else {
+            return Ok(Vec::new());
+        };
+        let encoding: SnapshotEncoding = self.snapshot_encoding;
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                // Look up the canonical IDs - if they don't exist, return empty timeline
-                let bid_year_id = match queries::lookup_bid_year_id_sqlite(conn, bid_year.year()) {
-                    Ok(id) => id,
-                    Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
-                    Err(e) => return Err(e),
-                };
-                let area_id = match queries::lookup_area_id_sqlite(conn, bid_year_id, area.id()) {
-                    Ok(id) => id,
-                    Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
-                    Err(e) => return Err(e),
-                };
-
-                queries::get_audit_timeline_sqlite(conn, bid_year_id, area_id)
-            }
-            BackendConnection::Mysql(conn) => {
-                // Look up the canonical IDs - if they don't exist, return empty timeline
-                let bid_year_id = match queries::lookup_bid_year_id_mysql(conn, bid_year.year()) {
-                    Ok(id) => id,
-                    Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
-                    Err(e) => return Err(e),
-                };
-                let area_id = match queries::lookup_area_id_mysql(conn, bid_year_id, area.id()) {
-                    Ok(id) => id,
-                    Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
-                    Err(e) => return Err(e),
-                };
-
-                queries::get_audit_timeline_mysql(conn, bid_year_id, area_id)
-            }
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                let persisted: i64 =
+                    queries::write_queue::get_write_queue_progress_sqlite(conn, queue_name)?
+                        .unwrap_or(0);
+                let results: Vec<mutations::PersistTransitionResult> = entries
+                    .iter()
+                    .filter(|(sequence, _)| *sequence > persisted)
+                    .map(|(_, result)| {
+                        let should_snapshot: bool =
+                            queries::state::should_snapshot(&result.audit_event.action.name);
+                        mutations::persist_transition_sqlite(
+                            conn,
+                            result,
+                            should_snapshot,
+                            encoding,
+                            ulids,
+                        )
+                    })
+                    .collect::<Result<_, _>>()?;
+                queries::write_queue::record_write_queue_progress_sqlite(
+                    conn,
+                    queue_name,
+                    last_sequence.max(persisted),
+                    updated_at,
+                )?;
+                Ok(results)
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                let persisted: i64 =
+                    queries::write_queue::get_write_queue_progress_mysql(conn, queue_name)?
+                        .unwrap_or(0);
+                let results: Vec<mutations::PersistTransitionResult> = entries
+                    .iter()
+                    .filter(|(sequence, _)| *sequence > persisted)
+                    .map(|(_, result)| {
+                        let should_snapshot: bool =
+                            queries::state::should_snapshot(&result.audit_event.action.name);
+                        mutations::persist_transition_mysql(
+                            conn,
+                            result,
+                            should_snapshot,
+                            encoding,
+                            ulids,
+                        )
+                    })
+                    .collect::<Result<_, _>>()?;
+                queries::write_queue::record_write_queue_progress_mysql(
+                    conn,
+                    queue_name,
+                    last_sequence.max(persisted),
+                    updated_at,
+                )?;
+                Ok(results)
+            }),
         }
     }
 
-    /// Retrieves all global audit events (events with no bid year or area scope).
+    /// Gets the highest sequence number a write-ahead queue has persisted.
     ///
     /// # Errors
     ///
-    /// Returns an error if events cannot be retrieved or deserialized.
-    pub fn get_global_audit_events(&mut self) -> Result<Vec<AuditEvent>, PersistenceError> {
+    /// Returns an error if the query fails.
+    pub fn get_write_queue_progress(
+        &mut self,
+        queue_name: &str,
+    ) -> Result<Option<i64>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::get_global_audit_events_sqlite(conn),
-            BackendConnection::Mysql(conn) => queries::get_global_audit_events_mysql(conn),
+            BackendConnection::Sqlite(conn) => {
+                queries::write_queue::get_write_queue_progress_sqlite(conn, queue_name)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::write_queue::get_write_queue_progress_mysql(conn, queue_name)
+            }
         }
     }
 
     // ========================================================================
-    // Bootstrap & Canonical Queries
+    // Dashboard Projections
     // ========================================================================
 
-    /// Reconstructs bootstrap metadata from canonical tables.
+    /// Gets the last audit event a projection has applied.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn get_bootstrap_metadata(&mut self) -> Result<BootstrapMetadata, PersistenceError> {
+    /// Returns an error if the query fails.
+    pub fn get_projection_cursor(
+        &mut self,
+        projection_name: &str,
+    ) -> Result<Option<i64>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::get_bootstrap_metadata_sqlite(conn),
-            BackendConnection::Mysql(conn) => queries::get_bootstrap_metadata_mysql(conn),
+            BackendConnection::Sqlite(conn) => {
+                queries::projections::get_projection_cursor_sqlite(conn, projection_name)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::projections::get_projection_cursor_mysql(conn, projection_name)
+            }
         }
     }
 
-    /// Lists all bid years that have been created.
+    /// Lists the distinct `(bid_year_id, area_id)` scopes of the audit
+    /// events after `after_event_id`, up to and including `through_event_id`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn list_bid_years(&mut self) -> Result<Vec<CanonicalBidYear>, PersistenceError> {
+    /// Returns an error if the query fails.
+    pub fn list_audit_scopes(
+        &mut self,
+        after_event_id: i64,
+        through_event_id: i64,
+    ) -> Result<Vec<AuditScope>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::list_bid_years_sqlite(conn),
-            BackendConnection::Mysql(conn) => queries::list_bid_years_mysql(conn),
+            BackendConnection::Sqlite(conn) => queries::projections::list_audit_scopes_sqlite(
+                conn,
+                after_event_id,
+                through_event_id,
+            ),
+            BackendConnection::Mysql(conn) => queries::projections::list_audit_scopes_mysql(
+                conn,
+                after_event_id,
+                through_event_id,
+            ),
         }
     }
 
-    /// Lists all areas for a given bid year.
+    /// Replaces the projected rows of each area and advances the
+    /// projection's cursor, all in one transaction.
     ///
     /// # Arguments
     ///
-    /// * `bid_year` - The bid year to list areas for
+    /// * `projection_name` - The projection
+    /// * `areas` - The new rows of every area that changed
+    /// * `last_event_id` - The last audit event the rows reflect
+    /// * `updated_at` - When the projection was updated (RFC 3339)
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn list_areas(&mut self, bid_year: &BidYear) -> Result<Vec<Area>, PersistenceError> {
+    /// Returns an error if the write fails; nothing is applied then.
+    pub fn apply_area_projections(
+        &mut self,
+        projection_name: &str,
+        areas: &[AreaProjectionData],
+        last_event_id: i64,
+        updated_at: &str,
+    ) -> Result<(), PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                let bid_year_id = match bid_year.bid_year_id() {
-                    Some(id) => id,
-                    None => match queries::lookup_bid_year_id_sqlite(conn, bid_year.year()) {
-                        Ok(id) => id,
-                        Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
-                        Err(e) => return Err(e),
-                    },
-                };
-                queries::list_areas_sqlite(conn, bid_year_id)
-            }
-            BackendConnection::Mysql(conn) => {
-                let bid_year_id = match bid_year.bid_year_id() {
-                    Some(id) => id,
-                    None => match queries::lookup_bid_year_id_mysql(conn, bid_year.year()) {
-                        Ok(id) => id,
-                        Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
-                        Err(e) => return Err(e),
-                    },
-                };
-                queries::list_areas_mysql(conn, bid_year_id)
-            }
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                for area in areas {
+                    queries::projections::replace_area_projection_sqlite(conn, area)?;
+                }
+                queries::projections::record_projection_cursor_sqlite(
+                    conn,
+                    projection_name,
+                    last_event_id,
+                    updated_at,
+                )
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                for area in areas {
+                    queries::projections::replace_area_projection_mysql(conn, area)?;
+                }
+                queries::projections::record_projection_cursor_mysql(
+                    conn,
+                    projection_name,
+                    last_event_id,
+                    updated_at,
+                )
+            }),
         }
     }
 
-    /// Lists all users for a given `(BidYear, Area)` scope.
-    ///
-    /// # Arguments
-    ///
-    /// * `bid_year` - The bid year
-    /// * `area` - The area
+    /// Lists the projected leave slots of an area within a date range,
+    /// ordered by round, then date.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn list_users(
+    /// Returns an error if the query fails.
+    pub fn list_projected_daily_slots(
         &mut self,
-        bid_year: &BidYear,
-        area: &Area,
-    ) -> Result<Vec<User>, PersistenceError> {
+        area_id: i64,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<ProjectedDailySlotsData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                let bid_year_id = queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                let area_id = queries::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
-                queries::list_users_sqlite(conn, bid_year_id, area_id, bid_year, area)
+                queries::projections::list_projected_daily_slots_sqlite(
+                    conn, area_id, start_date, end_date,
+                )
             }
             BackendConnection::Mysql(conn) => {
-                let bid_year_id = queries::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                let area_id = queries::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
-                queries::list_users_mysql(conn, bid_year_id, area_id, bid_year, area)
+                queries::projections::list_projected_daily_slots_mysql(
+                    conn, area_id, start_date, end_date,
+                )
             }
         }
     }
 
-    // ========================================================================
-    // Completeness Queries
-    // ========================================================================
-
-    /// Counts users per area for a given bid year.
-    ///
-    /// # Arguments
-    ///
-    /// * `bid_year` - The bid year to count users for
+    /// Lists the projected approved leave of every user in an area.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn count_users_by_area(
+    /// Returns an error if the query fails.
+    pub fn list_projected_user_awards(
         &mut self,
-        bid_year: &BidYear,
-    ) -> Result<Vec<(String, usize)>, PersistenceError> {
-        let bid_year_id = bid_year.bid_year_id().ok_or_else(|| {
-            PersistenceError::ReconstructionError(
-                "BidYear must have a bid_year_id to count users".to_string(),
-            )
-        })?;
+        area_id: i64,
+    ) -> Result<Vec<ProjectedUserAwardData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::count_users_by_area_sqlite(conn, bid_year_id)
+                queries::projections::list_projected_user_awards_sqlite(conn, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::projections::list_projected_user_awards_mysql(conn, area_id)
             }
-            BackendConnection::Mysql(conn) => queries::count_users_by_area_mysql(conn, bid_year_id),
         }
     }
 
-    /// Counts areas per bid year.
+    /// Lists the projected bid status counts of every area in a bid year,
+    /// ordered by area, then round.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn count_areas_by_bid_year(&mut self) -> Result<Vec<(u16, usize)>, PersistenceError> {
+    /// Returns an error if the query fails.
+    pub fn list_projected_area_progress(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<ProjectedAreaProgressData>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::count_areas_by_bid_year_sqlite(conn),
-            BackendConnection::Mysql(conn) => queries::count_areas_by_bid_year_mysql(conn),
+            BackendConnection::Sqlite(conn) => {
+                queries::projections::list_projected_area_progress_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::projections::list_projected_area_progress_mysql(conn, bid_year_id)
+            }
         }
     }
 
-    /// Counts total users per bid year across all areas.
+    /// Persists an audit event.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The audit event to persist
+    ///
+    /// # Returns
+    ///
+    /// The event ID assigned to the persisted audit event.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn count_users_by_bid_year(&mut self) -> Result<Vec<(u16, usize)>, PersistenceError> {
+    /// Returns an error if persistence fails.
+    pub fn persist_audit_event(&mut self, event: &AuditEvent) -> Result<i64, PersistenceError> {
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::count_users_by_bid_year_sqlite(conn),
-            BackendConnection::Mysql(conn) => queries::count_users_by_bid_year_mysql(conn),
+            BackendConnection::Sqlite(conn) => {
+                mutations::persist_audit_event_sqlite(conn, event, ulids)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::persist_audit_event_mysql(conn, event, ulids)
+            }
         }
     }
 
-    /// Counts users per (`bid_year`, `area_id`) combination.
+    /// Persists a bootstrap result (audit event for bid year/area creation).
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The bootstrap result to persist
+    ///
+    /// # Returns
+    ///
+    /// The event ID assigned to the persisted audit event.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn count_users_by_bid_year_and_area(
-        &mut self,
-    ) -> Result<Vec<(u16, String, usize)>, PersistenceError> {
+    /// Returns an error if persistence fails.
+    pub fn persist_bootstrap(&mut self, result: &BootstrapResult) -> Result<i64, PersistenceError> {
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::count_users_by_bid_year_and_area_sqlite(conn)
+                mutations::persist_bootstrap_sqlite(conn, result, self.snapshot_encoding, ulids)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::persist_bootstrap_mysql(conn, result, self.snapshot_encoding, ulids)
             }
-            BackendConnection::Mysql(conn) => queries::count_users_by_bid_year_and_area_mysql(conn),
         }
     }
 
-    /// Finds the system area (No Bid) for a given bid year.
-    ///
-    /// Phase 25B: Returns the area ID and area code of the system area.
+    // ========================================================================
+    // Audit Event Queries
+    // ========================================================================
+
+    /// Retrieves an audit event by ID.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(Some((area_id, area_code)))` if a system area exists
-    /// * `Ok(None)` if no system area exists
+    /// * `event_id` - The event ID to retrieve
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn find_system_area(
-        &mut self,
-        bid_year_id: i64,
-    ) -> Result<Option<(i64, String)>, PersistenceError> {
+    /// Returns an error if the event is not found or cannot be deserialized.
+    pub fn get_audit_event(&mut self, event_id: i64) -> Result<AuditEvent, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::find_system_area_sqlite(conn, bid_year_id),
-            BackendConnection::Mysql(conn) => queries::find_system_area_mysql(conn, bid_year_id),
+            BackendConnection::Sqlite(conn) => {
+                queries::audit::get_audit_event_sqlite(conn, event_id)
+            }
+            BackendConnection::Mysql(conn) => queries::audit::get_audit_event_mysql(conn, event_id),
         }
     }
 
-    /// Counts users in the system area (No Bid) for a given bid year.
+    /// Retrieves an audit event by its ULID.
     ///
-    /// Phase 25B: Used to check if bootstrap can be completed.
+    /// Unlike its numeric ID, an event's ULID survives export to another
+    /// database.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    ///
-    /// # Returns
-    ///
-    /// The number of users in the No Bid area (0 if no system area exists).
+    /// * `event_ulid` - The event's ULID
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn count_users_in_system_area(
+    /// Returns an error if no event has the ULID or it cannot be deserialized.
+    pub fn get_audit_event_by_ulid(
         &mut self,
-        bid_year_id: i64,
-    ) -> Result<usize, PersistenceError> {
-        match &mut self.conn {
+        event_ulid: &zab_bid_audit::Ulid,
+    ) -> Result<AuditEvent, PersistenceError> {
+        let event_id: Option<i64> = match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::count_users_in_system_area_sqlite(conn, bid_year_id)
+                queries::audit::find_audit_event_id_by_ulid_sqlite(conn, event_ulid)?
             }
             BackendConnection::Mysql(conn) => {
-                queries::count_users_in_system_area_mysql(conn, bid_year_id)
+                queries::audit::find_audit_event_id_by_ulid_mysql(conn, event_ulid)?
             }
-        }
+        };
+        let event_id: i64 = event_id
+            .ok_or_else(|| PersistenceError::NotFound(format!("Audit event {event_ulid}")))?;
+        self.get_audit_event(event_id)
     }
 
-    /// Lists users in the system area (No Bid) for a given bid year.
+    /// Attaches a note to an existing audit event.
     ///
-    /// Phase 25B: Returns up to `limit` user initials for error reporting.
+    /// The event itself is never modified.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `limit` - Maximum number of initials to return
-    ///
-    /// # Returns
-    ///
-    /// A vector of user initials (empty if no system area or no users).
+    /// * `event_id` - The annotated audit event
+    /// * `operator_id` - The operator writing the note
+    /// * `note` - The note text
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn list_users_in_system_area(
+    /// Returns an error if the event does not exist or the annotation cannot
+    /// be recorded.
+    pub fn add_audit_annotation(
         &mut self,
-        bid_year_id: i64,
-        limit: i64,
-    ) -> Result<Vec<String>, PersistenceError> {
+        event_id: i64,
+        operator_id: i64,
+        note: &str,
+    ) -> Result<i64, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::list_users_in_system_area_sqlite(conn, bid_year_id, limit)
+                mutations::audit_annotations::add_audit_annotation_sqlite(
+                    conn,
+                    event_id,
+                    operator_id,
+                    note,
+                )
             }
             BackendConnection::Mysql(conn) => {
-                queries::list_users_in_system_area_mysql(conn, bid_year_id, limit)
+                mutations::audit_annotations::add_audit_annotation_mysql(
+                    conn,
+                    event_id,
+                    operator_id,
+                    note,
+                )
             }
         }
     }
 
-    /// Checks if an area is a system area.
-    ///
-    /// Phase 25B: Used to prevent deletion/renaming of system areas.
-    ///
-    /// # Arguments
-    ///
-    /// * `area_id` - The canonical area ID to check
-    ///
-    /// # Returns
-    ///
-    /// `true` if the area is a system area, `false` otherwise.
+    /// Lists the annotations attached to a set of audit events, ordered by
+    /// event and then oldest first.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried or the area doesn't exist.
-    pub fn is_system_area(&mut self, area_id: i64) -> Result<bool, PersistenceError> {
+    /// Returns an error if the query fails.
+    pub fn list_audit_annotations(
+        &mut self,
+        event_ids: &[i64],
+    ) -> Result<Vec<AuditAnnotationData>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::is_system_area_sqlite(conn, area_id),
-            BackendConnection::Mysql(conn) => queries::is_system_area_mysql(conn, area_id),
+            BackendConnection::Sqlite(conn) => {
+                queries::audit_annotations::list_audit_annotations_sqlite(conn, event_ids)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::audit_annotations::list_audit_annotations_mysql(conn, event_ids)
+            }
         }
     }
 
-    /// Updates an area's display name.
-    ///
-    /// Phase 26C: Used to edit area metadata (display name only, not area code).
+    /// Retrieves the most recent state snapshot for a `(BidYear, Area)` scope.
     ///
     /// # Arguments
     ///
-    /// * `area_id` - The canonical area ID
-    /// * `area_name` - The new display name (or `None` to clear)
+    /// * `bid_year` - The bid year
+    /// * `area` - The area
     ///
     /// # Errors
     ///
-    /// Returns an error if the area doesn't exist or the database operation fails.
-    pub fn update_area_name(
+    /// Returns an error if no snapshot exists or cannot be deserialized.
+    pub fn get_latest_snapshot(
         &mut self,
-        area_id: i64,
-        area_name: Option<&str>,
-    ) -> Result<(), PersistenceError> {
+        bid_year: &BidYear,
+        area: &Area,
+    ) -> Result<(State, i64), PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::update_area_name_sqlite(conn, area_id, area_name)
+                let bid_year_id = queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
+                queries::get_latest_snapshot_sqlite(conn, bid_year_id, area_id)
             }
             BackendConnection::Mysql(conn) => {
-                mutations::update_area_name_mysql(conn, area_id, area_name)
+                let bid_year_id = queries::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
+                queries::get_latest_snapshot_mysql(conn, bid_year_id, area_id)
             }
         }
     }
 
-    /// Determines if a given action requires a full snapshot.
+    /// Retrieves the most recent state snapshot of a `(BidYear, Area)` scope
+    /// recorded at or before an audit event.
     ///
     /// # Arguments
     ///
-    /// * `action_name` - The action name to check
+    /// * `bid_year` - The bid year
+    /// * `area` - The area
+    /// * `event_id` - The target audit event ID
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// `true` if the action requires a snapshot, `false` otherwise.
-    #[must_use]
-    pub fn should_snapshot(&self, action_name: &str) -> bool {
-        queries::should_snapshot(action_name)
+    /// Returns an error if no snapshot exists at or before the event or it
+    /// cannot be deserialized.
+    pub fn get_snapshot_at_or_before_event(
+        &mut self,
+        bid_year: &BidYear,
+        area: &Area,
+        event_id: i64,
+    ) -> Result<(State, i64), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let bid_year_id = queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
+                queries::get_snapshot_at_or_before_event_sqlite(
+                    conn,
+                    bid_year_id,
+                    area_id,
+                    event_id,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                let bid_year_id = queries::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
+                queries::get_snapshot_at_or_before_event_mysql(conn, bid_year_id, area_id, event_id)
+            }
+        }
     }
 
-    // ========================================================================
-    // Operator Queries
-    // ========================================================================
-
-    /// Creates a new operator.
+    /// Retrieves all audit events for a `(BidYear, Area)` scope after a given event ID.
     ///
     /// # Arguments
     ///
-    /// * `login_name` - The login name (will be normalized)
-    /// * `display_name` - The display name
-    /// * `password` - The plain-text password (will be hashed)
-    /// * `role` - The role (Admin or Bidder)
+    /// * `bid_year` - The bid year
+    /// * `area` - The area
+    /// * `after_event_id` - Only return events after this ID (exclusive)
     ///
     /// # Errors
     ///
-    /// Returns an error if the operator cannot be created.
-    pub fn create_operator(
+    /// Returns an error if events cannot be retrieved or deserialized.
+    pub fn get_events_after(
         &mut self,
-        login_name: &str,
-        display_name: &str,
-        password: &str,
-        role: &str,
-    ) -> Result<i64, PersistenceError> {
+        bid_year: &BidYear,
+        area: &Area,
+        after_event_id: i64,
+    ) -> Result<Vec<AuditEvent>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::create_operator_sqlite(conn, login_name, display_name, password, role)
+                let bid_year_id = queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
+                queries::get_events_after_sqlite(conn, bid_year_id, area_id, after_event_id)
             }
             BackendConnection::Mysql(conn) => {
-                mutations::create_operator_mysql(conn, login_name, display_name, password, role)
+                let bid_year_id = queries::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
+                queries::get_events_after_mysql(conn, bid_year_id, area_id, after_event_id)
             }
         }
     }
 
-    /// Retrieves an operator by login name.
+    /// Retrieves the current effective state for a given `(BidYear, Area)` scope.
     ///
     /// # Arguments
     ///
-    /// * `login_name` - The login name to search for
+    /// * `bid_year` - The bid year
+    /// * `area` - The area
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn get_operator_by_login(
+    /// Returns an error if the database cannot be queried.
+    pub fn get_current_state(
         &mut self,
-        login_name: &str,
-    ) -> Result<Option<OperatorData>, PersistenceError> {
+        bid_year: &BidYear,
+        area: &Area,
+    ) -> Result<State, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::operators::get_operator_by_login_sqlite(conn, login_name)
+                let bid_year_id = queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
+                queries::get_current_state_sqlite(conn, bid_year_id, area_id, bid_year, area)
             }
             BackendConnection::Mysql(conn) => {
-                queries::operators::get_operator_by_login_mysql(conn, login_name)
+                let bid_year_id = queries::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
+                queries::get_current_state_mysql(conn, bid_year_id, area_id, bid_year, area)
             }
         }
     }
 
-    /// Retrieves an operator by ID.
+    /// Retrieves the current state of every area in a bid year.
+    ///
+    /// Areas are split across up to `max_connections` workers, each loading
+    /// its share on a connection of its own, so facilities with many areas
+    /// load in roughly the time of their largest share. States are returned
+    /// in the same order as [`Self::list_areas`].
     ///
     /// # Arguments
     ///
-    /// * `operator_id` - The operator ID
+    /// * `bid_year` - The bid year
+    /// * `max_connections` - The most connections to load with at once
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn get_operator_by_id(
+    /// Returns an error if the bid year does not exist, a worker connection
+    /// cannot be opened, or any area's state cannot be loaded.
+    pub fn load_bid_year_states(
         &mut self,
-        operator_id: i64,
-    ) -> Result<Option<OperatorData>, PersistenceError> {
-        match &mut self.conn {
+        bid_year: &BidYear,
+        max_connections: usize,
+    ) -> Result<Vec<State>, PersistenceError> {
+        let bid_year_id: i64 = match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::operators::get_operator_by_id_sqlite(conn, operator_id)
+                queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?
             }
             BackendConnection::Mysql(conn) => {
-                queries::operators::get_operator_by_id_mysql(conn, operator_id)
+                queries::lookup_bid_year_id_mysql(conn, bid_year.year())?
             }
+        };
+        let bid_year: BidYear = BidYear::with_id(bid_year_id, bid_year.year());
+        let areas: Vec<Area> = self.list_areas(&bid_year)?;
+
+        let workers: usize = max_connections.clamp(1, areas.len().max(1));
+        if workers == 1 {
+            return load_area_states(&mut self.conn, bid_year_id, &bid_year, &areas);
+        }
+
+        let shares: Vec<&[Area]> = areas.chunks(areas.len().div_ceil(workers)).collect();
+        let bid_year: &BidYear = &bid_year;
+        let database_url: &str = &self.database_url;
+        let is_sqlite: bool = matches!(self.conn, BackendConnection::Sqlite(_));
+        let loaded: Vec<Result<Vec<State>, PersistenceError>> = std::thread::scope(|scope| {
+            // Spawn every worker before joining any of them.
+            let mut handles: Vec<std::thread::ScopedJoinHandle<'_, _>> = Vec::new();
+            for share in shares {
+                handles.push(scope.spawn(move || {
+                    let mut conn: BackendConnection = if is_sqlite {
+                        BackendConnection::Sqlite(backend::sqlite::open_database(database_url)?)
+                    } else {
+                        BackendConnection::Mysql(MysqlConnection::establish(database_url)?)
+                    };
+                    load_area_states(&mut conn, bid_year_id, bid_year, share)
+                }));
+            }
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle.join().unwrap_or_else(|_| {
+                        Err(PersistenceError::Other(String::from(
+                            "State loading worker panicked",
+                        )))
+                    })
+                })
+                .collect()
+        });
+
+        let mut states: Vec<State> = Vec::with_capacity(areas.len());
+        for share in loaded {
+            states.extend(share?);
         }
+        Ok(states)
     }
 
-    /// Updates the last login timestamp for an operator.
+    /// Retrieves the effective state for a given `(BidYear, Area)` scope at a specific timestamp.
     ///
     /// # Arguments
     ///
-    /// * `operator_id` - The operator ID
+    /// * `bid_year` - The bid year
+    /// * `area` - The area
+    /// * `timestamp` - The target timestamp (ISO 8601 format)
     ///
     /// # Errors
     ///
-    /// Returns an error if the database update fails.
-    pub fn update_last_login(&mut self, operator_id: i64) -> Result<(), PersistenceError> {
+    /// Returns an error if no snapshot exists before the timestamp.
+    pub fn get_historical_state(
+        &mut self,
+        bid_year: &BidYear,
+        area: &Area,
+        timestamp: &str,
+    ) -> Result<State, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::update_last_login_sqlite(conn, operator_id)
+                let bid_year_id = queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
+                queries::get_historical_state_sqlite(conn, bid_year_id, area_id, timestamp)
+            }
+            BackendConnection::Mysql(conn) => {
+                let bid_year_id = queries::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
+                queries::get_historical_state_mysql(conn, bid_year_id, area_id, timestamp)
             }
-            BackendConnection::Mysql(conn) => mutations::update_last_login_mysql(conn, operator_id),
         }
     }
 
-    /// Disables an operator.
+    /// Retrieves the ordered audit event timeline for a given `(BidYear, Area)` scope.
     ///
     /// # Arguments
     ///
-    /// * `operator_id` - The operator ID
+    /// * `bid_year` - The bid year
+    /// * `area` - The area
     ///
     /// # Errors
     ///
-    /// Returns an error if the database update fails.
-    pub fn disable_operator(&mut self, operator_id: i64) -> Result<(), PersistenceError> {
+    /// Returns an error if events cannot be retrieved or deserialized.
+    pub fn get_audit_timeline(
+        &mut self,
+        bid_year: &BidYear,
+        area: &Area,
+    ) -> Result<Vec<AuditEvent>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::disable_operator_sqlite(conn, operator_id)
+                // Look up the canonical IDs - if they don't exist, return empty timeline
+                let bid_year_id = match queries::lookup_bid_year_id_sqlite(conn, bid_year.year()) {
+                    Ok(id) => id,
+                    Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
+                    Err(e) => return Err(e),
+                };
+                let area_id = match queries::lookup_area_id_sqlite(conn, bid_year_id, area.id()) {
+                    Ok(id) => id,
+                    Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
+                    Err(e) => return Err(e),
+                };
+
+                queries::get_audit_timeline_sqlite(conn, bid_year_id, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                // Look up the canonical IDs - if they don't exist, return empty timeline
+                let bid_year_id = match queries::lookup_bid_year_id_mysql(conn, bid_year.year()) {
+                    Ok(id) => id,
+                    Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
+                    Err(e) => return Err(e),
+                };
+                let area_id = match queries::lookup_area_id_mysql(conn, bid_year_id, area.id()) {
+                    Ok(id) => id,
+                    Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
+                    Err(e) => return Err(e),
+                };
+
+                queries::get_audit_timeline_mysql(conn, bid_year_id, area_id)
             }
-            BackendConnection::Mysql(conn) => mutations::disable_operator_mysql(conn, operator_id),
         }
     }
 
-    /// Re-enables a disabled operator.
-    ///
-    /// # Arguments
-    ///
-    /// * `operator_id` - The operator ID
+    /// Retrieves all global audit events (events with no bid year or area scope).
     ///
     /// # Errors
     ///
-    /// Returns an error if the database update fails.
-    pub fn enable_operator(&mut self, operator_id: i64) -> Result<(), PersistenceError> {
+    /// Returns an error if events cannot be retrieved or deserialized.
+    pub fn get_global_audit_events(&mut self) -> Result<Vec<AuditEvent>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::enable_operator_sqlite(conn, operator_id),
-            BackendConnection::Mysql(conn) => mutations::enable_operator_mysql(conn, operator_id),
+            BackendConnection::Sqlite(conn) => queries::get_global_audit_events_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::get_global_audit_events_mysql(conn),
         }
     }
 
-    /// Deletes an operator if they are not referenced by any audit events.
-    ///
-    /// # Arguments
-    ///
-    /// * `operator_id` - The operator ID
+    /// Retrieves the highest persisted audit event ID, if any events exist.
     ///
     /// # Errors
     ///
-    /// Returns an error if the operator is referenced or doesn't exist.
-    pub fn delete_operator(&mut self, operator_id: i64) -> Result<(), PersistenceError> {
+    /// Returns an error if the query fails.
+    pub fn get_latest_audit_event_id(&mut self) -> Result<Option<i64>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::delete_operator_sqlite(conn, operator_id),
-            BackendConnection::Mysql(conn) => mutations::delete_operator_mysql(conn, operator_id),
+            BackendConnection::Sqlite(conn) => queries::get_latest_audit_event_id_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::get_latest_audit_event_id_mysql(conn),
         }
     }
 
-    /// Lists all operators.
+    /// Retrieves one page of the audit timeline for a scope with optional filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Global, bid year, or area scope
+    /// * `filter` - Optional action, actor, and time-range filters
+    /// * `after_event_id` - Cursor returned by a previous page
+    /// * `limit` - Maximum number of entries to return
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn list_operators(&mut self) -> Result<Vec<OperatorData>, PersistenceError> {
-        match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::operators::list_operators_sqlite(conn),
-            BackendConnection::Mysql(conn) => queries::operators::list_operators_mysql(conn),
-        }
-    }
-
-    /// Checks if an operator is referenced by any audit events.
-    ///
-    /// # Arguments
-    ///
-    /// * `operator_id` - The operator ID to check
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the database query fails.
-    pub fn is_operator_referenced(&mut self, operator_id: i64) -> Result<bool, PersistenceError> {
+    /// Returns an error if events cannot be retrieved or deserialized.
+    pub fn get_audit_timeline_page(
+        &mut self,
+        scope: AuditTimelineScope,
+        filter: &AuditTimelineFilter,
+        after_event_id: Option<i64>,
+        limit: u32,
+    ) -> Result<AuditTimelinePage, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::operators::is_operator_referenced_sqlite(conn, operator_id)
+                queries::get_audit_timeline_page_sqlite(conn, scope, filter, after_event_id, limit)
             }
             BackendConnection::Mysql(conn) => {
-                queries::operators::is_operator_referenced_mysql(conn, operator_id)
+                queries::get_audit_timeline_page_mysql(conn, scope, filter, after_event_id, limit)
             }
         }
     }
 
-    /// Counts the total number of operators.
+    /// Retrieves one page of audit event headers, without their snapshots.
+    ///
+    /// Scope, filters, and cursors behave as in
+    /// [`Self::get_audit_timeline_page`]. Callers that only need action
+    /// names or scopes should prefer this, and fetch full events with
+    /// [`Self::get_audit_timeline_entries`] when they need them.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Global, bid year, or area scope
+    /// * `filter` - Optional action, actor, and time-range filters
+    /// * `after_event_id` - Cursor returned by a previous page
+    /// * `limit` - Maximum number of headers to return
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn count_operators(&mut self) -> Result<i64, PersistenceError> {
+    /// Returns an error if events cannot be retrieved or deserialized.
+    pub fn get_audit_event_headers_page(
+        &mut self,
+        scope: AuditTimelineScope,
+        filter: &AuditTimelineFilter,
+        after_event_id: Option<i64>,
+        limit: u32,
+    ) -> Result<AuditEventHeaderPage, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::operators::count_operators_sqlite(conn),
-            BackendConnection::Mysql(conn) => queries::operators::count_operators_mysql(conn),
+            BackendConnection::Sqlite(conn) => queries::get_audit_event_headers_page_sqlite(
+                conn,
+                scope,
+                filter,
+                after_event_id,
+                limit,
+            ),
+            BackendConnection::Mysql(conn) => queries::get_audit_event_headers_page_mysql(
+                conn,
+                scope,
+                filter,
+                after_event_id,
+                limit,
+            ),
         }
     }
 
-    /// Counts the number of active admin operators.
+    /// Retrieves full audit events, with their snapshots, by ID.
+    ///
+    /// IDs that do not exist are skipped; entries are returned in ascending
+    /// `event_id` order.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn count_active_admin_operators(&mut self) -> Result<i64, PersistenceError> {
+    /// Returns an error if events cannot be retrieved or deserialized.
+    pub fn get_audit_timeline_entries(
+        &mut self,
+        event_ids: &[i64],
+    ) -> Result<Vec<AuditTimelineEntry>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::operators::count_active_admin_operators_sqlite(conn)
+                queries::get_audit_timeline_entries_sqlite(conn, event_ids)
             }
             BackendConnection::Mysql(conn) => {
-                queries::operators::count_active_admin_operators_mysql(conn)
+                queries::get_audit_timeline_entries_mysql(conn, event_ids)
             }
         }
     }
 
-    /// Verifies a password against a stored hash.
+    /// Retrieves an area's most recent audit events with any of the given
+    /// action names, newest first.
     ///
     /// # Arguments
     ///
-    /// * `password` - The plain text password to verify
-    /// * `password_hash` - The stored bcrypt hash
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    /// * `action_names` - The action names to include
+    /// * `limit` - Maximum number of entries to return
     ///
     /// # Errors
     ///
-    /// Returns an error if password verification fails.
-    pub fn verify_password(
-        &self,
-        password: &str,
-        password_hash: &str,
-    ) -> Result<bool, PersistenceError> {
-        queries::operators::verify_password(password, password_hash)
+    /// Returns an error if events cannot be retrieved or deserialized.
+    pub fn get_recent_area_events(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        action_names: &[&str],
+        limit: u32,
+    ) -> Result<Vec<AuditTimelineEntry>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::get_recent_area_events_sqlite(
+                conn,
+                bid_year_id,
+                area_id,
+                action_names,
+                limit,
+            ),
+            BackendConnection::Mysql(conn) => queries::get_recent_area_events_mysql(
+                conn,
+                bid_year_id,
+                area_id,
+                action_names,
+                limit,
+            ),
+        }
     }
 
-    /// Updates an operator's password.
-    ///
-    /// # Arguments
+    /// Lists which of the given audit events a rollback has superseded.
     ///
-    /// * `operator_id` - The operator ID
-    /// * `new_password` - The new password (will be hashed)
+    /// Superseded events stay in the audit log; a rollback only marks them
+    /// inactive.
     ///
     /// # Errors
     ///
-    /// Returns an error if the update fails.
-    pub fn update_password(
+    /// Returns an error if the query fails.
+    pub fn list_superseded_event_ids(
         &mut self,
-        operator_id: i64,
-        new_password: &str,
-    ) -> Result<(), PersistenceError> {
+        event_ids: &[i64],
+    ) -> Result<Vec<i64>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::update_password_sqlite(conn, operator_id, new_password)
+                queries::list_superseded_event_ids_sqlite(conn, event_ids)
             }
             BackendConnection::Mysql(conn) => {
-                mutations::update_password_mysql(conn, operator_id, new_password)
+                queries::list_superseded_event_ids_mysql(conn, event_ids)
             }
         }
     }
 
-    /// Deletes all sessions for a specific operator.
+    /// Records that an audit event depends on events in other scopes.
     ///
     /// # Arguments
     ///
-    /// * `operator_id` - The operator ID whose sessions should be deleted
+    /// * `event_id` - The dependent event
+    /// * `depends_on_event_ids` - The events it was derived from
     ///
     /// # Errors
     ///
-    /// Returns an error if the database delete fails.
-    pub fn delete_sessions_for_operator(
+    /// Returns an error if an insert fails.
+    pub fn record_event_dependencies(
         &mut self,
-        operator_id: i64,
-    ) -> Result<usize, PersistenceError> {
+        event_id: i64,
+        depends_on_event_ids: &[i64],
+    ) -> Result<(), PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                mutations::delete_sessions_for_operator_sqlite(conn, operator_id)
-            }
-            BackendConnection::Mysql(conn) => {
-                mutations::delete_sessions_for_operator_mysql(conn, operator_id)
-            }
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                mutations::record_event_dependencies_sqlite(conn, event_id, depends_on_event_ids)
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                mutations::record_event_dependencies_mysql(conn, event_id, depends_on_event_ids)
+            }),
         }
     }
 
-    // ========================================================================
-    // Session Management
-    // ========================================================================
-
-    /// Creates a new session for an operator.
-    ///
-    /// # Arguments
+    /// Lists the events that depend on any of the given events.
     ///
-    /// * `session_token` - The unique session token
-    /// * `operator_id` - The operator ID
-    /// * `expires_at` - The expiration timestamp (ISO 8601 format)
+    /// Dependents a rollback has superseded are skipped.
     ///
     /// # Errors
     ///
-    /// Returns an error if the session cannot be created.
-    pub fn create_session(
+    /// Returns an error if the query fails.
+    pub fn list_event_dependents(
         &mut self,
-        session_token: &str,
-        operator_id: i64,
-        expires_at: &str,
-    ) -> Result<i64, PersistenceError> {
+        event_ids: &[i64],
+    ) -> Result<Vec<AuditEventDependency>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::create_session_sqlite(conn, session_token, operator_id, expires_at)
-            }
-            BackendConnection::Mysql(conn) => {
-                mutations::create_session_mysql(conn, session_token, operator_id, expires_at)
+                queries::list_event_dependents_sqlite(conn, event_ids)
             }
+            BackendConnection::Mysql(conn) => queries::list_event_dependents_mysql(conn, event_ids),
         }
     }
 
-    /// Retrieves a session by token.
-    ///
-    /// # Arguments
+    /// Lists the latest unsuperseded event recorded in each area of a bid year.
     ///
-    /// * `session_token` - The session token
+    /// Events derived from every area's state record these as their
+    /// dependencies.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn get_session_by_token(
+    /// Returns an error if the query fails.
+    pub fn list_latest_area_event_ids(
         &mut self,
-        session_token: &str,
-    ) -> Result<Option<SessionData>, PersistenceError> {
+        bid_year_id: i64,
+    ) -> Result<Vec<i64>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::operators::get_session_by_token_sqlite(conn, session_token)
+                queries::list_latest_area_event_ids_sqlite(conn, bid_year_id)
             }
             BackendConnection::Mysql(conn) => {
-                queries::operators::get_session_by_token_mysql(conn, session_token)
+                queries::list_latest_area_event_ids_mysql(conn, bid_year_id)
             }
         }
     }
 
-    /// Updates the last activity timestamp for a session.
+    /// Records that audit events were recorded by one operation.
     ///
     /// # Arguments
     ///
-    /// * `session_id` - The session ID
+    /// * `event_ids` - The operation's events
+    /// * `correlation_id` - The operation's correlation ID
     ///
     /// # Errors
     ///
-    /// Returns an error if the database update fails.
-    pub fn update_session_activity(&mut self, session_id: i64) -> Result<(), PersistenceError> {
+    /// Returns an error if an insert fails.
+    pub fn record_event_correlations(
+        &mut self,
+        event_ids: &[i64],
+        correlation_id: &str,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                mutations::record_event_correlations_sqlite(conn, event_ids, correlation_id)
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                mutations::record_event_correlations_mysql(conn, event_ids, correlation_id)
+            }),
+        }
+    }
+
+    /// Returns the correlation ID of the operation that recorded an audit
+    /// event, if it has one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_event_correlation_id(
+        &mut self,
+        event_id: i64,
+    ) -> Result<Option<String>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::update_session_activity_sqlite(conn, session_id)
+                queries::get_event_correlation_id_sqlite(conn, event_id)
             }
             BackendConnection::Mysql(conn) => {
-                mutations::update_session_activity_mysql(conn, session_id)
+                queries::get_event_correlation_id_mysql(conn, event_id)
             }
         }
     }
 
-    /// Deletes a session by token.
-    ///
-    /// # Arguments
-    ///
-    /// * `session_token` - The session token to delete
+    /// Returns the rollback that superseded an audit event, if any.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database delete fails.
-    pub fn delete_session(&mut self, session_token: &str) -> Result<(), PersistenceError> {
+    /// Returns an error if the query fails.
+    pub fn get_superseding_event_id(
+        &mut self,
+        event_id: i64,
+    ) -> Result<Option<i64>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::delete_session_sqlite(conn, session_token)
+                queries::get_superseding_event_id_sqlite(conn, event_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::get_superseding_event_id_mysql(conn, event_id)
             }
-            BackendConnection::Mysql(conn) => mutations::delete_session_mysql(conn, session_token),
         }
     }
 
-    /// Deletes all expired sessions.
+    /// Lists the checkpoints recorded in a scope, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The scope to list checkpoints in
     ///
     /// # Errors
     ///
-    /// Returns an error if the database delete fails.
-    pub fn delete_expired_sessions(&mut self) -> Result<usize, PersistenceError> {
+    /// Returns an error if the query fails or a checkpoint's action cannot
+    /// be deserialized.
+    pub fn list_checkpoints(
+        &mut self,
+        scope: AuditTimelineScope,
+    ) -> Result<Vec<CheckpointData>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::delete_expired_sessions_sqlite(conn),
-            BackendConnection::Mysql(conn) => mutations::delete_expired_sessions_mysql(conn),
+            BackendConnection::Sqlite(conn) => queries::list_checkpoints_sqlite(conn, scope),
+            BackendConnection::Mysql(conn) => queries::list_checkpoints_mysql(conn, scope),
         }
     }
 
     // ========================================================================
-    // Bootstrap Configuration
+    // Audit Log Compaction
     // ========================================================================
 
-    /// Sets a bid year as active.
+    /// Lists a bid year's area events a compaction can archive.
+    ///
+    /// Events any other record refers to, including undelivered outbox
+    /// rows, stay in the live log and are not listed.
     ///
     /// # Arguments
     ///
-    /// * `year` - The year to mark as active
+    /// * `bid_year_id` - The canonical bid year ID
+    ///
+    /// # Returns
+    ///
+    /// The archivable events, oldest first.
     ///
     /// # Errors
     ///
-    /// Returns an error if the bid year doesn't exist or update fails.
-    pub fn set_active_bid_year(&mut self, bid_year: &BidYear) -> Result<(), PersistenceError> {
+    /// Returns an error if the query fails or an action cannot be decoded.
+    pub fn list_archivable_audit_events(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<ArchivableEventData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                mutations::set_active_bid_year_sqlite(conn, bid_year_id)
+                let referenced: HashSet<i64> =
+                    queries::compaction::list_referenced_event_ids_sqlite(conn)?;
+                queries::compaction::list_archivable_audit_events_sqlite(
+                    conn,
+                    bid_year_id,
+                    &referenced,
+                )
             }
             BackendConnection::Mysql(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                mutations::set_active_bid_year_mysql(conn, bid_year_id)
+                let referenced: HashSet<i64> =
+                    queries::compaction::list_referenced_event_ids_mysql(conn)?;
+                queries::compaction::list_archivable_audit_events_mysql(
+                    conn,
+                    bid_year_id,
+                    &referenced,
+                )
             }
         }
     }
 
-    /// Gets the active bid year.
-    ///
-    /// # Errors
+    /// Compacts a bid year's audit log.
     ///
-    /// Returns an error if no active bid year exists.
-    pub fn get_active_bid_year(&mut self) -> Result<u16, PersistenceError> {
-        match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::canonical::get_active_bid_year_sqlite(conn),
-            BackendConnection::Mysql(conn) => queries::canonical::get_active_bid_year_mysql(conn),
-        }
-    }
-
-    /// Sets the expected area count for a bid year.
+    /// Each digest event is persisted with a snapshot of its state, then
+    /// the given events are moved to the archive, all in one transaction.
     ///
     /// # Arguments
     ///
-    /// * `bid_year` - The bid year
-    /// * `count` - The expected number of areas
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `event_ids` - The events to archive, from
+    ///   [`Self::list_archivable_audit_events`]
+    /// * `digests` - Each area's digest event and sealed state
+    ///
+    /// # Returns
+    ///
+    /// The compaction and the digest events' IDs, in the order given.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be updated or the bid year doesn't exist.
-    pub fn set_expected_area_count(
+    /// Returns an error if the bid year was already compacted or any write
+    /// fails. Nothing is persisted in that case.
+    pub fn compact_audit_log(
         &mut self,
-        bid_year: &BidYear,
-        count: usize,
-    ) -> Result<(), PersistenceError> {
+        bid_year_id: i64,
+        event_ids: &[i64],
+        digests: &[(AuditEvent, State)],
+    ) -> Result<(AuditCompactionData, Vec<i64>), PersistenceError> {
+        let encoding: SnapshotEncoding = self.snapshot_encoding;
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                mutations::set_expected_area_count_sqlite(conn, bid_year_id, count)
-            }
-            BackendConnection::Mysql(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                mutations::set_expected_area_count_mysql(conn, bid_year_id, count)
-            }
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                let mut digest_event_ids: Vec<i64> = Vec::with_capacity(digests.len());
+                for (event, state) in digests {
+                    let event_id: i64 = mutations::persist_audit_event_sqlite(conn, event, ulids)?;
+                    mutations::audit::persist_state_snapshot_sqlite(
+                        conn, state, event_id, encoding,
+                    )?;
+                    digest_event_ids.push(event_id);
+                }
+                let compaction: AuditCompactionData =
+                    mutations::compaction::archive_audit_events_sqlite(
+                        conn,
+                        bid_year_id,
+                        event_ids,
+                    )?;
+                Ok((compaction, digest_event_ids))
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                let mut digest_event_ids: Vec<i64> = Vec::with_capacity(digests.len());
+                for (event, state) in digests {
+                    let event_id: i64 = mutations::persist_audit_event_mysql(conn, event, ulids)?;
+                    mutations::audit::persist_state_snapshot_mysql(
+                        conn, state, event_id, encoding,
+                    )?;
+                    digest_event_ids.push(event_id);
+                }
+                let compaction: AuditCompactionData =
+                    mutations::compaction::archive_audit_events_mysql(
+                        conn,
+                        bid_year_id,
+                        event_ids,
+                    )?;
+                Ok((compaction, digest_event_ids))
+            }),
         }
     }
 
-    /// Gets the expected area count for a bid year.
+    /// Returns a bid year's audit log compaction.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `bid_year` - The bid year
+    /// The compaction, or `None` if the bid year has not been compacted.
     ///
     /// # Errors
     ///
-    /// Returns an error if the bid year doesn't exist.
-    pub fn get_expected_area_count(
+    /// Returns an error if the query fails.
+    pub fn get_audit_compaction(
         &mut self,
-        bid_year: &BidYear,
-    ) -> Result<Option<usize>, PersistenceError> {
+        bid_year_id: i64,
+    ) -> Result<Option<AuditCompactionData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                queries::canonical::get_expected_area_count_sqlite(conn, bid_year_id)
+                queries::compaction::get_audit_compaction_sqlite(conn, bid_year_id)
             }
             BackendConnection::Mysql(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                queries::canonical::get_expected_area_count_mysql(conn, bid_year_id)
+                queries::compaction::get_audit_compaction_mysql(conn, bid_year_id)
             }
         }
     }
 
-    /// Sets the expected user count for an area.
-    ///
-    /// # Arguments
-    ///
-    /// * `bid_year` - The bid year
-    /// * `area` - The area
-    /// * `count` - The expected number of users
+    /// Recomputes a bid year's archive hash chain.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be updated or the area doesn't exist.
-    pub fn set_expected_user_count(
+    /// Returns an error if the bid year has not been compacted or the
+    /// archive cannot be read. A broken chain is reported in the result
+    /// instead.
+    pub fn verify_audit_archive(
         &mut self,
-        bid_year: &BidYear,
-        area: &Area,
-        count: usize,
-    ) -> Result<(), PersistenceError> {
+        bid_year_id: i64,
+    ) -> Result<AuditArchiveVerification, PersistenceError> {
+        let compaction: AuditCompactionData =
+            self.get_audit_compaction(bid_year_id)?.ok_or_else(|| {
+                PersistenceError::NotFound(format!(
+                    "Bid year {bid_year_id} has no audit log compaction"
+                ))
+            })?;
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                let area_id =
-                    queries::canonical::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
-                mutations::set_expected_user_count_sqlite(conn, bid_year_id, area_id, count)
+                queries::compaction::verify_audit_archive_sqlite(conn, &compaction)
             }
             BackendConnection::Mysql(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                let area_id =
-                    queries::canonical::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
-                mutations::set_expected_user_count_mysql(conn, bid_year_id, area_id, count)
+                queries::compaction::verify_audit_archive_mysql(conn, &compaction)
             }
         }
     }
 
-    /// Gets the expected user count for an area.
+    // ========================================================================
+    // Bootstrap & Canonical Queries
+    // ========================================================================
+
+    /// Reconstructs bootstrap metadata from canonical tables.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `bid_year` - The bid year
-    /// * `area` - The area
+    /// Returns an error if the database cannot be queried.
+    pub fn get_bootstrap_metadata(&mut self) -> Result<BootstrapMetadata, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::get_bootstrap_metadata_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::get_bootstrap_metadata_mysql(conn),
+        }
+    }
+
+    /// Lists all bid years that have been created.
     ///
     /// # Errors
     ///
-    /// Returns an error if the area doesn't exist.
-    pub fn get_expected_user_count(
-        &mut self,
-        bid_year: &BidYear,
-        area: &Area,
-    ) -> Result<Option<usize>, PersistenceError> {
+    /// Returns an error if the database cannot be queried.
+    pub fn list_bid_years(&mut self) -> Result<Vec<CanonicalBidYear>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                let area_id =
-                    queries::canonical::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
-                queries::canonical::get_expected_user_count_sqlite(conn, bid_year_id, area_id)
-            }
-            BackendConnection::Mysql(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                let area_id =
-                    queries::canonical::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
-                queries::canonical::get_expected_user_count_mysql(conn, bid_year_id, area_id)
-            }
+            BackendConnection::Sqlite(conn) => queries::list_bid_years_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::list_bid_years_mysql(conn),
         }
     }
 
-    /// Gets the actual area count for a bid year.
+    /// Lists all areas for a given bid year.
     ///
     /// # Arguments
     ///
-    /// * `bid_year` - The bid year
+    /// * `bid_year` - The bid year to list areas for
     ///
     /// # Errors
     ///
     /// Returns an error if the database cannot be queried.
-    pub fn get_actual_area_count(&mut self, bid_year: &BidYear) -> Result<usize, PersistenceError> {
+    pub fn list_areas(&mut self, bid_year: &BidYear) -> Result<Vec<Area>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                queries::canonical::get_actual_area_count_sqlite(conn, bid_year_id)
+                let bid_year_id = match bid_year.bid_year_id() {
+                    Some(id) => id,
+                    None => match queries::lookup_bid_year_id_sqlite(conn, bid_year.year()) {
+                        Ok(id) => id,
+                        Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
+                        Err(e) => return Err(e),
+                    },
+                };
+                queries::list_areas_sqlite(conn, bid_year_id)
             }
             BackendConnection::Mysql(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                queries::canonical::get_actual_area_count_mysql(conn, bid_year_id)
+                let bid_year_id = match bid_year.bid_year_id() {
+                    Some(id) => id,
+                    None => match queries::lookup_bid_year_id_mysql(conn, bid_year.year()) {
+                        Ok(id) => id,
+                        Err(PersistenceError::ReconstructionError(_)) => return Ok(Vec::new()),
+                        Err(e) => return Err(e),
+                    },
+                };
+                queries::list_areas_mysql(conn, bid_year_id)
             }
         }
     }
 
-    /// Gets the actual user count for an area.
+    /// Lists all users for a given `(BidYear, Area)` scope.
     ///
     /// # Arguments
     ///
@@ -1457,1843 +2339,7392 @@ impl Persistence {
     /// # Errors
     ///
     /// Returns an error if the database cannot be queried.
-    pub fn get_actual_user_count(
+    pub fn list_users(
         &mut self,
         bid_year: &BidYear,
         area: &Area,
-    ) -> Result<usize, PersistenceError> {
+    ) -> Result<Vec<User>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
-                let area_id =
-                    queries::canonical::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
-                queries::canonical::get_actual_user_count_sqlite(conn, bid_year_id, area_id)
+                let bid_year_id = queries::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
+                queries::list_users_sqlite(conn, bid_year_id, area_id, bid_year, area)
             }
             BackendConnection::Mysql(conn) => {
-                let bid_year_id =
-                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
-                let area_id =
-                    queries::canonical::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
-                queries::canonical::get_actual_user_count_mysql(conn, bid_year_id, area_id)
+                let bid_year_id = queries::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                let area_id = queries::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
+                queries::list_users_mysql(conn, bid_year_id, area_id, bid_year, area)
             }
         }
     }
 
-    /// Updates an existing user's information.
+    // ========================================================================
+    // Completeness Queries
+    // ========================================================================
+
+    /// Counts users per area for a given bid year.
     ///
     /// # Arguments
     ///
-    /// * `user_id` - The user's canonical internal identifier
-    /// * `initials` - The user's initials
-    /// * `name` - The user's name
-    /// * `area` - The user's area
-    /// * `user_type` - The user's type classification
-    /// * `crew` - The user's crew (optional)
-    /// * `cumulative_natca_bu_date` - Cumulative NATCA bargaining unit date
-    /// * `natca_bu_date` - NATCA bargaining unit date
-    /// * `eod_faa_date` - Entry on Duty / FAA date
-    /// * `service_computation_date` - Service Computation Date
-    /// * `lottery_value` - Optional lottery value
+    /// * `bid_year` - The bid year to count users for
     ///
     /// # Errors
     ///
-    /// Returns an error if the user doesn't exist or update fails.
-    #[allow(clippy::too_many_arguments)]
-    pub fn update_user(
+    /// Returns an error if the database cannot be queried.
+    pub fn count_users_by_area(
         &mut self,
-        user_id: i64,
-        initials: &Initials,
-        name: &str,
-        area: &Area,
-        user_type: &str,
-        crew: Option<u8>,
-        cumulative_natca_bu_date: &str,
-        natca_bu_date: &str,
-        eod_faa_date: &str,
-        service_computation_date: &str,
-        lottery_value: Option<u32>,
-    ) -> Result<(), PersistenceError> {
+        bid_year: &BidYear,
+    ) -> Result<Vec<(String, usize)>, PersistenceError> {
+        let bid_year_id = bid_year.bid_year_id().ok_or_else(|| {
+            PersistenceError::ReconstructionError(
+                "BidYear must have a bid_year_id to count users".to_string(),
+            )
+        })?;
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::update_user_sqlite(
-                conn,
-                user_id,
-                initials,
-                name,
-                area,
-                user_type,
-                crew,
-                cumulative_natca_bu_date,
-                natca_bu_date,
-                eod_faa_date,
-                service_computation_date,
-                lottery_value,
-            ),
-            BackendConnection::Mysql(conn) => mutations::update_user_mysql(
-                conn,
-                user_id,
-                initials,
-                name,
-                area,
-                user_type,
-                crew,
-                cumulative_natca_bu_date,
-                natca_bu_date,
-                eod_faa_date,
-                service_computation_date,
-                lottery_value,
-            ),
+            BackendConnection::Sqlite(conn) => {
+                queries::count_users_by_area_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => queries::count_users_by_area_mysql(conn, bid_year_id),
         }
     }
 
-    /// Creates a system area (e.g., "No Bid") for a bid year.
-    ///
-    /// Phase 25B: System areas are auto-created and cannot be deleted or renamed.
+    /// Counts areas per bid year.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `area_code` - The area code (e.g., "NO BID")
+    /// Returns an error if the database cannot be queried.
+    pub fn count_areas_by_bid_year(&mut self) -> Result<Vec<(u16, usize)>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::count_areas_by_bid_year_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::count_areas_by_bid_year_mysql(conn),
+        }
+    }
+
+    /// Counts total users per bid year across all areas.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// The generated `area_id` for the new system area.
+    /// Returns an error if the database cannot be queried.
+    pub fn count_users_by_bid_year(&mut self) -> Result<Vec<(u16, usize)>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::count_users_by_bid_year_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::count_users_by_bid_year_mysql(conn),
+        }
+    }
+
+    /// Counts users per (`bid_year`, `area_id`) combination.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database operation fails.
-    pub fn create_system_area(
+    /// Returns an error if the database cannot be queried.
+    pub fn count_users_by_bid_year_and_area(
         &mut self,
-        bid_year_id: i64,
-        area_code: &str,
-    ) -> Result<i64, PersistenceError> {
+    ) -> Result<Vec<(u16, String, usize)>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::create_system_area_sqlite(conn, bid_year_id, area_code)
-            }
-            BackendConnection::Mysql(conn) => {
-                mutations::create_system_area_mysql(conn, bid_year_id, area_code)
+                queries::count_users_by_bid_year_and_area_sqlite(conn)
             }
+            BackendConnection::Mysql(conn) => queries::count_users_by_bid_year_and_area_mysql(conn),
         }
     }
 
-    /// Gets the lifecycle state for a bid year.
+    /// Finds the system area (No Bid) for a given bid year.
+    ///
+    /// Phase 25B: Returns the area ID and area code of the system area.
     ///
     /// # Arguments
     ///
     /// * `bid_year_id` - The canonical bid year ID
     ///
+    /// # Returns
+    ///
+    /// * `Ok(Some((area_id, area_code)))` if a system area exists
+    /// * `Ok(None)` if no system area exists
+    ///
     /// # Errors
     ///
-    /// Returns an error if the bid year doesn't exist or the database cannot be queried.
-    pub fn get_lifecycle_state(&mut self, bid_year_id: i64) -> Result<String, PersistenceError> {
+    /// Returns an error if the database cannot be queried.
+    pub fn find_system_area(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Option<(i64, String)>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                queries::canonical::get_lifecycle_state_sqlite(conn, bid_year_id)
-            }
-            BackendConnection::Mysql(conn) => {
-                queries::canonical::get_lifecycle_state_mysql(conn, bid_year_id)
-            }
+            BackendConnection::Sqlite(conn) => queries::find_system_area_sqlite(conn, bid_year_id),
+            BackendConnection::Mysql(conn) => queries::find_system_area_mysql(conn, bid_year_id),
         }
     }
 
-    /// Updates the lifecycle state for a bid year.
+    /// Counts users in the system area (No Bid) for a given bid year.
+    ///
+    /// Phase 25B: Used to check if bootstrap can be completed.
     ///
     /// # Arguments
     ///
     /// * `bid_year_id` - The canonical bid year ID
-    /// * `new_state` - The new lifecycle state as a string
+    ///
+    /// # Returns
+    ///
+    /// The number of users in the No Bid area (0 if no system area exists).
     ///
     /// # Errors
     ///
-    /// Returns an error if the bid year doesn't exist or the database cannot be updated.
-    pub fn update_lifecycle_state(
+    /// Returns an error if the database cannot be queried.
+    pub fn count_users_in_system_area(
         &mut self,
         bid_year_id: i64,
-        new_state: &str,
-    ) -> Result<(), PersistenceError> {
+    ) -> Result<usize, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::canonical::update_lifecycle_state_sqlite(conn, bid_year_id, new_state)
+                queries::count_users_in_system_area_sqlite(conn, bid_year_id)
             }
             BackendConnection::Mysql(conn) => {
-                queries::canonical::update_lifecycle_state_mysql(conn, bid_year_id, new_state)
+                queries::count_users_in_system_area_mysql(conn, bid_year_id)
             }
         }
     }
 
-    /// Retrieves the metadata (label and notes) for a bid year.
+    /// Lists users in the system area (No Bid) for a given bid year.
+    ///
+    /// Phase 25B: Returns up to `limit` user initials for error reporting.
     ///
     /// # Arguments
     ///
     /// * `bid_year_id` - The canonical bid year ID
+    /// * `limit` - Maximum number of initials to return
+    ///
+    /// # Returns
+    ///
+    /// A vector of user initials (empty if no system area or no users).
     ///
     /// # Errors
     ///
-    /// Returns an error if the bid year doesn't exist or the database cannot be queried.
-    pub fn get_bid_year_metadata(
+    /// Returns an error if the database cannot be queried.
+    pub fn list_users_in_system_area(
         &mut self,
         bid_year_id: i64,
-    ) -> Result<(Option<String>, Option<String>), PersistenceError> {
+        limit: i64,
+    ) -> Result<Vec<String>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::canonical::get_bid_year_metadata_sqlite(conn, bid_year_id)
+                queries::list_users_in_system_area_sqlite(conn, bid_year_id, limit)
             }
             BackendConnection::Mysql(conn) => {
-                queries::canonical::get_bid_year_metadata_mysql(conn, bid_year_id)
+                queries::list_users_in_system_area_mysql(conn, bid_year_id, limit)
             }
         }
     }
 
-    /// Updates the metadata fields (label and notes) for a bid year.
+    /// Checks if an area is a system area.
+    ///
+    /// Phase 25B: Used to prevent deletion/renaming of system areas.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `label` - Optional display label (max 100 characters)
-    /// * `notes` - Optional operational notes (max 2000 characters)
+    /// * `area_id` - The canonical area ID to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if the area is a system area, `false` otherwise.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be updated or the bid year doesn't exist.
-    pub fn update_bid_year_metadata(
-        &mut self,
-        bid_year_id: i64,
-        label: Option<&str>,
-        notes: Option<&str>,
-    ) -> Result<(), PersistenceError> {
+    /// Returns an error if the database cannot be queried or the area doesn't exist.
+    pub fn is_system_area(&mut self, area_id: i64) -> Result<bool, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                mutations::bootstrap::update_bid_year_metadata_sqlite(
-                    conn,
-                    bid_year_id,
-                    label,
-                    notes,
-                )
-            }
-            BackendConnection::Mysql(conn) => mutations::bootstrap::update_bid_year_metadata_mysql(
-                conn,
-                bid_year_id,
-                label,
-                notes,
-            ),
+            BackendConnection::Sqlite(conn) => queries::is_system_area_sqlite(conn, area_id),
+            BackendConnection::Mysql(conn) => queries::is_system_area_mysql(conn, area_id),
         }
     }
 
-    /// Retrieves the bid schedule for a bid year.
+    /// Updates an area's display name.
     ///
-    /// Phase 29C: Returns bid schedule fields if set, or None values if not configured.
+    /// Phase 26C: Used to edit area metadata (display name only, not area code).
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    /// * `area_name` - The new display name (or `None` to clear)
     ///
     /// # Errors
     ///
-    /// Returns an error if the bid year doesn't exist or the database cannot be queried.
-    pub fn get_bid_schedule(
+    /// Returns an error if the area doesn't exist or the database operation fails.
+    pub fn update_area_name(
         &mut self,
-        bid_year_id: i64,
-    ) -> Result<mutations::bootstrap::BidScheduleFields, PersistenceError> {
+        area_id: i64,
+        area_name: Option<&str>,
+    ) -> Result<(), PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::bootstrap::get_bid_schedule_sqlite(conn, bid_year_id)
+                mutations::update_area_name_sqlite(conn, area_id, area_name)
             }
             BackendConnection::Mysql(conn) => {
-                mutations::bootstrap::get_bid_schedule_mysql(conn, bid_year_id)
+                mutations::update_area_name_mysql(conn, area_id, area_name)
             }
         }
     }
 
-    /// Updates the bid schedule for a bid year.
-    ///
-    /// Phase 29C: Sets all bid schedule fields atomically.
+    /// Assigns the round group an area bids with.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `timezone` - IANA timezone identifier
-    /// * `start_date` - Bid start date (ISO 8601 format)
-    /// * `window_start_time` - Daily window start time (HH:MM:SS format)
-    /// * `window_end_time` - Daily window end time (HH:MM:SS format)
-    /// * `bidders_per_day` - Number of bidders per area per day
+    /// * `area_id` - The canonical area ID
+    /// * `round_group_id` - The round group (or `None` to clear)
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be updated or the bid year doesn't exist.
-    pub fn update_bid_schedule(
+    /// Returns an error if the area doesn't exist or the database operation fails.
+    pub fn update_area_round_group(
         &mut self,
-        bid_year_id: i64,
-        timezone: Option<&str>,
-        start_date: Option<&str>,
-        window_start_time: Option<&str>,
-        window_end_time: Option<&str>,
-        bidders_per_day: Option<i32>,
+        area_id: i64,
+        round_group_id: Option<i64>,
     ) -> Result<(), PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::bootstrap::update_bid_schedule_sqlite(
-                conn,
-                bid_year_id,
-                timezone,
-                start_date,
-                window_start_time,
-                window_end_time,
-                bidders_per_day,
-            ),
-            BackendConnection::Mysql(conn) => mutations::bootstrap::update_bid_schedule_mysql(
-                conn,
-                bid_year_id,
-                timezone,
-                start_date,
-                window_start_time,
-                window_end_time,
-                bidders_per_day,
-            ),
+            BackendConnection::Sqlite(conn) => {
+                mutations::update_area_round_group_sqlite(conn, area_id, round_group_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::update_area_round_group_mysql(conn, area_id, round_group_id)
+            }
         }
     }
 
-    /// Queries whether any bid year is in the `BiddingActive` lifecycle state.
+    /// Determines if a given action requires a full snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `action_name` - The action name to check
     ///
     /// # Returns
     ///
-    /// * `Ok(Some(year))` if a bid year is `BiddingActive`
-    /// * `Ok(None)` if no bid year is `BiddingActive`
+    /// `true` if the action requires a snapshot, `false` otherwise.
+    #[must_use]
+    pub fn should_snapshot(&self, action_name: &str) -> bool {
+        queries::should_snapshot(action_name)
+    }
+
+    // ========================================================================
+    // Operator Queries
+    // ========================================================================
+
+    /// Creates a new operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `login_name` - The login name (will be normalized)
+    /// * `display_name` - The display name
+    /// * `password` - The plain-text password (will be hashed)
+    /// * `role` - The role (Admin or Bidder)
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn get_bidding_active_year(&mut self) -> Result<Option<u16>, PersistenceError> {
+    /// Returns an error if the operator cannot be created.
+    pub fn create_operator(
+        &mut self,
+        login_name: &str,
+        display_name: &str,
+        password: &str,
+        role: &str,
+    ) -> Result<i64, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::canonical::get_bidding_active_year_sqlite(conn)
+                mutations::create_operator_sqlite(conn, login_name, display_name, password, role)
             }
             BackendConnection::Mysql(conn) => {
-                queries::canonical::get_bidding_active_year_mysql(conn)
+                mutations::create_operator_mysql(conn, login_name, display_name, password, role)
             }
         }
     }
 
-    // ========================================================================
-    // Canonical ID Lookups (Test Support)
-    // ========================================================================
-
-    /// Queries the canonical `bid_year_id` for a given year.
-    /// Get the year value for a given canonical bid year ID.
+    /// Retrieves an operator by login name.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID to query
+    /// * `login_name` - The login name to search for
     ///
     /// # Errors
     ///
-    /// Returns an error if the bid year is not found or the query fails.
-    pub fn get_bid_year_from_id(&mut self, bid_year_id: i64) -> Result<u16, PersistenceError> {
-        use diesel_schema::bid_years;
-
+    /// Returns an error if the database query fails.
+    pub fn get_operator_by_login(
+        &mut self,
+        login_name: &str,
+    ) -> Result<Option<OperatorData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                let result: Result<i32, diesel::result::Error> = bid_years::table
-                    .select(bid_years::year)
-                    .filter(bid_years::bid_year_id.eq(bid_year_id))
-                    .first::<i32>(conn);
-
-                match result {
-                    Ok(year) => Ok(u16::try_from(year).map_err(|e| {
-                        PersistenceError::Other(format!("Invalid year value: {e}"))
-                    })?),
-                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
-                        format!("Bid year with ID {bid_year_id} does not exist"),
-                    )),
-                    Err(e) => Err(PersistenceError::from(e)),
-                }
+                queries::operators::get_operator_by_login_sqlite(conn, login_name)
             }
             BackendConnection::Mysql(conn) => {
-                let result: Result<i32, diesel::result::Error> = bid_years::table
-                    .select(bid_years::year)
-                    .filter(bid_years::bid_year_id.eq(bid_year_id))
-                    .first::<i32>(conn);
-
-                match result {
-                    Ok(year) => Ok(u16::try_from(year).map_err(|e| {
-                        PersistenceError::Other(format!("Invalid year value: {e}"))
-                    })?),
-                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
-                        format!("Bid year with ID {bid_year_id} does not exist"),
-                    )),
-                    Err(e) => Err(PersistenceError::from(e)),
-                }
+                queries::operators::get_operator_by_login_mysql(conn, login_name)
             }
         }
     }
 
-    /// Get the canonical bid year ID for a given year.
+    /// Retrieves an operator by ID.
     ///
     /// # Arguments
     ///
-    /// * `year` - The year to query
+    /// * `operator_id` - The operator ID
     ///
     /// # Errors
     ///
-    /// Returns an error if the bid year is not found or the query fails.
-    pub fn get_bid_year_id(&mut self, year: u16) -> Result<i64, PersistenceError> {
-        use diesel_schema::bid_years;
-
+    /// Returns an error if the database query fails.
+    pub fn get_operator_by_id(
+        &mut self,
+        operator_id: i64,
+    ) -> Result<Option<OperatorData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                let result: Result<i64, diesel::result::Error> = bid_years::table
-                    .select(bid_years::bid_year_id)
-                    .filter(bid_years::year.eq(i32::from(year)))
-                    .first::<i64>(conn);
-
-                match result {
-                    Ok(id) => Ok(id),
-                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
-                        format!("Bid year {year} does not exist"),
-                    )),
-                    Err(e) => Err(PersistenceError::from(e)),
-                }
+                queries::operators::get_operator_by_id_sqlite(conn, operator_id)
             }
             BackendConnection::Mysql(conn) => {
-                let result: Result<i64, diesel::result::Error> = bid_years::table
-                    .select(bid_years::bid_year_id)
-                    .filter(bid_years::year.eq(i32::from(year)))
-                    .first::<i64>(conn);
-
-                match result {
-                    Ok(id) => Ok(id),
-                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
-                        format!("Bid year {year} does not exist"),
-                    )),
-                    Err(e) => Err(PersistenceError::from(e)),
-                }
+                queries::operators::get_operator_by_id_mysql(conn, operator_id)
             }
         }
     }
 
-    /// Queries the canonical `area_id` for a given bid year and area code.
+    /// Updates the last login timestamp for an operator.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year identifier
-    /// * `area_code` - The area code
+    /// * `operator_id` - The operator ID
     ///
     /// # Errors
     ///
-    /// Returns an error if the area is not found or the query fails.
-    pub fn get_area_id(
-        &mut self,
-        bid_year_id: i64,
-        area_code: &str,
-    ) -> Result<i64, PersistenceError> {
-        use diesel_schema::areas;
-
-        let normalized_code: String = area_code.to_uppercase();
-
+    /// Returns an error if the database update fails.
+    pub fn update_last_login(&mut self, operator_id: i64) -> Result<(), PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                let result: Result<i64, diesel::result::Error> = areas::table
-                    .select(areas::area_id)
-                    .filter(areas::bid_year_id.eq(bid_year_id))
-                    .filter(areas::area_code.eq(&normalized_code))
-                    .first::<i64>(conn);
-
-                match result {
-                    Ok(id) => Ok(id),
-                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
-                        format!("Area {area_code} does not exist"),
-                    )),
-                    Err(e) => Err(PersistenceError::from(e)),
-                }
-            }
-            BackendConnection::Mysql(conn) => {
-                let result: Result<i64, diesel::result::Error> = areas::table
-                    .select(areas::area_id)
-                    .filter(areas::bid_year_id.eq(bid_year_id))
-                    .filter(areas::area_code.eq(&normalized_code))
-                    .first::<i64>(conn);
-
-                match result {
-                    Ok(id) => Ok(id),
-                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
-                        format!("Area {area_code} does not exist"),
-                    )),
-                    Err(e) => Err(PersistenceError::from(e)),
-                }
+                mutations::update_last_login_sqlite(conn, operator_id)
             }
+            BackendConnection::Mysql(conn) => mutations::update_last_login_mysql(conn, operator_id),
         }
     }
 
-    /// Canonicalizes a bid year by populating canonical data tables.
-    ///
-    /// This function persists the audit event and creates canonical rows for:
-    /// - Area membership
-    /// - Eligibility
-    /// - Bid order (NULL until computed)
-    /// - Bid windows (NULL until computed)
+    /// Disables an operator.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The bid year to canonicalize
-    /// * `audit_event` - The audit event recording canonicalization
-    ///
-    /// # Returns
-    ///
-    /// The `event_id` of the persisted audit event.
+    /// * `operator_id` - The operator ID
     ///
     /// # Errors
     ///
-    /// Returns an error if any database operation fails.
-    pub fn canonicalize_bid_year(
-        &mut self,
-        bid_year_id: i64,
-        audit_event: &zab_bid_audit::AuditEvent,
-    ) -> Result<i64, PersistenceError> {
+    /// Returns an error if the database update fails.
+    pub fn disable_operator(&mut self, operator_id: i64) -> Result<(), PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::bootstrap::canonicalize_bid_year_sqlite(conn, bid_year_id, audit_event)
-            }
-            BackendConnection::Mysql(conn) => {
-                mutations::bootstrap::canonicalize_bid_year_mysql(conn, bid_year_id, audit_event)
+                mutations::disable_operator_sqlite(conn, operator_id)
             }
+            BackendConnection::Mysql(conn) => mutations::disable_operator_mysql(conn, operator_id),
         }
     }
 
-    /// Lists users with lifecycle-aware routing.
-    ///
-    /// Phase 25C: Routes reads to canonical or derived tables based on lifecycle state.
-    ///
-    /// When `lifecycle_state >= Canonicalized`, reads come from canonical tables.
-    /// When `lifecycle_state < Canonicalized`, reads come from the users table.
+    /// Re-enables a disabled operator.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `area_id` - The canonical area ID
-    /// * `bid_year` - The `BidYear` domain object
-    /// * `area` - The Area domain object
+    /// * `operator_id` - The operator ID
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The database cannot be queried
-    /// - Canonical data is missing when lifecycle >= Canonicalized
-    pub fn list_users_with_routing(
-        &mut self,
-        bid_year_id: i64,
-        area_id: i64,
-        bid_year: &BidYear,
-        area: &Area,
-    ) -> Result<Vec<User>, PersistenceError> {
+    /// Returns an error if the database update fails.
+    pub fn enable_operator(&mut self, operator_id: i64) -> Result<(), PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::canonical::list_users_with_routing_sqlite(
-                conn,
-                bid_year_id,
-                area_id,
-                bid_year,
-                area,
-            ),
-            BackendConnection::Mysql(conn) => queries::canonical::list_users_with_routing_mysql(
-                conn,
-                bid_year_id,
-                area_id,
-                bid_year,
-                area,
-            ),
+            BackendConnection::Sqlite(conn) => mutations::enable_operator_sqlite(conn, operator_id),
+            BackendConnection::Mysql(conn) => mutations::enable_operator_mysql(conn, operator_id),
         }
     }
 
-    /// Override a user's area assignment after canonicalization.
+    /// Deletes an operator if they are not referenced by any audit events.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `user_id` - The canonical user ID
-    /// * `new_area_id` - The new area ID to assign
-    /// * `reason` - The reason for the override
-    ///
-    /// # Returns
-    ///
-    /// Returns a tuple of (`previous_area_id`, `was_already_overridden`).
+    /// * `operator_id` - The operator ID
     ///
     /// # Errors
     ///
-    /// Returns an error if the canonical record does not exist or the database operation fails.
-    pub fn override_area_assignment(
-        &mut self,
-        bid_year_id: i64,
-        user_id: i64,
-        new_area_id: i64,
-        reason: &str,
-    ) -> Result<(i64, bool), PersistenceError> {
+    /// Returns an error if the operator is referenced or doesn't exist.
+    pub fn delete_operator(&mut self, operator_id: i64) -> Result<(), PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                mutations::canonical::override_area_assignment_sqlite(
-                    conn,
-                    bid_year_id,
-                    user_id,
-                    new_area_id,
-                    reason,
-                )
-            }
-            BackendConnection::Mysql(conn) => mutations::canonical::override_area_assignment_mysql(
-                conn,
-                bid_year_id,
-                user_id,
-                new_area_id,
-                reason,
-            ),
+            BackendConnection::Sqlite(conn) => mutations::delete_operator_sqlite(conn, operator_id),
+            BackendConnection::Mysql(conn) => mutations::delete_operator_mysql(conn, operator_id),
         }
     }
 
-    /// Override a user's eligibility after canonicalization.
-    ///
-    /// # Arguments
-    ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `user_id` - The canonical user ID
-    /// * `can_bid` - The new eligibility status
-    /// * `reason` - The reason for the override
-    ///
-    /// # Returns
-    ///
-    /// Returns a tuple of (`previous_can_bid`, `was_already_overridden`).
+    /// Lists all operators.
     ///
     /// # Errors
     ///
-    /// Returns an error if the canonical record does not exist or the database operation fails.
-    pub fn override_eligibility(
-        &mut self,
-        bid_year_id: i64,
-        user_id: i64,
-        can_bid: bool,
-        reason: &str,
-    ) -> Result<(bool, bool), PersistenceError> {
+    /// Returns an error if the database query fails.
+    pub fn list_operators(&mut self) -> Result<Vec<OperatorData>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::canonical::override_eligibility_sqlite(
-                conn,
-                bid_year_id,
-                user_id,
-                can_bid,
-                reason,
-            ),
-            BackendConnection::Mysql(conn) => mutations::canonical::override_eligibility_mysql(
-                conn,
-                bid_year_id,
-                user_id,
-                can_bid,
-                reason,
-            ),
+            BackendConnection::Sqlite(conn) => queries::operators::list_operators_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::operators::list_operators_mysql(conn),
         }
     }
 
-    /// Override a user's bid order after canonicalization.
-    ///
-    /// # Arguments
-    ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `user_id` - The canonical user ID
-    /// * `bid_order` - The new bid order (or `None` to clear)
-    /// * `reason` - The reason for the override
-    ///
-    /// Bulk inserts canonical bid order records.
-    ///
-    /// # Arguments
-    ///
-    /// * `records` - The canonical bid order records to insert
+    /// Lists all operators with their sign-in activity and area scopes.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database operation fails.
-    pub fn bulk_insert_canonical_bid_order(
+    /// Returns an error if the database query fails.
+    pub fn list_operators_with_activity(
         &mut self,
-        records: &[data_models::NewCanonicalBidOrder],
-    ) -> Result<(), PersistenceError> {
+    ) -> Result<Vec<OperatorActivityData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::canonical::bulk_insert_canonical_bid_order_sqlite(conn, records)
+                queries::operators::list_operators_with_activity_sqlite(conn)
             }
             BackendConnection::Mysql(conn) => {
-                mutations::canonical::bulk_insert_canonical_bid_order_mysql(conn, records)
+                queries::operators::list_operators_with_activity_mysql(conn)
             }
         }
     }
 
-    /// Bulk inserts bid window records.
+    /// Records a failed sign-in attempt for an operator.
     ///
     /// # Arguments
     ///
-    /// * `records` - The bid window records to insert
+    /// * `operator_id` - The operator ID
     ///
     /// # Errors
     ///
-    /// Returns an error if the database operation fails.
-    pub fn bulk_insert_bid_windows(
-        &mut self,
-        records: &[data_models::NewBidWindow],
-    ) -> Result<(), PersistenceError> {
+    /// Returns an error if the database update fails.
+    pub fn record_failed_login(&mut self, operator_id: i64) -> Result<(), PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::canonical::bulk_insert_bid_windows_sqlite(conn, records)
+                mutations::record_failed_login_sqlite(conn, operator_id)
             }
             BackendConnection::Mysql(conn) => {
-                mutations::canonical::bulk_insert_bid_windows_mysql(conn, records)
+                mutations::record_failed_login_mysql(conn, operator_id)
             }
         }
     }
 
-    /// Lists all rounds for a given bid year (across all round groups).
+    /// Replaces the set of areas an operator is assigned to.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The bid year ID
+    /// * `operator_id` - The operator ID
+    /// * `area_ids` - The areas to assign; an empty slice clears all scopes
     ///
     /// # Errors
     ///
-    /// Returns an error if the query fails.
-    pub fn list_all_rounds_for_bid_year(
+    /// Returns an error if an area does not exist or the database write fails.
+    pub fn set_operator_area_scopes(
         &mut self,
-        bid_year_id: i64,
-    ) -> Result<Vec<(i64, String)>, PersistenceError> {
+        operator_id: i64,
+        area_ids: &[i64],
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                mutations::set_operator_area_scopes_sqlite(conn, operator_id, area_ids)
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                mutations::set_operator_area_scopes_mysql(conn, operator_id, area_ids)
+            }),
+        }
+    }
+
+    /// Lists the IDs of the areas an operator is assigned to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_operator_area_ids(
+        &mut self,
+        operator_id: i64,
+    ) -> Result<Vec<i64>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::rounds::list_all_rounds_for_bid_year_sqlite(conn, bid_year_id)
+                queries::operators::list_operator_area_ids_sqlite(conn, operator_id)
             }
             BackendConnection::Mysql(conn) => {
-                queries::rounds::list_all_rounds_for_bid_year_mysql(conn, bid_year_id)
+                queries::operators::list_operator_area_ids_mysql(conn, operator_id)
             }
         }
     }
 
-    /// Bulk inserts bid status records (used at confirmation).
+    /// Records the delegation of an area rep's bidding authority.
     ///
     /// # Arguments
     ///
-    /// * `records` - The bid status records to insert
+    /// * `area_id` - The area ID
+    /// * `delegator_operator_id` - The area rep handing over authority
+    /// * `delegate_operator_id` - The operator receiving it
+    /// * `start_date` - The first day the delegation applies (`YYYY-MM-DD`)
+    /// * `end_date` - The last day the delegation applies (`YYYY-MM-DD`)
     ///
     /// # Errors
     ///
     /// Returns an error if the insert fails.
-    pub fn bulk_insert_bid_status(
+    pub fn insert_area_delegation(
         &mut self,
-        records: &[data_models::NewBidStatus],
-    ) -> Result<(), PersistenceError> {
+        area_id: i64,
+        delegator_operator_id: i64,
+        delegate_operator_id: i64,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<i64, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::bid_status::bulk_insert_bid_status_sqlite(conn, records)
+                queries::area_delegations::insert_area_delegation_sqlite(
+                    conn,
+                    area_id,
+                    delegator_operator_id,
+                    delegate_operator_id,
+                    start_date,
+                    end_date,
+                )
             }
             BackendConnection::Mysql(conn) => {
-                mutations::bid_status::bulk_insert_bid_status_mysql(conn, records)
+                queries::area_delegations::insert_area_delegation_mysql(
+                    conn,
+                    area_id,
+                    delegator_operator_id,
+                    delegate_operator_id,
+                    start_date,
+                    end_date,
+                )
             }
         }
     }
 
-    /// # Returns
-    ///
-    /// Returns a tuple of (`previous_bid_order`, `was_already_overridden`).
+    /// Gets a single area delegation.
     ///
     /// # Errors
     ///
-    /// Returns an error if the canonical record does not exist or the database operation fails.
-    pub fn override_bid_order(
+    /// Returns an error if the query fails.
+    pub fn get_area_delegation(
         &mut self,
-        bid_year_id: i64,
-        user_id: i64,
-        bid_order: Option<i32>,
-        reason: &str,
-    ) -> Result<(Option<i32>, bool), PersistenceError> {
+        area_delegation_id: i64,
+    ) -> Result<Option<AreaDelegationData>, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::canonical::override_bid_order_sqlite(
-                conn,
-                bid_year_id,
-                user_id,
-                bid_order,
-                reason,
-            ),
-            BackendConnection::Mysql(conn) => mutations::canonical::override_bid_order_mysql(
-                conn,
-                bid_year_id,
-                user_id,
-                bid_order,
-                reason,
-            ),
+            BackendConnection::Sqlite(conn) => {
+                queries::area_delegations::get_area_delegation_sqlite(conn, area_delegation_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::area_delegations::get_area_delegation_mysql(conn, area_delegation_id)
+            }
         }
     }
 
-    /// Override a user's bid window after canonicalization.
-    ///
-    /// # Arguments
-    ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `user_id` - The canonical user ID
-    /// * `window_start` - The new window start date (or `None` to clear)
-    /// * `window_end` - The new window end date (or `None` to clear)
-    /// * `reason` - The reason for the override
-    ///
-    /// # Returns
-    ///
-    /// Returns a tuple of (`previous_window_start`, `previous_window_end`, `was_already_overridden`).
+    /// Deletes an area delegation, returning whether it existed.
     ///
     /// # Errors
     ///
-    /// Returns an error if the canonical record does not exist or the database operation fails.
-    pub fn override_bid_window(
+    /// Returns an error if the delete fails.
+    pub fn delete_area_delegation(
         &mut self,
-        bid_year_id: i64,
-        user_id: i64,
-        window_start: Option<&String>,
-        window_end: Option<&String>,
-        reason: &str,
-    ) -> Result<(Option<String>, Option<String>, bool), PersistenceError> {
+        area_delegation_id: i64,
+    ) -> Result<bool, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::canonical::override_bid_window_sqlite(
-                conn,
-                bid_year_id,
-                user_id,
-                window_start,
-                window_end,
-                reason,
-            ),
-            BackendConnection::Mysql(conn) => mutations::canonical::override_bid_window_mysql(
-                conn,
-                bid_year_id,
-                user_id,
-                window_start,
-                window_end,
-                reason,
-            ),
+            BackendConnection::Sqlite(conn) => {
+                queries::area_delegations::delete_area_delegation_sqlite(conn, area_delegation_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::area_delegations::delete_area_delegation_mysql(conn, area_delegation_id)
+            }
         }
     }
 
-    /// Get user details for override operations.
-    ///
-    /// # Arguments
-    ///
-    /// * `user_id` - The canonical user ID
+    /// Lists every area delegation, ordered by start date.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns a tuple of (`bid_year_id`, `user_initials`).
+    /// Returns an error if the query fails.
+    pub fn list_area_delegations(&mut self) -> Result<Vec<AreaDelegationData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::area_delegations::list_area_delegations_sqlite(conn)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::area_delegations::list_area_delegations_mysql(conn)
+            }
+        }
+    }
+
+    /// Lists the areas delegated to an operator on a date (`YYYY-MM-DD`).
     ///
     /// # Errors
     ///
-    /// Returns an error if the user does not exist or the database operation fails.
-    pub fn get_user_details(&mut self, user_id: i64) -> Result<(i64, String), PersistenceError> {
+    /// Returns an error if the query fails.
+    pub fn list_delegated_area_ids(
+        &mut self,
+        delegate_operator_id: i64,
+        on_date: &str,
+    ) -> Result<Vec<i64>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::canonical::get_user_details_for_override_sqlite(conn, user_id)
+                queries::area_delegations::list_delegated_area_ids_sqlite(
+                    conn,
+                    delegate_operator_id,
+                    on_date,
+                )
             }
             BackendConnection::Mysql(conn) => {
-                queries::canonical::get_user_details_for_override_mysql(conn, user_id)
+                queries::area_delegations::list_delegated_area_ids_mysql(
+                    conn,
+                    delegate_operator_id,
+                    on_date,
+                )
             }
         }
     }
 
-    /// Get the area ID for a user.
+    /// Checks if an operator is referenced by any audit events.
     ///
     /// # Arguments
     ///
-    /// * `user_id` - The canonical user ID
-    ///
-    /// # Returns
-    ///
-    /// Returns the `area_id` where the user is currently assigned.
+    /// * `operator_id` - The operator ID to check
     ///
     /// # Errors
     ///
-    /// Returns an error if the user does not exist or the database operation fails.
-    pub fn get_user_area_id(&mut self, user_id: i64) -> Result<i64, PersistenceError> {
+    /// Returns an error if the database query fails.
+    pub fn is_operator_referenced(&mut self, operator_id: i64) -> Result<bool, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::canonical::get_user_area_id_sqlite(conn, user_id)
+                queries::operators::is_operator_referenced_sqlite(conn, operator_id)
             }
             BackendConnection::Mysql(conn) => {
-                queries::canonical::get_user_area_id_mysql(conn, user_id)
+                queries::operators::is_operator_referenced_mysql(conn, operator_id)
             }
         }
     }
 
-    /// Get area details for override operations.
-    ///
-    /// # Arguments
-    ///
-    /// * `area_id` - The canonical area ID
+    /// Counts the total number of operators.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns a tuple of (`area_code`, `area_name`).
+    /// Returns an error if the database query fails.
+    pub fn count_operators(&mut self) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::operators::count_operators_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::operators::count_operators_mysql(conn),
+        }
+    }
+
+    /// Counts the number of active admin operators.
     ///
     /// # Errors
     ///
-    /// Returns an error if the area does not exist or the database operation fails.
-    pub fn get_area_details(
-        &mut self,
-        area_id: i64,
-    ) -> Result<(String, Option<String>), PersistenceError> {
+    /// Returns an error if the database query fails.
+    pub fn count_active_admin_operators(&mut self) -> Result<i64, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::canonical::get_area_details_for_override_sqlite(conn, area_id)
+                queries::operators::count_active_admin_operators_sqlite(conn)
             }
             BackendConnection::Mysql(conn) => {
-                queries::canonical::get_area_details_for_override_mysql(conn, area_id)
+                queries::operators::count_active_admin_operators_mysql(conn)
             }
         }
     }
 
-    /// Get current canonical area assignment for a user.
+    /// Verifies a password against a stored hash.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `user_id` - The canonical user ID
+    /// * `password` - The plain text password to verify
+    /// * `password_hash` - The stored bcrypt hash
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns the current `area_id`.
+    /// Returns an error if password verification fails.
+    pub fn verify_password(
+        &self,
+        password: &str,
+        password_hash: &str,
+    ) -> Result<bool, PersistenceError> {
+        queries::operators::verify_password(password, password_hash)
+    }
+
+    /// Updates an operator's password.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_id` - The operator ID
+    /// * `new_password` - The new password (will be hashed)
     ///
     /// # Errors
     ///
-    /// Returns an error if the canonical record does not exist or the database operation fails.
-    pub fn get_current_area_assignment(
+    /// Returns an error if the update fails.
+    pub fn update_password(
         &mut self,
-        bid_year_id: i64,
-        user_id: i64,
-    ) -> Result<i64, PersistenceError> {
+        operator_id: i64,
+        new_password: &str,
+    ) -> Result<(), PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::canonical::get_current_area_assignment_for_override_sqlite(
-                    conn,
-                    bid_year_id,
-                    user_id,
-                )
+                mutations::update_password_sqlite(conn, operator_id, new_password)
             }
             BackendConnection::Mysql(conn) => {
-                queries::canonical::get_current_area_assignment_for_override_mysql(
-                    conn,
-                    bid_year_id,
-                    user_id,
-                )
+                mutations::update_password_mysql(conn, operator_id, new_password)
             }
         }
     }
 
-    // ========================================================================
-    // Phase 29B: Round Groups and Rounds
-    // ========================================================================
-
-    /// Lists all round groups for a bid year.
+    /// Deletes all sessions for a specific operator.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The bid year ID
+    /// * `operator_id` - The operator ID whose sessions should be deleted
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn list_round_groups(
+    /// Returns an error if the database delete fails.
+    pub fn delete_sessions_for_operator(
         &mut self,
-        bid_year_id: i64,
-    ) -> Result<Vec<RoundGroup>, PersistenceError> {
+        operator_id: i64,
+    ) -> Result<usize, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::rounds::list_round_groups_sqlite(conn, bid_year_id)
+                mutations::delete_sessions_for_operator_sqlite(conn, operator_id)
             }
             BackendConnection::Mysql(conn) => {
-                queries::rounds::list_round_groups_mysql(conn, bid_year_id)
+                mutations::delete_sessions_for_operator_mysql(conn, operator_id)
             }
         }
     }
 
-    /// Gets a single round group by ID.
+    /// Deletes all sessions for an operator except the given one.
+    ///
+    /// The kept session must already be stored under the current session
+    /// key, which holds for any session just validated.
     ///
     /// # Arguments
     ///
-    /// * `round_group_id` - The round group ID
+    /// * `operator_id` - The operator ID whose sessions should be deleted
+    /// * `keep_session_token` - The token of the session to keep
     ///
     /// # Errors
     ///
-    /// Returns an error if the round group does not exist or the query fails.
-    pub fn get_round_group(&mut self, round_group_id: i64) -> Result<RoundGroup, PersistenceError> {
+    /// Returns an error if the database delete fails.
+    pub fn delete_other_sessions_for_operator(
+        &mut self,
+        operator_id: i64,
+        keep_session_token: &SecretToken,
+    ) -> Result<usize, PersistenceError> {
+        let keep_session_token_hash: String = self.session_keyring.digest(keep_session_token);
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::rounds::get_round_group_sqlite(conn, round_group_id)
-            }
-            BackendConnection::Mysql(conn) => {
-                queries::rounds::get_round_group_mysql(conn, round_group_id)
+                mutations::delete_other_sessions_for_operator_sqlite(
+                    conn,
+                    operator_id,
+                    &keep_session_token_hash,
+                )
             }
+            BackendConnection::Mysql(conn) => mutations::delete_other_sessions_for_operator_mysql(
+                conn,
+                operator_id,
+                &keep_session_token_hash,
+            ),
         }
     }
 
-    /// Inserts a new round group.
+    /// Updates an operator's display name.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The bid year ID
-    /// * `name` - The round group name
-    /// * `editing_enabled` - Whether editing is enabled
+    /// * `operator_id` - The operator ID
+    /// * `display_name` - The new display name
     ///
     /// # Errors
     ///
-    /// Returns an error if the insert fails.
-    pub fn insert_round_group(
+    /// Returns an error if the operator does not exist or the update fails.
+    pub fn update_display_name(
         &mut self,
-        bid_year_id: i64,
-        name: &str,
-        editing_enabled: bool,
-    ) -> Result<i64, PersistenceError> {
+        operator_id: i64,
+        display_name: &str,
+    ) -> Result<(), PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::rounds::insert_round_group_sqlite(conn, bid_year_id, name, editing_enabled)
+                mutations::update_display_name_sqlite(conn, operator_id, display_name)
             }
             BackendConnection::Mysql(conn) => {
-                queries::rounds::insert_round_group_mysql(conn, bid_year_id, name, editing_enabled)
+                mutations::update_display_name_mysql(conn, operator_id, display_name)
             }
         }
     }
 
-    /// Updates an existing round group.
+    /// Updates an operator's role.
     ///
     /// # Arguments
     ///
-    /// * `round_group_id` - The round group ID
-    /// * `name` - The new name
-    /// * `editing_enabled` - The new `editing_enabled` value
+    /// * `operator_id` - The operator ID
+    /// * `role` - The new role (Admin or Bidder)
     ///
     /// # Errors
     ///
-    /// Returns an error if the update fails.
-    pub fn update_round_group(
+    /// Returns an error if the operator does not exist or the update fails.
+    pub fn update_operator_role(
         &mut self,
-        round_group_id: i64,
-        name: &str,
-        editing_enabled: bool,
+        operator_id: i64,
+        role: &str,
     ) -> Result<(), PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::rounds::update_round_group_sqlite(
-                conn,
-                round_group_id,
-                name,
-                editing_enabled,
-            ),
-            BackendConnection::Mysql(conn) => queries::rounds::update_round_group_mysql(
-                conn,
-                round_group_id,
-                name,
-                editing_enabled,
-            ),
+            BackendConnection::Sqlite(conn) => {
+                mutations::update_operator_role_sqlite(conn, operator_id, role)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::update_operator_role_mysql(conn, operator_id, role)
+            }
         }
     }
 
-    /// Deletes a round group.
+    // ========================================================================
+    // Session Management
+    // ========================================================================
+
+    /// Creates a new session for an operator.
+    ///
+    /// Only the digest of the token under the current session key is
+    /// stored.
     ///
     /// # Arguments
     ///
-    /// * `round_group_id` - The round group ID
+    /// * `session_token` - The unique session token
+    /// * `operator_id` - The operator ID
+    /// * `expires_at` - The expiration timestamp (ISO 8601 format)
     ///
     /// # Errors
     ///
-    /// Returns an error if the delete fails.
-    pub fn delete_round_group(&mut self, round_group_id: i64) -> Result<(), PersistenceError> {
+    /// Returns an error if the session cannot be created.
+    pub fn create_session(
+        &mut self,
+        session_token: &SecretToken,
+        operator_id: i64,
+        expires_at: &str,
+    ) -> Result<i64, PersistenceError> {
+        let session_token_hash: String = self.session_keyring.digest(session_token);
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::rounds::delete_round_group_sqlite(conn, round_group_id)
+                mutations::create_session_sqlite(conn, &session_token_hash, operator_id, expires_at)
             }
             BackendConnection::Mysql(conn) => {
-                queries::rounds::delete_round_group_mysql(conn, round_group_id)
+                mutations::create_session_mysql(conn, &session_token_hash, operator_id, expires_at)
             }
         }
     }
 
-    /// Checks if a round group is referenced by any rounds.
+    /// Retrieves a session by token.
+    ///
+    /// A session stored under the previous session key is still found, and
+    /// is re-keyed to the current key so the previous key can be retired.
     ///
     /// # Arguments
     ///
-    /// * `round_group_id` - The round group ID
+    /// * `session_token` - The session token
     ///
     /// # Errors
     ///
-    /// Returns an error if the query fails.
-    pub fn count_rounds_using_group(
+    /// Returns an error if the database query fails.
+    pub fn get_session_by_token(
         &mut self,
-        round_group_id: i64,
-    ) -> Result<usize, PersistenceError> {
+        session_token: &SecretToken,
+    ) -> Result<Option<SessionData>, PersistenceError> {
+        let session_token_hash: String = self.session_keyring.digest(session_token);
+        let previous_hash: Option<String> = self.session_keyring.previous_digest(session_token);
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::rounds::count_rounds_using_group_sqlite(conn, round_group_id)
+                let found: Option<SessionData> =
+                    queries::operators::get_session_by_token_sqlite(conn, &session_token_hash)?;
+                let Some(previous_hash) = previous_hash.filter(|_| found.is_none()) else {
+                    return Ok(found);
+                };
+                let Some(mut session) =
+                    queries::operators::get_session_by_token_sqlite(conn, &previous_hash)?
+                else {
+                    return Ok(None);
+                };
+                mutations::rekey_session_sqlite(conn, session.session_id, &session_token_hash)?;
+                session.session_token_hash = session_token_hash;
+                Ok(Some(session))
             }
             BackendConnection::Mysql(conn) => {
-                queries::rounds::count_rounds_using_group_mysql(conn, round_group_id)
+                let found: Option<SessionData> =
+                    queries::operators::get_session_by_token_mysql(conn, &session_token_hash)?;
+                let Some(previous_hash) = previous_hash.filter(|_| found.is_none()) else {
+                    return Ok(found);
+                };
+                let Some(mut session) =
+                    queries::operators::get_session_by_token_mysql(conn, &previous_hash)?
+                else {
+                    return Ok(None);
+                };
+                mutations::rekey_session_mysql(conn, session.session_id, &session_token_hash)?;
+                session.session_token_hash = session_token_hash;
+                Ok(Some(session))
             }
         }
     }
 
-    /// Checks if a round group name exists within a bid year.
+    /// Updates the last activity timestamp for a session.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The bid year ID
-    /// * `name` - The round group name
-    /// * `exclude_id` - Optional round group ID to exclude from the check
+    /// * `session_id` - The session ID
     ///
     /// # Errors
     ///
-    /// Returns an error if the query fails.
-    pub fn round_group_name_exists(
-        &mut self,
-        bid_year_id: i64,
-        name: &str,
-        exclude_id: Option<i64>,
-    ) -> Result<bool, PersistenceError> {
+    /// Returns an error if the database update fails.
+    pub fn update_session_activity(&mut self, session_id: i64) -> Result<(), PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::rounds::round_group_name_exists_sqlite(conn, bid_year_id, name, exclude_id)
+                mutations::update_session_activity_sqlite(conn, session_id)
             }
             BackendConnection::Mysql(conn) => {
-                queries::rounds::round_group_name_exists_mysql(conn, bid_year_id, name, exclude_id)
+                mutations::update_session_activity_mysql(conn, session_id)
             }
         }
     }
 
-    /// Lists all rounds for a given round group.
+    /// Deletes a session by token.
+    ///
+    /// The session is deleted whether it is stored under the current or
+    /// the previous session key.
     ///
     /// # Arguments
     ///
-    /// * `round_group_id` - The round group ID
+    /// * `session_token` - The session token to delete
     ///
     /// # Errors
     ///
-    /// Returns an error if the query fails.
-    pub fn list_rounds(&mut self, round_group_id: i64) -> Result<Vec<Round>, PersistenceError> {
+    /// Returns an error if the database delete fails.
+    pub fn delete_session(&mut self, session_token: &SecretToken) -> Result<(), PersistenceError> {
+        let session_token_hashes: Vec<String> =
+            std::iter::once(self.session_keyring.digest(session_token))
+                .chain(self.session_keyring.previous_digest(session_token))
+                .collect();
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::rounds::list_rounds_sqlite(conn, round_group_id)
+                mutations::delete_session_sqlite(conn, &session_token_hashes)
             }
             BackendConnection::Mysql(conn) => {
-                queries::rounds::list_rounds_mysql(conn, round_group_id)
+                mutations::delete_session_mysql(conn, &session_token_hashes)
             }
         }
     }
 
-    /// Gets a single round by ID.
-    ///
-    /// # Arguments
-    ///
-    /// * `round_id` - The round ID
+    /// Deletes all sessions that expired before this adapter's clock.
     ///
     /// # Errors
     ///
-    /// Returns an error if the round does not exist or the query fails.
-    pub fn get_round(&mut self, round_id: i64) -> Result<Round, PersistenceError> {
+    /// Returns an error if the clock cannot be formatted or the database
+    /// delete fails.
+    pub fn delete_expired_sessions(&mut self) -> Result<usize, PersistenceError> {
+        let now: String = self
+            .clock
+            .now()
+            .format(time::macros::format_description!(
+                "[year]-[month]-[day] [hour]:[minute]:[second]"
+            ))
+            .map_err(|e| PersistenceError::Other(format!("Failed to format clock time: {e}")))?;
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::rounds::get_round_sqlite(conn, round_id),
-            BackendConnection::Mysql(conn) => queries::rounds::get_round_mysql(conn, round_id),
+            BackendConnection::Sqlite(conn) => {
+                mutations::delete_expired_sessions_sqlite(conn, &now)
+            }
+            BackendConnection::Mysql(conn) => mutations::delete_expired_sessions_mysql(conn, &now),
         }
     }
 
-    /// Inserts a new round.
+    // ========================================================================
+    // Controller Portal Links
+    // ========================================================================
+
+    /// Stores a user's portal link, replacing any link the user already holds.
+    ///
+    /// Only the digest of the token under the current session key is
+    /// stored.
     ///
     /// # Arguments
     ///
-    /// * `round_group_id` - The round group ID
-    /// * `round_number` - The round number
-    /// * `name` - The round name
-    /// * `slots_per_day` - Slots per day
-    /// * `max_groups` - Maximum groups
-    /// * `max_total_hours` - Maximum total hours
-    /// * `include_holidays` - Whether holidays are included
-    /// * `allow_overbid` - Whether overbidding is allowed
+    /// * `link_token` - The link token
+    /// * `user_id` - The user the link belongs to
+    /// * `bid_year_id` - The user's bid year
+    /// * `expires_at` - When the link stops working (RFC 3339)
     ///
     /// # Errors
     ///
-    /// Returns an error if the insert fails.
-    #[allow(clippy::too_many_arguments)]
-    pub fn insert_round(
+    /// Returns an error if the link cannot be stored.
+    pub fn create_portal_link(
         &mut self,
-        round_group_id: i64,
-        round_number: u32,
-        name: &str,
-        slots_per_day: u32,
-        max_groups: u32,
-        max_total_hours: u32,
-        include_holidays: bool,
-        allow_overbid: bool,
-    ) -> Result<i64, PersistenceError> {
+        link_token: &SecretToken,
+        user_id: i64,
+        bid_year_id: i64,
+        expires_at: &str,
+    ) -> Result<(), PersistenceError> {
+        let token_hash: String = self.session_keyring.digest(link_token);
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::rounds::insert_round_sqlite(
+            BackendConnection::Sqlite(conn) => queries::portal_links::create_portal_link_sqlite(
                 conn,
-                round_group_id,
-                round_number,
-                name,
-                slots_per_day,
-                max_groups,
-                max_total_hours,
-                include_holidays,
-                allow_overbid,
+                &token_hash,
+                user_id,
+                bid_year_id,
+                expires_at,
             ),
-            BackendConnection::Mysql(conn) => queries::rounds::insert_round_mysql(
+            BackendConnection::Mysql(conn) => queries::portal_links::create_portal_link_mysql(
                 conn,
-                round_group_id,
-                round_number,
-                name,
-                slots_per_day,
-                max_groups,
-                max_total_hours,
-                include_holidays,
-                allow_overbid,
+                &token_hash,
+                user_id,
+                bid_year_id,
+                expires_at,
             ),
         }
     }
 
-    /// Updates an existing round.
+    /// Retrieves a portal link by token.
+    ///
+    /// A link stored under the previous session key is still found, and
+    /// is re-keyed to the current key.
     ///
     /// # Arguments
     ///
-    /// * `round_id` - The round ID
-    /// * `name` - The new name
-    /// * `slots_per_day` - The new `slots_per_day`
-    /// * `max_groups` - The new `max_groups`
-    /// * `max_total_hours` - The new `max_total_hours`
-    /// * `include_holidays` - The new `include_holidays`
-    /// * `allow_overbid` - The new `allow_overbid`
+    /// * `link_token` - The link token
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_portal_link_by_token(
+        &mut self,
+        link_token: &SecretToken,
+    ) -> Result<Option<PortalLinkData>, PersistenceError> {
+        let token_hash: String = self.session_keyring.digest(link_token);
+        let previous_hash: Option<String> = self.session_keyring.previous_digest(link_token);
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let link: Option<PortalLinkData> =
+                    queries::portal_links::get_portal_link_by_token_sqlite(conn, &token_hash)?;
+                let Some(previous_hash) = previous_hash.filter(|_| link.is_none()) else {
+                    return Ok(link);
+                };
+                let Some(mut link) =
+                    queries::portal_links::get_portal_link_by_token_sqlite(conn, &previous_hash)?
+                else {
+                    return Ok(None);
+                };
+                queries::portal_links::rekey_portal_link_sqlite(
+                    conn,
+                    link.portal_link_id,
+                    &token_hash,
+                )?;
+                link.token_hash = token_hash;
+                Ok(Some(link))
+            }
+            BackendConnection::Mysql(conn) => {
+                let link: Option<PortalLinkData> =
+                    queries::portal_links::get_portal_link_by_token_mysql(conn, &token_hash)?;
+                let Some(previous_hash) = previous_hash.filter(|_| link.is_none()) else {
+                    return Ok(link);
+                };
+                let Some(mut link) =
+                    queries::portal_links::get_portal_link_by_token_mysql(conn, &previous_hash)?
+                else {
+                    return Ok(None);
+                };
+                queries::portal_links::rekey_portal_link_mysql(
+                    conn,
+                    link.portal_link_id,
+                    &token_hash,
+                )?;
+                link.token_hash = token_hash;
+                Ok(Some(link))
+            }
+        }
+    }
+
+    /// Revokes a user's portal link.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user whose link is revoked
+    ///
+    /// # Returns
+    ///
+    /// Whether the user held a link.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database delete fails.
+    pub fn delete_portal_link(&mut self, user_id: i64) -> Result<bool, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::portal_links::delete_portal_link_sqlite(conn, user_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::portal_links::delete_portal_link_mysql(conn, user_id)
+            }
+        }
+    }
+
+    /// Lists a user's bid windows with their rounds, ordered by window start.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_user_bid_windows(
+        &mut self,
+        user_id: i64,
+    ) -> Result<Vec<UserBidWindowData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::portal_links::list_user_bid_windows_sqlite(conn, user_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::portal_links::list_user_bid_windows_mysql(conn, user_id)
+            }
+        }
+    }
+
+    // ========================================================================
+    // Bootstrap Configuration
+    // ========================================================================
+
+    /// Sets a bid year as active.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year to mark as active
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bid year doesn't exist or update fails.
+    pub fn set_active_bid_year(&mut self, bid_year: &BidYear) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                mutations::set_active_bid_year_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                mutations::set_active_bid_year_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Gets the active bid year.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no active bid year exists.
+    pub fn get_active_bid_year(&mut self) -> Result<u16, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::canonical::get_active_bid_year_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::canonical::get_active_bid_year_mysql(conn),
+        }
+    }
+
+    /// Sets the expected area count for a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year` - The bid year
+    /// * `count` - The expected number of areas
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be updated or the bid year doesn't exist.
+    pub fn set_expected_area_count(
+        &mut self,
+        bid_year: &BidYear,
+        count: usize,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                mutations::set_expected_area_count_sqlite(conn, bid_year_id, count)
+            }
+            BackendConnection::Mysql(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                mutations::set_expected_area_count_mysql(conn, bid_year_id, count)
+            }
+        }
+    }
+
+    /// Gets the expected area count for a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year` - The bid year
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bid year doesn't exist.
+    pub fn get_expected_area_count(
+        &mut self,
+        bid_year: &BidYear,
+    ) -> Result<Option<usize>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                queries::canonical::get_expected_area_count_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                queries::canonical::get_expected_area_count_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Sets the expected user count for an area.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year` - The bid year
+    /// * `area` - The area
+    /// * `count` - The expected number of users
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be updated or the area doesn't exist.
+    pub fn set_expected_user_count(
+        &mut self,
+        bid_year: &BidYear,
+        area: &Area,
+        count: usize,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                let area_id =
+                    queries::canonical::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
+                mutations::set_expected_user_count_sqlite(conn, bid_year_id, area_id, count)
+            }
+            BackendConnection::Mysql(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                let area_id =
+                    queries::canonical::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
+                mutations::set_expected_user_count_mysql(conn, bid_year_id, area_id, count)
+            }
+        }
+    }
+
+    /// Gets the expected user count for an area.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year` - The bid year
+    /// * `area` - The area
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area doesn't exist.
+    pub fn get_expected_user_count(
+        &mut self,
+        bid_year: &BidYear,
+        area: &Area,
+    ) -> Result<Option<usize>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                let area_id =
+                    queries::canonical::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
+                queries::canonical::get_expected_user_count_sqlite(conn, bid_year_id, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                let area_id =
+                    queries::canonical::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
+                queries::canonical::get_expected_user_count_mysql(conn, bid_year_id, area_id)
+            }
+        }
+    }
+
+    /// Gets the actual area count for a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year` - The bid year
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn get_actual_area_count(&mut self, bid_year: &BidYear) -> Result<usize, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                queries::canonical::get_actual_area_count_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                queries::canonical::get_actual_area_count_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Gets the actual user count for an area.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year` - The bid year
+    /// * `area` - The area
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn get_actual_user_count(
+        &mut self,
+        bid_year: &BidYear,
+        area: &Area,
+    ) -> Result<usize, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_sqlite(conn, bid_year.year())?;
+                let area_id =
+                    queries::canonical::lookup_area_id_sqlite(conn, bid_year_id, area.id())?;
+                queries::canonical::get_actual_user_count_sqlite(conn, bid_year_id, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                let bid_year_id =
+                    queries::canonical::lookup_bid_year_id_mysql(conn, bid_year.year())?;
+                let area_id =
+                    queries::canonical::lookup_area_id_mysql(conn, bid_year_id, area.id())?;
+                queries::canonical::get_actual_user_count_mysql(conn, bid_year_id, area_id)
+            }
+        }
+    }
+
+    /// Updates an existing user's information.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user's canonical internal identifier
+    /// * `initials` - The user's initials
+    /// * `name` - The user's name
+    /// * `area` - The user's area
+    /// * `user_type` - The user's type classification
+    /// * `crew` - The user's crew (optional)
+    /// * `cumulative_natca_bu_date` - Cumulative NATCA bargaining unit date
+    /// * `natca_bu_date` - NATCA bargaining unit date
+    /// * `eod_faa_date` - Entry on Duty / FAA date
+    /// * `service_computation_date` - Service Computation Date
+    /// * `lottery_value` - Optional lottery value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user doesn't exist or update fails.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_user(
+        &mut self,
+        user_id: i64,
+        initials: &Initials,
+        name: &str,
+        area: &Area,
+        user_type: &str,
+        crew: Option<u8>,
+        cumulative_natca_bu_date: &str,
+        natca_bu_date: &str,
+        eod_faa_date: &str,
+        service_computation_date: &str,
+        lottery_value: Option<u32>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::update_user_sqlite(
+                conn,
+                user_id,
+                initials,
+                name,
+                area,
+                user_type,
+                crew,
+                cumulative_natca_bu_date,
+                natca_bu_date,
+                eod_faa_date,
+                service_computation_date,
+                lottery_value,
+            ),
+            BackendConnection::Mysql(conn) => mutations::update_user_mysql(
+                conn,
+                user_id,
+                initials,
+                name,
+                area,
+                user_type,
+                crew,
+                cumulative_natca_bu_date,
+                natca_bu_date,
+                eod_faa_date,
+                service_computation_date,
+                lottery_value,
+            ),
+        }
+    }
+
+    /// Sets a user's participation flags.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be updated or the user does not exist.
+    pub fn set_user_participation(
+        &mut self,
+        user_id: i64,
+        excluded_from_bidding: bool,
+        excluded_from_leave_calculation: bool,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::set_user_participation_sqlite(
+                conn,
+                user_id,
+                excluded_from_bidding,
+                excluded_from_leave_calculation,
+            ),
+            BackendConnection::Mysql(conn) => mutations::set_user_participation_mysql(
+                conn,
+                user_id,
+                excluded_from_bidding,
+                excluded_from_leave_calculation,
+            ),
+        }
+    }
+
+    /// Creates a system area (e.g., "No Bid") for a bid year.
+    ///
+    /// Phase 25B: System areas are auto-created and cannot be deleted or renamed.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_code` - The area code (e.g., "NO BID")
+    ///
+    /// # Returns
+    ///
+    /// The generated `area_id` for the new system area.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn create_system_area(
+        &mut self,
+        bid_year_id: i64,
+        area_code: &str,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::create_system_area_sqlite(conn, bid_year_id, area_code)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::create_system_area_mysql(conn, bid_year_id, area_code)
+            }
+        }
+    }
+
+    /// Gets the lifecycle state for a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bid year doesn't exist or the database cannot be queried.
+    pub fn get_lifecycle_state(&mut self, bid_year_id: i64) -> Result<String, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::canonical::get_lifecycle_state_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::canonical::get_lifecycle_state_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Updates the lifecycle state for a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `new_state` - The new lifecycle state as a string
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bid year doesn't exist or the database cannot be updated.
+    pub fn update_lifecycle_state(
+        &mut self,
+        bid_year_id: i64,
+        new_state: &str,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::canonical::update_lifecycle_state_sqlite(conn, bid_year_id, new_state)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::canonical::update_lifecycle_state_mysql(conn, bid_year_id, new_state)
+            }
+        }
+    }
+
+    /// Retrieves the metadata (label and notes) for a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bid year doesn't exist or the database cannot be queried.
+    pub fn get_bid_year_metadata(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<(Option<String>, Option<String>), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::canonical::get_bid_year_metadata_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::canonical::get_bid_year_metadata_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Updates the metadata fields (label and notes) for a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `label` - Optional display label (max 100 characters)
+    /// * `notes` - Optional operational notes (max 2000 characters)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be updated or the bid year doesn't exist.
+    pub fn update_bid_year_metadata(
+        &mut self,
+        bid_year_id: i64,
+        label: Option<&str>,
+        notes: Option<&str>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::bootstrap::update_bid_year_metadata_sqlite(
+                    conn,
+                    bid_year_id,
+                    label,
+                    notes,
+                )
+            }
+            BackendConnection::Mysql(conn) => mutations::bootstrap::update_bid_year_metadata_mysql(
+                conn,
+                bid_year_id,
+                label,
+                notes,
+            ),
+        }
+    }
+
+    /// Retrieves the bid schedule for a bid year.
+    ///
+    /// Phase 29C: Returns bid schedule fields if set, or None values if not configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bid year doesn't exist or the database cannot be queried.
+    pub fn get_bid_schedule(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<mutations::bootstrap::BidScheduleFields, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::bootstrap::get_bid_schedule_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::bootstrap::get_bid_schedule_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Updates the bid schedule for a bid year.
+    ///
+    /// Phase 29C: Sets all bid schedule fields atomically.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `timezone` - IANA timezone identifier
+    /// * `start_date` - Bid start date (ISO 8601 format)
+    /// * `window_start_time` - Daily window start time (HH:MM:SS format)
+    /// * `window_end_time` - Daily window end time (HH:MM:SS format)
+    /// * `bidders_per_day` - Number of bidders per area per day
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be updated or the bid year doesn't exist.
+    pub fn update_bid_schedule(
+        &mut self,
+        bid_year_id: i64,
+        timezone: Option<&str>,
+        start_date: Option<&str>,
+        window_start_time: Option<&str>,
+        window_end_time: Option<&str>,
+        bidders_per_day: Option<i32>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::bootstrap::update_bid_schedule_sqlite(
+                conn,
+                bid_year_id,
+                timezone,
+                start_date,
+                window_start_time,
+                window_end_time,
+                bidders_per_day,
+            ),
+            BackendConnection::Mysql(conn) => mutations::bootstrap::update_bid_schedule_mysql(
+                conn,
+                bid_year_id,
+                timezone,
+                start_date,
+                window_start_time,
+                window_end_time,
+                bidders_per_day,
+            ),
+        }
+    }
+
+    /// Gets the bid schedule override for an area, if one is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `area_id` - The canonical area ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn get_area_bid_schedule_override(
+        &mut self,
+        area_id: i64,
+    ) -> Result<Option<mutations::bootstrap::AreaBidScheduleOverrideFields>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::bootstrap::get_area_bid_schedule_override_sqlite(conn, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::bootstrap::get_area_bid_schedule_override_mysql(conn, area_id)
+            }
+        }
+    }
+
+    /// Lists the bid schedule overrides of all areas in a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    ///
+    /// # Returns
+    ///
+    /// `(area_id, area_code, fields)` for each area with an override, ordered by area code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn list_area_bid_schedule_overrides(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<
+        Vec<(
+            i64,
+            String,
+            mutations::bootstrap::AreaBidScheduleOverrideFields,
+        )>,
+        PersistenceError,
+    > {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::bootstrap::list_area_bid_schedule_overrides_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::bootstrap::list_area_bid_schedule_overrides_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Sets the bid schedule override for an area, replacing any existing one.
+    ///
+    /// Fields passed as `None` fall back to the bid year's schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `area_id` - The canonical area ID
+    /// * `start_date` - Bid start date (ISO 8601 format)
+    /// * `window_start_time` - Daily window start time (HH:MM:SS format)
+    /// * `window_end_time` - Daily window end time (HH:MM:SS format)
+    /// * `bidders_per_day` - Number of bidders per day
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be updated.
+    pub fn set_area_bid_schedule_override(
+        &mut self,
+        area_id: i64,
+        start_date: Option<&str>,
+        window_start_time: Option<&str>,
+        window_end_time: Option<&str>,
+        bidders_per_day: Option<i32>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::bootstrap::set_area_bid_schedule_override_sqlite(
+                    conn,
+                    area_id,
+                    start_date,
+                    window_start_time,
+                    window_end_time,
+                    bidders_per_day,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::bootstrap::set_area_bid_schedule_override_mysql(
+                    conn,
+                    area_id,
+                    start_date,
+                    window_start_time,
+                    window_end_time,
+                    bidders_per_day,
+                )
+            }
+        }
+    }
+
+    /// Removes the bid schedule override for an area.
+    ///
+    /// # Arguments
+    ///
+    /// * `area_id` - The canonical area ID
+    ///
+    /// # Returns
+    ///
+    /// `true` if an override was removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be updated.
+    pub fn clear_area_bid_schedule_override(
+        &mut self,
+        area_id: i64,
+    ) -> Result<bool, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::bootstrap::clear_area_bid_schedule_override_sqlite(conn, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::bootstrap::clear_area_bid_schedule_override_mysql(conn, area_id)
+            }
+        }
+    }
+
+    /// Retrieves the bid schedule that applies to an area.
+    ///
+    /// Fields the area overrides take the area's value; all other fields
+    /// fall back to the bid year's schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `area_id` - The canonical area ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area doesn't exist or the database cannot be queried.
+    pub fn get_effective_bid_schedule(
+        &mut self,
+        area_id: i64,
+    ) -> Result<mutations::bootstrap::BidScheduleFields, PersistenceError> {
+        let (_area, bid_year_id) = self.get_area_by_id(area_id)?;
+        let bid_year_schedule = self.get_bid_schedule(bid_year_id)?;
+        let area_override = self.get_area_bid_schedule_override(area_id)?;
+        Ok(mutations::bootstrap::merge_area_bid_schedule(
+            bid_year_schedule,
+            area_override,
+        ))
+    }
+
+    /// Queries whether any bid year is in the `BiddingActive` lifecycle state.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(year))` if a bid year is `BiddingActive`
+    /// * `Ok(None)` if no bid year is `BiddingActive`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn get_bidding_active_year(&mut self) -> Result<Option<u16>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::canonical::get_bidding_active_year_sqlite(conn)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::canonical::get_bidding_active_year_mysql(conn)
+            }
+        }
+    }
+
+    // ========================================================================
+    // Canonical ID Lookups (Test Support)
+    // ========================================================================
+
+    /// Queries the canonical `bid_year_id` for a given year.
+    /// Get the year value for a given canonical bid year ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID to query
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bid year is not found or the query fails.
+    pub fn get_bid_year_from_id(&mut self, bid_year_id: i64) -> Result<u16, PersistenceError> {
+        use diesel_schema::bid_years;
+
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let result: Result<i32, diesel::result::Error> = bid_years::table
+                    .select(bid_years::year)
+                    .filter(bid_years::bid_year_id.eq(bid_year_id))
+                    .first::<i32>(conn);
+
+                match result {
+                    Ok(year) => Ok(u16::try_from(year).map_err(|e| {
+                        PersistenceError::Other(format!("Invalid year value: {e}"))
+                    })?),
+                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
+                        format!("Bid year with ID {bid_year_id} does not exist"),
+                    )),
+                    Err(e) => Err(PersistenceError::from(e)),
+                }
+            }
+            BackendConnection::Mysql(conn) => {
+                let result: Result<i32, diesel::result::Error> = bid_years::table
+                    .select(bid_years::year)
+                    .filter(bid_years::bid_year_id.eq(bid_year_id))
+                    .first::<i32>(conn);
+
+                match result {
+                    Ok(year) => Ok(u16::try_from(year).map_err(|e| {
+                        PersistenceError::Other(format!("Invalid year value: {e}"))
+                    })?),
+                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
+                        format!("Bid year with ID {bid_year_id} does not exist"),
+                    )),
+                    Err(e) => Err(PersistenceError::from(e)),
+                }
+            }
+        }
+    }
+
+    /// Get the canonical bid year ID for a given year.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year to query
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bid year is not found or the query fails.
+    pub fn get_bid_year_id(&mut self, year: u16) -> Result<i64, PersistenceError> {
+        use diesel_schema::bid_years;
+
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let result: Result<i64, diesel::result::Error> = bid_years::table
+                    .select(bid_years::bid_year_id)
+                    .filter(bid_years::year.eq(i32::from(year)))
+                    .first::<i64>(conn);
+
+                match result {
+                    Ok(id) => Ok(id),
+                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
+                        format!("Bid year {year} does not exist"),
+                    )),
+                    Err(e) => Err(PersistenceError::from(e)),
+                }
+            }
+            BackendConnection::Mysql(conn) => {
+                let result: Result<i64, diesel::result::Error> = bid_years::table
+                    .select(bid_years::bid_year_id)
+                    .filter(bid_years::year.eq(i32::from(year)))
+                    .first::<i64>(conn);
+
+                match result {
+                    Ok(id) => Ok(id),
+                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
+                        format!("Bid year {year} does not exist"),
+                    )),
+                    Err(e) => Err(PersistenceError::from(e)),
+                }
+            }
+        }
+    }
+
+    /// Queries the canonical `area_id` for a given bid year and area code.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year identifier
+    /// * `area_code` - The area code
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area is not found or the query fails.
+    pub fn get_area_id(
+        &mut self,
+        bid_year_id: i64,
+        area_code: &str,
+    ) -> Result<i64, PersistenceError> {
+        use diesel_schema::areas;
+
+        let normalized_code: String = area_code.to_uppercase();
+
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                let result: Result<i64, diesel::result::Error> = areas::table
+                    .select(areas::area_id)
+                    .filter(areas::bid_year_id.eq(bid_year_id))
+                    .filter(areas::area_code.eq(&normalized_code))
+                    .first::<i64>(conn);
+
+                match result {
+                    Ok(id) => Ok(id),
+                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
+                        format!("Area {area_code} does not exist"),
+                    )),
+                    Err(e) => Err(PersistenceError::from(e)),
+                }
+            }
+            BackendConnection::Mysql(conn) => {
+                let result: Result<i64, diesel::result::Error> = areas::table
+                    .select(areas::area_id)
+                    .filter(areas::bid_year_id.eq(bid_year_id))
+                    .filter(areas::area_code.eq(&normalized_code))
+                    .first::<i64>(conn);
+
+                match result {
+                    Ok(id) => Ok(id),
+                    Err(diesel::result::Error::NotFound) => Err(PersistenceError::NotFound(
+                        format!("Area {area_code} does not exist"),
+                    )),
+                    Err(e) => Err(PersistenceError::from(e)),
+                }
+            }
+        }
+    }
+
+    /// Canonicalizes a bid year by populating canonical data tables.
+    ///
+    /// This function persists the audit event and creates canonical rows for:
+    /// - Area membership
+    /// - Eligibility
+    /// - Bid order (NULL until computed)
+    /// - Bid windows (NULL until computed)
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year to canonicalize
+    /// * `audit_event` - The audit event recording canonicalization
+    ///
+    /// # Returns
+    ///
+    /// The `event_id` of the persisted audit event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any database operation fails.
+    pub fn canonicalize_bid_year(
+        &mut self,
+        bid_year_id: i64,
+        audit_event: &zab_bid_audit::AuditEvent,
+    ) -> Result<i64, PersistenceError> {
+        let now: time::OffsetDateTime = self.clock.now();
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::bootstrap::canonicalize_bid_year_sqlite(
+                conn,
+                bid_year_id,
+                audit_event,
+                now,
+                ulids,
+            ),
+            BackendConnection::Mysql(conn) => mutations::bootstrap::canonicalize_bid_year_mysql(
+                conn,
+                bid_year_id,
+                audit_event,
+                now,
+                ulids,
+            ),
+        }
+    }
+
+    /// Reopens a canonicalized bid year, returning it to `BootstrapComplete`.
+    ///
+    /// In one transaction this checks that no bids have been entered,
+    /// deletes every canonical row and the bid windows, bid status, and
+    /// current bidders derived from them, records the audit event, and
+    /// updates the lifecycle state.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year to reopen
+    /// * `audit_event` - The audit event recording the reopen
+    ///
+    /// # Returns
+    ///
+    /// The `event_id` of the audit event and the number of rows deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BidYearHasBids` if a leave bid or bid preference has been
+    /// entered, or an error if any database operation fails. Nothing is
+    /// changed in either case.
+    pub fn reopen_bid_year(
+        &mut self,
+        bid_year_id: i64,
+        audit_event: &zab_bid_audit::AuditEvent,
+    ) -> Result<(i64, usize), PersistenceError> {
+        let lifecycle_state: &str = zab_bid_domain::BidYearLifecycle::BootstrapComplete.as_str();
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                let bids: usize = queries::canonical::count_entered_bids_sqlite(conn, bid_year_id)?;
+                if bids > 0 {
+                    return Err(PersistenceError::BidYearHasBids { bid_year_id, bids });
+                }
+                let deleted: usize = mutations::clear_canonical_bid_year_sqlite(conn, bid_year_id)?;
+                let event_id: i64 =
+                    mutations::persist_audit_event_sqlite(conn, audit_event, ulids)?;
+                queries::canonical::update_lifecycle_state_sqlite(
+                    conn,
+                    bid_year_id,
+                    lifecycle_state,
+                )?;
+                Ok((event_id, deleted))
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                let bids: usize = queries::canonical::count_entered_bids_mysql(conn, bid_year_id)?;
+                if bids > 0 {
+                    return Err(PersistenceError::BidYearHasBids { bid_year_id, bids });
+                }
+                let deleted: usize = mutations::clear_canonical_bid_year_mysql(conn, bid_year_id)?;
+                let event_id: i64 = mutations::persist_audit_event_mysql(conn, audit_event, ulids)?;
+                queries::canonical::update_lifecycle_state_mysql(
+                    conn,
+                    bid_year_id,
+                    lifecycle_state,
+                )?;
+                Ok((event_id, deleted))
+            }),
+        }
+    }
+
+    /// Lists users with lifecycle-aware routing.
+    ///
+    /// Phase 25C: Routes reads to canonical or derived tables based on lifecycle state.
+    ///
+    /// When `lifecycle_state >= Canonicalized`, reads come from canonical tables.
+    /// When `lifecycle_state < Canonicalized`, reads come from the users table.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    /// * `bid_year` - The `BidYear` domain object
+    /// * `area` - The Area domain object
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database cannot be queried
+    /// - Canonical data is missing when lifecycle >= Canonicalized
+    pub fn list_users_with_routing(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        bid_year: &BidYear,
+        area: &Area,
+    ) -> Result<Vec<User>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::canonical::list_users_with_routing_sqlite(
+                conn,
+                bid_year_id,
+                area_id,
+                bid_year,
+                area,
+            ),
+            BackendConnection::Mysql(conn) => queries::canonical::list_users_with_routing_mysql(
+                conn,
+                bid_year_id,
+                area_id,
+                bid_year,
+                area,
+            ),
+        }
+    }
+
+    /// Lists an area's canonical seniority list in bid order.
+    ///
+    /// Users without a materialized bid order are omitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn list_seniority_list(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+    ) -> Result<Vec<SeniorityListEntryData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::canonical::list_seniority_list_sqlite(conn, bid_year_id, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::canonical::list_seniority_list_mysql(conn, bid_year_id, area_id)
+            }
+        }
+    }
+
+    /// Lists an area's results for one round in bid order.
+    ///
+    /// Each user on the seniority list is paired with their bid status and
+    /// bid window for the round, when present.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    /// * `round_id` - The round ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn list_round_results(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        round_id: i64,
+    ) -> Result<Vec<RoundResultEntryData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::canonical::list_round_results_sqlite(conn, bid_year_id, area_id, round_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::canonical::list_round_results_mysql(conn, bid_year_id, area_id, round_id)
+            }
+        }
+    }
+
+    /// Records a day of approved leave for a user within a round.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    /// * `user_id` - The canonical user ID
+    /// * `round_id` - The round the leave was bid in
+    /// * `leave_date` - The leave date (`YYYY-MM-DD`)
+    /// * `hours` - The leave hours charged for the day
+    /// * `on_behalf_of` - The initials of the controller the bid was entered for
+    /// * `received_via` - How the bid was received (`phone`, `in_person`, `written`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the leave cannot be recorded.
+    #[allow(clippy::too_many_arguments)]
+    pub fn insert_leave_bid(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        user_id: i64,
+        round_id: i64,
+        leave_date: &str,
+        hours: i32,
+        on_behalf_of: &str,
+        received_via: &str,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::insert_leave_bid_sqlite(
+                conn,
+                bid_year_id,
+                area_id,
+                user_id,
+                round_id,
+                leave_date,
+                hours,
+                on_behalf_of,
+                received_via,
+            ),
+            BackendConnection::Mysql(conn) => mutations::insert_leave_bid_mysql(
+                conn,
+                bid_year_id,
+                area_id,
+                user_id,
+                round_id,
+                leave_date,
+                hours,
+                on_behalf_of,
+                received_via,
+            ),
+        }
+    }
+
+    /// Records a controller's leave days and the audit event that entered
+    /// them in one transaction.
+    ///
+    /// Each day's approved leave is counted inside the transaction, so two
+    /// bids racing for a day's last slot cannot both be recorded. Nothing
+    /// is written if any day is full.
+    ///
+    /// Returns the leave bid IDs in the order of `bids.days` and the
+    /// audit event ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LeaveSlotsExhausted` if a day has no leave slots remaining,
+    /// or an error if the database operation fails.
+    pub fn record_leave_bids(
+        &mut self,
+        audit_event: &zab_bid_audit::AuditEvent,
+        bids: &NewLeaveBids,
+    ) -> Result<(Vec<i64>, i64), PersistenceError> {
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                let mut leave_bid_ids: Vec<i64> = Vec::with_capacity(bids.days.len());
+                for (leave_date, capacity) in &bids.days {
+                    let approved: i64 = queries::leave::count_approved_leave_sqlite(
+                        conn,
+                        bids.area_id,
+                        bids.round_id,
+                        leave_date,
+                        bids.slot_crew,
+                    )?;
+                    if approved >= i64::from(*capacity) {
+                        return Err(PersistenceError::LeaveSlotsExhausted {
+                            round_id: bids.round_id,
+                            leave_date: leave_date.clone(),
+                        });
+                    }
+                    leave_bid_ids.push(mutations::insert_leave_bid_sqlite(
+                        conn,
+                        bids.bid_year_id,
+                        bids.area_id,
+                        bids.user_id,
+                        bids.round_id,
+                        leave_date,
+                        bids.hours,
+                        &bids.on_behalf_of,
+                        &bids.received_via,
+                    )?);
+                }
+                let event_id: i64 =
+                    mutations::persist_audit_event_sqlite(conn, audit_event, ulids)?;
+                Ok((leave_bid_ids, event_id))
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                let mut leave_bid_ids: Vec<i64> = Vec::with_capacity(bids.days.len());
+                for (leave_date, capacity) in &bids.days {
+                    let approved: i64 = queries::leave::count_approved_leave_mysql(
+                        conn,
+                        bids.area_id,
+                        bids.round_id,
+                        leave_date,
+                        bids.slot_crew,
+                    )?;
+                    if approved >= i64::from(*capacity) {
+                        return Err(PersistenceError::LeaveSlotsExhausted {
+                            round_id: bids.round_id,
+                            leave_date: leave_date.clone(),
+                        });
+                    }
+                    leave_bid_ids.push(mutations::insert_leave_bid_mysql(
+                        conn,
+                        bids.bid_year_id,
+                        bids.area_id,
+                        bids.user_id,
+                        bids.round_id,
+                        leave_date,
+                        bids.hours,
+                        &bids.on_behalf_of,
+                        &bids.received_via,
+                    )?);
+                }
+                let event_id: i64 = mutations::persist_audit_event_mysql(conn, audit_event, ulids)?;
+                Ok((leave_bid_ids, event_id))
+            }),
+        }
+    }
+
+    /// Marks a leave bid as withdrawn so it no longer occupies a daily slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn withdraw_leave_bid(&mut self, leave_bid_id: i64) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::withdraw_leave_bid_sqlite(conn, leave_bid_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::withdraw_leave_bid_mysql(conn, leave_bid_id)
+            }
+        }
+    }
+
+    /// Lists the leave bids for an area, ordered by date, round, and ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_leave_bids(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+    ) -> Result<Vec<LeaveBidData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::leave::list_leave_bids_sqlite(conn, bid_year_id, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::leave::list_leave_bids_mysql(conn, bid_year_id, area_id)
+            }
+        }
+    }
+
+    /// Counts approved leave per day, area, round, and crew over a date range.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `start_date` - The first date to include (`YYYY-MM-DD`)
+    /// * `end_date` - The last date to include (`YYYY-MM-DD`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_daily_leave_counts(
+        &mut self,
+        bid_year_id: i64,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<DailyLeaveCountData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::leave::list_daily_leave_counts_sqlite(
+                conn,
+                bid_year_id,
+                start_date,
+                end_date,
+            ),
+            BackendConnection::Mysql(conn) => queries::leave::list_daily_leave_counts_mysql(
+                conn,
+                bid_year_id,
+                start_date,
+                end_date,
+            ),
+        }
+    }
+
+    /// Override a user's area assignment after canonicalization.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `user_id` - The canonical user ID
+    /// * `new_area_id` - The new area ID to assign
+    /// * `reason` - The reason for the override
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of (`previous_area_id`, `was_already_overridden`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the canonical record does not exist or the database operation fails.
+    pub fn override_area_assignment(
+        &mut self,
+        bid_year_id: i64,
+        user_id: i64,
+        new_area_id: i64,
+        reason: &str,
+    ) -> Result<(i64, bool), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::canonical::override_area_assignment_sqlite(
+                    conn,
+                    bid_year_id,
+                    user_id,
+                    new_area_id,
+                    reason,
+                )
+            }
+            BackendConnection::Mysql(conn) => mutations::canonical::override_area_assignment_mysql(
+                conn,
+                bid_year_id,
+                user_id,
+                new_area_id,
+                reason,
+            ),
+        }
+    }
+
+    /// Override a user's eligibility after canonicalization.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `user_id` - The canonical user ID
+    /// * `can_bid` - The new eligibility status
+    /// * `reason` - The reason for the override
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of (`previous_can_bid`, `was_already_overridden`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the canonical record does not exist or the database operation fails.
+    pub fn override_eligibility(
+        &mut self,
+        bid_year_id: i64,
+        user_id: i64,
+        can_bid: bool,
+        reason: &str,
+    ) -> Result<(bool, bool), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::canonical::override_eligibility_sqlite(
+                conn,
+                bid_year_id,
+                user_id,
+                can_bid,
+                reason,
+            ),
+            BackendConnection::Mysql(conn) => mutations::canonical::override_eligibility_mysql(
+                conn,
+                bid_year_id,
+                user_id,
+                can_bid,
+                reason,
+            ),
+        }
+    }
+
+    /// Override a user's bid order after canonicalization.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `user_id` - The canonical user ID
+    /// * `bid_order` - The new bid order (or `None` to clear)
+    /// * `reason` - The reason for the override
+    ///
+    /// Bulk inserts canonical bid order records.
+    ///
+    /// # Arguments
+    ///
+    /// * `records` - The canonical bid order records to insert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn bulk_insert_canonical_bid_order(
+        &mut self,
+        records: &[data_models::NewCanonicalBidOrder],
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::canonical::bulk_insert_canonical_bid_order_sqlite(conn, records)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::canonical::bulk_insert_canonical_bid_order_mysql(conn, records)
+            }
+        }
+    }
+
+    /// Bulk inserts bid window records.
+    ///
+    /// # Arguments
+    ///
+    /// * `records` - The bid window records to insert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn bulk_insert_bid_windows(
+        &mut self,
+        records: &[data_models::NewBidWindow],
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::canonical::bulk_insert_bid_windows_sqlite(conn, records)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::canonical::bulk_insert_bid_windows_mysql(conn, records)
+            }
+        }
+    }
+
+    /// Lists all rounds for a given bid year (across all round groups).
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_all_rounds_for_bid_year(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<(i64, String)>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::rounds::list_all_rounds_for_bid_year_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::rounds::list_all_rounds_for_bid_year_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Bulk inserts bid status records (used at confirmation).
+    ///
+    /// # Arguments
+    ///
+    /// * `records` - The bid status records to insert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails.
+    pub fn bulk_insert_bid_status(
+        &mut self,
+        records: &[data_models::NewBidStatus],
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::bid_status::bulk_insert_bid_status_sqlite(conn, records)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::bid_status::bulk_insert_bid_status_mysql(conn, records)
+            }
+        }
+    }
+
+    /// # Returns
+    ///
+    /// Returns a tuple of (`previous_bid_order`, `was_already_overridden`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the canonical record does not exist or the database operation fails.
+    pub fn override_bid_order(
+        &mut self,
+        bid_year_id: i64,
+        user_id: i64,
+        bid_order: Option<i32>,
+        reason: &str,
+    ) -> Result<(Option<i32>, bool), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::canonical::override_bid_order_sqlite(
+                conn,
+                bid_year_id,
+                user_id,
+                bid_order,
+                reason,
+            ),
+            BackendConnection::Mysql(conn) => mutations::canonical::override_bid_order_mysql(
+                conn,
+                bid_year_id,
+                user_id,
+                bid_order,
+                reason,
+            ),
+        }
+    }
+
+    /// Override a user's bid window after canonicalization.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `user_id` - The canonical user ID
+    /// * `window_start` - The new window start date (or `None` to clear)
+    /// * `window_end` - The new window end date (or `None` to clear)
+    /// * `reason` - The reason for the override
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of (`previous_window_start`, `previous_window_end`, `was_already_overridden`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the canonical record does not exist or the database operation fails.
+    pub fn override_bid_window(
+        &mut self,
+        bid_year_id: i64,
+        user_id: i64,
+        window_start: Option<&String>,
+        window_end: Option<&String>,
+        reason: &str,
+    ) -> Result<(Option<String>, Option<String>, bool), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::canonical::override_bid_window_sqlite(
+                conn,
+                bid_year_id,
+                user_id,
+                window_start,
+                window_end,
+                reason,
+            ),
+            BackendConnection::Mysql(conn) => mutations::canonical::override_bid_window_mysql(
+                conn,
+                bid_year_id,
+                user_id,
+                window_start,
+                window_end,
+                reason,
+            ),
+        }
+    }
+
+    /// Records an applied override in the override ledger.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `user_id` - The canonical user ID
+    /// * `previous_value` - The canonical value before the override
+    /// * `new_value` - The canonical value after the override
+    /// * `reason` - The reason given for the override
+    /// * `audit_event_id` - The audit event that recorded the override
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn record_override(
+        &mut self,
+        bid_year_id: i64,
+        user_id: i64,
+        previous_value: &OverrideValue,
+        new_value: &OverrideValue,
+        reason: &str,
+        audit_event_id: i64,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::insert_canonical_override_sqlite(
+                conn,
+                bid_year_id,
+                user_id,
+                previous_value,
+                new_value,
+                reason,
+                audit_event_id,
+            ),
+            BackendConnection::Mysql(conn) => mutations::insert_canonical_override_mysql(
+                conn,
+                bid_year_id,
+                user_id,
+                previous_value,
+                new_value,
+                reason,
+                audit_event_id,
+            ),
+        }
+    }
+
+    /// Lists every override recorded for a bid year, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_overrides(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<CanonicalOverrideData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::overrides::list_canonical_overrides_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::overrides::list_canonical_overrides_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Gets a single override from the override ledger.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::NotFound` if the override does not exist.
+    pub fn get_override(
+        &mut self,
+        override_id: i64,
+    ) -> Result<CanonicalOverrideData, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::overrides::get_canonical_override_sqlite(conn, override_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::overrides::get_canonical_override_mysql(conn, override_id)
+            }
+        }
+    }
+
+    /// Lists the canonical bid order of every user in a bid year, as
+    /// (`user_id`, `bid_order`) pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_canonical_bid_orders(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<(i64, Option<i32>)>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::overrides::list_canonical_bid_orders_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::overrides::list_canonical_bid_orders_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Reverts an override, restoring the canonical value it replaced.
+    ///
+    /// Only the most recent unreverted override of a kind for a user should
+    /// be reverted; earlier overrides remain in effect afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn revert_override(
+        &mut self,
+        record: &CanonicalOverrideData,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::revert_canonical_override_sqlite(
+                conn,
+                record.override_id,
+                record.bid_year_id,
+                record.user_id,
+                &record.previous_value,
+            ),
+            BackendConnection::Mysql(conn) => mutations::revert_canonical_override_mysql(
+                conn,
+                record.override_id,
+                record.bid_year_id,
+                record.user_id,
+                &record.previous_value,
+            ),
+        }
+    }
+
+    /// Merges a duplicate user record into the record that survives,
+    /// persisting the audit event that records the merge in the same
+    /// transaction.
+    ///
+    /// Callers check beforehand that the records' bids do not collide.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The audit event recording the merge
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `keep` - The surviving user
+    /// * `merge` - The duplicate user
+    /// * `reason` - Why the records are merged
+    ///
+    /// # Returns
+    ///
+    /// The merge's audit event ID and its ID in the user merge ledger.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails. Nothing is
+    /// persisted in that case.
+    pub fn merge_users(
+        &mut self,
+        event: &AuditEvent,
+        bid_year_id: i64,
+        keep: i64,
+        merge: i64,
+        reason: &str,
+    ) -> Result<(i64, i64), PersistenceError> {
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                let event_id: i64 = mutations::persist_audit_event_sqlite(conn, event, ulids)?;
+                let user_merge_id: i64 = mutations::user_merges::merge_user_records_sqlite(
+                    conn,
+                    bid_year_id,
+                    keep,
+                    merge,
+                    reason,
+                    event_id,
+                )?;
+                Ok((event_id, user_merge_id))
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                let event_id: i64 = mutations::persist_audit_event_mysql(conn, event, ulids)?;
+                let user_merge_id: i64 = mutations::user_merges::merge_user_records_mysql(
+                    conn,
+                    bid_year_id,
+                    keep,
+                    merge,
+                    reason,
+                    event_id,
+                )?;
+                Ok((event_id, user_merge_id))
+            }),
+        }
+    }
+
+    /// Reverts a user merge, persisting the audit event that records the
+    /// revert in the same transaction.
+    ///
+    /// # Returns
+    ///
+    /// The revert's audit event ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails. Nothing is
+    /// persisted in that case.
+    pub fn revert_user_merge(
+        &mut self,
+        record: &UserMergeData,
+        event: &AuditEvent,
+    ) -> Result<i64, PersistenceError> {
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                let event_id: i64 = mutations::persist_audit_event_sqlite(conn, event, ulids)?;
+                mutations::user_merges::revert_user_merge_records_sqlite(conn, record, event_id)?;
+                Ok(event_id)
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                let event_id: i64 = mutations::persist_audit_event_mysql(conn, event, ulids)?;
+                mutations::user_merges::revert_user_merge_records_mysql(conn, record, event_id)?;
+                Ok(event_id)
+            }),
+        }
+    }
+
+    /// Lists every user merge recorded for a bid year, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_user_merges(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<UserMergeData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::user_merges::list_user_merges_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::user_merges::list_user_merges_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Gets a single merge from the user merge ledger.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::NotFound` if the merge does not exist.
+    pub fn get_user_merge(
+        &mut self,
+        user_merge_id: i64,
+    ) -> Result<UserMergeData, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::user_merges::get_user_merge_sqlite(conn, user_merge_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::user_merges::get_user_merge_mysql(conn, user_merge_id)
+            }
+        }
+    }
+
+    /// Lists the merges in force that a user takes part in, as either the
+    /// surviving or the merged record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_active_user_merges_for_user(
+        &mut self,
+        user_id: i64,
+    ) -> Result<Vec<UserMergeData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::user_merges::list_active_user_merges_for_user_sqlite(conn, user_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::user_merges::list_active_user_merges_for_user_mysql(conn, user_id)
+            }
+        }
+    }
+
+    /// Describes the bids that would collide if `merge`'s bids were
+    /// reassigned to `keep`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn find_user_merge_conflicts(
+        &mut self,
+        keep: i64,
+        merge: i64,
+    ) -> Result<Vec<String>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::user_merges::find_user_merge_conflicts_sqlite(conn, keep, merge)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::user_merges::find_user_merge_conflicts_mysql(conn, keep, merge)
+            }
+        }
+    }
+
+    /// Changes a user's initials, persisting the audit event that records
+    /// the change and an alias for the previous initials in the same
+    /// transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The audit event recording the change
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `user_id` - The user whose initials change
+    /// * `from` - The user's current initials
+    /// * `to` - The user's new initials
+    /// * `effective_date` - The date the new initials take effect (`YYYY-MM-DD`)
+    ///
+    /// # Returns
+    ///
+    /// The change's audit event ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not hold `from` or the database
+    /// operation fails. Nothing is persisted in that case.
+    pub fn change_user_initials(
+        &mut self,
+        event: &AuditEvent,
+        bid_year_id: i64,
+        user_id: i64,
+        from: &str,
+        to: &str,
+        effective_date: &str,
+    ) -> Result<i64, PersistenceError> {
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                let event_id: i64 = mutations::persist_audit_event_sqlite(conn, event, ulids)?;
+                mutations::initials_aliases::change_user_initials_sqlite(
+                    conn,
+                    bid_year_id,
+                    user_id,
+                    from,
+                    to,
+                    effective_date,
+                    event_id,
+                )?;
+                Ok(event_id)
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                let event_id: i64 = mutations::persist_audit_event_mysql(conn, event, ulids)?;
+                mutations::initials_aliases::change_user_initials_mysql(
+                    conn,
+                    bid_year_id,
+                    user_id,
+                    from,
+                    to,
+                    effective_date,
+                    event_id,
+                )?;
+                Ok(event_id)
+            }),
+        }
+    }
+
+    /// Lists the initials a user held before their current ones, oldest
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_initials_aliases(
+        &mut self,
+        user_id: i64,
+    ) -> Result<Vec<InitialsAliasData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::initials_aliases::list_initials_aliases_sqlite(conn, user_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::initials_aliases::list_initials_aliases_mysql(conn, user_id)
+            }
+        }
+    }
+
+    /// Resolves current or retired initials to the user of a bid year who
+    /// holds or held them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn resolve_user_initials(
+        &mut self,
+        bid_year_id: i64,
+        initials: &str,
+    ) -> Result<Option<i64>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::initials_aliases::resolve_user_initials_sqlite(conn, bid_year_id, initials)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::initials_aliases::resolve_user_initials_mysql(conn, bid_year_id, initials)
+            }
+        }
+    }
+
+    /// Erases a user's personal data and records the erasure.
+    ///
+    /// Names and seniority dates are replaced with tombstone values in the
+    /// users table and in every stored snapshot of the bid year; the user's
+    /// email contact is removed. Initials, bids, and the audit log are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The audit event recording the erasure
+    /// * `bid_year_id` - The user's bid year
+    /// * `user_id` - The user to anonymize
+    /// * `held_initials` - Every initials the user has held in the bid year
+    ///
+    /// # Returns
+    ///
+    /// The erasure's audit event ID and the number of snapshots rewritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not exist, a snapshot cannot be
+    /// decoded, or the database operation fails. Nothing is persisted in
+    /// that case.
+    pub fn anonymize_user(
+        &mut self,
+        event: &AuditEvent,
+        bid_year_id: i64,
+        user_id: i64,
+        held_initials: &[String],
+    ) -> Result<(i64, usize), PersistenceError> {
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                let event_id: i64 = mutations::persist_audit_event_sqlite(conn, event, ulids)?;
+                let rewritten: usize = mutations::anonymization::anonymize_user_sqlite(
+                    conn,
+                    bid_year_id,
+                    user_id,
+                    held_initials,
+                    event_id,
+                )?;
+                Ok((event_id, rewritten))
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                let event_id: i64 = mutations::persist_audit_event_mysql(conn, event, ulids)?;
+                let rewritten: usize = mutations::anonymization::anonymize_user_mysql(
+                    conn,
+                    bid_year_id,
+                    user_id,
+                    held_initials,
+                    event_id,
+                )?;
+                Ok((event_id, rewritten))
+            }),
+        }
+    }
+
+    /// Gets the erasure record of a user, if their data was erased.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_user_anonymization(
+        &mut self,
+        user_id: i64,
+    ) -> Result<Option<UserAnonymizationData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::anonymization::get_user_anonymization_sqlite(conn, user_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::anonymization::get_user_anonymization_mysql(conn, user_id)
+            }
+        }
+    }
+
+    /// Get user details for override operations.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The canonical user ID
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of (`bid_year_id`, `user_initials`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not exist or the database operation fails.
+    pub fn get_user_details(&mut self, user_id: i64) -> Result<(i64, String), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::canonical::get_user_details_for_override_sqlite(conn, user_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::canonical::get_user_details_for_override_mysql(conn, user_id)
+            }
+        }
+    }
+
+    /// Get the area ID for a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The canonical user ID
+    ///
+    /// # Returns
+    ///
+    /// Returns the `area_id` where the user is currently assigned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not exist or the database operation fails.
+    pub fn get_user_area_id(&mut self, user_id: i64) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::canonical::get_user_area_id_sqlite(conn, user_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::canonical::get_user_area_id_mysql(conn, user_id)
+            }
+        }
+    }
+
+    /// Get area details for override operations.
+    ///
+    /// # Arguments
+    ///
+    /// * `area_id` - The canonical area ID
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of (`area_code`, `area_name`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area does not exist or the database operation fails.
+    pub fn get_area_details(
+        &mut self,
+        area_id: i64,
+    ) -> Result<(String, Option<String>), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::canonical::get_area_details_for_override_sqlite(conn, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::canonical::get_area_details_for_override_mysql(conn, area_id)
+            }
+        }
+    }
+
+    /// Get current canonical area assignment for a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `user_id` - The canonical user ID
+    ///
+    /// # Returns
+    ///
+    /// Returns the current `area_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the canonical record does not exist or the database operation fails.
+    pub fn get_current_area_assignment(
+        &mut self,
+        bid_year_id: i64,
+        user_id: i64,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::canonical::get_current_area_assignment_for_override_sqlite(
+                    conn,
+                    bid_year_id,
+                    user_id,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::canonical::get_current_area_assignment_for_override_mysql(
+                    conn,
+                    bid_year_id,
+                    user_id,
+                )
+            }
+        }
+    }
+
+    // ========================================================================
+    // Phase 29B: Round Groups and Rounds
+    // ========================================================================
+
+    /// Lists all round groups for a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_round_groups(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<RoundGroup>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::rounds::list_round_groups_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::rounds::list_round_groups_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Gets a single round group by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_group_id` - The round group ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the round group does not exist or the query fails.
+    pub fn get_round_group(&mut self, round_group_id: i64) -> Result<RoundGroup, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::rounds::get_round_group_sqlite(conn, round_group_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::rounds::get_round_group_mysql(conn, round_group_id)
+            }
+        }
+    }
+
+    /// Inserts a new round group.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `name` - The round group name
+    /// * `editing_enabled` - Whether editing is enabled
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails.
+    pub fn insert_round_group(
+        &mut self,
+        bid_year_id: i64,
+        name: &str,
+        editing_enabled: bool,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::rounds::insert_round_group_sqlite(conn, bid_year_id, name, editing_enabled)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::rounds::insert_round_group_mysql(conn, bid_year_id, name, editing_enabled)
+            }
+        }
+    }
+
+    /// Updates an existing round group.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_group_id` - The round group ID
+    /// * `name` - The new name
+    /// * `editing_enabled` - The new `editing_enabled` value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update fails.
+    pub fn update_round_group(
+        &mut self,
+        round_group_id: i64,
+        name: &str,
+        editing_enabled: bool,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::rounds::update_round_group_sqlite(
+                conn,
+                round_group_id,
+                name,
+                editing_enabled,
+            ),
+            BackendConnection::Mysql(conn) => queries::rounds::update_round_group_mysql(
+                conn,
+                round_group_id,
+                name,
+                editing_enabled,
+            ),
+        }
+    }
+
+    /// Deletes a round group.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_group_id` - The round group ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails.
+    pub fn delete_round_group(&mut self, round_group_id: i64) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::rounds::delete_round_group_sqlite(conn, round_group_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::rounds::delete_round_group_mysql(conn, round_group_id)
+            }
+        }
+    }
+
+    /// Checks if a round group is referenced by any rounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_group_id` - The round group ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn count_rounds_using_group(
+        &mut self,
+        round_group_id: i64,
+    ) -> Result<usize, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::rounds::count_rounds_using_group_sqlite(conn, round_group_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::rounds::count_rounds_using_group_mysql(conn, round_group_id)
+            }
+        }
+    }
+
+    /// Checks if a round group name exists within a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `name` - The round group name
+    /// * `exclude_id` - Optional round group ID to exclude from the check
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn round_group_name_exists(
+        &mut self,
+        bid_year_id: i64,
+        name: &str,
+        exclude_id: Option<i64>,
+    ) -> Result<bool, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::rounds::round_group_name_exists_sqlite(conn, bid_year_id, name, exclude_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::rounds::round_group_name_exists_mysql(conn, bid_year_id, name, exclude_id)
+            }
+        }
+    }
+
+    /// Lists all rounds for a given round group.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_group_id` - The round group ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_rounds(&mut self, round_group_id: i64) -> Result<Vec<Round>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::rounds::list_rounds_sqlite(conn, round_group_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::rounds::list_rounds_mysql(conn, round_group_id)
+            }
+        }
+    }
+
+    /// Gets a single round by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_id` - The round ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the round does not exist or the query fails.
+    pub fn get_round(&mut self, round_id: i64) -> Result<Round, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::rounds::get_round_sqlite(conn, round_id),
+            BackendConnection::Mysql(conn) => queries::rounds::get_round_mysql(conn, round_id),
+        }
+    }
+
+    /// Inserts a new round.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_group_id` - The round group ID
+    /// * `round_number` - The round number
+    /// * `name` - The round name
+    /// * `slots_per_day` - Slots per day
+    /// * `max_groups` - Maximum groups
+    /// * `max_total_hours` - Maximum total hours
+    /// * `include_holidays` - Whether holidays are included
+    /// * `allow_overbid` - Whether overbidding is allowed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails.
+    #[allow(clippy::too_many_arguments)]
+    pub fn insert_round(
+        &mut self,
+        round_group_id: i64,
+        round_number: u32,
+        name: &str,
+        slots_per_day: u32,
+        max_groups: u32,
+        max_total_hours: u32,
+        include_holidays: bool,
+        allow_overbid: bool,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::rounds::insert_round_sqlite(
+                conn,
+                round_group_id,
+                round_number,
+                name,
+                slots_per_day,
+                max_groups,
+                max_total_hours,
+                include_holidays,
+                allow_overbid,
+            ),
+            BackendConnection::Mysql(conn) => queries::rounds::insert_round_mysql(
+                conn,
+                round_group_id,
+                round_number,
+                name,
+                slots_per_day,
+                max_groups,
+                max_total_hours,
+                include_holidays,
+                allow_overbid,
+            ),
+        }
+    }
+
+    /// Updates an existing round.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_id` - The round ID
+    /// * `name` - The new name
+    /// * `slots_per_day` - The new `slots_per_day`
+    /// * `max_groups` - The new `max_groups`
+    /// * `max_total_hours` - The new `max_total_hours`
+    /// * `include_holidays` - The new `include_holidays`
+    /// * `allow_overbid` - The new `allow_overbid`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update fails.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_round(
+        &mut self,
+        round_id: i64,
+        name: &str,
+        slots_per_day: u32,
+        max_groups: u32,
+        max_total_hours: u32,
+        include_holidays: bool,
+        allow_overbid: bool,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::rounds::update_round_sqlite(
+                conn,
+                round_id,
+                name,
+                slots_per_day,
+                max_groups,
+                max_total_hours,
+                include_holidays,
+                allow_overbid,
+            ),
+            BackendConnection::Mysql(conn) => queries::rounds::update_round_mysql(
+                conn,
+                round_id,
+                name,
+                slots_per_day,
+                max_groups,
+                max_total_hours,
+                include_holidays,
+                allow_overbid,
+            ),
+        }
+    }
+
+    /// Deletes a round.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_id` - The round ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails.
+    pub fn delete_round(&mut self, round_id: i64) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::rounds::delete_round_sqlite(conn, round_id),
+            BackendConnection::Mysql(conn) => queries::rounds::delete_round_mysql(conn, round_id),
+        }
+    }
+
+    /// Renumbers the rounds of a round group to match the given order.
+    ///
+    /// The first ID becomes round 1, the second round 2, and so on. The
+    /// renumbering is atomic and never violates round number uniqueness.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_group_id` - The round group ID
+    /// * `ordered_ids` - Every round ID of the group, in the new order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `ordered_ids` is not exactly the group's rounds,
+    /// or if an update fails.
+    pub fn reorder_rounds(
+        &mut self,
+        round_group_id: i64,
+        ordered_ids: &[i64],
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::rounds::reorder_rounds_sqlite(conn, round_group_id, ordered_ids)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::rounds::reorder_rounds_mysql(conn, round_group_id, ordered_ids)
+            }
+        }
+    }
+
+    /// Checks if a round number exists within a round group.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_group_id` - The round group ID
+    /// * `round_number` - The round number
+    /// * `exclude_id` - Optional round ID to exclude from the check
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn round_number_exists(
+        &mut self,
+        round_group_id: i64,
+        round_number: u32,
+        exclude_id: Option<i64>,
+    ) -> Result<bool, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::rounds::round_number_exists_sqlite(
+                conn,
+                round_group_id,
+                round_number,
+                exclude_id,
+            ),
+            BackendConnection::Mysql(conn) => queries::rounds::round_number_exists_mysql(
+                conn,
+                round_group_id,
+                round_number,
+                exclude_id,
+            ),
+        }
+    }
+
+    /// Stores a new round group template with its rounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The template name (unique across all templates)
+    /// * `editing_enabled` - The `editing_enabled` value for instantiated groups
+    /// * `rounds` - The template's rounds
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails.
+    pub fn insert_round_group_template(
+        &mut self,
+        name: &str,
+        editing_enabled: bool,
+        rounds: &[RoundSpecData],
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_templates::insert_round_group_template_sqlite(
+                    conn,
+                    name,
+                    editing_enabled,
+                    rounds,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_templates::insert_round_group_template_mysql(
+                    conn,
+                    name,
+                    editing_enabled,
+                    rounds,
+                )
+            }
+        }
+    }
+
+    /// Lists all round group templates with their rounds, ordered by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_round_group_templates(
+        &mut self,
+    ) -> Result<Vec<RoundGroupTemplateData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_templates::list_round_group_templates_sqlite(conn)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_templates::list_round_group_templates_mysql(conn)
+            }
+        }
+    }
+
+    /// Gets a single round group template with its rounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::NotFound` if the template does not exist.
+    pub fn get_round_group_template(
+        &mut self,
+        template_id: i64,
+    ) -> Result<RoundGroupTemplateData, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_templates::get_round_group_template_sqlite(conn, template_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_templates::get_round_group_template_mysql(conn, template_id)
+            }
+        }
+    }
+
+    /// Checks if a round group template name is taken.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn round_group_template_name_exists(
+        &mut self,
+        name: &str,
+    ) -> Result<bool, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_templates::round_group_template_name_exists_sqlite(conn, name)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_templates::round_group_template_name_exists_mysql(conn, name)
+            }
+        }
+    }
+
+    /// Deletes a round group template and its rounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails.
+    pub fn delete_round_group_template(
+        &mut self,
+        template_id: i64,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_templates::delete_round_group_template_sqlite(conn, template_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_templates::delete_round_group_template_mysql(conn, template_id)
+            }
+        }
+    }
+
+    /// Stores a new blackout date for a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `blackout_date` - The blackout date (ISO 8601 format)
+    /// * `reason` - Why no bidding occurs on the date
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails.
+    pub fn insert_blackout_date(
+        &mut self,
+        bid_year_id: i64,
+        blackout_date: &str,
+        reason: &str,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::blackout_dates::insert_blackout_date_sqlite(
+                    conn,
+                    bid_year_id,
+                    blackout_date,
+                    reason,
+                )
+            }
+            BackendConnection::Mysql(conn) => queries::blackout_dates::insert_blackout_date_mysql(
+                conn,
+                bid_year_id,
+                blackout_date,
+                reason,
+            ),
+        }
+    }
+
+    /// Lists the blackout dates of a bid year, ordered by date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_blackout_dates(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<BlackoutDateData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::blackout_dates::list_blackout_dates_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::blackout_dates::list_blackout_dates_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Gets a single blackout date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::NotFound` if the blackout date does not exist.
+    pub fn get_blackout_date(
+        &mut self,
+        blackout_date_id: i64,
+    ) -> Result<BlackoutDateData, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::blackout_dates::get_blackout_date_sqlite(conn, blackout_date_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::blackout_dates::get_blackout_date_mysql(conn, blackout_date_id)
+            }
+        }
+    }
+
+    /// Checks whether a date is already blacked out in a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `blackout_date` - The date to check (ISO 8601 format)
+    /// * `exclude_id` - A blackout date ID to ignore (for updates)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn blackout_date_exists(
+        &mut self,
+        bid_year_id: i64,
+        blackout_date: &str,
+        exclude_id: Option<i64>,
+    ) -> Result<bool, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::blackout_dates::blackout_date_exists_sqlite(
+                    conn,
+                    bid_year_id,
+                    blackout_date,
+                    exclude_id,
+                )
+            }
+            BackendConnection::Mysql(conn) => queries::blackout_dates::blackout_date_exists_mysql(
+                conn,
+                bid_year_id,
+                blackout_date,
+                exclude_id,
+            ),
+        }
+    }
+
+    /// Updates the date and reason of a blackout date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::NotFound` if the blackout date does not exist.
+    pub fn update_blackout_date(
+        &mut self,
+        blackout_date_id: i64,
+        blackout_date: &str,
+        reason: &str,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::blackout_dates::update_blackout_date_sqlite(
+                    conn,
+                    blackout_date_id,
+                    blackout_date,
+                    reason,
+                )
+            }
+            BackendConnection::Mysql(conn) => queries::blackout_dates::update_blackout_date_mysql(
+                conn,
+                blackout_date_id,
+                blackout_date,
+                reason,
+            ),
+        }
+    }
+
+    /// Deletes a blackout date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::NotFound` if the blackout date does not exist.
+    pub fn delete_blackout_date(&mut self, blackout_date_id: i64) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::blackout_dates::delete_blackout_date_sqlite(conn, blackout_date_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::blackout_dates::delete_blackout_date_mysql(conn, blackout_date_id)
+            }
+        }
+    }
+
+    /// Gets the tracked current bidder of a round in an area.
+    ///
+    /// Returns `None` if the round has not started.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_current_bidder_state(
+        &mut self,
+        area_id: i64,
+        round_id: i64,
+    ) -> Result<Option<CurrentBidderStateData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::current_bidders::get_current_bidder_state_sqlite(conn, area_id, round_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::current_bidders::get_current_bidder_state_mysql(conn, area_id, round_id)
+            }
+        }
+    }
+
+    /// Lists a round's bidders in the order they bid, with their windows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_round_bidders(
+        &mut self,
+        area_id: i64,
+        round_id: i64,
+    ) -> Result<Vec<RoundBidderData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::current_bidders::list_round_bidders_sqlite(conn, area_id, round_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::current_bidders::list_round_bidders_mysql(conn, area_id, round_id)
+            }
+        }
+    }
+
+    /// Records the current bidder of a round in an area.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `area_id` - The area ID
+    /// * `round_id` - The round ID
+    /// * `user_id` - The new current bidder, or `None` when the round is complete
+    /// * `became_current_at` - When the handover happened (ISO 8601 format)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn set_current_bidder(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        round_id: i64,
+        user_id: Option<i64>,
+        became_current_at: &str,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::current_bidders::set_current_bidder_sqlite(
+                conn,
+                bid_year_id,
+                area_id,
+                round_id,
+                user_id,
+                became_current_at,
+            ),
+            BackendConnection::Mysql(conn) => queries::current_bidders::set_current_bidder_mysql(
+                conn,
+                bid_year_id,
+                area_id,
+                round_id,
+                user_id,
+                became_current_at,
+            ),
+        }
+    }
+
+    /// Gets the bidder whose window is active now in an area.
+    ///
+    /// Returns `None` if no round in the area has a current bidder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_current_bidder(
+        &mut self,
+        area_id: i64,
+    ) -> Result<Option<CurrentBidderData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::current_bidders::get_current_bidder_sqlite(conn, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::current_bidders::get_current_bidder_mysql(conn, area_id)
+            }
+        }
+    }
+
+    /// Lists the bid preferences for a round in an area.
+    ///
+    /// Preferences are ordered by user, then by rank.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_bid_preferences(
+        &mut self,
+        area_id: i64,
+        round_id: i64,
+    ) -> Result<Vec<BidPreferenceData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::bid_preferences::list_bid_preferences_sqlite(conn, area_id, round_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::bid_preferences::list_bid_preferences_mysql(conn, area_id, round_id)
+            }
+        }
+    }
+
+    /// Replaces a user's pending preferences for a round and records the
+    /// audit event that submitted them in one transaction.
+    ///
+    /// Returns the audit event ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either write fails; nothing is written then.
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_bid_preferences(
+        &mut self,
+        audit_event: &zab_bid_audit::AuditEvent,
+        bid_year_id: i64,
+        area_id: i64,
+        user_id: i64,
+        round_id: i64,
+        on_behalf_of: &str,
+        received_via: &str,
+        preferences: &[BidPreferenceSpecData],
+    ) -> Result<i64, PersistenceError> {
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                queries::bid_preferences::replace_bid_preferences_sqlite(
+                    conn,
+                    bid_year_id,
+                    area_id,
+                    user_id,
+                    round_id,
+                    on_behalf_of,
+                    received_via,
+                    preferences,
+                )?;
+                mutations::persist_audit_event_sqlite(conn, audit_event, ulids)
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                queries::bid_preferences::replace_bid_preferences_mysql(
+                    conn,
+                    bid_year_id,
+                    area_id,
+                    user_id,
+                    round_id,
+                    on_behalf_of,
+                    received_via,
+                    preferences,
+                )?;
+                mutations::persist_audit_event_mysql(conn, audit_event, ulids)
+            }),
+        }
+    }
+
+    /// Replaces a user's pending preferences for a round.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `area_id` - The area ID
+    /// * `user_id` - The user ID
+    /// * `round_id` - The round ID
+    /// * `on_behalf_of` - The initials of the controller the list was entered for
+    /// * `received_via` - How the list was received (`phone`, `in_person`, `written`)
+    /// * `preferences` - The ranked preferences
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    #[allow(clippy::too_many_arguments)]
+    pub fn replace_bid_preferences(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        user_id: i64,
+        round_id: i64,
+        on_behalf_of: &str,
+        received_via: &str,
+        preferences: &[BidPreferenceSpecData],
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::bid_preferences::replace_bid_preferences_sqlite(
+                    conn,
+                    bid_year_id,
+                    area_id,
+                    user_id,
+                    round_id,
+                    on_behalf_of,
+                    received_via,
+                    preferences,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::bid_preferences::replace_bid_preferences_mysql(
+                    conn,
+                    bid_year_id,
+                    area_id,
+                    user_id,
+                    round_id,
+                    on_behalf_of,
+                    received_via,
+                    preferences,
+                )
+            }
+        }
+    }
+
+    /// Lists the pending bid preferences of every area in a bid year.
+    ///
+    /// Preferences are ordered by user, then by round, then by rank.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_pending_bid_preferences(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<BidPreferenceData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::bid_preferences::list_pending_bid_preferences_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::bid_preferences::list_pending_bid_preferences_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Records what became of a bid preference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update fails.
+    pub fn set_bid_preference_status(
+        &mut self,
+        bid_preference_id: i64,
+        status: &str,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::bid_preferences::set_bid_preference_status_sqlite(
+                    conn,
+                    bid_preference_id,
+                    status,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::bid_preferences::set_bid_preference_status_mysql(
+                    conn,
+                    bid_preference_id,
+                    status,
+                )
+            }
+        }
+    }
+
+    /// Lists the bid validation rules of a bid year in evaluation order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_bid_rules(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<BidRuleData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::bid_rules::list_bid_rules_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::bid_rules::list_bid_rules_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Replaces the bid validation rules of a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `rules` - The rules in evaluation order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn replace_bid_rules(
+        &mut self,
+        bid_year_id: i64,
+        rules: &[BidRuleSpecData],
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::bid_rules::replace_bid_rules_sqlite(conn, bid_year_id, rules)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::bid_rules::replace_bid_rules_mysql(conn, bid_year_id, rules)
+            }
+        }
+    }
+
+    /// Lists the eligibility exceptions of a bid year.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_eligibility_exceptions(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<EligibilityExceptionData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::eligibility::list_eligibility_exceptions_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::eligibility::list_eligibility_exceptions_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Replaces the eligibility exceptions of a bid year.
+    ///
+    /// Derived eligibility is not recomputed; callers follow this with
+    /// `recompute_eligibility`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `exceptions` - The new exceptions
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn replace_eligibility_exceptions(
+        &mut self,
+        bid_year_id: i64,
+        exceptions: &[EligibilityExceptionSpecData],
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::eligibility::replace_eligibility_exceptions_sqlite(
+                    conn,
+                    bid_year_id,
+                    exceptions,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::eligibility::replace_eligibility_exceptions_mysql(
+                    conn,
+                    bid_year_id,
+                    exceptions,
+                )
+            }
+        }
+    }
+
+    /// Recomputes the derived eligibility of a bid year's canonical users.
+    ///
+    /// Overridden users keep their effective eligibility. A bid year that
+    /// has not been canonicalized has nothing to recompute.
+    ///
+    /// # Returns
+    ///
+    /// The number of users whose derived eligibility changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query or write fails.
+    pub fn recompute_eligibility(&mut self, bid_year_id: i64) -> Result<usize, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                let derived: BTreeMap<i64, zab_bid::DerivedEligibility> =
+                    queries::eligibility::derive_bid_year_eligibility_sqlite(conn, bid_year_id)?;
+                queries::eligibility::apply_derived_eligibility_sqlite(conn, bid_year_id, &derived)
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                let derived: BTreeMap<i64, zab_bid::DerivedEligibility> =
+                    queries::eligibility::derive_bid_year_eligibility_mysql(conn, bid_year_id)?;
+                queries::eligibility::apply_derived_eligibility_mysql(conn, bid_year_id, &derived)
+            }),
+        }
+    }
+
+    /// Lists the canonical eligibility of every user in a bid year.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_user_eligibility(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<UserEligibilityData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::eligibility::list_user_eligibility_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::eligibility::list_user_eligibility_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Stores a new prime period for a bid year.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `label` - The period's name
+    /// * `start_date` - The first prime date (ISO 8601 format)
+    /// * `end_date` - The last prime date (ISO 8601 format)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails.
+    pub fn insert_prime_period(
+        &mut self,
+        bid_year_id: i64,
+        label: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::prime_dates::insert_prime_period_sqlite(
+                conn,
+                bid_year_id,
+                label,
+                start_date,
+                end_date,
+            ),
+            BackendConnection::Mysql(conn) => queries::prime_dates::insert_prime_period_mysql(
+                conn,
+                bid_year_id,
+                label,
+                start_date,
+                end_date,
+            ),
+        }
+    }
+
+    /// Lists the prime periods of a bid year, ordered by start date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_prime_periods(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<PrimePeriodData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::prime_dates::list_prime_periods_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::prime_dates::list_prime_periods_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Gets a single prime period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::NotFound` if the prime period does not
+    /// exist, or an error if the query fails.
+    pub fn get_prime_period(
+        &mut self,
+        prime_period_id: i64,
+    ) -> Result<PrimePeriodData, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::prime_dates::get_prime_period_sqlite(conn, prime_period_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::prime_dates::get_prime_period_mysql(conn, prime_period_id)
+            }
+        }
+    }
+
+    /// Deletes a prime period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::NotFound` if the prime period does not
+    /// exist, or an error if the delete fails.
+    pub fn delete_prime_period(&mut self, prime_period_id: i64) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::prime_dates::delete_prime_period_sqlite(conn, prime_period_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::prime_dates::delete_prime_period_mysql(conn, prime_period_id)
+            }
+        }
+    }
+
+    /// Lists the prime caps of a bid year's rounds, ordered by round.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_round_prime_caps(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<RoundPrimeCapData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::prime_dates::list_round_prime_caps_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::prime_dates::list_round_prime_caps_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Sets or clears a round's cap on prime dates.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `round_id` - The round ID
+    /// * `prime_slots_per_day` - The slots per prime date, or `None` to
+    ///   remove the cap
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn set_round_prime_cap(
+        &mut self,
+        bid_year_id: i64,
+        round_id: i64,
+        prime_slots_per_day: Option<i32>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::prime_dates::set_round_prime_cap_sqlite(
+                conn,
+                bid_year_id,
+                round_id,
+                prime_slots_per_day,
+            ),
+            BackendConnection::Mysql(conn) => queries::prime_dates::set_round_prime_cap_mysql(
+                conn,
+                bid_year_id,
+                round_id,
+                prime_slots_per_day,
+            ),
+        }
+    }
+
+    /// Lists the eligibility criteria of a bid year's rounds, ordered by round.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_round_eligibility(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<RoundEligibilityData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_eligibility::list_round_eligibility_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_eligibility::list_round_eligibility_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Gets a round's eligibility criteria, if it has any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_round_eligibility(
+        &mut self,
+        round_id: i64,
+    ) -> Result<Option<RoundEligibilityData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_eligibility::get_round_eligibility_sqlite(conn, round_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_eligibility::get_round_eligibility_mysql(conn, round_id)
+            }
+        }
+    }
+
+    /// Sets or clears a round's eligibility criteria.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `round_id` - The round ID
+    /// * `criteria` - The round's criteria, or `None` to admit every bidder
+    ///   (its `round_id` is ignored)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn set_round_eligibility(
+        &mut self,
+        bid_year_id: i64,
+        round_id: i64,
+        criteria: Option<&RoundEligibilityData>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_eligibility::set_round_eligibility_sqlite(
+                    conn,
+                    bid_year_id,
+                    round_id,
+                    criteria,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_eligibility::set_round_eligibility_mysql(
+                    conn,
+                    bid_year_id,
+                    round_id,
+                    criteria,
+                )
+            }
+        }
+    }
+
+    /// Lists a bid year's rounds with their round number and their round
+    /// group's rotation, ordered by round.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_round_sequencing(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<RoundSequencingData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_bid_order::list_round_sequencing_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_bid_order::list_round_sequencing_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Gets a round group's bid order rotation, if one is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_round_group_rotation(
+        &mut self,
+        round_group_id: i64,
+    ) -> Result<Option<String>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_bid_order::get_round_group_rotation_sqlite(conn, round_group_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_bid_order::get_round_group_rotation_mysql(conn, round_group_id)
+            }
+        }
+    }
+
+    /// Sets or clears a round group's bid order rotation.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `round_group_id` - The round group ID
+    /// * `rotation` - The rotation's name, or `None` to bid every round in
+    ///   canonical bid order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn set_round_group_rotation(
+        &mut self,
+        bid_year_id: i64,
+        round_group_id: i64,
+        rotation: Option<&str>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_bid_order::set_round_group_rotation_sqlite(
+                    conn,
+                    bid_year_id,
+                    round_group_id,
+                    rotation,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_bid_order::set_round_group_rotation_mysql(
+                    conn,
+                    bid_year_id,
+                    round_group_id,
+                    rotation,
+                )
+            }
+        }
+    }
+
+    /// Lists a round's bidding sequence, ordered by area then position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_round_bid_order(
+        &mut self,
+        round_id: i64,
+    ) -> Result<Vec<RoundBidOrderData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_bid_order::list_round_bid_order_sqlite(conn, round_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_bid_order::list_round_bid_order_mysql(conn, round_id)
+            }
+        }
+    }
+
+    /// Stores rounds' bidding sequences.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails.
+    pub fn bulk_insert_round_bid_order(
+        &mut self,
+        records: &[RoundBidOrderData],
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_bid_order::bulk_insert_round_bid_order_sqlite(conn, records)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_bid_order::bulk_insert_round_bid_order_mysql(conn, records)
+            }
+        }
+    }
+
+    /// Lists the rounds of a bid year that allocate slots per crew.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_crew_partitioned_rounds(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<i64>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::crew_slots::list_crew_partitioned_rounds_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::crew_slots::list_crew_partitioned_rounds_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Lists the per-crew slot allocations of a bid year's rounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_round_crew_slots(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<RoundCrewSlotsData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::crew_slots::list_round_crew_slots_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::crew_slots::list_round_crew_slots_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Sets whether a round allocates slots per crew, and each crew's slots.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `round_id` - The round ID
+    /// * `crew_partitioned` - Whether the round allocates slots per crew
+    /// * `crew_slots` - `(crew, slots_per_day)` pairs
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn set_round_crew_slots(
+        &mut self,
+        bid_year_id: i64,
+        round_id: i64,
+        crew_partitioned: bool,
+        crew_slots: &[(i32, i32)],
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::crew_slots::set_round_crew_slots_sqlite(
+                conn,
+                bid_year_id,
+                round_id,
+                crew_partitioned,
+                crew_slots,
+            ),
+            BackendConnection::Mysql(conn) => queries::crew_slots::set_round_crew_slots_mysql(
+                conn,
+                bid_year_id,
+                round_id,
+                crew_partitioned,
+                crew_slots,
+            ),
+        }
+    }
+
+    /// Records the sign-off of a round in an area.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `area_id` - The area ID
+    /// * `round_id` - The round ID
+    /// * `audit_event_id` - The audit event that recorded the sign-off
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the round is already signed off in the area or
+    /// the insert fails.
+    pub fn insert_round_sign_off(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        round_id: i64,
+        audit_event_id: i64,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_sign_offs::insert_round_sign_off_sqlite(
+                    conn,
+                    bid_year_id,
+                    area_id,
+                    round_id,
+                    audit_event_id,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_sign_offs::insert_round_sign_off_mysql(
+                    conn,
+                    bid_year_id,
+                    area_id,
+                    round_id,
+                    audit_event_id,
+                )
+            }
+        }
+    }
+
+    /// Gets the sign-off of a round in an area, if it has been signed off.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_round_sign_off(
+        &mut self,
+        area_id: i64,
+        round_id: i64,
+    ) -> Result<Option<RoundSignOffData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_sign_offs::get_round_sign_off_sqlite(conn, area_id, round_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_sign_offs::get_round_sign_off_mysql(conn, area_id, round_id)
+            }
+        }
+    }
+
+    /// Lists the round sign-offs of a bid year, ordered by area then round.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_round_sign_offs(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<RoundSignOffData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_sign_offs::list_round_sign_offs_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_sign_offs::list_round_sign_offs_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Records the freeze of an area.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `area_id` - The area ID
+    /// * `reason` - Why the area is frozen
+    /// * `audit_event_id` - The audit event that recorded the freeze
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area is already frozen or the insert fails.
+    pub fn insert_scope_freeze(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        reason: &str,
+        audit_event_id: i64,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::scope_freezes::insert_scope_freeze_sqlite(
+                conn,
+                bid_year_id,
+                area_id,
+                reason,
+                audit_event_id,
+            ),
+            BackendConnection::Mysql(conn) => queries::scope_freezes::insert_scope_freeze_mysql(
+                conn,
+                bid_year_id,
+                area_id,
+                reason,
+                audit_event_id,
+            ),
+        }
+    }
+
+    /// Lifts the freeze of an area, returning whether it was frozen.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails.
+    pub fn delete_scope_freeze(&mut self, area_id: i64) -> Result<bool, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::scope_freezes::delete_scope_freeze_sqlite(conn, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::scope_freezes::delete_scope_freeze_mysql(conn, area_id)
+            }
+        }
+    }
+
+    /// Lists the frozen areas of a bid year, ordered by area.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_scope_freezes(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<ScopeFreezeData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::scope_freezes::list_scope_freezes_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::scope_freezes::list_scope_freezes_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Gets a bid year's amendment policy, if one has been set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_bid_amendment_policy(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Option<BidAmendmentPolicyData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::amendment_policies::get_bid_amendment_policy_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::amendment_policies::get_bid_amendment_policy_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Sets a bid year's amendment policy, replacing any previous policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `policy` - The policy kind
+    /// * `window_hours` - The amendment window in hours (`within_hours` only)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn set_bid_amendment_policy(
+        &mut self,
+        bid_year_id: i64,
+        policy: &str,
+        window_hours: Option<i32>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::amendment_policies::set_bid_amendment_policy_sqlite(
+                    conn,
+                    bid_year_id,
+                    policy,
+                    window_hours,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::amendment_policies::set_bid_amendment_policy_mysql(
+                    conn,
+                    bid_year_id,
+                    policy,
+                    window_hours,
+                )
+            }
+        }
+    }
+
+    /// Gets the facility configuration, if one has been set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_facility(&mut self) -> Result<Option<FacilityData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::facility::get_facility_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::facility::get_facility_mysql(conn),
+        }
+    }
+
+    /// Sets the facility configuration, replacing any previous configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn set_facility(&mut self, data: &FacilityData) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::facility::set_facility_sqlite(conn, data),
+            BackendConnection::Mysql(conn) => queries::facility::set_facility_mysql(conn, data),
+        }
+    }
+
+    /// Gets whether a feature flag is enabled for a bid year, if it has been
+    /// set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_feature_flag(
+        &mut self,
+        bid_year_id: i64,
+        flag: &str,
+    ) -> Result<Option<bool>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::feature_flags::get_feature_flag_sqlite(conn, bid_year_id, flag)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::feature_flags::get_feature_flag_mysql(conn, bid_year_id, flag)
+            }
+        }
+    }
+
+    /// Lists the feature flags set for a bid year, ordered by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_feature_flags(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<FeatureFlagData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::feature_flags::list_feature_flags_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::feature_flags::list_feature_flags_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Sets a feature flag for a bid year, replacing any previous setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `flag` - The flag name
+    /// * `enabled` - Whether the flag is on
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn set_feature_flag(
+        &mut self,
+        bid_year_id: i64,
+        flag: &str,
+        enabled: bool,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::feature_flags::set_feature_flag_sqlite(conn, bid_year_id, flag, enabled)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::feature_flags::set_feature_flag_mysql(conn, bid_year_id, flag, enabled)
+            }
+        }
+    }
+
+    /// Lists the last run of every background job that has run, ordered by
+    /// name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_job_runs(&mut self) -> Result<Vec<JobRunData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::job_runs::list_job_runs_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::job_runs::list_job_runs_mysql(conn),
+        }
+    }
+
+    /// Records a background job's latest run, replacing its previous one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn record_job_run(&mut self, run: &JobRunData) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::job_runs::record_job_run_sqlite(conn, run),
+            BackendConnection::Mysql(conn) => queries::job_runs::record_job_run_mysql(conn, run),
+        }
+    }
+
+    /// Gets a bid year's carryover cap in hours, if one has been set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_leave_cap(&mut self, bid_year_id: i64) -> Result<Option<i32>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::leave_caps::get_leave_cap_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::leave_caps::get_leave_cap_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Sets a bid year's carryover cap, replacing any previous cap.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `carryover_cap_hours` - The most leave that may carry into the next year
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn set_leave_cap(
+        &mut self,
+        bid_year_id: i64,
+        carryover_cap_hours: i32,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::leave_caps::set_leave_cap_sqlite(conn, bid_year_id, carryover_cap_hours)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::leave_caps::set_leave_cap_mysql(conn, bid_year_id, carryover_cap_hours)
+            }
+        }
+    }
+
+    /// Lists the leave carried into a bid year, ordered by user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_leave_carryovers(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<LeaveCarryoverData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::leave_caps::list_leave_carryovers_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::leave_caps::list_leave_carryovers_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Sets the leave a user carries into a bid year, replacing any previous
+    /// value.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `user_id` - The user ID
+    /// * `carryover_hours` - Hours carried in from the prior year
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn set_leave_carryover(
+        &mut self,
+        bid_year_id: i64,
+        user_id: i64,
+        carryover_hours: i32,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::leave_caps::set_leave_carryover_sqlite(
+                conn,
+                bid_year_id,
+                user_id,
+                carryover_hours,
+            ),
+            BackendConnection::Mysql(conn) => queries::leave_caps::set_leave_carryover_mysql(
+                conn,
+                bid_year_id,
+                user_id,
+                carryover_hours,
+            ),
+        }
+    }
+
+    /// Records a leave slot freed by a withdrawal after its round closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The freed slot (`waitlist_slot_id`, `status`, and
+    ///   `created_at` are ignored)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the insert fails.
+    pub fn insert_waitlist_slot(
+        &mut self,
+        slot: &WaitlistSlotData,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::waitlist::insert_waitlist_slot_sqlite(conn, slot)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::waitlist::insert_waitlist_slot_mysql(conn, slot)
+            }
+        }
+    }
+
+    /// Gets a waitlist slot by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_waitlist_slot(
+        &mut self,
+        waitlist_slot_id: i64,
+    ) -> Result<Option<WaitlistSlotData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::waitlist::get_waitlist_slot_sqlite(conn, waitlist_slot_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::waitlist::get_waitlist_slot_mysql(conn, waitlist_slot_id)
+            }
+        }
+    }
+
+    /// Lists the waitlist slots of a round in an area.
+    ///
+    /// # Arguments
+    ///
+    /// * `area_id` - The area ID
+    /// * `round_id` - The round ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_waitlist_slots(
+        &mut self,
+        area_id: i64,
+        round_id: i64,
+    ) -> Result<Vec<WaitlistSlotData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::waitlist::list_waitlist_slots_sqlite(conn, area_id, round_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::waitlist::list_waitlist_slots_mysql(conn, area_id, round_id)
+            }
+        }
+    }
+
+    /// Sets the status of a waitlist slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `waitlist_slot_id` - The waitlist slot ID
+    /// * `status` - `open`, `filled`, or `unclaimed`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update fails.
+    pub fn set_waitlist_slot_status(
+        &mut self,
+        waitlist_slot_id: i64,
+        status: &str,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::waitlist::set_waitlist_slot_status_sqlite(conn, waitlist_slot_id, status)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::waitlist::set_waitlist_slot_status_mysql(conn, waitlist_slot_id, status)
+            }
+        }
+    }
+
+    /// Records a pending offer of a waitlist slot to a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `waitlist_slot_id` - The waitlist slot ID
+    /// * `user_id` - The user offered the slot
+    /// * `offered_at` - When the offer was made (RFC 3339)
+    /// * `expires_at` - When the offer lapses (RFC 3339)
+    /// * `offer_event_id` - The audit event that recorded the offer
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user was already offered the slot or the
+    /// insert fails.
+    pub fn insert_waitlist_offer(
+        &mut self,
+        waitlist_slot_id: i64,
+        user_id: i64,
+        offered_at: &str,
+        expires_at: &str,
+        offer_event_id: i64,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::waitlist::insert_waitlist_offer_sqlite(
+                conn,
+                waitlist_slot_id,
+                user_id,
+                offered_at,
+                expires_at,
+                offer_event_id,
+            ),
+            BackendConnection::Mysql(conn) => queries::waitlist::insert_waitlist_offer_mysql(
+                conn,
+                waitlist_slot_id,
+                user_id,
+                offered_at,
+                expires_at,
+                offer_event_id,
+            ),
+        }
+    }
+
+    /// Gets a waitlist offer by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_waitlist_offer(
+        &mut self,
+        waitlist_offer_id: i64,
+    ) -> Result<Option<WaitlistOfferData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::waitlist::get_waitlist_offer_sqlite(conn, waitlist_offer_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::waitlist::get_waitlist_offer_mysql(conn, waitlist_offer_id)
+            }
+        }
+    }
+
+    /// Lists the offers made of a waitlist slot, in the order they were made.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_waitlist_offers(
+        &mut self,
+        waitlist_slot_id: i64,
+    ) -> Result<Vec<WaitlistOfferData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::waitlist::list_waitlist_offers_sqlite(conn, waitlist_slot_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::waitlist::list_waitlist_offers_mysql(conn, waitlist_slot_id)
+            }
+        }
+    }
+
+    /// Lists every waitlist offer still waiting for an answer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_pending_waitlist_offers(
+        &mut self,
+    ) -> Result<Vec<WaitlistOfferData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::waitlist::list_pending_waitlist_offers_sqlite(conn)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::waitlist::list_pending_waitlist_offers_mysql(conn)
+            }
+        }
+    }
+
+    /// Records the answer to, or expiry of, a pending waitlist offer.
+    ///
+    /// # Arguments
+    ///
+    /// * `waitlist_offer_id` - The waitlist offer ID
+    /// * `status` - `accepted`, `declined`, or `expired`
+    /// * `responded_at` - When the offer was answered or lapsed (RFC 3339)
+    /// * `response_event_id` - The audit event that recorded the response
+    /// * `leave_bid_id` - The leave granted on acceptance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update fails.
+    pub fn respond_to_waitlist_offer(
+        &mut self,
+        waitlist_offer_id: i64,
+        status: &str,
+        responded_at: &str,
+        response_event_id: i64,
+        leave_bid_id: Option<i64>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::waitlist::respond_to_waitlist_offer_sqlite(
+                conn,
+                waitlist_offer_id,
+                status,
+                responded_at,
+                response_event_id,
+                leave_bid_id,
+            ),
+            BackendConnection::Mysql(conn) => queries::waitlist::respond_to_waitlist_offer_mysql(
+                conn,
+                waitlist_offer_id,
+                status,
+                responded_at,
+                response_event_id,
+                leave_bid_id,
+            ),
+        }
+    }
+
+    /// Lists the staffing adjustments of a bid year within a date range.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `start_date` - The first date to include
+    /// * `end_date` - The last date to include
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_slot_adjustments(
+        &mut self,
+        bid_year_id: i64,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<SlotAdjustmentData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::slot_inventory::list_slot_adjustments_sqlite(
+                    conn,
+                    bid_year_id,
+                    start_date,
+                    end_date,
+                )
+            }
+            BackendConnection::Mysql(conn) => queries::slot_inventory::list_slot_adjustments_mysql(
+                conn,
+                bid_year_id,
+                start_date,
+                end_date,
+            ),
+        }
+    }
+
+    /// Gets the staffing adjustment of one day of a round in an area.
+    ///
+    /// Returns `0` when no adjustment is stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_slot_adjustment(
+        &mut self,
+        area_id: i64,
+        round_id: i64,
+        slot_date: &str,
+    ) -> Result<i32, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::slot_inventory::get_slot_adjustment_sqlite(
+                conn, area_id, round_id, slot_date,
+            ),
+            BackendConnection::Mysql(conn) => queries::slot_inventory::get_slot_adjustment_mysql(
+                conn, area_id, round_id, slot_date,
+            ),
+        }
+    }
+
+    /// Records the staffing adjustment of one day of a round in an area.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year ID
+    /// * `area_id` - The area ID
+    /// * `round_id` - The round ID
+    /// * `slot_date` - The date (`YYYY-MM-DD`)
+    /// * `staffing_adjustment` - The change to the round's `slots_per_day`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn set_slot_adjustment(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        round_id: i64,
+        slot_date: &str,
+        staffing_adjustment: i32,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::slot_inventory::set_slot_adjustment_sqlite(
+                conn,
+                bid_year_id,
+                area_id,
+                round_id,
+                slot_date,
+                staffing_adjustment,
+            ),
+            BackendConnection::Mysql(conn) => queries::slot_inventory::set_slot_adjustment_mysql(
+                conn,
+                bid_year_id,
+                area_id,
+                round_id,
+                slot_date,
+                staffing_adjustment,
+            ),
+        }
+    }
+
+    /// Records a pending overbid request.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    /// * `user_id` - The canonical user ID
+    /// * `round_id` - The round the leave is requested in
+    /// * `leave_date` - The leave date (`YYYY-MM-DD`)
+    /// * `hours` - The leave hours charged for the day
+    /// * `on_behalf_of` - The initials of the controller the request was entered for
+    /// * `received_via` - How the request was received (`phone`, `in_person`, `written`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be recorded.
+    #[allow(clippy::too_many_arguments)]
+    pub fn insert_overbid_request(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        user_id: i64,
+        round_id: i64,
+        leave_date: &str,
+        hours: i32,
+        on_behalf_of: &str,
+        received_via: &str,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::insert_overbid_request_sqlite(
+                conn,
+                bid_year_id,
+                area_id,
+                user_id,
+                round_id,
+                leave_date,
+                hours,
+                on_behalf_of,
+                received_via,
+            ),
+            BackendConnection::Mysql(conn) => mutations::insert_overbid_request_mysql(
+                conn,
+                bid_year_id,
+                area_id,
+                user_id,
+                round_id,
+                leave_date,
+                hours,
+                on_behalf_of,
+                received_via,
+            ),
+        }
+    }
+
+    /// Links an overbid request to the audit event that recorded it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn set_overbid_request_event(
+        &mut self,
+        overbid_request_id: i64,
+        request_event_id: i64,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::set_overbid_request_event_sqlite(
+                conn,
+                overbid_request_id,
+                request_event_id,
+            ),
+            BackendConnection::Mysql(conn) => mutations::set_overbid_request_event_mysql(
+                conn,
+                overbid_request_id,
+                request_event_id,
+            ),
+        }
+    }
+
+    /// Records the Admin decision on an overbid request.
+    ///
+    /// # Arguments
+    ///
+    /// * `overbid_request_id` - The overbid request ID
+    /// * `status` - The decision (`approved` or `denied`)
+    /// * `decision_reason` - Why the request was denied, if it was
+    /// * `decision_event_id` - The audit event that recorded the decision
+    /// * `leave_bid_id` - The leave granted on approval
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn decide_overbid_request(
+        &mut self,
+        overbid_request_id: i64,
+        status: &str,
+        decision_reason: Option<&str>,
+        decision_event_id: i64,
+        leave_bid_id: Option<i64>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::decide_overbid_request_sqlite(
+                conn,
+                overbid_request_id,
+                status,
+                decision_reason,
+                decision_event_id,
+                leave_bid_id,
+            ),
+            BackendConnection::Mysql(conn) => mutations::decide_overbid_request_mysql(
+                conn,
+                overbid_request_id,
+                status,
+                decision_reason,
+                decision_event_id,
+                leave_bid_id,
+            ),
+        }
+    }
+
+    /// Gets an overbid request by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_overbid_request(
+        &mut self,
+        overbid_request_id: i64,
+    ) -> Result<Option<OverbidRequestData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::overbids::get_overbid_request_sqlite(conn, overbid_request_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::overbids::get_overbid_request_mysql(conn, overbid_request_id)
+            }
+        }
+    }
+
+    /// Lists the overbid requests for a round in an area.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_overbid_requests(
+        &mut self,
+        area_id: i64,
+        round_id: i64,
+    ) -> Result<Vec<OverbidRequestData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::overbids::list_overbid_requests_sqlite(conn, area_id, round_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::overbids::list_overbid_requests_mysql(conn, area_id, round_id)
+            }
+        }
+    }
+
+    /// Links an operator to the controller they are in the roster.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator_id` - The operator ID
+    /// * `initials` - The controller's initials; `None` clears the link
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database write fails.
+    pub fn set_operator_controller(
+        &mut self,
+        operator_id: i64,
+        initials: Option<&str>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                mutations::set_operator_controller_sqlite(conn, operator_id, initials)
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                mutations::set_operator_controller_mysql(conn, operator_id, initials)
+            }),
+        }
+    }
+
+    /// Gets the initials of the controller an operator is in the roster.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_operator_controller(
+        &mut self,
+        operator_id: i64,
+    ) -> Result<Option<String>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::self_bids::get_operator_controller_sqlite(conn, operator_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::self_bids::get_operator_controller_mysql(conn, operator_id)
+            }
+        }
+    }
+
+    /// Records a pending self-service bid request, persisting the audit
+    /// event that records it in the same transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The audit event recording the request
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    /// * `user_id` - The canonical user ID
+    /// * `round_id` - The round the leave is requested in
+    /// * `requested_by_operator_id` - The operator who is also the controller
+    /// * `leave_dates` - The leave dates (`YYYY-MM-DD`), in date order
+    /// * `hours` - The leave hours charged per day
+    /// * `on_behalf_of` - The initials of the controller
+    /// * `received_via` - How the bid was received (`phone`, `in_person`, `written`)
+    ///
+    /// # Returns
+    ///
+    /// The request's ID and the ID of its audit event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails. Nothing is
+    /// persisted in that case.
+    #[allow(clippy::too_many_arguments)]
+    pub fn record_self_bid_request(
+        &mut self,
+        event: &AuditEvent,
+        bid_year_id: i64,
+        area_id: i64,
+        user_id: i64,
+        round_id: i64,
+        requested_by_operator_id: i64,
+        leave_dates: &[String],
+        hours: i32,
+        on_behalf_of: &str,
+        received_via: &str,
+    ) -> Result<(i64, i64), PersistenceError> {
+        let ulids: &dyn IdGenerator = self.ulids.as_ref();
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => conn.transaction(|conn| {
+                let event_id: i64 = mutations::persist_audit_event_sqlite(conn, event, ulids)?;
+                let self_bid_request_id: i64 = mutations::insert_self_bid_request_sqlite(
+                    conn,
+                    event_id,
+                    bid_year_id,
+                    area_id,
+                    user_id,
+                    round_id,
+                    requested_by_operator_id,
+                    leave_dates,
+                    hours,
+                    on_behalf_of,
+                    received_via,
+                )?;
+                Ok((self_bid_request_id, event_id))
+            }),
+            BackendConnection::Mysql(conn) => conn.transaction(|conn| {
+                let event_id: i64 = mutations::persist_audit_event_mysql(conn, event, ulids)?;
+                let self_bid_request_id: i64 = mutations::insert_self_bid_request_mysql(
+                    conn,
+                    event_id,
+                    bid_year_id,
+                    area_id,
+                    user_id,
+                    round_id,
+                    requested_by_operator_id,
+                    leave_dates,
+                    hours,
+                    on_behalf_of,
+                    received_via,
+                )?;
+                Ok((self_bid_request_id, event_id))
+            }),
+        }
+    }
+
+    /// Records the confirmation of a self-service bid request.
+    ///
+    /// # Arguments
+    ///
+    /// * `self_bid_request_id` - The self-service bid request ID
+    /// * `confirmed_by_operator_id` - The operator who confirmed it
+    /// * `confirm_event_id` - The audit event that recorded the confirmation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn confirm_self_bid_request(
+        &mut self,
+        self_bid_request_id: i64,
+        confirmed_by_operator_id: i64,
+        confirm_event_id: i64,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::confirm_self_bid_request_sqlite(
+                conn,
+                self_bid_request_id,
+                confirmed_by_operator_id,
+                confirm_event_id,
+            ),
+            BackendConnection::Mysql(conn) => mutations::confirm_self_bid_request_mysql(
+                conn,
+                self_bid_request_id,
+                confirmed_by_operator_id,
+                confirm_event_id,
+            ),
+        }
+    }
+
+    /// Gets a self-service bid request by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_self_bid_request(
+        &mut self,
+        self_bid_request_id: i64,
+    ) -> Result<Option<SelfBidRequestData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::self_bids::get_self_bid_request_sqlite(conn, self_bid_request_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::self_bids::get_self_bid_request_mysql(conn, self_bid_request_id)
+            }
+        }
+    }
+
+    /// Lists the self-service bid requests for a round in an area.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_self_bid_requests(
+        &mut self,
+        area_id: i64,
+        round_id: i64,
+    ) -> Result<Vec<SelfBidRequestData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::self_bids::list_self_bid_requests_sqlite(conn, area_id, round_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::self_bids::list_self_bid_requests_mysql(conn, area_id, round_id)
+            }
+        }
+    }
+
+    /// Saves a named audit query.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The unique query name
+    /// * `scope_json` - The timeline scope, as JSON
+    /// * `filter_json` - The timeline filters, as JSON
+    /// * `weekly_recipients` - Addresses to email the results to each week
+    /// * `created_by_operator_id` - The operator saving the query
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be saved.
+    pub fn create_saved_audit_query(
+        &mut self,
+        name: &str,
+        scope_json: &str,
+        filter_json: &str,
+        weekly_recipients: &[String],
+        created_by_operator_id: i64,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::create_saved_audit_query_sqlite(
+                conn,
+                name,
+                scope_json,
+                filter_json,
+                weekly_recipients,
+                created_by_operator_id,
+            ),
+            BackendConnection::Mysql(conn) => mutations::create_saved_audit_query_mysql(
+                conn,
+                name,
+                scope_json,
+                filter_json,
+                weekly_recipients,
+                created_by_operator_id,
+            ),
+        }
+    }
+
+    /// Lists all saved audit queries ordered by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list_saved_audit_queries(
+        &mut self,
+    ) -> Result<Vec<SavedAuditQueryData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::saved_audit_queries::list_saved_audit_queries_sqlite(conn)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::saved_audit_queries::list_saved_audit_queries_mysql(conn)
+            }
+        }
+    }
+
+    /// Retrieves a saved audit query by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get_saved_audit_query(
+        &mut self,
+        saved_query_id: i64,
+    ) -> Result<Option<SavedAuditQueryData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::saved_audit_queries::get_saved_audit_query_sqlite(conn, saved_query_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::saved_audit_queries::get_saved_audit_query_mysql(conn, saved_query_id)
+            }
+        }
+    }
+
+    /// Sets the addresses a saved audit query is emailed to each week.
+    ///
+    /// An empty list stops the weekly delivery.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update fails.
+    pub fn set_saved_audit_query_recipients(
+        &mut self,
+        saved_query_id: i64,
+        weekly_recipients: &[String],
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::set_saved_audit_query_recipients_sqlite(
+                conn,
+                saved_query_id,
+                weekly_recipients,
+            ),
+            BackendConnection::Mysql(conn) => mutations::set_saved_audit_query_recipients_mysql(
+                conn,
+                saved_query_id,
+                weekly_recipients,
+            ),
+        }
+    }
+
+    /// Records that a saved audit query's results were emailed.
+    ///
+    /// # Arguments
+    ///
+    /// * `saved_query_id` - The saved query ID
+    /// * `delivered_at` - When the results were sent (RFC 3339)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update fails.
+    pub fn mark_saved_audit_query_delivered(
+        &mut self,
+        saved_query_id: i64,
+        delivered_at: &str,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::mark_saved_audit_query_delivered_sqlite(
+                conn,
+                saved_query_id,
+                delivered_at,
+            ),
+            BackendConnection::Mysql(conn) => mutations::mark_saved_audit_query_delivered_mysql(
+                conn,
+                saved_query_id,
+                delivered_at,
+            ),
+        }
+    }
+
+    /// Deletes a saved audit query.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails.
+    pub fn delete_saved_audit_query(
+        &mut self,
+        saved_query_id: i64,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::delete_saved_audit_query_sqlite(conn, saved_query_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::delete_saved_audit_query_mysql(conn, saved_query_id)
+            }
+        }
+    }
+
+    /// Creates round groups and their rounds in a bid year atomically.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The bid year to create the groups in
+    /// * `groups` - The round groups to create
+    ///
+    /// # Returns
+    ///
+    /// The new round group IDs, in the order of `groups`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any insert fails; nothing is created in that case.
+    pub fn instantiate_round_groups(
+        &mut self,
+        bid_year_id: i64,
+        groups: &[RoundGroupSpecData],
+    ) -> Result<Vec<i64>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::round_templates::instantiate_round_groups_sqlite(conn, bid_year_id, groups)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::round_templates::instantiate_round_groups_mysql(conn, bid_year_id, groups)
+            }
+        }
+    }
+
+    /// Gets an area by its canonical ID, returning both the Area and its `bid_year_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `area_id` - The canonical area ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area does not exist or the query fails.
+    pub fn get_area_by_id(&mut self, area_id: i64) -> Result<(Area, i64), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::canonical::get_area_by_id_sqlite(conn, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::canonical::get_area_by_id_mysql(conn, area_id)
+            }
+        }
+    }
+
+    // ========================================================================
+    // Phase 29D: Readiness Evaluation
+    // ========================================================================
+
+    /// Checks if a bid year has a valid bid schedule configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    ///
+    /// # Returns
+    ///
+    /// `true` if all bid schedule fields are set, `false` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn is_bid_schedule_set(&mut self, bid_year_id: i64) -> Result<bool, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::readiness::is_bid_schedule_set_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::readiness::is_bid_schedule_set_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Gets non-system areas that have no rounds configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    ///
+    /// # Returns
+    ///
+    /// Vector of area codes for areas missing round configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn get_areas_missing_rounds(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<String>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::readiness::get_areas_missing_rounds_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::readiness::get_areas_missing_rounds_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Counts users in system areas who have not been reviewed.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    ///
+    /// # Returns
+    ///
+    /// Count of unreviewed users in system areas (No Bid).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn count_unreviewed_no_bid_users(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::readiness::count_unreviewed_no_bid_users_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::readiness::count_unreviewed_no_bid_users_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Counts users violating the participation flag directional invariant.
+    ///
+    /// Invariant: `excluded_from_leave_calculation == true` ⇒ `excluded_from_bidding == true`
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    ///
+    /// # Returns
+    ///
+    /// Count of users violating the invariant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn count_participation_flag_violations(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::readiness::count_participation_flag_violations_sqlite(conn, bid_year_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::readiness::count_participation_flag_violations_mysql(conn, bid_year_id)
+            }
+        }
+    }
+
+    /// Marks a user in a system area as reviewed.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user's canonical ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be updated.
+    pub fn mark_user_no_bid_reviewed(&mut self, user_id: i64) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::readiness::mark_user_no_bid_reviewed_sqlite(conn, user_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::readiness::mark_user_no_bid_reviewed_mysql(conn, user_id)
+            }
+        }
+    }
+
+    /// Gets all users grouped by area for seniority conflict detection.
+    ///
+    /// Returns users in non-system areas only.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    ///
+    /// # Returns
+    ///
+    /// Vector of tuples containing (`area_id`, `area_code`, users in that area).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn get_users_by_area_for_conflict_detection(
+        &mut self,
+        bid_year_id: i64,
+    ) -> Result<Vec<(i64, String, Vec<zab_bid_domain::User>)>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::readiness::get_users_by_area_for_conflict_detection_sqlite(
+                    conn,
+                    bid_year_id,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::readiness::get_users_by_area_for_conflict_detection_mysql(
+                    conn,
+                    bid_year_id,
+                )
+            }
+        }
+    }
+
+    /// Get user information by ID.
+    ///
+    /// Returns a simple struct with user initials for display purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The canonical user ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user does not exist or the database operation fails.
+    pub fn get_user_by_id(&mut self, user_id: i64) -> Result<UserInfo, PersistenceError> {
+        let (_bid_year_id, initials) = self.get_user_details(user_id)?;
+        Ok(UserInfo { initials })
+    }
+
+    /// Get round information by ID.
+    ///
+    /// Returns round details including the round name.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_id` - The round ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the round does not exist or the database operation fails.
+    pub fn get_round_by_id(&mut self, round_id: i64) -> Result<RoundInfo, PersistenceError> {
+        let round = self.get_round(round_id)?;
+        Ok(RoundInfo {
+            round_name: round.name().to_string(),
+        })
+    }
+
+    /// Get bid status for an area.
+    ///
+    /// Returns all bid status records for users in the specified area.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn get_bid_status_for_area(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+    ) -> Result<Vec<BidStatusRow>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::bid_status::get_bid_status_for_area_sqlite(conn, bid_year_id, area_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::bid_status::get_bid_status_for_area_mysql(conn, bid_year_id, area_id)
+            }
+        }
+    }
+
+    /// Get bid status for a specific user and round.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    /// * `user_id` - The canonical user ID
+    /// * `round_id` - The round ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record is not found or the database cannot be queried.
+    pub fn get_bid_status_for_user_and_round(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        user_id: i64,
+        round_id: i64,
+    ) -> Result<BidStatusRow, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::bid_status::get_bid_status_for_user_and_round_sqlite(
+                    conn,
+                    bid_year_id,
+                    area_id,
+                    user_id,
+                    round_id,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::bid_status::get_bid_status_for_user_and_round_mysql(
+                    conn,
+                    bid_year_id,
+                    area_id,
+                    user_id,
+                    round_id,
+                )
+            }
+        }?
+        .ok_or_else(|| {
+            PersistenceError::NotFound(format!(
+                "Bid status not found for user {user_id} in round {round_id}"
+            ))
+        })
+    }
+
+    /// Get bid status by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_status_id` - The bid status record ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record is not found or the database cannot be queried.
+    pub fn get_bid_status_by_id(
+        &mut self,
+        bid_status_id: i64,
+    ) -> Result<BidStatusRow, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::bid_status::get_bid_status_by_id_sqlite(conn, bid_status_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::bid_status::get_bid_status_by_id_mysql(conn, bid_status_id)
+            }
+        }?
+        .ok_or_else(|| PersistenceError::NotFound(format!("Bid status {bid_status_id} not found")))
+    }
+
+    /// Get bid status history for a bid status record.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_status_id` - The bid status record ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be queried.
+    pub fn get_bid_status_history(
+        &mut self,
+        bid_status_id: i64,
+    ) -> Result<Vec<BidStatusHistoryRow>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::bid_status::get_bid_status_history_sqlite(conn, bid_status_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::bid_status::get_bid_status_history_mysql(conn, bid_status_id)
+            }
+        }
+    }
+
+    /// Update bid status.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_status_id` - The bid status record ID
+    /// * `new_status` - The new status string
+    /// * `updated_at` - The update timestamp
+    /// * `updated_by` - The operator ID making the update
+    /// * `notes` - Optional notes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn update_bid_status(
+        &mut self,
+        bid_status_id: i64,
+        new_status: &str,
+        updated_at: &str,
+        updated_by: i64,
+        notes: Option<&str>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::bid_status::update_bid_status_sqlite(
+                conn,
+                bid_status_id,
+                new_status,
+                updated_at,
+                updated_by,
+                notes.map(ToString::to_string),
+            ),
+            BackendConnection::Mysql(conn) => mutations::bid_status::update_bid_status_mysql(
+                conn,
+                bid_status_id,
+                new_status,
+                updated_at,
+                updated_by,
+                notes.map(ToString::to_string),
+            ),
+        }
+    }
+
+    /// Insert bid status history record.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_status_id` - The bid status record ID
+    /// * `audit_event_id` - The audit event ID
+    /// * `previous_status` - The previous status (if any)
+    /// * `new_status` - The new status
+    /// * `transitioned_at` - The transition timestamp
+    /// * `transitioned_by` - The operator ID making the transition
+    /// * `notes` - Optional notes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database insert fails.
+    #[allow(clippy::too_many_arguments)]
+    pub fn insert_bid_status_history(
+        &mut self,
+        bid_status_id: i64,
+        audit_event_id: i64,
+        previous_status: Option<&str>,
+        new_status: &str,
+        transitioned_at: &str,
+        transitioned_by: i64,
+        notes: Option<&str>,
+    ) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::bid_status::insert_bid_status_history_sqlite(
+                    conn,
+                    bid_status_id,
+                    audit_event_id,
+                    previous_status,
+                    new_status,
+                    transitioned_at,
+                    transitioned_by,
+                    notes,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::bid_status::insert_bid_status_history_mysql(
+                    conn,
+                    bid_status_id,
+                    audit_event_id,
+                    previous_status,
+                    new_status,
+                    transitioned_at,
+                    transitioned_by,
+                    notes,
+                )
+            }
+        }
+    }
+
+    /// Get the next audit event ID (temporary helper for Phase 29F).
+    ///
+    /// This is a placeholder until proper audit event creation is integrated.
+    ///
+    /// # Returns
+    ///
+    /// The next available audit event ID.
+    ///
+    /// # Errors
+    ///
+    /// Currently always returns `Ok`, but signature allows for future error cases.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn get_next_audit_event_id(&mut self) -> Result<i64, PersistenceError> {
+        // This is a simplified implementation - in production, this would be
+        // part of the audit event creation flow
+        Ok(1)
+    }
+
+    // ========================================================================
+    // Phase 29G: Post-Confirmation Bid Order Adjustments
+    // ========================================================================
+
+    /// Adjusts a bid window for a specific user and round.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    /// * `user_id` - The canonical user ID
+    /// * `round_id` - The round ID
+    /// * `new_window_start` - The new window start datetime (ISO 8601)
+    /// * `new_window_end` - The new window end datetime (ISO 8601)
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of (`previous_window_start`, `previous_window_end`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bid window record does not exist or the database operation fails.
+    pub fn adjust_bid_window(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        user_id: i64,
+        round_id: i64,
+        new_window_start: &str,
+        new_window_end: &str,
+    ) -> Result<(String, String), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::canonical::adjust_bid_window_sqlite(
+                conn,
+                bid_year_id,
+                area_id,
+                user_id,
+                round_id,
+                new_window_start,
+                new_window_end,
+            ),
+            BackendConnection::Mysql(conn) => mutations::canonical::adjust_bid_window_mysql(
+                conn,
+                bid_year_id,
+                area_id,
+                user_id,
+                round_id,
+                new_window_start,
+                new_window_end,
+            ),
+        }
+    }
+
+    /// Deletes bid windows for specific users and rounds (used before recalculation).
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_year_id` - The canonical bid year ID
+    /// * `area_id` - The canonical area ID
+    /// * `user_ids` - List of user IDs
+    /// * `round_ids` - List of round IDs
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of deleted records.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn delete_bid_windows_for_users_and_rounds(
+        &mut self,
+        bid_year_id: i64,
+        area_id: i64,
+        user_ids: &[i64],
+        round_ids: &[i64],
+    ) -> Result<usize, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::canonical::delete_bid_windows_for_users_and_rounds_sqlite(
+                    conn,
+                    bid_year_id,
+                    area_id,
+                    user_ids,
+                    round_ids,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::canonical::delete_bid_windows_for_users_and_rounds_mysql(
+                    conn,
+                    bid_year_id,
+                    area_id,
+                    user_ids,
+                    round_ids,
+                )
+            }
+        }
+    }
+
+    // ========================================================================
+    // Webhooks
+    // ========================================================================
+
+    /// Creates a new webhook.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The delivery URL
+    /// * `secret` - The shared secret used to sign payloads
+    /// * `event_filter` - The audit action names to deliver
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the webhook cannot be created.
+    pub fn create_webhook(
+        &mut self,
+        url: &str,
+        secret: &str,
+        event_filter: &[String],
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::create_webhook_sqlite(conn, url, secret, event_filter)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::create_webhook_mysql(conn, url, secret, event_filter)
+            }
+        }
+    }
+
+    /// Lists all configured webhooks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_webhooks(&mut self) -> Result<Vec<WebhookData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::webhooks::list_webhooks_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::webhooks::list_webhooks_mysql(conn),
+        }
+    }
+
+    /// Retrieves a webhook by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook_id` - The webhook ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_webhook(
+        &mut self,
+        webhook_id: i64,
+    ) -> Result<Option<WebhookData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::webhooks::get_webhook_sqlite(conn, webhook_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::webhooks::get_webhook_mysql(conn, webhook_id)
+            }
+        }
+    }
+
+    /// Updates a webhook's URL, event filter, and enabled flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook_id` - The webhook ID
+    /// * `url` - The new delivery URL
+    /// * `event_filter` - The new event filter
+    /// * `is_enabled` - Whether deliveries are active
     ///
     /// # Errors
     ///
-    /// Returns an error if the update fails.
-    #[allow(clippy::too_many_arguments)]
-    pub fn update_round(
+    /// Returns an error if the database update fails.
+    pub fn update_webhook(
         &mut self,
-        round_id: i64,
-        name: &str,
-        slots_per_day: u32,
-        max_groups: u32,
-        max_total_hours: u32,
-        include_holidays: bool,
-        allow_overbid: bool,
+        webhook_id: i64,
+        url: &str,
+        event_filter: &[String],
+        is_enabled: bool,
     ) -> Result<(), PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::rounds::update_round_sqlite(
-                conn,
-                round_id,
-                name,
-                slots_per_day,
-                max_groups,
-                max_total_hours,
-                include_holidays,
-                allow_overbid,
-            ),
-            BackendConnection::Mysql(conn) => queries::rounds::update_round_mysql(
-                conn,
-                round_id,
-                name,
-                slots_per_day,
-                max_groups,
-                max_total_hours,
-                include_holidays,
-                allow_overbid,
-            ),
+            BackendConnection::Sqlite(conn) => {
+                mutations::update_webhook_sqlite(conn, webhook_id, url, event_filter, is_enabled)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::update_webhook_mysql(conn, webhook_id, url, event_filter, is_enabled)
+            }
         }
     }
 
-    /// Deletes a round.
+    /// Deletes a webhook and its dead-letter log.
     ///
     /// # Arguments
     ///
-    /// * `round_id` - The round ID
+    /// * `webhook_id` - The webhook ID
     ///
     /// # Errors
     ///
-    /// Returns an error if the delete fails.
-    pub fn delete_round(&mut self, round_id: i64) -> Result<(), PersistenceError> {
+    /// Returns an error if the database delete fails.
+    pub fn delete_webhook(&mut self, webhook_id: i64) -> Result<(), PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::rounds::delete_round_sqlite(conn, round_id),
-            BackendConnection::Mysql(conn) => queries::rounds::delete_round_mysql(conn, round_id),
+            BackendConnection::Sqlite(conn) => mutations::delete_webhook_sqlite(conn, webhook_id),
+            BackendConnection::Mysql(conn) => mutations::delete_webhook_mysql(conn, webhook_id),
         }
     }
 
-    /// Checks if a round number exists within a round group.
+    /// Records a webhook delivery that exhausted its retries.
     ///
     /// # Arguments
     ///
-    /// * `round_group_id` - The round group ID
-    /// * `round_number` - The round number
-    /// * `exclude_id` - Optional round ID to exclude from the check
+    /// * `webhook_id` - The webhook the delivery was for
+    /// * `audit_event_id` - The audit event that was being delivered
+    /// * `payload` - The JSON body that was sent
+    /// * `attempts` - The number of attempts made
+    /// * `last_error` - The error from the final attempt
     ///
     /// # Errors
     ///
-    /// Returns an error if the query fails.
-    pub fn round_number_exists(
+    /// Returns an error if the entry cannot be recorded.
+    pub fn record_webhook_dead_letter(
         &mut self,
-        round_group_id: i64,
-        round_number: u32,
-        exclude_id: Option<i64>,
-    ) -> Result<bool, PersistenceError> {
+        webhook_id: i64,
+        audit_event_id: i64,
+        payload: &str,
+        attempts: i32,
+        last_error: &str,
+    ) -> Result<i64, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => queries::rounds::round_number_exists_sqlite(
+            BackendConnection::Sqlite(conn) => mutations::record_webhook_dead_letter_sqlite(
                 conn,
-                round_group_id,
-                round_number,
-                exclude_id,
+                webhook_id,
+                audit_event_id,
+                payload,
+                attempts,
+                last_error,
             ),
-            BackendConnection::Mysql(conn) => queries::rounds::round_number_exists_mysql(
+            BackendConnection::Mysql(conn) => mutations::record_webhook_dead_letter_mysql(
                 conn,
-                round_group_id,
-                round_number,
-                exclude_id,
+                webhook_id,
+                audit_event_id,
+                payload,
+                attempts,
+                last_error,
             ),
         }
     }
 
-    /// Gets an area by its canonical ID, returning both the Area and its `bid_year_id`.
+    /// Lists dead-lettered webhook deliveries, newest first.
     ///
     /// # Arguments
     ///
-    /// * `area_id` - The canonical area ID
+    /// * `webhook_id` - Restrict to one webhook, or `None` for all
+    /// * `limit` - Maximum number of entries to return
     ///
     /// # Errors
     ///
-    /// Returns an error if the area does not exist or the query fails.
-    pub fn get_area_by_id(&mut self, area_id: i64) -> Result<(Area, i64), PersistenceError> {
+    /// Returns an error if the database query fails.
+    pub fn list_webhook_dead_letters(
+        &mut self,
+        webhook_id: Option<i64>,
+        limit: u32,
+    ) -> Result<Vec<WebhookDeadLetterData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::canonical::get_area_by_id_sqlite(conn, area_id)
+                queries::webhooks::list_webhook_dead_letters_sqlite(conn, webhook_id, limit)
             }
             BackendConnection::Mysql(conn) => {
-                queries::canonical::get_area_by_id_mysql(conn, area_id)
+                queries::webhooks::list_webhook_dead_letters_mysql(conn, webhook_id, limit)
             }
         }
     }
 
     // ========================================================================
-    // Phase 29D: Readiness Evaluation
+    // Outbox
     // ========================================================================
 
-    /// Checks if a bid year has a valid bid schedule configured.
+    /// Lists audit events waiting to be published, oldest first.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    ///
-    /// # Returns
-    ///
-    /// `true` if all bid schedule fields are set, `false` otherwise.
+    /// * `limit` - Maximum number of entries to return
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn is_bid_schedule_set(&mut self, bid_year_id: i64) -> Result<bool, PersistenceError> {
+    /// Returns an error if the database query fails.
+    pub fn list_pending_outbox_entries(
+        &mut self,
+        limit: u32,
+    ) -> Result<Vec<OutboxEntryData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::readiness::is_bid_schedule_set_sqlite(conn, bid_year_id)
+                queries::outbox::list_pending_outbox_entries_sqlite(conn, limit)
             }
             BackendConnection::Mysql(conn) => {
-                queries::readiness::is_bid_schedule_set_mysql(conn, bid_year_id)
+                queries::outbox::list_pending_outbox_entries_mysql(conn, limit)
             }
         }
     }
 
-    /// Gets non-system areas that have no rounds configured.
-    ///
-    /// # Arguments
+    /// Retrieves the outbox entry written for an audit event.
     ///
-    /// * `bid_year_id` - The canonical bid year ID
+    /// Returns `Ok(None)` for events persisted before the outbox existed
+    /// and for imported events, which are never published.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// Vector of area codes for areas missing round configuration.
+    /// * `audit_event_id` - The audit event ID
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn get_areas_missing_rounds(
+    /// Returns an error if the database query fails.
+    pub fn get_outbox_entry_for_event(
         &mut self,
-        bid_year_id: i64,
-    ) -> Result<Vec<String>, PersistenceError> {
+        audit_event_id: i64,
+    ) -> Result<Option<OutboxEntryData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::readiness::get_areas_missing_rounds_sqlite(conn, bid_year_id)
+                queries::outbox::get_outbox_entry_for_event_sqlite(conn, audit_event_id)
             }
             BackendConnection::Mysql(conn) => {
-                queries::readiness::get_areas_missing_rounds_mysql(conn, bid_year_id)
+                queries::outbox::get_outbox_entry_for_event_mysql(conn, audit_event_id)
             }
         }
     }
 
-    /// Counts users in system areas who have not been reviewed.
+    /// Marks outbox entries as delivered.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
+    /// * `outbox_ids` - The entries that were published
     ///
     /// # Returns
     ///
-    /// Count of unreviewed users in system areas (No Bid).
+    /// The number of entries that were still pending.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn count_unreviewed_no_bid_users(
+    /// Returns an error if the database update fails.
+    pub fn mark_outbox_entries_delivered(
         &mut self,
-        bid_year_id: i64,
-    ) -> Result<i64, PersistenceError> {
+        outbox_ids: &[i64],
+    ) -> Result<usize, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::readiness::count_unreviewed_no_bid_users_sqlite(conn, bid_year_id)
+                mutations::mark_outbox_entries_delivered_sqlite(conn, outbox_ids)
             }
             BackendConnection::Mysql(conn) => {
-                queries::readiness::count_unreviewed_no_bid_users_mysql(conn, bid_year_id)
+                mutations::mark_outbox_entries_delivered_mysql(conn, outbox_ids)
             }
         }
     }
 
-    /// Counts users violating the participation flag directional invariant.
-    ///
-    /// Invariant: `excluded_from_leave_calculation == true` ⇒ `excluded_from_bidding == true`
+    // ========================================================================
+    // Notifications
+    // ========================================================================
+
+    /// Creates or replaces a user's contact details.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    ///
-    /// # Returns
-    ///
-    /// Count of users violating the invariant.
+    /// * `user_id` - The canonical user ID
+    /// * `email` - The email address to notify
+    /// * `email_enabled` - Whether email notifications are sent
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn count_participation_flag_violations(
+    /// Returns an error if the database operation fails.
+    pub fn set_user_contact(
         &mut self,
-        bid_year_id: i64,
-    ) -> Result<i64, PersistenceError> {
+        user_id: i64,
+        email: &str,
+        email_enabled: bool,
+    ) -> Result<(), PersistenceError> {
+        let email: String = self.seal_column(&email_context(user_id), email);
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::readiness::count_participation_flag_violations_sqlite(conn, bid_year_id)
+                mutations::set_user_contact_sqlite(conn, user_id, &email, email_enabled)
             }
             BackendConnection::Mysql(conn) => {
-                queries::readiness::count_participation_flag_violations_mysql(conn, bid_year_id)
+                mutations::set_user_contact_mysql(conn, user_id, &email, email_enabled)
             }
         }
     }
 
-    /// Marks a user in a system area as reviewed.
+    /// Removes a user's contact details.
     ///
     /// # Arguments
     ///
-    /// * `user_id` - The user's canonical ID
+    /// * `user_id` - The canonical user ID
+    ///
+    /// # Returns
+    ///
+    /// `true` if contact details were removed.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be updated.
-    pub fn mark_user_no_bid_reviewed(&mut self, user_id: i64) -> Result<(), PersistenceError> {
+    /// Returns an error if the database delete fails.
+    pub fn delete_user_contact(&mut self, user_id: i64) -> Result<bool, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => {
-                queries::readiness::mark_user_no_bid_reviewed_sqlite(conn, user_id)
-            }
-            BackendConnection::Mysql(conn) => {
-                queries::readiness::mark_user_no_bid_reviewed_mysql(conn, user_id)
-            }
+            BackendConnection::Sqlite(conn) => mutations::delete_user_contact_sqlite(conn, user_id),
+            BackendConnection::Mysql(conn) => mutations::delete_user_contact_mysql(conn, user_id),
         }
     }
 
-    /// Gets all users grouped by area for seniority conflict detection.
-    ///
-    /// Returns users in non-system areas only.
+    /// Retrieves a user's contact details.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    ///
-    /// # Returns
-    ///
-    /// Vector of tuples containing (`area_id`, `area_code`, users in that area).
+    /// * `user_id` - The canonical user ID
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn get_users_by_area_for_conflict_detection(
+    /// Returns an error if the database query fails.
+    pub fn get_user_contact(
         &mut self,
-        bid_year_id: i64,
-    ) -> Result<Vec<(i64, String, Vec<zab_bid_domain::User>)>, PersistenceError> {
-        match &mut self.conn {
+        user_id: i64,
+    ) -> Result<Option<UserContactData>, PersistenceError> {
+        let contact: Option<UserContactData> = match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::readiness::get_users_by_area_for_conflict_detection_sqlite(
-                    conn,
-                    bid_year_id,
-                )
+                queries::notifications::get_user_contact_sqlite(conn, user_id)
             }
             BackendConnection::Mysql(conn) => {
-                queries::readiness::get_users_by_area_for_conflict_detection_mysql(
-                    conn,
-                    bid_year_id,
-                )
-            }
-        }
+                queries::notifications::get_user_contact_mysql(conn, user_id)
+            }
+        }?;
+        contact
+            .map(|mut contact| {
+                contact.email = self.open_column(&email_context(user_id), &contact.email)?;
+                Ok(contact)
+            })
+            .transpose()
     }
 
-    /// Get user information by ID.
-    ///
-    /// Returns a simple struct with user initials for display purposes.
-    ///
-    /// # Arguments
-    ///
-    /// * `user_id` - The canonical user ID
+    /// Lists bid windows in `BiddingActive` bid years whose users can be emailed.
     ///
     /// # Errors
     ///
-    /// Returns an error if the user does not exist or the database operation fails.
-    pub fn get_user_by_id(&mut self, user_id: i64) -> Result<UserInfo, PersistenceError> {
-        let (_bid_year_id, initials) = self.get_user_details(user_id)?;
-        Ok(UserInfo { initials })
+    /// Returns an error if the database query fails.
+    pub fn list_window_notification_candidates(
+        &mut self,
+    ) -> Result<Vec<WindowNotificationCandidate>, PersistenceError> {
+        let candidates: Vec<WindowNotificationCandidate> = match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::notifications::list_window_notification_candidates_sqlite(conn)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::notifications::list_window_notification_candidates_mysql(conn)
+            }
+        }?;
+        candidates
+            .into_iter()
+            .map(|mut candidate| {
+                candidate.email =
+                    self.open_column(&email_context(candidate.user_id), &candidate.email)?;
+                Ok(candidate)
+            })
+            .collect()
     }
 
-    /// Get round information by ID.
-    ///
-    /// Returns round details including the round name.
+    /// Lists bid status transitions after `after_history_id` for users who can be emailed.
     ///
     /// # Arguments
     ///
-    /// * `round_id` - The round ID
+    /// * `after_history_id` - Only return transitions with a greater history ID
+    /// * `statuses` - The new statuses of interest
+    /// * `limit` - Maximum number of transitions to return
     ///
     /// # Errors
     ///
-    /// Returns an error if the round does not exist or the database operation fails.
-    pub fn get_round_by_id(&mut self, round_id: i64) -> Result<RoundInfo, PersistenceError> {
-        let round = self.get_round(round_id)?;
-        Ok(RoundInfo {
-            round_name: round.name().to_string(),
-        })
+    /// Returns an error if the database query fails.
+    pub fn list_bid_entry_notification_candidates(
+        &mut self,
+        after_history_id: i64,
+        statuses: &[&str],
+        limit: u32,
+    ) -> Result<Vec<BidEntryNotificationCandidate>, PersistenceError> {
+        let candidates: Vec<BidEntryNotificationCandidate> = match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::notifications::list_bid_entry_notification_candidates_sqlite(
+                    conn,
+                    after_history_id,
+                    statuses,
+                    limit,
+                )
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::notifications::list_bid_entry_notification_candidates_mysql(
+                    conn,
+                    after_history_id,
+                    statuses,
+                    limit,
+                )
+            }
+        }?;
+        candidates
+            .into_iter()
+            .map(|mut candidate| {
+                candidate.email =
+                    self.open_column(&email_context(candidate.user_id), &candidate.email)?;
+                Ok(candidate)
+            })
+            .collect()
     }
 
-    /// Get bid status for an area.
-    ///
-    /// Returns all bid status records for users in the specified area.
-    ///
-    /// # Arguments
-    ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `area_id` - The canonical area ID
+    /// Retrieves the highest bid status history ID.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn get_bid_status_for_area(
-        &mut self,
-        bid_year_id: i64,
-        area_id: i64,
-    ) -> Result<Vec<BidStatusRow>, PersistenceError> {
+    /// Returns an error if the database query fails.
+    pub fn get_latest_bid_status_history_id(&mut self) -> Result<Option<i64>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::bid_status::get_bid_status_for_area_sqlite(conn, bid_year_id, area_id)
+                queries::notifications::get_latest_bid_status_history_id_sqlite(conn)
             }
             BackendConnection::Mysql(conn) => {
-                queries::bid_status::get_bid_status_for_area_mysql(conn, bid_year_id, area_id)
+                queries::notifications::get_latest_bid_status_history_id_mysql(conn)
             }
         }
     }
 
-    /// Get bid status for a specific user and round.
+    /// Returns true if a notification of `kind` has been recorded for `reference_id`.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `area_id` - The canonical area ID
-    /// * `user_id` - The canonical user ID
-    /// * `round_id` - The round ID
+    /// * `kind` - The notification kind
+    /// * `reference_id` - The bid window or history ID the notification is about
     ///
     /// # Errors
     ///
-    /// Returns an error if the record is not found or the database cannot be queried.
-    pub fn get_bid_status_for_user_and_round(
+    /// Returns an error if the database query fails.
+    pub fn notification_exists(
         &mut self,
-        bid_year_id: i64,
-        area_id: i64,
-        user_id: i64,
-        round_id: i64,
-    ) -> Result<BidStatusRow, PersistenceError> {
+        kind: &str,
+        reference_id: i64,
+    ) -> Result<bool, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::bid_status::get_bid_status_for_user_and_round_sqlite(
-                    conn,
-                    bid_year_id,
-                    area_id,
-                    user_id,
-                    round_id,
-                )
-            }
-            BackendConnection::Mysql(conn) => {
-                queries::bid_status::get_bid_status_for_user_and_round_mysql(
-                    conn,
-                    bid_year_id,
-                    area_id,
-                    user_id,
-                    round_id,
-                )
+                queries::notifications::notification_exists_sqlite(conn, kind, reference_id)
             }
-        }?
-        .ok_or_else(|| {
-            PersistenceError::NotFound(format!(
-                "Bid status not found for user {user_id} in round {round_id}"
-            ))
-        })
+            BackendConnection::Mysql(conn) => {
+                queries::notifications::notification_exists_mysql(conn, kind, reference_id)
+            }
+        }
     }
 
-    /// Get bid status by ID.
+    /// Records a notification in the notification log.
     ///
     /// # Arguments
     ///
-    /// * `bid_status_id` - The bid status record ID
+    /// * `user_id` - The user who was notified
+    /// * `kind` - The notification kind
+    /// * `reference_id` - The bid window or history ID the notification is about
+    /// * `recipient` - The address the notification was sent to
+    /// * `subject` - The rendered subject line
+    /// * `error` - The delivery error, or `None` if the notification was delivered
     ///
     /// # Errors
     ///
-    /// Returns an error if the record is not found or the database cannot be queried.
-    pub fn get_bid_status_by_id(
+    /// Returns an error if the entry cannot be recorded.
+    pub fn record_notification(
         &mut self,
-        bid_status_id: i64,
-    ) -> Result<BidStatusRow, PersistenceError> {
+        user_id: i64,
+        kind: &str,
+        reference_id: i64,
+        recipient: &str,
+        subject: &str,
+        error: Option<&str>,
+    ) -> Result<i64, PersistenceError> {
+        let recipient: String = self.seal_column(&recipient_context(user_id), recipient);
         match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::record_notification_sqlite(
+                conn,
+                user_id,
+                kind,
+                reference_id,
+                &recipient,
+                subject,
+                error,
+            ),
+            BackendConnection::Mysql(conn) => mutations::record_notification_mysql(
+                conn,
+                user_id,
+                kind,
+                reference_id,
+                &recipient,
+                subject,
+                error,
+            ),
+        }
+    }
+
+    /// Lists notifications recorded for a user, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The canonical user ID
+    /// * `limit` - Maximum number of entries to return
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_notifications_for_user(
+        &mut self,
+        user_id: i64,
+        limit: u32,
+    ) -> Result<Vec<NotificationLogData>, PersistenceError> {
+        let entries: Vec<NotificationLogData> = match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::bid_status::get_bid_status_by_id_sqlite(conn, bid_status_id)
+                queries::notifications::list_notifications_for_user_sqlite(conn, user_id, limit)
             }
             BackendConnection::Mysql(conn) => {
-                queries::bid_status::get_bid_status_by_id_mysql(conn, bid_status_id)
-            }
-        }?
-        .ok_or_else(|| PersistenceError::NotFound(format!("Bid status {bid_status_id} not found")))
+                queries::notifications::list_notifications_for_user_mysql(conn, user_id, limit)
+            }
+        }?;
+        entries
+            .into_iter()
+            .map(|mut entry| {
+                entry.recipient =
+                    self.open_column(&recipient_context(entry.user_id), &entry.recipient)?;
+                Ok(entry)
+            })
+            .collect()
     }
 
-    /// Get bid status history for a bid status record.
+    // ========================================================================
+    // Chat Channels
+    // ========================================================================
+
+    /// Creates a chat channel.
     ///
     /// # Arguments
     ///
-    /// * `bid_status_id` - The bid status record ID
+    /// * `bid_year_id` - The bid year announced to this channel
+    /// * `area_id` - The area announced to this channel, or `None` for every area
+    /// * `provider` - The incoming webhook provider (`slack` or `teams`)
+    /// * `url` - The incoming webhook URL
     ///
     /// # Errors
     ///
-    /// Returns an error if the database cannot be queried.
-    pub fn get_bid_status_history(
+    /// Returns an error if the channel cannot be created.
+    pub fn create_chat_channel(
         &mut self,
-        bid_status_id: i64,
-    ) -> Result<Vec<BidStatusHistoryRow>, PersistenceError> {
+        bid_year_id: i64,
+        area_id: Option<i64>,
+        provider: &str,
+        url: &str,
+    ) -> Result<i64, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                queries::bid_status::get_bid_status_history_sqlite(conn, bid_status_id)
+                mutations::create_chat_channel_sqlite(conn, bid_year_id, area_id, provider, url)
             }
             BackendConnection::Mysql(conn) => {
-                queries::bid_status::get_bid_status_history_mysql(conn, bid_status_id)
+                mutations::create_chat_channel_mysql(conn, bid_year_id, area_id, provider, url)
             }
         }
     }
 
-    /// Update bid status.
-    ///
-    /// # Arguments
-    ///
-    /// * `bid_status_id` - The bid status record ID
-    /// * `new_status` - The new status string
-    /// * `updated_at` - The update timestamp
-    /// * `updated_by` - The operator ID making the update
-    /// * `notes` - Optional notes
+    /// Updates a chat channel's provider, URL, and enabled flag.
     ///
     /// # Errors
     ///
     /// Returns an error if the database update fails.
-    pub fn update_bid_status(
+    pub fn update_chat_channel(
         &mut self,
-        bid_status_id: i64,
-        new_status: &str,
-        updated_at: &str,
-        updated_by: i64,
-        notes: Option<&str>,
+        chat_channel_id: i64,
+        provider: &str,
+        url: &str,
+        is_enabled: bool,
     ) -> Result<(), PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::bid_status::update_bid_status_sqlite(
+            BackendConnection::Sqlite(conn) => mutations::update_chat_channel_sqlite(
                 conn,
-                bid_status_id,
-                new_status,
-                updated_at,
-                updated_by,
-                notes.map(ToString::to_string),
+                chat_channel_id,
+                provider,
+                url,
+                is_enabled,
             ),
-            BackendConnection::Mysql(conn) => mutations::bid_status::update_bid_status_mysql(
+            BackendConnection::Mysql(conn) => mutations::update_chat_channel_mysql(
                 conn,
-                bid_status_id,
-                new_status,
-                updated_at,
-                updated_by,
-                notes.map(ToString::to_string),
+                chat_channel_id,
+                provider,
+                url,
+                is_enabled,
             ),
         }
     }
 
-    /// Insert bid status history record.
+    /// Deletes a chat channel and its announcement log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database delete fails.
+    pub fn delete_chat_channel(&mut self, chat_channel_id: i64) -> Result<(), PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                mutations::delete_chat_channel_sqlite(conn, chat_channel_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                mutations::delete_chat_channel_mysql(conn, chat_channel_id)
+            }
+        }
+    }
+
+    /// Lists chat channels ordered by ID.
     ///
     /// # Arguments
     ///
-    /// * `bid_status_id` - The bid status record ID
-    /// * `audit_event_id` - The audit event ID
-    /// * `previous_status` - The previous status (if any)
-    /// * `new_status` - The new status
-    /// * `transitioned_at` - The transition timestamp
-    /// * `transitioned_by` - The operator ID making the transition
-    /// * `notes` - Optional notes
+    /// * `bid_year_id` - Restrict to one bid year, or `None` for all
     ///
     /// # Errors
     ///
-    /// Returns an error if the database insert fails.
-    #[allow(clippy::too_many_arguments)]
-    pub fn insert_bid_status_history(
+    /// Returns an error if the database query fails.
+    pub fn list_chat_channels(
         &mut self,
-        bid_status_id: i64,
-        audit_event_id: i64,
-        previous_status: Option<&str>,
-        new_status: &str,
-        transitioned_at: &str,
-        transitioned_by: i64,
-        notes: Option<&str>,
-    ) -> Result<(), PersistenceError> {
+        bid_year_id: Option<i64>,
+    ) -> Result<Vec<ChatChannelData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::bid_status::insert_bid_status_history_sqlite(
-                    conn,
-                    bid_status_id,
-                    audit_event_id,
-                    previous_status,
-                    new_status,
-                    transitioned_at,
-                    transitioned_by,
-                    notes,
-                )
+                queries::chat::list_chat_channels_sqlite(conn, bid_year_id)
             }
             BackendConnection::Mysql(conn) => {
-                mutations::bid_status::insert_bid_status_history_mysql(
-                    conn,
-                    bid_status_id,
-                    audit_event_id,
-                    previous_status,
-                    new_status,
-                    transitioned_at,
-                    transitioned_by,
-                    notes,
-                )
+                queries::chat::list_chat_channels_mysql(conn, bid_year_id)
             }
         }
     }
 
-    /// Get the next audit event ID (temporary helper for Phase 29F).
-    ///
-    /// This is a placeholder until proper audit event creation is integrated.
+    /// Retrieves a chat channel by ID.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// The next available audit event ID.
+    /// Returns an error if the database query fails.
+    /// Returns `Ok(None)` if the channel is not found.
+    pub fn get_chat_channel(
+        &mut self,
+        chat_channel_id: i64,
+    ) -> Result<Option<ChatChannelData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::chat::get_chat_channel_sqlite(conn, chat_channel_id)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::chat::get_chat_channel_mysql(conn, chat_channel_id)
+            }
+        }
+    }
+
+    /// Lists every bid window in `BiddingActive` bid years.
     ///
     /// # Errors
     ///
-    /// Currently always returns `Ok`, but signature allows for future error cases.
-    #[allow(clippy::missing_const_for_fn)]
-    pub fn get_next_audit_event_id(&mut self) -> Result<i64, PersistenceError> {
-        // This is a simplified implementation - in production, this would be
-        // part of the audit event creation flow
-        Ok(1)
+    /// Returns an error if the database query fails.
+    pub fn list_active_bid_windows(
+        &mut self,
+    ) -> Result<Vec<ActiveBidWindowData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => queries::chat::list_active_bid_windows_sqlite(conn),
+            BackendConnection::Mysql(conn) => queries::chat::list_active_bid_windows_mysql(conn),
+        }
     }
 
-    // ========================================================================
-    // Phase 29G: Post-Confirmation Bid Order Adjustments
-    // ========================================================================
-
-    /// Adjusts a bid window for a specific user and round.
+    /// Records a chat announcement in the announcement log.
     ///
     /// # Arguments
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `area_id` - The canonical area ID
-    /// * `user_id` - The canonical user ID
-    /// * `round_id` - The round ID
-    /// * `new_window_start` - The new window start datetime (ISO 8601)
-    /// * `new_window_end` - The new window end datetime (ISO 8601)
+    /// * `chat_channel_id` - The channel the announcement was posted to
+    /// * `kind` - The announcement kind
+    /// * `reference_key` - What the announcement is about
+    /// * `message` - The posted text
+    /// * `error` - The delivery error, or `None` if the announcement was delivered
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns a tuple of (`previous_window_start`, `previous_window_end`).
+    /// Returns an error if the entry cannot be recorded.
+    pub fn record_chat_notification(
+        &mut self,
+        chat_channel_id: i64,
+        kind: &str,
+        reference_key: &str,
+        message: &str,
+        error: Option<&str>,
+    ) -> Result<i64, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => mutations::record_chat_notification_sqlite(
+                conn,
+                chat_channel_id,
+                kind,
+                reference_key,
+                message,
+                error,
+            ),
+            BackendConnection::Mysql(conn) => mutations::record_chat_notification_mysql(
+                conn,
+                chat_channel_id,
+                kind,
+                reference_key,
+                message,
+                error,
+            ),
+        }
+    }
+
+    /// Checks whether an announcement was already recorded for a channel.
     ///
     /// # Errors
     ///
-    /// Returns an error if the bid window record does not exist or the database operation fails.
-    pub fn adjust_bid_window(
+    /// Returns an error if the database query fails.
+    pub fn chat_notification_exists(
         &mut self,
-        bid_year_id: i64,
-        area_id: i64,
-        user_id: i64,
-        round_id: i64,
-        new_window_start: &str,
-        new_window_end: &str,
-    ) -> Result<(String, String), PersistenceError> {
+        chat_channel_id: i64,
+        kind: &str,
+        reference_key: &str,
+    ) -> Result<bool, PersistenceError> {
         match &mut self.conn {
-            BackendConnection::Sqlite(conn) => mutations::canonical::adjust_bid_window_sqlite(
+            BackendConnection::Sqlite(conn) => queries::chat::chat_notification_exists_sqlite(
                 conn,
-                bid_year_id,
-                area_id,
-                user_id,
-                round_id,
-                new_window_start,
-                new_window_end,
+                chat_channel_id,
+                kind,
+                reference_key,
             ),
-            BackendConnection::Mysql(conn) => mutations::canonical::adjust_bid_window_mysql(
+            BackendConnection::Mysql(conn) => queries::chat::chat_notification_exists_mysql(
                 conn,
-                bid_year_id,
-                area_id,
-                user_id,
-                round_id,
-                new_window_start,
-                new_window_end,
+                chat_channel_id,
+                kind,
+                reference_key,
             ),
         }
     }
 
-    /// Deletes bid windows for specific users and rounds (used before recalculation).
+    /// Retrieves the most recent announcement of a kind recorded for a channel.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `bid_year_id` - The canonical bid year ID
-    /// * `area_id` - The canonical area ID
-    /// * `user_ids` - List of user IDs
-    /// * `round_ids` - List of round IDs
+    /// Returns an error if the database query fails.
+    /// Returns `Ok(None)` if nothing of that kind was recorded.
+    pub fn get_latest_chat_notification(
+        &mut self,
+        chat_channel_id: i64,
+        kind: &str,
+    ) -> Result<Option<ChatNotificationLogData>, PersistenceError> {
+        match &mut self.conn {
+            BackendConnection::Sqlite(conn) => {
+                queries::chat::get_latest_chat_notification_sqlite(conn, chat_channel_id, kind)
+            }
+            BackendConnection::Mysql(conn) => {
+                queries::chat::get_latest_chat_notification_mysql(conn, chat_channel_id, kind)
+            }
+        }
+    }
+
+    /// Lists recorded chat announcements, newest first.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// Returns the number of deleted records.
+    /// * `chat_channel_id` - Restrict to one channel, or `None` for all
+    /// * `limit` - Maximum number of entries to return
     ///
     /// # Errors
     ///
-    /// Returns an error if the database operation fails.
-    pub fn delete_bid_windows_for_users_and_rounds(
+    /// Returns an error if the database query fails.
+    pub fn list_chat_notifications(
         &mut self,
-        bid_year_id: i64,
-        area_id: i64,
-        user_ids: &[i64],
-        round_ids: &[i64],
-    ) -> Result<usize, PersistenceError> {
+        chat_channel_id: Option<i64>,
+        limit: u32,
+    ) -> Result<Vec<ChatNotificationLogData>, PersistenceError> {
         match &mut self.conn {
             BackendConnection::Sqlite(conn) => {
-                mutations::canonical::delete_bid_windows_for_users_and_rounds_sqlite(
-                    conn,
-                    bid_year_id,
-                    area_id,
-                    user_ids,
-                    round_ids,
-                )
+                queries::chat::list_chat_notifications_sqlite(conn, chat_channel_id, limit)
             }
             BackendConnection::Mysql(conn) => {
-                mutations::canonical::delete_bid_windows_for_users_and_rounds_mysql(
-                    conn,
-                    bid_year_id,
-                    area_id,
-                    user_ids,
-                    round_ids,
-                )
+                queries::chat::list_chat_notifications_mysql(conn, chat_channel_id, limit)
             }
         }
     }
 }
 
+/// Loads the current state of each of `areas` over one connection.
+fn load_area_states(
+    conn: &mut BackendConnection,
+    bid_year_id: i64,
+    bid_year: &BidYear,
+    areas: &[Area],
+) -> Result<Vec<State>, PersistenceError> {
+    areas
+        .iter()
+        .map(|area| {
+            let area_id: i64 = area.area_id().ok_or_else(|| {
+                PersistenceError::ReconstructionError(format!("Area {} has no ID", area.id()))
+            })?;
+            match conn {
+                BackendConnection::Sqlite(conn) => {
+                    queries::get_current_state_sqlite(conn, bid_year_id, area_id, bid_year, area)
+                }
+                BackendConnection::Mysql(conn) => {
+                    queries::get_current_state_mysql(conn, bid_year_id, area_id, bid_year, area)
+                }
+            }
+        })
+        .collect()
+}
+
 /// Simple user info struct for display purposes.
 #[derive(Debug, Clone)]
 pub struct UserInfo {
@@ -0,0 +1,180 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! User anonymization mutations.
+//!
+//! This module contains backend-agnostic mutations for erasing a former
+//! employee's personal data once their records are no longer needed. Names
+//! and seniority dates are replaced with tombstone values in the users
+//! table and in every stored state snapshot; initials, bids, and the audit
+//! log are left intact so history still reads the same.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+use zab_bid::State;
+use zab_bid_domain::{SeniorityData, User};
+
+use crate::data_models::SnapshotEncoding;
+use crate::diesel_schema::{
+    notification_log, portal_links, state_snapshots, user_anonymizations, user_contacts, users,
+};
+use crate::error::PersistenceError;
+use crate::mutations::audit::encode_state;
+use crate::queries::state::StateSnapshotRow;
+
+/// The name recorded in place of an anonymized user's name.
+pub const ANONYMIZED_NAME: &str = "Former employee";
+
+/// The date recorded in place of an anonymized user's seniority dates.
+pub const ANONYMIZED_DATE: &str = "1900-01-01";
+
+/// The recipient recorded in place of an anonymized user's email address.
+pub const ANONYMIZED_RECIPIENT: &str = "anonymized";
+
+/// Number of snapshots decoded at a time while rewriting.
+const SNAPSHOT_PAGE_SIZE: i64 = 200;
+
+/// Replaces a user's personal data with tombstone values.
+fn scrub_user(user: &mut User) {
+    user.name = String::from(ANONYMIZED_NAME);
+    user.seniority_data = SeniorityData::new(
+        String::from(ANONYMIZED_DATE),
+        String::from(ANONYMIZED_DATE),
+        String::from(ANONYMIZED_DATE),
+        String::from(ANONYMIZED_DATE),
+        user.seniority_data.lottery_value,
+    );
+}
+
+/// Scrubs the matching user from a snapshot's state.
+///
+/// Users captured before they were first persisted carry no `user_id`, so
+/// those are matched by the initials the user held.
+///
+/// # Returns
+///
+/// The rewritten state, or `None` if the user is not in the snapshot.
+fn scrub_snapshot(state: State, user_id: i64, held_initials: &[String]) -> Option<State> {
+    let mut found: bool = false;
+    let users: Vec<User> = state
+        .users
+        .values()
+        .cloned()
+        .map(|mut user| {
+            let matches: bool = user.user_id.map_or_else(
+                || held_initials.iter().any(|i| i == user.initials.value()),
+                |id| id == user_id,
+            );
+            if matches {
+                scrub_user(&mut user);
+                found = true;
+            }
+            user
+        })
+        .collect();
+
+    found.then(|| State::with_users(state.bid_year, state.area, users))
+}
+
+backend_fn! {
+/// Erases a user's personal data and records the erasure.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The user's bid year
+/// * `user_id` - The user to anonymize
+/// * `held_initials` - Every initials the user has held in the bid year
+/// * `event_id` - The audit event recording the erasure
+///
+/// # Returns
+///
+/// The number of state snapshots rewritten.
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the user does not exist, or an
+/// error if a snapshot cannot be decoded or the database update fails.
+pub fn anonymize_user(
+    conn: &mut _,
+    bid_year_id: i64,
+    user_id: i64,
+    held_initials: &[String],
+    event_id: i64,
+) -> Result<usize, PersistenceError> {
+    let updated: usize = diesel::update(users::table)
+        .filter(users::user_id.eq(user_id))
+        .filter(users::bid_year_id.eq(bid_year_id))
+        .set((
+            users::name.eq(ANONYMIZED_NAME),
+            users::cumulative_natca_bu_date.eq(ANONYMIZED_DATE),
+            users::natca_bu_date.eq(ANONYMIZED_DATE),
+            users::eod_faa_date.eq(ANONYMIZED_DATE),
+            users::service_computation_date.eq(ANONYMIZED_DATE),
+        ))
+        .execute(conn)?;
+    if updated == 0 {
+        return Err(PersistenceError::NotFound(format!(
+            "User {user_id} not found in bid year {bid_year_id}"
+        )));
+    }
+
+    diesel::delete(user_contacts::table.filter(user_contacts::user_id.eq(user_id)))
+        .execute(conn)?;
+    diesel::delete(portal_links::table.filter(portal_links::user_id.eq(user_id))).execute(conn)?;
+    diesel::update(notification_log::table)
+        .filter(notification_log::user_id.eq(user_id))
+        .set(notification_log::recipient.eq(ANONYMIZED_RECIPIENT))
+        .execute(conn)?;
+
+    let mut snapshots_rewritten: usize = 0;
+    let mut after_snapshot_id: i64 = 0;
+    loop {
+        let rows: Vec<(i64, StateSnapshotRow)> = state_snapshots::table
+            .filter(state_snapshots::bid_year_id.eq(bid_year_id))
+            .filter(state_snapshots::snapshot_id.gt(after_snapshot_id))
+            .order_by(state_snapshots::snapshot_id.asc())
+            .limit(SNAPSHOT_PAGE_SIZE)
+            .select((state_snapshots::snapshot_id, StateSnapshotRow::as_select()))
+            .load(conn)?;
+        let Some((last_snapshot_id, _)) = rows.last() else {
+            break;
+        };
+        after_snapshot_id = *last_snapshot_id;
+
+        for (snapshot_id, row) in rows {
+            let encoding: SnapshotEncoding = row.encoding()?;
+            let (state, _) = row.into_state()?;
+            let Some(scrubbed) = scrub_snapshot(state, user_id, held_initials) else {
+                continue;
+            };
+            let (state_json, state_binary) = encode_state(&scrubbed, encoding)?;
+            diesel::update(state_snapshots::table)
+                .filter(state_snapshots::snapshot_id.eq(snapshot_id))
+                .set((
+                    state_snapshots::state_json.eq(state_json),
+                    state_snapshots::state_binary.eq(state_binary),
+                ))
+                .execute(conn)?;
+            snapshots_rewritten += 1;
+        }
+    }
+
+    diesel::insert_into(user_anonymizations::table)
+        .values((
+            user_anonymizations::user_id.eq(user_id),
+            user_anonymizations::bid_year_id.eq(bid_year_id),
+            user_anonymizations::event_id.eq(event_id),
+            user_anonymizations::snapshots_rewritten
+                .eq(i32::try_from(snapshots_rewritten).unwrap_or(i32::MAX)),
+        ))
+        .execute(conn)?;
+
+    info!(user_id, bid_year_id, event_id, snapshots_rewritten, "User anonymized");
+
+    Ok(snapshots_rewritten)
+}
+}
@@ -12,18 +12,125 @@
 use diesel::prelude::*;
 use diesel::{MysqlConnection, SqliteConnection};
 use tracing::debug;
-use zab_bid::State;
+use zab_bid::{IdGenerator, State};
 use zab_bid_audit::AuditEvent;
-use zab_bid_domain::Area;
+use zab_bid_domain::{Area, User};
 
 use crate::backend::PersistenceBackend;
-use crate::data_models::{ActionData, ActorData, CauseData, StateData, StateSnapshotData};
+use crate::data_models::{
+    ActionData, ActorData, BinaryStateData, CauseData, SnapshotEncoding, StateData,
+    StateSnapshotData,
+};
 use crate::diesel_schema;
 use crate::error::PersistenceError;
 use crate::queries::canonical::{
     lookup_area_id_mysql, lookup_area_id_sqlite, lookup_bid_year_id_mysql,
     lookup_bid_year_id_sqlite,
 };
+use crate::queries::operators::SYSTEM_OPERATOR_ID;
+
+/// The JSON columns written for one audit event.
+///
+/// Historical replay reads these columns back, so their shape must not
+/// change without a migration.
+#[allow(clippy::struct_field_names)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializedAuditEvent {
+    /// The `actor_json` column.
+    pub actor_json: String,
+    /// The `cause_json` column.
+    pub cause_json: String,
+    /// The `action_json` column.
+    pub action_json: String,
+    /// The `before_snapshot_json` column.
+    pub before_json: String,
+    /// The `after_snapshot_json` column.
+    pub after_json: String,
+}
+
+/// Serializes an audit event into the JSON columns it is persisted as.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn serialize_audit_event(event: &AuditEvent) -> Result<SerializedAuditEvent, PersistenceError> {
+    let actor_data: ActorData = ActorData {
+        id: event.actor.id.clone(),
+        actor_type: event.actor.actor_type.clone(),
+    };
+
+    let cause_data: CauseData = CauseData {
+        id: event.cause.id.clone(),
+        description: event.cause.description.clone(),
+    };
+
+    let action_data: ActionData = ActionData {
+        name: event.action.name.clone(),
+        details: event.action.details.clone(),
+    };
+
+    let before_data: StateSnapshotData = StateSnapshotData {
+        data: event.before.data.clone(),
+    };
+
+    let after_data: StateSnapshotData = StateSnapshotData {
+        data: event.after.data.clone(),
+    };
+
+    Ok(SerializedAuditEvent {
+        actor_json: serde_json::to_string(&actor_data)?,
+        cause_json: serde_json::to_string(&cause_data)?,
+        action_json: serde_json::to_string(&action_data)?,
+        before_json: serde_json::to_string(&before_data)?,
+        after_json: serde_json::to_string(&after_data)?,
+    })
+}
+
+/// Serializes a state into the JSON persisted as a full state snapshot.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn serialize_state(state: &State) -> Result<String, PersistenceError> {
+    let state_data: StateData = StateData {
+        bid_year: state.bid_year.year(),
+        area: state.area.id().to_string(),
+        users_json: serde_json::to_string(&state.users.values().collect::<Vec<&User>>())?,
+    };
+
+    Ok(serde_json::to_string(&state_data)?)
+}
+
+/// Serializes a state into the binary persisted as a full state snapshot.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn serialize_state_binary(state: &State) -> Result<Vec<u8>, PersistenceError> {
+    let state_data: BinaryStateData<&User> = BinaryStateData {
+        bid_year: state.bid_year.year(),
+        area: state.area.id().to_string(),
+        users: state.users.values().collect(),
+    };
+
+    Ok(postcard::to_stdvec(&state_data)?)
+}
+
+/// Serializes a state for a snapshot in the given encoding.
+///
+/// # Returns
+///
+/// The `state_json` and `state_binary` column values. Binary snapshots
+/// leave `state_json` empty.
+pub fn encode_state(
+    state: &State,
+    encoding: SnapshotEncoding,
+) -> Result<(String, Option<Vec<u8>>), PersistenceError> {
+    match encoding {
+        SnapshotEncoding::Json => Ok((serialize_state(state)?, None)),
+        SnapshotEncoding::Postcard => Ok((String::new(), Some(serialize_state_binary(state)?))),
+    }
+}
 
 /// Persists an audit event (`SQLite` version).
 ///
@@ -33,6 +140,7 @@ use crate::queries::canonical::{
 ///
 /// * `conn` - The active database connection
 /// * `event` - The audit event to persist
+/// * `ulids` - Where the event's ULID is drawn from, if it has none
 ///
 /// # Returns
 ///
@@ -44,6 +152,7 @@ use crate::queries::canonical::{
 pub fn persist_audit_event_sqlite(
     conn: &mut SqliteConnection,
     event: &AuditEvent,
+    ulids: &dyn IdGenerator,
 ) -> Result<i64, PersistenceError> {
     // Look up canonical IDs if bid_year and area are present (Phase 23B)
     let (bid_year_id, area_id): (Option<i64>, Option<i64>) = match (&event.bid_year, &event.area) {
@@ -64,7 +173,7 @@ pub fn persist_audit_event_sqlite(
         }
     };
 
-    persist_audit_event_with_ids_sqlite(conn, event, bid_year_id, area_id)
+    persist_audit_event_with_ids_sqlite(conn, event, bid_year_id, area_id, ulids)
 }
 
 /// Persists an audit event (`MySQL` version).
@@ -75,6 +184,7 @@ pub fn persist_audit_event_sqlite(
 ///
 /// * `conn` - The active database connection
 /// * `event` - The audit event to persist
+/// * `ulids` - Where the event's ULID is drawn from, if it has none
 ///
 /// # Returns
 ///
@@ -86,6 +196,7 @@ pub fn persist_audit_event_sqlite(
 pub fn persist_audit_event_mysql(
     conn: &mut MysqlConnection,
     event: &AuditEvent,
+    ulids: &dyn IdGenerator,
 ) -> Result<i64, PersistenceError> {
     // Look up canonical IDs if bid_year and area are present (Phase 23B)
     let (bid_year_id, area_id): (Option<i64>, Option<i64>) = match (&event.bid_year, &event.area) {
@@ -106,7 +217,7 @@ pub fn persist_audit_event_mysql(
         }
     };
 
-    persist_audit_event_with_ids_mysql(conn, event, bid_year_id, area_id)
+    persist_audit_event_with_ids_mysql(conn, event, bid_year_id, area_id, ulids)
 }
 
 backend_fn! {
@@ -117,12 +228,16 @@ backend_fn! {
 /// Phase 23A: `area_id` is optional to support `CreateBidYear` events.
 /// Phase 23B: `bid_year_id` is also optional to support global events.
 ///
+/// The event is also added to the transactional outbox, to be published
+/// by the relay once the caller's transaction commits.
+///
 /// # Arguments
 ///
 /// * `conn` - The active database connection
 /// * `event` - The audit event to persist
 /// * `bid_year_id` - The bid year ID (None for global events)
 /// * `area_id` - The area ID (None for global or bid-year-only events)
+/// * `ulids` - Where the event's ULID is drawn from, if it has none
 ///
 /// # Returns
 ///
@@ -136,32 +251,12 @@ pub fn persist_audit_event_with_ids(
     event: &AuditEvent,
     bid_year_id: Option<i64>,
     area_id: Option<i64>,
+    ulids: &dyn IdGenerator,
 ) -> Result<i64, PersistenceError> {
-    let actor_data: ActorData = ActorData {
-        id: event.actor.id.clone(),
-        actor_type: event.actor.actor_type.clone(),
-    };
-
-    let cause_data: CauseData = CauseData {
-        id: event.cause.id.clone(),
-        description: event.cause.description.clone(),
-    };
-
-    let action_data: ActionData = ActionData {
-        name: event.action.name.clone(),
-        details: event.action.details.clone(),
-    };
-
-    let before_data: StateSnapshotData = StateSnapshotData {
-        data: event.before.data.clone(),
-    };
-
-    let after_data: StateSnapshotData = StateSnapshotData {
-        data: event.after.data.clone(),
-    };
+    let serialized: SerializedAuditEvent = serialize_audit_event(event)?;
 
     // Extract operator information (Phase 14)
-    let actor_operator_id: i64 = event.actor.operator_id.unwrap_or(0);
+    let actor_operator_id: i64 = event.actor.operator_id.unwrap_or(SYSTEM_OPERATOR_ID);
     let actor_login_name: String = event
         .actor
         .operator_login_name
@@ -181,34 +276,42 @@ pub fn persist_audit_event_with_ids(
         i32::from(by.year())
     });
     let area_code: &str = event.area.as_ref().map_or("", Area::id);
+    // Imported events keep the ULID they were first recorded with
+    let event_ulid: String = event
+        .event_ulid
+        .unwrap_or_else(|| ulids.next_ulid())
+        .to_string();
 
-    // Serialize JSON fields
-    let actor_json: String = serde_json::to_string(&actor_data)?;
-    let cause_json: String = serde_json::to_string(&cause_data)?;
-    let action_json: String = serde_json::to_string(&action_data)?;
-    let before_json: String = serde_json::to_string(&before_data)?;
-    let after_json: String = serde_json::to_string(&after_data)?;
+    // The event and its outbox row are written together or not at all
+    conn.transaction::<i64, PersistenceError, _>(|conn| {
+        diesel::insert_into(diesel_schema::audit_events::table)
+            .values((
+                diesel_schema::audit_events::bid_year_id.eq(bid_year_id),
+                diesel_schema::audit_events::area_id.eq(area_id),
+                diesel_schema::audit_events::year.eq(year),
+                diesel_schema::audit_events::area_code.eq(area_code),
+                diesel_schema::audit_events::actor_operator_id.eq(actor_operator_id),
+                diesel_schema::audit_events::actor_login_name.eq(actor_login_name),
+                diesel_schema::audit_events::actor_display_name.eq(actor_display_name),
+                diesel_schema::audit_events::actor_json.eq(serialized.actor_json),
+                diesel_schema::audit_events::cause_json.eq(serialized.cause_json),
+                diesel_schema::audit_events::action_json.eq(serialized.action_json),
+                diesel_schema::audit_events::before_snapshot_json.eq(serialized.before_json),
+                diesel_schema::audit_events::after_snapshot_json.eq(serialized.after_json),
+                diesel_schema::audit_events::event_ulid.eq(event_ulid),
+                diesel_schema::audit_events::user_id.eq(event.user_id),
+            ))
+            .execute(conn)?;
 
-    diesel::insert_into(diesel_schema::audit_events::table)
-        .values((
-            diesel_schema::audit_events::bid_year_id.eq(bid_year_id),
-            diesel_schema::audit_events::area_id.eq(area_id),
-            diesel_schema::audit_events::year.eq(year),
-            diesel_schema::audit_events::area_code.eq(area_code),
-            diesel_schema::audit_events::actor_operator_id.eq(actor_operator_id),
-            diesel_schema::audit_events::actor_login_name.eq(actor_login_name),
-            diesel_schema::audit_events::actor_display_name.eq(actor_display_name),
-            diesel_schema::audit_events::actor_json.eq(actor_json),
-            diesel_schema::audit_events::cause_json.eq(cause_json),
-            diesel_schema::audit_events::action_json.eq(action_json),
-            diesel_schema::audit_events::before_snapshot_json.eq(before_json),
-            diesel_schema::audit_events::after_snapshot_json.eq(after_json),
-        ))
-        .execute(conn)?;
+        let event_id: i64 = conn.get_last_insert_rowid()?;
 
-    let event_id: i64 = conn.get_last_insert_rowid()?;
+        // Queue the event for publication by the relay
+        diesel::insert_into(diesel_schema::event_outbox::table)
+            .values(diesel_schema::event_outbox::audit_event_id.eq(event_id))
+            .execute(conn)?;
 
-    Ok(event_id)
+        Ok(event_id)
+    })
 }
 }
 
@@ -221,6 +324,7 @@ pub fn persist_audit_event_with_ids(
 /// * `conn` - The active database connection
 /// * `state` - The state to snapshot
 /// * `event_id` - The associated audit event ID
+/// * `encoding` - How to encode the snapshot
 ///
 /// # Errors
 ///
@@ -229,18 +333,13 @@ pub fn persist_state_snapshot_sqlite(
     conn: &mut SqliteConnection,
     state: &State,
     event_id: i64,
+    encoding: SnapshotEncoding,
 ) -> Result<(), PersistenceError> {
     // Look up the canonical IDs (Phase 23A)
     let bid_year_id: i64 = lookup_bid_year_id_sqlite(conn, state.bid_year.year())?;
     let area_id: i64 = lookup_area_id_sqlite(conn, bid_year_id, state.area.id())?;
 
-    let state_data: StateData = StateData {
-        bid_year: state.bid_year.year(),
-        area: state.area.id().to_string(),
-        users_json: serde_json::to_string(&state.users)?,
-    };
-
-    let state_json: String = serde_json::to_string(&state_data)?;
+    let (state_json, state_binary): (String, Option<Vec<u8>>) = encode_state(state, encoding)?;
 
     diesel::insert_into(diesel_schema::state_snapshots::table)
         .values((
@@ -248,10 +347,16 @@ pub fn persist_state_snapshot_sqlite(
             diesel_schema::state_snapshots::bid_year_id.eq(bid_year_id),
             diesel_schema::state_snapshots::area_id.eq(area_id),
             diesel_schema::state_snapshots::state_json.eq(state_json),
+            diesel_schema::state_snapshots::state_format.eq(encoding.as_str()),
+            diesel_schema::state_snapshots::state_binary.eq(state_binary),
         ))
         .execute(conn)?;
 
-    debug!(event_id, "Persisted state snapshot");
+    debug!(
+        event_id,
+        encoding = encoding.as_str(),
+        "Persisted state snapshot"
+    );
 
     Ok(())
 }
@@ -265,6 +370,7 @@ pub fn persist_state_snapshot_sqlite(
 /// * `conn` - The active database connection
 /// * `state` - The state to snapshot
 /// * `event_id` - The associated audit event ID
+/// * `encoding` - How to encode the snapshot
 ///
 /// # Errors
 ///
@@ -273,18 +379,13 @@ pub fn persist_state_snapshot_mysql(
     conn: &mut MysqlConnection,
     state: &State,
     event_id: i64,
+    encoding: SnapshotEncoding,
 ) -> Result<(), PersistenceError> {
     // Look up the canonical IDs (Phase 23A)
     let bid_year_id: i64 = lookup_bid_year_id_mysql(conn, state.bid_year.year())?;
     let area_id: i64 = lookup_area_id_mysql(conn, bid_year_id, state.area.id())?;
 
-    let state_data: StateData = StateData {
-        bid_year: state.bid_year.year(),
-        area: state.area.id().to_string(),
-        users_json: serde_json::to_string(&state.users)?,
-    };
-
-    let state_json: String = serde_json::to_string(&state_data)?;
+    let (state_json, state_binary): (String, Option<Vec<u8>>) = encode_state(state, encoding)?;
 
     diesel::insert_into(diesel_schema::state_snapshots::table)
         .values((
@@ -292,10 +393,89 @@ pub fn persist_state_snapshot_mysql(
             diesel_schema::state_snapshots::bid_year_id.eq(bid_year_id),
             diesel_schema::state_snapshots::area_id.eq(area_id),
             diesel_schema::state_snapshots::state_json.eq(state_json),
+            diesel_schema::state_snapshots::state_format.eq(encoding.as_str()),
+            diesel_schema::state_snapshots::state_binary.eq(state_binary),
         ))
         .execute(conn)?;
 
-    debug!(event_id, "Persisted state snapshot");
+    debug!(
+        event_id,
+        encoding = encoding.as_str(),
+        "Persisted state snapshot"
+    );
 
     Ok(())
 }
+
+backend_fn! {
+/// Records that an audit event depends on events in other scopes.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `event_id` - The dependent event
+/// * `depends_on_event_ids` - The events it was derived from
+///
+/// # Errors
+///
+/// Returns an error if an insert fails.
+pub fn record_event_dependencies(
+    conn: &mut _,
+    event_id: i64,
+    depends_on_event_ids: &[i64],
+) -> Result<(), PersistenceError> {
+    for depends_on_event_id in depends_on_event_ids {
+        diesel::insert_into(diesel_schema::audit_event_dependencies::table)
+            .values((
+                diesel_schema::audit_event_dependencies::event_id.eq(event_id),
+                diesel_schema::audit_event_dependencies::depends_on_event_id
+                    .eq(depends_on_event_id),
+            ))
+            .execute(conn)?;
+    }
+
+    debug!(
+        event_id,
+        dependencies = depends_on_event_ids.len(),
+        "Recorded event dependencies"
+    );
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Records that audit events were recorded by one operation.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `event_ids` - The operation's events
+/// * `correlation_id` - The operation's correlation ID
+///
+/// # Errors
+///
+/// Returns an error if an insert fails.
+pub fn record_event_correlations(
+    conn: &mut _,
+    event_ids: &[i64],
+    correlation_id: &str,
+) -> Result<(), PersistenceError> {
+    for event_id in event_ids {
+        diesel::insert_into(diesel_schema::audit_event_correlations::table)
+            .values((
+                diesel_schema::audit_event_correlations::event_id.eq(event_id),
+                diesel_schema::audit_event_correlations::correlation_id.eq(correlation_id),
+            ))
+            .execute(conn)?;
+    }
+
+    debug!(
+        correlation_id,
+        events = event_ids.len(),
+        "Recorded event correlations"
+    );
+
+    Ok(())
+}
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Audit annotation mutations.
+//!
+//! This module contains backend-agnostic mutations for attaching notes to
+//! audit events. Annotations are append-only and never touch the event
+//! they describe.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::diesel_schema::{audit_event_annotations, audit_events};
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Attaches a note to an existing audit event.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `event_id` - The annotated audit event
+/// * `operator_id` - The operator writing the note
+/// * `note` - The note text
+///
+/// # Returns
+///
+/// The new annotation's ID.
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the audit event does not exist,
+/// or an error if the annotation cannot be recorded.
+pub fn add_audit_annotation(
+    conn: &mut _,
+    event_id: i64,
+    operator_id: i64,
+    note: &str,
+) -> Result<i64, PersistenceError> {
+    let exists: i64 = audit_events::table
+        .filter(audit_events::event_id.eq(event_id))
+        .count()
+        .get_result(conn)?;
+    if exists == 0 {
+        return Err(PersistenceError::NotFound(format!(
+            "Audit event {event_id} not found"
+        )));
+    }
+
+    diesel::insert_into(audit_event_annotations::table)
+        .values((
+            audit_event_annotations::event_id.eq(event_id),
+            audit_event_annotations::operator_id.eq(operator_id),
+            audit_event_annotations::note.eq(note),
+        ))
+        .execute(conn)?;
+
+    let annotation_id: i64 = conn.get_last_insert_rowid()?;
+
+    info!(annotation_id, event_id, operator_id, "Audit event annotated");
+
+    Ok(annotation_id)
+}
+}
@@ -9,17 +9,23 @@
 //! bootstrap results and transitions. These functions coordinate multiple
 //! lower-level mutations.
 
+use std::collections::BTreeMap;
+
 use diesel::prelude::*;
 use diesel::{MysqlConnection, SqliteConnection};
 use num_traits::ToPrimitive;
+use time::OffsetDateTime;
 use tracing::{debug, info};
-use zab_bid::{BootstrapResult, State, TransitionResult};
+use zab_bid::{
+    BootstrapResult, DerivedEligibility, IdGenerator, State, TransitionResult,
+    rollback_target_event_id,
+};
 use zab_bid_domain::CanonicalBidYear;
 
 use crate::backend::PersistenceBackend;
 use crate::data_models::{
     NewCanonicalAreaMembership, NewCanonicalBidOrder, NewCanonicalBidWindows,
-    NewCanonicalEligibility,
+    NewCanonicalEligibility, SnapshotEncoding,
 };
 use crate::diesel_schema;
 use crate::error::PersistenceError;
@@ -36,6 +42,7 @@ use crate::mutations::canonical::{
     insert_new_user_mysql, insert_new_user_sqlite, sync_canonical_users_mysql,
     sync_canonical_users_sqlite,
 };
+use crate::mutations::rollback::{restore_rollback_target_mysql, restore_rollback_target_sqlite};
 use crate::queries::canonical::{lookup_bid_year_id_mysql, lookup_bid_year_id_sqlite};
 
 /// Type alias for bid schedule fields returned from database queries.
@@ -49,6 +56,13 @@ pub type BidScheduleFields = (
     Option<i32>,
 );
 
+/// Type alias for per-area bid schedule override fields.
+///
+/// Start date, window start time, window end time, and bidders per day,
+/// each `None` when the area uses its bid year's value.
+pub type AreaBidScheduleOverrideFields =
+    (Option<String>, Option<String>, Option<String>, Option<i32>);
+
 /// Result of persisting a transition.
 ///
 /// Contains the event ID assigned to the audit event, and optionally the
@@ -61,6 +75,11 @@ pub struct PersistTransitionResult {
     pub user_id: Option<i64>,
 }
 
+/// Returns whether an action records a milestone without changing state.
+fn is_state_preserving(action_name: &str) -> bool {
+    matches!(action_name, "Checkpoint" | "Finalize")
+}
+
 /// Persists a transition result (audit event and optionally a full snapshot) - `SQLite` version.
 ///
 /// # Arguments
@@ -68,6 +87,8 @@ pub struct PersistTransitionResult {
 /// * `conn` - The active database connection
 /// * `result` - The transition result to persist
 /// * `should_snapshot` - Whether to persist a full state snapshot
+/// * `encoding` - How to encode the snapshot, if one is persisted
+/// * `ulids` - Where the audit event's ULID is drawn from
 ///
 /// # Returns
 ///
@@ -80,16 +101,42 @@ pub fn persist_transition_sqlite(
     conn: &mut SqliteConnection,
     result: &TransitionResult,
     should_snapshot: bool,
+    encoding: SnapshotEncoding,
+    ulids: &dyn IdGenerator,
 ) -> Result<PersistTransitionResult, PersistenceError> {
     // Persist the audit event
-    let event_id: i64 = persist_audit_event_sqlite(conn, &result.audit_event)?;
+    let event_id: i64 = persist_audit_event_sqlite(conn, &result.audit_event, ulids)?;
     debug!(event_id, "Persisted audit event");
 
+    // A rollback restores the area as of its target, and snapshots the
+    // restored state rather than the state it was applied to
+    if let Some(target_event_id) = rollback_target_event_id(&result.audit_event.action) {
+        restore_rollback_target_sqlite(
+            conn,
+            &result.new_state,
+            target_event_id,
+            event_id,
+            encoding,
+        )?;
+        info!(event_id, target_event_id, "Persisted rollback");
+        return Ok(PersistTransitionResult {
+            event_id,
+            user_id: None,
+        });
+    }
+
     // Update canonical state based on action type
     // RegisterUser is incremental (insert one user), others are full state replacement
-    let user_id: Option<i64> = if result.audit_event.action.name.as_str() == "RegisterUser" {
+    // Checkpoint and Finalize leave the state unchanged, so there is nothing to sync
+    let user_id: Option<i64> = if is_state_preserving(&result.audit_event.action.name) {
+        None
+    } else if result.audit_event.action.name.as_str() == "RegisterUser" {
         // Insert just the new user incrementally and capture the user_id
         let new_user_id: i64 = insert_new_user_sqlite(conn, &result.new_state)?;
+        // The user had no ID when core recorded the event
+        diesel::update(diesel_schema::audit_events::table.find(event_id))
+            .set(diesel_schema::audit_events::user_id.eq(new_user_id))
+            .execute(conn)?;
         debug!(
             bid_year = result.new_state.bid_year.year(),
             area = result.new_state.area.id(),
@@ -111,7 +158,7 @@ pub fn persist_transition_sqlite(
 
     // Persist full snapshot if required
     if should_snapshot {
-        persist_state_snapshot_sqlite(conn, &result.new_state, event_id)?;
+        persist_state_snapshot_sqlite(conn, &result.new_state, event_id, encoding)?;
         debug!(event_id, "Persisted full state snapshot");
     }
 
@@ -127,6 +174,8 @@ pub fn persist_transition_sqlite(
 /// * `conn` - The active database connection
 /// * `result` - The transition result to persist
 /// * `should_snapshot` - Whether to persist a full state snapshot
+/// * `encoding` - How to encode the snapshot, if one is persisted
+/// * `ulids` - Where the audit event's ULID is drawn from
 ///
 /// # Returns
 ///
@@ -139,16 +188,42 @@ pub fn persist_transition_mysql(
     conn: &mut MysqlConnection,
     result: &TransitionResult,
     should_snapshot: bool,
+    encoding: SnapshotEncoding,
+    ulids: &dyn IdGenerator,
 ) -> Result<PersistTransitionResult, PersistenceError> {
     // Persist the audit event
-    let event_id: i64 = persist_audit_event_mysql(conn, &result.audit_event)?;
+    let event_id: i64 = persist_audit_event_mysql(conn, &result.audit_event, ulids)?;
     debug!(event_id, "Persisted audit event");
 
+    // A rollback restores the area as of its target, and snapshots the
+    // restored state rather than the state it was applied to
+    if let Some(target_event_id) = rollback_target_event_id(&result.audit_event.action) {
+        restore_rollback_target_mysql(
+            conn,
+            &result.new_state,
+            target_event_id,
+            event_id,
+            encoding,
+        )?;
+        info!(event_id, target_event_id, "Persisted rollback");
+        return Ok(PersistTransitionResult {
+            event_id,
+            user_id: None,
+        });
+    }
+
     // Update canonical state based on action type
     // RegisterUser is incremental (insert one user), others are full state replacement
-    let user_id: Option<i64> = if result.audit_event.action.name.as_str() == "RegisterUser" {
+    // Checkpoint and Finalize leave the state unchanged, so there is nothing to sync
+    let user_id: Option<i64> = if is_state_preserving(&result.audit_event.action.name) {
+        None
+    } else if result.audit_event.action.name.as_str() == "RegisterUser" {
         // Insert just the new user incrementally and capture the user_id
         let new_user_id: i64 = insert_new_user_mysql(conn, &result.new_state)?;
+        // The user had no ID when core recorded the event
+        diesel::update(diesel_schema::audit_events::table.find(event_id))
+            .set(diesel_schema::audit_events::user_id.eq(new_user_id))
+            .execute(conn)?;
         debug!(
             bid_year = result.new_state.bid_year.year(),
             area = result.new_state.area.id(),
@@ -170,7 +245,7 @@ pub fn persist_transition_mysql(
 
     // Persist full snapshot if required
     if should_snapshot {
-        persist_state_snapshot_mysql(conn, &result.new_state, event_id)?;
+        persist_state_snapshot_mysql(conn, &result.new_state, event_id, encoding)?;
         debug!(event_id, "Persisted full state snapshot");
     }
 
@@ -188,6 +263,8 @@ pub fn persist_transition_mysql(
 ///
 /// * `conn` - The active database connection
 /// * `result` - The bootstrap result to persist
+/// * `encoding` - How to encode the initial snapshot of a new area
+/// * `ulids` - Where the audit event's ULID is drawn from
 ///
 /// # Returns
 ///
@@ -200,6 +277,8 @@ pub fn persist_transition_mysql(
 pub fn persist_bootstrap_sqlite(
     conn: &mut SqliteConnection,
     result: &BootstrapResult,
+    encoding: SnapshotEncoding,
+    ulids: &dyn IdGenerator,
 ) -> Result<i64, PersistenceError> {
     // Update canonical tables first to generate IDs
     match result.audit_event.action.name.as_str() {
@@ -249,6 +328,7 @@ pub fn persist_bootstrap_sqlite(
                 &result.audit_event,
                 Some(bid_year_id),
                 None,
+                ulids,
             )?;
             debug!(
                 event_id,
@@ -309,6 +389,7 @@ pub fn persist_bootstrap_sqlite(
                 &result.audit_event,
                 Some(bid_year_id),
                 Some(area_id),
+                ulids,
             )?;
             debug!(event_id, "Persisted bootstrap audit event for CreateArea");
 
@@ -321,7 +402,7 @@ pub fn persist_bootstrap_sqlite(
                     PersistenceError::Other("CreateArea must have area".to_string())
                 })?,
             );
-            persist_state_snapshot_sqlite(conn, &initial_state, event_id)?;
+            persist_state_snapshot_sqlite(conn, &initial_state, event_id, encoding)?;
             debug!(event_id, "Created initial empty snapshot for new area");
 
             info!(event_id, area_id, bid_year_id, "Persisted CreateArea");
@@ -329,7 +410,7 @@ pub fn persist_bootstrap_sqlite(
         }
         _ => {
             // Non-bootstrap actions should use the standard persist path
-            let event_id: i64 = persist_audit_event_sqlite(conn, &result.audit_event)?;
+            let event_id: i64 = persist_audit_event_sqlite(conn, &result.audit_event, ulids)?;
             debug!(event_id, "Persisted bootstrap audit event");
             info!(event_id, "Persisted bootstrap operation");
             Ok(event_id)
@@ -346,6 +427,8 @@ pub fn persist_bootstrap_sqlite(
 ///
 /// * `conn` - The active database connection
 /// * `result` - The bootstrap result to persist
+/// * `encoding` - How to encode the initial snapshot of a new area
+/// * `ulids` - Where the audit event's ULID is drawn from
 ///
 /// # Returns
 ///
@@ -358,6 +441,8 @@ pub fn persist_bootstrap_sqlite(
 pub fn persist_bootstrap_mysql(
     conn: &mut MysqlConnection,
     result: &BootstrapResult,
+    encoding: SnapshotEncoding,
+    ulids: &dyn IdGenerator,
 ) -> Result<i64, PersistenceError> {
     // Update canonical tables first to generate IDs
     match result.audit_event.action.name.as_str() {
@@ -407,6 +492,7 @@ pub fn persist_bootstrap_mysql(
                 &result.audit_event,
                 Some(bid_year_id),
                 None,
+                ulids,
             )?;
             debug!(
                 event_id,
@@ -467,6 +553,7 @@ pub fn persist_bootstrap_mysql(
                 &result.audit_event,
                 Some(bid_year_id),
                 Some(area_id),
+                ulids,
             )?;
             debug!(event_id, "Persisted bootstrap audit event for CreateArea");
 
@@ -479,7 +566,7 @@ pub fn persist_bootstrap_mysql(
                     PersistenceError::Other("CreateArea must have area".to_string())
                 })?,
             );
-            persist_state_snapshot_mysql(conn, &initial_state, event_id)?;
+            persist_state_snapshot_mysql(conn, &initial_state, event_id, encoding)?;
             debug!(event_id, "Created initial empty snapshot for new area");
 
             info!(event_id, area_id, bid_year_id, "Persisted CreateArea");
@@ -487,7 +574,7 @@ pub fn persist_bootstrap_mysql(
         }
         _ => {
             // Non-bootstrap actions should use the standard persist path
-            let event_id: i64 = persist_audit_event_mysql(conn, &result.audit_event)?;
+            let event_id: i64 = persist_audit_event_mysql(conn, &result.audit_event, ulids)?;
             debug!(event_id, "Persisted bootstrap audit event");
             info!(event_id, "Persisted bootstrap operation");
             Ok(event_id)
@@ -735,12 +822,197 @@ pub fn update_bid_schedule(
 }
 }
 
+backend_fn! {
+/// Gets the bid schedule override for an area, if one is set.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be queried.
+pub fn get_area_bid_schedule_override(
+    conn: &mut _,
+    area_id: i64,
+) -> Result<Option<AreaBidScheduleOverrideFields>, PersistenceError> {
+    let result: Option<AreaBidScheduleOverrideFields> =
+        diesel_schema::area_bid_schedule_overrides::table
+            .select((
+                diesel_schema::area_bid_schedule_overrides::bid_start_date,
+                diesel_schema::area_bid_schedule_overrides::bid_window_start_time,
+                diesel_schema::area_bid_schedule_overrides::bid_window_end_time,
+                diesel_schema::area_bid_schedule_overrides::bidders_per_area_per_day,
+            ))
+            .filter(diesel_schema::area_bid_schedule_overrides::area_id.eq(area_id))
+            .first::<AreaBidScheduleOverrideFields>(conn)
+            .optional()?;
+
+    Ok(result)
+}
+}
+
+backend_fn! {
+/// Lists the bid schedule overrides of all areas in a bid year.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Returns
+///
+/// `(area_id, area_code, fields)` for each area with an override, ordered by area code.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be queried.
+pub fn list_area_bid_schedule_overrides(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<(i64, String, AreaBidScheduleOverrideFields)>, PersistenceError> {
+    #[allow(clippy::type_complexity)]
+    let rows: Vec<(i64, String, Option<String>, Option<String>, Option<String>, Option<i32>)> =
+        diesel_schema::area_bid_schedule_overrides::table
+            .inner_join(diesel_schema::areas::table)
+            .filter(diesel_schema::areas::bid_year_id.eq(bid_year_id))
+            .select((
+                diesel_schema::areas::area_id,
+                diesel_schema::areas::area_code,
+                diesel_schema::area_bid_schedule_overrides::bid_start_date,
+                diesel_schema::area_bid_schedule_overrides::bid_window_start_time,
+                diesel_schema::area_bid_schedule_overrides::bid_window_end_time,
+                diesel_schema::area_bid_schedule_overrides::bidders_per_area_per_day,
+            ))
+            .order_by(diesel_schema::areas::area_code.asc())
+            .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(area_id, area_code, sd, wst, wet, bpd)| (area_id, area_code, (sd, wst, wet, bpd)))
+        .collect())
+}
+}
+
+backend_fn! {
+/// Sets the bid schedule override for an area, replacing any existing one.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+/// * `start_date` - Bid start date (ISO 8601 format), or `None` to use the bid year's
+/// * `window_start_time` - Daily window start time (HH:MM:SS format), or `None`
+/// * `window_end_time` - Daily window end time (HH:MM:SS format), or `None`
+/// * `bidders_per_day` - Number of bidders per day, or `None`
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be updated.
+pub fn set_area_bid_schedule_override(
+    conn: &mut _,
+    area_id: i64,
+    start_date: Option<&str>,
+    window_start_time: Option<&str>,
+    window_end_time: Option<&str>,
+    bidders_per_day: Option<i32>,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(
+            diesel_schema::area_bid_schedule_overrides::table
+                .filter(diesel_schema::area_bid_schedule_overrides::area_id.eq(area_id)),
+        )
+        .execute(conn)?;
+
+        diesel::insert_into(diesel_schema::area_bid_schedule_overrides::table)
+            .values((
+                diesel_schema::area_bid_schedule_overrides::area_id.eq(area_id),
+                diesel_schema::area_bid_schedule_overrides::bid_start_date.eq(start_date),
+                diesel_schema::area_bid_schedule_overrides::bid_window_start_time
+                    .eq(window_start_time),
+                diesel_schema::area_bid_schedule_overrides::bid_window_end_time
+                    .eq(window_end_time),
+                diesel_schema::area_bid_schedule_overrides::bidders_per_area_per_day
+                    .eq(bidders_per_day),
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    })?;
+
+    debug!(area_id, "Set area bid schedule override");
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Removes the bid schedule override for an area.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+///
+/// # Returns
+///
+/// `true` if an override was removed.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be updated.
+pub fn clear_area_bid_schedule_override(
+    conn: &mut _,
+    area_id: i64,
+) -> Result<bool, PersistenceError> {
+    let rows_affected: usize = diesel::delete(
+        diesel_schema::area_bid_schedule_overrides::table
+            .filter(diesel_schema::area_bid_schedule_overrides::area_id.eq(area_id)),
+    )
+    .execute(conn)?;
+
+    debug!(area_id, "Cleared area bid schedule override");
+    Ok(rows_affected > 0)
+}
+}
+
+/// Applies an area's bid schedule override on top of its bid year's schedule.
+///
+/// Each overridden field replaces the bid year's value; fields the area
+/// leaves unset fall back to the bid year. The timezone always comes from
+/// the bid year.
+#[must_use]
+pub fn merge_area_bid_schedule(
+    bid_year_schedule: BidScheduleFields,
+    area_override: Option<AreaBidScheduleOverrideFields>,
+) -> BidScheduleFields {
+    let (timezone, start_date, window_start_time, window_end_time, bidders_per_day) =
+        bid_year_schedule;
+    match area_override {
+        Some((area_start_date, area_window_start, area_window_end, area_bidders)) => (
+            timezone,
+            area_start_date.or(start_date),
+            area_window_start.or(window_start_time),
+            area_window_end.or(window_end_time),
+            area_bidders.or(bidders_per_day),
+        ),
+        None => (
+            timezone,
+            start_date,
+            window_start_time,
+            window_end_time,
+            bidders_per_day,
+        ),
+    }
+}
+
 /// Canonicalize a bid year by populating canonical data tables (`SQLite` version).
 ///
 /// This function:
 /// 1. Inserts canonical rows for area membership, eligibility, bid order, and bid windows
 /// 2. Persists the audit event
-/// 3. Returns the `event_id`
+/// 3. Records that the event depends on the latest event in each area
+/// 4. Returns the `event_id`
 ///
 /// Canonicalization must be called within a transaction to ensure atomicity.
 ///
@@ -749,6 +1021,7 @@ pub fn update_bid_schedule(
 /// * `conn` - The database connection
 /// * `bid_year_id` - The bid year to canonicalize
 /// * `audit_event` - The audit event recording canonicalization
+/// * `canonicalized_at` - When canonicalization happens, recorded in the snapshot
 ///
 /// # Returns
 ///
@@ -764,6 +1037,8 @@ fn build_canonical_records_and_snapshot(
     year: i32,
     user_rows: &[(i64, String, String, i64, String, Option<String>)],
     area_rows: &[(i64, String, Option<String>)],
+    eligibility: &BTreeMap<i64, DerivedEligibility>,
+    canonicalized_at: OffsetDateTime,
 ) -> Result<
     (
         Vec<NewCanonicalAreaMembership>,
@@ -781,6 +1056,10 @@ fn build_canonical_records_and_snapshot(
     let mut snapshot_users: Vec<crate::data_models::CanonicalizedUserSnapshot> = Vec::new();
 
     for (user_id, initials, name, area_id, area_code, area_name) in user_rows {
+        let can_bid: bool = eligibility
+            .get(user_id)
+            .is_none_or(|derived| derived.can_bid);
+
         area_membership_records.push(NewCanonicalAreaMembership {
             bid_year_id,
             audit_event_id: 0,
@@ -794,9 +1073,10 @@ fn build_canonical_records_and_snapshot(
             bid_year_id,
             audit_event_id: 0,
             user_id: *user_id,
-            can_bid: 1,
+            can_bid: i32::from(can_bid),
             is_overridden: 0,
             override_reason: None,
+            derived_can_bid: i32::from(can_bid),
         });
 
         bid_order_records.push(NewCanonicalBidOrder {
@@ -825,7 +1105,7 @@ fn build_canonical_records_and_snapshot(
             area_id: *area_id,
             area_code: area_code.clone(),
             area_name: area_name.clone().unwrap_or_default(),
-            can_bid: true,
+            can_bid,
             bid_order: None,
             window_start_date: None,
             window_end_date: None,
@@ -858,13 +1138,7 @@ fn build_canonical_records_and_snapshot(
         area_count: area_rows.len(),
         users: snapshot_users,
         areas: snapshot_areas,
-        timestamp: {
-            use std::time::{SystemTime, UNIX_EPOCH};
-            let duration = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .map_err(|e| PersistenceError::Other(format!("SystemTime error: {e}")))?;
-            format!("unix_{}", duration.as_secs())
-        },
+        timestamp: format!("unix_{}", canonicalized_at.unix_timestamp()),
     };
 
     Ok((
@@ -880,9 +1154,12 @@ pub fn canonicalize_bid_year_sqlite(
     conn: &mut SqliteConnection,
     bid_year_id: i64,
     audit_event: &zab_bid_audit::AuditEvent,
+    canonicalized_at: OffsetDateTime,
+    ulids: &dyn IdGenerator,
 ) -> Result<i64, PersistenceError> {
     use crate::diesel_schema::{areas, bid_years, canonical_area_membership, users};
     use crate::queries::canonical::canonical_rows_exist_sqlite;
+    use crate::queries::eligibility::derive_bid_year_eligibility_sqlite;
 
     type UserWithAreaTuple = (i64, String, String, i64, String, Option<String>);
     type AreaTuple = (i64, String, Option<String>);
@@ -921,19 +1198,32 @@ pub fn canonicalize_bid_year_sqlite(
         .filter(bid_years::bid_year_id.eq(bid_year_id))
         .first(conn)?;
 
+    let eligibility: BTreeMap<i64, DerivedEligibility> =
+        derive_bid_year_eligibility_sqlite(conn, bid_year_id)?;
+
     let (
         mut area_membership_records,
         mut eligibility_records,
         mut bid_order_records,
         mut bid_windows_records,
         snapshot,
-    ) = build_canonical_records_and_snapshot(bid_year_id, year, &user_rows, &area_rows)?;
+    ) = build_canonical_records_and_snapshot(
+        bid_year_id,
+        year,
+        &user_rows,
+        &area_rows,
+        &eligibility,
+        canonicalized_at,
+    )?;
 
     let snapshot_json = serde_json::to_string(&snapshot)?;
     let mut audit_event_with_snapshot = audit_event.clone();
     audit_event_with_snapshot.after = zab_bid_audit::StateSnapshot::new(snapshot_json);
 
-    let event_id: i64 = persist_audit_event_sqlite(conn, &audit_event_with_snapshot)?;
+    let depends_on: Vec<i64> =
+        crate::queries::audit::list_latest_area_event_ids_sqlite(conn, bid_year_id)?;
+    let event_id: i64 = persist_audit_event_sqlite(conn, &audit_event_with_snapshot, ulids)?;
+    crate::mutations::audit::record_event_dependencies_sqlite(conn, event_id, &depends_on)?;
 
     for record in &mut area_membership_records {
         record.audit_event_id = event_id;
@@ -967,7 +1257,8 @@ pub fn canonicalize_bid_year_sqlite(
 /// This function:
 /// 1. Inserts canonical rows for area membership, eligibility, bid order, and bid windows
 /// 2. Persists the audit event
-/// 3. Returns the `event_id`
+/// 3. Records that the event depends on the latest event in each area
+/// 4. Returns the `event_id`
 ///
 /// Canonicalization must be called within a transaction to ensure atomicity.
 ///
@@ -976,6 +1267,7 @@ pub fn canonicalize_bid_year_sqlite(
 /// * `conn` - The database connection
 /// * `bid_year_id` - The bid year to canonicalize
 /// * `audit_event` - The audit event recording canonicalization
+/// * `canonicalized_at` - When canonicalization happens, recorded in the snapshot
 ///
 /// # Returns
 ///
@@ -988,9 +1280,12 @@ pub fn canonicalize_bid_year_mysql(
     conn: &mut MysqlConnection,
     bid_year_id: i64,
     audit_event: &zab_bid_audit::AuditEvent,
+    canonicalized_at: OffsetDateTime,
+    ulids: &dyn IdGenerator,
 ) -> Result<i64, PersistenceError> {
     use crate::diesel_schema::{areas, bid_years, canonical_area_membership, users};
     use crate::queries::canonical::canonical_rows_exist_mysql;
+    use crate::queries::eligibility::derive_bid_year_eligibility_mysql;
 
     type UserWithAreaTuple = (i64, String, String, i64, String, Option<String>);
     type AreaTuple = (i64, String, Option<String>);
@@ -1029,19 +1324,32 @@ pub fn canonicalize_bid_year_mysql(
         .filter(bid_years::bid_year_id.eq(bid_year_id))
         .first(conn)?;
 
+    let eligibility: BTreeMap<i64, DerivedEligibility> =
+        derive_bid_year_eligibility_mysql(conn, bid_year_id)?;
+
     let (
         mut area_membership_records,
         mut eligibility_records,
         mut bid_order_records,
         mut bid_windows_records,
         snapshot,
-    ) = build_canonical_records_and_snapshot(bid_year_id, year, &user_rows, &area_rows)?;
+    ) = build_canonical_records_and_snapshot(
+        bid_year_id,
+        year,
+        &user_rows,
+        &area_rows,
+        &eligibility,
+        canonicalized_at,
+    )?;
 
     let snapshot_json = serde_json::to_string(&snapshot)?;
     let mut audit_event_with_snapshot = audit_event.clone();
     audit_event_with_snapshot.after = zab_bid_audit::StateSnapshot::new(snapshot_json);
 
-    let event_id: i64 = persist_audit_event_mysql(conn, &audit_event_with_snapshot)?;
+    let depends_on: Vec<i64> =
+        crate::queries::audit::list_latest_area_event_ids_mysql(conn, bid_year_id)?;
+    let event_id: i64 = persist_audit_event_mysql(conn, &audit_event_with_snapshot, ulids)?;
+    crate::mutations::audit::record_event_dependencies_mysql(conn, event_id, &depends_on)?;
 
     for record in &mut area_membership_records {
         record.audit_event_id = event_id;
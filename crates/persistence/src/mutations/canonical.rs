@@ -27,7 +27,7 @@ use crate::queries::canonical::{
     lookup_bid_year_id_sqlite,
 };
 
-/// Inserts a new user from the last user in the state (`SQLite` version).
+/// Inserts the new user from the state (`SQLite` version).
 ///
 /// This is used for incremental `RegisterUser` operations where only one user
 /// is being added.
@@ -35,30 +35,36 @@ use crate::queries::canonical::{
 /// # Arguments
 ///
 /// * `conn` - The database connection
-/// * `state` - The state containing the new user (as the last element)
+/// * `state` - The state containing the new user (the one without a `user_id` or a row)
 ///
 /// # Errors
 ///
-/// Returns an error if the state has no users or if the database operation fails.
+/// Returns an error if the state has no new user or if the database operation fails.
 pub fn insert_new_user_sqlite(
     conn: &mut SqliteConnection,
     state: &State,
 ) -> Result<i64, PersistenceError> {
+    // Look up the IDs
+    let bid_year_id: i64 = lookup_bid_year_id_sqlite(conn, state.bid_year.year())?;
+    let area_id: i64 = lookup_area_id_sqlite(conn, bid_year_id, state.area.id())?;
+
+    // Users registered earlier in the same batch have no user_id in this
+    // state either, so the new user is the one without a row yet
+    let persisted_initials: Vec<String> = diesel_schema::users::table
+        .filter(diesel_schema::users::bid_year_id.eq(bid_year_id))
+        .filter(diesel_schema::users::area_id.eq(area_id))
+        .select(diesel_schema::users::initials)
+        .load(conn)?;
     let user = state
         .users
-        .last()
-        .ok_or_else(|| PersistenceError::ReconstructionError("No users in state".to_string()))?;
-
-    // User should not have user_id for a new insertion
-    if user.user_id.is_some() {
-        return Err(PersistenceError::ReconstructionError(
-            "New user should not have user_id".to_string(),
-        ));
-    }
-
-    // Look up the IDs
-    let bid_year_id: i64 = lookup_bid_year_id_sqlite(conn, user.bid_year.year())?;
-    let area_id: i64 = lookup_area_id_sqlite(conn, bid_year_id, user.area.id())?;
+        .values()
+        .find(|user| {
+            user.user_id.is_none()
+                && !persisted_initials
+                    .iter()
+                    .any(|i| i == user.initials.value())
+        })
+        .ok_or_else(|| PersistenceError::ReconstructionError("No new user in state".to_string()))?;
 
     // Seniority data fields are already strings - just borrow them
     let cumulative_natca_bu_date: &str = &user.seniority_data.cumulative_natca_bu_date;
@@ -101,7 +107,7 @@ pub fn insert_new_user_sqlite(
     Ok(user_id)
 }
 
-/// Inserts a new user from the last user in the state (`MySQL` version).
+/// Inserts the new user from the state (`MySQL` version).
 ///
 /// This is used for incremental `RegisterUser` operations where only one user
 /// is being added.
@@ -109,30 +115,36 @@ pub fn insert_new_user_sqlite(
 /// # Arguments
 ///
 /// * `conn` - The database connection
-/// * `state` - The state containing the new user (as the last element)
+/// * `state` - The state containing the new user (the one without a `user_id` or a row)
 ///
 /// # Errors
 ///
-/// Returns an error if the state has no users or if the database operation fails.
+/// Returns an error if the state has no new user or if the database operation fails.
 pub fn insert_new_user_mysql(
     conn: &mut MysqlConnection,
     state: &State,
 ) -> Result<i64, PersistenceError> {
+    // Look up the IDs
+    let bid_year_id: i64 = lookup_bid_year_id_mysql(conn, state.bid_year.year())?;
+    let area_id: i64 = lookup_area_id_mysql(conn, bid_year_id, state.area.id())?;
+
+    // Users registered earlier in the same batch have no user_id in this
+    // state either, so the new user is the one without a row yet
+    let persisted_initials: Vec<String> = diesel_schema::users::table
+        .filter(diesel_schema::users::bid_year_id.eq(bid_year_id))
+        .filter(diesel_schema::users::area_id.eq(area_id))
+        .select(diesel_schema::users::initials)
+        .load(conn)?;
     let user = state
         .users
-        .last()
-        .ok_or_else(|| PersistenceError::ReconstructionError("No users in state".to_string()))?;
-
-    // User should not have user_id for a new insertion
-    if user.user_id.is_some() {
-        return Err(PersistenceError::ReconstructionError(
-            "New user should not have user_id".to_string(),
-        ));
-    }
-
-    // Look up the IDs
-    let bid_year_id: i64 = lookup_bid_year_id_mysql(conn, user.bid_year.year())?;
-    let area_id: i64 = lookup_area_id_mysql(conn, bid_year_id, user.area.id())?;
+        .values()
+        .find(|user| {
+            user.user_id.is_none()
+                && !persisted_initials
+                    .iter()
+                    .any(|i| i == user.initials.value())
+        })
+        .ok_or_else(|| PersistenceError::ReconstructionError("No new user in state".to_string()))?;
 
     // Seniority data fields are already strings - just borrow them
     let cumulative_natca_bu_date: &str = &user.seniority_data.cumulative_natca_bu_date;
@@ -208,7 +220,7 @@ pub fn sync_canonical_users_sqlite(
     .execute(conn)?;
 
     // Insert all users from the new state
-    for user in &state.users {
+    for user in state.users.values() {
         // Seniority data fields are already strings - just borrow them
         let cumulative_natca_bu_date: &str = &user.seniority_data.cumulative_natca_bu_date;
         let natca_bu_date: &str = &user.seniority_data.natca_bu_date;
@@ -300,7 +312,7 @@ pub fn sync_canonical_users_mysql(
     .execute(conn)?;
 
     // Insert all users from the new state
-    for user in &state.users {
+    for user in state.users.values() {
         // Seniority data fields are already strings - just borrow them
         let cumulative_natca_bu_date: &str = &user.seniority_data.cumulative_natca_bu_date;
         let natca_bu_date: &str = &user.seniority_data.natca_bu_date;
@@ -434,6 +446,124 @@ pub fn update_user(
 }
 }
 
+backend_fn! {
+/// Sets a user's participation flags.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `user_id` - The user's canonical internal identifier
+/// * `excluded_from_bidding` - Whether the user is excluded from bidding
+/// * `excluded_from_leave_calculation` - Whether the user is excluded from leave calculation
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be updated or the user does not exist.
+pub fn set_user_participation(
+    conn: &mut _,
+    user_id: i64,
+    excluded_from_bidding: bool,
+    excluded_from_leave_calculation: bool,
+) -> Result<(), PersistenceError> {
+    let rows_affected: usize = diesel::update(diesel_schema::users::table)
+        .filter(diesel_schema::users::user_id.eq(user_id))
+        .set((
+            diesel_schema::users::excluded_from_bidding.eq(i32::from(excluded_from_bidding)),
+            diesel_schema::users::excluded_from_leave_calculation
+                .eq(i32::from(excluded_from_leave_calculation)),
+        ))
+        .execute(conn)?;
+
+    if rows_affected == 0 {
+        return Err(PersistenceError::NotFound(format!(
+            "User with user_id {user_id} not found"
+        )));
+    }
+
+    debug!(user_id, excluded_from_bidding, "Updated user participation");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Deletes everything canonicalization and bid readiness confirmation
+/// materialized for a bid year.
+///
+/// This clears canonical area membership, eligibility, bid order, bid
+/// windows, and overrides, along with the per-round bid order, bid
+/// windows, bid status, and current bidders derived from them. Callers run
+/// this inside a transaction together with the lifecycle change.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+///
+/// # Returns
+///
+/// The number of rows deleted.
+///
+/// # Errors
+///
+/// Returns an error if any delete fails.
+pub fn clear_canonical_bid_year(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<usize, PersistenceError> {
+    use diesel_schema::{
+        bid_status, bid_status_history, bid_windows, canonical_area_membership,
+        canonical_bid_order, canonical_bid_windows, canonical_eligibility, canonical_overrides,
+        current_bidders, round_bid_order,
+    };
+
+    let status_ids = bid_status::table
+        .filter(bid_status::bid_year_id.eq(bid_year_id))
+        .select(bid_status::bid_status_id);
+    let mut deleted: usize = diesel::delete(
+        bid_status_history::table.filter(bid_status_history::bid_status_id.eq_any(status_ids)),
+    )
+    .execute(conn)?;
+    deleted += diesel::delete(bid_status::table.filter(bid_status::bid_year_id.eq(bid_year_id)))
+        .execute(conn)?;
+    deleted += diesel::delete(
+        current_bidders::table.filter(current_bidders::bid_year_id.eq(bid_year_id)),
+    )
+    .execute(conn)?;
+    deleted += diesel::delete(bid_windows::table.filter(bid_windows::bid_year_id.eq(bid_year_id)))
+        .execute(conn)?;
+    deleted += diesel::delete(
+        round_bid_order::table.filter(round_bid_order::bid_year_id.eq(bid_year_id)),
+    )
+    .execute(conn)?;
+    deleted += diesel::delete(
+        canonical_overrides::table.filter(canonical_overrides::bid_year_id.eq(bid_year_id)),
+    )
+    .execute(conn)?;
+    deleted += diesel::delete(
+        canonical_bid_windows::table.filter(canonical_bid_windows::bid_year_id.eq(bid_year_id)),
+    )
+    .execute(conn)?;
+    deleted += diesel::delete(
+        canonical_bid_order::table.filter(canonical_bid_order::bid_year_id.eq(bid_year_id)),
+    )
+    .execute(conn)?;
+    deleted += diesel::delete(
+        canonical_eligibility::table.filter(canonical_eligibility::bid_year_id.eq(bid_year_id)),
+    )
+    .execute(conn)?;
+    deleted += diesel::delete(
+        canonical_area_membership::table
+            .filter(canonical_area_membership::bid_year_id.eq(bid_year_id)),
+    )
+    .execute(conn)?;
+
+    debug!(bid_year_id, deleted, "Cleared canonical bid year");
+
+    Ok(deleted)
+}
+}
+
 /// Creates a system area (e.g., "No Bid") for a bid year (`SQLite` version).
 ///
 /// Phase 25B: System areas are auto-created and cannot be deleted or renamed.
@@ -1328,6 +1458,72 @@ pub fn update_area_name_mysql(
     Ok(())
 }
 
+/// Assigns an area's round group (`SQLite` version).
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The canonical area identifier
+/// * `round_group_id` - The round group the area bids with (or `None` to clear)
+///
+/// # Errors
+///
+/// Returns an error if the area doesn't exist or the database operation fails.
+pub fn update_area_round_group_sqlite(
+    conn: &mut SqliteConnection,
+    area_id: i64,
+    round_group_id: Option<i64>,
+) -> Result<(), PersistenceError> {
+    use crate::diesel_schema::areas;
+
+    let rows_affected = diesel::update(areas::table.filter(areas::area_id.eq(area_id)))
+        .set(areas::round_group_id.eq(round_group_id))
+        .execute(conn)?;
+
+    if rows_affected == 0 {
+        return Err(PersistenceError::ReconstructionError(format!(
+            "Area with ID {area_id} not found"
+        )));
+    }
+
+    debug!(area_id, ?round_group_id, "Updated area round group");
+
+    Ok(())
+}
+
+/// Assigns an area's round group (`MySQL` version).
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The canonical area identifier
+/// * `round_group_id` - The round group the area bids with (or `None` to clear)
+///
+/// # Errors
+///
+/// Returns an error if the area doesn't exist or the database operation fails.
+pub fn update_area_round_group_mysql(
+    conn: &mut MysqlConnection,
+    area_id: i64,
+    round_group_id: Option<i64>,
+) -> Result<(), PersistenceError> {
+    use crate::diesel_schema::areas;
+
+    let rows_affected = diesel::update(areas::table.filter(areas::area_id.eq(area_id)))
+        .set(areas::round_group_id.eq(round_group_id))
+        .execute(conn)?;
+
+    if rows_affected == 0 {
+        return Err(PersistenceError::ReconstructionError(format!(
+            "Area with ID {area_id} not found"
+        )));
+    }
+
+    debug!(area_id, ?round_group_id, "Updated area round group");
+
+    Ok(())
+}
+
 // ============================================================================
 // Phase 29G: Post-Confirmation Bid Order Adjustments
 // ============================================================================
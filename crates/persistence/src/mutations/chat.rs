@@ -0,0 +1,171 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Chat channel mutations.
+//!
+//! This module contains backend-agnostic mutations for managing chat
+//! channels and recording the announcements posted to them.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::diesel_schema::{chat_channels, chat_notification_log};
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Creates a new chat channel.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year announced to this channel
+/// * `area_id` - The area announced to this channel, or `None` for every area
+/// * `provider` - The incoming webhook provider (`slack` or `teams`)
+/// * `url` - The incoming webhook URL
+///
+/// # Errors
+///
+/// Returns an error if the channel cannot be created.
+pub fn create_chat_channel(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: Option<i64>,
+    provider: &str,
+    url: &str,
+) -> Result<i64, PersistenceError> {
+    diesel::insert_into(chat_channels::table)
+        .values((
+            chat_channels::bid_year_id.eq(bid_year_id),
+            chat_channels::area_id.eq(area_id),
+            chat_channels::provider.eq(provider),
+            chat_channels::url.eq(url),
+        ))
+        .execute(conn)?;
+
+    let chat_channel_id: i64 = conn.get_last_insert_rowid()?;
+
+    info!(chat_channel_id, bid_year_id, ?area_id, provider, "Chat channel created");
+
+    Ok(chat_channel_id)
+}
+}
+
+backend_fn! {
+/// Updates a chat channel's provider, URL, and enabled flag.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `chat_channel_id` - The chat channel ID
+/// * `provider` - The new incoming webhook provider
+/// * `url` - The new incoming webhook URL
+/// * `is_enabled` - Whether announcements are posted
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn update_chat_channel(
+    conn: &mut _,
+    chat_channel_id: i64,
+    provider: &str,
+    url: &str,
+    is_enabled: bool,
+) -> Result<(), PersistenceError> {
+    diesel::update(chat_channels::table)
+        .filter(chat_channels::chat_channel_id.eq(chat_channel_id))
+        .set((
+            chat_channels::provider.eq(provider),
+            chat_channels::url.eq(url),
+            chat_channels::is_enabled.eq(i32::from(is_enabled)),
+        ))
+        .execute(conn)?;
+
+    info!(chat_channel_id, "Chat channel updated");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Deletes a chat channel and its announcement log.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `chat_channel_id` - The chat channel ID
+///
+/// # Errors
+///
+/// Returns an error if the database delete fails.
+pub fn delete_chat_channel(conn: &mut _, chat_channel_id: i64) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(
+            chat_notification_log::table
+                .filter(chat_notification_log::chat_channel_id.eq(chat_channel_id)),
+        )
+        .execute(conn)?;
+        diesel::delete(
+            chat_channels::table.filter(chat_channels::chat_channel_id.eq(chat_channel_id)),
+        )
+        .execute(conn)?;
+        Ok(())
+    })?;
+
+    info!(chat_channel_id, "Chat channel deleted");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Records a chat announcement in the announcement log.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `chat_channel_id` - The channel the announcement was posted to
+/// * `kind` - The announcement kind
+/// * `reference_key` - What the announcement is about
+/// * `message` - The posted text
+/// * `error` - The delivery error, or `None` if the announcement was delivered
+///
+/// # Errors
+///
+/// Returns an error if the entry cannot be recorded.
+pub fn record_chat_notification(
+    conn: &mut _,
+    chat_channel_id: i64,
+    kind: &str,
+    reference_key: &str,
+    message: &str,
+    error: Option<&str>,
+) -> Result<i64, PersistenceError> {
+    diesel::insert_into(chat_notification_log::table)
+        .values((
+            chat_notification_log::chat_channel_id.eq(chat_channel_id),
+            chat_notification_log::kind.eq(kind),
+            chat_notification_log::reference_key.eq(reference_key),
+            chat_notification_log::message.eq(message),
+            chat_notification_log::delivered.eq(i32::from(error.is_none())),
+            chat_notification_log::error.eq(error),
+        ))
+        .execute(conn)?;
+
+    let chat_notification_id: i64 = conn.get_last_insert_rowid()?;
+
+    info!(
+        chat_notification_id,
+        chat_channel_id,
+        kind,
+        reference_key,
+        delivered = error.is_none(),
+        "Chat notification recorded"
+    );
+
+    Ok(chat_notification_id)
+}
+}
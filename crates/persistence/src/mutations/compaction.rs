@@ -0,0 +1,149 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Audit log compaction mutations.
+//!
+//! Compacting a completed bid year moves its unreferenced area events out
+//! of the live log into `archived_audit_events`, chaining them by SHA-256
+//! as they are copied. Their delivered outbox rows are deleted with them.
+//! The compaction's digest events and sealed snapshots are written by the
+//! caller in the same transaction.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use num_traits::ToPrimitive;
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::AuditCompactionData;
+use crate::diesel_schema::{archived_audit_events, audit_compactions, audit_events, event_outbox};
+use crate::error::PersistenceError;
+use crate::queries::compaction::{AuditEventRow, GENESIS_CHAIN_HASH, event_columns};
+
+/// Events moved per statement, well under `SQLite`'s bound parameter
+/// limit.
+const ARCHIVE_CHUNK_SIZE: usize = 500;
+
+backend_fn! {
+/// Moves a bid year's audit events to the archive and records the
+/// compaction.
+///
+/// Events are chained oldest first. Callers pass only events
+/// `list_archivable_audit_events` returned, and run this in the same
+/// transaction as the compaction's digest events.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `event_ids` - The events to archive
+///
+/// # Returns
+///
+/// The recorded compaction.
+///
+/// # Errors
+///
+/// Returns an error if the bid year was already compacted or any write
+/// fails.
+pub fn archive_audit_events(
+    conn: &mut _,
+    bid_year_id: i64,
+    event_ids: &[i64],
+) -> Result<AuditCompactionData, PersistenceError> {
+    diesel::insert_into(audit_compactions::table)
+        .values((
+            audit_compactions::bid_year_id.eq(bid_year_id),
+            audit_compactions::archived_event_count.eq(0),
+            audit_compactions::chain_head.eq(GENESIS_CHAIN_HASH),
+        ))
+        .execute(conn)?;
+    let compaction_id: i64 = conn.get_last_insert_rowid()?;
+
+    let mut sorted: Vec<i64> = event_ids.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut chain_head: String = GENESIS_CHAIN_HASH.to_string();
+    for chunk in sorted.chunks(ARCHIVE_CHUNK_SIZE) {
+        let rows: Vec<AuditEventRow> = audit_events::table
+            .filter(audit_events::event_id.eq_any(chunk))
+            .order(audit_events::event_id.asc())
+            .select(event_columns!(audit_events))
+            .load(conn)?;
+        if rows.len() != chunk.len() {
+            return Err(PersistenceError::NotFound(format!(
+                "{} of the audit events to archive do not exist",
+                chunk.len() - rows.len()
+            )));
+        }
+
+        for row in rows {
+            chain_head = row.chain_hash(&chain_head);
+            diesel::insert_into(archived_audit_events::table)
+                .values((
+                    archived_audit_events::event_id.eq(row.event_id),
+                    archived_audit_events::compaction_id.eq(compaction_id),
+                    archived_audit_events::bid_year_id.eq(row.bid_year_id),
+                    archived_audit_events::area_id.eq(row.area_id),
+                    archived_audit_events::year.eq(row.year),
+                    archived_audit_events::area_code.eq(row.area_code),
+                    archived_audit_events::actor_operator_id.eq(row.actor_operator_id),
+                    archived_audit_events::actor_login_name.eq(row.actor_login_name),
+                    archived_audit_events::actor_display_name.eq(row.actor_display_name),
+                    archived_audit_events::actor_json.eq(row.actor_json),
+                    archived_audit_events::cause_json.eq(row.cause_json),
+                    archived_audit_events::action_json.eq(row.action_json),
+                    archived_audit_events::before_snapshot_json.eq(row.before_snapshot_json),
+                    archived_audit_events::after_snapshot_json.eq(row.after_snapshot_json),
+                    archived_audit_events::created_at.eq(row.created_at),
+                    archived_audit_events::event_ulid.eq(row.event_ulid),
+                    archived_audit_events::user_id.eq(row.user_id),
+                    archived_audit_events::chain_hash.eq(&chain_head),
+                ))
+                .execute(conn)?;
+        }
+
+        diesel::delete(event_outbox::table.filter(event_outbox::audit_event_id.eq_any(chunk)))
+            .execute(conn)?;
+        diesel::delete(audit_events::table.filter(audit_events::event_id.eq_any(chunk)))
+            .execute(conn)?;
+    }
+
+    let archived_event_count: i32 = sorted.len().to_i32().ok_or_else(|| {
+        PersistenceError::Other(format!("Too many events to archive: {}", sorted.len()))
+    })?;
+    diesel::update(audit_compactions::table.find(compaction_id))
+        .set((
+            audit_compactions::archived_event_count.eq(archived_event_count),
+            audit_compactions::first_event_id.eq(sorted.first().copied()),
+            audit_compactions::last_event_id.eq(sorted.last().copied()),
+            audit_compactions::chain_head.eq(&chain_head),
+        ))
+        .execute(conn)?;
+    let created_at: String = audit_compactions::table
+        .find(compaction_id)
+        .select(audit_compactions::created_at)
+        .first(conn)?;
+
+    info!(
+        bid_year_id,
+        compaction_id,
+        archived = sorted.len(),
+        chain_head = %chain_head,
+        "Archived audit events"
+    );
+
+    Ok(AuditCompactionData {
+        compaction_id,
+        bid_year_id,
+        archived_event_count: sorted.len(),
+        first_event_id: sorted.first().copied(),
+        last_event_id: sorted.last().copied(),
+        chain_head,
+        created_at,
+    })
+}
+}
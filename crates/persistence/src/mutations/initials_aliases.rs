@@ -0,0 +1,76 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Initials change mutations.
+//!
+//! Changing a user's initials rewrites the canonical record and keeps the
+//! retired value as an alias, so history recorded under it still resolves
+//! to the same user.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::diesel_schema::{user_initials_aliases, users};
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Changes a user's initials, recording the previous value as an alias.
+///
+/// Callers run this in the same transaction as the audit event that
+/// records the change, after checking the new initials are free.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `user_id` - The user whose initials change
+/// * `from` - The user's current initials
+/// * `to` - The user's new initials
+/// * `effective_date` - The date the new initials take effect (`YYYY-MM-DD`)
+/// * `change_event_id` - The audit event that recorded the change
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if no user of the bid year holds
+/// `from` under `user_id`, or an error if a write fails.
+pub fn change_user_initials(
+    conn: &mut _,
+    bid_year_id: i64,
+    user_id: i64,
+    from: &str,
+    to: &str,
+    effective_date: &str,
+    change_event_id: i64,
+) -> Result<(), PersistenceError> {
+    let updated: usize = diesel::update(
+        users::table
+            .filter(users::user_id.eq(user_id))
+            .filter(users::bid_year_id.eq(bid_year_id))
+            .filter(users::initials.eq(from)),
+    )
+    .set(users::initials.eq(to))
+    .execute(conn)?;
+    if updated == 0 {
+        return Err(PersistenceError::NotFound(format!(
+            "User {user_id} with initials '{from}' not found in bid year {bid_year_id}"
+        )));
+    }
+
+    diesel::insert_into(user_initials_aliases::table)
+        .values((
+            user_initials_aliases::bid_year_id.eq(bid_year_id),
+            user_initials_aliases::user_id.eq(user_id),
+            user_initials_aliases::initials.eq(from),
+            user_initials_aliases::effective_date.eq(effective_date),
+            user_initials_aliases::change_event_id.eq(change_event_id),
+        ))
+        .execute(conn)?;
+
+    info!(user_id, from, to, effective_date, "Changed user initials");
+
+    Ok(())
+}
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Leave bid mutations.
+//!
+//! This module contains backend-agnostic mutations for recording awarded
+//! leave and releasing it again.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::LeaveBidData;
+use crate::diesel_schema::leave_bids;
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Records a day of approved leave for a user within a round.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+/// * `user_id` - The canonical user ID
+/// * `round_id` - The round the leave was bid in
+/// * `leave_date` - The leave date (`YYYY-MM-DD`)
+/// * `hours` - The leave hours charged for the day
+/// * `on_behalf_of` - The initials of the controller the bid was entered for
+/// * `received_via` - How the bid was received (`phone`, `in_person`, `written`,
+///   or `legacy_import` for imported history)
+///
+/// # Errors
+///
+/// Returns an error if the leave cannot be recorded, including when the
+/// user already holds leave on that date in the round.
+#[allow(clippy::too_many_arguments)]
+pub fn insert_leave_bid(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    user_id: i64,
+    round_id: i64,
+    leave_date: &str,
+    hours: i32,
+    on_behalf_of: &str,
+    received_via: &str,
+) -> Result<i64, PersistenceError> {
+    diesel::insert_into(leave_bids::table)
+        .values((
+            leave_bids::bid_year_id.eq(bid_year_id),
+            leave_bids::area_id.eq(area_id),
+            leave_bids::user_id.eq(user_id),
+            leave_bids::round_id.eq(round_id),
+            leave_bids::leave_date.eq(leave_date),
+            leave_bids::hours.eq(hours),
+            leave_bids::on_behalf_of.eq(on_behalf_of),
+            leave_bids::received_via.eq(received_via),
+        ))
+        .execute(conn)?;
+
+    let leave_bid_id: i64 = conn.get_last_insert_rowid()?;
+
+    info!(
+        leave_bid_id,
+        user_id,
+        round_id,
+        leave_date,
+        hours,
+        on_behalf_of,
+        received_via,
+        "Leave bid recorded"
+    );
+
+    Ok(leave_bid_id)
+}
+}
+
+backend_fn! {
+/// Marks a leave bid as withdrawn so it no longer occupies a daily slot.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `leave_bid_id` - The leave bid ID
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn withdraw_leave_bid(conn: &mut _, leave_bid_id: i64) -> Result<(), PersistenceError> {
+    diesel::update(leave_bids::table)
+        .filter(leave_bids::leave_bid_id.eq(leave_bid_id))
+        .set(leave_bids::status.eq(LeaveBidData::STATUS_WITHDRAWN))
+        .execute(conn)?;
+
+    info!(leave_bid_id, "Leave bid withdrawn");
+
+    Ok(())
+}
+}
@@ -11,9 +11,24 @@
 //!
 //! ## Module Organization
 //!
+//! - `anonymization` — Erasure of former employees' personal data
 //! - `audit` — Audit event and snapshot persistence
+//! - `audit_annotations` — Notes attached to audit events after the fact
 //! - `canonical` — Canonical entity mutations (users, bid years, areas)
+//! - `chat` — Chat channel and announcement log mutations
+//! - `compaction` — Archiving completed bid years' audit events
+//! - `initials_aliases` — Initials changes and the aliases they leave behind
+//! - `leave` — Awarded leave mutations
+//! - `notifications` — User contact and notification log mutations
 //! - `operators` — Operator and session mutations
+//! - `outbox` — Transactional outbox of audit events awaiting publication
+//! - `overbids` — Overbid request and decision mutations
+//! - `overrides` — Canonical override ledger mutations
+//! - `rollback` — Restoring an area as of a rollback's target
+//! - `saved_audit_queries` — Saved audit filters and their weekly deliveries
+//! - `self_bids` — Operator controller links and self-service bid requests
+//! - `user_merges` — Merges of duplicate user records and their reverts
+//! - `webhooks` — Webhook configuration and dead-letter mutations
 //! - `bootstrap` — High-level orchestration (`persist_transition`, `persist_bootstrap`)
 //!
 //! ## Backend-Specific Code
@@ -21,14 +36,33 @@
 //! Backend-specific helpers (e.g., `get_last_insert_rowid()`) are imported from
 //! the `backend` module. All other code uses Diesel DSL exclusively.
 
+pub mod anonymization;
 pub mod audit;
+pub mod audit_annotations;
 pub mod bid_status;
 pub mod bootstrap;
 pub mod canonical;
+pub mod chat;
+pub mod compaction;
+pub mod initials_aliases;
+pub mod leave;
+pub mod notifications;
 pub mod operators;
+pub mod outbox;
+pub mod overbids;
+pub mod overrides;
+pub mod rollback;
+pub mod saved_audit_queries;
+pub mod self_bids;
+pub mod user_merges;
+pub mod webhooks;
 
 // Re-export backend-specific mutation functions used by lib.rs
-pub use audit::{persist_audit_event_mysql, persist_audit_event_sqlite};
+pub use audit::{
+    persist_audit_event_mysql, persist_audit_event_sqlite, record_event_correlations_mysql,
+    record_event_correlations_sqlite, record_event_dependencies_mysql,
+    record_event_dependencies_sqlite,
+};
 #[allow(unused_imports)]
 pub use bid_status::{
     bulk_insert_bid_status_history_mysql, bulk_insert_bid_status_history_sqlite,
@@ -42,15 +76,61 @@ pub use bootstrap::{
     set_expected_user_count_mysql, set_expected_user_count_sqlite,
 };
 pub use canonical::{
-    create_system_area_mysql, create_system_area_sqlite, update_area_name_mysql,
-    update_area_name_sqlite, update_user_mysql, update_user_sqlite,
+    clear_canonical_bid_year_mysql, clear_canonical_bid_year_sqlite, create_system_area_mysql,
+    create_system_area_sqlite, set_user_participation_mysql, set_user_participation_sqlite,
+    update_area_name_mysql, update_area_name_sqlite, update_area_round_group_mysql,
+    update_area_round_group_sqlite, update_user_mysql, update_user_sqlite,
+};
+pub use chat::{
+    create_chat_channel_mysql, create_chat_channel_sqlite, delete_chat_channel_mysql,
+    delete_chat_channel_sqlite, record_chat_notification_mysql, record_chat_notification_sqlite,
+    update_chat_channel_mysql, update_chat_channel_sqlite,
+};
+pub use leave::{
+    insert_leave_bid_mysql, insert_leave_bid_sqlite, withdraw_leave_bid_mysql,
+    withdraw_leave_bid_sqlite,
+};
+pub use notifications::{
+    delete_user_contact_mysql, delete_user_contact_sqlite, record_notification_mysql,
+    record_notification_sqlite, reseal_contact_columns_mysql, reseal_contact_columns_sqlite,
+    set_user_contact_mysql, set_user_contact_sqlite,
 };
 pub use operators::{
     create_operator_mysql, create_operator_sqlite, create_session_mysql, create_session_sqlite,
     delete_expired_sessions_mysql, delete_expired_sessions_sqlite, delete_operator_mysql,
-    delete_operator_sqlite, delete_session_mysql, delete_session_sqlite,
+    delete_operator_sqlite, delete_other_sessions_for_operator_mysql,
+    delete_other_sessions_for_operator_sqlite, delete_session_mysql, delete_session_sqlite,
     delete_sessions_for_operator_mysql, delete_sessions_for_operator_sqlite,
     disable_operator_mysql, disable_operator_sqlite, enable_operator_mysql, enable_operator_sqlite,
-    update_last_login_mysql, update_last_login_sqlite, update_password_mysql,
-    update_password_sqlite, update_session_activity_mysql, update_session_activity_sqlite,
+    record_failed_login_mysql, record_failed_login_sqlite, rekey_session_mysql,
+    rekey_session_sqlite, set_operator_area_scopes_mysql, set_operator_area_scopes_sqlite,
+    update_display_name_mysql, update_display_name_sqlite, update_last_login_mysql,
+    update_last_login_sqlite, update_operator_role_mysql, update_operator_role_sqlite,
+    update_password_mysql, update_password_sqlite, update_session_activity_mysql,
+    update_session_activity_sqlite,
+};
+pub use outbox::{mark_outbox_entries_delivered_mysql, mark_outbox_entries_delivered_sqlite};
+pub use overbids::{
+    decide_overbid_request_mysql, decide_overbid_request_sqlite, insert_overbid_request_mysql,
+    insert_overbid_request_sqlite, set_overbid_request_event_mysql,
+    set_overbid_request_event_sqlite,
+};
+pub use overrides::{
+    insert_canonical_override_mysql, insert_canonical_override_sqlite,
+    revert_canonical_override_mysql, revert_canonical_override_sqlite,
+};
+pub use saved_audit_queries::{
+    create_saved_audit_query_mysql, create_saved_audit_query_sqlite,
+    delete_saved_audit_query_mysql, delete_saved_audit_query_sqlite,
+    mark_saved_audit_query_delivered_mysql, mark_saved_audit_query_delivered_sqlite,
+    set_saved_audit_query_recipients_mysql, set_saved_audit_query_recipients_sqlite,
+};
+pub use self_bids::{
+    confirm_self_bid_request_mysql, confirm_self_bid_request_sqlite, insert_self_bid_request_mysql,
+    insert_self_bid_request_sqlite, set_operator_controller_mysql, set_operator_controller_sqlite,
+};
+pub use webhooks::{
+    create_webhook_mysql, create_webhook_sqlite, delete_webhook_mysql, delete_webhook_sqlite,
+    record_webhook_dead_letter_mysql, record_webhook_dead_letter_sqlite, update_webhook_mysql,
+    update_webhook_sqlite,
 };
@@ -0,0 +1,215 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Notification mutations.
+//!
+//! This module contains backend-agnostic mutations for user contact details
+//! and the append-only notification log.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::diesel_schema::{notification_log, user_contacts};
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Creates or replaces a user's contact details.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `user_id` - The canonical user ID
+/// * `email` - The email address to notify
+/// * `email_enabled` - Whether email notifications are sent
+///
+/// # Errors
+///
+/// Returns an error if the database operation fails.
+pub fn set_user_contact(
+    conn: &mut _,
+    user_id: i64,
+    email: &str,
+    email_enabled: bool,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        let updated: usize = diesel::update(user_contacts::table)
+            .filter(user_contacts::user_id.eq(user_id))
+            .set((
+                user_contacts::email.eq(email),
+                user_contacts::email_enabled.eq(i32::from(email_enabled)),
+                user_contacts::updated_at.eq(diesel::dsl::sql::<diesel::sql_types::Text>(
+                    "CURRENT_TIMESTAMP",
+                )),
+            ))
+            .execute(conn)?;
+
+        if updated == 0 {
+            diesel::insert_into(user_contacts::table)
+                .values((
+                    user_contacts::user_id.eq(user_id),
+                    user_contacts::email.eq(email),
+                    user_contacts::email_enabled.eq(i32::from(email_enabled)),
+                ))
+                .execute(conn)?;
+        }
+        Ok(())
+    })?;
+
+    info!(user_id, email_enabled, "User contact set");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Removes a user's contact details.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `user_id` - The canonical user ID
+///
+/// # Returns
+///
+/// `true` if contact details were removed.
+///
+/// # Errors
+///
+/// Returns an error if the database delete fails.
+pub fn delete_user_contact(conn: &mut _, user_id: i64) -> Result<bool, PersistenceError> {
+    let deleted: usize =
+        diesel::delete(user_contacts::table.filter(user_contacts::user_id.eq(user_id)))
+            .execute(conn)?;
+
+    info!(user_id, deleted, "User contact deleted");
+
+    Ok(deleted > 0)
+}
+}
+
+backend_fn! {
+/// Records a notification in the notification log.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `user_id` - The user who was notified
+/// * `kind` - The notification kind
+/// * `reference_id` - The bid window or history ID the notification is about
+/// * `recipient` - The address the notification was sent to
+/// * `subject` - The rendered subject line
+/// * `error` - The delivery error, or `None` if the notification was delivered
+///
+/// # Errors
+///
+/// Returns an error if the entry cannot be recorded, including when a
+/// notification of the same kind was already recorded for `reference_id`.
+pub fn record_notification(
+    conn: &mut _,
+    user_id: i64,
+    kind: &str,
+    reference_id: i64,
+    recipient: &str,
+    subject: &str,
+    error: Option<&str>,
+) -> Result<i64, PersistenceError> {
+    diesel::insert_into(notification_log::table)
+        .values((
+            notification_log::user_id.eq(user_id),
+            notification_log::kind.eq(kind),
+            notification_log::reference_id.eq(reference_id),
+            notification_log::recipient.eq(recipient),
+            notification_log::subject.eq(subject),
+            notification_log::delivered.eq(i32::from(error.is_none())),
+            notification_log::error.eq(error),
+        ))
+        .execute(conn)?;
+
+    let notification_id: i64 = conn.get_last_insert_rowid()?;
+
+    info!(
+        notification_id,
+        user_id,
+        kind,
+        reference_id,
+        delivered = error.is_none(),
+        "Notification recorded"
+    );
+
+    Ok(notification_id)
+}
+}
+
+/// Returns the value to store in place of a user's stored column value, or
+/// `None` to leave it alone.
+pub type Reseal<'a> = &'a dyn Fn(i64, &str) -> Result<Option<String>, PersistenceError>;
+
+backend_fn! {
+/// Rewrites contact email addresses that `reseal` asks to change.
+///
+/// `reseal` is called with each row's user ID and stored address, in both
+/// `user_contacts` and `notification_log`, and returns the value to store
+/// instead, or `None` to leave the row alone. All rows are rewritten in one
+/// transaction.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `reseal_email` - Reseals a `user_contacts.email` value
+/// * `reseal_recipient` - Reseals a `notification_log.recipient` value
+///
+/// # Returns
+///
+/// The number of rows rewritten.
+///
+/// # Errors
+///
+/// Returns an error if a row cannot be resealed or the database operation
+/// fails.
+pub fn reseal_contact_columns(
+    conn: &mut _,
+    reseal_email: Reseal<'_>,
+    reseal_recipient: Reseal<'_>,
+) -> Result<usize, PersistenceError> {
+    conn.transaction::<usize, PersistenceError, _>(|conn| {
+        let mut resealed: usize = 0;
+
+        let contacts: Vec<(i64, String)> = user_contacts::table
+            .select((user_contacts::user_id, user_contacts::email))
+            .load(conn)?;
+        for (user_id, email) in contacts {
+            if let Some(sealed) = reseal_email(user_id, &email)? {
+                diesel::update(user_contacts::table.filter(user_contacts::user_id.eq(user_id)))
+                    .set(user_contacts::email.eq(sealed))
+                    .execute(conn)?;
+                resealed += 1;
+            }
+        }
+
+        let recipients: Vec<(i64, i64, String)> = notification_log::table
+            .select((
+                notification_log::notification_id,
+                notification_log::user_id,
+                notification_log::recipient,
+            ))
+            .load(conn)?;
+        for (notification_id, user_id, recipient) in recipients {
+            if let Some(sealed) = reseal_recipient(user_id, &recipient)? {
+                diesel::update(
+                    notification_log::table
+                        .filter(notification_log::notification_id.eq(notification_id)),
+                )
+                .set(notification_log::recipient.eq(sealed))
+                .execute(conn)?;
+                resealed += 1;
+            }
+        }
+
+        Ok(resealed)
+    })
+}
+}
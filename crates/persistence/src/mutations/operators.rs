@@ -14,7 +14,7 @@ use diesel::{MysqlConnection, SqliteConnection};
 use tracing::{debug, info};
 
 use crate::backend::PersistenceBackend;
-use crate::diesel_schema::{operators, sessions};
+use crate::diesel_schema::{operator_area_scopes, operators, sessions};
 use crate::error::PersistenceError;
 use crate::queries::operators::{is_operator_referenced_mysql, is_operator_referenced_sqlite};
 
@@ -74,6 +74,8 @@ pub fn create_operator(
 backend_fn! {
 /// Updates the last login timestamp for an operator.
 ///
+/// A successful sign-in also clears the failed sign-in count.
+///
 /// # Arguments
 ///
 /// * `conn` - The database connection
@@ -92,6 +94,34 @@ pub fn update_last_login(conn: &mut _, operator_id: i64) -> Result<(), Persisten
         >("CURRENT_TIMESTAMP")))
         .execute(conn)?;
 
+    diesel::update(operators::table)
+        .filter(operators::operator_id.eq(operator_id))
+        .set(operators::failed_login_count.eq(0))
+        .execute(conn)?;
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Records a failed sign-in attempt for an operator.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `operator_id` - The operator ID
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn record_failed_login(conn: &mut _, operator_id: i64) -> Result<(), PersistenceError> {
+    debug!("Recording failed login for operator ID: {}", operator_id);
+
+    diesel::update(operators::table)
+        .filter(operators::operator_id.eq(operator_id))
+        .set(operators::failed_login_count.eq(operators::failed_login_count + 1))
+        .execute(conn)?;
+
     Ok(())
 }
 }
@@ -238,7 +268,7 @@ backend_fn! {
 /// # Arguments
 ///
 /// * `conn` - The database connection
-/// * `session_token` - The unique session token
+/// * `session_token_hash` - The keyed digest of the session token
 /// * `operator_id` - The operator ID
 /// * `expires_at` - The expiration timestamp (ISO 8601 format)
 ///
@@ -247,7 +277,7 @@ backend_fn! {
 /// Returns an error if the session cannot be created.
 pub fn create_session(
     conn: &mut _,
-    session_token: &str,
+    session_token_hash: &str,
     operator_id: i64,
     expires_at: &str,
 ) -> Result<i64, PersistenceError> {
@@ -258,7 +288,7 @@ pub fn create_session(
 
     diesel::insert_into(sessions::table)
         .values((
-            sessions::session_token.eq(session_token),
+            sessions::session_token_hash.eq(session_token_hash),
             sessions::operator_id.eq(operator_id),
             sessions::expires_at.eq(expires_at),
         ))
@@ -299,6 +329,37 @@ pub fn update_session_activity(conn: &mut _, session_id: i64) -> Result<(), Pers
 }
 }
 
+backend_fn! {
+/// Moves a session to a new token digest.
+///
+/// This is used when the session key is rotated, so a session stored under
+/// the previous key is stored under the current one from then on.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `session_id` - The session ID
+/// * `session_token_hash` - The digest of the token under the current key
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn rekey_session(
+    conn: &mut _,
+    session_id: i64,
+    session_token_hash: &str,
+) -> Result<(), PersistenceError> {
+    debug!("Re-keying session ID: {}", session_id);
+
+    diesel::update(sessions::table)
+        .filter(sessions::session_id.eq(session_id))
+        .set(sessions::session_token_hash.eq(session_token_hash))
+        .execute(conn)?;
+
+    Ok(())
+}
+}
+
 backend_fn! {
 /// Deletes a session by token.
 ///
@@ -307,16 +368,19 @@ backend_fn! {
 /// # Arguments
 ///
 /// * `conn` - The database connection
-/// * `session_token` - The session token to delete
+/// * `session_token_hashes` - The digests the session token may be stored under
 ///
 /// # Errors
 ///
 /// Returns an error if the database delete fails.
-pub fn delete_session(conn: &mut _, session_token: &str) -> Result<(), PersistenceError> {
+pub fn delete_session(
+    conn: &mut _,
+    session_token_hashes: &[String],
+) -> Result<(), PersistenceError> {
     debug!("Deleting session by token");
 
     diesel::delete(sessions::table)
-        .filter(sessions::session_token.eq(session_token))
+        .filter(sessions::session_token_hash.eq_any(session_token_hashes))
         .execute(conn)?;
 
     Ok(())
@@ -331,19 +395,16 @@ backend_fn! {
 /// # Arguments
 ///
 /// * `conn` - The database connection
+/// * `now` - The current time, formatted like session expiries
 ///
 /// # Errors
 ///
 /// Returns an error if the database delete fails.
-pub fn delete_expired_sessions(conn: &mut _) -> Result<usize, PersistenceError> {
+pub fn delete_expired_sessions(conn: &mut _, now: &str) -> Result<usize, PersistenceError> {
     debug!("Deleting expired sessions");
 
     let rows_affected: usize = diesel::delete(sessions::table)
-        .filter(
-            sessions::expires_at.lt(diesel::dsl::sql::<diesel::sql_types::Text>(
-                "CURRENT_TIMESTAMP",
-            )),
-        )
+        .filter(sessions::expires_at.lt(now))
         .execute(conn)?;
 
     info!("Deleted {} expired sessions", rows_affected);
@@ -414,3 +475,146 @@ pub fn delete_sessions_for_operator(
     Ok(rows_affected)
 }
 }
+
+backend_fn! {
+/// Deletes every session of an operator except one.
+///
+/// This is used when an operator changes their own password: the session
+/// the change was made from stays signed in, while sessions on other devices
+/// are invalidated.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `operator_id` - The operator ID whose sessions should be deleted
+/// * `keep_session_token_hash` - The token digest of the session to keep
+///
+/// # Errors
+///
+/// Returns an error if the database delete fails.
+pub fn delete_other_sessions_for_operator(
+    conn: &mut _,
+    operator_id: i64,
+    keep_session_token_hash: &str,
+) -> Result<usize, PersistenceError> {
+    info!("Deleting other sessions for operator ID: {}", operator_id);
+
+    let rows_affected: usize = diesel::delete(sessions::table)
+        .filter(sessions::operator_id.eq(operator_id))
+        .filter(sessions::session_token_hash.ne(keep_session_token_hash))
+        .execute(conn)?;
+
+    info!(
+        "Deleted {} other sessions for operator ID: {}",
+        rows_affected, operator_id
+    );
+    Ok(rows_affected)
+}
+}
+
+backend_fn! {
+/// Updates an operator's display name.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `operator_id` - The operator ID
+/// * `display_name` - The new display name
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the operator does not exist.
+pub fn update_display_name(
+    conn: &mut _,
+    operator_id: i64,
+    display_name: &str,
+) -> Result<(), PersistenceError> {
+    let rows_affected: usize = diesel::update(operators::table)
+        .filter(operators::operator_id.eq(operator_id))
+        .set(operators::display_name.eq(display_name))
+        .execute(conn)?;
+
+    if rows_affected == 0 {
+        return Err(PersistenceError::NotFound(format!(
+            "Operator {operator_id} not found"
+        )));
+    }
+
+    debug!("Updated display name for operator ID: {}", operator_id);
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Updates an operator's role.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `operator_id` - The operator ID
+/// * `role` - The new role (Admin or Bidder)
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the operator does not exist.
+pub fn update_operator_role(
+    conn: &mut _,
+    operator_id: i64,
+    role: &str,
+) -> Result<(), PersistenceError> {
+    info!("Changing role for operator ID: {} to {}", operator_id, role);
+
+    let rows_affected: usize = diesel::update(operators::table)
+        .filter(operators::operator_id.eq(operator_id))
+        .set(operators::role.eq(role))
+        .execute(conn)?;
+
+    if rows_affected == 0 {
+        return Err(PersistenceError::NotFound(format!(
+            "Operator {operator_id} not found"
+        )));
+    }
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Replaces the set of areas an operator is assigned to.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `operator_id` - The operator ID
+/// * `area_ids` - The areas to assign; an empty slice clears all scopes
+///
+/// # Errors
+///
+/// Returns an error if an area does not exist or the database write fails.
+pub fn set_operator_area_scopes(
+    conn: &mut _,
+    operator_id: i64,
+    area_ids: &[i64],
+) -> Result<(), PersistenceError> {
+    info!(
+        "Setting {} area scopes for operator ID: {}",
+        area_ids.len(),
+        operator_id
+    );
+
+    diesel::delete(operator_area_scopes::table)
+        .filter(operator_area_scopes::operator_id.eq(operator_id))
+        .execute(conn)?;
+
+    for area_id in area_ids {
+        diesel::insert_into(operator_area_scopes::table)
+            .values((
+                operator_area_scopes::operator_id.eq(operator_id),
+                operator_area_scopes::area_id.eq(area_id),
+            ))
+            .execute(conn)?;
+    }
+
+    Ok(())
+}
+}
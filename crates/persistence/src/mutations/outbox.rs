@@ -0,0 +1,60 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Transactional outbox mutations.
+//!
+//! Outbox rows are inserted alongside their audit events by
+//! `persist_audit_event_with_ids`, so they commit or roll back together.
+//! The relay marks rows delivered once it has published them.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::debug;
+
+use crate::diesel_schema::event_outbox;
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Marks outbox entries as delivered.
+///
+/// Entries already delivered keep their original delivery time.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `outbox_ids` - The entries that were published
+///
+/// # Returns
+///
+/// The number of entries marked.
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn mark_outbox_entries_delivered(
+    conn: &mut _,
+    outbox_ids: &[i64],
+) -> Result<usize, PersistenceError> {
+    if outbox_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let marked: usize = diesel::update(
+        event_outbox::table
+            .filter(event_outbox::outbox_id.eq_any(outbox_ids))
+            .filter(event_outbox::delivered_at.is_null()),
+    )
+    .set(
+        event_outbox::delivered_at.eq(diesel::dsl::sql::<
+            diesel::sql_types::Nullable<diesel::sql_types::Text>,
+        >("CURRENT_TIMESTAMP")),
+    )
+    .execute(conn)?;
+
+    debug!(marked, "Outbox entries delivered");
+
+    Ok(marked)
+}
+}
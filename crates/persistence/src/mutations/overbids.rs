@@ -0,0 +1,143 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Overbid request mutations.
+//!
+//! This module records requests for leave on full days and the Admin
+//! decisions on them, linking each row to the audit events that recorded
+//! the request and the decision.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::diesel_schema::overbid_requests;
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Records a pending overbid request.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+/// * `user_id` - The canonical user ID
+/// * `round_id` - The round the leave is requested in
+/// * `leave_date` - The leave date (`YYYY-MM-DD`)
+/// * `hours` - The leave hours charged for the day
+/// * `on_behalf_of` - The initials of the controller the request was entered for
+/// * `received_via` - How the request was received (`phone`, `in_person`, `written`)
+///
+/// # Errors
+///
+/// Returns an error if the request cannot be recorded.
+#[allow(clippy::too_many_arguments)]
+pub fn insert_overbid_request(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    user_id: i64,
+    round_id: i64,
+    leave_date: &str,
+    hours: i32,
+    on_behalf_of: &str,
+    received_via: &str,
+) -> Result<i64, PersistenceError> {
+    diesel::insert_into(overbid_requests::table)
+        .values((
+            overbid_requests::bid_year_id.eq(bid_year_id),
+            overbid_requests::area_id.eq(area_id),
+            overbid_requests::user_id.eq(user_id),
+            overbid_requests::round_id.eq(round_id),
+            overbid_requests::leave_date.eq(leave_date),
+            overbid_requests::hours.eq(hours),
+            overbid_requests::on_behalf_of.eq(on_behalf_of),
+            overbid_requests::received_via.eq(received_via),
+        ))
+        .execute(conn)?;
+
+    let overbid_request_id: i64 = conn.get_last_insert_rowid()?;
+
+    info!(
+        overbid_request_id,
+        user_id,
+        round_id,
+        leave_date,
+        "Overbid request recorded"
+    );
+
+    Ok(overbid_request_id)
+}
+}
+
+backend_fn! {
+/// Links an overbid request to the audit event that recorded it.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `overbid_request_id` - The overbid request ID
+/// * `request_event_id` - The audit event ID
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn set_overbid_request_event(
+    conn: &mut _,
+    overbid_request_id: i64,
+    request_event_id: i64,
+) -> Result<(), PersistenceError> {
+    diesel::update(overbid_requests::table)
+        .filter(overbid_requests::overbid_request_id.eq(overbid_request_id))
+        .set(overbid_requests::request_event_id.eq(Some(request_event_id)))
+        .execute(conn)?;
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Records the Admin decision on an overbid request.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `overbid_request_id` - The overbid request ID
+/// * `status` - The decision (`approved` or `denied`)
+/// * `decision_reason` - Why the request was denied, if it was
+/// * `decision_event_id` - The audit event that recorded the decision
+/// * `leave_bid_id` - The leave granted on approval
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn decide_overbid_request(
+    conn: &mut _,
+    overbid_request_id: i64,
+    status: &str,
+    decision_reason: Option<&str>,
+    decision_event_id: i64,
+    leave_bid_id: Option<i64>,
+) -> Result<(), PersistenceError> {
+    diesel::update(overbid_requests::table)
+        .filter(overbid_requests::overbid_request_id.eq(overbid_request_id))
+        .set((
+            overbid_requests::status.eq(status),
+            overbid_requests::decision_reason.eq(decision_reason),
+            overbid_requests::decision_event_id.eq(Some(decision_event_id)),
+            overbid_requests::leave_bid_id.eq(leave_bid_id),
+            overbid_requests::decided_at.eq(diesel::dsl::sql::<
+                diesel::sql_types::Nullable<diesel::sql_types::Text>,
+            >("CURRENT_TIMESTAMP")),
+        ))
+        .execute(conn)?;
+
+    info!(overbid_request_id, status, decision_event_id, "Overbid request decided");
+
+    Ok(())
+}
+}
@@ -0,0 +1,196 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Canonical override ledger mutations.
+//!
+//! This module contains backend-agnostic mutations for recording overrides
+//! applied to canonical tables and for reverting them.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::OverrideValue;
+use crate::diesel_schema::{
+    canonical_area_membership, canonical_bid_order, canonical_bid_windows, canonical_eligibility,
+    canonical_overrides,
+};
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Records an applied override in the ledger.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `user_id` - The canonical user ID
+/// * `previous_value` - The canonical value before the override
+/// * `new_value` - The canonical value after the override
+/// * `reason` - The reason given for the override
+/// * `audit_event_id` - The audit event that recorded the override
+///
+/// # Errors
+///
+/// Returns an error if the values cannot be encoded or the insert fails.
+pub fn insert_canonical_override(
+    conn: &mut _,
+    bid_year_id: i64,
+    user_id: i64,
+    previous_value: &OverrideValue,
+    new_value: &OverrideValue,
+    reason: &str,
+    audit_event_id: i64,
+) -> Result<i64, PersistenceError> {
+    let previous_json: String = serde_json::to_string(previous_value)?;
+    let new_json: String = serde_json::to_string(new_value)?;
+
+    diesel::insert_into(canonical_overrides::table)
+        .values((
+            canonical_overrides::bid_year_id.eq(bid_year_id),
+            canonical_overrides::user_id.eq(user_id),
+            canonical_overrides::override_kind.eq(new_value.kind()),
+            canonical_overrides::previous_value.eq(&previous_json),
+            canonical_overrides::new_value.eq(&new_json),
+            canonical_overrides::reason.eq(reason),
+            canonical_overrides::audit_event_id.eq(audit_event_id),
+        ))
+        .execute(conn)?;
+
+    let override_id: i64 = conn.get_last_insert_rowid()?;
+
+    info!(override_id, user_id, kind = new_value.kind(), "Override recorded");
+
+    Ok(override_id)
+}
+}
+
+backend_fn! {
+/// Reverts an override, restoring the canonical value it replaced.
+///
+/// If an earlier unreverted override of the same kind exists for the user,
+/// the canonical record stays marked as overridden with that override's
+/// reason; otherwise the override flag and reason are cleared. Reverting a
+/// user's last eligibility override restores their derived eligibility
+/// instead of `previous_value`. Callers are responsible for only reverting
+/// the most recent unreverted override.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `override_id` - The override to revert
+/// * `bid_year_id` - The canonical bid year ID
+/// * `user_id` - The canonical user ID
+/// * `previous_value` - The canonical value to restore
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn revert_canonical_override(
+    conn: &mut _,
+    override_id: i64,
+    bid_year_id: i64,
+    user_id: i64,
+    previous_value: &OverrideValue,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        let earlier_reason: Option<String> = canonical_overrides::table
+            .filter(canonical_overrides::bid_year_id.eq(bid_year_id))
+            .filter(canonical_overrides::user_id.eq(user_id))
+            .filter(canonical_overrides::override_kind.eq(previous_value.kind()))
+            .filter(canonical_overrides::override_id.lt(override_id))
+            .filter(canonical_overrides::is_reverted.eq(0))
+            .order_by(canonical_overrides::override_id.desc())
+            .select(canonical_overrides::reason)
+            .first::<String>(conn)
+            .optional()?;
+        let is_overridden: i32 = i32::from(earlier_reason.is_some());
+
+        match previous_value {
+            OverrideValue::AreaAssignment { area_id } => {
+                diesel::update(
+                    canonical_area_membership::table
+                        .filter(canonical_area_membership::bid_year_id.eq(bid_year_id))
+                        .filter(canonical_area_membership::user_id.eq(user_id)),
+                )
+                .set((
+                    canonical_area_membership::area_id.eq(area_id),
+                    canonical_area_membership::is_overridden.eq(is_overridden),
+                    canonical_area_membership::override_reason.eq(&earlier_reason),
+                ))
+                .execute(conn)?;
+            }
+            OverrideValue::Eligibility { can_bid } => {
+                let row = canonical_eligibility::table
+                    .filter(canonical_eligibility::bid_year_id.eq(bid_year_id))
+                    .filter(canonical_eligibility::user_id.eq(user_id));
+                if earlier_reason.is_some() {
+                    diesel::update(row)
+                        .set((
+                            canonical_eligibility::can_bid.eq(i32::from(*can_bid)),
+                            canonical_eligibility::is_overridden.eq(is_overridden),
+                            canonical_eligibility::override_reason.eq(&earlier_reason),
+                        ))
+                        .execute(conn)?;
+                } else {
+                    // With no override left in force, eligibility falls back
+                    // to the derived value, which may have been recomputed
+                    // since the override was recorded
+                    diesel::update(row)
+                        .set((
+                            canonical_eligibility::can_bid.eq(canonical_eligibility::derived_can_bid),
+                            canonical_eligibility::is_overridden.eq(is_overridden),
+                            canonical_eligibility::override_reason.eq(&earlier_reason),
+                        ))
+                        .execute(conn)?;
+                }
+            }
+            OverrideValue::BidOrder { bid_order } => {
+                diesel::update(
+                    canonical_bid_order::table
+                        .filter(canonical_bid_order::bid_year_id.eq(bid_year_id))
+                        .filter(canonical_bid_order::user_id.eq(user_id)),
+                )
+                .set((
+                    canonical_bid_order::bid_order.eq(bid_order),
+                    canonical_bid_order::is_overridden.eq(is_overridden),
+                    canonical_bid_order::override_reason.eq(&earlier_reason),
+                ))
+                .execute(conn)?;
+            }
+            OverrideValue::BidWindow {
+                window_start,
+                window_end,
+            } => {
+                diesel::update(
+                    canonical_bid_windows::table
+                        .filter(canonical_bid_windows::bid_year_id.eq(bid_year_id))
+                        .filter(canonical_bid_windows::user_id.eq(user_id)),
+                )
+                .set((
+                    canonical_bid_windows::window_start_date.eq(window_start),
+                    canonical_bid_windows::window_end_date.eq(window_end),
+                    canonical_bid_windows::is_overridden.eq(is_overridden),
+                    canonical_bid_windows::override_reason.eq(&earlier_reason),
+                ))
+                .execute(conn)?;
+            }
+        }
+
+        diesel::update(
+            canonical_overrides::table.filter(canonical_overrides::override_id.eq(override_id)),
+        )
+        .set(canonical_overrides::is_reverted.eq(1))
+        .execute(conn)?;
+
+        Ok(())
+    })?;
+
+    info!(override_id, user_id, "Override reverted");
+
+    Ok(())
+}
+}
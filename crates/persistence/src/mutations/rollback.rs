@@ -0,0 +1,613 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Rollback mutations.
+//!
+//! A `Rollback` event returns one `(BidYear, Area)` to its state as of an
+//! earlier audit event. The area's events after the target are marked
+//! superseded in `superseded_audit_events`; the audit log itself is never
+//! changed. Events in other scopes that depend on a superseded event are
+//! superseded with it. Overrides the superseded events recorded are
+//! reverted, the leave bids entered since are withdrawn, and the canonical
+//! users are restored from the last snapshot at or before the target. Once
+//! a bid year is canonicalized, the area's canonical rows are regenerated
+//! from the restored users. The restored state is then snapshotted at the
+//! rollback event, so later reads and replays start from it.
+//!
+//! Callers run this in the same transaction as the `Rollback` audit event.
+
+use std::collections::BTreeMap;
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use num_traits::ToPrimitive;
+use tracing::info;
+use zab_bid::{DerivedEligibility, State};
+use zab_bid_domain::User;
+
+use crate::data_models::{
+    CanonicalOverrideData, LeaveBidData, NewCanonicalAreaMembership, NewCanonicalBidOrder,
+    NewCanonicalBidWindows, NewCanonicalEligibility, SnapshotEncoding,
+};
+use crate::diesel_schema::{
+    audit_event_dependencies, audit_events, canonical_area_membership, canonical_bid_order,
+    canonical_bid_windows, canonical_eligibility, canonical_overrides, leave_bids,
+    superseded_audit_events, users,
+};
+use crate::error::PersistenceError;
+use crate::mutations::audit::{persist_state_snapshot_mysql, persist_state_snapshot_sqlite};
+use crate::mutations::overrides::{
+    revert_canonical_override_mysql, revert_canonical_override_sqlite,
+};
+use crate::queries::canonical::{
+    canonical_rows_exist_mysql, canonical_rows_exist_sqlite, lookup_area_id_mysql,
+    lookup_area_id_sqlite, lookup_bid_year_id_mysql, lookup_bid_year_id_sqlite,
+};
+use crate::queries::eligibility::{
+    apply_derived_eligibility_mysql, apply_derived_eligibility_sqlite,
+    derive_bid_year_eligibility_mysql, derive_bid_year_eligibility_sqlite,
+};
+use crate::queries::overrides::{list_canonical_overrides_mysql, list_canonical_overrides_sqlite};
+use crate::queries::state::{
+    get_current_state_mysql, get_current_state_sqlite, get_snapshot_at_or_before_event_mysql,
+    get_snapshot_at_or_before_event_sqlite,
+};
+
+/// An audit event's ID and when it was persisted.
+type EventTime = (i64, Option<String>);
+
+/// The column values of a user's canonical row, less its ID.
+macro_rules! user_values {
+    ($user:expr, $bid_year_id:expr, $area_id:expr) => {
+        (
+            users::bid_year_id.eq($bid_year_id),
+            users::area_id.eq($area_id),
+            users::initials.eq($user.initials.value()),
+            users::name.eq(&$user.name),
+            users::user_type.eq($user.user_type.as_str()),
+            users::crew.eq($user.crew.as_ref().map(|c| i32::from(c.number()))),
+            users::cumulative_natca_bu_date.eq(&$user.seniority_data.cumulative_natca_bu_date),
+            users::natca_bu_date.eq(&$user.seniority_data.natca_bu_date),
+            users::eod_faa_date.eq(&$user.seniority_data.eod_faa_date),
+            users::service_computation_date.eq(&$user.seniority_data.service_computation_date),
+            users::lottery_value.eq($user.seniority_data.lottery_value.and_then(|v| v.to_i32())),
+            users::excluded_from_bidding.eq(i32::from($user.excluded_from_bidding)),
+            users::excluded_from_leave_calculation
+                .eq(i32::from($user.excluded_from_leave_calculation)),
+            users::no_bid_reviewed.eq(i32::from($user.no_bid_reviewed)),
+        )
+    };
+}
+
+backend_fn! {
+/// Marks an area's events between a rollback and its target as superseded.
+///
+/// Events an earlier rollback already superseded are left as they are.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+/// * `target_event_id` - The event rolled back to
+/// * `rollback_event_id` - The `Rollback` event
+///
+/// # Returns
+///
+/// The newly superseded events, oldest first.
+///
+/// # Errors
+///
+/// Returns an error if a query or insert fails.
+pub fn supersede_area_events(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    target_event_id: i64,
+    rollback_event_id: i64,
+) -> Result<Vec<EventTime>, PersistenceError> {
+    let events: Vec<EventTime> = audit_events::table
+        .filter(audit_events::bid_year_id.eq(bid_year_id))
+        .filter(audit_events::area_id.eq(area_id))
+        .filter(audit_events::event_id.gt(target_event_id))
+        .filter(audit_events::event_id.lt(rollback_event_id))
+        .filter(
+            audit_events::event_id
+                .ne_all(superseded_audit_events::table.select(superseded_audit_events::event_id)),
+        )
+        .order(audit_events::event_id.asc())
+        .select((audit_events::event_id, audit_events::created_at))
+        .load(conn)?;
+
+    for (event_id, _) in &events {
+        diesel::insert_into(superseded_audit_events::table)
+            .values((
+                superseded_audit_events::event_id.eq(event_id),
+                superseded_audit_events::rollback_event_id.eq(rollback_event_id),
+            ))
+            .execute(conn)?;
+    }
+
+    Ok(events)
+}
+}
+
+backend_fn! {
+/// Marks the events that depend on superseded events as superseded.
+///
+/// Dependents are followed transitively, across scopes. Callers confirm
+/// the cascade before the rollback is recorded.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `event_ids` - The events the rollback superseded
+/// * `rollback_event_id` - The `Rollback` event
+///
+/// # Returns
+///
+/// The newly superseded dependents, in the order they were found.
+///
+/// # Errors
+///
+/// Returns an error if a query or insert fails.
+pub fn supersede_dependent_events(
+    conn: &mut _,
+    event_ids: &[i64],
+    rollback_event_id: i64,
+) -> Result<Vec<i64>, PersistenceError> {
+    let mut superseded: Vec<i64> = Vec::new();
+    let mut frontier: Vec<i64> = event_ids.to_vec();
+    while !frontier.is_empty() {
+        let mut dependents: Vec<i64> = audit_event_dependencies::table
+            .filter(audit_event_dependencies::depends_on_event_id.eq_any(&frontier))
+            .filter(audit_event_dependencies::event_id.ne_all(
+                superseded_audit_events::table.select(superseded_audit_events::event_id),
+            ))
+            .select(audit_event_dependencies::event_id)
+            .load(conn)?;
+        dependents.sort_unstable();
+        dependents.dedup();
+
+        for event_id in &dependents {
+            diesel::insert_into(superseded_audit_events::table)
+                .values((
+                    superseded_audit_events::event_id.eq(event_id),
+                    superseded_audit_events::rollback_event_id.eq(rollback_event_id),
+                ))
+                .execute(conn)?;
+        }
+        superseded.extend(&dependents);
+        frontier = dependents;
+    }
+
+    Ok(superseded)
+}
+}
+
+backend_fn! {
+/// Withdraws an area's approved leave bids entered at or after a time.
+///
+/// Leave bids are recorded just before the event that enters them, so
+/// bids entered by superseded events are those at or after the first
+/// superseded event.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+/// * `since` - When the first superseded event was persisted
+///
+/// # Returns
+///
+/// The number of leave bids withdrawn.
+///
+/// # Errors
+///
+/// Returns an error if the update fails.
+pub fn withdraw_leave_bids_since(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    since: &str,
+) -> Result<usize, PersistenceError> {
+    let withdrawn: usize = diesel::update(
+        leave_bids::table
+            .filter(leave_bids::bid_year_id.eq(bid_year_id))
+            .filter(leave_bids::area_id.eq(area_id))
+            .filter(leave_bids::status.eq(LeaveBidData::STATUS_APPROVED))
+            .filter(leave_bids::created_at.ge(since)),
+    )
+    .set(leave_bids::status.eq(LeaveBidData::STATUS_WITHDRAWN))
+    .execute(conn)?;
+    Ok(withdrawn)
+}
+}
+
+backend_fn! {
+/// Restores an area's canonical users to those of a restored state.
+///
+/// Unlike a full sync, rows are updated in place, so users that other
+/// tables reference keep their rows. Users the restored state does not
+/// hold are deleted, along with their canonical rows and overrides, and
+/// users it holds without a row are inserted with their original IDs.
+/// Restored users without an ID are matched to a current row by initials.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+/// * `state` - The restored state
+///
+/// # Errors
+///
+/// Returns an error if a write fails, including when a deleted user is
+/// still referenced by other records.
+pub fn restore_canonical_users(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    state: &State,
+) -> Result<(), PersistenceError> {
+    let current: Vec<(i64, String)> = users::table
+        .filter(users::bid_year_id.eq(bid_year_id))
+        .filter(users::area_id.eq(area_id))
+        .select((users::user_id, users::initials))
+        .load(conn)?;
+
+    let restored: Vec<(Option<i64>, &User)> = state
+        .users
+        .values()
+        .map(|user| {
+            let user_id: Option<i64> = user.user_id.or_else(|| {
+                current
+                    .iter()
+                    .find(|(_, initials)| initials == user.initials.value())
+                    .map(|(user_id, _)| *user_id)
+            });
+            (user_id, user)
+        })
+        .collect();
+
+    let removed: Vec<i64> = current
+        .iter()
+        .map(|(user_id, _)| *user_id)
+        .filter(|user_id| !restored.iter().any(|(id, _)| *id == Some(*user_id)))
+        .collect();
+    if !removed.is_empty() {
+        diesel::delete(
+            canonical_area_membership::table
+                .filter(canonical_area_membership::user_id.eq_any(&removed)),
+        )
+        .execute(conn)?;
+        diesel::delete(
+            canonical_eligibility::table.filter(canonical_eligibility::user_id.eq_any(&removed)),
+        )
+        .execute(conn)?;
+        diesel::delete(
+            canonical_bid_order::table.filter(canonical_bid_order::user_id.eq_any(&removed)),
+        )
+        .execute(conn)?;
+        diesel::delete(
+            canonical_bid_windows::table.filter(canonical_bid_windows::user_id.eq_any(&removed)),
+        )
+        .execute(conn)?;
+        diesel::delete(
+            canonical_overrides::table.filter(canonical_overrides::user_id.eq_any(&removed)),
+        )
+        .execute(conn)?;
+        diesel::delete(users::table.filter(users::user_id.eq_any(&removed))).execute(conn)?;
+    }
+
+    for (user_id, user) in &restored {
+        let existing: Option<i64> = match user_id {
+            Some(user_id) => users::table
+                .find(user_id)
+                .select(users::user_id)
+                .first::<i64>(conn)
+                .optional()?,
+            None => None,
+        };
+        match (user_id, existing) {
+            (Some(user_id), Some(_)) => {
+                diesel::update(users::table.find(user_id))
+                    .set(user_values!(user, bid_year_id, area_id))
+                    .execute(conn)?;
+            }
+            (Some(user_id), None) => {
+                diesel::insert_into(users::table)
+                    .values((
+                        users::user_id.eq(user_id),
+                        user_values!(user, bid_year_id, area_id),
+                    ))
+                    .execute(conn)?;
+            }
+            (None, _) => {
+                diesel::insert_into(users::table)
+                    .values(user_values!(user, bid_year_id, area_id))
+                    .execute(conn)?;
+            }
+        }
+    }
+
+    info!(
+        bid_year_id,
+        area_id,
+        removed = removed.len(),
+        restored = restored.len(),
+        "Restored canonical users"
+    );
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Regenerates the canonical rows of an area's restored users.
+///
+/// Users the rollback brought back have no canonical rows, so they are
+/// inserted as canonicalization would have: a member of the area, with
+/// their derived eligibility and no bid order or window, all recorded
+/// against the `Rollback` event. Users that kept their rows keep them;
+/// the overrides the rollback superseded are already reverted.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+/// * `rollback_event_id` - The `Rollback` event
+/// * `derived` - Each user's derived eligibility, keyed by user ID
+///
+/// # Returns
+///
+/// The number of users whose canonical rows were regenerated.
+///
+/// # Errors
+///
+/// Returns an error if a query or insert fails.
+pub fn regenerate_canonical_rows(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    rollback_event_id: i64,
+    derived: &BTreeMap<i64, DerivedEligibility>,
+) -> Result<usize, PersistenceError> {
+    let missing: Vec<i64> = users::table
+        .filter(users::bid_year_id.eq(bid_year_id))
+        .filter(users::area_id.eq(area_id))
+        .filter(
+            users::user_id.ne_all(
+                canonical_area_membership::table
+                    .filter(canonical_area_membership::bid_year_id.eq(bid_year_id))
+                    .select(canonical_area_membership::user_id),
+            ),
+        )
+        .select(users::user_id)
+        .load(conn)?;
+
+    for user_id in &missing {
+        let can_bid: i32 = i32::from(derived.get(user_id).is_none_or(|d| d.can_bid));
+        diesel::insert_into(canonical_area_membership::table)
+            .values(NewCanonicalAreaMembership {
+                bid_year_id,
+                audit_event_id: rollback_event_id,
+                user_id: *user_id,
+                area_id,
+                is_overridden: 0,
+                override_reason: None,
+            })
+            .execute(conn)?;
+        diesel::insert_into(canonical_eligibility::table)
+            .values(NewCanonicalEligibility {
+                bid_year_id,
+                audit_event_id: rollback_event_id,
+                user_id: *user_id,
+                can_bid,
+                is_overridden: 0,
+                override_reason: None,
+                derived_can_bid: can_bid,
+            })
+            .execute(conn)?;
+        diesel::insert_into(canonical_bid_order::table)
+            .values(NewCanonicalBidOrder {
+                bid_year_id,
+                audit_event_id: rollback_event_id,
+                user_id: *user_id,
+                bid_order: None,
+                is_overridden: 0,
+                override_reason: None,
+            })
+            .execute(conn)?;
+        diesel::insert_into(canonical_bid_windows::table)
+            .values(NewCanonicalBidWindows {
+                bid_year_id,
+                audit_event_id: rollback_event_id,
+                user_id: *user_id,
+                window_start_date: None,
+                window_end_date: None,
+                is_overridden: 0,
+                override_reason: None,
+            })
+            .execute(conn)?;
+    }
+
+    Ok(missing.len())
+}
+}
+
+/// Restores an area to its state as of a rollback's target (`SQLite` version).
+///
+/// # Arguments
+///
+/// * `conn` - The active database connection
+/// * `state` - The area's state when the rollback was applied
+/// * `target_event_id` - The event rolled back to
+/// * `rollback_event_id` - The persisted `Rollback` event
+/// * `encoding` - How to encode the restored state's snapshot
+///
+/// # Errors
+///
+/// Returns an error if the area has no snapshot at or before the target,
+/// or if any query or write fails.
+pub fn restore_rollback_target_sqlite(
+    conn: &mut SqliteConnection,
+    state: &State,
+    target_event_id: i64,
+    rollback_event_id: i64,
+    encoding: SnapshotEncoding,
+) -> Result<(), PersistenceError> {
+    let bid_year_id: i64 = lookup_bid_year_id_sqlite(conn, state.bid_year.year())?;
+    let area_id: i64 = lookup_area_id_sqlite(conn, bid_year_id, state.area.id())?;
+    let (restored, snapshot_event_id): (State, i64) =
+        get_snapshot_at_or_before_event_sqlite(conn, bid_year_id, area_id, target_event_id)?;
+
+    let superseded: Vec<EventTime> = supersede_area_events_sqlite(
+        conn,
+        bid_year_id,
+        area_id,
+        target_event_id,
+        rollback_event_id,
+    )?;
+    let area_event_ids: Vec<i64> = superseded.iter().map(|(event_id, _)| *event_id).collect();
+    let dependents: Vec<i64> =
+        supersede_dependent_events_sqlite(conn, &area_event_ids, rollback_event_id)?;
+
+    // Newest first, so each revert restores the value the next older one set
+    let overrides: Vec<CanonicalOverrideData> = list_canonical_overrides_sqlite(conn, bid_year_id)?;
+    for record in overrides.iter().rev().filter(|record| {
+        !record.is_reverted
+            && (area_event_ids.contains(&record.audit_event_id)
+                || dependents.contains(&record.audit_event_id))
+    }) {
+        revert_canonical_override_sqlite(
+            conn,
+            record.override_id,
+            bid_year_id,
+            record.user_id,
+            &record.previous_value,
+        )?;
+    }
+
+    let withdrawn: usize = match superseded.first().and_then(|(_, at)| at.as_deref()) {
+        Some(since) => withdraw_leave_bids_since_sqlite(conn, bid_year_id, area_id, since)?,
+        None => 0,
+    };
+
+    restore_canonical_users_sqlite(conn, bid_year_id, area_id, &restored)?;
+    let regenerated: usize = if canonical_rows_exist_sqlite(conn, bid_year_id)? {
+        // Restored users may derive different eligibility than the current ones
+        let derived: BTreeMap<i64, DerivedEligibility> =
+            derive_bid_year_eligibility_sqlite(conn, bid_year_id)?;
+        apply_derived_eligibility_sqlite(conn, bid_year_id, &derived)?;
+        regenerate_canonical_rows_sqlite(conn, bid_year_id, area_id, rollback_event_id, &derived)?
+    } else {
+        0
+    };
+    let current: State =
+        get_current_state_sqlite(conn, bid_year_id, area_id, &state.bid_year, &state.area)?;
+    persist_state_snapshot_sqlite(conn, &current, rollback_event_id, encoding)?;
+
+    info!(
+        rollback_event_id,
+        target_event_id,
+        snapshot_event_id,
+        superseded = superseded.len(),
+        dependents = dependents.len(),
+        withdrawn,
+        regenerated,
+        "Restored rollback target"
+    );
+
+    Ok(())
+}
+
+/// Restores an area to its state as of a rollback's target (`MySQL` version).
+///
+/// # Arguments
+///
+/// * `conn` - The active database connection
+/// * `state` - The area's state when the rollback was applied
+/// * `target_event_id` - The event rolled back to
+/// * `rollback_event_id` - The persisted `Rollback` event
+/// * `encoding` - How to encode the restored state's snapshot
+///
+/// # Errors
+///
+/// Returns an error if the area has no snapshot at or before the target,
+/// or if any query or write fails.
+pub fn restore_rollback_target_mysql(
+    conn: &mut MysqlConnection,
+    state: &State,
+    target_event_id: i64,
+    rollback_event_id: i64,
+    encoding: SnapshotEncoding,
+) -> Result<(), PersistenceError> {
+    let bid_year_id: i64 = lookup_bid_year_id_mysql(conn, state.bid_year.year())?;
+    let area_id: i64 = lookup_area_id_mysql(conn, bid_year_id, state.area.id())?;
+    let (restored, snapshot_event_id): (State, i64) =
+        get_snapshot_at_or_before_event_mysql(conn, bid_year_id, area_id, target_event_id)?;
+
+    let superseded: Vec<EventTime> = supersede_area_events_mysql(
+        conn,
+        bid_year_id,
+        area_id,
+        target_event_id,
+        rollback_event_id,
+    )?;
+    let area_event_ids: Vec<i64> = superseded.iter().map(|(event_id, _)| *event_id).collect();
+    let dependents: Vec<i64> =
+        supersede_dependent_events_mysql(conn, &area_event_ids, rollback_event_id)?;
+
+    // Newest first, so each revert restores the value the next older one set
+    let overrides: Vec<CanonicalOverrideData> = list_canonical_overrides_mysql(conn, bid_year_id)?;
+    for record in overrides.iter().rev().filter(|record| {
+        !record.is_reverted
+            && (area_event_ids.contains(&record.audit_event_id)
+                || dependents.contains(&record.audit_event_id))
+    }) {
+        revert_canonical_override_mysql(
+            conn,
+            record.override_id,
+            bid_year_id,
+            record.user_id,
+            &record.previous_value,
+        )?;
+    }
+
+    let withdrawn: usize = match superseded.first().and_then(|(_, at)| at.as_deref()) {
+        Some(since) => withdraw_leave_bids_since_mysql(conn, bid_year_id, area_id, since)?,
+        None => 0,
+    };
+
+    restore_canonical_users_mysql(conn, bid_year_id, area_id, &restored)?;
+    let regenerated: usize = if canonical_rows_exist_mysql(conn, bid_year_id)? {
+        // Restored users may derive different eligibility than the current ones
+        let derived: BTreeMap<i64, DerivedEligibility> =
+            derive_bid_year_eligibility_mysql(conn, bid_year_id)?;
+        apply_derived_eligibility_mysql(conn, bid_year_id, &derived)?;
+        regenerate_canonical_rows_mysql(conn, bid_year_id, area_id, rollback_event_id, &derived)?
+    } else {
+        0
+    };
+    let current: State =
+        get_current_state_mysql(conn, bid_year_id, area_id, &state.bid_year, &state.area)?;
+    persist_state_snapshot_mysql(conn, &current, rollback_event_id, encoding)?;
+
+    info!(
+        rollback_event_id,
+        target_event_id,
+        snapshot_event_id,
+        superseded = superseded.len(),
+        dependents = dependents.len(),
+        withdrawn,
+        regenerated,
+        "Restored rollback target"
+    );
+
+    Ok(())
+}
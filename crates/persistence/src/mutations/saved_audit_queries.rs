@@ -0,0 +1,143 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Saved audit query mutations.
+//!
+//! This module saves and deletes named audit timeline filters, sets their
+//! weekly delivery recipients, and records each delivery.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::diesel_schema::saved_audit_queries;
+use crate::error::PersistenceError;
+use crate::queries::saved_audit_queries::encode_recipients;
+
+backend_fn! {
+/// Saves a named audit query.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `name` - The unique query name
+/// * `scope_json` - The timeline scope, as JSON
+/// * `filter_json` - The timeline filters, as JSON
+/// * `weekly_recipients` - Addresses to email the results to each week
+/// * `created_by_operator_id` - The operator saving the query
+///
+/// # Errors
+///
+/// Returns an error if the query cannot be saved, including when the name
+/// is already taken.
+pub fn create_saved_audit_query(
+    conn: &mut _,
+    name: &str,
+    scope_json: &str,
+    filter_json: &str,
+    weekly_recipients: &[String],
+    created_by_operator_id: i64,
+) -> Result<i64, PersistenceError> {
+    diesel::insert_into(saved_audit_queries::table)
+        .values((
+            saved_audit_queries::name.eq(name),
+            saved_audit_queries::scope_json.eq(scope_json),
+            saved_audit_queries::filter_json.eq(filter_json),
+            saved_audit_queries::weekly_recipients.eq(encode_recipients(weekly_recipients)),
+            saved_audit_queries::created_by_operator_id.eq(created_by_operator_id),
+        ))
+        .execute(conn)?;
+
+    let saved_query_id: i64 = conn.get_last_insert_rowid()?;
+
+    info!(saved_query_id, name, "Audit query saved");
+
+    Ok(saved_query_id)
+}
+}
+
+backend_fn! {
+/// Sets the addresses a saved audit query is emailed to each week.
+///
+/// An empty list stops the weekly delivery.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `saved_query_id` - The saved query ID
+/// * `weekly_recipients` - The new recipients
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn set_saved_audit_query_recipients(
+    conn: &mut _,
+    saved_query_id: i64,
+    weekly_recipients: &[String],
+) -> Result<(), PersistenceError> {
+    diesel::update(saved_audit_queries::table)
+        .filter(saved_audit_queries::saved_query_id.eq(saved_query_id))
+        .set(saved_audit_queries::weekly_recipients.eq(encode_recipients(weekly_recipients)))
+        .execute(conn)?;
+
+    info!(
+        saved_query_id,
+        recipients = weekly_recipients.len(),
+        "Audit query schedule updated"
+    );
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Records that a saved audit query's results were emailed.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `saved_query_id` - The saved query ID
+/// * `delivered_at` - When the results were sent (RFC 3339)
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn mark_saved_audit_query_delivered(
+    conn: &mut _,
+    saved_query_id: i64,
+    delivered_at: &str,
+) -> Result<(), PersistenceError> {
+    diesel::update(saved_audit_queries::table)
+        .filter(saved_audit_queries::saved_query_id.eq(saved_query_id))
+        .set(saved_audit_queries::last_delivered_at.eq(Some(delivered_at)))
+        .execute(conn)?;
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Deletes a saved audit query.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `saved_query_id` - The saved query ID
+///
+/// # Errors
+///
+/// Returns an error if the database delete fails.
+pub fn delete_saved_audit_query(conn: &mut _, saved_query_id: i64) -> Result<(), PersistenceError> {
+    diesel::delete(
+        saved_audit_queries::table.filter(saved_audit_queries::saved_query_id.eq(saved_query_id)),
+    )
+    .execute(conn)?;
+
+    info!(saved_query_id, "Audit query deleted");
+
+    Ok(())
+}
+}
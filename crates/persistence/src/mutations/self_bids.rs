@@ -0,0 +1,159 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Self-service bid mutations.
+//!
+//! This module links operators to the controller they are in the roster
+//! and records the leave such operators ask to enter for themselves,
+//! linking each request to the audit events that recorded it and its
+//! confirmation by a second operator.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::diesel_schema::{operator_controllers, self_bid_requests};
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Links an operator to a controller's initials, or clears the link.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `operator_id` - The operator ID
+/// * `initials` - The controller's initials; `None` clears the link
+///
+/// # Errors
+///
+/// Returns an error if the database write fails.
+pub fn set_operator_controller(
+    conn: &mut _,
+    operator_id: i64,
+    initials: Option<&str>,
+) -> Result<(), PersistenceError> {
+    diesel::delete(operator_controllers::table)
+        .filter(operator_controllers::operator_id.eq(operator_id))
+        .execute(conn)?;
+
+    if let Some(initials) = initials {
+        diesel::insert_into(operator_controllers::table)
+            .values((
+                operator_controllers::operator_id.eq(operator_id),
+                operator_controllers::initials.eq(initials),
+            ))
+            .execute(conn)?;
+    }
+
+    info!(operator_id, initials, "Operator controller link set");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Records a pending self-service bid request.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `request_event_id` - The audit event that recorded the request
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+/// * `user_id` - The canonical user ID
+/// * `round_id` - The round the leave is requested in
+/// * `requested_by_operator_id` - The operator who is also the controller
+/// * `leave_dates` - The leave dates (`YYYY-MM-DD`), in date order
+/// * `hours` - The leave hours charged per day
+/// * `on_behalf_of` - The initials of the controller
+/// * `received_via` - How the bid was received (`phone`, `in_person`, `written`)
+///
+/// # Errors
+///
+/// Returns an error if the request cannot be recorded.
+#[allow(clippy::too_many_arguments)]
+pub fn insert_self_bid_request(
+    conn: &mut _,
+    request_event_id: i64,
+    bid_year_id: i64,
+    area_id: i64,
+    user_id: i64,
+    round_id: i64,
+    requested_by_operator_id: i64,
+    leave_dates: &[String],
+    hours: i32,
+    on_behalf_of: &str,
+    received_via: &str,
+) -> Result<i64, PersistenceError> {
+    diesel::insert_into(self_bid_requests::table)
+        .values((
+            self_bid_requests::bid_year_id.eq(bid_year_id),
+            self_bid_requests::area_id.eq(area_id),
+            self_bid_requests::user_id.eq(user_id),
+            self_bid_requests::round_id.eq(round_id),
+            self_bid_requests::requested_by_operator_id.eq(requested_by_operator_id),
+            self_bid_requests::leave_dates.eq(leave_dates.join("|")),
+            self_bid_requests::hours.eq(hours),
+            self_bid_requests::on_behalf_of.eq(on_behalf_of),
+            self_bid_requests::received_via.eq(received_via),
+            self_bid_requests::request_event_id.eq(Some(request_event_id)),
+        ))
+        .execute(conn)?;
+
+    let self_bid_request_id: i64 = conn.get_last_insert_rowid()?;
+
+    info!(
+        self_bid_request_id,
+        user_id,
+        round_id,
+        requested_by_operator_id,
+        request_event_id,
+        "Self-service bid request recorded"
+    );
+
+    Ok(self_bid_request_id)
+}
+}
+
+backend_fn! {
+/// Records the confirmation of a self-service bid request.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `self_bid_request_id` - The self-service bid request ID
+/// * `confirmed_by_operator_id` - The operator who confirmed it
+/// * `confirm_event_id` - The audit event that recorded the confirmation
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn confirm_self_bid_request(
+    conn: &mut _,
+    self_bid_request_id: i64,
+    confirmed_by_operator_id: i64,
+    confirm_event_id: i64,
+) -> Result<(), PersistenceError> {
+    diesel::update(self_bid_requests::table)
+        .filter(self_bid_requests::self_bid_request_id.eq(self_bid_request_id))
+        .set((
+            self_bid_requests::status.eq("confirmed"),
+            self_bid_requests::confirmed_by_operator_id.eq(Some(confirmed_by_operator_id)),
+            self_bid_requests::confirm_event_id.eq(Some(confirm_event_id)),
+            self_bid_requests::confirmed_at.eq(diesel::dsl::sql::<
+                diesel::sql_types::Nullable<diesel::sql_types::Text>,
+            >("CURRENT_TIMESTAMP")),
+        ))
+        .execute(conn)?;
+
+    info!(
+        self_bid_request_id,
+        confirmed_by_operator_id, confirm_event_id, "Self-service bid request confirmed"
+    );
+
+    Ok(())
+}
+}
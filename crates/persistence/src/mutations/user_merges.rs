@@ -0,0 +1,465 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! User merge mutations.
+//!
+//! Merging a duplicate user record moves its leave bids, bid preferences,
+//! overbid requests, waitlist offers, overrides, and leave carryover to the
+//! surviving record, and excludes the duplicate from bidding and leave
+//! calculation. An override in force moves together with the canonical
+//! value it set: the two records' canonical rows of that kind are swapped.
+//! Every reassigned row is recorded against the merge so a revert moves
+//! exactly those rows back.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::UserMergeData;
+use crate::diesel_schema::{
+    bid_preferences, canonical_area_membership, canonical_bid_order, canonical_bid_windows,
+    canonical_eligibility, canonical_overrides, leave_bids, leave_carryovers, overbid_requests,
+    user_merge_rows, user_merges, users, waitlist_offers,
+};
+use crate::error::PersistenceError;
+
+/// Moves every row of `$table` owned by `$from` to `$to`, recording each
+/// moved row against the merge.
+macro_rules! reassign_rows {
+    ($conn:ident, $table:ident, $id:ident, $merge_id:expr, $from:expr, $to:expr) => {{
+        let row_ids: Vec<i64> = $table::table
+            .filter($table::user_id.eq($from))
+            .select($table::$id)
+            .load($conn)?;
+        if !row_ids.is_empty() {
+            diesel::update($table::table.filter($table::$id.eq_any(&row_ids)))
+                .set($table::user_id.eq($to))
+                .execute($conn)?;
+            for row_id in &row_ids {
+                diesel::insert_into(user_merge_rows::table)
+                    .values((
+                        user_merge_rows::user_merge_id.eq($merge_id),
+                        user_merge_rows::source_table.eq(stringify!($table)),
+                        user_merge_rows::row_id.eq(row_id),
+                    ))
+                    .execute($conn)?;
+            }
+        }
+        row_ids.len()
+    }};
+}
+
+/// Moves the rows of `$table` a merge reassigned back to `$to`.
+macro_rules! restore_rows {
+    ($conn:ident, $table:ident, $id:ident, $merge_id:expr, $to:expr) => {{
+        let row_ids: Vec<i64> = user_merge_rows::table
+            .filter(user_merge_rows::user_merge_id.eq($merge_id))
+            .filter(user_merge_rows::source_table.eq(stringify!($table)))
+            .select(user_merge_rows::row_id)
+            .load($conn)?;
+        if !row_ids.is_empty() {
+            diesel::update($table::table.filter($table::$id.eq_any(&row_ids)))
+                .set($table::user_id.eq($to))
+                .execute($conn)?;
+        }
+    }};
+}
+
+/// Swaps the canonical values of two users' rows in `$table`.
+///
+/// Swapping is its own inverse, so a revert swaps the same rows again.
+macro_rules! swap_canonical_rows {
+    ($conn:ident, $table:ident, $bid_year_id:expr, $first:expr, $second:expr, ($($col:ident),+), $ty:ty) => {{
+        let values = |conn: &mut _, user_id: i64| {
+            $table::table
+                .filter($table::bid_year_id.eq($bid_year_id))
+                .filter($table::user_id.eq(user_id))
+                .select(($($table::$col,)+))
+                .first::<$ty>(conn)
+                .optional()
+        };
+        let first: Option<$ty> = values($conn, $first)?;
+        let second: Option<$ty> = values($conn, $second)?;
+        if let (Some(first), Some(second)) = (first, second) {
+            let row = |user_id: i64| {
+                $table::table
+                    .filter($table::bid_year_id.eq($bid_year_id))
+                    .filter($table::user_id.eq(user_id))
+            };
+            let ($($col,)+) = second;
+            diesel::update(row($first))
+                .set(($($table::$col.eq($col),)+))
+                .execute($conn)?;
+            let ($($col,)+) = first;
+            diesel::update(row($second))
+                .set(($($table::$col.eq($col),)+))
+                .execute($conn)?;
+        }
+    }};
+}
+
+/// Hands an override in force on the merged user's `$table` row to the
+/// surviving user, recording the merged user's row against the merge.
+macro_rules! transfer_override_state {
+    ($conn:ident, $table:ident, $merge_id:expr, $bid_year_id:expr, $keep:expr, $merge:expr, ($($col:ident),+), $ty:ty) => {{
+        let overridden: Option<i64> = $table::table
+            .filter($table::bid_year_id.eq($bid_year_id))
+            .filter($table::user_id.eq($merge))
+            .filter($table::is_overridden.eq(1))
+            .select($table::id)
+            .first($conn)
+            .optional()?;
+        if let Some(row_id) = overridden {
+            swap_canonical_rows!($conn, $table, $bid_year_id, $keep, $merge, ($($col),+), $ty);
+            diesel::insert_into(user_merge_rows::table)
+                .values((
+                    user_merge_rows::user_merge_id.eq($merge_id),
+                    user_merge_rows::source_table.eq(stringify!($table)),
+                    user_merge_rows::row_id.eq(row_id),
+                ))
+                .execute($conn)?;
+        }
+        usize::from(overridden.is_some())
+    }};
+}
+
+/// Hands an override a merge transferred in `$table` back to the merged
+/// user.
+macro_rules! restore_override_state {
+    ($conn:ident, $table:ident, $merge_id:expr, $bid_year_id:expr, $keep:expr, $merge:expr, ($($col:ident),+), $ty:ty) => {{
+        let transferred: bool = diesel::select(diesel::dsl::exists(
+            user_merge_rows::table
+                .filter(user_merge_rows::user_merge_id.eq($merge_id))
+                .filter(user_merge_rows::source_table.eq(stringify!($table))),
+        ))
+        .get_result($conn)?;
+        if transferred {
+            swap_canonical_rows!($conn, $table, $bid_year_id, $keep, $merge, ($($col),+), $ty);
+        }
+    }};
+}
+
+/// The canonical values of an area membership row.
+type AreaMembershipValues = (i64, i32, Option<String>);
+/// The canonical values of a bid order row.
+type BidOrderValues = (Option<i32>, i32, Option<String>);
+/// The canonical values of a bid window row.
+type BidWindowValues = (Option<String>, Option<String>, i32, Option<String>);
+/// The canonical values of an eligibility row.
+type EligibilityValues = (i32, i32, Option<String>);
+
+backend_fn! {
+/// Merges a duplicate user record into the record that survives.
+///
+/// Callers run this in the same transaction as the audit event that
+/// records the merge, after checking the records' bids do not collide.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `keep` - The surviving user
+/// * `merge` - The duplicate user
+/// * `reason` - Why the records are merged
+/// * `merge_event_id` - The audit event that recorded the merge
+///
+/// # Returns
+///
+/// The ID of the merge in the user merge ledger.
+///
+/// # Errors
+///
+/// Returns an error if either user does not exist or a write fails.
+#[allow(clippy::too_many_lines)]
+pub fn merge_user_records(
+    conn: &mut _,
+    bid_year_id: i64,
+    keep: i64,
+    merge: i64,
+    reason: &str,
+    merge_event_id: i64,
+) -> Result<i64, PersistenceError> {
+    let (excluded_from_bidding, excluded_from_leave_calculation): (i32, i32) = users::table
+        .filter(users::user_id.eq(merge))
+        .select((users::excluded_from_bidding, users::excluded_from_leave_calculation))
+        .first(conn)
+        .optional()?
+        .ok_or_else(|| PersistenceError::NotFound(format!("User {merge} not found")))?;
+
+    let carryover = |user_id: i64| {
+        leave_carryovers::table
+            .filter(leave_carryovers::bid_year_id.eq(bid_year_id))
+            .filter(leave_carryovers::user_id.eq(user_id))
+    };
+    let merged_carryover: Option<i32> = carryover(merge)
+        .select(leave_carryovers::carryover_hours)
+        .first(conn)
+        .optional()?;
+    let kept_carryover: Option<i32> = carryover(keep)
+        .select(leave_carryovers::carryover_hours)
+        .first(conn)
+        .optional()?;
+
+    diesel::insert_into(user_merges::table)
+        .values((
+            user_merges::bid_year_id.eq(bid_year_id),
+            user_merges::kept_user_id.eq(keep),
+            user_merges::merged_user_id.eq(merge),
+            user_merges::reason.eq(reason),
+            user_merges::previous_excluded_from_bidding.eq(excluded_from_bidding),
+            user_merges::previous_excluded_from_leave_calculation
+                .eq(excluded_from_leave_calculation),
+            user_merges::merged_carryover_hours.eq(merged_carryover),
+            user_merges::kept_carryover_hours.eq(kept_carryover),
+            user_merges::merge_event_id.eq(merge_event_id),
+        ))
+        .execute(conn)?;
+    let user_merge_id: i64 = conn.get_last_insert_rowid()?;
+
+    let mut rows_moved: usize = 0;
+    rows_moved += reassign_rows!(conn, leave_bids, leave_bid_id, user_merge_id, merge, keep);
+    rows_moved += reassign_rows!(
+        conn,
+        bid_preferences,
+        bid_preference_id,
+        user_merge_id,
+        merge,
+        keep
+    );
+    rows_moved += reassign_rows!(
+        conn,
+        overbid_requests,
+        overbid_request_id,
+        user_merge_id,
+        merge,
+        keep
+    );
+    rows_moved += reassign_rows!(
+        conn,
+        waitlist_offers,
+        waitlist_offer_id,
+        user_merge_id,
+        merge,
+        keep
+    );
+    rows_moved += reassign_rows!(
+        conn,
+        canonical_overrides,
+        override_id,
+        user_merge_id,
+        merge,
+        keep
+    );
+
+    // Overrides in force on the duplicate now apply to the surviving record
+    rows_moved += transfer_override_state!(
+        conn,
+        canonical_area_membership,
+        user_merge_id,
+        bid_year_id,
+        keep,
+        merge,
+        (area_id, is_overridden, override_reason),
+        AreaMembershipValues
+    );
+    rows_moved += transfer_override_state!(
+        conn,
+        canonical_bid_order,
+        user_merge_id,
+        bid_year_id,
+        keep,
+        merge,
+        (bid_order, is_overridden, override_reason),
+        BidOrderValues
+    );
+    rows_moved += transfer_override_state!(
+        conn,
+        canonical_bid_windows,
+        user_merge_id,
+        bid_year_id,
+        keep,
+        merge,
+        (
+            window_start_date,
+            window_end_date,
+            is_overridden,
+            override_reason
+        ),
+        BidWindowValues
+    );
+    rows_moved += transfer_override_state!(
+        conn,
+        canonical_eligibility,
+        user_merge_id,
+        bid_year_id,
+        keep,
+        merge,
+        (can_bid, is_overridden, override_reason),
+        EligibilityValues
+    );
+
+    // The surviving record takes on the duplicate's carried-in leave
+    if let Some(hours) = merged_carryover {
+        diesel::delete(carryover(merge)).execute(conn)?;
+        if let Some(kept_hours) = kept_carryover {
+            diesel::update(carryover(keep))
+                .set(leave_carryovers::carryover_hours.eq(kept_hours + hours))
+                .execute(conn)?;
+        } else {
+            diesel::insert_into(leave_carryovers::table)
+                .values((
+                    leave_carryovers::bid_year_id.eq(bid_year_id),
+                    leave_carryovers::user_id.eq(keep),
+                    leave_carryovers::carryover_hours.eq(hours),
+                ))
+                .execute(conn)?;
+        }
+    }
+
+    diesel::update(users::table.filter(users::user_id.eq(merge)))
+        .set((
+            users::excluded_from_bidding.eq(1),
+            users::excluded_from_leave_calculation.eq(1),
+        ))
+        .execute(conn)?;
+
+    info!(user_merge_id, keep, merge, rows_moved, "Users merged");
+
+    Ok(user_merge_id)
+}
+}
+
+backend_fn! {
+/// Reverts a merge, moving the reassigned rows and leave carryover back
+/// to the merged record and restoring its participation flags.
+///
+/// Callers run this in the same transaction as the audit event that
+/// records the revert, and only revert a merge still in force.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `record` - The merge to revert
+/// * `revert_event_id` - The audit event that recorded the revert
+///
+/// # Errors
+///
+/// Returns an error if a write fails.
+pub fn revert_user_merge_records(
+    conn: &mut _,
+    record: &UserMergeData,
+    revert_event_id: i64,
+) -> Result<(), PersistenceError> {
+    let merge_id: i64 = record.user_merge_id;
+    let merged: i64 = record.merged_user_id;
+    restore_rows!(conn, leave_bids, leave_bid_id, merge_id, merged);
+    restore_rows!(conn, bid_preferences, bid_preference_id, merge_id, merged);
+    restore_rows!(conn, overbid_requests, overbid_request_id, merge_id, merged);
+    restore_rows!(conn, waitlist_offers, waitlist_offer_id, merge_id, merged);
+    restore_rows!(conn, canonical_overrides, override_id, merge_id, merged);
+
+    let bid_year_id: i64 = record.bid_year_id;
+    let keep: i64 = record.kept_user_id;
+    restore_override_state!(
+        conn,
+        canonical_area_membership,
+        merge_id,
+        bid_year_id,
+        keep,
+        merged,
+        (area_id, is_overridden, override_reason),
+        AreaMembershipValues
+    );
+    restore_override_state!(
+        conn,
+        canonical_bid_order,
+        merge_id,
+        bid_year_id,
+        keep,
+        merged,
+        (bid_order, is_overridden, override_reason),
+        BidOrderValues
+    );
+    restore_override_state!(
+        conn,
+        canonical_bid_windows,
+        merge_id,
+        bid_year_id,
+        keep,
+        merged,
+        (
+            window_start_date,
+            window_end_date,
+            is_overridden,
+            override_reason
+        ),
+        BidWindowValues
+    );
+    restore_override_state!(
+        conn,
+        canonical_eligibility,
+        merge_id,
+        bid_year_id,
+        keep,
+        merged,
+        (can_bid, is_overridden, override_reason),
+        EligibilityValues
+    );
+    // Derived eligibility was recomputed while the merge was in force, so a
+    // swapped-back value without an override is stale
+    diesel::update(
+        canonical_eligibility::table
+            .filter(canonical_eligibility::bid_year_id.eq(bid_year_id))
+            .filter(canonical_eligibility::user_id.eq_any([keep, merged]))
+            .filter(canonical_eligibility::is_overridden.eq(0)),
+    )
+    .set(canonical_eligibility::can_bid.eq(canonical_eligibility::derived_can_bid))
+    .execute(conn)?;
+
+    if let Some(hours) = record.merged_carryover_hours {
+        let carryover = |user_id: i64| {
+            leave_carryovers::table
+                .filter(leave_carryovers::bid_year_id.eq(record.bid_year_id))
+                .filter(leave_carryovers::user_id.eq(user_id))
+        };
+        if let Some(kept_hours) = record.kept_carryover_hours {
+            diesel::update(carryover(record.kept_user_id))
+                .set(leave_carryovers::carryover_hours.eq(kept_hours))
+                .execute(conn)?;
+        } else {
+            diesel::delete(carryover(record.kept_user_id)).execute(conn)?;
+        }
+        diesel::insert_into(leave_carryovers::table)
+            .values((
+                leave_carryovers::bid_year_id.eq(record.bid_year_id),
+                leave_carryovers::user_id.eq(merged),
+                leave_carryovers::carryover_hours.eq(hours),
+            ))
+            .execute(conn)?;
+    }
+
+    diesel::update(users::table.filter(users::user_id.eq(merged)))
+        .set((
+            users::excluded_from_bidding.eq(i32::from(record.previous_excluded_from_bidding)),
+            users::excluded_from_leave_calculation
+                .eq(i32::from(record.previous_excluded_from_leave_calculation)),
+        ))
+        .execute(conn)?;
+
+    diesel::update(user_merges::table.filter(user_merges::user_merge_id.eq(merge_id)))
+        .set(user_merges::revert_event_id.eq(revert_event_id))
+        .execute(conn)?;
+
+    info!(
+        user_merge_id = merge_id,
+        keep = record.kept_user_id,
+        merge = merged,
+        "User merge reverted"
+    );
+
+    Ok(())
+}
+}
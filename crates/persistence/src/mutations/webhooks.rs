@@ -0,0 +1,161 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Webhook mutations.
+//!
+//! This module contains backend-agnostic mutations for managing webhooks
+//! and recording failed deliveries.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::{info, warn};
+
+use crate::backend::PersistenceBackend;
+use crate::diesel_schema::{webhook_dead_letters, webhooks};
+use crate::error::PersistenceError;
+use crate::queries::webhooks::encode_event_filter;
+
+backend_fn! {
+/// Creates a new webhook.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `url` - The delivery URL
+/// * `secret` - The shared secret used to sign payloads
+/// * `event_filter` - The audit action names to deliver
+///
+/// # Errors
+///
+/// Returns an error if the webhook cannot be created.
+pub fn create_webhook(
+    conn: &mut _,
+    url: &str,
+    secret: &str,
+    event_filter: &[String],
+) -> Result<i64, PersistenceError> {
+    diesel::insert_into(webhooks::table)
+        .values((
+            webhooks::url.eq(url),
+            webhooks::secret.eq(secret),
+            webhooks::event_filter.eq(encode_event_filter(event_filter)),
+        ))
+        .execute(conn)?;
+
+    let webhook_id: i64 = conn.get_last_insert_rowid()?;
+
+    info!(webhook_id, url, "Webhook created");
+
+    Ok(webhook_id)
+}
+}
+
+backend_fn! {
+/// Updates a webhook's URL, event filter, and enabled flag.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `webhook_id` - The webhook ID
+/// * `url` - The new delivery URL
+/// * `event_filter` - The new event filter
+/// * `is_enabled` - Whether deliveries are active
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn update_webhook(
+    conn: &mut _,
+    webhook_id: i64,
+    url: &str,
+    event_filter: &[String],
+    is_enabled: bool,
+) -> Result<(), PersistenceError> {
+    diesel::update(webhooks::table)
+        .filter(webhooks::webhook_id.eq(webhook_id))
+        .set((
+            webhooks::url.eq(url),
+            webhooks::event_filter.eq(encode_event_filter(event_filter)),
+            webhooks::is_enabled.eq(i32::from(is_enabled)),
+        ))
+        .execute(conn)?;
+
+    info!(webhook_id, "Webhook updated");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Deletes a webhook and its dead-letter log.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `webhook_id` - The webhook ID
+///
+/// # Errors
+///
+/// Returns an error if the database delete fails.
+pub fn delete_webhook(conn: &mut _, webhook_id: i64) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(
+            webhook_dead_letters::table.filter(webhook_dead_letters::webhook_id.eq(webhook_id)),
+        )
+        .execute(conn)?;
+        diesel::delete(webhooks::table.filter(webhooks::webhook_id.eq(webhook_id)))
+            .execute(conn)?;
+        Ok(())
+    })?;
+
+    info!(webhook_id, "Webhook deleted");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Records a delivery that exhausted its retries.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `webhook_id` - The webhook the delivery was for
+/// * `audit_event_id` - The audit event that was being delivered
+/// * `payload` - The JSON body that was sent
+/// * `attempts` - The number of attempts made
+/// * `last_error` - The error from the final attempt
+///
+/// # Errors
+///
+/// Returns an error if the entry cannot be recorded.
+pub fn record_webhook_dead_letter(
+    conn: &mut _,
+    webhook_id: i64,
+    audit_event_id: i64,
+    payload: &str,
+    attempts: i32,
+    last_error: &str,
+) -> Result<i64, PersistenceError> {
+    diesel::insert_into(webhook_dead_letters::table)
+        .values((
+            webhook_dead_letters::webhook_id.eq(webhook_id),
+            webhook_dead_letters::audit_event_id.eq(audit_event_id),
+            webhook_dead_letters::payload.eq(payload),
+            webhook_dead_letters::attempts.eq(attempts),
+            webhook_dead_letters::last_error.eq(last_error),
+        ))
+        .execute(conn)?;
+
+    let dead_letter_id: i64 = conn.get_last_insert_rowid()?;
+
+    warn!(
+        webhook_id,
+        audit_event_id, attempts, "Webhook delivery dead-lettered"
+    );
+
+    Ok(dead_letter_id)
+}
+}
@@ -0,0 +1,95 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Bid amendment policy queries.
+//!
+//! This module contains queries for the per-bid-year policy on whether,
+//! and for how long, controllers may amend submitted bids.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::data_models::BidAmendmentPolicyData;
+use crate::diesel_schema::bid_amendment_policies;
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Gets a bid year's amendment policy, if one has been set.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_bid_amendment_policy(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Option<BidAmendmentPolicyData>, PersistenceError> {
+    let row: Option<(String, Option<i32>)> = bid_amendment_policies::table
+        .filter(bid_amendment_policies::bid_year_id.eq(bid_year_id))
+        .select((
+            bid_amendment_policies::policy,
+            bid_amendment_policies::window_hours,
+        ))
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(|(policy, window_hours)| BidAmendmentPolicyData {
+        bid_year_id,
+        policy,
+        window_hours,
+    }))
+}
+}
+
+backend_fn! {
+/// Sets a bid year's amendment policy, replacing any previous policy.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `policy` - The policy kind
+/// * `window_hours` - The amendment window in hours (`within_hours` only)
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn set_bid_amendment_policy(
+    conn: &mut _,
+    bid_year_id: i64,
+    policy: &str,
+    window_hours: Option<i32>,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(
+            bid_amendment_policies::table
+                .filter(bid_amendment_policies::bid_year_id.eq(bid_year_id)),
+        )
+        .execute(conn)?;
+
+        diesel::insert_into(bid_amendment_policies::table)
+            .values((
+                bid_amendment_policies::bid_year_id.eq(bid_year_id),
+                bid_amendment_policies::policy.eq(policy),
+                bid_amendment_policies::window_hours.eq(window_hours),
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    })?;
+
+    info!(bid_year_id, policy, window_hours, "Bid amendment policy set");
+
+    Ok(())
+}
+}
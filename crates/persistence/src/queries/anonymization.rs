@@ -0,0 +1,59 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! User anonymization queries.
+//!
+//! This module contains backend-agnostic queries for the record of users
+//! whose personal data was erased.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+
+use crate::data_models::UserAnonymizationData;
+use crate::diesel_schema::user_anonymizations;
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for user anonymization rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = user_anonymizations)]
+struct UserAnonymizationRow {
+    user_id: i64,
+    bid_year_id: i64,
+    event_id: i64,
+    snapshots_rewritten: i32,
+    created_at: String,
+}
+
+impl From<UserAnonymizationRow> for UserAnonymizationData {
+    fn from(row: UserAnonymizationRow) -> Self {
+        Self {
+            user_id: row.user_id,
+            bid_year_id: row.bid_year_id,
+            event_id: row.event_id,
+            snapshots_rewritten: usize::try_from(row.snapshots_rewritten).unwrap_or(0),
+            created_at: row.created_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Gets the erasure record of a user, if their data was erased.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_user_anonymization(
+    conn: &mut _,
+    user_id: i64,
+) -> Result<Option<UserAnonymizationData>, PersistenceError> {
+    let row: Option<UserAnonymizationRow> = user_anonymizations::table
+        .filter(user_anonymizations::user_id.eq(user_id))
+        .select(UserAnonymizationRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(UserAnonymizationData::from))
+}
+}
@@ -0,0 +1,207 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Area rep delegation queries.
+//!
+//! This module records, revokes, and reads delegations of an area rep's
+//! bidding authority to another operator. Dates are stored as
+//! `YYYY-MM-DD`, so date ranges compare as strings. Revoking a delegation
+//! deletes its row, leaving the audit log as the history of past
+//! delegations.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::AreaDelegationData;
+use crate::diesel_schema::area_delegations;
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for area delegation rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = area_delegations)]
+struct AreaDelegationRow {
+    area_delegation_id: i64,
+    area_id: i64,
+    delegator_operator_id: i64,
+    delegate_operator_id: i64,
+    start_date: String,
+    end_date: String,
+    created_at: String,
+}
+
+impl From<AreaDelegationRow> for AreaDelegationData {
+    fn from(row: AreaDelegationRow) -> Self {
+        Self {
+            area_delegation_id: row.area_delegation_id,
+            area_id: row.area_id,
+            delegator_operator_id: row.delegator_operator_id,
+            delegate_operator_id: row.delegate_operator_id,
+            start_date: row.start_date,
+            end_date: row.end_date,
+            created_at: row.created_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Records the delegation of an area rep's bidding authority.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+/// * `delegator_operator_id` - The area rep handing over authority
+/// * `delegate_operator_id` - The operator receiving it
+/// * `start_date` - The first day the delegation applies (`YYYY-MM-DD`)
+/// * `end_date` - The last day the delegation applies (`YYYY-MM-DD`)
+///
+/// # Errors
+///
+/// Returns an error if the insert fails.
+pub fn insert_area_delegation(
+    conn: &mut _,
+    area_id: i64,
+    delegator_operator_id: i64,
+    delegate_operator_id: i64,
+    start_date: &str,
+    end_date: &str,
+) -> Result<i64, PersistenceError> {
+    let area_delegation_id: i64 = conn.transaction::<i64, PersistenceError, _>(|conn| {
+        diesel::insert_into(area_delegations::table)
+            .values((
+                area_delegations::area_id.eq(area_id),
+                area_delegations::delegator_operator_id.eq(delegator_operator_id),
+                area_delegations::delegate_operator_id.eq(delegate_operator_id),
+                area_delegations::start_date.eq(start_date),
+                area_delegations::end_date.eq(end_date),
+            ))
+            .execute(conn)?;
+        conn.get_last_insert_rowid()
+    })?;
+
+    info!(
+        area_delegation_id,
+        area_id, delegate_operator_id, start_date, end_date, "Area delegation created"
+    );
+
+    Ok(area_delegation_id)
+}
+}
+
+backend_fn! {
+/// Gets a single area delegation.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_delegation_id` - The area delegation ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_area_delegation(
+    conn: &mut _,
+    area_delegation_id: i64,
+) -> Result<Option<AreaDelegationData>, PersistenceError> {
+    let row: Option<AreaDelegationRow> = area_delegations::table
+        .filter(area_delegations::area_delegation_id.eq(area_delegation_id))
+        .select(AreaDelegationRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(AreaDelegationData::from))
+}
+}
+
+backend_fn! {
+/// Deletes an area delegation.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_delegation_id` - The area delegation ID
+///
+/// # Returns
+///
+/// Whether the delegation existed.
+///
+/// # Errors
+///
+/// Returns an error if the delete fails.
+pub fn delete_area_delegation(
+    conn: &mut _,
+    area_delegation_id: i64,
+) -> Result<bool, PersistenceError> {
+    let deleted: usize = diesel::delete(
+        area_delegations::table
+            .filter(area_delegations::area_delegation_id.eq(area_delegation_id)),
+    )
+    .execute(conn)?;
+
+    if deleted > 0 {
+        info!(area_delegation_id, "Area delegation revoked");
+    }
+
+    Ok(deleted > 0)
+}
+}
+
+backend_fn! {
+/// Lists every area delegation, ordered by start date.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_area_delegations(conn: &mut _) -> Result<Vec<AreaDelegationData>, PersistenceError> {
+    let rows: Vec<AreaDelegationRow> = area_delegations::table
+        .select(AreaDelegationRow::as_select())
+        .order_by((
+            area_delegations::start_date.asc(),
+            area_delegations::area_delegation_id.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(AreaDelegationData::from).collect())
+}
+}
+
+backend_fn! {
+/// Lists the areas delegated to an operator on a date.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `delegate_operator_id` - The operator receiving authority
+/// * `on_date` - The date to check (`YYYY-MM-DD`)
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_delegated_area_ids(
+    conn: &mut _,
+    delegate_operator_id: i64,
+    on_date: &str,
+) -> Result<Vec<i64>, PersistenceError> {
+    let area_ids: Vec<i64> = area_delegations::table
+        .filter(area_delegations::delegate_operator_id.eq(delegate_operator_id))
+        .filter(area_delegations::start_date.le(on_date))
+        .filter(area_delegations::end_date.ge(on_date))
+        .select(area_delegations::area_id)
+        .distinct()
+        .order_by(area_delegations::area_id.asc())
+        .load(conn)?;
+
+    Ok(area_ids)
+}
+}
@@ -12,11 +12,17 @@
 use diesel::prelude::*;
 use diesel::{MysqlConnection, SqliteConnection};
 use num_traits::ToPrimitive;
-use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot, Ulid};
 use zab_bid_domain::{Area, BidYear};
 
-use crate::data_models::{ActionData, ActorData, CauseData, StateSnapshotData};
-use crate::diesel_schema::audit_events;
+use crate::data_models::{
+    ActionData, ActorData, AuditEventDependency, AuditEventHeader, AuditEventHeaderPage,
+    AuditTimelineEntry, AuditTimelineFilter, AuditTimelinePage, AuditTimelineScope, CauseData,
+    CheckpointData, StateSnapshotData,
+};
+use crate::diesel_schema::{
+    audit_event_correlations, audit_event_dependencies, audit_events, superseded_audit_events,
+};
 use crate::error::PersistenceError;
 
 /// Diesel Queryable struct for full audit event rows.
@@ -36,8 +42,179 @@ struct AuditEventFullRow {
     action_json: String,
     before_snapshot_json: String,
     after_snapshot_json: String,
-    #[allow(dead_code)]
     created_at: Option<String>,
+    event_ulid: Option<String>,
+    user_id: Option<i64>,
+}
+
+/// Parses a stored event ULID.
+///
+/// Events recorded before ULIDs existed have none.
+pub fn parse_event_ulid(value: Option<String>) -> Result<Option<Ulid>, PersistenceError> {
+    value
+        .map(|value| {
+            Ulid::from_string(&value).map_err(|e| {
+                PersistenceError::ReconstructionError(format!("Invalid event ULID '{value}': {e}"))
+            })
+        })
+        .transpose()
+}
+
+/// Diesel Queryable struct for audit event headers, without snapshots.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = audit_events)]
+struct AuditEventHeaderRow {
+    event_id: i64,
+    bid_year_id: Option<i64>,
+    area_id: Option<i64>,
+    actor_operator_id: i64,
+    actor_login_name: String,
+    action_json: String,
+    created_at: Option<String>,
+}
+
+impl AuditEventHeaderRow {
+    /// Reads the action name and builds the header.
+    fn into_header(self) -> Result<AuditEventHeader, PersistenceError> {
+        let action_data: ActionData = serde_json::from_str(&self.action_json)?;
+        Ok(AuditEventHeader {
+            event_id: self.event_id,
+            action_name: action_data.name,
+            actor_operator_id: self.actor_operator_id,
+            actor_login_name: self.actor_login_name,
+            bid_year_id: self.bid_year_id,
+            area_id: self.area_id,
+            created_at: self.created_at,
+        })
+    }
+}
+
+/// Diesel Queryable struct for checkpoint rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = audit_events)]
+struct CheckpointRow {
+    event_id: i64,
+    bid_year_id: Option<i64>,
+    area_id: Option<i64>,
+    area_code: String,
+    actor_login_name: String,
+    action_json: String,
+    created_at: Option<String>,
+}
+
+/// Narrows a boxed `audit_events` query to a timeline scope, cursor, and
+/// filter.
+///
+/// A macro rather than a function because the boxed query type differs by
+/// backend and by selection.
+macro_rules! filter_timeline {
+    ($query:expr, $scope:expr, $filter:expr, $after_event_id:expr) => {{
+        let filter: &AuditTimelineFilter = $filter;
+
+        // The user's alternatives are OR-ed onto the still empty where
+        // clause, so every later filter is AND-ed onto the whole group.
+        // Events recorded before events carried a user ID fall back to
+        // matching the user's initials in their action.
+        let mut query = $query;
+        if let Some(user_id) = filter.user_id {
+            query = query.filter(audit_events::user_id.eq(user_id));
+            for initials in &filter.user_initials {
+                query = query.or_filter(
+                    audit_events::user_id
+                        .is_null()
+                        .and(audit_events::action_json.like(format!("%'{initials}'%"))),
+                );
+            }
+        }
+
+        let mut query = match $scope {
+            AuditTimelineScope::All => query,
+            AuditTimelineScope::Global => query
+                .filter(audit_events::bid_year_id.is_null())
+                .filter(audit_events::area_id.is_null()),
+            AuditTimelineScope::BidYear { bid_year_id } => {
+                query.filter(audit_events::bid_year_id.eq(bid_year_id))
+            }
+            AuditTimelineScope::Area {
+                bid_year_id,
+                area_id,
+            } => query
+                .filter(audit_events::bid_year_id.eq(bid_year_id))
+                .filter(audit_events::area_id.eq(area_id)),
+        };
+
+        if let Some(cursor) = $after_event_id {
+            query = query.filter(audit_events::event_id.gt(cursor));
+        }
+        if let Some(name) = &filter.action_name {
+            // action_json is serialized from ActionData, whose first field is the name
+            let prefix: String = format!("{{\"name\":{},%", serde_json::to_string(name)?);
+            query = query.filter(audit_events::action_json.like(prefix));
+        }
+        if let Some(operator_id) = filter.actor_operator_id {
+            query = query.filter(audit_events::actor_operator_id.eq(operator_id));
+        }
+        if let Some(from) = &filter.created_from {
+            query = query.filter(audit_events::created_at.ge(from.clone()));
+        }
+        if let Some(to) = &filter.created_to {
+            query = query.filter(audit_events::created_at.le(to.clone()));
+        }
+        query
+    }};
+}
+
+/// Reconstructs a timeline entry from a full audit event row.
+///
+/// Bid year and area are only attached when the row carries the
+/// corresponding canonical ID, so global events have no scope.
+fn timeline_entry_from_row(row: AuditEventFullRow) -> Result<AuditTimelineEntry, PersistenceError> {
+    let actor_data: ActorData = serde_json::from_str(&row.actor_json)?;
+    let cause_data: CauseData = serde_json::from_str(&row.cause_json)?;
+    let action_data: ActionData = serde_json::from_str(&row.action_json)?;
+    let before_data: StateSnapshotData = serde_json::from_str(&row.before_snapshot_json)?;
+    let after_data: StateSnapshotData = serde_json::from_str(&row.after_snapshot_json)?;
+
+    let actor: Actor = if row.actor_operator_id != 0 {
+        Actor::with_operator(
+            actor_data.id,
+            actor_data.actor_type,
+            row.actor_operator_id,
+            row.actor_login_name,
+            row.actor_display_name,
+        )
+    } else {
+        Actor::new(actor_data.id, actor_data.actor_type)
+    };
+
+    let bid_year: Option<BidYear> = match row.bid_year_id {
+        Some(id) => {
+            let year: u16 = row.year.to_u16().ok_or_else(|| {
+                PersistenceError::ReconstructionError("Year out of range".to_string())
+            })?;
+            Some(BidYear::with_id(id, year))
+        }
+        None => None,
+    };
+    let area: Option<Area> = row
+        .area_id
+        .map(|id| Area::with_id(id, &row.area_code, None, false, None));
+
+    Ok(AuditTimelineEntry {
+        event: AuditEvent {
+            event_id: Some(row.event_id),
+            event_ulid: parse_event_ulid(row.event_ulid)?,
+            actor,
+            cause: Cause::new(cause_data.id, cause_data.description),
+            action: Action::new(action_data.name, action_data.details),
+            before: StateSnapshot::new(before_data.data),
+            after: StateSnapshot::new(after_data.data),
+            bid_year,
+            area,
+            user_id: row.user_id,
+        },
+        created_at: row.created_at,
+    })
 }
 
 backend_fn! {
@@ -111,7 +288,9 @@ pub fn get_audit_event(conn: &mut _, event_id: i64) -> Result<AuditEvent, Persis
         StateSnapshot::new(after_data.data),
         bid_year,
         area,
-    ))
+    )
+    .with_ulid(parse_event_ulid(row.event_ulid)?)
+    .with_user_id(row.user_id))
 }
 }
 
@@ -190,7 +369,9 @@ pub fn get_events_after(
                 StateSnapshot::new(after_data.data),
                 bid_year,
                 area,
-            ))
+            )
+            .with_ulid(parse_event_ulid(row.event_ulid)?)
+            .with_user_id(row.user_id))
         })
         .collect();
 
@@ -236,6 +417,8 @@ pub fn get_audit_timeline(
             audit_events::action_json,
             audit_events::before_snapshot_json,
             audit_events::after_snapshot_json,
+            audit_events::event_ulid,
+            audit_events::user_id,
         ))
         .load::<(
             i64,
@@ -249,6 +432,8 @@ pub fn get_audit_timeline(
             String,
             String,
             String,
+            Option<String>,
+            Option<i64>,
         )>(conn)?;
 
     let events: Result<Vec<AuditEvent>, PersistenceError> = rows
@@ -266,6 +451,8 @@ pub fn get_audit_timeline(
                 action_json,
                 before_snapshot_json,
                 after_snapshot_json,
+                event_ulid,
+                user_id,
             )| {
                 let year = year_i32.to_u16().ok_or_else(|| {
                     PersistenceError::ReconstructionError("Year out of range".to_string())
@@ -300,7 +487,9 @@ pub fn get_audit_timeline(
                     ),
                     BidYear::with_id(bid_year_id, year),
                     Area::with_id(area_id, &area_code, None, false, None),
-                ))
+                )
+                .with_ulid(parse_event_ulid(event_ulid)?)
+                .with_user_id(user_id))
             },
         )
         .collect();
@@ -350,6 +539,7 @@ pub fn get_global_audit_events(conn: &mut _) -> Result<Vec<AuditEvent>, Persiste
             audit_events::action_json,
             audit_events::before_snapshot_json,
             audit_events::after_snapshot_json,
+            audit_events::event_ulid,
         ))
         .load::<(
             i64,
@@ -361,6 +551,7 @@ pub fn get_global_audit_events(conn: &mut _) -> Result<Vec<AuditEvent>, Persiste
             String,
             String,
             String,
+            Option<String>,
         )>(conn)?;
 
     let events: Result<Vec<AuditEvent>, PersistenceError> = rows
@@ -376,6 +567,7 @@ pub fn get_global_audit_events(conn: &mut _) -> Result<Vec<AuditEvent>, Persiste
                 action_json,
                 before_snapshot_json,
                 after_snapshot_json,
+                event_ulid,
             )| {
                 let actor_data: ActorData = serde_json::from_str(&actor_json)?;
                 let cause_data: CauseData = serde_json::from_str(&cause_json)?;
@@ -400,6 +592,7 @@ pub fn get_global_audit_events(conn: &mut _) -> Result<Vec<AuditEvent>, Persiste
                 // Create event with event_id but no scope
                 Ok(AuditEvent {
                     event_id: Some(event_id),
+                    event_ulid: parse_event_ulid(event_ulid)?,
                     actor,
                     cause: Cause::new(cause_data.id, cause_data.description),
                     action: Action::new(action_data.name, action_data.details),
@@ -407,6 +600,7 @@ pub fn get_global_audit_events(conn: &mut _) -> Result<Vec<AuditEvent>, Persiste
                     after: StateSnapshot::new(after_data.data),
                     bid_year: None,
                     area: None,
+                    user_id: None,
                 })
             },
         )
@@ -422,3 +616,498 @@ pub fn get_global_audit_events(conn: &mut _) -> Result<Vec<AuditEvent>, Persiste
     Ok(event_list)
 }
 }
+
+backend_fn! {
+/// Retrieves one page of audit events for a scope, applying optional filters.
+///
+/// Pagination is keyset-based on `event_id`: entries are returned in ascending
+/// order starting strictly after `after_event_id`. One extra row is fetched to
+/// determine whether a further page exists.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `scope` - The scope to read events from
+/// * `filter` - Optional action, actor, and time-range filters
+/// * `after_event_id` - Cursor from a previous page, or `None` to start at the beginning
+/// * `limit` - Maximum number of entries to return
+///
+/// # Errors
+///
+/// Returns an error if events cannot be retrieved or deserialized.
+pub fn get_audit_timeline_page(
+    conn: &mut _,
+    scope: AuditTimelineScope,
+    filter: &AuditTimelineFilter,
+    after_event_id: Option<i64>,
+    limit: u32,
+) -> Result<AuditTimelinePage, PersistenceError> {
+    let query = audit_events::table
+        .select(AuditEventFullRow::as_select())
+        .into_boxed();
+    let query = filter_timeline!(query, scope, filter, after_event_id);
+
+    let mut rows: Vec<AuditEventFullRow> = query
+        .order(audit_events::event_id.asc())
+        .limit(i64::from(limit) + 1)
+        .load::<AuditEventFullRow>(conn)?;
+
+    let has_more: bool = rows.len() > limit as usize;
+    rows.truncate(limit as usize);
+
+    let entries: Vec<AuditTimelineEntry> = rows
+        .into_iter()
+        .map(timeline_entry_from_row)
+        .collect::<Result<_, _>>()?;
+    let next_cursor: Option<i64> = if has_more {
+        entries.last().and_then(|entry| entry.event.event_id)
+    } else {
+        None
+    };
+
+    Ok(AuditTimelinePage {
+        entries,
+        next_cursor,
+    })
+}
+}
+
+backend_fn! {
+/// Retrieves one page of audit event headers for a scope, applying optional
+/// filters.
+///
+/// Scope, filters, and pagination behave exactly as in
+/// `get_audit_timeline_page`, but the before and after snapshots are never
+/// read. Use `get_audit_timeline_entries` to fetch full events by ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `scope` - The scope to read events from
+/// * `filter` - Optional action, actor, and time-range filters
+/// * `after_event_id` - Cursor from a previous page, or `None` to start at the beginning
+/// * `limit` - Maximum number of headers to return
+///
+/// # Errors
+///
+/// Returns an error if events cannot be retrieved or their actions cannot
+/// be deserialized.
+pub fn get_audit_event_headers_page(
+    conn: &mut _,
+    scope: AuditTimelineScope,
+    filter: &AuditTimelineFilter,
+    after_event_id: Option<i64>,
+    limit: u32,
+) -> Result<AuditEventHeaderPage, PersistenceError> {
+    let query = audit_events::table
+        .select(AuditEventHeaderRow::as_select())
+        .into_boxed();
+    let query = filter_timeline!(query, scope, filter, after_event_id);
+
+    let mut rows: Vec<AuditEventHeaderRow> = query
+        .order(audit_events::event_id.asc())
+        .limit(i64::from(limit) + 1)
+        .load::<AuditEventHeaderRow>(conn)?;
+
+    let has_more: bool = rows.len() > limit as usize;
+    rows.truncate(limit as usize);
+
+    let headers: Vec<AuditEventHeader> = rows
+        .into_iter()
+        .map(AuditEventHeaderRow::into_header)
+        .collect::<Result<_, _>>()?;
+    let next_cursor: Option<i64> = if has_more {
+        headers.last().map(|header| header.event_id)
+    } else {
+        None
+    };
+
+    Ok(AuditEventHeaderPage {
+        headers,
+        next_cursor,
+    })
+}
+}
+
+backend_fn! {
+/// Retrieves full audit events by ID, with their snapshots.
+///
+/// IDs that do not exist are skipped.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `event_ids` - The events to retrieve
+///
+/// # Returns
+///
+/// The entries in ascending `event_id` order.
+///
+/// # Errors
+///
+/// Returns an error if events cannot be retrieved or deserialized.
+pub fn get_audit_timeline_entries(
+    conn: &mut _,
+    event_ids: &[i64],
+) -> Result<Vec<AuditTimelineEntry>, PersistenceError> {
+    if event_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rows: Vec<AuditEventFullRow> = audit_events::table
+        .filter(audit_events::event_id.eq_any(event_ids))
+        .select(AuditEventFullRow::as_select())
+        .order(audit_events::event_id.asc())
+        .load::<AuditEventFullRow>(conn)?;
+
+    rows.into_iter().map(timeline_entry_from_row).collect()
+}
+}
+
+backend_fn! {
+/// Retrieves an area's most recent audit events with any of the given
+/// action names, newest first.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `area_id` - The area ID
+/// * `action_names` - The action names to include
+/// * `limit` - Maximum number of entries to return
+///
+/// # Errors
+///
+/// Returns an error if events cannot be retrieved or deserialized.
+pub fn get_recent_area_events(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    action_names: &[&str],
+    limit: u32,
+) -> Result<Vec<AuditTimelineEntry>, PersistenceError> {
+    if action_names.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // The action names are OR-ed onto the still empty where clause, so the
+    // scope is AND-ed onto the whole group
+    let mut query = audit_events::table
+        .select(AuditEventFullRow::as_select())
+        .into_boxed();
+    for name in action_names {
+        // action_json is serialized from ActionData, whose first field is the name
+        let prefix: String = format!("{{\"name\":{},%", serde_json::to_string(name)?);
+        query = query.or_filter(audit_events::action_json.like(prefix));
+    }
+
+    let rows: Vec<AuditEventFullRow> = query
+        .filter(audit_events::bid_year_id.eq(bid_year_id))
+        .filter(audit_events::area_id.eq(area_id))
+        .order(audit_events::event_id.desc())
+        .limit(i64::from(limit))
+        .load::<AuditEventFullRow>(conn)?;
+
+    rows.into_iter().map(timeline_entry_from_row).collect()
+}
+}
+
+backend_fn! {
+/// Looks up the numeric ID of the audit event with a ULID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `event_ulid` - The event's ULID
+///
+/// # Returns
+///
+/// `None` if no event has the ULID.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn find_audit_event_id_by_ulid(
+    conn: &mut _,
+    event_ulid: &Ulid,
+) -> Result<Option<i64>, PersistenceError> {
+    let event_id: Option<i64> = audit_events::table
+        .filter(audit_events::event_ulid.eq(event_ulid.to_string()))
+        .select(audit_events::event_id)
+        .first::<i64>(conn)
+        .optional()?;
+    Ok(event_id)
+}
+}
+
+backend_fn! {
+/// Retrieves the highest persisted audit event ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Returns
+///
+/// `None` if no audit events have been persisted.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_latest_audit_event_id(conn: &mut _) -> Result<Option<i64>, PersistenceError> {
+    let latest: Option<i64> = audit_events::table
+        .select(diesel::dsl::max(audit_events::event_id))
+        .first::<Option<i64>>(conn)?;
+    Ok(latest)
+}
+}
+
+backend_fn! {
+/// Lists which of the given audit events a rollback has superseded.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `event_ids` - The events to check
+///
+/// # Returns
+///
+/// The superseded events' IDs, in ascending order.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_superseded_event_ids(
+    conn: &mut _,
+    event_ids: &[i64],
+) -> Result<Vec<i64>, PersistenceError> {
+    if event_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let superseded: Vec<i64> = superseded_audit_events::table
+        .filter(superseded_audit_events::event_id.eq_any(event_ids))
+        .select(superseded_audit_events::event_id)
+        .order(superseded_audit_events::event_id.asc())
+        .load::<i64>(conn)?;
+    Ok(superseded)
+}
+}
+
+backend_fn! {
+/// Returns the rollback that superseded an audit event.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `event_id` - The event to check
+///
+/// # Returns
+///
+/// The `Rollback` event's ID, or `None` if the event is not superseded.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_superseding_event_id(
+    conn: &mut _,
+    event_id: i64,
+) -> Result<Option<i64>, PersistenceError> {
+    let rollback_event_id: Option<i64> = superseded_audit_events::table
+        .filter(superseded_audit_events::event_id.eq(event_id))
+        .select(superseded_audit_events::rollback_event_id)
+        .first(conn)
+        .optional()?;
+    Ok(rollback_event_id)
+}
+}
+
+backend_fn! {
+/// Returns the correlation ID of the operation that recorded an audit event.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `event_id` - The event to look up
+///
+/// # Returns
+///
+/// The correlation ID, or `None` if the event has none.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_event_correlation_id(
+    conn: &mut _,
+    event_id: i64,
+) -> Result<Option<String>, PersistenceError> {
+    let correlation_id: Option<String> = audit_event_correlations::table
+        .filter(audit_event_correlations::event_id.eq(event_id))
+        .select(audit_event_correlations::correlation_id)
+        .first(conn)
+        .optional()?;
+    Ok(correlation_id)
+}
+}
+
+backend_fn! {
+/// Lists the events that depend on any of the given events.
+///
+/// Dependents a rollback has superseded are skipped.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `event_ids` - The depended-on events
+///
+/// # Returns
+///
+/// The dependencies, ordered by dependent event ID.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_event_dependents(
+    conn: &mut _,
+    event_ids: &[i64],
+) -> Result<Vec<AuditEventDependency>, PersistenceError> {
+    if event_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rows: Vec<(i64, i64)> = audit_event_dependencies::table
+        .filter(audit_event_dependencies::depends_on_event_id.eq_any(event_ids))
+        .filter(
+            audit_event_dependencies::event_id
+                .ne_all(superseded_audit_events::table.select(superseded_audit_events::event_id)),
+        )
+        .order((
+            audit_event_dependencies::event_id.asc(),
+            audit_event_dependencies::depends_on_event_id.asc(),
+        ))
+        .select((
+            audit_event_dependencies::event_id,
+            audit_event_dependencies::depends_on_event_id,
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(event_id, depends_on_event_id)| AuditEventDependency {
+            event_id,
+            depends_on_event_id,
+        })
+        .collect())
+}
+}
+
+backend_fn! {
+/// Lists the latest event recorded in each area of a bid year.
+///
+/// Events a rollback has superseded are skipped.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+///
+/// # Returns
+///
+/// One event ID per area with events, in ascending order.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_latest_area_event_ids(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<i64>, PersistenceError> {
+    let latest: Vec<Option<i64>> = audit_events::table
+        .filter(audit_events::bid_year_id.eq(bid_year_id))
+        .filter(audit_events::area_id.is_not_null())
+        .filter(
+            audit_events::event_id
+                .ne_all(superseded_audit_events::table.select(superseded_audit_events::event_id)),
+        )
+        .group_by(audit_events::area_id)
+        .select(diesel::dsl::max(audit_events::event_id))
+        .load(conn)?;
+
+    let mut event_ids: Vec<i64> = latest.into_iter().flatten().collect();
+    event_ids.sort_unstable();
+    Ok(event_ids)
+}
+}
+
+backend_fn! {
+/// Lists the checkpoints recorded in a scope, newest first.
+///
+/// Labels and descriptions are read from each checkpoint's action details.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `scope` - The scope to list checkpoints in
+///
+/// # Errors
+///
+/// Returns an error if the query fails or a checkpoint's action cannot be
+/// deserialized.
+pub fn list_checkpoints(
+    conn: &mut _,
+    scope: AuditTimelineScope,
+) -> Result<Vec<CheckpointData>, PersistenceError> {
+    let filter: AuditTimelineFilter = AuditTimelineFilter {
+        action_name: Some(String::from("Checkpoint")),
+        ..AuditTimelineFilter::default()
+    };
+    let query = audit_events::table
+        .select(CheckpointRow::as_select())
+        .into_boxed();
+    let query = filter_timeline!(query, scope, &filter, None::<i64>);
+    let rows: Vec<CheckpointRow> = query
+        .order(audit_events::event_id.desc())
+        .load::<CheckpointRow>(conn)?;
+
+    let event_ids: Vec<i64> = rows.iter().map(|row| row.event_id).collect();
+    let superseded: Vec<i64> = superseded_audit_events::table
+        .filter(superseded_audit_events::event_id.eq_any(&event_ids))
+        .select(superseded_audit_events::event_id)
+        .load(conn)?;
+    let correlations: Vec<(i64, String)> = audit_event_correlations::table
+        .filter(audit_event_correlations::event_id.eq_any(&event_ids))
+        .select((
+            audit_event_correlations::event_id,
+            audit_event_correlations::correlation_id,
+        ))
+        .load(conn)?;
+
+    rows.into_iter()
+        .map(|row| {
+            let action_data: ActionData = serde_json::from_str(&row.action_json)?;
+            let action: Action = Action::new(action_data.name, action_data.details);
+            let (label, description) = match zab_bid::checkpoint_label(&action) {
+                Some((label, description)) => {
+                    (Some(label.to_string()), description.map(str::to_string))
+                }
+                None => (None, None),
+            };
+            Ok(CheckpointData {
+                event_id: row.event_id,
+                bid_year_id: row.bid_year_id,
+                area_id: row.area_id,
+                area_code: row.area_code,
+                label,
+                description,
+                actor_login_name: row.actor_login_name,
+                created_at: row.created_at,
+                superseded: superseded.contains(&row.event_id),
+                correlation_id: correlations
+                    .iter()
+                    .find(|(event_id, _)| *event_id == row.event_id)
+                    .map(|(_, correlation_id)| correlation_id.clone()),
+            })
+        })
+        .collect()
+}
+}
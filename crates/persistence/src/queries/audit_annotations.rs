@@ -0,0 +1,81 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Audit annotation queries.
+//!
+//! This module contains backend-agnostic queries for the notes operators
+//! attach to audit events after the fact.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+
+use crate::data_models::AuditAnnotationData;
+use crate::diesel_schema::{audit_event_annotations, operators};
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Lists the annotations attached to a set of audit events.
+///
+/// Annotations are ordered by event, then oldest first.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `event_ids` - The annotated audit events
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_audit_annotations(
+    conn: &mut _,
+    event_ids: &[i64],
+) -> Result<Vec<AuditAnnotationData>, PersistenceError> {
+    if event_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rows: Vec<(i64, i64, i64, String, String, String, String)> =
+        audit_event_annotations::table
+            .inner_join(operators::table)
+            .filter(audit_event_annotations::event_id.eq_any(event_ids))
+            .order_by((
+                audit_event_annotations::event_id.asc(),
+                audit_event_annotations::annotation_id.asc(),
+            ))
+            .select((
+                audit_event_annotations::annotation_id,
+                audit_event_annotations::event_id,
+                audit_event_annotations::operator_id,
+                operators::login_name,
+                operators::display_name,
+                audit_event_annotations::note,
+                audit_event_annotations::created_at,
+            ))
+            .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(
+                annotation_id,
+                event_id,
+                operator_id,
+                operator_login_name,
+                operator_display_name,
+                note,
+                created_at,
+            )| AuditAnnotationData {
+                annotation_id,
+                event_id,
+                operator_id,
+                operator_login_name,
+                operator_display_name,
+                note,
+                created_at,
+            },
+        )
+        .collect())
+}
+}
@@ -0,0 +1,230 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Bid preference queries.
+//!
+//! This module stores the ranked leave preferences users hand in before
+//! their bid window opens, and tracks what became of each preference once
+//! the window opened.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::data_models::{BidPreferenceData, BidPreferenceSpecData};
+use crate::diesel_schema::bid_preferences;
+use crate::error::PersistenceError;
+
+/// Separator between the dates of a stored preference.
+const DATE_SEPARATOR: &str = ",";
+
+/// Diesel Queryable struct for bid preference rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = bid_preferences)]
+struct BidPreferenceRow {
+    bid_preference_id: i64,
+    bid_year_id: i64,
+    area_id: i64,
+    user_id: i64,
+    round_id: i64,
+    preference_rank: i32,
+    leave_dates: String,
+    hours: i32,
+    on_behalf_of: String,
+    received_via: String,
+    status: String,
+    created_at: String,
+}
+
+impl From<BidPreferenceRow> for BidPreferenceData {
+    fn from(row: BidPreferenceRow) -> Self {
+        Self {
+            bid_preference_id: row.bid_preference_id,
+            bid_year_id: row.bid_year_id,
+            area_id: row.area_id,
+            user_id: row.user_id,
+            round_id: row.round_id,
+            preference_rank: row.preference_rank,
+            leave_dates: row
+                .leave_dates
+                .split(DATE_SEPARATOR)
+                .filter(|date| !date.is_empty())
+                .map(String::from)
+                .collect(),
+            hours: row.hours,
+            on_behalf_of: row.on_behalf_of,
+            received_via: row.received_via,
+            status: row.status,
+            created_at: row.created_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Lists the bid preferences for a round in an area.
+///
+/// Preferences are ordered by user, then by rank.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_bid_preferences(
+    conn: &mut _,
+    area_id: i64,
+    round_id: i64,
+) -> Result<Vec<BidPreferenceData>, PersistenceError> {
+    let rows: Vec<BidPreferenceRow> = bid_preferences::table
+        .filter(bid_preferences::area_id.eq(area_id))
+        .filter(bid_preferences::round_id.eq(round_id))
+        .select(BidPreferenceRow::as_select())
+        .order_by((
+            bid_preferences::user_id.asc(),
+            bid_preferences::preference_rank.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(BidPreferenceData::from).collect())
+}
+}
+
+backend_fn! {
+/// Lists the pending bid preferences of every area in a bid year.
+///
+/// Preferences are ordered by user, then by round, then by rank.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_pending_bid_preferences(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<BidPreferenceData>, PersistenceError> {
+    let rows: Vec<BidPreferenceRow> = bid_preferences::table
+        .filter(bid_preferences::bid_year_id.eq(bid_year_id))
+        .filter(bid_preferences::status.eq(BidPreferenceData::STATUS_PENDING))
+        .select(BidPreferenceRow::as_select())
+        .order_by((
+            bid_preferences::user_id.asc(),
+            bid_preferences::round_id.asc(),
+            bid_preferences::preference_rank.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(BidPreferenceData::from).collect())
+}
+}
+
+backend_fn! {
+/// Replaces a user's pending preferences for a round.
+///
+/// Pending preferences are deleted and the new list is inserted in one
+/// transaction. Preferences that have already been processed are kept.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `area_id` - The area ID
+/// * `user_id` - The user ID
+/// * `round_id` - The round ID
+/// * `on_behalf_of` - The initials of the controller the list was entered for
+/// * `received_via` - How the list was received (`phone`, `in_person`, `written`)
+/// * `preferences` - The ranked preferences
+///
+/// # Errors
+///
+/// Returns an error if the write fails, including when a rank is already
+/// taken by a processed preference.
+#[allow(clippy::too_many_arguments)]
+pub fn replace_bid_preferences(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    user_id: i64,
+    round_id: i64,
+    on_behalf_of: &str,
+    received_via: &str,
+    preferences: &[BidPreferenceSpecData],
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(
+            bid_preferences::table
+                .filter(bid_preferences::user_id.eq(user_id))
+                .filter(bid_preferences::round_id.eq(round_id))
+                .filter(bid_preferences::status.eq(BidPreferenceData::STATUS_PENDING)),
+        )
+        .execute(conn)?;
+
+        for preference in preferences {
+            diesel::insert_into(bid_preferences::table)
+                .values((
+                    bid_preferences::bid_year_id.eq(bid_year_id),
+                    bid_preferences::area_id.eq(area_id),
+                    bid_preferences::user_id.eq(user_id),
+                    bid_preferences::round_id.eq(round_id),
+                    bid_preferences::preference_rank.eq(preference.preference_rank),
+                    bid_preferences::leave_dates.eq(preference.leave_dates.join(DATE_SEPARATOR)),
+                    bid_preferences::hours.eq(preference.hours),
+                    bid_preferences::on_behalf_of.eq(on_behalf_of),
+                    bid_preferences::received_via.eq(received_via),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })?;
+
+    info!(
+        user_id,
+        round_id,
+        count = preferences.len(),
+        "Bid preferences replaced"
+    );
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Records what became of a bid preference.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_preference_id` - The preference ID
+/// * `status` - The new status
+///
+/// # Errors
+///
+/// Returns an error if the update fails.
+pub fn set_bid_preference_status(
+    conn: &mut _,
+    bid_preference_id: i64,
+    status: &str,
+) -> Result<(), PersistenceError> {
+    diesel::update(bid_preferences::table)
+        .filter(bid_preferences::bid_preference_id.eq(bid_preference_id))
+        .set(bid_preferences::status.eq(status))
+        .execute(conn)?;
+
+    info!(bid_preference_id, status, "Bid preference status updated");
+
+    Ok(())
+}
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Bid validation rule queries.
+//!
+//! This module stores the list of validation rules a bid year layers on
+//! top of its round limits. The list is always replaced as a whole.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::data_models::{BidRuleData, BidRuleSpecData};
+use crate::diesel_schema::bid_rules;
+use crate::error::PersistenceError;
+
+/// Separator between the dates of a stored rule.
+const DATE_SEPARATOR: &str = ",";
+
+/// Diesel Queryable struct for bid rule rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = bid_rules)]
+struct BidRuleRow {
+    bid_rule_id: i64,
+    bid_year_id: i64,
+    rule_position: i32,
+    rule_kind: String,
+    rule_value: Option<i32>,
+    rule_dates: String,
+    created_at: String,
+}
+
+impl From<BidRuleRow> for BidRuleData {
+    fn from(row: BidRuleRow) -> Self {
+        Self {
+            bid_rule_id: row.bid_rule_id,
+            bid_year_id: row.bid_year_id,
+            rule_position: row.rule_position,
+            rule_kind: row.rule_kind,
+            rule_value: row.rule_value,
+            rule_dates: row
+                .rule_dates
+                .split(DATE_SEPARATOR)
+                .filter(|date| !date.is_empty())
+                .map(String::from)
+                .collect(),
+            created_at: row.created_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Lists the bid validation rules of a bid year in evaluation order.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_bid_rules(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<BidRuleData>, PersistenceError> {
+    let rows: Vec<BidRuleRow> = bid_rules::table
+        .filter(bid_rules::bid_year_id.eq(bid_year_id))
+        .select(BidRuleRow::as_select())
+        .order_by(bid_rules::rule_position.asc())
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(BidRuleData::from).collect())
+}
+}
+
+backend_fn! {
+/// Replaces the bid validation rules of a bid year.
+///
+/// Existing rules are deleted and the new list is inserted in one
+/// transaction, positioned in list order.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `rules` - The rules in evaluation order
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn replace_bid_rules(
+    conn: &mut _,
+    bid_year_id: i64,
+    rules: &[BidRuleSpecData],
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(bid_rules::table.filter(bid_rules::bid_year_id.eq(bid_year_id)))
+            .execute(conn)?;
+
+        for (position, rule) in (1..).zip(rules) {
+            diesel::insert_into(bid_rules::table)
+                .values((
+                    bid_rules::bid_year_id.eq(bid_year_id),
+                    bid_rules::rule_position.eq(position),
+                    bid_rules::rule_kind.eq(&rule.rule_kind),
+                    bid_rules::rule_value.eq(rule.rule_value),
+                    bid_rules::rule_dates.eq(rule.rule_dates.join(DATE_SEPARATOR)),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })?;
+
+    info!(bid_year_id, count = rules.len(), "Bid rules replaced");
+
+    Ok(())
+}
+}
@@ -0,0 +1,243 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Blackout date queries.
+//!
+//! This module contains queries for managing the per-bid-year blackout
+//! dates on which no bid windows are laid out.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::BlackoutDateData;
+use crate::diesel_schema::bid_year_blackout_dates;
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for blackout date rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = bid_year_blackout_dates)]
+struct BlackoutDateRow {
+    blackout_date_id: i64,
+    bid_year_id: i64,
+    blackout_date: String,
+    reason: String,
+}
+
+impl From<BlackoutDateRow> for BlackoutDateData {
+    fn from(row: BlackoutDateRow) -> Self {
+        Self {
+            blackout_date_id: row.blackout_date_id,
+            bid_year_id: row.bid_year_id,
+            blackout_date: row.blackout_date,
+            reason: row.reason,
+        }
+    }
+}
+
+backend_fn! {
+/// Stores a new blackout date for a bid year.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `blackout_date` - The blackout date (ISO 8601 format)
+/// * `reason` - Why no bidding occurs on the date
+///
+/// # Errors
+///
+/// Returns an error if the insert fails, including when the date is
+/// already blacked out in the bid year.
+pub fn insert_blackout_date(
+    conn: &mut _,
+    bid_year_id: i64,
+    blackout_date: &str,
+    reason: &str,
+) -> Result<i64, PersistenceError> {
+    let blackout_date_id: i64 = conn.transaction::<i64, PersistenceError, _>(|conn| {
+        diesel::insert_into(bid_year_blackout_dates::table)
+            .values((
+                bid_year_blackout_dates::bid_year_id.eq(bid_year_id),
+                bid_year_blackout_dates::blackout_date.eq(blackout_date),
+                bid_year_blackout_dates::reason.eq(reason),
+            ))
+            .execute(conn)?;
+        conn.get_last_insert_rowid()
+    })?;
+
+    info!(blackout_date_id, bid_year_id, blackout_date, "Blackout date created");
+
+    Ok(blackout_date_id)
+}
+}
+
+backend_fn! {
+/// Lists the blackout dates of a bid year, ordered by date.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_blackout_dates(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<BlackoutDateData>, PersistenceError> {
+    let rows: Vec<BlackoutDateRow> = bid_year_blackout_dates::table
+        .filter(bid_year_blackout_dates::bid_year_id.eq(bid_year_id))
+        .select(BlackoutDateRow::as_select())
+        .order_by(bid_year_blackout_dates::blackout_date.asc())
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(BlackoutDateData::from).collect())
+}
+}
+
+backend_fn! {
+/// Gets a single blackout date.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `blackout_date_id` - The blackout date ID
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the blackout date does not
+/// exist, or an error if the query fails.
+pub fn get_blackout_date(
+    conn: &mut _,
+    blackout_date_id: i64,
+) -> Result<BlackoutDateData, PersistenceError> {
+    let row: BlackoutDateRow = bid_year_blackout_dates::table
+        .filter(bid_year_blackout_dates::blackout_date_id.eq(blackout_date_id))
+        .select(BlackoutDateRow::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or_else(|| {
+            PersistenceError::NotFound(format!("Blackout date {blackout_date_id} not found"))
+        })?;
+
+    Ok(BlackoutDateData::from(row))
+}
+}
+
+backend_fn! {
+/// Checks whether a date is already blacked out in a bid year.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `blackout_date` - The date to check (ISO 8601 format)
+/// * `exclude_id` - A blackout date ID to ignore (for updates)
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn blackout_date_exists(
+    conn: &mut _,
+    bid_year_id: i64,
+    blackout_date: &str,
+    exclude_id: Option<i64>,
+) -> Result<bool, PersistenceError> {
+    let mut query = bid_year_blackout_dates::table
+        .filter(bid_year_blackout_dates::bid_year_id.eq(bid_year_id))
+        .filter(bid_year_blackout_dates::blackout_date.eq(blackout_date))
+        .into_boxed();
+
+    if let Some(id) = exclude_id {
+        query = query.filter(bid_year_blackout_dates::blackout_date_id.ne(id));
+    }
+
+    let count: i64 = query.count().get_result(conn)?;
+
+    Ok(count > 0)
+}
+}
+
+backend_fn! {
+/// Updates the date and reason of a blackout date.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `blackout_date_id` - The blackout date ID
+/// * `blackout_date` - The new date (ISO 8601 format)
+/// * `reason` - The new reason
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the blackout date does not
+/// exist, or an error if the update fails.
+pub fn update_blackout_date(
+    conn: &mut _,
+    blackout_date_id: i64,
+    blackout_date: &str,
+    reason: &str,
+) -> Result<(), PersistenceError> {
+    let rows_affected: usize = diesel::update(
+        bid_year_blackout_dates::table
+            .filter(bid_year_blackout_dates::blackout_date_id.eq(blackout_date_id)),
+    )
+    .set((
+        bid_year_blackout_dates::blackout_date.eq(blackout_date),
+        bid_year_blackout_dates::reason.eq(reason),
+    ))
+    .execute(conn)?;
+
+    if rows_affected == 0 {
+        return Err(PersistenceError::NotFound(format!(
+            "Blackout date {blackout_date_id} not found"
+        )));
+    }
+
+    info!(blackout_date_id, blackout_date, "Blackout date updated");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Deletes a blackout date.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `blackout_date_id` - The blackout date ID
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the blackout date does not
+/// exist, or an error if the delete fails.
+pub fn delete_blackout_date(
+    conn: &mut _,
+    blackout_date_id: i64,
+) -> Result<(), PersistenceError> {
+    let rows_affected: usize = diesel::delete(
+        bid_year_blackout_dates::table
+            .filter(bid_year_blackout_dates::blackout_date_id.eq(blackout_date_id)),
+    )
+    .execute(conn)?;
+
+    if rows_affected == 0 {
+        return Err(PersistenceError::NotFound(format!(
+            "Blackout date {blackout_date_id} not found"
+        )));
+    }
+
+    info!(blackout_date_id, "Blackout date deleted");
+
+    Ok(())
+}
+}
@@ -20,7 +20,10 @@ use zab_bid_domain::{
     Area, BidYear, CanonicalBidYear, Crew, Initials, SeniorityData, User, UserType,
 };
 
-use crate::diesel_schema::{areas, bid_years, users};
+use crate::data_models::{RoundResultEntryData, SeniorityListEntryData};
+use crate::diesel_schema::{
+    areas, bid_preferences, bid_years, leave_bids, operator_controllers, scope_freezes, users,
+};
 use crate::error::PersistenceError;
 
 backend_fn! {
@@ -152,6 +155,31 @@ pub fn get_bootstrap_metadata(conn: &mut _) -> Result<BootstrapMetadata, Persist
         metadata.areas.push((bid_year, area));
     }
 
+    // Query frozen areas, resolved against the areas loaded above
+    let frozen_area_ids: Vec<i64> = scope_freezes::table
+        .select(scope_freezes::area_id)
+        .order(scope_freezes::area_id.asc())
+        .load::<i64>(conn)?;
+    metadata.frozen_scopes = metadata
+        .areas
+        .iter()
+        .filter(|(_, area)| {
+            area.area_id()
+                .is_some_and(|area_id| frozen_area_ids.contains(&area_id))
+        })
+        .cloned()
+        .collect();
+
+    // Query the controllers linked operators are in the roster
+    let controller_rows: Vec<(i64, String)> = operator_controllers::table
+        .select((operator_controllers::operator_id, operator_controllers::initials))
+        .order(operator_controllers::operator_id.asc())
+        .load::<(i64, String)>(conn)?;
+    metadata.operator_controllers = controller_rows
+        .into_iter()
+        .map(|(operator_id, initials)| (operator_id, Initials::new(&initials)))
+        .collect();
+
     Ok(metadata)
 }
 }
@@ -761,6 +789,35 @@ pub fn count_users_in_system_area(
 }
 }
 
+backend_fn! {
+/// Counts the bids entered in a bid year.
+///
+/// Leave bids and bid preferences both count, whatever their status.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be queried.
+pub fn count_entered_bids(conn: &mut _, bid_year_id: i64) -> Result<usize, PersistenceError> {
+    let leave_bids: i64 = leave_bids::table
+        .filter(leave_bids::bid_year_id.eq(bid_year_id))
+        .count()
+        .get_result(conn)?;
+    let preferences: i64 = bid_preferences::table
+        .filter(bid_preferences::bid_year_id.eq(bid_year_id))
+        .count()
+        .get_result(conn)?;
+
+    (leave_bids + preferences)
+        .to_usize()
+        .ok_or_else(|| PersistenceError::DatabaseError("Count conversion failed".to_string()))
+}
+}
+
 backend_fn! {
 /// Lists users in the system area (No Bid) for a given bid year.
 ///
@@ -1001,6 +1058,223 @@ pub fn list_users_canonical(
 }
 }
 
+backend_fn! {
+/// Lists an area's canonical seniority list in bid order.
+///
+/// Reads area membership and bid order from the canonical tables so the
+/// list matches what bidding uses. Users without a materialized bid order
+/// are omitted. Ties (which confirmation never produces) fall back to
+/// initials.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be queried.
+pub fn list_seniority_list(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+) -> Result<Vec<SeniorityListEntryData>, PersistenceError> {
+    use crate::diesel_schema::{canonical_area_membership, canonical_bid_order};
+
+    type SeniorityRowTuple = (
+        Option<i32>,
+        i64,
+        String,
+        String,
+        String,
+        Option<i32>,
+        String,
+        String,
+        String,
+        String,
+        Option<i32>,
+        i32,
+    );
+
+    let rows: Vec<SeniorityRowTuple> = users::table
+        .inner_join(
+            canonical_area_membership::table.on(
+                users::user_id.eq(canonical_area_membership::user_id)
+                    .and(canonical_area_membership::bid_year_id.eq(bid_year_id))
+                    .and(canonical_area_membership::area_id.eq(area_id))
+            )
+        )
+        .inner_join(
+            canonical_bid_order::table.on(
+                users::user_id.eq(canonical_bid_order::user_id)
+                    .and(canonical_bid_order::bid_year_id.eq(bid_year_id))
+            )
+        )
+        .filter(users::bid_year_id.eq(bid_year_id))
+        .filter(canonical_bid_order::bid_order.is_not_null())
+        .select((
+            canonical_bid_order::bid_order,
+            users::user_id,
+            users::initials,
+            users::name,
+            users::user_type,
+            users::crew,
+            users::cumulative_natca_bu_date,
+            users::natca_bu_date,
+            users::eod_faa_date,
+            users::service_computation_date,
+            users::lottery_value,
+            canonical_bid_order::is_overridden,
+        ))
+        .order((canonical_bid_order::bid_order.asc(), users::initials.asc()))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(
+            |(
+                bid_order,
+                user_id,
+                initials,
+                name,
+                user_type,
+                crew,
+                cumulative_natca_bu_date,
+                natca_bu_date,
+                eod_faa_date,
+                service_computation_date,
+                lottery_value,
+                is_overridden,
+            )| {
+                Some(SeniorityListEntryData {
+                    bid_order: bid_order?,
+                    user_id,
+                    initials,
+                    name,
+                    user_type,
+                    crew,
+                    cumulative_natca_bu_date,
+                    natca_bu_date,
+                    eod_faa_date,
+                    service_computation_date,
+                    lottery_value,
+                    is_overridden: is_overridden != 0,
+                })
+            },
+        )
+        .collect())
+}
+}
+
+backend_fn! {
+/// Lists an area's results for one round in bid order.
+///
+/// Each user on the canonical seniority list is paired with their bid
+/// status and bid window for the round, when present. Ordering matches
+/// `list_seniority_list`.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be queried.
+pub fn list_round_results(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    round_id: i64,
+) -> Result<Vec<RoundResultEntryData>, PersistenceError> {
+    use crate::diesel_schema::{
+        bid_status, bid_windows, canonical_area_membership, canonical_bid_order,
+    };
+
+    type RoundResultRowTuple = (
+        Option<i32>,
+        i64,
+        String,
+        String,
+        Option<String>,
+        Option<String>,
+        Option<String>,
+    );
+
+    let rows: Vec<RoundResultRowTuple> = users::table
+        .inner_join(
+            canonical_area_membership::table.on(
+                users::user_id.eq(canonical_area_membership::user_id)
+                    .and(canonical_area_membership::bid_year_id.eq(bid_year_id))
+                    .and(canonical_area_membership::area_id.eq(area_id))
+            )
+        )
+        .inner_join(
+            canonical_bid_order::table.on(
+                users::user_id.eq(canonical_bid_order::user_id)
+                    .and(canonical_bid_order::bid_year_id.eq(bid_year_id))
+            )
+        )
+        .left_join(
+            bid_status::table.on(
+                users::user_id.eq(bid_status::user_id)
+                    .and(bid_status::bid_year_id.eq(bid_year_id))
+                    .and(bid_status::area_id.eq(area_id))
+                    .and(bid_status::round_id.eq(round_id))
+            )
+        )
+        .left_join(
+            bid_windows::table.on(
+                users::user_id.eq(bid_windows::user_id)
+                    .and(bid_windows::bid_year_id.eq(bid_year_id))
+                    .and(bid_windows::area_id.eq(area_id))
+                    .and(bid_windows::round_id.eq(round_id))
+            )
+        )
+        .filter(users::bid_year_id.eq(bid_year_id))
+        .filter(canonical_bid_order::bid_order.is_not_null())
+        .select((
+            canonical_bid_order::bid_order,
+            users::user_id,
+            users::initials,
+            users::name,
+            bid_status::status.nullable(),
+            bid_windows::window_start_datetime.nullable(),
+            bid_windows::window_end_datetime.nullable(),
+        ))
+        .order((canonical_bid_order::bid_order.asc(), users::initials.asc()))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(
+            |(
+                bid_order,
+                user_id,
+                initials,
+                name,
+                status,
+                window_start_datetime,
+                window_end_datetime,
+            )| {
+                Some(RoundResultEntryData {
+                    bid_order: bid_order?,
+                    user_id,
+                    initials,
+                    name,
+                    status,
+                    window_start_datetime,
+                    window_end_datetime,
+                })
+            },
+        )
+        .collect())
+}
+}
+
 /// Lists users with lifecycle-aware routing (`SQLite` version).
 ///
 /// Phase 25C: Routes to canonical or derived tables based on lifecycle state.
@@ -0,0 +1,322 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Chat channel queries.
+//!
+//! This module contains backend-agnostic queries for configured chat
+//! channels, the chat announcement log, and the bid windows announced to
+//! those channels.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::debug;
+
+use crate::data_models::{ActiveBidWindowData, ChatChannelData, ChatNotificationLogData};
+use crate::diesel_schema::{
+    areas, bid_windows, bid_years, chat_channels, chat_notification_log, rounds, users,
+};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for chat channel rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = chat_channels)]
+struct ChatChannelRow {
+    chat_channel_id: i64,
+    bid_year_id: i64,
+    area_id: Option<i64>,
+    provider: String,
+    url: String,
+    is_enabled: i32,
+    created_at: String,
+}
+
+impl From<ChatChannelRow> for ChatChannelData {
+    fn from(row: ChatChannelRow) -> Self {
+        Self {
+            chat_channel_id: row.chat_channel_id,
+            bid_year_id: row.bid_year_id,
+            area_id: row.area_id,
+            provider: row.provider,
+            url: row.url,
+            is_enabled: row.is_enabled != 0,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// Diesel Queryable struct for chat announcement log rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = chat_notification_log)]
+struct ChatNotificationLogRow {
+    chat_notification_id: i64,
+    chat_channel_id: i64,
+    kind: String,
+    reference_key: String,
+    message: String,
+    delivered: i32,
+    error: Option<String>,
+    sent_at: String,
+}
+
+impl From<ChatNotificationLogRow> for ChatNotificationLogData {
+    fn from(row: ChatNotificationLogRow) -> Self {
+        Self {
+            chat_notification_id: row.chat_notification_id,
+            chat_channel_id: row.chat_channel_id,
+            kind: row.kind,
+            reference_key: row.reference_key,
+            message: row.message,
+            delivered: row.delivered != 0,
+            error: row.error,
+            sent_at: row.sent_at,
+        }
+    }
+}
+
+/// Active bid window columns, in select order.
+type ActiveBidWindowRow = (
+    i64,
+    i64,
+    i32,
+    i64,
+    String,
+    i64,
+    i32,
+    String,
+    String,
+    String,
+    String,
+);
+
+backend_fn! {
+/// Lists chat channels ordered by ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - Restrict to one bid year, or `None` for all
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_chat_channels(
+    conn: &mut _,
+    bid_year_id: Option<i64>,
+) -> Result<Vec<ChatChannelData>, PersistenceError> {
+    debug!(?bid_year_id, "Listing chat channels");
+
+    let mut query = chat_channels::table
+        .select(ChatChannelRow::as_select())
+        .into_boxed();
+    if let Some(id) = bid_year_id {
+        query = query.filter(chat_channels::bid_year_id.eq(id));
+    }
+
+    let rows: Vec<ChatChannelRow> = query
+        .order_by(chat_channels::chat_channel_id.asc())
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(ChatChannelData::from).collect())
+}
+}
+
+backend_fn! {
+/// Retrieves a chat channel by ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `chat_channel_id` - The chat channel ID
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+/// Returns `Ok(None)` if the channel is not found.
+pub fn get_chat_channel(
+    conn: &mut _,
+    chat_channel_id: i64,
+) -> Result<Option<ChatChannelData>, PersistenceError> {
+    debug!(chat_channel_id, "Looking up chat channel");
+
+    let row: Option<ChatChannelRow> = chat_channels::table
+        .filter(chat_channels::chat_channel_id.eq(chat_channel_id))
+        .select(ChatChannelRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(ChatChannelData::from))
+}
+}
+
+backend_fn! {
+/// Lists every bid window in `BiddingActive` bid years.
+///
+/// Windows are ordered by area, round, start time, and ID so that windows
+/// opening together are adjacent.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_active_bid_windows(
+    conn: &mut _,
+) -> Result<Vec<ActiveBidWindowData>, PersistenceError> {
+    let rows: Vec<ActiveBidWindowRow> = bid_windows::table
+        .inner_join(bid_years::table)
+        .inner_join(areas::table)
+        .inner_join(rounds::table)
+        .inner_join(users::table)
+        .filter(bid_years::lifecycle_state.eq("BiddingActive"))
+        .select((
+            bid_windows::bid_window_id,
+            bid_windows::bid_year_id,
+            bid_years::year,
+            bid_windows::area_id,
+            areas::area_code,
+            bid_windows::round_id,
+            rounds::round_number,
+            rounds::name,
+            users::initials,
+            bid_windows::window_start_datetime,
+            bid_windows::window_end_datetime,
+        ))
+        .order_by((
+            bid_windows::area_id.asc(),
+            rounds::round_number.asc(),
+            bid_windows::window_start_datetime.asc(),
+            bid_windows::bid_window_id.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(
+                bid_window_id,
+                bid_year_id,
+                year,
+                area_id,
+                area_code,
+                round_id,
+                round_number,
+                round_name,
+                initials,
+                window_start_datetime,
+                window_end_datetime,
+            )| ActiveBidWindowData {
+                bid_window_id,
+                bid_year_id,
+                year,
+                area_id,
+                area_code,
+                round_id,
+                round_number,
+                round_name,
+                initials,
+                window_start_datetime,
+                window_end_datetime,
+            },
+        )
+        .collect())
+}
+}
+
+backend_fn! {
+/// Checks whether an announcement was already recorded for a channel.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `chat_channel_id` - The chat channel ID
+/// * `kind` - The announcement kind
+/// * `reference_key` - What the announcement is about
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn chat_notification_exists(
+    conn: &mut _,
+    chat_channel_id: i64,
+    kind: &str,
+    reference_key: &str,
+) -> Result<bool, PersistenceError> {
+    let count: i64 = chat_notification_log::table
+        .filter(chat_notification_log::chat_channel_id.eq(chat_channel_id))
+        .filter(chat_notification_log::kind.eq(kind))
+        .filter(chat_notification_log::reference_key.eq(reference_key))
+        .count()
+        .get_result(conn)?;
+
+    Ok(count > 0)
+}
+}
+
+backend_fn! {
+/// Retrieves the most recent announcement of a kind recorded for a channel.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `chat_channel_id` - The chat channel ID
+/// * `kind` - The announcement kind
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+/// Returns `Ok(None)` if nothing of that kind was recorded.
+pub fn get_latest_chat_notification(
+    conn: &mut _,
+    chat_channel_id: i64,
+    kind: &str,
+) -> Result<Option<ChatNotificationLogData>, PersistenceError> {
+    let row: Option<ChatNotificationLogRow> = chat_notification_log::table
+        .filter(chat_notification_log::chat_channel_id.eq(chat_channel_id))
+        .filter(chat_notification_log::kind.eq(kind))
+        .order_by(chat_notification_log::chat_notification_id.desc())
+        .select(ChatNotificationLogRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(ChatNotificationLogData::from))
+}
+}
+
+backend_fn! {
+/// Lists recorded chat announcements, newest first.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `chat_channel_id` - Restrict to one channel, or `None` for all
+/// * `limit` - Maximum number of entries to return
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_chat_notifications(
+    conn: &mut _,
+    chat_channel_id: Option<i64>,
+    limit: u32,
+) -> Result<Vec<ChatNotificationLogData>, PersistenceError> {
+    debug!(?chat_channel_id, limit, "Listing chat notifications");
+
+    let mut query = chat_notification_log::table
+        .select(ChatNotificationLogRow::as_select())
+        .into_boxed();
+    if let Some(id) = chat_channel_id {
+        query = query.filter(chat_notification_log::chat_channel_id.eq(id));
+    }
+
+    let rows: Vec<ChatNotificationLogRow> = query
+        .order_by(chat_notification_log::chat_notification_id.desc())
+        .limit(i64::from(limit))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(ChatNotificationLogData::from).collect())
+}
+}
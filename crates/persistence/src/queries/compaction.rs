@@ -0,0 +1,403 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Audit log compaction queries.
+//!
+//! A compaction moves a completed bid year's area events into
+//! `archived_audit_events`. Only events nothing else references are
+//! moved: snapshots, canonical records, ledgers, rollback bookkeeping, and
+//! undelivered outbox rows all keep their events in the live log.
+//!
+//! Archived events are chained by SHA-256 in event order, starting from
+//! [`GENESIS_CHAIN_HASH`]. Each row stores its link and the compaction
+//! stores the last, so the archive can be checked against it.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use num_traits::ToPrimitive;
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+
+use crate::data_models::{
+    ActionData, ArchivableEventData, AuditArchiveVerification, AuditCompactionData,
+};
+use crate::diesel_schema::{
+    archived_audit_events, audit_compactions, audit_event_annotations, audit_event_correlations,
+    audit_event_dependencies, audit_events, bid_status_history, canonical_area_membership,
+    canonical_bid_order, canonical_bid_windows, canonical_eligibility, canonical_overrides,
+    event_outbox, overbid_requests, round_sign_offs, scope_freezes, self_bid_requests,
+    state_snapshots, superseded_audit_events, user_anonymizations, user_initials_aliases,
+    user_merges, waitlist_offers, waitlist_slots, webhook_dead_letters,
+};
+use crate::error::PersistenceError;
+
+/// The chain hash before the first archived event.
+pub const GENESIS_CHAIN_HASH: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Archived rows read per query when verifying a chain.
+const VERIFY_PAGE_SIZE: i64 = 500;
+
+/// The columns of an audit event, in [`AuditEventRow`] order, for either
+/// the live or the archive table.
+macro_rules! event_columns {
+    ($table:ident) => {
+        (
+            $table::event_id,
+            $table::bid_year_id,
+            $table::area_id,
+            $table::year,
+            $table::area_code,
+            $table::actor_operator_id,
+            $table::actor_login_name,
+            $table::actor_display_name,
+            $table::actor_json,
+            $table::cause_json,
+            $table::action_json,
+            $table::before_snapshot_json,
+            $table::after_snapshot_json,
+            $table::created_at,
+            $table::event_ulid,
+            $table::user_id,
+        )
+    };
+}
+pub(crate) use event_columns;
+
+/// An audit event's columns, as moved to and read from the archive.
+#[derive(Debug, Clone, Queryable)]
+pub struct AuditEventRow {
+    pub event_id: i64,
+    pub bid_year_id: Option<i64>,
+    pub area_id: Option<i64>,
+    pub year: i32,
+    pub area_code: String,
+    pub actor_operator_id: i64,
+    pub actor_login_name: String,
+    pub actor_display_name: String,
+    pub actor_json: String,
+    pub cause_json: String,
+    pub action_json: String,
+    pub before_snapshot_json: String,
+    pub after_snapshot_json: String,
+    pub created_at: Option<String>,
+    pub event_ulid: Option<String>,
+    pub user_id: Option<i64>,
+}
+
+impl AuditEventRow {
+    /// Returns this event's link in the archive chain.
+    ///
+    /// Each column is length-prefixed, so no two rows hash the same
+    /// bytes. Missing values hash differently from empty ones, except a
+    /// missing user, which is left out so events archived before users
+    /// were recorded keep their links.
+    #[must_use]
+    pub fn chain_hash(&self, previous: &str) -> String {
+        fn field(hasher: &mut Sha256, value: Option<&str>) {
+            match value {
+                Some(value) => {
+                    hasher.update(format!("{}:", value.len()));
+                    hasher.update(value);
+                }
+                None => hasher.update("-"),
+            }
+            hasher.update("\n");
+        }
+
+        let mut hasher: Sha256 = Sha256::new();
+        field(&mut hasher, Some(previous));
+        field(&mut hasher, Some(&self.event_id.to_string()));
+        field(
+            &mut hasher,
+            self.bid_year_id.map(|id| id.to_string()).as_deref(),
+        );
+        field(
+            &mut hasher,
+            self.area_id.map(|id| id.to_string()).as_deref(),
+        );
+        field(&mut hasher, Some(&self.year.to_string()));
+        field(&mut hasher, Some(&self.area_code));
+        field(&mut hasher, Some(&self.actor_operator_id.to_string()));
+        field(&mut hasher, Some(&self.actor_login_name));
+        field(&mut hasher, Some(&self.actor_display_name));
+        field(&mut hasher, Some(&self.actor_json));
+        field(&mut hasher, Some(&self.cause_json));
+        field(&mut hasher, Some(&self.action_json));
+        field(&mut hasher, Some(&self.before_snapshot_json));
+        field(&mut hasher, Some(&self.after_snapshot_json));
+        field(&mut hasher, self.created_at.as_deref());
+        field(&mut hasher, self.event_ulid.as_deref());
+        if let Some(user_id) = self.user_id {
+            field(&mut hasher, Some(&user_id.to_string()));
+        }
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// A compaction row, before conversion.
+type CompactionRow = (i64, i64, i32, Option<i64>, Option<i64>, String, String);
+
+/// Converts a compaction row into its data model.
+fn to_compaction_data(row: CompactionRow) -> AuditCompactionData {
+    let (
+        compaction_id,
+        bid_year_id,
+        archived_event_count,
+        first_event_id,
+        last_event_id,
+        chain_head,
+        created_at,
+    ) = row;
+    AuditCompactionData {
+        compaction_id,
+        bid_year_id,
+        archived_event_count: archived_event_count.to_usize().unwrap_or(0),
+        first_event_id,
+        last_event_id,
+        chain_head,
+        created_at,
+    }
+}
+
+/// Adds every value of a non-nullable event ID column to a set.
+macro_rules! collect_references {
+    ($conn:expr, $set:expr, $table:ident, $column:ident) => {
+        $set.extend($table::table.select($table::$column).load::<i64>($conn)?);
+    };
+}
+
+/// Adds every non-null value of a nullable event ID column to a set.
+macro_rules! collect_nullable_references {
+    ($conn:expr, $set:expr, $table:ident, $column:ident) => {
+        $set.extend(
+            $table::table
+                .filter($table::$column.is_not_null())
+                .select($table::$column.assume_not_null())
+                .load::<i64>($conn)?,
+        );
+    };
+}
+
+backend_fn! {
+/// Lists every audit event another record still refers to.
+///
+/// Undelivered outbox rows count as references, so no event is archived
+/// before the relay has published it.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if a query fails.
+pub fn list_referenced_event_ids(conn: &mut _) -> Result<HashSet<i64>, PersistenceError> {
+    let mut referenced: HashSet<i64> = HashSet::new();
+    collect_references!(conn, referenced, state_snapshots, event_id);
+    collect_references!(conn, referenced, canonical_area_membership, audit_event_id);
+    collect_references!(conn, referenced, canonical_bid_order, audit_event_id);
+    collect_references!(conn, referenced, canonical_bid_windows, audit_event_id);
+    collect_references!(conn, referenced, canonical_eligibility, audit_event_id);
+    collect_references!(conn, referenced, canonical_overrides, audit_event_id);
+    collect_references!(conn, referenced, bid_status_history, audit_event_id);
+    collect_references!(conn, referenced, webhook_dead_letters, audit_event_id);
+    collect_references!(conn, referenced, round_sign_offs, audit_event_id);
+    collect_references!(conn, referenced, scope_freezes, audit_event_id);
+    collect_references!(conn, referenced, waitlist_slots, release_event_id);
+    collect_references!(conn, referenced, waitlist_offers, offer_event_id);
+    collect_nullable_references!(conn, referenced, waitlist_offers, response_event_id);
+    collect_nullable_references!(conn, referenced, overbid_requests, request_event_id);
+    collect_nullable_references!(conn, referenced, overbid_requests, decision_event_id);
+    collect_nullable_references!(conn, referenced, self_bid_requests, request_event_id);
+    collect_nullable_references!(conn, referenced, self_bid_requests, confirm_event_id);
+    collect_references!(conn, referenced, user_merges, merge_event_id);
+    collect_nullable_references!(conn, referenced, user_merges, revert_event_id);
+    collect_references!(conn, referenced, user_initials_aliases, change_event_id);
+    collect_references!(conn, referenced, user_anonymizations, event_id);
+    collect_references!(conn, referenced, audit_event_annotations, event_id);
+    collect_references!(conn, referenced, audit_event_correlations, event_id);
+    collect_references!(conn, referenced, audit_event_dependencies, event_id);
+    collect_references!(conn, referenced, audit_event_dependencies, depends_on_event_id);
+    collect_references!(conn, referenced, superseded_audit_events, event_id);
+    collect_references!(conn, referenced, superseded_audit_events, rollback_event_id);
+    referenced.extend(
+        event_outbox::table
+            .filter(event_outbox::delivered_at.is_null())
+            .select(event_outbox::audit_event_id)
+            .load::<i64>(conn)?,
+    );
+    Ok(referenced)
+}
+}
+
+backend_fn! {
+/// Lists a bid year's area events a compaction can archive.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `referenced` - The events other records refer to
+///
+/// # Returns
+///
+/// The unreferenced area events, oldest first.
+///
+/// # Errors
+///
+/// Returns an error if the query fails or an action cannot be decoded.
+pub fn list_archivable_audit_events(
+    conn: &mut _,
+    bid_year_id: i64,
+    referenced: &HashSet<i64>,
+) -> Result<Vec<ArchivableEventData>, PersistenceError> {
+    let rows: Vec<(i64, String, String)> = audit_events::table
+        .filter(audit_events::bid_year_id.eq(bid_year_id))
+        .filter(audit_events::area_id.is_not_null())
+        .order(audit_events::event_id.asc())
+        .select((
+            audit_events::event_id,
+            audit_events::area_code,
+            audit_events::action_json,
+        ))
+        .load(conn)?;
+
+    rows.into_iter()
+        .filter(|(event_id, _, _)| !referenced.contains(event_id))
+        .map(|(event_id, area_code, action_json)| {
+            let action: ActionData = serde_json::from_str(&action_json)?;
+            Ok(ArchivableEventData {
+                event_id,
+                area_code,
+                action_name: action.name,
+            })
+        })
+        .collect()
+}
+}
+
+backend_fn! {
+/// Returns a bid year's audit log compaction.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+///
+/// # Returns
+///
+/// The compaction, or `None` if the bid year has not been compacted.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_audit_compaction(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Option<AuditCompactionData>, PersistenceError> {
+    let row: Option<CompactionRow> = audit_compactions::table
+        .filter(audit_compactions::bid_year_id.eq(bid_year_id))
+        .select((
+            audit_compactions::compaction_id,
+            audit_compactions::bid_year_id,
+            audit_compactions::archived_event_count,
+            audit_compactions::first_event_id,
+            audit_compactions::last_event_id,
+            audit_compactions::chain_head,
+            audit_compactions::created_at,
+        ))
+        .first(conn)
+        .optional()?;
+    Ok(row.map(to_compaction_data))
+}
+}
+
+backend_fn! {
+/// Lists every audit log compaction, oldest first.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_audit_compactions(conn: &mut _) -> Result<Vec<AuditCompactionData>, PersistenceError> {
+    let rows: Vec<CompactionRow> = audit_compactions::table
+        .order(audit_compactions::compaction_id.asc())
+        .select((
+            audit_compactions::compaction_id,
+            audit_compactions::bid_year_id,
+            audit_compactions::archived_event_count,
+            audit_compactions::first_event_id,
+            audit_compactions::last_event_id,
+            audit_compactions::chain_head,
+            audit_compactions::created_at,
+        ))
+        .load(conn)?;
+    Ok(rows.into_iter().map(to_compaction_data).collect())
+}
+}
+
+backend_fn! {
+/// Recomputes a compaction's hash chain from its archived events.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `compaction` - The compaction to verify
+///
+/// # Errors
+///
+/// Returns an error if the archive cannot be read. A broken chain is
+/// reported in the result instead.
+pub fn verify_audit_archive(
+    conn: &mut _,
+    compaction: &AuditCompactionData,
+) -> Result<AuditArchiveVerification, PersistenceError> {
+    let mut previous: String = GENESIS_CHAIN_HASH.to_string();
+    let mut recomputed_head: String = GENESIS_CHAIN_HASH.to_string();
+    let mut events_checked: usize = 0;
+    let mut broken_event_ids: Vec<i64> = Vec::new();
+    let mut after_event_id: i64 = 0;
+    loop {
+        let rows: Vec<(AuditEventRow, String)> = archived_audit_events::table
+            .filter(archived_audit_events::compaction_id.eq(compaction.compaction_id))
+            .filter(archived_audit_events::event_id.gt(after_event_id))
+            .order(archived_audit_events::event_id.asc())
+            .limit(VERIFY_PAGE_SIZE)
+            .select((
+                event_columns!(archived_audit_events),
+                archived_audit_events::chain_hash,
+            ))
+            .load(conn)?;
+        let Some((last, _)) = rows.last() else { break };
+        after_event_id = last.event_id;
+        events_checked += rows.len();
+
+        // Each link is checked against the stored one before it, so only
+        // the rows that were changed are reported
+        for (row, stored) in rows {
+            let recomputed: String = row.chain_hash(&previous);
+            if recomputed != stored {
+                broken_event_ids.push(row.event_id);
+            }
+            recomputed_head = recomputed;
+            previous = stored;
+        }
+    }
+
+    Ok(AuditArchiveVerification {
+        compaction: compaction.clone(),
+        events_checked,
+        recomputed_head,
+        broken_event_ids,
+    })
+}
+}
@@ -0,0 +1,142 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Crew slot partition queries.
+//!
+//! This module contains queries for the per-round `crew_partitioned` flag
+//! and the daily leave slots allocated to each crew of a partitioned
+//! round.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::data_models::RoundCrewSlotsData;
+use crate::diesel_schema::{round_crew_slots, round_groups, rounds};
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Lists the rounds of a bid year that allocate slots per crew.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_crew_partitioned_rounds(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<i64>, PersistenceError> {
+    let round_ids: Vec<i64> = rounds::table
+        .inner_join(round_groups::table)
+        .filter(round_groups::bid_year_id.eq(bid_year_id))
+        .filter(rounds::crew_partitioned.eq(1))
+        .select(rounds::round_id)
+        .order_by(rounds::round_id.asc())
+        .load(conn)?;
+
+    Ok(round_ids)
+}
+}
+
+backend_fn! {
+/// Lists the per-crew slot allocations of a bid year's rounds, ordered by
+/// round then crew.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_round_crew_slots(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<RoundCrewSlotsData>, PersistenceError> {
+    let rows: Vec<(i64, i32, i32)> = round_crew_slots::table
+        .filter(round_crew_slots::bid_year_id.eq(bid_year_id))
+        .select((
+            round_crew_slots::round_id,
+            round_crew_slots::crew,
+            round_crew_slots::slots_per_day,
+        ))
+        .order_by((round_crew_slots::round_id.asc(), round_crew_slots::crew.asc()))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(round_id, crew, slots_per_day)| RoundCrewSlotsData {
+            round_id,
+            crew,
+            slots_per_day,
+        })
+        .collect())
+}
+}
+
+backend_fn! {
+/// Sets whether a round allocates slots per crew, and each crew's slots.
+///
+/// The round's previous crew allocations are replaced as a whole in one
+/// transaction.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `round_id` - The round ID
+/// * `crew_partitioned` - Whether the round allocates slots per crew
+/// * `crew_slots` - `(crew, slots_per_day)` pairs
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn set_round_crew_slots(
+    conn: &mut _,
+    bid_year_id: i64,
+    round_id: i64,
+    crew_partitioned: bool,
+    crew_slots: &[(i32, i32)],
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::update(rounds::table.filter(rounds::round_id.eq(round_id)))
+            .set(rounds::crew_partitioned.eq(i32::from(crew_partitioned)))
+            .execute(conn)?;
+
+        diesel::delete(round_crew_slots::table.filter(round_crew_slots::round_id.eq(round_id)))
+            .execute(conn)?;
+
+        for (crew, slots_per_day) in crew_slots {
+            diesel::insert_into(round_crew_slots::table)
+                .values((
+                    round_crew_slots::round_id.eq(round_id),
+                    round_crew_slots::bid_year_id.eq(bid_year_id),
+                    round_crew_slots::crew.eq(crew),
+                    round_crew_slots::slots_per_day.eq(slots_per_day),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })?;
+
+    info!(
+        round_id,
+        crew_partitioned,
+        crews = crew_slots.len(),
+        "Round crew slots set"
+    );
+
+    Ok(())
+}
+}
@@ -0,0 +1,265 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Current bidder queries.
+//!
+//! This module tracks whose bid window is active now in each area and
+//! round. The bidder sequence of a round follows its bid windows, ordered
+//! by window start.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::data_models::{CurrentBidderData, CurrentBidderStateData, RoundBidderData};
+use crate::diesel_schema::{bid_windows, current_bidders, rounds, users};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for current bidder rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = current_bidders)]
+struct CurrentBidderRow {
+    current_bidder_id: i64,
+    bid_year_id: i64,
+    area_id: i64,
+    round_id: i64,
+    user_id: Option<i64>,
+    became_current_at: String,
+}
+
+impl From<CurrentBidderRow> for CurrentBidderStateData {
+    fn from(row: CurrentBidderRow) -> Self {
+        Self {
+            current_bidder_id: row.current_bidder_id,
+            bid_year_id: row.bid_year_id,
+            area_id: row.area_id,
+            round_id: row.round_id,
+            user_id: row.user_id,
+            became_current_at: row.became_current_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Gets the tracked current bidder of a round in an area.
+///
+/// Returns `None` if the round has not started.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_current_bidder_state(
+    conn: &mut _,
+    area_id: i64,
+    round_id: i64,
+) -> Result<Option<CurrentBidderStateData>, PersistenceError> {
+    let row: Option<CurrentBidderRow> = current_bidders::table
+        .filter(current_bidders::area_id.eq(area_id))
+        .filter(current_bidders::round_id.eq(round_id))
+        .select(CurrentBidderRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(CurrentBidderStateData::from))
+}
+}
+
+backend_fn! {
+/// Lists a round's bidders in the order they bid, with their initials and
+/// windows.
+///
+/// Bidders are ordered by window start, then by window ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_round_bidders(
+    conn: &mut _,
+    area_id: i64,
+    round_id: i64,
+) -> Result<Vec<RoundBidderData>, PersistenceError> {
+    let rows: Vec<(i64, String, String, String)> = bid_windows::table
+        .inner_join(users::table)
+        .filter(bid_windows::area_id.eq(area_id))
+        .filter(bid_windows::round_id.eq(round_id))
+        .select((
+            bid_windows::user_id,
+            users::initials,
+            bid_windows::window_start_datetime,
+            bid_windows::window_end_datetime,
+        ))
+        .order_by((
+            bid_windows::window_start_datetime.asc(),
+            bid_windows::bid_window_id.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(user_id, initials, window_start_datetime, window_end_datetime)| RoundBidderData {
+                user_id,
+                initials,
+                window_start_datetime,
+                window_end_datetime,
+            },
+        )
+        .collect())
+}
+}
+
+backend_fn! {
+/// Records the current bidder of a round in an area.
+///
+/// Replaces any previously tracked bidder for the round.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+/// * `user_id` - The new current bidder, or `None` when the round is complete
+/// * `became_current_at` - When the handover happened (ISO 8601 format)
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn set_current_bidder(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    round_id: i64,
+    user_id: Option<i64>,
+    became_current_at: &str,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        let rows_affected: usize = diesel::update(
+            current_bidders::table
+                .filter(current_bidders::area_id.eq(area_id))
+                .filter(current_bidders::round_id.eq(round_id)),
+        )
+        .set((
+            current_bidders::user_id.eq(user_id),
+            current_bidders::became_current_at.eq(became_current_at),
+        ))
+        .execute(conn)?;
+
+        if rows_affected == 0 {
+            diesel::insert_into(current_bidders::table)
+                .values((
+                    current_bidders::bid_year_id.eq(bid_year_id),
+                    current_bidders::area_id.eq(area_id),
+                    current_bidders::round_id.eq(round_id),
+                    current_bidders::user_id.eq(user_id),
+                    current_bidders::became_current_at.eq(became_current_at),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })?;
+
+    info!(area_id, round_id, ?user_id, "Current bidder updated");
+
+    Ok(())
+}
+}
+
+/// Current bidder columns joined with round and user, in select order.
+type CurrentBidderJoinRow = (i64, i64, i64, i32, String, i64, String, String);
+
+backend_fn! {
+/// Gets the bidder whose window is active now in an area.
+///
+/// When several rounds have a current bidder, the lowest-numbered round
+/// is the one in progress. Returns `None` if no round has a current bidder.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_current_bidder(
+    conn: &mut _,
+    area_id: i64,
+) -> Result<Option<CurrentBidderData>, PersistenceError> {
+    let row: Option<CurrentBidderJoinRow> = current_bidders::table
+        .inner_join(rounds::table)
+        .inner_join(users::table)
+        .filter(current_bidders::area_id.eq(area_id))
+        .select((
+            current_bidders::bid_year_id,
+            current_bidders::area_id,
+            current_bidders::round_id,
+            rounds::round_number,
+            rounds::name,
+            users::user_id,
+            users::initials,
+            current_bidders::became_current_at,
+        ))
+        .order_by(rounds::round_number.asc())
+        .first(conn)
+        .optional()?;
+
+    let Some((
+        bid_year_id,
+        area_id,
+        round_id,
+        round_number,
+        round_name,
+        user_id,
+        initials,
+        became_current_at,
+    )) = row
+    else {
+        return Ok(None);
+    };
+
+    let window: Option<(String, String)> = bid_windows::table
+        .filter(bid_windows::area_id.eq(area_id))
+        .filter(bid_windows::round_id.eq(round_id))
+        .filter(bid_windows::user_id.eq(user_id))
+        .select((
+            bid_windows::window_start_datetime,
+            bid_windows::window_end_datetime,
+        ))
+        .first(conn)
+        .optional()?;
+
+    let (window_start_datetime, window_end_datetime) = window.unzip();
+
+    Ok(Some(CurrentBidderData {
+        bid_year_id,
+        area_id,
+        round_id,
+        round_number,
+        round_name,
+        user_id,
+        initials,
+        window_start_datetime,
+        window_end_datetime,
+        became_current_at,
+    }))
+}
+}
@@ -0,0 +1,337 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Eligibility queries.
+//!
+//! This module stores a bid year's eligibility exceptions, which are always
+//! replaced as a whole, and keeps the derived eligibility in
+//! `canonical_eligibility` current. `derived_can_bid` holds what the rules
+//! derive; `can_bid` holds the effective value, which only differs from the
+//! derived value while an override is in force.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+use zab_bid::{DerivedEligibility, EligibilityException, EligibilitySubject, derive_eligibility};
+use zab_bid_domain::UserType;
+
+use crate::data_models::{
+    EligibilityExceptionData, EligibilityExceptionSpecData, UserEligibilityData,
+};
+use crate::diesel_schema::{
+    areas, canonical_area_membership, canonical_eligibility, eligibility_exceptions, users,
+};
+use crate::error::PersistenceError;
+
+/// Parses a stored user type.
+fn parse_user_type(user_type: &str) -> Result<UserType, PersistenceError> {
+    UserType::parse(user_type).map_err(|e| PersistenceError::ReconstructionError(e.to_string()))
+}
+
+backend_fn! {
+/// Lists the eligibility exceptions of a bid year.
+///
+/// Bid-year-wide exceptions come first, then area exceptions by area.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_eligibility_exceptions(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<EligibilityExceptionData>, PersistenceError> {
+    let rows: Vec<(i64, i64, String, Option<i64>, i32)> = eligibility_exceptions::table
+        .filter(eligibility_exceptions::bid_year_id.eq(bid_year_id))
+        .order_by((
+            eligibility_exceptions::area_id.asc(),
+            eligibility_exceptions::eligibility_exception_id.asc(),
+        ))
+        .select((
+            eligibility_exceptions::eligibility_exception_id,
+            eligibility_exceptions::bid_year_id,
+            eligibility_exceptions::user_type,
+            eligibility_exceptions::area_id,
+            eligibility_exceptions::can_bid,
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(eligibility_exception_id, bid_year_id, user_type, area_id, can_bid)| {
+                EligibilityExceptionData {
+                    eligibility_exception_id,
+                    bid_year_id,
+                    user_type,
+                    area_id,
+                    can_bid: can_bid != 0,
+                }
+            },
+        )
+        .collect())
+}
+}
+
+backend_fn! {
+/// Replaces the eligibility exceptions of a bid year.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `exceptions` - The new exceptions
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn replace_eligibility_exceptions(
+    conn: &mut _,
+    bid_year_id: i64,
+    exceptions: &[EligibilityExceptionSpecData],
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(
+            eligibility_exceptions::table
+                .filter(eligibility_exceptions::bid_year_id.eq(bid_year_id)),
+        )
+        .execute(conn)?;
+
+        for exception in exceptions {
+            diesel::insert_into(eligibility_exceptions::table)
+                .values((
+                    eligibility_exceptions::bid_year_id.eq(bid_year_id),
+                    eligibility_exceptions::user_type.eq(&exception.user_type),
+                    eligibility_exceptions::area_id.eq(exception.area_id),
+                    eligibility_exceptions::can_bid.eq(i32::from(exception.can_bid)),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })?;
+
+    info!(
+        bid_year_id,
+        count = exceptions.len(),
+        "Eligibility exceptions replaced"
+    );
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Derives the eligibility of every user in a bid year.
+///
+/// Once a bid year is canonicalized, a user's area is their canonical area
+/// membership, so area overrides are taken into account.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Returns
+///
+/// Each user's derived eligibility, keyed by user ID.
+///
+/// # Errors
+///
+/// Returns an error if a query fails or a stored user type is invalid.
+pub fn derive_bid_year_eligibility(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<BTreeMap<i64, DerivedEligibility>, PersistenceError> {
+    let exception_rows: Vec<(String, Option<i64>, i32)> = eligibility_exceptions::table
+        .filter(eligibility_exceptions::bid_year_id.eq(bid_year_id))
+        .select((
+            eligibility_exceptions::user_type,
+            eligibility_exceptions::area_id,
+            eligibility_exceptions::can_bid,
+        ))
+        .load(conn)?;
+    let exceptions: Vec<EligibilityException> = exception_rows
+        .into_iter()
+        .map(|(user_type, area_id, can_bid)| {
+            Ok(EligibilityException {
+                user_type: parse_user_type(&user_type)?,
+                area_id,
+                can_bid: can_bid != 0,
+            })
+        })
+        .collect::<Result<_, PersistenceError>>()?;
+
+    let system_areas: BTreeSet<i64> = areas::table
+        .filter(areas::bid_year_id.eq(bid_year_id))
+        .filter(areas::is_system_area.eq(1))
+        .select(areas::area_id)
+        .load::<i64>(conn)?
+        .into_iter()
+        .collect();
+
+    let canonical_areas: BTreeMap<i64, i64> = canonical_area_membership::table
+        .filter(canonical_area_membership::bid_year_id.eq(bid_year_id))
+        .select((
+            canonical_area_membership::user_id,
+            canonical_area_membership::area_id,
+        ))
+        .load::<(i64, i64)>(conn)?
+        .into_iter()
+        .collect();
+
+    let user_rows: Vec<(i64, String, i32, i64)> = users::table
+        .filter(users::bid_year_id.eq(bid_year_id))
+        .select((
+            users::user_id,
+            users::user_type,
+            users::excluded_from_bidding,
+            users::area_id,
+        ))
+        .load(conn)?;
+
+    user_rows
+        .into_iter()
+        .map(|(user_id, user_type, excluded_from_bidding, area_id)| {
+            let area_id: i64 = canonical_areas.get(&user_id).copied().unwrap_or(area_id);
+            let subject: EligibilitySubject = EligibilitySubject {
+                user_type: parse_user_type(&user_type)?,
+                excluded_from_bidding: excluded_from_bidding != 0,
+                area_id,
+                in_system_area: system_areas.contains(&area_id),
+            };
+            Ok((user_id, derive_eligibility(&subject, &exceptions)))
+        })
+        .collect()
+}
+}
+
+backend_fn! {
+/// Records newly derived eligibility against a bid year's canonical users.
+///
+/// A user's effective eligibility follows the derived value unless it is
+/// overridden, in which case only the derived value is updated. Callers
+/// run this inside a transaction together with the derivation.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `derived` - Each user's derived eligibility, keyed by user ID
+///
+/// # Returns
+///
+/// The number of users whose derived eligibility changed.
+///
+/// # Errors
+///
+/// Returns an error if a query or write fails.
+pub fn apply_derived_eligibility(
+    conn: &mut _,
+    bid_year_id: i64,
+    derived: &BTreeMap<i64, DerivedEligibility>,
+) -> Result<usize, PersistenceError> {
+    let current: Vec<(i64, i32, i32)> = canonical_eligibility::table
+        .filter(canonical_eligibility::bid_year_id.eq(bid_year_id))
+        .select((
+            canonical_eligibility::user_id,
+            canonical_eligibility::derived_can_bid,
+            canonical_eligibility::is_overridden,
+        ))
+        .load(conn)?;
+
+    let mut changed: usize = 0;
+    for (user_id, derived_can_bid, is_overridden) in current {
+        let Some(eligibility) = derived.get(&user_id) else {
+            continue;
+        };
+        let can_bid: i32 = i32::from(eligibility.can_bid);
+        if can_bid == derived_can_bid {
+            continue;
+        }
+
+        let row = canonical_eligibility::table
+            .filter(canonical_eligibility::bid_year_id.eq(bid_year_id))
+            .filter(canonical_eligibility::user_id.eq(user_id));
+        if is_overridden == 0 {
+            diesel::update(row)
+                .set((
+                    canonical_eligibility::derived_can_bid.eq(can_bid),
+                    canonical_eligibility::can_bid.eq(can_bid),
+                ))
+                .execute(conn)?;
+        } else {
+            diesel::update(row)
+                .set(canonical_eligibility::derived_can_bid.eq(can_bid))
+                .execute(conn)?;
+        }
+        changed += 1;
+    }
+
+    if changed > 0 {
+        info!(bid_year_id, changed, "Derived eligibility updated");
+    }
+
+    Ok(changed)
+}
+}
+
+backend_fn! {
+/// Lists the canonical eligibility of every user in a bid year.
+///
+/// Users are ordered by initials.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_user_eligibility(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<UserEligibilityData>, PersistenceError> {
+    let rows: Vec<(i64, String, i32, i32, i32, Option<String>)> = canonical_eligibility::table
+        .inner_join(users::table)
+        .filter(canonical_eligibility::bid_year_id.eq(bid_year_id))
+        .order_by(users::initials.asc())
+        .select((
+            canonical_eligibility::user_id,
+            users::initials,
+            canonical_eligibility::derived_can_bid,
+            canonical_eligibility::can_bid,
+            canonical_eligibility::is_overridden,
+            canonical_eligibility::override_reason,
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(user_id, initials, derived_can_bid, can_bid, is_overridden, override_reason)| {
+                UserEligibilityData {
+                    user_id,
+                    initials,
+                    derived_can_bid: derived_can_bid != 0,
+                    can_bid: can_bid != 0,
+                    is_overridden: is_overridden != 0,
+                    override_reason,
+                }
+            },
+        )
+        .collect())
+}
+}
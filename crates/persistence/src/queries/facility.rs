@@ -0,0 +1,116 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Facility configuration queries.
+//!
+//! This module contains queries for the single facility configuration row
+//! whose timezone, accrual rates, and carryover cap bid years inherit.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::data_models::FacilityData;
+use crate::diesel_schema::facility;
+use crate::error::PersistenceError;
+
+/// The ID of the only facility row.
+const FACILITY_ID: i64 = 1;
+
+/// A facility row as selected from the database.
+type FacilityRow = (String, String, String, i32, i32, i32, i32, String);
+
+backend_fn! {
+/// Gets the facility configuration, if one has been set.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_facility(conn: &mut _) -> Result<Option<FacilityData>, PersistenceError> {
+    let row: Option<FacilityRow> = facility::table
+        .filter(facility::facility_id.eq(FACILITY_ID))
+        .select((
+            facility::identifier,
+            facility::name,
+            facility::default_timezone,
+            facility::junior_accrual_hours,
+            facility::mid_accrual_hours,
+            facility::senior_accrual_hours,
+            facility::max_carryover_hours,
+            facility::updated_at,
+        ))
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(
+        |(
+            identifier,
+            name,
+            default_timezone,
+            junior_accrual_hours,
+            mid_accrual_hours,
+            senior_accrual_hours,
+            max_carryover_hours,
+            updated_at,
+        )| FacilityData {
+            identifier,
+            name,
+            default_timezone,
+            junior_accrual_hours,
+            mid_accrual_hours,
+            senior_accrual_hours,
+            max_carryover_hours,
+            updated_at,
+        },
+    ))
+}
+}
+
+backend_fn! {
+/// Sets the facility configuration, replacing any previous configuration.
+///
+/// The `updated_at` field of `data` is ignored; the database records the
+/// time of the write.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `data` - The new configuration
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn set_facility(conn: &mut _, data: &FacilityData) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(facility::table).execute(conn)?;
+
+        diesel::insert_into(facility::table)
+            .values((
+                facility::facility_id.eq(FACILITY_ID),
+                facility::identifier.eq(&data.identifier),
+                facility::name.eq(&data.name),
+                facility::default_timezone.eq(&data.default_timezone),
+                facility::junior_accrual_hours.eq(data.junior_accrual_hours),
+                facility::mid_accrual_hours.eq(data.mid_accrual_hours),
+                facility::senior_accrual_hours.eq(data.senior_accrual_hours),
+                facility::max_carryover_hours.eq(data.max_carryover_hours),
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    })?;
+
+    info!(identifier = %data.identifier, "Facility configuration set");
+
+    Ok(())
+}
+}
@@ -0,0 +1,126 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Feature flag queries.
+//!
+//! This module contains queries for the per-bid-year feature flags that
+//! switch optional subsystems on or off. A bid year without a row for a
+//! flag uses the flag's default.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::data_models::FeatureFlagData;
+use crate::diesel_schema::feature_flags;
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Gets whether a feature flag is enabled for a bid year, if it has been
+/// set.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `flag` - The flag name
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_feature_flag(
+    conn: &mut _,
+    bid_year_id: i64,
+    flag: &str,
+) -> Result<Option<bool>, PersistenceError> {
+    let enabled: Option<i32> = feature_flags::table
+        .filter(feature_flags::bid_year_id.eq(bid_year_id))
+        .filter(feature_flags::flag.eq(flag))
+        .select(feature_flags::enabled)
+        .first(conn)
+        .optional()?;
+
+    Ok(enabled.map(|enabled| enabled != 0))
+}
+}
+
+backend_fn! {
+/// Lists the feature flags set for a bid year, ordered by name.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_feature_flags(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<FeatureFlagData>, PersistenceError> {
+    let rows: Vec<(String, i32)> = feature_flags::table
+        .filter(feature_flags::bid_year_id.eq(bid_year_id))
+        .order(feature_flags::flag.asc())
+        .select((feature_flags::flag, feature_flags::enabled))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(flag, enabled)| FeatureFlagData {
+            bid_year_id,
+            flag,
+            enabled: enabled != 0,
+        })
+        .collect())
+}
+}
+
+backend_fn! {
+/// Sets a feature flag for a bid year, replacing any previous setting.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `flag` - The flag name
+/// * `enabled` - Whether the flag is on
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn set_feature_flag(
+    conn: &mut _,
+    bid_year_id: i64,
+    flag: &str,
+    enabled: bool,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(
+            feature_flags::table
+                .filter(feature_flags::bid_year_id.eq(bid_year_id))
+                .filter(feature_flags::flag.eq(flag)),
+        )
+        .execute(conn)?;
+
+        diesel::insert_into(feature_flags::table)
+            .values((
+                feature_flags::bid_year_id.eq(bid_year_id),
+                feature_flags::flag.eq(flag),
+                feature_flags::enabled.eq(i32::from(enabled)),
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    })?;
+
+    info!(bid_year_id, flag, enabled, "Feature flag set");
+
+    Ok(())
+}
+}
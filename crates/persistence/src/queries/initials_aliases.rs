@@ -0,0 +1,110 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Initials alias queries.
+//!
+//! This module contains backend-agnostic queries for the initials users
+//! held before an initials change, and for resolving either value to the
+//! same user.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+
+use crate::data_models::InitialsAliasData;
+use crate::diesel_schema::{user_initials_aliases, users};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for initials alias rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = user_initials_aliases)]
+struct InitialsAliasRow {
+    alias_id: i64,
+    bid_year_id: i64,
+    user_id: i64,
+    initials: String,
+    effective_date: String,
+    change_event_id: i64,
+    created_at: String,
+}
+
+impl From<InitialsAliasRow> for InitialsAliasData {
+    fn from(row: InitialsAliasRow) -> Self {
+        Self {
+            alias_id: row.alias_id,
+            bid_year_id: row.bid_year_id,
+            user_id: row.user_id,
+            initials: row.initials,
+            effective_date: row.effective_date,
+            change_event_id: row.change_event_id,
+            created_at: row.created_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Lists the initials a user held before their current ones, oldest first.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_initials_aliases(
+    conn: &mut _,
+    user_id: i64,
+) -> Result<Vec<InitialsAliasData>, PersistenceError> {
+    let rows: Vec<InitialsAliasRow> = user_initials_aliases::table
+        .filter(user_initials_aliases::user_id.eq(user_id))
+        .order_by(user_initials_aliases::alias_id.asc())
+        .select(InitialsAliasRow::as_select())
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(InitialsAliasData::from).collect())
+}
+}
+
+backend_fn! {
+/// Resolves initials to the user of a bid year who holds or held them.
+///
+/// A user currently holding the initials takes precedence over an alias.
+/// When several users retired the same initials, the most recent change
+/// wins.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `initials` - Current or retired initials
+///
+/// # Returns
+///
+/// The user's canonical ID, or `None` if nobody in the bid year holds or
+/// held the initials.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn resolve_user_initials(
+    conn: &mut _,
+    bid_year_id: i64,
+    initials: &str,
+) -> Result<Option<i64>, PersistenceError> {
+    let current: Option<i64> = users::table
+        .filter(users::bid_year_id.eq(bid_year_id))
+        .filter(users::initials.eq(initials))
+        .select(users::user_id)
+        .first(conn)
+        .optional()?;
+    if current.is_some() {
+        return Ok(current);
+    }
+
+    Ok(user_initials_aliases::table
+        .filter(user_initials_aliases::bid_year_id.eq(bid_year_id))
+        .filter(user_initials_aliases::initials.eq(initials))
+        .order_by(user_initials_aliases::alias_id.desc())
+        .select(user_initials_aliases::user_id)
+        .first(conn)
+        .optional()?)
+}
+}
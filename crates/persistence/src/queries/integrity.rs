@@ -0,0 +1,257 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Integrity check queries.
+//!
+//! These read the audit log and snapshots as raw rows, so a damaged row is
+//! recorded as a discrepancy instead of failing the whole check. The checks
+//! themselves are orchestrated by `Persistence::verify_integrity`.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use zab_bid::State;
+use zab_bid_domain::{Area, BidYear, User};
+
+use crate::data_models::{ActionData, ActorData, CauseData, IntegrityReport, StateSnapshotData};
+use crate::diesel_schema::{audit_events, state_snapshots};
+use crate::error::PersistenceError;
+
+/// An audit event as stored, before any column is decoded.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = audit_events)]
+pub struct AuditIntegrityRow {
+    pub event_id: i64,
+    bid_year_id: Option<i64>,
+    area_id: Option<i64>,
+    actor_json: String,
+    cause_json: String,
+    action_json: String,
+    before_snapshot_json: String,
+    after_snapshot_json: String,
+    pub created_at: Option<String>,
+}
+
+impl AuditIntegrityRow {
+    /// Records every problem with this event.
+    ///
+    /// Event IDs are assigned in commit order, so an event must not be
+    /// older than the one before it.
+    pub fn check(&self, previous_created_at: Option<&str>, report: &mut IntegrityReport) {
+        let subject: String = format!("audit event {}", self.event_id);
+        let columns: [(&str, Option<String>); 5] = [
+            ("actor", decode_error::<ActorData>(&self.actor_json)),
+            ("cause", decode_error::<CauseData>(&self.cause_json)),
+            ("action", decode_error::<ActionData>(&self.action_json)),
+            (
+                "before snapshot",
+                decode_error::<StateSnapshotData>(&self.before_snapshot_json),
+            ),
+            (
+                "after snapshot",
+                decode_error::<StateSnapshotData>(&self.after_snapshot_json),
+            ),
+        ];
+        for (column, error) in columns {
+            if let Some(error) = error {
+                report.record(
+                    "audit_log",
+                    &subject,
+                    format!("{column} does not decode: {error}"),
+                );
+            }
+        }
+
+        if self.area_id.is_some() && self.bid_year_id.is_none() {
+            report.record("audit_log", &subject, "area scope without a bid year");
+        }
+        if let (Some(previous), Some(created_at)) = (previous_created_at, &self.created_at)
+            && created_at.as_str() < previous
+        {
+            report.record(
+                "audit_log",
+                &subject,
+                format!("created at {created_at}, before the preceding event ({previous})"),
+            );
+        }
+    }
+}
+
+/// Returns why `json` does not decode as `T`, if it does not.
+fn decode_error<T: DeserializeOwned>(json: &str) -> Option<String> {
+    serde_json::from_str::<T>(json).err().map(|e| e.to_string())
+}
+
+/// A snapshot's scope alongside the scope of the event it was taken at.
+#[derive(Queryable)]
+#[allow(clippy::struct_field_names)]
+pub struct SnapshotScopeRow {
+    pub snapshot_id: i64,
+    bid_year_id: i64,
+    area_id: i64,
+    event_id: i64,
+    event_bid_year_id: Option<i64>,
+    event_area_id: Option<i64>,
+}
+
+impl SnapshotScopeRow {
+    /// Records a problem if the snapshot and its event disagree on scope.
+    pub fn check(&self, report: &mut IntegrityReport) {
+        if self.event_bid_year_id != Some(self.bid_year_id)
+            || self.event_area_id != Some(self.area_id)
+        {
+            report.record(
+                "snapshots",
+                format!("snapshot {}", self.snapshot_id),
+                format!(
+                    "scoped to bid year {} area {}, but audit event {} is not",
+                    self.bid_year_id, self.area_id, self.event_id
+                ),
+            );
+        }
+    }
+}
+
+/// Records every user that differs between an area's latest snapshot and
+/// its canonical users.
+///
+/// Users are matched by initials. Canonical IDs are not compared, since a
+/// snapshot may be taken before the users in it are assigned one.
+pub fn reconcile_area(
+    subject: &str,
+    snapshot: &State,
+    canonical: &State,
+    report: &mut IntegrityReport,
+) {
+    let by_initials = |state: &State| -> BTreeMap<String, User> {
+        state
+            .users
+            .values()
+            .map(|user| {
+                let mut user: User = user.clone();
+                user.user_id = None;
+                user.bid_year = BidYear::new(user.bid_year.year());
+                user.area = Area::new(user.area.id());
+                (user.initials.value().to_string(), user)
+            })
+            .collect()
+    };
+    let snapshot_users: BTreeMap<String, User> = by_initials(snapshot);
+    let canonical_users: BTreeMap<String, User> = by_initials(canonical);
+
+    for (initials, user) in &snapshot_users {
+        match canonical_users.get(initials) {
+            None => report.record(
+                "canonical_state",
+                subject,
+                format!("{initials} is in the latest snapshot but not the users table"),
+            ),
+            Some(canonical_user) if canonical_user != user => report.record(
+                "canonical_state",
+                subject,
+                format!("{initials} differs between the latest snapshot and the users table"),
+            ),
+            Some(_) => {}
+        }
+    }
+    for initials in canonical_users.keys() {
+        if !snapshot_users.contains_key(initials) {
+            report.record(
+                "canonical_state",
+                subject,
+                format!("{initials} is in the users table but not the latest snapshot"),
+            );
+        }
+    }
+}
+
+backend_fn! {
+/// Lists one page of audit events, undecoded, in `event_id` order.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `after_event_id` - Only events after this ID are returned
+/// * `limit` - Maximum number of events to return
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_audit_integrity_rows(
+    conn: &mut _,
+    after_event_id: i64,
+    limit: i64,
+) -> Result<Vec<AuditIntegrityRow>, PersistenceError> {
+    Ok(audit_events::table
+        .filter(audit_events::event_id.gt(after_event_id))
+        .order(audit_events::event_id.asc())
+        .limit(limit)
+        .select(AuditIntegrityRow::as_select())
+        .load::<AuditIntegrityRow>(conn)?)
+}
+}
+
+backend_fn! {
+/// Lists one page of snapshot scopes in `snapshot_id` order.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `after_snapshot_id` - Only snapshots after this ID are returned
+/// * `limit` - Maximum number of snapshots to return
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_snapshot_scopes(
+    conn: &mut _,
+    after_snapshot_id: i64,
+    limit: i64,
+) -> Result<Vec<SnapshotScopeRow>, PersistenceError> {
+    Ok(state_snapshots::table
+        .inner_join(audit_events::table)
+        .filter(state_snapshots::snapshot_id.gt(after_snapshot_id))
+        .order(state_snapshots::snapshot_id.asc())
+        .limit(limit)
+        .select((
+            state_snapshots::snapshot_id,
+            state_snapshots::bid_year_id,
+            state_snapshots::area_id,
+            state_snapshots::event_id,
+            audit_events::bid_year_id,
+            audit_events::area_id,
+        ))
+        .load::<SnapshotScopeRow>(conn)?)
+}
+}
+
+backend_fn! {
+/// Gets the ID of the latest audit event scoped to an area, if any.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_latest_area_event_id(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+) -> Result<Option<i64>, PersistenceError> {
+    Ok(audit_events::table
+        .filter(audit_events::bid_year_id.eq(bid_year_id))
+        .filter(audit_events::area_id.eq(area_id))
+        .select(diesel::dsl::max(audit_events::event_id))
+        .first::<Option<i64>>(conn)?)
+}
+}
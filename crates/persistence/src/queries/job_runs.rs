@@ -0,0 +1,91 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Background job run queries.
+//!
+//! This module records the last run of each server background job so
+//! operators can see when periodic work last happened and whether it
+//! succeeded. Only the latest run per job is kept.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+
+use crate::data_models::JobRunData;
+use crate::diesel_schema::job_runs;
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Lists the last run of every job that has run, ordered by name.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_job_runs(conn: &mut _) -> Result<Vec<JobRunData>, PersistenceError> {
+    let rows: Vec<(String, String, String, i32, i64, Option<String>)> = job_runs::table
+        .order(job_runs::job_name.asc())
+        .select((
+            job_runs::job_name,
+            job_runs::started_at,
+            job_runs::finished_at,
+            job_runs::succeeded,
+            job_runs::duration_ms,
+            job_runs::message,
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(job_name, started_at, finished_at, succeeded, duration_ms, message)| JobRunData {
+                job_name,
+                started_at,
+                finished_at,
+                succeeded: succeeded != 0,
+                duration_ms,
+                message,
+            },
+        )
+        .collect())
+}
+}
+
+backend_fn! {
+/// Records a job's latest run, replacing its previous one.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `run` - The run to record
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn record_job_run(conn: &mut _, run: &JobRunData) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(job_runs::table.filter(job_runs::job_name.eq(&run.job_name)))
+            .execute(conn)?;
+
+        diesel::insert_into(job_runs::table)
+            .values((
+                job_runs::job_name.eq(&run.job_name),
+                job_runs::started_at.eq(&run.started_at),
+                job_runs::finished_at.eq(&run.finished_at),
+                job_runs::succeeded.eq(i32::from(run.succeeded)),
+                job_runs::duration_ms.eq(run.duration_ms),
+                job_runs::message.eq(run.message.as_deref()),
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    })
+}
+}
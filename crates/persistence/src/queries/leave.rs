@@ -0,0 +1,185 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Leave bid queries.
+//!
+//! This module contains backend-agnostic queries for awarded leave and
+//! the per-day leave counts used by coverage reporting.
+
+use std::collections::BTreeMap;
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::debug;
+
+use crate::data_models::{DailyLeaveCountData, LeaveBidData};
+use crate::diesel_schema::{leave_bids, users};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for leave bid rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = leave_bids)]
+struct LeaveBidRow {
+    leave_bid_id: i64,
+    bid_year_id: i64,
+    area_id: i64,
+    user_id: i64,
+    round_id: i64,
+    leave_date: String,
+    hours: i32,
+    status: String,
+    created_at: String,
+    on_behalf_of: Option<String>,
+    received_via: Option<String>,
+}
+
+impl From<LeaveBidRow> for LeaveBidData {
+    fn from(row: LeaveBidRow) -> Self {
+        Self {
+            leave_bid_id: row.leave_bid_id,
+            bid_year_id: row.bid_year_id,
+            area_id: row.area_id,
+            user_id: row.user_id,
+            round_id: row.round_id,
+            leave_date: row.leave_date,
+            hours: row.hours,
+            status: row.status,
+            created_at: row.created_at,
+            on_behalf_of: row.on_behalf_of,
+            received_via: row.received_via,
+        }
+    }
+}
+
+backend_fn! {
+/// Lists the leave bids for an area, ordered by date, round, and ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_leave_bids(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+) -> Result<Vec<LeaveBidData>, PersistenceError> {
+    debug!(bid_year_id, area_id, "Listing leave bids");
+
+    let rows: Vec<LeaveBidRow> = leave_bids::table
+        .filter(leave_bids::bid_year_id.eq(bid_year_id))
+        .filter(leave_bids::area_id.eq(area_id))
+        .select(LeaveBidRow::as_select())
+        .order_by((
+            leave_bids::leave_date.asc(),
+            leave_bids::round_id.asc(),
+            leave_bids::leave_bid_id.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(LeaveBidData::from).collect())
+}
+}
+
+backend_fn! {
+/// Counts approved leave per day, area, round, and crew over a date range.
+///
+/// Dates are `YYYY-MM-DD` strings and both bounds are inclusive. Results
+/// are ordered by date, area, round, and crew (users without a crew first).
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `start_date` - The first date to include
+/// * `end_date` - The last date to include
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_daily_leave_counts(
+    conn: &mut _,
+    bid_year_id: i64,
+    start_date: &str,
+    end_date: &str,
+) -> Result<Vec<DailyLeaveCountData>, PersistenceError> {
+    debug!(bid_year_id, start_date, end_date, "Counting daily leave");
+
+    let rows: Vec<(String, i64, i64, Option<i32>)> = leave_bids::table
+        .inner_join(users::table)
+        .filter(leave_bids::bid_year_id.eq(bid_year_id))
+        .filter(leave_bids::status.eq(LeaveBidData::STATUS_APPROVED))
+        .filter(leave_bids::leave_date.ge(start_date))
+        .filter(leave_bids::leave_date.le(end_date))
+        .select((
+            leave_bids::leave_date,
+            leave_bids::area_id,
+            leave_bids::round_id,
+            users::crew,
+        ))
+        .load(conn)?;
+
+    let mut counts: BTreeMap<(String, i64, i64, Option<i32>), i64> = BTreeMap::new();
+    for key in rows {
+        *counts.entry(key).or_insert(0) += 1;
+    }
+
+    Ok(counts
+        .into_iter()
+        .map(
+            |((leave_date, area_id, round_id, crew), approved)| DailyLeaveCountData {
+                leave_date,
+                area_id,
+                round_id,
+                crew,
+                approved,
+            },
+        )
+        .collect())
+}
+}
+
+backend_fn! {
+/// Counts the approved leave on one day of one round in one area.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The canonical area ID
+/// * `round_id` - The round the leave was bid in
+/// * `leave_date` - The leave date (`YYYY-MM-DD`)
+/// * `crew` - When set, only leave held by users of this crew is counted
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn count_approved_leave(
+    conn: &mut _,
+    area_id: i64,
+    round_id: i64,
+    leave_date: &str,
+    crew: Option<i32>,
+) -> Result<i64, PersistenceError> {
+    debug!(area_id, round_id, leave_date, ?crew, "Counting approved leave");
+
+    let mut query = leave_bids::table
+        .inner_join(users::table)
+        .filter(leave_bids::area_id.eq(area_id))
+        .filter(leave_bids::round_id.eq(round_id))
+        .filter(leave_bids::leave_date.eq(leave_date))
+        .filter(leave_bids::status.eq(LeaveBidData::STATUS_APPROVED))
+        .into_boxed();
+    if let Some(crew) = crew {
+        query = query.filter(users::crew.eq(crew));
+    }
+
+    let approved: i64 = query.count().get_result(conn)?;
+    Ok(approved)
+}
+}
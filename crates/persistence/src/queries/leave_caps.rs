@@ -0,0 +1,159 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Leave cap queries.
+//!
+//! This module contains queries for the per-bid-year annual leave carryover
+//! cap and for the leave each user carries into a bid year.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::data_models::LeaveCarryoverData;
+use crate::diesel_schema::{leave_caps, leave_carryovers};
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Gets a bid year's carryover cap in hours, if one has been set.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_leave_cap(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Option<i32>, PersistenceError> {
+    let cap: Option<i32> = leave_caps::table
+        .filter(leave_caps::bid_year_id.eq(bid_year_id))
+        .select(leave_caps::carryover_cap_hours)
+        .first(conn)
+        .optional()?;
+
+    Ok(cap)
+}
+}
+
+backend_fn! {
+/// Sets a bid year's carryover cap, replacing any previous cap.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `carryover_cap_hours` - The most leave that may carry into the next year
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn set_leave_cap(
+    conn: &mut _,
+    bid_year_id: i64,
+    carryover_cap_hours: i32,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(leave_caps::table.filter(leave_caps::bid_year_id.eq(bid_year_id)))
+            .execute(conn)?;
+
+        diesel::insert_into(leave_caps::table)
+            .values((
+                leave_caps::bid_year_id.eq(bid_year_id),
+                leave_caps::carryover_cap_hours.eq(carryover_cap_hours),
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    })?;
+
+    info!(bid_year_id, carryover_cap_hours, "Leave carryover cap set");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Lists the leave carried into a bid year, ordered by user.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_leave_carryovers(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<LeaveCarryoverData>, PersistenceError> {
+    let rows: Vec<(i64, i32)> = leave_carryovers::table
+        .filter(leave_carryovers::bid_year_id.eq(bid_year_id))
+        .select((leave_carryovers::user_id, leave_carryovers::carryover_hours))
+        .order_by(leave_carryovers::user_id.asc())
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(user_id, carryover_hours)| LeaveCarryoverData {
+            bid_year_id,
+            user_id,
+            carryover_hours,
+        })
+        .collect())
+}
+}
+
+backend_fn! {
+/// Sets the leave a user carries into a bid year, replacing any previous
+/// value.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `user_id` - The user ID
+/// * `carryover_hours` - Hours carried in from the prior year
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn set_leave_carryover(
+    conn: &mut _,
+    bid_year_id: i64,
+    user_id: i64,
+    carryover_hours: i32,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(
+            leave_carryovers::table
+                .filter(leave_carryovers::bid_year_id.eq(bid_year_id))
+                .filter(leave_carryovers::user_id.eq(user_id)),
+        )
+        .execute(conn)?;
+
+        diesel::insert_into(leave_carryovers::table)
+            .values((
+                leave_carryovers::bid_year_id.eq(bid_year_id),
+                leave_carryovers::user_id.eq(user_id),
+                leave_carryovers::carryover_hours.eq(carryover_hours),
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    })?;
+
+    info!(bid_year_id, user_id, carryover_hours, "Leave carryover set");
+
+    Ok(())
+}
+}
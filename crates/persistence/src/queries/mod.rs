@@ -9,11 +9,45 @@
 //!
 //! ## Module Organization
 //!
+//! - `amendment_policies` — Per-bid-year bid amendment policies
+//! - `anonymization` — Users whose personal data was erased
+//! - `area_delegations` — Area rep bidding authority delegated to other operators
 //! - `audit` — Audit event queries
+//! - `audit_annotations` — Notes attached to audit events after the fact
+//! - `bid_rules` — Per-bid-year bid validation rules
+//! - `blackout_dates` — Per-bid-year blackout date management
 //! - `state` — State snapshot and reconstruction queries
 //! - `canonical` — Canonical entity queries (bid years, areas, users)
+//! - `chat` — Chat channel, announcement log, and active bid window queries
+//! - `compaction` — Audit log compactions and their archived, hash-chained events
+//! - `crew_slots` — Per-round crew slot partitions
+//! - `current_bidders` — Current bidder tracking per area and round
+//! - `eligibility` — Eligibility exceptions and derived canonical eligibility
+//! - `facility` — Facility configuration inherited by bid years
+//! - `feature_flags` — Per-bid-year feature flags
+//! - `initials_aliases` — Initials users held before an initials change
+//! - `integrity` — Raw audit log and snapshot reads for integrity checks
+//! - `job_runs` — Last run of each server background job
+//! - `leave` — Awarded leave and daily leave count queries
+//! - `leave_caps` — Annual leave carryover caps and carried-in balances
 //! - `operators` — Operator and session queries
+//! - `outbox` — Audit events waiting to be published
+//! - `overrides` — Canonical override ledger queries
+//! - `portal_links` — Controller portal links and the windows they show
+//! - `prime_dates` — Per-bid-year prime periods and per-round prime caps
+//! - `projections` — Denormalized dashboard read tables and their cursors
+//! - `round_bid_order` — Round group rotations and per-round bidding sequences
+//! - `round_eligibility` — Per-round eligibility criteria
+//! - `round_sign_offs` — Sign-offs of completed rounds per area
+//! - `saved_audit_queries` — Saved audit filters and their weekly schedules
+//! - `scope_freezes` — Areas frozen against every change
+//! - `self_bids` — Operator controller links and self-service bid requests
 //! - `completeness` — Count and aggregation queries
+//! - `notifications` — User contact, notification log, and candidate queries
+//! - `user_merges` — Ledger of merged duplicate user records
+//! - `waitlist` — Waitlisted leave slots and their offers
+//! - `webhooks` — Webhook configuration and dead-letter queries
+//! - `write_queue` — Write-ahead queue encoding and persisted progress
 //!
 //! ## Backend-Specific Functions
 //!
@@ -24,22 +58,69 @@
 //! The `Persistence` adapter in `lib.rs` dispatches to the appropriate version
 //! based on the active backend connection.
 
+pub mod amendment_policies;
+pub mod anonymization;
+pub mod area_delegations;
 pub mod audit;
+pub mod audit_annotations;
+pub mod bid_preferences;
+pub mod bid_rules;
 pub mod bid_status;
+pub mod blackout_dates;
 pub mod canonical;
+pub mod chat;
+pub mod compaction;
 pub mod completeness;
+pub mod crew_slots;
+pub mod current_bidders;
+pub mod eligibility;
+pub mod facility;
+pub mod feature_flags;
+pub mod initials_aliases;
+pub mod integrity;
+pub mod job_runs;
+pub mod leave;
+pub mod leave_caps;
+pub mod notifications;
 pub mod operators;
+pub mod outbox;
+pub mod overbids;
+pub mod overrides;
+pub mod portal_links;
+pub mod prime_dates;
+pub mod projections;
 pub mod readiness;
+pub mod round_bid_order;
+pub mod round_eligibility;
+pub mod round_sign_offs;
+pub mod round_templates;
 pub mod rounds;
+pub mod saved_audit_queries;
+pub mod scope_freezes;
+pub mod self_bids;
+pub mod slot_inventory;
 pub mod state;
+pub mod user_merges;
+pub mod waitlist;
+pub mod webhooks;
+pub mod write_queue;
 
 // Re-export the should_snapshot helper (not backend-specific)
 pub use state::should_snapshot;
 
 // Re-export backend-specific query functions used by lib.rs
 pub use audit::{
-    get_audit_timeline_mysql, get_audit_timeline_sqlite, get_events_after_mysql,
+    get_audit_event_headers_page_mysql, get_audit_event_headers_page_sqlite,
+    get_audit_timeline_entries_mysql, get_audit_timeline_entries_sqlite, get_audit_timeline_mysql,
+    get_audit_timeline_page_mysql, get_audit_timeline_page_sqlite, get_audit_timeline_sqlite,
+    get_event_correlation_id_mysql, get_event_correlation_id_sqlite, get_events_after_mysql,
     get_events_after_sqlite, get_global_audit_events_mysql, get_global_audit_events_sqlite,
+    get_latest_audit_event_id_mysql, get_latest_audit_event_id_sqlite,
+    get_recent_area_events_mysql, get_recent_area_events_sqlite, get_superseding_event_id_mysql,
+    get_superseding_event_id_sqlite, list_checkpoints_mysql, list_checkpoints_sqlite,
+    list_event_dependents_mysql, list_event_dependents_sqlite, list_latest_area_event_ids_mysql,
+    list_latest_area_event_ids_sqlite, list_superseded_event_ids_mysql,
+    list_superseded_event_ids_sqlite,
 };
 pub use canonical::{
     count_users_in_system_area_mysql, count_users_in_system_area_sqlite, find_system_area_mysql,
@@ -79,6 +160,7 @@ pub use rounds::{
 pub use state::{
     get_current_state_mysql, get_current_state_sqlite, get_historical_state_mysql,
     get_historical_state_sqlite, get_latest_snapshot_mysql, get_latest_snapshot_sqlite,
+    get_snapshot_at_or_before_event_mysql, get_snapshot_at_or_before_event_sqlite,
 };
 
 // Phase 29F: Bid status query re-exports
@@ -0,0 +1,358 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Notification queries.
+//!
+//! This module contains backend-agnostic queries for user contact details,
+//! the notification log, and the bid windows and bid status transitions that
+//! may need a notification.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::debug;
+
+use crate::data_models::{
+    BidEntryNotificationCandidate, NotificationLogData, UserContactData,
+    WindowNotificationCandidate,
+};
+use crate::diesel_schema::{
+    areas, bid_status, bid_status_history, bid_windows, bid_years, notification_log, rounds,
+    user_contacts, users,
+};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for user contact rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = user_contacts)]
+struct UserContactRow {
+    user_id: i64,
+    email: String,
+    email_enabled: i32,
+    updated_at: String,
+}
+
+impl From<UserContactRow> for UserContactData {
+    fn from(row: UserContactRow) -> Self {
+        Self {
+            user_id: row.user_id,
+            email: row.email,
+            email_enabled: row.email_enabled != 0,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Diesel Queryable struct for notification log rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = notification_log)]
+struct NotificationLogRow {
+    notification_id: i64,
+    user_id: i64,
+    kind: String,
+    reference_id: i64,
+    recipient: String,
+    subject: String,
+    delivered: i32,
+    error: Option<String>,
+    sent_at: String,
+}
+
+impl From<NotificationLogRow> for NotificationLogData {
+    fn from(row: NotificationLogRow) -> Self {
+        Self {
+            notification_id: row.notification_id,
+            user_id: row.user_id,
+            kind: row.kind,
+            reference_id: row.reference_id,
+            recipient: row.recipient,
+            subject: row.subject,
+            delivered: row.delivered != 0,
+            error: row.error,
+            sent_at: row.sent_at,
+        }
+    }
+}
+
+/// Bid window candidate columns, in select order.
+type WindowCandidateRow = (
+    i64,
+    i64,
+    String,
+    String,
+    String,
+    i32,
+    String,
+    String,
+    String,
+    String,
+);
+
+/// Bid entry candidate columns, in select order.
+type BidEntryCandidateRow = (
+    i64,
+    i64,
+    String,
+    String,
+    String,
+    i32,
+    String,
+    String,
+    Option<String>,
+    String,
+);
+
+backend_fn! {
+/// Retrieves a user's contact details.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `user_id` - The canonical user ID
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+/// Returns `Ok(None)` if no contact details are stored.
+pub fn get_user_contact(
+    conn: &mut _,
+    user_id: i64,
+) -> Result<Option<UserContactData>, PersistenceError> {
+    debug!(user_id, "Looking up user contact");
+
+    let row: Option<UserContactRow> = user_contacts::table
+        .filter(user_contacts::user_id.eq(user_id))
+        .select(UserContactRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(UserContactData::from))
+}
+}
+
+backend_fn! {
+/// Lists bid windows in `BiddingActive` bid years whose users can be emailed.
+///
+/// Windows are ordered by start time. Timing and de-duplication are left to
+/// the caller.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_window_notification_candidates(
+    conn: &mut _,
+) -> Result<Vec<WindowNotificationCandidate>, PersistenceError> {
+    let rows: Vec<WindowCandidateRow> = bid_windows::table
+        .inner_join(users::table.inner_join(user_contacts::table))
+        .inner_join(bid_years::table)
+        .inner_join(areas::table)
+        .inner_join(rounds::table)
+        .filter(bid_years::lifecycle_state.eq("BiddingActive"))
+        .filter(user_contacts::email_enabled.eq(1))
+        .select((
+            bid_windows::bid_window_id,
+            bid_windows::user_id,
+            users::initials,
+            users::name,
+            user_contacts::email,
+            bid_years::year,
+            areas::area_code,
+            rounds::name,
+            bid_windows::window_start_datetime,
+            bid_windows::window_end_datetime,
+        ))
+        .order_by((
+            bid_windows::window_start_datetime.asc(),
+            bid_windows::bid_window_id.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(
+                bid_window_id,
+                user_id,
+                initials,
+                name,
+                email,
+                year,
+                area_code,
+                round_name,
+                window_start_datetime,
+                window_end_datetime,
+            )| WindowNotificationCandidate {
+                bid_window_id,
+                user_id,
+                initials,
+                name,
+                email,
+                year,
+                area_code,
+                round_name,
+                window_start_datetime,
+                window_end_datetime,
+            },
+        )
+        .collect())
+}
+}
+
+backend_fn! {
+/// Lists bid status transitions after `after_history_id` for users who can be emailed.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `after_history_id` - Only return transitions with a greater history ID
+/// * `statuses` - The new statuses of interest
+/// * `limit` - Maximum number of transitions to return
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_bid_entry_notification_candidates(
+    conn: &mut _,
+    after_history_id: i64,
+    statuses: &[&str],
+    limit: u32,
+) -> Result<Vec<BidEntryNotificationCandidate>, PersistenceError> {
+    let rows: Vec<BidEntryCandidateRow> = bid_status_history::table
+        .inner_join(
+            bid_status::table
+                .inner_join(users::table.inner_join(user_contacts::table))
+                .inner_join(bid_years::table)
+                .inner_join(areas::table)
+                .inner_join(rounds::table),
+        )
+        .filter(bid_status_history::history_id.gt(after_history_id))
+        .filter(bid_status_history::new_status.eq_any(statuses))
+        .filter(user_contacts::email_enabled.eq(1))
+        .select((
+            bid_status_history::history_id,
+            bid_status::user_id,
+            users::initials,
+            users::name,
+            user_contacts::email,
+            bid_years::year,
+            areas::area_code,
+            rounds::name,
+            bid_status_history::previous_status,
+            bid_status_history::new_status,
+        ))
+        .order_by(bid_status_history::history_id.asc())
+        .limit(i64::from(limit))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(
+                history_id,
+                user_id,
+                initials,
+                name,
+                email,
+                year,
+                area_code,
+                round_name,
+                previous_status,
+                new_status,
+            )| BidEntryNotificationCandidate {
+                history_id,
+                user_id,
+                initials,
+                name,
+                email,
+                year,
+                area_code,
+                round_name,
+                previous_status,
+                new_status,
+            },
+        )
+        .collect())
+}
+}
+
+backend_fn! {
+/// Retrieves the highest bid status history ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+/// Returns `Ok(None)` if no transitions have been recorded.
+pub fn get_latest_bid_status_history_id(conn: &mut _) -> Result<Option<i64>, PersistenceError> {
+    let latest: Option<i64> = bid_status_history::table
+        .select(diesel::dsl::max(bid_status_history::history_id))
+        .first(conn)?;
+
+    Ok(latest)
+}
+}
+
+backend_fn! {
+/// Returns true if a notification of `kind` has been recorded for `reference_id`.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `kind` - The notification kind
+/// * `reference_id` - The bid window or history ID the notification is about
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn notification_exists(
+    conn: &mut _,
+    kind: &str,
+    reference_id: i64,
+) -> Result<bool, PersistenceError> {
+    let count: i64 = notification_log::table
+        .filter(notification_log::kind.eq(kind))
+        .filter(notification_log::reference_id.eq(reference_id))
+        .count()
+        .get_result(conn)?;
+
+    Ok(count > 0)
+}
+}
+
+backend_fn! {
+/// Lists notifications recorded for a user, newest first.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `user_id` - The canonical user ID
+/// * `limit` - Maximum number of entries to return
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_notifications_for_user(
+    conn: &mut _,
+    user_id: i64,
+    limit: u32,
+) -> Result<Vec<NotificationLogData>, PersistenceError> {
+    debug!(user_id, limit, "Listing notifications");
+
+    let rows: Vec<NotificationLogRow> = notification_log::table
+        .filter(notification_log::user_id.eq(user_id))
+        .select(NotificationLogRow::as_select())
+        .order_by(notification_log::notification_id.desc())
+        .limit(i64::from(limit))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(NotificationLogData::from).collect())
+}
+}
@@ -13,10 +13,21 @@ use diesel::prelude::*;
 use diesel::{MysqlConnection, SqliteConnection};
 use tracing::debug;
 
-use crate::data_models::{OperatorData, SessionData};
-use crate::diesel_schema::{audit_events, operators, sessions};
+use crate::data_models::{OperatorActivityData, OperatorAreaScopeData, OperatorData, SessionData};
+use crate::diesel_schema::{
+    areas, audit_event_annotations, audit_events, bid_years, operator_area_scopes, operators,
+    sessions,
+};
 use crate::error::PersistenceError;
 
+/// The ID of the built-in system operator.
+///
+/// Audit events with no operator behind them, such as rate limit trips
+/// from unauthenticated clients, are attributed to it. It is disabled,
+/// cannot sign in, and is left out of operator lookups, listings, and
+/// counts.
+pub const SYSTEM_OPERATOR_ID: i64 = 0;
+
 /// Diesel Queryable struct for operator rows.
 #[derive(Queryable, Selectable)]
 #[diesel(table_name = operators)]
@@ -32,12 +43,28 @@ struct OperatorRow {
     last_login_at: Option<String>,
 }
 
+impl OperatorRow {
+    fn into_data(self) -> OperatorData {
+        OperatorData {
+            operator_id: self.operator_id,
+            login_name: self.login_name,
+            display_name: self.display_name,
+            password_hash: self.password_hash,
+            role: self.role,
+            is_disabled: self.is_disabled != 0,
+            created_at: self.created_at,
+            disabled_at: self.disabled_at,
+            last_login_at: self.last_login_at,
+        }
+    }
+}
+
 /// Diesel Queryable struct for session rows.
 #[derive(Queryable, Selectable)]
 #[diesel(table_name = sessions)]
 struct SessionRow {
     session_id: i64,
-    session_token: String,
+    session_token_hash: String,
     operator_id: i64,
     created_at: String,
     last_activity_at: String,
@@ -68,21 +95,12 @@ pub fn get_operator_by_login(
 
     let result: Result<OperatorRow, diesel::result::Error> = operators::table
         .filter(operators::login_name.eq(&normalized_login))
+        .filter(operators::operator_id.ne(SYSTEM_OPERATOR_ID))
         .select(OperatorRow::as_select())
         .first(conn);
 
     match result {
-        Ok(row) => Ok(Some(OperatorData {
-            operator_id: row.operator_id,
-            login_name: row.login_name,
-            display_name: row.display_name,
-            password_hash: row.password_hash,
-            role: row.role,
-            is_disabled: row.is_disabled != 0,
-            created_at: row.created_at,
-            disabled_at: row.disabled_at,
-            last_login_at: row.last_login_at,
-        })),
+        Ok(row) => Ok(Some(row.into_data())),
         Err(diesel::result::Error::NotFound) => Ok(None),
         Err(e) => Err(PersistenceError::from(e)),
     }
@@ -109,21 +127,12 @@ pub fn get_operator_by_id(
 
     let result: Result<OperatorRow, diesel::result::Error> = operators::table
         .filter(operators::operator_id.eq(operator_id))
+        .filter(operators::operator_id.ne(SYSTEM_OPERATOR_ID))
         .select(OperatorRow::as_select())
         .first(conn);
 
     match result {
-        Ok(row) => Ok(Some(OperatorData {
-            operator_id: row.operator_id,
-            login_name: row.login_name,
-            display_name: row.display_name,
-            password_hash: row.password_hash,
-            role: row.role,
-            is_disabled: row.is_disabled != 0,
-            created_at: row.created_at,
-            disabled_at: row.disabled_at,
-            last_login_at: row.last_login_at,
-        })),
+        Ok(row) => Ok(Some(row.into_data())),
         Err(diesel::result::Error::NotFound) => Ok(None),
         Err(e) => Err(PersistenceError::from(e)),
     }
@@ -136,7 +145,7 @@ backend_fn! {
 /// # Arguments
 ///
 /// * `conn` - The database connection
-/// * `session_token` - The session token
+/// * `session_token_hash` - The keyed digest of the session token
 ///
 /// # Errors
 ///
@@ -144,19 +153,19 @@ backend_fn! {
 /// Returns `Ok(None)` if the session is not found.
 pub fn get_session_by_token(
     conn: &mut _,
-    session_token: &str,
+    session_token_hash: &str,
 ) -> Result<Option<SessionData>, PersistenceError> {
     debug!("Looking up session by token");
 
     let result: Result<SessionRow, diesel::result::Error> = sessions::table
-        .filter(sessions::session_token.eq(session_token))
+        .filter(sessions::session_token_hash.eq(session_token_hash))
         .select(SessionRow::as_select())
         .first(conn);
 
     match result {
         Ok(row) => Ok(Some(SessionData {
             session_id: row.session_id,
-            session_token: row.session_token,
+            session_token_hash: row.session_token_hash,
             operator_id: row.operator_id,
             created_at: row.created_at,
             last_activity_at: row.last_activity_at,
@@ -169,7 +178,7 @@ pub fn get_session_by_token(
 }
 
 backend_fn! {
-/// Checks if an operator is referenced by any audit events.
+/// Checks if an operator is referenced by any audit events or annotations.
 ///
 /// # Arguments
 ///
@@ -187,17 +196,21 @@ pub fn is_operator_referenced(conn: &mut _, operator_id: i64) -> Result<bool, Pe
         operator_id
     );
 
-    let count: i64 = audit_events::table
+    let events: i64 = audit_events::table
         .filter(audit_events::actor_operator_id.eq(operator_id))
         .select(count(audit_events::event_id))
         .first(conn)?;
+    let annotations: i64 = audit_event_annotations::table
+        .filter(audit_event_annotations::operator_id.eq(operator_id))
+        .select(count(audit_event_annotations::annotation_id))
+        .first(conn)?;
 
-    Ok(count > 0)
+    Ok(events > 0 || annotations > 0)
 }
 }
 
 backend_fn! {
-/// Lists all operators.
+/// Lists all operators except the system operator.
 ///
 /// # Arguments
 ///
@@ -210,31 +223,134 @@ pub fn list_operators(conn: &mut _) -> Result<Vec<OperatorData>, PersistenceErro
     debug!("Listing all operators");
 
     let rows: Vec<OperatorRow> = operators::table
+        .filter(operators::operator_id.ne(SYSTEM_OPERATOR_ID))
         .select(OperatorRow::as_select())
         .order_by(operators::login_name.asc())
         .load(conn)?;
 
     let operators_list: Vec<OperatorData> = rows
         .into_iter()
-        .map(|row| OperatorData {
-            operator_id: row.operator_id,
-            login_name: row.login_name,
-            display_name: row.display_name,
-            password_hash: row.password_hash,
-            role: row.role,
-            is_disabled: row.is_disabled != 0,
-            created_at: row.created_at,
-            disabled_at: row.disabled_at,
-            last_login_at: row.last_login_at,
-        })
+        .map(OperatorRow::into_data)
         .collect();
 
     Ok(operators_list)
 }
 }
 
+/// An operator row joined with its activity and one of its area scopes.
+type OperatorActivityRow = (
+    OperatorRow,
+    i32,                             // failed_login_count
+    Option<i64>,                     // active session count
+    Option<(i64, i64, i32, String)>, // area_id, bid_year_id, year, area_code
+);
+
+backend_fn! {
+/// Lists all operators with their sign-in activity and area scopes.
+///
+/// Everything is read in a single statement: area scopes are left-joined
+/// (one row per scope) and active sessions are counted in a correlated
+/// subquery, so the result does not depend on the number of operators.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_operators_with_activity(
+    conn: &mut _,
+) -> Result<Vec<OperatorActivityData>, PersistenceError> {
+    debug!("Listing operators with activity");
+
+    let active_sessions = sessions::table
+        .filter(sessions::operator_id.eq(operators::operator_id))
+        .filter(
+            sessions::expires_at.ge(diesel::dsl::sql::<diesel::sql_types::Text>(
+                "CURRENT_TIMESTAMP",
+            )),
+        )
+        .count()
+        .single_value();
+
+    let rows: Vec<OperatorActivityRow> = operators::table
+        .left_join(
+            operator_area_scopes::table.inner_join(areas::table.inner_join(bid_years::table)),
+        )
+        .filter(operators::operator_id.ne(SYSTEM_OPERATOR_ID))
+        .select((
+            OperatorRow::as_select(),
+            operators::failed_login_count,
+            active_sessions,
+            (
+                areas::area_id,
+                areas::bid_year_id,
+                bid_years::year,
+                areas::area_code,
+            )
+                .nullable(),
+        ))
+        .order_by((
+            operators::login_name.asc(),
+            bid_years::year.asc(),
+            areas::area_code.asc(),
+        ))
+        .load(conn)?;
+
+    let mut operators_list: Vec<OperatorActivityData> = Vec::new();
+    for (row, failed_login_count, active_sessions, scope) in rows {
+        if operators_list
+            .last()
+            .is_none_or(|last| last.operator.operator_id != row.operator_id)
+        {
+            operators_list.push(OperatorActivityData {
+                operator: row.into_data(),
+                active_session_count: usize::try_from(active_sessions.unwrap_or(0)).unwrap_or(0),
+                failed_login_count: u32::try_from(failed_login_count).unwrap_or(0),
+                area_scopes: Vec::new(),
+            });
+        }
+        if let (Some(last), Some((area_id, bid_year_id, year, area_code))) =
+            (operators_list.last_mut(), scope)
+        {
+            last.area_scopes.push(OperatorAreaScopeData {
+                area_id,
+                bid_year_id,
+                year: u16::try_from(year).unwrap_or(0),
+                area_code,
+            });
+        }
+    }
+
+    Ok(operators_list)
+}
+}
+
+backend_fn! {
+/// Lists the IDs of the areas an operator is assigned to.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `operator_id` - The operator ID
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_operator_area_ids(conn: &mut _, operator_id: i64) -> Result<Vec<i64>, PersistenceError> {
+    let area_ids: Vec<i64> = operator_area_scopes::table
+        .filter(operator_area_scopes::operator_id.eq(operator_id))
+        .select(operator_area_scopes::area_id)
+        .order_by(operator_area_scopes::area_id.asc())
+        .load(conn)?;
+
+    Ok(area_ids)
+}
+}
+
 backend_fn! {
-/// Counts the total number of operators.
+/// Counts the total number of operators, not counting the system operator.
 ///
 /// # Arguments
 ///
@@ -249,6 +365,7 @@ pub fn count_operators(conn: &mut _) -> Result<i64, PersistenceError> {
     debug!("Counting operators");
 
     let count: i64 = operators::table
+        .filter(operators::operator_id.ne(SYSTEM_OPERATOR_ID))
         .select(count(operators::operator_id))
         .first(conn)?;
 
@@ -0,0 +1,92 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Transactional outbox queries.
+//!
+//! This module contains backend-agnostic queries for reading audit events
+//! that are waiting to be published.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::debug;
+
+use crate::data_models::OutboxEntryData;
+use crate::diesel_schema::event_outbox;
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for outbox rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = event_outbox)]
+struct OutboxEntryRow {
+    outbox_id: i64,
+    audit_event_id: i64,
+    created_at: String,
+    delivered_at: Option<String>,
+}
+
+impl From<OutboxEntryRow> for OutboxEntryData {
+    fn from(row: OutboxEntryRow) -> Self {
+        Self {
+            outbox_id: row.outbox_id,
+            audit_event_id: row.audit_event_id,
+            created_at: row.created_at,
+            delivered_at: row.delivered_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Lists undelivered outbox entries, oldest first.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `limit` - Maximum number of entries to return
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_pending_outbox_entries(
+    conn: &mut _,
+    limit: u32,
+) -> Result<Vec<OutboxEntryData>, PersistenceError> {
+    debug!(limit, "Listing pending outbox entries");
+
+    let rows: Vec<OutboxEntryRow> = event_outbox::table
+        .filter(event_outbox::delivered_at.is_null())
+        .select(OutboxEntryRow::as_select())
+        .order_by(event_outbox::outbox_id.asc())
+        .limit(i64::from(limit))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(OutboxEntryData::from).collect())
+}
+}
+
+backend_fn! {
+/// Retrieves the outbox entry written for an audit event.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `audit_event_id` - The audit event ID
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+/// Returns `Ok(None)` if the event has no outbox entry.
+pub fn get_outbox_entry_for_event(
+    conn: &mut _,
+    audit_event_id: i64,
+) -> Result<Option<OutboxEntryData>, PersistenceError> {
+    let row: Option<OutboxEntryRow> = event_outbox::table
+        .filter(event_outbox::audit_event_id.eq(audit_event_id))
+        .select(OutboxEntryRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(OutboxEntryData::from))
+}
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Overbid request queries.
+//!
+//! This module reads the requests for leave on full days and the Admin
+//! decisions on them.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+
+use crate::data_models::OverbidRequestData;
+use crate::diesel_schema::overbid_requests;
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for overbid request rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = overbid_requests)]
+struct OverbidRequestRow {
+    overbid_request_id: i64,
+    bid_year_id: i64,
+    area_id: i64,
+    user_id: i64,
+    round_id: i64,
+    leave_date: String,
+    hours: i32,
+    on_behalf_of: String,
+    received_via: String,
+    status: String,
+    decision_reason: Option<String>,
+    request_event_id: Option<i64>,
+    decision_event_id: Option<i64>,
+    leave_bid_id: Option<i64>,
+    created_at: String,
+    decided_at: Option<String>,
+}
+
+impl From<OverbidRequestRow> for OverbidRequestData {
+    fn from(row: OverbidRequestRow) -> Self {
+        Self {
+            overbid_request_id: row.overbid_request_id,
+            bid_year_id: row.bid_year_id,
+            area_id: row.area_id,
+            user_id: row.user_id,
+            round_id: row.round_id,
+            leave_date: row.leave_date,
+            hours: row.hours,
+            on_behalf_of: row.on_behalf_of,
+            received_via: row.received_via,
+            status: row.status,
+            decision_reason: row.decision_reason,
+            request_event_id: row.request_event_id,
+            decision_event_id: row.decision_event_id,
+            leave_bid_id: row.leave_bid_id,
+            created_at: row.created_at,
+            decided_at: row.decided_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Gets an overbid request by ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `overbid_request_id` - The overbid request ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_overbid_request(
+    conn: &mut _,
+    overbid_request_id: i64,
+) -> Result<Option<OverbidRequestData>, PersistenceError> {
+    let row: Option<OverbidRequestRow> = overbid_requests::table
+        .filter(overbid_requests::overbid_request_id.eq(overbid_request_id))
+        .select(OverbidRequestRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(OverbidRequestData::from))
+}
+}
+
+backend_fn! {
+/// Lists the overbid requests for a round in an area.
+///
+/// Requests are ordered by leave date, then by when they were made.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_overbid_requests(
+    conn: &mut _,
+    area_id: i64,
+    round_id: i64,
+) -> Result<Vec<OverbidRequestData>, PersistenceError> {
+    let rows: Vec<OverbidRequestRow> = overbid_requests::table
+        .filter(overbid_requests::area_id.eq(area_id))
+        .filter(overbid_requests::round_id.eq(round_id))
+        .select(OverbidRequestRow::as_select())
+        .order_by((
+            overbid_requests::leave_date.asc(),
+            overbid_requests::overbid_request_id.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(OverbidRequestData::from).collect())
+}
+}
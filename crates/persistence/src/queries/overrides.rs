@@ -0,0 +1,135 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Canonical override ledger queries.
+//!
+//! This module contains backend-agnostic queries for the overrides applied
+//! to canonical tables after canonicalization.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::debug;
+
+use crate::data_models::{CanonicalOverrideData, OverrideValue};
+use crate::diesel_schema::{canonical_bid_order, canonical_overrides};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for canonical override rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = canonical_overrides)]
+struct CanonicalOverrideRow {
+    override_id: i64,
+    bid_year_id: i64,
+    user_id: i64,
+    override_kind: String,
+    previous_value: String,
+    new_value: String,
+    reason: String,
+    audit_event_id: i64,
+    is_reverted: i32,
+    created_at: String,
+}
+
+impl TryFrom<CanonicalOverrideRow> for CanonicalOverrideData {
+    type Error = PersistenceError;
+
+    fn try_from(row: CanonicalOverrideRow) -> Result<Self, Self::Error> {
+        let previous_value: OverrideValue = serde_json::from_str(&row.previous_value)?;
+        let new_value: OverrideValue = serde_json::from_str(&row.new_value)?;
+
+        Ok(Self {
+            override_id: row.override_id,
+            bid_year_id: row.bid_year_id,
+            user_id: row.user_id,
+            override_kind: row.override_kind,
+            previous_value,
+            new_value,
+            reason: row.reason,
+            audit_event_id: row.audit_event_id,
+            is_reverted: row.is_reverted != 0,
+            created_at: row.created_at,
+        })
+    }
+}
+
+backend_fn! {
+/// Lists every override recorded for a bid year, oldest first.
+///
+/// Reverted overrides are included so the ledger reads as a history.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the database query fails or a stored value cannot
+/// be decoded.
+pub fn list_canonical_overrides(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<CanonicalOverrideData>, PersistenceError> {
+    debug!(bid_year_id, "Listing canonical overrides");
+
+    let rows: Vec<CanonicalOverrideRow> = canonical_overrides::table
+        .filter(canonical_overrides::bid_year_id.eq(bid_year_id))
+        .select(CanonicalOverrideRow::as_select())
+        .order_by(canonical_overrides::override_id.asc())
+        .load(conn)?;
+
+    rows.into_iter().map(CanonicalOverrideData::try_from).collect()
+}
+}
+
+backend_fn! {
+/// Gets a single override from the ledger.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `override_id` - The override ID
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the override does not exist, or
+/// an error if the database query fails.
+pub fn get_canonical_override(
+    conn: &mut _,
+    override_id: i64,
+) -> Result<CanonicalOverrideData, PersistenceError> {
+    let row: CanonicalOverrideRow = canonical_overrides::table
+        .filter(canonical_overrides::override_id.eq(override_id))
+        .select(CanonicalOverrideRow::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or_else(|| PersistenceError::NotFound(format!("Override {override_id} not found")))?;
+
+    CanonicalOverrideData::try_from(row)
+}
+}
+
+backend_fn! {
+/// Lists the canonical bid order of every user in a bid year.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_canonical_bid_orders(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<(i64, Option<i32>)>, PersistenceError> {
+    Ok(canonical_bid_order::table
+        .filter(canonical_bid_order::bid_year_id.eq(bid_year_id))
+        .select((canonical_bid_order::user_id, canonical_bid_order::bid_order))
+        .order_by(canonical_bid_order::user_id.asc())
+        .load(conn)?)
+}
+}
@@ -0,0 +1,212 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Controller portal link queries.
+//!
+//! This module contains queries for the links controllers use to read their
+//! own standing without an operator account, and for the bid windows the
+//! portal shows them. Links are looked up by token digest, never by token.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::{debug, info};
+
+use crate::data_models::{PortalLinkData, UserBidWindowData};
+use crate::diesel_schema::{bid_windows, portal_links, rounds};
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Stores a user's portal link, replacing any link the user already holds.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `token_hash` - The digest of the link token
+/// * `user_id` - The user the link belongs to
+/// * `bid_year_id` - The user's bid year
+/// * `expires_at` - When the link stops working (RFC 3339)
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn create_portal_link(
+    conn: &mut _,
+    token_hash: &str,
+    user_id: i64,
+    bid_year_id: i64,
+    expires_at: &str,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(portal_links::table.filter(portal_links::user_id.eq(user_id)))
+            .execute(conn)?;
+        diesel::insert_into(portal_links::table)
+            .values((
+                portal_links::token_hash.eq(token_hash),
+                portal_links::user_id.eq(user_id),
+                portal_links::bid_year_id.eq(bid_year_id),
+                portal_links::expires_at.eq(expires_at),
+            ))
+            .execute(conn)?;
+        Ok(())
+    })?;
+
+    info!(user_id, expires_at, "Portal link issued");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Looks up a portal link by token digest.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `token_hash` - The digest of the link token
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_portal_link_by_token(
+    conn: &mut _,
+    token_hash: &str,
+) -> Result<Option<PortalLinkData>, PersistenceError> {
+    debug!("Looking up portal link by token");
+
+    let row: Option<(i64, String, i64, i64, String, String)> = portal_links::table
+        .filter(portal_links::token_hash.eq(token_hash))
+        .select((
+            portal_links::portal_link_id,
+            portal_links::token_hash,
+            portal_links::user_id,
+            portal_links::bid_year_id,
+            portal_links::expires_at,
+            portal_links::created_at,
+        ))
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(
+        |(portal_link_id, token_hash, user_id, bid_year_id, expires_at, created_at)| {
+            PortalLinkData {
+                portal_link_id,
+                token_hash,
+                user_id,
+                bid_year_id,
+                expires_at,
+                created_at,
+            }
+        },
+    ))
+}
+}
+
+backend_fn! {
+/// Replaces the stored digest of a portal link.
+///
+/// Used to move a link stored under the previous session key to the
+/// current one.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `portal_link_id` - The portal link ID
+/// * `token_hash` - The digest under the current key
+///
+/// # Errors
+///
+/// Returns an error if the update fails.
+pub fn rekey_portal_link(
+    conn: &mut _,
+    portal_link_id: i64,
+    token_hash: &str,
+) -> Result<(), PersistenceError> {
+    diesel::update(portal_links::table)
+        .filter(portal_links::portal_link_id.eq(portal_link_id))
+        .set(portal_links::token_hash.eq(token_hash))
+        .execute(conn)?;
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Deletes a user's portal link.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `user_id` - The user whose link is revoked
+///
+/// # Returns
+///
+/// Whether the user held a link.
+///
+/// # Errors
+///
+/// Returns an error if the delete fails.
+pub fn delete_portal_link(conn: &mut _, user_id: i64) -> Result<bool, PersistenceError> {
+    let deleted: usize =
+        diesel::delete(portal_links::table.filter(portal_links::user_id.eq(user_id)))
+            .execute(conn)?;
+
+    if deleted > 0 {
+        info!(user_id, "Portal link revoked");
+    }
+
+    Ok(deleted > 0)
+}
+}
+
+backend_fn! {
+/// Lists a user's bid windows with their rounds, ordered by window start.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `user_id` - The user ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_user_bid_windows(
+    conn: &mut _,
+    user_id: i64,
+) -> Result<Vec<UserBidWindowData>, PersistenceError> {
+    let rows: Vec<(i64, i32, String, String, String)> = bid_windows::table
+        .inner_join(rounds::table)
+        .filter(bid_windows::user_id.eq(user_id))
+        .select((
+            bid_windows::round_id,
+            rounds::round_number,
+            rounds::name,
+            bid_windows::window_start_datetime,
+            bid_windows::window_end_datetime,
+        ))
+        .order_by((
+            bid_windows::window_start_datetime.asc(),
+            rounds::round_number.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(round_id, round_number, round_name, window_start_datetime, window_end_datetime)| {
+                UserBidWindowData {
+                    round_id,
+                    round_number,
+                    round_name,
+                    window_start_datetime,
+                    window_end_datetime,
+                }
+            },
+        )
+        .collect())
+}
+}
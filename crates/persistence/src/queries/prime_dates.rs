@@ -0,0 +1,247 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Prime date queries.
+//!
+//! This module contains queries for managing the per-bid-year prime
+//! (high-demand) periods and the per-round caps on leave slots during
+//! them.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::{PrimePeriodData, RoundPrimeCapData};
+use crate::diesel_schema::{prime_periods, round_prime_caps};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for prime period rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = prime_periods)]
+struct PrimePeriodRow {
+    prime_period_id: i64,
+    bid_year_id: i64,
+    label: String,
+    start_date: String,
+    end_date: String,
+    created_at: String,
+}
+
+impl From<PrimePeriodRow> for PrimePeriodData {
+    fn from(row: PrimePeriodRow) -> Self {
+        Self {
+            prime_period_id: row.prime_period_id,
+            bid_year_id: row.bid_year_id,
+            label: row.label,
+            start_date: row.start_date,
+            end_date: row.end_date,
+            created_at: row.created_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Stores a new prime period for a bid year.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `label` - The period's name (e.g. "Christmas week")
+/// * `start_date` - The first prime date (ISO 8601 format)
+/// * `end_date` - The last prime date (ISO 8601 format)
+///
+/// # Errors
+///
+/// Returns an error if the insert fails.
+pub fn insert_prime_period(
+    conn: &mut _,
+    bid_year_id: i64,
+    label: &str,
+    start_date: &str,
+    end_date: &str,
+) -> Result<i64, PersistenceError> {
+    let prime_period_id: i64 = conn.transaction::<i64, PersistenceError, _>(|conn| {
+        diesel::insert_into(prime_periods::table)
+            .values((
+                prime_periods::bid_year_id.eq(bid_year_id),
+                prime_periods::label.eq(label),
+                prime_periods::start_date.eq(start_date),
+                prime_periods::end_date.eq(end_date),
+            ))
+            .execute(conn)?;
+        conn.get_last_insert_rowid()
+    })?;
+
+    info!(prime_period_id, bid_year_id, start_date, end_date, "Prime period created");
+
+    Ok(prime_period_id)
+}
+}
+
+backend_fn! {
+/// Lists the prime periods of a bid year, ordered by start date.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_prime_periods(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<PrimePeriodData>, PersistenceError> {
+    let rows: Vec<PrimePeriodRow> = prime_periods::table
+        .filter(prime_periods::bid_year_id.eq(bid_year_id))
+        .select(PrimePeriodRow::as_select())
+        .order_by((prime_periods::start_date.asc(), prime_periods::prime_period_id.asc()))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(PrimePeriodData::from).collect())
+}
+}
+
+backend_fn! {
+/// Gets a single prime period.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `prime_period_id` - The prime period ID
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the prime period does not
+/// exist, or an error if the query fails.
+pub fn get_prime_period(
+    conn: &mut _,
+    prime_period_id: i64,
+) -> Result<PrimePeriodData, PersistenceError> {
+    let row: PrimePeriodRow = prime_periods::table
+        .filter(prime_periods::prime_period_id.eq(prime_period_id))
+        .select(PrimePeriodRow::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or_else(|| {
+            PersistenceError::NotFound(format!("Prime period {prime_period_id} not found"))
+        })?;
+
+    Ok(PrimePeriodData::from(row))
+}
+}
+
+backend_fn! {
+/// Deletes a prime period.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `prime_period_id` - The prime period ID
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the prime period does not
+/// exist, or an error if the delete fails.
+pub fn delete_prime_period(
+    conn: &mut _,
+    prime_period_id: i64,
+) -> Result<(), PersistenceError> {
+    let rows_affected: usize = diesel::delete(
+        prime_periods::table.filter(prime_periods::prime_period_id.eq(prime_period_id)),
+    )
+    .execute(conn)?;
+
+    if rows_affected == 0 {
+        return Err(PersistenceError::NotFound(format!(
+            "Prime period {prime_period_id} not found"
+        )));
+    }
+
+    info!(prime_period_id, "Prime period deleted");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Lists the prime caps of a bid year's rounds, ordered by round.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_round_prime_caps(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<RoundPrimeCapData>, PersistenceError> {
+    let rows: Vec<(i64, i32)> = round_prime_caps::table
+        .filter(round_prime_caps::bid_year_id.eq(bid_year_id))
+        .select((round_prime_caps::round_id, round_prime_caps::prime_slots_per_day))
+        .order_by(round_prime_caps::round_id.asc())
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(round_id, prime_slots_per_day)| RoundPrimeCapData {
+            round_id,
+            prime_slots_per_day,
+        })
+        .collect())
+}
+}
+
+backend_fn! {
+/// Sets or clears a round's cap on prime dates.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `round_id` - The round ID
+/// * `prime_slots_per_day` - The slots per prime date, or `None` to
+///   remove the cap
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn set_round_prime_cap(
+    conn: &mut _,
+    bid_year_id: i64,
+    round_id: i64,
+    prime_slots_per_day: Option<i32>,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(round_prime_caps::table.filter(round_prime_caps::round_id.eq(round_id)))
+            .execute(conn)?;
+
+        if let Some(prime_slots_per_day) = prime_slots_per_day {
+            diesel::insert_into(round_prime_caps::table)
+                .values((
+                    round_prime_caps::round_id.eq(round_id),
+                    round_prime_caps::bid_year_id.eq(bid_year_id),
+                    round_prime_caps::prime_slots_per_day.eq(prime_slots_per_day),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })?;
+
+    info!(round_id, prime_slots_per_day, "Round prime cap set");
+
+    Ok(())
+}
+}
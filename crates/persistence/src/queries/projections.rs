@@ -0,0 +1,342 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Dashboard projection queries.
+//!
+//! Projections are denormalized read tables derived from the canonical
+//! tables: the remaining leave slots per day, each user's approved leave,
+//! and each area's bid status counts. They are rebuilt one area at a time
+//! for every area the audit log shows has changed, and each projection
+//! records the last audit event it has applied in `projection_cursors`.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+
+use crate::data_models::{
+    AreaProjectionData, AuditScope, ProjectedAreaProgressData, ProjectedDailySlotsData,
+    ProjectedUserAwardData,
+};
+use crate::diesel_schema::{
+    audit_events, projected_area_progress, projected_daily_slots, projected_user_awards,
+    projection_cursors,
+};
+use crate::error::PersistenceError;
+
+/// Rows written per insert statement, well under `SQLite`'s bound
+/// parameter limit.
+const INSERT_CHUNK_SIZE: usize = 500;
+
+backend_fn! {
+/// Gets the last audit event a projection has applied.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `projection_name` - The projection
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_projection_cursor(
+    conn: &mut _,
+    projection_name: &str,
+) -> Result<Option<i64>, PersistenceError> {
+    Ok(projection_cursors::table
+        .filter(projection_cursors::projection_name.eq(projection_name))
+        .select(projection_cursors::last_event_id)
+        .first::<i64>(conn)
+        .optional()?)
+}
+}
+
+backend_fn! {
+/// Records the last audit event a projection has applied.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `projection_name` - The projection
+/// * `last_event_id` - The last audit event applied
+/// * `updated_at` - When the projection was updated (RFC 3339)
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn record_projection_cursor(
+    conn: &mut _,
+    projection_name: &str,
+    last_event_id: i64,
+    updated_at: &str,
+) -> Result<(), PersistenceError> {
+    diesel::delete(
+        projection_cursors::table.filter(projection_cursors::projection_name.eq(projection_name)),
+    )
+    .execute(conn)?;
+    diesel::insert_into(projection_cursors::table)
+        .values((
+            projection_cursors::projection_name.eq(projection_name),
+            projection_cursors::last_event_id.eq(last_event_id),
+            projection_cursors::updated_at.eq(updated_at),
+        ))
+        .execute(conn)?;
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Lists the distinct scopes of the audit events in a range.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `after_event_id` - Only events after this ID are included
+/// * `through_event_id` - Only events up to this ID are included
+///
+/// # Returns
+///
+/// Each distinct `(bid_year_id, area_id)` pair.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_audit_scopes(
+    conn: &mut _,
+    after_event_id: i64,
+    through_event_id: i64,
+) -> Result<Vec<AuditScope>, PersistenceError> {
+    Ok(audit_events::table
+        .filter(audit_events::event_id.gt(after_event_id))
+        .filter(audit_events::event_id.le(through_event_id))
+        .select((audit_events::bid_year_id, audit_events::area_id))
+        .distinct()
+        .load(conn)?)
+}
+}
+
+backend_fn! {
+/// Replaces every projection row of an area.
+///
+/// Callers run this inside a transaction together with the cursor update.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `projection` - The area's new rows
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn replace_area_projection(
+    conn: &mut _,
+    projection: &AreaProjectionData,
+) -> Result<(), PersistenceError> {
+    let bid_year_id: i64 = projection.bid_year_id;
+    let area_id: i64 = projection.area_id;
+
+    diesel::delete(projected_daily_slots::table.filter(projected_daily_slots::area_id.eq(area_id)))
+        .execute(conn)?;
+    diesel::delete(projected_user_awards::table.filter(projected_user_awards::area_id.eq(area_id)))
+        .execute(conn)?;
+    diesel::delete(
+        projected_area_progress::table.filter(projected_area_progress::area_id.eq(area_id)),
+    )
+    .execute(conn)?;
+
+    for chunk in projection.daily_slots.chunks(INSERT_CHUNK_SIZE) {
+        let rows: Vec<_> = chunk
+            .iter()
+            .map(|day| {
+                (
+                    projected_daily_slots::area_id.eq(area_id),
+                    projected_daily_slots::round_id.eq(day.round_id),
+                    projected_daily_slots::slot_date.eq(&day.slot_date),
+                    projected_daily_slots::bid_year_id.eq(bid_year_id),
+                    projected_daily_slots::capacity.eq(day.capacity),
+                    projected_daily_slots::used.eq(day.used),
+                    projected_daily_slots::remaining.eq(day.remaining),
+                )
+            })
+            .collect();
+        diesel::insert_into(projected_daily_slots::table)
+            .values(rows)
+            .execute(conn)?;
+    }
+    for award in &projection.user_awards {
+        diesel::insert_into(projected_user_awards::table)
+            .values((
+                projected_user_awards::user_id.eq(award.user_id),
+                projected_user_awards::bid_year_id.eq(bid_year_id),
+                projected_user_awards::area_id.eq(area_id),
+                projected_user_awards::awarded_days.eq(award.awarded_days),
+                projected_user_awards::awarded_hours.eq(award.awarded_hours),
+            ))
+            .execute(conn)?;
+    }
+    for progress in &projection.progress {
+        diesel::insert_into(projected_area_progress::table)
+            .values((
+                projected_area_progress::area_id.eq(area_id),
+                projected_area_progress::round_id.eq(progress.round_id),
+                projected_area_progress::bid_year_id.eq(bid_year_id),
+                projected_area_progress::bidders.eq(progress.bidders),
+                projected_area_progress::not_started.eq(progress.not_started),
+                projected_area_progress::in_progress.eq(progress.in_progress),
+                projected_area_progress::completed.eq(progress.completed),
+            ))
+            .execute(conn)?;
+    }
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Lists the projected leave slots of an area within a date range.
+///
+/// Days are ordered by round, then date.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+/// * `start_date` - The first date to include
+/// * `end_date` - The last date to include
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_projected_daily_slots(
+    conn: &mut _,
+    area_id: i64,
+    start_date: &str,
+    end_date: &str,
+) -> Result<Vec<ProjectedDailySlotsData>, PersistenceError> {
+    let rows: Vec<(i64, i64, String, i32, i32, i32)> = projected_daily_slots::table
+        .filter(projected_daily_slots::area_id.eq(area_id))
+        .filter(projected_daily_slots::slot_date.ge(start_date))
+        .filter(projected_daily_slots::slot_date.le(end_date))
+        .order_by((
+            projected_daily_slots::round_id.asc(),
+            projected_daily_slots::slot_date.asc(),
+        ))
+        .select((
+            projected_daily_slots::area_id,
+            projected_daily_slots::round_id,
+            projected_daily_slots::slot_date,
+            projected_daily_slots::capacity,
+            projected_daily_slots::used,
+            projected_daily_slots::remaining,
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(area_id, round_id, slot_date, capacity, used, remaining)| ProjectedDailySlotsData {
+                area_id,
+                round_id,
+                slot_date,
+                capacity,
+                used,
+                remaining,
+            },
+        )
+        .collect())
+}
+}
+
+backend_fn! {
+/// Lists the projected approved leave of every user in an area.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_projected_user_awards(
+    conn: &mut _,
+    area_id: i64,
+) -> Result<Vec<ProjectedUserAwardData>, PersistenceError> {
+    let rows: Vec<(i64, i64, i32, i32)> = projected_user_awards::table
+        .filter(projected_user_awards::area_id.eq(area_id))
+        .order_by(projected_user_awards::user_id.asc())
+        .select((
+            projected_user_awards::user_id,
+            projected_user_awards::area_id,
+            projected_user_awards::awarded_days,
+            projected_user_awards::awarded_hours,
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(user_id, area_id, awarded_days, awarded_hours)| ProjectedUserAwardData {
+                user_id,
+                area_id,
+                awarded_days,
+                awarded_hours,
+            },
+        )
+        .collect())
+}
+}
+
+backend_fn! {
+/// Lists the projected bid status counts of every area in a bid year.
+///
+/// Rows are ordered by area, then round.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_projected_area_progress(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<ProjectedAreaProgressData>, PersistenceError> {
+    let rows: Vec<(i64, i64, i32, i32, i32, i32)> = projected_area_progress::table
+        .filter(projected_area_progress::bid_year_id.eq(bid_year_id))
+        .order_by((
+            projected_area_progress::area_id.asc(),
+            projected_area_progress::round_id.asc(),
+        ))
+        .select((
+            projected_area_progress::area_id,
+            projected_area_progress::round_id,
+            projected_area_progress::bidders,
+            projected_area_progress::not_started,
+            projected_area_progress::in_progress,
+            projected_area_progress::completed,
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(area_id, round_id, bidders, not_started, in_progress, completed)| {
+                ProjectedAreaProgressData {
+                    area_id,
+                    round_id,
+                    bidders,
+                    not_started,
+                    in_progress,
+                    completed,
+                }
+            },
+        )
+        .collect())
+}
+}
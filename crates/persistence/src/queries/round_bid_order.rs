@@ -0,0 +1,190 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Per-round bid order queries.
+//!
+//! This module contains queries for each round group's bid order rotation
+//! and for the per-round bidding sequences materialized at confirmation.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use std::collections::HashMap;
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::data_models::{RoundBidOrderData, RoundSequencingData};
+use crate::diesel_schema::{round_bid_order, round_group_rotations, round_groups, rounds};
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Lists a bid year's rounds with their round number and their round
+/// group's rotation, ordered by round.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_round_sequencing(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<RoundSequencingData>, PersistenceError> {
+    let round_rows: Vec<(i64, i64, i32)> = rounds::table
+        .inner_join(round_groups::table)
+        .filter(round_groups::bid_year_id.eq(bid_year_id))
+        .select((rounds::round_id, rounds::round_group_id, rounds::round_number))
+        .order_by(rounds::round_id.asc())
+        .load(conn)?;
+    let rotations: HashMap<i64, String> = round_group_rotations::table
+        .filter(round_group_rotations::bid_year_id.eq(bid_year_id))
+        .select((
+            round_group_rotations::round_group_id,
+            round_group_rotations::rotation,
+        ))
+        .load::<(i64, String)>(conn)?
+        .into_iter()
+        .collect();
+
+    Ok(round_rows
+        .into_iter()
+        .map(|(round_id, round_group_id, round_number)| RoundSequencingData {
+            round_id,
+            round_group_id,
+            round_number,
+            rotation: rotations.get(&round_group_id).cloned(),
+        })
+        .collect())
+}
+}
+
+backend_fn! {
+/// Gets a round group's bid order rotation, if one is set.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `round_group_id` - The round group ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_round_group_rotation(
+    conn: &mut _,
+    round_group_id: i64,
+) -> Result<Option<String>, PersistenceError> {
+    let rotation: Option<String> = round_group_rotations::table
+        .filter(round_group_rotations::round_group_id.eq(round_group_id))
+        .select(round_group_rotations::rotation)
+        .first(conn)
+        .optional()?;
+
+    Ok(rotation)
+}
+}
+
+backend_fn! {
+/// Sets or clears a round group's bid order rotation.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `round_group_id` - The round group ID
+/// * `rotation` - The rotation's name, or `None` to bid every round in
+///   canonical bid order
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn set_round_group_rotation(
+    conn: &mut _,
+    bid_year_id: i64,
+    round_group_id: i64,
+    rotation: Option<&str>,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(
+            round_group_rotations::table
+                .filter(round_group_rotations::round_group_id.eq(round_group_id)),
+        )
+        .execute(conn)?;
+
+        if let Some(rotation) = rotation {
+            diesel::insert_into(round_group_rotations::table)
+                .values((
+                    round_group_rotations::round_group_id.eq(round_group_id),
+                    round_group_rotations::bid_year_id.eq(bid_year_id),
+                    round_group_rotations::rotation.eq(rotation),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })?;
+
+    info!(round_group_id, rotation, "Round group rotation set");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Lists a round's bidding sequence, ordered by area then position.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_round_bid_order(
+    conn: &mut _,
+    round_id: i64,
+) -> Result<Vec<RoundBidOrderData>, PersistenceError> {
+    let rows: Vec<RoundBidOrderData> = round_bid_order::table
+        .filter(round_bid_order::round_id.eq(round_id))
+        .select(RoundBidOrderData::as_select())
+        .order_by((
+            round_bid_order::area_id.asc(),
+            round_bid_order::position.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows)
+}
+}
+
+backend_fn! {
+/// Stores rounds' bidding sequences.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `records` - One record per user and round
+///
+/// # Errors
+///
+/// Returns an error if the insert fails.
+pub fn bulk_insert_round_bid_order(
+    conn: &mut _,
+    records: &[RoundBidOrderData],
+) -> Result<(), PersistenceError> {
+    diesel::insert_into(round_bid_order::table)
+        .values(records)
+        .execute(conn)?;
+
+    info!(count = records.len(), "Round bid order stored");
+
+    Ok(())
+}
+}
@@ -0,0 +1,142 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Round eligibility queries.
+//!
+//! This module contains queries for the criteria that limit a round to a
+//! subset of an area's bidders. User types are stored comma-separated.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::data_models::RoundEligibilityData;
+use crate::diesel_schema::round_eligibility;
+use crate::error::PersistenceError;
+
+/// Separates the user types stored in one column.
+const USER_TYPE_SEPARATOR: &str = ",";
+
+/// Diesel Queryable struct for round eligibility rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = round_eligibility)]
+struct RoundEligibilityRow {
+    round_id: i64,
+    min_remaining_hours: Option<i32>,
+    user_types: String,
+}
+
+impl From<RoundEligibilityRow> for RoundEligibilityData {
+    fn from(row: RoundEligibilityRow) -> Self {
+        Self {
+            round_id: row.round_id,
+            min_remaining_hours: row.min_remaining_hours,
+            user_types: row
+                .user_types
+                .split(USER_TYPE_SEPARATOR)
+                .filter(|user_type| !user_type.is_empty())
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+}
+
+backend_fn! {
+/// Lists the eligibility criteria of a bid year's rounds, ordered by round.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_round_eligibility(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<RoundEligibilityData>, PersistenceError> {
+    let rows: Vec<RoundEligibilityRow> = round_eligibility::table
+        .filter(round_eligibility::bid_year_id.eq(bid_year_id))
+        .select(RoundEligibilityRow::as_select())
+        .order_by(round_eligibility::round_id.asc())
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(RoundEligibilityData::from).collect())
+}
+}
+
+backend_fn! {
+/// Gets a round's eligibility criteria, if it has any.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_round_eligibility(
+    conn: &mut _,
+    round_id: i64,
+) -> Result<Option<RoundEligibilityData>, PersistenceError> {
+    let row: Option<RoundEligibilityRow> = round_eligibility::table
+        .filter(round_eligibility::round_id.eq(round_id))
+        .select(RoundEligibilityRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(RoundEligibilityData::from))
+}
+}
+
+backend_fn! {
+/// Sets or clears a round's eligibility criteria.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `round_id` - The round ID
+/// * `criteria` - The round's criteria, or `None` to admit every bidder
+///   (its `round_id` is ignored)
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn set_round_eligibility(
+    conn: &mut _,
+    bid_year_id: i64,
+    round_id: i64,
+    criteria: Option<&RoundEligibilityData>,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(round_eligibility::table.filter(round_eligibility::round_id.eq(round_id)))
+            .execute(conn)?;
+
+        if let Some(criteria) = criteria {
+            diesel::insert_into(round_eligibility::table)
+                .values((
+                    round_eligibility::round_id.eq(round_id),
+                    round_eligibility::bid_year_id.eq(bid_year_id),
+                    round_eligibility::min_remaining_hours.eq(criteria.min_remaining_hours),
+                    round_eligibility::user_types
+                        .eq(criteria.user_types.join(USER_TYPE_SEPARATOR)),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })?;
+
+    info!(round_id, restricted = criteria.is_some(), "Round eligibility set");
+
+    Ok(())
+}
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Round sign-off queries.
+//!
+//! This module records and reads the sign-offs of completed rounds. A
+//! round is signed off once per area.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::RoundSignOffData;
+use crate::diesel_schema::round_sign_offs;
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for round sign-off rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = round_sign_offs)]
+struct RoundSignOffRow {
+    round_sign_off_id: i64,
+    bid_year_id: i64,
+    area_id: i64,
+    round_id: i64,
+    audit_event_id: i64,
+    signed_off_at: String,
+}
+
+impl From<RoundSignOffRow> for RoundSignOffData {
+    fn from(row: RoundSignOffRow) -> Self {
+        Self {
+            round_sign_off_id: row.round_sign_off_id,
+            bid_year_id: row.bid_year_id,
+            area_id: row.area_id,
+            round_id: row.round_id,
+            audit_event_id: row.audit_event_id,
+            signed_off_at: row.signed_off_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Records the sign-off of a round in an area.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+/// * `audit_event_id` - The audit event that recorded the sign-off
+///
+/// # Errors
+///
+/// Returns an error if the round is already signed off in the area or the
+/// insert fails.
+pub fn insert_round_sign_off(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    round_id: i64,
+    audit_event_id: i64,
+) -> Result<i64, PersistenceError> {
+    let round_sign_off_id: i64 = conn.transaction::<i64, PersistenceError, _>(|conn| {
+        diesel::insert_into(round_sign_offs::table)
+            .values((
+                round_sign_offs::bid_year_id.eq(bid_year_id),
+                round_sign_offs::area_id.eq(area_id),
+                round_sign_offs::round_id.eq(round_id),
+                round_sign_offs::audit_event_id.eq(audit_event_id),
+            ))
+            .execute(conn)?;
+        conn.get_last_insert_rowid()
+    })?;
+
+    info!(round_sign_off_id, area_id, round_id, "Round signed off");
+
+    Ok(round_sign_off_id)
+}
+}
+
+backend_fn! {
+/// Gets the sign-off of a round in an area, if it has been signed off.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_round_sign_off(
+    conn: &mut _,
+    area_id: i64,
+    round_id: i64,
+) -> Result<Option<RoundSignOffData>, PersistenceError> {
+    let row: Option<RoundSignOffRow> = round_sign_offs::table
+        .filter(round_sign_offs::area_id.eq(area_id))
+        .filter(round_sign_offs::round_id.eq(round_id))
+        .select(RoundSignOffRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(RoundSignOffData::from))
+}
+}
+
+backend_fn! {
+/// Lists the round sign-offs of a bid year, ordered by area then round.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_round_sign_offs(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<RoundSignOffData>, PersistenceError> {
+    let rows: Vec<RoundSignOffRow> = round_sign_offs::table
+        .filter(round_sign_offs::bid_year_id.eq(bid_year_id))
+        .select(RoundSignOffRow::as_select())
+        .order_by((round_sign_offs::area_id.asc(), round_sign_offs::round_id.asc()))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(RoundSignOffData::from).collect())
+}
+}
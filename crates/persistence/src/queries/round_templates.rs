@@ -0,0 +1,327 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Round group template queries.
+//!
+//! This module contains queries for managing the global round group
+//! templates and for instantiating round groups with their rounds in a
+//! bid year, whether from a template or copied from another bid year.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use std::collections::BTreeMap;
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use num_traits::cast::ToPrimitive;
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::{RoundGroupSpecData, RoundGroupTemplateData, RoundSpecData};
+use crate::diesel_schema::{round_group_templates, round_groups, round_templates, rounds};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for round template rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = round_templates)]
+struct RoundTemplateRow {
+    template_id: i64,
+    round_number: i32,
+    name: String,
+    slots_per_day: i32,
+    max_groups: i32,
+    max_total_hours: i32,
+    include_holidays: i32,
+    allow_overbid: i32,
+}
+
+impl From<RoundTemplateRow> for RoundSpecData {
+    fn from(row: RoundTemplateRow) -> Self {
+        Self {
+            round_number: row.round_number.to_u32().unwrap_or(0),
+            name: row.name,
+            slots_per_day: row.slots_per_day.to_u32().unwrap_or(0),
+            max_groups: row.max_groups.to_u32().unwrap_or(0),
+            max_total_hours: row.max_total_hours.to_u32().unwrap_or(0),
+            include_holidays: row.include_holidays != 0,
+            allow_overbid: row.allow_overbid != 0,
+        }
+    }
+}
+
+backend_fn! {
+/// Stores a new round group template with its rounds.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `name` - The template name (unique across all templates)
+/// * `editing_enabled` - The `editing_enabled` value for instantiated groups
+/// * `rounds` - The template's rounds
+///
+/// # Errors
+///
+/// Returns an error if the insert fails, including when the name is taken.
+pub fn insert_round_group_template(
+    conn: &mut _,
+    name: &str,
+    editing_enabled: bool,
+    rounds: &[RoundSpecData],
+) -> Result<i64, PersistenceError> {
+    let template_id: i64 = conn.transaction::<i64, PersistenceError, _>(|conn| {
+        diesel::insert_into(round_group_templates::table)
+            .values((
+                round_group_templates::name.eq(name),
+                round_group_templates::editing_enabled.eq(i32::from(editing_enabled)),
+            ))
+            .execute(conn)?;
+        let template_id: i64 = conn.get_last_insert_rowid()?;
+
+        for round in rounds {
+            diesel::insert_into(round_templates::table)
+                .values((
+                    round_templates::template_id.eq(template_id),
+                    round_templates::round_number.eq(round.round_number.to_i32().unwrap_or(0)),
+                    round_templates::name.eq(&round.name),
+                    round_templates::slots_per_day.eq(round.slots_per_day.to_i32().unwrap_or(0)),
+                    round_templates::max_groups.eq(round.max_groups.to_i32().unwrap_or(0)),
+                    round_templates::max_total_hours
+                        .eq(round.max_total_hours.to_i32().unwrap_or(0)),
+                    round_templates::include_holidays.eq(i32::from(round.include_holidays)),
+                    round_templates::allow_overbid.eq(i32::from(round.allow_overbid)),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(template_id)
+    })?;
+
+    info!(template_id, name, round_count = rounds.len(), "Round group template created");
+
+    Ok(template_id)
+}
+}
+
+backend_fn! {
+/// Lists all round group templates with their rounds, ordered by name.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_round_group_templates(
+    conn: &mut _,
+) -> Result<Vec<RoundGroupTemplateData>, PersistenceError> {
+    let templates: Vec<(i64, String, i32, String)> = round_group_templates::table
+        .select((
+            round_group_templates::template_id,
+            round_group_templates::name,
+            round_group_templates::editing_enabled,
+            round_group_templates::created_at,
+        ))
+        .order_by(round_group_templates::name.asc())
+        .load(conn)?;
+
+    let mut rounds_by_template: BTreeMap<i64, Vec<RoundSpecData>> = BTreeMap::new();
+    let round_rows: Vec<RoundTemplateRow> = round_templates::table
+        .select(RoundTemplateRow::as_select())
+        .order_by((
+            round_templates::template_id.asc(),
+            round_templates::round_number.asc(),
+        ))
+        .load(conn)?;
+    for row in round_rows {
+        rounds_by_template
+            .entry(row.template_id)
+            .or_default()
+            .push(RoundSpecData::from(row));
+    }
+
+    Ok(templates
+        .into_iter()
+        .map(|(template_id, name, editing_enabled, created_at)| RoundGroupTemplateData {
+            template_id,
+            name,
+            editing_enabled: editing_enabled != 0,
+            rounds: rounds_by_template.remove(&template_id).unwrap_or_default(),
+            created_at,
+        })
+        .collect())
+}
+}
+
+backend_fn! {
+/// Gets a single round group template with its rounds.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `template_id` - The template ID
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the template does not exist, or
+/// an error if the query fails.
+pub fn get_round_group_template(
+    conn: &mut _,
+    template_id: i64,
+) -> Result<RoundGroupTemplateData, PersistenceError> {
+    let (name, editing_enabled, created_at): (String, i32, String) = round_group_templates::table
+        .filter(round_group_templates::template_id.eq(template_id))
+        .select((
+            round_group_templates::name,
+            round_group_templates::editing_enabled,
+            round_group_templates::created_at,
+        ))
+        .first(conn)
+        .optional()?
+        .ok_or_else(|| {
+            PersistenceError::NotFound(format!("Round group template {template_id} not found"))
+        })?;
+
+    let rounds: Vec<RoundSpecData> = round_templates::table
+        .filter(round_templates::template_id.eq(template_id))
+        .select(RoundTemplateRow::as_select())
+        .order_by(round_templates::round_number.asc())
+        .load(conn)?
+        .into_iter()
+        .map(RoundSpecData::from)
+        .collect();
+
+    Ok(RoundGroupTemplateData {
+        template_id,
+        name,
+        editing_enabled: editing_enabled != 0,
+        rounds,
+        created_at,
+    })
+}
+}
+
+backend_fn! {
+/// Checks if a round group template name is taken.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `name` - The template name
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn round_group_template_name_exists(
+    conn: &mut _,
+    name: &str,
+) -> Result<bool, PersistenceError> {
+    let count: i64 = round_group_templates::table
+        .filter(round_group_templates::name.eq(name))
+        .count()
+        .get_result(conn)?;
+
+    Ok(count > 0)
+}
+}
+
+backend_fn! {
+/// Deletes a round group template and its rounds.
+///
+/// Round groups already instantiated from the template are unaffected.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `template_id` - The template ID
+///
+/// # Errors
+///
+/// Returns an error if the delete fails.
+pub fn delete_round_group_template(
+    conn: &mut _,
+    template_id: i64,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        diesel::delete(round_templates::table.filter(round_templates::template_id.eq(template_id)))
+            .execute(conn)?;
+        diesel::delete(
+            round_group_templates::table.filter(round_group_templates::template_id.eq(template_id)),
+        )
+        .execute(conn)?;
+        Ok(())
+    })?;
+
+    info!(template_id, "Round group template deleted");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Creates round groups and their rounds in a bid year atomically.
+///
+/// Either every group and round is created or none are.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year to create the groups in
+/// * `groups` - The round groups to create
+///
+/// # Returns
+///
+/// The new round group IDs, in the order of `groups`.
+///
+/// # Errors
+///
+/// Returns an error if any insert fails.
+pub fn instantiate_round_groups(
+    conn: &mut _,
+    bid_year_id: i64,
+    groups: &[RoundGroupSpecData],
+) -> Result<Vec<i64>, PersistenceError> {
+    let round_group_ids: Vec<i64> = conn.transaction::<Vec<i64>, PersistenceError, _>(|conn| {
+        let mut round_group_ids: Vec<i64> = Vec::with_capacity(groups.len());
+        for group in groups {
+            diesel::insert_into(round_groups::table)
+                .values((
+                    round_groups::bid_year_id.eq(bid_year_id),
+                    round_groups::name.eq(&group.name),
+                    round_groups::editing_enabled.eq(i32::from(group.editing_enabled)),
+                ))
+                .execute(conn)?;
+            let round_group_id: i64 = conn.get_last_insert_rowid()?;
+
+            for round in &group.rounds {
+                diesel::insert_into(rounds::table)
+                    .values((
+                        rounds::round_group_id.eq(round_group_id),
+                        rounds::round_number.eq(round.round_number.to_i32().unwrap_or(0)),
+                        rounds::name.eq(&round.name),
+                        rounds::slots_per_day.eq(round.slots_per_day.to_i32().unwrap_or(0)),
+                        rounds::max_groups.eq(round.max_groups.to_i32().unwrap_or(0)),
+                        rounds::max_total_hours.eq(round.max_total_hours.to_i32().unwrap_or(0)),
+                        rounds::include_holidays.eq(i32::from(round.include_holidays)),
+                        rounds::allow_overbid.eq(i32::from(round.allow_overbid)),
+                    ))
+                    .execute(conn)?;
+            }
+
+            round_group_ids.push(round_group_id);
+        }
+        Ok(round_group_ids)
+    })?;
+
+    info!(
+        bid_year_id,
+        group_count = round_group_ids.len(),
+        "Round groups instantiated"
+    );
+
+    Ok(round_group_ids)
+}
+}
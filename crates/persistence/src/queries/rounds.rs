@@ -522,6 +522,61 @@ pub fn round_number_exists(
 }
 }
 
+backend_fn! {
+/// Renumbers the rounds of a round group to match the given order.
+///
+/// The round at position `i` of `ordered_round_ids` becomes round `i + 1`.
+/// Rounds are first moved to temporary negative numbers so the
+/// `(round_group_id, round_number)` uniqueness constraint holds at every
+/// step, and the whole renumbering runs in a single transaction.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `round_group_id` - The round group ID
+/// * `ordered_round_ids` - Every round ID of the group, in the new order
+///
+/// # Errors
+///
+/// Returns an error if `ordered_round_ids` is not exactly the set of rounds
+/// in the group, or if an update fails. Nothing is changed on error.
+pub fn reorder_rounds(
+    conn: &mut _,
+    round_group_id: i64,
+    ordered_round_ids: &[i64],
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        let mut existing: Vec<i64> = rounds::table
+            .filter(rounds::round_group_id.eq(round_group_id))
+            .select(rounds::round_id)
+            .load(conn)?;
+        existing.sort_unstable();
+        let mut requested: Vec<i64> = ordered_round_ids.to_vec();
+        requested.sort_unstable();
+        if existing != requested {
+            return Err(PersistenceError::Other(format!(
+                "Round order for round group {round_group_id} must list each of its rounds exactly once"
+            )));
+        }
+
+        for (position, round_id) in ordered_round_ids.iter().enumerate() {
+            let temporary: i32 = -(position.to_i32().unwrap_or(0) + 1);
+            diesel::update(rounds::table.filter(rounds::round_id.eq(round_id)))
+                .set(rounds::round_number.eq(temporary))
+                .execute(conn)?;
+        }
+        for (position, round_id) in ordered_round_ids.iter().enumerate() {
+            let round_number: i32 = position.to_i32().unwrap_or(0) + 1;
+            diesel::update(rounds::table.filter(rounds::round_id.eq(round_id)))
+                .set(rounds::round_number.eq(round_number))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })
+}
+}
+
 backend_fn! {
 /// Lists all rounds for a given bid year (across all round groups).
 ///
@@ -0,0 +1,111 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Saved audit query queries.
+//!
+//! This module reads the named audit timeline filters admins have saved
+//! and their weekly delivery schedules.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+
+use crate::data_models::SavedAuditQueryData;
+use crate::diesel_schema::saved_audit_queries;
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for saved audit query rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = saved_audit_queries)]
+struct SavedAuditQueryRow {
+    saved_query_id: i64,
+    name: String,
+    scope_json: String,
+    filter_json: String,
+    weekly_recipients: String,
+    created_by_operator_id: i64,
+    created_at: String,
+    last_delivered_at: Option<String>,
+}
+
+impl From<SavedAuditQueryRow> for SavedAuditQueryData {
+    fn from(row: SavedAuditQueryRow) -> Self {
+        Self {
+            saved_query_id: row.saved_query_id,
+            name: row.name,
+            scope_json: row.scope_json,
+            filter_json: row.filter_json,
+            weekly_recipients: decode_recipients(&row.weekly_recipients),
+            created_by_operator_id: row.created_by_operator_id,
+            created_at: row.created_at,
+            last_delivered_at: row.last_delivered_at,
+        }
+    }
+}
+
+/// Encodes recipient addresses for storage as a comma-separated list.
+pub fn encode_recipients(recipients: &[String]) -> String {
+    recipients.join(",")
+}
+
+/// Decodes a stored comma-separated recipient list.
+fn decode_recipients(stored: &str) -> Vec<String> {
+    stored
+        .split(',')
+        .map(str::trim)
+        .filter(|recipient| !recipient.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+backend_fn! {
+/// Lists all saved audit queries ordered by name.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_saved_audit_queries(
+    conn: &mut _,
+) -> Result<Vec<SavedAuditQueryData>, PersistenceError> {
+    let rows: Vec<SavedAuditQueryRow> = saved_audit_queries::table
+        .select(SavedAuditQueryRow::as_select())
+        .order_by(saved_audit_queries::name.asc())
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(SavedAuditQueryData::from).collect())
+}
+}
+
+backend_fn! {
+/// Retrieves a saved audit query by ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `saved_query_id` - The saved query ID
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+/// Returns `Ok(None)` if the saved query is not found.
+pub fn get_saved_audit_query(
+    conn: &mut _,
+    saved_query_id: i64,
+) -> Result<Option<SavedAuditQueryData>, PersistenceError> {
+    let row: Option<SavedAuditQueryRow> = saved_audit_queries::table
+        .filter(saved_audit_queries::saved_query_id.eq(saved_query_id))
+        .select(SavedAuditQueryRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(SavedAuditQueryData::from))
+}
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Scope freeze queries.
+//!
+//! This module records, lifts, and reads freezes of areas. An area is
+//! frozen at most once at a time; unfreezing deletes its row, leaving the
+//! audit log as the history of past freezes.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::ScopeFreezeData;
+use crate::diesel_schema::scope_freezes;
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for scope freeze rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = scope_freezes)]
+struct ScopeFreezeRow {
+    scope_freeze_id: i64,
+    bid_year_id: i64,
+    area_id: i64,
+    reason: String,
+    audit_event_id: i64,
+    frozen_at: String,
+}
+
+impl From<ScopeFreezeRow> for ScopeFreezeData {
+    fn from(row: ScopeFreezeRow) -> Self {
+        Self {
+            scope_freeze_id: row.scope_freeze_id,
+            bid_year_id: row.bid_year_id,
+            area_id: row.area_id,
+            reason: row.reason,
+            audit_event_id: row.audit_event_id,
+            frozen_at: row.frozen_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Records the freeze of an area.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `area_id` - The area ID
+/// * `reason` - Why the area is frozen
+/// * `audit_event_id` - The audit event that recorded the freeze
+///
+/// # Errors
+///
+/// Returns an error if the area is already frozen or the insert fails.
+pub fn insert_scope_freeze(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    reason: &str,
+    audit_event_id: i64,
+) -> Result<i64, PersistenceError> {
+    let scope_freeze_id: i64 = conn.transaction::<i64, PersistenceError, _>(|conn| {
+        diesel::insert_into(scope_freezes::table)
+            .values((
+                scope_freezes::bid_year_id.eq(bid_year_id),
+                scope_freezes::area_id.eq(area_id),
+                scope_freezes::reason.eq(reason),
+                scope_freezes::audit_event_id.eq(audit_event_id),
+            ))
+            .execute(conn)?;
+        conn.get_last_insert_rowid()
+    })?;
+
+    info!(scope_freeze_id, area_id, "Area frozen");
+
+    Ok(scope_freeze_id)
+}
+}
+
+backend_fn! {
+/// Lifts the freeze of an area.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+///
+/// # Returns
+///
+/// Whether the area was frozen.
+///
+/// # Errors
+///
+/// Returns an error if the delete fails.
+pub fn delete_scope_freeze(conn: &mut _, area_id: i64) -> Result<bool, PersistenceError> {
+    let deleted: usize =
+        diesel::delete(scope_freezes::table.filter(scope_freezes::area_id.eq(area_id)))
+            .execute(conn)?;
+
+    if deleted > 0 {
+        info!(area_id, "Area unfrozen");
+    }
+
+    Ok(deleted > 0)
+}
+}
+
+backend_fn! {
+/// Lists the frozen areas of a bid year, ordered by area.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_scope_freezes(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<ScopeFreezeData>, PersistenceError> {
+    let rows: Vec<ScopeFreezeRow> = scope_freezes::table
+        .filter(scope_freezes::bid_year_id.eq(bid_year_id))
+        .select(ScopeFreezeRow::as_select())
+        .order_by(scope_freezes::area_id.asc())
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(ScopeFreezeData::from).collect())
+}
+}
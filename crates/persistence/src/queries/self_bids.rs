@@ -0,0 +1,149 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Self-service bid queries.
+//!
+//! This module reads the controller each operator is in the roster and
+//! the leave such operators asked to enter for themselves.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+
+use crate::data_models::SelfBidRequestData;
+use crate::diesel_schema::{operator_controllers, self_bid_requests};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for self-service bid request rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = self_bid_requests)]
+struct SelfBidRequestRow {
+    self_bid_request_id: i64,
+    bid_year_id: i64,
+    area_id: i64,
+    user_id: i64,
+    round_id: i64,
+    requested_by_operator_id: i64,
+    leave_dates: String,
+    hours: i32,
+    on_behalf_of: String,
+    received_via: String,
+    status: String,
+    request_event_id: Option<i64>,
+    confirmed_by_operator_id: Option<i64>,
+    confirm_event_id: Option<i64>,
+    created_at: String,
+    confirmed_at: Option<String>,
+}
+
+impl From<SelfBidRequestRow> for SelfBidRequestData {
+    fn from(row: SelfBidRequestRow) -> Self {
+        Self {
+            self_bid_request_id: row.self_bid_request_id,
+            bid_year_id: row.bid_year_id,
+            area_id: row.area_id,
+            user_id: row.user_id,
+            round_id: row.round_id,
+            requested_by_operator_id: row.requested_by_operator_id,
+            leave_dates: row
+                .leave_dates
+                .split('|')
+                .filter(|date| !date.is_empty())
+                .map(String::from)
+                .collect(),
+            hours: row.hours,
+            on_behalf_of: row.on_behalf_of,
+            received_via: row.received_via,
+            status: row.status,
+            request_event_id: row.request_event_id,
+            confirmed_by_operator_id: row.confirmed_by_operator_id,
+            confirm_event_id: row.confirm_event_id,
+            created_at: row.created_at,
+            confirmed_at: row.confirmed_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Gets the initials of the controller an operator is in the roster.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `operator_id` - The operator ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_operator_controller(
+    conn: &mut _,
+    operator_id: i64,
+) -> Result<Option<String>, PersistenceError> {
+    let initials: Option<String> = operator_controllers::table
+        .filter(operator_controllers::operator_id.eq(operator_id))
+        .select(operator_controllers::initials)
+        .first(conn)
+        .optional()?;
+
+    Ok(initials)
+}
+}
+
+backend_fn! {
+/// Gets a self-service bid request by ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `self_bid_request_id` - The self-service bid request ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_self_bid_request(
+    conn: &mut _,
+    self_bid_request_id: i64,
+) -> Result<Option<SelfBidRequestData>, PersistenceError> {
+    let row: Option<SelfBidRequestRow> = self_bid_requests::table
+        .filter(self_bid_requests::self_bid_request_id.eq(self_bid_request_id))
+        .select(SelfBidRequestRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(SelfBidRequestData::from))
+}
+}
+
+backend_fn! {
+/// Lists the self-service bid requests for a round in an area.
+///
+/// Requests are ordered by when they were made.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_self_bid_requests(
+    conn: &mut _,
+    area_id: i64,
+    round_id: i64,
+) -> Result<Vec<SelfBidRequestData>, PersistenceError> {
+    let rows: Vec<SelfBidRequestRow> = self_bid_requests::table
+        .filter(self_bid_requests::area_id.eq(area_id))
+        .filter(self_bid_requests::round_id.eq(round_id))
+        .select(SelfBidRequestRow::as_select())
+        .order_by(self_bid_requests::self_bid_request_id.asc())
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(SelfBidRequestData::from).collect())
+}
+}
@@ -0,0 +1,173 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Slot inventory queries.
+//!
+//! This module stores the staffing adjustments to the daily leave slots of
+//! each round, per area and date. A day's capacity is the round's
+//! `slots_per_day` plus its adjustment; days without a stored adjustment
+//! use `slots_per_day` unchanged.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::data_models::SlotAdjustmentData;
+use crate::diesel_schema::slot_inventory;
+use crate::error::PersistenceError;
+
+backend_fn! {
+/// Lists the staffing adjustments of a bid year within a date range.
+///
+/// Adjustments are ordered by date, then area, then round.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `start_date` - The first date to include
+/// * `end_date` - The last date to include
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_slot_adjustments(
+    conn: &mut _,
+    bid_year_id: i64,
+    start_date: &str,
+    end_date: &str,
+) -> Result<Vec<SlotAdjustmentData>, PersistenceError> {
+    let rows: Vec<(i64, i64, String, i32)> = slot_inventory::table
+        .filter(slot_inventory::bid_year_id.eq(bid_year_id))
+        .filter(slot_inventory::slot_date.ge(start_date))
+        .filter(slot_inventory::slot_date.le(end_date))
+        .select((
+            slot_inventory::area_id,
+            slot_inventory::round_id,
+            slot_inventory::slot_date,
+            slot_inventory::staffing_adjustment,
+        ))
+        .order_by((
+            slot_inventory::slot_date.asc(),
+            slot_inventory::area_id.asc(),
+            slot_inventory::round_id.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(area_id, round_id, slot_date, staffing_adjustment)| SlotAdjustmentData {
+                area_id,
+                round_id,
+                slot_date,
+                staffing_adjustment,
+            },
+        )
+        .collect())
+}
+}
+
+backend_fn! {
+/// Gets the staffing adjustment of one day of a round in an area.
+///
+/// Returns `0` when no adjustment is stored.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+/// * `slot_date` - The date (`YYYY-MM-DD`)
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn get_slot_adjustment(
+    conn: &mut _,
+    area_id: i64,
+    round_id: i64,
+    slot_date: &str,
+) -> Result<i32, PersistenceError> {
+    let adjustment: Option<i32> = slot_inventory::table
+        .filter(slot_inventory::area_id.eq(area_id))
+        .filter(slot_inventory::round_id.eq(round_id))
+        .filter(slot_inventory::slot_date.eq(slot_date))
+        .select(slot_inventory::staffing_adjustment)
+        .first(conn)
+        .optional()?;
+
+    Ok(adjustment.unwrap_or(0))
+}
+}
+
+backend_fn! {
+/// Records the staffing adjustment of one day of a round in an area.
+///
+/// Replaces any previously stored adjustment for the day.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The bid year ID
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+/// * `slot_date` - The date (`YYYY-MM-DD`)
+/// * `staffing_adjustment` - The change to the round's `slots_per_day`
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn set_slot_adjustment(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    round_id: i64,
+    slot_date: &str,
+    staffing_adjustment: i32,
+) -> Result<(), PersistenceError> {
+    conn.transaction::<(), PersistenceError, _>(|conn| {
+        let existing: Option<i64> = slot_inventory::table
+            .filter(slot_inventory::area_id.eq(area_id))
+            .filter(slot_inventory::round_id.eq(round_id))
+            .filter(slot_inventory::slot_date.eq(slot_date))
+            .select(slot_inventory::slot_inventory_id)
+            .first(conn)
+            .optional()?;
+
+        if let Some(slot_inventory_id) = existing {
+            diesel::update(slot_inventory::table)
+                .filter(slot_inventory::slot_inventory_id.eq(slot_inventory_id))
+                .set(slot_inventory::staffing_adjustment.eq(staffing_adjustment))
+                .execute(conn)?;
+        } else {
+            diesel::insert_into(slot_inventory::table)
+                .values((
+                    slot_inventory::bid_year_id.eq(bid_year_id),
+                    slot_inventory::area_id.eq(area_id),
+                    slot_inventory::round_id.eq(round_id),
+                    slot_inventory::slot_date.eq(slot_date),
+                    slot_inventory::staffing_adjustment.eq(staffing_adjustment),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })?;
+
+    info!(
+        area_id,
+        round_id,
+        slot_date,
+        staffing_adjustment,
+        "Slot adjustment updated"
+    );
+
+    Ok(())
+}
+}
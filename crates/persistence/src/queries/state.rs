@@ -16,17 +16,58 @@ use diesel::{MysqlConnection, SqliteConnection};
 use zab_bid::State;
 use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, User, UserType};
 
-use crate::data_models::StateData;
+use crate::data_models::{BinaryStateData, SnapshotEncoding, StateData};
 use crate::diesel_schema::{audit_events, state_snapshots, users};
 use crate::error::PersistenceError;
 
 /// Diesel Queryable struct for state snapshot rows.
 #[derive(Queryable, Selectable)]
 #[diesel(table_name = state_snapshots)]
-#[allow(dead_code)]
-struct StateSnapshotRow {
+pub struct StateSnapshotRow {
     state_json: String,
     event_id: i64,
+    state_format: String,
+    state_binary: Option<Vec<u8>>,
+}
+
+impl StateSnapshotRow {
+    /// Reads the encoding the snapshot was written with.
+    pub fn encoding(&self) -> Result<SnapshotEncoding, PersistenceError> {
+        self.state_format.parse().map_err(|e: String| {
+            PersistenceError::ReconstructionError(format!("Snapshot {}: {e}", self.event_id))
+        })
+    }
+
+    /// Decodes the snapshot in the encoding it was written with.
+    pub fn into_state(self) -> Result<(State, i64), PersistenceError> {
+        let encoding: SnapshotEncoding = self.encoding()?;
+        let state: State = match encoding {
+            SnapshotEncoding::Json => {
+                let state_data: StateData = serde_json::from_str(&self.state_json)?;
+                let users: Vec<User> = serde_json::from_str(&state_data.users_json)?;
+                State::with_users(
+                    BidYear::new(state_data.bid_year),
+                    Area::new(&state_data.area),
+                    users,
+                )
+            }
+            SnapshotEncoding::Postcard => {
+                let bytes: &[u8] = self.state_binary.as_deref().ok_or_else(|| {
+                    PersistenceError::ReconstructionError(format!(
+                        "Snapshot {} is missing its binary state",
+                        self.event_id
+                    ))
+                })?;
+                let state_data: BinaryStateData<User> = postcard::from_bytes(bytes)?;
+                State::with_users(
+                    BidYear::new(state_data.bid_year),
+                    Area::new(&state_data.area),
+                    state_data.users,
+                )
+            }
+        };
+        Ok((state, self.event_id))
+    }
 }
 
 /// Diesel Queryable struct for user rows.
@@ -80,10 +121,10 @@ pub fn get_latest_snapshot(
         .filter(state_snapshots::bid_year_id.eq(bid_year_id))
         .filter(state_snapshots::area_id.eq(area_id))
         .order(state_snapshots::event_id.desc())
-        .select((state_snapshots::state_json, state_snapshots::event_id))
-        .first::<(String, i64)>(conn);
+        .select(StateSnapshotRow::as_select())
+        .first::<StateSnapshotRow>(conn);
 
-    let (state_json, event_id) = match result {
+    let row: StateSnapshotRow = match result {
         Ok(r) => r,
         Err(diesel::result::Error::NotFound) => {
             return Err(PersistenceError::SnapshotNotFound {
@@ -94,17 +135,7 @@ pub fn get_latest_snapshot(
         Err(e) => return Err(PersistenceError::from(e)),
     };
 
-    let state_data: StateData = serde_json::from_str(&state_json)?;
-    let users: Vec<_> = serde_json::from_str(&state_data.users_json)?;
-
-    Ok((
-        State {
-            bid_year: BidYear::new(state_data.bid_year),
-            area: Area::new(&state_data.area),
-            users,
-        },
-        event_id,
-    ))
+    row.into_state()
 }
 }
 
@@ -138,10 +169,57 @@ pub fn get_snapshot_before_timestamp(
         .filter(state_snapshots::area_id.eq(area_id))
         .filter(audit_events::created_at.le(timestamp))
         .order(state_snapshots::event_id.desc())
-        .select((state_snapshots::state_json, state_snapshots::event_id))
-        .first::<(String, i64)>(conn);
+        .select(StateSnapshotRow::as_select())
+        .first::<StateSnapshotRow>(conn);
+
+    let row: StateSnapshotRow = match result {
+        Ok(r) => r,
+        Err(diesel::result::Error::NotFound) => {
+            return Err(PersistenceError::SnapshotNotFound {
+                bid_year: 0,
+                area: String::from("unknown"),
+            });
+        }
+        Err(e) => return Err(PersistenceError::from(e)),
+    };
+
+    row.into_state()
+}
+}
+
+backend_fn! {
+/// Retrieves the most recent snapshot at or before a given audit event.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `bid_year_id` - The canonical bid year ID
+/// * `area_id` - The canonical area ID
+/// * `event_id` - The target audit event ID
+///
+/// # Errors
+///
+/// Returns an error if no snapshot exists at or before the event.
+///
+/// # Generated Functions
+///
+/// - `get_snapshot_at_or_before_event_sqlite(&mut SqliteConnection, i64, i64, i64)`
+/// - `get_snapshot_at_or_before_event_mysql(&mut MysqlConnection, i64, i64, i64)`
+pub fn get_snapshot_at_or_before_event(
+    conn: &mut _,
+    bid_year_id: i64,
+    area_id: i64,
+    event_id: i64,
+) -> Result<(State, i64), PersistenceError> {
+    let result = state_snapshots::table
+        .filter(state_snapshots::bid_year_id.eq(bid_year_id))
+        .filter(state_snapshots::area_id.eq(area_id))
+        .filter(state_snapshots::event_id.le(event_id))
+        .order(state_snapshots::event_id.desc())
+        .select(StateSnapshotRow::as_select())
+        .first::<StateSnapshotRow>(conn);
 
-    let (state_json, event_id) = match result {
+    let row: StateSnapshotRow = match result {
         Ok(r) => r,
         Err(diesel::result::Error::NotFound) => {
             return Err(PersistenceError::SnapshotNotFound {
@@ -152,17 +230,7 @@ pub fn get_snapshot_before_timestamp(
         Err(e) => return Err(PersistenceError::from(e)),
     };
 
-    let state_data: StateData = serde_json::from_str(&state_json)?;
-    let users: Vec<_> = serde_json::from_str(&state_data.users_json)?;
-
-    Ok((
-        State {
-            bid_year: BidYear::new(state_data.bid_year),
-            area: Area::new(&state_data.area),
-            users,
-        },
-        event_id,
-    ))
+    row.into_state()
 }
 }
 
@@ -239,11 +307,7 @@ pub fn get_current_state(
         users_vec.push(user);
     }
 
-    let state: State = State {
-        bid_year: bid_year.clone(),
-        area: area.clone(),
-        users: users_vec,
-    };
+    let state: State = State::with_users(bid_year.clone(), area.clone(), users_vec);
 
     tracing::info!(
         bid_year = bid_year.year(),
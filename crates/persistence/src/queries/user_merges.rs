@@ -0,0 +1,270 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! User merge ledger queries.
+//!
+//! This module contains backend-agnostic queries for the ledger of
+//! duplicate user records merged into a surviving record, and for the
+//! checks made before a merge.
+
+use std::collections::BTreeMap;
+
+use diesel::dsl::count_star;
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+
+use crate::data_models::UserMergeData;
+use crate::diesel_schema::{
+    bid_preferences, canonical_area_membership, canonical_bid_order, canonical_bid_windows,
+    canonical_eligibility, leave_bids, user_merge_rows, user_merges, waitlist_offers,
+};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for user merge rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = user_merges)]
+struct UserMergeRow {
+    user_merge_id: i64,
+    bid_year_id: i64,
+    kept_user_id: i64,
+    merged_user_id: i64,
+    reason: String,
+    previous_excluded_from_bidding: i32,
+    previous_excluded_from_leave_calculation: i32,
+    merged_carryover_hours: Option<i32>,
+    kept_carryover_hours: Option<i32>,
+    merge_event_id: i64,
+    revert_event_id: Option<i64>,
+    created_at: String,
+}
+
+impl UserMergeRow {
+    fn into_data(self, rows_moved: usize) -> UserMergeData {
+        UserMergeData {
+            user_merge_id: self.user_merge_id,
+            bid_year_id: self.bid_year_id,
+            kept_user_id: self.kept_user_id,
+            merged_user_id: self.merged_user_id,
+            reason: self.reason,
+            previous_excluded_from_bidding: self.previous_excluded_from_bidding != 0,
+            previous_excluded_from_leave_calculation: self.previous_excluded_from_leave_calculation
+                != 0,
+            merged_carryover_hours: self.merged_carryover_hours,
+            kept_carryover_hours: self.kept_carryover_hours,
+            merge_event_id: self.merge_event_id,
+            revert_event_id: self.revert_event_id,
+            rows_moved,
+            created_at: self.created_at,
+        }
+    }
+}
+
+backend_fn! {
+/// Lists every merge recorded for a bid year, oldest first.
+///
+/// Reverted merges are included so the ledger reads as a history.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_user_merges(
+    conn: &mut _,
+    bid_year_id: i64,
+) -> Result<Vec<UserMergeData>, PersistenceError> {
+    let rows: Vec<UserMergeRow> = user_merges::table
+        .filter(user_merges::bid_year_id.eq(bid_year_id))
+        .order_by(user_merges::user_merge_id.asc())
+        .select(UserMergeRow::as_select())
+        .load(conn)?;
+
+    let moved: BTreeMap<i64, i64> = user_merge_rows::table
+        .inner_join(user_merges::table)
+        .filter(user_merges::bid_year_id.eq(bid_year_id))
+        .group_by(user_merge_rows::user_merge_id)
+        .select((user_merge_rows::user_merge_id, count_star()))
+        .load::<(i64, i64)>(conn)?
+        .into_iter()
+        .collect();
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let rows_moved: usize = moved
+                .get(&row.user_merge_id)
+                .and_then(|count| usize::try_from(*count).ok())
+                .unwrap_or(0);
+            row.into_data(rows_moved)
+        })
+        .collect())
+}
+}
+
+backend_fn! {
+/// Gets a single merge from the user merge ledger.
+///
+/// # Errors
+///
+/// Returns `PersistenceError::NotFound` if the merge does not exist.
+pub fn get_user_merge(
+    conn: &mut _,
+    user_merge_id: i64,
+) -> Result<UserMergeData, PersistenceError> {
+    let row: UserMergeRow = user_merges::table
+        .filter(user_merges::user_merge_id.eq(user_merge_id))
+        .select(UserMergeRow::as_select())
+        .first(conn)
+        .optional()?
+        .ok_or_else(|| PersistenceError::NotFound(format!("User merge {user_merge_id} not found")))?;
+
+    let rows_moved: i64 = user_merge_rows::table
+        .filter(user_merge_rows::user_merge_id.eq(user_merge_id))
+        .count()
+        .get_result(conn)?;
+
+    Ok(row.into_data(usize::try_from(rows_moved).unwrap_or(0)))
+}
+}
+
+backend_fn! {
+/// Lists the merges in force that a user takes part in, either as the
+/// surviving record or as the merged one, oldest first.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_active_user_merges_for_user(
+    conn: &mut _,
+    user_id: i64,
+) -> Result<Vec<UserMergeData>, PersistenceError> {
+    let rows: Vec<UserMergeRow> = user_merges::table
+        .filter(
+            user_merges::kept_user_id
+                .eq(user_id)
+                .or(user_merges::merged_user_id.eq(user_id)),
+        )
+        .filter(user_merges::revert_event_id.is_null())
+        .order_by(user_merges::user_merge_id.asc())
+        .select(UserMergeRow::as_select())
+        .load(conn)?;
+
+    // Callers only check for the existence of a merge, not its size
+    Ok(rows.into_iter().map(|row| row.into_data(0)).collect())
+}
+}
+
+backend_fn! {
+/// Describes the records that would collide if one user's bids were
+/// reassigned to another.
+///
+/// A user holds at most one leave bid per round and day, one bid
+/// preference per round and rank, one offer per waitlisted slot, and one
+/// override in force of each kind.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `keep` - The surviving user
+/// * `merge` - The user whose bids would be reassigned
+///
+/// # Returns
+///
+/// One description per collision; empty when the merge can proceed.
+///
+/// # Errors
+///
+/// Returns an error if a query fails.
+pub fn find_user_merge_conflicts(
+    conn: &mut _,
+    keep: i64,
+    merge: i64,
+) -> Result<Vec<String>, PersistenceError> {
+    let mut conflicts: Vec<String> = Vec::new();
+
+    let kept_leave: Vec<(i64, String)> = leave_bids::table
+        .filter(leave_bids::user_id.eq(keep))
+        .select((leave_bids::round_id, leave_bids::leave_date))
+        .load(conn)?;
+    let merged_leave: Vec<(i64, String)> = leave_bids::table
+        .filter(leave_bids::user_id.eq(merge))
+        .select((leave_bids::round_id, leave_bids::leave_date))
+        .load(conn)?;
+    for (round_id, leave_date) in merged_leave.iter().filter(|key| kept_leave.contains(key)) {
+        conflicts.push(format!(
+            "both records hold leave on {leave_date} in round {round_id}"
+        ));
+    }
+
+    let kept_preferences: Vec<(i64, i32)> = bid_preferences::table
+        .filter(bid_preferences::user_id.eq(keep))
+        .select((bid_preferences::round_id, bid_preferences::preference_rank))
+        .load(conn)?;
+    let merged_preferences: Vec<(i64, i32)> = bid_preferences::table
+        .filter(bid_preferences::user_id.eq(merge))
+        .select((bid_preferences::round_id, bid_preferences::preference_rank))
+        .load(conn)?;
+    for (round_id, rank) in merged_preferences
+        .iter()
+        .filter(|key| kept_preferences.contains(key))
+    {
+        conflicts.push(format!(
+            "both records hold a rank {rank} bid preference in round {round_id}"
+        ));
+    }
+
+    let kept_slots: Vec<i64> = waitlist_offers::table
+        .filter(waitlist_offers::user_id.eq(keep))
+        .select(waitlist_offers::waitlist_slot_id)
+        .load(conn)?;
+    let merged_slots: Vec<i64> = waitlist_offers::table
+        .filter(waitlist_offers::user_id.eq(merge))
+        .select(waitlist_offers::waitlist_slot_id)
+        .load(conn)?;
+    for slot_id in merged_slots.iter().filter(|slot_id| kept_slots.contains(slot_id)) {
+        conflicts.push(format!("both records were offered waitlisted slot {slot_id}"));
+    }
+
+    let overridden_kinds: [(&str, i64); 4] = [
+        (
+            "area assignment",
+            canonical_area_membership::table
+                .filter(canonical_area_membership::user_id.eq_any([keep, merge]))
+                .filter(canonical_area_membership::is_overridden.eq(1))
+                .count()
+                .get_result(conn)?,
+        ),
+        (
+            "bid order",
+            canonical_bid_order::table
+                .filter(canonical_bid_order::user_id.eq_any([keep, merge]))
+                .filter(canonical_bid_order::is_overridden.eq(1))
+                .count()
+                .get_result(conn)?,
+        ),
+        (
+            "bid window",
+            canonical_bid_windows::table
+                .filter(canonical_bid_windows::user_id.eq_any([keep, merge]))
+                .filter(canonical_bid_windows::is_overridden.eq(1))
+                .count()
+                .get_result(conn)?,
+        ),
+        (
+            "eligibility",
+            canonical_eligibility::table
+                .filter(canonical_eligibility::user_id.eq_any([keep, merge]))
+                .filter(canonical_eligibility::is_overridden.eq(1))
+                .count()
+                .get_result(conn)?,
+        ),
+    ];
+    for (kind, overridden) in overridden_kinds {
+        if overridden > 1 {
+            conflicts.push(format!("both records have a {kind} override in force"));
+        }
+    }
+
+    Ok(conflicts)
+}
+}
@@ -0,0 +1,382 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Waitlist queries.
+//!
+//! This module contains queries for leave slots freed by withdrawals after
+//! a round closed, and for the offers of those slots made down the
+//! seniority list.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::info;
+
+use crate::backend::PersistenceBackend;
+use crate::data_models::{WaitlistOfferData, WaitlistSlotData};
+use crate::diesel_schema::{waitlist_offers, waitlist_slots};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for waitlist slot rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = waitlist_slots)]
+struct WaitlistSlotRow {
+    waitlist_slot_id: i64,
+    bid_year_id: i64,
+    area_id: i64,
+    round_id: i64,
+    leave_date: String,
+    hours: i32,
+    crew: Option<i32>,
+    offer_hours: i32,
+    released_leave_bid_id: i64,
+    release_event_id: i64,
+    status: String,
+    created_at: String,
+}
+
+impl From<WaitlistSlotRow> for WaitlistSlotData {
+    fn from(row: WaitlistSlotRow) -> Self {
+        Self {
+            waitlist_slot_id: row.waitlist_slot_id,
+            bid_year_id: row.bid_year_id,
+            area_id: row.area_id,
+            round_id: row.round_id,
+            leave_date: row.leave_date,
+            hours: row.hours,
+            crew: row.crew,
+            offer_hours: row.offer_hours,
+            released_leave_bid_id: row.released_leave_bid_id,
+            release_event_id: row.release_event_id,
+            status: row.status,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// Diesel Queryable struct for waitlist offer rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = waitlist_offers)]
+struct WaitlistOfferRow {
+    waitlist_offer_id: i64,
+    waitlist_slot_id: i64,
+    user_id: i64,
+    status: String,
+    offered_at: String,
+    expires_at: String,
+    responded_at: Option<String>,
+    offer_event_id: i64,
+    response_event_id: Option<i64>,
+    leave_bid_id: Option<i64>,
+}
+
+impl From<WaitlistOfferRow> for WaitlistOfferData {
+    fn from(row: WaitlistOfferRow) -> Self {
+        Self {
+            waitlist_offer_id: row.waitlist_offer_id,
+            waitlist_slot_id: row.waitlist_slot_id,
+            user_id: row.user_id,
+            status: row.status,
+            offered_at: row.offered_at,
+            expires_at: row.expires_at,
+            responded_at: row.responded_at,
+            offer_event_id: row.offer_event_id,
+            response_event_id: row.response_event_id,
+            leave_bid_id: row.leave_bid_id,
+        }
+    }
+}
+
+backend_fn! {
+/// Records a leave slot freed by a withdrawal after its round closed.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `slot` - The freed slot (`waitlist_slot_id`, `status`, and
+///   `created_at` are ignored)
+///
+/// # Errors
+///
+/// Returns an error if the insert fails.
+pub fn insert_waitlist_slot(
+    conn: &mut _,
+    slot: &WaitlistSlotData,
+) -> Result<i64, PersistenceError> {
+    let waitlist_slot_id: i64 = conn.transaction::<i64, PersistenceError, _>(|conn| {
+        diesel::insert_into(waitlist_slots::table)
+            .values((
+                waitlist_slots::bid_year_id.eq(slot.bid_year_id),
+                waitlist_slots::area_id.eq(slot.area_id),
+                waitlist_slots::round_id.eq(slot.round_id),
+                waitlist_slots::leave_date.eq(&slot.leave_date),
+                waitlist_slots::hours.eq(slot.hours),
+                waitlist_slots::crew.eq(slot.crew),
+                waitlist_slots::offer_hours.eq(slot.offer_hours),
+                waitlist_slots::released_leave_bid_id.eq(slot.released_leave_bid_id),
+                waitlist_slots::release_event_id.eq(slot.release_event_id),
+            ))
+            .execute(conn)?;
+        conn.get_last_insert_rowid()
+    })?;
+
+    info!(
+        waitlist_slot_id,
+        area_id = slot.area_id,
+        round_id = slot.round_id,
+        leave_date = %slot.leave_date,
+        "Waitlist slot opened"
+    );
+
+    Ok(waitlist_slot_id)
+}
+}
+
+backend_fn! {
+/// Gets a waitlist slot by ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `waitlist_slot_id` - The waitlist slot ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_waitlist_slot(
+    conn: &mut _,
+    waitlist_slot_id: i64,
+) -> Result<Option<WaitlistSlotData>, PersistenceError> {
+    let row: Option<WaitlistSlotRow> = waitlist_slots::table
+        .filter(waitlist_slots::waitlist_slot_id.eq(waitlist_slot_id))
+        .select(WaitlistSlotRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(WaitlistSlotData::from))
+}
+}
+
+backend_fn! {
+/// Lists the waitlist slots of a round in an area, ordered by leave date
+/// then by when they were freed.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `area_id` - The area ID
+/// * `round_id` - The round ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_waitlist_slots(
+    conn: &mut _,
+    area_id: i64,
+    round_id: i64,
+) -> Result<Vec<WaitlistSlotData>, PersistenceError> {
+    let rows: Vec<WaitlistSlotRow> = waitlist_slots::table
+        .filter(waitlist_slots::area_id.eq(area_id))
+        .filter(waitlist_slots::round_id.eq(round_id))
+        .select(WaitlistSlotRow::as_select())
+        .order_by((
+            waitlist_slots::leave_date.asc(),
+            waitlist_slots::waitlist_slot_id.asc(),
+        ))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(WaitlistSlotData::from).collect())
+}
+}
+
+backend_fn! {
+/// Sets the status of a waitlist slot.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `waitlist_slot_id` - The waitlist slot ID
+/// * `status` - `open`, `filled`, or `unclaimed`
+///
+/// # Errors
+///
+/// Returns an error if the update fails.
+pub fn set_waitlist_slot_status(
+    conn: &mut _,
+    waitlist_slot_id: i64,
+    status: &str,
+) -> Result<(), PersistenceError> {
+    diesel::update(
+        waitlist_slots::table.filter(waitlist_slots::waitlist_slot_id.eq(waitlist_slot_id)),
+    )
+    .set(waitlist_slots::status.eq(status))
+    .execute(conn)?;
+
+    info!(waitlist_slot_id, status, "Waitlist slot status set");
+
+    Ok(())
+}
+}
+
+backend_fn! {
+/// Records a pending offer of a waitlist slot to a user.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `waitlist_slot_id` - The waitlist slot ID
+/// * `user_id` - The user offered the slot
+/// * `offered_at` - When the offer was made (RFC 3339)
+/// * `expires_at` - When the offer lapses (RFC 3339)
+/// * `offer_event_id` - The audit event that recorded the offer
+///
+/// # Errors
+///
+/// Returns an error if the user was already offered the slot or the insert
+/// fails.
+pub fn insert_waitlist_offer(
+    conn: &mut _,
+    waitlist_slot_id: i64,
+    user_id: i64,
+    offered_at: &str,
+    expires_at: &str,
+    offer_event_id: i64,
+) -> Result<i64, PersistenceError> {
+    let waitlist_offer_id: i64 = conn.transaction::<i64, PersistenceError, _>(|conn| {
+        diesel::insert_into(waitlist_offers::table)
+            .values((
+                waitlist_offers::waitlist_slot_id.eq(waitlist_slot_id),
+                waitlist_offers::user_id.eq(user_id),
+                waitlist_offers::offered_at.eq(offered_at),
+                waitlist_offers::expires_at.eq(expires_at),
+                waitlist_offers::offer_event_id.eq(offer_event_id),
+            ))
+            .execute(conn)?;
+        conn.get_last_insert_rowid()
+    })?;
+
+    info!(
+        waitlist_offer_id,
+        waitlist_slot_id, user_id, expires_at, "Waitlist offer made"
+    );
+
+    Ok(waitlist_offer_id)
+}
+}
+
+backend_fn! {
+/// Gets a waitlist offer by ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `waitlist_offer_id` - The waitlist offer ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_waitlist_offer(
+    conn: &mut _,
+    waitlist_offer_id: i64,
+) -> Result<Option<WaitlistOfferData>, PersistenceError> {
+    let row: Option<WaitlistOfferRow> = waitlist_offers::table
+        .filter(waitlist_offers::waitlist_offer_id.eq(waitlist_offer_id))
+        .select(WaitlistOfferRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(WaitlistOfferData::from))
+}
+}
+
+backend_fn! {
+/// Lists the offers made of a waitlist slot, in the order they were made.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `waitlist_slot_id` - The waitlist slot ID
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_waitlist_offers(
+    conn: &mut _,
+    waitlist_slot_id: i64,
+) -> Result<Vec<WaitlistOfferData>, PersistenceError> {
+    let rows: Vec<WaitlistOfferRow> = waitlist_offers::table
+        .filter(waitlist_offers::waitlist_slot_id.eq(waitlist_slot_id))
+        .select(WaitlistOfferRow::as_select())
+        .order_by(waitlist_offers::waitlist_offer_id.asc())
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(WaitlistOfferData::from).collect())
+}
+}
+
+backend_fn! {
+/// Lists every waitlist offer still waiting for an answer.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn list_pending_waitlist_offers(
+    conn: &mut _,
+) -> Result<Vec<WaitlistOfferData>, PersistenceError> {
+    let rows: Vec<WaitlistOfferRow> = waitlist_offers::table
+        .filter(waitlist_offers::status.eq(WaitlistOfferData::STATUS_PENDING))
+        .select(WaitlistOfferRow::as_select())
+        .order_by(waitlist_offers::waitlist_offer_id.asc())
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(WaitlistOfferData::from).collect())
+}
+}
+
+backend_fn! {
+/// Records the answer to, or expiry of, a pending waitlist offer.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `waitlist_offer_id` - The waitlist offer ID
+/// * `status` - `accepted`, `declined`, or `expired`
+/// * `responded_at` - When the offer was answered or lapsed (RFC 3339)
+/// * `response_event_id` - The audit event that recorded the response
+/// * `leave_bid_id` - The leave granted on acceptance
+///
+/// # Errors
+///
+/// Returns an error if the update fails.
+pub fn respond_to_waitlist_offer(
+    conn: &mut _,
+    waitlist_offer_id: i64,
+    status: &str,
+    responded_at: &str,
+    response_event_id: i64,
+    leave_bid_id: Option<i64>,
+) -> Result<(), PersistenceError> {
+    diesel::update(
+        waitlist_offers::table.filter(waitlist_offers::waitlist_offer_id.eq(waitlist_offer_id)),
+    )
+    .set((
+        waitlist_offers::status.eq(status),
+        waitlist_offers::responded_at.eq(responded_at),
+        waitlist_offers::response_event_id.eq(response_event_id),
+        waitlist_offers::leave_bid_id.eq(leave_bid_id),
+    ))
+    .execute(conn)?;
+
+    info!(waitlist_offer_id, status, "Waitlist offer answered");
+
+    Ok(())
+}
+}
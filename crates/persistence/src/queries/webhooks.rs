@@ -0,0 +1,166 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Webhook queries.
+//!
+//! This module contains backend-agnostic queries for retrieving configured
+//! webhooks and their dead-letter log.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use tracing::debug;
+
+use crate::data_models::{WebhookData, WebhookDeadLetterData};
+use crate::diesel_schema::{webhook_dead_letters, webhooks};
+use crate::error::PersistenceError;
+
+/// Diesel Queryable struct for webhook rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = webhooks)]
+struct WebhookRow {
+    webhook_id: i64,
+    url: String,
+    secret: String,
+    event_filter: String,
+    is_enabled: i32,
+    created_at: String,
+}
+
+impl From<WebhookRow> for WebhookData {
+    fn from(row: WebhookRow) -> Self {
+        Self {
+            webhook_id: row.webhook_id,
+            url: row.url,
+            secret: row.secret,
+            event_filter: decode_event_filter(&row.event_filter),
+            is_enabled: row.is_enabled != 0,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// Diesel Queryable struct for dead-letter rows.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = webhook_dead_letters)]
+struct WebhookDeadLetterRow {
+    dead_letter_id: i64,
+    webhook_id: i64,
+    audit_event_id: i64,
+    payload: String,
+    attempts: i32,
+    last_error: String,
+    created_at: String,
+}
+
+impl From<WebhookDeadLetterRow> for WebhookDeadLetterData {
+    fn from(row: WebhookDeadLetterRow) -> Self {
+        Self {
+            dead_letter_id: row.dead_letter_id,
+            webhook_id: row.webhook_id,
+            audit_event_id: row.audit_event_id,
+            payload: row.payload,
+            attempts: row.attempts,
+            last_error: row.last_error,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// Encodes an event filter for storage as a comma-separated list.
+pub fn encode_event_filter(event_filter: &[String]) -> String {
+    event_filter.join(",")
+}
+
+/// Decodes a stored comma-separated event filter.
+fn decode_event_filter(stored: &str) -> Vec<String> {
+    stored
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+backend_fn! {
+/// Lists all configured webhooks ordered by ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_webhooks(conn: &mut _) -> Result<Vec<WebhookData>, PersistenceError> {
+    debug!("Listing webhooks");
+
+    let rows: Vec<WebhookRow> = webhooks::table
+        .select(WebhookRow::as_select())
+        .order_by(webhooks::webhook_id.asc())
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(WebhookData::from).collect())
+}
+}
+
+backend_fn! {
+/// Retrieves a webhook by ID.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `webhook_id` - The webhook ID
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+/// Returns `Ok(None)` if the webhook is not found.
+pub fn get_webhook(conn: &mut _, webhook_id: i64) -> Result<Option<WebhookData>, PersistenceError> {
+    debug!(webhook_id, "Looking up webhook");
+
+    let row: Option<WebhookRow> = webhooks::table
+        .filter(webhooks::webhook_id.eq(webhook_id))
+        .select(WebhookRow::as_select())
+        .first(conn)
+        .optional()?;
+
+    Ok(row.map(WebhookData::from))
+}
+}
+
+backend_fn! {
+/// Lists dead-lettered deliveries, newest first.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `webhook_id` - Restrict to one webhook, or `None` for all
+/// * `limit` - Maximum number of entries to return
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn list_webhook_dead_letters(
+    conn: &mut _,
+    webhook_id: Option<i64>,
+    limit: u32,
+) -> Result<Vec<WebhookDeadLetterData>, PersistenceError> {
+    debug!(?webhook_id, limit, "Listing webhook dead letters");
+
+    let mut query = webhook_dead_letters::table
+        .select(WebhookDeadLetterRow::as_select())
+        .into_boxed();
+    if let Some(id) = webhook_id {
+        query = query.filter(webhook_dead_letters::webhook_id.eq(id));
+    }
+
+    let rows: Vec<WebhookDeadLetterRow> = query
+        .order_by(webhook_dead_letters::dead_letter_id.desc())
+        .limit(i64::from(limit))
+        .load(conn)?;
+
+    Ok(rows.into_iter().map(WebhookDeadLetterData::from).collect())
+}
+}
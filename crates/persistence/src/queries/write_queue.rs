@@ -0,0 +1,151 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Write-ahead queue encoding and progress queries.
+//!
+//! The server can acknowledge transitions once they are appended to a
+//! local queue file and persist them afterwards. This module encodes queued
+//! transitions and records how far each queue has been persisted, so a
+//! replayed queue file never persists a transition twice.
+//!
+//! All queries are generated in backend-specific monomorphic versions
+//! (`_sqlite` and `_mysql` suffixes) using the `backend_fn!` macro.
+
+use diesel::prelude::*;
+use diesel::{MysqlConnection, SqliteConnection};
+use zab_bid::{State, TransitionResult};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::User;
+
+use crate::data_models::QueuedTransitionData;
+use crate::diesel_schema::write_queue_progress;
+use crate::error::PersistenceError;
+use crate::queries::audit::parse_event_ulid;
+
+/// Encodes a transition as a single line of JSON for a queue file.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn encode_queued_transition(result: &TransitionResult) -> Result<String, PersistenceError> {
+    let event: &AuditEvent = &result.audit_event;
+    let data: QueuedTransitionData = QueuedTransitionData {
+        actor_id: event.actor.id.clone(),
+        actor_type: event.actor.actor_type.clone(),
+        operator_id: event.actor.operator_id,
+        operator_login_name: event.actor.operator_login_name.clone(),
+        operator_display_name: event.actor.operator_display_name.clone(),
+        cause_id: event.cause.id.clone(),
+        cause_description: event.cause.description.clone(),
+        action_name: event.action.name.clone(),
+        action_details: event.action.details.clone(),
+        before: event.before.data.clone(),
+        after: event.after.data.clone(),
+        event_bid_year: event.bid_year.clone(),
+        event_area: event.area.clone(),
+        event_ulid: event.event_ulid.map(|ulid| ulid.to_string()),
+        event_user_id: event.user_id,
+        bid_year: result.new_state.bid_year.clone(),
+        area: result.new_state.area.clone(),
+        users: result.new_state.users.values().cloned().collect(),
+    };
+
+    Ok(serde_json::to_string(&data)?)
+}
+
+/// Decodes a transition written by [`encode_queued_transition`].
+///
+/// # Errors
+///
+/// Returns an error if `line` is not an encoded transition.
+pub fn decode_queued_transition(line: &str) -> Result<TransitionResult, PersistenceError> {
+    let data: QueuedTransitionData = serde_json::from_str(line)?;
+    let actor: Actor = Actor {
+        id: data.actor_id,
+        actor_type: data.actor_type,
+        operator_id: data.operator_id,
+        operator_login_name: data.operator_login_name,
+        operator_display_name: data.operator_display_name,
+    };
+    let audit_event: AuditEvent = AuditEvent {
+        event_id: None,
+        event_ulid: parse_event_ulid(data.event_ulid)?,
+        actor,
+        cause: Cause::new(data.cause_id, data.cause_description),
+        action: Action::new(data.action_name, data.action_details),
+        before: StateSnapshot::new(data.before),
+        after: StateSnapshot::new(data.after),
+        bid_year: data.event_bid_year,
+        area: data.event_area,
+        user_id: data.event_user_id,
+    };
+    let users: Vec<User> = data.users;
+
+    Ok(TransitionResult {
+        audit_event,
+        new_state: State::with_users(data.bid_year, data.area, users),
+    })
+}
+
+backend_fn! {
+/// Gets the highest sequence number a queue has persisted, if any.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `queue_name` - The queue's name
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn get_write_queue_progress(
+    conn: &mut _,
+    queue_name: &str,
+) -> Result<Option<i64>, PersistenceError> {
+    Ok(write_queue_progress::table
+        .filter(write_queue_progress::queue_name.eq(queue_name))
+        .select(write_queue_progress::last_sequence)
+        .first(conn)
+        .optional()?)
+}
+}
+
+backend_fn! {
+/// Records the highest sequence number a queue has persisted.
+///
+/// Callers write this in the same transaction as the queued transitions.
+///
+/// # Arguments
+///
+/// * `conn` - The database connection
+/// * `queue_name` - The queue's name
+/// * `last_sequence` - The last persisted sequence number
+/// * `updated_at` - When the progress was recorded (RFC 3339)
+///
+/// # Errors
+///
+/// Returns an error if the write fails.
+pub fn record_write_queue_progress(
+    conn: &mut _,
+    queue_name: &str,
+    last_sequence: i64,
+    updated_at: &str,
+) -> Result<(), PersistenceError> {
+    diesel::delete(
+        write_queue_progress::table.filter(write_queue_progress::queue_name.eq(queue_name)),
+    )
+    .execute(conn)?;
+
+    diesel::insert_into(write_queue_progress::table)
+        .values((
+            write_queue_progress::queue_name.eq(queue_name),
+            write_queue_progress::last_sequence.eq(last_sequence),
+            write_queue_progress::updated_at.eq(updated_at),
+        ))
+        .execute(conn)?;
+
+    Ok(())
+}
+}
@@ -0,0 +1,165 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Session token secrets.
+//!
+//! # Threat Model
+//!
+//! A session token is a bearer credential: whoever holds it acts as the
+//! operator until it expires. Two leaks are guarded against here:
+//!
+//! - **Database disclosure.** A copied database file, backup, or bundle must
+//!   not yield usable tokens. Only an HMAC-SHA256 digest of each token is
+//!   stored, keyed with a [`SessionKey`] that lives in server configuration
+//!   and never in the database. Without the key a stored digest cannot be
+//!   turned back into a token, nor can candidate tokens be checked offline.
+//! - **Log disclosure.** Tokens and keys are wrapped in [`SecretToken`] and
+//!   [`SessionKey`], whose `Debug` output is redacted, so they cannot reach
+//!   tracing output or error messages by accident.
+//!
+//! # Key Rotation
+//!
+//! A [`SessionKeyring`] holds the current key and, during a rotation, the
+//! previous one. New sessions are always stored under the current key.
+//! A token stored under the previous key is still accepted and is re-keyed
+//! to the current key on first use, so operators stay signed in across the
+//! rotation. Once every session has either been used or has expired, the
+//! previous key can be dropped.
+
+use std::fmt;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Minimum length of a configured session key, in bytes.
+pub const MIN_SESSION_KEY_LENGTH: usize = 32;
+
+/// Number of random bytes in a generated session token.
+const SESSION_TOKEN_BYTES: usize = 32;
+
+/// A bearer credential such as a session token.
+///
+/// The value is only reachable through [`SecretToken::expose`], and is
+/// redacted from `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretToken(String);
+
+impl SecretToken {
+    /// Wraps a token received from a client.
+    #[must_use]
+    pub const fn new(token: String) -> Self {
+        Self(token)
+    }
+
+    /// Generates a new random token.
+    #[must_use]
+    pub fn generate() -> Self {
+        let bytes: [u8; SESSION_TOKEN_BYTES] = rand::random();
+        Self(hex::encode(bytes))
+    }
+
+    /// Returns the token itself, for handing to the client it belongs to.
+    #[must_use]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for SecretToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretToken(<redacted>)")
+    }
+}
+
+/// A key used to derive the stored digest of a session token.
+#[derive(Clone)]
+pub struct SessionKey(Vec<u8>);
+
+impl SessionKey {
+    /// Generates a random key that lives only as long as the process.
+    #[must_use]
+    pub fn generate() -> Self {
+        let bytes: [u8; MIN_SESSION_KEY_LENGTH] = rand::random();
+        Self(bytes.to_vec())
+    }
+
+    /// Computes the hex-encoded HMAC-SHA256 digest of a token.
+    fn digest(&self, token: &SecretToken) -> String {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&self.0)
+            .unwrap_or_else(|_| unreachable!("HMAC accepts keys of any length"));
+        mac.update(token.expose().as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+}
+
+impl std::str::FromStr for SessionKey {
+    type Err = String;
+
+    /// Creates a key from configured secret material.
+    ///
+    /// The error never repeats the secret.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() < MIN_SESSION_KEY_LENGTH {
+            return Err(format!(
+                "Session key must be at least {MIN_SESSION_KEY_LENGTH} bytes"
+            ));
+        }
+        Ok(Self(s.as_bytes().to_vec()))
+    }
+}
+
+impl fmt::Debug for SessionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SessionKey(<redacted>)")
+    }
+}
+
+/// The keys session token digests are computed with.
+#[derive(Debug, Clone)]
+pub struct SessionKeyring {
+    current: SessionKey,
+    previous: Option<SessionKey>,
+}
+
+impl SessionKeyring {
+    /// Creates a keyring from the current key and, during a rotation, the
+    /// previous one.
+    #[must_use]
+    pub const fn new(current: SessionKey, previous: Option<SessionKey>) -> Self {
+        Self { current, previous }
+    }
+
+    /// Creates a keyring with a random key.
+    ///
+    /// Sessions stored under it do not survive a restart.
+    #[must_use]
+    pub fn ephemeral() -> Self {
+        Self::new(SessionKey::generate(), None)
+    }
+
+    /// Returns whether a previous key is still accepted.
+    #[must_use]
+    pub const fn is_rotating(&self) -> bool {
+        self.previous.is_some()
+    }
+
+    /// Computes the digest a token is stored under.
+    #[must_use]
+    pub fn digest(&self, token: &SecretToken) -> String {
+        self.current.digest(token)
+    }
+
+    /// Computes the digest a token was stored under before the rotation.
+    #[must_use]
+    pub fn previous_digest(&self, token: &SecretToken) -> Option<String> {
+        self.previous.as_ref().map(|key| key.digest(token))
+    }
+}
+
+impl Default for SessionKeyring {
+    fn default() -> Self {
+        Self::ephemeral()
+    }
+}
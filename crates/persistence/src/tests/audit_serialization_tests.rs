@@ -9,11 +9,11 @@
 //! serialized, persisted, and deserialized. Focus is on integration behavior
 //! rather than testing `serde_json` itself.
 
-use crate::SqlitePersistence;
 use crate::tests::{
     create_test_actor, create_test_bid_year_and_area, create_test_operator,
     create_test_seniority_data,
 };
+use crate::{SnapshotEncoding, SqlitePersistence};
 use zab_bid::{BootstrapMetadata, Command, State, apply};
 use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
 use zab_bid_domain::{Area, BidYear, Crew, Initials, UserType};
@@ -126,8 +126,78 @@ fn test_persist_state_snapshot_integration() {
         .unwrap();
 
     assert_eq!(retrieved_state.users.len(), 1, "Should have one user");
-    assert_eq!(retrieved_state.users[0].initials.value(), "AB");
-    assert_eq!(retrieved_state.users[0].name, "Alice Bob");
+    assert_eq!(
+        retrieved_state
+            .users
+            .values()
+            .next()
+            .unwrap()
+            .initials
+            .value(),
+        "AB"
+    );
+    assert_eq!(
+        retrieved_state.users.values().next().unwrap().name,
+        "Alice Bob"
+    );
+}
+
+#[test]
+fn test_binary_snapshot_round_trip() {
+    let mut persistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    create_test_bid_year_and_area(&mut persistence, 2026, "NORTH");
+    let bid_year = BidYear::new(2026);
+    let area = Area::new("NORTH");
+
+    let mut metadata = BootstrapMetadata::new();
+    metadata.bid_years.push(bid_year.clone());
+    metadata.areas.push((bid_year.clone(), area.clone()));
+
+    // The area's initial snapshot was written before switching encodings
+    persistence.set_snapshot_encoding(SnapshotEncoding::Postcard);
+    let (initial, _) = persistence.get_latest_snapshot(&bid_year, &area).unwrap();
+    assert!(initial.users.is_empty());
+
+    let register = apply(
+        &metadata,
+        &initial,
+        &bid_year,
+        Command::RegisterUser {
+            initials: Initials::new("AB"),
+            name: String::from("Alice Bob"),
+            area: area.clone(),
+            user_type: UserType::CPC,
+            crew: Some(Crew::new(1).unwrap()),
+            seniority_data: create_test_seniority_data(),
+        },
+        create_test_actor(),
+        Cause::new(String::from("test"), String::from("Test")),
+    )
+    .unwrap();
+    persistence.persist_transition(&register).unwrap();
+
+    let state = persistence.get_current_state(&bid_year, &area).unwrap();
+    let checkpoint = apply(
+        &metadata,
+        &state,
+        &bid_year,
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+        create_test_actor(),
+        Cause::new(String::from("test"), String::from("Test")),
+    )
+    .unwrap();
+    let event_id = persistence
+        .persist_transition(&checkpoint)
+        .unwrap()
+        .event_id;
+
+    let (snapshot, snapshot_event_id) = persistence.get_latest_snapshot(&bid_year, &area).unwrap();
+    assert_eq!(snapshot_event_id, event_id);
+    assert_eq!(snapshot, state);
 }
 
 #[test]
@@ -0,0 +1,481 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Golden-file tests for the persisted JSON form of audit events.
+//!
+//! Historical replay reads the audit log back exactly as it was written, so
+//! any change to how an action serializes breaks every existing database.
+//! Each test renders the JSON columns of one action built from fixed inputs
+//! and compares them against a committed snapshot. An intentional format
+//! change shows up as a reviewable diff via `cargo insta review`.
+//!
+//! Override and round configuration events are built in the API layer and
+//! are not covered here.
+
+use time::Date;
+use time::macros::{date, datetime};
+use zab_bid::{BootstrapMetadata, Command, State, apply, apply_bootstrap};
+use zab_bid_audit::{AuditEvent, Cause};
+use zab_bid_domain::{
+    Area, BidAmendment, BidAmendmentPolicy, BidPreference, BidReceiptMethod, BidYear, Initials,
+    ReadinessEvaluation, User,
+};
+use zab_bid_test_support::BidYearFixture;
+
+use crate::mutations::audit::{SerializedAuditEvent, serialize_audit_event, serialize_state};
+use crate::tests::{create_test_actor, create_test_metadata, create_test_start_date};
+
+const YEAR: u16 = 2026;
+
+fn cause() -> Cause {
+    Cause::new(String::from("snapshot"), String::from("Golden file"))
+}
+
+fn area() -> Area {
+    Area::new("North")
+}
+
+/// Returns the fixture's first `count` users in 2026/North.
+fn roster(count: usize) -> Vec<User> {
+    BidYearFixture::new(YEAR).with_users(count).users("North")
+}
+
+/// Returns AA, the fixture's most senior user, with a fixed ID.
+fn user() -> User {
+    let mut user: User = roster(1).remove(0);
+    user.user_id = Some(7);
+    user
+}
+
+fn state() -> State {
+    let mut state: State = State::new(BidYear::new(YEAR), area());
+    state.insert_user(user());
+    state
+}
+
+fn preference(rank: u32, dates: Vec<Date>) -> BidPreference {
+    BidPreference::new(rank, dates, 8).unwrap()
+}
+
+/// Renders every JSON column of an event, one per line.
+fn render(event: &AuditEvent) -> String {
+    let serialized: SerializedAuditEvent = serialize_audit_event(event).unwrap();
+    format!(
+        "actor: {}\ncause: {}\naction: {}\nbefore: {}\nafter: {}\n",
+        serialized.actor_json,
+        serialized.cause_json,
+        serialized.action_json,
+        serialized.before_json,
+        serialized.after_json
+    )
+}
+
+fn bootstrap_with(metadata: &BootstrapMetadata, command: Command) -> String {
+    let event: AuditEvent = apply_bootstrap(
+        metadata,
+        &BidYear::new(YEAR),
+        command,
+        create_test_actor(),
+        cause(),
+    )
+    .unwrap()
+    .audit_event;
+    render(&event)
+}
+
+fn bootstrap(command: Command) -> String {
+    bootstrap_with(&create_test_metadata(), command)
+}
+
+fn transition(command: Command) -> String {
+    let event: AuditEvent = apply(
+        &create_test_metadata(),
+        &state(),
+        &BidYear::new(YEAR),
+        command,
+        create_test_actor(),
+        cause(),
+    )
+    .unwrap()
+    .audit_event;
+    render(&event)
+}
+
+#[test]
+fn test_create_bid_year() {
+    insta::assert_snapshot!(bootstrap_with(
+        &BootstrapMetadata::new(),
+        Command::CreateBidYear {
+            year: YEAR,
+            start_date: create_test_start_date(),
+            num_pay_periods: 26,
+        }
+    ));
+}
+
+#[test]
+fn test_create_area() {
+    insta::assert_snapshot!(bootstrap(Command::CreateArea {
+        area_id: String::from("South"),
+    }));
+}
+
+#[test]
+fn test_set_active_bid_year() {
+    insta::assert_snapshot!(bootstrap(Command::SetActiveBidYear { year: YEAR }));
+}
+
+#[test]
+fn test_set_expected_area_count() {
+    insta::assert_snapshot!(bootstrap(Command::SetExpectedAreaCount {
+        expected_count: 4
+    }));
+}
+
+#[test]
+fn test_set_expected_user_count() {
+    insta::assert_snapshot!(bootstrap(Command::SetExpectedUserCount {
+        area: area(),
+        expected_count: 30,
+    }));
+}
+
+#[test]
+fn test_transition_to_bootstrap_complete() {
+    insta::assert_snapshot!(bootstrap(Command::TransitionToBootstrapComplete {
+        year: YEAR
+    }));
+}
+
+#[test]
+fn test_transition_to_canonicalized() {
+    insta::assert_snapshot!(bootstrap(Command::TransitionToCanonicalized { year: YEAR }));
+}
+
+#[test]
+fn test_confirm_ready_to_bid() {
+    insta::assert_snapshot!(bootstrap(Command::ConfirmReadyToBid { year: YEAR }));
+}
+
+#[test]
+fn test_activate_bidding() {
+    insta::assert_snapshot!(bootstrap(Command::ActivateBidding {
+        year: YEAR,
+        readiness: ReadinessEvaluation {
+            areas_missing_rounds: vec![],
+            no_bid_users_pending_review: 0,
+            participation_flag_violations: 0,
+            seniority_conflicts: vec![],
+            bid_schedule_set: true,
+        },
+    }));
+}
+
+#[test]
+fn test_transition_to_bidding_closed() {
+    insta::assert_snapshot!(bootstrap(Command::TransitionToBiddingClosed { year: YEAR }));
+}
+
+#[test]
+fn test_advance_bidder() {
+    insta::assert_snapshot!(bootstrap(Command::AdvanceBidder {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        previous_user_id: Some(7),
+        next_user_id: Some(8),
+        time_driven: true,
+    }));
+}
+
+#[test]
+fn test_expire_bid_window() {
+    insta::assert_snapshot!(bootstrap(Command::ExpireBidWindow {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        user_id: 7,
+        marked_missed: true,
+    }));
+}
+
+#[test]
+fn test_enter_leave_bid() {
+    insta::assert_snapshot!(bootstrap(Command::EnterLeaveBid {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        user_id: 7,
+        on_behalf_of: Initials::new("AA"),
+        received_via: BidReceiptMethod::Phone,
+        leave_dates: vec![date!(2026 - 03 - 10), date!(2026 - 03 - 09)],
+        hours: 16,
+        signed_off: false,
+        override_reason: None,
+        amendment: None,
+    }));
+}
+
+#[test]
+fn test_enter_leave_bid_amendment_with_override() {
+    insta::assert_snapshot!(bootstrap(Command::EnterLeaveBid {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        user_id: 7,
+        on_behalf_of: Initials::new("AA"),
+        received_via: BidReceiptMethod::Written,
+        leave_dates: vec![date!(2026 - 03 - 12)],
+        hours: 8,
+        signed_off: true,
+        override_reason: Some(String::from("Late paperwork")),
+        amendment: Some(BidAmendment {
+            policy: BidAmendmentPolicy::WithinHours(24),
+            first_submitted_at: datetime!(2026-03-02 14:00 UTC),
+            window_closes_at: Some(datetime!(2026-03-02 22:00 UTC)),
+            amended_at: datetime!(2026-03-02 18:30 UTC),
+        }),
+    }));
+}
+
+#[test]
+fn test_submit_bid_preferences() {
+    insta::assert_snapshot!(bootstrap(Command::SubmitBidPreferences {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        user_id: 7,
+        on_behalf_of: Initials::new("AA"),
+        received_via: BidReceiptMethod::InPerson,
+        preferences: vec![
+            preference(1, vec![date!(2026 - 07 - 06), date!(2026 - 07 - 07)]),
+            preference(2, vec![date!(2026 - 08 - 03)]),
+        ],
+    }));
+}
+
+#[test]
+fn test_apply_bid_preference() {
+    insta::assert_snapshot!(bootstrap(Command::ApplyBidPreference {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        user_id: 7,
+        on_behalf_of: Initials::new("AA"),
+        applied: Some(preference(2, vec![date!(2026 - 08 - 03)])),
+        passed_over: vec![1],
+    }));
+}
+
+#[test]
+fn test_apply_bid_preference_none_available() {
+    insta::assert_snapshot!(bootstrap(Command::ApplyBidPreference {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        user_id: 7,
+        on_behalf_of: Initials::new("AA"),
+        applied: None,
+        passed_over: vec![1, 2],
+    }));
+}
+
+#[test]
+fn test_adjust_slot_inventory() {
+    insta::assert_snapshot!(bootstrap(Command::AdjustSlotInventory {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        date: date!(2026 - 07 - 04),
+        previous_adjustment: 0,
+        staffing_adjustment: -2,
+    }));
+}
+
+#[test]
+fn test_request_overbid() {
+    insta::assert_snapshot!(bootstrap(Command::RequestOverbid {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        user_id: 7,
+        on_behalf_of: Initials::new("AA"),
+        received_via: BidReceiptMethod::Phone,
+        leave_date: date!(2026 - 12 - 24),
+        hours: 8,
+    }));
+}
+
+#[test]
+fn test_approve_overbid() {
+    insta::assert_snapshot!(bootstrap(Command::ApproveOverbid {
+        year: YEAR,
+        area: area(),
+        overbid_request_id: 5,
+        request_event_id: 120,
+        round_id: 11,
+        user_id: 7,
+        leave_date: date!(2026 - 12 - 24),
+        previous_adjustment: 1,
+    }));
+}
+
+#[test]
+fn test_deny_overbid() {
+    insta::assert_snapshot!(bootstrap(Command::DenyOverbid {
+        year: YEAR,
+        area: area(),
+        overbid_request_id: 5,
+        request_event_id: 120,
+        round_id: 11,
+        user_id: 7,
+        leave_date: date!(2026 - 12 - 24),
+        reason: String::from("Minimum staffing"),
+    }));
+}
+
+#[test]
+fn test_sign_off_round() {
+    insta::assert_snapshot!(bootstrap(Command::SignOffRound {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        bid: 24,
+        skipped: 3,
+        waived: 1,
+        outstanding: vec![],
+    }));
+}
+
+#[test]
+fn test_withdraw_leave_bid() {
+    insta::assert_snapshot!(bootstrap(Command::WithdrawLeaveBid {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        user_id: 7,
+        on_behalf_of: Initials::new("AA"),
+        leave_bid_id: 42,
+        leave_date: date!(2026 - 03 - 09),
+        round_closed: false,
+    }));
+}
+
+#[test]
+fn test_offer_waitlist_slot() {
+    insta::assert_snapshot!(bootstrap(Command::OfferWaitlistSlot {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        waitlist_slot_id: 9,
+        user_id: 7,
+        leave_date: date!(2026 - 03 - 09),
+        offered_at: datetime!(2026-03-05 09:00 UTC),
+        expires_at: datetime!(2026-03-06 09:00 UTC),
+    }));
+}
+
+#[test]
+fn test_accept_waitlist_offer() {
+    insta::assert_snapshot!(bootstrap(Command::AcceptWaitlistOffer {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        waitlist_offer_id: 13,
+        user_id: 7,
+        on_behalf_of: Initials::new("AA"),
+        received_via: BidReceiptMethod::Phone,
+        leave_date: date!(2026 - 03 - 09),
+        expires_at: datetime!(2026-03-06 09:00 UTC),
+        accepted_at: datetime!(2026-03-05 15:45 UTC),
+    }));
+}
+
+#[test]
+fn test_decline_waitlist_offer() {
+    insta::assert_snapshot!(bootstrap(Command::DeclineWaitlistOffer {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        waitlist_offer_id: 13,
+        user_id: 7,
+        leave_date: date!(2026 - 03 - 09),
+    }));
+}
+
+#[test]
+fn test_expire_waitlist_offer() {
+    insta::assert_snapshot!(bootstrap(Command::ExpireWaitlistOffer {
+        year: YEAR,
+        area: area(),
+        round_id: 11,
+        waitlist_offer_id: 13,
+        user_id: 7,
+        leave_date: date!(2026 - 03 - 09),
+        expires_at: datetime!(2026-03-06 09:00 UTC),
+    }));
+}
+
+#[test]
+fn test_register_user() {
+    let user: User = roster(2).remove(1);
+    insta::assert_snapshot!(transition(Command::RegisterUser {
+        initials: user.initials,
+        name: user.name,
+        area: user.area,
+        user_type: user.user_type,
+        crew: user.crew,
+        seniority_data: user.seniority_data,
+    }));
+}
+
+#[test]
+fn test_update_user() {
+    let user: User = user();
+    insta::assert_snapshot!(transition(Command::UpdateUser {
+        user_id: 7,
+        initials: user.initials,
+        name: String::from("Controller AA-Smith"),
+        area: user.area,
+        user_type: user.user_type,
+        crew: None,
+        seniority_data: user.seniority_data,
+    }));
+}
+
+#[test]
+fn test_update_user_participation() {
+    insta::assert_snapshot!(transition(Command::UpdateUserParticipation {
+        user_id: 7,
+        initials: Initials::new("AA"),
+        excluded_from_bidding: true,
+        excluded_from_leave_calculation: false,
+    }));
+}
+
+#[test]
+fn test_checkpoint() {
+    insta::assert_snapshot!(transition(Command::Checkpoint {
+        label: None,
+        description: None,
+    }));
+}
+
+#[test]
+fn test_finalize() {
+    insta::assert_snapshot!(transition(Command::Finalize));
+}
+
+#[test]
+fn test_rollback() {
+    insta::assert_snapshot!(transition(Command::RollbackToEventId {
+        target_event_id: 17
+    }));
+}
+
+#[test]
+fn test_full_state_snapshot() {
+    insta::assert_snapshot!(serialize_state(&state()).unwrap());
+}
@@ -3,12 +3,12 @@
 // license that can be found in the LICENSE file or at
 // https://opensource.org/licenses/MIT.
 
-use crate::SqlitePersistence;
 use crate::tests::{
     create_test_actor, create_test_cause, create_test_metadata, create_test_operator,
     create_test_pay_periods, create_test_seniority_data, create_test_start_date,
     create_test_start_date_for_year,
 };
+use crate::{BlackoutDateData, PersistenceError, SqlitePersistence};
 use zab_bid::{
     BootstrapMetadata, BootstrapResult, Command, State, TransitionResult, apply, apply_bootstrap,
 };
@@ -842,3 +842,124 @@ fn test_bootstrap_success_persists_all_state() {
     let canonical = result.canonical_bid_year.unwrap();
     assert_eq!(canonical.year(), 2026);
 }
+
+#[test]
+fn test_area_bid_schedule_override_falls_back_to_bid_year() {
+    let mut persistence: SqlitePersistence = create_bootstrapped_persistence();
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    let area_id: i64 = persistence.get_area_id(bid_year_id, "North").unwrap();
+
+    persistence
+        .update_bid_schedule(
+            bid_year_id,
+            Some("America/New_York"),
+            Some("2026-03-02"),
+            Some("08:00:00"),
+            Some("17:00:00"),
+            Some(5),
+        )
+        .unwrap();
+
+    // Without an override the area uses the bid year's schedule
+    assert_eq!(
+        persistence.get_effective_bid_schedule(area_id).unwrap(),
+        persistence.get_bid_schedule(bid_year_id).unwrap()
+    );
+
+    persistence
+        .set_area_bid_schedule_override(area_id, Some("2026-03-09"), None, None, Some(3))
+        .unwrap();
+    assert_eq!(
+        persistence.get_effective_bid_schedule(area_id).unwrap(),
+        (
+            Some(String::from("America/New_York")),
+            Some(String::from("2026-03-09")),
+            Some(String::from("08:00:00")),
+            Some(String::from("17:00:00")),
+            Some(3),
+        )
+    );
+
+    let overrides = persistence
+        .list_area_bid_schedule_overrides(bid_year_id)
+        .unwrap();
+    assert_eq!(overrides.len(), 1);
+    assert_eq!(overrides[0].0, area_id);
+    assert_eq!(
+        overrides[0].2,
+        (Some(String::from("2026-03-09")), None, None, Some(3))
+    );
+
+    assert!(
+        persistence
+            .clear_area_bid_schedule_override(area_id)
+            .unwrap()
+    );
+    assert!(
+        !persistence
+            .clear_area_bid_schedule_override(area_id)
+            .unwrap()
+    );
+    assert!(
+        persistence
+            .get_area_bid_schedule_override(area_id)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_blackout_dates_round_trip() {
+    let mut persistence: SqlitePersistence = create_bootstrapped_persistence();
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+
+    let labor_day: i64 = persistence
+        .insert_blackout_date(bid_year_id, "2026-09-07", "Labor Day")
+        .unwrap();
+    persistence
+        .insert_blackout_date(bid_year_id, "2026-05-25", "Memorial Day")
+        .unwrap();
+
+    // A date may only be blacked out once per bid year
+    assert!(
+        persistence
+            .insert_blackout_date(bid_year_id, "2026-05-25", "Duplicate")
+            .is_err()
+    );
+
+    let dates: Vec<String> = persistence
+        .list_blackout_dates(bid_year_id)
+        .unwrap()
+        .into_iter()
+        .map(|b| b.blackout_date)
+        .collect();
+    assert_eq!(dates, vec!["2026-05-25", "2026-09-07"]);
+
+    assert!(
+        persistence
+            .blackout_date_exists(bid_year_id, "2026-09-07", None)
+            .unwrap()
+    );
+    assert!(
+        !persistence
+            .blackout_date_exists(bid_year_id, "2026-09-07", Some(labor_day))
+            .unwrap()
+    );
+
+    persistence
+        .update_blackout_date(labor_day, "2026-10-12", "Columbus Day")
+        .unwrap();
+    let updated: BlackoutDateData = persistence.get_blackout_date(labor_day).unwrap();
+    assert_eq!(updated.blackout_date, "2026-10-12");
+    assert_eq!(updated.reason, "Columbus Day");
+
+    persistence.delete_blackout_date(labor_day).unwrap();
+    assert!(matches!(
+        persistence.get_blackout_date(labor_day),
+        Err(PersistenceError::NotFound(_))
+    ));
+    assert!(matches!(
+        persistence.delete_blackout_date(labor_day),
+        Err(PersistenceError::NotFound(_))
+    ));
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Bid year bundle export and import tests.
+
+use crate::tests::{
+    create_test_actor, create_test_bid_year_and_area, create_test_cause, create_test_metadata,
+    create_test_operator,
+};
+use crate::{
+    BUNDLE_FORMAT_VERSION, BidYearBundle, IntegrityReport, PersistenceError, SqlitePersistence,
+};
+use zab_bid::{Command, State, TransitionResult, apply};
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_test_support::BidYearFixture;
+
+const EXPORTED_AT: &str = "2026-03-01T00:00:00Z";
+
+/// Creates a database with two registered users, checkpointed.
+fn create_source_persistence() -> SqlitePersistence {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    create_test_bid_year_and_area(&mut persistence, 2026, "North");
+
+    let registrations: Vec<TransitionResult> = BidYearFixture::new(2026)
+        .with_users(2)
+        .registrations("North", &create_test_metadata(), &create_test_actor())
+        .unwrap();
+    for result in &registrations {
+        persistence.persist_transition(result).unwrap();
+    }
+
+    let state: &State = &registrations.last().unwrap().new_state;
+    let result: TransitionResult = apply(
+        &create_test_metadata(),
+        state,
+        &BidYear::new(2026),
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    persistence.persist_transition(&result).unwrap();
+    persistence
+}
+
+#[test]
+fn test_export_records_manifest_and_contents() {
+    let mut persistence: SqlitePersistence = create_source_persistence();
+
+    let bundle: BidYearBundle = persistence.export_bid_year(2026, EXPORTED_AT).unwrap();
+
+    assert_eq!(bundle.manifest.format_version, BUNDLE_FORMAT_VERSION);
+    assert_eq!(bundle.manifest.year, 2026);
+    assert!(bundle.manifest.schema_version.is_some());
+    assert_eq!(bundle.areas.len(), 1);
+    assert_eq!(bundle.users.len(), 2);
+    assert_eq!(bundle.audit_events.len(), 5);
+    assert_eq!(bundle.snapshots.len(), 2);
+}
+
+#[test]
+fn test_import_round_trips_into_fresh_database() {
+    let bundle: BidYearBundle = create_source_persistence()
+        .export_bid_year(2026, EXPORTED_AT)
+        .unwrap();
+    let mut target: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut target);
+
+    target.import_bid_year(&bundle).unwrap();
+
+    let state: State = target
+        .get_current_state(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    assert_eq!(state.users.len(), 2);
+    let report: IntegrityReport = target.verify_integrity().unwrap();
+    assert!(report.is_consistent(), "{:?}", report.discrepancies);
+    assert_eq!(report.areas_reconciled, 1);
+
+    let reexported: BidYearBundle = target.export_bid_year(2026, EXPORTED_AT).unwrap();
+    assert_eq!(reexported.audit_events.len(), bundle.audit_events.len());
+    assert_eq!(reexported.snapshots.len(), bundle.snapshots.len());
+    assert_eq!(reexported.bid_year.is_active, 0);
+    // Imported history is not published again
+    assert!(target.list_pending_outbox_entries(10).unwrap().is_empty());
+}
+
+#[test]
+fn test_import_keeps_event_ulids() {
+    let bundle: BidYearBundle = create_source_persistence()
+        .export_bid_year(2026, EXPORTED_AT)
+        .unwrap();
+    assert!(bundle.audit_events.iter().all(|e| e.event_ulid.is_some()));
+    let mut target: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut target);
+
+    target.import_bid_year(&bundle).unwrap();
+
+    let reexported: BidYearBundle = target.export_bid_year(2026, EXPORTED_AT).unwrap();
+    let ulids = |bundle: &BidYearBundle| -> Vec<Option<String>> {
+        bundle
+            .audit_events
+            .iter()
+            .map(|e| e.event_ulid.clone())
+            .collect()
+    };
+    assert_eq!(ulids(&reexported), ulids(&bundle));
+}
+
+#[test]
+fn test_import_rejects_existing_bid_year() {
+    let mut persistence: SqlitePersistence = create_source_persistence();
+    let bundle: BidYearBundle = persistence.export_bid_year(2026, EXPORTED_AT).unwrap();
+
+    let result: Result<i64, PersistenceError> = persistence.import_bid_year(&bundle);
+
+    assert!(matches!(result, Err(PersistenceError::Other(_))));
+}
+
+#[test]
+fn test_import_without_acting_operator_leaves_nothing_behind() {
+    let bundle: BidYearBundle = create_source_persistence()
+        .export_bid_year(2026, EXPORTED_AT)
+        .unwrap();
+    let mut target: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+
+    let result: Result<i64, PersistenceError> = target.import_bid_year(&bundle);
+
+    assert!(matches!(result, Err(PersistenceError::OperatorNotFound(_))));
+    assert!(target.list_bid_years().unwrap().is_empty());
+}
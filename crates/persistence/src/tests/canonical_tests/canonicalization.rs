@@ -8,6 +8,8 @@
 //! These tests verify that the canonicalization persistence layer works correctly.
 
 use diesel::prelude::*;
+use time::OffsetDateTime;
+use zab_bid::UlidGenerator;
 use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
 use zab_bid_domain::BidYear;
 
@@ -59,6 +61,7 @@ fn test_canonicalize_creates_tables_sqlite() {
             // Create audit event
             let audit_event = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor: Actor {
                     actor_type: String::from("Operator"),
                     id: String::from("1"),
@@ -78,11 +81,18 @@ fn test_canonicalize_creates_tables_sqlite() {
                 after: StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
                 bid_year: Some(BidYear::new(2026)),
                 area: None,
+                user_id: None,
             };
 
             // Canonicalize
-            let event_id = canonicalize_bid_year_sqlite(conn, 1, &audit_event)
-                .expect("Canonicalization failed");
+            let event_id = canonicalize_bid_year_sqlite(
+                conn,
+                1,
+                &audit_event,
+                OffsetDateTime::now_utc(),
+                &UlidGenerator::default(),
+            )
+            .expect("Canonicalization failed");
 
             assert!(event_id > 0, "Event ID should be assigned");
 
@@ -172,6 +182,7 @@ fn test_canonicalize_with_no_users_sqlite() {
 
             let audit_event = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor: Actor {
                     actor_type: String::from("Operator"),
                     id: String::from("1"),
@@ -191,10 +202,17 @@ fn test_canonicalize_with_no_users_sqlite() {
                 after: StateSnapshot::new(String::from("after")),
                 bid_year: Some(BidYear::new(2026)),
                 area: None,
+                user_id: None,
             };
 
-            let event_id = canonicalize_bid_year_sqlite(conn, 1, &audit_event)
-                .expect("Canonicalization failed");
+            let event_id = canonicalize_bid_year_sqlite(
+                conn,
+                1,
+                &audit_event,
+                OffsetDateTime::now_utc(),
+                &UlidGenerator::default(),
+            )
+            .expect("Canonicalization failed");
 
             assert!(event_id > 0);
 
@@ -249,6 +267,7 @@ fn test_canonicalize_idempotent_sqlite() {
 
             let audit_event = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor: Actor {
                     actor_type: String::from("Operator"),
                     id: String::from("1"),
@@ -268,16 +287,29 @@ fn test_canonicalize_idempotent_sqlite() {
                 after: StateSnapshot::new(String::from("after")),
                 bid_year: Some(BidYear::new(2026)),
                 area: None,
+                user_id: None,
             };
 
             // First canonicalization
-            let event_id_1 = canonicalize_bid_year_sqlite(conn, 1, &audit_event)
-                .expect("First canonicalization failed");
+            let event_id_1 = canonicalize_bid_year_sqlite(
+                conn,
+                1,
+                &audit_event,
+                OffsetDateTime::now_utc(),
+                &UlidGenerator::default(),
+            )
+            .expect("First canonicalization failed");
             assert!(event_id_1 > 0, "First event ID should be positive");
 
             // Second canonicalization - should be idempotent
-            let event_id_2 = canonicalize_bid_year_sqlite(conn, 1, &audit_event)
-                .expect("Second canonicalization failed");
+            let event_id_2 = canonicalize_bid_year_sqlite(
+                conn,
+                1,
+                &audit_event,
+                OffsetDateTime::now_utc(),
+                &UlidGenerator::default(),
+            )
+            .expect("Second canonicalization failed");
             assert_eq!(
                 event_id_1, event_id_2,
                 "Second canonicalization should return same event ID"
@@ -387,6 +419,7 @@ fn test_read_routing_after_canonicalization_sqlite() {
 
             let audit_event = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor: Actor {
                     actor_type: String::from("Operator"),
                     id: String::from("1"),
@@ -406,10 +439,18 @@ fn test_read_routing_after_canonicalization_sqlite() {
                 after: StateSnapshot::new(String::from("after")),
                 bid_year: Some(BidYear::new(2026)),
                 area: None,
+                user_id: None,
             };
 
             // Canonicalize
-            canonicalize_bid_year_sqlite(conn, 1, &audit_event).expect("Canonicalization failed");
+            canonicalize_bid_year_sqlite(
+                conn,
+                1,
+                &audit_event,
+                OffsetDateTime::now_utc(),
+                &UlidGenerator::default(),
+            )
+            .expect("Canonicalization failed");
 
             // Update lifecycle state to Canonicalized
             diesel::sql_query(
@@ -455,6 +496,7 @@ fn test_read_routing_after_canonicalization_sqlite() {
 
 /// Test that audit snapshot contains complete data (`SQLite`).
 #[test]
+#[allow(clippy::too_many_lines)]
 fn test_canonicalize_audit_snapshot_sqlite() {
     let mut persistence = Persistence::new_in_memory().expect("Failed to create persistence");
 
@@ -493,6 +535,7 @@ fn test_canonicalize_audit_snapshot_sqlite() {
 
             let audit_event = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor: Actor {
                     actor_type: String::from("Operator"),
                     id: String::from("1"),
@@ -512,10 +555,17 @@ fn test_canonicalize_audit_snapshot_sqlite() {
                 after: StateSnapshot::new(String::from("after")),
                 bid_year: Some(BidYear::new(2026)),
                 area: None,
+                user_id: None,
             };
 
-            let event_id = canonicalize_bid_year_sqlite(conn, 1, &audit_event)
-                .expect("Canonicalization failed");
+            let event_id = canonicalize_bid_year_sqlite(
+                conn,
+                1,
+                &audit_event,
+                OffsetDateTime::now_utc(),
+                &UlidGenerator::default(),
+            )
+            .expect("Canonicalization failed");
 
             // Retrieve the audit event
             let after_json: String = crate::diesel_schema::audit_events::table
@@ -0,0 +1,175 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for derived eligibility and eligibility exceptions.
+
+use diesel::prelude::*;
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::BidYear;
+
+use crate::data_models::{
+    CanonicalOverrideData, EligibilityExceptionSpecData, OverrideValue, UserEligibilityData,
+};
+use crate::{BackendConnection, Persistence};
+
+/// Creates a bid year with a CPC, a Dev-D, an excluded CPC, and a user in
+/// the No Bid area, without canonicalizing it.
+fn setup_roster() -> Persistence {
+    let mut persistence = Persistence::new_in_memory().expect("Failed to create persistence");
+    match &mut persistence.conn {
+        BackendConnection::Sqlite(conn) => {
+            diesel::sql_query(
+                "INSERT INTO bid_years (bid_year_id, year, start_date, num_pay_periods, is_active, lifecycle_state)
+                 VALUES (1, 2026, '2026-01-04', 26, 1, 'BootstrapComplete')",
+            )
+            .execute(conn)
+            .expect("Failed to insert bid year");
+
+            diesel::sql_query(
+                "INSERT INTO areas (area_id, bid_year_id, area_code, area_name, is_system_area)
+                 VALUES (1, 1, 'AREA1', 'Area One', 0), (2, 1, 'NO BID', 'No Bid', 1)",
+            )
+            .execute(conn)
+            .expect("Failed to insert areas");
+
+            diesel::sql_query(
+                "INSERT INTO users (user_id, bid_year_id, area_id, initials, name, user_type, cumulative_natca_bu_date, natca_bu_date, eod_faa_date, service_computation_date, excluded_from_bidding, excluded_from_leave_calculation)
+                 VALUES
+                 (1, 1, 1, 'AAA', 'User One', 'CPC', '2020-01-01', '2020-01-01', '2020-01-01', '2020-01-01', 0, 0),
+                 (2, 1, 1, 'BBB', 'User Two', 'Dev-D', '2021-01-01', '2021-01-01', '2021-01-01', '2021-01-01', 0, 0),
+                 (3, 1, 1, 'CCC', 'User Three', 'CPC', '2022-01-01', '2022-01-01', '2022-01-01', '2022-01-01', 1, 0),
+                 (4, 1, 2, 'DDD', 'User Four', 'CPC', '2023-01-01', '2023-01-01', '2023-01-01', '2023-01-01', 0, 0)",
+            )
+            .execute(conn)
+            .expect("Failed to insert users");
+
+            diesel::sql_query(
+                "INSERT INTO operators (operator_id, login_name, display_name, password_hash, role, is_disabled, created_at)
+                 VALUES (1, 'admin', 'Admin', 'hash', 'Admin', 0, '2026-01-01T00:00:00')",
+            )
+            .execute(conn)
+            .expect("Failed to insert operator");
+        }
+        BackendConnection::Mysql(_) => panic!("Expected SQLite connection"),
+    }
+    persistence
+}
+
+fn canonicalize(persistence: &mut Persistence) -> i64 {
+    let audit_event = AuditEvent {
+        event_id: None,
+        event_ulid: None,
+        actor: Actor {
+            actor_type: String::from("Operator"),
+            id: String::from("1"),
+            operator_id: Some(1),
+            operator_login_name: Some(String::from("admin")),
+            operator_display_name: Some(String::from("Admin")),
+        },
+        cause: Cause {
+            id: String::from("test"),
+            description: String::from("Test canonicalization"),
+        },
+        action: Action {
+            name: String::from("CanonicalizeBidYear"),
+            details: None,
+        },
+        before: StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        after: StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+        bid_year: Some(BidYear::new(2026)),
+        area: None,
+        user_id: None,
+    };
+    persistence
+        .canonicalize_bid_year(1, &audit_event)
+        .expect("Canonicalization failed")
+}
+
+fn exception(user_type: &str, can_bid: bool) -> EligibilityExceptionSpecData {
+    EligibilityExceptionSpecData {
+        user_type: user_type.to_string(),
+        area_id: None,
+        can_bid,
+    }
+}
+
+/// Returns `(initials, derived_can_bid, can_bid)` for every user.
+fn eligibility(persistence: &mut Persistence) -> Vec<(String, bool, bool)> {
+    persistence
+        .list_user_eligibility(1)
+        .expect("Failed to list eligibility")
+        .into_iter()
+        .map(|user: UserEligibilityData| (user.initials, user.derived_can_bid, user.can_bid))
+        .collect()
+}
+
+#[test]
+fn test_canonicalization_derives_eligibility() {
+    let mut persistence: Persistence = setup_roster();
+    persistence
+        .replace_eligibility_exceptions(1, &[exception("Dev-D", false)])
+        .expect("Failed to store exceptions");
+
+    canonicalize(&mut persistence);
+
+    assert_eq!(
+        eligibility(&mut persistence),
+        vec![
+            (String::from("AAA"), true, true),
+            (String::from("BBB"), false, false),
+            (String::from("CCC"), false, false),
+            (String::from("DDD"), false, false),
+        ]
+    );
+}
+
+#[test]
+fn test_recompute_leaves_overrides_in_force_until_reverted() {
+    let mut persistence: Persistence = setup_roster();
+    let event_id: i64 = canonicalize(&mut persistence);
+
+    // Override the Dev-D controller off, then restrict every Dev-D
+    persistence
+        .override_eligibility(1, 2, false, "Still completing facility training")
+        .expect("Failed to override eligibility");
+    let override_id: i64 = persistence
+        .record_override(
+            1,
+            2,
+            &OverrideValue::Eligibility { can_bid: true },
+            &OverrideValue::Eligibility { can_bid: false },
+            "Still completing facility training",
+            event_id,
+        )
+        .expect("Failed to record override");
+
+    persistence
+        .replace_eligibility_exceptions(1, &[exception("Dev-D", false), exception("CPC", false)])
+        .expect("Failed to store exceptions");
+    assert_eq!(persistence.recompute_eligibility(1).unwrap(), 2);
+    assert_eq!(persistence.recompute_eligibility(1).unwrap(), 0);
+
+    // Lifting the Dev-D restriction changes only the derived value
+    persistence
+        .replace_eligibility_exceptions(1, &[])
+        .expect("Failed to clear exceptions");
+    assert_eq!(persistence.recompute_eligibility(1).unwrap(), 2);
+    assert_eq!(
+        eligibility(&mut persistence)[..2],
+        [
+            (String::from("AAA"), true, true),
+            (String::from("BBB"), true, false),
+        ]
+    );
+
+    // Reverting the override falls back to the derived value
+    let record: CanonicalOverrideData = persistence.get_override(override_id).unwrap();
+    persistence
+        .revert_override(&record)
+        .expect("Failed to revert override");
+    let users: Vec<UserEligibilityData> = persistence.list_user_eligibility(1).unwrap();
+    assert!(users[1].can_bid);
+    assert!(!users[1].is_overridden);
+}
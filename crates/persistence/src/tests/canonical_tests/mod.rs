@@ -6,4 +6,6 @@
 //! Tests for canonical data operations.
 
 mod canonicalization;
+mod eligibility;
 mod lookup_failures;
+mod seniority_list;
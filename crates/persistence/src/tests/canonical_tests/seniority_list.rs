@@ -0,0 +1,215 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the canonical seniority list and round results queries.
+
+use diesel::prelude::*;
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_domain::BidYear;
+
+use crate::diesel_schema::canonical_bid_order;
+use crate::{Persistence, RoundResultEntryData, SeniorityListEntryData};
+
+/// Creates a canonicalized 2026 bid year with areas AREA1 (ID 1) and
+/// AREA2 (ID 2). AREA1 holds ABC, DEF, and GHI; AREA2 holds JKL.
+///
+/// ABC and DEF are placed 2 and 1; GHI has no bid order.
+fn setup(persistence: &mut Persistence) {
+    match &mut persistence.conn {
+        crate::BackendConnection::Sqlite(conn) => {
+            diesel::sql_query(
+                "INSERT INTO bid_years (bid_year_id, year, start_date, num_pay_periods, is_active, lifecycle_state)
+                 VALUES (1, 2026, '2026-01-04', 26, 1, 'Canonicalized')",
+            )
+            .execute(conn)
+            .expect("Failed to insert bid year");
+
+            diesel::sql_query(
+                "INSERT INTO areas (area_id, bid_year_id, area_code, area_name, is_system_area)
+                 VALUES (1, 1, 'AREA1', 'Area One', 0), (2, 1, 'AREA2', 'Area Two', 0)",
+            )
+            .execute(conn)
+            .expect("Failed to insert areas");
+
+            diesel::sql_query(
+                "INSERT INTO users (user_id, bid_year_id, area_id, initials, name, user_type, crew, cumulative_natca_bu_date, natca_bu_date, eod_faa_date, service_computation_date, lottery_value, excluded_from_bidding, excluded_from_leave_calculation)
+                 VALUES
+                 (1, 1, 1, 'ABC', 'User One', 'CPC', 1, '2020-01-01', '2020-02-01', '2020-03-01', '2020-04-01', 7, 0, 0),
+                 (2, 1, 1, 'DEF', 'User Two', 'CPC', 2, '2019-01-01', '2019-02-01', '2019-03-01', '2019-04-01', NULL, 0, 0),
+                 (3, 1, 1, 'GHI', 'User Three', 'Dev-R', NULL, '2021-01-01', '2021-01-01', '2021-01-01', '2021-01-01', NULL, 1, 0),
+                 (4, 1, 2, 'JKL', 'User Four', 'CPC', 1, '2018-01-01', '2018-01-01', '2018-01-01', '2018-01-01', NULL, 0, 0)",
+            )
+            .execute(conn)
+            .expect("Failed to insert users");
+
+            diesel::sql_query(
+                "INSERT INTO operators (operator_id, login_name, display_name, password_hash, role, is_disabled, created_at)
+                 VALUES (1, 'admin', 'Admin', 'hash', 'Admin', 0, '2026-01-01T00:00:00')",
+            )
+            .execute(conn)
+            .expect("Failed to insert operator");
+        }
+        crate::BackendConnection::Mysql(_) => {
+            panic!("This test is SQLite-specific");
+        }
+    }
+
+    let audit_event = AuditEvent {
+        event_id: None,
+        event_ulid: None,
+        actor: Actor {
+            actor_type: String::from("Operator"),
+            id: String::from("1"),
+            operator_id: Some(1),
+            operator_login_name: Some(String::from("admin")),
+            operator_display_name: Some(String::from("Admin")),
+        },
+        cause: Cause {
+            id: String::from("test"),
+            description: String::from("Test canonicalization"),
+        },
+        action: Action {
+            name: String::from("CanonicalizeBidYear"),
+            details: None,
+        },
+        before: StateSnapshot::new(String::from("lifecycle_state=BootstrapComplete")),
+        after: StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
+        bid_year: Some(BidYear::new(2026)),
+        area: None,
+        user_id: None,
+    };
+    persistence
+        .canonicalize_bid_year(1, &audit_event)
+        .expect("Canonicalization failed");
+
+    match &mut persistence.conn {
+        crate::BackendConnection::Sqlite(conn) => {
+            for (user_id, bid_order) in [(1, 2), (2, 1), (4, 1)] {
+                diesel::update(
+                    canonical_bid_order::table
+                        .filter(canonical_bid_order::bid_year_id.eq(1))
+                        .filter(canonical_bid_order::user_id.eq(user_id)),
+                )
+                .set(canonical_bid_order::bid_order.eq(Some(bid_order)))
+                .execute(conn)
+                .expect("Failed to set bid order");
+            }
+        }
+        crate::BackendConnection::Mysql(_) => {
+            panic!("This test is SQLite-specific");
+        }
+    }
+}
+
+#[test]
+fn test_seniority_list_follows_canonical_bid_order() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    let list: Vec<SeniorityListEntryData> = persistence.list_seniority_list(1, 1).unwrap();
+
+    let initials: Vec<&str> = list.iter().map(|e| e.initials.as_str()).collect();
+    assert_eq!(initials, vec!["DEF", "ABC"]);
+    assert_eq!(list[0].bid_order, 1);
+    assert_eq!(list[1].bid_order, 2);
+    assert_eq!(list[1].name, "User One");
+    assert_eq!(list[1].crew, Some(1));
+    assert_eq!(list[1].natca_bu_date, "2020-02-01");
+    assert_eq!(list[1].service_computation_date, "2020-04-01");
+    assert_eq!(list[1].lottery_value, Some(7));
+    assert!(!list[1].is_overridden);
+}
+
+#[test]
+fn test_seniority_list_uses_canonical_area_and_overrides() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    persistence
+        .override_area_assignment(1, 4, 1, "Moved to AREA1 for the bid")
+        .unwrap();
+    persistence
+        .override_bid_order(1, 4, Some(3), "Placed after ABC")
+        .unwrap();
+
+    let list: Vec<SeniorityListEntryData> = persistence.list_seniority_list(1, 1).unwrap();
+    let initials: Vec<&str> = list.iter().map(|e| e.initials.as_str()).collect();
+    assert_eq!(initials, vec!["DEF", "ABC", "JKL"]);
+    assert!(list[2].is_overridden);
+
+    assert!(persistence.list_seniority_list(1, 2).unwrap().is_empty());
+}
+
+#[test]
+fn test_round_results_pair_status_and_window_for_round() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    match &mut persistence.conn {
+        crate::BackendConnection::Sqlite(conn) => {
+            diesel::sql_query(
+                "INSERT INTO round_groups (round_group_id, bid_year_id, name, editing_enabled)
+                 VALUES (1, 1, 'Default', 1)",
+            )
+            .execute(conn)
+            .expect("Failed to insert round group");
+
+            diesel::sql_query(
+                "INSERT INTO rounds (round_id, round_group_id, round_number, name, slots_per_day, max_groups, max_total_hours, include_holidays, allow_overbid)
+                 VALUES (1, 1, 1, 'Round 1', 2, 1, 80, 0, 0), (2, 1, 2, 'Round 2', 2, 1, 80, 0, 0)",
+            )
+            .execute(conn)
+            .expect("Failed to insert rounds");
+
+            diesel::sql_query(
+                "INSERT INTO bid_windows (bid_year_id, area_id, user_id, round_id, window_start_datetime, window_end_datetime)
+                 VALUES
+                 (1, 1, 1, 1, '2026-03-02T13:00:00+00:00', '2026-03-02T21:00:00+00:00'),
+                 (1, 1, 2, 1, '2026-03-01T13:00:00+00:00', '2026-03-01T21:00:00+00:00'),
+                 (1, 1, 2, 2, '2026-04-01T13:00:00+00:00', '2026-04-01T21:00:00+00:00')",
+            )
+            .execute(conn)
+            .expect("Failed to insert bid windows");
+
+            diesel::sql_query(
+                "INSERT INTO bid_status (bid_year_id, area_id, user_id, round_id, status, updated_at, updated_by)
+                 VALUES
+                 (1, 1, 2, 1, 'missed', '2026-03-01T21:00:00+00:00', 1),
+                 (1, 1, 2, 2, 'completed_on_time', '2026-04-01T14:00:00+00:00', 1)",
+            )
+            .execute(conn)
+            .expect("Failed to insert bid status");
+        }
+        crate::BackendConnection::Mysql(_) => {
+            panic!("This test is SQLite-specific");
+        }
+    }
+
+    let results: Vec<RoundResultEntryData> = persistence.list_round_results(1, 1, 1).unwrap();
+
+    assert_eq!(
+        results,
+        vec![
+            RoundResultEntryData {
+                bid_order: 1,
+                user_id: 2,
+                initials: String::from("DEF"),
+                name: String::from("User Two"),
+                status: Some(String::from("missed")),
+                window_start_datetime: Some(String::from("2026-03-01T13:00:00+00:00")),
+                window_end_datetime: Some(String::from("2026-03-01T21:00:00+00:00")),
+            },
+            RoundResultEntryData {
+                bid_order: 2,
+                user_id: 1,
+                initials: String::from("ABC"),
+                name: String::from("User One"),
+                status: None,
+                window_start_datetime: Some(String::from("2026-03-02T13:00:00+00:00")),
+                window_end_datetime: Some(String::from("2026-03-02T21:00:00+00:00")),
+            },
+        ]
+    );
+}
@@ -0,0 +1,262 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for chat channels, active bid windows, and the chat announcement log.
+
+use crate::{
+    ActiveBidWindowData, ChatChannelData, ChatNotificationLogData, NewBidWindow, Persistence,
+};
+use diesel::prelude::*;
+
+/// Creates a `BiddingActive` 2026 bid year with areas NORTH (ID 1) and
+/// SOUTH (ID 2), one round, and users AB and CD in NORTH with windows
+/// opening at different times.
+fn setup(persistence: &mut Persistence) {
+    match &mut persistence.conn {
+        crate::BackendConnection::Sqlite(conn) => {
+            use crate::diesel_schema::{areas, bid_years, round_groups, rounds, users};
+
+            diesel::insert_into(bid_years::table)
+                .values((
+                    bid_years::bid_year_id.eq(1),
+                    bid_years::year.eq(2026),
+                    bid_years::start_date.eq("2026-01-04"),
+                    bid_years::num_pay_periods.eq(26),
+                    bid_years::is_active.eq(1),
+                    bid_years::lifecycle_state.eq("BiddingActive"),
+                ))
+                .execute(conn)
+                .expect("Failed to insert bid year");
+            diesel::insert_into(round_groups::table)
+                .values((
+                    round_groups::round_group_id.eq(1),
+                    round_groups::bid_year_id.eq(1),
+                    round_groups::name.eq("Standard"),
+                    round_groups::editing_enabled.eq(1),
+                ))
+                .execute(conn)
+                .expect("Failed to insert round group");
+            diesel::insert_into(rounds::table)
+                .values((
+                    rounds::round_id.eq(1),
+                    rounds::round_group_id.eq(1),
+                    rounds::round_number.eq(1),
+                    rounds::name.eq("Round 1"),
+                    rounds::slots_per_day.eq(1),
+                    rounds::max_groups.eq(1),
+                    rounds::max_total_hours.eq(80),
+                    rounds::include_holidays.eq(0),
+                    rounds::allow_overbid.eq(0),
+                ))
+                .execute(conn)
+                .expect("Failed to insert round");
+            for (area_id, area_code) in [(1, "NORTH"), (2, "SOUTH")] {
+                diesel::insert_into(areas::table)
+                    .values((
+                        areas::area_id.eq(area_id),
+                        areas::bid_year_id.eq(1),
+                        areas::area_code.eq(area_code),
+                        areas::is_system_area.eq(0),
+                        areas::round_group_id.eq(Some(1)),
+                    ))
+                    .execute(conn)
+                    .expect("Failed to insert area");
+            }
+            for (user_id, initials) in [(1, "AB"), (2, "CD")] {
+                diesel::insert_into(users::table)
+                    .values((
+                        users::user_id.eq(user_id),
+                        users::bid_year_id.eq(1),
+                        users::area_id.eq(1),
+                        users::initials.eq(initials),
+                        users::name.eq(initials),
+                        users::user_type.eq("CPC"),
+                        users::crew.eq(None::<i32>),
+                        users::cumulative_natca_bu_date.eq("2020-01-01"),
+                        users::natca_bu_date.eq("2020-01-01"),
+                        users::eod_faa_date.eq("2020-01-01"),
+                        users::service_computation_date.eq("2020-01-01"),
+                    ))
+                    .execute(conn)
+                    .expect("Failed to insert user");
+            }
+        }
+        crate::BackendConnection::Mysql(_) => {
+            panic!("This test is SQLite-specific");
+        }
+    }
+
+    persistence
+        .bulk_insert_bid_windows(&[
+            NewBidWindow {
+                bid_year_id: 1,
+                area_id: 1,
+                user_id: 2,
+                round_id: 1,
+                window_start_datetime: String::from("2026-03-03T13:00:00+00:00"),
+                window_end_datetime: String::from("2026-03-03T21:00:00+00:00"),
+            },
+            NewBidWindow {
+                bid_year_id: 1,
+                area_id: 1,
+                user_id: 1,
+                round_id: 1,
+                window_start_datetime: String::from("2026-03-02T13:00:00+00:00"),
+                window_end_datetime: String::from("2026-03-02T21:00:00+00:00"),
+            },
+        ])
+        .unwrap();
+}
+
+#[test]
+fn test_create_update_and_list_chat_channels() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    let area_channel: i64 = persistence
+        .create_chat_channel(1, Some(1), "slack", "https://hooks.slack.test/a")
+        .unwrap();
+    let year_channel: i64 = persistence
+        .create_chat_channel(1, None, "teams", "https://teams.test/b")
+        .unwrap();
+
+    let channels: Vec<ChatChannelData> = persistence.list_chat_channels(Some(1)).unwrap();
+    assert_eq!(channels.len(), 2);
+    assert_eq!(channels[0].chat_channel_id, area_channel);
+    assert_eq!(channels[0].area_id, Some(1));
+    assert_eq!(channels[1].provider, "teams");
+    assert!(channels[1].is_enabled);
+    assert!(persistence.list_chat_channels(Some(99)).unwrap().is_empty());
+
+    persistence
+        .update_chat_channel(year_channel, "slack", "https://hooks.slack.test/c", false)
+        .unwrap();
+    let updated: ChatChannelData = persistence.get_chat_channel(year_channel).unwrap().unwrap();
+    assert_eq!(updated.provider, "slack");
+    assert_eq!(updated.url, "https://hooks.slack.test/c");
+    assert!(!updated.is_enabled);
+}
+
+#[test]
+fn test_chat_channel_coverage() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    let area_channel: i64 = persistence
+        .create_chat_channel(1, Some(1), "slack", "https://hooks.slack.test/a")
+        .unwrap();
+    let year_channel: i64 = persistence
+        .create_chat_channel(1, None, "slack", "https://hooks.slack.test/b")
+        .unwrap();
+    let area: ChatChannelData = persistence.get_chat_channel(area_channel).unwrap().unwrap();
+    let year: ChatChannelData = persistence.get_chat_channel(year_channel).unwrap().unwrap();
+
+    assert!(area.covers(1, 1));
+    assert!(!area.covers(1, 2));
+    assert!(year.covers(1, 1));
+    assert!(year.covers(1, 2));
+    assert!(!year.covers(2, 1));
+
+    persistence
+        .update_chat_channel(year_channel, "slack", &year.url, false)
+        .unwrap();
+    let disabled: ChatChannelData = persistence.get_chat_channel(year_channel).unwrap().unwrap();
+    assert!(!disabled.covers(1, 1));
+}
+
+#[test]
+fn test_list_active_bid_windows_ordered_by_start() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    let windows: Vec<ActiveBidWindowData> = persistence.list_active_bid_windows().unwrap();
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows[0].initials, "AB");
+    assert_eq!(windows[0].area_code, "NORTH");
+    assert_eq!(windows[0].round_name, "Round 1");
+    assert_eq!(windows[0].year, 2026);
+    assert_eq!(windows[1].initials, "CD");
+
+    persistence
+        .update_lifecycle_state(1, "BiddingClosed")
+        .unwrap();
+    assert!(persistence.list_active_bid_windows().unwrap().is_empty());
+}
+
+#[test]
+fn test_record_and_query_chat_notifications() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    let channel: i64 = persistence
+        .create_chat_channel(1, Some(1), "slack", "https://hooks.slack.test/a")
+        .unwrap();
+
+    assert!(
+        !persistence
+            .chat_notification_exists(channel, "round_opened", "1:1")
+            .unwrap()
+    );
+    assert_eq!(
+        persistence
+            .get_latest_chat_notification(channel, "readiness_blockers")
+            .unwrap(),
+        None
+    );
+
+    persistence
+        .record_chat_notification(channel, "round_opened", "1:1", "Round 1 is open", None)
+        .unwrap();
+    persistence
+        .record_chat_notification(
+            channel,
+            "readiness_blockers",
+            "2026",
+            "Bid schedule is not set",
+            Some("HTTP 500"),
+        )
+        .unwrap();
+
+    assert!(
+        persistence
+            .chat_notification_exists(channel, "round_opened", "1:1")
+            .unwrap()
+    );
+    let latest: ChatNotificationLogData = persistence
+        .get_latest_chat_notification(channel, "readiness_blockers")
+        .unwrap()
+        .unwrap();
+    assert_eq!(latest.message, "Bid schedule is not set");
+    assert!(!latest.delivered);
+    assert_eq!(latest.error.as_deref(), Some("HTTP 500"));
+
+    let log: Vec<ChatNotificationLogData> = persistence
+        .list_chat_notifications(Some(channel), 10)
+        .unwrap();
+    assert_eq!(log.len(), 2);
+    assert_eq!(log[0].kind, "readiness_blockers");
+    assert_eq!(log[1].kind, "round_opened");
+}
+
+#[test]
+fn test_delete_chat_channel_removes_log() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    let channel: i64 = persistence
+        .create_chat_channel(1, None, "teams", "https://teams.test/a")
+        .unwrap();
+    persistence
+        .record_chat_notification(channel, "round_opened", "1:1", "Round 1 is open", None)
+        .unwrap();
+
+    persistence.delete_chat_channel(channel).unwrap();
+
+    assert_eq!(persistence.get_chat_channel(channel).unwrap(), None);
+    assert!(
+        persistence
+            .list_chat_notifications(None, 10)
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -0,0 +1,204 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Audit log compaction tests.
+
+use diesel::prelude::*;
+use diesel_migrations::MigrationHarness;
+
+use crate::backend::sqlite::MIGRATIONS;
+use crate::diesel_schema::audit_events;
+use crate::tests::{
+    create_test_actor, create_test_bid_year_and_area, create_test_cause, create_test_metadata,
+    create_test_operator,
+};
+use crate::{
+    ArchivableEventData, AuditArchiveVerification, AuditCompactionData, BackendConnection,
+    IntegrityReport, OutboxEntryData, SqlitePersistence,
+};
+use zab_bid::{Command, State, TransitionResult, apply};
+use zab_bid_audit::{Action, AuditEvent, StateSnapshot};
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_test_support::BidYearFixture;
+
+/// Creates a database with users AA and AB registered around a
+/// checkpoint, with every event published.
+fn create_published_persistence() -> SqlitePersistence {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    create_test_bid_year_and_area(&mut persistence, 2026, "North");
+
+    let registrations: Vec<TransitionResult> = BidYearFixture::new(2026)
+        .with_users(2)
+        .registrations("North", &create_test_metadata(), &create_test_actor())
+        .unwrap();
+    persistence.persist_transition(&registrations[0]).unwrap();
+    let checkpoint: TransitionResult = apply(
+        &create_test_metadata(),
+        &registrations[0].new_state,
+        &BidYear::new(2026),
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    persistence.persist_transition(&checkpoint).unwrap();
+    persistence.persist_transition(&registrations[1]).unwrap();
+
+    let pending: Vec<OutboxEntryData> = persistence.list_pending_outbox_entries(100).unwrap();
+    let outbox_ids: Vec<i64> = pending.iter().map(|entry| entry.outbox_id).collect();
+    persistence
+        .mark_outbox_entries_delivered(&outbox_ids)
+        .unwrap();
+    persistence
+}
+
+fn bid_year_id(persistence: &mut SqlitePersistence) -> i64 {
+    persistence.get_bid_year_id(2026).unwrap()
+}
+
+/// Archives every archivable event of 2026 behind a sealed digest.
+fn compact(persistence: &mut SqlitePersistence) -> AuditCompactionData {
+    let bid_year_id: i64 = bid_year_id(persistence);
+    let archivable: Vec<ArchivableEventData> = persistence
+        .list_archivable_audit_events(bid_year_id)
+        .unwrap();
+    let event_ids: Vec<i64> = archivable.iter().map(|event| event.event_id).collect();
+    let state: State = persistence
+        .get_current_state(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    let digest: AuditEvent = AuditEvent::new(
+        create_test_actor(),
+        create_test_cause(),
+        Action::new(String::from("AuditLogCompacted"), None),
+        StateSnapshot::new(String::from("compacted=false")),
+        StateSnapshot::new(String::from("compacted=true")),
+        BidYear::new(2026),
+        Area::new("North"),
+    );
+    let (compaction, digest_event_ids): (AuditCompactionData, Vec<i64>) = persistence
+        .compact_audit_log(bid_year_id, &event_ids, &[(digest, state)])
+        .unwrap();
+    assert_eq!(digest_event_ids.len(), 1);
+    compaction
+}
+
+/// Runs raw SQL against the test database.
+fn execute(persistence: &mut SqlitePersistence, sql: &str) {
+    let BackendConnection::Sqlite(conn) = &mut persistence.conn else {
+        panic!("expected SQLite");
+    };
+    diesel::sql_query(sql).execute(conn).unwrap();
+}
+
+#[test]
+fn test_snapshotted_events_are_not_archivable() {
+    let mut persistence: SqlitePersistence = create_published_persistence();
+    let bid_year_id: i64 = bid_year_id(&mut persistence);
+
+    let archivable: Vec<ArchivableEventData> = persistence
+        .list_archivable_audit_events(bid_year_id)
+        .unwrap();
+
+    let actions: Vec<&str> = archivable
+        .iter()
+        .map(|event| event.action_name.as_str())
+        .collect();
+    assert_eq!(actions, vec!["RegisterUser", "RegisterUser"]);
+}
+
+#[test]
+fn test_archived_events_leave_the_live_log() {
+    let mut persistence: SqlitePersistence = create_published_persistence();
+
+    let compaction: AuditCompactionData = compact(&mut persistence);
+
+    assert_eq!(compaction.archived_event_count, 2);
+    for event_id in [compaction.first_event_id, compaction.last_event_id] {
+        assert!(persistence.get_audit_event(event_id.unwrap()).is_err());
+    }
+    let report: IntegrityReport = persistence.verify_integrity().unwrap();
+    assert!(report.is_consistent(), "{:?}", report.discrepancies);
+    assert_eq!(report.archived_events_checked, 2);
+}
+
+#[test]
+fn test_archive_verifies_against_chain_head() {
+    let mut persistence: SqlitePersistence = create_published_persistence();
+    let compaction: AuditCompactionData = compact(&mut persistence);
+    let bid_year_id: i64 = bid_year_id(&mut persistence);
+
+    let verification: AuditArchiveVerification =
+        persistence.verify_audit_archive(bid_year_id).unwrap();
+
+    assert!(verification.is_intact());
+    assert_eq!(verification.recomputed_head, compaction.chain_head);
+}
+
+#[test]
+fn test_tampered_archive_breaks_the_chain() {
+    let mut persistence: SqlitePersistence = create_published_persistence();
+    let compaction: AuditCompactionData = compact(&mut persistence);
+    let tampered: i64 = compaction.first_event_id.unwrap();
+    execute(
+        &mut persistence,
+        &format!(
+            "UPDATE archived_audit_events SET actor_login_name = 'someone-else' WHERE event_id = {tampered}"
+        ),
+    );
+    let bid_year_id: i64 = bid_year_id(&mut persistence);
+
+    let verification: AuditArchiveVerification =
+        persistence.verify_audit_archive(bid_year_id).unwrap();
+    let report: IntegrityReport = persistence.verify_integrity().unwrap();
+
+    assert!(!verification.is_intact());
+    assert_eq!(verification.broken_event_ids, vec![tampered]);
+    assert_eq!(report.discrepancies.len(), 1);
+    assert_eq!(report.discrepancies[0].check, "audit_archive");
+}
+
+#[test]
+fn test_bid_year_is_compacted_only_once() {
+    let mut persistence: SqlitePersistence = create_published_persistence();
+    compact(&mut persistence);
+    let bid_year_id: i64 = bid_year_id(&mut persistence);
+
+    let again = persistence.compact_audit_log(bid_year_id, &[], &[]);
+
+    assert!(again.is_err());
+}
+
+#[test]
+fn test_reverting_compaction_restores_archived_events() {
+    let mut persistence: SqlitePersistence = create_published_persistence();
+    let compaction: AuditCompactionData = compact(&mut persistence);
+    let archived: Vec<i64> = vec![
+        compaction.first_event_id.unwrap(),
+        compaction.last_event_id.unwrap(),
+    ];
+    let BackendConnection::Sqlite(conn) = &mut persistence.conn else {
+        panic!("expected SQLite");
+    };
+
+    // Later migrations are reverted first, down to the compaction tables
+    while !conn
+        .revert_last_migration(MIGRATIONS)
+        .unwrap()
+        .to_string()
+        .starts_with("20260201")
+    {}
+
+    let restored: Vec<i64> = audit_events::table
+        .filter(audit_events::event_id.eq_any(&archived))
+        .select(audit_events::event_id)
+        .order(audit_events::event_id.asc())
+        .load(conn)
+        .unwrap();
+    assert_eq!(restored, archived);
+}
@@ -72,3 +72,108 @@ fn test_migrations_applied_on_initialization() {
         "Migrations must have applied for bid_years table to exist"
     );
 }
+
+#[test]
+fn test_health_reports_ready_for_fresh_database() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+
+    let health: crate::PersistenceHealth = persistence.health();
+
+    assert_eq!(health.backend, "sqlite");
+    assert!(health.database_reachable);
+    assert!(health.foreign_keys_enforced);
+    assert_eq!(health.migrations.as_ref().map(|m| m.pending), Some(0));
+    assert!(health.schema_version().is_some_and(|v| !v.is_empty()));
+    assert!(health.errors.is_empty());
+    assert!(health.is_ready());
+}
+
+#[test]
+fn test_health_reports_not_ready_without_foreign_keys() {
+    use diesel::RunQueryDsl;
+
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    if let crate::BackendConnection::Sqlite(conn) = &mut persistence.conn {
+        diesel::sql_query("PRAGMA foreign_keys = OFF")
+            .execute(conn)
+            .unwrap();
+    }
+
+    let health: crate::PersistenceHealth = persistence.health();
+
+    assert!(health.database_reachable);
+    assert!(!health.foreign_keys_enforced);
+    assert!(!health.is_ready());
+    assert_eq!(health.errors.len(), 1);
+}
+
+#[test]
+fn test_backup_writes_openable_copy() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    let destination: std::path::PathBuf =
+        std::env::temp_dir().join(format!("zabbid-backup-test-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&destination);
+
+    persistence.backup_to(&destination).unwrap();
+
+    let mut restored: SqlitePersistence = SqlitePersistence::new_with_file(&destination).unwrap();
+    assert!(restored.health().is_ready());
+    assert!(persistence.backup_to(&destination).is_err());
+
+    let _ = std::fs::remove_file(&destination);
+}
+
+#[test]
+fn test_checkpoint_wal_truncates_log() {
+    let path: std::path::PathBuf =
+        std::env::temp_dir().join(format!("zabbid-checkpoint-test-{}.db", std::process::id()));
+    let wal: std::path::PathBuf = path.with_extension("db-wal");
+    let _ = std::fs::remove_file(&path);
+
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_with_file(&path).unwrap();
+    persistence.checkpoint_wal().unwrap();
+
+    assert_eq!(std::fs::metadata(&wal).map_or(0, |m| m.len()), 0);
+    assert!(persistence.health().is_ready());
+
+    drop(persistence);
+    let _ = std::fs::remove_file(&path);
+    let _ = std::fs::remove_file(&wal);
+    let _ = std::fs::remove_file(path.with_extension("db-shm"));
+}
+
+#[test]
+fn test_record_queries_captures_statements_without_binds() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+
+    let (session, sql): (Option<crate::SessionData>, Vec<String>) =
+        persistence.record_queries(|p| {
+            p.get_session_by_token(&crate::SecretToken::new(String::from("secret-token")))
+                .unwrap()
+        });
+
+    assert!(session.is_none());
+    assert_eq!(sql.len(), 1);
+    assert!(sql[0].contains("FROM `sessions`"));
+    assert!(!sql[0].contains("secret-token"));
+
+    // Recording stops once the closure returns
+    let ((), sql): ((), Vec<String>) = persistence.record_queries(|_| ());
+    assert!(sql.is_empty());
+}
+
+#[test]
+fn test_explain_query_plan_reports_scans_and_index_searches() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+
+    let scan: Vec<crate::QueryPlanStep> = persistence
+        .explain_query_plan("SELECT * FROM sessions WHERE expires_at < '2026-01-01'")
+        .unwrap();
+    assert!(scan.iter().any(crate::QueryPlanStep::is_table_scan));
+
+    let search: Vec<crate::QueryPlanStep> = persistence
+        .explain_query_plan("SELECT * FROM sessions WHERE session_token_hash = 'x'")
+        .unwrap();
+    assert!(!search.is_empty());
+    assert!(!search.iter().any(crate::QueryPlanStep::is_table_scan));
+}
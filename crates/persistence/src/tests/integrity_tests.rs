@@ -0,0 +1,109 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Whole-database integrity check tests.
+
+use diesel::RunQueryDsl;
+
+use crate::tests::{
+    create_test_actor, create_test_bid_year_and_area, create_test_cause, create_test_metadata,
+    create_test_operator,
+};
+use crate::{BackendConnection, IntegrityReport, SqlitePersistence};
+use zab_bid::{Command, TransitionResult, apply};
+use zab_bid_domain::BidYear;
+use zab_bid_test_support::BidYearFixture;
+
+/// Returns the registrations of the fixture's users AA and AB.
+fn registrations() -> Vec<TransitionResult> {
+    BidYearFixture::new(2026)
+        .with_users(2)
+        .registrations("North", &create_test_metadata(), &create_test_actor())
+        .unwrap()
+}
+
+/// Creates a database with user AA registered, checkpointed.
+fn create_checkpointed_persistence() -> SqlitePersistence {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    create_test_bid_year_and_area(&mut persistence, 2026, "North");
+
+    let registered: TransitionResult = registrations().remove(0);
+    persistence.persist_transition(&registered).unwrap();
+    let result: TransitionResult = apply(
+        &create_test_metadata(),
+        &registered.new_state,
+        &BidYear::new(2026),
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    persistence.persist_transition(&result).unwrap();
+    persistence
+}
+
+/// Runs raw SQL against the test database.
+fn execute(persistence: &mut SqlitePersistence, sql: &str) {
+    let BackendConnection::Sqlite(conn) = &mut persistence.conn else {
+        panic!("expected SQLite");
+    };
+    diesel::sql_query(sql).execute(conn).unwrap();
+}
+
+#[test]
+fn test_consistent_database_passes() {
+    let mut persistence: SqlitePersistence = create_checkpointed_persistence();
+
+    let report: IntegrityReport = persistence.verify_integrity().unwrap();
+
+    assert!(report.is_consistent(), "{:?}", report.discrepancies);
+    assert_eq!(report.audit_events_checked, 4);
+    assert_eq!(report.snapshots_checked, 2);
+    assert_eq!(report.areas_reconciled, 1);
+}
+
+#[test]
+fn test_undecodable_audit_event_is_reported() {
+    let mut persistence: SqlitePersistence = create_checkpointed_persistence();
+    execute(
+        &mut persistence,
+        "UPDATE audit_events SET action_json = 'not json' WHERE event_id = 3",
+    );
+
+    let report: IntegrityReport = persistence.verify_integrity().unwrap();
+
+    assert_eq!(report.discrepancies.len(), 1);
+    assert_eq!(report.discrepancies[0].check, "audit_log");
+    assert_eq!(report.discrepancies[0].subject, "audit event 3");
+}
+
+#[test]
+fn test_canonical_user_drift_is_reported() {
+    let mut persistence: SqlitePersistence = create_checkpointed_persistence();
+    execute(&mut persistence, "UPDATE users SET name = 'Renamed'");
+
+    let report: IntegrityReport = persistence.verify_integrity().unwrap();
+
+    assert_eq!(report.discrepancies.len(), 1);
+    assert_eq!(report.discrepancies[0].check, "canonical_state");
+    assert_eq!(report.discrepancies[0].subject, "2026/NORTH");
+}
+
+#[test]
+fn test_areas_changed_since_their_snapshot_are_not_reconciled() {
+    let mut persistence: SqlitePersistence = create_checkpointed_persistence();
+    persistence
+        .persist_transition(&registrations().remove(1))
+        .unwrap();
+
+    let report: IntegrityReport = persistence.verify_integrity().unwrap();
+
+    assert!(report.is_consistent(), "{:?}", report.discrepancies);
+    assert_eq!(report.areas_reconciled, 0);
+}
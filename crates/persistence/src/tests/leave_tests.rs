@@ -0,0 +1,1015 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for leave bid persistence, daily leave counts, bid preferences,
+//! slot adjustments, overbid requests, bid rules, prime dates, crew slot
+//! partitions, per-round bid order, round sign-offs, waitlists, leave caps, the facility
+//! configuration, and feature flags.
+
+use diesel::prelude::*;
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+
+use crate::tests::create_test_operator;
+use crate::{
+    BidAmendmentPolicyData, BidPreferenceData, BidPreferenceSpecData, BidRuleData, BidRuleSpecData,
+    DailyLeaveCountData, FacilityData, FeatureFlagData, LeaveBidData, LeaveCarryoverData,
+    NewLeaveBids, OverbidRequestData, Persistence, PersistenceError, PrimePeriodData,
+    RoundBidOrderData, RoundCrewSlotsData, RoundEligibilityData, RoundPrimeCapData,
+    RoundSequencingData, RoundSignOffData, SlotAdjustmentData, WaitlistOfferData, WaitlistSlotData,
+};
+
+/// Creates a 2026 bid year with areas AREA1 (ID 1) and AREA2 (ID 2) and
+/// rounds 1 and 2. AREA1 holds ABC (crew 1), DEF (crew 2), and GHI (no
+/// crew); AREA2 holds JKL (crew 1).
+fn setup(persistence: &mut Persistence) {
+    match &mut persistence.conn {
+        crate::BackendConnection::Sqlite(conn) => {
+            diesel::sql_query(
+                "INSERT INTO bid_years (bid_year_id, year, start_date, num_pay_periods, is_active, lifecycle_state)
+                 VALUES (1, 2026, '2026-01-04', 26, 1, 'BiddingActive')",
+            )
+            .execute(conn)
+            .expect("Failed to insert bid year");
+
+            diesel::sql_query(
+                "INSERT INTO areas (area_id, bid_year_id, area_code, area_name, is_system_area)
+                 VALUES (1, 1, 'AREA1', 'Area One', 0), (2, 1, 'AREA2', 'Area Two', 0)",
+            )
+            .execute(conn)
+            .expect("Failed to insert areas");
+
+            diesel::sql_query(
+                "INSERT INTO users (user_id, bid_year_id, area_id, initials, name, user_type, crew, cumulative_natca_bu_date, natca_bu_date, eod_faa_date, service_computation_date, lottery_value, excluded_from_bidding, excluded_from_leave_calculation)
+                 VALUES
+                 (1, 1, 1, 'ABC', 'User One', 'CPC', 1, '2020-01-01', '2020-01-01', '2020-01-01', '2020-01-01', NULL, 0, 0),
+                 (2, 1, 1, 'DEF', 'User Two', 'CPC', 2, '2019-01-01', '2019-01-01', '2019-01-01', '2019-01-01', NULL, 0, 0),
+                 (3, 1, 1, 'GHI', 'User Three', 'CPC', NULL, '2021-01-01', '2021-01-01', '2021-01-01', '2021-01-01', NULL, 0, 0),
+                 (4, 1, 2, 'JKL', 'User Four', 'CPC', 1, '2018-01-01', '2018-01-01', '2018-01-01', '2018-01-01', NULL, 0, 0)",
+            )
+            .execute(conn)
+            .expect("Failed to insert users");
+
+            diesel::sql_query(
+                "INSERT INTO round_groups (round_group_id, bid_year_id, name, editing_enabled)
+                 VALUES (1, 1, 'Default', 1)",
+            )
+            .execute(conn)
+            .expect("Failed to insert round group");
+
+            diesel::sql_query(
+                "INSERT INTO rounds (round_id, round_group_id, round_number, name, slots_per_day, max_groups, max_total_hours, include_holidays, allow_overbid)
+                 VALUES (1, 1, 1, 'Round 1', 2, 1, 80, 0, 0), (2, 1, 2, 'Round 2', 1, 1, 80, 0, 0)",
+            )
+            .execute(conn)
+            .expect("Failed to insert rounds");
+        }
+        crate::BackendConnection::Mysql(_) => {
+            panic!("This test is SQLite-specific");
+        }
+    }
+}
+
+fn count(
+    leave_date: &str,
+    area_id: i64,
+    round_id: i64,
+    crew: Option<i32>,
+    approved: i64,
+) -> DailyLeaveCountData {
+    DailyLeaveCountData {
+        leave_date: String::from(leave_date),
+        area_id,
+        round_id,
+        crew,
+        approved,
+    }
+}
+
+#[test]
+fn test_daily_leave_counts_group_by_area_round_and_crew() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    persistence
+        .insert_leave_bid(1, 1, 1, 1, "2026-06-01", 8, "AB", "phone")
+        .unwrap();
+    persistence
+        .insert_leave_bid(1, 1, 2, 1, "2026-06-01", 8, "AB", "phone")
+        .unwrap();
+    persistence
+        .insert_leave_bid(1, 1, 3, 1, "2026-06-01", 8, "AB", "phone")
+        .unwrap();
+    persistence
+        .insert_leave_bid(1, 1, 1, 2, "2026-06-02", 8, "AB", "phone")
+        .unwrap();
+    persistence
+        .insert_leave_bid(1, 2, 4, 1, "2026-06-01", 8, "AB", "phone")
+        .unwrap();
+    persistence
+        .insert_leave_bid(1, 1, 2, 1, "2026-07-01", 8, "AB", "phone")
+        .unwrap();
+
+    let counts: Vec<DailyLeaveCountData> = persistence
+        .list_daily_leave_counts(1, "2026-06-01", "2026-06-30")
+        .unwrap();
+
+    assert_eq!(
+        counts,
+        vec![
+            count("2026-06-01", 1, 1, None, 1),
+            count("2026-06-01", 1, 1, Some(1), 1),
+            count("2026-06-01", 1, 1, Some(2), 1),
+            count("2026-06-01", 2, 1, Some(1), 1),
+            count("2026-06-02", 1, 2, Some(1), 1),
+        ]
+    );
+}
+
+#[test]
+fn test_withdrawn_leave_is_not_counted() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    let leave_bid_id: i64 = persistence
+        .insert_leave_bid(1, 1, 1, 1, "2026-06-01", 8, "AB", "phone")
+        .unwrap();
+    persistence
+        .insert_leave_bid(1, 1, 2, 1, "2026-06-01", 8, "AB", "phone")
+        .unwrap();
+    persistence.withdraw_leave_bid(leave_bid_id).unwrap();
+
+    let counts: Vec<DailyLeaveCountData> = persistence
+        .list_daily_leave_counts(1, "2026-06-01", "2026-06-01")
+        .unwrap();
+    assert_eq!(counts, vec![count("2026-06-01", 1, 1, Some(2), 1)]);
+
+    let bids: Vec<LeaveBidData> = persistence.list_leave_bids(1, 1).unwrap();
+    assert_eq!(bids.len(), 2);
+    assert_eq!(bids[0].status, LeaveBidData::STATUS_WITHDRAWN);
+    assert_eq!(bids[1].status, LeaveBidData::STATUS_APPROVED);
+    assert_eq!(bids[1].on_behalf_of.as_deref(), Some("AB"));
+    assert_eq!(bids[1].received_via.as_deref(), Some("phone"));
+}
+
+#[test]
+fn test_duplicate_leave_day_in_round_is_rejected() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    persistence
+        .insert_leave_bid(1, 1, 1, 1, "2026-06-01", 8, "AB", "phone")
+        .unwrap();
+
+    assert!(
+        persistence
+            .insert_leave_bid(1, 1, 1, 1, "2026-06-01", 8, "AB", "phone")
+            .is_err()
+    );
+    assert!(
+        persistence
+            .insert_leave_bid(1, 1, 1, 2, "2026-06-01", 8, "AB", "phone")
+            .is_ok()
+    );
+}
+
+fn leave_bids(user_id: i64, days: &[&str], slot_crew: Option<i32>) -> NewLeaveBids {
+    NewLeaveBids {
+        bid_year_id: 1,
+        area_id: 1,
+        user_id,
+        round_id: 2,
+        days: days.iter().map(|day| (day.to_string(), 1)).collect(),
+        hours: 8,
+        on_behalf_of: String::from("DEF"),
+        received_via: String::from("phone"),
+        slot_crew,
+    }
+}
+
+fn enter_leave_bid_event() -> AuditEvent {
+    AuditEvent::new_global(
+        Actor::with_operator(
+            String::from("1"),
+            String::from("admin"),
+            1,
+            String::from("testop"),
+            String::from("Test Operator"),
+        ),
+        Cause::new(String::from("test"), String::from("Test")),
+        Action::new(String::from("EnterLeaveBid"), None),
+        StateSnapshot::new(String::from("leave_days=0")),
+        StateSnapshot::new(String::from("leave_days=1")),
+    )
+}
+
+fn count_audit_events(persistence: &mut Persistence) -> i64 {
+    match &mut persistence.conn {
+        crate::BackendConnection::Sqlite(conn) => crate::diesel_schema::audit_events::table
+            .count()
+            .get_result(conn)
+            .unwrap(),
+        crate::BackendConnection::Mysql(_) => {
+            panic!("This test is SQLite-specific");
+        }
+    }
+}
+
+#[test]
+fn test_leave_bids_on_a_full_day_are_not_recorded() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    create_test_operator(&mut persistence);
+
+    // Round 2 has one slot a day, and ABC (crew 1) holds 2026-06-02
+    persistence
+        .insert_leave_bid(1, 1, 1, 2, "2026-06-02", 8, "ABC", "phone")
+        .unwrap();
+
+    let result: Result<(Vec<i64>, i64), PersistenceError> = persistence.record_leave_bids(
+        &enter_leave_bid_event(),
+        &leave_bids(2, &["2026-06-01", "2026-06-02"], None),
+    );
+    assert!(matches!(
+        result,
+        Err(PersistenceError::LeaveSlotsExhausted { round_id: 2, ref leave_date })
+            if leave_date == "2026-06-02"
+    ));
+    assert_eq!(persistence.list_leave_bids(1, 1).unwrap().len(), 1);
+    assert_eq!(count_audit_events(&mut persistence), 0);
+
+    let (leave_bid_ids, event_id): (Vec<i64>, i64) = persistence
+        .record_leave_bids(
+            &enter_leave_bid_event(),
+            &leave_bids(2, &["2026-06-01"], None),
+        )
+        .unwrap();
+    assert_eq!(leave_bid_ids.len(), 1);
+    assert_eq!(
+        persistence.get_audit_event(event_id).unwrap().action.name,
+        "EnterLeaveBid"
+    );
+    assert_eq!(count_audit_events(&mut persistence), 1);
+}
+
+#[test]
+fn test_leave_bids_count_only_the_slot_crew() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    create_test_operator(&mut persistence);
+
+    persistence
+        .insert_leave_bid(1, 1, 1, 2, "2026-06-02", 8, "ABC", "phone")
+        .unwrap();
+
+    // ABC's leave fills crew 1's slot but not crew 2's
+    let (leave_bid_ids, _): (Vec<i64>, i64) = persistence
+        .record_leave_bids(
+            &enter_leave_bid_event(),
+            &leave_bids(2, &["2026-06-02"], Some(2)),
+        )
+        .unwrap();
+    assert_eq!(leave_bid_ids.len(), 1);
+}
+
+fn preference(preference_rank: i32, leave_dates: &[&str]) -> BidPreferenceSpecData {
+    BidPreferenceSpecData {
+        preference_rank,
+        leave_dates: leave_dates.iter().map(ToString::to_string).collect(),
+        hours: 8,
+    }
+}
+
+#[test]
+fn test_replacing_bid_preferences_keeps_processed_ones() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    persistence
+        .replace_bid_preferences(
+            1,
+            1,
+            1,
+            1,
+            "ABC",
+            "written",
+            &[
+                preference(1, &["2026-06-01", "2026-06-02"]),
+                preference(2, &["2026-07-01"]),
+            ],
+        )
+        .unwrap();
+    let stored: Vec<BidPreferenceData> = persistence.list_bid_preferences(1, 1).unwrap();
+    assert_eq!(stored.len(), 2);
+    assert_eq!(stored[0].preference_rank, 1);
+    assert_eq!(stored[0].leave_dates, vec!["2026-06-01", "2026-06-02"]);
+    assert_eq!(stored[0].on_behalf_of, "ABC");
+    assert_eq!(stored[0].received_via, "written");
+    assert_eq!(stored[0].status, BidPreferenceData::STATUS_PENDING);
+
+    persistence
+        .set_bid_preference_status(
+            stored[0].bid_preference_id,
+            BidPreferenceData::STATUS_APPLIED,
+        )
+        .unwrap();
+    persistence
+        .replace_bid_preferences(
+            1,
+            1,
+            1,
+            1,
+            "ABC",
+            "phone",
+            &[preference(3, &["2026-08-01"])],
+        )
+        .unwrap();
+
+    let stored: Vec<BidPreferenceData> = persistence.list_bid_preferences(1, 1).unwrap();
+    assert_eq!(
+        stored
+            .iter()
+            .map(|p| (p.preference_rank, p.status.as_str()))
+            .collect::<Vec<(i32, &str)>>(),
+        vec![
+            (1, BidPreferenceData::STATUS_APPLIED),
+            (3, BidPreferenceData::STATUS_PENDING),
+        ]
+    );
+    assert!(persistence.list_bid_preferences(1, 2).unwrap().is_empty());
+}
+
+fn submit_bid_preferences_event() -> AuditEvent {
+    AuditEvent::new_global(
+        Actor::with_operator(
+            String::from("1"),
+            String::from("admin"),
+            1,
+            String::from("testop"),
+            String::from("Test Operator"),
+        ),
+        Cause::new(String::from("test"), String::from("Test")),
+        Action::new(String::from("SubmitBidPreferences"), None),
+        StateSnapshot::new(String::from("preferences=1")),
+        StateSnapshot::new(String::from("preferences=1")),
+    )
+}
+
+#[test]
+fn test_submitted_preferences_are_kept_only_with_their_audit_event() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    persistence
+        .replace_bid_preferences(
+            1,
+            1,
+            1,
+            1,
+            "ABC",
+            "written",
+            &[preference(1, &["2026-06-01"])],
+        )
+        .unwrap();
+
+    // Without its operator the audit event cannot be written, so the
+    // replacement is rolled back
+    assert!(
+        persistence
+            .submit_bid_preferences(
+                &submit_bid_preferences_event(),
+                1,
+                1,
+                1,
+                1,
+                "ABC",
+                "phone",
+                &[preference(1, &["2026-07-01"])],
+            )
+            .is_err()
+    );
+    let stored: Vec<BidPreferenceData> = persistence.list_bid_preferences(1, 1).unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].leave_dates, vec!["2026-06-01"]);
+
+    create_test_operator(&mut persistence);
+    let event_id: i64 = persistence
+        .submit_bid_preferences(
+            &submit_bid_preferences_event(),
+            1,
+            1,
+            1,
+            1,
+            "ABC",
+            "phone",
+            &[preference(1, &["2026-07-01"])],
+        )
+        .unwrap();
+    let stored: Vec<BidPreferenceData> = persistence.list_bid_preferences(1, 1).unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].leave_dates, vec!["2026-07-01"]);
+    assert_eq!(
+        persistence.get_audit_event(event_id).unwrap().action.name,
+        "SubmitBidPreferences"
+    );
+}
+
+#[test]
+fn test_slot_adjustment_is_replaced_per_day() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    assert_eq!(
+        persistence.get_slot_adjustment(1, 1, "2026-06-01").unwrap(),
+        0
+    );
+
+    persistence
+        .set_slot_adjustment(1, 1, 1, "2026-06-01", -1)
+        .unwrap();
+    persistence
+        .set_slot_adjustment(1, 2, 1, "2026-06-02", 2)
+        .unwrap();
+    persistence
+        .set_slot_adjustment(1, 1, 1, "2026-06-01", 1)
+        .unwrap();
+
+    assert_eq!(
+        persistence.get_slot_adjustment(1, 1, "2026-06-01").unwrap(),
+        1
+    );
+    assert_eq!(
+        persistence
+            .list_slot_adjustments(1, "2026-06-01", "2026-06-30")
+            .unwrap(),
+        vec![
+            SlotAdjustmentData {
+                area_id: 1,
+                round_id: 1,
+                slot_date: String::from("2026-06-01"),
+                staffing_adjustment: 1,
+            },
+            SlotAdjustmentData {
+                area_id: 2,
+                round_id: 1,
+                slot_date: String::from("2026-06-02"),
+                staffing_adjustment: 2,
+            },
+        ]
+    );
+    assert!(
+        persistence
+            .list_slot_adjustments(1, "2026-07-01", "2026-07-31")
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_overbid_requests_listed_per_round() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    let later: i64 = persistence
+        .insert_overbid_request(1, 1, 2, 1, "2026-06-02", 8, "DEF", "phone")
+        .unwrap();
+    let earlier: i64 = persistence
+        .insert_overbid_request(1, 1, 1, 1, "2026-06-01", 8, "ABC", "written")
+        .unwrap();
+    persistence
+        .insert_overbid_request(1, 1, 1, 2, "2026-06-01", 8, "ABC", "written")
+        .unwrap();
+
+    let requests: Vec<OverbidRequestData> = persistence.list_overbid_requests(1, 1).unwrap();
+    assert_eq!(
+        requests
+            .iter()
+            .map(|r| r.overbid_request_id)
+            .collect::<Vec<i64>>(),
+        vec![earlier, later]
+    );
+    assert!(
+        requests
+            .iter()
+            .all(|r| r.status == OverbidRequestData::STATUS_PENDING
+                && r.request_event_id.is_none()
+                && r.decided_at.is_none())
+    );
+
+    let request: OverbidRequestData = persistence.get_overbid_request(later).unwrap().unwrap();
+    assert_eq!(request.on_behalf_of, "DEF");
+    assert_eq!(request.received_via, "phone");
+    assert!(
+        persistence
+            .get_overbid_request(later + 100)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_bid_rules_replaced_as_a_whole() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    persistence
+        .replace_bid_rules(
+            1,
+            &[BidRuleSpecData {
+                rule_kind: String::from("min_consecutive_days"),
+                rule_value: Some(2),
+                rule_dates: Vec::new(),
+            }],
+        )
+        .unwrap();
+    persistence
+        .replace_bid_rules(
+            1,
+            &[
+                BidRuleSpecData {
+                    rule_kind: String::from("holiday_pairing"),
+                    rule_value: None,
+                    rule_dates: vec![String::from("2026-07-03"), String::from("2026-12-25")],
+                },
+                BidRuleSpecData {
+                    rule_kind: String::from("max_splits_per_round"),
+                    rule_value: Some(3),
+                    rule_dates: Vec::new(),
+                },
+            ],
+        )
+        .unwrap();
+
+    let rules: Vec<BidRuleData> = persistence.list_bid_rules(1).unwrap();
+    assert_eq!(
+        rules
+            .iter()
+            .map(|r| (r.rule_position, r.rule_kind.as_str(), r.rule_value))
+            .collect::<Vec<(i32, &str, Option<i32>)>>(),
+        vec![
+            (1, "holiday_pairing", None),
+            (2, "max_splits_per_round", Some(3)),
+        ]
+    );
+    assert_eq!(rules[0].rule_dates, vec!["2026-07-03", "2026-12-25"]);
+    assert!(rules[1].rule_dates.is_empty());
+
+    persistence.replace_bid_rules(1, &[]).unwrap();
+    assert!(persistence.list_bid_rules(1).unwrap().is_empty());
+}
+
+#[test]
+fn test_prime_periods_and_round_caps() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    let christmas: i64 = persistence
+        .insert_prime_period(1, "Christmas week", "2026-12-21", "2026-12-27")
+        .unwrap();
+    let spring: i64 = persistence
+        .insert_prime_period(1, "Spring break", "2026-03-16", "2026-03-20")
+        .unwrap();
+
+    let periods: Vec<PrimePeriodData> = persistence.list_prime_periods(1).unwrap();
+    assert_eq!(
+        periods
+            .iter()
+            .map(|p| p.prime_period_id)
+            .collect::<Vec<i64>>(),
+        vec![spring, christmas]
+    );
+    assert_eq!(
+        persistence.get_prime_period(christmas).unwrap().label,
+        "Christmas week"
+    );
+
+    persistence.delete_prime_period(spring).unwrap();
+    assert!(persistence.get_prime_period(spring).is_err());
+    assert!(persistence.delete_prime_period(spring).is_err());
+
+    persistence.set_round_prime_cap(1, 1, Some(1)).unwrap();
+    persistence.set_round_prime_cap(1, 2, Some(0)).unwrap();
+    persistence.set_round_prime_cap(1, 1, Some(3)).unwrap();
+    persistence.set_round_prime_cap(1, 2, None).unwrap();
+    assert_eq!(
+        persistence.list_round_prime_caps(1).unwrap(),
+        vec![RoundPrimeCapData {
+            round_id: 1,
+            prime_slots_per_day: 3,
+        }]
+    );
+}
+
+#[test]
+fn test_crew_slots_replaced_per_round() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    assert!(
+        persistence
+            .list_crew_partitioned_rounds(1)
+            .unwrap()
+            .is_empty()
+    );
+
+    persistence
+        .set_round_crew_slots(1, 1, true, &[(1, 2), (2, 1)])
+        .unwrap();
+    persistence
+        .set_round_crew_slots(1, 2, true, &[(3, 1)])
+        .unwrap();
+    persistence
+        .set_round_crew_slots(1, 1, true, &[(2, 4)])
+        .unwrap();
+    persistence.set_round_crew_slots(1, 2, false, &[]).unwrap();
+
+    assert_eq!(
+        persistence.list_crew_partitioned_rounds(1).unwrap(),
+        vec![1]
+    );
+    assert_eq!(
+        persistence.list_round_crew_slots(1).unwrap(),
+        vec![RoundCrewSlotsData {
+            round_id: 1,
+            crew: 2,
+            slots_per_day: 4,
+        }]
+    );
+}
+
+#[test]
+fn test_round_eligibility_replaced_per_round() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    assert!(persistence.get_round_eligibility(1).unwrap().is_none());
+
+    let carryover: RoundEligibilityData = RoundEligibilityData {
+        round_id: 2,
+        min_remaining_hours: Some(16),
+        user_types: Vec::new(),
+    };
+    let cpc_only: RoundEligibilityData = RoundEligibilityData {
+        round_id: 1,
+        min_remaining_hours: None,
+        user_types: vec![String::from("CPC"), String::from("CPC-IT")],
+    };
+    persistence
+        .set_round_eligibility(
+            1,
+            1,
+            Some(&RoundEligibilityData {
+                round_id: 1,
+                min_remaining_hours: Some(8),
+                user_types: Vec::new(),
+            }),
+        )
+        .unwrap();
+    persistence
+        .set_round_eligibility(1, 1, Some(&cpc_only))
+        .unwrap();
+    persistence
+        .set_round_eligibility(1, 2, Some(&carryover))
+        .unwrap();
+
+    assert_eq!(
+        persistence.list_round_eligibility(1).unwrap(),
+        vec![cpc_only.clone(), carryover]
+    );
+    persistence.set_round_eligibility(1, 2, None).unwrap();
+    assert_eq!(
+        persistence.get_round_eligibility(1).unwrap(),
+        Some(cpc_only)
+    );
+    assert!(persistence.get_round_eligibility(2).unwrap().is_none());
+}
+
+#[test]
+fn test_round_bid_order_stored_per_round() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    persistence
+        .set_round_group_rotation(1, 1, Some("reverse_even_rounds"))
+        .unwrap();
+    assert_eq!(
+        persistence.get_round_group_rotation(1).unwrap().as_deref(),
+        Some("reverse_even_rounds")
+    );
+    assert_eq!(
+        persistence.list_round_sequencing(1).unwrap()[1],
+        RoundSequencingData {
+            round_id: 2,
+            round_group_id: 1,
+            round_number: 2,
+            rotation: Some(String::from("reverse_even_rounds")),
+        }
+    );
+    persistence.set_round_group_rotation(1, 1, None).unwrap();
+    assert!(persistence.get_round_group_rotation(1).unwrap().is_none());
+
+    let entry = |round_id: i64, user_id: i64, area_id: i64, position: i32| RoundBidOrderData {
+        round_id,
+        user_id,
+        bid_year_id: 1,
+        area_id,
+        position,
+    };
+    persistence
+        .bulk_insert_round_bid_order(&[
+            entry(2, 4, 2, 1),
+            entry(2, 2, 1, 1),
+            entry(2, 1, 1, 2),
+            entry(1, 1, 1, 1),
+        ])
+        .unwrap();
+    assert_eq!(
+        persistence.list_round_bid_order(2).unwrap(),
+        vec![entry(2, 2, 1, 1), entry(2, 1, 1, 2), entry(2, 4, 2, 1)]
+    );
+}
+
+#[test]
+fn test_round_sign_offs_recorded_once_per_area() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    create_test_operator(&mut persistence);
+    let event_id: i64 = persistence
+        .persist_audit_event(&AuditEvent::new_global(
+            Actor::with_operator(
+                String::from("1"),
+                String::from("admin"),
+                1,
+                String::from("testop"),
+                String::from("Test Operator"),
+            ),
+            Cause::new(String::from("test"), String::from("Test")),
+            Action::new(String::from("SignOffRound"), None),
+            StateSnapshot::new(String::from("round_id=1,signed_off=false")),
+            StateSnapshot::new(String::from("round_id=1,signed_off=true")),
+        ))
+        .unwrap();
+
+    assert!(persistence.get_round_sign_off(1, 1).unwrap().is_none());
+    persistence
+        .insert_round_sign_off(1, 2, 1, event_id)
+        .unwrap();
+    let sign_off_id: i64 = persistence
+        .insert_round_sign_off(1, 1, 1, event_id)
+        .unwrap();
+
+    // A round is signed off once per area
+    assert!(
+        persistence
+            .insert_round_sign_off(1, 1, 1, event_id)
+            .is_err()
+    );
+
+    let sign_off: RoundSignOffData = persistence.get_round_sign_off(1, 1).unwrap().unwrap();
+    assert_eq!(sign_off.round_sign_off_id, sign_off_id);
+    assert_eq!(sign_off.audit_event_id, event_id);
+    assert!(persistence.get_round_sign_off(1, 2).unwrap().is_none());
+    assert_eq!(
+        persistence
+            .list_round_sign_offs(1)
+            .unwrap()
+            .iter()
+            .map(|s| (s.area_id, s.round_id))
+            .collect::<Vec<(i64, i64)>>(),
+        vec![(1, 1), (2, 1)]
+    );
+}
+
+#[test]
+fn test_bid_amendment_policy_replaced_per_bid_year() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    assert!(persistence.get_bid_amendment_policy(1).unwrap().is_none());
+    persistence
+        .set_bid_amendment_policy(1, "within_hours", Some(24))
+        .unwrap();
+    persistence
+        .set_bid_amendment_policy(1, "until_window_closes", None)
+        .unwrap();
+
+    assert_eq!(
+        persistence.get_bid_amendment_policy(1).unwrap(),
+        Some(BidAmendmentPolicyData {
+            bid_year_id: 1,
+            policy: String::from("until_window_closes"),
+            window_hours: None,
+        })
+    );
+}
+
+#[test]
+fn test_feature_flags_replaced_per_bid_year() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    assert_eq!(persistence.get_feature_flag(1, "waitlists").unwrap(), None);
+    persistence.set_feature_flag(1, "waitlists", false).unwrap();
+    persistence
+        .set_feature_flag(1, "auto_advance", false)
+        .unwrap();
+    persistence
+        .set_feature_flag(1, "auto_advance", true)
+        .unwrap();
+
+    assert_eq!(
+        persistence.get_feature_flag(1, "waitlists").unwrap(),
+        Some(false)
+    );
+    assert_eq!(
+        persistence.list_feature_flags(1).unwrap(),
+        vec![
+            FeatureFlagData {
+                bid_year_id: 1,
+                flag: String::from("auto_advance"),
+                enabled: true,
+            },
+            FeatureFlagData {
+                bid_year_id: 1,
+                flag: String::from("waitlists"),
+                enabled: false,
+            },
+        ]
+    );
+}
+
+#[test]
+#[allow(clippy::too_many_lines)]
+fn test_waitlist_slot_offered_down_the_list() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    create_test_operator(&mut persistence);
+    let event_id: i64 = persistence
+        .persist_audit_event(&AuditEvent::new_global(
+            Actor::with_operator(
+                String::from("1"),
+                String::from("admin"),
+                1,
+                String::from("testop"),
+                String::from("Test Operator"),
+            ),
+            Cause::new(String::from("test"), String::from("Test")),
+            Action::new(String::from("WithdrawLeaveBid"), None),
+            StateSnapshot::new(String::from("status=approved")),
+            StateSnapshot::new(String::from("status=withdrawn,waitlist=open")),
+        ))
+        .unwrap();
+    let leave_bid_id: i64 = persistence
+        .insert_leave_bid(1, 1, 1, 1, "2026-07-01", 8, "ABC", "phone")
+        .unwrap();
+    persistence.withdraw_leave_bid(leave_bid_id).unwrap();
+
+    let slot_id: i64 = persistence
+        .insert_waitlist_slot(&WaitlistSlotData {
+            waitlist_slot_id: 0,
+            bid_year_id: 1,
+            area_id: 1,
+            round_id: 1,
+            leave_date: String::from("2026-07-01"),
+            hours: 8,
+            crew: Some(1),
+            offer_hours: 24,
+            released_leave_bid_id: leave_bid_id,
+            release_event_id: event_id,
+            status: String::new(),
+            created_at: String::new(),
+        })
+        .unwrap();
+    let slot: WaitlistSlotData = persistence.get_waitlist_slot(slot_id).unwrap().unwrap();
+    assert_eq!(slot.status, WaitlistSlotData::STATUS_OPEN);
+    assert_eq!(slot.crew, Some(1));
+    assert_eq!(persistence.list_waitlist_slots(1, 1).unwrap().len(), 1);
+    assert!(persistence.list_waitlist_slots(2, 1).unwrap().is_empty());
+
+    let first_offer: i64 = persistence
+        .insert_waitlist_offer(
+            slot_id,
+            2,
+            "2026-07-01T10:00:00Z",
+            "2026-07-02T10:00:00Z",
+            event_id,
+        )
+        .unwrap();
+    // A user is offered a slot at most once
+    assert!(
+        persistence
+            .insert_waitlist_offer(
+                slot_id,
+                2,
+                "2026-07-01T11:00:00Z",
+                "2026-07-02T11:00:00Z",
+                event_id,
+            )
+            .is_err()
+    );
+    persistence
+        .respond_to_waitlist_offer(
+            first_offer,
+            WaitlistOfferData::STATUS_DECLINED,
+            "2026-07-01T12:00:00Z",
+            event_id,
+            None,
+        )
+        .unwrap();
+    let second_offer: i64 = persistence
+        .insert_waitlist_offer(
+            slot_id,
+            3,
+            "2026-07-01T12:00:00Z",
+            "2026-07-02T12:00:00Z",
+            event_id,
+        )
+        .unwrap();
+
+    let pending: Vec<WaitlistOfferData> = persistence.list_pending_waitlist_offers().unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].waitlist_offer_id, second_offer);
+    assert_eq!(pending[0].user_id, 3);
+
+    let declined: WaitlistOfferData = persistence
+        .get_waitlist_offer(first_offer)
+        .unwrap()
+        .unwrap();
+    assert_eq!(declined.status, WaitlistOfferData::STATUS_DECLINED);
+    assert_eq!(declined.response_event_id, Some(event_id));
+    assert_eq!(
+        persistence
+            .list_waitlist_offers(slot_id)
+            .unwrap()
+            .iter()
+            .map(|o| o.user_id)
+            .collect::<Vec<i64>>(),
+        vec![2, 3]
+    );
+
+    persistence
+        .set_waitlist_slot_status(slot_id, WaitlistSlotData::STATUS_UNCLAIMED)
+        .unwrap();
+    assert_eq!(
+        persistence
+            .get_waitlist_slot(slot_id)
+            .unwrap()
+            .unwrap()
+            .status,
+        WaitlistSlotData::STATUS_UNCLAIMED
+    );
+}
+
+#[test]
+fn test_facility_replaced() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    let mut facility: FacilityData = FacilityData {
+        identifier: String::from("ZAB"),
+        name: String::from("Albuquerque Center"),
+        default_timezone: String::from("America/Denver"),
+        junior_accrual_hours: 4,
+        mid_accrual_hours: 6,
+        senior_accrual_hours: 8,
+        max_carryover_hours: 240,
+        updated_at: String::new(),
+    };
+
+    assert_eq!(persistence.get_facility().unwrap(), None);
+    persistence.set_facility(&facility).unwrap();
+    facility.max_carryover_hours = 360;
+    persistence.set_facility(&facility).unwrap();
+
+    let stored: FacilityData = persistence.get_facility().unwrap().unwrap();
+    assert_eq!(stored.max_carryover_hours, 360);
+    assert_eq!(stored.default_timezone, "America/Denver");
+    assert!(!stored.updated_at.is_empty());
+}
+
+#[test]
+fn test_leave_cap_and_carryovers_replaced() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    assert_eq!(persistence.get_leave_cap(1).unwrap(), None);
+    persistence.set_leave_cap(1, 240).unwrap();
+    persistence.set_leave_cap(1, 200).unwrap();
+    assert_eq!(persistence.get_leave_cap(1).unwrap(), Some(200));
+
+    persistence.set_leave_carryover(1, 2, 80).unwrap();
+    persistence.set_leave_carryover(1, 1, 300).unwrap();
+    persistence.set_leave_carryover(1, 2, 96).unwrap();
+    assert_eq!(
+        persistence.list_leave_carryovers(1).unwrap(),
+        vec![
+            LeaveCarryoverData {
+                bid_year_id: 1,
+                user_id: 1,
+                carryover_hours: 300,
+            },
+            LeaveCarryoverData {
+                bid_year_id: 1,
+                user_id: 2,
+                carryover_hours: 96,
+            },
+        ]
+    );
+}
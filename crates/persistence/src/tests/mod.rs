@@ -6,15 +6,26 @@
 #![allow(clippy::expect_used, clippy::unwrap_used)]
 
 mod audit_serialization_tests;
+mod audit_snapshot_tests;
 mod backend_validation_tests;
 mod bootstrap_tests;
+mod bundle_tests;
 mod canonical_tests;
+mod chat_tests;
+mod compaction_tests;
 mod completeness_tests;
 mod initialization_tests;
+mod integrity_tests;
+mod leave_tests;
 mod mutation_error_tests;
+mod notification_tests;
 mod operator_tests;
+mod outbox_tests;
 mod override_tests;
+mod projection_tests;
 mod state_tests;
+mod webhook_tests;
+mod write_queue_tests;
 
 use time::Date;
 use zab_bid::BootstrapMetadata;
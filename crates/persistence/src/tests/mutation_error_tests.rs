@@ -368,3 +368,54 @@ fn test_lookup_area_id_with_nonexistent_bid_year_returns_not_found() {
         other => panic!("Expected NotFound error, got: {other:?}"),
     }
 }
+
+#[test]
+fn test_record_self_bid_request_with_nonexistent_user_persists_nothing() {
+    use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+
+    let (mut persistence, bid_year_id, area_id) =
+        setup_test_persistence_with_entities().expect("Failed to setup persistence");
+    let bid_year = BidYear::new(2026);
+    let area = Area::new("NORTH");
+    let before: usize = persistence
+        .get_audit_timeline(&bid_year, &area)
+        .expect("Failed to read timeline")
+        .len();
+
+    let event: AuditEvent = AuditEvent::new(
+        Actor::new(String::from("test-admin"), String::from("admin")),
+        Cause::new(String::from("test"), String::from("Test")),
+        Action::new(String::from("RequestSelfBidEntry"), None),
+        StateSnapshot::new(String::from("self_bid=none")),
+        StateSnapshot::new(String::from("self_bid=pending")),
+        bid_year.clone(),
+        area.clone(),
+    );
+    let result = persistence.record_self_bid_request(
+        &event,
+        bid_year_id,
+        area_id,
+        99999,
+        99999,
+        1,
+        &[String::from("2026-06-01")],
+        8,
+        "AB",
+        "in_person",
+    );
+
+    assert!(result.is_err());
+    assert_eq!(
+        persistence
+            .get_audit_timeline(&bid_year, &area)
+            .expect("Failed to read timeline")
+            .len(),
+        before
+    );
+    assert!(
+        persistence
+            .list_self_bid_requests(area_id, 99999)
+            .expect("Failed to list requests")
+            .is_empty()
+    );
+}
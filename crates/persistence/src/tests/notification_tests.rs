@@ -0,0 +1,495 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for user contacts, notification candidates, the notification log,
+//! and encryption of contact email addresses.
+
+use crate::{
+    BidEntryNotificationCandidate, COLUMN_KEY_LENGTH, ColumnKey, ColumnKeyring, NewBidStatus,
+    NewBidWindow, NotificationLogData, Persistence, PersistenceError, UserContactData,
+    WindowNotificationCandidate,
+};
+use diesel::prelude::*;
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+
+/// IDs created by [`setup`].
+#[allow(clippy::struct_field_names)]
+struct Fixture {
+    operator_id: i64,
+    audit_event_id: i64,
+    bid_status_id: i64,
+}
+
+/// Creates a `BiddingActive` bid year with one area, round, and user, a bid
+/// window for that user, and a bid status record.
+#[allow(clippy::too_many_lines)]
+fn setup(persistence: &mut Persistence) -> Fixture {
+    match &mut persistence.conn {
+        crate::BackendConnection::Sqlite(conn) => {
+            use crate::diesel_schema::{areas, bid_years, round_groups, rounds, users};
+
+            diesel::insert_into(bid_years::table)
+                .values((
+                    bid_years::bid_year_id.eq(1),
+                    bid_years::year.eq(2026),
+                    bid_years::start_date.eq("2026-01-04"),
+                    bid_years::num_pay_periods.eq(26),
+                    bid_years::is_active.eq(1),
+                    bid_years::lifecycle_state.eq("BiddingActive"),
+                ))
+                .execute(conn)
+                .expect("Failed to insert bid year");
+            diesel::insert_into(round_groups::table)
+                .values((
+                    round_groups::round_group_id.eq(1),
+                    round_groups::bid_year_id.eq(1),
+                    round_groups::name.eq("Standard"),
+                    round_groups::editing_enabled.eq(1),
+                ))
+                .execute(conn)
+                .expect("Failed to insert round group");
+            diesel::insert_into(rounds::table)
+                .values((
+                    rounds::round_id.eq(1),
+                    rounds::round_group_id.eq(1),
+                    rounds::round_number.eq(1),
+                    rounds::name.eq("Round 1"),
+                    rounds::slots_per_day.eq(1),
+                    rounds::max_groups.eq(1),
+                    rounds::max_total_hours.eq(80),
+                    rounds::include_holidays.eq(0),
+                    rounds::allow_overbid.eq(0),
+                ))
+                .execute(conn)
+                .expect("Failed to insert round");
+            diesel::insert_into(areas::table)
+                .values((
+                    areas::area_id.eq(1),
+                    areas::bid_year_id.eq(1),
+                    areas::area_code.eq("NORTH"),
+                    areas::is_system_area.eq(0),
+                    areas::round_group_id.eq(Some(1)),
+                ))
+                .execute(conn)
+                .expect("Failed to insert area");
+            diesel::insert_into(users::table)
+                .values((
+                    users::user_id.eq(1),
+                    users::bid_year_id.eq(1),
+                    users::area_id.eq(1),
+                    users::initials.eq("AB"),
+                    users::name.eq("Alice Baker"),
+                    users::user_type.eq("CPC"),
+                    users::crew.eq(None::<i32>),
+                    users::cumulative_natca_bu_date.eq("2020-01-01"),
+                    users::natca_bu_date.eq("2020-01-01"),
+                    users::eod_faa_date.eq("2020-01-01"),
+                    users::service_computation_date.eq("2020-01-01"),
+                ))
+                .execute(conn)
+                .expect("Failed to insert user");
+        }
+        crate::BackendConnection::Mysql(_) => {
+            panic!("This test is SQLite-specific");
+        }
+    }
+
+    let operator_id: i64 = persistence
+        .create_operator("admin", "Admin", "password", "Admin")
+        .unwrap();
+    let event: AuditEvent = AuditEvent::new_global(
+        Actor::with_operator(
+            operator_id.to_string(),
+            String::from("operator"),
+            operator_id,
+            String::from("admin"),
+            String::from("Admin"),
+        ),
+        Cause::new(String::from("test"), String::from("Test cause")),
+        Action::new(String::from("TestAction"), None),
+        StateSnapshot::new(String::from("before")),
+        StateSnapshot::new(String::from("after")),
+    );
+    let audit_event_id: i64 = persistence.persist_audit_event(&event).unwrap();
+
+    persistence
+        .bulk_insert_bid_windows(&[NewBidWindow {
+            bid_year_id: 1,
+            area_id: 1,
+            user_id: 1,
+            round_id: 1,
+            window_start_datetime: String::from("2026-03-02T13:00:00+00:00"),
+            window_end_datetime: String::from("2026-03-02T21:00:00+00:00"),
+        }])
+        .unwrap();
+    persistence
+        .bulk_insert_bid_status(&[NewBidStatus {
+            bid_year_id: 1,
+            area_id: 1,
+            user_id: 1,
+            round_id: 1,
+            status: String::from("not_started_in_window"),
+            updated_at: String::from("2026-03-02T13:00:00Z"),
+            updated_by: operator_id,
+            notes: None,
+        }])
+        .unwrap();
+    let bid_status_id: i64 = persistence
+        .get_bid_status_for_user_and_round(1, 1, 1, 1)
+        .unwrap()
+        .bid_status_id;
+
+    Fixture {
+        operator_id,
+        audit_event_id,
+        bid_status_id,
+    }
+}
+
+fn record_transition(persistence: &mut Persistence, fixture: &Fixture, previous: &str, new: &str) {
+    persistence
+        .insert_bid_status_history(
+            fixture.bid_status_id,
+            fixture.audit_event_id,
+            Some(previous),
+            new,
+            "2026-03-02T14:00:00Z",
+            fixture.operator_id,
+            Some("Entered by phone"),
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_set_user_contact_inserts_then_updates() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    assert_eq!(persistence.get_user_contact(1).unwrap(), None);
+
+    persistence
+        .set_user_contact(1, "ab@example.test", true)
+        .unwrap();
+    persistence
+        .set_user_contact(1, "alice@example.test", false)
+        .unwrap();
+
+    let contact: UserContactData = persistence.get_user_contact(1).unwrap().unwrap();
+    assert_eq!(contact.email, "alice@example.test");
+    assert!(!contact.email_enabled);
+}
+
+#[test]
+fn test_delete_user_contact() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    persistence
+        .set_user_contact(1, "ab@example.test", true)
+        .unwrap();
+
+    assert!(persistence.delete_user_contact(1).unwrap());
+    assert!(!persistence.delete_user_contact(1).unwrap());
+    assert_eq!(persistence.get_user_contact(1).unwrap(), None);
+}
+
+#[test]
+fn test_window_candidates_require_enabled_contact() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    assert!(
+        persistence
+            .list_window_notification_candidates()
+            .unwrap()
+            .is_empty()
+    );
+
+    persistence
+        .set_user_contact(1, "ab@example.test", true)
+        .unwrap();
+    let candidates: Vec<WindowNotificationCandidate> =
+        persistence.list_window_notification_candidates().unwrap();
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].initials, "AB");
+    assert_eq!(candidates[0].email, "ab@example.test");
+    assert_eq!(candidates[0].year, 2026);
+    assert_eq!(candidates[0].area_code, "NORTH");
+    assert_eq!(candidates[0].round_name, "Round 1");
+
+    persistence
+        .set_user_contact(1, "ab@example.test", false)
+        .unwrap();
+    assert!(
+        persistence
+            .list_window_notification_candidates()
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_window_candidates_exclude_inactive_bid_years() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    persistence
+        .set_user_contact(1, "ab@example.test", true)
+        .unwrap();
+    persistence
+        .update_lifecycle_state(1, "BiddingClosed")
+        .unwrap();
+
+    assert!(
+        persistence
+            .list_window_notification_candidates()
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_bid_entry_candidates_filter_by_cursor_and_status() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    let fixture: Fixture = setup(&mut persistence);
+    persistence
+        .set_user_contact(1, "ab@example.test", true)
+        .unwrap();
+
+    assert_eq!(
+        persistence.get_latest_bid_status_history_id().unwrap(),
+        None
+    );
+
+    record_transition(
+        &mut persistence,
+        &fixture,
+        "not_started_in_window",
+        "in_progress",
+    );
+    record_transition(&mut persistence, &fixture, "in_progress", "missed");
+    record_transition(&mut persistence, &fixture, "missed", "completed_late");
+
+    let statuses: [&str; 2] = ["in_progress", "completed_late"];
+    let all: Vec<BidEntryNotificationCandidate> = persistence
+        .list_bid_entry_notification_candidates(0, &statuses, 10)
+        .unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[0].new_status, "in_progress");
+    assert_eq!(
+        all[0].previous_status.as_deref(),
+        Some("not_started_in_window")
+    );
+    assert_eq!(all[1].new_status, "completed_late");
+
+    let after_first: Vec<BidEntryNotificationCandidate> = persistence
+        .list_bid_entry_notification_candidates(all[0].history_id, &statuses, 10)
+        .unwrap();
+    assert_eq!(after_first.len(), 1);
+    assert_eq!(after_first[0].history_id, all[1].history_id);
+
+    assert_eq!(
+        persistence.get_latest_bid_status_history_id().unwrap(),
+        Some(all[1].history_id)
+    );
+}
+
+#[test]
+fn test_record_notification_is_unique_per_kind_and_reference() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+
+    assert!(!persistence.notification_exists("window_opened", 1).unwrap());
+    persistence
+        .record_notification(
+            1,
+            "window_opened",
+            1,
+            "ab@example.test",
+            "Your bid window is open",
+            None,
+        )
+        .unwrap();
+    assert!(persistence.notification_exists("window_opened", 1).unwrap());
+    assert!(
+        !persistence
+            .notification_exists("window_closing", 1)
+            .unwrap()
+    );
+
+    let duplicate = persistence.record_notification(
+        1,
+        "window_opened",
+        1,
+        "ab@example.test",
+        "Your bid window is open",
+        None,
+    );
+    assert!(duplicate.is_err());
+}
+
+#[test]
+fn test_list_notifications_for_user_newest_first() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    persistence
+        .record_notification(1, "window_opened", 1, "ab@example.test", "Open", None)
+        .unwrap();
+    persistence
+        .record_notification(
+            1,
+            "window_closing",
+            1,
+            "ab@example.test",
+            "Closing",
+            Some("connection refused"),
+        )
+        .unwrap();
+
+    let log: Vec<NotificationLogData> = persistence.list_notifications_for_user(1, 10).unwrap();
+    assert_eq!(log.len(), 2);
+    assert_eq!(log[0].kind, "window_closing");
+    assert!(!log[0].delivered);
+    assert_eq!(log[0].error.as_deref(), Some("connection refused"));
+    assert_eq!(log[1].kind, "window_opened");
+    assert!(log[1].delivered);
+
+    assert_eq!(
+        persistence.list_notifications_for_user(1, 1).unwrap().len(),
+        1
+    );
+}
+
+fn column_keyring(current: u8, previous: Option<u8>) -> ColumnKeyring {
+    ColumnKeyring::new(
+        ColumnKey::new([current; COLUMN_KEY_LENGTH]),
+        previous.map(|byte| ColumnKey::new([byte; COLUMN_KEY_LENGTH])),
+    )
+}
+
+/// Reads the stored email and notification recipient for user 1.
+fn stored_addresses(persistence: &mut Persistence) -> (String, String) {
+    use crate::diesel_schema::{notification_log, user_contacts};
+
+    let crate::BackendConnection::Sqlite(conn) = &mut persistence.conn else {
+        panic!("Expected SQLite");
+    };
+    let email: String = user_contacts::table
+        .filter(user_contacts::user_id.eq(1))
+        .select(user_contacts::email)
+        .first(conn)
+        .unwrap();
+    let recipient: String = notification_log::table
+        .filter(notification_log::user_id.eq(1))
+        .select(notification_log::recipient)
+        .first(conn)
+        .unwrap();
+    (email, recipient)
+}
+
+#[test]
+fn test_contact_addresses_are_encrypted_at_rest() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    persistence.set_column_keyring(column_keyring(1, None));
+
+    persistence
+        .set_user_contact(1, "ab@example.test", true)
+        .unwrap();
+    persistence
+        .record_notification(1, "window_opened", 1, "ab@example.test", "Open", None)
+        .unwrap();
+
+    let (email, recipient) = stored_addresses(&mut persistence);
+    assert!(email.starts_with("enc:v1:"));
+    assert!(!email.contains("example"));
+    assert!(!recipient.contains("example"));
+
+    assert_eq!(
+        persistence.get_user_contact(1).unwrap().unwrap().email,
+        "ab@example.test"
+    );
+    assert_eq!(
+        persistence.list_notifications_for_user(1, 10).unwrap()[0].recipient,
+        "ab@example.test"
+    );
+    let candidates: Vec<WindowNotificationCandidate> =
+        persistence.list_window_notification_candidates().unwrap();
+    assert_eq!(candidates[0].email, "ab@example.test");
+}
+
+#[test]
+fn test_reseal_encrypts_plaintext_rows_and_rotates_keys() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    persistence
+        .set_user_contact(1, "ab@example.test", true)
+        .unwrap();
+    persistence
+        .record_notification(1, "window_opened", 1, "ab@example.test", "Open", None)
+        .unwrap();
+    assert_eq!(persistence.reseal_sensitive_columns().unwrap(), 0);
+    assert_eq!(stored_addresses(&mut persistence).0, "ab@example.test");
+
+    // Plaintext rows written before a key was configured
+    persistence.set_column_keyring(column_keyring(1, None));
+    assert_eq!(persistence.reseal_sensitive_columns().unwrap(), 2);
+    let (sealed_email, _) = stored_addresses(&mut persistence);
+    assert!(sealed_email.starts_with("enc:v1:"));
+    assert_eq!(persistence.reseal_sensitive_columns().unwrap(), 0);
+
+    // Rotation re-encrypts under the new key, after which the old key can go
+    persistence.set_column_keyring(column_keyring(2, Some(1)));
+    assert_eq!(persistence.reseal_sensitive_columns().unwrap(), 2);
+    assert_ne!(stored_addresses(&mut persistence).0, sealed_email);
+    persistence.set_column_keyring(column_keyring(2, None));
+    assert_eq!(
+        persistence.get_user_contact(1).unwrap().unwrap().email,
+        "ab@example.test"
+    );
+    assert_eq!(
+        persistence.list_notifications_for_user(1, 10).unwrap()[0].recipient,
+        "ab@example.test"
+    );
+}
+
+#[test]
+fn test_encrypted_contact_cannot_be_read_under_unknown_key() {
+    let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+    setup(&mut persistence);
+    persistence.set_column_keyring(column_keyring(1, None));
+    persistence
+        .set_user_contact(1, "ab@example.test", true)
+        .unwrap();
+
+    persistence.set_column_keyring(column_keyring(3, None));
+    assert!(matches!(
+        persistence.get_user_contact(1),
+        Err(PersistenceError::DecryptionFailed(_))
+    ));
+    // A failed reseal rewrites nothing
+    assert!(persistence.reseal_sensitive_columns().is_err());
+    persistence.set_column_keyring(column_keyring(1, None));
+    assert_eq!(
+        persistence.get_user_contact(1).unwrap().unwrap().email,
+        "ab@example.test"
+    );
+}
+
+#[test]
+fn test_sealed_value_is_bound_to_its_row() {
+    let keyring: ColumnKeyring = column_keyring(1, None);
+    let sealed: String = keyring.seal("user_contacts.email:1", "ab@example.test");
+
+    assert_eq!(
+        keyring.open("user_contacts.email:1", &sealed).unwrap(),
+        "ab@example.test"
+    );
+    assert!(keyring.open("user_contacts.email:2", &sealed).is_err());
+    assert!(keyring.needs_reseal("ab@example.test"));
+    assert!(!keyring.needs_reseal(&sealed));
+
+    let key: ColumnKey = "ab".repeat(COLUMN_KEY_LENGTH).parse().unwrap();
+    assert_eq!(format!("{key:?}"), "ColumnKey(<redacted>)");
+    assert!(!"abcd".parse::<ColumnKey>().unwrap_err().contains("abcd"));
+}
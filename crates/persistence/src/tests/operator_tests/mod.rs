@@ -5,7 +5,10 @@
 
 //! Tests for operator lifecycle persistence operations.
 
-use crate::{PersistenceError, SqlitePersistence};
+use crate::{
+    PersistenceError, SYSTEM_OPERATOR_ID, SecretToken, SessionKey, SessionKeyring,
+    SqlitePersistence,
+};
 use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
 
 #[test]
@@ -444,13 +447,48 @@ fn test_get_operator_by_id_not_found() {
     );
 }
 
+#[test]
+fn test_events_without_an_operator_are_recorded_as_the_system_operator() {
+    let mut persistence = SqlitePersistence::new_in_memory().unwrap();
+
+    let event = AuditEvent::new_global(
+        Actor::new(String::from("rate_limit"), String::from("system")),
+        Cause::new(String::from("rate_limit"), String::from("Test")),
+        Action::new(String::from("RateLimitExceeded"), None),
+        StateSnapshot::new(String::from("rate_limit=ok")),
+        StateSnapshot::new(String::from("rate_limit=exceeded")),
+    );
+    persistence.persist_audit_event(&event).unwrap();
+
+    let events = persistence.get_global_audit_events().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].actor.actor_type, "system");
+    assert_eq!(events[0].actor.operator_id, None);
+
+    // The system operator cannot be looked up, listed, or counted
+    assert!(
+        persistence
+            .get_operator_by_id(SYSTEM_OPERATOR_ID)
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        persistence
+            .get_operator_by_login("system")
+            .unwrap()
+            .is_none()
+    );
+    assert!(persistence.list_operators().unwrap().is_empty());
+    assert_eq!(persistence.count_operators().unwrap(), 0);
+}
+
 #[test]
 fn test_get_session_by_token_not_found() {
     let mut persistence = SqlitePersistence::new_in_memory().unwrap();
 
     // Try to get a nonexistent session
     let result = persistence
-        .get_session_by_token("nonexistent-token")
+        .get_session_by_token(&SecretToken::new(String::from("nonexistent-token")))
         .unwrap();
 
     assert!(
@@ -458,3 +496,106 @@ fn test_get_session_by_token_not_found() {
         "Should return None for nonexistent session token"
     );
 }
+
+fn session_key(seed: char) -> SessionKey {
+    std::iter::repeat_n(seed, 32)
+        .collect::<String>()
+        .parse()
+        .unwrap()
+}
+
+#[test]
+fn test_session_token_is_stored_as_digest() {
+    let mut persistence = SqlitePersistence::new_in_memory().unwrap();
+    let operator_id = persistence
+        .create_operator("testop", "Test Operator", "password", "Admin")
+        .unwrap();
+    let token = SecretToken::generate();
+
+    persistence
+        .create_session(&token, operator_id, "2099-01-01 00:00:00")
+        .unwrap();
+
+    let session = persistence.get_session_by_token(&token).unwrap().unwrap();
+    assert_eq!(session.operator_id, operator_id);
+    assert_ne!(session.session_token_hash, token.expose());
+    assert!(!session.session_token_hash.contains(token.expose()));
+}
+
+#[test]
+fn test_session_from_previous_key_is_rekeyed() {
+    let mut persistence = SqlitePersistence::new_in_memory().unwrap();
+    persistence.set_session_keyring(SessionKeyring::new(session_key('a'), None));
+    let operator_id = persistence
+        .create_operator("testop", "Test Operator", "password", "Admin")
+        .unwrap();
+    let token = SecretToken::generate();
+    persistence
+        .create_session(&token, operator_id, "2099-01-01 00:00:00")
+        .unwrap();
+    let old_hash = persistence
+        .get_session_by_token(&token)
+        .unwrap()
+        .unwrap()
+        .session_token_hash;
+
+    // Rotate: the old key moves to the previous slot
+    persistence.set_session_keyring(SessionKeyring::new(
+        session_key('b'),
+        Some(session_key('a')),
+    ));
+    let session = persistence.get_session_by_token(&token).unwrap().unwrap();
+    assert_ne!(session.session_token_hash, old_hash);
+
+    // Once the previous key is dropped the re-keyed session still validates
+    persistence.set_session_keyring(SessionKeyring::new(session_key('b'), None));
+    assert!(persistence.get_session_by_token(&token).unwrap().is_some());
+}
+
+#[test]
+fn test_session_from_unknown_key_is_rejected() {
+    let mut persistence = SqlitePersistence::new_in_memory().unwrap();
+    persistence.set_session_keyring(SessionKeyring::new(session_key('a'), None));
+    let operator_id = persistence
+        .create_operator("testop", "Test Operator", "password", "Admin")
+        .unwrap();
+    let token = SecretToken::generate();
+    persistence
+        .create_session(&token, operator_id, "2099-01-01 00:00:00")
+        .unwrap();
+
+    persistence.set_session_keyring(SessionKeyring::new(session_key('b'), None));
+
+    assert!(persistence.get_session_by_token(&token).unwrap().is_none());
+}
+
+#[test]
+fn test_delete_session_during_rotation() {
+    let mut persistence = SqlitePersistence::new_in_memory().unwrap();
+    persistence.set_session_keyring(SessionKeyring::new(session_key('a'), None));
+    let operator_id = persistence
+        .create_operator("testop", "Test Operator", "password", "Admin")
+        .unwrap();
+    let token = SecretToken::generate();
+    persistence
+        .create_session(&token, operator_id, "2099-01-01 00:00:00")
+        .unwrap();
+
+    persistence.set_session_keyring(SessionKeyring::new(
+        session_key('b'),
+        Some(session_key('a')),
+    ));
+    persistence.delete_session(&token).unwrap();
+
+    assert!(persistence.get_session_by_token(&token).unwrap().is_none());
+}
+
+#[test]
+fn test_secrets_are_redacted_from_debug() {
+    let token = SecretToken::new(String::from("do-not-log-me"));
+    let key = session_key('k');
+
+    assert!(!format!("{token:?}").contains("do-not-log-me"));
+    assert!(!format!("{key:?}").contains("kkkk"));
+    assert!("too-short".parse::<SessionKey>().is_err());
+}
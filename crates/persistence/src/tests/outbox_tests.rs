@@ -0,0 +1,89 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for the transactional outbox of audit events.
+
+use crate::tests::{create_test_actor, create_test_operator};
+use crate::{OutboxEntryData, PersistenceError, SqlitePersistence};
+use zab_bid::{IdGenerator, UlidGenerator};
+use zab_bid_audit::{Action, AuditEvent, Cause, StateSnapshot};
+
+fn global_event() -> AuditEvent {
+    AuditEvent::new_global(
+        create_test_actor(),
+        Cause::new(String::from("test"), String::from("Test cause")),
+        Action::new(String::from("TestAction"), None),
+        StateSnapshot::new(String::from("before")),
+        StateSnapshot::new(String::from("after")),
+    )
+}
+
+fn pending_event_ids(persistence: &mut SqlitePersistence) -> Vec<i64> {
+    persistence
+        .list_pending_outbox_entries(100)
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.audit_event_id)
+        .collect()
+}
+
+#[test]
+fn test_persisted_events_are_queued_in_order() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+
+    let first: i64 = persistence.persist_audit_event(&global_event()).unwrap();
+    let second: i64 = persistence.persist_audit_event(&global_event()).unwrap();
+
+    assert_eq!(pending_event_ids(&mut persistence), vec![first, second]);
+}
+
+#[test]
+fn test_delivered_entries_leave_the_pending_list() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    let first: i64 = persistence.persist_audit_event(&global_event()).unwrap();
+    let second: i64 = persistence.persist_audit_event(&global_event()).unwrap();
+    let entry: OutboxEntryData = persistence
+        .get_outbox_entry_for_event(first)
+        .unwrap()
+        .unwrap();
+    assert!(entry.delivered_at.is_none());
+
+    assert_eq!(
+        persistence
+            .mark_outbox_entries_delivered(&[entry.outbox_id])
+            .unwrap(),
+        1
+    );
+    // Marking again changes nothing
+    assert_eq!(
+        persistence
+            .mark_outbox_entries_delivered(&[entry.outbox_id])
+            .unwrap(),
+        0
+    );
+
+    assert_eq!(pending_event_ids(&mut persistence), vec![second]);
+    let entry: OutboxEntryData = persistence
+        .get_outbox_entry_for_event(first)
+        .unwrap()
+        .unwrap();
+    assert!(entry.delivered_at.is_some());
+}
+
+#[test]
+fn test_rejected_event_leaves_no_outbox_entry() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    let event: AuditEvent = global_event().with_ulid(Some(UlidGenerator::default().next_ulid()));
+    persistence.persist_audit_event(&event).unwrap();
+
+    // The ULID is already taken, so the insert fails
+    let result: Result<i64, PersistenceError> = persistence.persist_audit_event(&event);
+
+    assert!(result.is_err());
+    assert_eq!(pending_event_ids(&mut persistence).len(), 1);
+}
@@ -7,6 +7,7 @@
 
 use crate::Persistence;
 use diesel::prelude::*;
+use zab_bid::UlidGenerator;
 use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
 use zab_bid_domain::BidYear;
 
@@ -102,6 +103,7 @@ fn setup_area_assignment_test() -> Persistence {
             // Create audit event for canonicalization
             let audit_event = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor: Actor {
                     actor_type: String::from("Operator"),
                     id: String::from("1"),
@@ -121,11 +123,18 @@ fn setup_area_assignment_test() -> Persistence {
                 after: StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
                 bid_year: Some(BidYear::new(2026)),
                 area: None,
+                user_id: None,
             };
 
             // Canonicalize to create canonical tables properly
-            canonicalize_bid_year_sqlite(conn, 1, &audit_event)
-                .expect("Failed to canonicalize bid year");
+            canonicalize_bid_year_sqlite(
+                conn,
+                1,
+                &audit_event,
+                time::OffsetDateTime::now_utc(),
+                &UlidGenerator::default(),
+            )
+            .expect("Failed to canonicalize bid year");
         }
         crate::BackendConnection::Mysql(_) => panic!("Expected SQLite connection"),
     }
@@ -219,6 +228,7 @@ fn test_override_eligibility_sqlite() {
 
             let audit_event = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor: Actor {
                     actor_type: String::from("Operator"),
                     id: String::from("1"),
@@ -238,10 +248,17 @@ fn test_override_eligibility_sqlite() {
                 after: StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
                 bid_year: Some(BidYear::new(2026)),
                 area: None,
+                user_id: None,
             };
 
-            canonicalize_bid_year_sqlite(conn, 1, &audit_event)
-                .expect("Failed to canonicalize bid year");
+            canonicalize_bid_year_sqlite(
+                conn,
+                1,
+                &audit_event,
+                time::OffsetDateTime::now_utc(),
+                &UlidGenerator::default(),
+            )
+            .expect("Failed to canonicalize bid year");
         }
         crate::BackendConnection::Mysql(_) => panic!("Expected SQLite connection"),
     }
@@ -371,6 +388,7 @@ fn setup_bid_order_test() -> Persistence {
 
             let audit_event = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor: Actor {
                     actor_type: String::from("Operator"),
                     id: String::from("1"),
@@ -390,10 +408,17 @@ fn setup_bid_order_test() -> Persistence {
                 after: StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
                 bid_year: Some(BidYear::new(2026)),
                 area: None,
+                user_id: None,
             };
 
-            canonicalize_bid_year_sqlite(conn, 1, &audit_event)
-                .expect("Failed to canonicalize bid year");
+            canonicalize_bid_year_sqlite(
+                conn,
+                1,
+                &audit_event,
+                time::OffsetDateTime::now_utc(),
+                &UlidGenerator::default(),
+            )
+            .expect("Failed to canonicalize bid year");
         }
         crate::BackendConnection::Mysql(_) => panic!("Expected SQLite connection"),
     }
@@ -505,6 +530,7 @@ fn setup_bid_window_test() -> Persistence {
 
             let audit_event = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor: Actor {
                     actor_type: String::from("Operator"),
                     id: String::from("1"),
@@ -524,10 +550,17 @@ fn setup_bid_window_test() -> Persistence {
                 after: StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
                 bid_year: Some(BidYear::new(2026)),
                 area: None,
+                user_id: None,
             };
 
-            canonicalize_bid_year_sqlite(conn, 1, &audit_event)
-                .expect("Failed to canonicalize bid year");
+            canonicalize_bid_year_sqlite(
+                conn,
+                1,
+                &audit_event,
+                time::OffsetDateTime::now_utc(),
+                &UlidGenerator::default(),
+            )
+            .expect("Failed to canonicalize bid year");
         }
         crate::BackendConnection::Mysql(_) => panic!("Expected SQLite connection"),
     }
@@ -566,6 +599,7 @@ fn verify_bid_window_override(persistence: &mut Persistence) {
 }
 
 #[test]
+#[allow(clippy::too_many_lines)]
 fn test_override_twice_tracks_was_overridden() {
     let mut persistence = Persistence::new_in_memory().expect("Failed to create persistence");
 
@@ -624,6 +658,7 @@ fn test_override_twice_tracks_was_overridden() {
 
             let audit_event = AuditEvent {
                 event_id: None,
+                event_ulid: None,
                 actor: Actor {
                     actor_type: String::from("Operator"),
                     id: String::from("1"),
@@ -643,10 +678,17 @@ fn test_override_twice_tracks_was_overridden() {
                 after: StateSnapshot::new(String::from("lifecycle_state=Canonicalized")),
                 bid_year: Some(BidYear::new(2026)),
                 area: None,
+                user_id: None,
             };
 
-            canonicalize_bid_year_sqlite(conn, 1, &audit_event)
-                .expect("Failed to canonicalize bid year");
+            canonicalize_bid_year_sqlite(
+                conn,
+                1,
+                &audit_event,
+                time::OffsetDateTime::now_utc(),
+                &UlidGenerator::default(),
+            )
+            .expect("Failed to canonicalize bid year");
         }
         crate::BackendConnection::Mysql(_) => panic!("Expected SQLite connection"),
     }
@@ -679,3 +721,75 @@ fn test_override_twice_tracks_was_overridden() {
         "Second override should report previously overridden"
     );
 }
+
+fn area_membership(persistence: &mut Persistence) -> (i64, i64, i32, Option<String>) {
+    match &mut persistence.conn {
+        crate::BackendConnection::Sqlite(conn) => {
+            use crate::diesel_schema::canonical_area_membership;
+
+            canonical_area_membership::table
+                .filter(canonical_area_membership::user_id.eq(1))
+                .select((
+                    canonical_area_membership::audit_event_id,
+                    canonical_area_membership::area_id,
+                    canonical_area_membership::is_overridden,
+                    canonical_area_membership::override_reason,
+                ))
+                .first(conn)
+                .expect("Failed to query canonical_area_membership")
+        }
+        crate::BackendConnection::Mysql(_) => panic!("Expected SQLite connection"),
+    }
+}
+
+#[test]
+fn test_revert_overrides_unwinds_stack() {
+    use crate::{CanonicalOverrideData, OverrideValue};
+
+    let mut persistence = setup_area_assignment_test();
+    let (audit_event_id, _, _, _) = area_membership(&mut persistence);
+    let area = |area_id: i64| OverrideValue::AreaAssignment { area_id };
+
+    let first_reason = "User requested transfer due to personal circumstances";
+    persistence
+        .override_area_assignment(1, 1, 2, first_reason)
+        .unwrap();
+    persistence
+        .record_override(1, 1, &area(1), &area(2), first_reason, audit_event_id)
+        .unwrap();
+
+    let second_reason = "Transfer rescinded by facility management";
+    persistence
+        .override_area_assignment(1, 1, 1, second_reason)
+        .unwrap();
+    let second_id = persistence
+        .record_override(1, 1, &area(2), &area(1), second_reason, audit_event_id)
+        .unwrap();
+
+    let second: CanonicalOverrideData = persistence.get_override(second_id).unwrap();
+    assert_eq!(second.override_kind, "area_assignment");
+    assert_eq!(second.previous_value, area(2));
+    persistence.revert_override(&second).unwrap();
+
+    let (_, area_id, is_overridden, reason) = area_membership(&mut persistence);
+    assert_eq!(area_id, 2, "Earlier override should be back in effect");
+    assert_eq!(is_overridden, 1);
+    assert_eq!(reason.as_deref(), Some(first_reason));
+
+    let overrides: Vec<CanonicalOverrideData> = persistence.list_overrides(1).unwrap();
+    assert_eq!(overrides.len(), 2);
+    assert!(!overrides[0].is_reverted);
+    assert!(overrides[1].is_reverted);
+
+    persistence.revert_override(&overrides[0]).unwrap();
+
+    let (_, area_id, is_overridden, reason) = area_membership(&mut persistence);
+    assert_eq!(area_id, 1, "Original area should be restored");
+    assert_eq!(is_overridden, 0);
+    assert_eq!(reason, None);
+
+    assert!(matches!(
+        persistence.get_override(999),
+        Err(crate::PersistenceError::NotFound(_))
+    ));
+}
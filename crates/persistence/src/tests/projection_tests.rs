@@ -0,0 +1,133 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Dashboard projection table tests.
+
+use crate::tests::{create_test_bid_year_and_area, create_test_operator};
+use crate::{
+    AreaProjectionData, ProjectedAreaProgressData, ProjectedDailySlotsData, ProjectedUserAwardData,
+    SqlitePersistence,
+};
+
+const PROJECTION: &str = "test-projection";
+const UPDATED_AT: &str = "2026-01-05T12:00:00Z";
+
+fn day(round_id: i64, slot_date: &str, used: i32) -> ProjectedDailySlotsData {
+    ProjectedDailySlotsData {
+        area_id: 1,
+        round_id,
+        slot_date: slot_date.to_string(),
+        capacity: 3,
+        used,
+        remaining: 3 - used,
+    }
+}
+
+fn projection(used: i32) -> AreaProjectionData {
+    AreaProjectionData {
+        bid_year_id: 1,
+        area_id: 1,
+        daily_slots: vec![
+            day(7, "2026-02-01", used),
+            day(7, "2026-02-02", 0),
+            day(8, "2026-02-01", 0),
+        ],
+        user_awards: vec![ProjectedUserAwardData {
+            user_id: 4,
+            area_id: 1,
+            awarded_days: used,
+            awarded_hours: used * 8,
+        }],
+        progress: vec![ProjectedAreaProgressData {
+            area_id: 1,
+            round_id: 7,
+            bidders: 5,
+            not_started: 3,
+            in_progress: 1,
+            completed: 1,
+        }],
+    }
+}
+
+#[test]
+fn test_applied_projection_is_listed_and_cursor_recorded() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    assert_eq!(persistence.get_projection_cursor(PROJECTION).unwrap(), None);
+
+    persistence
+        .apply_area_projections(PROJECTION, &[projection(2)], 12, UPDATED_AT)
+        .unwrap();
+
+    assert_eq!(
+        persistence.get_projection_cursor(PROJECTION).unwrap(),
+        Some(12)
+    );
+    let days: Vec<ProjectedDailySlotsData> = persistence
+        .list_projected_daily_slots(1, "2026-02-01", "2026-02-01")
+        .unwrap();
+    assert_eq!(days, vec![day(7, "2026-02-01", 2), day(8, "2026-02-01", 0)]);
+    assert_eq!(
+        persistence.list_projected_user_awards(1).unwrap(),
+        projection(2).user_awards
+    );
+    assert_eq!(
+        persistence.list_projected_area_progress(1).unwrap(),
+        projection(2).progress
+    );
+}
+
+#[test]
+fn test_applying_an_area_replaces_its_previous_rows() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    persistence
+        .apply_area_projections(PROJECTION, &[projection(2)], 12, UPDATED_AT)
+        .unwrap();
+
+    let cleared: AreaProjectionData = AreaProjectionData {
+        bid_year_id: 1,
+        area_id: 1,
+        ..AreaProjectionData::default()
+    };
+    persistence
+        .apply_area_projections(PROJECTION, &[projection(1), cleared], 15, UPDATED_AT)
+        .unwrap();
+
+    assert!(
+        persistence
+            .list_projected_daily_slots(1, "2026-01-01", "2026-12-31")
+            .unwrap()
+            .is_empty()
+    );
+    assert!(
+        persistence
+            .list_projected_user_awards(1)
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        persistence.get_projection_cursor(PROJECTION).unwrap(),
+        Some(15)
+    );
+}
+
+#[test]
+fn test_audit_scopes_are_listed_once_within_range() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    create_test_bid_year_and_area(&mut persistence, 2026, "North");
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    let latest: i64 = persistence.get_latest_audit_event_id().unwrap().unwrap();
+
+    let scopes: Vec<(Option<i64>, Option<i64>)> = persistence.list_audit_scopes(0, latest).unwrap();
+
+    assert!(!scopes.is_empty());
+    assert!(scopes.iter().all(|(scope, _)| *scope == Some(bid_year_id)));
+    assert!(
+        persistence
+            .list_audit_scopes(latest, latest)
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -3,11 +3,14 @@
 // license that can be found in the LICENSE file or at
 // https://opensource.org/licenses/MIT.
 
-use crate::SqlitePersistence;
 use crate::tests::{
     create_test_actor, create_test_bid_year_and_area, create_test_cause, create_test_metadata,
     create_test_operator,
 };
+use crate::{
+    AuditEventHeaderPage, AuditTimelineEntry, AuditTimelineFilter, AuditTimelinePage,
+    AuditTimelineScope, SqlitePersistence,
+};
 use zab_bid::{Command, State, TransitionResult, apply};
 use zab_bid_audit::AuditEvent;
 use zab_bid_domain::{Area, BidYear};
@@ -20,7 +23,10 @@ fn test_get_audit_timeline_returns_events_in_order() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create multiple events
-    let command1: Command = Command::Checkpoint;
+    let command1: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result1: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -30,7 +36,7 @@ fn test_get_audit_timeline_returns_events_in_order() {
         create_test_cause(),
     )
     .unwrap();
-    persistence.persist_transition(&result1).unwrap();
+    let checkpoint_id: i64 = persistence.persist_transition(&result1).unwrap().event_id;
 
     let command2: Command = Command::Finalize;
     let result2: TransitionResult = apply(
@@ -44,7 +50,9 @@ fn test_get_audit_timeline_returns_events_in_order() {
     .unwrap();
     persistence.persist_transition(&result2).unwrap();
 
-    let command3: Command = Command::RollbackToEventId { target_event_id: 1 };
+    let command3: Command = Command::RollbackToEventId {
+        target_event_id: checkpoint_id,
+    };
     let result3: TransitionResult = apply(
         &create_test_metadata(),
         &result2.new_state,
@@ -94,7 +102,10 @@ fn test_get_audit_timeline_includes_rollback_events() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create checkpoint
-    let command1: Command = Command::Checkpoint;
+    let command1: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result1: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -141,7 +152,10 @@ fn test_get_audit_timeline_does_not_mutate() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create events
-    let command: Command = Command::Checkpoint;
+    let command: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -177,7 +191,10 @@ fn test_read_operations_are_side_effect_free() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create initial snapshot
-    let command: Command = Command::Checkpoint;
+    let command: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -228,3 +245,235 @@ fn test_read_operations_are_side_effect_free() {
 
     assert_eq!(final_timeline.len(), initial_count);
 }
+
+/// Persists `count` checkpoint transitions into the 2026/North scope.
+fn persist_checkpoints(persistence: &mut SqlitePersistence, count: usize) {
+    let mut state: State = State::new(BidYear::new(2026), Area::new("North"));
+    for _ in 0..count {
+        let result: TransitionResult = apply(
+            &create_test_metadata(),
+            &state,
+            &BidYear::new(2026),
+            Command::Checkpoint {
+                label: None,
+                description: None,
+            },
+            create_test_actor(),
+            create_test_cause(),
+        )
+        .unwrap();
+        persistence.persist_transition(&result).unwrap();
+        state = result.new_state;
+    }
+}
+
+#[test]
+fn test_get_audit_timeline_page_follows_cursor() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    create_test_bid_year_and_area(&mut persistence, 2026, "North");
+    persist_checkpoints(&mut persistence, 4);
+
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    let area_id: i64 = persistence.get_area_id(bid_year_id, "North").unwrap();
+    let scope: AuditTimelineScope = AuditTimelineScope::Area {
+        bid_year_id,
+        area_id,
+    };
+    let filter: AuditTimelineFilter = AuditTimelineFilter::default();
+
+    // CreateArea + 4 checkpoints, read in pages of 2
+    let first: AuditTimelinePage = persistence
+        .get_audit_timeline_page(scope, &filter, None, 2)
+        .unwrap();
+    assert_eq!(first.entries.len(), 2);
+    assert_eq!(first.entries[0].event.action.name, "CreateArea");
+    assert!(first.entries[0].created_at.is_some());
+    let cursor: i64 = first.next_cursor.unwrap();
+    assert_eq!(Some(cursor), first.entries[1].event.event_id);
+
+    let second: AuditTimelinePage = persistence
+        .get_audit_timeline_page(scope, &filter, Some(cursor), 2)
+        .unwrap();
+    assert_eq!(second.entries.len(), 2);
+    assert!(second.entries[0].event.event_id.unwrap() > cursor);
+
+    let third: AuditTimelinePage = persistence
+        .get_audit_timeline_page(scope, &filter, second.next_cursor, 2)
+        .unwrap();
+    assert_eq!(third.entries.len(), 1);
+    assert!(third.next_cursor.is_none());
+}
+
+#[test]
+fn test_get_audit_timeline_page_filters_by_action_and_actor() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    let operator_id: i64 = create_test_operator(&mut persistence);
+    create_test_bid_year_and_area(&mut persistence, 2026, "North");
+    persist_checkpoints(&mut persistence, 3);
+
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    let scope: AuditTimelineScope = AuditTimelineScope::BidYear { bid_year_id };
+
+    let checkpoints: AuditTimelinePage = persistence
+        .get_audit_timeline_page(
+            scope,
+            &AuditTimelineFilter {
+                action_name: Some(String::from("Checkpoint")),
+                ..AuditTimelineFilter::default()
+            },
+            None,
+            50,
+        )
+        .unwrap();
+    assert_eq!(checkpoints.entries.len(), 3);
+    assert!(
+        checkpoints
+            .entries
+            .iter()
+            .all(|entry| entry.event.action.name == "Checkpoint")
+    );
+
+    let by_other_actor: AuditTimelinePage = persistence
+        .get_audit_timeline_page(
+            scope,
+            &AuditTimelineFilter {
+                actor_operator_id: Some(operator_id + 1000),
+                ..AuditTimelineFilter::default()
+            },
+            None,
+            50,
+        )
+        .unwrap();
+    assert!(by_other_actor.entries.is_empty());
+
+    let future_only: AuditTimelinePage = persistence
+        .get_audit_timeline_page(
+            scope,
+            &AuditTimelineFilter {
+                created_from: Some(String::from("9999-01-01 00:00:00")),
+                ..AuditTimelineFilter::default()
+            },
+            None,
+            50,
+        )
+        .unwrap();
+    assert!(future_only.entries.is_empty());
+}
+
+#[test]
+fn test_get_audit_timeline_page_global_scope_excludes_scoped_events() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    create_test_bid_year_and_area(&mut persistence, 2026, "North");
+    persist_checkpoints(&mut persistence, 1);
+
+    let page: AuditTimelinePage = persistence
+        .get_audit_timeline_page(
+            AuditTimelineScope::Global,
+            &AuditTimelineFilter::default(),
+            None,
+            50,
+        )
+        .unwrap();
+
+    assert!(
+        page.entries
+            .iter()
+            .all(|entry| entry.event.bid_year.is_none())
+    );
+    assert!(
+        page.entries
+            .iter()
+            .all(|entry| entry.event.action.name != "Checkpoint")
+    );
+}
+
+#[test]
+fn test_get_latest_audit_event_id_tracks_newest_event() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    assert_eq!(persistence.get_latest_audit_event_id().unwrap(), None);
+
+    create_test_bid_year_and_area(&mut persistence, 2026, "North");
+    persist_checkpoints(&mut persistence, 2);
+
+    let all: AuditTimelinePage = persistence
+        .get_audit_timeline_page(
+            AuditTimelineScope::All,
+            &AuditTimelineFilter::default(),
+            None,
+            50,
+        )
+        .unwrap();
+    let newest: Option<i64> = all.entries.last().and_then(|entry| entry.event.event_id);
+
+    assert_eq!(all.entries.len(), 4);
+    assert_eq!(persistence.get_latest_audit_event_id().unwrap(), newest);
+}
+
+#[test]
+fn test_get_audit_event_headers_page_matches_timeline_page() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    create_test_bid_year_and_area(&mut persistence, 2026, "North");
+    persist_checkpoints(&mut persistence, 3);
+
+    let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+    let scope: AuditTimelineScope = AuditTimelineScope::BidYear { bid_year_id };
+    let filter: AuditTimelineFilter = AuditTimelineFilter {
+        action_name: Some(String::from("Checkpoint")),
+        ..AuditTimelineFilter::default()
+    };
+
+    let full: AuditTimelinePage = persistence
+        .get_audit_timeline_page(scope, &filter, None, 2)
+        .unwrap();
+    let headers: AuditEventHeaderPage = persistence
+        .get_audit_event_headers_page(scope, &filter, None, 2)
+        .unwrap();
+
+    assert_eq!(headers.headers.len(), 2);
+    assert_eq!(headers.next_cursor, full.next_cursor);
+    for (header, entry) in headers.headers.iter().zip(&full.entries) {
+        assert_eq!(Some(header.event_id), entry.event.event_id);
+        assert_eq!(header.action_name, entry.event.action.name);
+        assert_eq!(header.bid_year_id, Some(bid_year_id));
+        assert_eq!(header.created_at, entry.created_at);
+    }
+}
+
+#[test]
+fn test_get_audit_timeline_entries_fetches_requested_events() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    create_test_bid_year_and_area(&mut persistence, 2026, "North");
+    persist_checkpoints(&mut persistence, 3);
+
+    let all: AuditTimelinePage = persistence
+        .get_audit_timeline_page(
+            AuditTimelineScope::All,
+            &AuditTimelineFilter::default(),
+            None,
+            50,
+        )
+        .unwrap();
+    let last: &AuditTimelineEntry = all.entries.last().unwrap();
+    let last_id: i64 = last.event.event_id.unwrap();
+
+    // Unknown IDs are skipped and results come back in event order
+    let entries: Vec<AuditTimelineEntry> = persistence
+        .get_audit_timeline_entries(&[last_id, 9999, 1])
+        .unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].event.event_id, Some(1));
+    assert_eq!(entries[1].event.event_id, Some(last_id));
+    assert_eq!(entries[1].event.after.data, last.event.after.data);
+
+    assert!(
+        persistence
+            .get_audit_timeline_entries(&[])
+            .unwrap()
+            .is_empty()
+    );
+}
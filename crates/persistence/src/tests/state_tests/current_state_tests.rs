@@ -58,7 +58,10 @@ fn test_get_current_state_with_no_deltas() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create a snapshot with no users
-    let command: Command = Command::Checkpoint;
+    let command: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -86,7 +89,10 @@ fn test_get_current_state_after_snapshot_with_user() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create initial empty snapshot
-    let command1: Command = Command::Checkpoint;
+    let command1: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result1: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -119,7 +125,10 @@ fn test_get_current_state_after_snapshot_with_user() {
     persistence.persist_transition(&result2).unwrap();
 
     // Create another snapshot to capture the state with the user
-    let command3: Command = Command::Checkpoint;
+    let command3: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result3: TransitionResult = apply(
         &create_test_metadata(),
         &result2.new_state,
@@ -139,7 +148,16 @@ fn test_get_current_state_after_snapshot_with_user() {
     assert_eq!(current_state.bid_year.year(), 2026);
     assert_eq!(current_state.area.id(), "NORTH");
     assert_eq!(current_state.users.len(), 1);
-    assert_eq!(current_state.users[0].initials.value(), "AB");
+    assert_eq!(
+        current_state
+            .users
+            .values()
+            .next()
+            .unwrap()
+            .initials
+            .value(),
+        "AB"
+    );
 }
 
 #[test]
@@ -163,7 +181,10 @@ fn test_get_current_state_is_deterministic() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create initial snapshot
-    let command1: Command = Command::Checkpoint;
+    let command1: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result1: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -196,7 +217,10 @@ fn test_get_current_state_is_deterministic() {
     persistence.persist_transition(&result2).unwrap();
 
     // Create another snapshot
-    let command3: Command = Command::Checkpoint;
+    let command3: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result3: TransitionResult = apply(
         &create_test_metadata(),
         &result2.new_state,
@@ -221,9 +245,9 @@ fn test_get_current_state_is_deterministic() {
 
     assert_eq!(state1.users.len(), state2.users.len());
     assert_eq!(state2.users.len(), state3.users.len());
-    assert_eq!(state1.users[0].initials.value(), "XY");
-    assert_eq!(state2.users[0].initials.value(), "XY");
-    assert_eq!(state3.users[0].initials.value(), "XY");
+    assert_eq!(state1.users.values().next().unwrap().initials.value(), "XY");
+    assert_eq!(state2.users.values().next().unwrap().initials.value(), "XY");
+    assert_eq!(state3.users.values().next().unwrap().initials.value(), "XY");
 }
 
 #[test]
@@ -232,7 +256,10 @@ fn test_get_current_state_does_not_mutate() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create a snapshot
-    let command: Command = Command::Checkpoint;
+    let command: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -270,7 +297,10 @@ fn test_get_current_state_with_multiple_users() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create initial snapshot
-    let command1: Command = Command::Checkpoint;
+    let command1: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result1: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -323,7 +353,10 @@ fn test_get_current_state_with_multiple_users() {
     persistence.persist_transition(&result3).unwrap();
 
     // Create snapshot with both users
-    let command4: Command = Command::Checkpoint;
+    let command4: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result4: TransitionResult = apply(
         &create_test_metadata(),
         &result3.new_state,
@@ -343,8 +376,8 @@ fn test_get_current_state_with_multiple_users() {
     assert_eq!(current_state.users.len(), 2);
     let initials: Vec<String> = current_state
         .users
-        .iter()
-        .map(|u| u.initials.value().to_string())
+        .keys()
+        .map(|i| i.value().to_string())
         .collect();
     assert!(initials.contains(&String::from("AA")));
     assert!(initials.contains(&String::from("BB")));
@@ -361,7 +394,10 @@ fn bootstrap_area_with_user(
     let state: State = State::new(BidYear::new(2026), area.clone());
 
     // Initial checkpoint
-    let cmd1: Command = Command::Checkpoint;
+    let cmd1: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let res1: TransitionResult = apply(
         metadata,
         &state,
@@ -394,7 +430,10 @@ fn bootstrap_area_with_user(
     persistence.persist_transition(&res2).unwrap();
 
     // Final checkpoint
-    let cmd3: Command = Command::Checkpoint;
+    let cmd3: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let res3: TransitionResult = apply(
         metadata,
         &res2.new_state,
@@ -414,7 +453,10 @@ fn create_empty_area_state(
     area_name: &str,
 ) {
     let state: State = State::new(BidYear::new(2026), Area::new(area_name));
-    let cmd: Command = Command::Checkpoint;
+    let cmd: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let res: TransitionResult = apply(
         metadata,
         &state,
@@ -492,7 +534,16 @@ fn test_get_current_state_different_areas_isolated() {
         .get_current_state(&BidYear::new(2026), &Area::new("North"))
         .unwrap();
     assert_eq!(north_current.users.len(), 1);
-    assert_eq!(north_current.users[0].initials.value(), "NN");
+    assert_eq!(
+        north_current
+            .users
+            .values()
+            .next()
+            .unwrap()
+            .initials
+            .value(),
+        "NN"
+    );
 
     // Verify South is empty
     let south_current: State = persistence
@@ -500,3 +551,75 @@ fn test_get_current_state_different_areas_isolated() {
         .unwrap();
     assert_eq!(south_current.users.len(), 0);
 }
+
+#[test]
+fn test_load_bid_year_states_matches_serial_loading() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    let mut metadata = zab_bid::BootstrapMetadata::new();
+
+    let bid_year_result = zab_bid::apply_bootstrap(
+        &metadata,
+        &BidYear::new(2026),
+        Command::CreateBidYear {
+            year: 2026,
+            start_date: create_test_start_date(),
+            num_pay_periods: create_test_pay_periods(),
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    persistence.persist_bootstrap(&bid_year_result).unwrap();
+    metadata.bid_years.push(BidYear::new(2026));
+
+    let areas: [(&str, &str); 5] = [
+        ("East", "EE"),
+        ("North", "NN"),
+        ("South", "SS"),
+        ("West", "WW"),
+        ("Tower", "TT"),
+    ];
+    for (area_name, _) in areas {
+        let area_result = zab_bid::apply_bootstrap(
+            &metadata,
+            &BidYear::new(2026),
+            Command::CreateArea {
+                area_id: String::from(area_name),
+            },
+            create_test_actor(),
+            create_test_cause(),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&area_result).unwrap();
+        metadata
+            .areas
+            .push((BidYear::new(2026), Area::new(area_name)));
+    }
+    for (area_name, initials) in areas {
+        bootstrap_area_with_user(&mut persistence, &metadata, area_name, initials);
+    }
+
+    let serial: Vec<State> = persistence
+        .load_bid_year_states(&BidYear::new(2026), 1)
+        .unwrap();
+    let parallel: Vec<State> = persistence
+        .load_bid_year_states(&BidYear::new(2026), 3)
+        .unwrap();
+
+    let area_codes: Vec<&str> = parallel.iter().map(|state| state.area.id()).collect();
+    assert_eq!(area_codes, ["EAST", "NORTH", "SOUTH", "TOWER", "WEST"]);
+    assert_eq!(parallel, serial);
+    assert!(parallel.iter().all(|state| state.users.len() == 1));
+}
+
+#[test]
+fn test_load_bid_year_states_unknown_bid_year_fails() {
+    let mut persistence: SqlitePersistence = create_bootstrapped_persistence();
+
+    assert!(
+        persistence
+            .load_bid_year_states(&BidYear::new(2030), 4)
+            .is_err()
+    );
+}
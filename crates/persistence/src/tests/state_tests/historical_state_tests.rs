@@ -61,7 +61,10 @@ fn test_get_historical_state_at_specific_timestamp() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create first snapshot with no users
-    let command1: Command = Command::Checkpoint;
+    let command1: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result1: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -94,7 +97,10 @@ fn test_get_historical_state_at_specific_timestamp() {
     persistence.persist_transition(&result2).unwrap();
 
     // Create second snapshot with user
-    let command3: Command = Command::Checkpoint;
+    let command3: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result3: TransitionResult = apply(
         &create_test_metadata(),
         &result2.new_state,
@@ -122,7 +128,16 @@ fn test_get_historical_state_at_specific_timestamp() {
         .unwrap();
 
     assert_eq!(historical_state.users.len(), 1);
-    assert_eq!(historical_state.users[0].initials.value(), "NE");
+    assert_eq!(
+        historical_state
+            .users
+            .values()
+            .next()
+            .unwrap()
+            .initials
+            .value(),
+        "NE"
+    );
 }
 
 #[test]
@@ -131,7 +146,10 @@ fn test_get_historical_state_before_any_snapshot_returns_error() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create a snapshot
-    let command: Command = Command::Checkpoint;
+    let command: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -164,7 +182,10 @@ fn test_get_historical_state_is_deterministic() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create snapshot
-    let command: Command = Command::Checkpoint;
+    let command: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -204,7 +225,10 @@ fn test_get_historical_state_does_not_mutate() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create snapshot
-    let command: Command = Command::Checkpoint;
+    let command: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result: TransitionResult = apply(
         &create_test_metadata(),
         &state,
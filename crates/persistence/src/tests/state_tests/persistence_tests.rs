@@ -3,16 +3,19 @@
 // license that can be found in the LICENSE file or at
 // https://opensource.org/licenses/MIT.
 
-use crate::SqlitePersistence;
+use std::sync::Arc;
+
 use crate::tests::{
     create_test_actor, create_test_bid_year_and_area, create_test_cause, create_test_metadata,
     create_test_operator, create_test_pay_periods, create_test_seniority_data,
     create_test_start_date,
 };
+use crate::{PersistenceError, SqlitePersistence};
 use zab_bid::{
-    BootstrapMetadata, BootstrapResult, Command, State, TransitionResult, apply, apply_bootstrap,
+    BootstrapMetadata, BootstrapResult, Command, SequentialIdGenerator, State, TransitionResult,
+    apply, apply_bootstrap,
 };
-use zab_bid_audit::AuditEvent;
+use zab_bid_audit::{AuditEvent, Ulid};
 use zab_bid_domain::{Area, BidYear, Crew, Initials, UserType};
 
 /// Creates a fully bootstrapped test persistence instance with bid year 2026 and area "North".
@@ -90,12 +93,56 @@ fn test_persist_and_retrieve_audit_event() {
     assert_eq!(retrieved.action.name, "RegisterUser");
 }
 
+#[test]
+fn test_audit_event_ulid_is_persisted_and_looked_up() {
+    let mut persistence: SqlitePersistence = create_bootstrapped_persistence();
+    persistence.set_id_generator(Arc::new(SequentialIdGenerator::new()));
+    let state: State = State::new(BidYear::new(2026), Area::new("North"));
+    let result: TransitionResult = apply(
+        &create_test_metadata(),
+        &state,
+        &BidYear::new(2026),
+        Command::Checkpoint {
+            label: None,
+            description: None,
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap();
+    // Core leaves the ULID to persistence, so applying a command stays deterministic
+    assert_eq!(result.audit_event.event_ulid, None);
+
+    let event_id: i64 = persistence.persist_transition(&result).unwrap().event_id;
+
+    let ulid: Ulid = Ulid::from_parts(0, 1);
+    let retrieved: AuditEvent = persistence.get_audit_event(event_id).unwrap();
+    assert_eq!(retrieved.event_ulid, Some(ulid));
+    let by_ulid: AuditEvent = persistence.get_audit_event_by_ulid(&ulid).unwrap();
+    assert_eq!(by_ulid.event_id, Some(event_id));
+    assert!(matches!(
+        persistence.get_audit_event_by_ulid(&Ulid::nil()),
+        Err(PersistenceError::NotFound(_))
+    ));
+
+    // An event that already carries a ULID keeps it
+    let imported: Ulid = Ulid::from_parts(1, 7);
+    let event_id: i64 = persistence
+        .persist_audit_event(&result.audit_event.with_ulid(Some(imported)))
+        .unwrap();
+    let retrieved: AuditEvent = persistence.get_audit_event(event_id).unwrap();
+    assert_eq!(retrieved.event_ulid, Some(imported));
+}
+
 #[test]
 fn test_persist_with_snapshot() {
     let mut persistence: SqlitePersistence = create_bootstrapped_persistence();
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
-    let command: Command = Command::Checkpoint;
+    let command: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -123,7 +170,10 @@ fn test_get_events_after() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create first event
-    let command1: Command = Command::Checkpoint;
+    let command1: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result1: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -180,7 +230,10 @@ fn test_atomic_persistence_failure() {
     create_test_bid_year_and_area(&mut persistence, 2026, "North");
 
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
-    let command: Command = Command::Checkpoint;
+    let command: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -201,7 +254,10 @@ fn test_state_reconstruction_with_snapshot_then_deltas() {
     let state: State = State::new(BidYear::new(2026), Area::new("North"));
 
     // Create initial snapshot
-    let command1: Command = Command::Checkpoint;
+    let command1: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result1: TransitionResult = apply(
         &create_test_metadata(),
         &state,
@@ -234,7 +290,10 @@ fn test_state_reconstruction_with_snapshot_then_deltas() {
     persistence.persist_transition(&result2).unwrap();
 
     // Create another snapshot
-    let command3: Command = Command::Checkpoint;
+    let command3: Command = Command::Checkpoint {
+        label: None,
+        description: None,
+    };
     let result3: TransitionResult = apply(
         &create_test_metadata(),
         &result2.new_state,
@@ -252,5 +311,107 @@ fn test_state_reconstruction_with_snapshot_then_deltas() {
         .unwrap();
 
     assert_eq!(current_state.users.len(), 1);
-    assert_eq!(current_state.users[0].initials.value(), "TS");
+    assert_eq!(
+        current_state
+            .users
+            .values()
+            .next()
+            .unwrap()
+            .initials
+            .value(),
+        "TS"
+    );
+}
+
+fn register_transition(state: &State, initials: &str) -> TransitionResult {
+    apply(
+        &create_test_metadata(),
+        state,
+        &BidYear::new(2026),
+        Command::RegisterUser {
+            initials: Initials::new(initials),
+            name: format!("User {initials}"),
+            area: Area::new("North"),
+            user_type: UserType::CPC,
+            crew: Some(Crew::new(1).unwrap()),
+            seniority_data: create_test_seniority_data(),
+        },
+        create_test_actor(),
+        create_test_cause(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_persist_transitions_persists_all_in_order() {
+    let mut persistence: SqlitePersistence = create_bootstrapped_persistence();
+    let state: State = State::new(BidYear::new(2026), Area::new("North"));
+    let first: TransitionResult = register_transition(&state, "AB");
+    let second: TransitionResult = register_transition(&first.new_state, "CD");
+
+    let persisted = persistence.persist_transitions(&[first, second]).unwrap();
+
+    assert_eq!(persisted.len(), 2);
+    assert!(persisted[0].event_id < persisted[1].event_id);
+    assert!(persisted.iter().all(|p| p.user_id.is_some()));
+    let users = persistence
+        .list_users(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    assert_eq!(users.len(), 2);
+}
+
+#[test]
+fn test_persist_transitions_rolls_back_on_failure() {
+    let mut persistence: SqlitePersistence = create_bootstrapped_persistence();
+    let state: State = State::new(BidYear::new(2026), Area::new("North"));
+    // Both transitions start from the same state, so the second insert
+    // violates the (bid_year, area, initials) uniqueness constraint.
+    let first: TransitionResult = register_transition(&state, "AB");
+    let duplicate: TransitionResult = register_transition(&state, "AB");
+
+    let result = persistence.persist_transitions(&[first, duplicate]);
+
+    assert!(result.is_err());
+    let users = persistence
+        .list_users(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    assert!(users.is_empty());
+}
+
+#[test]
+fn test_persist_correlated_transitions_records_every_event_under_the_correlation() {
+    let mut persistence: SqlitePersistence = create_bootstrapped_persistence();
+    let state: State = State::new(BidYear::new(2026), Area::new("North"));
+    let first: TransitionResult = register_transition(&state, "AB");
+    let second: TransitionResult = register_transition(&first.new_state, "CD");
+    let correlation_id: String = Ulid::new().to_string();
+
+    let persisted = persistence
+        .persist_correlated_transitions(&[first, second], &correlation_id)
+        .unwrap();
+
+    assert_eq!(persisted.len(), 2);
+    for p in &persisted {
+        assert_eq!(
+            persistence.get_event_correlation_id(p.event_id).unwrap(),
+            Some(correlation_id.clone())
+        );
+    }
+}
+
+#[test]
+fn test_persist_correlated_transitions_rolls_back_on_failure() {
+    let mut persistence: SqlitePersistence = create_bootstrapped_persistence();
+    let state: State = State::new(BidYear::new(2026), Area::new("North"));
+    let first: TransitionResult = register_transition(&state, "AB");
+    let duplicate: TransitionResult = register_transition(&state, "AB");
+    let correlation_id: String = Ulid::new().to_string();
+
+    let result = persistence.persist_correlated_transitions(&[first, duplicate], &correlation_id);
+
+    assert!(result.is_err());
+    let users = persistence
+        .list_users(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    assert!(users.is_empty());
 }
@@ -0,0 +1,146 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Tests for webhook configuration and dead-letter persistence.
+
+use crate::{SqlitePersistence, WebhookData, WebhookDeadLetterData};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+
+/// Persists a global audit event and returns its ID.
+fn persist_test_event(persistence: &mut SqlitePersistence) -> i64 {
+    let operator_id: i64 = persistence
+        .create_operator("admin", "Admin", "password", "Admin")
+        .unwrap();
+    let actor: Actor = Actor::with_operator(
+        operator_id.to_string(),
+        String::from("operator"),
+        operator_id,
+        String::from("ADMIN"),
+        String::from("Admin"),
+    );
+    let event: AuditEvent = AuditEvent::new_global(
+        actor,
+        Cause::new(String::from("test"), String::from("Test cause")),
+        Action::new(String::from("TestAction"), None),
+        StateSnapshot::new(String::from("before")),
+        StateSnapshot::new(String::from("after")),
+    );
+    persistence.persist_audit_event(&event).unwrap()
+}
+
+fn filter(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| (*name).to_string()).collect()
+}
+
+#[test]
+fn test_create_and_get_webhook() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+
+    let webhook_id: i64 = persistence
+        .create_webhook(
+            "https://example.test/hook",
+            "s3cret",
+            &filter(&["RegisterUser", "Finalize"]),
+        )
+        .unwrap();
+
+    let webhook: WebhookData = persistence.get_webhook(webhook_id).unwrap().unwrap();
+    assert_eq!(webhook.url, "https://example.test/hook");
+    assert_eq!(webhook.secret, "s3cret");
+    assert_eq!(webhook.event_filter, filter(&["RegisterUser", "Finalize"]));
+    assert!(webhook.is_enabled);
+    assert!(webhook.accepts("Finalize"));
+    assert!(!webhook.accepts("Checkpoint"));
+}
+
+#[test]
+fn test_get_missing_webhook_returns_none() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    assert!(persistence.get_webhook(99).unwrap().is_none());
+}
+
+#[test]
+fn test_update_webhook_changes_filter_and_enabled() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    let webhook_id: i64 = persistence
+        .create_webhook("https://example.test/a", "s", &filter(&["Finalize"]))
+        .unwrap();
+
+    persistence
+        .update_webhook(
+            webhook_id,
+            "https://example.test/b",
+            &filter(&[WebhookData::ALL_EVENTS]),
+            false,
+        )
+        .unwrap();
+
+    let webhook: WebhookData = persistence.get_webhook(webhook_id).unwrap().unwrap();
+    assert_eq!(webhook.url, "https://example.test/b");
+    assert!(!webhook.is_enabled);
+    // Disabled webhooks accept nothing, even with a wildcard filter
+    assert!(!webhook.accepts("Finalize"));
+}
+
+#[test]
+fn test_list_webhooks_in_id_order() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    let first: i64 = persistence
+        .create_webhook("https://example.test/1", "s", &filter(&["*"]))
+        .unwrap();
+    let second: i64 = persistence
+        .create_webhook("https://example.test/2", "s", &filter(&["*"]))
+        .unwrap();
+
+    let ids: Vec<i64> = persistence
+        .list_webhooks()
+        .unwrap()
+        .iter()
+        .map(|w| w.webhook_id)
+        .collect();
+    assert_eq!(ids, vec![first, second]);
+}
+
+#[test]
+fn test_dead_letters_recorded_and_removed_with_webhook() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    let event_id: i64 = persist_test_event(&mut persistence);
+    let webhook_id: i64 = persistence
+        .create_webhook("https://example.test/hook", "s", &filter(&["*"]))
+        .unwrap();
+
+    persistence
+        .record_webhook_dead_letter(webhook_id, event_id, "{}", 5, "connection refused")
+        .unwrap();
+
+    let dead_letters: Vec<WebhookDeadLetterData> = persistence
+        .list_webhook_dead_letters(Some(webhook_id), 10)
+        .unwrap();
+    assert_eq!(dead_letters.len(), 1);
+    assert_eq!(dead_letters[0].audit_event_id, event_id);
+    assert_eq!(dead_letters[0].attempts, 5);
+    assert_eq!(dead_letters[0].last_error, "connection refused");
+
+    persistence.delete_webhook(webhook_id).unwrap();
+
+    assert!(persistence.get_webhook(webhook_id).unwrap().is_none());
+    assert!(
+        persistence
+            .list_webhook_dead_letters(None, 10)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_dead_letter_requires_existing_audit_event() {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    let webhook_id: i64 = persistence
+        .create_webhook("https://example.test/hook", "s", &filter(&["*"]))
+        .unwrap();
+
+    let result = persistence.record_webhook_dead_letter(webhook_id, 12345, "{}", 1, "boom");
+    assert!(result.is_err());
+}
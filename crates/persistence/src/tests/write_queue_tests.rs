@@ -0,0 +1,104 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Write-ahead queue encoding and exactly-once replay tests.
+
+use crate::tests::{
+    create_test_actor, create_test_bid_year_and_area, create_test_metadata, create_test_operator,
+};
+use crate::{SqlitePersistence, decode_queued_transition, encode_queued_transition};
+use zab_bid::{State, TransitionResult};
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_test_support::BidYearFixture;
+
+const QUEUE: &str = "test-queue";
+const FLUSHED_AT: &str = "2026-01-05T12:00:00Z";
+
+fn create_persistence() -> SqlitePersistence {
+    let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory().unwrap();
+    create_test_operator(&mut persistence);
+    create_test_bid_year_and_area(&mut persistence, 2026, "North");
+    persistence
+}
+
+/// Registers the fixture's first `count` users in turn, returning one
+/// transition per user.
+fn register_users(count: usize) -> Vec<TransitionResult> {
+    BidYearFixture::new(2026)
+        .with_users(count)
+        .registrations("North", &create_test_metadata(), &create_test_actor())
+        .unwrap()
+}
+
+#[test]
+fn test_queued_transition_round_trips() {
+    let transition: TransitionResult = register_users(2).pop().unwrap();
+
+    let line: String = encode_queued_transition(&transition).unwrap();
+    assert!(!line.contains('\n'));
+
+    let decoded: TransitionResult = decode_queued_transition(&line).unwrap();
+    assert_eq!(decoded.audit_event, transition.audit_event);
+    assert_eq!(decoded.new_state, transition.new_state);
+}
+
+#[test]
+fn test_persist_queued_transitions_records_progress() {
+    let mut persistence: SqlitePersistence = create_persistence();
+    let entries: Vec<(i64, TransitionResult)> = (1..).zip(register_users(2)).collect();
+
+    assert_eq!(persistence.get_write_queue_progress(QUEUE).unwrap(), None);
+    let persisted: usize = persistence
+        .persist_queued_transitions(QUEUE, &entries, FLUSHED_AT)
+        .unwrap()
+        .len();
+
+    assert_eq!(persisted, 2);
+    assert_eq!(
+        persistence.get_write_queue_progress(QUEUE).unwrap(),
+        Some(2)
+    );
+    let state: State = persistence
+        .get_current_state(&BidYear::new(2026), &Area::new("North"))
+        .unwrap();
+    assert_eq!(state.users.len(), 2);
+}
+
+#[test]
+fn test_replayed_queue_skips_persisted_entries() {
+    let mut persistence: SqlitePersistence = create_persistence();
+    let entries: Vec<(i64, TransitionResult)> = (1..).zip(register_users(3)).collect();
+    persistence
+        .persist_queued_transitions(QUEUE, &entries[..2], FLUSHED_AT)
+        .unwrap();
+
+    // Re-registering AA or AB would violate the unique initials constraint
+    let persisted: usize = persistence
+        .persist_queued_transitions(QUEUE, &entries, FLUSHED_AT)
+        .unwrap()
+        .len();
+
+    assert_eq!(persisted, 1);
+    assert_eq!(
+        persistence.get_write_queue_progress(QUEUE).unwrap(),
+        Some(3)
+    );
+}
+
+#[test]
+fn test_failed_flush_persists_nothing() {
+    let mut persistence: SqlitePersistence = create_persistence();
+    let mut transitions: Vec<TransitionResult> = register_users(1);
+    transitions.push(transitions[0].clone());
+    let entries: Vec<(i64, TransitionResult)> = (1..).zip(transitions).collect();
+
+    assert!(
+        persistence
+            .persist_queued_transitions(QUEUE, &entries, FLUSHED_AT)
+            .is_err()
+    );
+    assert_eq!(persistence.get_write_queue_progress(QUEUE).unwrap(), None);
+    assert_eq!(persistence.get_latest_audit_event_id().unwrap(), Some(2));
+}
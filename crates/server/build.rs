@@ -0,0 +1,48 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Embeds build information for the `/version` endpoint.
+//!
+//! `ZABBID_GIT_SHA` and `SOURCE_DATE_EPOCH` may be set by the caller (e.g.
+//! container or Nix builds without a `.git` directory); otherwise the values
+//! are derived from git and the current time.
+
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn git_sha() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let sha: String = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    if sha.is_empty() { None } else { Some(sha) }
+}
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=ZABBID_GIT_SHA");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    println!("cargo:rerun-if-changed=../../.git/HEAD");
+
+    let sha: String = std::env::var("ZABBID_GIT_SHA")
+        .ok()
+        .or_else(git_sha)
+        .unwrap_or_else(|| String::from("unknown"));
+
+    let build_timestamp: u64 = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs())
+        });
+
+    println!("cargo:rustc-env=ZABBID_GIT_SHA={sha}");
+    println!("cargo:rustc-env=ZABBID_BUILD_TIMESTAMP={build_timestamp}");
+}
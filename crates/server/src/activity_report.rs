@@ -0,0 +1,336 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Monthly admin activity summaries.
+//!
+//! When `--activity-report-to` is given and SMTP is configured, the
+//! `admin_activity_report` [`Job`] emails each recipient a summary of the
+//! previous calendar month's operator activity: commands, overrides,
+//! rollbacks, and after-hours commands per operator, with days and business
+//! hours read in `--activity-report-timezone`.
+//!
+//! The job checks every few hours and sends once per month. A month's
+//! summary is sent by the first run after the month ends; it is retried on
+//! the next run if delivery fails. The same figures, for any range, are
+//! rendered as PDF, HTML, or CSV by `GET /reports/admin-activity`.
+
+use futures::future::BoxFuture;
+use lettre::message::Mailbox;
+use std::fmt::Write;
+use std::time::Duration;
+use time::format_description::well_known::Rfc3339;
+use time::{Date, OffsetDateTime};
+use tokio::sync::Mutex;
+use tracing::info;
+use zab_bid_api::{AdminActivitySummary, OperatorActivityInfo};
+use zab_bid_persistence::{JobRunData, Persistence};
+
+use crate::email::{EmailMessage, Mailer};
+use crate::jobs::Job;
+
+/// How often the job checks whether a month's summary is due.
+pub const ACTIVITY_REPORT_POLL_INTERVAL: Duration = Duration::from_hours(6);
+
+/// Monthly admin activity summary settings.
+#[derive(clap::Args, Debug, Clone)]
+pub struct ActivityReportArgs {
+    /// Comma-separated addresses the monthly admin activity summary is
+    /// emailed to. The summary is not sent when omitted.
+    #[arg(long)]
+    pub activity_report_to: Option<String>,
+
+    /// IANA timezone the summary's days and business hours are read in
+    #[arg(long, default_value = "UTC")]
+    pub activity_report_timezone: String,
+}
+
+impl Default for ActivityReportArgs {
+    fn default() -> Self {
+        Self {
+            activity_report_to: None,
+            activity_report_timezone: String::from("UTC"),
+        }
+    }
+}
+
+impl ActivityReportArgs {
+    /// Validates the summary settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a recipient address or the timezone is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        for recipient in self.recipients() {
+            recipient
+                .parse::<Mailbox>()
+                .map_err(|e| format!("Invalid --activity-report-to address '{recipient}': {e}"))?;
+        }
+        zab_bid_domain::parse_timezone(&self.activity_report_timezone)
+            .map_err(|e| format!("Invalid --activity-report-timezone: {e}"))?;
+        Ok(())
+    }
+
+    /// Returns the recipient addresses, which are empty when disabled.
+    #[must_use]
+    pub fn recipients(&self) -> Vec<String> {
+        self.activity_report_to
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|recipient| !recipient.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// Emails the previous month's admin activity summary.
+pub struct ActivityReportJob<M: Mailer> {
+    /// Delivers the summary.
+    pub mailer: M,
+    /// Who the summary is sent to.
+    pub recipients: Vec<String>,
+    /// The IANA timezone days and business hours are read in.
+    pub timezone: String,
+}
+
+/// Returns the first day of the month `instant` falls in, in `timezone`.
+fn month_start(timezone: &str, instant: OffsetDateTime) -> Result<Date, String> {
+    let local: Date = zab_bid_domain::utc_to_local(timezone, instant)
+        .map_err(|e| e.to_string())?
+        .date();
+    local.replace_day(1).map_err(|e| e.to_string())
+}
+
+/// Formats the summary as a plain-text email body.
+fn render_summary(summary: &AdminActivitySummary) -> String {
+    let mut body: String = format!(
+        "Admin activity from {} to {} ({}).\n\n",
+        summary.start_date, summary.end_date, summary.timezone
+    );
+    if summary.operators.is_empty() {
+        body.push_str("No commands were recorded.\n");
+        return body;
+    }
+    let _ = writeln!(
+        body,
+        "{:<24} {:>9} {:>9} {:>9} {:>11}",
+        "Operator", "Commands", "Overrides", "Rollbacks", "After hours"
+    );
+    for operator in &summary.operators {
+        let OperatorActivityInfo {
+            login_name,
+            commands,
+            overrides,
+            rollbacks,
+            after_hours,
+            ..
+        } = operator;
+        let _ = writeln!(
+            body,
+            "{login_name:<24} {commands:>9} {overrides:>9} {rollbacks:>9} {after_hours:>11}"
+        );
+    }
+    body.push_str(
+        "\nAfter hours: weekdays before 07:00 or from 18:00, and weekends.\n\
+         The full report is available from /reports/admin-activity.\n",
+    );
+    body
+}
+
+impl<M: Mailer + 'static> Job for ActivityReportJob<M> {
+    fn name(&self) -> &'static str {
+        "admin_activity_report"
+    }
+
+    fn run<'a>(
+        &'a self,
+        persistence: &'a Mutex<Persistence>,
+        now: OffsetDateTime,
+    ) -> BoxFuture<'a, Result<String, String>> {
+        Box::pin(async move {
+            let this_month: Date = month_start(&self.timezone, now)?;
+            let end_date: Date = this_month
+                .previous_day()
+                .ok_or_else(|| format!("No month precedes {this_month}"))?;
+            let start_date: Date = end_date.replace_day(1).map_err(|e| e.to_string())?;
+
+            let mut persistence = persistence.lock().await;
+            // A successful run this month has already sent last month's summary
+            let last_run: Option<JobRunData> = persistence
+                .list_job_runs()
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .find(|run| run.job_name == self.name());
+            if let Some(run) = last_run.filter(|run| run.succeeded) {
+                let started: OffsetDateTime =
+                    OffsetDateTime::parse(&run.started_at, &Rfc3339).map_err(|e| e.to_string())?;
+                if month_start(&self.timezone, started)? == this_month {
+                    return Ok(format!(
+                        "Summary for {start_date} to {end_date} already sent"
+                    ));
+                }
+            }
+            let summary: AdminActivitySummary = zab_bid_api::summarize_admin_activity(
+                &mut persistence,
+                start_date,
+                end_date,
+                &self.timezone,
+            )
+            .map_err(|e| format!("Failed to summarize admin activity: {e}"))?;
+            drop(persistence);
+
+            let body: String = render_summary(&summary);
+            for recipient in &self.recipients {
+                self.mailer
+                    .send(&EmailMessage {
+                        to: recipient.clone(),
+                        subject: format!("Admin activity {start_date} to {end_date}"),
+                        body: body.clone(),
+                        attachments: Vec::new(),
+                    })
+                    .await
+                    .map_err(|e| format!("Failed to send summary to {recipient}: {e}"))?;
+            }
+
+            info!(
+                start_date = %start_date,
+                end_date = %end_date,
+                recipients = self.recipients.len(),
+                "Admin activity summary sent"
+            );
+            Ok(format!(
+                "Sent summary for {start_date} to {end_date} to {} recipients",
+                self.recipients.len()
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+    use zab_bid_test_support::BidYearFixture;
+
+    /// Records sent messages.
+    #[derive(Default)]
+    struct RecordingMailer {
+        sent: std::sync::Mutex<Vec<EmailMessage>>,
+    }
+
+    impl Mailer for RecordingMailer {
+        async fn send(&self, message: &EmailMessage) -> Result<(), String> {
+            self.sent.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    fn job() -> ActivityReportJob<RecordingMailer> {
+        ActivityReportJob {
+            mailer: RecordingMailer::default(),
+            recipients: vec![
+                String::from("union@example.test"),
+                String::from("manager@example.test"),
+            ],
+            timezone: String::from("America/New_York"),
+        }
+    }
+
+    #[test]
+    fn test_args_parse_recipients_and_timezone() {
+        let mut args: ActivityReportArgs = ActivityReportArgs::default();
+        assert!(args.validate().is_ok());
+        assert!(args.recipients().is_empty());
+
+        args.activity_report_to = Some(String::from(
+            "union@example.test, Manager <manager@example.test>",
+        ));
+        assert!(args.validate().is_ok());
+        assert_eq!(args.recipients().len(), 2);
+
+        args.activity_report_timezone = String::from("Mars/Olympus_Mons");
+        assert!(args.validate().is_err());
+
+        args.activity_report_timezone = String::from("UTC");
+        args.activity_report_to = Some(String::from("not an address"));
+        assert!(args.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_previous_month_is_sent_once() {
+        let persistence: Mutex<Persistence> =
+            Mutex::new(BidYearFixture::new(2026).persist().unwrap().persistence);
+        let job: ActivityReportJob<RecordingMailer> = job();
+        // 03:00 UTC on October 1 is still September in New York
+        let september: OffsetDateTime = datetime!(2026-10-01 03:00 UTC);
+        let october: OffsetDateTime = datetime!(2026-10-01 12:00 UTC);
+
+        let summary: String = job.run(&persistence, september).await.unwrap();
+        assert_eq!(
+            summary,
+            "Sent summary for 2026-08-01 to 2026-08-31 to 2 recipients"
+        );
+        let summary: String = job.run(&persistence, october).await.unwrap();
+        assert_eq!(
+            summary,
+            "Sent summary for 2026-09-01 to 2026-09-30 to 2 recipients"
+        );
+        let sent: Vec<EmailMessage> = job.mailer.sent.lock().unwrap().clone();
+        assert_eq!(sent.len(), 4);
+        assert_eq!(sent[3].to, "manager@example.test");
+        assert_eq!(sent[3].subject, "Admin activity 2026-09-01 to 2026-09-30");
+        assert!(
+            sent[3]
+                .body
+                .starts_with("Admin activity from 2026-09-01 to 2026-09-30 (America/New_York).")
+        );
+
+        // Once this month's run is recorded, later runs do not resend
+        persistence
+            .lock()
+            .await
+            .record_job_run(&JobRunData {
+                job_name: job.name().to_string(),
+                started_at: october.format(&Rfc3339).unwrap(),
+                finished_at: october.format(&Rfc3339).unwrap(),
+                succeeded: true,
+                duration_ms: 0,
+                message: None,
+            })
+            .unwrap();
+        let summary: String = job
+            .run(&persistence, datetime!(2026-10-20 12:00 UTC))
+            .await
+            .unwrap();
+        assert_eq!(summary, "Summary for 2026-09-01 to 2026-09-30 already sent");
+        assert_eq!(job.mailer.sent.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_summary_lists_each_operator() {
+        let body: String = render_summary(&AdminActivitySummary {
+            start_date: String::from("2026-09-01"),
+            end_date: String::from("2026-09-30"),
+            timezone: String::from("UTC"),
+            operators: vec![OperatorActivityInfo {
+                operator_id: 1,
+                login_name: String::from("admin"),
+                commands: 12,
+                overrides: 2,
+                rollbacks: 1,
+                after_hours: 3,
+            }],
+        });
+
+        assert!(
+            body.contains("Operator                  Commands Overrides Rollbacks After hours")
+        );
+        assert!(
+            body.contains("admin                           12         2         1           3")
+        );
+    }
+}
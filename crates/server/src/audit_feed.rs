@@ -0,0 +1,260 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Audit change feed for the `/ws` live update channel.
+//!
+//! A background task tails the audit log and republishes newly persisted
+//! events as typed [`LiveEvent`]s. Because events are derived from what was
+//! actually committed, every writer (HTTP handlers, bulk operations, future
+//! background jobs) is covered without having to broadcast explicitly.
+//!
+//! The feed starts at the newest event present at startup; history is never
+//! replayed to clients.
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+use zab_bid_persistence::{
+    AuditTimelineFilter, AuditTimelinePage, AuditTimelineScope, Persistence, PersistenceError,
+};
+
+use crate::live::{LiveEvent, LiveEventBroadcaster};
+use crate::shutdown::ShutdownSignal;
+
+/// Maximum number of audit events read per poll.
+const FEED_BATCH_SIZE: u32 = 200;
+
+/// Live events derived from a contiguous run of audit events.
+#[derive(Debug, Clone)]
+pub struct FeedBatch {
+    /// Mapped live events paired with the audit event ID they came from.
+    pub events: Vec<(i64, LiveEvent)>,
+    /// The last audit event ID read, including events that did not map.
+    pub cursor: Option<i64>,
+}
+
+/// Reads every audit event persisted after `cursor` and maps it to live events.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer
+/// * `cursor` - The last audit event ID already consumed, or `None` for the beginning
+///
+/// # Errors
+///
+/// Returns an error if the audit log cannot be read.
+pub async fn read_after(
+    persistence: &Mutex<Persistence>,
+    cursor: Option<i64>,
+) -> Result<FeedBatch, PersistenceError> {
+    let mut batch: FeedBatch = FeedBatch {
+        events: Vec::new(),
+        cursor,
+    };
+
+    loop {
+        let page: AuditTimelinePage = persistence.lock().await.get_audit_timeline_page(
+            AuditTimelineScope::All,
+            &AuditTimelineFilter::default(),
+            batch.cursor,
+            FEED_BATCH_SIZE,
+        )?;
+
+        for entry in &page.entries {
+            if let (Some(event_id), Some(event)) = (
+                entry.event.event_id,
+                LiveEvent::from_audit_event(&entry.event),
+            ) {
+                batch.events.push((event_id, event));
+            }
+        }
+        if let Some(last_id) = page.entries.last().and_then(|e| e.event.event_id) {
+            batch.cursor = Some(last_id);
+        }
+        if page.next_cursor.is_none() {
+            break;
+        }
+    }
+
+    Ok(batch)
+}
+
+/// Reads audit events persisted after `cursor` and broadcasts the mapped live events.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer
+/// * `broadcaster` - The broadcaster for `/ws` clients
+/// * `cursor` - The last event ID already published; advanced past every event read
+///
+/// # Returns
+///
+/// The number of live events broadcast.
+///
+/// # Errors
+///
+/// Returns an error if the audit log cannot be read.
+pub async fn poll_once(
+    persistence: &Mutex<Persistence>,
+    broadcaster: &LiveEventBroadcaster,
+    cursor: &mut Option<i64>,
+) -> Result<usize, PersistenceError> {
+    let batch: FeedBatch = read_after(persistence, *cursor).await?;
+    for (_, event) in &batch.events {
+        broadcaster.broadcast(event);
+    }
+    *cursor = batch.cursor;
+    Ok(batch.events.len())
+}
+
+/// Spawns the background task that tails the audit log.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer
+/// * `broadcaster` - The broadcaster for `/ws` clients
+/// * `poll_interval` - How often to check for new audit events
+/// * `shutdown` - Stops the task when the server shuts down
+///
+/// # Errors
+///
+/// Returns an error if the starting cursor cannot be read.
+pub async fn spawn(
+    persistence: Arc<Mutex<Persistence>>,
+    broadcaster: Arc<LiveEventBroadcaster>,
+    poll_interval: Duration,
+    mut shutdown: ShutdownSignal,
+) -> Result<tokio::task::JoinHandle<()>, PersistenceError> {
+    let mut cursor: Option<i64> = persistence.lock().await.get_latest_audit_event_id()?;
+    debug!(?cursor, "Starting audit change feed");
+
+    Ok(tokio::spawn(async move {
+        let mut ticker: tokio::time::Interval = tokio::time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                () = shutdown.wait() => break,
+            }
+            if let Err(e) = poll_once(&persistence, &broadcaster, &mut cursor).await {
+                warn!(error = %e, "Audit change feed poll failed");
+            }
+        }
+        debug!("Audit change feed stopped");
+    }))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use zab_bid::{BootstrapMetadata, Command, State, TransitionResult, apply, apply_bootstrap};
+    use zab_bid_audit::{Actor, Cause};
+    use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, UserType};
+
+    fn test_actor(operator_id: i64) -> Actor {
+        Actor::with_operator(
+            String::from("admin"),
+            String::from("admin"),
+            operator_id,
+            String::from("admin"),
+            String::from("Admin"),
+        )
+    }
+
+    fn test_cause() -> Cause {
+        Cause::new(String::from("test"), String::from("Test"))
+    }
+
+    /// Bootstraps 2026/North and returns the metadata.
+    fn bootstrap(persistence: &mut Persistence, operator_id: i64) -> BootstrapMetadata {
+        let bid_year: BidYear = BidYear::new(2026);
+        let by_result = apply_bootstrap(
+            &BootstrapMetadata::new(),
+            &bid_year,
+            Command::CreateBidYear {
+                year: 2026,
+                start_date: time::Date::from_calendar_date(2026, time::Month::January, 4).unwrap(),
+                num_pay_periods: 26,
+            },
+            test_actor(operator_id),
+            test_cause(),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&by_result).unwrap();
+        let area_result = apply_bootstrap(
+            &by_result.new_metadata,
+            &bid_year,
+            Command::CreateArea {
+                area_id: String::from("North"),
+            },
+            test_actor(operator_id),
+            test_cause(),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&area_result).unwrap();
+        area_result.new_metadata
+    }
+
+    #[tokio::test]
+    async fn test_poll_once_publishes_only_new_mapped_events() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let operator_id: i64 = persistence
+            .create_operator("admin", "Admin", "password", "Admin")
+            .unwrap();
+        let metadata: BootstrapMetadata = bootstrap(&mut persistence, operator_id);
+        let mut cursor: Option<i64> = persistence.get_latest_audit_event_id().unwrap();
+
+        let result: TransitionResult = apply(
+            &metadata,
+            &State::new(BidYear::new(2026), Area::new("North")),
+            &BidYear::new(2026),
+            Command::RegisterUser {
+                initials: Initials::new("AB"),
+                name: String::from("Test User"),
+                area: Area::new("North"),
+                user_type: UserType::CPC,
+                crew: Some(Crew::new(1).unwrap()),
+                seniority_data: SeniorityData::new(
+                    String::from("2019-01-15"),
+                    String::from("2019-06-01"),
+                    String::from("2020-01-15"),
+                    String::from("2020-01-15"),
+                    Some(1),
+                ),
+            },
+            test_actor(operator_id),
+            test_cause(),
+        )
+        .unwrap();
+        persistence.persist_transition(&result).unwrap();
+
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let broadcaster: LiveEventBroadcaster = LiveEventBroadcaster::new();
+        let mut rx = broadcaster.subscribe();
+
+        let batch: FeedBatch = read_after(&persistence, cursor).await.unwrap();
+        assert_eq!(batch.events.len(), 1);
+        assert!(batch.events[0].0 > cursor.unwrap());
+        assert_eq!(batch.cursor, Some(batch.events[0].0));
+
+        let published: usize = poll_once(&persistence, &broadcaster, &mut cursor)
+            .await
+            .unwrap();
+
+        assert_eq!(published, 1);
+        match rx.try_recv() {
+            Ok(LiveEvent::UserRegistered { initials, .. }) => assert_eq!(initials, "AB"),
+            other => panic!("Expected UserRegistered, got {other:?}"),
+        }
+
+        // A second poll finds nothing new
+        let published_again: usize = poll_once(&persistence, &broadcaster, &mut cursor)
+            .await
+            .unwrap();
+        assert_eq!(published_again, 0);
+    }
+}
@@ -0,0 +1,610 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Chat channel announcements.
+//!
+//! A background task posts to the Slack or Teams incoming webhooks
+//! configured for each bid year when:
+//!
+//! - the first bid windows of a round open in an area
+//! - bidding advances to the next group of windows in an area
+//! - the readiness blockers of a bootstrapped bid year change
+//!
+//! Channels scoped to an area only receive that area's announcements; bid
+//! year channels receive every area's. Every attempt, delivered or not, is
+//! written to the chat announcement log. Window announcements are attempted
+//! at most once per channel, and readiness blockers are only posted when
+//! the text differs from the last one posted. Failed posts are not retried.
+
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+use zab_bid::{BootstrapMetadata, Clock};
+use zab_bid_api::{ApiError, GetBidYearReadinessResponse};
+use zab_bid_persistence::{
+    ActiveBidWindowData, ChatChannelData, ChatNotificationLogData, Persistence, PersistenceError,
+};
+
+use crate::shutdown::ShutdownSignal;
+
+/// How often the chat notifier checks for announcements.
+pub const CHAT_POLL_INTERVAL: Duration = Duration::from_mins(1);
+
+/// Per-request timeout for posting to a chat webhook.
+const CHAT_POST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The kinds of announcement posted to chat channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatAnnouncementKind {
+    /// The first windows of a round opened.
+    RoundOpened,
+    /// Bidding advanced to the next group of windows.
+    WindowAdvanced,
+    /// A bid year's readiness blockers changed.
+    ReadinessBlockers,
+}
+
+impl ChatAnnouncementKind {
+    /// Returns the identifier stored in the chat announcement log.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::RoundOpened => "round_opened",
+            Self::WindowAdvanced => "window_advanced",
+            Self::ReadinessBlockers => "readiness_blockers",
+        }
+    }
+}
+
+/// A window announcement due for one area.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WindowAnnouncement {
+    kind: ChatAnnouncementKind,
+    bid_year_id: i64,
+    area_id: i64,
+    reference_key: String,
+    text: String,
+}
+
+/// Builds the JSON body expected by a provider's incoming webhook.
+///
+/// Teams receives an Adaptive Card, which both Workflows and legacy
+/// connector webhooks accept.
+fn payload(provider: &str, text: &str) -> serde_json::Value {
+    if provider == ChatChannelData::PROVIDER_TEAMS {
+        json!({
+            "type": "message",
+            "attachments": [{
+                "contentType": "application/vnd.microsoft.card.adaptive",
+                "content": {
+                    "type": "AdaptiveCard",
+                    "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                    "version": "1.4",
+                    "body": [{ "type": "TextBlock", "text": text, "wrap": true }],
+                },
+            }],
+        })
+    } else {
+        json!({ "text": text })
+    }
+}
+
+/// Returns the window announcements due at `now`.
+///
+/// Windows that open at the same time in the same area and round form a
+/// group. A group is announced while any of its windows is open; groups
+/// that have already ended are never announced, so a late start does not
+/// replay the whole round. The earliest group of a round announces the
+/// round opening, later groups announce the advancement.
+///
+/// `windows` must be ordered as returned by
+/// `Persistence::list_active_bid_windows`.
+fn due_window_announcements(
+    windows: &[ActiveBidWindowData],
+    now: OffsetDateTime,
+) -> Vec<WindowAnnouncement> {
+    let mut due: Vec<WindowAnnouncement> = Vec::new();
+    let mut previous_round: Option<(i64, i64)> = None;
+
+    for group in windows.chunk_by(|a, b| {
+        a.area_id == b.area_id
+            && a.round_id == b.round_id
+            && a.window_start_datetime == b.window_start_datetime
+    }) {
+        let first: &ActiveBidWindowData = &group[0];
+        let round: (i64, i64) = (first.area_id, first.round_id);
+        let opens_round: bool = previous_round != Some(round);
+        previous_round = Some(round);
+
+        let Ok(start) = OffsetDateTime::parse(&first.window_start_datetime, &Rfc3339) else {
+            warn!(
+                bid_window_id = first.bid_window_id,
+                "Skipping bid window with unparseable datetimes"
+            );
+            continue;
+        };
+        let still_open: bool = group.iter().any(|window| {
+            OffsetDateTime::parse(&window.window_end_datetime, &Rfc3339).is_ok_and(|end| now < end)
+        });
+        if now < start || !still_open {
+            continue;
+        }
+
+        let initials: String = group
+            .iter()
+            .map(|window| window.initials.as_str())
+            .collect::<Vec<&str>>()
+            .join(", ");
+        let (kind, text) = if opens_round {
+            (
+                ChatAnnouncementKind::RoundOpened,
+                format!(
+                    "{} is open for {} ({}). Bidding now: {initials} (from {})",
+                    first.round_name, first.area_code, first.year, first.window_start_datetime
+                ),
+            )
+        } else {
+            (
+                ChatAnnouncementKind::WindowAdvanced,
+                format!(
+                    "{} {} ({}) has advanced. Bidding now: {initials} (from {})",
+                    first.area_code, first.round_name, first.year, first.window_start_datetime
+                ),
+            )
+        };
+        due.push(WindowAnnouncement {
+            kind,
+            bid_year_id: first.bid_year_id,
+            area_id: first.area_id,
+            reference_key: format!(
+                "{}:{}:{}",
+                first.area_id, first.round_id, first.window_start_datetime
+            ),
+            text,
+        });
+    }
+
+    due
+}
+
+/// Renders the readiness announcement for a bid year.
+fn readiness_text(readiness: &GetBidYearReadinessResponse) -> String {
+    if readiness.blocking_reasons.is_empty() {
+        return format!(
+            "Bid year {} has no remaining readiness blockers.",
+            readiness.year
+        );
+    }
+    let mut text: String = format!("Bid year {} is not ready to confirm:", readiness.year);
+    for reason in &readiness.blocking_reasons {
+        text.push_str("\n- ");
+        text.push_str(reason);
+    }
+    text
+}
+
+/// Posts one announcement and reports any transport error or non-2xx status.
+async fn post_once(
+    client: &reqwest::Client,
+    channel: &ChatChannelData,
+    text: &str,
+) -> Result<(), String> {
+    let response = client
+        .post(&channel.url)
+        .timeout(CHAT_POST_TIMEOUT)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(payload(&channel.provider, text).to_string())
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("HTTP {}", response.status()))
+    }
+}
+
+/// Posts one announcement and records the attempt in the chat announcement log.
+///
+/// # Returns
+///
+/// `true` if the announcement was delivered.
+async fn post_and_record(
+    persistence: &Mutex<Persistence>,
+    client: &reqwest::Client,
+    channel: &ChatChannelData,
+    kind: ChatAnnouncementKind,
+    reference_key: &str,
+    text: &str,
+) -> Result<bool, PersistenceError> {
+    let result: Result<(), String> = post_once(client, channel, text).await;
+    if let Err(e) = &result {
+        warn!(
+            chat_channel_id = channel.chat_channel_id,
+            kind = kind.as_str(),
+            reference_key,
+            error = %e,
+            "Chat announcement failed"
+        );
+    }
+    persistence.lock().await.record_chat_notification(
+        channel.chat_channel_id,
+        kind.as_str(),
+        reference_key,
+        text,
+        result.as_ref().err().map(String::as_str),
+    )?;
+    Ok(result.is_ok())
+}
+
+/// Posts readiness blocker changes for bootstrapped bid years.
+///
+/// A bid year's first announcement is skipped when it has no blockers.
+async fn announce_readiness(
+    persistence: &Mutex<Persistence>,
+    client: &reqwest::Client,
+    channels: &[ChatChannelData],
+) -> Result<usize, PersistenceError> {
+    let mut delivered: usize = 0;
+    let bid_year_ids: BTreeSet<i64> = channels.iter().map(|c| c.bid_year_id).collect();
+
+    for bid_year_id in bid_year_ids {
+        let readiness: GetBidYearReadinessResponse = {
+            let mut persistence = persistence.lock().await;
+            if persistence.get_lifecycle_state(bid_year_id)? != "BootstrapComplete" {
+                continue;
+            }
+            let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata()?;
+            let readiness: Result<GetBidYearReadinessResponse, ApiError> =
+                zab_bid_api::get_bid_year_readiness(&mut persistence, &metadata, bid_year_id);
+            drop(persistence);
+            match readiness {
+                Ok(readiness) => readiness,
+                Err(e) => {
+                    warn!(bid_year_id, error = %e, "Failed to evaluate bid year readiness");
+                    continue;
+                }
+            }
+        };
+        let text: String = readiness_text(&readiness);
+
+        for channel in channels.iter().filter(|c| c.bid_year_id == bid_year_id) {
+            let last: Option<ChatNotificationLogData> =
+                persistence.lock().await.get_latest_chat_notification(
+                    channel.chat_channel_id,
+                    ChatAnnouncementKind::ReadinessBlockers.as_str(),
+                )?;
+            let unchanged: bool = match &last {
+                Some(last) => last.message == text,
+                None => readiness.is_ready,
+            };
+            if unchanged {
+                continue;
+            }
+            if post_and_record(
+                persistence,
+                client,
+                channel,
+                ChatAnnouncementKind::ReadinessBlockers,
+                &bid_year_id.to_string(),
+                &text,
+            )
+            .await?
+            {
+                delivered += 1;
+            }
+        }
+    }
+
+    Ok(delivered)
+}
+
+/// Posts every announcement due at `now`.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer
+/// * `client` - The HTTP client used to post announcements
+/// * `now` - The current time
+///
+/// # Returns
+///
+/// The number of announcements delivered.
+///
+/// # Errors
+///
+/// Returns an error if channels or windows cannot be read or an attempt
+/// cannot be recorded.
+pub async fn poll_once(
+    persistence: &Mutex<Persistence>,
+    client: &reqwest::Client,
+    now: OffsetDateTime,
+) -> Result<usize, PersistenceError> {
+    let channels: Vec<ChatChannelData> = persistence
+        .lock()
+        .await
+        .list_chat_channels(None)?
+        .into_iter()
+        .filter(|channel| channel.is_enabled)
+        .collect();
+    if channels.is_empty() {
+        return Ok(0);
+    }
+
+    let mut delivered: usize = 0;
+    let windows: Vec<ActiveBidWindowData> = persistence.lock().await.list_active_bid_windows()?;
+    for announcement in due_window_announcements(&windows, now) {
+        for channel in channels
+            .iter()
+            .filter(|c| c.covers(announcement.bid_year_id, announcement.area_id))
+        {
+            if persistence.lock().await.chat_notification_exists(
+                channel.chat_channel_id,
+                announcement.kind.as_str(),
+                &announcement.reference_key,
+            )? {
+                continue;
+            }
+            if post_and_record(
+                persistence,
+                client,
+                channel,
+                announcement.kind,
+                &announcement.reference_key,
+                &announcement.text,
+            )
+            .await?
+            {
+                delivered += 1;
+            }
+        }
+    }
+
+    delivered += announce_readiness(persistence, client, &channels).await?;
+
+    Ok(delivered)
+}
+
+/// Spawns the background task that posts chat announcements.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer
+/// * `client` - The HTTP client used to post announcements
+/// * `poll_interval` - How often to check for announcements
+/// * `clock` - The source of the current time
+/// * `shutdown` - Stops the task, after a final poll, when the server shuts down
+pub fn spawn(
+    persistence: Arc<Mutex<Persistence>>,
+    client: reqwest::Client,
+    poll_interval: Duration,
+    clock: Arc<dyn Clock>,
+    mut shutdown: ShutdownSignal,
+) -> tokio::task::JoinHandle<()> {
+    debug!("Starting chat notifier");
+
+    tokio::spawn(async move {
+        let mut ticker: tokio::time::Interval = tokio::time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                () = shutdown.wait() => break,
+            }
+            let now: OffsetDateTime = clock.now();
+            if let Err(e) = poll_once(&persistence, &client, now).await {
+                warn!(error = %e, "Chat notifier poll failed");
+            }
+        }
+        // Post announcements for anything committed before the server stopped
+        let now: OffsetDateTime = clock.now();
+        if let Err(e) = poll_once(&persistence, &client, now).await {
+            warn!(error = %e, "Final chat notifier poll failed");
+        }
+        debug!("Chat notifier stopped");
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use axum::{Json, Router, routing::post};
+    use time::macros::datetime;
+    use zab_bid::{Command, apply_bootstrap};
+    use zab_bid_audit::{Actor, Cause};
+    use zab_bid_domain::BidYear;
+
+    fn window(
+        bid_window_id: i64,
+        round_id: i64,
+        initials: &str,
+        start: &str,
+        end: &str,
+    ) -> ActiveBidWindowData {
+        ActiveBidWindowData {
+            bid_window_id,
+            bid_year_id: 1,
+            year: 2026,
+            area_id: 1,
+            area_code: String::from("NORTH"),
+            round_id,
+            round_number: i32::try_from(round_id).unwrap(),
+            round_name: format!("Round {round_id}"),
+            initials: initials.to_string(),
+            window_start_datetime: start.to_string(),
+            window_end_datetime: end.to_string(),
+        }
+    }
+
+    fn round_one() -> Vec<ActiveBidWindowData> {
+        vec![
+            window(
+                1,
+                1,
+                "AB",
+                "2026-03-02T13:00:00+00:00",
+                "2026-03-02T21:00:00+00:00",
+            ),
+            window(
+                2,
+                1,
+                "CD",
+                "2026-03-03T13:00:00+00:00",
+                "2026-03-03T21:00:00+00:00",
+            ),
+            window(
+                3,
+                1,
+                "EF",
+                "2026-03-03T13:00:00+00:00",
+                "2026-03-03T21:00:00+00:00",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_first_group_opens_round() {
+        let due: Vec<WindowAnnouncement> =
+            due_window_announcements(&round_one(), datetime!(2026-03-02 14:00 UTC));
+
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].kind, ChatAnnouncementKind::RoundOpened);
+        assert_eq!(due[0].reference_key, "1:1:2026-03-02T13:00:00+00:00");
+        assert!(due[0].text.starts_with("Round 1 is open for NORTH (2026)"));
+        assert!(due[0].text.contains("Bidding now: AB"));
+    }
+
+    #[test]
+    fn test_later_group_advances_and_ended_groups_are_skipped() {
+        let due: Vec<WindowAnnouncement> =
+            due_window_announcements(&round_one(), datetime!(2026-03-03 14:00 UTC));
+
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].kind, ChatAnnouncementKind::WindowAdvanced);
+        assert!(due[0].text.contains("Bidding now: CD, EF"));
+
+        assert!(due_window_announcements(&round_one(), datetime!(2026-03-01 12:00 UTC)).is_empty());
+        assert!(due_window_announcements(&round_one(), datetime!(2026-03-04 12:00 UTC)).is_empty());
+    }
+
+    #[test]
+    fn test_payload_matches_provider() {
+        let slack: serde_json::Value = payload(ChatChannelData::PROVIDER_SLACK, "hello");
+        assert_eq!(slack, json!({ "text": "hello" }));
+
+        let teams: serde_json::Value = payload(ChatChannelData::PROVIDER_TEAMS, "hello");
+        assert_eq!(teams["type"], "message");
+        assert_eq!(
+            teams["attachments"][0]["content"]["body"][0]["text"],
+            "hello"
+        );
+    }
+
+    /// Persists a `BootstrapComplete` 2026 bid year with no bid schedule.
+    fn bootstrapped_bid_year(persistence: &mut Persistence) -> i64 {
+        let operator_id: i64 = persistence
+            .create_operator("admin", "Admin", "password", "Admin")
+            .unwrap();
+        let result = apply_bootstrap(
+            &BootstrapMetadata::new(),
+            &BidYear::new(2026),
+            Command::CreateBidYear {
+                year: 2026,
+                start_date: time::Date::from_calendar_date(2026, time::Month::January, 4).unwrap(),
+                num_pay_periods: 26,
+            },
+            Actor::with_operator(
+                String::from("admin"),
+                String::from("admin"),
+                operator_id,
+                String::from("admin"),
+                String::from("Admin"),
+            ),
+            Cause::new(String::from("test"), String::from("Test")),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&result).unwrap();
+        let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+        persistence
+            .update_lifecycle_state(bid_year_id, "BootstrapComplete")
+            .unwrap();
+        bid_year_id
+    }
+
+    #[tokio::test]
+    async fn test_readiness_blockers_posted_once() {
+        let received: Arc<Mutex<Vec<serde_json::Value>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink: Arc<Mutex<Vec<serde_json::Value>>> = Arc::clone(&received);
+        let app: Router = Router::new().route(
+            "/hook",
+            post(move |Json(body): Json<serde_json::Value>| {
+                let sink = Arc::clone(&sink);
+                async move {
+                    sink.lock().await.push(body);
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr: std::net::SocketAddr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let bid_year_id: i64 = bootstrapped_bid_year(&mut persistence);
+        let chat_channel_id: i64 = persistence
+            .create_chat_channel(bid_year_id, None, "slack", &format!("http://{addr}/hook"))
+            .unwrap();
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let client: reqwest::Client = reqwest::Client::new();
+        let now: OffsetDateTime = datetime!(2026-01-10 12:00 UTC);
+
+        assert_eq!(poll_once(&persistence, &client, now).await.unwrap(), 1);
+        assert_eq!(poll_once(&persistence, &client, now).await.unwrap(), 0);
+
+        let received = received.lock().await;
+        assert_eq!(received.len(), 1);
+        let text: &str = received[0]["text"].as_str().unwrap();
+        assert!(text.starts_with("Bid year 2026 is not ready to confirm:"));
+        drop(received);
+
+        let log: Vec<ChatNotificationLogData> = persistence
+            .lock()
+            .await
+            .list_chat_notifications(Some(chat_channel_id), 10)
+            .unwrap();
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].kind, "readiness_blockers");
+        assert!(log[0].delivered);
+    }
+
+    #[tokio::test]
+    async fn test_failed_post_is_logged_and_not_retried() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let bid_year_id: i64 = bootstrapped_bid_year(&mut persistence);
+        let chat_channel_id: i64 = persistence
+            .create_chat_channel(bid_year_id, None, "teams", "http://127.0.0.1:1/hook")
+            .unwrap();
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let client: reqwest::Client = reqwest::Client::new();
+        let now: OffsetDateTime = datetime!(2026-01-10 12:00 UTC);
+
+        assert_eq!(poll_once(&persistence, &client, now).await.unwrap(), 0);
+        assert_eq!(poll_once(&persistence, &client, now).await.unwrap(), 0);
+
+        let log: Vec<ChatNotificationLogData> = persistence
+            .lock()
+            .await
+            .list_chat_notifications(Some(chat_channel_id), 10)
+            .unwrap();
+        assert_eq!(log.len(), 1);
+        assert!(!log[0].delivered);
+        assert!(log[0].error.is_some());
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Cookie session mode for the browser frontend.
+//!
+//! In bearer mode (the default) clients send their session token in an
+//! `Authorization: Bearer <token>` header. In cookie mode the token is
+//! instead kept in an `HttpOnly` cookie that page scripts cannot read, so a
+//! cross-site scripting bug cannot exfiltrate it.
+//!
+//! A cookie is sent by the browser on its own, which opens the door to
+//! cross-site request forgery. Cookie mode therefore also issues a CSRF
+//! token in a second cookie that scripts can read, and every state-changing
+//! request must echo it back in the `X-CSRF-Token` header (the
+//! double-submit pattern). Another site can make the browser send the
+//! cookies but cannot read them to set the header. Both cookies are
+//! `SameSite=Strict` as a second line of defense.
+
+use axum::{
+    Json,
+    extract::{Request, State as AxumState},
+    http::{HeaderMap, HeaderValue, StatusCode, header::COOKIE},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tracing::warn;
+use zab_bid_persistence::SecretToken;
+
+use crate::rate_limit::{is_login_path, is_mutation_method};
+use crate::{AppState, ErrorResponse};
+
+/// Name of the cookie holding the session token.
+pub const SESSION_COOKIE: &str = "zabbid_session";
+
+/// Name of the cookie holding the CSRF token.
+pub const CSRF_COOKIE: &str = "zabbid_csrf";
+
+/// Header state-changing requests echo the CSRF token in.
+pub const CSRF_HEADER: &str = "X-CSRF-Token";
+
+/// Stand-in for the session token in a cookie-mode login response.
+///
+/// The real token is only ever in the `HttpOnly` cookie. Clients that track
+/// whether they are signed in by the token's presence keep working.
+pub const COOKIE_SESSION_MARKER: &str = "cookie";
+
+/// How clients present their session token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMode {
+    /// An `Authorization: Bearer <token>` header.
+    Bearer,
+    /// An `HttpOnly` cookie, with double-submit CSRF tokens on mutations.
+    Cookie,
+}
+
+impl AuthMode {
+    /// Returns the mode's command-line spelling.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Bearer => "bearer",
+            Self::Cookie => "cookie",
+        }
+    }
+}
+
+impl std::str::FromStr for AuthMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bearer" => Ok(Self::Bearer),
+            "cookie" => Ok(Self::Cookie),
+            _ => Err(format!(
+                "Unknown auth mode: '{s}'. Valid options: bearer, cookie"
+            )),
+        }
+    }
+}
+
+/// How sessions are presented and, in cookie mode, how cookies are set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuthConfig {
+    /// How clients present their session token.
+    pub mode: AuthMode,
+    /// Whether cookies carry the `Secure` attribute. Only turned off for
+    /// plain-HTTP development setups.
+    pub secure_cookies: bool,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            mode: AuthMode::Bearer,
+            secure_cookies: true,
+        }
+    }
+}
+
+impl AuthConfig {
+    /// Builds the `Set-Cookie` values that start a cookie session.
+    ///
+    /// Neither cookie has an expiry, so both end with the browser session;
+    /// the server still enforces the session's own expiry.
+    #[must_use]
+    pub fn session_cookies(
+        self,
+        session_token: &SecretToken,
+        csrf_token: &SecretToken,
+    ) -> Vec<String> {
+        vec![
+            self.cookie(SESSION_COOKIE, session_token.expose(), true, false),
+            self.cookie(CSRF_COOKIE, csrf_token.expose(), false, false),
+        ]
+    }
+
+    /// Builds the `Set-Cookie` values that end a cookie session.
+    #[must_use]
+    pub fn cleared_cookies(self) -> Vec<String> {
+        vec![
+            self.cookie(SESSION_COOKIE, "", true, true),
+            self.cookie(CSRF_COOKIE, "", false, true),
+        ]
+    }
+
+    fn cookie(self, name: &str, value: &str, http_only: bool, expired: bool) -> String {
+        let mut cookie: String = format!("{name}={value}; Path=/; SameSite=Strict");
+        if http_only {
+            cookie.push_str("; HttpOnly");
+        }
+        if self.secure_cookies {
+            cookie.push_str("; Secure");
+        }
+        if expired {
+            cookie.push_str("; Max-Age=0");
+        }
+        cookie
+    }
+}
+
+/// Returns the value of a request cookie, if present.
+#[must_use]
+pub fn cookie_value<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
+
+/// Appends `Set-Cookie` headers to a response.
+pub fn set_cookies(response: &mut Response, cookies: Vec<String>) {
+    for cookie in cookies {
+        if let Ok(value) = HeaderValue::from_str(&cookie) {
+            response
+                .headers_mut()
+                .append(axum::http::header::SET_COOKIE, value);
+        }
+    }
+}
+
+/// Compares two tokens in time independent of where they first differ.
+fn tokens_match(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0_u8, |diff, (x, y)| diff | (x ^ y))
+            == 0
+}
+
+/// Axum middleware enforcing double-submit CSRF tokens in cookie mode.
+///
+/// Requests that do not change state, logins, and every request in bearer
+/// mode pass through untouched.
+pub async fn csrf_middleware(
+    AxumState(app_state): AxumState<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if app_state.auth.mode != AuthMode::Cookie
+        || !is_mutation_method(request.method())
+        || is_login_path(request.uri().path())
+    {
+        return next.run(request).await;
+    }
+
+    let cookie: Option<&str> = cookie_value(request.headers(), CSRF_COOKIE);
+    let header: Option<&str> = request
+        .headers()
+        .get(CSRF_HEADER)
+        .and_then(|value| value.to_str().ok());
+    let token_matches: bool = matches!(
+        (cookie, header),
+        (Some(cookie), Some(header)) if !cookie.is_empty() && tokens_match(cookie, header)
+    );
+    if token_matches {
+        return next.run(request).await;
+    }
+
+    warn!(path = %request.uri().path(), "Rejected request without a matching CSRF token");
+    let body: Json<ErrorResponse> = Json(ErrorResponse {
+        error: true,
+        message: String::from("Missing or invalid CSRF token"),
+    });
+    (StatusCode::FORBIDDEN, body).into_response()
+}
@@ -0,0 +1,245 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Outbound email.
+//!
+//! [`Mailer`] abstracts message delivery so the notifier can be exercised
+//! without a mail server. [`SmtpMailer`] is the production implementation;
+//! it sends a message with attachments as `multipart/mixed`.
+//! Email is disabled unless `--smtp-host` is given.
+
+use lettre::message::header::ContentType;
+use lettre::message::{Attachment, Mailbox, MultiPart, SinglePart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::future::Future;
+
+/// A rendered plain-text email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    /// The recipient address.
+    pub to: String,
+    /// The subject line.
+    pub subject: String,
+    /// The plain-text body.
+    pub body: String,
+    /// Files attached after the body.
+    pub attachments: Vec<EmailAttachment>,
+}
+
+/// A file attached to an email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailAttachment {
+    /// The file name shown to the recipient.
+    pub filename: String,
+    /// The MIME type, e.g. `text/csv; charset=utf-8`.
+    pub content_type: String,
+    /// The file contents.
+    pub body: Vec<u8>,
+}
+
+/// Delivers email messages.
+pub trait Mailer: Send + Sync {
+    /// Sends one message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the failure if the message was not accepted.
+    fn send(&self, message: &EmailMessage) -> impl Future<Output = Result<(), String>> + Send;
+}
+
+/// SMTP connection settings.
+#[derive(clap::Args, Debug, Clone)]
+#[allow(clippy::struct_field_names)]
+pub struct SmtpArgs {
+    /// SMTP relay host. Email notifications are disabled when omitted.
+    #[arg(long)]
+    pub smtp_host: Option<String>,
+
+    /// SMTP relay port
+    #[arg(long, default_value_t = 587)]
+    pub smtp_port: u16,
+
+    /// SMTP username (optional)
+    #[arg(long)]
+    pub smtp_username: Option<String>,
+
+    /// SMTP password (required with --smtp-username)
+    #[arg(long)]
+    pub smtp_password: Option<String>,
+
+    /// Sender address for notifications, e.g. `Zabbid <bids@example.org>`
+    #[arg(long)]
+    pub smtp_from: Option<String>,
+
+    /// Connect without STARTTLS (for a local relay only)
+    #[arg(long, default_value_t = false)]
+    pub smtp_insecure: bool,
+}
+
+impl Default for SmtpArgs {
+    fn default() -> Self {
+        Self {
+            smtp_host: None,
+            smtp_port: 587,
+            smtp_username: None,
+            smtp_password: None,
+            smtp_from: None,
+            smtp_insecure: false,
+        }
+    }
+}
+
+impl SmtpArgs {
+    /// Validates the SMTP settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a host is configured without a valid sender, or
+    /// if only one of username and password is given.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.smtp_host.is_none() {
+            return Ok(());
+        }
+        let from: &str = self
+            .smtp_from
+            .as_deref()
+            .ok_or_else(|| String::from("--smtp-host requires --smtp-from"))?;
+        from.parse::<Mailbox>()
+            .map_err(|e| format!("Invalid --smtp-from address '{from}': {e}"))?;
+        if self.smtp_username.is_some() != self.smtp_password.is_some() {
+            return Err(String::from(
+                "--smtp-username and --smtp-password must be given together",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Sends email through an SMTP relay.
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    /// Builds a mailer from validated settings.
+    ///
+    /// # Returns
+    ///
+    /// `None` if no SMTP host is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the settings are invalid or the TLS transport
+    /// cannot be created.
+    pub fn from_args(args: &SmtpArgs) -> Result<Option<Self>, String> {
+        args.validate()?;
+        let Some(host) = args.smtp_host.as_deref() else {
+            return Ok(None);
+        };
+        let from: Mailbox = args
+            .smtp_from
+            .as_deref()
+            .unwrap_or_default()
+            .parse()
+            .map_err(|e| format!("Invalid --smtp-from address: {e}"))?;
+
+        let mut builder = if args.smtp_insecure {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+                .map_err(|e| format!("Failed to configure SMTP relay '{host}': {e}"))?
+        }
+        .port(args.smtp_port);
+        if let (Some(username), Some(password)) = (&args.smtp_username, &args.smtp_password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Some(Self {
+            transport: builder.build(),
+            from,
+        }))
+    }
+}
+
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: &EmailMessage) -> Result<(), String> {
+        let to: Mailbox = message
+            .to
+            .parse()
+            .map_err(|e| format!("Invalid recipient '{}': {e}", message.to))?;
+        let builder = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(message.subject.clone());
+        let email: Message = if message.attachments.is_empty() {
+            builder
+                .header(ContentType::TEXT_PLAIN)
+                .body(message.body.clone())
+        } else {
+            let mut parts: MultiPart =
+                MultiPart::mixed().singlepart(SinglePart::plain(message.body.clone()));
+            for attachment in &message.attachments {
+                let content_type: ContentType = ContentType::parse(&attachment.content_type)
+                    .map_err(|e| {
+                        format!("Invalid attachment type '{}': {e}", attachment.content_type)
+                    })?;
+                parts = parts.singlepart(
+                    Attachment::new(attachment.filename.clone())
+                        .body(attachment.body.clone(), content_type),
+                );
+            }
+            builder.multipart(parts)
+        }
+        .map_err(|e| format!("Failed to build message: {e}"))?;
+
+        self.transport
+            .send(email)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configured() -> SmtpArgs {
+        SmtpArgs {
+            smtp_host: Some(String::from("smtp.example.test")),
+            smtp_from: Some(String::from("Zabbid <bids@example.test>")),
+            ..SmtpArgs::default()
+        }
+    }
+
+    #[test]
+    fn test_unconfigured_smtp_is_valid_and_disabled() {
+        assert!(SmtpArgs::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_smtp_host_requires_valid_sender() {
+        let mut args: SmtpArgs = configured();
+        assert!(args.validate().is_ok());
+
+        args.smtp_from = None;
+        assert!(args.validate().is_err());
+
+        args.smtp_from = Some(String::from("not an address"));
+        assert!(args.validate().is_err());
+    }
+
+    #[test]
+    fn test_smtp_credentials_must_be_paired() {
+        let mut args: SmtpArgs = configured();
+        args.smtp_username = Some(String::from("zabbid"));
+        assert!(args.validate().is_err());
+
+        args.smtp_password = Some(String::from("secret"));
+        assert!(args.validate().is_ok());
+    }
+}
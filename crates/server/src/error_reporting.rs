@@ -0,0 +1,391 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Error reporting to Sentry.
+//!
+//! When `--sentry-dsn` is given, the server reports to Sentry, or any
+//! service accepting Sentry's protocol such as `GlitchTip`:
+//!
+//! - panics, in request handlers and background tasks alike
+//! - requests answered with a 5xx status, which is how persistence errors
+//!   and internal API errors reach clients
+//! - failed background job runs
+//!
+//! Every request carries a correlation ID: the `X-Request-Id` header it
+//! arrived with, or a new ULID. The ID is returned in the response's
+//! `X-Request-Id` header, recorded on the request's log lines, and tagged
+//! on anything reported while handling it, so a failure a facility
+//! describes can be found in both the logs and the report.
+//!
+//! Reports carry the method, path, and status of the request and the error
+//! message, with email addresses and quoted values scrubbed from the
+//! message. Request bodies, headers, query strings, and client addresses
+//! are never sent.
+
+use axum::extract::Request;
+use axum::http::{HeaderName, HeaderValue, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+use sentry::protocol::Event;
+use sentry::types::Dsn;
+use sentry::{ClientInitGuard, ClientOptions, Hub, Level, SentryFutureExt};
+use std::borrow::Cow;
+use std::sync::Arc;
+use tracing::Instrument;
+use zab_bid_audit::Ulid;
+
+/// Header carrying a request's correlation ID.
+pub const CORRELATION_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest correlation ID accepted from a client.
+const MAX_CORRELATION_ID_LEN: usize = 64;
+
+/// Replaces scrubbed email addresses.
+const SCRUBBED_EMAIL: &str = "[email]";
+
+/// Replaces scrubbed quoted values.
+const SCRUBBED_VALUE: &str = "[redacted]";
+
+/// Error reporting settings.
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct SentryArgs {
+    /// Sentry DSN failures are reported to. Error reporting is disabled
+    /// when omitted.
+    #[arg(long)]
+    pub sentry_dsn: Option<String>,
+
+    /// Environment reports are filed under, e.g. `production`
+    #[arg(long)]
+    pub sentry_environment: Option<String>,
+}
+
+impl SentryArgs {
+    /// Validates the error reporting settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the DSN cannot be parsed, or an environment is
+    /// given without a DSN.
+    pub fn validate(&self) -> Result<(), String> {
+        self.dsn().map(|_| ())
+    }
+
+    /// Returns the parsed DSN, or `None` if reporting is disabled.
+    fn dsn(&self) -> Result<Option<Dsn>, String> {
+        let Some(dsn) = &self.sentry_dsn else {
+            if self.sentry_environment.is_some() {
+                return Err(String::from("--sentry-environment requires --sentry-dsn"));
+            }
+            return Ok(None);
+        };
+        dsn.parse::<Dsn>()
+            .map(Some)
+            .map_err(|e| format!("Invalid --sentry-dsn: {e}"))
+    }
+
+    /// Returns the client options reports are sent with.
+    fn options(&self, dsn: Option<Dsn>) -> ClientOptions {
+        ClientOptions {
+            dsn,
+            release: sentry::release_name!(),
+            environment: self.sentry_environment.clone().map(Cow::Owned),
+            send_default_pii: false,
+            before_send: Some(Arc::new(|event: Event<'static>| Some(scrub_event(event)))),
+            ..ClientOptions::default()
+        }
+    }
+}
+
+/// Starts error reporting, if a DSN is configured.
+///
+/// Reports still queued are sent when the returned guard is dropped, so
+/// it must be held until the server exits.
+///
+/// # Errors
+///
+/// Returns an error if the settings are invalid.
+pub fn init(args: &SentryArgs) -> Result<Option<ClientInitGuard>, String> {
+    let Some(dsn) = args.dsn()? else {
+        return Ok(None);
+    };
+    Ok(Some(sentry::init(args.options(Some(dsn)))))
+}
+
+/// A request's correlation ID, attached to the request.
+#[derive(Debug, Clone)]
+pub struct CorrelationId(pub String);
+
+/// The message of a 5xx response, attached to the response for reporting.
+#[derive(Debug, Clone)]
+pub struct ServerError(pub String);
+
+/// Reports a failed background job run.
+pub fn report_job_failure(job_name: &str, error: &str) {
+    sentry::with_scope(
+        |scope| scope.set_tag("job", job_name),
+        || sentry::capture_message(&format!("Job {job_name} failed: {error}"), Level::Error),
+    );
+}
+
+/// Returns whether a client's correlation ID can be used as given.
+fn is_valid_correlation_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_CORRELATION_ID_LEN
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// Assigns each request a correlation ID and reports its server errors.
+///
+/// Anything reported while the request is handled, including a panic, is
+/// tagged with the correlation ID.
+pub async fn correlation_middleware(mut request: Request, next: Next) -> Response {
+    let correlation_id: String = request
+        .headers()
+        .get(&CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| is_valid_correlation_id(id))
+        .map_or_else(|| Ulid::new().to_string(), str::to_string);
+    let method: String = request.method().to_string();
+    let path: String = request.uri().path().to_string();
+    request
+        .extensions_mut()
+        .insert(CorrelationId(correlation_id.clone()));
+
+    let hub: Arc<Hub> = Arc::new(Hub::new_from_top(Hub::current()));
+    hub.configure_scope(|scope| scope.set_tag("correlation_id", &correlation_id));
+    let span: tracing::Span = tracing::info_span!("request", correlation_id = %correlation_id);
+
+    let mut response: Response = next
+        .run(request)
+        .bind_hub(Arc::clone(&hub))
+        .instrument(span)
+        .await;
+
+    let status: StatusCode = response.status();
+    if status.is_server_error() {
+        let message: String = response.extensions().get::<ServerError>().map_or_else(
+            || format!("{method} {path} returned {status}"),
+            |error| error.0.clone(),
+        );
+        hub.with_scope(
+            |scope| {
+                scope.set_tag("http.method", &method);
+                scope.set_tag("http.path", &path);
+                scope.set_tag("http.status_code", status.as_u16());
+            },
+            || hub.capture_message(&message, Level::Error),
+        );
+    }
+
+    if let Ok(value) = HeaderValue::from_str(&correlation_id) {
+        response.headers_mut().insert(CORRELATION_ID_HEADER, value);
+    }
+    response
+}
+
+/// Scrubs the messages of an event before it is sent.
+fn scrub_event(mut event: Event<'static>) -> Event<'static> {
+    event.message = event.message.as_deref().map(scrub);
+    if let Some(logentry) = &mut event.logentry {
+        logentry.message = scrub(&logentry.message);
+        logentry.params.clear();
+    }
+    for exception in &mut event.exception.values {
+        exception.value = exception.value.as_deref().map(scrub);
+    }
+    event.request = None;
+    event.user = None;
+    event
+}
+
+/// Scrubs email addresses and quoted values from a message.
+///
+/// Error messages quote the values they concern, such as `User 'AB' not
+/// found`; the quotes are kept so the shape of the message survives.
+fn scrub(message: &str) -> String {
+    let mut scrubbed: String = String::with_capacity(message.len());
+    let mut rest: &str = message;
+    while let Some(start) = rest.find(['\'', '"']) {
+        let quote: char = rest[start..].chars().next().unwrap_or('\'');
+        let opens: bool = rest[..start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric());
+        let close: Option<usize> = rest[start + 1..].find(quote);
+        match close {
+            Some(len) if opens => {
+                scrubbed.push_str(&rest[..start]);
+                scrubbed.push(quote);
+                scrubbed.push_str(SCRUBBED_VALUE);
+                scrubbed.push(quote);
+                rest = &rest[start + 1 + len + 1..];
+            }
+            _ => {
+                scrubbed.push_str(&rest[..=start]);
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+    scrubbed.push_str(rest);
+
+    scrubbed
+        .split(' ')
+        .map(|word| if is_email(word) { SCRUBBED_EMAIL } else { word })
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Returns whether a word of a message is an email address.
+fn is_email(word: &str) -> bool {
+    word.split_once('@').is_some_and(|(local, domain)| {
+        !local.is_empty()
+            && domain
+                .trim_end_matches(['.', ',', ';', ':', ')'])
+                .contains('.')
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::HttpError;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::routing::get;
+    use sentry::protocol::Value;
+    use tower::ServiceExt;
+
+    fn args(dsn: Option<&str>, environment: Option<&str>) -> SentryArgs {
+        SentryArgs {
+            sentry_dsn: dsn.map(str::to_string),
+            sentry_environment: environment.map(str::to_string),
+        }
+    }
+
+    /// Sends a request through the middleware, returning the response and
+    /// the events reported while handling it.
+    fn send(request: Request<Body>) -> (Response, Vec<Event<'static>>) {
+        let app: Router = Router::new()
+            .route("/ok", get(|| async { "ok" }))
+            .route(
+                "/fails",
+                get(|| async {
+                    Err::<(), HttpError>(HttpError {
+                        status: StatusCode::INTERNAL_SERVER_ERROR,
+                        message: String::from(
+                            "Persistence error: no contact for 'AB' (ab@example.org)",
+                        ),
+                    })
+                }),
+            )
+            .layer(axum::middleware::from_fn(correlation_middleware));
+
+        let mut response: Option<Response> = None;
+        let events: Vec<Event<'static>> = sentry::test::with_captured_events_options(
+            || {
+                let runtime: tokio::runtime::Runtime =
+                    tokio::runtime::Builder::new_current_thread()
+                        .build()
+                        .unwrap();
+                response = Some(runtime.block_on(app.oneshot(request)).unwrap());
+            },
+            args(None, None).options(None),
+        );
+        (response.unwrap(), events)
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(args(None, None).validate().is_ok());
+        assert!(
+            args(
+                Some("https://public@sentry.example.org/42"),
+                Some("production")
+            )
+            .validate()
+            .is_ok()
+        );
+        assert!(args(Some("not a dsn"), None).validate().is_err());
+        assert_eq!(
+            args(None, Some("production")).validate(),
+            Err(String::from("--sentry-environment requires --sentry-dsn"))
+        );
+    }
+
+    #[test]
+    fn test_scrub_removes_emails_and_quoted_values() {
+        assert_eq!(
+            scrub("User 'AB' has no contact \"Alice Baker\" at alice@example.org."),
+            "User '[redacted]' has no contact \"[redacted]\" at [email]"
+        );
+        // Apostrophes within words are not quotes
+        assert_eq!(
+            scrub("Can't read the bid year's rounds"),
+            "Can't read the bid year's rounds"
+        );
+        assert_eq!(
+            scrub("UNIQUE constraint failed: users.initials"),
+            "UNIQUE constraint failed: users.initials"
+        );
+    }
+
+    #[test]
+    fn test_server_errors_are_reported_with_correlation_id() {
+        let request: Request<Body> = Request::builder()
+            .uri("/fails?email=ab@example.org")
+            .header(&CORRELATION_ID_HEADER, "facility-ticket-17")
+            .body(Body::empty())
+            .unwrap();
+
+        let (response, events) = send(request);
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(
+            response.headers().get(&CORRELATION_ID_HEADER).unwrap(),
+            "facility-ticket-17"
+        );
+        assert_eq!(events.len(), 1);
+        let event: &Event<'static> = &events[0];
+        assert_eq!(event.level, Level::Error);
+        assert_eq!(
+            event.message.as_deref(),
+            Some("Persistence error: no contact for '[redacted]' [email]")
+        );
+        assert_eq!(event.tags["correlation_id"], "facility-ticket-17");
+        assert_eq!(event.tags["http.path"], "/fails");
+        assert_eq!(event.tags["http.status_code"], "500");
+        assert!(event.request.is_none());
+        assert!(
+            !event
+                .extra
+                .values()
+                .any(|value: &Value| { value.to_string().contains("example.org") })
+        );
+    }
+
+    #[test]
+    fn test_successful_requests_get_a_correlation_id_and_no_report() {
+        let request: Request<Body> = Request::builder()
+            .uri("/ok")
+            .header(&CORRELATION_ID_HEADER, "not valid!")
+            .body(Body::empty())
+            .unwrap();
+
+        let (response, events) = send(request);
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let correlation_id: &str = response
+            .headers()
+            .get(&CORRELATION_ID_HEADER)
+            .unwrap()
+            .to_str()
+            .unwrap();
+        assert!(correlation_id.parse::<Ulid>().is_ok());
+        assert!(events.is_empty());
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Message broker publishing.
+//!
+//! When `--nats-url` is given, the outbox relay publishes every audit event
+//! to a NATS subject for the facility, so data warehouses can consume
+//! bidding activity without polling the HTTP API:
+//!
+//! ```text
+//! zabbid.<facility>.events
+//! ```
+//!
+//! Messages carry the same JSON body as webhook deliveries, with headers:
+//!
+//! ```text
+//! Nats-Msg-Id: <audit event ULID, or id when the event has none>
+//! X-Zabbid-Event-Id: <audit event id>
+//! ```
+//!
+//! `Nats-Msg-Id` lets a `JetStream` stream drop the duplicates that
+//! at-least-once delivery can produce. Events are published in outbox
+//! order; the relay stops at the first failure and retries from that event
+//! on its next poll.
+
+use async_nats::HeaderMap;
+use futures::future::BoxFuture;
+use std::time::Duration;
+use tracing::info;
+
+use crate::webhook_delivery::{EVENT_ID_HEADER, WebhookPayload};
+
+/// How long a publish may take, including the flush to the server.
+const PUBLISH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Header `JetStream` uses to deduplicate messages.
+const MESSAGE_ID_HEADER: &str = "Nats-Msg-Id";
+
+/// Publishes serialized audit events to a message broker.
+pub trait EventPublisher: Send + Sync {
+    /// Publishes one event.
+    ///
+    /// `body` is `payload` serialized as JSON.
+    ///
+    /// Returns a description of the failure if the broker did not accept
+    /// the message.
+    fn publish<'a>(
+        &'a self,
+        payload: &'a WebhookPayload,
+        body: &'a str,
+    ) -> BoxFuture<'a, Result<(), String>>;
+}
+
+/// NATS connection settings.
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct BrokerArgs {
+    /// NATS server URL, e.g. `nats://nats.example.org:4222`. Audit events
+    /// are not published to a broker when omitted.
+    #[arg(long)]
+    pub nats_url: Option<String>,
+
+    /// Facility identifier used in the subject, e.g. `ZAB` publishes to
+    /// `zabbid.ZAB.events` (required with --nats-url)
+    #[arg(long)]
+    pub nats_facility: Option<String>,
+}
+
+impl BrokerArgs {
+    /// Validates the broker settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a URL is configured without a facility, or the
+    /// facility is not a single subject token.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.nats_url.is_none() {
+            return Ok(());
+        }
+        let facility: &str = self
+            .nats_facility
+            .as_deref()
+            .ok_or_else(|| String::from("--nats-url requires --nats-facility"))?;
+        let valid: bool = !facility.is_empty()
+            && facility
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(format!(
+                "Invalid --nats-facility '{facility}': use letters, digits, '-' and '_' only"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the subject events are published to.
+    fn subject(&self) -> Option<String> {
+        self.nats_facility
+            .as_deref()
+            .map(|facility| format!("zabbid.{facility}.events"))
+    }
+}
+
+/// Publishes events to a NATS subject.
+pub struct NatsPublisher {
+    client: async_nats::Client,
+    subject: String,
+}
+
+impl NatsPublisher {
+    /// Connects to the configured NATS server.
+    ///
+    /// The server starts even if NATS is unreachable; the client keeps
+    /// reconnecting and the relay retries undelivered events.
+    ///
+    /// # Returns
+    ///
+    /// `None` if no NATS URL is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the settings are invalid or the URL cannot be
+    /// parsed.
+    pub async fn from_args(args: &BrokerArgs) -> Result<Option<Self>, String> {
+        args.validate()?;
+        let (Some(url), Some(subject)) = (args.nats_url.as_deref(), args.subject()) else {
+            return Ok(None);
+        };
+        let client: async_nats::Client = async_nats::ConnectOptions::new()
+            .retry_on_initial_connect()
+            .connect(url)
+            .await
+            .map_err(|e| format!("Failed to configure NATS connection '{url}': {e}"))?;
+        info!(subject, "Publishing audit events to NATS");
+
+        Ok(Some(Self { client, subject }))
+    }
+
+    /// Publishes and waits for the server to receive the message.
+    async fn publish_and_flush(&self, payload: &WebhookPayload, body: &str) -> Result<(), String> {
+        let mut headers: HeaderMap = HeaderMap::new();
+        headers.insert(
+            MESSAGE_ID_HEADER,
+            payload
+                .event_ulid
+                .clone()
+                .unwrap_or_else(|| payload.event_id.to_string())
+                .as_str(),
+        );
+        headers.insert(EVENT_ID_HEADER, payload.event_id.to_string().as_str());
+
+        self.client
+            .publish_with_headers(self.subject.clone(), headers, body.to_string().into())
+            .await
+            .map_err(|e| e.to_string())?;
+        self.client.flush().await.map_err(|e| e.to_string())
+    }
+}
+
+impl EventPublisher for NatsPublisher {
+    fn publish<'a>(
+        &'a self,
+        payload: &'a WebhookPayload,
+        body: &'a str,
+    ) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async move {
+            tokio::time::timeout(PUBLISH_TIMEOUT, self.publish_and_flush(payload, body))
+                .await
+                .map_err(|_| String::from("Timed out publishing to NATS"))?
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configured() -> BrokerArgs {
+        BrokerArgs {
+            nats_url: Some(String::from("nats://127.0.0.1:4222")),
+            nats_facility: Some(String::from("ZAB")),
+        }
+    }
+
+    #[test]
+    fn test_unconfigured_broker_is_valid_and_disabled() {
+        assert!(BrokerArgs::default().validate().is_ok());
+        assert_eq!(BrokerArgs::default().subject(), None);
+    }
+
+    #[test]
+    fn test_subject_is_per_facility() {
+        assert_eq!(configured().subject().as_deref(), Some("zabbid.ZAB.events"));
+    }
+
+    #[test]
+    fn test_nats_url_requires_single_token_facility() {
+        let mut args: BrokerArgs = configured();
+        assert!(args.validate().is_ok());
+
+        args.nats_facility = None;
+        assert!(args.validate().is_err());
+
+        args.nats_facility = Some(String::from("ZAB.north"));
+        assert!(args.validate().is_err());
+
+        args.nats_facility = Some(String::from("ZAB *"));
+        assert!(args.validate().is_err());
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Deployment monitoring endpoints.
+//!
+//! - `/healthz` — liveness: the process is up and serving requests
+//! - `/readyz` — readiness: the database is reachable, fully migrated, and
+//!   enforcing foreign keys
+//! - `/version` — build information and the current schema version
+//!
+//! None of these endpoints require authentication or emit audit events.
+
+use axum::{Json, Router, extract::State as AxumState, http::StatusCode, routing::get};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::warn;
+use zab_bid_persistence::{Persistence, PersistenceHealth};
+
+/// The short git commit the server was built from.
+const GIT_SHA: &str = env!("ZABBID_GIT_SHA");
+
+/// Unix timestamp (seconds) of the build.
+const BUILD_TIMESTAMP: &str = env!("ZABBID_BUILD_TIMESTAMP");
+
+/// Readiness probe response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadinessResponse {
+    /// Whether every check passed.
+    pub ready: bool,
+    /// The database backend in use.
+    pub backend: String,
+    /// Whether a trivial query succeeded.
+    pub database_reachable: bool,
+    /// Number of migrations not yet applied, if known.
+    pub pending_migrations: Option<usize>,
+    /// Whether foreign key enforcement is active.
+    pub foreign_keys_enforced: bool,
+    /// The latest applied migration version.
+    pub schema_version: Option<String>,
+    /// Messages from failed checks.
+    pub errors: Vec<String>,
+}
+
+impl From<PersistenceHealth> for ReadinessResponse {
+    fn from(health: PersistenceHealth) -> Self {
+        Self {
+            ready: health.is_ready(),
+            backend: health.backend.to_string(),
+            database_reachable: health.database_reachable,
+            pending_migrations: health.migrations.as_ref().map(|status| status.pending),
+            foreign_keys_enforced: health.foreign_keys_enforced,
+            schema_version: health.schema_version().map(str::to_string),
+            errors: health.errors,
+        }
+    }
+}
+
+/// Build information response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionResponse {
+    /// The crate version.
+    pub version: String,
+    /// The short git commit, or `unknown`.
+    pub git_sha: String,
+    /// The build time (RFC 3339), or `unknown`.
+    pub build_time: String,
+    /// The latest applied migration version, if it could be read.
+    pub schema_version: Option<String>,
+}
+
+/// Formats the embedded build timestamp as RFC 3339.
+fn build_time() -> String {
+    BUILD_TIMESTAMP
+        .parse::<i64>()
+        .ok()
+        .and_then(|seconds| time::OffsetDateTime::from_unix_timestamp(seconds).ok())
+        .and_then(|instant| {
+            instant
+                .format(&time::format_description::well_known::Rfc3339)
+                .ok()
+        })
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+/// Liveness probe. Succeeds whenever the server can answer requests.
+async fn handle_healthz() -> (StatusCode, &'static str) {
+    (StatusCode::OK, "ok\n")
+}
+
+/// Readiness probe. Returns 503 until the database passes every check.
+async fn handle_readyz(
+    AxumState(persistence): AxumState<Arc<Mutex<Persistence>>>,
+) -> (StatusCode, Json<ReadinessResponse>) {
+    let health: PersistenceHealth = persistence.lock().await.health();
+    let response: ReadinessResponse = ReadinessResponse::from(health);
+
+    if response.ready {
+        (StatusCode::OK, Json(response))
+    } else {
+        warn!(errors = ?response.errors, "Readiness check failed");
+        (StatusCode::SERVICE_UNAVAILABLE, Json(response))
+    }
+}
+
+/// Build information, including the schema version of the connected database.
+async fn handle_version(
+    AxumState(persistence): AxumState<Arc<Mutex<Persistence>>>,
+) -> Json<VersionResponse> {
+    let health: PersistenceHealth = persistence.lock().await.health();
+
+    Json(VersionResponse {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_sha: GIT_SHA.to_string(),
+        build_time: build_time(),
+        schema_version: health.schema_version().map(str::to_string),
+    })
+}
+
+/// Builds the router for the monitoring endpoints.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer to probe
+pub fn router(persistence: Arc<Mutex<Persistence>>) -> Router {
+    Router::new()
+        .route("/healthz", get(handle_healthz))
+        .route("/readyz", get(handle_readyz))
+        .route("/version", get(handle_version))
+        .with_state(persistence)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn test_router() -> Router {
+        let persistence: Persistence = Persistence::new_in_memory().unwrap();
+        router(Arc::new(Mutex::new(persistence)))
+    }
+
+    async fn get_body(uri: &str) -> (StatusCode, Vec<u8>) {
+        let response = test_router()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status: StatusCode = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, body.to_vec())
+    }
+
+    #[tokio::test]
+    async fn test_healthz_is_ok() {
+        let (status, body) = get_body("/healthz").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, b"ok\n");
+    }
+
+    #[tokio::test]
+    async fn test_readyz_reports_ready_database() {
+        let (status, body) = get_body("/readyz").await;
+        assert_eq!(status, StatusCode::OK);
+        let response: ReadinessResponse = serde_json::from_slice(&body).unwrap();
+        assert!(response.ready);
+        assert_eq!(response.backend, "sqlite");
+        assert_eq!(response.pending_migrations, Some(0));
+        assert!(response.foreign_keys_enforced);
+        assert!(response.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_version_includes_build_and_schema_info() {
+        let (status, body) = get_body("/version").await;
+        assert_eq!(status, StatusCode::OK);
+        let response: VersionResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response.version, env!("CARGO_PKG_VERSION"));
+        assert!(!response.git_sha.is_empty());
+        assert!(!response.build_time.is_empty());
+        assert!(response.schema_version.is_some());
+    }
+
+    #[test]
+    fn test_readiness_response_from_unhealthy_report() {
+        let health: PersistenceHealth = PersistenceHealth {
+            backend: "sqlite",
+            database_reachable: true,
+            migrations: None,
+            foreign_keys_enforced: true,
+            errors: vec![String::from("migrations: unreadable")],
+        };
+        let response: ReadinessResponse = ReadinessResponse::from(health);
+        assert!(!response.ready);
+        assert_eq!(response.pending_migrations, None);
+        assert_eq!(response.errors.len(), 1);
+    }
+}
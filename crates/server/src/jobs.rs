@@ -0,0 +1,661 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Background job runner.
+//!
+//! Periodic server work is written as a [`Job`] and registered with a
+//! [`JobRunner`] together with its [`JobSchedule`]. Each job runs in its
+//! own task. Before every run the task waits for the job's interval plus a
+//! random jitter, so jobs that share an interval do not all contend for the
+//! persistence lock at the same moment.
+//!
+//! Every run updates the job's in-process [`JobStats`] and replaces its row
+//! in the `job_runs` table. `GET /jobs` reports both, so operators can see
+//! when each job last ran and whether it succeeded, even across restarts.
+//! A failed run is logged and retried on the next tick; it never stops the
+//! job.
+
+use axum::{Json, Router, extract::State as AxumState, http::StatusCode, routing::get};
+use futures::future::BoxFuture;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+use zab_bid::{BootstrapMetadata, Clock};
+use zab_bid_api::ProjectionRefresh;
+use zab_bid_persistence::{JobRunData, Persistence, PersistenceError};
+
+use crate::error_reporting;
+use crate::logging;
+use crate::shutdown::ShutdownSignal;
+
+/// How often expired sessions are deleted.
+pub const EXPIRED_SESSIONS_INTERVAL: Duration = Duration::from_mins(15);
+
+/// How often the dashboard projections catch up with the audit log.
+pub const DASHBOARD_PROJECTION_INTERVAL: Duration = Duration::from_secs(10);
+
+/// A unit of periodic server work.
+pub trait Job: Send + Sync + 'static {
+    /// The job's stable name, used in logs, metrics, and `job_runs`.
+    fn name(&self) -> &'static str;
+
+    /// Runs the job once.
+    ///
+    /// Returns a short summary of the work done, or an error message.
+    fn run<'a>(
+        &'a self,
+        persistence: &'a Mutex<Persistence>,
+        now: OffsetDateTime,
+    ) -> BoxFuture<'a, Result<String, String>>;
+}
+
+/// When a job runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobSchedule {
+    /// The time between runs.
+    pub interval: Duration,
+    /// The most extra time to wait before a run, chosen at random each
+    /// time.
+    pub jitter: Duration,
+}
+
+impl JobSchedule {
+    /// Creates a schedule with up to a tenth of the interval as jitter.
+    #[must_use]
+    pub fn every(interval: Duration) -> Self {
+        Self {
+            interval,
+            jitter: interval / 10,
+        }
+    }
+
+    /// Returns how long to wait before the next run.
+    fn next_delay(&self) -> Duration {
+        if self.jitter.is_zero() {
+            return self.interval;
+        }
+        self.interval + rand::rng().random_range(Duration::ZERO..=self.jitter)
+    }
+}
+
+/// A job's run statistics since the server started.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobStats {
+    /// Completed runs, including failed ones.
+    pub runs: u64,
+    /// Runs that returned an error.
+    pub failures: u64,
+    /// Duration of the latest run in milliseconds.
+    pub last_duration_ms: u64,
+    /// Duration of the slowest run in milliseconds.
+    pub max_duration_ms: u64,
+    /// Combined duration of every run in milliseconds.
+    pub total_duration_ms: u64,
+}
+
+/// In-process metrics for every registered job.
+#[derive(Debug, Default)]
+pub struct JobMetrics {
+    jobs: std::sync::Mutex<BTreeMap<&'static str, (JobSchedule, JobStats)>>,
+}
+
+impl JobMetrics {
+    /// Creates an empty metrics registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Locks the registry, recovering it if a panicking thread poisoned it.
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<&'static str, (JobSchedule, JobStats)>> {
+        self.jobs
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Adds a job with empty statistics.
+    fn register(&self, name: &'static str, schedule: JobSchedule) {
+        self.lock().insert(name, (schedule, JobStats::default()));
+    }
+
+    /// Records a completed run of a registered job.
+    fn record(&self, name: &'static str, succeeded: bool, duration: Duration) {
+        let duration_ms: u64 = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+        if let Some((_, stats)) = self.lock().get_mut(name) {
+            stats.runs += 1;
+            if !succeeded {
+                stats.failures += 1;
+            }
+            stats.last_duration_ms = duration_ms;
+            stats.max_duration_ms = stats.max_duration_ms.max(duration_ms);
+            stats.total_duration_ms = stats.total_duration_ms.saturating_add(duration_ms);
+        }
+    }
+
+    /// Returns every registered job's schedule and statistics, by name.
+    #[must_use]
+    pub fn snapshot(&self) -> BTreeMap<&'static str, (JobSchedule, JobStats)> {
+        self.lock().clone()
+    }
+}
+
+/// Formats a run timestamp as RFC 3339.
+fn format_timestamp(instant: OffsetDateTime) -> String {
+    instant
+        .format(&Rfc3339)
+        .unwrap_or_else(|_| instant.to_string())
+}
+
+/// Runs a job once, recording the run in `metrics` and `job_runs`.
+///
+/// A run that cannot be recorded in `job_runs` is logged; the job's own
+/// result is returned either way.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer
+/// * `metrics` - The metrics registry to update
+/// * `job` - The job to run
+/// * `now` - When the run starts
+///
+/// # Errors
+///
+/// Returns the job's error message if the run failed.
+pub async fn run_job(
+    persistence: &Mutex<Persistence>,
+    metrics: &JobMetrics,
+    job: &dyn Job,
+    now: OffsetDateTime,
+) -> Result<String, String> {
+    let started: Instant = Instant::now();
+    let result: Result<String, String> = job.run(persistence, now).await;
+    let elapsed: Duration = started.elapsed();
+    metrics.record(job.name(), result.is_ok(), elapsed);
+
+    match &result {
+        Ok(summary) => info!(
+            action = job.name(),
+            duration_ms = logging::duration_ms(elapsed),
+            outcome = "success",
+            summary,
+            "Job finished"
+        ),
+        Err(e) => {
+            warn!(
+                action = job.name(),
+                duration_ms = logging::duration_ms(elapsed),
+                outcome = "failed",
+                error = %e,
+                "Job failed"
+            );
+            error_reporting::report_job_failure(job.name(), e);
+        }
+    }
+
+    let run: JobRunData = JobRunData {
+        job_name: job.name().to_string(),
+        started_at: format_timestamp(now),
+        finished_at: format_timestamp(now + elapsed),
+        succeeded: result.is_ok(),
+        duration_ms: i64::try_from(elapsed.as_millis()).unwrap_or(i64::MAX),
+        message: Some(match &result {
+            Ok(summary) => summary.clone(),
+            Err(e) => e.clone(),
+        }),
+    };
+    let recorded: Result<(), PersistenceError> = persistence.lock().await.record_job_run(&run);
+    if let Err(e) = recorded {
+        warn!(job = job.name(), error = %e, "Failed to record job run");
+    }
+
+    result
+}
+
+/// Registers jobs and spawns one task per job.
+pub struct JobRunner {
+    persistence: Arc<Mutex<Persistence>>,
+    metrics: Arc<JobMetrics>,
+    clock: Arc<dyn Clock>,
+    jobs: Vec<(Arc<dyn Job>, JobSchedule)>,
+}
+
+impl JobRunner {
+    /// Creates a runner with no jobs.
+    ///
+    /// # Arguments
+    ///
+    /// * `persistence` - The shared persistence layer jobs run against
+    /// * `metrics` - The registry the runs are recorded in
+    /// * `clock` - Where each run reads the time it is handed
+    #[must_use]
+    pub fn new(
+        persistence: Arc<Mutex<Persistence>>,
+        metrics: Arc<JobMetrics>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            persistence,
+            metrics,
+            clock,
+            jobs: Vec::new(),
+        }
+    }
+
+    /// Adds a job to run on `schedule`.
+    pub fn register(&mut self, job: impl Job, schedule: JobSchedule) {
+        self.metrics.register(job.name(), schedule);
+        self.jobs.push((Arc::new(job), schedule));
+    }
+
+    /// Spawns a task for every registered job.
+    ///
+    /// Each job first runs one jittered interval after startup. On shutdown
+    /// a task stops before its next run; a run already under way finishes.
+    ///
+    /// # Returns
+    ///
+    /// Each job's name and task.
+    pub fn spawn(
+        self,
+        shutdown: &ShutdownSignal,
+    ) -> Vec<(&'static str, tokio::task::JoinHandle<()>)> {
+        self.jobs
+            .into_iter()
+            .map(|(job, schedule)| {
+                info!(
+                    job = job.name(),
+                    interval_secs = schedule.interval.as_secs(),
+                    "Starting background job"
+                );
+                let persistence: Arc<Mutex<Persistence>> = Arc::clone(&self.persistence);
+                let metrics: Arc<JobMetrics> = Arc::clone(&self.metrics);
+                let clock: Arc<dyn Clock> = Arc::clone(&self.clock);
+                let mut shutdown: ShutdownSignal = shutdown.clone();
+                let name: &'static str = job.name();
+                let task: tokio::task::JoinHandle<()> = tokio::spawn(async move {
+                    loop {
+                        tokio::select! {
+                            () = tokio::time::sleep(schedule.next_delay()) => {}
+                            () = shutdown.wait() => break,
+                        }
+                        let now: OffsetDateTime = clock.now();
+                        // The outcome is already logged and recorded
+                        let _ = run_job(&persistence, &metrics, job.as_ref(), now).await;
+                    }
+                    debug!(job = job.name(), "Background job stopped");
+                });
+                (name, task)
+            })
+            .collect()
+    }
+}
+
+/// Deletes sessions that have passed their expiry.
+pub struct ExpiredSessionsJob;
+
+impl Job for ExpiredSessionsJob {
+    fn name(&self) -> &'static str {
+        "expired_sessions"
+    }
+
+    fn run<'a>(
+        &'a self,
+        persistence: &'a Mutex<Persistence>,
+        _now: OffsetDateTime,
+    ) -> BoxFuture<'a, Result<String, String>> {
+        Box::pin(async move {
+            let deleted: usize = persistence
+                .lock()
+                .await
+                .delete_expired_sessions()
+                .map_err(|e| format!("Failed to delete expired sessions: {e}"))?;
+            Ok(format!("Deleted {deleted} expired sessions"))
+        })
+    }
+}
+
+/// Rebuilds the dashboard projections of every area changed since the
+/// last run.
+pub struct DashboardProjectionJob;
+
+impl Job for DashboardProjectionJob {
+    fn name(&self) -> &'static str {
+        "dashboard_projections"
+    }
+
+    fn run<'a>(
+        &'a self,
+        persistence: &'a Mutex<Persistence>,
+        now: OffsetDateTime,
+    ) -> BoxFuture<'a, Result<String, String>> {
+        Box::pin(async move {
+            let mut persistence = persistence.lock().await;
+            let metadata: BootstrapMetadata = persistence
+                .get_bootstrap_metadata()
+                .map_err(|e| format!("Failed to load metadata: {e}"))?;
+            let refresh: ProjectionRefresh = zab_bid_api::refresh_dashboard_projections(
+                &mut persistence,
+                &metadata,
+                &format_timestamp(now),
+            )
+            .map_err(|e| format!("Failed to refresh dashboard projections: {e}"))?;
+            drop(persistence);
+            Ok(format!("Refreshed {} areas", refresh.areas_refreshed))
+        })
+    }
+}
+
+/// A job's status in the `/jobs` response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobStatusInfo {
+    /// The job's name.
+    pub name: String,
+    /// Seconds between runs, or `None` if this server does not run the
+    /// job.
+    pub interval_secs: Option<u64>,
+    /// Run statistics since the server started.
+    pub stats: JobStats,
+    /// When the latest recorded run started (RFC 3339).
+    pub last_started_at: Option<String>,
+    /// When the latest recorded run finished (RFC 3339).
+    pub last_finished_at: Option<String>,
+    /// Whether the latest recorded run succeeded.
+    pub last_succeeded: Option<bool>,
+    /// The latest recorded run's summary or error.
+    pub last_message: Option<String>,
+}
+
+/// Response for `GET /jobs`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListJobsResponse {
+    /// Every registered or previously recorded job, by name.
+    pub jobs: Vec<JobStatusInfo>,
+}
+
+/// Builds the job list from the registry and the recorded runs.
+fn list_jobs(
+    metrics: &BTreeMap<&'static str, (JobSchedule, JobStats)>,
+    runs: Vec<JobRunData>,
+) -> Vec<JobStatusInfo> {
+    let mut jobs: BTreeMap<String, JobStatusInfo> = metrics
+        .iter()
+        .map(|(name, (schedule, stats))| {
+            (
+                (*name).to_string(),
+                JobStatusInfo {
+                    name: (*name).to_string(),
+                    interval_secs: Some(schedule.interval.as_secs()),
+                    stats: stats.clone(),
+                    last_started_at: None,
+                    last_finished_at: None,
+                    last_succeeded: None,
+                    last_message: None,
+                },
+            )
+        })
+        .collect();
+
+    for run in runs {
+        let info: &mut JobStatusInfo =
+            jobs.entry(run.job_name.clone())
+                .or_insert_with(|| JobStatusInfo {
+                    name: run.job_name.clone(),
+                    interval_secs: None,
+                    stats: JobStats::default(),
+                    last_started_at: None,
+                    last_finished_at: None,
+                    last_succeeded: None,
+                    last_message: None,
+                });
+        info.last_started_at = Some(run.started_at);
+        info.last_finished_at = Some(run.finished_at);
+        info.last_succeeded = Some(run.succeeded);
+        info.last_message = run.message;
+    }
+
+    jobs.into_values().collect()
+}
+
+/// State for the job status endpoint.
+#[derive(Clone)]
+struct JobsState {
+    persistence: Arc<Mutex<Persistence>>,
+    metrics: Arc<JobMetrics>,
+}
+
+/// Reports every job's schedule, statistics, and latest recorded run.
+async fn handle_list_jobs(
+    AxumState(state): AxumState<JobsState>,
+) -> Result<Json<ListJobsResponse>, (StatusCode, String)> {
+    let runs: Vec<JobRunData> = state
+        .persistence
+        .lock()
+        .await
+        .list_job_runs()
+        .map_err(|e| {
+            warn!(error = %e, "Failed to list job runs");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to list job runs: {e}"),
+            )
+        })?;
+
+    Ok(Json(ListJobsResponse {
+        jobs: list_jobs(&state.metrics.snapshot(), runs),
+    }))
+}
+
+/// Builds the router for the job status endpoint.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer holding `job_runs`
+/// * `metrics` - The registry of this server's jobs
+pub fn router(persistence: Arc<Mutex<Persistence>>, metrics: Arc<JobMetrics>) -> Router {
+    Router::new()
+        .route("/jobs", get(handle_list_jobs))
+        .with_state(JobsState {
+            persistence,
+            metrics,
+        })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use time::macros::datetime;
+    use tower::ServiceExt;
+
+    /// A job that fails on every other run.
+    struct FlakyJob {
+        runs: std::sync::atomic::AtomicU32,
+    }
+
+    impl Job for FlakyJob {
+        fn name(&self) -> &'static str {
+            "flaky"
+        }
+
+        fn run<'a>(
+            &'a self,
+            _persistence: &'a Mutex<Persistence>,
+            _now: OffsetDateTime,
+        ) -> BoxFuture<'a, Result<String, String>> {
+            Box::pin(async move {
+                let run: u32 = self.runs.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                if run.is_multiple_of(2) {
+                    Ok(format!("run {run}"))
+                } else {
+                    Err(format!("run {run} failed"))
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_runs_are_counted_and_recorded() {
+        let persistence: Mutex<Persistence> = Mutex::new(Persistence::new_in_memory().unwrap());
+        let metrics: JobMetrics = JobMetrics::new();
+        let job: FlakyJob = FlakyJob {
+            runs: std::sync::atomic::AtomicU32::new(0),
+        };
+        metrics.register(job.name(), JobSchedule::every(Duration::from_mins(1)));
+
+        let first: OffsetDateTime = datetime!(2026-03-02 13:00 UTC);
+        assert_eq!(
+            run_job(&persistence, &metrics, &job, first).await,
+            Ok(String::from("run 0"))
+        );
+        let second: OffsetDateTime = datetime!(2026-03-02 13:01 UTC);
+        assert!(run_job(&persistence, &metrics, &job, second).await.is_err());
+
+        let (_, stats) = metrics.snapshot()["flaky"].clone();
+        assert_eq!((stats.runs, stats.failures), (2, 1));
+
+        // Only the latest run is kept
+        let runs: Vec<JobRunData> = persistence.lock().await.list_job_runs().unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].job_name, "flaky");
+        assert_eq!(runs[0].started_at, "2026-03-02T13:01:00Z");
+        assert!(!runs[0].succeeded);
+        assert_eq!(runs[0].message.as_deref(), Some("run 1 failed"));
+    }
+
+    #[tokio::test]
+    async fn test_job_tasks_stop_on_shutdown() {
+        let mut runner: JobRunner = JobRunner::new(
+            Arc::new(Mutex::new(Persistence::new_in_memory().unwrap())),
+            Arc::new(JobMetrics::new()),
+            Arc::new(zab_bid::SystemClock),
+        );
+        runner.register(
+            ExpiredSessionsJob,
+            JobSchedule::every(EXPIRED_SESSIONS_INTERVAL),
+        );
+        let mut shutdown: crate::shutdown::Shutdown = crate::shutdown::Shutdown::new();
+        for (name, task) in runner.spawn(&shutdown.signal()) {
+            assert_eq!(name, "expired_sessions");
+            shutdown.track(name, task);
+        }
+
+        // The first run is minutes away, so the task must stop while sleeping
+        assert_eq!(shutdown.stop(Duration::from_secs(5)).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_expired_sessions_job_succeeds_on_empty_database() {
+        let persistence: Mutex<Persistence> = Mutex::new(Persistence::new_in_memory().unwrap());
+        let metrics: JobMetrics = JobMetrics::new();
+
+        let summary: String = run_job(
+            &persistence,
+            &metrics,
+            &ExpiredSessionsJob,
+            OffsetDateTime::now_utc(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(summary, "Deleted 0 expired sessions");
+    }
+
+    #[tokio::test]
+    async fn test_expired_sessions_job_expires_by_persistence_clock() {
+        let clock: Arc<zab_bid::ManualClock> =
+            Arc::new(zab_bid::ManualClock::new(datetime!(2026-03-01 12:00 UTC)));
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        persistence.set_clock(clock.clone());
+        let operator_id: i64 = persistence
+            .create_operator("testop", "Test Operator", "Password123!", "Bidder")
+            .unwrap();
+        persistence
+            .create_session(
+                &zab_bid_persistence::SecretToken::new(String::from("session_token_123")),
+                operator_id,
+                "2026-03-02 12:00:00.000000",
+            )
+            .unwrap();
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let metrics: JobMetrics = JobMetrics::new();
+
+        let summary: String = run_job(&persistence, &metrics, &ExpiredSessionsJob, clock.now())
+            .await
+            .unwrap();
+        assert_eq!(summary, "Deleted 0 expired sessions");
+
+        clock.advance(time::Duration::days(2));
+        let summary: String = run_job(&persistence, &metrics, &ExpiredSessionsJob, clock.now())
+            .await
+            .unwrap();
+        assert_eq!(summary, "Deleted 1 expired sessions");
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let schedule: JobSchedule = JobSchedule {
+            interval: Duration::from_secs(30),
+            jitter: Duration::from_secs(5),
+        };
+        for _ in 0..100 {
+            let delay: Duration = schedule.next_delay();
+            assert!(delay >= schedule.interval);
+            assert!(delay <= schedule.interval + schedule.jitter);
+        }
+        assert_eq!(
+            JobSchedule::every(Duration::from_secs(30)).jitter,
+            Duration::from_secs(3)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_jobs_endpoint_merges_metrics_and_recorded_runs() {
+        let persistence: Arc<Mutex<Persistence>> =
+            Arc::new(Mutex::new(Persistence::new_in_memory().unwrap()));
+        let metrics: Arc<JobMetrics> = Arc::new(JobMetrics::new());
+        metrics.register(
+            "expired_sessions",
+            JobSchedule::every(EXPIRED_SESSIONS_INTERVAL),
+        );
+        // A job recorded by an earlier server that this one does not run
+        persistence
+            .lock()
+            .await
+            .record_job_run(&JobRunData {
+                job_name: String::from("window_expiry"),
+                started_at: String::from("2026-03-02T13:00:00Z"),
+                finished_at: String::from("2026-03-02T13:00:01Z"),
+                succeeded: true,
+                duration_ms: 1000,
+                message: Some(String::from("Advanced 1 rounds")),
+            })
+            .unwrap();
+
+        let response = router(persistence, metrics)
+            .oneshot(Request::builder().uri("/jobs").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let listed: ListJobsResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(listed.jobs.len(), 2);
+        assert_eq!(listed.jobs[0].name, "expired_sessions");
+        assert_eq!(listed.jobs[0].interval_secs, Some(900));
+        assert_eq!(listed.jobs[0].last_succeeded, None);
+        assert_eq!(listed.jobs[1].name, "window_expiry");
+        assert_eq!(listed.jobs[1].interval_secs, None);
+        assert_eq!(listed.jobs[1].last_succeeded, Some(true));
+    }
+}
@@ -29,6 +29,7 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::broadcast;
 use tracing::{debug, error, info, warn};
+use zab_bid_audit::AuditEvent;
 
 /// Maximum number of events to buffer in the broadcast channel.
 /// If clients cannot keep up, older events will be dropped.
@@ -97,6 +98,29 @@ pub enum LiveEvent {
         /// The area identifier.
         area: String,
     },
+    /// A user's bid was submitted.
+    BidSubmitted {
+        /// The bid year.
+        bid_year: u16,
+        /// The area identifier.
+        area: String,
+        /// The user's initials.
+        initials: String,
+    },
+    /// A bidding round was closed for an area.
+    RoundClosed {
+        /// The bid year.
+        bid_year: u16,
+        /// The area identifier.
+        area: String,
+    },
+    /// The active bid window advanced to the next bidder.
+    WindowAdvanced {
+        /// The bid year.
+        bid_year: u16,
+        /// The area identifier.
+        area: String,
+    },
     /// Connection confirmation (sent on initial connect).
     Connected {
         /// Server timestamp (ISO 8601).
@@ -104,6 +128,50 @@ pub enum LiveEvent {
     },
 }
 
+impl LiveEvent {
+    /// Derives a live event from a persisted audit event.
+    ///
+    /// Only area-scoped actions relevant to the bid status board are mapped:
+    ///
+    /// | Audit action       | Live event       |
+    /// |--------------------|------------------|
+    /// | `RegisterUser`     | `UserRegistered` |
+    /// | `SubmitBid`        | `BidSubmitted`   |
+    /// | `Finalize`         | `RoundClosed`    |
+    /// | `AdvanceBidWindow` | `WindowAdvanced` |
+    ///
+    /// Returns `None` for any other action or for events without an area scope.
+    #[must_use]
+    pub fn from_audit_event(event: &AuditEvent) -> Option<Self> {
+        let bid_year: u16 = event.bid_year.as_ref()?.year();
+        let area: String = event.area.as_ref()?.id().to_string();
+
+        match event.action.name.as_str() {
+            "RegisterUser" => Some(Self::UserRegistered {
+                bid_year,
+                area,
+                initials: quoted_initials(event)?,
+            }),
+            "SubmitBid" => Some(Self::BidSubmitted {
+                bid_year,
+                area,
+                initials: quoted_initials(event)?,
+            }),
+            "Finalize" => Some(Self::RoundClosed { bid_year, area }),
+            "AdvanceBidWindow" => Some(Self::WindowAdvanced { bid_year, area }),
+            _ => None,
+        }
+    }
+}
+
+/// Extracts the user's initials from action details of the form `... '<initials>' ...`.
+fn quoted_initials(event: &AuditEvent) -> Option<String> {
+    let details: &str = event.action.details.as_deref()?;
+    let (_, rest) = details.split_once('\'')?;
+    let (initials, _) = rest.split_once('\'')?;
+    Some(initials.to_string())
+}
+
 /// Broadcaster for live state events.
 ///
 /// This is a lightweight wrapper around `tokio::sync::broadcast` that allows
@@ -142,7 +210,7 @@ impl LiveEventBroadcaster {
     ///
     /// Returns a receiver that will receive all future events.
     /// Events sent before subscription are not received.
-    fn subscribe(&self) -> broadcast::Receiver<LiveEvent> {
+    pub fn subscribe(&self) -> broadcast::Receiver<LiveEvent> {
         self.tx.subscribe()
     }
 }
@@ -328,4 +396,66 @@ mod tests {
             _ => panic!("Wrong event type"),
         }
     }
+
+    fn scoped_event(action: &str, details: Option<&str>) -> AuditEvent {
+        use zab_bid_audit::{Action, Actor, Cause, StateSnapshot};
+        use zab_bid_domain::{Area, BidYear};
+
+        AuditEvent::new(
+            Actor::new(String::from("admin"), String::from("admin")),
+            Cause::new(String::from("test"), String::from("Test")),
+            Action::new(String::from(action), details.map(String::from)),
+            StateSnapshot::new(String::new()),
+            StateSnapshot::new(String::new()),
+            BidYear::new(2026),
+            Area::new("ZAB"),
+        )
+    }
+
+    #[test]
+    fn test_from_audit_event_maps_register_user() {
+        let event = scoped_event(
+            "RegisterUser",
+            Some("Registered user with initials 'AB' for bid year 2026"),
+        );
+
+        match LiveEvent::from_audit_event(&event) {
+            Some(LiveEvent::UserRegistered {
+                bid_year,
+                area,
+                initials,
+            }) => {
+                assert_eq!(bid_year, 2026);
+                assert_eq!(area, "ZAB");
+                assert_eq!(initials, "AB");
+            }
+            other => panic!("Expected UserRegistered, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_audit_event_maps_round_and_window_events() {
+        assert!(matches!(
+            LiveEvent::from_audit_event(&scoped_event("Finalize", None)),
+            Some(LiveEvent::RoundClosed { .. })
+        ));
+        assert!(matches!(
+            LiveEvent::from_audit_event(&scoped_event("AdvanceBidWindow", None)),
+            Some(LiveEvent::WindowAdvanced { .. })
+        ));
+    }
+
+    #[test]
+    fn test_from_audit_event_ignores_unmapped_actions() {
+        assert!(LiveEvent::from_audit_event(&scoped_event("Checkpoint", None)).is_none());
+
+        let global = AuditEvent::new_global(
+            zab_bid_audit::Actor::new(String::from("admin"), String::from("admin")),
+            zab_bid_audit::Cause::new(String::from("test"), String::from("Test")),
+            zab_bid_audit::Action::new(String::from("RegisterUser"), None),
+            zab_bid_audit::StateSnapshot::new(String::new()),
+            zab_bid_audit::StateSnapshot::new(String::new()),
+        );
+        assert!(LiveEvent::from_audit_event(&global).is_none());
+    }
 }
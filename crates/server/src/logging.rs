@@ -0,0 +1,284 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Log output and per-call context.
+//!
+//! Logs are written as JSON lines by default, one object per event, so a
+//! log shipper can index them without parsing. `--log-format text` writes
+//! the human-readable format instead, for development. `RUST_LOG` filters
+//! either format.
+//!
+//! Every API call and background job run ends with one event carrying:
+//!
+//! - `request_id`: the call's correlation ID (API calls only)
+//! - `operator_id`: the signed-in operator, once the session is validated
+//! - `action`: the method and route, e.g. `POST /api/v1/users/update`, or
+//!   the job's name
+//! - `scope`: the bid year and area acted on, e.g. `2026/NORTH`, when the
+//!   call loads one area's state
+//! - `duration_ms`: how long the call or run took
+//! - `outcome`: `success`, `rejected` (a 4xx response), or `failed`
+//!
+//! Handlers do not log the operator or scope themselves: the session
+//! extractor calls [`record_operator`] and loading a scope's state calls
+//! [`record_scope`], and both land on the call being handled.
+
+use axum::extract::{MatchedPath, Request};
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::info;
+
+use crate::error_reporting::CorrelationId;
+
+tokio::task_local! {
+    /// Context of the API call the current task is handling.
+    static API_CALL: Arc<Mutex<CallContext>>;
+}
+
+/// How log events are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// One JSON object per line.
+    Json,
+    /// Human-readable lines, for development.
+    Text,
+}
+
+impl LogFormat {
+    /// Returns the format's command-line spelling.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Text => "text",
+        }
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "text" => Ok(Self::Text),
+            _ => Err(format!(
+                "Unknown log format: '{s}'. Valid options: json, text"
+            )),
+        }
+    }
+}
+
+/// Installs the global log subscriber.
+pub fn init(format: LogFormat) {
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+    match format {
+        LogFormat::Json => builder
+            .json()
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(false)
+            .init(),
+        LogFormat::Text => builder.init(),
+    }
+}
+
+/// What an API call acted on, filled in while it is handled.
+#[derive(Debug, Default)]
+struct CallContext {
+    operator_id: Option<i64>,
+    scope: Option<String>,
+}
+
+/// Records the operator making the current API call.
+pub fn record_operator(operator_id: i64) {
+    let _ = API_CALL.try_with(|call| {
+        if let Ok(mut call) = call.lock() {
+            call.operator_id = Some(operator_id);
+        }
+    });
+}
+
+/// Records the bid year and area the current API call acts on.
+pub fn record_scope(year: u16, area_code: &str) {
+    let _ = API_CALL.try_with(|call| {
+        if let Ok(mut call) = call.lock() {
+            call.scope = Some(format!("{year}/{area_code}"));
+        }
+    });
+}
+
+/// Returns the outcome logged for a response status.
+fn outcome(status: StatusCode) -> &'static str {
+    if status.is_server_error() {
+        "failed"
+    } else if status.is_client_error() {
+        "rejected"
+    } else {
+        "success"
+    }
+}
+
+/// Returns a duration in whole milliseconds, for logging.
+pub fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Logs each API call once it is answered.
+///
+/// Applied as a route layer, so the action is the matched route rather
+/// than the raw path.
+pub async fn api_call_middleware(request: Request, next: Next) -> Response {
+    let started: Instant = Instant::now();
+    let action: String = format!(
+        "{} {}",
+        request.method(),
+        request
+            .extensions()
+            .get::<MatchedPath>()
+            .map_or_else(|| request.uri().path(), MatchedPath::as_str)
+    );
+    let request_id: Option<String> = request
+        .extensions()
+        .get::<CorrelationId>()
+        .map(|id| id.0.clone());
+
+    let call: Arc<Mutex<CallContext>> = Arc::new(Mutex::new(CallContext::default()));
+    let response: Response = API_CALL.scope(Arc::clone(&call), next.run(request)).await;
+
+    let (operator_id, scope) = call
+        .lock()
+        .map(|call| (call.operator_id, call.scope.clone()))
+        .unwrap_or_default();
+    let status: StatusCode = response.status();
+    info!(
+        request_id,
+        operator_id,
+        action,
+        scope,
+        status = status.as_u16(),
+        duration_ms = duration_ms(started.elapsed()),
+        outcome = outcome(status),
+        "API call finished"
+    );
+    response
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::error_reporting::{CORRELATION_ID_HEADER, correlation_middleware};
+    use axum::Router;
+    use axum::body::Body;
+    use axum::routing::post;
+    use serde_json::Value;
+    use std::io::Write;
+    use tower::ServiceExt;
+
+    /// Collects what the subscriber writes.
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Sends a request, returning the JSON events logged while handling it.
+    fn logged(request: Request<Body>) -> Vec<Value> {
+        let app: Router = Router::new()
+            .route(
+                "/areas/{area_id}",
+                post(|| async {
+                    record_operator(7);
+                    record_scope(2026, "NORTH");
+                    StatusCode::UNPROCESSABLE_ENTITY
+                }),
+            )
+            .route_layer(axum::middleware::from_fn(api_call_middleware))
+            .layer(axum::middleware::from_fn(correlation_middleware));
+
+        let buffer: Buffer = Buffer::default();
+        let writer: Buffer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .json()
+            .flatten_event(true)
+            .with_writer(move || writer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, || {
+            tokio::runtime::Builder::new_current_thread()
+                .build()
+                .unwrap()
+                .block_on(app.oneshot(request))
+                .unwrap();
+        });
+
+        let output: Vec<u8> = buffer.0.lock().unwrap().clone();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_log_format_parses_its_spelling() {
+        for format in [LogFormat::Json, LogFormat::Text] {
+            assert_eq!(format.as_str().parse::<LogFormat>(), Ok(format));
+        }
+        assert!("pretty".parse::<LogFormat>().is_err());
+    }
+
+    #[test]
+    fn test_api_call_is_logged_with_its_context() {
+        let request: Request<Body> = Request::builder()
+            .method("POST")
+            .uri("/areas/3")
+            .header(&CORRELATION_ID_HEADER, "ticket-17")
+            .body(Body::empty())
+            .unwrap();
+
+        let events: Vec<Value> = logged(request);
+
+        assert_eq!(events.len(), 1);
+        let event: &Value = &events[0];
+        assert_eq!(event["message"], "API call finished");
+        assert_eq!(event["request_id"], "ticket-17");
+        assert_eq!(event["operator_id"], 7);
+        assert_eq!(event["action"], "POST /areas/{area_id}");
+        assert_eq!(event["scope"], "2026/NORTH");
+        assert_eq!(event["status"], 422);
+        assert_eq!(event["outcome"], "rejected");
+        assert!(event["duration_ms"].is_u64());
+    }
+
+    #[test]
+    fn test_recording_outside_a_call_is_ignored() {
+        record_operator(7);
+        record_scope(2026, "NORTH");
+        assert_eq!(outcome(StatusCode::OK), "success");
+        assert_eq!(outcome(StatusCode::SERVICE_UNAVAILABLE), "failed");
+    }
+}
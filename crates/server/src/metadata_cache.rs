@@ -0,0 +1,312 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! In-memory cache for bootstrap metadata and bid schedules.
+//!
+//! Almost every request needs the bootstrap metadata, and bidders' screens
+//! poll the bid schedule throughout live bidding, yet both change only
+//! during setup. They are cached against a global change counter that is
+//! bumped whenever an audit event that can change them is committed, and
+//! whenever a handler invalidates the cache explicitly after an unaudited
+//! write.
+//!
+//! Every lookup first reads the latest audit event ID, a single indexed
+//! query. Only when it has moved are the new events' actions checked, so
+//! changes made by background jobs, the CLI, or another server process are
+//! seen on the next request.
+
+use std::collections::BTreeMap;
+use std::sync::MutexGuard;
+use zab_bid::BootstrapMetadata;
+use zab_bid_api::{GetBidScheduleResponse, get_bid_schedule};
+use zab_bid_persistence::{
+    AuditEventHeaderPage, AuditTimelineFilter, AuditTimelineScope, Persistence, PersistenceError,
+};
+
+use crate::HttpError;
+
+/// Audit actions that can change bootstrap metadata or a bid schedule.
+const INVALIDATING_ACTIONS: &[&str] = &[
+    "CreateBidYear",
+    "CreateArea",
+    "UpdateAreaMetadata",
+    "UpdateBidYearMetadata",
+    "CanonicalizeBidYear",
+    "SetBidSchedule",
+    "SetAreaBidSchedule",
+    "ClearAreaBidSchedule",
+    "FreezeScope",
+    "Unfreeze",
+    "SetOperatorController",
+];
+
+/// Maximum number of audit events read per page when checking for changes.
+const SCAN_BATCH_SIZE: u32 = 200;
+
+/// A cached value and the change counter it was loaded at.
+struct Cached<T> {
+    generation: u64,
+    value: T,
+}
+
+/// Everything guarded by the cache lock.
+#[derive(Default)]
+struct CacheState {
+    /// The global change counter.
+    generation: u64,
+    /// The latest audit event already checked for changes.
+    last_event_id: Option<i64>,
+    metadata: Option<Cached<BootstrapMetadata>>,
+    /// Bid schedules keyed by bid year ID.
+    schedules: BTreeMap<i64, Cached<GetBidScheduleResponse>>,
+}
+
+impl CacheState {
+    /// Returns whether any cached value is still current.
+    fn has_current_entries(&self) -> bool {
+        self.metadata
+            .as_ref()
+            .is_some_and(|cached| cached.generation == self.generation)
+            || self
+                .schedules
+                .values()
+                .any(|cached| cached.generation == self.generation)
+    }
+
+    /// Bumps the change counter and drops every cached value.
+    fn invalidate(&mut self) {
+        self.generation += 1;
+        self.metadata = None;
+        self.schedules.clear();
+    }
+}
+
+/// Caches bootstrap metadata and bid schedules between requests.
+///
+/// Callers must hold the persistence lock, so loads never race writes made
+/// through the same server.
+#[derive(Default)]
+pub struct MetadataCache {
+    state: std::sync::Mutex<CacheState>,
+}
+
+impl MetadataCache {
+    /// Creates an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheState> {
+        // A panic while holding the lock cannot leave the cache inconsistent
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Drops every cached value.
+    ///
+    /// Handlers call this after writing metadata without an audit event.
+    pub fn invalidate(&self) {
+        self.lock().invalidate();
+    }
+
+    /// Bumps the change counter if an invalidating audit event has been
+    /// committed since the last check.
+    fn observe(
+        state: &mut CacheState,
+        persistence: &mut Persistence,
+    ) -> Result<(), PersistenceError> {
+        let latest: Option<i64> = persistence.get_latest_audit_event_id()?;
+        if latest == state.last_event_id {
+            return Ok(());
+        }
+        // With nothing cached there is nothing to invalidate
+        if !state.has_current_entries() {
+            state.last_event_id = latest;
+            return Ok(());
+        }
+
+        let mut cursor: Option<i64> = state.last_event_id;
+        loop {
+            let page: AuditEventHeaderPage = persistence.get_audit_event_headers_page(
+                AuditTimelineScope::All,
+                &AuditTimelineFilter::default(),
+                cursor,
+                SCAN_BATCH_SIZE,
+            )?;
+            if page
+                .headers
+                .iter()
+                .any(|header| INVALIDATING_ACTIONS.contains(&header.action_name.as_str()))
+            {
+                state.invalidate();
+                break;
+            }
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        state.last_event_id = latest;
+        Ok(())
+    }
+
+    /// Returns the bootstrap metadata, loading it if the cache is stale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the audit log or metadata cannot be read.
+    pub fn metadata(
+        &self,
+        persistence: &mut Persistence,
+    ) -> Result<BootstrapMetadata, PersistenceError> {
+        let mut state = self.lock();
+        Self::observe(&mut state, persistence)?;
+        Self::cached_metadata(&mut state, persistence)
+    }
+
+    fn cached_metadata(
+        state: &mut CacheState,
+        persistence: &mut Persistence,
+    ) -> Result<BootstrapMetadata, PersistenceError> {
+        if let Some(cached) = &state.metadata
+            && cached.generation == state.generation
+        {
+            return Ok(cached.value.clone());
+        }
+        let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata()?;
+        state.metadata = Some(Cached {
+            generation: state.generation,
+            value: metadata.clone(),
+        });
+        Ok(metadata)
+    }
+
+    /// Returns a bid year's schedule, loading it if the cache is stale.
+    ///
+    /// Lookups that fail, such as for an unknown bid year, are not cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bid year does not exist or the schedule
+    /// cannot be read.
+    pub fn bid_schedule(
+        &self,
+        persistence: &mut Persistence,
+        bid_year_id: i64,
+    ) -> Result<GetBidScheduleResponse, HttpError> {
+        let mut state = self.lock();
+        Self::observe(&mut state, persistence)?;
+        if let Some(cached) = state.schedules.get(&bid_year_id)
+            && cached.generation == state.generation
+        {
+            return Ok(cached.value.clone());
+        }
+
+        let metadata: BootstrapMetadata = Self::cached_metadata(&mut state, persistence)?;
+        let schedule: GetBidScheduleResponse =
+            get_bid_schedule(persistence, &metadata, bid_year_id)?;
+        let generation: u64 = state.generation;
+        state.schedules.insert(
+            bid_year_id,
+            Cached {
+                generation,
+                value: schedule.clone(),
+            },
+        );
+        drop(state);
+        Ok(schedule)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use zab_bid::{BootstrapResult, Command, apply_bootstrap};
+    use zab_bid_audit::{Actor, Cause};
+    use zab_bid_domain::BidYear;
+
+    fn create_bid_year(persistence: &mut Persistence, year: u16) {
+        let operator_id: i64 = match persistence.get_operator_by_login("admin").unwrap() {
+            Some(operator) => operator.operator_id,
+            None => persistence
+                .create_operator("admin", "Admin", "password", "Admin")
+                .unwrap(),
+        };
+        let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+        let result: BootstrapResult = apply_bootstrap(
+            &metadata,
+            &BidYear::new(year),
+            Command::CreateBidYear {
+                year,
+                // Bid years start on a Sunday
+                start_date: time::Date::from_calendar_date(
+                    i32::from(year),
+                    time::Month::January,
+                    1,
+                )
+                .unwrap()
+                .next_occurrence(time::Weekday::Sunday),
+                num_pay_periods: 26,
+            },
+            Actor::with_operator(
+                String::from("admin"),
+                String::from("admin"),
+                operator_id,
+                String::from("admin"),
+                String::from("Admin"),
+            ),
+            Cause::new(String::from("test"), String::from("Test")),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&result).unwrap();
+    }
+
+    #[test]
+    fn test_metadata_is_reused_until_an_invalidating_event() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let cache: MetadataCache = MetadataCache::new();
+        create_bid_year(&mut persistence, 2026);
+
+        assert_eq!(cache.metadata(&mut persistence).unwrap().bid_years.len(), 1);
+        let generation: u64 = cache.lock().generation;
+        assert_eq!(cache.metadata(&mut persistence).unwrap().bid_years.len(), 1);
+        assert_eq!(cache.lock().generation, generation);
+
+        create_bid_year(&mut persistence, 2027);
+        assert_eq!(cache.metadata(&mut persistence).unwrap().bid_years.len(), 2);
+        assert!(cache.lock().generation > generation);
+    }
+
+    #[test]
+    fn test_explicit_invalidation_reloads_metadata() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let cache: MetadataCache = MetadataCache::new();
+        create_bid_year(&mut persistence, 2026);
+        let bid_year_id: i64 = cache.metadata(&mut persistence).unwrap().bid_years[0]
+            .bid_year_id()
+            .unwrap();
+
+        // System areas are created without an audit event
+        persistence
+            .create_system_area(bid_year_id, zab_bid_domain::Area::NO_BID_AREA_CODE)
+            .unwrap();
+        assert!(cache.metadata(&mut persistence).unwrap().areas.is_empty());
+
+        cache.invalidate();
+        assert_eq!(cache.metadata(&mut persistence).unwrap().areas.len(), 1);
+    }
+
+    #[test]
+    fn test_unknown_bid_year_schedule_is_not_cached() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let cache: MetadataCache = MetadataCache::new();
+
+        assert!(cache.bid_schedule(&mut persistence, 99).is_err());
+        assert!(cache.lock().schedules.is_empty());
+    }
+}
@@ -0,0 +1,842 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Email notifications for bidders.
+//!
+//! A background task emails users who have contact details on file when:
+//!
+//! - their bid window opens
+//! - their bid window is about to close
+//! - a bid is entered or amended on their behalf
+//!
+//! Every notification attempt, delivered or not, is written to the
+//! notification log, which is the audit record for outbound email. The log
+//! is unique per kind and bid window (or bid status transition), so each
+//! notification is attempted at most once. Failed sends are logged with the
+//! error and are not retried.
+//!
+//! Bid entry notifications start at the newest bid status transition present
+//! at startup; earlier transitions are never notified.
+
+use std::sync::Arc;
+use std::time::Duration;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+use zab_bid::Clock;
+use zab_bid_persistence::{
+    BidEntryNotificationCandidate, Persistence, PersistenceError, WindowNotificationCandidate,
+};
+
+use crate::email::{EmailMessage, Mailer};
+use crate::shutdown::ShutdownSignal;
+
+/// How often the notifier checks for due notifications.
+pub const NOTIFIER_POLL_INTERVAL: Duration = Duration::from_mins(1);
+
+/// Maximum number of bid status transitions read per poll.
+const BID_ENTRY_BATCH_SIZE: u32 = 200;
+
+/// Bid statuses that indicate a bid was entered for the user.
+const BID_ENTRY_STATUSES: [&str; 4] = [
+    "in_progress",
+    "completed_on_time",
+    "completed_late",
+    "proxy",
+];
+
+/// The kinds of notification sent to bidders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    /// The user's bid window has opened.
+    WindowOpened,
+    /// The user's bid window closes soon.
+    WindowClosing,
+    /// A bid was entered for the user.
+    BidEntered,
+    /// A previously entered bid was changed.
+    BidAmended,
+}
+
+impl NotificationKind {
+    /// Returns the identifier stored in the notification log.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::WindowOpened => "window_opened",
+            Self::WindowClosing => "window_closing",
+            Self::BidEntered => "bid_entered",
+            Self::BidAmended => "bid_amended",
+        }
+    }
+
+    /// Returns the subject and body templates for this kind.
+    const fn templates(self) -> (&'static str, &'static str) {
+        match self {
+            Self::WindowOpened => (
+                "Your {year} {round} bid window is open",
+                "Hello {name},\n\n\
+                 Your bid window for {round} ({area}, {year}) is now open.\n\n\
+                 Window: {window_start} to {window_end}\n",
+            ),
+            Self::WindowClosing => (
+                "Your {year} {round} bid window closes soon",
+                "Hello {name},\n\n\
+                 Your bid window for {round} ({area}, {year}) closes at {window_end}.\n\n\
+                 If you have not bid yet, please do so before the window closes.\n",
+            ),
+            Self::BidEntered => (
+                "Your {year} {round} bid was entered",
+                "Hello {name},\n\n\
+                 A bid for {round} ({area}, {year}) was entered for {initials}.\n\n\
+                 Status: {status}\n",
+            ),
+            Self::BidAmended => (
+                "Your {year} {round} bid was amended",
+                "Hello {name},\n\n\
+                 The bid for {round} ({area}, {year}) was changed for {initials}.\n\n\
+                 Status: {status}\n",
+            ),
+        }
+    }
+
+    /// Renders the message for `fields`, substituting each `{key}` placeholder.
+    fn render(self, to: &str, fields: &[(&str, &str)]) -> EmailMessage {
+        let (subject, body) = self.templates();
+        EmailMessage {
+            to: to.to_string(),
+            subject: substitute(subject, fields),
+            body: substitute(body, fields),
+            attachments: Vec::new(),
+        }
+    }
+}
+
+/// Replaces `{key}` placeholders in `template`.
+fn substitute(template: &str, fields: &[(&str, &str)]) -> String {
+    fields
+        .iter()
+        .fold(template.to_string(), |text, (key, value)| {
+            text.replace(&format!("{{{key}}}"), value)
+        })
+}
+
+/// Renders a bid window notification.
+fn window_message(kind: NotificationKind, window: &WindowNotificationCandidate) -> EmailMessage {
+    let year: String = window.year.to_string();
+    kind.render(
+        &window.email,
+        &[
+            ("name", window.name.as_str()),
+            ("initials", window.initials.as_str()),
+            ("year", year.as_str()),
+            ("area", window.area_code.as_str()),
+            ("round", window.round_name.as_str()),
+            ("window_start", window.window_start_datetime.as_str()),
+            ("window_end", window.window_end_datetime.as_str()),
+        ],
+    )
+}
+
+/// Classifies a bid status transition as a new or amended bid.
+fn bid_entry_kind(entry: &BidEntryNotificationCandidate) -> NotificationKind {
+    match entry.previous_status.as_deref() {
+        None => NotificationKind::BidEntered,
+        Some(previous) if previous.starts_with("not_started") => NotificationKind::BidEntered,
+        Some(_) => NotificationKind::BidAmended,
+    }
+}
+
+/// Renders a bid entry notification.
+fn bid_entry_message(
+    kind: NotificationKind,
+    entry: &BidEntryNotificationCandidate,
+) -> EmailMessage {
+    let year: String = entry.year.to_string();
+    kind.render(
+        &entry.email,
+        &[
+            ("name", entry.name.as_str()),
+            ("initials", entry.initials.as_str()),
+            ("year", year.as_str()),
+            ("area", entry.area_code.as_str()),
+            ("round", entry.round_name.as_str()),
+            ("status", entry.new_status.as_str()),
+        ],
+    )
+}
+
+/// Returns the window notifications due at `now`.
+///
+/// A window is open from its start until its end. The closing reminder is
+/// due during the final `closing_lead` of the window. Nothing is due once a
+/// window has ended.
+fn due_window_kinds(
+    window: &WindowNotificationCandidate,
+    closing_lead: time::Duration,
+    now: OffsetDateTime,
+) -> Vec<NotificationKind> {
+    let (Ok(start), Ok(end)) = (
+        OffsetDateTime::parse(&window.window_start_datetime, &Rfc3339),
+        OffsetDateTime::parse(&window.window_end_datetime, &Rfc3339),
+    ) else {
+        warn!(
+            bid_window_id = window.bid_window_id,
+            "Skipping bid window with unparseable datetimes"
+        );
+        return Vec::new();
+    };
+
+    let mut due: Vec<NotificationKind> = Vec::new();
+    if now >= end {
+        return due;
+    }
+    if now >= start {
+        due.push(NotificationKind::WindowOpened);
+    }
+    if now >= end - closing_lead {
+        due.push(NotificationKind::WindowClosing);
+    }
+    due
+}
+
+/// Sends one notification and records the attempt in the notification log.
+///
+/// # Returns
+///
+/// `true` if the message was delivered.
+async fn send_and_record<M: Mailer>(
+    persistence: &Mutex<Persistence>,
+    mailer: &M,
+    kind: NotificationKind,
+    user_id: i64,
+    reference_id: i64,
+    message: &EmailMessage,
+) -> Result<bool, PersistenceError> {
+    let result: Result<(), String> = mailer.send(message).await;
+    if let Err(e) = &result {
+        warn!(
+            kind = kind.as_str(),
+            user_id,
+            reference_id,
+            error = %e,
+            "Notification delivery failed"
+        );
+    }
+    persistence.lock().await.record_notification(
+        user_id,
+        kind.as_str(),
+        reference_id,
+        &message.to,
+        &message.subject,
+        result.as_ref().err().map(String::as_str),
+    )?;
+    Ok(result.is_ok())
+}
+
+/// Sends every notification due at `now`.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer
+/// * `mailer` - The mailer used to send notifications
+/// * `closing_lead` - How long before a window closes to send the reminder
+/// * `now` - The current time
+/// * `cursor` - The last bid status history ID already considered; advanced past every transition read
+///
+/// # Returns
+///
+/// The number of notifications delivered.
+///
+/// # Errors
+///
+/// Returns an error if candidates cannot be read or an attempt cannot be
+/// recorded.
+pub async fn poll_once<M: Mailer>(
+    persistence: &Mutex<Persistence>,
+    mailer: &M,
+    closing_lead: time::Duration,
+    now: OffsetDateTime,
+    cursor: &mut Option<i64>,
+) -> Result<usize, PersistenceError> {
+    let mut delivered: usize = 0;
+
+    let windows: Vec<WindowNotificationCandidate> = persistence
+        .lock()
+        .await
+        .list_window_notification_candidates()?;
+    for window in &windows {
+        for kind in due_window_kinds(window, closing_lead, now) {
+            if persistence
+                .lock()
+                .await
+                .notification_exists(kind.as_str(), window.bid_window_id)?
+            {
+                continue;
+            }
+            let message: EmailMessage = window_message(kind, window);
+            if send_and_record(
+                persistence,
+                mailer,
+                kind,
+                window.user_id,
+                window.bid_window_id,
+                &message,
+            )
+            .await?
+            {
+                delivered += 1;
+            }
+        }
+    }
+
+    let entries: Vec<BidEntryNotificationCandidate> = persistence
+        .lock()
+        .await
+        .list_bid_entry_notification_candidates(
+            cursor.unwrap_or(0),
+            &BID_ENTRY_STATUSES,
+            BID_ENTRY_BATCH_SIZE,
+        )?;
+    for entry in &entries {
+        let kind: NotificationKind = bid_entry_kind(entry);
+        if !persistence
+            .lock()
+            .await
+            .notification_exists(kind.as_str(), entry.history_id)?
+        {
+            let message: EmailMessage = bid_entry_message(kind, entry);
+            if send_and_record(
+                persistence,
+                mailer,
+                kind,
+                entry.user_id,
+                entry.history_id,
+                &message,
+            )
+            .await?
+            {
+                delivered += 1;
+            }
+        }
+        *cursor = Some(entry.history_id);
+    }
+
+    Ok(delivered)
+}
+
+/// Spawns the background task that sends bidder notifications.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer
+/// * `mailer` - The mailer used to send notifications
+/// * `poll_interval` - How often to check for due notifications
+/// * `closing_lead` - How long before a window closes to send the reminder
+/// * `clock` - The source of the current time
+/// * `shutdown` - Stops the task, after a final poll, when the server shuts down
+///
+/// # Errors
+///
+/// Returns an error if the starting cursor cannot be read.
+pub async fn spawn<M: Mailer + 'static>(
+    persistence: Arc<Mutex<Persistence>>,
+    mailer: M,
+    poll_interval: Duration,
+    closing_lead: time::Duration,
+    clock: Arc<dyn Clock>,
+    mut shutdown: ShutdownSignal,
+) -> Result<tokio::task::JoinHandle<()>, PersistenceError> {
+    let mut cursor: Option<i64> = persistence
+        .lock()
+        .await
+        .get_latest_bid_status_history_id()?;
+    debug!(?cursor, "Starting bidder notifier");
+
+    Ok(tokio::spawn(async move {
+        let mut ticker: tokio::time::Interval = tokio::time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                () = shutdown.wait() => break,
+            }
+            let now: OffsetDateTime = clock.now();
+            if let Err(e) = poll_once(&persistence, &mailer, closing_lead, now, &mut cursor).await {
+                warn!(error = %e, "Bidder notifier poll failed");
+            }
+        }
+        // Send notifications for bids committed before the server stopped
+        let now: OffsetDateTime = clock.now();
+        if let Err(e) = poll_once(&persistence, &mailer, closing_lead, now, &mut cursor).await {
+            warn!(error = %e, "Final bidder notifier poll failed");
+        }
+        debug!("Bidder notifier stopped");
+    }))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::shutdown::Shutdown;
+    use time::macros::datetime;
+    use zab_bid::{
+        BootstrapMetadata, Command, ManualClock, State, TransitionResult, apply, apply_bootstrap,
+    };
+    use zab_bid_audit::{Actor, Cause};
+    use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, UserType};
+    use zab_bid_persistence::{NewBidStatus, NewBidWindow, NotificationLogData};
+
+    /// Records messages instead of sending them.
+    #[derive(Default)]
+    struct RecordingMailer {
+        sent: std::sync::Mutex<Vec<EmailMessage>>,
+        fail: bool,
+    }
+
+    impl RecordingMailer {
+        fn sent(&self) -> Vec<EmailMessage> {
+            self.sent.lock().unwrap().clone()
+        }
+    }
+
+    impl Mailer for RecordingMailer {
+        async fn send(&self, message: &EmailMessage) -> Result<(), String> {
+            if self.fail {
+                return Err(String::from("connection refused"));
+            }
+            self.sent.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    /// IDs created by [`setup`].
+    #[allow(clippy::struct_field_names)]
+    struct Fixture {
+        user_id: i64,
+        operator_id: i64,
+        bid_status_id: i64,
+    }
+
+    fn test_actor(operator_id: i64) -> Actor {
+        Actor::with_operator(
+            String::from("admin"),
+            String::from("admin"),
+            operator_id,
+            String::from("admin"),
+            String::from("Admin"),
+        )
+    }
+
+    fn test_cause() -> Cause {
+        Cause::new(String::from("test"), String::from("Test"))
+    }
+
+    /// Creates an active 2026/North bid year with one emailable user whose
+    /// round 1 window runs 13:00-21:00 UTC on 2 March 2026.
+    fn setup(persistence: &mut Persistence) -> Fixture {
+        let operator_id: i64 = persistence
+            .create_operator("admin", "Admin", "password", "Admin")
+            .unwrap();
+        let bid_year: BidYear = BidYear::new(2026);
+        let area: Area = Area::new("North");
+
+        let by_result = apply_bootstrap(
+            &BootstrapMetadata::new(),
+            &bid_year,
+            Command::CreateBidYear {
+                year: 2026,
+                start_date: time::Date::from_calendar_date(2026, time::Month::January, 4).unwrap(),
+                num_pay_periods: 26,
+            },
+            test_actor(operator_id),
+            test_cause(),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&by_result).unwrap();
+        let area_result = apply_bootstrap(
+            &by_result.new_metadata,
+            &bid_year,
+            Command::CreateArea {
+                area_id: String::from("North"),
+            },
+            test_actor(operator_id),
+            test_cause(),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&area_result).unwrap();
+
+        let user_result: TransitionResult = apply(
+            &area_result.new_metadata,
+            &State::new(bid_year.clone(), area.clone()),
+            &bid_year,
+            Command::RegisterUser {
+                initials: Initials::new("AB"),
+                name: String::from("Alice Baker"),
+                area: area.clone(),
+                user_type: UserType::CPC,
+                crew: Some(Crew::new(1).unwrap()),
+                seniority_data: SeniorityData::new(
+                    String::from("2019-01-15"),
+                    String::from("2019-06-01"),
+                    String::from("2020-01-15"),
+                    String::from("2020-01-15"),
+                    Some(1),
+                ),
+            },
+            test_actor(operator_id),
+            test_cause(),
+        )
+        .unwrap();
+        persistence.persist_transition(&user_result).unwrap();
+        let state: State = persistence.get_current_state(&bid_year, &area).unwrap();
+        let user_id: i64 = state.users.values().next().unwrap().user_id.unwrap();
+
+        let bid_year_id: i64 = persistence.get_bid_year_id(2026).unwrap();
+        let area_id: i64 = persistence.get_area_id(bid_year_id, "North").unwrap();
+        let round_group_id: i64 = persistence
+            .insert_round_group(bid_year_id, "Standard", true)
+            .unwrap();
+        let round_id: i64 = persistence
+            .insert_round(round_group_id, 1, "Round 1", 1, 1, 80, false, false)
+            .unwrap();
+        persistence
+            .update_lifecycle_state(bid_year_id, "BiddingActive")
+            .unwrap();
+
+        persistence
+            .bulk_insert_bid_windows(&[NewBidWindow {
+                bid_year_id,
+                area_id,
+                user_id,
+                round_id,
+                window_start_datetime: String::from("2026-03-02T13:00:00+00:00"),
+                window_end_datetime: String::from("2026-03-02T21:00:00+00:00"),
+            }])
+            .unwrap();
+        persistence
+            .bulk_insert_bid_status(&[NewBidStatus {
+                bid_year_id,
+                area_id,
+                user_id,
+                round_id,
+                status: String::from("not_started_in_window"),
+                updated_at: String::from("2026-03-02T13:00:00Z"),
+                updated_by: operator_id,
+                notes: None,
+            }])
+            .unwrap();
+        let bid_status_id: i64 = persistence
+            .get_bid_status_for_user_and_round(bid_year_id, area_id, user_id, round_id)
+            .unwrap()
+            .bid_status_id;
+
+        persistence
+            .set_user_contact(user_id, "alice@example.test", true)
+            .unwrap();
+
+        Fixture {
+            user_id,
+            operator_id,
+            bid_status_id,
+        }
+    }
+
+    fn record_transition(
+        persistence: &mut Persistence,
+        fixture: &Fixture,
+        previous: &str,
+        new: &str,
+    ) {
+        let audit_event_id: i64 = persistence.get_latest_audit_event_id().unwrap().unwrap();
+        persistence
+            .insert_bid_status_history(
+                fixture.bid_status_id,
+                audit_event_id,
+                Some(previous),
+                new,
+                "2026-03-02T14:00:00Z",
+                fixture.operator_id,
+                None,
+            )
+            .unwrap();
+    }
+
+    /// Polls at `now` with a one hour closing lead.
+    async fn poll_at(
+        persistence: &Mutex<Persistence>,
+        mailer: &RecordingMailer,
+        now: OffsetDateTime,
+        cursor: &mut Option<i64>,
+    ) -> usize {
+        poll_once(persistence, mailer, time::Duration::hours(1), now, cursor)
+            .await
+            .unwrap()
+    }
+
+    fn log_kinds(persistence: &mut Persistence, user_id: i64) -> Vec<String> {
+        let log: Vec<NotificationLogData> = persistence
+            .list_notifications_for_user(user_id, 100)
+            .unwrap();
+        log.into_iter().rev().map(|entry| entry.kind).collect()
+    }
+
+    #[test]
+    fn test_render_substitutes_placeholders() {
+        let message: EmailMessage = NotificationKind::BidAmended.render(
+            "alice@example.test",
+            &[
+                ("name", "Alice Baker"),
+                ("initials", "AB"),
+                ("year", "2026"),
+                ("area", "NORTH"),
+                ("round", "Round 1"),
+                ("status", "completed_late"),
+            ],
+        );
+        assert_eq!(message.to, "alice@example.test");
+        assert_eq!(message.subject, "Your 2026 Round 1 bid was amended");
+        assert!(message.body.starts_with("Hello Alice Baker,"));
+        assert!(message.body.contains("(NORTH, 2026) was changed for AB"));
+        assert!(message.body.contains("Status: completed_late"));
+        assert!(!message.body.contains('{'));
+    }
+
+    #[tokio::test]
+    async fn test_window_notifications_sent_once_when_due() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let fixture: Fixture = setup(&mut persistence);
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let mailer: RecordingMailer = RecordingMailer::default();
+        let mut cursor: Option<i64> = None;
+
+        let before: usize = poll_at(
+            &persistence,
+            &mailer,
+            datetime!(2026-03-02 12:59 UTC),
+            &mut cursor,
+        )
+        .await;
+        assert_eq!(before, 0);
+
+        let opened: usize = poll_at(
+            &persistence,
+            &mailer,
+            datetime!(2026-03-02 13:00 UTC),
+            &mut cursor,
+        )
+        .await;
+        assert_eq!(opened, 1);
+        let repeat: usize = poll_at(
+            &persistence,
+            &mailer,
+            datetime!(2026-03-02 14:00 UTC),
+            &mut cursor,
+        )
+        .await;
+        assert_eq!(repeat, 0);
+
+        let closing: usize = poll_at(
+            &persistence,
+            &mailer,
+            datetime!(2026-03-02 20:00 UTC),
+            &mut cursor,
+        )
+        .await;
+        assert_eq!(closing, 1);
+        let ended: usize = poll_at(
+            &persistence,
+            &mailer,
+            datetime!(2026-03-02 21:00 UTC),
+            &mut cursor,
+        )
+        .await;
+        assert_eq!(ended, 0);
+
+        let sent: Vec<EmailMessage> = mailer.sent();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].subject, "Your 2026 Round 1 bid window is open");
+        assert!(sent[0].body.contains("2026-03-02T13:00:00+00:00"));
+        assert_eq!(sent[1].subject, "Your 2026 Round 1 bid window closes soon");
+        assert_eq!(
+            log_kinds(&mut *persistence.lock().await, fixture.user_id),
+            ["window_opened", "window_closing"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spawned_notifier_reads_the_injected_clock() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let fixture: Fixture = setup(&mut persistence);
+        let persistence: Arc<Mutex<Persistence>> = Arc::new(Mutex::new(persistence));
+        // The window is long over by the wall clock, but open by this one
+        let clock: Arc<ManualClock> = Arc::new(ManualClock::new(datetime!(2026-03-02 13:00 UTC)));
+        let mut coordinator: Shutdown = Shutdown::new();
+
+        let task: tokio::task::JoinHandle<()> = spawn(
+            Arc::clone(&persistence),
+            RecordingMailer::default(),
+            Duration::from_hours(1),
+            time::Duration::hours(1),
+            clock,
+            coordinator.signal(),
+        )
+        .await
+        .unwrap();
+        coordinator.track("notifier", task);
+        assert_eq!(coordinator.stop(Duration::from_secs(5)).await, 0);
+
+        assert_eq!(
+            log_kinds(&mut *persistence.lock().await, fixture.user_id),
+            ["window_opened"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ended_windows_are_not_notified() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        setup(&mut persistence);
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let mailer: RecordingMailer = RecordingMailer::default();
+        let mut cursor: Option<i64> = None;
+
+        let delivered: usize = poll_once(
+            &persistence,
+            &mailer,
+            time::Duration::hours(1),
+            datetime!(2026-03-03 09:00 UTC),
+            &mut cursor,
+        )
+        .await
+        .unwrap();
+        assert_eq!(delivered, 0);
+        assert!(mailer.sent().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_bid_entries_and_amendments_notified_after_cursor() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let fixture: Fixture = setup(&mut persistence);
+        record_transition(
+            &mut persistence,
+            &fixture,
+            "not_started_pre_window",
+            "not_started_in_window",
+        );
+        let mut cursor: Option<i64> = persistence.get_latest_bid_status_history_id().unwrap();
+
+        record_transition(
+            &mut persistence,
+            &fixture,
+            "not_started_in_window",
+            "in_progress",
+        );
+        record_transition(
+            &mut persistence,
+            &fixture,
+            "in_progress",
+            "completed_on_time",
+        );
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let mailer: RecordingMailer = RecordingMailer::default();
+
+        let delivered: usize = poll_once(
+            &persistence,
+            &mailer,
+            time::Duration::hours(1),
+            datetime!(2026-03-01 00:00 UTC),
+            &mut cursor,
+        )
+        .await
+        .unwrap();
+        assert_eq!(delivered, 2);
+
+        let sent: Vec<EmailMessage> = mailer.sent();
+        assert_eq!(sent[0].subject, "Your 2026 Round 1 bid was entered");
+        assert!(sent[0].body.contains("Status: in_progress"));
+        assert_eq!(sent[1].subject, "Your 2026 Round 1 bid was amended");
+        assert!(sent[1].body.contains("Status: completed_on_time"));
+        assert_eq!(
+            cursor,
+            persistence
+                .lock()
+                .await
+                .get_latest_bid_status_history_id()
+                .unwrap()
+        );
+
+        let again: usize = poll_once(
+            &persistence,
+            &mailer,
+            time::Duration::hours(1),
+            datetime!(2026-03-01 00:00 UTC),
+            &mut cursor,
+        )
+        .await
+        .unwrap();
+        assert_eq!(again, 0);
+    }
+
+    #[tokio::test]
+    async fn test_failed_delivery_is_logged_and_not_retried() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let fixture: Fixture = setup(&mut persistence);
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let mailer: RecordingMailer = RecordingMailer {
+            fail: true,
+            ..RecordingMailer::default()
+        };
+        let now: OffsetDateTime = datetime!(2026-03-02 13:30 UTC);
+        let mut cursor: Option<i64> = None;
+
+        let lead: time::Duration = time::Duration::hours(1);
+        assert_eq!(
+            poll_once(&persistence, &mailer, lead, now, &mut cursor)
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            poll_once(&persistence, &mailer, lead, now, &mut cursor)
+                .await
+                .unwrap(),
+            0
+        );
+
+        let log: Vec<NotificationLogData> = persistence
+            .lock()
+            .await
+            .list_notifications_for_user(fixture.user_id, 100)
+            .unwrap();
+        assert_eq!(log.len(), 1);
+        assert!(!log[0].delivered);
+        assert_eq!(log[0].error.as_deref(), Some("connection refused"));
+    }
+
+    #[tokio::test]
+    async fn test_disabled_contacts_are_not_notified() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let fixture: Fixture = setup(&mut persistence);
+        persistence
+            .set_user_contact(fixture.user_id, "alice@example.test", false)
+            .unwrap();
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let mailer: RecordingMailer = RecordingMailer::default();
+        let mut cursor: Option<i64> = None;
+
+        let delivered: usize = poll_once(
+            &persistence,
+            &mailer,
+            time::Duration::hours(1),
+            datetime!(2026-03-02 20:30 UTC),
+            &mut cursor,
+        )
+        .await
+        .unwrap();
+        assert_eq!(delivered, 0);
+        assert!(mailer.sent().is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Scheduled offsite backups.
+//!
+//! When an S3 bucket is configured, the `offsite_backup` [`Job`] uploads an
+//! encrypted copy of the `SQLite` database and a bundle of every bid year,
+//! then deletes all but the newest `--s3-backup-keep` backups of each. On
+//! `MySQL` only the bundles are uploaded; the database itself is dumped by
+//! the deployment's backup job.
+//!
+//! Backups are taken while holding the persistence lock, so each is a
+//! consistent copy, and uploaded after releasing it.
+
+use futures::future::BoxFuture;
+use std::path::PathBuf;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tokio::sync::Mutex;
+use tracing::info;
+use zab_bid_audit::Ulid;
+use zab_bid_offsite::{
+    DATABASE_DIR, OffsiteBackups, bundle_backup_name, bundle_dir, database_backup_name,
+};
+use zab_bid_persistence::{Persistence, PersistenceError};
+
+use crate::jobs::Job;
+
+/// Hours between offsite backups unless configured.
+pub const DEFAULT_BACKUP_INTERVAL_HOURS: u32 = 24;
+
+/// Backups of each kind kept in the bucket unless configured.
+pub const DEFAULT_BACKUP_KEEP: usize = 14;
+
+/// Uploads encrypted backups to the configured bucket.
+pub struct OffsiteBackupJob {
+    /// Where backups are stored.
+    pub backups: OffsiteBackups,
+    /// How many backups of the database, and of each bid year, to keep.
+    pub keep: usize,
+}
+
+/// Backups taken in one run, named as they are stored.
+struct TakenBackups {
+    database: Option<(String, Vec<u8>)>,
+    bundles: Vec<(u16, String, Vec<u8>)>,
+}
+
+/// Copies the database and exports every bid year.
+///
+/// # Errors
+///
+/// Returns an error if a copy or export fails.
+fn take_backups(
+    persistence: &mut Persistence,
+    now: OffsetDateTime,
+) -> Result<TakenBackups, String> {
+    let database: Option<(String, Vec<u8>)> = if persistence.is_sqlite() {
+        let path: PathBuf = std::env::temp_dir().join(format!("zabbid-offsite-{}.db", Ulid::new()));
+        let copied: Result<Vec<u8>, String> = persistence
+            .backup_to(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|()| std::fs::read(&path).map_err(|e| e.to_string()));
+        let _ = std::fs::remove_file(&path);
+        Some((database_backup_name(now), copied?))
+    } else {
+        None
+    };
+
+    let exported_at: String = now.format(&Rfc3339).map_err(|e| e.to_string())?;
+    let mut bundles: Vec<(u16, String, Vec<u8>)> = Vec::new();
+    for bid_year in persistence
+        .list_bid_years()
+        .map_err(|e: PersistenceError| e.to_string())?
+    {
+        let year: u16 = bid_year.year();
+        let bundle = persistence
+            .export_bid_year(year, &exported_at)
+            .map_err(|e| format!("Failed to export bid year {year}: {e}"))?;
+        let bytes: Vec<u8> = serde_json::to_vec(&bundle).map_err(|e| e.to_string())?;
+        bundles.push((year, bundle_backup_name(year, now), bytes));
+    }
+
+    Ok(TakenBackups { database, bundles })
+}
+
+impl Job for OffsiteBackupJob {
+    fn name(&self) -> &'static str {
+        "offsite_backup"
+    }
+
+    fn run<'a>(
+        &'a self,
+        persistence: &'a Mutex<Persistence>,
+        now: OffsetDateTime,
+    ) -> BoxFuture<'a, Result<String, String>> {
+        Box::pin(async move {
+            let taken: TakenBackups = take_backups(&mut *persistence.lock().await, now)?;
+
+            let mut uploaded: usize = 0;
+            let mut removed: usize = 0;
+            if let Some((name, bytes)) = &taken.database {
+                self.backups.upload(name, bytes).await?;
+                removed += self.backups.rotate(DATABASE_DIR, self.keep).await?;
+                uploaded += 1;
+            }
+            for (year, name, bytes) in &taken.bundles {
+                self.backups.upload(name, bytes).await?;
+                removed += self.backups.rotate(&bundle_dir(*year), self.keep).await?;
+                uploaded += 1;
+            }
+
+            info!(uploaded, removed, "Offsite backup complete");
+            Ok(format!(
+                "Uploaded {uploaded} backups, removed {removed} expired"
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+    use time::macros::datetime;
+    use zab_bid::{BootstrapMetadata, Command, apply_bootstrap};
+    use zab_bid_audit::{Actor, Cause};
+    use zab_bid_domain::BidYear;
+    use zab_bid_offsite::{BACKUP_KEY_LENGTH, BackupKey, ObjectStore};
+
+    type Objects = Arc<std::sync::Mutex<BTreeMap<String, Vec<u8>>>>;
+
+    /// An in-memory bucket.
+    struct MemoryStore(Objects);
+
+    impl ObjectStore for MemoryStore {
+        fn put<'a>(&'a self, key: &'a str, body: Vec<u8>) -> BoxFuture<'a, Result<(), String>> {
+            self.0.lock().unwrap().insert(key.to_string(), body);
+            Box::pin(async { Ok(()) })
+        }
+
+        fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Vec<u8>, String>> {
+            let body: Option<Vec<u8>> = self.0.lock().unwrap().get(key).cloned();
+            Box::pin(async move { body.ok_or_else(|| format!("{key} not found")) })
+        }
+
+        fn list<'a>(&'a self, prefix: &'a str) -> BoxFuture<'a, Result<Vec<String>, String>> {
+            let keys: Vec<String> = self
+                .0
+                .lock()
+                .unwrap()
+                .keys()
+                .filter(|key| key.starts_with(prefix))
+                .cloned()
+                .collect();
+            Box::pin(async { Ok(keys) })
+        }
+
+        fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), String>> {
+            self.0.lock().unwrap().remove(key);
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    fn job(objects: &Objects, keep: usize) -> OffsiteBackupJob {
+        OffsiteBackupJob {
+            backups: OffsiteBackups::new(
+                Box::new(MemoryStore(Arc::clone(objects))),
+                BackupKey::new([3; BACKUP_KEY_LENGTH]),
+                "zabbid/",
+            ),
+            keep,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_database_backup_is_uploaded_encrypted_and_restorable() {
+        let persistence: Mutex<Persistence> = Mutex::new(Persistence::new_in_memory().unwrap());
+        let objects: Objects = Objects::default();
+        let job: OffsiteBackupJob = job(&objects, 2);
+
+        let summary: String = job
+            .run(&persistence, datetime!(2026-01-27 09:00 UTC))
+            .await
+            .unwrap();
+        assert_eq!(summary, "Uploaded 1 backups, removed 0 expired");
+
+        let names: Vec<String> = job.backups.list(DATABASE_DIR).await.unwrap();
+        assert_eq!(names, vec!["database/zabbid-20260127T090000Z.sqlite.enc"]);
+        let stored: Vec<u8> = objects.lock().unwrap()[&format!("zabbid/{}", names[0])].clone();
+        assert!(!stored.starts_with(b"SQLite format 3"));
+
+        let restored: Vec<u8> = job.backups.download(&names[0]).await.unwrap();
+        assert!(restored.starts_with(b"SQLite format 3\0"));
+    }
+
+    fn create_bid_year(persistence: &mut Persistence) {
+        let operator_id: i64 = persistence
+            .create_operator("admin", "Admin", "password", "Admin")
+            .unwrap();
+        let result = apply_bootstrap(
+            &BootstrapMetadata::new(),
+            &BidYear::new(2026),
+            Command::CreateBidYear {
+                year: 2026,
+                start_date: time::Date::from_calendar_date(2026, time::Month::January, 4).unwrap(),
+                num_pay_periods: 26,
+            },
+            Actor::with_operator(
+                String::from("admin"),
+                String::from("admin"),
+                operator_id,
+                String::from("admin"),
+                String::from("Admin"),
+            ),
+            Cause::new(String::from("test"), String::from("Test")),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&result).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_bundles_are_uploaded_and_old_backups_rotated_out() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        create_bid_year(&mut persistence);
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let objects: Objects = Objects::default();
+        let job: OffsiteBackupJob = job(&objects, 2);
+
+        let mut summary: String = String::new();
+        for hour in 1..=3 {
+            let now: OffsetDateTime = datetime!(2026-01-27 00:00 UTC) + time::Duration::hours(hour);
+            summary = job.run(&persistence, now).await.unwrap();
+        }
+        assert_eq!(summary, "Uploaded 2 backups, removed 2 expired");
+
+        let bundles: Vec<String> = job.backups.list(&bundle_dir(2026)).await.unwrap();
+        assert_eq!(
+            bundles,
+            vec![
+                "bundles/2026/zabbid-2026-20260127T020000Z.zab.enc",
+                "bundles/2026/zabbid-2026-20260127T030000Z.zab.enc",
+            ]
+        );
+        let bundle: serde_json::Value =
+            serde_json::from_slice(&job.backups.download(&bundles[1]).await.unwrap()).unwrap();
+        assert_eq!(bundle["manifest"]["year"], 2026);
+        assert_eq!(
+            job.backups.list(DATABASE_DIR).await.unwrap(),
+            vec![
+                "database/zabbid-20260127T020000Z.sqlite.enc",
+                "database/zabbid-20260127T030000Z.sqlite.enc",
+            ]
+        );
+    }
+}
@@ -0,0 +1,722 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Rate limiting and brute-force protection for the HTTP boundary.
+//!
+//! Two independent limiters are maintained:
+//!
+//! - **Login limiter** — applied to the login endpoints, keyed by both
+//!   client IP and the login name being tried. This bounds
+//!   password-guessing attempts, including ones spread across many clients
+//!   against a single account.
+//! - **Mutation limiter** — applied to every state-changing request
+//!   (`POST` / `DELETE`), keyed by both client IP and authenticated operator.
+//!
+//! Both use a fixed-window counter. When a key exceeds its budget the request
+//! is rejected with `429 Too Many Requests` and a `Retry-After` header.
+//!
+//! Behind a reverse proxy every connection comes from the proxy, so requests
+//! from a configured trusted proxy are counted against the client named in
+//! its `X-Forwarded-For` or `X-Real-IP` header. Those headers are ignored
+//! from any other peer, since clients can set them to anything.
+//!
+//! The first rejection within a window is a security-relevant fact and is
+//! recorded as a global `RateLimitExceeded` audit event:
+//!
+//! - Operator-keyed trips are attributed to the throttled operator.
+//! - IP- and login-name-keyed trips have no operator behind them, so they
+//!   are attributed to the built-in system operator.
+//!
+//! Rate limit state is in-memory only and is reset on restart.
+
+use axum::{
+    Json,
+    body::Body,
+    extract::{ConnectInfo, Request, State as AxumState},
+    http::{HeaderMap, HeaderValue, Method, StatusCode, header::RETRY_AFTER},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use tracing::{error, warn};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_persistence::{OperatorData, SecretToken, SessionData};
+
+use crate::{AppState, ErrorResponse, session};
+
+/// Configuration for a single fixed-window limiter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// Maximum number of requests permitted per key within one window.
+    pub max_requests: u32,
+    /// Length of the counting window.
+    pub window: Duration,
+}
+
+impl RateLimitConfig {
+    /// Creates a per-minute configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_requests` - The number of requests permitted per minute
+    #[must_use]
+    pub const fn per_minute(max_requests: u32) -> Self {
+        Self {
+            max_requests,
+            window: Duration::from_mins(1),
+        }
+    }
+}
+
+/// The identity a request is counted against.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+    /// Counted against the client IP address.
+    Ip(IpAddr),
+    /// Counted against an authenticated operator.
+    Operator(i64),
+    /// Counted against the login name a sign-in attempt names, uppercased
+    /// as login names are stored.
+    LoginName(String),
+}
+
+/// The outcome of a rate limit check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitDecision {
+    /// The request is within budget.
+    Allowed,
+    /// The request exceeds the budget.
+    Limited {
+        /// Time remaining until the current window resets.
+        retry_after: Duration,
+        /// Whether this is the first rejection within the current window.
+        ///
+        /// Only the first rejection is reported as a security event so a
+        /// sustained attack does not flood the audit log.
+        first_trip: bool,
+    },
+}
+
+/// Counter state for one key.
+#[derive(Debug, Clone, Copy)]
+struct WindowState {
+    /// When the current window started.
+    started_at: Instant,
+    /// Requests counted in the current window.
+    count: u32,
+    /// Whether a rejection has already been reported in this window.
+    tripped: bool,
+}
+
+/// A fixed-window rate limiter keyed by `RateLimitKey`.
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// The limiter configuration.
+    config: RateLimitConfig,
+    /// Per-key counters.
+    windows: Mutex<HashMap<RateLimitKey, WindowState>>,
+}
+
+impl RateLimiter {
+    /// Creates a new limiter with the given configuration.
+    #[must_use]
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Counts a request against `key` using the current time.
+    #[must_use]
+    pub fn check(&self, key: &RateLimitKey) -> RateLimitDecision {
+        self.check_at(key, Instant::now())
+    }
+
+    /// Counts a request against `key` at an explicit instant.
+    ///
+    /// Separated from `check` so window behavior can be tested
+    /// deterministically.
+    #[must_use]
+    pub fn check_at(&self, key: &RateLimitKey, now: Instant) -> RateLimitDecision {
+        let mut windows = self.windows.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Drop expired windows so memory stays bounded by active clients.
+        let window_length: Duration = self.config.window;
+        windows.retain(|_, state| now.saturating_duration_since(state.started_at) < window_length);
+
+        let state: &mut WindowState = windows.entry(key.clone()).or_insert(WindowState {
+            started_at: now,
+            count: 0,
+            tripped: false,
+        });
+
+        if state.count < self.config.max_requests {
+            state.count += 1;
+            return RateLimitDecision::Allowed;
+        }
+
+        let elapsed: Duration = now.saturating_duration_since(state.started_at);
+        let retry_after: Duration = window_length.saturating_sub(elapsed);
+        let first_trip: bool = !state.tripped;
+        state.tripped = true;
+        drop(windows);
+
+        RateLimitDecision::Limited {
+            retry_after,
+            first_trip,
+        }
+    }
+}
+
+/// The limiters applied by the server.
+#[derive(Debug)]
+pub struct RateLimits {
+    /// Limiter for login attempts, keyed by IP and login name.
+    pub login: RateLimiter,
+    /// Limiter for state-changing requests, keyed by IP and operator.
+    pub mutations: RateLimiter,
+    /// Proxies whose forwarding headers name the client IP.
+    pub trusted_proxies: Vec<TrustedProxy>,
+}
+
+impl RateLimits {
+    /// Creates the server limiters.
+    ///
+    /// # Arguments
+    ///
+    /// * `login_per_minute` - Login attempts allowed per IP and per login name per minute
+    /// * `mutations_per_minute` - Mutations allowed per IP and per operator per minute
+    #[must_use]
+    pub fn new(login_per_minute: u32, mutations_per_minute: u32) -> Self {
+        Self {
+            login: RateLimiter::new(RateLimitConfig::per_minute(login_per_minute)),
+            mutations: RateLimiter::new(RateLimitConfig::per_minute(mutations_per_minute)),
+            trusted_proxies: Vec::new(),
+        }
+    }
+}
+
+/// A proxy trusted to report the client IP, as an address or CIDR range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrustedProxy {
+    /// The proxy address, or the first address of the range.
+    network: IpAddr,
+    /// The number of leading bits an address must share with `network`.
+    prefix_len: u8,
+}
+
+impl TrustedProxy {
+    /// Returns true if `ip` is this proxy or falls within its range.
+    #[must_use]
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, ip.to_canonical()) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask: u32 = u32::MAX
+                    .checked_shl(32 - u32::from(self.prefix_len))
+                    .unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask: u128 = u128::MAX
+                    .checked_shl(128 - u32::from(self.prefix_len))
+                    .unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for TrustedProxy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: &str = s.trim();
+        let (address, prefix) = s.split_once('/').map_or((s, None), |(a, p)| (a, Some(p)));
+        let network: IpAddr = address
+            .parse()
+            .map_err(|_| format!("'{address}' is not an IP address"))?;
+        let max_prefix_len: u8 = if network.is_ipv4() { 32 } else { 128 };
+        let prefix_len: u8 = match prefix {
+            None => max_prefix_len,
+            Some(prefix) => prefix
+                .parse()
+                .ok()
+                .filter(|len| *len <= max_prefix_len)
+                .ok_or_else(|| format!("'{prefix}' is not a prefix length for {address}"))?,
+        };
+        Ok(Self {
+            network,
+            prefix_len,
+        })
+    }
+}
+
+/// Returns the client IP a request is counted against.
+///
+/// A request from a trusted proxy is counted against the nearest untrusted
+/// address in its `X-Forwarded-For` header, or else its `X-Real-IP` header.
+/// Any other request is counted against the connecting peer.
+///
+/// # Arguments
+///
+/// * `peer` - The address of the connecting peer
+/// * `headers` - The request headers
+/// * `trusted_proxies` - The proxies whose forwarding headers are trusted
+pub fn client_ip(peer: IpAddr, headers: &HeaderMap, trusted_proxies: &[TrustedProxy]) -> IpAddr {
+    let is_trusted = |ip: IpAddr| trusted_proxies.iter().any(|proxy| proxy.contains(ip));
+    if !is_trusted(peer) {
+        return peer;
+    }
+
+    // Each proxy appends the address it received the request from, so the
+    // client is the last address no trusted proxy added
+    let forwarded = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|address| address.trim().parse::<IpAddr>().ok());
+    if let Some(ip) = forwarded.rev().find(|ip| !is_trusted(*ip)) {
+        return ip;
+    }
+
+    headers
+        .get("x-real-ip")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(peer)
+}
+
+/// Returns true if the request path is a login endpoint.
+pub fn is_login_path(path: &str) -> bool {
+    path.ends_with("/auth/login") || path.ends_with("/auth/bootstrap/login")
+}
+
+/// The account a login request body names.
+///
+/// Operator logins name it `login_name`; bootstrap logins `username`.
+#[derive(Deserialize)]
+struct LoginAttempt {
+    #[serde(alias = "username")]
+    login_name: String,
+}
+
+/// Returns the login name a login request body names, uppercased as login
+/// names are stored, or `None` if the body does not name one.
+fn attempted_login_name(body: &[u8]) -> Option<String> {
+    serde_json::from_slice::<LoginAttempt>(body)
+        .ok()
+        .map(|attempt| attempt.login_name.trim().to_uppercase())
+        .filter(|login_name| !login_name.is_empty())
+}
+
+/// Returns true if the request method changes state.
+pub fn is_mutation_method(method: &Method) -> bool {
+    *method == Method::POST
+        || *method == Method::PUT
+        || *method == Method::PATCH
+        || *method == Method::DELETE
+}
+
+/// Builds the `429 Too Many Requests` response.
+fn too_many_requests(retry_after: Duration) -> Response {
+    // Round up so clients never retry before the window resets.
+    let seconds: u64 = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+    let body: Json<ErrorResponse> = Json(ErrorResponse {
+        error: true,
+        message: format!("Rate limit exceeded. Retry after {seconds} second(s)"),
+    });
+
+    let mut response: Response = (StatusCode::TOO_MANY_REQUESTS, body).into_response();
+    if let Ok(value) = HeaderValue::from_str(&seconds.to_string()) {
+        response.headers_mut().insert(RETRY_AFTER, value);
+    }
+    response
+}
+
+/// Builds the global audit event recording a rate limit trip.
+fn trip_event(actor: Actor, details: String) -> AuditEvent {
+    let cause: Cause = Cause::new(
+        String::from("rate_limit"),
+        String::from("Request rate threshold exceeded"),
+    );
+    let action: Action = Action::new(String::from("RateLimitExceeded"), Some(details));
+    AuditEvent::new_global(
+        actor,
+        cause,
+        action,
+        StateSnapshot::new(String::from("rate_limit=ok")),
+        StateSnapshot::new(String::from("rate_limit=exceeded")),
+    )
+}
+
+/// Records a security audit event for an operator whose limit tripped.
+///
+/// Failure to record is logged but never converts the 429 into a 500:
+/// the rejection itself is the protective action.
+async fn record_operator_trip(app_state: &AppState, operator_id: i64, path: &str) {
+    let mut persistence = app_state.persistence.lock().await;
+
+    let operator: Option<OperatorData> = match persistence.get_operator_by_id(operator_id) {
+        Ok(operator) => operator,
+        Err(e) => {
+            error!(error = %e, operator_id, "Failed to load operator for rate limit audit");
+            return;
+        }
+    };
+
+    let Some(operator) = operator else {
+        return;
+    };
+
+    let actor: Actor = Actor::with_operator(
+        operator.operator_id.to_string(),
+        String::from("operator"),
+        operator.operator_id,
+        operator.login_name.clone(),
+        operator.display_name.clone(),
+    );
+    let details: String = format!(
+        "Operator {} exceeded the mutation rate limit on {path}",
+        operator.login_name
+    );
+
+    if let Err(e) = persistence.persist_audit_event(&trip_event(actor, details)) {
+        error!(error = %e, operator_id, "Failed to persist rate limit audit event");
+    }
+}
+
+/// Records a security audit event for a trip with no operator behind it.
+///
+/// Unauthenticated clients have no operator to attribute the trip to, so
+/// it is recorded as the built-in system operator. As for operator trips,
+/// failures are only logged.
+async fn record_system_trip(app_state: &AppState, details: String) {
+    let actor: Actor = Actor::new(String::from("rate_limit"), String::from("system"));
+
+    let mut persistence = app_state.persistence.lock().await;
+    if let Err(e) = persistence.persist_audit_event(&trip_event(actor, details)) {
+        error!(error = %e, "Failed to persist rate limit audit event");
+    }
+}
+
+/// Resolves the operator owning a bearer token without extending the session.
+async fn resolve_operator_id(app_state: &AppState, token: &str) -> Option<i64> {
+    let mut persistence = app_state.persistence.lock().await;
+    let session: Option<SessionData> = persistence
+        .get_session_by_token(&SecretToken::new(token.to_string()))
+        .ok()
+        .flatten();
+    drop(persistence);
+    session.map(|s| s.operator_id)
+}
+
+/// Axum middleware enforcing the login and mutation rate limits.
+///
+/// Requests that are neither logins nor mutations pass through untouched.
+/// Login requests are buffered so the login name they try can be counted.
+/// Requests without connection info (e.g. in-process tests) are not
+/// subject to IP-keyed limits.
+pub async fn rate_limit_middleware(
+    AxumState(app_state): AxumState<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path: String = request.uri().path().to_string();
+    let client_ip: Option<IpAddr> =
+        request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| {
+                client_ip(
+                    addr.ip(),
+                    request.headers(),
+                    &app_state.rate_limits.trusted_proxies,
+                )
+            });
+
+    if is_login_path(&path) {
+        if let Some(ip) = client_ip
+            && let RateLimitDecision::Limited {
+                retry_after,
+                first_trip,
+            } = app_state.rate_limits.login.check(&RateLimitKey::Ip(ip))
+        {
+            if first_trip {
+                warn!(%ip, path = %path, "Login rate limit exceeded");
+                let details: String =
+                    format!("Client {ip} exceeded the login rate limit on {path}");
+                record_system_trip(&app_state, details).await;
+            }
+            return too_many_requests(retry_after);
+        }
+
+        let (parts, body) = request.into_parts();
+        let Ok(bytes) = axum::body::to_bytes(body, app_state.max_body_bytes).await else {
+            let body: Json<ErrorResponse> = Json(ErrorResponse {
+                error: true,
+                message: String::from("Request body too large"),
+            });
+            return (StatusCode::PAYLOAD_TOO_LARGE, body).into_response();
+        };
+        if let Some(login_name) = attempted_login_name(&bytes)
+            && let RateLimitDecision::Limited {
+                retry_after,
+                first_trip,
+            } = app_state
+                .rate_limits
+                .login
+                .check(&RateLimitKey::LoginName(login_name.clone()))
+        {
+            if first_trip {
+                warn!(login_name, path = %path, "Login rate limit exceeded for login name");
+                let details: String =
+                    format!("Login name {login_name} exceeded the login rate limit on {path}");
+                record_system_trip(&app_state, details).await;
+            }
+            return too_many_requests(retry_after);
+        }
+        return next
+            .run(Request::from_parts(parts, Body::from(bytes)))
+            .await;
+    }
+
+    if !is_mutation_method(request.method()) {
+        return next.run(request).await;
+    }
+
+    if let Some(ip) = client_ip
+        && let RateLimitDecision::Limited {
+            retry_after,
+            first_trip,
+        } = app_state.rate_limits.mutations.check(&RateLimitKey::Ip(ip))
+    {
+        if first_trip {
+            warn!(%ip, path = %path, "Mutation rate limit exceeded for client IP");
+            let details: String = format!("Client {ip} exceeded the mutation rate limit on {path}");
+            record_system_trip(&app_state, details).await;
+        }
+        return too_many_requests(retry_after);
+    }
+
+    if let Ok(token) =
+        session::request_token(request.headers(), app_state.auth.mode).map(str::to_string)
+        && let Some(operator_id) = resolve_operator_id(&app_state, &token).await
+        && let RateLimitDecision::Limited {
+            retry_after,
+            first_trip,
+        } = app_state
+            .rate_limits
+            .mutations
+            .check(&RateLimitKey::Operator(operator_id))
+    {
+        if first_trip {
+            warn!(operator_id, path = %path, "Mutation rate limit exceeded for operator");
+            record_operator_trip(&app_state, operator_id, &path).await;
+        }
+        return too_many_requests(retry_after);
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn limiter(max_requests: u32) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            max_requests,
+            window: Duration::from_mins(1),
+        })
+    }
+
+    #[test]
+    fn test_requests_within_budget_are_allowed() {
+        let limiter = limiter(3);
+        let key = RateLimitKey::Ip(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert_eq!(limiter.check_at(&key, now), RateLimitDecision::Allowed);
+        }
+    }
+
+    #[test]
+    fn test_request_over_budget_is_limited_with_retry_after() {
+        let limiter = limiter(1);
+        let key = RateLimitKey::Operator(7);
+        let start = Instant::now();
+
+        assert_eq!(limiter.check_at(&key, start), RateLimitDecision::Allowed);
+
+        let decision = limiter.check_at(&key, start + Duration::from_secs(20));
+        assert_eq!(
+            decision,
+            RateLimitDecision::Limited {
+                retry_after: Duration::from_secs(40),
+                first_trip: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_only_first_rejection_in_window_is_reported() {
+        let limiter = limiter(1);
+        let key = RateLimitKey::Operator(7);
+        let now = Instant::now();
+
+        let _ = limiter.check_at(&key, now);
+        let first = limiter.check_at(&key, now);
+        let second = limiter.check_at(&key, now);
+
+        assert!(matches!(
+            first,
+            RateLimitDecision::Limited {
+                first_trip: true,
+                ..
+            }
+        ));
+        assert!(matches!(
+            second,
+            RateLimitDecision::Limited {
+                first_trip: false,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_window_resets_after_expiry() {
+        let limiter = limiter(1);
+        let key = RateLimitKey::Ip(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        let start = Instant::now();
+
+        let _ = limiter.check_at(&key, start);
+        assert!(matches!(
+            limiter.check_at(&key, start),
+            RateLimitDecision::Limited { .. }
+        ));
+
+        assert_eq!(
+            limiter.check_at(&key, start + Duration::from_secs(61)),
+            RateLimitDecision::Allowed
+        );
+    }
+
+    #[test]
+    fn test_keys_are_counted_independently() {
+        let limiter = limiter(1);
+        let now = Instant::now();
+
+        assert_eq!(
+            limiter.check_at(&RateLimitKey::Operator(1), now),
+            RateLimitDecision::Allowed
+        );
+        assert_eq!(
+            limiter.check_at(&RateLimitKey::Operator(2), now),
+            RateLimitDecision::Allowed
+        );
+    }
+
+    #[test]
+    fn test_attempted_login_name_reads_operator_and_bootstrap_logins() {
+        assert_eq!(
+            attempted_login_name(br#"{"login_name": " jdoe ", "password": "x"}"#),
+            Some(String::from("JDOE"))
+        );
+        assert_eq!(
+            attempted_login_name(br#"{"username": "admin", "password": "admin"}"#),
+            Some(String::from("ADMIN"))
+        );
+        assert_eq!(attempted_login_name(br#"{"login_name": ""}"#), None);
+        assert_eq!(attempted_login_name(b"not json"), None);
+    }
+
+    #[test]
+    fn test_login_path_detection() {
+        assert!(is_login_path("/auth/login"));
+        assert!(is_login_path("/api/auth/bootstrap/login"));
+        assert!(!is_login_path("/auth/logout"));
+    }
+
+    fn forwarded(headers: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in headers {
+            map.append(*name, HeaderValue::from_str(value).unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn test_trusted_proxy_parses_addresses_and_ranges() {
+        let single: TrustedProxy = "127.0.0.1".parse().unwrap();
+        assert!(single.contains("127.0.0.1".parse().unwrap()));
+        assert!(!single.contains("127.0.0.2".parse().unwrap()));
+
+        let range: TrustedProxy = "172.16.0.0/12".parse().unwrap();
+        assert!(range.contains("172.20.0.3".parse().unwrap()));
+        assert!(range.contains("::ffff:172.20.0.3".parse().unwrap()));
+        assert!(!range.contains("172.32.0.1".parse().unwrap()));
+
+        assert!("10.0.0.0/33".parse::<TrustedProxy>().is_err());
+        assert!("proxy".parse::<TrustedProxy>().is_err());
+    }
+
+    #[test]
+    fn test_client_ip_ignores_forwarding_headers_from_untrusted_peers() {
+        let peer: IpAddr = "198.51.100.7".parse().unwrap();
+        let headers = forwarded(&[("x-forwarded-for", "203.0.113.5")]);
+
+        assert_eq!(client_ip(peer, &headers, &[]), peer);
+    }
+
+    #[test]
+    fn test_client_ip_reads_nearest_untrusted_forwarded_address() {
+        let proxies: Vec<TrustedProxy> = vec!["10.0.0.0/8".parse().unwrap()];
+        let peer: IpAddr = "10.0.0.2".parse().unwrap();
+        // The client forged the first address; the proxies added the rest
+        let headers = forwarded(&[
+            ("x-forwarded-for", "192.0.2.1, 203.0.113.5"),
+            ("x-forwarded-for", "10.0.0.9"),
+            ("x-real-ip", "10.0.0.9"),
+        ]);
+
+        assert_eq!(
+            client_ip(peer, &headers, &proxies),
+            "203.0.113.5".parse::<IpAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_client_ip_falls_back_to_real_ip_then_peer() {
+        let proxies: Vec<TrustedProxy> = vec!["10.0.0.2".parse().unwrap()];
+        let peer: IpAddr = "10.0.0.2".parse().unwrap();
+
+        let headers = forwarded(&[("x-real-ip", "203.0.113.5")]);
+        assert_eq!(
+            client_ip(peer, &headers, &proxies),
+            "203.0.113.5".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(client_ip(peer, &HeaderMap::new(), &proxies), peer);
+    }
+
+    #[test]
+    fn test_too_many_requests_sets_retry_after_header() {
+        let response = too_many_requests(Duration::from_millis(1500));
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers().get(RETRY_AFTER).unwrap(), "2");
+    }
+}
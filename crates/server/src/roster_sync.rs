@@ -0,0 +1,555 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Roster sync from LDAP or Active Directory.
+//!
+//! When `--ldap-url` is given, each area is mapped to a directory group
+//! with `--ldap-area-groups`:
+//!
+//! ```text
+//! NORTH=CN=ZAB North,OU=Groups,DC=example,DC=org;SOUTH=CN=ZAB South,OU=Groups,DC=example,DC=org
+//! ```
+//!
+//! Members of each group are read with a `memberOf` search under
+//! `--ldap-base-dn`. Their attributes default to `initials`, `displayName`,
+//! and `employeeType` (the user type); `--ldap-attributes` overrides these
+//! and maps the crew and seniority dates, which have no standard attribute:
+//!
+//! ```text
+//! name=cn;crew=extensionAttribute1;service_computation_date=extensionAttribute2
+//! ```
+//!
+//! The `roster_sync` [`Job`] reads the directory on a schedule and logs the
+//! operations it would propose, without changing anything. Admins review
+//! the same dry run at `GET /roster-sync` and apply the operations they
+//! approve with `POST /roster-sync/apply`; see [`zab_bid_api::apply_roster_sync`].
+
+use futures::future::BoxFuture;
+use ldap3::{LdapConnAsync, LdapConnSettings, Scope, SearchEntry, ldap_escape};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use time::OffsetDateTime;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+use zab_bid::BootstrapMetadata;
+use zab_bid_api::{DirectoryMember, RosterSyncAction, RosterSyncPlanResponse};
+use zab_bid_persistence::Persistence;
+
+use crate::jobs::Job;
+
+/// Minutes between directory reads unless configured.
+pub const DEFAULT_SYNC_INTERVAL_MINUTES: u32 = 60;
+
+/// How long connecting to the directory server may take.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Directory connection settings.
+#[derive(clap::Args, Debug, Clone, Default)]
+#[allow(clippy::struct_field_names)]
+pub struct LdapArgs {
+    /// LDAP server URL, e.g. `ldaps://dc.example.org`. Roster sync is
+    /// disabled when omitted.
+    #[arg(long)]
+    pub ldap_url: Option<String>,
+
+    /// DN to bind as. Binds anonymously when omitted.
+    #[arg(long)]
+    pub ldap_bind_dn: Option<String>,
+
+    /// Password for --ldap-bind-dn
+    #[arg(long)]
+    pub ldap_bind_password: Option<String>,
+
+    /// DN members are searched for under (required with --ldap-url)
+    #[arg(long)]
+    pub ldap_base_dn: Option<String>,
+
+    /// Groups each area's members belong to, as `AREA=group DN` pairs
+    /// separated by `;` (required with --ldap-url)
+    #[arg(long)]
+    pub ldap_area_groups: Option<String>,
+
+    /// Member attributes read for each field, as `field=attribute` pairs
+    /// separated by `;`, overriding the defaults
+    #[arg(long)]
+    pub ldap_attributes: Option<String>,
+
+    /// Minutes between directory reads
+    #[arg(long, default_value_t = DEFAULT_SYNC_INTERVAL_MINUTES)]
+    pub ldap_sync_interval_minutes: u32,
+}
+
+impl LdapArgs {
+    /// Validates the directory settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a URL is configured without a base DN or area
+    /// groups, the groups or attributes cannot be parsed, only one of the
+    /// bind DN and password is given, or the interval is zero.
+    pub fn validate(&self) -> Result<(), String> {
+        self.config().map(|_| ())
+    }
+
+    /// Returns the parsed settings, or `None` if no URL is configured.
+    fn config(&self) -> Result<Option<LdapConfig>, String> {
+        let Some(url) = self.ldap_url.clone() else {
+            return Ok(None);
+        };
+        let base_dn: String = self
+            .ldap_base_dn
+            .clone()
+            .ok_or_else(|| String::from("--ldap-url requires --ldap-base-dn"))?;
+        let groups: Vec<(String, String)> = parse_pairs(
+            "--ldap-area-groups",
+            self.ldap_area_groups
+                .as_deref()
+                .ok_or_else(|| String::from("--ldap-url requires --ldap-area-groups"))?,
+        )?
+        .into_iter()
+        .map(|(area, dn)| (area.to_uppercase(), dn))
+        .collect();
+        if groups.is_empty() {
+            return Err(String::from(
+                "--ldap-area-groups must map at least one area",
+            ));
+        }
+
+        let mut attributes: AttributeMap = AttributeMap::default();
+        for (field, attribute) in parse_pairs(
+            "--ldap-attributes",
+            self.ldap_attributes.as_deref().unwrap_or_default(),
+        )? {
+            attributes.set(&field, attribute)?;
+        }
+
+        let bind: Option<(String, String)> = match (&self.ldap_bind_dn, &self.ldap_bind_password) {
+            (Some(dn), Some(password)) => Some((dn.clone(), password.clone())),
+            (None, None) => None,
+            _ => {
+                return Err(String::from(
+                    "--ldap-bind-dn and --ldap-bind-password must be given together",
+                ));
+            }
+        };
+        if self.ldap_sync_interval_minutes == 0 {
+            return Err(String::from(
+                "--ldap-sync-interval-minutes must be at least 1",
+            ));
+        }
+
+        Ok(Some(LdapConfig {
+            url,
+            bind,
+            base_dn,
+            groups,
+            attributes,
+        }))
+    }
+}
+
+/// Splits `key=value;key=value` settings, splitting each pair at its first
+/// `=` so values may be DNs.
+fn parse_pairs(flag: &str, text: &str) -> Result<Vec<(String, String)>, String> {
+    text.split(';')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            pair.split_once('=')
+                .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+                .filter(|(key, value)| !key.is_empty() && !value.is_empty())
+                .ok_or_else(|| format!("Invalid {flag} entry '{pair}': expected key=value"))
+        })
+        .collect()
+}
+
+/// The directory attribute each member field is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AttributeMap {
+    initials: String,
+    name: String,
+    user_type: Option<String>,
+    crew: Option<String>,
+    cumulative_natca_bu_date: Option<String>,
+    natca_bu_date: Option<String>,
+    eod_faa_date: Option<String>,
+    service_computation_date: Option<String>,
+}
+
+impl Default for AttributeMap {
+    fn default() -> Self {
+        Self {
+            initials: String::from("initials"),
+            name: String::from("displayName"),
+            user_type: Some(String::from("employeeType")),
+            crew: None,
+            cumulative_natca_bu_date: None,
+            natca_bu_date: None,
+            eod_faa_date: None,
+            service_computation_date: None,
+        }
+    }
+}
+
+impl AttributeMap {
+    /// Maps a field to an attribute.
+    fn set(&mut self, field: &str, attribute: String) -> Result<(), String> {
+        match field {
+            "initials" => self.initials = attribute,
+            "name" => self.name = attribute,
+            "user_type" => self.user_type = Some(attribute),
+            "crew" => self.crew = Some(attribute),
+            "cumulative_natca_bu_date" => self.cumulative_natca_bu_date = Some(attribute),
+            "natca_bu_date" => self.natca_bu_date = Some(attribute),
+            "eod_faa_date" => self.eod_faa_date = Some(attribute),
+            "service_computation_date" => self.service_computation_date = Some(attribute),
+            unknown => return Err(format!("Unknown --ldap-attributes field '{unknown}'")),
+        }
+        Ok(())
+    }
+
+    /// Returns every attribute to request.
+    fn requested(&self) -> Vec<&str> {
+        [
+            Some(&self.initials),
+            Some(&self.name),
+            self.user_type.as_ref(),
+            self.crew.as_ref(),
+            self.cumulative_natca_bu_date.as_ref(),
+            self.natca_bu_date.as_ref(),
+            self.eod_faa_date.as_ref(),
+            self.service_computation_date.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect()
+    }
+
+    /// Builds a member of `area_code` from a directory entry's attributes,
+    /// or returns `None` if it has no initials or name.
+    fn member(
+        &self,
+        attrs: &HashMap<String, Vec<String>>,
+        area_code: &str,
+    ) -> Option<DirectoryMember> {
+        // Attribute names are case-insensitive
+        let value = |attribute: &str| -> Option<String> {
+            attrs
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(attribute))
+                .and_then(|(_, values)| values.first())
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let mapped = |attribute: &Option<String>| attribute.as_deref().and_then(value);
+
+        Some(DirectoryMember {
+            initials: value(&self.initials)?,
+            name: value(&self.name)?,
+            area_code: area_code.to_string(),
+            user_type: mapped(&self.user_type),
+            crew: mapped(&self.crew).and_then(|crew| crew.parse().ok()),
+            cumulative_natca_bu_date: mapped(&self.cumulative_natca_bu_date),
+            natca_bu_date: mapped(&self.natca_bu_date),
+            eod_faa_date: mapped(&self.eod_faa_date),
+            service_computation_date: mapped(&self.service_computation_date),
+        })
+    }
+}
+
+/// Parsed directory settings.
+#[derive(Debug, Clone)]
+struct LdapConfig {
+    url: String,
+    bind: Option<(String, String)>,
+    base_dn: String,
+    groups: Vec<(String, String)>,
+    attributes: AttributeMap,
+}
+
+/// Reads the controllers listed in a directory.
+pub trait DirectorySource: Send + Sync {
+    /// Reads every member of every mapped group.
+    ///
+    /// Returns a description of the failure if the directory cannot be
+    /// read.
+    fn members(&self) -> BoxFuture<'_, Result<Vec<DirectoryMember>, String>>;
+}
+
+/// Reads group members from an LDAP server.
+pub struct LdapDirectory {
+    config: LdapConfig,
+}
+
+impl LdapDirectory {
+    /// Creates a directory from the configured settings.
+    ///
+    /// No connection is made until the directory is read.
+    ///
+    /// # Returns
+    ///
+    /// `None` if no LDAP URL is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the settings are invalid.
+    pub fn from_args(args: &LdapArgs) -> Result<Option<Self>, String> {
+        Ok(args.config()?.map(|config| Self { config }))
+    }
+
+    async fn read(&self) -> Result<Vec<DirectoryMember>, ldap3::LdapError> {
+        let settings: LdapConnSettings = LdapConnSettings::new().set_conn_timeout(CONNECT_TIMEOUT);
+        let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &self.config.url).await?;
+        ldap3::drive!(conn);
+
+        if let Some((dn, password)) = &self.config.bind {
+            ldap.simple_bind(dn, password).await?.success()?;
+        }
+
+        let attributes: Vec<&str> = self.config.attributes.requested();
+        let mut members: Vec<DirectoryMember> = Vec::new();
+        for (area_code, group_dn) in &self.config.groups {
+            let filter: String = format!("(memberOf={})", ldap_escape(group_dn));
+            let (entries, _) = ldap
+                .search(&self.config.base_dn, Scope::Subtree, &filter, &attributes)
+                .await?
+                .success()?;
+            for entry in entries {
+                let entry: SearchEntry = SearchEntry::construct(entry);
+                if let Some(member) = self.config.attributes.member(&entry.attrs, area_code) {
+                    members.push(member);
+                } else {
+                    warn!(dn = %entry.dn, "Directory entry skipped: no initials or name");
+                }
+            }
+        }
+
+        ldap.unbind().await?;
+        Ok(members)
+    }
+}
+
+impl DirectorySource for LdapDirectory {
+    fn members(&self) -> BoxFuture<'_, Result<Vec<DirectoryMember>, String>> {
+        Box::pin(async move {
+            self.read()
+                .await
+                .map_err(|e| format!("Failed to read directory: {e}"))
+        })
+    }
+}
+
+/// Summarizes a plan, e.g. `2 proposed (1 add, 0 updates, 1 removal), 0 blocked`.
+fn summarize(plan: &RosterSyncPlanResponse) -> String {
+    let count = |action: RosterSyncAction| {
+        plan.operations
+            .iter()
+            .filter(|op| op.action == action)
+            .count()
+    };
+    let blocked: usize = plan
+        .operations
+        .iter()
+        .filter(|op| op.blocked_reason.is_some())
+        .count();
+    let plural = |n: usize, word: &str| {
+        if n == 1 {
+            format!("{n} {word}")
+        } else {
+            format!("{n} {word}s")
+        }
+    };
+    format!(
+        "{} proposed ({}, {}, {}), {blocked} blocked",
+        plan.operations.len(),
+        plural(count(RosterSyncAction::Add), "add"),
+        plural(count(RosterSyncAction::Update), "update"),
+        plural(count(RosterSyncAction::Remove), "removal"),
+    )
+}
+
+/// The background job that proposes roster changes from the directory.
+pub struct RosterSyncJob {
+    /// Where members are read from.
+    pub source: Arc<dyn DirectorySource>,
+}
+
+impl Job for RosterSyncJob {
+    fn name(&self) -> &'static str {
+        "roster_sync"
+    }
+
+    fn run<'a>(
+        &'a self,
+        persistence: &'a Mutex<Persistence>,
+        _now: OffsetDateTime,
+    ) -> BoxFuture<'a, Result<String, String>> {
+        Box::pin(async move {
+            let members: Vec<DirectoryMember> = self.source.members().await?;
+
+            let mut persistence = persistence.lock().await;
+            let metadata: BootstrapMetadata = persistence
+                .get_bootstrap_metadata()
+                .map_err(|e| e.to_string())?;
+            let plan: RosterSyncPlanResponse =
+                zab_bid_api::plan_roster_sync(&mut persistence, &metadata, &members)
+                    .map_err(|e| e.to_string())?;
+            drop(persistence);
+
+            for op in &plan.operations {
+                info!(
+                    operation_id = %op.operation_id,
+                    initials = %op.initials,
+                    changes = %op.changes.join("; "),
+                    blocked = op.blocked_reason.as_deref(),
+                    "Roster sync proposes change"
+                );
+            }
+            Ok(summarize(&plan))
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use zab_bid_test_support::BidYearFixture;
+
+    fn args(groups: &str, attributes: Option<&str>) -> LdapArgs {
+        LdapArgs {
+            ldap_url: Some(String::from("ldaps://dc.example.org")),
+            ldap_bind_dn: None,
+            ldap_bind_password: None,
+            ldap_base_dn: Some(String::from("DC=example,DC=org")),
+            ldap_area_groups: Some(groups.to_string()),
+            ldap_attributes: attributes.map(str::to_string),
+            ldap_sync_interval_minutes: DEFAULT_SYNC_INTERVAL_MINUTES,
+        }
+    }
+
+    #[test]
+    fn test_area_groups_keep_commas_in_dns() {
+        let config: LdapConfig = args(
+            "north=CN=ZAB North,OU=Groups,DC=example,DC=org; SOUTH=CN=ZAB South,DC=example,DC=org",
+            None,
+        )
+        .config()
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            config.groups,
+            vec![
+                (
+                    String::from("NORTH"),
+                    String::from("CN=ZAB North,OU=Groups,DC=example,DC=org")
+                ),
+                (
+                    String::from("SOUTH"),
+                    String::from("CN=ZAB South,DC=example,DC=org")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_settings_are_rejected() {
+        assert!(LdapArgs::default().validate().is_ok());
+        assert!(args("NORTH", None).validate().is_err());
+        assert!(args("", None).validate().is_err());
+        assert!(
+            args("NORTH=CN=North", Some("seniority=x"))
+                .validate()
+                .unwrap_err()
+                .contains("seniority")
+        );
+
+        let mut half_bind: LdapArgs = args("NORTH=CN=North", None);
+        half_bind.ldap_bind_dn = Some(String::from("CN=zabbid"));
+        assert!(half_bind.validate().is_err());
+
+        let mut no_base: LdapArgs = args("NORTH=CN=North", None);
+        no_base.ldap_base_dn = None;
+        assert!(no_base.validate().is_err());
+    }
+
+    #[test]
+    fn test_member_reads_mapped_attributes() {
+        let mut map: AttributeMap = AttributeMap::default();
+        map.set("crew", String::from("extensionAttribute1"))
+            .unwrap();
+        map.set("service_computation_date", String::from("scd"))
+            .unwrap();
+        let attrs: HashMap<String, Vec<String>> = [
+            ("initials", "ab"),
+            ("displayname", "Alice Baker"),
+            ("employeeType", "CPC"),
+            ("extensionAttribute1", "3"),
+            ("scd", "2010-05-02"),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), vec![value.to_string()]))
+        .collect();
+
+        let member: DirectoryMember = map.member(&attrs, "NORTH").unwrap();
+        assert_eq!(member.initials, "ab");
+        assert_eq!(member.name, "Alice Baker");
+        assert_eq!(member.area_code, "NORTH");
+        assert_eq!(member.user_type.as_deref(), Some("CPC"));
+        assert_eq!(member.crew, Some(3));
+        assert_eq!(
+            member.service_computation_date.as_deref(),
+            Some("2010-05-02")
+        );
+        assert_eq!(member.eod_faa_date, None);
+
+        let unnamed: HashMap<String, Vec<String>> =
+            HashMap::from([(String::from("initials"), vec![String::from("CD")])]);
+        assert_eq!(map.member(&unnamed, "NORTH"), None);
+    }
+
+    /// A directory with fixed members.
+    struct StaticDirectory(Vec<DirectoryMember>);
+
+    impl DirectorySource for StaticDirectory {
+        fn members(&self) -> BoxFuture<'_, Result<Vec<DirectoryMember>, String>> {
+            let members: Vec<DirectoryMember> = self.0.clone();
+            Box::pin(async move { Ok(members) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_job_proposes_without_changing_roster() {
+        let fixture = BidYearFixture::new(2026).with_users(2);
+        let persistence: Mutex<Persistence> = Mutex::new(fixture.persist().unwrap().persistence);
+        let job: RosterSyncJob = RosterSyncJob {
+            source: Arc::new(StaticDirectory(vec![DirectoryMember {
+                initials: String::from("AA"),
+                name: String::from("Controller AA"),
+                area_code: String::from("NORTH"),
+                user_type: None,
+                crew: None,
+                cumulative_natca_bu_date: None,
+                natca_bu_date: None,
+                eod_faa_date: None,
+                service_computation_date: None,
+            }])),
+        };
+
+        for _ in 0..2 {
+            let summary: String = job
+                .run(&persistence, OffsetDateTime::UNIX_EPOCH)
+                .await
+                .unwrap();
+            assert_eq!(
+                summary,
+                "1 proposed (0 adds, 0 updates, 1 removal), 0 blocked"
+            );
+        }
+    }
+}
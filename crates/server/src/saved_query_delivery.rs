@@ -0,0 +1,289 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Weekly delivery of saved audit queries.
+//!
+//! When SMTP is configured, the `saved_audit_query_delivery` [`Job`] emails
+//! every saved audit query that has weekly recipients. Each recipient gets
+//! the query's results as a CSV attachment, the same document
+//! `GET /audit/saved-queries/export` returns.
+//!
+//! A query is sent by the first run after it is scheduled, then again once
+//! a week has passed since its last delivery. A query whose delivery fails
+//! is retried on the next run; the others are still sent.
+
+use futures::future::BoxFuture;
+use std::fmt::Write;
+use std::time::Duration;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tokio::sync::Mutex;
+use tracing::info;
+use zab_bid_api::{MAX_EXPORT_EVENTS, SavedAuditQueryExport};
+use zab_bid_persistence::{Persistence, SavedAuditQueryData};
+
+use crate::email::{EmailAttachment, EmailMessage, Mailer};
+use crate::jobs::Job;
+
+/// How often the job checks for saved queries that are due.
+pub const SAVED_QUERY_POLL_INTERVAL: Duration = Duration::from_hours(1);
+
+/// The time between deliveries of a scheduled query.
+const DELIVERY_PERIOD: time::Duration = time::Duration::weeks(1);
+
+/// Emails scheduled saved audit queries once a week.
+pub struct SavedQueryDeliveryJob<M: Mailer> {
+    /// Delivers the results.
+    pub mailer: M,
+}
+
+/// Returns true if `query` has recipients and has not been sent this week.
+fn is_due(query: &SavedAuditQueryData, now: OffsetDateTime) -> Result<bool, String> {
+    if query.weekly_recipients.is_empty() {
+        return Ok(false);
+    }
+    let Some(last_delivered_at) = query.last_delivered_at.as_deref() else {
+        return Ok(true);
+    };
+    let last: OffsetDateTime =
+        OffsetDateTime::parse(last_delivered_at, &Rfc3339).map_err(|e| e.to_string())?;
+    Ok(now - last >= DELIVERY_PERIOD)
+}
+
+/// Renders the email sent to `to` for one export.
+fn render_message(export: &SavedAuditQueryExport, to: &str, now: OffsetDateTime) -> EmailMessage {
+    let mut body: String = format!(
+        "{} audit events matched the saved query '{}' on {}.\n\
+         The results are attached as CSV.\n",
+        export.event_count,
+        export.name,
+        now.date()
+    );
+    if export.truncated {
+        let _ = write!(
+            body,
+            "\nOnly the first {MAX_EXPORT_EVENTS} events are included. \
+             Narrow the query's filters to see the rest.\n"
+        );
+    }
+    EmailMessage {
+        to: to.to_string(),
+        subject: format!("Saved audit query: {}", export.name),
+        body,
+        attachments: vec![EmailAttachment {
+            filename: export.report.filename.clone(),
+            content_type: export.report.content_type.to_string(),
+            body: export.report.body.clone(),
+        }],
+    }
+}
+
+impl<M: Mailer + 'static> SavedQueryDeliveryJob<M> {
+    /// Sends one export to each of its query's recipients.
+    async fn deliver(
+        &self,
+        query: &SavedAuditQueryData,
+        export: &SavedAuditQueryExport,
+        now: OffsetDateTime,
+    ) -> Result<(), String> {
+        for recipient in &query.weekly_recipients {
+            self.mailer
+                .send(&render_message(export, recipient, now))
+                .await
+                .map_err(|e| format!("Failed to send to {recipient}: {e}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl<M: Mailer + 'static> Job for SavedQueryDeliveryJob<M> {
+    fn name(&self) -> &'static str {
+        "saved_audit_query_delivery"
+    }
+
+    fn run<'a>(
+        &'a self,
+        persistence: &'a Mutex<Persistence>,
+        now: OffsetDateTime,
+    ) -> BoxFuture<'a, Result<String, String>> {
+        Box::pin(async move {
+            let delivered_at: String = now.format(&Rfc3339).map_err(|e| e.to_string())?;
+            let mut failures: Vec<String> = Vec::new();
+
+            let mut locked = persistence.lock().await;
+            let mut due: Vec<(SavedAuditQueryData, SavedAuditQueryExport)> = Vec::new();
+            for query in locked
+                .list_saved_audit_queries()
+                .map_err(|e| e.to_string())?
+            {
+                match is_due(&query, now) {
+                    Ok(false) => {}
+                    Ok(true) => {
+                        match zab_bid_api::render_saved_audit_query(
+                            &mut locked,
+                            query.saved_query_id,
+                        ) {
+                            Ok(export) => due.push((query, export)),
+                            Err(e) => failures.push(format!("'{}': {e}", query.name)),
+                        }
+                    }
+                    Err(e) => failures.push(format!("'{}': {e}", query.name)),
+                }
+            }
+            drop(locked);
+
+            let mut delivered: usize = 0;
+            for (query, export) in &due {
+                if let Err(e) = self.deliver(query, export, now).await {
+                    failures.push(format!("'{}': {e}", query.name));
+                    continue;
+                }
+                persistence
+                    .lock()
+                    .await
+                    .mark_saved_audit_query_delivered(query.saved_query_id, &delivered_at)
+                    .map_err(|e| e.to_string())?;
+                delivered += 1;
+                info!(
+                    saved_query_id = query.saved_query_id,
+                    name = %query.name,
+                    events = export.event_count,
+                    recipients = query.weekly_recipients.len(),
+                    "Saved audit query delivered"
+                );
+            }
+
+            if failures.is_empty() {
+                Ok(format!("Delivered {delivered} saved audit queries"))
+            } else {
+                Err(format!(
+                    "Delivered {delivered} saved audit queries; failed {}",
+                    failures.join("; ")
+                ))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+    use zab_bid_test_support::BidYearFixture;
+
+    /// Records sent messages.
+    #[derive(Default)]
+    struct RecordingMailer {
+        sent: std::sync::Mutex<Vec<EmailMessage>>,
+    }
+
+    impl Mailer for RecordingMailer {
+        async fn send(&self, message: &EmailMessage) -> Result<(), String> {
+            self.sent.lock().unwrap().push(message.clone());
+            Ok(())
+        }
+    }
+
+    /// Saves an unscheduled query and one scheduled to two recipients.
+    fn setup() -> Mutex<Persistence> {
+        let mut persistence: Persistence = BidYearFixture::new(2026).persist().unwrap().persistence;
+        let operator_id: i64 = persistence
+            .create_operator("admin", "Admin", "password", "Admin")
+            .unwrap();
+        for (name, recipients) in [
+            ("All events", Vec::new()),
+            (
+                "Global events",
+                vec![
+                    String::from("union@example.test"),
+                    String::from("manager@example.test"),
+                ],
+            ),
+        ] {
+            persistence
+                .create_saved_audit_query(
+                    name,
+                    r#"{"type":"global"}"#,
+                    "{}",
+                    &recipients,
+                    operator_id,
+                )
+                .unwrap();
+        }
+        Mutex::new(persistence)
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_query_is_sent_weekly() {
+        let persistence: Mutex<Persistence> = setup();
+        let job: SavedQueryDeliveryJob<RecordingMailer> = SavedQueryDeliveryJob {
+            mailer: RecordingMailer::default(),
+        };
+
+        let summary: String = job
+            .run(&persistence, datetime!(2026-10-05 08:00 UTC))
+            .await
+            .unwrap();
+        assert_eq!(summary, "Delivered 1 saved audit queries");
+        let sent: Vec<EmailMessage> = job.mailer.sent.lock().unwrap().clone();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[1].to, "manager@example.test");
+        assert_eq!(sent[1].subject, "Saved audit query: Global events");
+        assert_eq!(sent[1].attachments.len(), 1);
+        assert_eq!(sent[1].attachments[0].filename, "audit-global-events.csv");
+        assert!(
+            sent[1].attachments[0]
+                .body
+                .starts_with(b"event_id,created_at,actor,action")
+        );
+
+        // Not due again until a week has passed
+        let summary: String = job
+            .run(&persistence, datetime!(2026-10-12 07:59 UTC))
+            .await
+            .unwrap();
+        assert_eq!(summary, "Delivered 0 saved audit queries");
+        let summary: String = job
+            .run(&persistence, datetime!(2026-10-12 08:00 UTC))
+            .await
+            .unwrap();
+        assert_eq!(summary, "Delivered 1 saved audit queries");
+        assert_eq!(job.mailer.sent.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_message_notes_truncated_results() {
+        let export: SavedAuditQueryExport = SavedAuditQueryExport {
+            name: String::from("All overrides 2026"),
+            report: zab_bid_api::RenderedReport {
+                content_type: "text/csv; charset=utf-8",
+                filename: String::from("audit-all-overrides-2026.csv"),
+                body: b"event_id\n".to_vec(),
+            },
+            event_count: MAX_EXPORT_EVENTS,
+            truncated: true,
+        };
+
+        let message: EmailMessage = render_message(
+            &export,
+            "union@example.test",
+            datetime!(2026-10-05 08:00 UTC),
+        );
+
+        assert!(message.body.starts_with(
+            "10000 audit events matched the saved query 'All overrides 2026' on 2026-10-05."
+        ));
+        assert!(
+            message
+                .body
+                .contains("Only the first 10000 events are included.")
+        );
+        assert_eq!(
+            message.attachments[0].content_type,
+            "text/csv; charset=utf-8"
+        );
+    }
+}
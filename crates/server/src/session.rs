@@ -10,19 +10,22 @@
 
 use axum::{
     extract::FromRequestParts,
-    http::{StatusCode, request::Parts},
+    http::{HeaderMap, StatusCode, request::Parts},
     response::{IntoResponse, Response},
 };
 use tracing::{debug, warn};
-use zab_bid_api::{AuthenticatedActor, AuthenticationService};
-use zab_bid_persistence::OperatorData;
+use zab_bid_api::{AuthenticatedActor, AuthenticationService, PortalIdentity};
+use zab_bid_persistence::{OperatorData, SecretToken};
 
 use crate::AppState;
+use crate::cookie_auth::{AuthMode, SESSION_COOKIE, cookie_value};
+use crate::logging;
 
 /// Extractor for authenticated operators.
 ///
-/// This extractor validates the session token from the Authorization header
-/// and returns the authenticated operator context.
+/// This extractor validates the session token from the Authorization header,
+/// or from the session cookie in cookie auth mode, and returns the
+/// authenticated operator context.
 ///
 /// # Usage
 ///
@@ -38,7 +41,8 @@ use crate::AppState;
 ///
 /// # Authentication Flow
 ///
-/// 1. Extract `Authorization: Bearer <token>` header
+/// 1. Extract `Authorization: Bearer <token>` header (or the session
+///    cookie in cookie auth mode)
 /// 2. Validate session token via `AuthenticationService::validate_session`
 /// 3. Check session expiration
 /// 4. Check operator disabled status
@@ -61,44 +65,134 @@ impl FromRequestParts<AppState> for SessionOperator {
         parts: &mut Parts,
         state: &AppState,
     ) -> Result<Self, Self::Rejection> {
-        // Extract Authorization header
-        let auth_header = parts
-            .headers
-            .get("Authorization")
-            .ok_or_else(|| {
-                debug!("Missing Authorization header");
-                SessionError::MissingAuthorizationHeader
-            })?
-            .to_str()
-            .map_err(|_| {
-                warn!("Invalid Authorization header encoding");
-                SessionError::InvalidAuthorizationHeader
-            })?;
-
-        // Parse Bearer token
-        let token = auth_header.strip_prefix("Bearer ").ok_or_else(|| {
-            warn!("Authorization header does not start with 'Bearer '");
-            SessionError::InvalidAuthorizationHeader
-        })?;
+        let token: SecretToken =
+            SecretToken::new(request_token(&parts.headers, state.auth.mode)?.to_string());
 
         // Validate session
         let mut persistence = state.persistence.lock().await;
-        let (actor, operator) = AuthenticationService::validate_session(&mut persistence, token)
-            .map_err(|e| {
-                warn!(error = %e, "Session validation failed");
-                SessionError::InvalidSession(e.to_string())
-            })?;
+        let validated = AuthenticationService::validate_session(&mut persistence, &token);
+        drop(persistence);
+        let (actor, operator) = validated.map_err(|e| {
+            warn!(error = %e, "Session validation failed");
+            SessionError::InvalidSession(e.to_string())
+        })?;
 
         debug!(
             login_name = %operator.login_name,
             role = ?actor.role,
             "Session validated successfully"
         );
+        logging::record_operator(operator.operator_id);
 
         Ok(Self(actor, operator))
     }
 }
 
+/// Extractor for the session token the request was made with.
+///
+/// Used alongside [`SessionOperator`] by handlers that act on the calling
+/// session itself, such as changing the operator's own password while
+/// keeping the current session signed in. It does not validate the session.
+pub struct SessionToken(pub SecretToken);
+
+impl FromRequestParts<AppState> for SessionToken {
+    type Rejection = SessionError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        request_token(&parts.headers, state.auth.mode)
+            .map(|token| Self(SecretToken::new(token.to_string())))
+    }
+}
+
+/// The header a controller portal link token is sent in.
+pub const PORTAL_TOKEN_HEADER: &str = "X-Portal-Token";
+
+/// Extractor for the controller a portal link was issued to.
+///
+/// Portal reads do not use operator sessions: the token from the
+/// [`PORTAL_TOKEN_HEADER`] header is checked via
+/// `zab_bid_api::authenticate_portal`, and identifies exactly one user.
+///
+/// # Errors
+///
+/// Returns HTTP 401 Unauthorized if the header is missing, or the link is
+/// unknown or expired.
+pub struct PortalUser(pub PortalIdentity);
+
+impl FromRequestParts<AppState> for PortalUser {
+    type Rejection = SessionError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let token: SecretToken = parts
+            .headers
+            .get(PORTAL_TOKEN_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|token| !token.is_empty())
+            .map(|token| SecretToken::new(token.to_string()))
+            .ok_or_else(|| {
+                debug!("Missing portal token");
+                SessionError::MissingPortalToken
+            })?;
+
+        let mut persistence = state.persistence.lock().await;
+        let identity = zab_bid_api::authenticate_portal(&mut persistence, &token);
+        drop(persistence);
+        let identity: PortalIdentity = identity.map_err(|e| {
+            warn!(error = %e, "Portal link validation failed");
+            SessionError::InvalidPortalLink(e.to_string())
+        })?;
+
+        debug!(user_id = identity.user_id, "Portal link validated");
+
+        Ok(Self(identity))
+    }
+}
+
+/// Extracts the session token the way the configured auth mode expects it.
+///
+/// # Errors
+///
+/// Returns an error if the token is missing or malformed.
+pub fn request_token(headers: &HeaderMap, mode: AuthMode) -> Result<&str, SessionError> {
+    match mode {
+        AuthMode::Bearer => bearer_token(headers),
+        AuthMode::Cookie => cookie_value(headers, SESSION_COOKIE)
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| {
+                debug!("Missing session cookie");
+                SessionError::MissingSessionCookie
+            }),
+    }
+}
+
+/// Extracts the bearer token from the `Authorization` header.
+fn bearer_token(headers: &HeaderMap) -> Result<&str, SessionError> {
+    // Extract Authorization header
+    let auth_header = headers
+        .get("Authorization")
+        .ok_or_else(|| {
+            debug!("Missing Authorization header");
+            SessionError::MissingAuthorizationHeader
+        })?
+        .to_str()
+        .map_err(|_| {
+            warn!("Invalid Authorization header encoding");
+            SessionError::InvalidAuthorizationHeader
+        })?;
+
+    // Parse Bearer token
+    auth_header.strip_prefix("Bearer ").ok_or_else(|| {
+        warn!("Authorization header does not start with 'Bearer '");
+        SessionError::InvalidAuthorizationHeader
+    })
+}
+
 /// Session extraction errors.
 ///
 /// These errors are returned when session validation fails and are
@@ -109,8 +203,14 @@ pub enum SessionError {
     MissingAuthorizationHeader,
     /// Authorization header format is invalid.
     InvalidAuthorizationHeader,
+    /// Session cookie is missing (cookie auth mode).
+    MissingSessionCookie,
     /// Session validation failed.
     InvalidSession(String),
+    /// Portal token header is missing.
+    MissingPortalToken,
+    /// Portal link validation failed.
+    InvalidPortalLink(String),
 }
 
 impl IntoResponse for SessionError {
@@ -123,6 +223,8 @@ impl IntoResponse for SessionError {
                 StatusCode::UNAUTHORIZED,
                 "Invalid Authorization header format. Expected: 'Bearer <token>'",
             ),
+            Self::MissingSessionCookie => (StatusCode::UNAUTHORIZED, "Missing session cookie"),
+            Self::MissingPortalToken => (StatusCode::UNAUTHORIZED, "Missing X-Portal-Token header"),
             Self::InvalidSession(reason) => {
                 return (
                     StatusCode::UNAUTHORIZED,
@@ -130,6 +232,13 @@ impl IntoResponse for SessionError {
                 )
                     .into_response();
             }
+            Self::InvalidPortalLink(reason) => {
+                return (
+                    StatusCode::UNAUTHORIZED,
+                    format!("Portal link validation failed: {reason}"),
+                )
+                    .into_response();
+            }
         };
 
         (status, message).into_response()
@@ -0,0 +1,274 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Coordinated shutdown.
+//!
+//! On SIGTERM or Ctrl-C the server stops accepting connections and lets
+//! in-flight requests finish, so a bid that was submitted is either
+//! persisted or was never acknowledged. Background tasks are then told to
+//! stop: webhook and notification tasks run one final poll to flush what
+//! was committed, and jobs finish any run already in progress. Finally a
+//! shutdown audit event is recorded and the `SQLite` WAL is checkpointed.
+
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+use zab_bid_audit::{Action, Actor, AuditEvent, Cause, StateSnapshot};
+use zab_bid_persistence::{OperatorData, Persistence, PersistenceError};
+
+/// How long in-flight requests, and then background tasks, have to finish.
+pub const DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A handle background tasks use to learn that shutdown has begun.
+#[derive(Clone)]
+pub struct ShutdownSignal {
+    receiver: watch::Receiver<bool>,
+}
+
+impl ShutdownSignal {
+    /// Resolves once shutdown has begun.
+    pub async fn wait(&mut self) {
+        if self.receiver.wait_for(|stopping| *stopping).await.is_err() {
+            // The coordinator was dropped without signalling, so never stop
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+/// Tells background tasks to stop and waits for them to finish.
+pub struct Shutdown {
+    sender: watch::Sender<bool>,
+    tasks: Vec<(&'static str, JoinHandle<()>)>,
+}
+
+impl Shutdown {
+    /// Creates a coordinator with no tracked tasks.
+    #[must_use]
+    pub fn new() -> Self {
+        let (sender, _receiver): (watch::Sender<bool>, watch::Receiver<bool>) =
+            watch::channel(false);
+        Self {
+            sender,
+            tasks: Vec::new(),
+        }
+    }
+
+    /// Returns a signal for a background task to watch.
+    #[must_use]
+    pub fn signal(&self) -> ShutdownSignal {
+        ShutdownSignal {
+            receiver: self.sender.subscribe(),
+        }
+    }
+
+    /// Tracks a background task so shutdown waits for it.
+    pub fn track(&mut self, name: &'static str, task: JoinHandle<()>) {
+        self.tasks.push((name, task));
+    }
+
+    /// Signals every task to stop and waits up to `timeout` for all of them.
+    ///
+    /// Tasks still running when the timeout elapses are aborted.
+    ///
+    /// # Returns
+    ///
+    /// The number of tasks that had to be aborted.
+    pub async fn stop(self, timeout: Duration) -> usize {
+        self.sender.send_replace(true);
+
+        let deadline: tokio::time::Instant = tokio::time::Instant::now() + timeout;
+        let mut aborted: usize = 0;
+        for (name, mut task) in self.tasks {
+            match tokio::time::timeout_at(deadline, &mut task).await {
+                Ok(Ok(())) => info!(task = name, "Background task stopped"),
+                Ok(Err(e)) => warn!(task = name, error = %e, "Background task failed"),
+                Err(_) => {
+                    warn!(
+                        task = name,
+                        "Background task did not stop in time; aborting"
+                    );
+                    task.abort();
+                    aborted += 1;
+                }
+            }
+        }
+        aborted
+    }
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Resolves when the process receives Ctrl-C or, on Unix, SIGTERM.
+///
+/// # Returns
+///
+/// The name of the signal received.
+pub async fn terminated() -> &'static str {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            warn!(error = %e, "Failed to listen for Ctrl-C");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut stream) => {
+                stream.recv().await;
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to listen for SIGTERM");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => "SIGINT",
+        () = terminate => "SIGTERM",
+    }
+}
+
+/// Records the shutdown in the audit log.
+///
+/// Audit events must be attributed to an operator, so the event is
+/// recorded as the scheduler operator and skipped when that operator does
+/// not exist.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `operator_login` - The login name of the operator to attribute the event to
+/// * `reason` - Why the server is shutting down
+///
+/// # Returns
+///
+/// Whether the event was recorded.
+///
+/// # Errors
+///
+/// Returns an error if the operator cannot be read or the event cannot be
+/// persisted.
+pub fn record_shutdown(
+    persistence: &mut Persistence,
+    operator_login: &str,
+    reason: &str,
+) -> Result<bool, PersistenceError> {
+    let Some(operator) = persistence.get_operator_by_login(operator_login)? else {
+        warn!(
+            operator_login,
+            "Shutdown not audited: scheduler operator not found"
+        );
+        return Ok(false);
+    };
+    persistence.persist_audit_event(&shutdown_event(&operator, reason))?;
+    Ok(true)
+}
+
+/// Builds the global audit event recording a shutdown.
+fn shutdown_event(operator: &OperatorData, reason: &str) -> AuditEvent {
+    let actor: Actor = Actor::with_operator(
+        String::from("scheduler"),
+        String::from("system"),
+        operator.operator_id,
+        operator.login_name.clone(),
+        operator.display_name.clone(),
+    );
+    let cause: Cause = Cause::new(String::from("shutdown"), format!("{reason} received"));
+    let action: Action = Action::new(
+        String::from("ServerShutdown"),
+        Some(String::from("Server shut down")),
+    );
+    AuditEvent::new_global(
+        actor,
+        cause,
+        action,
+        StateSnapshot::new(String::from("server=running")),
+        StateSnapshot::new(String::from("server=stopped")),
+    )
+}
+
+/// Flushes the database after every writer has stopped.
+///
+/// # Arguments
+///
+/// * `persistence` - The persistence layer
+/// * `operator_login` - The operator to attribute the shutdown event to, if any
+/// * `reason` - Why the server is shutting down
+pub fn finish(persistence: &mut Persistence, operator_login: Option<&str>, reason: &str) {
+    if let Some(login) = operator_login {
+        if let Err(e) = record_shutdown(persistence, login, reason) {
+            warn!(error = %e, "Failed to record shutdown audit event");
+        }
+    } else {
+        info!("Shutdown not audited (no --scheduler-operator)");
+    }
+    if let Err(e) = persistence.checkpoint_wal() {
+        warn!(error = %e, "Failed to checkpoint WAL");
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[tokio::test]
+    async fn test_stop_waits_for_tasks_to_finish() {
+        let mut shutdown: Shutdown = Shutdown::new();
+        let finished: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+        let mut signal: ShutdownSignal = shutdown.signal();
+        let flag: Arc<AtomicBool> = Arc::clone(&finished);
+        shutdown.track(
+            "test",
+            tokio::spawn(async move {
+                signal.wait().await;
+                flag.store(true, Ordering::SeqCst);
+            }),
+        );
+
+        assert_eq!(shutdown.stop(Duration::from_secs(5)).await, 0);
+        assert!(finished.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_stop_aborts_tasks_that_ignore_the_signal() {
+        let mut shutdown: Shutdown = Shutdown::new();
+        shutdown.track("stuck", tokio::spawn(std::future::pending::<()>()));
+
+        assert_eq!(shutdown.stop(Duration::from_millis(10)).await, 1);
+    }
+
+    #[test]
+    fn test_record_shutdown_audits_as_operator() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        persistence
+            .create_operator("scheduler", "Scheduler", "password", "Admin")
+            .unwrap();
+        let before: Option<i64> = persistence.get_latest_audit_event_id().unwrap();
+
+        assert!(record_shutdown(&mut persistence, "scheduler", "SIGTERM").unwrap());
+        assert!(persistence.get_latest_audit_event_id().unwrap() > before);
+    }
+
+    #[test]
+    fn test_record_shutdown_skips_unknown_operator() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+
+        assert!(!record_shutdown(&mut persistence, "nobody", "SIGTERM").unwrap());
+        assert_eq!(persistence.get_latest_audit_event_id().unwrap(), None);
+    }
+}
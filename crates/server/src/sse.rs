@@ -0,0 +1,189 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Server-Sent Events fallback for the live update channel.
+//!
+//! Some facility networks proxy HTTP but strip WebSocket upgrades. This
+//! endpoint delivers the same [`LiveEvent`] payloads as `/ws` over a plain
+//! `text/event-stream` response.
+//!
+//! Every SSE event carries the ID of the audit event it was derived from.
+//! Browsers send that ID back in the `Last-Event-ID` header when they
+//! reconnect, and the stream resumes with the first event committed after
+//! it, so no updates are lost across a dropped connection. Clients that
+//! connect without the header start at the newest event.
+//!
+//! Streams end when the server begins shutting down, so open streams do not
+//! hold up the drain of in-flight requests. Browsers reconnect and resume.
+
+use axum::{
+    extract::State as AxumState,
+    http::{HeaderMap, StatusCode},
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures::stream::{self, Stream, StreamExt};
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+use zab_bid_persistence::Persistence;
+
+use crate::HttpError;
+use crate::audit_feed::{self, FeedBatch};
+use crate::live::LiveEvent;
+use crate::shutdown::ShutdownSignal;
+
+/// The header browsers send when resuming an event stream.
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+/// State for the SSE endpoint.
+#[derive(Clone)]
+pub struct SseState {
+    /// The shared persistence layer, read directly for resumable cursors.
+    pub persistence: Arc<Mutex<Persistence>>,
+    /// How often each stream checks for new audit events.
+    pub poll_interval: Duration,
+    /// Ends every stream when the server shuts down.
+    pub shutdown: ShutdownSignal,
+}
+
+/// Parses the `Last-Event-ID` header into an audit event ID.
+///
+/// # Arguments
+///
+/// * `headers` - The request headers
+///
+/// # Returns
+///
+/// `Ok(None)` if the header is absent or blank.
+///
+/// # Errors
+///
+/// Returns an error message if the header is present but not a valid event ID.
+fn last_event_id(headers: &HeaderMap) -> Result<Option<i64>, String> {
+    let Some(value) = headers.get(LAST_EVENT_ID_HEADER) else {
+        return Ok(None);
+    };
+    let text: &str = value
+        .to_str()
+        .map_err(|_| String::from("Last-Event-ID must be ASCII"))?
+        .trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    text.parse::<i64>()
+        .map(Some)
+        .map_err(|_| format!("Last-Event-ID '{text}' is not an audit event ID"))
+}
+
+/// Converts a mapped live event into an SSE event.
+fn to_sse_event(event_id: i64, event: &LiveEvent) -> Option<Event> {
+    match Event::default().id(event_id.to_string()).json_data(event) {
+        Ok(sse_event) => Some(sse_event),
+        Err(e) => {
+            warn!(event_id, error = %e, "Failed to serialize live event for SSE");
+            None
+        }
+    }
+}
+
+/// Handler for the SSE live update stream.
+///
+/// # Errors
+///
+/// Returns 400 if `Last-Event-ID` is malformed, or 500 if the starting
+/// cursor cannot be read.
+pub async fn sse_handler(
+    AxumState(state): AxumState<SseState>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, HttpError> {
+    let resume_from: Option<i64> = last_event_id(&headers).map_err(|message| HttpError {
+        status: StatusCode::BAD_REQUEST,
+        message,
+    })?;
+
+    let cursor: Option<i64> = match resume_from {
+        Some(event_id) => Some(event_id),
+        None => state
+            .persistence
+            .lock()
+            .await
+            .get_latest_audit_event_id()
+            .map_err(|e| HttpError {
+                status: StatusCode::INTERNAL_SERVER_ERROR,
+                message: e.to_string(),
+            })?,
+    };
+    debug!(
+        ?cursor,
+        resumed = resume_from.is_some(),
+        "SSE client connected"
+    );
+
+    let mut ticker: tokio::time::Interval = tokio::time::interval(state.poll_interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut shutdown: ShutdownSignal = state.shutdown;
+
+    let events = stream::unfold(
+        (state.persistence, cursor, ticker),
+        |(persistence, cursor, mut ticker)| async move {
+            ticker.tick().await;
+            let (sse_events, next_cursor): (Vec<Result<Event, Infallible>>, Option<i64>) =
+                match audit_feed::read_after(&persistence, cursor).await {
+                    Ok(FeedBatch { events, cursor }) => (
+                        events
+                            .iter()
+                            .filter_map(|(event_id, event)| to_sse_event(*event_id, event))
+                            .map(Ok)
+                            .collect(),
+                        cursor,
+                    ),
+                    Err(e) => {
+                        warn!(error = %e, "SSE audit poll failed");
+                        (Vec::new(), cursor)
+                    }
+                };
+            Some((stream::iter(sse_events), (persistence, next_cursor, ticker)))
+        },
+    )
+    .flatten()
+    .take_until(async move { shutdown.wait().await });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn test_last_event_id_absent() {
+        assert_eq!(last_event_id(&HeaderMap::new()), Ok(None));
+    }
+
+    #[test]
+    fn test_last_event_id_blank_is_absent() {
+        let mut headers: HeaderMap = HeaderMap::new();
+        headers.insert(LAST_EVENT_ID_HEADER, HeaderValue::from_static("  "));
+        assert_eq!(last_event_id(&headers), Ok(None));
+    }
+
+    #[test]
+    fn test_last_event_id_parses_event_id() {
+        let mut headers: HeaderMap = HeaderMap::new();
+        headers.insert(LAST_EVENT_ID_HEADER, HeaderValue::from_static("42"));
+        assert_eq!(last_event_id(&headers), Ok(Some(42)));
+    }
+
+    #[test]
+    fn test_last_event_id_rejects_garbage() {
+        let mut headers: HeaderMap = HeaderMap::new();
+        headers.insert(LAST_EVENT_ID_HEADER, HeaderValue::from_static("abc"));
+        assert!(last_event_id(&headers).is_err());
+    }
+}
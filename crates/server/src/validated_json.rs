@@ -0,0 +1,81 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! JSON request bodies with structured validation errors.
+//!
+//! Axum's `Json` extractor answers a body that parses but does not fit the
+//! request type with a plain-text 422. Request DTOs enforce their free-text
+//! limits during deserialization, so those failures are input errors the
+//! client should be able to act on. [`ValidatedJson`] reports them as
+//! [`ApiError::InvalidInput`], naming the offending field, in the usual
+//! JSON error body.
+//!
+//! Malformed JSON, a missing content type, and an oversized body keep
+//! axum's own responses.
+
+use axum::{
+    Json,
+    extract::{FromRequest, Request, rejection::JsonRejection},
+    response::{IntoResponse, Response},
+};
+use serde::de::DeserializeOwned;
+use zab_bid_api::ApiError;
+
+use crate::HttpError;
+
+/// A JSON request body whose data errors become [`ApiError::InvalidInput`].
+pub struct ValidatedJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Json::<T>::from_request(req, state).await {
+            Ok(Json(value)) => Ok(Self(value)),
+            Err(JsonRejection::JsonDataError(err)) => {
+                Err(HttpError::from(invalid_input(&err)).into_response())
+            }
+            Err(rejection) => Err(rejection.into_response()),
+        }
+    }
+}
+
+/// Translates a body that does not fit the request type into an input error.
+fn invalid_input(err: &(dyn std::error::Error + 'static)) -> ApiError {
+    let mut source: Option<&(dyn std::error::Error + 'static)> = err.source();
+    while let Some(current) = source {
+        if let Some(path_error) =
+            current.downcast_ref::<serde_path_to_error::Error<serde_json::Error>>()
+        {
+            let path: String = path_error.path().to_string();
+            return ApiError::InvalidInput {
+                field: if path == "." {
+                    String::from("body")
+                } else {
+                    path
+                },
+                message: without_position(path_error.inner()),
+            };
+        }
+        source = current.source();
+    }
+    ApiError::InvalidInput {
+        field: String::from("body"),
+        message: err.to_string(),
+    }
+}
+
+/// Renders a `serde_json` error without its line and column suffix.
+fn without_position(err: &serde_json::Error) -> String {
+    let message: String = err.to_string();
+    let suffix: String = format!(" at line {} column {}", err.line(), err.column());
+    message
+        .strip_suffix(&suffix)
+        .map_or_else(|| message.clone(), str::to_string)
+}
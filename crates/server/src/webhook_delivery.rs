@@ -0,0 +1,756 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Outbound webhook delivery and the outbox relay.
+//!
+//! Every persisted audit event is written to the transactional outbox in
+//! the same database transaction as the event itself. A background relay
+//! reads the undelivered outbox rows in order, publishes each event to the
+//! message broker when one is configured (see [`crate::event_broker`]),
+//! POSTs it to every enabled webhook whose event filter accepts it, and
+//! then marks the rows
+//! delivered. A row is only marked once all of its deliveries have
+//! finished, so an event committed before a crash is delivered after the
+//! restart: delivery is at least once, and subscribers should ignore event
+//! IDs they have already seen. Bodies are signed with HMAC-SHA256 using the
+//! webhook's shared secret:
+//!
+//! ```text
+//! X-Zabbid-Signature: sha256=<hex digest of the raw body>
+//! X-Zabbid-Event-Id: <audit event id>
+//! ```
+//!
+//! Failed deliveries are retried with exponential backoff. Once the attempts
+//! are exhausted the payload is written to the dead-letter log so operators
+//! can inspect and replay it.
+
+use futures::future::join_all;
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+use zab_bid_persistence::{
+    AuditTimelineEntry, OutboxEntryData, Persistence, PersistenceError, WebhookData,
+};
+
+use crate::event_broker::EventPublisher;
+use crate::shutdown::ShutdownSignal;
+
+/// Maximum number of outbox entries read per batch.
+const DELIVERY_BATCH_SIZE: u32 = 200;
+
+/// Header carrying the payload signature.
+pub const SIGNATURE_HEADER: &str = "X-Zabbid-Signature";
+
+/// Header carrying the audit event ID being delivered.
+pub const EVENT_ID_HEADER: &str = "X-Zabbid-Event-Id";
+
+/// Retry behaviour for a single delivery.
+#[derive(Debug, Clone, Copy)]
+pub struct DeliveryPolicy {
+    /// Total attempts before the delivery is dead-lettered.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled after each failure.
+    pub initial_backoff: Duration,
+    /// Per-request timeout.
+    pub timeout: Duration,
+}
+
+impl Default for DeliveryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// The JSON body sent to webhook subscribers.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookPayload {
+    /// The audit event ID.
+    pub event_id: i64,
+    /// The audit event ULID, if it has one.
+    pub event_ulid: Option<String>,
+    /// The audit action name (e.g. `RegisterUser`).
+    pub action: String,
+    /// Optional action details.
+    pub details: Option<String>,
+    /// The login name of the operator who performed the action, if any.
+    pub actor_login_name: Option<String>,
+    /// The bid year the event applies to, if scoped.
+    pub bid_year: Option<u16>,
+    /// The area code the event applies to, if scoped.
+    pub area: Option<String>,
+    /// When the event was persisted.
+    pub created_at: Option<String>,
+    /// The state snapshot before the action.
+    pub before: String,
+    /// The state snapshot after the action.
+    pub after: String,
+}
+
+impl WebhookPayload {
+    /// Builds the payload for a persisted audit event.
+    ///
+    /// Returns `None` for events without an ID.
+    #[must_use]
+    pub fn from_entry(entry: &AuditTimelineEntry) -> Option<Self> {
+        let event = &entry.event;
+        Some(Self {
+            event_id: event.event_id?,
+            event_ulid: event.event_ulid.as_ref().map(ToString::to_string),
+            action: event.action.name.clone(),
+            details: event.action.details.clone(),
+            actor_login_name: event.actor.operator_login_name.clone(),
+            bid_year: event.bid_year.as_ref().map(zab_bid_domain::BidYear::year),
+            area: event.area.as_ref().map(|a| a.area_code().to_string()),
+            created_at: entry.created_at.clone(),
+            before: event.before.data.clone(),
+            after: event.after.data.clone(),
+        })
+    }
+}
+
+/// Computes the hex-encoded HMAC-SHA256 of `body` keyed with `secret`.
+#[must_use]
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(secret.as_bytes())
+        .unwrap_or_else(|_| unreachable!("HMAC accepts keys of any length"));
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Sends one POST and reports any transport error or non-2xx status.
+async fn post_once(
+    client: &reqwest::Client,
+    webhook: &WebhookData,
+    event_id: i64,
+    body: &str,
+    signature: &str,
+    timeout: Duration,
+) -> Result<(), String> {
+    let response = client
+        .post(&webhook.url)
+        .timeout(timeout)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(SIGNATURE_HEADER, format!("sha256={signature}"))
+        .header(EVENT_ID_HEADER, event_id.to_string())
+        .body(body.to_string())
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("HTTP {}", response.status()))
+    }
+}
+
+/// Delivers one payload, retrying with backoff and dead-lettering on failure.
+///
+/// # Returns
+///
+/// `true` if the subscriber accepted the delivery.
+async fn deliver(
+    persistence: &Mutex<Persistence>,
+    client: &reqwest::Client,
+    policy: &DeliveryPolicy,
+    webhook: &WebhookData,
+    event_id: i64,
+    body: &str,
+) -> bool {
+    let signature: String = sign(&webhook.secret, body.as_bytes());
+    let max_attempts: u32 = policy.max_attempts.max(1);
+    let mut backoff: Duration = policy.initial_backoff;
+    let mut last_error: String = String::new();
+
+    for attempt in 1..=max_attempts {
+        match post_once(client, webhook, event_id, body, &signature, policy.timeout).await {
+            Ok(()) => {
+                debug!(
+                    webhook_id = webhook.webhook_id,
+                    event_id, attempt, "Webhook delivered"
+                );
+                return true;
+            }
+            Err(e) => {
+                debug!(
+                    webhook_id = webhook.webhook_id,
+                    event_id,
+                    attempt,
+                    error = %e,
+                    "Webhook delivery attempt failed"
+                );
+                last_error = e;
+            }
+        }
+        if attempt < max_attempts {
+            tokio::time::sleep(backoff).await;
+            backoff = backoff.saturating_mul(2);
+        }
+    }
+
+    let attempts: i32 = i32::try_from(max_attempts).unwrap_or(i32::MAX);
+    let recorded: Result<i64, PersistenceError> = persistence
+        .lock()
+        .await
+        .record_webhook_dead_letter(webhook.webhook_id, event_id, body, attempts, &last_error);
+    if let Err(e) = recorded {
+        warn!(
+            webhook_id = webhook.webhook_id,
+            event_id,
+            error = %e,
+            "Failed to record webhook dead letter"
+        );
+    }
+    false
+}
+
+/// Serializes the payload of every audit event read.
+fn encode_entries(entries: &[AuditTimelineEntry]) -> Vec<(WebhookPayload, String)> {
+    let mut encoded: Vec<(WebhookPayload, String)> = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let Some(payload) = WebhookPayload::from_entry(entry) else {
+            continue;
+        };
+        match serde_json::to_string(&payload) {
+            Ok(body) => encoded.push((payload, body)),
+            Err(e) => {
+                warn!(event_id = payload.event_id, error = %e, "Failed to encode webhook payload");
+            }
+        }
+    }
+    encoded
+}
+
+/// Publishes pending entries to the broker in outbox order.
+///
+/// # Returns
+///
+/// The number of leading entries published. Publishing stops at the first
+/// failure, so the entries from that one on stay pending.
+async fn publish_to_broker(
+    publisher: &dyn EventPublisher,
+    pending: &[OutboxEntryData],
+    encoded: &[(WebhookPayload, String)],
+) -> usize {
+    for (index, outbox_entry) in pending.iter().enumerate() {
+        let Some((payload, body)) = encoded
+            .iter()
+            .find(|(payload, _)| payload.event_id == outbox_entry.audit_event_id)
+        else {
+            continue;
+        };
+        if let Err(e) = publisher.publish(payload, body).await {
+            warn!(
+                event_id = payload.event_id,
+                error = %e,
+                "Failed to publish audit event to broker"
+            );
+            return index;
+        }
+    }
+    pending.len()
+}
+
+/// Delivers every pending outbox entry to the broker and matching webhooks.
+///
+/// Entries are published to the broker, if one is configured, before any
+/// webhook delivery. Deliveries for a batch run concurrently; the batch's
+/// outbox entries are only marked delivered once every delivery has either
+/// succeeded or been dead-lettered. Entries nothing wants are marked
+/// delivered straight away. If the broker rejects an entry, that entry and
+/// the rest of the batch stay pending, without webhook deliveries, until
+/// the next poll.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer
+/// * `client` - The HTTP client used for deliveries
+/// * `policy` - Retry behaviour for each delivery
+/// * `publisher` - The message broker, if one is configured
+///
+/// # Returns
+///
+/// The number of successful webhook deliveries.
+///
+/// # Errors
+///
+/// Returns an error if the outbox or webhook list cannot be read, or the
+/// entries cannot be marked delivered.
+pub async fn poll_once(
+    persistence: &Mutex<Persistence>,
+    client: &reqwest::Client,
+    policy: &DeliveryPolicy,
+    publisher: Option<&dyn EventPublisher>,
+) -> Result<usize, PersistenceError> {
+    let mut delivered: usize = 0;
+
+    loop {
+        let (pending, entries, webhooks): (
+            Vec<OutboxEntryData>,
+            Vec<AuditTimelineEntry>,
+            Vec<WebhookData>,
+        ) = {
+            let mut guard = persistence.lock().await;
+            let pending: Vec<OutboxEntryData> =
+                guard.list_pending_outbox_entries(DELIVERY_BATCH_SIZE)?;
+            let webhooks: Vec<WebhookData> = if pending.is_empty() {
+                Vec::new()
+            } else {
+                guard
+                    .list_webhooks()?
+                    .into_iter()
+                    .filter(|w| w.is_enabled)
+                    .collect()
+            };
+            let entries: Vec<AuditTimelineEntry> = if webhooks.is_empty() && publisher.is_none() {
+                Vec::new()
+            } else {
+                let event_ids: Vec<i64> = pending.iter().map(|e| e.audit_event_id).collect();
+                guard.get_audit_timeline_entries(&event_ids)?
+            };
+            drop(guard);
+            (pending, entries, webhooks)
+        };
+        if pending.is_empty() {
+            break;
+        }
+
+        let encoded: Vec<(WebhookPayload, String)> = encode_entries(&entries);
+        let handled: usize = match publisher {
+            Some(publisher) => publish_to_broker(publisher, &pending, &encoded).await,
+            None => pending.len(),
+        };
+        let handled_entries: &[OutboxEntryData] = &pending[..handled];
+
+        let deliveries = encoded
+            .iter()
+            .filter(|(payload, _)| {
+                handled_entries
+                    .iter()
+                    .any(|e| e.audit_event_id == payload.event_id)
+            })
+            .flat_map(|(payload, body)| {
+                webhooks
+                    .iter()
+                    .filter(move |w| w.accepts(&payload.action))
+                    .map(move |webhook| {
+                        deliver(persistence, client, policy, webhook, payload.event_id, body)
+                    })
+            });
+        delivered += join_all(deliveries)
+            .await
+            .into_iter()
+            .filter(|ok| *ok)
+            .count();
+
+        let outbox_ids: Vec<i64> = handled_entries.iter().map(|e| e.outbox_id).collect();
+        persistence
+            .lock()
+            .await
+            .mark_outbox_entries_delivered(&outbox_ids)?;
+        if handled < pending.len() || pending.len() < DELIVERY_BATCH_SIZE as usize {
+            break;
+        }
+    }
+
+    Ok(delivered)
+}
+
+/// Spawns the relay task that delivers outbox entries to the broker and
+/// webhooks.
+///
+/// Entries left pending by a previous run, including one that stopped
+/// mid-delivery, are delivered on the first poll.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer
+/// * `client` - The HTTP client used for deliveries
+/// * `poll_interval` - How often to check the outbox
+/// * `policy` - Retry behaviour for each delivery
+/// * `publisher` - The message broker, if one is configured
+/// * `shutdown` - Stops the task, after a final delivery pass, when the
+///   server shuts down
+pub fn spawn(
+    persistence: Arc<Mutex<Persistence>>,
+    client: reqwest::Client,
+    poll_interval: Duration,
+    policy: DeliveryPolicy,
+    publisher: Option<Box<dyn EventPublisher>>,
+    mut shutdown: ShutdownSignal,
+) -> tokio::task::JoinHandle<()> {
+    debug!("Starting webhook delivery");
+
+    tokio::spawn(async move {
+        let publisher: Option<&dyn EventPublisher> = publisher.as_deref();
+        let mut ticker: tokio::time::Interval = tokio::time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                () = shutdown.wait() => break,
+            }
+            if let Err(e) = poll_once(&persistence, &client, &policy, publisher).await {
+                warn!(error = %e, "Webhook delivery poll failed");
+            }
+        }
+        // Deliver everything committed before the server stopped accepting
+        if let Err(e) = poll_once(&persistence, &client, &policy, publisher).await {
+            warn!(error = %e, "Final webhook delivery poll failed");
+        }
+        debug!("Webhook delivery stopped");
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use axum::{Router, http::HeaderMap, routing::post};
+    use zab_bid::{BootstrapMetadata, Command, apply_bootstrap};
+    use zab_bid_audit::{Actor, Cause};
+    use zab_bid_domain::BidYear;
+    use zab_bid_persistence::WebhookDeadLetterData;
+
+    fn fast_policy() -> DeliveryPolicy {
+        DeliveryPolicy {
+            max_attempts: 2,
+            initial_backoff: Duration::from_millis(1),
+            timeout: Duration::from_secs(2),
+        }
+    }
+
+    /// Persists a `CreateBidYear` audit event and returns its ID.
+    fn persist_event(persistence: &mut Persistence) -> i64 {
+        let operator_id: i64 = persistence
+            .create_operator("admin", "Admin", "password", "Admin")
+            .unwrap();
+        let result = apply_bootstrap(
+            &BootstrapMetadata::new(),
+            &BidYear::new(2026),
+            Command::CreateBidYear {
+                year: 2026,
+                start_date: time::Date::from_calendar_date(2026, time::Month::January, 4).unwrap(),
+                num_pay_periods: 26,
+            },
+            Actor::with_operator(
+                String::from("admin"),
+                String::from("admin"),
+                operator_id,
+                String::from("admin"),
+                String::from("Admin"),
+            ),
+            Cause::new(String::from("test"), String::from("Test")),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&result).unwrap()
+    }
+
+    #[test]
+    fn test_sign_matches_rfc_4231_vector() {
+        assert_eq!(
+            sign("Jefe", b"what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_webhook_is_dead_lettered() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let webhook_id: i64 = persistence
+            .create_webhook(
+                "http://127.0.0.1:1/hook",
+                "0123456789abcdef",
+                &[String::from("*")],
+            )
+            .unwrap();
+        let event_id: i64 = persist_event(&mut persistence);
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+
+        let delivered: usize =
+            poll_once(&persistence, &reqwest::Client::new(), &fast_policy(), None)
+                .await
+                .unwrap();
+
+        assert_eq!(delivered, 0);
+        let dead_letters: Vec<WebhookDeadLetterData> = persistence
+            .lock()
+            .await
+            .list_webhook_dead_letters(Some(webhook_id), 10)
+            .unwrap();
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].attempts, 2);
+        assert_eq!(dead_letters[0].audit_event_id, event_id);
+        assert!(dead_letters[0].payload.contains("CreateBidYear"));
+    }
+
+    #[tokio::test]
+    async fn test_delivery_is_signed() {
+        let received: Arc<Mutex<Vec<(HeaderMap, String)>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink: Arc<Mutex<Vec<(HeaderMap, String)>>> = Arc::clone(&received);
+        let app: Router = Router::new().route(
+            "/hook",
+            post(move |headers: HeaderMap, body: String| {
+                let sink = Arc::clone(&sink);
+                async move {
+                    sink.lock().await.push((headers, body));
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr: std::net::SocketAddr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let secret: &str = "0123456789abcdef";
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        persistence
+            .create_webhook(
+                &format!("http://{addr}/hook"),
+                secret,
+                &[String::from("CreateBidYear")],
+            )
+            .unwrap();
+        let event_id: i64 = persist_event(&mut persistence);
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+
+        let delivered: usize =
+            poll_once(&persistence, &reqwest::Client::new(), &fast_policy(), None)
+                .await
+                .unwrap();
+
+        assert_eq!(delivered, 1);
+        let received = received.lock().await;
+        assert_eq!(received.len(), 1);
+        let (headers, body) = &received[0];
+        assert_eq!(
+            headers.get(SIGNATURE_HEADER).unwrap().to_str().unwrap(),
+            format!("sha256={}", sign(secret, body.as_bytes()))
+        );
+        assert_eq!(
+            headers.get(EVENT_ID_HEADER).unwrap().to_str().unwrap(),
+            event_id.to_string()
+        );
+        let payload: serde_json::Value = serde_json::from_str(body).unwrap();
+        drop(received);
+        assert_eq!(payload["action"], "CreateBidYear");
+        assert_eq!(payload["bid_year"], 2026);
+    }
+
+    #[tokio::test]
+    async fn test_filtered_and_disabled_webhooks_are_skipped() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let webhook_id: i64 = persistence
+            .create_webhook(
+                "http://127.0.0.1:1/hook",
+                "0123456789abcdef",
+                &[String::from("*")],
+            )
+            .unwrap();
+        persistence
+            .update_webhook(
+                webhook_id,
+                "http://127.0.0.1:1/hook",
+                &[String::from("*")],
+                false,
+            )
+            .unwrap();
+        persistence
+            .create_webhook(
+                "http://127.0.0.1:1/other",
+                "0123456789abcdef",
+                &[String::from("RegisterUser")],
+            )
+            .unwrap();
+        let event_id: i64 = persist_event(&mut persistence);
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+
+        poll_once(&persistence, &reqwest::Client::new(), &fast_policy(), None)
+            .await
+            .unwrap();
+
+        let dead_letters: Vec<WebhookDeadLetterData> = persistence
+            .lock()
+            .await
+            .list_webhook_dead_letters(None, 10)
+            .unwrap();
+        assert!(dead_letters.is_empty());
+        // Nothing wanted the event, so it leaves the outbox anyway
+        let entry: OutboxEntryData = persistence
+            .lock()
+            .await
+            .get_outbox_entry_for_event(event_id)
+            .unwrap()
+            .unwrap();
+        assert!(entry.delivered_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_events_committed_before_start_are_delivered_once() {
+        let received: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink: Arc<Mutex<Vec<String>>> = Arc::clone(&received);
+        let app: Router = Router::new().route(
+            "/hook",
+            post(move |body: String| {
+                let sink = Arc::clone(&sink);
+                async move {
+                    sink.lock().await.push(body);
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr: std::net::SocketAddr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        // The event is committed while no relay is running, as after a crash
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        persistence
+            .create_webhook(
+                &format!("http://{addr}/hook"),
+                "0123456789abcdef",
+                &[String::from("*")],
+            )
+            .unwrap();
+        let event_id: i64 = persist_event(&mut persistence);
+        assert!(
+            persistence
+                .list_pending_outbox_entries(10)
+                .unwrap()
+                .iter()
+                .any(|entry| entry.audit_event_id == event_id)
+        );
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let client: reqwest::Client = reqwest::Client::new();
+
+        let first: usize = poll_once(&persistence, &client, &fast_policy(), None)
+            .await
+            .unwrap();
+        let second: usize = poll_once(&persistence, &client, &fast_policy(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(first, 1);
+        assert_eq!(second, 0);
+        assert_eq!(received.lock().await.len(), 1);
+        assert!(
+            persistence
+                .lock()
+                .await
+                .list_pending_outbox_entries(10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    /// Records published events, failing on event IDs listed in `reject`.
+    #[derive(Default)]
+    struct RecordingPublisher {
+        published: std::sync::Mutex<Vec<i64>>,
+        reject: Vec<i64>,
+    }
+
+    impl EventPublisher for RecordingPublisher {
+        fn publish<'a>(
+            &'a self,
+            payload: &'a WebhookPayload,
+            _body: &'a str,
+        ) -> futures::future::BoxFuture<'a, Result<(), String>> {
+            Box::pin(async move {
+                if self.reject.contains(&payload.event_id) {
+                    return Err(String::from("broker unavailable"));
+                }
+                self.published.lock().unwrap().push(payload.event_id);
+                Ok(())
+            })
+        }
+    }
+
+    fn pending_event_ids(persistence: &mut Persistence) -> Vec<i64> {
+        persistence
+            .list_pending_outbox_entries(10)
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.audit_event_id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_events_are_published_to_broker_without_webhooks() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let event_id: i64 = persist_event(&mut persistence);
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let publisher: RecordingPublisher = RecordingPublisher::default();
+
+        poll_once(
+            &persistence,
+            &reqwest::Client::new(),
+            &fast_policy(),
+            Some(&publisher),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(*publisher.published.lock().unwrap(), vec![event_id]);
+        assert!(pending_event_ids(&mut *persistence.lock().await).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_broker_failure_leaves_events_pending_and_undelivered() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        persistence
+            .create_webhook(
+                "http://127.0.0.1:1/hook",
+                "0123456789abcdef",
+                &[String::from("*")],
+            )
+            .unwrap();
+        let event_id: i64 = persist_event(&mut persistence);
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let publisher: RecordingPublisher = RecordingPublisher {
+            reject: vec![event_id],
+            ..RecordingPublisher::default()
+        };
+
+        poll_once(
+            &persistence,
+            &reqwest::Client::new(),
+            &fast_policy(),
+            Some(&publisher),
+        )
+        .await
+        .unwrap();
+
+        // Not attempted against the webhook, so nothing was dead-lettered
+        let mut guard = persistence.lock().await;
+        assert!(
+            guard
+                .list_webhook_dead_letters(None, 10)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(pending_event_ids(&mut guard), vec![event_id]);
+        drop(guard);
+
+        // The next poll retries once the broker accepts it
+        let publisher: RecordingPublisher = RecordingPublisher::default();
+        poll_once(
+            &persistence,
+            &reqwest::Client::new(),
+            &fast_policy(),
+            Some(&publisher),
+        )
+        .await
+        .unwrap();
+        assert_eq!(*publisher.published.lock().unwrap(), vec![event_id]);
+        assert!(pending_event_ids(&mut *persistence.lock().await).is_empty());
+    }
+}
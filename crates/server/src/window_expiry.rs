@@ -0,0 +1,354 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Automatic bid window expiry.
+//!
+//! A background job walks every round with bid windows in a
+//! `BiddingActive` bid year and hands the floor to the next bidder when
+//! the current bidder's window elapses, so no operator has to watch the
+//! clock. A round is started once its first window opens. When the
+//! elapsed window has no submitted bid, a `WindowExpired` audit event is
+//! recorded and the configured [`MissedWindowPolicy`] decides whether the
+//! bidder is marked missed.
+//!
+//! The same poll expires waitlist offers whose answer window has passed,
+//! passing each freed slot to the next controller in bid order.
+//!
+//! The poll runs as the `window_expiry` [`Job`] under the operator named
+//! by `--scheduler-operator`. Polls are skipped while that operator does
+//! not exist or is disabled.
+
+use futures::future::BoxFuture;
+use std::collections::BTreeSet;
+use std::time::Duration;
+use time::OffsetDateTime;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+use zab_bid::BootstrapMetadata;
+use zab_bid_api::MissedWindowPolicy;
+use zab_bid_persistence::{ActiveBidWindowData, Persistence, PersistenceError};
+
+use crate::jobs::Job;
+
+/// How often the window expiry job checks for elapsed windows.
+pub const WINDOW_EXPIRY_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Advances every round whose current window has elapsed at `now`, after
+/// expiring lapsed waitlist offers.
+///
+/// A failure in one round, or in waitlist expiry, is logged and does not
+/// stop the others.
+///
+/// # Arguments
+///
+/// * `persistence` - The shared persistence layer
+/// * `operator_login` - The login name of the operator the task acts as
+/// * `policy` - How to treat a bidder whose window elapsed without a bid
+/// * `now` - The current time
+///
+/// # Returns
+///
+/// The number of rounds advanced.
+///
+/// # Errors
+///
+/// Returns an error if the operator, metadata, or active windows cannot be
+/// read.
+pub async fn poll_once(
+    persistence: &Mutex<Persistence>,
+    operator_login: &str,
+    policy: MissedWindowPolicy,
+    now: OffsetDateTime,
+) -> Result<usize, PersistenceError> {
+    let mut persistence = persistence.lock().await;
+
+    let Some(operator) = persistence.get_operator_by_login(operator_login)? else {
+        warn!(
+            operator_login,
+            "Window expiry skipped: scheduler operator not found"
+        );
+        return Ok(0);
+    };
+    if operator.is_disabled {
+        warn!(
+            operator_login,
+            "Window expiry skipped: scheduler operator is disabled"
+        );
+        return Ok(0);
+    }
+
+    let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata()?;
+    match zab_bid_api::expire_waitlist_offers(&mut persistence, &metadata, &operator, now) {
+        Ok(0) => {}
+        Ok(expired) => info!(expired, "Expired lapsed waitlist offers"),
+        Err(e) => warn!(error = %e, "Waitlist offer expiry failed"),
+    }
+
+    let windows: Vec<ActiveBidWindowData> = persistence.list_active_bid_windows()?;
+    let rounds: BTreeSet<(i64, i64)> = windows
+        .iter()
+        .map(|window| (window.area_id, window.round_id))
+        .collect();
+    if rounds.is_empty() {
+        return Ok(0);
+    }
+
+    let mut advanced: usize = 0;
+    for (area_id, round_id) in rounds {
+        match zab_bid_api::advance_elapsed_bidder(
+            &mut persistence,
+            &metadata,
+            area_id,
+            round_id,
+            &operator,
+            policy,
+            now,
+        ) {
+            Ok(Some(outcome)) => {
+                if let Some(user_id) = outcome.expired_user_id {
+                    info!(
+                        area_id,
+                        round_id,
+                        user_id,
+                        marked_missed = outcome.marked_missed,
+                        "Bid window expired without a submitted bid"
+                    );
+                }
+                info!(
+                    area_id,
+                    round_id,
+                    next_user_id = ?outcome.advance.next_user_id,
+                    "Advanced round on window expiry"
+                );
+                advanced += 1;
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warn!(area_id, round_id, error = %e, "Window expiry failed");
+            }
+        }
+    }
+    drop(persistence);
+
+    Ok(advanced)
+}
+
+/// The background job that expires elapsed bid windows.
+pub struct WindowExpiryJob {
+    /// The login name of the operator the job acts as.
+    pub operator_login: String,
+    /// How to treat a bidder whose window elapsed without a bid.
+    pub policy: MissedWindowPolicy,
+}
+
+impl Job for WindowExpiryJob {
+    fn name(&self) -> &'static str {
+        "window_expiry"
+    }
+
+    fn run<'a>(
+        &'a self,
+        persistence: &'a Mutex<Persistence>,
+        now: OffsetDateTime,
+    ) -> BoxFuture<'a, Result<String, String>> {
+        Box::pin(async move {
+            let advanced: usize = poll_once(persistence, &self.operator_login, self.policy, now)
+                .await
+                .map_err(|e| format!("Window expiry poll failed: {e}"))?;
+            Ok(format!("Advanced {advanced} rounds"))
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+    use zab_bid::{BootstrapResult, Command, State, TransitionResult, apply, apply_bootstrap};
+    use zab_bid_audit::{Actor, Cause};
+    use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, UserType};
+    use zab_bid_persistence::NewBidWindow;
+
+    fn admin(operator_id: i64) -> Actor {
+        Actor::with_operator(
+            String::from("admin"),
+            String::from("admin"),
+            operator_id,
+            String::from("admin"),
+            String::from("Admin"),
+        )
+    }
+
+    fn cause() -> Cause {
+        Cause::new(String::from("test"), String::from("Test"))
+    }
+
+    /// Persists a `BiddingActive` 2026/North with one round and one
+    /// window for user AB from 13:00 to 14:00 UTC on 2026-03-02.
+    fn bidding_round(persistence: &mut Persistence) {
+        let operator_id: i64 = persistence
+            .create_operator("admin", "Admin", "password", "Admin")
+            .unwrap();
+        let bid_year: BidYear = BidYear::new(2026);
+
+        let result: BootstrapResult = apply_bootstrap(
+            &BootstrapMetadata::new(),
+            &bid_year,
+            Command::CreateBidYear {
+                year: 2026,
+                start_date: time::Date::from_calendar_date(2026, time::Month::January, 4).unwrap(),
+                num_pay_periods: 26,
+            },
+            admin(operator_id),
+            cause(),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&result).unwrap();
+
+        let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+        let result: BootstrapResult = apply_bootstrap(
+            &metadata,
+            &bid_year,
+            Command::CreateArea {
+                area_id: String::from("North"),
+            },
+            admin(operator_id),
+            cause(),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&result).unwrap();
+
+        let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+        let area: Area = Area::new("North");
+        let state: State = persistence.get_current_state(&bid_year, &area).unwrap();
+        let result: TransitionResult = apply(
+            &metadata,
+            &state,
+            &bid_year,
+            Command::RegisterUser {
+                initials: Initials::new("AB"),
+                name: String::from("Alice Baker"),
+                area: area.clone(),
+                user_type: UserType::CPC,
+                crew: Some(Crew::new(1).unwrap()),
+                seniority_data: SeniorityData::new(
+                    String::from("2019-01-15"),
+                    String::from("2019-06-01"),
+                    String::from("2020-01-15"),
+                    String::from("2020-01-15"),
+                    None,
+                ),
+            },
+            admin(operator_id),
+            cause(),
+        )
+        .unwrap();
+        persistence.persist_transition(&result).unwrap();
+
+        let state: State = persistence.get_current_state(&bid_year, &area).unwrap();
+        let user_id: i64 = state.users.values().next().unwrap().user_id.unwrap();
+        let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+        let (bid_year, area) = metadata.areas.first().unwrap();
+        let bid_year_id: i64 = bid_year.bid_year_id().unwrap();
+        let area_id: i64 = area.area_id().unwrap();
+
+        let round_group_id: i64 = persistence
+            .insert_round_group(bid_year_id, "Default", true)
+            .unwrap();
+        let round_id: i64 = persistence
+            .insert_round(round_group_id, 1, "Round 1", 2, 1, 80, false, false)
+            .unwrap();
+        persistence
+            .bulk_insert_bid_windows(&[NewBidWindow {
+                bid_year_id,
+                area_id,
+                user_id,
+                round_id,
+                window_start_datetime: String::from("2026-03-02T13:00:00Z"),
+                window_end_datetime: String::from("2026-03-02T14:00:00Z"),
+            }])
+            .unwrap();
+        persistence
+            .update_lifecycle_state(bid_year_id, "BiddingActive")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_elapsed_window_is_expired_and_round_advanced() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        bidding_round(&mut persistence);
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+        let policy: MissedWindowPolicy = MissedWindowPolicy::MarkMissed;
+
+        let before_open: OffsetDateTime = datetime!(2026-03-02 12:00 UTC);
+        assert_eq!(
+            poll_once(&persistence, "admin", policy, before_open)
+                .await
+                .unwrap(),
+            0
+        );
+
+        let opened: OffsetDateTime = datetime!(2026-03-02 13:00 UTC);
+        assert_eq!(
+            poll_once(&persistence, "admin", policy, opened)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            poll_once(&persistence, "admin", policy, opened)
+                .await
+                .unwrap(),
+            0
+        );
+
+        let elapsed: OffsetDateTime = datetime!(2026-03-02 14:00 UTC);
+        assert_eq!(
+            poll_once(&persistence, "admin", policy, elapsed)
+                .await
+                .unwrap(),
+            1
+        );
+
+        let actions: Vec<String> = persistence
+            .lock()
+            .await
+            .get_audit_timeline(&BidYear::new(2026), &Area::new("North"))
+            .unwrap()
+            .into_iter()
+            .map(|event| event.action.name)
+            .filter(|name| name == "AdvanceBidder" || name == "WindowExpired")
+            .collect();
+        assert_eq!(
+            actions,
+            vec!["AdvanceBidder", "WindowExpired", "AdvanceBidder"]
+        );
+
+        // The round is complete; nothing further happens
+        let later: OffsetDateTime = datetime!(2026-03-02 18:00 UTC);
+        assert_eq!(
+            poll_once(&persistence, "admin", policy, later)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_operator_skips_poll() {
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        bidding_round(&mut persistence);
+        let persistence: Mutex<Persistence> = Mutex::new(persistence);
+
+        let now: OffsetDateTime = datetime!(2026-03-02 13:00 UTC);
+        assert_eq!(
+            poll_once(&persistence, "nobody", MissedWindowPolicy::MarkMissed, now)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+}
@@ -0,0 +1,603 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Durable write-ahead queue for transition persistence.
+//!
+//! Persisting a transition writes its audit event and, for some actions, a
+//! full state snapshot while the persistence lock is held. With
+//! `--write-queue`, checkpoint, finalize, and rollback transitions are
+//! instead appended to a local file and synced to disk before the request
+//! is acknowledged, and a background flusher persists them afterwards.
+//!
+//! Entries are flushed strictly in the order they were appended, so every
+//! `(bid year, area)` scope has its transitions applied in order. Handlers
+//! flush a scope's queued transitions before reading its state, so new
+//! transitions are always computed from persisted state. Each flush records
+//! the queue's progress in the same database transaction as the
+//! transitions, so a queue file replayed after a crash never persists a
+//! transition twice.
+//!
+//! The file holds one entry per line: the entry's sequence number, a tab,
+//! and the encoded transition. It is truncated whenever the queue drains.
+
+use axum::{Json, Router, extract::State as AxumState, routing::get};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::{Arc, MutexGuard};
+use std::time::{Duration, Instant};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tokio::sync::{Mutex, Notify};
+use tracing::{debug, info, warn};
+use zab_bid::TransitionResult;
+use zab_bid_persistence::{
+    Persistence, PersistenceError, decode_queued_transition, encode_queued_transition,
+};
+
+use crate::shutdown::ShutdownSignal;
+
+/// How often the flusher persists queued transitions when not woken early.
+pub const FLUSH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Maximum number of transitions persisted in one database transaction.
+const FLUSH_BATCH_SIZE: usize = 256;
+
+/// Errors reading, appending to, or flushing a write-ahead queue.
+#[derive(Debug)]
+pub enum WriteQueueError {
+    /// The queue file could not be read or written.
+    Io(std::io::Error),
+    /// A queued transition could not be encoded, decoded, or persisted.
+    Persistence(PersistenceError),
+    /// A line of the queue file is not a queue entry.
+    Corrupt {
+        /// The 1-based line number.
+        line: usize,
+        /// Why the line could not be read.
+        reason: String,
+    },
+}
+
+impl std::fmt::Display for WriteQueueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Write queue I/O failed: {e}"),
+            Self::Persistence(e) => write!(f, "Write queue persistence failed: {e}"),
+            Self::Corrupt { line, reason } => {
+                write!(f, "Write queue file is corrupt at line {line}: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WriteQueueError {}
+
+impl From<std::io::Error> for WriteQueueError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<PersistenceError> for WriteQueueError {
+    fn from(err: PersistenceError) -> Self {
+        Self::Persistence(err)
+    }
+}
+
+/// Persistence lag and throughput of a write-ahead queue.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WriteQueueStats {
+    /// Transitions acknowledged but not yet persisted.
+    pub pending: usize,
+    /// Age of the oldest pending transition in milliseconds.
+    pub lag_ms: u64,
+    /// Highest lag seen by any flush, in milliseconds.
+    pub max_lag_ms: u64,
+    /// Transitions appended since the server started.
+    pub enqueued: u64,
+    /// Transitions persisted since the server started.
+    pub flushed: u64,
+    /// Flushes that failed and will be retried.
+    pub failed_flushes: u64,
+    /// Duration of the latest successful flush in milliseconds.
+    pub last_flush_ms: u64,
+    /// The error of the latest flush, if it failed.
+    pub last_error: Option<String>,
+}
+
+/// A transition appended to the file but not yet persisted.
+struct Pending {
+    sequence: i64,
+    /// The `(bid year, area code)` scope the transition applies to.
+    scope: (u16, String),
+    enqueued_at: Instant,
+    transition: TransitionResult,
+}
+
+impl Pending {
+    fn new(sequence: i64, transition: TransitionResult) -> Self {
+        Self {
+            sequence,
+            scope: scope_of(&transition),
+            enqueued_at: Instant::now(),
+            transition,
+        }
+    }
+}
+
+/// Everything guarded by the queue lock.
+struct QueueState {
+    file: File,
+    next_sequence: i64,
+    pending: VecDeque<Pending>,
+    stats: WriteQueueStats,
+}
+
+/// A durable queue of transitions awaiting persistence.
+///
+/// Callers must hold the persistence lock while enqueueing or flushing, so
+/// entries are appended in the order their transitions were computed.
+pub struct WriteQueue {
+    name: String,
+    state: std::sync::Mutex<QueueState>,
+    wake: Notify,
+}
+
+/// Returns the `(bid year, area code)` scope a transition applies to.
+fn scope_of(transition: &TransitionResult) -> (u16, String) {
+    (
+        transition.new_state.bid_year.year(),
+        transition.new_state.area.id().to_string(),
+    )
+}
+
+/// Converts a duration to whole milliseconds, saturating.
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+impl WriteQueue {
+    /// Opens the queue file at `path`, creating it if needed.
+    ///
+    /// Entries the database has not yet persisted become pending and are
+    /// flushed by the next flush. A partially written final line, left by
+    /// a crash during an append that was never acknowledged, is discarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The queue file
+    /// * `persistence` - The persistence layer holding the queue's progress
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or any complete line is
+    /// not a queue entry.
+    pub fn open(path: &Path, persistence: &mut Persistence) -> Result<Self, WriteQueueError> {
+        let name: String = path.display().to_string();
+        let persisted: i64 = persistence.get_write_queue_progress(&name)?.unwrap_or(0);
+
+        let mut file: File = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)?;
+        let mut pending: VecDeque<Pending> = VecDeque::new();
+        let mut last_sequence: i64 = persisted;
+        let mut valid_len: u64 = 0;
+        let mut reader: BufReader<&File> = BufReader::new(&file);
+        let mut line: String = String::new();
+        let mut number: usize = 0;
+        while reader.read_line(&mut line)? > 0 {
+            number += 1;
+            let Some(entry) = line.strip_suffix('\n') else {
+                warn!(path = %name, line = number, "Discarding partially written queue entry");
+                break;
+            };
+            let (sequence, encoded) =
+                entry
+                    .split_once('\t')
+                    .ok_or_else(|| WriteQueueError::Corrupt {
+                        line: number,
+                        reason: String::from("missing sequence number"),
+                    })?;
+            let sequence: i64 = sequence.parse().map_err(|e| WriteQueueError::Corrupt {
+                line: number,
+                reason: format!("invalid sequence number: {e}"),
+            })?;
+            if sequence > persisted {
+                pending.push_back(Pending::new(sequence, decode_queued_transition(encoded)?));
+            }
+            last_sequence = last_sequence.max(sequence);
+            valid_len += line.len() as u64;
+            line.clear();
+        }
+        drop(reader);
+        file.set_len(valid_len)?;
+        file.seek(SeekFrom::End(0))?;
+
+        if !pending.is_empty() {
+            info!(path = %name, pending = pending.len(), "Replaying write queue");
+        }
+        let stats: WriteQueueStats = WriteQueueStats {
+            pending: pending.len(),
+            ..WriteQueueStats::default()
+        };
+        Ok(Self {
+            name,
+            state: std::sync::Mutex::new(QueueState {
+                file,
+                next_sequence: last_sequence + 1,
+                pending,
+                stats,
+            }),
+            wake: Notify::new(),
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        // Every update leaves the state consistent before it can panic
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Appends a transition and syncs it to disk.
+    ///
+    /// Once this returns the transition will be persisted, even if the
+    /// server crashes first.
+    ///
+    /// # Returns
+    ///
+    /// The entry's sequence number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transition cannot be encoded or written.
+    pub fn enqueue(&self, transition: &TransitionResult) -> Result<i64, WriteQueueError> {
+        let encoded: String = encode_queued_transition(transition)?;
+        let mut state = self.lock();
+        let sequence: i64 = state.next_sequence;
+        state
+            .file
+            .write_all(format!("{sequence}\t{encoded}\n").as_bytes())?;
+        state.file.sync_data()?;
+
+        state.next_sequence += 1;
+        state
+            .pending
+            .push_back(Pending::new(sequence, transition.clone()));
+        state.stats.enqueued += 1;
+        drop(state);
+        self.wake.notify_one();
+        debug!(sequence, "Queued transition");
+        Ok(sequence)
+    }
+
+    /// Persists every pending transition, in order.
+    ///
+    /// # Returns
+    ///
+    /// The number of transitions persisted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a batch fails to persist. That batch stays
+    /// pending and is retried by the next flush.
+    pub fn flush(&self, persistence: &mut Persistence) -> Result<usize, WriteQueueError> {
+        let mut state = self.lock();
+        let mut flushed: usize = 0;
+        while !state.pending.is_empty() {
+            match self.flush_batch(&mut state, persistence) {
+                Ok(count) => flushed += count,
+                Err(e) => {
+                    state.stats.failed_flushes += 1;
+                    state.stats.last_error = Some(e.to_string());
+                    return Err(e);
+                }
+            }
+        }
+        state.stats.last_error = None;
+        drop(state);
+        Ok(flushed)
+    }
+
+    /// Persists pending transitions up to and including the last one for a
+    /// scope, so the scope's state can be read.
+    ///
+    /// Earlier transitions for other scopes are persisted first to keep the
+    /// global order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a batch fails to persist.
+    pub fn flush_scope(
+        &self,
+        persistence: &mut Persistence,
+        bid_year: u16,
+        area_code: &str,
+    ) -> Result<(), WriteQueueError> {
+        let has_scope: bool = self
+            .lock()
+            .pending
+            .iter()
+            .any(|entry| entry.scope.0 == bid_year && entry.scope.1 == area_code);
+        if has_scope {
+            self.flush(persistence)?;
+        }
+        Ok(())
+    }
+
+    /// Persists the oldest batch of pending transitions.
+    fn flush_batch(
+        &self,
+        state: &mut QueueState,
+        persistence: &mut Persistence,
+    ) -> Result<usize, WriteQueueError> {
+        let started: Instant = Instant::now();
+        let batch: Vec<(i64, TransitionResult)> = state
+            .pending
+            .iter()
+            .take(FLUSH_BATCH_SIZE)
+            .map(|entry| (entry.sequence, entry.transition.clone()))
+            .collect();
+        let lag: Duration = state
+            .pending
+            .front()
+            .map_or(Duration::ZERO, |entry| entry.enqueued_at.elapsed());
+        let now: OffsetDateTime = persistence.now();
+        let now: String = now.format(&Rfc3339).unwrap_or_else(|_| now.to_string());
+        persistence.persist_queued_transitions(&self.name, &batch, &now)?;
+
+        state.pending.drain(..batch.len());
+        if state.pending.is_empty() {
+            // Everything in the file is persisted
+            state.file.set_len(0)?;
+        }
+        state.stats.flushed += batch.len() as u64;
+        state.stats.last_flush_ms = millis(started.elapsed());
+        state.stats.max_lag_ms = state.stats.max_lag_ms.max(millis(lag));
+        Ok(batch.len())
+    }
+
+    /// Returns the queue's current lag and throughput.
+    #[must_use]
+    pub fn stats(&self) -> WriteQueueStats {
+        let state = self.lock();
+        WriteQueueStats {
+            pending: state.pending.len(),
+            lag_ms: state
+                .pending
+                .front()
+                .map_or(0, |entry| millis(entry.enqueued_at.elapsed())),
+            ..state.stats.clone()
+        }
+    }
+}
+
+/// Flushes the queue whenever transitions are enqueued, and on every tick.
+///
+/// On shutdown the queue is flushed one final time, so everything that was
+/// acknowledged is persisted before the server exits.
+///
+/// # Arguments
+///
+/// * `queue` - The queue to flush
+/// * `persistence` - The shared persistence layer
+/// * `interval` - How often to retry when no transitions are enqueued
+/// * `shutdown` - Stops the flusher
+pub fn spawn(
+    queue: Arc<WriteQueue>,
+    persistence: Arc<Mutex<Persistence>>,
+    interval: Duration,
+    mut shutdown: ShutdownSignal,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker: tokio::time::Interval = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                () = queue.wake.notified() => {}
+                _ = ticker.tick() => {}
+                () = shutdown.wait() => break,
+            }
+            let result: Result<usize, WriteQueueError> =
+                queue.flush(&mut *persistence.lock().await);
+            if let Err(e) = result {
+                warn!(error = %e, "Write queue flush failed");
+            }
+        }
+        let result: Result<usize, WriteQueueError> = queue.flush(&mut *persistence.lock().await);
+        match result {
+            Ok(flushed) => debug!(flushed, "Write queue flusher stopped"),
+            Err(e) => warn!(error = %e, "Final write queue flush failed"),
+        }
+    })
+}
+
+/// Reports the queue's lag and throughput.
+async fn handle_write_queue_stats(
+    AxumState(queue): AxumState<Arc<WriteQueue>>,
+) -> Json<WriteQueueStats> {
+    Json(queue.stats())
+}
+
+/// Builds the router for the write queue status endpoint.
+pub fn router(queue: Arc<WriteQueue>) -> Router {
+    Router::new()
+        .route("/write-queue", get(handle_write_queue_stats))
+        .with_state(queue)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use zab_bid::{BootstrapMetadata, Command, State, apply, apply_bootstrap};
+    use zab_bid_audit::{Actor, Cause};
+    use zab_bid_domain::{Area, BidYear};
+
+    fn actor(operator_id: i64) -> Actor {
+        Actor::with_operator(
+            String::from("admin"),
+            String::from("admin"),
+            operator_id,
+            String::from("admin"),
+            String::from("Admin"),
+        )
+    }
+
+    fn cause() -> Cause {
+        Cause::new(String::from("test"), String::from("Test"))
+    }
+
+    /// Creates bid year 2026 with the given areas, returning the operator ID.
+    fn bootstrap(persistence: &mut Persistence, areas: &[&str]) -> i64 {
+        let operator_id: i64 = persistence
+            .create_operator("admin", "Admin", "password", "Admin")
+            .unwrap();
+        let mut metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+        let result = apply_bootstrap(
+            &metadata,
+            &BidYear::new(2026),
+            Command::CreateBidYear {
+                year: 2026,
+                start_date: time::macros::date!(2026 - 01 - 04),
+                num_pay_periods: 26,
+            },
+            actor(operator_id),
+            cause(),
+        )
+        .unwrap();
+        persistence.persist_bootstrap(&result).unwrap();
+        for area in areas {
+            metadata = persistence.get_bootstrap_metadata().unwrap();
+            let result = apply_bootstrap(
+                &metadata,
+                &BidYear::new(2026),
+                Command::CreateArea {
+                    area_id: (*area).to_string(),
+                },
+                actor(operator_id),
+                cause(),
+            )
+            .unwrap();
+            persistence.persist_bootstrap(&result).unwrap();
+        }
+        operator_id
+    }
+
+    fn checkpoint(persistence: &mut Persistence, area: &str, operator_id: i64) -> TransitionResult {
+        let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata().unwrap();
+        apply(
+            &metadata,
+            &State::new(BidYear::new(2026), Area::new(area)),
+            &BidYear::new(2026),
+            Command::Checkpoint {
+                label: None,
+                description: None,
+            },
+            actor(operator_id),
+            cause(),
+        )
+        .unwrap()
+    }
+
+    fn queue_path(test: &str) -> PathBuf {
+        let path: PathBuf = std::env::temp_dir().join(format!(
+            "zabbid-write-queue-{test}-{}.log",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_flush_persists_in_order_and_truncates() {
+        let path: PathBuf = queue_path("order");
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let operator_id: i64 = bootstrap(&mut persistence, &["NORTH", "SOUTH"]);
+        let before: i64 = persistence.get_latest_audit_event_id().unwrap().unwrap();
+        let queue: WriteQueue = WriteQueue::open(&path, &mut persistence).unwrap();
+
+        for area in ["NORTH", "SOUTH", "NORTH"] {
+            let transition: TransitionResult = checkpoint(&mut persistence, area, operator_id);
+            queue.enqueue(&transition).unwrap();
+        }
+        assert_eq!(queue.stats().pending, 3);
+        assert_eq!(
+            persistence.get_latest_audit_event_id().unwrap(),
+            Some(before)
+        );
+
+        assert_eq!(queue.flush(&mut persistence).unwrap(), 3);
+        let stats: WriteQueueStats = queue.stats();
+        assert_eq!((stats.pending, stats.enqueued, stats.flushed), (0, 3, 3));
+        assert_eq!(
+            persistence.get_latest_audit_event_id().unwrap(),
+            Some(before + 3)
+        );
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 0);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_flush_scope_skips_unrelated_scopes() {
+        let path: PathBuf = queue_path("scope");
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let operator_id: i64 = bootstrap(&mut persistence, &["NORTH", "SOUTH"]);
+        let queue: WriteQueue = WriteQueue::open(&path, &mut persistence).unwrap();
+        let transition: TransitionResult = checkpoint(&mut persistence, "NORTH", operator_id);
+        queue.enqueue(&transition).unwrap();
+
+        queue.flush_scope(&mut persistence, 2026, "SOUTH").unwrap();
+        assert_eq!(queue.stats().pending, 1);
+
+        queue.flush_scope(&mut persistence, 2026, "NORTH").unwrap();
+        assert_eq!(queue.stats().pending, 0);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_reopened_queue_replays_unpersisted_entries() {
+        let path: PathBuf = queue_path("replay");
+        let mut persistence: Persistence = Persistence::new_in_memory().unwrap();
+        let operator_id: i64 = bootstrap(&mut persistence, &["NORTH"]);
+        let before: i64 = persistence.get_latest_audit_event_id().unwrap().unwrap();
+
+        let queue: WriteQueue = WriteQueue::open(&path, &mut persistence).unwrap();
+        for _ in 0..2 {
+            let transition: TransitionResult = checkpoint(&mut persistence, "NORTH", operator_id);
+            queue.enqueue(&transition).unwrap();
+        }
+        drop(queue);
+        // A crash mid-append leaves a partial line that was never acknowledged
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"3\t{\"actor")
+            .unwrap();
+
+        let queue: WriteQueue = WriteQueue::open(&path, &mut persistence).unwrap();
+        assert_eq!(queue.stats().pending, 2);
+        assert_eq!(queue.flush(&mut persistence).unwrap(), 2);
+        assert_eq!(
+            persistence.get_latest_audit_event_id().unwrap(),
+            Some(before + 2)
+        );
+
+        // Sequence numbers continue after the persisted entries
+        let transition: TransitionResult = checkpoint(&mut persistence, "NORTH", operator_id);
+        assert_eq!(queue.enqueue(&transition).unwrap(), 3);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,368 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Deterministic fixture builders.
+//!
+//! A [`BidYearFixture`] describes a bid year, its areas, and a roster. The
+//! same description produces matching [`BootstrapMetadata`] and [`State`]
+//! values for core tests and, with the `persistence` feature, a populated
+//! in-memory database for API and server tests. Building the same fixture
+//! twice always yields the same data.
+
+use time::{Date, Duration, Month, Weekday};
+use zab_bid::{BootstrapMetadata, Command, CoreError, State, TransitionResult, apply};
+use zab_bid_audit::{Actor, Cause};
+use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, User, UserType};
+
+/// The most users a fixture can hold, since initials are two letters and
+/// unique within a bid year.
+pub const MAX_USERS: usize = 26 * 26;
+
+/// Login name of the operator a persisted fixture acts as.
+pub const OPERATOR_LOGIN: &str = "test-operator";
+
+/// Display name of the operator a persisted fixture acts as.
+pub const OPERATOR_DISPLAY_NAME: &str = "Test Operator";
+
+/// Leave slots per day each fixture round offers unless set otherwise.
+pub const DEFAULT_SLOTS_PER_DAY: u32 = 2;
+
+/// A bid year with its areas, roster, and rounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidYearFixture {
+    year: u16,
+    areas: Vec<String>,
+    users_per_area: usize,
+    rounds: u32,
+    slots_per_day: u32,
+    lifecycle_state: Option<String>,
+}
+
+impl BidYearFixture {
+    /// Creates a fixture for `year` with a single area, `North`, and no users.
+    #[must_use]
+    pub fn new(year: u16) -> Self {
+        Self {
+            year,
+            areas: vec![String::from("North")],
+            users_per_area: 0,
+            rounds: 0,
+            slots_per_day: DEFAULT_SLOTS_PER_DAY,
+            lifecycle_state: None,
+        }
+    }
+
+    /// Replaces the fixture's areas.
+    #[must_use]
+    pub fn with_areas(mut self, areas: &[&str]) -> Self {
+        self.areas = areas.iter().map(|area| (*area).to_string()).collect();
+        self
+    }
+
+    /// Registers `count` users in every area.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixture would hold more than [`MAX_USERS`] users.
+    #[must_use]
+    pub fn with_users(mut self, count: usize) -> Self {
+        assert!(
+            count * self.areas.len() <= MAX_USERS,
+            "a bid year holds at most {MAX_USERS} users"
+        );
+        self.users_per_area = count;
+        self
+    }
+
+    /// Adds a `Default` round group holding `count` rounds, numbered from
+    /// one, and assigns every area to it.
+    ///
+    /// Each round offers [`DEFAULT_SLOTS_PER_DAY`] slots per day, one leave
+    /// group, and up to 80 hours.
+    #[must_use]
+    pub const fn with_rounds(mut self, count: u32) -> Self {
+        self.rounds = count;
+        self
+    }
+
+    /// Sets the leave slots per day each round offers.
+    #[must_use]
+    pub const fn with_slots_per_day(mut self, slots_per_day: u32) -> Self {
+        self.slots_per_day = slots_per_day;
+        self
+    }
+
+    /// Leaves the persisted bid year in the given lifecycle state.
+    ///
+    /// The state is written directly, without the transitions that would
+    /// lead to it.
+    #[must_use]
+    pub fn with_lifecycle_state(mut self, lifecycle_state: &str) -> Self {
+        self.lifecycle_state = Some(lifecycle_state.to_string());
+        self
+    }
+
+    /// Returns the number of rounds in the fixture's round group.
+    #[must_use]
+    pub const fn rounds(&self) -> u32 {
+        self.rounds
+    }
+
+    /// Returns the leave slots per day each round offers.
+    #[must_use]
+    pub const fn slots_per_day(&self) -> u32 {
+        self.slots_per_day
+    }
+
+    /// Returns the lifecycle state the persisted bid year is left in, if
+    /// one was set.
+    #[must_use]
+    pub fn lifecycle_state(&self) -> Option<&str> {
+        self.lifecycle_state.as_deref()
+    }
+
+    /// Returns the bid year.
+    #[must_use]
+    pub const fn bid_year(&self) -> BidYear {
+        BidYear::new(self.year)
+    }
+
+    /// Returns the bid year's start date, the first Sunday of January.
+    #[must_use]
+    pub fn start_date(&self) -> Date {
+        let mut date: Date =
+            Date::from_calendar_date(i32::from(self.year), Month::January, 1).unwrap_or(Date::MIN);
+        while date.weekday() != Weekday::Sunday {
+            date = date.next_day().unwrap_or(Date::MAX);
+        }
+        date
+    }
+
+    /// Returns the fixture's areas.
+    #[must_use]
+    pub fn areas(&self) -> Vec<Area> {
+        self.areas.iter().map(|code| Area::new(code)).collect()
+    }
+
+    /// Returns metadata holding the bid year and every area.
+    #[must_use]
+    pub fn metadata(&self) -> BootstrapMetadata {
+        let mut metadata: BootstrapMetadata = BootstrapMetadata::new();
+        metadata.bid_years.push(self.bid_year());
+        for area in self.areas() {
+            metadata.areas.push((self.bid_year(), area));
+        }
+        metadata
+    }
+
+    /// Returns the unpersisted users of one area, most senior first.
+    ///
+    /// Returns no users for an area the fixture does not have.
+    #[must_use]
+    pub fn users(&self, area_code: &str) -> Vec<User> {
+        let Some(position) = self
+            .areas
+            .iter()
+            .position(|code| code.eq_ignore_ascii_case(area_code))
+        else {
+            return Vec::new();
+        };
+        let area: Area = Area::new(&self.areas[position]);
+        let first: usize = position * self.users_per_area;
+
+        (first..first + self.users_per_area)
+            .map(|index| self.user(index, &area))
+            .collect()
+    }
+
+    /// Returns the state of one area with its users registered.
+    #[must_use]
+    pub fn state(&self, area_code: &str) -> State {
+        State::with_users(self.bid_year(), Area::new(area_code), self.users(area_code))
+    }
+
+    /// Returns the state of every area, in area order.
+    #[must_use]
+    pub fn states(&self) -> Vec<State> {
+        self.areas.iter().map(|code| self.state(code)).collect()
+    }
+
+    /// Registers one area's users in turn, starting from an empty area.
+    ///
+    /// Returns one transition per user, each applied to the state the one
+    /// before it produced, ready to persist in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if core rejects a registration.
+    pub fn registrations(
+        &self,
+        area_code: &str,
+        metadata: &BootstrapMetadata,
+        actor: &Actor,
+    ) -> Result<Vec<TransitionResult>, CoreError> {
+        let mut state: State = State::new(self.bid_year(), Area::new(area_code));
+        let mut transitions: Vec<TransitionResult> = Vec::new();
+        for user in self.users(area_code) {
+            let result: TransitionResult = apply(
+                metadata,
+                &state,
+                &self.bid_year(),
+                Command::RegisterUser {
+                    initials: user.initials,
+                    name: user.name,
+                    area: user.area,
+                    user_type: user.user_type,
+                    crew: user.crew,
+                    seniority_data: user.seniority_data,
+                },
+                actor.clone(),
+                Self::cause(),
+            )?;
+            state = result.new_state.clone();
+            transitions.push(result);
+        }
+        Ok(transitions)
+    }
+
+    /// Builds the user at a bid-year-wide roster position.
+    ///
+    /// Lower positions are more senior. Initials run `AA`, `AB`, ... so they
+    /// never collide within the bid year.
+    fn user(&self, index: usize, area: &Area) -> User {
+        let user_types: [UserType; 4] = [
+            UserType::CPC,
+            UserType::CPC,
+            UserType::CpcIt,
+            UserType::DevR,
+        ];
+        let letter = |n: usize| char::from(b'A' + u8::try_from(n % 26).unwrap_or(0));
+        let initials: String = [letter(index / 26), letter(index)].iter().collect();
+        let crew: Option<Crew> = u8::try_from(index % 7 + 1)
+            .ok()
+            .and_then(|n| Crew::new(n).ok());
+
+        // One hire every two weeks, starting in 1995
+        let hired: Date = Date::from_calendar_date(1995, Month::January, 3).unwrap_or(Date::MIN)
+            + Duration::weeks(i64::try_from(index).unwrap_or(0) * 2);
+        let date: String = format!(
+            "{:04}-{:02}-{:02}",
+            hired.year(),
+            u8::from(hired.month()),
+            hired.day()
+        );
+
+        User::new(
+            self.bid_year(),
+            Initials::new(&initials),
+            format!("Controller {initials}"),
+            area.clone(),
+            user_types[index % user_types.len()],
+            crew,
+            SeniorityData::new(
+                date.clone(),
+                date.clone(),
+                date.clone(),
+                date,
+                u32::try_from(index + 1).ok(),
+            ),
+            false,
+            false,
+            false,
+        )
+    }
+
+    /// Returns the actor fixture events are recorded under.
+    #[must_use]
+    pub fn actor(operator_id: i64) -> Actor {
+        Actor::with_operator(
+            String::from("test-admin"),
+            String::from("admin"),
+            operator_id,
+            String::from(OPERATOR_LOGIN),
+            String::from(OPERATOR_DISPLAY_NAME),
+        )
+    }
+
+    /// Returns the cause fixture events are recorded under.
+    #[must_use]
+    pub fn cause() -> Cause {
+        Cause::new(String::from("test-setup"), String::from("Test setup"))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_and_states_describe_the_same_areas() {
+        let fixture: BidYearFixture = BidYearFixture::new(2027)
+            .with_areas(&["North", "South"])
+            .with_users(3);
+        let metadata: BootstrapMetadata = fixture.metadata();
+
+        assert_eq!(metadata.bid_years, vec![BidYear::new(2027)]);
+        for state in fixture.states() {
+            assert!(metadata.has_area(&state.bid_year, &state.area));
+            assert_eq!(state.users.len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_initials_are_unique_across_the_bid_year() {
+        let fixture: BidYearFixture = BidYearFixture::new(2026)
+            .with_areas(&["North", "South", "East"])
+            .with_users(40);
+        let mut initials: Vec<String> = fixture
+            .states()
+            .iter()
+            .flat_map(|state| state.users.keys().map(|i| i.value().to_string()))
+            .collect();
+        initials.sort();
+        initials.dedup();
+        assert_eq!(initials.len(), 120);
+    }
+
+    #[test]
+    fn test_fixture_is_deterministic() {
+        let build = || BidYearFixture::new(2026).with_users(10).state("North");
+        assert_eq!(build(), build());
+    }
+
+    #[test]
+    fn test_registrations_end_in_the_fixture_state() {
+        let fixture: BidYearFixture = BidYearFixture::new(2026).with_users(4);
+        let transitions: Vec<TransitionResult> = fixture
+            .registrations("North", &fixture.metadata(), &BidYearFixture::actor(1))
+            .unwrap();
+
+        assert_eq!(transitions.len(), 4);
+        assert_eq!(
+            transitions.last().unwrap().new_state,
+            fixture.state("North")
+        );
+    }
+
+    #[test]
+    fn test_start_date_is_first_sunday_of_january() {
+        let date: Date = BidYearFixture::new(2027).start_date();
+        assert_eq!(
+            date,
+            Date::from_calendar_date(2027, Month::January, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unknown_area_has_no_users() {
+        assert!(
+            BidYearFixture::new(2026)
+                .with_users(5)
+                .users("West")
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,39 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+#![deny(
+    clippy::pedantic,
+    clippy::cargo,
+    clippy::nursery,
+    clippy::style,
+    clippy::correctness,
+    clippy::all,
+    clippy::suspicious,
+    clippy::complexity,
+    clippy::perf,
+    clippy::unwrap_used,
+    clippy::expect_used
+)]
+#![allow(clippy::multiple_crate_versions)]
+
+//! Shared test support for the ZAB Bidding System.
+//!
+//! This crate is only ever a dev-dependency. It holds the `proptest`
+//! strategies used by the property-based test suites and deterministic
+//! fixture builders, so every crate sets up domain data the same way.
+//!
+//! The `persistence` feature adds [`PersistedFixture`], which writes a
+//! fixture to an in-memory database.
+
+mod fixtures;
+#[cfg(feature = "persistence")]
+mod persisted;
+pub mod strategies;
+
+pub use fixtures::{
+    BidYearFixture, DEFAULT_SLOTS_PER_DAY, MAX_USERS, OPERATOR_DISPLAY_NAME, OPERATOR_LOGIN,
+};
+#[cfg(feature = "persistence")]
+pub use persisted::PersistedFixture;
@@ -0,0 +1,201 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Writing fixtures into an in-memory database.
+
+use std::collections::BTreeMap;
+
+use zab_bid::{
+    BootstrapMetadata, BootstrapResult, Command, State, TransitionResult, apply_bootstrap,
+};
+use zab_bid_audit::Actor;
+use zab_bid_domain::Area;
+use zab_bid_persistence::{PersistenceError, SqlitePersistence};
+
+use crate::fixtures::{BidYearFixture, OPERATOR_DISPLAY_NAME, OPERATOR_LOGIN};
+
+/// A fixture written to an in-memory database.
+pub struct PersistedFixture {
+    /// The populated database.
+    pub persistence: SqlitePersistence,
+    /// The admin operator every fixture event is recorded under.
+    pub operator_id: i64,
+    /// The canonical bid year identifier.
+    pub bid_year_id: i64,
+    /// Canonical area identifiers, keyed by area code.
+    pub area_ids: BTreeMap<String, i64>,
+    /// Canonical user identifiers, keyed by initials.
+    pub user_ids: BTreeMap<String, i64>,
+    /// The round group every area is assigned to, if the fixture has rounds.
+    pub round_group_id: Option<i64>,
+    /// The round identifiers, in round order.
+    pub round_ids: Vec<i64>,
+    /// Metadata read back from the database, carrying canonical IDs.
+    pub metadata: BootstrapMetadata,
+}
+
+impl PersistedFixture {
+    /// Returns the canonical identifier of an area.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixture has no such area.
+    #[must_use]
+    pub fn area_id(&self, area_code: &str) -> i64 {
+        self.area_ids[&area_code.to_uppercase()]
+    }
+
+    /// Returns the canonical identifier of a user.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixture has no user with these initials.
+    #[must_use]
+    pub fn user_id(&self, initials: &str) -> i64 {
+        self.user_ids[&initials.to_uppercase()]
+    }
+
+    /// Reads an area's current state, with canonical user IDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area does not exist or cannot be read.
+    pub fn state(&mut self, area_code: &str) -> Result<State, PersistenceError> {
+        let bid_year = self
+            .metadata
+            .bid_years
+            .first()
+            .cloned()
+            .ok_or_else(|| PersistenceError::Other(String::from("Fixture has no bid year")))?;
+        self.persistence
+            .get_current_state(&bid_year, &Area::new(area_code))
+    }
+}
+
+impl BidYearFixture {
+    /// Writes the fixture to a fresh in-memory database.
+    ///
+    /// Creates an admin operator, the bid year, and every area, marks the
+    /// bid year active, and registers the roster. Every step goes through
+    /// the core transitions and is audited. Rounds are then configured and
+    /// the lifecycle state set directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any step is rejected or fails to persist.
+    pub fn persist(&self) -> Result<PersistedFixture, PersistenceError> {
+        let mut persistence: SqlitePersistence = SqlitePersistence::new_in_memory()?;
+        let operator_id: i64 = persistence.create_operator(
+            OPERATOR_LOGIN,
+            OPERATOR_DISPLAY_NAME,
+            "password",
+            "Admin",
+        )?;
+        let actor: Actor = Self::actor(operator_id);
+        let rejected =
+            |e: zab_bid::CoreError| PersistenceError::Other(format!("Fixture rejected: {e}"));
+
+        let bid_year_result: BootstrapResult = apply_bootstrap(
+            &BootstrapMetadata::new(),
+            &self.bid_year(),
+            Command::CreateBidYear {
+                year: self.bid_year().year(),
+                start_date: self.start_date(),
+                num_pay_periods: 26,
+            },
+            actor.clone(),
+            Self::cause(),
+        )
+        .map_err(rejected)?;
+        persistence.persist_bootstrap(&bid_year_result)?;
+        let mut metadata: BootstrapMetadata = bid_year_result.new_metadata;
+
+        for area in self.areas() {
+            let area_result: BootstrapResult = apply_bootstrap(
+                &metadata,
+                &self.bid_year(),
+                Command::CreateArea {
+                    area_id: area.id().to_string(),
+                },
+                actor.clone(),
+                Self::cause(),
+            )
+            .map_err(rejected)?;
+            persistence.persist_bootstrap(&area_result)?;
+            metadata = area_result.new_metadata;
+        }
+        persistence.set_active_bid_year(&self.bid_year())?;
+
+        for area in self.areas() {
+            let transitions: Vec<TransitionResult> = self
+                .registrations(area.id(), &metadata, &actor)
+                .map_err(rejected)?;
+            persistence.persist_transitions(&transitions)?;
+        }
+
+        let bid_year_id: i64 = persistence.get_bid_year_id(self.bid_year().year())?;
+        let mut area_ids: BTreeMap<String, i64> = BTreeMap::new();
+        let mut user_ids: BTreeMap<String, i64> = BTreeMap::new();
+        for area in self.areas() {
+            let area_id: i64 = persistence.get_area_id(bid_year_id, area.id())?;
+            area_ids.insert(area.id().to_string(), area_id);
+            for user in persistence.list_users(&self.bid_year(), &area)? {
+                if let Some(user_id) = user.user_id {
+                    user_ids.insert(user.initials.value().to_string(), user_id);
+                }
+            }
+        }
+
+        let (round_group_id, round_ids): (Option<i64>, Vec<i64>) =
+            self.persist_rounds(&mut persistence, bid_year_id, &area_ids)?;
+        if let Some(lifecycle_state) = self.lifecycle_state() {
+            persistence.update_lifecycle_state(bid_year_id, lifecycle_state)?;
+        }
+        let metadata: BootstrapMetadata = persistence.get_bootstrap_metadata()?;
+
+        Ok(PersistedFixture {
+            persistence,
+            operator_id,
+            bid_year_id,
+            area_ids,
+            user_ids,
+            round_group_id,
+            round_ids,
+            metadata,
+        })
+    }
+
+    /// Creates the fixture's round group and rounds, if it has any, and
+    /// assigns every area to the group.
+    fn persist_rounds(
+        &self,
+        persistence: &mut SqlitePersistence,
+        bid_year_id: i64,
+        area_ids: &BTreeMap<String, i64>,
+    ) -> Result<(Option<i64>, Vec<i64>), PersistenceError> {
+        if self.rounds() == 0 {
+            return Ok((None, Vec::new()));
+        }
+
+        let round_group_id: i64 = persistence.insert_round_group(bid_year_id, "Default", true)?;
+        let mut round_ids: Vec<i64> = Vec::new();
+        for number in 1..=self.rounds() {
+            round_ids.push(persistence.insert_round(
+                round_group_id,
+                number,
+                &format!("Round {number}"),
+                self.slots_per_day(),
+                1,
+                80,
+                false,
+                false,
+            )?);
+        }
+        for area_id in area_ids.values() {
+            persistence.update_area_round_group(*area_id, Some(round_group_id))?;
+        }
+        Ok((Some(round_group_id), round_ids))
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! `proptest` strategies for domain values and core command sequences.
+//!
+//! Every strategy produces values that pass domain validation. The
+//! [`scenario`] strategy goes further and produces whole command sequences
+//! that a correct core must accept from start to finish.
+
+use std::collections::BTreeSet;
+
+use proptest::collection::{btree_set, vec};
+use proptest::prelude::*;
+use proptest::sample::Index;
+use zab_bid::{BootstrapMetadata, Command, State};
+use zab_bid_domain::{Area, BidYear, Crew, Initials, SeniorityData, User, UserType};
+
+/// The bid year every generated scenario is scoped to.
+pub const BID_YEAR: u16 = 2026;
+
+/// The area every generated scenario is scoped to.
+pub const AREA: &str = "North";
+
+/// Returns bootstrap metadata containing [`BID_YEAR`] and [`AREA`].
+#[must_use]
+pub fn metadata() -> BootstrapMetadata {
+    let mut metadata: BootstrapMetadata = BootstrapMetadata::new();
+    metadata.bid_years.push(BidYear::new(BID_YEAR));
+    metadata
+        .areas
+        .push((BidYear::new(BID_YEAR), Area::new(AREA)));
+    metadata
+}
+
+/// Two uppercase letters.
+pub fn initials() -> impl Strategy<Value = Initials> {
+    "[A-Z]{2}".prop_map(|value| Initials::new(&value))
+}
+
+/// A non-empty "First Last" name.
+pub fn name() -> impl Strategy<Value = String> {
+    "[A-Z][a-z]{1,9} [A-Z][a-z]{1,11}"
+}
+
+/// Any user type.
+pub fn user_type() -> impl Strategy<Value = UserType> {
+    prop_oneof![
+        Just(UserType::CPC),
+        Just(UserType::CpcIt),
+        Just(UserType::DevR),
+        Just(UserType::DevD),
+    ]
+}
+
+/// An optional crew between 1 and 7.
+pub fn crew() -> impl Strategy<Value = Option<Crew>> {
+    proptest::option::of((1u8..=7).prop_filter_map("crew out of range", |n| Crew::new(n).ok()))
+}
+
+/// A `YYYY-MM-DD` date between 1990 and 2025.
+pub fn date() -> impl Strategy<Value = String> {
+    (1990u16..=2025, 1u8..=12, 1u8..=28)
+        .prop_map(|(year, month, day)| format!("{year:04}-{month:02}-{day:02}"))
+}
+
+/// Seniority dates with an optional lottery value.
+pub fn seniority_data() -> impl Strategy<Value = SeniorityData> {
+    (
+        date(),
+        date(),
+        date(),
+        date(),
+        proptest::option::of(1u32..=10_000),
+    )
+        .prop_map(
+            |(cumulative_natca_bu_date, natca_bu_date, eod_faa_date, scd, lottery_value)| {
+                SeniorityData::new(
+                    cumulative_natca_bu_date,
+                    natca_bu_date,
+                    eod_faa_date,
+                    scd,
+                    lottery_value,
+                )
+            },
+        )
+}
+
+/// The mutable fields of a user record.
+#[derive(Debug, Clone)]
+pub struct UserFields {
+    /// The user's name.
+    pub name: String,
+    /// The user's type classification.
+    pub user_type: UserType,
+    /// The user's crew.
+    pub crew: Option<Crew>,
+    /// The user's seniority data.
+    pub seniority_data: SeniorityData,
+}
+
+/// A complete set of user fields.
+pub fn user_fields() -> impl Strategy<Value = UserFields> {
+    (name(), user_type(), crew(), seniority_data()).prop_map(
+        |(name, user_type, crew, seniority_data)| UserFields {
+            name,
+            user_type,
+            crew,
+            seniority_data,
+        },
+    )
+}
+
+/// One step of a generated scenario, before initials and user IDs are
+/// resolved against the state it applies to.
+#[derive(Debug, Clone)]
+enum Step {
+    Register(UserFields),
+    Update {
+        target: Index,
+        rename: bool,
+        fields: UserFields,
+    },
+    Participation {
+        target: Index,
+        excluded_from_bidding: bool,
+        excluded_from_leave_calculation: bool,
+    },
+    Checkpoint,
+    Finalize,
+    Rollback(i64),
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    prop_oneof![
+        4 => user_fields().prop_map(Step::Register),
+        3 => (any::<Index>(), any::<bool>(), user_fields()).prop_map(
+            |(target, rename, fields)| Step::Update { target, rename, fields }
+        ),
+        2 => (any::<Index>(), any::<bool>(), any::<bool>()).prop_map(
+            |(target, excluded_from_bidding, excluded_from_leave_calculation)| {
+                Step::Participation {
+                    target,
+                    excluded_from_bidding,
+                    excluded_from_leave_calculation,
+                }
+            }
+        ),
+        1 => Just(Step::Checkpoint),
+        1 => Just(Step::Finalize),
+        1 => (1i64..1_000).prop_map(Step::Rollback),
+    ]
+}
+
+/// A starting state and a command sequence that is valid against it.
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    /// The state before the first command, holding persisted users.
+    pub initial_state: State,
+    /// Commands that must all succeed when applied in order.
+    pub commands: Vec<Command>,
+}
+
+/// A roster of persisted users followed by up to `max_commands` commands.
+///
+/// Registrations and renames draw initials from a pool that is unique
+/// across the whole scenario, so no command collides with an existing
+/// user. Updates and participation changes only target users that carry
+/// a canonical ID, as they would after persistence.
+pub fn scenario(max_commands: usize) -> impl Strategy<Value = Scenario> {
+    (
+        btree_set("[A-Z]{2}", 1..=max_commands + 16),
+        vec(user_fields(), 0..=8),
+        vec(step(), 0..=max_commands),
+    )
+        .prop_map(|(pool, roster, steps)| build_scenario(&pool, roster, steps))
+}
+
+fn build_scenario(pool: &BTreeSet<String>, roster: Vec<UserFields>, steps: Vec<Step>) -> Scenario {
+    let bid_year: BidYear = BidYear::new(BID_YEAR);
+    let area: Area = Area::new(AREA);
+    let mut fresh = pool.iter().map(|value| Initials::new(value));
+
+    let mut initial_state: State = State::new(bid_year.clone(), area.clone());
+    for (user_id, fields) in (1i64..).zip(roster) {
+        let Some(initials) = fresh.next() else {
+            break;
+        };
+        initial_state.insert_user(User::with_id(
+            user_id,
+            bid_year.clone(),
+            initials,
+            fields.name,
+            area.clone(),
+            fields.user_type,
+            fields.crew,
+            fields.seniority_data,
+            false,
+            false,
+            false,
+        ));
+    }
+
+    // Current initials of each persisted user, by position in the roster
+    let mut persisted: Vec<(i64, Initials)> = initial_state
+        .users
+        .values()
+        .filter_map(|user| user.user_id.map(|id| (id, user.initials.clone())))
+        .collect();
+
+    let mut commands: Vec<Command> = Vec::with_capacity(steps.len());
+    for step in steps {
+        let command: Option<Command> = match step {
+            Step::Register(fields) => fresh.next().map(|initials| Command::RegisterUser {
+                initials,
+                name: fields.name,
+                area: area.clone(),
+                user_type: fields.user_type,
+                crew: fields.crew,
+                seniority_data: fields.seniority_data,
+            }),
+            Step::Update {
+                target,
+                rename,
+                fields,
+            } if !persisted.is_empty() => {
+                let slot: usize = target.index(persisted.len());
+                let (user_id, current) = &mut persisted[slot];
+                if rename && let Some(initials) = fresh.next() {
+                    *current = initials;
+                }
+                Some(Command::UpdateUser {
+                    user_id: *user_id,
+                    initials: current.clone(),
+                    name: fields.name,
+                    area: area.clone(),
+                    user_type: fields.user_type,
+                    crew: fields.crew,
+                    seniority_data: fields.seniority_data,
+                })
+            }
+            Step::Participation {
+                target,
+                excluded_from_bidding,
+                excluded_from_leave_calculation,
+            } if !persisted.is_empty() => {
+                let (user_id, initials) = &persisted[target.index(persisted.len())];
+                Some(Command::UpdateUserParticipation {
+                    user_id: *user_id,
+                    initials: initials.clone(),
+                    excluded_from_bidding,
+                    // Leave exclusion implies bidding exclusion
+                    excluded_from_leave_calculation: excluded_from_leave_calculation
+                        && excluded_from_bidding,
+                })
+            }
+            Step::Update { .. } | Step::Participation { .. } => None,
+            Step::Checkpoint => Some(Command::Checkpoint {
+                label: None,
+                description: None,
+            }),
+            Step::Finalize => Some(Command::Finalize),
+            Step::Rollback(target_event_id) => Some(Command::RollbackToEventId { target_event_id }),
+        };
+        commands.extend(command);
+    }
+
+    Scenario {
+        initial_state,
+        commands,
+    }
+}
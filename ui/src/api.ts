@@ -60,6 +60,34 @@ export class NetworkError extends Error {
   }
 }
 
+/**
+ * Name of the cookie the backend issues the CSRF token in when it runs in
+ * cookie session mode, and the header state-changing requests echo it in.
+ */
+const CSRF_COOKIE = "zabbid_csrf";
+const CSRF_HEADER = "X-CSRF-Token";
+
+/**
+ * Adds the CSRF token to state-changing requests, if the backend issued one.
+ * In bearer session mode no CSRF cookie is set and requests are unchanged.
+ */
+function withCsrfToken(init?: RequestInit): RequestInit | undefined {
+  const method = (init?.method ?? "GET").toUpperCase();
+  if (method === "GET" || method === "HEAD") {
+    return init;
+  }
+  const csrfToken = document.cookie
+    .split(";")
+    .map((pair) => pair.trim().split("="))
+    .find(([name]) => name === CSRF_COOKIE)?.[1];
+  if (!csrfToken) {
+    return init;
+  }
+  const headers = new Headers(init?.headers);
+  headers.set(CSRF_HEADER, csrfToken);
+  return { ...init, headers };
+}
+
 /**
  * Wrapper around fetch that handles JSON parsing and error responses.
  * Distinguishes between network failures (backend unreachable) and HTTP errors (backend responded with an error).
@@ -68,7 +96,7 @@ async function fetchJson<T>(url: string, init?: RequestInit): Promise<T> {
   let response: Response;
 
   try {
-    response = await fetch(url, init);
+    response = await fetch(url, withCsrfToken(init));
   } catch (_error) {
     // Network error: backend is unreachable
     // This includes DNS failures, connection refused, timeouts, etc.
@@ -590,10 +590,30 @@ export interface OperatorInfo {
   created_at: string;
   /** When the operator last logged in */
   last_login_at: string | null;
+  /** Number of sessions that have not yet expired */
+  active_session_count: number;
+  /** Consecutive failed sign-in attempts since the last successful one */
+  failed_login_count: number;
+  /** Areas the operator is assigned to look after */
+  area_scopes: OperatorAreaScopeInfo[];
   /** Target-specific capabilities for this operator instance */
   capabilities: OperatorCapabilities;
 }
 
+/**
+ * An area an operator is assigned to look after.
+ */
+export interface OperatorAreaScopeInfo {
+  /** The area's internal identifier */
+  area_id: number;
+  /** The bid year's internal identifier */
+  bid_year_id: number;
+  /** The bid year */
+  year: number;
+  /** The area code */
+  area_code: string;
+}
+
 /**
  * Response for the whoami endpoint.
  */
@@ -41,6 +41,12 @@
     clippy::all
 )]
 
+mod deploy;
+mod loadtest;
+mod migration_lint;
+mod query_plans;
+mod seed;
+
 use std::{fmt::Debug, io, process::Output, vec};
 
 use cargo_metadata::MetadataCommand;
@@ -97,6 +103,10 @@ enum Command {
     /// Run CI checks (lint, build, test)
     CI,
 
+    /// Run core and persistence benchmarks
+    #[command(visible_alias = "bn")]
+    Bench,
+
     /// Build the project
     #[command(visible_alias = "b")]
     Build,
@@ -117,11 +127,15 @@ enum Command {
     #[command(visible_alias = "cd")]
     Deny,
 
+    /// Write a Docker Compose deployment and its settings file
+    #[command(visible_alias = "dp")]
+    Deploy(deploy::DeployArgs),
+
     // Check unused dependencies
     #[command(visible_alias = "m")]
     Machete,
 
-    /// Lint formatting, typos, clippy, and docs
+    /// Lint formatting, typos, clippy, docs, and migrations
     #[command(visible_alias = "l")]
     Lint,
 
@@ -141,10 +155,22 @@ enum Command {
     #[command(visible_alias = "md")]
     LintMarkdown,
 
+    /// Check `SQLite` and `MySQL` migrations describe the same schema, without a database
+    #[command(visible_alias = "lmg")]
+    LintMigrations,
+
     /// Check for typos in the project
     #[command(visible_alias = "lt")]
     LintTypos,
 
+    /// Load test a release server against a seeded database
+    #[command(visible_alias = "load")]
+    Loadtest(loadtest::LoadtestArgs),
+
+    /// Check hot-path queries use indexes rather than table scans
+    #[command(visible_alias = "qp")]
+    QueryPlans,
+
     /// Fix clippy warnings in the project
     #[command(visible_alias = "fc")]
     FixClippy,
@@ -157,6 +183,10 @@ enum Command {
     #[command(visible_alias = "typos")]
     FixTypos,
 
+    /// Populate a fresh development database with realistic fixtures
+    #[command(visible_alias = "s")]
+    Seed(seed::SeedArgs),
+
     /// Run tests
     #[command(visible_alias = "t")]
     Test,
@@ -189,9 +219,11 @@ impl Command {
     fn run(self) -> Result<()> {
         match self {
             Self::CI => ci(),
+            Self::Bench => bench(),
             Self::Build => build(),
             Self::Check => check(),
             Self::Deny => deny(),
+            Self::Deploy(args) => deploy::deploy(&args),
             Self::Machete => machete(),
             Self::CheckReadme => check_readme(),
             Self::Coverage => coverage(),
@@ -201,9 +233,13 @@ impl Command {
             Self::LintFormatting => lint_format(),
             Self::LintTypos => lint_typos(),
             Self::LintMarkdown => lint_markdown(),
+            Self::LintMigrations => migration_lint::lint_migrations(),
+            Self::Loadtest(args) => loadtest::loadtest(&args),
+            Self::QueryPlans => query_plans::query_plans(),
             Self::FixClippy => fix_clippy(),
             Self::FixFormatting => fix_format(),
             Self::FixTypos => fix_typos(),
+            Self::Seed(args) => seed::seed(&args),
             Self::Test => test(),
             Self::TestDocs => test_docs(),
             Self::TestLibs => test_libs(),
@@ -235,6 +271,20 @@ fn machete() -> Result<()> {
     Ok(())
 }
 
+/// Run the core and persistence benchmarks
+///
+/// Results are kept under `target/criterion`, so a later run reports the
+/// change against the previous one.
+fn bench() -> Result<()> {
+    run_cargo(vec![
+        "bench",
+        "--package",
+        "zab-bid",
+        "--package",
+        "zab-bid-persistence",
+    ])
+}
+
 /// Build the project
 fn build() -> Result<()> {
     run_cargo(vec!["build", "--all-targets", "--all-features"])
@@ -261,12 +311,14 @@ fn coverage() -> Result<()> {
     ])
 }
 
-/// Lint formatting, typos, clippy, and docs (and a soft fail on markdown)
+/// Lint formatting, typos, clippy, docs, and migrations (and a soft fail on markdown)
 fn lint() -> Result<()> {
     lint_clippy()?;
     lint_docs()?;
     lint_format()?;
     lint_typos()?;
+    migration_lint::lint_migrations()?;
+    query_plans::query_plans()?;
     if let Err(err) = lint_markdown() {
         tracing::warn!("known issue: markdownlint is currently noisy and can be ignored: {err}");
     }
@@ -0,0 +1,809 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! Static schema parity check between the `SQLite` and `MySQL` migrations.
+//!
+//! `verify-migrations` proves parity against real databases but needs
+//! Docker, so drift between `migrations/` and `migrations_mysql/` usually
+//! goes unnoticed until someone runs it. This linter replays the DDL in
+//! both directories against an in-memory model instead, producing the
+//! same logical [`Schema`] the introspection path builds, and compares the
+//! two with [`compare_schemas`]. It needs no database and runs as part of
+//! `cargo xtask lint`.
+//!
+//! Only the DDL the migrations actually use is understood. Any statement
+//! the linter does not recognise fails the check rather than being
+//! skipped, so a new construct cannot silently hide drift.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{bail, eyre, Context};
+use color_eyre::Result;
+
+use crate::{
+    compare_schemas, normalize_mysql_type, normalize_sqlite_type, Column, ForeignKey, Index,
+    Schema, Table, UniqueConstraint,
+};
+
+/// The SQL dialect a migration directory is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dialect {
+    Sqlite,
+    Mysql,
+}
+
+impl Dialect {
+    fn normalize_type(self, declared: &str) -> String {
+        match self {
+            Self::Sqlite => normalize_sqlite_type(declared),
+            Self::Mysql => normalize_mysql_type(declared),
+        }
+    }
+}
+
+/// Compare the logical schemas described by both migration directories.
+pub fn lint_migrations() -> Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../crates/persistence");
+
+    tracing::info!("Replaying SQLite migrations");
+    let sqlite_schema = replay_migrations(&root.join("migrations"), Dialect::Sqlite)?;
+
+    tracing::info!("Replaying MySQL migrations");
+    let mysql_schema = replay_migrations(&root.join("migrations_mysql"), Dialect::Mysql)?;
+
+    tracing::info!("Comparing schemas");
+    compare_schemas(&sqlite_schema, &mysql_schema)?;
+
+    tracing::info!(
+        "✓ Migration lint passed ({} tables)",
+        sqlite_schema.tables.len()
+    );
+    Ok(())
+}
+
+/// Apply every `up.sql` in a migration directory, in order.
+fn replay_migrations(dir: &Path, dialect: Dialect) -> Result<Schema> {
+    let mut migrations: Vec<PathBuf> = fs::read_dir(dir)
+        .wrap_err(format!("Failed to read {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.join("up.sql").is_file())
+        .collect();
+    migrations.sort();
+
+    let mut model = Model::new(dialect);
+    for migration in migrations {
+        let path = migration.join("up.sql");
+        let sql =
+            fs::read_to_string(&path).wrap_err(format!("Failed to read {}", path.display()))?;
+        for statement in split_statements(&sql) {
+            model.apply(&tokenize(&statement)).wrap_err(format!(
+                "{}: {}",
+                path.display(),
+                first_line(&statement)
+            ))?;
+        }
+    }
+
+    Ok(model.into_schema())
+}
+
+fn first_line(statement: &str) -> &str {
+    statement.lines().next().unwrap_or_default().trim()
+}
+
+/// Split a migration into statements, dropping comments.
+fn split_statements(sql: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut chars = sql.chars().peekable();
+    let mut quote: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        if let Some(q) = quote {
+            current.push(c);
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' | '`' => {
+                quote = Some(c);
+                current.push(c);
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        current.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+                current.push(' ');
+            }
+            ';' => {
+                if !current.trim().is_empty() {
+                    statements.push(current.trim().to_string());
+                }
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() {
+        statements.push(current.trim().to_string());
+    }
+
+    statements
+}
+
+/// A lexical token of a DDL statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// A keyword or identifier, with any quoting removed.
+    Word(String),
+    /// A string literal.
+    Literal,
+    /// Any other symbol, including parentheses and commas.
+    Symbol(char),
+}
+
+impl Token {
+    fn is(&self, keyword: &str) -> bool {
+        matches!(self, Self::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+}
+
+fn tokenize(statement: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = statement.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '\'' => {
+                for c in chars.by_ref() {
+                    if c == '\'' {
+                        break;
+                    }
+                }
+                tokens.push(Token::Literal);
+            }
+            '"' | '`' => {
+                let word: String = chars.by_ref().take_while(|&next| next != c).collect();
+                tokens.push(Token::Word(word));
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut word = String::from(c);
+                while let Some(&next) = chars.peek() {
+                    if next.is_alphanumeric() || next == '_' || next == '.' {
+                        word.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Word(word));
+            }
+            c => tokens.push(Token::Symbol(c)),
+        }
+    }
+
+    tokens
+}
+
+/// Split tokens at commas that are not nested in parentheses.
+fn split_top_level(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Symbol('(') => depth += 1,
+            Token::Symbol(')') => depth = depth.saturating_sub(1),
+            Token::Symbol(',') if depth == 0 => {
+                parts.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&tokens[start..]);
+    parts
+}
+
+/// A cursor over the tokens of one statement or clause.
+struct Cursor<'a> {
+    tokens: &'a [Token],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    const fn new(tokens: &'a [Token]) -> Self {
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn peek_is(&self, keyword: &str) -> bool {
+        self.peek().is_some_and(|token| token.is(keyword))
+    }
+
+    const fn is_done(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+
+    /// Consume the keywords if they come next, in order.
+    fn eat(&mut self, keywords: &[&str]) -> bool {
+        let matched = keywords.iter().enumerate().all(|(offset, keyword)| {
+            self.tokens
+                .get(self.position + offset)
+                .is_some_and(|token| token.is(keyword))
+        });
+        if matched {
+            self.position += keywords.len();
+        }
+        matched
+    }
+
+    fn expect(&mut self, keywords: &[&str]) -> Result<()> {
+        if self.eat(keywords) {
+            Ok(())
+        } else {
+            Err(eyre!("expected {}", keywords.join(" ")))
+        }
+    }
+
+    fn word(&mut self) -> Result<String> {
+        match self.tokens.get(self.position) {
+            Some(Token::Word(word)) => {
+                self.position += 1;
+                Ok(word.clone())
+            }
+            other => Err(eyre!("expected an identifier, found {other:?}")),
+        }
+    }
+
+    /// Consume a parenthesised group and return the tokens inside it.
+    fn group(&mut self) -> Result<&'a [Token]> {
+        if self.peek() != Some(&Token::Symbol('(')) {
+            bail!("expected '('");
+        }
+        let start = self.position + 1;
+        let mut depth = 0usize;
+        while let Some(token) = self.tokens.get(self.position) {
+            self.position += 1;
+            match token {
+                Token::Symbol('(') => depth += 1,
+                Token::Symbol(')') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(&self.tokens[start..self.position - 1]);
+                    }
+                }
+                _ => {}
+            }
+        }
+        bail!("unbalanced parentheses")
+    }
+
+    /// Consume a parenthesised list of column names.
+    fn columns(&mut self) -> Result<Vec<String>> {
+        split_top_level(self.group()?)
+            .into_iter()
+            .map(|column| match column.first() {
+                Some(Token::Word(name)) => Ok(name.clone()),
+                other => Err(eyre!("expected a column name, found {other:?}")),
+            })
+            .collect()
+    }
+
+    /// Whether a `KEY` or `INDEX` keyword starts an index definition rather
+    /// than a column that happens to be named `key` or `index`.
+    fn is_index_definition(&self) -> bool {
+        let open = Some(&Token::Symbol('('));
+        self.tokens.get(self.position + 1) == open
+            || (matches!(self.tokens.get(self.position + 1), Some(Token::Word(_)))
+                && self.tokens.get(self.position + 2) == open)
+    }
+
+    fn skip(&mut self) {
+        if self.peek() == Some(&Token::Symbol('(')) {
+            let _ = self.group();
+        } else {
+            self.position += 1;
+        }
+    }
+}
+
+/// A foreign key as tracked while replaying, with its constraint name.
+#[derive(Debug, Clone)]
+struct NamedForeignKey {
+    name: String,
+    columns: Vec<String>,
+    to_table: String,
+    to_columns: Vec<String>,
+}
+
+/// An index or unique constraint as tracked while replaying.
+#[derive(Debug, Clone)]
+struct NamedIndex {
+    name: String,
+    columns: Vec<String>,
+    unique: bool,
+}
+
+/// A table as tracked while replaying, in declaration order.
+#[derive(Debug, Clone, Default)]
+struct ModelTable {
+    columns: Vec<Column>,
+    primary_key: Vec<String>,
+    foreign_keys: Vec<NamedForeignKey>,
+    indexes: Vec<NamedIndex>,
+}
+
+impl ModelTable {
+    fn column_mut(&mut self, name: &str) -> Option<&mut Column> {
+        self.columns.iter_mut().find(|column| column.name == name)
+    }
+
+    /// `MySQL` names unnamed keys after their first column.
+    fn next_index_name(&self, first_column: &str) -> String {
+        let taken = |name: &str| self.indexes.iter().any(|index| index.name == name);
+        if !taken(first_column) {
+            return first_column.to_string();
+        }
+        (2..=self.indexes.len() + 1)
+            .map(|n| format!("{first_column}_{n}"))
+            .find(|name| !taken(name))
+            .unwrap_or_default()
+    }
+
+    /// `MySQL` names unnamed foreign keys `<table>_ibfk_<n>`.
+    fn next_foreign_key_name(&self, table: &str) -> String {
+        (1..=self.foreign_keys.len() + 1)
+            .map(|n| format!("{table}_ibfk_{n}"))
+            .find(|name| self.foreign_keys.iter().all(|fk| &fk.name != name))
+            .unwrap_or_default()
+    }
+
+    fn add_index(&mut self, name: Option<String>, columns: Vec<String>, unique: bool) {
+        let name = name.unwrap_or_else(|| self.next_index_name(&columns[0]));
+        self.indexes.push(NamedIndex {
+            name,
+            columns,
+            unique,
+        });
+    }
+
+    fn add_foreign_key(
+        &mut self,
+        table: &str,
+        name: Option<String>,
+        columns: Vec<String>,
+        to_table: String,
+        to_columns: Vec<String>,
+    ) {
+        let name = name.unwrap_or_else(|| self.next_foreign_key_name(table));
+        self.foreign_keys.push(NamedForeignKey {
+            name,
+            columns,
+            to_table,
+            to_columns,
+        });
+    }
+
+    fn drop_column(&mut self, name: &str) -> Result<()> {
+        let before = self.columns.len();
+        self.columns.retain(|column| column.name != name);
+        if self.columns.len() == before {
+            bail!("no column {name} to drop");
+        }
+        self.primary_key.retain(|column| column != name);
+        for index in &mut self.indexes {
+            index.columns.retain(|column| column != name);
+        }
+        self.indexes.retain(|index| !index.columns.is_empty());
+        if self
+            .foreign_keys
+            .iter()
+            .any(|fk| fk.columns.iter().any(|c| c == name))
+        {
+            bail!("column {name} is still referenced by a foreign key");
+        }
+        Ok(())
+    }
+
+    fn into_table(self) -> Table {
+        let mut table = Table {
+            columns: BTreeMap::new(),
+            primary_keys: self.primary_key.iter().cloned().collect(),
+            foreign_keys: BTreeSet::new(),
+            unique_constraints: BTreeSet::new(),
+            indexes: BTreeSet::new(),
+        };
+        for mut column in self.columns {
+            if self.primary_key.contains(&column.name) {
+                column.nullable = false;
+            }
+            table.columns.insert(column.name.clone(), column);
+        }
+        for fk in self.foreign_keys {
+            for (from_column, to_column) in fk.columns.into_iter().zip(fk.to_columns) {
+                table.foreign_keys.insert(ForeignKey {
+                    from_column,
+                    to_table: fk.to_table.clone(),
+                    to_column,
+                });
+            }
+        }
+        for index in self.indexes {
+            if index.unique {
+                if index.columns != self.primary_key {
+                    table.unique_constraints.insert(UniqueConstraint {
+                        columns: index.columns,
+                    });
+                }
+            } else {
+                table.indexes.insert(Index {
+                    name: index.name,
+                    columns: index.columns,
+                });
+            }
+        }
+        table
+    }
+}
+
+/// The schema built up while replaying one migration directory.
+struct Model {
+    dialect: Dialect,
+    tables: BTreeMap<String, ModelTable>,
+}
+
+impl Model {
+    const fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            tables: BTreeMap::new(),
+        }
+    }
+
+    fn into_schema(self) -> Schema {
+        Schema {
+            tables: self
+                .tables
+                .into_iter()
+                .map(|(name, table)| (name, table.into_table()))
+                .collect(),
+        }
+    }
+
+    fn table_mut(&mut self, name: &str) -> Result<&mut ModelTable> {
+        self.tables
+            .get_mut(name)
+            .ok_or_else(|| eyre!("table {name} does not exist"))
+    }
+
+    fn apply(&mut self, tokens: &[Token]) -> Result<()> {
+        let mut cursor = Cursor::new(tokens);
+        if cursor.eat(&["CREATE", "TABLE"]) {
+            self.create_table(&mut cursor)
+        } else if cursor.eat(&["CREATE", "UNIQUE", "INDEX"]) {
+            self.create_index(&mut cursor, true)
+        } else if cursor.eat(&["CREATE", "INDEX"]) {
+            self.create_index(&mut cursor, false)
+        } else if cursor.eat(&["DROP", "TABLE"]) {
+            let if_exists = cursor.eat(&["IF", "EXISTS"]);
+            let name = cursor.word()?;
+            if self.tables.remove(&name).is_none() && !if_exists {
+                bail!("table {name} does not exist");
+            }
+            Ok(())
+        } else if cursor.eat(&["DROP", "INDEX"]) {
+            self.drop_index(&mut cursor)
+        } else if cursor.eat(&["ALTER", "TABLE"]) {
+            self.alter_table(&mut cursor)
+        } else if ["INSERT", "UPDATE", "DELETE", "PRAGMA", "SET"]
+            .iter()
+            .any(|keyword| cursor.peek_is(keyword))
+        {
+            // Data changes and session settings do not affect the schema
+            Ok(())
+        } else {
+            bail!("unsupported statement")
+        }
+    }
+
+    fn create_table(&mut self, cursor: &mut Cursor) -> Result<()> {
+        let if_not_exists = cursor.eat(&["IF", "NOT", "EXISTS"]);
+        let name = cursor.word()?;
+        if self.tables.contains_key(&name) {
+            if if_not_exists {
+                return Ok(());
+            }
+            bail!("table {name} already exists");
+        }
+
+        let mut table = ModelTable::default();
+        for definition in split_top_level(cursor.group()?) {
+            self.table_element(&name, &mut table, definition)?;
+        }
+        // Anything after the definitions is a table option such as ENGINE
+        self.tables.insert(name, table);
+        Ok(())
+    }
+
+    /// Apply one element of a table definition: a column or a constraint.
+    fn table_element(
+        &self,
+        table_name: &str,
+        table: &mut ModelTable,
+        tokens: &[Token],
+    ) -> Result<()> {
+        let mut cursor = Cursor::new(tokens);
+        let constraint_name = if cursor.eat(&["CONSTRAINT"]) {
+            Some(cursor.word()?)
+        } else {
+            None
+        };
+
+        if cursor.eat(&["PRIMARY", "KEY"]) {
+            table.primary_key = cursor.columns()?;
+        } else if cursor.eat(&["FOREIGN", "KEY"]) {
+            let columns = cursor.columns()?;
+            cursor.expect(&["REFERENCES"])?;
+            let to_table = cursor.word()?;
+            let to_columns = cursor.columns()?;
+            table.add_foreign_key(table_name, constraint_name, columns, to_table, to_columns);
+        } else if cursor.eat(&["UNIQUE"]) {
+            let _ = cursor.eat(&["KEY"]) || cursor.eat(&["INDEX"]);
+            let name = match cursor.peek() {
+                Some(Token::Word(_)) => Some(cursor.word()?),
+                _ => constraint_name,
+            };
+            let columns = cursor.columns()?;
+            table.add_index(name, columns, true);
+        } else if cursor.eat(&["CHECK"]) {
+            cursor.group()?;
+        } else if (cursor.peek_is("KEY") || cursor.peek_is("INDEX")) && cursor.is_index_definition()
+        {
+            cursor.skip();
+            let name = match cursor.peek() {
+                Some(Token::Word(_)) => Some(cursor.word()?),
+                _ => None,
+            };
+            let columns = cursor.columns()?;
+            table.add_index(name, columns, false);
+        } else {
+            self.column(table_name, table, &mut cursor)?;
+        }
+        Ok(())
+    }
+
+    /// Apply a column definition, including any inline constraints.
+    fn column(&self, table_name: &str, table: &mut ModelTable, cursor: &mut Cursor) -> Result<()> {
+        let name = cursor.word()?;
+        if table.column_mut(&name).is_some() {
+            bail!("column {name} already exists");
+        }
+        let declared_type = cursor.word()?;
+        if cursor.peek() == Some(&Token::Symbol('(')) {
+            cursor.group()?;
+        }
+
+        let mut nullable = true;
+        while !cursor.is_done() {
+            if cursor.eat(&["NOT", "NULL"]) {
+                nullable = false;
+            } else if cursor.eat(&["PRIMARY", "KEY"]) {
+                table.primary_key = vec![name.clone()];
+            } else if cursor.eat(&["UNIQUE"]) {
+                let _ = cursor.eat(&["KEY"]);
+                table.add_index(Some(name.clone()), vec![name.clone()], true);
+            } else if cursor.eat(&["REFERENCES"]) {
+                let to_table = cursor.word()?;
+                let to_columns = cursor.columns()?;
+                table.add_foreign_key(table_name, None, vec![name.clone()], to_table, to_columns);
+            } else if cursor.eat(&["DEFAULT"]) || cursor.eat(&["CHECK"]) {
+                cursor.skip();
+            } else {
+                // NULL, AUTOINCREMENT, AUTO_INCREMENT, UNSIGNED, COLLATE ... and
+                // their arguments carry no logical schema information
+                cursor.skip();
+            }
+        }
+
+        table.columns.push(Column {
+            name,
+            normalized_type: self.dialect.normalize_type(&declared_type),
+            nullable,
+        });
+        Ok(())
+    }
+
+    fn create_index(&mut self, cursor: &mut Cursor, unique: bool) -> Result<()> {
+        let if_not_exists = cursor.eat(&["IF", "NOT", "EXISTS"]);
+        let name = cursor.word()?;
+        cursor.expect(&["ON"])?;
+        let table_name = cursor.word()?;
+        let columns = cursor.columns()?;
+
+        let table = self.table_mut(&table_name)?;
+        if table.indexes.iter().any(|index| index.name == name) {
+            if if_not_exists {
+                return Ok(());
+            }
+            bail!("index {name} already exists on {table_name}");
+        }
+        table.add_index(Some(name), columns, unique);
+        Ok(())
+    }
+
+    fn drop_index(&mut self, cursor: &mut Cursor) -> Result<()> {
+        let if_exists = cursor.eat(&["IF", "EXISTS"]);
+        let name = cursor.word()?;
+        let table_name = if cursor.eat(&["ON"]) {
+            Some(cursor.word()?)
+        } else {
+            None
+        };
+
+        let dropped = self
+            .tables
+            .iter_mut()
+            .filter(|(table, _)| table_name.as_ref().is_none_or(|name| name == *table))
+            .any(|(_, table)| {
+                let before = table.indexes.len();
+                table.indexes.retain(|index| index.name != name);
+                table.indexes.len() != before
+            });
+        if !dropped && !if_exists {
+            bail!("index {name} does not exist");
+        }
+        Ok(())
+    }
+
+    fn alter_table(&mut self, cursor: &mut Cursor) -> Result<()> {
+        let name = cursor.word()?;
+        let rest = &cursor.tokens[cursor.position..];
+
+        for clause in split_top_level(rest) {
+            let mut clause = Cursor::new(clause);
+            if clause.eat(&["RENAME", "TO"]) {
+                let new_name = clause.word()?;
+                self.rename_table(&name, &new_name)?;
+                return Ok(());
+            }
+
+            let mut table = self
+                .tables
+                .remove(&name)
+                .ok_or_else(|| eyre!("table {name} does not exist"))?;
+            let result = self.alter_clause(&name, &mut table, &mut clause);
+            self.tables.insert(name.clone(), table);
+            result?;
+        }
+        Ok(())
+    }
+
+    fn alter_clause(
+        &self,
+        table_name: &str,
+        table: &mut ModelTable,
+        clause: &mut Cursor,
+    ) -> Result<()> {
+        if clause.eat(&["ADD"]) {
+            let element = &clause.tokens[clause.position..];
+            if clause.eat(&["COLUMN"]) {
+                return self.column(table_name, table, clause);
+            }
+            return self.table_element(table_name, table, element);
+        }
+        if clause.eat(&["DROP", "COLUMN"]) {
+            return table.drop_column(&clause.word()?);
+        }
+        if clause.eat(&["DROP", "FOREIGN", "KEY"]) {
+            let name = clause.word()?;
+            let before = table.foreign_keys.len();
+            table.foreign_keys.retain(|fk| fk.name != name);
+            if table.foreign_keys.len() == before {
+                bail!("foreign key {name} does not exist on {table_name}");
+            }
+            return Ok(());
+        }
+        if clause.eat(&["DROP", "INDEX"]) || clause.eat(&["DROP", "KEY"]) {
+            let name = clause.word()?;
+            let before = table.indexes.len();
+            table.indexes.retain(|index| index.name != name);
+            if table.indexes.len() == before {
+                bail!("index {name} does not exist on {table_name}");
+            }
+            return Ok(());
+        }
+        if clause.eat(&["MODIFY", "COLUMN"]) || clause.eat(&["MODIFY"]) {
+            // Redefine the column in place, keeping its position
+            let Some(Token::Word(name)) = clause.peek().cloned() else {
+                bail!("expected a column to modify");
+            };
+            let position = table
+                .columns
+                .iter()
+                .position(|column| column.name == name)
+                .ok_or_else(|| eyre!("no column {name} to modify"))?;
+            let previous = table.columns.remove(position);
+            if let Err(e) = self.column(table_name, table, clause) {
+                table.columns.insert(position, previous);
+                return Err(e);
+            }
+            let modified = table
+                .columns
+                .pop()
+                .ok_or_else(|| eyre!("column {name} was not redefined"))?;
+            table.columns.insert(position, modified);
+            return Ok(());
+        }
+        if clause.eat(&["RENAME", "COLUMN"]) {
+            let old = clause.word()?;
+            clause.expect(&["TO"])?;
+            let new = clause.word()?;
+            let column = table
+                .column_mut(&old)
+                .ok_or_else(|| eyre!("no column {old} to rename"))?;
+            column.name.clone_from(&new);
+            for columns in table
+                .indexes
+                .iter_mut()
+                .map(|index| &mut index.columns)
+                .chain(table.foreign_keys.iter_mut().map(|fk| &mut fk.columns))
+                .chain(std::iter::once(&mut table.primary_key))
+            {
+                for column in columns.iter_mut().filter(|column| **column == old) {
+                    column.clone_from(&new);
+                }
+            }
+            return Ok(());
+        }
+        bail!("unsupported ALTER TABLE clause")
+    }
+
+    /// Rename a table, following references to it as both backends do.
+    fn rename_table(&mut self, old: &str, new: &str) -> Result<()> {
+        if self.tables.contains_key(new) {
+            bail!("table {new} already exists");
+        }
+        let table = self
+            .tables
+            .remove(old)
+            .ok_or_else(|| eyre!("table {old} does not exist"))?;
+        self.tables.insert(new.to_string(), table);
+        for table in self.tables.values_mut() {
+            for fk in table
+                .foreign_keys
+                .iter_mut()
+                .filter(|fk| fk.to_table == old)
+            {
+                fk.to_table = new.to_string();
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! # Query plan audit
+//!
+//! `cargo xtask query-plans` checks that the hottest lookups are served by
+//! an index. It seeds a facility into an in-memory `SQLite` database, runs
+//! each hot path through the persistence layer while recording the SQL
+//! Diesel generates, and asks `SQLite` to `EXPLAIN QUERY PLAN` every
+//! statement. Any step that scans a whole table fails the audit.
+//!
+//! Plans are checked against `SQLite` only; the `MySQL` migrations create
+//! the same indexes, which `lint-migrations` verifies.
+
+use color_eyre::{eyre::bail, Result};
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_persistence::{
+    AuditTimelineFilter, AuditTimelineScope, Persistence, PersistenceError, QueryPlanStep,
+    SecretToken,
+};
+
+use crate::seed::{populate, Facility, ADMIN_LOGIN};
+
+/// Random seed of the audited facility.
+const SEED: u64 = 2026;
+
+/// Bid year of the audited facility.
+const YEAR: u16 = 2026;
+
+/// Controllers in the audited facility; enough for every area to have a
+/// full roster.
+const USERS: usize = 64;
+
+/// Session token created for the session lookup.
+const SESSION_TOKEN: &str = "query-plan-audit";
+
+/// A persistence call whose statements must all be index-backed.
+type HotPath = Box<dyn Fn(&mut Persistence) -> Result<(), PersistenceError>>;
+
+/// Audits the query plans of the hot paths.
+pub fn query_plans() -> Result<()> {
+    let mut persistence: Persistence = Persistence::new_in_memory()?;
+    let facility: Facility = populate(&mut persistence, SEED, YEAR, USERS)?;
+    let Some(operator) = persistence.get_operator_by_login(ADMIN_LOGIN)? else {
+        bail!("seeded admin operator not found");
+    };
+    let session_token: SecretToken = SecretToken::new(String::from(SESSION_TOKEN));
+    persistence.create_session(&session_token, operator.operator_id, "2099-01-01 00:00:00")?;
+
+    let mut scans: Vec<String> = Vec::new();
+    let mut statements: usize = 0;
+    for (name, path) in hot_paths(&mut persistence, &facility)? {
+        let (result, sql): (Result<(), PersistenceError>, Vec<String>) =
+            persistence.record_queries(|persistence| path(persistence));
+        result?;
+        if sql.is_empty() {
+            bail!("hot path '{name}' issued no statements");
+        }
+
+        for statement in sql {
+            let plan: Vec<QueryPlanStep> = persistence.explain_query_plan(&statement)?;
+            for step in &plan {
+                tracing::debug!(path = name, step = step.detail, "{statement}");
+            }
+            scans.extend(
+                plan.iter()
+                    .filter(|step| step.is_table_scan())
+                    .map(|step| format!("{name}: {}\n    {statement}", step.detail)),
+            );
+            statements += 1;
+        }
+    }
+
+    if !scans.is_empty() {
+        bail!(
+            "{} hot path statement(s) scan a whole table:\n{}",
+            scans.len(),
+            scans.join("\n")
+        );
+    }
+    tracing::info!(statements, "Every hot path statement uses an index");
+    Ok(())
+}
+
+/// Builds the hot paths against the seeded facility.
+fn hot_paths(
+    persistence: &mut Persistence,
+    facility: &Facility,
+) -> Result<Vec<(&'static str, HotPath)>> {
+    let bid_year_id: i64 = facility.bid_year_id;
+    let area_id: i64 = facility.area_ids[0];
+    let round_id: i64 = facility.round_ids[0];
+    let Some((bid_year, area)) = persistence
+        .get_bootstrap_metadata()?
+        .areas
+        .into_iter()
+        .find(|(_, area)| area.area_id() == Some(area_id))
+    else {
+        bail!("seeded area {area_id} not found");
+    };
+    let start: String = facility.start_date.to_string();
+    let end: String = (facility.start_date + time::Duration::days(13)).to_string();
+
+    let area_scope: AuditTimelineScope = AuditTimelineScope::Area {
+        bid_year_id,
+        area_id,
+    };
+    let state_bid_year: BidYear = bid_year.clone();
+    let state_area: Area = area.clone();
+    Ok(vec![
+        (
+            "audit events by scope",
+            Box::new(move |persistence: &mut Persistence| {
+                persistence.get_audit_timeline_page(
+                    area_scope,
+                    &AuditTimelineFilter::default(),
+                    Some(1),
+                    50,
+                )?;
+                Ok(())
+            }),
+        ),
+        (
+            "audit event headers after a cursor",
+            Box::new(|persistence: &mut Persistence| {
+                persistence.get_audit_event_headers_page(
+                    AuditTimelineScope::All,
+                    &AuditTimelineFilter::default(),
+                    Some(1),
+                    200,
+                )?;
+                Ok(())
+            }),
+        ),
+        (
+            "pending outbox entries",
+            Box::new(|persistence: &mut Persistence| {
+                persistence.list_pending_outbox_entries(200)?;
+                Ok(())
+            }),
+        ),
+        (
+            "session by token",
+            Box::new(|persistence: &mut Persistence| {
+                persistence.get_session_by_token(&SecretToken::new(String::from(SESSION_TOKEN)))?;
+                Ok(())
+            }),
+        ),
+        (
+            "users by bid year and area",
+            Box::new(move |persistence: &mut Persistence| {
+                persistence.list_users(&bid_year, &area)?;
+                Ok(())
+            }),
+        ),
+        (
+            "current area state",
+            Box::new(move |persistence: &mut Persistence| {
+                persistence.get_current_state(&state_bid_year, &state_area)?;
+                Ok(())
+            }),
+        ),
+        (
+            "leave bids by area",
+            Box::new(move |persistence: &mut Persistence| {
+                persistence.list_leave_bids(bid_year_id, area_id)?;
+                Ok(())
+            }),
+        ),
+        (
+            "leave bids by round and date",
+            Box::new(move |persistence: &mut Persistence| {
+                persistence.list_daily_leave_counts(bid_year_id, &start, &end)?;
+                Ok(())
+            }),
+        ),
+        (
+            "round bidders",
+            Box::new(move |persistence: &mut Persistence| {
+                persistence.list_round_bidders(area_id, round_id)?;
+                Ok(())
+            }),
+        ),
+    ])
+}
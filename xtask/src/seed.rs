@@ -0,0 +1,504 @@
+// Copyright (C) 2026 Fred Clausen
+// Use of this source code is governed by an MIT-style
+// license that can be found in the LICENSE file or at
+// https://opensource.org/licenses/MIT.
+
+//! # Development database seeding
+//!
+//! `cargo xtask seed` builds a complete facility in a fresh `SQLite`
+//! database: an admin operator, an active bid year, eight areas, a roster
+//! with a realistic spread of seniority, a round group assigned to every
+//! area, and a bid schedule.
+//!
+//! Everything is written through the API functions the server uses, so the
+//! result carries a normal audit trail. The same `--seed` always produces
+//! the same facility.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use color_eyre::{
+    eyre::{bail, eyre},
+    Result,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use time::{macros::format_description, Date, Duration, Month, Weekday};
+use zab_bid::{BootstrapMetadata, BootstrapResult};
+use zab_bid_api::{
+    create_area, create_bid_year, create_first_admin, create_round, create_round_group,
+    register_users_bulk, set_active_bid_year, set_bid_schedule, AuthenticatedActor,
+    CreateAreaRequest, CreateBidYearRequest, CreateFirstAdminRequest, CreateRoundGroupRequest,
+    CreateRoundRequest, RegisterUserRequest, RegisterUsersBulkRequest, RegisterUsersBulkResponse,
+    Role, SetActiveBidYearRequest, SetBidScheduleRequest,
+};
+use zab_bid_audit::Cause;
+use zab_bid_domain::{Area, BidYear};
+use zab_bid_persistence::{OperatorData, Persistence};
+
+/// Login name of the seeded admin operator.
+pub const ADMIN_LOGIN: &str = "admin";
+
+/// Password of the seeded admin operator. Development databases only.
+pub const ADMIN_PASSWORD: &str = "Seed-Admin-2026";
+
+/// Area codes and display names of the seeded facility.
+const AREAS: [(&str, &str); 8] = [
+    ("NORTH", "North High"),
+    ("SOUTH", "South High"),
+    ("EAST", "East Low"),
+    ("WEST", "West Low"),
+    ("ARR", "Arrival"),
+    ("DEP", "Departure"),
+    ("OCEAN", "Oceanic"),
+    ("TRNG", "Training"),
+];
+
+const FIRST_NAMES: [&str; 32] = [
+    "Alex", "Bailey", "Carmen", "Dana", "Elliot", "Frances", "Gabriel", "Harper", "Isaac",
+    "Jordan", "Kendall", "Logan", "Morgan", "Noel", "Oscar", "Parker", "Quinn", "Riley", "Sam",
+    "Taylor", "Uma", "Victor", "Wren", "Xavier", "Yara", "Zane", "Avery", "Blake", "Casey", "Drew",
+    "Emerson", "Finley",
+];
+
+const LAST_NAMES: [&str; 32] = [
+    "Anderson",
+    "Brooks",
+    "Castillo",
+    "Dalton",
+    "Ellison",
+    "Fischer",
+    "Garcia",
+    "Hughes",
+    "Ingram",
+    "Jensen",
+    "Kowalski",
+    "Lindqvist",
+    "Moreno",
+    "Nakamura",
+    "Okafor",
+    "Patel",
+    "Quintero",
+    "Ramirez",
+    "Sullivan",
+    "Thompson",
+    "Underwood",
+    "Vasquez",
+    "Whitaker",
+    "Xu",
+    "Yamamoto",
+    "Zimmerman",
+    "Abbott",
+    "Bennett",
+    "Chen",
+    "Delgado",
+    "Evans",
+    "Foster",
+];
+
+/// Options for `cargo xtask seed`.
+#[derive(Clone, Debug, clap::Args)]
+pub struct SeedArgs {
+    /// Path of the `SQLite` database to create; must not already exist
+    #[arg(long, default_value = "zabbid-dev.db")]
+    database: PathBuf,
+
+    /// Random seed; the same seed always produces the same facility
+    #[arg(long, default_value_t = 2026)]
+    seed: u64,
+
+    /// The bid year to create
+    #[arg(long, default_value_t = 2026)]
+    year: u16,
+
+    /// Number of users to spread across the areas
+    #[arg(long, default_value_t = 320)]
+    users: usize,
+}
+
+/// State shared by the seeding steps: the API session and the random source.
+struct Seeder<'a> {
+    persistence: &'a mut Persistence,
+    actor: AuthenticatedActor,
+    operator: OperatorData,
+    rng: StdRng,
+}
+
+/// The identifiers of a seeded facility, for tools that drive it afterwards.
+pub struct Facility {
+    /// The active bid year.
+    pub bid_year_id: i64,
+    /// The first day of the bid year.
+    pub start_date: Date,
+    /// The facility's areas, in creation order.
+    pub area_ids: Vec<i64>,
+    /// The round group assigned to every area.
+    pub round_group_id: i64,
+    /// The group's rounds, in round order.
+    pub round_ids: Vec<i64>,
+    /// Every registered controller's area and initials.
+    pub roster: Vec<(i64, String)>,
+}
+
+/// Populates a fresh development database.
+pub fn seed(args: &SeedArgs) -> Result<()> {
+    if args.database.exists() {
+        bail!(
+            "{} already exists; seeding only writes fresh databases",
+            args.database.display()
+        );
+    }
+    check_users(args.users)?;
+
+    let mut persistence: Persistence = Persistence::new_with_file(&args.database)?;
+    let facility: Facility = populate(&mut persistence, args.seed, args.year, args.users)?;
+
+    tracing::info!(
+        database = %args.database.display(),
+        seed = args.seed,
+        bid_year = args.year,
+        areas = facility.area_ids.len(),
+        users = facility.roster.len(),
+        round_group_id = facility.round_group_id,
+        "Seeded development database (log in as {ADMIN_LOGIN} / {ADMIN_PASSWORD})"
+    );
+    Ok(())
+}
+
+/// Checks a roster size can be seeded.
+pub fn check_users(users: usize) -> Result<()> {
+    // Two-letter initials must be unique within the bid year
+    if users == 0 || users > 26 * 26 {
+        bail!("--users must be between 1 and {}", 26 * 26);
+    }
+    Ok(())
+}
+
+/// Seeds a facility into an empty database of either backend.
+///
+/// `users` must already have passed [`check_users`].
+pub fn populate(
+    persistence: &mut Persistence,
+    seed: u64,
+    year: u16,
+    users: usize,
+) -> Result<Facility> {
+    create_first_admin(
+        persistence,
+        CreateFirstAdminRequest {
+            login_name: String::from(ADMIN_LOGIN),
+            display_name: String::from("Seed Admin"),
+            password: String::from(ADMIN_PASSWORD),
+            password_confirmation: String::from(ADMIN_PASSWORD),
+        },
+    )?;
+    let operator: OperatorData = persistence
+        .get_operator_by_login(ADMIN_LOGIN)?
+        .ok_or_else(|| eyre!("seeded admin operator not found"))?;
+
+    let mut seeder: Seeder = Seeder {
+        persistence,
+        actor: AuthenticatedActor::new(String::from(ADMIN_LOGIN), Role::Admin),
+        operator,
+        rng: StdRng::seed_from_u64(seed),
+    };
+
+    let (bid_year_id, start_date) = seeder.bid_year(year)?;
+    let area_ids: Vec<i64> = seeder.areas()?;
+    let roster: Vec<(usize, String)> = seeder.roster(year, users)?;
+    let (round_group_id, round_ids) = seeder.round_group(bid_year_id, &area_ids)?;
+    seeder.schedule(bid_year_id, year)?;
+
+    Ok(Facility {
+        bid_year_id,
+        start_date,
+        roster: roster
+            .into_iter()
+            .map(|(area, initials)| (area_ids[area], initials))
+            .collect(),
+        area_ids,
+        round_group_id,
+        round_ids,
+    })
+}
+
+impl Seeder<'_> {
+    fn cause() -> Cause {
+        Cause::new(
+            String::from("xtask-seed"),
+            String::from("Development fixture data"),
+        )
+    }
+
+    /// Creates the bid year starting on the first Sunday of January and
+    /// makes it active.
+    ///
+    /// Returns the bid year and its start date.
+    fn bid_year(&mut self, year: u16) -> Result<(i64, Date)> {
+        let start_date: Date = first_weekday(i32::from(year), Month::January, Weekday::Sunday)?;
+        let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+        let result: BootstrapResult = create_bid_year(
+            &metadata,
+            &CreateBidYearRequest {
+                year,
+                start_date,
+                num_pay_periods: 26,
+            },
+            &self.actor,
+            &self.operator,
+            Self::cause(),
+        )?;
+        self.persistence.persist_bootstrap(&result)?;
+
+        let bid_year_id: i64 = self
+            .persistence
+            .get_bootstrap_metadata()?
+            .bid_years
+            .iter()
+            .find(|by| by.year() == year)
+            .and_then(BidYear::bid_year_id)
+            .ok_or_else(|| eyre!("bid year {year} missing after creation"))?;
+        self.persistence
+            .create_system_area(bid_year_id, Area::NO_BID_AREA_CODE)?;
+
+        let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+        set_active_bid_year(
+            self.persistence,
+            &metadata,
+            &SetActiveBidYearRequest { bid_year_id },
+            &self.actor,
+            &self.operator,
+            Self::cause(),
+        )?;
+
+        Ok((bid_year_id, start_date))
+    }
+
+    /// Creates the facility's areas in the active bid year.
+    fn areas(&mut self) -> Result<Vec<i64>> {
+        let mut area_ids: Vec<i64> = Vec::new();
+        for (code, name) in AREAS {
+            let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+            let result: BootstrapResult = create_area(
+                self.persistence,
+                &metadata,
+                &CreateAreaRequest {
+                    area_id: String::from(code),
+                },
+                &self.actor,
+                &self.operator,
+                Self::cause(),
+            )?;
+            self.persistence.persist_bootstrap(&result)?;
+
+            let area_id: i64 = self
+                .persistence
+                .get_bootstrap_metadata()?
+                .areas
+                .iter()
+                .find(|(_, area)| area.area_code() == code && !area.is_system_area())
+                .and_then(|(_, area)| area.area_id())
+                .ok_or_else(|| eyre!("area {code} missing after creation"))?;
+            self.persistence.update_area_name(area_id, Some(name))?;
+            area_ids.push(area_id);
+        }
+        Ok(area_ids)
+    }
+
+    /// Registers the roster, spread evenly across areas and crews.
+    ///
+    /// Returns each controller's area, as an index into [`AREAS`], and
+    /// initials.
+    fn roster(&mut self, year: u16, count: usize) -> Result<Vec<(usize, String)>> {
+        let bid_year_start: Date = Date::from_calendar_date(i32::from(year), Month::January, 1)?;
+        let mut taken: HashSet<String> = HashSet::new();
+        let mut lottery: Vec<u32> = (1..=u32::try_from(count)?).collect();
+        lottery.shuffle(&mut self.rng);
+
+        let mut users: Vec<RegisterUserRequest> = Vec::with_capacity(count);
+        let mut roster: Vec<(usize, String)> = Vec::with_capacity(count);
+        for (index, lottery_value) in lottery.into_iter().enumerate() {
+            let first: &str = FIRST_NAMES[self.rng.random_range(0..FIRST_NAMES.len())];
+            let last: &str = LAST_NAMES[self.rng.random_range(0..LAST_NAMES.len())];
+            let initials: String = self.initials(first, last, &mut taken);
+            let (area, _) = AREAS[index % AREAS.len()];
+            roster.push((index % AREAS.len(), initials.clone()));
+            let crew: u8 = u8::try_from(index / AREAS.len() % 7)? + 1;
+            let seniority: Seniority = self.seniority(bid_year_start);
+
+            users.push(RegisterUserRequest {
+                initials,
+                name: format!("{first} {last}"),
+                area: String::from(area),
+                user_type: String::from(seniority.user_type),
+                crew: Some(crew),
+                cumulative_natca_bu_date: format_date(seniority.cumulative_natca_bu_date)?,
+                natca_bu_date: format_date(seniority.natca_bu_date)?,
+                eod_faa_date: format_date(seniority.eod_faa_date)?,
+                service_computation_date: format_date(seniority.service_computation_date)?,
+                lottery_value: Some(lottery_value),
+            });
+        }
+
+        let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+        let response: RegisterUsersBulkResponse = register_users_bulk(
+            self.persistence,
+            &metadata,
+            &RegisterUsersBulkRequest {
+                users,
+                all_or_nothing: true,
+            },
+            &self.actor,
+            &self.operator,
+            &Self::cause(),
+        )?;
+        if response.failed_count > 0 {
+            bail!("{} seeded users failed to register", response.failed_count);
+        }
+        Ok(roster)
+    }
+
+    /// Picks unused initials, preferring the name's own.
+    fn initials(&mut self, first: &str, last: &str, taken: &mut HashSet<String>) -> String {
+        let mut initials: String = format!("{}{}", &first[..1], &last[..1]).to_uppercase();
+        while taken.contains(&initials) {
+            initials = [
+                char::from(self.rng.random_range(b'A'..=b'Z')),
+                char::from(self.rng.random_range(b'A'..=b'Z')),
+            ]
+            .iter()
+            .collect();
+        }
+        taken.insert(initials.clone());
+        initials
+    }
+
+    /// Draws a controller's seniority dates.
+    ///
+    /// Years of service are skewed toward the junior end, as at a facility
+    /// that hires steadily and loses senior controllers to retirement.
+    /// Trainees are the most recent hires; some controllers carry prior
+    /// military time in their service computation date or transferred in
+    /// with earlier bargaining unit time.
+    fn seniority(&mut self, bid_year_start: Date) -> Seniority {
+        let draw: i64 = self.rng.random_range(0..=1000);
+        let days_of_service: i64 = 60 + draw * draw * 28 * 365 / 1_000_000;
+        let eod_faa_date: Date = bid_year_start - Duration::days(days_of_service);
+
+        let user_type: &'static str = match days_of_service / 365 {
+            0 => "Dev-D",
+            1 => "Dev-R",
+            2 | 3 if self.rng.random_bool(0.5) => "CPC-IT",
+            _ => "CPC",
+        };
+
+        let service_computation_date: Date = if self.rng.random_bool(0.2) {
+            eod_faa_date - Duration::days(self.rng.random_range(365..=8 * 365))
+        } else {
+            eod_faa_date
+        };
+
+        let natca_bu_date: Date =
+            (eod_faa_date + Duration::days(self.rng.random_range(0..=730))).min(bid_year_start);
+        let cumulative_natca_bu_date: Date = if self.rng.random_bool(0.15) {
+            natca_bu_date - Duration::days(self.rng.random_range(365..=3 * 365))
+        } else {
+            natca_bu_date
+        };
+
+        Seniority {
+            user_type,
+            cumulative_natca_bu_date,
+            natca_bu_date,
+            eod_faa_date,
+            service_computation_date,
+        }
+    }
+
+    /// Creates a five-round group and assigns it to every area.
+    ///
+    /// Returns the group and its rounds.
+    fn round_group(&mut self, bid_year_id: i64, area_ids: &[i64]) -> Result<(i64, Vec<i64>)> {
+        let round_group_id: i64 = create_round_group(
+            self.persistence,
+            bid_year_id,
+            &CreateRoundGroupRequest {
+                name: String::from("Standard"),
+                editing_enabled: true,
+            },
+            &self.actor,
+        )?
+        .round_group_id;
+
+        let mut round_ids: Vec<i64> = Vec::new();
+        for round_number in 1..=5 {
+            let round_id: i64 = create_round(
+                self.persistence,
+                round_group_id,
+                &CreateRoundRequest {
+                    round_group_id,
+                    round_number,
+                    name: format!("Round {round_number}"),
+                    slots_per_day: 2,
+                    max_groups: if round_number == 1 { 2 } else { 1 },
+                    max_total_hours: if round_number == 1 { 80 } else { 40 },
+                    include_holidays: round_number > 2,
+                    allow_overbid: false,
+                },
+                &self.actor,
+            )?
+            .round_id;
+            round_ids.push(round_id);
+        }
+
+        for area_id in area_ids {
+            self.persistence
+                .update_area_round_group(*area_id, Some(round_group_id))?;
+        }
+        Ok((round_group_id, round_ids))
+    }
+
+    /// Schedules bidding to open on the first Monday of October before the
+    /// bid year.
+    fn schedule(&mut self, bid_year_id: i64, year: u16) -> Result<()> {
+        let start_date: Date = first_weekday(i32::from(year) - 1, Month::October, Weekday::Monday)?;
+        let metadata: BootstrapMetadata = self.persistence.get_bootstrap_metadata()?;
+        set_bid_schedule(
+            self.persistence,
+            &metadata,
+            &SetBidScheduleRequest {
+                bid_year_id,
+                timezone: Some(String::from("America/New_York")),
+                start_date: format_date(start_date)?,
+                window_start_time: String::from("08:00:00"),
+                window_end_time: String::from("16:00:00"),
+                bidders_per_day: 4,
+            },
+            &self.actor,
+            &self.operator,
+            Self::cause(),
+        )?;
+        Ok(())
+    }
+}
+
+/// A controller's drawn seniority dates.
+struct Seniority {
+    user_type: &'static str,
+    cumulative_natca_bu_date: Date,
+    natca_bu_date: Date,
+    eod_faa_date: Date,
+    service_computation_date: Date,
+}
+
+/// Returns the first `weekday` of a month.
+fn first_weekday(year: i32, month: Month, weekday: Weekday) -> Result<Date> {
+    let mut date: Date = Date::from_calendar_date(year, month, 1)?;
+    while date.weekday() != weekday {
+        date = date.next_day().ok_or_else(|| eyre!("date out of range"))?;
+    }
+    Ok(date)
+}
+
+fn format_date(date: Date) -> Result<String> {
+    Ok(date.format(format_description!("[year]-[month]-[day]"))?)
+}